@@ -8,10 +8,11 @@ use super::AccessToken;
 use crate::{
     Server,
     auth::{
-        AccessScope, AccessTo, AccessTokenInner, AccountTenantIds, Permissions, RECOVERY_ADMIN_ID,
+        AccessScope, AccessTo, AccessTokenInner, AccountTenantIds, DOMAIN_FLAG_REQUIRE_MFA,
+        NetworkPolicy, Permissions, RECOVERY_ADMIN_ID,
         permissions::{BuildPermissions, PermissionsListBuilder},
     },
-    network::limiter::{ConcurrencyLimiter, LimiterResult},
+    network::limiter::{BandwidthLimiter, ConcurrencyLimiter, LimiterResult},
 };
 use ahash::AHasher;
 use registry::{
@@ -123,14 +124,33 @@ impl Server {
                     }
                 }
 
+                // Logins are restricted to the allowed networks, countries or ASNs
+                let network_policy = (!account.allowed_networks.is_empty()
+                    || !account.allowed_countries.is_empty()
+                    || !account.allowed_asns.is_empty())
+                .then(|| {
+                    Box::new(NetworkPolicy {
+                        networks: account.allowed_networks.into_inner().into_boxed_slice(),
+                        countries: account.allowed_countries.into_inner().into_boxed_slice(),
+                        asns: account
+                            .allowed_asns
+                            .into_inner()
+                            .into_iter()
+                            .map(|asn| asn as u32)
+                            .collect(),
+                    })
+                });
+
                 let now = now();
                 let mut credential_scopes = Vec::with_capacity(account.credentials.len());
+                let mut has_mfa = false;
 
                 credential_scopes.push(AccessScope::new(permissions.finalize(), u32::MAX));
 
                 for credential in account.credentials {
                     match credential {
                         structs::Credential::Password(credential) => {
+                            has_mfa |= credential.otp_auth.is_some();
                             if credential.expires_at.is_some() || !credential.allowed_ips.is_empty()
                             {
                                 let credential_scope = &mut credential_scopes[0];
@@ -182,6 +202,18 @@ impl Server {
                     }
                 }
 
+                // Accounts that have not enrolled in two-factor authentication are restricted
+                // to changing their password until they do so. App passwords are not affected.
+                if !has_mfa
+                    && self
+                        .is_mfa_required(account.domain_id.document_id(), &member_of)
+                        .await?
+                {
+                    let credential_scope = &mut credential_scopes[0];
+                    credential_scope.permissions =
+                        downgrade_permissions(&credential_scope.permissions);
+                }
+
                 Ok(AccessTokenInner {
                     concurrent_imap_requests: self
                         .core
@@ -198,8 +230,15 @@ impl Server {
                         .jmap
                         .upload_max_concurrent
                         .map(ConcurrencyLimiter::new),
+                    imap_bandwidth: self
+                        .core
+                        .imap
+                        .bandwidth_account
+                        .as_ref()
+                        .map(|limit| BandwidthLimiter::new(limit.rate, limit.burst)),
                     obj_size: 0,
                     revision,
+                    network_policy,
                     revision_account,
                     account_id,
                     tenant_id,
@@ -240,8 +279,15 @@ impl Server {
                         .jmap
                         .upload_max_concurrent
                         .map(ConcurrencyLimiter::new),
+                    imap_bandwidth: self
+                        .core
+                        .imap
+                        .bandwidth_account
+                        .as_ref()
+                        .map(|limit| BandwidthLimiter::new(limit.rate, limit.burst)),
                     obj_size: 0,
                     revision,
+                    network_policy: None,
                     revision_account,
                     account_id,
                     tenant_id,
@@ -302,6 +348,28 @@ impl Server {
         }
     }
 
+    async fn is_mfa_required(&self, domain_id: u32, member_of: &[u32]) -> trc::Result<bool> {
+        if self
+            .domain_by_id(domain_id)
+            .await?
+            .is_some_and(|domain| domain.flags & DOMAIN_FLAG_REQUIRE_MFA != 0)
+        {
+            return Ok(true);
+        }
+
+        for group_id in member_of {
+            if self
+                .try_account(*group_id)
+                .await?
+                .is_some_and(|group| group.requires_mfa())
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
     pub(crate) async fn access_token_from_account(
         &self,
         account_id: u32,
@@ -516,22 +584,8 @@ impl AccessToken {
                 let mut scopes = Vec::with_capacity(access_token.inner.scopes.len());
                 for (idx, scope) in access_token.inner.scopes.iter().enumerate() {
                     if idx == 0 {
-                        let mut permissions = Permissions::new();
-
-                        for permission in [
-                            Permission::Authenticate,
-                            Permission::AuthenticateWithAlias,
-                            Permission::SysAccountPasswordGet,
-                            Permission::SysAccountPasswordUpdate,
-                            Permission::EmailReceive,
-                        ] {
-                            if scope.permissions.get(permission as usize) {
-                                permissions.set(permission as usize);
-                            }
-                        }
-
                         scopes.push(AccessScope {
-                            permissions,
+                            permissions: downgrade_permissions(&scope.permissions),
                             credential_id: scope.credential_id,
                             expires_at: u64::MAX,
                             allowed_ips: scope.allowed_ips.clone(),
@@ -550,6 +604,8 @@ impl AccessToken {
                     concurrent_http_requests: old_inner.concurrent_http_requests.clone(),
                     concurrent_imap_requests: old_inner.concurrent_imap_requests.clone(),
                     concurrent_uploads: old_inner.concurrent_uploads.clone(),
+                    imap_bandwidth: old_inner.imap_bandwidth.clone(),
+                    network_policy: old_inner.network_policy.clone(),
                     revision_account: old_inner.revision_account,
                     revision: old_inner.revision,
                     obj_size: old_inner.obj_size,
@@ -710,6 +766,10 @@ impl AccessToken {
             .map_or(LimiterResult::Disabled, |limiter| limiter.is_allowed())
     }
 
+    pub fn imap_bandwidth(&self) -> Option<&BandwidthLimiter> {
+        self.inner.imap_bandwidth.as_ref()
+    }
+
     pub fn is_upload_allowed(&self) -> LimiterResult {
         self.inner
             .concurrent_uploads
@@ -758,7 +818,9 @@ impl AccessToken {
                 concurrent_http_requests: Default::default(),
                 concurrent_imap_requests: Default::default(),
                 concurrent_uploads: Default::default(),
+                imap_bandwidth: Default::default(),
                 revision: Default::default(),
+                network_policy: Default::default(),
                 revision_account: Default::default(),
                 obj_size: Default::default(),
             }),
@@ -802,7 +864,9 @@ impl AccessTokenInner {
             concurrent_http_requests: Default::default(),
             concurrent_imap_requests: Default::default(),
             concurrent_uploads: Default::default(),
+            imap_bandwidth: Default::default(),
             revision: Default::default(),
+            network_policy: Default::default(),
             revision_account: Default::default(),
             obj_size: Default::default(),
         }
@@ -828,6 +892,24 @@ impl AccessScope {
     }
 }
 
+fn downgrade_permissions(permissions: &Permissions) -> Permissions {
+    let mut downgraded = Permissions::new();
+
+    for permission in [
+        Permission::Authenticate,
+        Permission::AuthenticateWithAlias,
+        Permission::SysAccountPasswordGet,
+        Permission::SysAccountPasswordUpdate,
+        Permission::EmailReceive,
+    ] {
+        if permissions.get(permission as usize) {
+            downgraded.set(permission as usize);
+        }
+    }
+
+    downgraded
+}
+
 fn hash_account(account: &Account) -> u64 {
     let mut s = AHasher::default();
 
@@ -859,6 +941,9 @@ fn hash_account(account: &Account) -> u64 {
             for group_id in account.member_group_ids.iter() {
                 group_id.hash(&mut s);
             }
+            account.allowed_networks.as_slice().hash(&mut s);
+            account.allowed_countries.as_slice().hash(&mut s);
+            account.allowed_asns.as_slice().hash(&mut s);
         }
         Account::Group(account) => {
             account.member_tenant_id.hash(&mut s);
@@ -38,11 +38,40 @@ pub struct Username {
 
 impl Server {
     pub async fn authenticate(&self, req: &AuthRequest) -> trc::Result<AccessToken> {
-        match Box::pin(self.route_auth_request(req))
+        // Slow down attempts against accounts with recent failures
+        let login = req.username().unwrap_or_default();
+        let auth_delay = self.auth_delay(login).await?;
+        if let Some((failures, delay)) = auth_delay {
+            trc::event!(
+                Security(trc::SecurityEvent::AuthenticationDelayed),
+                AccountName = login.to_string(),
+                RemoteIp = req.remote_ip,
+                SpanId = req.session_id,
+                TotalFailures = failures,
+                Elapsed = delay,
+            );
+
+            tokio::time::sleep(delay).await;
+        }
+
+        let result = match Box::pin(self.route_auth_request(req))
             .await
             .and_then(|token| token.assert_has_permission(Permission::Authenticate))
         {
-            Ok(token) => Ok(token),
+            Ok(token) => self
+                .assert_network_policy(&token, req.remote_ip)
+                .await
+                .map(|_| token),
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(token) => {
+                if auth_delay.is_some() {
+                    self.reset_auth_delay(login).await?;
+                }
+                Ok(token)
+            }
             Err(err) => {
                 // Random delay to mitigate user enumeration attacks
                 #[cfg(not(feature = "test_mode"))]
@@ -53,10 +82,16 @@ impl Server {
                     tokio::time::sleep(std::time::Duration::from_millis(delay)).await;
                 }
 
+                if err.matches(trc::EventType::Auth(trc::AuthEvent::Failed)) {
+                    self.inner.data.statistics.record_auth_failure();
+                    self.record_auth_delay_failure(login).await?;
+                }
+
                 if matches!(
                     err.as_ref(),
                     trc::EventType::Auth(trc::AuthEvent::Failed)
                         | trc::EventType::Security(trc::SecurityEvent::IpUnauthorized)
+                        | trc::EventType::Security(trc::SecurityEvent::NetworkPolicyBlocked)
                 ) && self.has_auth_fail2ban()
                     && self
                         .is_auth_fail2banned(req.remote_ip, req.username())
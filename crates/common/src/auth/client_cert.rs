@@ -0,0 +1,136 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{AccessToken, BuildAccessToken, EmailCache};
+use crate::Server;
+use registry::schema::enums::Permission;
+use std::net::IpAddr;
+use trc::AddContext;
+use x509_parser::{parse_x509_certificate, prelude::GeneralName};
+
+#[derive(Debug, Clone, Default)]
+pub struct ClientCertificate {
+    pub subject: String,
+    pub subject_alt_names: Vec<String>,
+    pub emails: Vec<String>,
+}
+
+impl ClientCertificate {
+    pub fn parse(der: &[u8]) -> Option<Self> {
+        let (_, cert) = parse_x509_certificate(der).ok()?;
+        let mut result = ClientCertificate {
+            subject: cert.subject().to_string(),
+            ..Default::default()
+        };
+
+        if let Ok(Some(san)) = cert.subject_alternative_name() {
+            for name in &san.value.general_names {
+                match name {
+                    GeneralName::RFC822Name(email) => {
+                        result.subject_alt_names.push(email.to_string());
+                        result.emails.push(email.to_lowercase());
+                    }
+                    GeneralName::DNSName(name) | GeneralName::URI(name) => {
+                        result.subject_alt_names.push(name.to_string());
+                    }
+                    _ => {}
+                }
+            }
+        }
+
+        // Older certificates carry the address in the subject instead
+        for email in cert
+            .subject()
+            .iter_email()
+            .filter_map(|email| email.as_str().ok())
+        {
+            let email = email.to_lowercase();
+            if !result.emails.contains(&email) {
+                result.emails.push(email);
+            }
+        }
+
+        Some(result)
+    }
+}
+
+impl Server {
+    // Maps a validated client certificate to the account owning one of its
+    // email addresses, returns None if no address belongs to a local account.
+    pub async fn authenticate_client_certificate(
+        &self,
+        cert: &ClientCertificate,
+        remote_ip: IpAddr,
+    ) -> trc::Result<Option<AccessToken>> {
+        for email in &cert.emails {
+            if let Some(EmailCache::Account(account_id)) = self
+                .rcpt_id_from_email(email)
+                .await
+                .caused_by(trc::location!())?
+            {
+                let access_token = self
+                    .access_token(account_id)
+                    .await
+                    .caused_by(trc::location!())?
+                    .build()
+                    .assert_has_permission(Permission::Authenticate)?;
+                self.assert_network_policy(&access_token, remote_ip).await?;
+
+                return Ok(Some(access_token));
+            }
+        }
+
+        Ok(None)
+    }
+
+    // SASL EXTERNAL authentication, an authorization identity requested by the
+    // client has to be one of the addresses listed in its certificate.
+    pub async fn authenticate_sasl_external(
+        &self,
+        cert: &ClientCertificate,
+        authz_id: &str,
+        session_id: u64,
+        remote_ip: IpAddr,
+    ) -> trc::Result<AccessToken> {
+        let access_token = if authz_id.is_empty() {
+            self.authenticate_client_certificate(cert, remote_ip)
+                .await?
+        } else if cert
+            .emails
+            .iter()
+            .any(|email| email.eq_ignore_ascii_case(authz_id))
+        {
+            self.authenticate_client_certificate(
+                &ClientCertificate {
+                    emails: vec![authz_id.to_lowercase()],
+                    ..cert.clone()
+                },
+                remote_ip,
+            )
+            .await?
+        } else {
+            None
+        };
+
+        if let Some(access_token) = access_token {
+            trc::event!(
+                Auth(trc::AuthEvent::ClientCertificateSuccess),
+                SpanId = session_id,
+                AccountId = access_token.account_id(),
+                RemoteIp = remote_ip,
+                Details = cert.subject.clone(),
+            );
+
+            Ok(access_token)
+        } else {
+            Err(trc::AuthEvent::ClientCertificateFailed
+                .into_err()
+                .ctx(trc::Key::SpanId, session_id)
+                .ctx(trc::Key::RemoteIp, remote_ip)
+                .details(cert.subject.clone()))
+        }
+    }
+}
@@ -0,0 +1,207 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{KV_LOGIN_HISTORY, Server};
+use registry::{
+    schema::{
+        enums::NetworkListenerProtocol,
+        prelude::{ObjectType, Property},
+        structs::LoginEntry,
+    },
+    types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, index::IndexBuilder, ipaddr},
+};
+use std::{fmt::Write, net::IpAddr};
+use store::{
+    ValueKey, blake3,
+    dispatch::lookup::KeyValue,
+    write::{BatchBuilder, RegistryClass, ValueClass, now},
+};
+use trc::AddContext;
+
+// Repeated logins from the same device and address are recorded once a day
+const LOGIN_HISTORY_DEDUP: u64 = 86400;
+
+pub trait LoginHistoryIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+impl LoginHistoryIndex for LoginEntry {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::LoginEntry.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::AccountId, self.account_id.id());
+        index_builder.search(Property::Timestamp, self.timestamp.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        if is_set {
+            batch.set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(key);
+        }
+    }
+}
+
+impl Server {
+    pub fn is_login_history_enabled(&self) -> bool {
+        self.core.email.login_history_retention.is_some()
+    }
+
+    // Returns the id of the new entry, or None if the device was already
+    // recorded recently or the login history is disabled.
+    pub async fn record_login(
+        &self,
+        account_id: u32,
+        protocol: NetworkListenerProtocol,
+        remote_ip: IpAddr,
+        user_agent: Option<&str>,
+    ) -> Option<u64> {
+        let expires_in = self.core.email.login_history_retention?;
+        let user_agent = user_agent
+            .map(|ua| ua.trim())
+            .filter(|ua| !ua.is_empty())
+            .map(|ua| ua.chars().take(255).collect::<String>());
+        let fingerprint = login_fingerprint(protocol, user_agent.as_deref());
+
+        // Skip devices that were seen recently from the same address
+        let key = login_dedup_key(account_id, &fingerprint, remote_ip);
+        match self
+            .in_memory_store()
+            .key_exists(KeyValue::<()>::build_key(KV_LOGIN_HISTORY, &key))
+            .await
+        {
+            Ok(true) => return None,
+            Ok(false) => {}
+            Err(err) => {
+                trc::error!(
+                    err.account_id(account_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to lookup login history")
+                );
+                return None;
+            }
+        }
+
+        let timestamp = now();
+        let item_id = self.inner.data.registry_id_gen.generate();
+        let mut batch = BatchBuilder::new();
+        LoginEntry {
+            account_id: account_id.into(),
+            protocol,
+            remote_ip: ipaddr::IpAddr(remote_ip),
+            user_agent,
+            fingerprint,
+            timestamp: UTCDateTime::from_timestamp(timestamp as i64),
+            expires_at: UTCDateTime::from_timestamp((timestamp + expires_in.as_secs()) as i64),
+        }
+        .write_ops(&mut batch, item_id, true);
+
+        let result = self
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!());
+        let result = match result {
+            Ok(_) => {
+                self.in_memory_store()
+                    .key_set(
+                        KeyValue::with_prefix(KV_LOGIN_HISTORY, &key, vec![])
+                            .expires(LOGIN_HISTORY_DEDUP),
+                    )
+                    .await
+            }
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(_) => Some(item_id),
+            Err(err) => {
+                trc::error!(
+                    err.account_id(account_id)
+                        .details("Failed to write login history")
+                );
+                None
+            }
+        }
+    }
+
+    // Used by protocols that identify the client after authenticating,
+    // such as IMAP clients issuing the ID command once logged in.
+    pub async fn update_login_user_agent(&self, item_id: u64, user_agent: &str) {
+        let object_id = ObjectType::LoginEntry.to_id();
+        let user_agent = user_agent.trim();
+        if user_agent.is_empty() {
+            return;
+        }
+
+        let result = async {
+            let Some(mut entry) = self
+                .store()
+                .get_value::<LoginEntry>(ValueKey::from(ValueClass::Registry(
+                    RegistryClass::Item { object_id, item_id },
+                )))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                return Ok(());
+            };
+
+            let mut batch = BatchBuilder::new();
+            entry.user_agent = Some(user_agent.chars().take(255).collect());
+            entry.fingerprint = login_fingerprint(entry.protocol, entry.user_agent.as_deref());
+            entry.write_ops(&mut batch, item_id, true);
+            self.store()
+                .write(batch.build_all())
+                .await
+                .caused_by(trc::location!())?;
+
+            // Later logins from the identified client are not recorded again
+            self.in_memory_store()
+                .key_set(
+                    KeyValue::with_prefix(
+                        KV_LOGIN_HISTORY,
+                        login_dedup_key(
+                            entry.account_id.document_id(),
+                            &entry.fingerprint,
+                            entry.remote_ip.0,
+                        ),
+                        vec![],
+                    )
+                    .expires(LOGIN_HISTORY_DEDUP),
+                )
+                .await
+        }
+        .await;
+
+        if let Err(err) = result {
+            trc::error!(err.details("Failed to update login history"));
+        }
+    }
+}
+
+fn login_dedup_key(account_id: u32, fingerprint: &str, remote_ip: IpAddr) -> Vec<u8> {
+    let mut key = Vec::with_capacity(fingerprint.len() + 20);
+    key.extend_from_slice(&account_id.to_be_bytes());
+    key.extend_from_slice(fingerprint.as_bytes());
+    key.extend_from_slice(remote_ip.to_string().as_bytes());
+    key
+}
+
+// Identifies a client by protocol and normalized user agent
+pub fn login_fingerprint(protocol: NetworkListenerProtocol, user_agent: Option<&str>) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(&protocol.to_id().to_be_bytes());
+    if let Some(user_agent) = user_agent {
+        hasher.update(user_agent.trim().to_lowercase().as_bytes());
+    }
+
+    let mut fingerprint = String::with_capacity(16);
+    for byte in &hasher.finalize().as_bytes()[..8] {
+        let _ = write!(fingerprint, "{byte:02x}");
+    }
+    fingerprint
+}
@@ -5,14 +5,15 @@
  */
 
 use crate::{
+    config::mailstore::email::DefaultFolder,
     expr::if_block::IfBlock,
-    network::limiter::ConcurrencyLimiter,
+    network::limiter::{BandwidthLimiter, ConcurrencyLimiter},
     storage::{ObjectQuota, TenantQuota},
 };
 use directory::Credentials;
 use quick_cache::Equivalent;
 use registry::{
-    schema::enums::{Locale, Permission},
+    schema::enums::{Locale, MailingListReplyTo, MailingListSenderRewrite, Permission},
     types::{EnumImpl, ipmask::IpAddrOrMask},
 };
 use std::{
@@ -27,7 +28,10 @@ use utils::{cache::CacheItemWeight, map::bitmap::Bitmap};
 
 pub mod access_token;
 pub mod authentication;
+pub mod client_cert;
 pub mod credential;
+pub mod login_history;
+pub mod network_policy;
 pub mod oauth;
 pub mod permissions;
 pub mod rate_limit;
@@ -62,17 +66,22 @@ pub struct DomainCache {
     pub id_tenant: Option<u32>,
     pub catch_all: Option<Box<str>>,
     pub sub_addressing_custom: Option<Box<IfBlock>>,
+    pub default_folders: Option<Box<[DefaultFolder]>>,
+    pub shared_folder: Option<Box<str>>,
     pub flags: u8,
 }
 
 pub const DOMAIN_FLAG_RELAY: u8 = 1;
 pub const DOMAIN_FLAG_SUB_ADDRESSING: u8 = 1 << 1;
+pub const DOMAIN_FLAG_VIRTUAL_FOLDERS: u8 = 1 << 2;
+pub const DOMAIN_FLAG_REQUIRE_MFA: u8 = 1 << 3;
 
 #[derive(Debug, Clone, Default)]
 pub struct AccountCache {
     pub name: Box<str>,
     pub id: u32,
     pub addresses: Box<[EmailAddress]>,
+    pub addresses_expire_at: Option<u64>,
     pub id_tenant: Option<u32>,
     pub id_member_of: TinyVec<[u32; 3]>,
     pub quota_disk: u64,
@@ -80,6 +89,8 @@ pub struct AccountCache {
     pub description: Option<Box<str>>,
     pub encryption_key: Option<EncryptionKeys>,
     pub locale: Locale,
+    pub data_region: Option<Box<str>>,
+    pub undo_send_window: u64,
     pub flags: u64,
 }
 
@@ -92,6 +103,7 @@ pub const ACCOUNT_FLAG_ENCRYPT_METHOD_PGP: u64 = 1 << 3;
 pub const ACCOUNT_FLAG_ENCRYPT_ALGO_AES256: u64 = 1 << 4;
 pub const ACCOUNT_FLAG_ENCRYPT_ALGO_AES128: u64 = 1 << 5;
 pub const ACCOUNT_FLAG_ENCRYPT_APPEND: u64 = 1 << 6;
+pub const ACCOUNT_FLAG_REQUIRE_MFA: u64 = 1 << 7;
 
 #[derive(Debug, Clone)]
 pub struct RoleCache {
@@ -99,9 +111,22 @@ pub struct RoleCache {
     pub permissions: PermissionsGroup,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct MailingListCache {
+    pub id: u32,
+    pub address: Box<str>,
     pub recipients: Arc<[Box<str>]>,
+    pub policy: Option<Box<MailingListPolicy>>,
+}
+
+// Lists without a policy are expanded in place, like an alias
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct MailingListPolicy {
+    pub sender_rewrite: MailingListSenderRewrite,
+    pub subject_prefix: Option<Box<str>>,
+    pub reply_to: MailingListReplyTo,
+    pub members_only: bool,
+    pub archive: Option<(u32, Box<str>)>,
 }
 
 #[derive(Debug, Clone)]
@@ -135,6 +160,8 @@ pub struct AccessTokenInner {
     pub(crate) concurrent_http_requests: Option<ConcurrencyLimiter>,
     pub(crate) concurrent_imap_requests: Option<ConcurrencyLimiter>,
     pub(crate) concurrent_uploads: Option<ConcurrencyLimiter>,
+    pub(crate) imap_bandwidth: Option<BandwidthLimiter>,
+    pub(crate) network_policy: Option<Box<NetworkPolicy>>,
     pub(crate) revision_account: u64,
     pub(crate) revision: u64,
     pub(crate) obj_size: u64,
@@ -148,6 +175,13 @@ pub struct AccessScope {
     pub allowed_ips: Box<[IpAddrOrMask]>,
 }
 
+#[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
+pub struct NetworkPolicy {
+    pub networks: Box<[IpAddrOrMask]>,
+    pub countries: Box<[String]>,
+    pub asns: Box<[u32]>,
+}
+
 #[derive(Debug, Default, Hash, PartialEq, Eq, Clone)]
 pub(crate) struct AccessTo {
     pub account_id: u32,
@@ -196,10 +230,23 @@ impl CacheItemWeight for DomainCache {
         std::mem::size_of::<DomainCache>() as u64
             + self.names.iter().map(|s| s.len() as u64).sum::<u64>()
             + self.catch_all.as_ref().map_or(0, |s| s.len() as u64)
+            + self.shared_folder.as_ref().map_or(0, |s| s.len() as u64)
             + self
                 .sub_addressing_custom
                 .as_ref()
                 .map_or(0, |s| s.weight())
+            + self.default_folders.as_ref().map_or(0, |folders| {
+                folders
+                    .iter()
+                    .map(|f| {
+                        (std::mem::size_of::<DefaultFolder>() + f.name.len()) as u64
+                            + f.localized_names
+                                .iter()
+                                .map(|(_, name)| name.len() as u64)
+                                .sum::<u64>()
+                    })
+                    .sum::<u64>()
+            })
     }
 }
 
@@ -232,6 +279,7 @@ impl CacheItemWeight for AccountCache {
                 .map(|s| s.local_part.len() as u64 + std::mem::size_of::<EmailAddress>() as u64)
                 .sum::<u64>()
             + self.description.as_ref().map_or(0, |s| s.len() as u64)
+            + self.data_region.as_ref().map_or(0, |s| s.len() as u64)
     }
 }
 
@@ -245,7 +293,12 @@ impl CacheItemWeight for MailingListCache {
     fn weight(&self) -> u64 {
         std::mem::size_of::<MailingListCache>() as u64
             //+ self.addresses.iter().map(|s| s.len() as u64).sum::<u64>()
+            + self.address.len() as u64
             + self.recipients.iter().map(|s| s.len() as u64).sum::<u64>()
+            + self
+                .policy
+                .as_ref()
+                .map_or(0, |_| std::mem::size_of::<MailingListPolicy>() as u64)
     }
 }
 
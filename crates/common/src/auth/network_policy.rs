@@ -0,0 +1,51 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::AccessToken;
+use crate::Server;
+use std::net::IpAddr;
+
+impl Server {
+    pub async fn assert_network_policy(
+        &self,
+        access_token: &AccessToken,
+        remote_ip: IpAddr,
+    ) -> trc::Result<()> {
+        let Some(policy) = access_token.inner.network_policy.as_deref() else {
+            return Ok(());
+        };
+
+        if policy.networks.iter().any(|mask| mask.matches(&remote_ip)) {
+            return Ok(());
+        }
+
+        // Countries and ASNs require the ASN/GeoIP lookup to be configured
+        let (asn, country) = if !policy.asns.is_empty() || !policy.countries.is_empty() {
+            let result = self.lookup_asn_country(remote_ip).await;
+            (result.asn.map(|asn| asn.id), result.country)
+        } else {
+            (None, None)
+        };
+        if asn.is_some_and(|asn| policy.asns.contains(&asn))
+            || country.as_ref().is_some_and(|country| {
+                policy
+                    .countries
+                    .iter()
+                    .any(|allowed| allowed.eq_ignore_ascii_case(country))
+            })
+        {
+            return Ok(());
+        }
+
+        Err(trc::SecurityEvent::NetworkPolicyBlocked
+            .into_err()
+            .ctx(trc::Key::AccountId, access_token.account_id())
+            .ctx(trc::Key::RemoteIp, remote_ip)
+            .ctx_opt(trc::Key::Id, asn)
+            .ctx_opt(trc::Key::Value, country.map(|country| country.to_string()))
+            .reason("Login not allowed from this network."))
+    }
+}
@@ -15,6 +15,7 @@ pub const DEVICE_CODE_LEN: usize = 40;
 pub const USER_CODE_LEN: usize = 8;
 pub const RANDOM_CODE_LEN: usize = 32;
 pub const CLIENT_ID_MAX_LEN: usize = 100;
+pub const DEVICE_POLL_INTERVAL: u64 = 5;
 
 pub const USER_CODE_ALPHABET: &[u8] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789"; // No 0, O, I, 1
 
@@ -26,6 +27,7 @@ pub enum GrantType {
     LiveMetrics,
     LiveDelivery,
     Rsvp,
+    QuarantineRelease,
 }
 
 impl GrantType {
@@ -37,6 +39,7 @@ impl GrantType {
             GrantType::LiveMetrics => "live_metrics",
             GrantType::LiveDelivery => "live_delivery",
             GrantType::Rsvp => "rsvp",
+            GrantType::QuarantineRelease => "quarantine_release",
         }
     }
 
@@ -48,6 +51,7 @@ impl GrantType {
             GrantType::LiveMetrics => 3,
             GrantType::LiveDelivery => 4,
             GrantType::Rsvp => 5,
+            GrantType::QuarantineRelease => 6,
         }
     }
 
@@ -59,6 +63,7 @@ impl GrantType {
             3 => Some(GrantType::LiveMetrics),
             4 => Some(GrantType::LiveDelivery),
             5 => Some(GrantType::Rsvp),
+            6 => Some(GrantType::QuarantineRelease),
             _ => None,
         }
     }
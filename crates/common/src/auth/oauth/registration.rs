@@ -20,6 +20,10 @@ pub struct ClientRegistrationRequest {
     #[serde(skip_serializing_if = "Vec::is_empty")]
     pub grant_types: Vec<String>,
 
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<String>,
+
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub application_type: Option<ApplicationType>,
@@ -44,7 +44,7 @@ impl Server {
         // Build context
         let mut password_hash = String::new();
 
-        if !matches!(grant_type, GrantType::Rsvp) {
+        if !matches!(grant_type, GrantType::Rsvp | GrantType::QuarantineRelease) {
             if client_id.len() > CLIENT_ID_MAX_LEN {
                 return Err(trc::AuthEvent::Error
                     .into_err()
@@ -163,7 +163,9 @@ impl Server {
         }
 
         // Obtain password hash
-        let password_hash = if !matches!(grant_type, GrantType::Rsvp) && expiry - issued_at > 3600 {
+        let password_hash = if !matches!(grant_type, GrantType::Rsvp | GrantType::QuarantineRelease)
+            && expiry - issued_at > 3600
+        {
             self.password_hash(account_id)
                 .await
                 .map_err(|err| trc::AuthEvent::Error.into_err().ctx(trc::Key::Details, err))?
@@ -255,3 +257,18 @@ impl Server {
         }
     }
 }
+
+// Obtains the client id of an already validated access token
+pub fn access_token_client_id(token: &str) -> Option<String> {
+    let token = general_purpose::URL_SAFE_NO_PAD
+        .decode(token.as_bytes())
+        .ok()?;
+    let mut bytes = token
+        .get((RANDOM_CODE_LEN + SymmetricEncrypt::ENCRYPT_TAG_LEN)..)?
+        .iter();
+    bytes.next_leb128::<u32>()?;
+    GrantType::from_id(bytes.next().copied()?)?;
+    bytes.next_leb128::<u64>()?;
+    bytes.next_leb128::<u64>()?;
+    Some(bytes.copied().map(char::from).collect())
+}
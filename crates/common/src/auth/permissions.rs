@@ -277,6 +277,9 @@ impl Default for DefaultPermissions {
                         || name.starts_with("sysAccountSettings")
                         || name.starts_with("sysPublicKey")
                         || (name.starts_with("sysSpamTrainingSample") && !name.contains("Create"))
+                        || (name.starts_with("sysQuarantinedMessage")
+                            && !name.contains("Create")
+                            && !name.contains("Update"))
                     {
                         default.user.push(permission);
                         default.group.push(permission);
@@ -284,6 +287,9 @@ impl Default for DefaultPermissions {
                     } else if name.starts_with("sysAccountPassword")
                         || name.starts_with("sysApiKey")
                         || name.starts_with("sysAppPassword")
+                        || (name.starts_with("sysLoginEntry")
+                            && !name.contains("Create")
+                            && !name.contains("Update"))
                     {
                         default.user.push(permission);
                         default.superuser.push(permission);
@@ -297,6 +303,8 @@ impl Default for DefaultPermissions {
                         || name.starts_with("sysExternalReport")
                         || name.starts_with("sysDnsServer")
                         || name.starts_with("sysQueuedMessage")
+                        || (name.starts_with("sysTenantUsage")
+                            && (name.ends_with("Get") || name.ends_with("Query")))
                     {
                         default.tenant.push(permission);
                         default.superuser.push(permission);
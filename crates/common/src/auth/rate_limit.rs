@@ -5,19 +5,62 @@
  */
 
 use crate::auth::AccessToken;
-use crate::network::ip_to_bytes;
 use crate::network::limiter::{InFlight, LimiterResult};
-use crate::{KV_RATE_LIMIT_HTTP_ANONYMOUS, KV_RATE_LIMIT_HTTP_AUTHENTICATED, Server};
-use registry::schema::enums::Permission;
+use crate::{
+    KV_RATE_LIMIT_HTTP_ANONYMOUS, KV_RATE_LIMIT_HTTP_AUTHENTICATED, KV_RATE_LIMIT_HTTP_CLASS,
+    KV_RATE_LIMIT_HTTP_CLIENT, Server,
+};
+use registry::schema::{enums::Permission, structs::Rate};
 use std::net::IpAddr;
 use trc::AddContext;
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpRateLimitClass {
+    Upload,
+    Query,
+    Set,
+    Admin,
+}
+
+// Identifies the integration behind a request, so that one client can't starve
+// the others sharing the same account
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpClientId {
+    OAuth(String),
+    Credential(u32),
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpRateLimitStatus {
+    pub name: &'static str,
+    pub limit: u64,
+    pub remaining: u64,
+    pub window: u64,
+    pub reset: u64,
+}
+
 impl Server {
     pub async fn is_http_authenticated_request_allowed(
         &self,
         access_token: &AccessToken,
+        client_id: Option<&HttpClientId>,
         addr: IpAddr,
     ) -> trc::Result<Option<InFlight>> {
+        // Enforce per-client limit
+        if let Some(client_id) = client_id
+            && let Some(rate) = &self.core.network.http.rate_client
+        {
+            self.http_rate_limit(
+                KV_RATE_LIMIT_HTTP_CLIENT,
+                "client",
+                rate,
+                access_token,
+                client_id.key(access_token.account_id(), None),
+                addr,
+            )
+            .await?;
+        }
+
         let rate_reset = if let Some(rate) = &self.core.network.http.rate_authenticated {
             if self.is_ip_allowed(addr) {
                 None
@@ -74,7 +117,7 @@ impl Server {
                 .memory
                 .is_rate_allowed(
                     KV_RATE_LIMIT_HTTP_ANONYMOUS,
-                    &ip_to_bytes(&addr),
+                    &self.ip_rate_key(&addr),
                     rate,
                     false,
                 )
@@ -89,6 +132,81 @@ impl Server {
         Ok(())
     }
 
+    pub async fn is_http_class_request_allowed(
+        &self,
+        access_token: &AccessToken,
+        client_id: Option<&HttpClientId>,
+        class: HttpRateLimitClass,
+        addr: IpAddr,
+    ) -> trc::Result<Option<HttpRateLimitStatus>> {
+        let rate = match class {
+            HttpRateLimitClass::Upload => &self.core.network.http.rate_upload,
+            HttpRateLimitClass::Query => &self.core.network.http.rate_query,
+            HttpRateLimitClass::Set => &self.core.network.http.rate_set,
+            HttpRateLimitClass::Admin => &self.core.network.http.rate_admin,
+        };
+
+        if let Some(rate) = rate {
+            let key = match client_id {
+                Some(client_id) => client_id.key(access_token.account_id(), Some(class)),
+                None => {
+                    let mut key = access_token.account_id().to_be_bytes().to_vec();
+                    key.push(class as u8);
+                    key
+                }
+            };
+            self.http_rate_limit(
+                KV_RATE_LIMIT_HTTP_CLASS,
+                class.as_str(),
+                rate,
+                access_token,
+                key,
+                addr,
+            )
+            .await
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn http_rate_limit(
+        &self,
+        prefix: u8,
+        name: &'static str,
+        rate: &Rate,
+        access_token: &AccessToken,
+        key: Vec<u8>,
+        addr: IpAddr,
+    ) -> trc::Result<Option<HttpRateLimitStatus>> {
+        if self.is_ip_allowed(addr) || access_token.has_permission(Permission::UnlimitedRequests) {
+            return Ok(None);
+        }
+
+        let (requests, reset) = self
+            .core
+            .storage
+            .memory
+            .rate_usage(prefix, &key, rate, false)
+            .await
+            .caused_by(trc::location!())?;
+
+        if requests <= rate.count {
+            Ok(Some(HttpRateLimitStatus {
+                name,
+                limit: rate.count,
+                remaining: rate.count - requests,
+                window: rate.period.as_secs(),
+                reset,
+            }))
+        } else {
+            Err(trc::LimitEvent::TooManyRequests
+                .into_err()
+                .ctx(trc::Key::Expires, reset)
+                .ctx(trc::Key::Limit, rate.count)
+                .ctx(trc::Key::Type, name))
+        }
+    }
+
     pub fn is_upload_allowed(&self, access_token: &AccessToken) -> trc::Result<Option<InFlight>> {
         match access_token.is_upload_allowed() {
             LimiterResult::Allowed(in_flight) => Ok(Some(in_flight)),
@@ -105,3 +223,35 @@ impl Server {
         }
     }
 }
+
+impl HttpRateLimitClass {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            HttpRateLimitClass::Upload => "upload",
+            HttpRateLimitClass::Query => "query",
+            HttpRateLimitClass::Set => "set",
+            HttpRateLimitClass::Admin => "admin",
+        }
+    }
+}
+
+impl HttpClientId {
+    fn key(&self, account_id: u32, class: Option<HttpRateLimitClass>) -> Vec<u8> {
+        let mut key = Vec::with_capacity(16);
+        key.extend_from_slice(&account_id.to_be_bytes());
+        if let Some(class) = class {
+            key.push(class as u8);
+        }
+        match self {
+            HttpClientId::OAuth(client_id) => {
+                key.push(0);
+                key.extend_from_slice(client_id.as_bytes());
+            }
+            HttpClientId::Credential(credential_id) => {
+                key.push(1);
+                key.extend_from_slice(&credential_id.to_be_bytes());
+            }
+        }
+        key
+    }
+}
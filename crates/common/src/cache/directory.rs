@@ -89,6 +89,7 @@ impl Server {
                             domain_id: Id::from(alias_domain.id),
                             enabled: true,
                             description: None,
+                            expires_at: None,
                         });
                         has_changes = true;
                     }
@@ -168,6 +169,7 @@ impl Server {
                             domain_id: Id::from(alias_domain.id),
                             enabled: true,
                             description: None,
+                            expires_at: None,
                         });
 
                         self.invalidate_local_negative_account_cache(local, alias_domain.id);
@@ -291,6 +293,7 @@ impl Server {
                             domain_id: Id::from(alias_domain.id),
                             enabled: true,
                             description: None,
+                            expires_at: None,
                         });
                         has_changes = true;
                     }
@@ -342,6 +345,7 @@ impl Server {
                             domain_id: Id::from(alias_domain.id),
                             enabled: true,
                             description: None,
+                            expires_at: None,
                         });
 
                         self.invalidate_local_negative_account_cache(local, alias_domain.id);
@@ -23,6 +23,7 @@ use types::id::Id;
 #[derive(Debug, Default)]
 pub struct CacheInvalidationBuilder {
     changes: AHashSet<CacheInvalidation>,
+    linked_access_tokens: AHashSet<ObjectId>,
 }
 
 impl CacheInvalidationBuilder {
@@ -45,6 +46,7 @@ impl CacheInvalidationBuilder {
                 let aliases_changed = current.aliases != new.aliases;
                 let credentials_changed = current.credentials != new.credentials;
                 let encryption_changed = current.encryption_at_rest != new.encryption_at_rest;
+                let region_changed = current.data_region != new.data_region;
 
                 if was_renamed
                     || aliases_changed
@@ -53,6 +55,7 @@ impl CacheInvalidationBuilder {
                     || quota_changed
                     || details_changed
                     || encryption_changed
+                    || region_changed
                 {
                     self.invalidate(CacheInvalidation::Account(id));
                 }
@@ -84,12 +87,16 @@ impl CacheInvalidationBuilder {
                 let details_changed =
                     current.locale != new.locale || current.description != new.description;
                 let aliases_changed = current.aliases != new.aliases;
+                let region_changed = current.data_region != new.data_region;
+                let mfa_changed = current.require_mfa != new.require_mfa;
 
                 if was_renamed
                     || aliases_changed
                     || tenant_changed
                     || quota_changed
                     || details_changed
+                    || region_changed
+                    || mfa_changed
                 {
                     self.invalidate(CacheInvalidation::Account(id));
                 }
@@ -98,6 +105,13 @@ impl CacheInvalidationBuilder {
                     self.invalidate(CacheInvalidation::AccessToken(id));
                 }
 
+                if mfa_changed {
+                    self.invalidate_linked_access_tokens(ObjectId::new(
+                        ObjectType::Account,
+                        id.into(),
+                    ));
+                }
+
                 if was_renamed {
                     self.invalidate(CacheInvalidation::DavResources(id));
                 }
@@ -111,10 +125,18 @@ impl CacheInvalidationBuilder {
                     || (current.sub_addressing != new.sub_addressing)
                     || (current.allow_relaying != new.allow_relaying)
                     || (current.is_enabled != new.is_enabled)
+                    || (current.require_mfa != new.require_mfa)
                 {
                     self.invalidate(CacheInvalidation::Domain(id));
                 }
 
+                if current.require_mfa != new.require_mfa {
+                    self.invalidate_linked_access_tokens(ObjectId::new(
+                        ObjectType::Domain,
+                        id.into(),
+                    ));
+                }
+
                 if current.logo != new.logo {
                     self.invalidate(CacheInvalidation::DomainLogo(id));
                 }
@@ -198,6 +220,11 @@ impl CacheInvalidationBuilder {
         self.changes.insert(change);
     }
 
+    /// Invalidates the access tokens of all accounts linking to the given object.
+    pub fn invalidate_linked_access_tokens(&mut self, id: ObjectId) {
+        self.linked_access_tokens.insert(id);
+    }
+
     pub fn with_invalidation(mut self, change: CacheInvalidation) -> Self {
         self.invalidate(change);
         self
@@ -206,11 +233,25 @@ impl CacheInvalidationBuilder {
 
 impl Server {
     pub async fn invalidate_caches(&self, changes: CacheInvalidationBuilder) -> trc::Result<()> {
-        let mut changes = changes.changes;
-        if changes.is_empty() {
+        let CacheInvalidationBuilder {
+            mut changes,
+            linked_access_tokens,
+        } = changes;
+        if changes.is_empty() && linked_access_tokens.is_empty() {
             return Ok(());
         }
 
+        // Invalidate accounts linking to objects with access policy changes
+        for object_id in linked_access_tokens {
+            for linked_object in self.registry().linked_objects(object_id).await? {
+                if linked_object.object() == ObjectType::Account {
+                    changes.insert(CacheInvalidation::AccessToken(
+                        linked_object.id().document_id(),
+                    ));
+                }
+            }
+        }
+
         // Invalidate objects linking roles
         let mut role_ids = changes
             .iter()
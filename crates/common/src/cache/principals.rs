@@ -9,12 +9,14 @@ use crate::{
     auth::{
         ACCOUNT_FLAG_ENCRYPT_ALGO_AES128, ACCOUNT_FLAG_ENCRYPT_ALGO_AES256,
         ACCOUNT_FLAG_ENCRYPT_APPEND, ACCOUNT_FLAG_ENCRYPT_METHOD_PGP,
-        ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME, ACCOUNT_FLAG_ENCRYPT_TRAIN_SPAM_FILTER, ACCOUNT_IS_USER,
-        AccountCache, AccountInfo, AccountTenantIds, DOMAIN_FLAG_RELAY, DOMAIN_FLAG_SUB_ADDRESSING,
-        DomainCache, EmailAddress, EmailAddressRef, EmailCache, MailingListCache, PermissionsGroup,
-        RECOVERY_ADMIN_ID, RoleCache, TenantCache, permissions::BuildPermissions,
+        ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME, ACCOUNT_FLAG_ENCRYPT_TRAIN_SPAM_FILTER,
+        ACCOUNT_FLAG_REQUIRE_MFA, ACCOUNT_IS_USER, AccountCache, AccountInfo, AccountTenantIds,
+        DOMAIN_FLAG_RELAY, DOMAIN_FLAG_REQUIRE_MFA, DOMAIN_FLAG_SUB_ADDRESSING,
+        DOMAIN_FLAG_VIRTUAL_FOLDERS, DomainCache, EmailAddress, EmailAddressRef, EmailCache,
+        MailingListCache, MailingListPolicy, PermissionsGroup, RECOVERY_ADMIN_ID, RoleCache,
+        TenantCache, permissions::BuildPermissions,
     },
-    config::smtp::auth::DkimSigner,
+    config::{mailstore::email::merge_default_folders, smtp::auth::DkimSigner},
     expr::if_block::BootstrapExprExt,
     network::mta::AddressResolver,
     storage::{
@@ -24,10 +26,13 @@ use crate::{
 };
 use registry::{
     schema::{
-        enums::{DkimRotationStage, Locale, StorageQuota, TenantStorageQuota},
+        enums::{
+            DkimRotationStage, Locale, MailingListReplyTo, MailingListSenderRewrite, StorageQuota,
+            TenantStorageQuota,
+        },
         prelude::{ObjectType, Property},
         structs::{
-            Account, DkimSignature, Domain, EncryptionAtRest, MailingList, MaskedEmail,
+            Account, DkimSignature, Domain, EmailAlias, EncryptionAtRest, MailingList, MaskedEmail,
             Permissions, PublicKey, Role, SubAddressing, Tenant,
         },
     },
@@ -140,6 +145,12 @@ impl Server {
                 if domain.allow_relaying {
                     flags |= DOMAIN_FLAG_RELAY;
                 }
+                if domain.show_virtual_folders {
+                    flags |= DOMAIN_FLAG_VIRTUAL_FOLDERS;
+                }
+                if domain.require_mfa {
+                    flags |= DOMAIN_FLAG_REQUIRE_MFA;
+                }
                 let sub_addressing_custom = match domain.sub_addressing {
                     SubAddressing::Enabled => {
                         flags |= DOMAIN_FLAG_SUB_ADDRESSING;
@@ -162,6 +173,20 @@ impl Server {
                     SubAddressing::Disabled => None,
                 };
 
+                let (default_folders, shared_folder) = if !domain.default_folders.is_empty() {
+                    let (default_folders, shared_folder) = merge_default_folders(
+                        &self.core.email.default_folders,
+                        &self.core.email.shared_folder,
+                        domain.default_folders,
+                    );
+                    (
+                        Some(default_folders.into_boxed_slice()),
+                        Some(shared_folder.into_boxed_str()),
+                    )
+                } else {
+                    (None, None)
+                };
+
                 let cache = Arc::new(DomainCache {
                     names: [domain.name.into_boxed_str()]
                         .into_iter()
@@ -177,6 +202,8 @@ impl Server {
                     id_tenant: domain.member_tenant_id.map(|id| id.document_id()),
                     catch_all: domain.catch_all_address.map(|s| s.into_boxed_str()),
                     sub_addressing_custom,
+                    default_folders,
+                    shared_folder,
                     flags,
                 });
 
@@ -291,6 +318,7 @@ impl Server {
                     .into(),
                 id: RECOVERY_ADMIN_ID,
                 addresses: Default::default(),
+                addresses_expire_at: Default::default(),
                 id_tenant: Default::default(),
                 id_member_of: Default::default(),
                 quota_disk: Default::default(),
@@ -298,6 +326,8 @@ impl Server {
                 description: Some("Recovery admin account".into()),
                 encryption_key: Default::default(),
                 locale: Default::default(),
+                data_region: Default::default(),
+                undo_send_window: Default::default(),
                 flags: Default::default(),
             }))
         } else {
@@ -317,7 +347,11 @@ impl Server {
             .get_value_or_guard_async(&account_id)
             .await
         {
-            Ok(account) => {
+            Ok(account)
+                if account
+                    .addresses_expire_at
+                    .is_none_or(|expires_at| expires_at > now()) =>
+            {
                 trc::event!(
                     Store(StoreEvent::CacheHit),
                     Key = account_id,
@@ -326,6 +360,11 @@ impl Server {
 
                 Ok(Some(account))
             }
+            Ok(_) => {
+                // An alias has expired since the account was cached
+                self.inner.cache.accounts.remove(&account_id);
+                Box::pin(self.try_account(account_id)).await
+            }
             Err(guard) => {
                 trc::event!(
                     Store(StoreEvent::CacheMiss),
@@ -388,11 +427,14 @@ impl Server {
                             if settings.encrypt_on_append {
                                 flags |= ACCOUNT_FLAG_ENCRYPT_APPEND;
                             }
-                            if let Some(public_key) = self
-                                .registry()
-                                .object::<PublicKey>(settings.public_key)
-                                .await
-                                .caused_by(trc::location!())?
+                            // Without a public key the key is obtained from the
+                            // key management service at encryption time
+                            if settings.public_key.is_valid()
+                                && let Some(public_key) = self
+                                    .registry()
+                                    .object::<PublicKey>(settings.public_key)
+                                    .await
+                                    .caused_by(trc::location!())?
                             {
                                 parse_public_key(&public_key)
                                     .unwrap_or_default()
@@ -414,6 +456,8 @@ impl Server {
                             None
                         };
 
+                        let (aliases, addresses_expire_at) = active_aliases(account.aliases);
+
                         AccountCache {
                             id: account_id,
                             name: name.into_boxed_str(),
@@ -422,11 +466,9 @@ impl Server {
                                 domain_id: account.domain_id.document_id(),
                             }]
                             .into_iter()
-                            .chain(account.aliases.into_iter().map(|alias| EmailAddress {
-                                local_part: alias.name.into(),
-                                domain_id: alias.domain_id.document_id(),
-                            }))
+                            .chain(aliases)
                             .collect(),
+                            addresses_expire_at,
                             id_tenant: account.member_tenant_id.map(|id| id.document_id()),
                             id_member_of: account
                                 .member_group_ids
@@ -437,6 +479,11 @@ impl Server {
                             quota_objects: quota_objects.map(Box::new),
                             description: account.description.map(Into::into),
                             locale: account.locale,
+                            data_region: account.data_region.map(Into::into),
+                            undo_send_window: account
+                                .undo_send_window
+                                .map(|window| window.into_inner().as_secs())
+                                .unwrap_or_default(),
                             encryption_key,
                             flags,
                         }
@@ -471,6 +518,8 @@ impl Server {
                             }
                         }
 
+                        let (aliases, addresses_expire_at) = active_aliases(account.aliases);
+
                         AccountCache {
                             id: account_id,
                             name: name.into_boxed_str(),
@@ -479,11 +528,9 @@ impl Server {
                                 domain_id: account.domain_id.document_id(),
                             }]
                             .into_iter()
-                            .chain(account.aliases.into_iter().map(|alias| EmailAddress {
-                                local_part: alias.name.into(),
-                                domain_id: alias.domain_id.document_id(),
-                            }))
+                            .chain(aliases)
                             .collect(),
+                            addresses_expire_at,
                             id_tenant: account.member_tenant_id.map(|id| id.document_id()),
                             id_member_of: Default::default(),
                             quota_disk,
@@ -491,7 +538,13 @@ impl Server {
                             description: account.description.map(Into::into),
                             encryption_key: None,
                             locale: account.locale,
-                            flags: 0,
+                            data_region: account.data_region.map(Into::into),
+                            undo_send_window: 0,
+                            flags: if account.require_mfa {
+                                ACCOUNT_FLAG_REQUIRE_MFA
+                            } else {
+                                0
+                            },
                         }
                     }
                 });
@@ -502,6 +555,19 @@ impl Server {
         }
     }
 
+    pub async fn account_domain(&self, account_id: u32) -> trc::Result<Option<Arc<DomainCache>>> {
+        if let Some(address) = self
+            .try_account(account_id)
+            .await?
+            .as_ref()
+            .and_then(|account| account.addresses.first())
+        {
+            self.domain_by_id(address.domain_id).await
+        } else {
+            Ok(None)
+        }
+    }
+
     pub async fn account_id_from_parts(
         &self,
         local_part: &str,
@@ -764,8 +830,40 @@ impl Server {
                 let Some(list) = self.registry().object::<MailingList>(id.into()).await? else {
                     return Ok(None);
                 };
+                let domain = self
+                    .domain_by_id(list.domain_id.document_id())
+                    .await
+                    .caused_by(trc::location!())?;
+                let address = format!(
+                    "{}@{}",
+                    list.name,
+                    domain
+                        .as_ref()
+                        .and_then(|domain| domain.names.first())
+                        .map(|name| name.as_ref())
+                        .unwrap_or_default()
+                );
+                let policy = (list.sender_rewrite != MailingListSenderRewrite::Disabled
+                    || list.subject_prefix.is_some()
+                    || list.reply_to != MailingListReplyTo::Unchanged
+                    || list.members_only
+                    || list.archive_account_id.is_some())
+                .then(|| {
+                    Box::new(MailingListPolicy {
+                        sender_rewrite: list.sender_rewrite,
+                        subject_prefix: list.subject_prefix.map(Into::into),
+                        reply_to: list.reply_to,
+                        members_only: list.members_only,
+                        archive: list
+                            .archive_account_id
+                            .map(|id| (id.document_id(), list.archive_folder.into_boxed_str())),
+                    })
+                });
                 let cache = Arc::new(MailingListCache {
+                    id,
+                    address: address.to_lowercase().into_boxed_str(),
                     recipients: list.recipients.into_iter().map(Into::into).collect(),
+                    policy,
                 });
                 let _ = guard.insert(cache.clone());
                 Ok(Some(cache))
@@ -889,6 +987,12 @@ impl AccountCache {
         self.id
     }
 
+    pub fn has_address(&self, local_part: &str, domain_id: u32) -> bool {
+        self.addresses.iter().any(|address| {
+            address.domain_id == domain_id && address.local_part.eq_ignore_ascii_case(local_part)
+        })
+    }
+
     #[inline(always)]
     pub fn name(&self) -> &str {
         self.name.as_ref()
@@ -919,6 +1023,16 @@ impl AccountCache {
         self.quota_objects.as_deref()
     }
 
+    #[inline(always)]
+    pub fn data_region(&self) -> Option<&str> {
+        self.data_region.as_deref()
+    }
+
+    #[inline(always)]
+    pub fn requires_mfa(&self) -> bool {
+        self.flags & ACCOUNT_FLAG_REQUIRE_MFA != 0
+    }
+
     #[inline(always)]
     pub fn account_tenant_ids(&self) -> AccountTenantIds {
         AccountTenantIds {
@@ -927,3 +1041,31 @@ impl AccountCache {
         }
     }
 }
+
+// Returns the aliases that have not expired and the time the next one expires
+fn active_aliases(
+    aliases: impl IntoIterator<Item = EmailAlias>,
+) -> (Vec<EmailAddress>, Option<u64>) {
+    let now = now() as i64;
+    let mut expires_at: Option<u64> = None;
+    let aliases = aliases
+        .into_iter()
+        .filter_map(|alias| {
+            if let Some(alias_expires_at) = alias.expires_at.map(|at| at.timestamp()) {
+                if alias_expires_at <= now {
+                    return None;
+                }
+                expires_at = Some(expires_at.map_or(alias_expires_at as u64, |at| {
+                    at.min(alias_expires_at as u64)
+                }));
+            }
+
+            Some(EmailAddress {
+                local_part: alias.name.into(),
+                domain_id: alias.domain_id.document_id(),
+            })
+        })
+        .collect();
+
+    (aliases, expires_at)
+}
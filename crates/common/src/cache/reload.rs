@@ -19,6 +19,7 @@ use directory::Directories;
 use registry::{
     schema::{prelude::ObjectType, structs::BlockedIp},
     types::error::{Error, Warning},
+    utils::secret::clear_secret_cache,
 };
 use std::sync::Arc;
 use store::{LookupStores, registry::bootstrap::Bootstrap, write::now};
@@ -96,6 +97,9 @@ impl Server {
                 }
             }
             _ => {
+                // Secrets from external providers are resolved again on reload
+                clear_secret_cache();
+
                 // Load stores
                 let directory = Directories::build(&mut bootstrap).await;
                 let storage = &self.core.storage;
@@ -147,6 +151,13 @@ impl Server {
                                 .await
                                 .ok();
 
+                            // Bind new listeners, drain removed ones and update the rest
+                            self.inner
+                                .data
+                                .listeners
+                                .update(servers, &mut bootstrap, &self.inner)
+                                .await;
+
                             return Ok(ReloadResult {
                                 errors: bootstrap.errors,
                                 warnings: bootstrap.warnings,
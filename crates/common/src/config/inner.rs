@@ -19,6 +19,7 @@ use crate::{
     },
     manager::application::WebApplications,
     network::security::BlockedIps,
+    storage::encryption::EncryptionParams,
 };
 use ahash::{AHashMap, AHashSet};
 use arc_swap::ArcSwap;
@@ -89,7 +90,11 @@ impl Data {
             queue_status: true.into(),
             applications,
             logos: Default::default(),
+            statistics: Default::default(),
+            usage: Default::default(),
             smtp_connectors: TlsConnectors::try_new().failed("Failed to build TLS connectors"),
+            listeners: Default::default(),
+            sessions: Default::default(),
             asn_geo_data: Default::default(),
         }
     }
@@ -166,6 +171,10 @@ impl Caches {
                 cache.dkim_signatures,
                 (std::mem::size_of::<DkimSigner>() + 255) as u64,
             ),
+            encryption_keys: CacheWithTtl::new(
+                cache.encryption_keys,
+                (std::mem::size_of::<EncryptionParams>() + 2048) as u64,
+            ),
             dns_txt: CacheWithTtl::new(cache.dns_txt, (std::mem::size_of::<Txt>() + 255) as u64),
             dns_mx: CacheWithTtl::new(cache.dns_mx, ((std::mem::size_of::<MX>() + 255) * 2) as u64),
             dns_ptr: CacheWithTtl::new(cache.dns_ptr, (std::mem::size_of::<IpAddr>() + 255) as u64),
@@ -229,7 +238,11 @@ impl Default for Data {
             queue_status: true.into(),
             applications: WebApplications::new(),
             logos: Default::default(),
+            statistics: Default::default(),
+            usage: Default::default(),
             smtp_connectors: TlsConnectors::try_new().unwrap(),
+            listeners: Default::default(),
+            sessions: Default::default(),
             asn_geo_data: Default::default(),
             lookup_stores: Default::default(),
         }
@@ -14,7 +14,7 @@ use jmap_proto::{
         BlobCapabilities, CalendarCapabilities, Capabilities, Capability, ContactsCapabilities,
         CoreCapabilities, EmptyCapabilities, FileNodeCapabilities, MailCapabilities,
         PrincipalAvailabilityCapabilities, PrincipalCapabilities, SieveAccountCapabilities,
-        SieveSessionCapabilities, SubmissionCapabilities,
+        SieveSessionCapabilities, SubmissionCapabilities, WebPushVapidCapabilities,
     },
     types::date::UTCDate,
 };
@@ -200,6 +200,7 @@ impl JmapConfig {
                     ("MT-PRIORITY".to_string(), vec!["MIXER".to_string()]),
                     ("REQUIRETLS".to_string(), vec![]),
                 ]),
+                undo_send_window: 0,
             }),
         );
 
@@ -276,5 +277,15 @@ impl JmapConfig {
             Capability::Quota,
             Capabilities::Empty(EmptyCapabilities::default()),
         );
+
+        // Add Web Push VAPID capabilities
+        if let Some(vapid) = &self.push_vapid {
+            self.capabilities.session.append(
+                Capability::WebPushVapid,
+                Capabilities::WebPushVapid(WebPushVapidCapabilities {
+                    application_server_key: vapid.public_key.clone(),
+                }),
+            );
+        }
     }
 }
@@ -5,17 +5,19 @@
  */
 
 use ahash::{AHashMap, AHashSet};
+use imap_proto::{parser::search::parse_stored_filters, protocol::search::Filter};
 use nlp::language::Language;
 use registry::{
     schema::{
         enums::{
-            CompressionAlgo, SearchCalendarField, SearchContactField, SearchEmailField,
-            StorageQuota,
+            CompressionAlgo, DuplicateMatch, Locale, SearchCalendarField, SearchContactField,
+            SearchEmailField, SearchFolderRole, SharedMailboxDestroy, StorageQuota,
+            VirtualFolderType,
         },
         prelude::ObjectType,
         structs::{
-            AddressBook, Authentication, Calendar, DataRetention, Domain, Email, FileStorage, Jmap,
-            Search, SieveUserInterpreter, SystemSettings,
+            AddressBook, Authentication, Calendar, DataRetention, Domain, Email, EmailFolder,
+            EncryptionKeyProvider, FileStorage, Jmap, Search, SieveUserInterpreter, SystemSettings,
         },
     },
     types::EnumImpl,
@@ -27,7 +29,7 @@ use store::{
     write::SearchIndex,
 };
 use types::special_use::SpecialUse;
-use utils::cron::SimpleCron;
+use utils::{cron::SimpleCron, map::vec_map::VecMap};
 
 use crate::storage::ObjectQuota;
 
@@ -42,21 +44,42 @@ pub struct EmailConfig {
 
     pub mail_attachments_max_size: usize,
     pub mail_max_size: usize,
+    pub max_message_keywords: usize,
+    pub max_custom_keywords: usize,
     pub mail_autoexpunge_after: Option<u64>,
     pub email_submission_autoexpunge_after: Option<u64>,
+    pub drafts_autoexpunge_after: Option<Duration>,
+    pub drafts_max_count: Option<usize>,
+    pub drafts_max_size: Option<u64>,
 
     pub changes_max_history: Option<usize>,
     pub share_notification_max_history: Option<Duration>,
+    pub audit_log_retention: Option<Duration>,
+    pub login_history_retention: Option<Duration>,
 
     pub sieve_max_script_name: usize,
+    pub sieve_max_total_size: Option<u64>,
 
     pub default_folders: Vec<DefaultFolder>,
     pub shared_folder: String,
+    pub virtual_folders: Vec<VirtualFolder>,
+    pub virtual_recent_period: Duration,
+    pub search_folders: Vec<SearchFolder>,
 
     pub encrypt: bool,
     pub encrypt_append: bool,
+    pub encryption_key_provider: EncryptionKeyProvider,
+    pub encryption_key_cache_ttl: Duration,
+    pub repair_mime: bool,
+    pub duplicate_window: Option<Duration>,
+    pub duplicate_match: DuplicateMatch,
+    pub shared_mailbox_destroy: SharedMailboxDestroy,
+    pub subscribe_on_create: SubscribeOnCreate,
 
     pub index_batch_size: usize,
+    pub index_max_body_size: Option<usize>,
+    pub reindex_batch_size: usize,
+    pub reindex_interval: Duration,
     pub index_fields: AHashMap<SearchIndex, AHashSet<SearchField>>,
 
     pub max_objects: ObjectQuota,
@@ -65,17 +88,47 @@ pub struct EmailConfig {
     pub account_purge_frequency: SimpleCron,
     pub data_purge_frequency: SimpleCron,
     pub blob_purge_frequency: SimpleCron,
+    pub blob_scrub_frequency: Option<Duration>,
+    pub blob_scrub_repair: bool,
+    pub blob_issue_retention: Option<Duration>,
 }
 
 #[derive(Clone, Debug)]
 pub struct DefaultFolder {
     pub name: String,
+    pub localized_names: Vec<(Locale, String)>,
     pub aliases: Vec<String>,
     pub special_use: SpecialUse,
     pub subscribe: bool,
     pub create: bool,
 }
 
+#[derive(Clone, Debug)]
+pub struct VirtualFolder {
+    pub name: String,
+    pub folder_type: VirtualFolderType,
+    pub subscribe: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct SearchFolder {
+    pub name: String,
+    pub filters: Vec<Filter>,
+    pub special_use: SpecialUse,
+    pub subscribe: bool,
+}
+
+// Whether new mailboxes are subscribed by their creator, depending on
+// whether they were created over IMAP, JMAP or by the server itself.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SubscribeOnCreate {
+    pub imap: bool,
+    pub jmap: bool,
+    pub auto: bool,
+}
+
+pub const MAX_SEARCH_FOLDERS: usize = 64;
+
 impl EmailConfig {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let email = bp.setting_infallible::<Email>().await;
@@ -145,52 +198,51 @@ impl EmailConfig {
         }
 
         // Parse default folders
-        let mut default_folders = Vec::new();
-        let mut shared_folder = "Shared Folders".to_string();
-        for (special_use, folder) in email.default_folders {
-            let special_use = match special_use {
-                registry::schema::enums::SpecialUse::Inbox => SpecialUse::Inbox,
-                registry::schema::enums::SpecialUse::Trash => SpecialUse::Trash,
-                registry::schema::enums::SpecialUse::Junk => SpecialUse::Junk,
-                registry::schema::enums::SpecialUse::Drafts => SpecialUse::Drafts,
-                registry::schema::enums::SpecialUse::Archive => SpecialUse::Archive,
-                registry::schema::enums::SpecialUse::Sent => SpecialUse::Sent,
-                registry::schema::enums::SpecialUse::Important => SpecialUse::Important,
-                registry::schema::enums::SpecialUse::Memos => SpecialUse::Memos,
-                registry::schema::enums::SpecialUse::Scheduled => SpecialUse::Scheduled,
-                registry::schema::enums::SpecialUse::Snoozed => SpecialUse::Snoozed,
-                registry::schema::enums::SpecialUse::Shared => {
-                    shared_folder = folder.name;
-                    continue;
-                }
-            };
-            default_folders.push(DefaultFolder {
+        let (default_folders, shared_folder) = parse_default_folders(email.default_folders);
+
+        // Parse virtual folders
+        let virtual_folders = email
+            .virtual_folders
+            .into_iter()
+            .filter(|(_, folder)| folder.create)
+            .map(|(folder_type, folder)| VirtualFolder {
                 name: folder.name,
-                aliases: folder.aliases.into_inner(),
-                special_use,
+                folder_type,
                 subscribe: folder.subscribe,
-                create: folder.create
-                    || matches!(
-                        special_use,
-                        SpecialUse::Inbox | SpecialUse::Trash | SpecialUse::Junk
-                    ),
-            });
-        }
-        for (special_use, name) in [
-            (SpecialUse::Inbox, "Inbox"),
-            (SpecialUse::Trash, "Deleted Items"),
-            (SpecialUse::Junk, "Junk Mail"),
-            (SpecialUse::Drafts, "Drafts"),
-            (SpecialUse::Sent, "Sent Items"),
-        ] {
-            if !default_folders.iter().any(|f| f.special_use == special_use) {
-                default_folders.push(DefaultFolder {
-                    name: name.to_string(),
-                    aliases: Vec::new(),
-                    special_use,
-                    subscribe: true,
-                    create: true,
-                });
+            })
+            .collect();
+
+        // Parse saved search folders
+        let mut search_folders = Vec::with_capacity(email.search_folders.len());
+        for folder in email.search_folders {
+            if search_folders.len() == MAX_SEARCH_FOLDERS {
+                bp.build_error(
+                    ObjectType::Email.singleton(),
+                    format!("Too many search folders, at most {MAX_SEARCH_FOLDERS} are allowed"),
+                );
+                break;
+            }
+
+            match parse_stored_filters(&folder.query) {
+                Ok(filters) => {
+                    search_folders.push(SearchFolder {
+                        name: folder.name,
+                        filters,
+                        special_use: match folder.role {
+                            SearchFolderRole::None => SpecialUse::None,
+                            SearchFolderRole::All => SpecialUse::All,
+                            SearchFolderRole::Flagged => SpecialUse::Flagged,
+                            SearchFolderRole::Important => SpecialUse::Important,
+                        },
+                        subscribe: folder.subscribe,
+                    });
+                }
+                Err(err) => {
+                    bp.build_error(
+                        ObjectType::Email.singleton(),
+                        format!("Invalid query for search folder {:?}: {err}", folder.name),
+                    );
+                }
             }
         }
 
@@ -273,26 +325,154 @@ impl EmailConfig {
             mailbox_name_max_len: email.max_mailbox_name_length as usize,
             mail_attachments_max_size: email.max_attachment_size as usize,
             mail_max_size: email.max_message_size as usize,
+            max_message_keywords: email.max_message_keywords as usize,
+            max_custom_keywords: email.max_custom_keywords as usize,
             mail_autoexpunge_after: dr.expunge_trash_after.map(|d| d.into_inner().as_secs()),
             email_submission_autoexpunge_after: dr
                 .expunge_submissions_after
                 .map(|d| d.into_inner().as_secs()),
+            drafts_autoexpunge_after: dr.expunge_drafts_after.map(|d| d.into_inner()),
+            drafts_max_count: email.max_drafts.map(|v| v as usize),
+            drafts_max_size: email.max_drafts_size,
             changes_max_history: dr.max_changes_history.map(|v| v as usize),
             share_notification_max_history: dr.expunge_share_notify_after.map(|v| v.into_inner()),
+            audit_log_retention: dr.hold_audit_log_for.map(|v| v.into_inner()),
+            login_history_retention: dr.hold_login_history_for.map(|v| v.into_inner()),
             sieve_max_script_name: sieve.max_script_name_length as usize,
+            sieve_max_total_size: sieve.max_total_script_size,
             encrypt: email.encrypt_at_rest,
             encrypt_append: email.encrypt_on_append,
+            encryption_key_provider: email.encryption_key_provider,
+            encryption_key_cache_ttl: email.encryption_key_cache_ttl.into_inner(),
+            repair_mime: email.repair_mime,
+            duplicate_window: email.duplicate_window.map(|v| v.into_inner()),
+            duplicate_match: email.duplicate_match,
+            shared_mailbox_destroy: email.shared_mailbox_destroy,
+            subscribe_on_create: SubscribeOnCreate {
+                imap: email.subscribe_on_imap_create,
+                jmap: email.subscribe_on_jmap_create,
+                auto: email.subscribe_on_auto_create,
+            },
             index_batch_size: search.index_batch_size as usize,
+            index_max_body_size: search.index_max_body_size.map(|v| v as usize),
+            reindex_batch_size: search.reindex_batch_size as usize,
+            reindex_interval: search.reindex_interval.into_inner(),
             index_fields,
             max_objects,
             default_folders,
-            shared_folder,
+            shared_folder: shared_folder.unwrap_or_else(|| "Shared Folders".to_string()),
+            virtual_folders,
+            virtual_recent_period: email.recent_folder_period.into_inner(),
+            search_folders,
             account_purge_frequency: dr.expunge_schedule.into(),
             data_purge_frequency: dr.data_cleanup_schedule.into(),
             blob_purge_frequency: dr.blob_cleanup_schedule.into(),
+            blob_scrub_frequency: dr.blob_scrub_interval.map(|v| v.into_inner()),
+            blob_scrub_repair: dr.blob_scrub_repair,
+            blob_issue_retention: dr.hold_blob_issues_for.map(|v| v.into_inner()),
             compression: email.compression_algorithm,
             default_domain_id: system.default_domain_id.id() as u32,
             default_domain_name,
         }
     }
 }
+
+impl DefaultFolder {
+    pub fn localized_name(&self, locale: Locale) -> &str {
+        self.localized_names
+            .iter()
+            .find(|(l, _)| *l == locale)
+            .map_or(self.name.as_str(), |(_, name)| name.as_str())
+    }
+}
+
+pub fn parse_default_folders(
+    folders: VecMap<registry::schema::enums::SpecialUse, EmailFolder>,
+) -> (Vec<DefaultFolder>, Option<String>) {
+    let (mut default_folders, shared_folder) = parse_folders(folders);
+    for (special_use, name) in [
+        (SpecialUse::Inbox, "Inbox"),
+        (SpecialUse::Trash, "Deleted Items"),
+        (SpecialUse::Junk, "Junk Mail"),
+        (SpecialUse::Drafts, "Drafts"),
+        (SpecialUse::Sent, "Sent Items"),
+    ] {
+        if !default_folders.iter().any(|f| f.special_use == special_use) {
+            default_folders.push(DefaultFolder {
+                name: name.to_string(),
+                localized_names: Vec::new(),
+                aliases: Vec::new(),
+                special_use,
+                subscribe: true,
+                create: true,
+            });
+        }
+    }
+
+    (default_folders, shared_folder)
+}
+
+// Domain folders replace the global defaults role by role, roles the domain
+// does not configure keep their global settings.
+pub fn merge_default_folders(
+    defaults: &[DefaultFolder],
+    shared_folder: &str,
+    folders: VecMap<registry::schema::enums::SpecialUse, EmailFolder>,
+) -> (Vec<DefaultFolder>, String) {
+    let (overrides, shared_override) = parse_folders(folders);
+    let mut default_folders = defaults.to_vec();
+    for folder in overrides {
+        if let Some(default_folder) = default_folders
+            .iter_mut()
+            .find(|f| f.special_use == folder.special_use)
+        {
+            *default_folder = folder;
+        } else {
+            default_folders.push(folder);
+        }
+    }
+
+    (
+        default_folders,
+        shared_override.unwrap_or_else(|| shared_folder.to_string()),
+    )
+}
+
+fn parse_folders(
+    folders: VecMap<registry::schema::enums::SpecialUse, EmailFolder>,
+) -> (Vec<DefaultFolder>, Option<String>) {
+    let mut default_folders = Vec::new();
+    let mut shared_folder = None;
+    for (special_use, folder) in folders {
+        let special_use = match special_use {
+            registry::schema::enums::SpecialUse::Inbox => SpecialUse::Inbox,
+            registry::schema::enums::SpecialUse::Trash => SpecialUse::Trash,
+            registry::schema::enums::SpecialUse::Junk => SpecialUse::Junk,
+            registry::schema::enums::SpecialUse::Drafts => SpecialUse::Drafts,
+            registry::schema::enums::SpecialUse::Archive => SpecialUse::Archive,
+            registry::schema::enums::SpecialUse::Sent => SpecialUse::Sent,
+            registry::schema::enums::SpecialUse::Important => SpecialUse::Important,
+            registry::schema::enums::SpecialUse::Memos => SpecialUse::Memos,
+            registry::schema::enums::SpecialUse::Scheduled => SpecialUse::Scheduled,
+            registry::schema::enums::SpecialUse::Snoozed => SpecialUse::Snoozed,
+            registry::schema::enums::SpecialUse::Shared => {
+                shared_folder = Some(folder.name);
+                continue;
+            }
+        };
+        default_folders.push(DefaultFolder {
+            name: folder.name,
+            localized_names: folder.localized_names.into_iter().collect(),
+            aliases: folder.aliases.into_inner(),
+            special_use,
+            subscribe: folder.subscribe,
+            create: folder.create
+                || matches!(
+                    special_use,
+                    SpecialUse::Inbox | SpecialUse::Trash | SpecialUse::Junk
+                ),
+        });
+    }
+
+    (default_folders, shared_folder)
+}
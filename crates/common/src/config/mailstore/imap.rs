@@ -4,11 +4,19 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use registry::schema::structs::{Imap, Rate};
+use crate::expr::{
+    Variable,
+    if_block::{BootstrapExprExt, IfBlock},
+};
+use registry::schema::{
+    enums::ExpressionConstant,
+    prelude::ObjectType,
+    structs::{BandwidthLimit, Imap, Rate},
+};
 use std::time::Duration;
 use store::registry::bootstrap::Bootstrap;
 
-#[derive(Default, Clone)]
+#[derive(Clone)]
 pub struct ImapConfig {
     pub max_request_size: usize,
     pub max_auth_failures: u32,
@@ -20,11 +28,27 @@ pub struct ImapConfig {
 
     pub rate_requests: Option<Rate>,
     pub rate_concurrent: Option<u64>,
+    pub bandwidth_account: Option<BandwidthLimit>,
+
+    pub client_policy: IfBlock,
+
+    // Messages appended to the own INBOX are filtered as if delivered
+    pub append_sieve: bool,
+    pub append_spam_filter: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImapClientPolicy {
+    Allow,
+    ReadOnly,
+    Reject,
 }
 
 impl ImapConfig {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let imap = bp.setting_infallible::<Imap>().await;
+        let client_policy =
+            bp.compile_expr(ObjectType::Imap.singleton(), &imap.ctx_client_policy());
 
         ImapConfig {
             max_request_size: imap.max_request_size as usize,
@@ -34,7 +58,29 @@ impl ImapConfig {
             timeout_idle: imap.timeout_idle.into_inner(),
             rate_requests: imap.max_request_rate,
             rate_concurrent: imap.max_concurrent,
+            bandwidth_account: imap.account_bandwidth,
             allow_plain_auth: imap.allow_plain_text_auth,
+            client_policy,
+            append_sieve: imap.append_sieve,
+            append_spam_filter: imap.append_spam_filter,
+        }
+    }
+}
+
+impl<'x> TryFrom<Variable<'x>> for ImapClientPolicy {
+    type Error = ();
+
+    fn try_from(value: Variable<'x>) -> Result<Self, Self::Error> {
+        match value {
+            Variable::Constant(value) => match value {
+                ExpressionConstant::Allow => Ok(ImapClientPolicy::Allow),
+                ExpressionConstant::ReadOnly => Ok(ImapClientPolicy::ReadOnly),
+                ExpressionConstant::Reject => Ok(ImapClientPolicy::Reject),
+                _ => Err(()),
+            },
+            Variable::Integer(0) => Ok(ImapClientPolicy::Reject),
+            Variable::Integer(_) => Ok(ImapClientPolicy::Allow),
+            _ => Err(()),
         }
     }
 }
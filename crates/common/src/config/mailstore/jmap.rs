@@ -4,15 +4,25 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
+use crate::config::build_ecdsa_pem;
+use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use jmap_proto::request::capability::BaseCapabilities;
-use registry::schema::structs::Jmap;
-use std::time::Duration;
-use store::registry::bootstrap::Bootstrap;
+use registry::schema::{prelude::ObjectType, structs::Jmap};
+use ring::{
+    rand::SystemRandom,
+    signature::{ECDSA_P256_SHA256_FIXED_SIGNING, EcdsaKeyPair, KeyPair},
+};
+use std::{sync::Arc, time::Duration};
+use store::{registry::bootstrap::Bootstrap, write::now};
+
+// RFC 8292 - Voluntary Application Server Identification (VAPID) for Web Push
+const VAPID_TOKEN_TTL: u64 = 12 * 60 * 60;
 
 #[derive(Default, Clone)]
 pub struct JmapConfig {
     pub query_max_results: usize,
     pub snippet_max_results: usize,
+    pub snippet_max_length: usize,
     pub changes_max_results: usize,
 
     pub request_max_size: usize,
@@ -34,6 +44,7 @@ pub struct JmapConfig {
     pub calendar_parse_max_items: usize,
 
     pub event_source_throttle: Duration,
+    pub event_source_replay_window: Option<Duration>,
     pub push_attempt_interval: Duration,
     pub push_attempts_max: u32,
     pub push_retry_interval: Duration,
@@ -41,6 +52,7 @@ pub struct JmapConfig {
     pub push_verify_timeout: Duration,
     pub push_throttle: Duration,
     pub push_total_shards: u32,
+    pub push_vapid: Option<Arc<VapidKey>>,
 
     pub web_socket_throttle: Duration,
     pub web_socket_timeout: Duration,
@@ -49,14 +61,35 @@ pub struct JmapConfig {
     pub capabilities: BaseCapabilities,
 }
 
+pub struct VapidKey {
+    pub key_pair: EcdsaKeyPair,
+    pub public_key: String,
+    pub subject: Option<String>,
+}
+
 impl JmapConfig {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let jmap = bp.setting_infallible::<Jmap>().await;
+        let push_vapid = match jmap.push_vapid_key.secret().await.and_then(|pem| {
+            pem.map(|pem| build_ecdsa_pem(&ECDSA_P256_SHA256_FIXED_SIGNING, pem.as_ref()))
+                .transpose()
+        }) {
+            Ok(key_pair) => key_pair
+                .map(|key_pair| Arc::new(VapidKey::new(key_pair, jmap.push_vapid_subject.clone()))),
+            Err(err) => {
+                bp.build_error(
+                    ObjectType::Jmap.singleton(),
+                    format!("Failed to parse VAPID key: {err}"),
+                );
+                None
+            }
+        };
 
         let mut jmap = JmapConfig {
             query_max_results: jmap.query_max_results as usize,
             changes_max_results: jmap.changes_max_results as usize,
             snippet_max_results: jmap.snippet_max_results as usize,
+            snippet_max_length: jmap.snippet_max_length as usize,
             request_max_size: jmap.max_request_size as usize,
             request_max_calls: jmap.max_method_calls as usize,
             request_max_concurrent: jmap.max_concurrent_requests,
@@ -71,6 +104,7 @@ impl JmapConfig {
             contact_parse_max_items: jmap.parse_limit_contact as usize,
             calendar_parse_max_items: jmap.parse_limit_event as usize,
             event_source_throttle: jmap.event_source_throttle.into_inner(),
+            event_source_replay_window: jmap.event_source_replay_window.map(|d| d.into_inner()),
             web_socket_throttle: jmap.websocket_throttle.into_inner(),
             web_socket_timeout: jmap.websocket_timeout.into_inner(),
             web_socket_heartbeat: jmap.websocket_heartbeat.into_inner(),
@@ -81,6 +115,7 @@ impl JmapConfig {
             push_verify_timeout: jmap.push_verify_timeout.into_inner(),
             push_throttle: jmap.push_throttle.into_inner(),
             push_total_shards: jmap.push_shards_total as u32,
+            push_vapid,
             capabilities: BaseCapabilities::default(),
         };
 
@@ -89,3 +124,49 @@ impl JmapConfig {
         jmap
     }
 }
+
+impl VapidKey {
+    pub fn new(key_pair: EcdsaKeyPair, subject: Option<String>) -> Self {
+        VapidKey {
+            public_key: URL_SAFE_NO_PAD.encode(key_pair.public_key().as_ref()),
+            key_pair,
+            subject,
+        }
+    }
+
+    pub fn authorization(&self, endpoint: &str) -> Result<String, String> {
+        // The audience is the origin of the push resource
+        let audience = endpoint
+            .split_once("://")
+            .map(|(scheme, rest)| {
+                format!(
+                    "{scheme}://{}",
+                    rest.split(['/', '?', '#']).next().unwrap_or_default()
+                )
+            })
+            .ok_or_else(|| format!("Invalid push endpoint {endpoint:?}"))?;
+        let mut claims = serde_json::json!({
+            "aud": audience,
+            "exp": now() + VAPID_TOKEN_TTL,
+        });
+        if let Some(subject) = &self.subject {
+            claims["sub"] = subject.as_str().into();
+        }
+
+        let token = format!(
+            "{}.{}",
+            URL_SAFE_NO_PAD.encode(r#"{"typ":"JWT","alg":"ES256"}"#),
+            URL_SAFE_NO_PAD.encode(claims.to_string())
+        );
+        let signature = self
+            .key_pair
+            .sign(&SystemRandom::new(), token.as_bytes())
+            .map_err(|err| format!("Failed to sign VAPID token: {err}"))?;
+
+        Ok(format!(
+            "vapid t={token}.{}, k={}",
+            URL_SAFE_NO_PAD.encode(signature.as_ref()),
+            self.public_key
+        ))
+    }
+}
@@ -9,23 +9,27 @@ use crate::{
     expr::if_block::{BootstrapExprExt, IfBlock},
     scripts::{
         functions::{register_functions_trusted, register_functions_untrusted},
+        library::{global_includes, invalid_includes},
         plugins::RegisterSievePlugins,
     },
 };
-use ahash::AHashMap;
+use ahash::{AHashMap, AHashSet};
 use registry::{
     schema::{
         prelude::ObjectType,
         structs::{
-            SieveSystemInterpreter, SieveSystemScript, SieveUserInterpreter, SieveUserScript,
+            Rate, SieveSystemInterpreter, SieveSystemScript, SieveUserInterpreter, SieveUserScript,
             SystemSettings,
         },
     },
     types::EnumImpl,
 };
 use sieve::{Compiler, Runtime, Sieve, compiler::grammar::Capability};
-use std::sync::Arc;
-use store::registry::bootstrap::Bootstrap;
+use std::{sync::Arc, time::Duration};
+use store::{LookupStores, registry::bootstrap::Bootstrap};
+
+// External list (RFC 6134) matching the addresses in the account's contacts
+pub const SIEVE_ADDRBOOK_LIST: &str = ":addrbook:default";
 
 pub struct Scripting {
     pub untrusted_compiler: Compiler,
@@ -38,6 +42,20 @@ pub struct Scripting {
     pub sign: IfBlock,
     pub trusted_scripts: AHashMap<String, Arc<Sieve>>,
     pub untrusted_scripts: AHashMap<String, Arc<Sieve>>,
+    pub untrusted_script_domains: AHashMap<String, u32>,
+    pub untrusted_ext_lists: AHashSet<String>,
+    pub untrusted_redirect_rate: Option<Rate>,
+    pub untrusted_max_notify: usize,
+    pub untrusted_max_nested_includes: usize,
+    pub untrusted_max_run_time: Duration,
+    pub untrusted_notify: SieveNotify,
+}
+
+#[derive(Clone, Default)]
+pub struct SieveNotify {
+    pub key: String,
+    pub gateway_url: Option<String>,
+    pub timeout: Duration,
 }
 
 impl Scripting {
@@ -58,6 +76,36 @@ impl Scripting {
             .with_max_includes(untrusted.max_includes as usize)
             .register_functions(&mut fnc_map_untrusted);
 
+        // External lists (RFC 6134) are resolved against lookup stores, user scripts
+        // may only reference the explicitly allowed ones
+        let ext_lists = LookupStores::namespaces(bp).await;
+        let untrusted_ext_lists = untrusted
+            .allowed_ext_lists
+            .into_iter()
+            .filter(|list| ext_lists.contains(list))
+            .chain([SIEVE_ADDRBOOK_LIST.to_string()])
+            .collect::<AHashSet<_>>();
+
+        // Notifications are signed with the configured key, methods other than
+        // https are handed over to the external gateway
+        let untrusted_notify = SieveNotify {
+            key: untrusted
+                .notify_signature_key
+                .secret()
+                .await
+                .map_err(|err| {
+                    bp.build_error(
+                        ObjectType::SieveUserInterpreter.singleton(),
+                        format!("Unable to retrieve notification signature key: {err}"),
+                    );
+                })
+                .unwrap_or_default()
+                .unwrap_or_default()
+                .into_owned(),
+            gateway_url: untrusted.notify_gateway_url,
+            timeout: untrusted.notify_timeout.into_inner(),
+        };
+
         // Parse untrusted runtime
         let untrusted_runtime = Runtime::new()
             .with_functions(&mut fnc_map_untrusted)
@@ -80,6 +128,7 @@ impl Scripting {
                     .map(|cap| cap.as_str()),
             )
             .with_valid_notification_uris(untrusted.allowed_notify_uris)
+            .with_valid_ext_lists(untrusted_ext_lists.iter().cloned())
             .with_protected_headers(untrusted.protected_headers)
             .with_vacation_default_subject(untrusted.default_subject)
             .with_vacation_subject_prefix(untrusted.default_subject_prefix)
@@ -123,7 +172,7 @@ impl Scripting {
             .with_max_variable_size(trusted.max_var_size as usize)
             .with_max_header_size(10240)
             .with_valid_notification_uri("mailto")
-            //.with_valid_ext_lists(stores.in_memory_stores.keys().map(|k| k.to_string()))
+            .with_valid_ext_lists(ext_lists)
             .with_functions(&mut fnc_map_trusted)
             .with_max_redirects(trusted.max_redirects as usize)
             .with_max_out_messages(trusted.max_out_messages as usize)
@@ -153,8 +202,12 @@ impl Scripting {
             }
         }
 
-        // Parse untrusted scripts
+        // Parse untrusted scripts, library scripts that lead into an include cycle
+        // or exceed the nesting limit are rejected as they would fail at runtime
         let mut untrusted_scripts = AHashMap::new();
+        let mut untrusted_script_domains = AHashMap::new();
+        let mut untrusted_includes = AHashMap::new();
+        let mut untrusted_ids = Vec::new();
         for script in bp.list_infallible::<SieveUserScript>().await {
             if !script.object.is_active {
                 continue;
@@ -162,6 +215,15 @@ impl Scripting {
 
             match untrusted_compiler.compile(script.object.contents.as_bytes()) {
                 Ok(compiled) => {
+                    untrusted_includes.insert(
+                        script.object.name.to_lowercase(),
+                        global_includes(&script.object.contents),
+                    );
+                    untrusted_ids.push((script.id, script.object.name.clone()));
+                    if let Some(domain_id) = script.object.domain_id {
+                        untrusted_script_domains
+                            .insert(script.object.name.clone(), domain_id.document_id());
+                    }
                     untrusted_scripts.insert(script.object.name, compiled.into());
                 }
                 Err(err) => {
@@ -172,6 +234,19 @@ impl Scripting {
                 }
             }
         }
+        let invalid = invalid_includes(&untrusted_includes, untrusted.max_nested_includes as usize);
+        if !invalid.is_empty() {
+            for (id, name) in untrusted_ids {
+                if invalid.contains(&name.to_lowercase()) {
+                    untrusted_scripts.remove(&name);
+                    untrusted_script_domains.remove(&name);
+                    bp.build_error(
+                        id,
+                        "User global Sieve script includes a cycle or exceeds the maximum include depth",
+                    );
+                }
+            }
+        }
 
         Scripting {
             untrusted_compiler,
@@ -179,7 +254,14 @@ impl Scripting {
             trusted_runtime,
             trusted_compiler,
             untrusted_scripts,
+            untrusted_script_domains,
             trusted_scripts,
+            untrusted_ext_lists,
+            untrusted_redirect_rate: untrusted.redirect_rate_limit,
+            untrusted_max_notify: untrusted.max_notify_actions as usize,
+            untrusted_max_nested_includes: untrusted.max_nested_includes as usize,
+            untrusted_max_run_time: untrusted.max_run_time.into_inner(),
+            untrusted_notify,
             from_addr: bp.compile_expr(
                 ObjectType::SieveSystemScript.singleton(),
                 &trusted.ctx_default_from_address(),
@@ -212,7 +294,14 @@ impl Clone for Scripting {
             sign: self.sign.clone(),
             trusted_scripts: self.trusted_scripts.clone(),
             untrusted_scripts: self.untrusted_scripts.clone(),
+            untrusted_script_domains: self.untrusted_script_domains.clone(),
             trusted_compiler: self.trusted_compiler.clone(),
+            untrusted_ext_lists: self.untrusted_ext_lists.clone(),
+            untrusted_redirect_rate: self.untrusted_redirect_rate.clone(),
+            untrusted_max_notify: self.untrusted_max_notify,
+            untrusted_max_nested_includes: self.untrusted_max_nested_includes,
+            untrusted_max_run_time: self.untrusted_max_run_time,
+            untrusted_notify: self.untrusted_notify.clone(),
         }
     }
 }
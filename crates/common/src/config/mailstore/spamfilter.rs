@@ -17,7 +17,7 @@ use registry::schema::{
     prelude::ObjectType,
     structs::{
         self, SpamDnsblServer, SpamDnsblSettings, SpamFileExtension, SpamPyzor, SpamRule,
-        SpamSettings, SpamTag,
+        SpamSettings, SpamTag, SystemSettings,
     },
 };
 use std::{
@@ -55,6 +55,7 @@ pub struct SpamFilterConfig {
     pub pyzor: Option<PyzorConfig>,
     pub classifier: Option<ClassifierConfig>,
     pub scores: SpamFilterScoreConfig,
+    pub quarantine: Option<QuarantineConfig>,
     pub spam_rules_url: Option<String>,
 }
 
@@ -65,15 +66,36 @@ pub struct SpamFilterScoreConfig {
     pub spam_threshold: f32,
 }
 
+// Spam scoring at or above the threshold is held for review instead of
+// being delivered to the Junk folder.
+#[derive(Debug, Clone, Default)]
+pub struct QuarantineConfig {
+    pub threshold: f32,
+    pub hold_for: u64,
+    pub digest_frequency: Option<Duration>,
+    pub release_url: String,
+}
+
 #[derive(Debug, Clone, Default)]
 pub struct DnsBlConfig {
     pub max_ip_checks: usize,
     pub max_domain_checks: usize,
     pub max_email_checks: usize,
     pub max_url_checks: usize,
+    pub max_redirects: usize,
+    pub redirect_timeout: Duration,
     pub servers: Vec<DnsBlServer>,
 }
 
+// Default scores of the local URL reputation classes, configured tags take precedence
+const URL_REPUTATION_SCORES: &[(&str, f32)] = &[
+    ("URL_REP_MALWARE", 6.0),
+    ("URL_REP_PHISHING", 6.0),
+    ("URL_REP_SPAM", 4.0),
+    ("URL_REP_SUSPICIOUS", 2.0),
+    ("URL_REP_TRUSTED", -1.0),
+];
+
 #[derive(Debug, Clone, Default)]
 pub struct SpamFilterLists {
     pub file_extensions: GlobMap<FileExtension>,
@@ -201,6 +223,17 @@ impl SpamFilterConfig {
                 discard_threshold: spam.score_discard.into_inner() as f32,
                 spam_threshold: spam.score_spam.into_inner() as f32,
             },
+            quarantine: if spam.score_quarantine.into_inner() > 0.0 {
+                let system = bp.setting_infallible::<SystemSettings>().await;
+                Some(QuarantineConfig {
+                    threshold: spam.score_quarantine.into_inner() as f32,
+                    hold_for: spam.quarantine_for.into_inner().as_secs().max(3600),
+                    digest_frequency: spam.quarantine_digest_frequency.map(|d| d.into_inner()),
+                    release_url: format!("https://{}/quarantine", system.default_hostname),
+                })
+            } else {
+                None
+            },
             grey_list_expiry: spam.greylist_for.map(|d| d.into_inner().as_secs()),
             spam_rules_url: spam.spam_filter_rules_url,
         }
@@ -306,6 +339,8 @@ impl DnsBlConfig {
             max_domain_checks: dnsbl.domain_limit as usize,
             max_email_checks: dnsbl.email_limit as usize,
             max_url_checks: dnsbl.url_limit as usize,
+            max_redirects: dnsbl.redirect_limit as usize,
+            redirect_timeout: dnsbl.redirect_timeout.into_inner(),
             servers,
         }
     }
@@ -390,6 +425,14 @@ impl SpamFilterLists {
             }
         }
 
+        for (tag, score) in URL_REPUTATION_SCORES {
+            if lists.scores.get(tag).is_none() {
+                lists
+                    .scores
+                    .insert_entry(tag.to_string(), SpamFilterAction::Allow(*score));
+            }
+        }
+
         for ext in bp.list_infallible::<SpamFileExtension>().await {
             let ext = ext.object;
             lists.file_extensions.insert_pattern(
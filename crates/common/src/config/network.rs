@@ -38,6 +38,7 @@ pub struct Network {
     pub task_manager: TaskManager,
     pub has_acme_tls_challenge: bool,
     pub has_acme_http_challenge: bool,
+    pub ipv6_only: bool,
     pub info: NetworkInfo,
 }
 
@@ -58,6 +59,11 @@ pub struct Pacc {
 pub struct Http {
     pub rate_authenticated: Option<Rate>,
     pub rate_anonymous: Option<Rate>,
+    pub rate_client: Option<Rate>,
+    pub rate_upload: Option<Rate>,
+    pub rate_query: Option<Rate>,
+    pub rate_set: Option<Rate>,
+    pub rate_admin: Option<Rate>,
     pub url_https: String,
     pub allowed_endpoint: IfBlock,
     pub response_headers: Vec<(hyper::header::HeaderName, hyper::header::HeaderValue)>,
@@ -329,6 +335,7 @@ impl Network {
             task_manager: bp.setting_infallible::<TaskManager>().await,
             has_acme_tls_challenge,
             has_acme_http_challenge,
+            ipv6_only: system.ipv6_only,
             info: NetworkInfo {
                 mxs: system.mail_exchangers.into_iter().collect(),
                 services: system.services,
@@ -446,6 +453,11 @@ impl Http {
                 .compile_expr(ObjectType::Http.singleton(), &http.ctx_allowed_endpoints()),
             rate_authenticated: http.rate_limit_authenticated,
             rate_anonymous: http.rate_limit_anonymous,
+            rate_client: http.rate_limit_client,
+            rate_upload: http.rate_limit_upload,
+            rate_query: http.rate_limit_query,
+            rate_set: http.rate_limit_set,
+            rate_admin: http.rate_limit_admin,
             response_headers: http_headers,
             use_forwarded: http.use_x_forwarded,
         }
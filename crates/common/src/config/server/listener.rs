@@ -5,16 +5,18 @@
  */
 
 use super::{
-    Listener, Listeners, ServerProtocol, TcpListener,
-    tls::{TLS12_VERSION, TLS13_VERSION},
+    Listener, ListenerBinding, Listeners, ServerProtocol, TcpListener,
+    tls::{TLS12_VERSION, TLS13_VERSION, build_client_cert_verifier},
 };
 use crate::{
     Inner,
-    network::{TcpAcceptor, tls::CertificateResolver},
+    network::{TcpAcceptor, limiter::BandwidthLimiter, tls::CertificateResolver},
 };
 use registry::{
     schema::{
-        enums::{NetworkListenerProtocol, TlsCipherSuite, TlsVersion},
+        enums::{
+            NetworkListenerProtocol, TlsCipherSuite, TlsKeyExchangeGroup, TlsProfile, TlsVersion,
+        },
         prelude::{ObjectType, SocketAddr},
         structs::{ClusterListenerGroup, NetworkListener, SystemSettings},
     },
@@ -22,7 +24,10 @@ use registry::{
 };
 use rustls::{
     ALL_VERSIONS, ServerConfig, SupportedCipherSuite,
-    crypto::aws_lc_rs::{ALL_CIPHER_SUITES, cipher_suite::*, default_provider},
+    crypto::{
+        SupportedKxGroup,
+        aws_lc_rs::{ALL_CIPHER_SUITES, cipher_suite::*, default_provider, kx_group},
+    },
 };
 use std::{
     net::{IpAddr, Ipv4Addr, SocketAddr as StdSocketAddr},
@@ -192,9 +197,25 @@ impl Listeners {
         }
 
         let span_id_gen = self.span_id_gen.clone();
+        let binding = ListenerBinding {
+            protocol,
+            bind: listeners.iter().map(|listener| listener.addr).collect(),
+            backlog: listener.socket_backlog.map(|v| v as u32),
+            ttl: listener.socket_ttl.map(|v| v as u32),
+            nodelay: listener.socket_no_delay,
+            reuse_address: listener.socket_reuse_address,
+            reuse_port: listener.socket_reuse_port,
+            send_buffer_size: listener.socket_send_buffer_size,
+            receive_buffer_size: listener.socket_receive_buffer_size,
+            tos_v4: listener.socket_tos_v4,
+        };
 
         self.servers.push(Listener {
             max_connections: listener.max_connections.unwrap_or(system.max_connections),
+            bandwidth: listener
+                .bandwidth
+                .as_ref()
+                .map(|limit| BandwidthLimiter::new(limit.rate, limit.burst)),
             id: listener.name.clone(),
             registry_id: id,
             protocol,
@@ -205,6 +226,7 @@ impl Listeners {
                 system.proxy_trusted_networks.as_slice().to_vec()
             },
             span_id_gen,
+            binding,
         });
         self.parsed_listeners.push(RegistryObject {
             id,
@@ -222,11 +244,19 @@ impl Listeners {
 
             // Build TLS config
             let acceptor = if listener.use_tls {
+                // Profiles other than custom ignore the protocol and cipher suite settings,
+                // TLS 1.2 is the oldest protocol version and only AEAD suites are available.
+                let is_custom = matches!(listener.tls_profile, TlsProfile::Custom);
+
                 // Parse protocol versions
-                let mut tls_v2 = true;
+                let mut tls_v2 = !matches!(listener.tls_profile, TlsProfile::Modern);
                 let mut tls_v3 = true;
 
-                for disabled in listener.tls_disable_protocols {
+                for disabled in listener
+                    .tls_disable_protocols
+                    .into_iter()
+                    .filter(|_| is_custom)
+                {
                     match disabled {
                         TlsVersion::Tls12 => {
                             tls_v2 = false;
@@ -239,7 +269,11 @@ impl Listeners {
 
                 // Parse cipher suites
                 let mut disabled_ciphers: Vec<SupportedCipherSuite> = Vec::new();
-                for disabled in listener.tls_disable_cipher_suites {
+                for disabled in listener
+                    .tls_disable_cipher_suites
+                    .into_iter()
+                    .filter(|_| is_custom)
+                {
                     disabled_ciphers.push(match disabled {
                         TlsCipherSuite::Tls13Aes256GcmSha384 => TLS13_AES_256_GCM_SHA384,
                         TlsCipherSuite::Tls13Aes128GcmSha256 => TLS13_AES_128_GCM_SHA256,
@@ -277,8 +311,48 @@ impl Listeners {
                         .collect();
                 }
 
+                // Parse key exchange groups, the hybrid post-quantum group is preferred
+                // by the modern profile and otherwise offered after the classical groups
+                let mut kx_groups: Vec<&'static dyn SupportedKxGroup> =
+                    vec![kx_group::X25519, kx_group::SECP256R1, kx_group::SECP384R1];
+                if matches!(listener.tls_profile, TlsProfile::Modern)
+                    || listener.tls_prefer_post_quantum
+                {
+                    kx_groups.insert(0, kx_group::X25519MLKEM768);
+                } else {
+                    kx_groups.push(kx_group::X25519MLKEM768);
+                }
+                for disabled in listener.tls_disable_key_exchange_groups {
+                    let name = match disabled {
+                        TlsKeyExchangeGroup::X25519Mlkem768 => kx_group::X25519MLKEM768.name(),
+                        TlsKeyExchangeGroup::X25519 => kx_group::X25519.name(),
+                        TlsKeyExchangeGroup::Secp256r1 => kx_group::SECP256R1.name(),
+                        TlsKeyExchangeGroup::Secp384r1 => kx_group::SECP384R1.name(),
+                    };
+                    kx_groups.retain(|group| group.name() != name);
+                }
+                if kx_groups.is_empty() {
+                    bp.build_error(id, "At least one TLS key exchange group must be enabled");
+                    continue;
+                }
+                provider.kx_groups = kx_groups;
+                let provider = Arc::new(provider);
+
+                // Build client certificate verifier
+                let client_verifier = if let Some(trusted_cas) = &listener.tls_client_ca {
+                    match build_client_cert_verifier(trusted_cas, provider.clone()).await {
+                        Ok(verifier) => Some(verifier),
+                        Err(err) => {
+                            bp.build_error(id, err);
+                            None
+                        }
+                    }
+                } else {
+                    None
+                };
+
                 // Build server config
-                let mut server_config = match ServerConfig::builder_with_provider(provider.into())
+                let mut server_config = match ServerConfig::builder_with_provider(provider)
                     .with_protocol_versions(if tls_v3 == tls_v2 {
                         ALL_VERSIONS
                     } else if tls_v3 {
@@ -286,16 +360,23 @@ impl Listeners {
                     } else {
                         TLS12_VERSION
                     }) {
-                    Ok(server_config) => server_config
-                        .with_no_client_auth()
-                        .with_cert_resolver(resolver.clone()),
+                    Ok(server_config) => if let Some(verifier) = client_verifier {
+                        server_config.with_client_cert_verifier(verifier)
+                    } else {
+                        server_config.with_no_client_auth()
+                    }
+                    .with_cert_resolver(resolver.clone()),
                     Err(err) => {
                         bp.build_error(id, format!("Failed to build TLS server config: {err}"));
                         return;
                     }
                 };
 
-                server_config.ignore_client_order = listener.tls_ignore_client_order;
+                server_config.ignore_client_order = match listener.tls_profile {
+                    TlsProfile::Custom => listener.tls_ignore_client_order,
+                    TlsProfile::Old => true,
+                    TlsProfile::Modern | TlsProfile::Intermediate => false,
+                };
 
                 // Build acceptor
                 let default_config = Arc::new(server_config);
@@ -303,6 +384,7 @@ impl Listeners {
                     acceptor: TlsAcceptor::from(default_config.clone()),
                     config: default_config,
                     implicit: listener.tls_implicit,
+                    client_auth: listener.tls_client_auth && listener.tls_client_ca.is_some(),
                 }
             } else {
                 TcpAcceptor::Plain
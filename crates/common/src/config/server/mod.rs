@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::network::TcpAcceptor;
+use crate::network::{TcpAcceptor, limiter::BandwidthLimiter};
 use ahash::AHashMap;
 use registry::{
     schema::structs::NetworkListener,
@@ -35,7 +35,24 @@ pub struct Listener {
     pub listeners: Vec<TcpListener>,
     pub proxy_networks: Vec<IpAddrOrMask>,
     pub max_connections: u64,
+    pub bandwidth: Option<BandwidthLimiter>,
     pub span_id_gen: Arc<SnowflakeIdGenerator>,
+    pub binding: ListenerBinding,
+}
+
+// Settings that can only be changed by closing and binding the sockets again
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ListenerBinding {
+    pub protocol: ServerProtocol,
+    pub bind: Vec<SocketAddr>,
+    pub backlog: Option<u32>,
+    pub ttl: Option<u32>,
+    pub nodelay: bool,
+    pub reuse_address: bool,
+    pub reuse_port: bool,
+    pub send_buffer_size: Option<u64>,
+    pub receive_buffer_size: Option<u64>,
+    pub tos_v4: Option<u64>,
 }
 
 #[derive(Debug)]
@@ -8,11 +8,12 @@ use ahash::{AHashMap, AHashSet};
 use rcgen::generate_simple_self_signed;
 use registry::schema::{
     prelude::Object,
-    structs::{Certificate, SystemSettings},
+    structs::{Certificate, PublicText, SystemSettings},
 };
 use rustls::{
-    SupportedProtocolVersion,
-    crypto::aws_lc_rs::sign::any_supported_type,
+    RootCertStore, SupportedProtocolVersion,
+    crypto::{CryptoProvider, aws_lc_rs::sign::any_supported_type},
+    server::{WebPkiClientVerifier, danger::ClientCertVerifier},
     sign::CertifiedKey,
     version::{TLS12, TLS13},
 };
@@ -186,6 +187,33 @@ pub(crate) fn build_certified_key(
     }
 }
 
+// Clients that do not present a certificate are still accepted, the
+// certificate is only used to identify the client when available.
+pub(crate) async fn build_client_cert_verifier(
+    trusted_cas: &PublicText,
+    provider: Arc<CryptoProvider>,
+) -> Result<Arc<dyn ClientCertVerifier>, String> {
+    let pem = trusted_cas
+        .value()
+        .await
+        .map_err(|err| format!("Failed to obtain certificate authorities: {err}"))?;
+    let mut roots = RootCertStore::empty();
+    for cert in certs(&mut Cursor::new(pem.as_bytes())) {
+        let cert = cert.map_err(|err| format!("Failed to read certificates: {err}"))?;
+        roots
+            .add(cert)
+            .map_err(|err| format!("Invalid certificate authority: {err}"))?;
+    }
+    if roots.is_empty() {
+        return Err("No certificate authorities found.".to_string());
+    }
+
+    WebPkiClientVerifier::builder_with_provider(Arc::new(roots), provider)
+        .allow_unauthenticated()
+        .build()
+        .map_err(|err| format!("Failed to build client certificate verifier: {err}"))
+}
+
 pub(crate) fn build_self_signed_cert(
     domains: impl Into<Vec<String>>,
 ) -> Result<CertifiedKey, String> {
@@ -11,6 +11,7 @@ use crate::expr::{
 use mail_auth::{
     common::crypto::{Ed25519Key, HashAlgorithm, RsaKey, Sha256, SigningKey},
     dkim::{Canonicalization, Done},
+    report::PolicyOverrideReason,
 };
 use mail_parser::decoders::base64::base64_decode;
 use registry::{
@@ -56,6 +57,14 @@ pub struct SpfAuthConfig {
 #[derive(Clone)]
 pub struct DmarcAuthConfig {
     pub verify: IfBlock,
+    pub overrides: Vec<DmarcOverride>,
+}
+
+#[derive(Clone)]
+pub struct DmarcOverride {
+    pub sender_domain: Option<String>,
+    pub list_id: Option<String>,
+    pub reason: PolicyOverrideReason,
 }
 
 #[derive(Clone)]
@@ -112,6 +121,18 @@ impl MailAuthConfig {
             dmarc: DmarcAuthConfig {
                 verify: bp
                     .compile_expr(ObjectType::SenderAuth.singleton(), &auth.ctx_dmarc_verify()),
+                overrides: auth
+                    .dmarc_overrides
+                    .iter()
+                    .map(|entry| DmarcOverride {
+                        sender_domain: entry.sender_domain.as_ref().map(|d| d.to_lowercase()),
+                        list_id: entry.list_id.as_ref().map(|id| id.to_lowercase()),
+                        reason: PolicyOverrideReason {
+                            type_: entry.override_type.into(),
+                            comment: entry.comment.clone(),
+                        },
+                    })
+                    .collect(),
             },
             iprev: IpRevAuthConfig {
                 verify: bp.compile_expr(
@@ -123,6 +144,44 @@ impl MailAuthConfig {
     }
 }
 
+impl DmarcAuthConfig {
+    pub fn find_override<'x>(
+        &self,
+        mut authenticated_domains: impl Iterator<Item = &'x str>,
+        list_id: Option<&str>,
+    ) -> Option<&DmarcOverride> {
+        if self.overrides.is_empty() {
+            return None;
+        }
+
+        // Mailing lists are matched by their List-Id, forwarders by an authenticated domain
+        let list_id = list_id.map(|id| {
+            id.rsplit_once('<')
+                .and_then(|(_, id)| id.split_once('>'))
+                .map_or(id, |(id, _)| id)
+                .trim()
+                .to_lowercase()
+        });
+        if let Some(list_id) = &list_id
+            && let Some(entry) = self
+                .overrides
+                .iter()
+                .find(|entry| entry.list_id.as_ref() == Some(list_id))
+        {
+            return Some(entry);
+        }
+
+        authenticated_domains.find_map(|domain| {
+            self.overrides.iter().find(|entry| {
+                entry
+                    .sender_domain
+                    .as_ref()
+                    .is_some_and(|sender_domain| sender_domain.eq_ignore_ascii_case(domain))
+            })
+        })
+    }
+}
+
 impl DkimSigner {
     pub async fn new(domain: String, signature: DkimSignature) -> trc::Result<Self> {
         let mut errors = vec![];
@@ -288,10 +347,17 @@ fn build_dkim1_signer<T: SigningKey>(
     signature: Dkim1Signature,
     key: T,
 ) -> mail_auth::dkim::DkimSigner<T, Done> {
+    // Oversigned headers are listed once more than they appear in the message,
+    // which prevents additional instances from being added after signing
     let mut signer = mail_auth::dkim::DkimSigner::from_key(key)
         .domain(domain)
         .selector(signature.selector)
-        .headers(signature.headers)
+        .headers(
+            signature
+                .headers
+                .into_iter()
+                .chain(signature.oversign_headers),
+        )
         .reporting(signature.report);
 
     match signature.canonicalization {
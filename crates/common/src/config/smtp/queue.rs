@@ -13,23 +13,32 @@ use crate::{
     },
 };
 use ahash::AHashMap;
+use compact_str::CompactString;
 use directory::Credentials;
 use mail_auth::IpLookupStrategy;
-use registry::schema::{
-    enums::{self, ExpressionConstant, ExpressionVariable, MtaRequiredOrOptional},
-    prelude::ObjectType,
-    structs::{
-        DsnReportSettings, MtaConnectionStrategy, MtaDeliveryExpiration, MtaDeliverySchedule,
-        MtaDeliveryScheduleIntervalsOrDefault, MtaInboundThrottle, MtaOutboundStrategy,
-        MtaOutboundThrottle, MtaQueueQuota, MtaRoute, MtaTlsStrategy, MtaVirtualQueue,
+use registry::{
+    schema::{
+        enums::{
+            self, ExpressionConstant, ExpressionVariable, MtaDeliveryMode, MtaRequiredOrOptional,
+        },
+        prelude::{ObjectType, Property},
+        structs::{
+            self, DataRetention, DsnReportSettings, MtaConnectionStrategy, MtaDeliveryExpiration,
+            MtaDeliverySchedule, MtaDeliveryScheduleIntervalsOrDefault, MtaInboundThrottle,
+            MtaOutboundStrategy, MtaOutboundThrottle, MtaQueueQuota, MtaRoute, MtaTlsStrategy,
+            MtaVirtualQueue,
+        },
     },
+    types::EnumImpl,
 };
 use std::{
     fmt::Display,
     hash::{Hash, Hasher},
     net::IpAddr,
+    str::FromStr,
     time::Duration,
 };
+use utils::template::Template;
 
 #[derive(
     Debug,
@@ -47,6 +56,8 @@ use std::{
 #[repr(transparent)]
 pub struct QueueName([u8; 8]);
 
+pub const SATELLITE_TLS_STRATEGY: &str = "satellite";
+
 pub const DEFAULT_QUEUE_NAME: QueueName = QueueName([b'd', b'e', b'f', b'a', b'u', b'l', b't', 0]);
 
 #[derive(Clone)]
@@ -71,6 +82,9 @@ pub struct QueueConfig {
     pub routing_strategy: AHashMap<String, RoutingStrategy>,
     pub tls_strategy: AHashMap<String, TlsStrategy>,
     pub virtual_queues: AHashMap<QueueName, VirtualQueue>,
+
+    // Delivery traces
+    pub trace_retention: Option<Duration>,
 }
 
 #[derive(Clone, Hash, PartialEq, Eq, Debug)]
@@ -78,6 +92,7 @@ pub enum RoutingStrategy {
     Local,
     Mx(MxConfig),
     Relay(RelayConfig),
+    LmtpExternal(LmtpExternalConfig),
 }
 
 #[derive(Clone, Debug)]
@@ -85,6 +100,7 @@ pub struct MxConfig {
     pub max_mx: usize,
     pub max_multi_homed: usize,
     pub ip_lookup_strategy: IpLookupStrategy,
+    pub proxy: Option<ProxyConfig>,
 }
 
 #[derive(Clone)]
@@ -92,11 +108,35 @@ pub struct Dsn {
     pub name: IfBlock,
     pub address: IfBlock,
     pub sign: IfBlock,
+    pub templates: Vec<DsnTemplate>,
+}
+
+#[derive(Clone, Debug)]
+pub struct DsnTemplate {
+    pub domain_id: Option<u32>,
+    pub tenant_id: Option<u32>,
+    pub locale: Option<enums::Locale>,
+    pub subject: Option<Template<DsnTemplateVariable>>,
+    pub body: Template<DsnTemplateVariable>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DsnTemplateVariable {
+    Sender,
+    ReportingMta,
+    Summary,
+    Delivered,
+    Delayed,
+    Failed,
 }
 
 #[derive(Clone, Debug)]
 pub struct VirtualQueue {
     pub threads: usize,
+    // Threads only available to messages with a positive priority
+    pub priority_threads: usize,
+    // Maximum threads used by messages with a negative priority
+    pub low_priority_threads: Option<usize>,
 }
 
 #[derive(Clone, Debug)]
@@ -185,6 +225,28 @@ pub struct RelayConfig {
     pub auth: Option<Credentials>,
     pub tls_implicit: bool,
     pub tls_allow_invalid_certs: bool,
+    pub tls_fingerprint: Option<[u8; 32]>,
+    pub proxy: Option<ProxyConfig>,
+}
+
+#[derive(Clone, Hash, PartialEq, Eq)]
+pub struct ProxyConfig {
+    pub protocol: ProxyProtocol,
+    pub address: Box<str>,
+    pub port: u16,
+    pub auth: Option<(String, String)>,
+}
+
+#[derive(Clone, Copy, Debug, Hash, PartialEq, Eq)]
+pub enum ProxyProtocol {
+    Socks5,
+    Http,
+}
+
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct LmtpExternalConfig {
+    pub relay: RelayConfig,
+    pub deliver_local: bool,
 }
 
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
@@ -211,6 +273,7 @@ impl QueueConfig {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let st = bp.setting_infallible::<MtaOutboundStrategy>().await;
         let dsn = bp.setting_infallible::<DsnReportSettings>().await;
+        let dr = bp.setting_infallible::<DataRetention>().await;
 
         let mut queue = QueueConfig {
             route: bp.compile_expr(ObjectType::MtaOutboundStrategy.singleton(), &st.ctx_route()),
@@ -236,6 +299,7 @@ impl QueueConfig {
                     ObjectType::DsnReportSettings.singleton(),
                     &dsn.ctx_dkim_sign_domain(),
                 ),
+                templates: Default::default(),
             },
             inbound_limiters: QueueRateLimiters::parse_inbound(bp).await,
             outbound_limiters: QueueRateLimiters::parse_outbound(bp).await,
@@ -245,8 +309,36 @@ impl QueueConfig {
             routing_strategy: Default::default(),
             tls_strategy: Default::default(),
             virtual_queues: Default::default(),
+            trace_retention: dr.hold_delivery_traces_for.map(|v| v.into_inner()),
         };
 
+        // Parse DSN templates
+        for obj in bp.list_infallible::<structs::DsnTemplate>().await {
+            let id = obj.id;
+            let obj = obj.object;
+            let subject = match obj.subject.as_deref().map(Template::parse).transpose() {
+                Ok(subject) => subject,
+                Err(err) => {
+                    bp.invalid_property(id, Property::Subject, format!("Invalid template: {err}"));
+                    continue;
+                }
+            };
+            match Template::parse(&obj.body) {
+                Ok(body) => {
+                    queue.dsn.templates.push(DsnTemplate {
+                        domain_id: obj.domain_id.map(|id| id.document_id()),
+                        tenant_id: obj.member_tenant_id.map(|id| id.document_id()),
+                        locale: obj.locale,
+                        subject,
+                        body,
+                    });
+                }
+                Err(err) => {
+                    bp.invalid_property(id, Property::Body, format!("Invalid template: {err}"));
+                }
+            }
+        }
+
         // Parse virtual queues
         let mut queue_id_to_name = AHashMap::new();
         for obj in bp.list_infallible::<MtaVirtualQueue>().await {
@@ -256,6 +348,11 @@ impl QueueConfig {
                     queue_name,
                     VirtualQueue {
                         threads: obj.object.threads_per_node as usize,
+                        priority_threads: obj.object.priority_threads_per_node as usize,
+                        low_priority_threads: obj
+                            .object
+                            .low_priority_threads_per_node
+                            .map(|threads| threads as usize),
                     },
                 );
             }
@@ -365,6 +462,16 @@ impl QueueConfig {
                                 enums::MtaIpStrategy::V4Only => IpLookupStrategy::Ipv4Only,
                                 enums::MtaIpStrategy::V6Only => IpLookupStrategy::Ipv6Only,
                             },
+                            proxy: parse_proxy(
+                                bp,
+                                obj.id,
+                                route.proxy_address,
+                                route.proxy_port,
+                                route.proxy_protocol,
+                                route.proxy_username,
+                                &route.proxy_secret,
+                            )
+                            .await,
                         }),
                     );
                 }
@@ -377,6 +484,16 @@ impl QueueConfig {
                             bp.build_error(obj.id, err);
                         })
                         .unwrap_or_default();
+                    let proxy = parse_proxy(
+                        bp,
+                        obj.id,
+                        route.proxy_address,
+                        route.proxy_port,
+                        route.proxy_protocol,
+                        route.proxy_username,
+                        &route.proxy_secret,
+                    )
+                    .await;
                     queue.routing_strategy.insert(
                         route.name,
                         RoutingStrategy::Relay(RelayConfig {
@@ -403,6 +520,54 @@ impl QueueConfig {
                                 }),
                             tls_implicit: route.implicit_tls,
                             tls_allow_invalid_certs: route.allow_invalid_certs,
+                            tls_fingerprint: route
+                                .tls_fingerprint
+                                .as_deref()
+                                .and_then(parse_fingerprint),
+                            proxy,
+                        }),
+                    );
+                }
+                MtaRoute::LmtpExternal(route) => {
+                    let secret = route
+                        .auth_secret
+                        .secret()
+                        .await
+                        .map_err(|err| {
+                            bp.build_error(obj.id, err);
+                        })
+                        .unwrap_or_default();
+                    queue.routing_strategy.insert(
+                        route.name,
+                        RoutingStrategy::LmtpExternal(LmtpExternalConfig {
+                            relay: RelayConfig {
+                                address: if let Ok(ip) = route.address.parse() {
+                                    HostOrIp::Ip(IpStr {
+                                        ip,
+                                        ip_str: route.address.into(),
+                                    })
+                                } else {
+                                    HostOrIp::Host(route.address.into())
+                                },
+                                port: route.port as u16,
+                                protocol: ServerProtocol::Lmtp,
+                                auth: route
+                                    .auth_username
+                                    .and_then(|user| secret.map(|secret| (user, secret)))
+                                    .map(|(user, secret)| Credentials::Basic {
+                                        username: user,
+                                        secret: secret.into_owned(),
+                                        mfa_token: None,
+                                    }),
+                                tls_implicit: route.implicit_tls,
+                                tls_allow_invalid_certs: route.allow_invalid_certs,
+                                tls_fingerprint: route
+                                    .tls_fingerprint
+                                    .as_deref()
+                                    .and_then(parse_fingerprint),
+                                proxy: None,
+                            },
+                            deliver_local: route.deliver_local,
                         }),
                     );
                 }
@@ -441,8 +606,114 @@ impl QueueConfig {
             );
         }
 
+        // Satellite mode relays all messages to the smarthost
+        if st.delivery_mode == MtaDeliveryMode::Satellite {
+            queue.parse_satellite(bp, st.smarthost.as_deref());
+        }
+
         queue
     }
+
+    fn parse_satellite(&mut self, bp: &mut Bootstrap, smarthost: Option<&str>) {
+        let id = ObjectType::MtaOutboundStrategy.singleton();
+        let Some(smarthost) = smarthost else {
+            bp.build_error(id, "Satellite mode requires a smarthost");
+            return;
+        };
+        let relay = match self.routing_strategy.get(smarthost) {
+            Some(RoutingStrategy::Relay(relay)) => relay,
+            Some(_) => {
+                bp.build_error(id, format!("Smarthost {smarthost:?} is not a relay route"));
+                return;
+            }
+            None => {
+                bp.build_error(id, format!("Smarthost {smarthost:?} does not exist"));
+                return;
+            }
+        };
+        if relay.auth.is_none() {
+            bp.build_error(
+                id,
+                format!("Smarthost {smarthost:?} has no authentication credentials"),
+            );
+            return;
+        }
+        if relay.tls_allow_invalid_certs && relay.tls_fingerprint.is_none() {
+            bp.build_error(
+                id,
+                format!(
+                    "Smarthost {smarthost:?} allows invalid certificates without a pinned fingerprint"
+                ),
+            );
+            return;
+        }
+
+        // Disable MX resolution and require TLS towards the smarthost
+        let defaults = MtaTlsStrategy::default();
+        self.tls_strategy.insert(
+            SATELLITE_TLS_STRATEGY.to_string(),
+            TlsStrategy {
+                dane: RequireOptional::Disable,
+                mta_sts: RequireOptional::Disable,
+                tls: RequireOptional::Require,
+                allow_invalid_certs: false,
+                timeout_tls: defaults.tls_timeout.into_inner(),
+                timeout_mta_sts: defaults.mta_sts_timeout.into_inner(),
+            },
+        );
+        self.route = IfBlock {
+            id,
+            property: Property::Route,
+            if_then: Default::default(),
+            default: Expression::from(CompactString::from(smarthost)),
+        };
+        self.tls = IfBlock {
+            id,
+            property: Property::Tls,
+            if_then: Default::default(),
+            default: Expression::from(CompactString::from(SATELLITE_TLS_STRATEGY)),
+        };
+    }
+}
+
+async fn parse_proxy(
+    bp: &mut Bootstrap,
+    id: ObjectId,
+    address: Option<String>,
+    port: u64,
+    protocol: enums::MtaProxyProtocol,
+    username: Option<String>,
+    secret: &structs::SecretKeyOptional,
+) -> Option<ProxyConfig> {
+    let address = address?;
+    let secret = secret
+        .secret()
+        .await
+        .map_err(|err| {
+            bp.build_error(id, err);
+        })
+        .ok()?;
+
+    Some(ProxyConfig {
+        protocol: match protocol {
+            enums::MtaProxyProtocol::Socks5 => ProxyProtocol::Socks5,
+            enums::MtaProxyProtocol::Http => ProxyProtocol::Http,
+        },
+        address: address.into_boxed_str(),
+        port: port as u16,
+        auth: username.and_then(|username| secret.map(|secret| (username, secret.into_owned()))),
+    })
+}
+
+fn parse_fingerprint(value: &str) -> Option<[u8; 32]> {
+    let mut fingerprint = [0u8; 32];
+    if value.len() != fingerprint.len() * 2 || !value.is_ascii() {
+        return None;
+    }
+    for (byte, chunk) in fingerprint.iter_mut().zip(value.as_bytes().chunks(2)) {
+        *byte = u8::from_str_radix(std::str::from_utf8(chunk).ok()?, 16).ok()?;
+    }
+    Some(fingerprint)
 }
 
 impl QueueRateLimiters {
@@ -675,6 +946,19 @@ impl std::fmt::Debug for RelayConfig {
             .field("protocol", &self.protocol)
             .field("tls_implicit", &self.tls_implicit)
             .field("tls_allow_invalid_certs", &self.tls_allow_invalid_certs)
+            .field("tls_fingerprint", &self.tls_fingerprint.is_some())
+            .field("proxy", &self.proxy)
+            .finish()
+    }
+}
+
+impl std::fmt::Debug for ProxyConfig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ProxyConfig")
+            .field("protocol", &self.protocol)
+            .field("address", &self.address)
+            .field("port", &self.port)
+            .field("auth", &self.auth.is_some())
             .finish()
     }
 }
@@ -726,12 +1010,15 @@ impl Hash for MxConfig {
     fn hash<H: Hasher>(&self, state: &mut H) {
         self.max_mx.hash(state);
         self.max_multi_homed.hash(state);
+        self.proxy.hash(state);
     }
 }
 
 impl PartialEq for MxConfig {
     fn eq(&self, other: &Self) -> bool {
-        self.max_mx == other.max_mx && self.max_multi_homed == other.max_multi_homed
+        self.max_mx == other.max_mx
+            && self.max_multi_homed == other.max_multi_homed
+            && self.proxy == other.proxy
     }
 }
 
@@ -780,6 +1067,63 @@ impl ArchivedQueueName {
     }
 }
 
+impl Dsn {
+    // Domain templates take precedence over tenant and global templates,
+    // then the closest match to the sender's locale is preferred. Templates
+    // written for a different language are never used.
+    pub fn template(
+        &self,
+        domain_id: Option<u32>,
+        tenant_id: Option<u32>,
+        locale: enums::Locale,
+    ) -> Option<&DsnTemplate> {
+        let language = locale_language(locale);
+        self.templates
+            .iter()
+            .filter_map(|template| {
+                let scope = match (template.domain_id, template.tenant_id) {
+                    (Some(id), _) if Some(id) == domain_id => 3,
+                    (None, Some(id)) if Some(id) == tenant_id => 2,
+                    (None, None) => 1,
+                    _ => return None,
+                };
+                let locale_match = match template.locale {
+                    Some(template_locale) if template_locale == locale => 3,
+                    Some(template_locale) if locale_language(template_locale) == language => 2,
+                    None => 1,
+                    Some(_) => return None,
+                };
+
+                Some(((scope, locale_match), template))
+            })
+            .max_by_key(|(score, _)| *score)
+            .map(|(_, template)| template)
+    }
+}
+
+fn locale_language(locale: enums::Locale) -> &'static str {
+    let locale = locale.as_str();
+    locale
+        .split_once('_')
+        .map_or(locale, |(language, _)| language)
+}
+
+impl FromStr for DsnTemplateVariable {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sender" => Ok(DsnTemplateVariable::Sender),
+            "reporting_mta" => Ok(DsnTemplateVariable::ReportingMta),
+            "summary" => Ok(DsnTemplateVariable::Summary),
+            "delivered" => Ok(DsnTemplateVariable::Delivered),
+            "delayed" => Ok(DsnTemplateVariable::Delayed),
+            "failed" => Ok(DsnTemplateVariable::Failed),
+            _ => Err(format!("Unknown DSN template variable: {}", s)),
+        }
+    }
+}
+
 impl Default for QueueName {
     fn default() -> Self {
         DEFAULT_QUEUE_NAME
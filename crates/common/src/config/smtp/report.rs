@@ -13,8 +13,8 @@ use registry::schema::{
     enums::ExpressionConstant,
     prelude::ObjectType,
     structs::{
-        DataRetention, DkimReportSettings, DmarcReportSettings, ReportSettings, SpfReportSettings,
-        TlsReportSettings,
+        DataRetention, DkimReportSettings, DmarcReportSettings, Rate, ReportSettings,
+        SpfReportSettings, TlsReportSettings,
     },
 };
 use std::{str::FromStr, time::Duration};
@@ -36,6 +36,7 @@ pub struct ReportAnalysis {
     pub addresses: Vec<AddressMatch>,
     pub forward: bool,
     pub store: Option<Duration>,
+    pub tls_failure_rate: Option<Rate>,
 }
 
 #[derive(Clone)]
@@ -96,6 +97,7 @@ impl ReportConfig {
                     .collect(),
                 forward: report.inbound_report_forwarding,
                 store: dr.hold_mta_reports_for.map(|d| d.into_inner()),
+                tls_failure_rate: report.inbound_tls_failure_rate,
             },
             dkim: Report {
                 name: bp.compile_expr(
@@ -10,7 +10,7 @@ use mail_auth::{
         TokioResolver,
         config::{
             CLOUDFLARE, ConnectionConfig, GOOGLE, NameServerConfig, ProtocolConfig, QUAD9,
-            ResolverConfig, ResolverOpts,
+            ResolverConfig, ResolverOpts, ServerGroup,
         },
         net::runtime::TokioRuntimeProvider,
         system_conf::read_system_conf,
@@ -19,7 +19,7 @@ use mail_auth::{
 use registry::schema::{
     enums::{DnsResolverProtocol, PolicyEnforcement},
     prelude::ObjectType,
-    structs::{DnsResolver, MtaSts, SystemSettings},
+    structs::{DnsCustomResolver, DnsResolver, DnsResolverTls, MtaSts, SystemSettings},
 };
 use serde::{Deserialize, Serialize};
 use std::{
@@ -133,22 +133,8 @@ impl Resolvers {
                 resolver_config = ResolverConfig::default();
 
                 for server in resolver.servers {
-                    let ip = server.address.into_inner();
-                    let port = server.port as u16;
-                    let protocol = match server.protocol {
-                        DnsResolverProtocol::Udp => ProtocolConfig::Udp,
-                        DnsResolverProtocol::Tcp => ProtocolConfig::Tcp,
-                        DnsResolverProtocol::Tls => ProtocolConfig::Tls {
-                            server_name: Arc::from(server.address.to_string()),
-                        },
-                    };
-                    let mut connection = ConnectionConfig::new(protocol);
-                    connection.port = port;
-                    resolver_config.add_name_server(NameServerConfig::new(
-                        ip,
-                        true,
-                        vec![connection],
-                    ));
+                    resolver_config
+                        .add_name_server(custom_name_server(server, resolver.plaintext_fallback));
                 }
 
                 opts.num_concurrent_reqs = resolver.concurrency as usize;
@@ -159,12 +145,7 @@ impl Resolvers {
                 opts.edns0 = resolver.enable_edns;
             }
             DnsResolver::Cloudflare(resolver) => {
-                resolver_config = if resolver.use_tls {
-                    ResolverConfig::tls(&CLOUDFLARE)
-                } else {
-                    ResolverConfig::udp_and_tcp(&CLOUDFLARE)
-                };
-
+                resolver_config = encrypted_resolver_config(&CLOUDFLARE, &resolver);
                 opts.num_concurrent_reqs = resolver.concurrency as usize;
                 opts.timeout = resolver.timeout.into_inner();
                 opts.preserve_intermediates = resolver.preserve_intermediates;
@@ -173,11 +154,7 @@ impl Resolvers {
                 opts.edns0 = resolver.enable_edns;
             }
             DnsResolver::Quad9(resolver) => {
-                resolver_config = if resolver.use_tls {
-                    ResolverConfig::tls(&QUAD9)
-                } else {
-                    ResolverConfig::udp_and_tcp(&QUAD9)
-                };
+                resolver_config = encrypted_resolver_config(&QUAD9, &resolver);
                 opts.num_concurrent_reqs = resolver.concurrency as usize;
                 opts.timeout = resolver.timeout.into_inner();
                 opts.preserve_intermediates = resolver.preserve_intermediates;
@@ -219,6 +196,70 @@ impl Resolvers {
     }
 }
 
+pub fn custom_name_server(server: DnsCustomResolver, plaintext_fallback: bool) -> NameServerConfig {
+    let ip = server.address.into_inner();
+    let server_name = server
+        .tls_server_name
+        .unwrap_or_else(|| server.address.to_string());
+    let protocol = match server.protocol {
+        DnsResolverProtocol::Udp => ProtocolConfig::Udp,
+        DnsResolverProtocol::Tcp => ProtocolConfig::Tcp,
+        DnsResolverProtocol::Tls => ProtocolConfig::Tls {
+            server_name: Arc::from(server_name),
+        },
+        DnsResolverProtocol::Https => ProtocolConfig::Https {
+            server_name: Arc::from(server_name),
+            path: Arc::from(server.http_path.as_deref().unwrap_or("/dns-query")),
+        },
+    };
+    let is_encrypted = !matches!(
+        server.protocol,
+        DnsResolverProtocol::Udp | DnsResolverProtocol::Tcp
+    );
+    let mut connection = ConnectionConfig::new(protocol);
+    connection.port = server.port as u16;
+    let mut name_server = NameServerConfig::new(ip, true, vec![connection]);
+    if is_encrypted && plaintext_fallback {
+        add_plaintext_fallback(&mut name_server);
+    }
+    name_server
+}
+
+// Encrypted upstreams keep their connections open and reuse them across queries,
+// plaintext fallback connections are only attempted after the encrypted ones.
+pub fn encrypted_resolver_config(
+    group: &ServerGroup<'_>,
+    resolver: &DnsResolverTls,
+) -> ResolverConfig {
+    let config = if resolver.use_https {
+        ResolverConfig::https(group)
+    } else if resolver.use_tls {
+        ResolverConfig::tls(group)
+    } else {
+        return ResolverConfig::udp_and_tcp(group);
+    };
+
+    if resolver.plaintext_fallback {
+        let mut fallback_config = ResolverConfig::default();
+        for name_server in config.name_servers() {
+            let mut name_server = name_server.clone();
+            add_plaintext_fallback(&mut name_server);
+            fallback_config.add_name_server(name_server);
+        }
+        fallback_config
+    } else {
+        config
+    }
+}
+
+fn add_plaintext_fallback(name_server: &mut NameServerConfig) {
+    for protocol in [ProtocolConfig::Udp, ProtocolConfig::Tcp] {
+        name_server
+            .connections
+            .push(ConnectionConfig::new(protocol));
+    }
+}
+
 impl Policy {
     pub async fn try_parse(bp: &mut Bootstrap) -> Option<Self> {
         let mta = bp.setting_infallible::<MtaSts>().await;
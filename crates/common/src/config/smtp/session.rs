@@ -13,7 +13,7 @@ use crate::expr::{
 use ahash::AHashSet;
 use hyper::HeaderMap;
 use registry::schema::{
-    enums::{self, ExpressionConstant, MtaStage},
+    enums::{self, ExpressionConstant, MtaSenderMismatchAction, MtaStage, MtaStructureLimitAction},
     prelude::ObjectType,
     structs::{
         MtaExtensions, MtaHook, MtaInboundSession, MtaMilter, MtaStageAuth, MtaStageConnect,
@@ -68,17 +68,32 @@ pub struct Extensions {
     pub dsn: IfBlock,
     pub vrfy: IfBlock,
     pub expn: IfBlock,
+    pub vrfy_rate: IfBlock,
+    pub expn_rate: IfBlock,
     pub no_soliciting: IfBlock,
     pub future_release: IfBlock,
     pub deliver_by: IfBlock,
     pub mt_priority: IfBlock,
 }
 
+// How much a VRFY or EXPN answer discloses about local addresses
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum VrfyMode {
+    Full,
+    Ambiguous,
+    Privacy,
+    #[default]
+    Disable,
+}
+
 #[derive(Clone)]
 pub struct Auth {
     pub mechanisms: IfBlock,
     pub require: IfBlock,
     pub must_match_sender: IfBlock,
+    pub must_match_tenant_domain: IfBlock,
+    pub tenant_domain_exemptions: AHashSet<String>,
+    pub sender_mismatch_action: MtaSenderMismatchAction,
     pub errors_max: IfBlock,
     pub errors_wait: IfBlock,
 }
@@ -98,6 +113,10 @@ pub struct Rcpt {
     pub errors_max: IfBlock,
     pub errors_wait: IfBlock,
     pub max_recipients: IfBlock,
+    pub callout: IfBlock,
+    pub callout_timeout: Duration,
+    pub callout_cache_ttl: Duration,
+    pub callout_negative_cache_ttl: Duration,
 }
 
 #[derive(Debug, Default, Clone)]
@@ -108,20 +127,41 @@ pub enum AddressMapping {
     Disable,
 }
 
+// How much of the client's details are disclosed in the Received header
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ReceivedHeaderFormat {
+    #[default]
+    Full,
+    Privacy,
+    Minimal,
+}
+
 #[derive(Clone)]
 pub struct Data {
     pub script: IfBlock,
     pub spam_filter: IfBlock,
+    pub user_script_reject: IfBlock,
     pub max_messages: IfBlock,
     pub max_message_size: IfBlock,
+    pub large_message_size: IfBlock,
     pub max_received_headers: IfBlock,
     pub add_received: IfBlock,
     pub add_received_spf: IfBlock,
+    pub received_format: IfBlock,
+    pub scrub_trace_headers: IfBlock,
+    pub trace_headers: Vec<String>,
     pub add_return_path: IfBlock,
     pub add_auth_results: IfBlock,
     pub add_message_id: IfBlock,
     pub add_date: IfBlock,
     pub add_delivered_to: bool,
+    pub max_header_count: IfBlock,
+    pub max_header_size: IfBlock,
+    pub max_mime_depth: IfBlock,
+    pub max_mime_parts: IfBlock,
+    pub structure_limit_action: MtaStructureLimitAction,
+    pub reject_bare_lf: IfBlock,
+    pub reject_bare_cr: IfBlock,
 }
 
 #[derive(Clone)]
@@ -260,6 +300,16 @@ impl SessionConfig {
                     ObjectType::MtaStageAuth.singleton(),
                     &auth.ctx_must_match_sender(),
                 ),
+                must_match_tenant_domain: bp.compile_expr(
+                    ObjectType::MtaStageAuth.singleton(),
+                    &auth.ctx_must_match_tenant_domain(),
+                ),
+                tenant_domain_exemptions: auth
+                    .tenant_domain_exemptions
+                    .iter()
+                    .map(|domain| domain.trim().to_lowercase())
+                    .collect(),
+                sender_mismatch_action: auth.sender_mismatch_action,
                 errors_max: bp.compile_expr(
                     ObjectType::MtaStageAuth.singleton(),
                     &auth.ctx_max_failures(),
@@ -296,6 +346,10 @@ impl SessionConfig {
                     ObjectType::MtaStageRcpt.singleton(),
                     &rcpt.ctx_max_recipients(),
                 ),
+                callout: bp.compile_expr(ObjectType::MtaStageRcpt.singleton(), &rcpt.ctx_callout()),
+                callout_timeout: rcpt.callout_timeout.into_inner(),
+                callout_cache_ttl: rcpt.callout_cache_ttl.into_inner(),
+                callout_negative_cache_ttl: rcpt.callout_negative_cache_ttl.into_inner(),
             },
             data: Data {
                 script: bp.compile_expr(ObjectType::MtaStageData.singleton(), &data.ctx_script()),
@@ -303,6 +357,10 @@ impl SessionConfig {
                     ObjectType::MtaStageData.singleton(),
                     &data.ctx_enable_spam_filter(),
                 ),
+                user_script_reject: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_enable_user_script_reject(),
+                ),
                 max_messages: bp.compile_expr(
                     ObjectType::MtaStageData.singleton(),
                     &data.ctx_max_messages(),
@@ -311,6 +369,10 @@ impl SessionConfig {
                     ObjectType::MtaStageData.singleton(),
                     &data.ctx_max_message_size(),
                 ),
+                large_message_size: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_large_message_size(),
+                ),
                 max_received_headers: bp.compile_expr(
                     ObjectType::MtaStageData.singleton(),
                     &data.ctx_max_received_headers(),
@@ -323,6 +385,14 @@ impl SessionConfig {
                     ObjectType::MtaStageData.singleton(),
                     &data.ctx_add_received_spf_header(),
                 ),
+                received_format: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_received_header_format(),
+                ),
+                scrub_trace_headers: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_scrub_trace_headers(),
+                ),
                 add_return_path: bp.compile_expr(
                     ObjectType::MtaStageData.singleton(),
                     &data.ctx_add_return_path_header(),
@@ -340,6 +410,32 @@ impl SessionConfig {
                     &data.ctx_add_date_header(),
                 ),
                 add_delivered_to: data.add_delivered_to_header,
+                max_header_count: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_max_header_count(),
+                ),
+                max_header_size: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_max_header_size(),
+                ),
+                max_mime_depth: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_max_mime_depth(),
+                ),
+                max_mime_parts: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_max_mime_parts(),
+                ),
+                structure_limit_action: data.structure_limit_action,
+                reject_bare_lf: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_reject_bare_lf(),
+                ),
+                reject_bare_cr: bp.compile_expr(
+                    ObjectType::MtaStageData.singleton(),
+                    &data.ctx_reject_bare_cr(),
+                ),
+                trace_headers: data.trace_headers.into_inner(),
             },
             extensions: Extensions {
                 pipelining: bp
@@ -353,6 +449,10 @@ impl SessionConfig {
                 dsn: bp.compile_expr(ObjectType::MtaExtensions.singleton(), &ext.ctx_dsn()),
                 vrfy: bp.compile_expr(ObjectType::MtaExtensions.singleton(), &ext.ctx_vrfy()),
                 expn: bp.compile_expr(ObjectType::MtaExtensions.singleton(), &ext.ctx_expn()),
+                vrfy_rate: bp
+                    .compile_expr(ObjectType::MtaExtensions.singleton(), &ext.ctx_vrfy_rate()),
+                expn_rate: bp
+                    .compile_expr(ObjectType::MtaExtensions.singleton(), &ext.ctx_expn_rate()),
                 no_soliciting: bp.compile_expr(
                     ObjectType::MtaExtensions.singleton(),
                     &ext.ctx_no_soliciting(),
@@ -549,6 +649,42 @@ impl<'x> TryFrom<Variable<'x>> for MtPriority {
     }
 }
 
+impl<'x> TryFrom<Variable<'x>> for VrfyMode {
+    type Error = ();
+
+    fn try_from(value: Variable<'x>) -> Result<Self, Self::Error> {
+        match value {
+            Variable::Constant(value) => match value {
+                ExpressionConstant::Full => Ok(VrfyMode::Full),
+                ExpressionConstant::Ambiguous => Ok(VrfyMode::Ambiguous),
+                ExpressionConstant::Privacy => Ok(VrfyMode::Privacy),
+                ExpressionConstant::Disable => Ok(VrfyMode::Disable),
+                _ => Err(()),
+            },
+            // Boolean expressions predate the disclosure modes
+            Variable::Integer(0) => Ok(VrfyMode::Disable),
+            Variable::Integer(_) => Ok(VrfyMode::Full),
+            _ => Err(()),
+        }
+    }
+}
+
+impl<'x> TryFrom<Variable<'x>> for ReceivedHeaderFormat {
+    type Error = ();
+
+    fn try_from(value: Variable<'x>) -> Result<Self, Self::Error> {
+        match value {
+            Variable::Constant(value) => match value {
+                ExpressionConstant::Full => Ok(ReceivedHeaderFormat::Full),
+                ExpressionConstant::Privacy => Ok(ReceivedHeaderFormat::Privacy),
+                ExpressionConstant::Minimal => Ok(ReceivedHeaderFormat::Minimal),
+                _ => Err(()),
+            },
+            _ => Err(()),
+        }
+    }
+}
+
 impl From<MtaStage> for Stage {
     fn from(value: MtaStage) -> Self {
         match value {
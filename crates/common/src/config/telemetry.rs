@@ -17,7 +17,7 @@ use opentelemetry_semantic_conventions::resource::SERVICE_VERSION;
 use registry::schema::{
     enums::{EventPolicy, LogRotateFrequency},
     prelude::ObjectType,
-    structs::{self, EventTracingLevel, MetricsPrometheus, Tracer, WebHook},
+    structs::{self, DataRetention, EventTracingLevel, MetricsPrometheus, Tracer, WebHook},
 };
 use std::{collections::HashMap, str::FromStr, sync::Arc, time::Duration};
 use store::registry::bootstrap::Bootstrap;
@@ -127,6 +127,8 @@ pub struct Metrics {
     pub prometheus: Option<PrometheusMetrics>,
     pub otel: Option<Arc<OtelMetrics>>,
     pub log_path: Option<String>,
+    pub statistics_retention: Option<Duration>,
+    pub usage_retention: Option<Duration>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -581,6 +583,7 @@ impl Tracers {
 impl Metrics {
     pub async fn parse(bp: &mut Bootstrap) -> Self {
         let metrics = bp.setting_infallible::<structs::Metrics>().await;
+        let retention = bp.setting_infallible::<DataRetention>().await;
         let resource = Resource::builder()
             .with_service_name("stalwart")
             .with_attribute(KeyValue::new(SERVICE_VERSION, env!("CARGO_PKG_VERSION")))
@@ -696,6 +699,8 @@ impl Metrics {
                         None
                     }
                 }),
+            statistics_retention: retention.hold_statistics_for.map(|d| d.into_inner()),
+            usage_retention: retention.hold_tenant_usage_for.map(|d| d.into_inner()),
         }
     }
 }
@@ -46,7 +46,7 @@ impl Server {
             captures: Vec::new(),
             session_id,
         })
-        .eval()
+        .eval(None)
         .await
         {
             Ok(result) => {
@@ -87,6 +87,30 @@ impl Server {
         }
     }
 
+    pub async fn eval_if_with_trace<'x, V: ResolveVariable>(
+        &'x self,
+        if_block: &'x IfBlock,
+        resolver: &'x V,
+        session_id: u64,
+    ) -> trc::Result<IfBlockTrace<'x>> {
+        let mut conditions = Vec::with_capacity(if_block.if_then.len());
+        let result = if !if_block.is_empty() {
+            (EvalContext {
+                resolver,
+                core: self,
+                expr: if_block,
+                captures: Vec::new(),
+                session_id,
+            })
+            .eval(Some(&mut conditions))
+            .await?
+        } else {
+            Variable::default()
+        };
+
+        Ok(IfBlockTrace { conditions, result })
+    }
+
     pub async fn eval_expr<'x, R: TryFrom<Variable<'x>>, V: ResolveVariable>(
         &'x self,
         expr: &'x Expression,
@@ -148,6 +172,18 @@ impl Server {
     }
 }
 
+#[derive(Debug)]
+pub struct IfBlockTrace<'x> {
+    pub conditions: Vec<bool>,
+    pub result: Variable<'x>,
+}
+
+impl IfBlockTrace<'_> {
+    pub fn matched_branch(&self) -> Option<usize> {
+        self.conditions.iter().position(|matched| *matched)
+    }
+}
+
 struct EvalContext<'x, V: ResolveVariable, T, C> {
     resolver: &'x V,
     core: &'x Server,
@@ -157,9 +193,9 @@ struct EvalContext<'x, V: ResolveVariable, T, C> {
 }
 
 impl<'x, V: ResolveVariable> EvalContext<'x, V, IfBlock, Vec<CompactString>> {
-    async fn eval(&mut self) -> trc::Result<Variable<'x>> {
+    async fn eval(&mut self, mut trace: Option<&mut Vec<bool>>) -> trc::Result<Variable<'x>> {
         for if_then in &self.expr.if_then {
-            if (EvalContext {
+            let matched = (EvalContext {
                 resolver: self.resolver,
                 core: self.core,
                 expr: &if_then.expr,
@@ -168,8 +204,13 @@ impl<'x, V: ResolveVariable> EvalContext<'x, V, IfBlock, Vec<CompactString>> {
             })
             .eval()
             .await?
-            .to_bool()
-            {
+            .to_bool();
+
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(matched);
+            }
+
+            if matched {
                 return (EvalContext {
                     resolver: self.resolver,
                     core: self.core,
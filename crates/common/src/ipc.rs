@@ -107,6 +107,7 @@ pub enum QueueEvent {
     WorkerDone {
         queue_id: u64,
         queue_name: QueueName,
+        priority: i16,
         status: QueueEventStatus,
     },
     Paused(bool),
@@ -125,6 +126,7 @@ pub enum QueueEventStatus {
 pub enum ReportingEvent {
     Dmarc(Box<DmarcEvent>),
     Tls(Box<TlsEvent>),
+    TlsInbound(Box<TlsInboundEvent>),
     Stop,
 }
 
@@ -147,6 +149,13 @@ pub struct TlsEvent {
     pub span_id: u64,
 }
 
+#[derive(Debug)]
+pub struct TlsInboundEvent {
+    pub domain: String,
+    pub failure: FailureDetails,
+    pub span_id: u64,
+}
+
 #[derive(Debug, Hash, PartialEq, Eq)]
 pub enum PolicyType {
     Tlsa(Option<Arc<Tlsa>>),
@@ -224,6 +233,12 @@ impl From<TlsEvent> for ReportingEvent {
     }
 }
 
+impl From<TlsInboundEvent> for ReportingEvent {
+    fn from(value: TlsInboundEvent) -> Self {
+        ReportingEvent::TlsInbound(Box::new(value))
+    }
+}
+
 impl From<Arc<Tlsa>> for PolicyType {
     fn from(value: Arc<Tlsa>) -> Self {
         PolicyType::Tlsa(Some(value))
@@ -9,6 +9,7 @@
 use crate::auth::{AccessTokenInner, EmailAddress};
 use crate::manager::application::WebApplications;
 use crate::network::asn::AsnGeoLookupData;
+use crate::telemetry::{statistics::StatisticsCollector, usage::UsageCollector};
 use crate::{
     auth::{AccountCache, DomainCache, EmailCache, MailingListCache, RoleCache, TenantCache},
     config::{
@@ -21,7 +22,8 @@ use crate::{
         smtp::auth::DkimSigner,
     },
     ipc::TrainTaskController,
-    network::security::BlockedIps,
+    network::{listen::ActiveListeners, security::BlockedIps, sessions::ActiveSessions},
+    storage::encryption::EncryptionParams,
 };
 use ahash::{AHashMap, AHashSet};
 use arc_swap::ArcSwap;
@@ -51,9 +53,9 @@ use std::{
 };
 use store::InMemoryStore;
 use tinyvec::TinyVec;
-use tokio::sync::{Notify, Semaphore, mpsc};
+use tokio::sync::{Notify, OnceCell, Semaphore, mpsc};
 use tokio_rustls::TlsConnector;
-use types::{acl::AclGrant, special_use::SpecialUse};
+use types::{acl::AclGrant, keyword::KeywordRegistry, special_use::SpecialUse};
 use utils::{
     cache::{Cache, CacheWithTtl},
     snowflake::SnowflakeIdGenerator,
@@ -122,11 +124,26 @@ pub const KV_RATE_LIMIT_HTTP_AUTHENTICATED: u8 = 8;
 pub const KV_RATE_LIMIT_HTTP_ANONYMOUS: u8 = 9;
 pub const KV_RATE_LIMIT_IMAP: u8 = 10;
 pub const KV_QUOTA_BLOB: u8 = 11;
+pub const KV_RATE_LIMIT_HTTP_CLIENT: u8 = 12;
+pub const KV_RATE_LIMIT_HTTP_CLASS: u8 = 13;
 pub const KV_GREYLIST: u8 = 16;
 pub const KV_LOCK_QUEUE_MESSAGE: u8 = 21;
 pub const KV_LOCK_TASK: u8 = 23;
 pub const KV_LOCK_DAV: u8 = 25;
 pub const KV_SIEVE_ID: u8 = 26;
+pub const KV_RATE_LIMIT_SIEVE: u8 = 27;
+pub const KV_AUTH_FAILURES: u8 = 28;
+pub const KV_LOGIN_HISTORY: u8 = 29;
+pub const KV_MAINTENANCE_WINDOW: u8 = 30;
+pub const KV_DELIVERY_DEDUP: u8 = 31;
+pub const KV_SMTP_CALLOUT: u8 = 32;
+pub const KV_OAUTH_POLL: u8 = 33;
+pub const KV_RATE_LIMIT_SMUGGLING: u8 = 34;
+pub const KV_SIEVE_STATS: u8 = 35;
+pub const KV_SIEVE_REDIRECT: u8 = 36;
+pub const KV_TASK_PROGRESS: u8 = 37;
+pub const KV_TASK_CANCEL: u8 = 38;
+pub const KV_RATE_LIMIT_TLS_REPORT: u8 = 39;
 
 #[derive(Clone)]
 pub struct Server {
@@ -161,8 +178,12 @@ pub struct Data {
 
     pub applications: WebApplications,
     pub logos: Mutex<AHashMap<Box<str>, LogoCache>>,
+    pub statistics: StatisticsCollector,
+    pub usage: UsageCollector,
 
     pub smtp_connectors: TlsConnectors,
+    pub listeners: ActiveListeners,
+    pub sessions: ActiveSessions,
 }
 
 #[derive(Clone)]
@@ -194,6 +215,7 @@ pub struct Caches {
     pub lists: Cache<u32, Arc<MailingListCache>>,
 
     pub dkim_signers: Cache<u32, Arc<[DkimSigner]>>,
+    pub encryption_keys: CacheWithTtl<Box<str>, Arc<EncryptionParams>>,
 
     pub dns_txt: CacheWithTtl<Box<str>, Txt>,
     pub dns_mx: CacheWithTtl<Box<str>, Arc<[MX]>>,
@@ -214,6 +236,20 @@ pub struct MessageStoreCache {
     pub update_lock: Arc<UpdateLock>,
     pub last_change_id: u64,
     pub size: u64,
+    // Mailbox counters computed on first use, reset whenever the cache changes
+    pub status: Arc<OnceCell<AHashMap<u32, MailboxStatus>>>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MailboxStatus {
+    pub total_messages: u64,
+    pub total_unseen: u64,
+    pub total_deleted: u64,
+    pub total_threads: u64,
+    pub unread_threads: u64,
+    pub size: u64,
+    pub deleted_size: u64,
+    pub uid_next: u64,
 }
 
 #[derive(Debug, Clone)]
@@ -229,7 +265,7 @@ pub struct MessagesCache {
     pub change_id: u64,
     pub items: Box<[MessageCache]>,
     pub index: AHashMap<u32, u32>,
-    pub keywords: Box<[Box<str>]>,
+    pub keywords: KeywordRegistry,
     pub size: u64,
 }
 
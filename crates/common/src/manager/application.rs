@@ -16,6 +16,7 @@ use std::{
     time::Duration,
 };
 use store::{
+    SerializeInfallible,
     registry::{RegistryObject, bootstrap::Bootstrap},
     write::{BatchBuilder, BlobLink, BlobOp, now},
 };
@@ -228,7 +229,7 @@ impl WebApplicationManager {
                     BlobOp::Commit {
                         hash: self.blob_key.clone(),
                     },
-                    Vec::new(),
+                    (resource.len() as u64).serialize(),
                 );
             server
                 .store()
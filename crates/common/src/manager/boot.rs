@@ -49,6 +49,8 @@ Options:
   -e, --export <PATH>              Export all store data to a specific path
   -i, --import <PATH>              Import store data from a specific path
   -o, --console                    Open the store console
+  -k, --check                      Check the store for inconsistencies
+  -r, --repair                     Check the store and repair inconsistencies
   -h, --help                       Print help
   -V, --version                    Print version
 "#
@@ -59,6 +61,7 @@ enum StoreOp {
     Export(BackupParams),
     Import(PathBuf),
     Console,
+    Doctor { repair: bool },
     None,
 }
 
@@ -102,6 +105,12 @@ impl BootManager {
                     ("console" | "o", None) => {
                         import_export = StoreOp::Console;
                     }
+                    ("check" | "k", None) => {
+                        import_export = StoreOp::Doctor { repair: false };
+                    }
+                    ("repair" | "r", None) => {
+                        import_export = StoreOp::Doctor { repair: true };
+                    }
                     (_, None) => {
                         failed(&format!("Unrecognized command '{key}', try '--help'."));
                     }
@@ -274,6 +283,17 @@ impl BootManager {
                 .await;
                 std::process::exit(0);
             }
+            StoreOp::Doctor { repair } => {
+                // Enable telemetry
+                telemetry.enable(false);
+
+                // Check store consistency
+                Box::pin(Core::parse(&mut bootstrap, storage))
+                    .await
+                    .store_doctor_cli(repair)
+                    .await;
+                std::process::exit(0);
+            }
         }
     }
 }
@@ -163,11 +163,13 @@ async fn insert_safe_defaults(bp: &mut Bootstrap) -> trc::Result<()> {
                 description: "Local delivery queue".to_string().into(),
                 name: "local".into(),
                 threads_per_node: 25,
+                ..Default::default()
             },
             MtaVirtualQueue {
                 description: "Remote delivery queue".to_string().into(),
                 name: "remote".into(),
                 threads_per_node: 50,
+                ..Default::default()
             },
             MtaVirtualQueue {
                 description: "Delivery Status Notification delivery queue"
@@ -175,11 +177,13 @@ async fn insert_safe_defaults(bp: &mut Bootstrap) -> trc::Result<()> {
                     .into(),
                 name: "dsn".into(),
                 threads_per_node: 5,
+                ..Default::default()
             },
             MtaVirtualQueue {
                 description: "DMARC and TLS report delivery queue".to_string().into(),
                 name: "report".into(),
                 threads_per_node: 5,
+                ..Default::default()
             },
         ]
         .into_iter()
@@ -309,6 +313,7 @@ async fn insert_safe_defaults(bp: &mut Bootstrap) -> trc::Result<()> {
                 max_multihomed: 2,
                 max_mx_hosts: 2,
                 name: "mx".into(),
+                ..Default::default()
             }),
             MtaRoute::Local(MtaRouteCommon {
                 description: "Local delivery route".to_string().into(),
@@ -0,0 +1,46 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::Core;
+use registry::schema::prelude::ObjectType;
+use store::{registry::RegistryQuery, roaring::RoaringBitmap, write::doctor::StoreDoctorReport};
+use trc::AddContext;
+use utils::UnwrapFailure;
+
+impl Core {
+    pub async fn store_doctor(&self, repair: bool) -> trc::Result<StoreDoctorReport> {
+        let accounts = self
+            .storage
+            .registry
+            .query::<RoaringBitmap>(RegistryQuery::new(ObjectType::Account))
+            .await
+            .caused_by(trc::location!())?;
+
+        self.storage
+            .data
+            .doctor(&accounts, repair)
+            .await
+            .caused_by(trc::location!())
+    }
+
+    pub async fn store_doctor_cli(&self, repair: bool) {
+        let report = self
+            .store_doctor(repair)
+            .await
+            .failed("Failed to check data store");
+
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&report).unwrap_or_default()
+        );
+        if !repair && !report.findings.is_empty() {
+            eprintln!(
+                "Found {} issues, run with '--repair' to fix the ones that can be repaired safely.",
+                report.findings.len()
+            );
+        }
+    }
+}
@@ -13,11 +13,14 @@ use std::{
 };
 use utils::HttpLimitResponse;
 
+pub use utils::http::is_localhost_url;
+
 pub mod application;
 pub mod backup;
 pub mod boot;
 pub mod console;
 pub mod defaults;
+pub mod doctor;
 pub mod restore;
 
 pub const SPAM_TRAINER_KEY: &[u8] = "STALWART_SPAM_TRAIN_DATA.lz4".as_bytes();
@@ -72,12 +75,3 @@ pub async fn fetch_resource(
         }
     })
 }
-
-pub fn is_localhost_url(url: &str) -> bool {
-    url.split_once("://")
-        .map(|(_, url)| url.split_once('/').map_or(url, |(host, _)| host))
-        .is_some_and(|host| {
-            let host = host.rsplit_once(':').map_or(host, |(host, _)| host);
-            host == "localhost" || host == "127.0.0.1" || host == "[::1]"
-        })
-}
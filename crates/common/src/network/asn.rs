@@ -50,6 +50,8 @@ pub struct IpRange<I: Ord, T> {
 
 impl Server {
     pub async fn lookup_asn_country(&self, ip: IpAddr) -> AsnGeoLookupResult {
+        // IPv4-mapped addresses are looked up in the IPv4 tables and zone
+        let ip = ip.to_canonical();
         let mut result = AsnGeoLookupResult::default();
 
         match &self.core.network.asn_geo_lookup {
@@ -7,3 +7,4 @@
 pub mod records;
 pub mod resolve;
 pub mod update;
+pub mod webhook;
@@ -4,6 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
+use super::webhook::DnsWebhook;
 use crate::{Core, Server};
 use base64::{Engine, engine::general_purpose};
 use dns_update::{
@@ -23,7 +24,7 @@ use trc::DnsEvent;
 use types::id::Id;
 
 pub struct DnsUpdater {
-    pub updater: dns_update::DnsUpdater,
+    pub updater: DnsBackend,
     pub polling_interval: Duration,
     pub propagation_timeout: Duration,
     pub propagation_delay: Option<Duration>,
@@ -31,6 +32,11 @@ pub struct DnsUpdater {
     core: Arc<Core>,
 }
 
+pub enum DnsBackend {
+    Native(dns_update::DnsUpdater),
+    Webhook(DnsWebhook),
+}
+
 #[cfg(feature = "test_mode")]
 pub static DNS_RECORDS: std::sync::LazyLock<
     Arc<std::sync::Mutex<Vec<dns_update::NamedDnsRecord>>>,
@@ -73,7 +79,8 @@ impl DnsUpdater {
                         enums::TsigAlgorithm::HmacSha512256 => TsigAlgorithm::HmacSha512_256,
                     },
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Cloudflare(server) => {
                 let updater = {
@@ -107,7 +114,7 @@ impl DnsUpdater {
                     propagation_delay: server.propagation_delay.map(|d| d.into_inner()),
                     ttl: server.ttl.into_inner(),
                     core,
-                    updater,
+                    updater: updater.into(),
                 })
             }
             DnsServer::DigitalOcean(server) => Ok(DnsUpdater {
@@ -120,7 +127,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::DeSEC(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -132,7 +140,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Ovh(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -154,7 +163,8 @@ impl DnsUpdater {
                     },
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Bunny(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -166,7 +176,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Porkbun(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -179,7 +190,8 @@ impl DnsUpdater {
                     server.secret_api_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Dnsimple(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -192,7 +204,8 @@ impl DnsUpdater {
                     server.account_identifier.as_str(),
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Spaceship(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -205,7 +218,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Route53(server) => {
                 let secret_access_key = server.secret_access_key.secret().await?.into_owned();
@@ -225,7 +239,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_route53(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::GoogleCloudDns(server) => {
@@ -245,7 +260,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_google_cloud_dns(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::Alidns(server) => {
@@ -269,7 +285,8 @@ impl DnsUpdater {
                         server.line.as_deref(),
                         server.timeout.into_inner().into(),
                     )
-                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                    .into(),
                 })
             }
             DnsServer::ArvanCloud(server) => Ok(DnsUpdater {
@@ -282,7 +299,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Autodns(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -296,7 +314,8 @@ impl DnsUpdater {
                     server.context.map(|v| v as u32),
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::AzureDns(server) => {
                 let client_secret = server.client_secret.secret().await?.into_owned();
@@ -326,7 +345,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_azuredns(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::BaiduCloud(server) => Ok(DnsUpdater {
@@ -340,7 +360,8 @@ impl DnsUpdater {
                     server.secret_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::BluecatV2(server) => {
                 let password = server.password.secret().await?.into_owned();
@@ -360,7 +381,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_bluecatv2(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::ClouDns(server) => Ok(DnsUpdater {
@@ -375,7 +397,8 @@ impl DnsUpdater {
                     server.password.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Constellix(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -388,7 +411,8 @@ impl DnsUpdater {
                     server.secret_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Cpanel(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -402,7 +426,8 @@ impl DnsUpdater {
                     server.token.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Ddnss(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -414,7 +439,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::DnsMadeEasy(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -427,7 +453,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Domeneshop(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -440,7 +467,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Dreamhost(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -452,7 +480,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::DuckDns(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -464,7 +493,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Dynu(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -476,7 +506,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::EasyDns(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -489,7 +520,8 @@ impl DnsUpdater {
                     server.key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::EdgeDns(server) => {
                 let client_secret = server.client_secret.secret().await?.into_owned();
@@ -509,7 +541,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_edgedns(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::Exoscale(server) => Ok(DnsUpdater {
@@ -523,7 +556,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::FreeMyIp(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -535,7 +569,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::GandiV5(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -547,7 +582,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Gcore(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -559,7 +595,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Glesys(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -572,7 +609,8 @@ impl DnsUpdater {
                     server.api_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Godaddy(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -585,7 +623,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Hetzner(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -597,7 +636,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::HostingDe(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -609,7 +649,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Hostinger(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -621,7 +662,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::HuaweiCloud(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -635,7 +677,8 @@ impl DnsUpdater {
                     server.region.as_str(),
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Hurricane(server) => {
                 let mut credentials = std::collections::HashMap::new();
@@ -653,7 +696,8 @@ impl DnsUpdater {
                         credentials,
                         server.timeout.into_inner().into(),
                     )
-                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                    .into(),
                 })
             }
             DnsServer::IbmCloud(server) => Ok(DnsUpdater {
@@ -667,7 +711,8 @@ impl DnsUpdater {
                     server.api_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Infoblox(server) => {
                 let password = server.password.secret().await?.into_owned();
@@ -687,7 +732,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_infoblox(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::Infomaniak(server) => Ok(DnsUpdater {
@@ -700,7 +746,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Inwx(server) => {
                 let password = server.password.secret().await?.into_owned();
@@ -716,7 +763,8 @@ impl DnsUpdater {
                         server.sandbox,
                         server.timeout.into_inner().into(),
                     )
-                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                    .into(),
                 })
             }
             DnsServer::Ionos(server) => Ok(DnsUpdater {
@@ -729,7 +777,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Ipv64(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -741,7 +790,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Joker(server) => {
                 let auth = match server.auth {
@@ -767,7 +817,8 @@ impl DnsUpdater {
                         auth,
                         server.timeout.into_inner().into(),
                     )
-                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                    .into(),
                 })
             }
             DnsServer::Lightsail(server) => {
@@ -788,7 +839,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_lightsail(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::Linode(server) => Ok(DnsUpdater {
@@ -801,7 +853,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::LuaDns(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -814,7 +867,8 @@ impl DnsUpdater {
                     server.auth_token.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::MythicBeasts(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -827,7 +881,8 @@ impl DnsUpdater {
                     server.password.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Namecheap(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -842,7 +897,8 @@ impl DnsUpdater {
                     server.username.as_deref(),
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::NameDotCom(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -855,7 +911,8 @@ impl DnsUpdater {
                     server.auth_token.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::NameSilo(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -867,7 +924,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Netcup(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -881,7 +939,8 @@ impl DnsUpdater {
                     server.password.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Netlify(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -893,7 +952,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Nifcloud(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -906,7 +966,8 @@ impl DnsUpdater {
                     server.secret_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Ns1(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -918,7 +979,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::OracleCloud(server) => {
                 let private_key_pem = server.private_key_pem.secret().await?.into_owned();
@@ -944,7 +1006,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_oraclecloud(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::Plesk(server) => Ok(DnsUpdater {
@@ -958,7 +1021,8 @@ impl DnsUpdater {
                     server.api_key.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Safedns(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -970,7 +1034,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Scaleway(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -982,7 +1047,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::TencentCloud(server) => {
                 let secret_key = server.secret_key.secret().await?.into_owned();
@@ -1000,7 +1066,8 @@ impl DnsUpdater {
                         session_token.as_deref(),
                         server.timeout.into_inner().into(),
                     )
-                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                    .into(),
                 })
             }
             DnsServer::Transip(server) => Ok(DnsUpdater {
@@ -1015,7 +1082,8 @@ impl DnsUpdater {
                     true,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::UltraDns(server) => {
                 let password = server.password.secret().await?.into_owned();
@@ -1031,7 +1099,8 @@ impl DnsUpdater {
                         server.endpoint,
                         server.timeout.into_inner().into(),
                     )
-                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                    .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                    .into(),
                 })
             }
             DnsServer::Vercel(server) => Ok(DnsUpdater {
@@ -1045,7 +1114,8 @@ impl DnsUpdater {
                     server.team_id.as_deref(),
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::Volcengine(server) => {
                 let secret_key = server.secret_key.secret().await?.into_owned();
@@ -1064,7 +1134,8 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_volcengine(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
             DnsServer::Vultr(server) => Ok(DnsUpdater {
@@ -1077,7 +1148,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::WebSupport(server) => Ok(DnsUpdater {
                 polling_interval: server.polling_interval.into_inner(),
@@ -1090,7 +1162,8 @@ impl DnsUpdater {
                     server.secret.secret().await?,
                     server.timeout.into_inner().into(),
                 )
-                .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                .into(),
             }),
             DnsServer::YandexCloud(server) => {
                 let iam_token_b64 = server.api_key.secret().await?.into_owned();
@@ -1106,9 +1179,22 @@ impl DnsUpdater {
                     ttl: server.ttl.into_inner(),
                     core,
                     updater: dns_update::DnsUpdater::new_yandexcloud(config)
-                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?
+                        .into(),
                 })
             }
+            DnsServer::Webhook(server) => Ok(DnsUpdater {
+                polling_interval: server.polling_interval.into_inner(),
+                propagation_timeout: server.propagation_timeout.into_inner(),
+                propagation_delay: server.propagation_delay.map(|d| d.into_inner()),
+                ttl: server.ttl.into_inner(),
+                core,
+                updater: DnsBackend::Webhook(
+                    DnsWebhook::build(&server)
+                        .await
+                        .map_err(|err| format!("Failed to build DNS updater: {}", err))?,
+                ),
+            }),
             DnsServer::Deprecated1 => Err("DNS server type no longer supported".to_string()),
         }
     }
@@ -1230,7 +1316,9 @@ impl DnsUpdater {
         #[cfg(feature = "test_mode")]
         if matches!(
             self.updater,
-            dns_update::DnsUpdater::Pebble(_) | dns_update::DnsUpdater::InMemory(_)
+            DnsBackend::Native(
+                dns_update::DnsUpdater::Pebble(_) | dns_update::DnsUpdater::InMemory(_)
+            )
         ) {
             return true;
         }
@@ -1294,6 +1382,91 @@ impl DnsUpdater {
     }
 }
 
+impl DnsBackend {
+    pub async fn set_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        ttl: u32,
+        records: Vec<DnsRecord>,
+        origin: &str,
+    ) -> dns_update::Result<()> {
+        match self {
+            DnsBackend::Native(updater) => {
+                updater
+                    .set_rrset(name, record_type, ttl, records, origin)
+                    .await
+            }
+            DnsBackend::Webhook(webhook) => {
+                webhook
+                    .set_rrset(name, record_type, ttl, records, origin)
+                    .await
+            }
+        }
+    }
+
+    pub async fn add_to_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        ttl: u32,
+        records: Vec<DnsRecord>,
+        origin: &str,
+    ) -> dns_update::Result<()> {
+        match self {
+            DnsBackend::Native(updater) => {
+                updater
+                    .add_to_rrset(name, record_type, ttl, records, origin)
+                    .await
+            }
+            DnsBackend::Webhook(webhook) => {
+                webhook
+                    .add_to_rrset(name, record_type, ttl, records, origin)
+                    .await
+            }
+        }
+    }
+
+    pub async fn list_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        origin: &str,
+    ) -> dns_update::Result<Vec<DnsRecord>> {
+        match self {
+            DnsBackend::Native(updater) => updater.list_rrset(name, record_type, origin).await,
+            DnsBackend::Webhook(webhook) => webhook.list_rrset(name, record_type, origin).await,
+        }
+    }
+
+    pub async fn remove_from_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        records: Vec<DnsRecord>,
+        origin: &str,
+    ) -> dns_update::Result<()> {
+        match self {
+            DnsBackend::Native(updater) => {
+                updater
+                    .remove_from_rrset(name, record_type, records, origin)
+                    .await
+            }
+            DnsBackend::Webhook(webhook) => {
+                webhook
+                    .remove_from_rrset(name, record_type, records, origin)
+                    .await
+            }
+        }
+    }
+}
+
+impl From<dns_update::DnsUpdater> for DnsBackend {
+    fn from(updater: dns_update::DnsUpdater) -> Self {
+        DnsBackend::Native(updater)
+    }
+}
+
 impl Server {
     pub async fn build_dns_updater_for_domain(
         &self,
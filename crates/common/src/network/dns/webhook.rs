@@ -0,0 +1,184 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use dns_update::{DnsRecord, DnsRecordType};
+use registry::schema::structs::DnsServerWebhook;
+use utils::{Client, HttpLimitResponse};
+
+const MAX_RESPONSE_SIZE: usize = 1024 * 1024;
+
+// Generic DNS provider that forwards record changes to an HTTP endpoint,
+// allowing any DNS service to be used for DNS-01 challenges and record
+// management by deploying a small adapter.
+pub struct DnsWebhook {
+    url: String,
+    client: Client,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DnsWebhookAction {
+    Set,
+    Add,
+    Remove,
+    List,
+}
+
+#[derive(Debug, serde::Serialize)]
+pub struct DnsWebhookRequest<'x> {
+    pub action: DnsWebhookAction,
+    pub origin: &'x str,
+    pub name: &'x str,
+    #[serde(rename = "type")]
+    pub record_type: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ttl: Option<u32>,
+    pub records: Vec<String>,
+}
+
+#[derive(Debug, Default, serde::Deserialize)]
+pub struct DnsWebhookResponse {
+    #[serde(default)]
+    pub records: Vec<String>,
+}
+
+impl DnsWebhook {
+    pub async fn build(server: &DnsServerWebhook) -> Result<Self, String> {
+        Ok(DnsWebhook {
+            url: server.url.clone(),
+            client: server
+                .http_auth
+                .build_http_client(
+                    server.http_headers.clone(),
+                    "application/json".into(),
+                    server.timeout,
+                    server.allow_invalid_certs,
+                )
+                .await?,
+        })
+    }
+
+    pub async fn set_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        ttl: u32,
+        records: Vec<DnsRecord>,
+        origin: &str,
+    ) -> dns_update::Result<()> {
+        self.send(DnsWebhookRequest {
+            action: DnsWebhookAction::Set,
+            origin,
+            name,
+            record_type: record_type.as_str(),
+            ttl: Some(ttl),
+            records: records.iter().map(|r| r.to_string()).collect(),
+        })
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn add_to_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        ttl: u32,
+        records: Vec<DnsRecord>,
+        origin: &str,
+    ) -> dns_update::Result<()> {
+        self.send(DnsWebhookRequest {
+            action: DnsWebhookAction::Add,
+            origin,
+            name,
+            record_type: record_type.as_str(),
+            ttl: Some(ttl),
+            records: records.iter().map(|r| r.to_string()).collect(),
+        })
+        .await
+        .map(|_| ())
+    }
+
+    pub async fn remove_from_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        records: Vec<DnsRecord>,
+        origin: &str,
+    ) -> dns_update::Result<()> {
+        self.send(DnsWebhookRequest {
+            action: DnsWebhookAction::Remove,
+            origin,
+            name,
+            record_type: record_type.as_str(),
+            ttl: None,
+            records: records.iter().map(|r| r.to_string()).collect(),
+        })
+        .await
+        .map(|_| ())
+    }
+
+    // Only TXT records can be listed, as other record types would have to be
+    // parsed back from their presentation format.
+    pub async fn list_rrset(
+        &self,
+        name: &str,
+        record_type: DnsRecordType,
+        origin: &str,
+    ) -> dns_update::Result<Vec<DnsRecord>> {
+        if !matches!(record_type, DnsRecordType::TXT) {
+            return Err(dns_update::Error::Unsupported(
+                "webhook providers can only list TXT records".into(),
+            ));
+        }
+
+        self.send(DnsWebhookRequest {
+            action: DnsWebhookAction::List,
+            origin,
+            name,
+            record_type: record_type.as_str(),
+            ttl: None,
+            records: Vec::new(),
+        })
+        .await
+        .map(|response| response.records.into_iter().map(DnsRecord::TXT).collect())
+    }
+
+    async fn send(&self, request: DnsWebhookRequest<'_>) -> dns_update::Result<DnsWebhookResponse> {
+        let response = self
+            .client
+            .post(&self.url)
+            .body(serde_json::to_string(&request).map_err(|err| {
+                dns_update::Error::Api(format!("Failed to serialize webhook request: {err}"))
+            })?)
+            .send()
+            .await
+            .map_err(|err| dns_update::Error::Api(format!("Webhook request failed: {err}")))?;
+
+        if response.status().is_success() {
+            let bytes = response
+                .bytes_with_limit(MAX_RESPONSE_SIZE)
+                .await
+                .map_err(|err| {
+                    dns_update::Error::Api(format!("Failed to read webhook response: {err}"))
+                })?
+                .ok_or_else(|| dns_update::Error::Api("Webhook response too large".into()))?;
+
+            if bytes.iter().all(|ch| ch.is_ascii_whitespace()) {
+                Ok(DnsWebhookResponse::default())
+            } else {
+                serde_json::from_slice(&bytes).map_err(|err| {
+                    dns_update::Error::Api(format!("Failed to parse webhook response: {err}"))
+                })
+            }
+        } else {
+            Err(dns_update::Error::Api(format!(
+                "Webhook request failed with code {}: {}",
+                response.status().as_u16(),
+                response.status().canonical_reason().unwrap_or("Unknown")
+            )))
+        }
+    }
+}
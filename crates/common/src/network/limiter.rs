@@ -5,12 +5,14 @@
  */
 
 use crate::{ThrottleKey, ThrottleKeyHasher, ThrottleKeyHasherBuilder};
+use parking_lot::Mutex;
 use std::{
     hash::{BuildHasher, Hasher},
     sync::{
         Arc,
         atomic::{AtomicU64, Ordering},
     },
+    time::{Duration, Instant},
 };
 
 #[derive(Debug, Clone)]
@@ -25,6 +27,23 @@ pub struct ConcurrencyLimiterInner {
 
 pub struct InFlight(Arc<ConcurrencyLimiterInner>);
 
+#[derive(Debug, Clone)]
+#[repr(transparent)]
+pub struct BandwidthLimiter(Arc<BandwidthLimiterInner>);
+
+#[derive(Debug)]
+pub struct BandwidthLimiterInner {
+    rate: u64,
+    burst: u64,
+    bucket: Mutex<TokenBucket>,
+}
+
+#[derive(Debug)]
+struct TokenBucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
 impl Drop for InFlight {
     fn drop(&mut self) {
         self.0.concurrent.fetch_sub(1, Ordering::Relaxed);
@@ -62,6 +81,61 @@ impl ConcurrencyLimiter {
     }
 }
 
+impl BandwidthLimiter {
+    pub fn new(rate: u64, burst: u64) -> Self {
+        BandwidthLimiter(Arc::new(BandwidthLimiterInner {
+            rate: rate.max(1),
+            burst,
+            bucket: Mutex::new(TokenBucket {
+                tokens: burst as f64,
+                last_refill: Instant::now(),
+            }),
+        }))
+    }
+
+    /// Takes `bytes` from the bucket and returns how long the caller has to
+    /// wait before sending them. Transfers larger than the burst size are
+    /// allowed to overdraw the bucket, which delays subsequent transfers.
+    pub fn consume(&self, bytes: usize) -> Option<Duration> {
+        let mut bucket = self.0.bucket.lock();
+        let now = Instant::now();
+        bucket.tokens = (bucket.tokens
+            + now.duration_since(bucket.last_refill).as_secs_f64() * self.0.rate as f64)
+            .min(self.0.burst as f64);
+        bucket.last_refill = now;
+        bucket.tokens -= bytes as f64;
+
+        if bucket.tokens < 0.0 {
+            Some(Duration::from_secs_f64(-bucket.tokens / self.0.rate as f64))
+        } else {
+            None
+        }
+    }
+
+    pub fn is_throttled(&self) -> bool {
+        self.0.bucket.lock().tokens < 0.0
+    }
+
+    pub fn rate(&self) -> u64 {
+        self.0.rate
+    }
+
+    pub fn burst(&self) -> u64 {
+        self.0.burst
+    }
+}
+
+/// Returns the longest wait imposed by any of the limiters for a transfer of `bytes`.
+pub fn bandwidth_wait<'x>(
+    limiters: impl IntoIterator<Item = &'x BandwidthLimiter>,
+    bytes: usize,
+) -> Option<Duration> {
+    limiters
+        .into_iter()
+        .filter_map(|limiter| limiter.consume(bytes))
+        .max()
+}
+
 impl InFlight {
     pub fn num_concurrent(&self) -> u64 {
         self.0.concurrent.load(Ordering::Relaxed)
@@ -125,3 +199,23 @@ impl BuildHasher for ThrottleKeyHasherBuilder {
         ThrottleKeyHasher::default()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BandwidthLimiter;
+    use std::time::Duration;
+
+    #[test]
+    fn bandwidth_limiter() {
+        let limiter = BandwidthLimiter::new(1000, 2000);
+
+        // Transfers within the burst size are not delayed
+        assert_eq!(limiter.consume(1500), None);
+        assert!(!limiter.is_throttled());
+
+        // Overdrawing the bucket delays the transfer until it refills
+        let wait = limiter.consume(1500).unwrap();
+        assert!(wait > Duration::from_millis(900) && wait <= Duration::from_millis(1000));
+        assert!(limiter.is_throttled());
+    }
+}
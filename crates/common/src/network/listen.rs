@@ -10,16 +10,24 @@ use super::{
 };
 use crate::{
     BuildServer, Inner, Server,
-    config::server::{Listener, Listeners, ServerProtocol, TcpListener},
+    auth::client_cert::ClientCertificate,
+    config::server::{Listener, ListenerBinding, Listeners, ServerProtocol, TcpListener},
 };
+use ahash::AHashMap;
+use arc_swap::ArcSwap;
 use proxy_header::io::ProxiedStream;
+use registry::types::id::ObjectId;
 use rustls::crypto::aws_lc_rs::cipher_suite::TLS13_AES_128_GCM_SHA256;
 use std::{
     net::{IpAddr, SocketAddr},
     sync::Arc,
 };
 use store::registry::bootstrap::Bootstrap;
-use tokio::{net::TcpStream, sync::watch};
+use tokio::{
+    net::TcpStream,
+    sync::{Mutex, watch},
+    task::JoinHandle,
+};
 use tokio_rustls::server::TlsStream;
 use trc::{EventType, HttpEvent, ImapEvent, ManageSieveEvent, Pop3Event, SmtpEvent};
 use utils::UnwrapFailure;
@@ -31,24 +39,25 @@ impl Listener {
         inner: Arc<Inner>,
         acceptor: TcpAcceptor,
         shutdown_rx: watch::Receiver<bool>,
-    ) {
+    ) -> ListenerHandle {
         // Prepare instance
-        let instance = Arc::new(ServerInstance {
+        let instance = Arc::new(ArcSwap::from_pointee(ServerInstance {
             id: self.id,
             protocol: self.protocol,
             proxy_networks: self.proxy_networks,
             limiter: ConcurrencyLimiter::new(self.max_connections),
+            bandwidth: self.bandwidth,
             acceptor,
             shutdown_rx,
             span_id_gen: self.span_id_gen,
-        });
-        let is_tls = matches!(instance.acceptor, TcpAcceptor::Tls { implicit, .. } if implicit);
-        let is_https = is_tls && self.protocol == ServerProtocol::Http;
-        let has_proxies = !instance.proxy_networks.is_empty();
+        }));
+        let stop_tx = Arc::new(watch::channel(false).0);
+        let mut tasks = Vec::with_capacity(self.listeners.len());
 
         // Spawn listeners
         for listener in self.listeners {
             let local_addr = listener.addr;
+            let is_tls = instance.load().is_implicit_tls();
 
             // Obtain TCP options
             let opts = SocketOpts {
@@ -61,7 +70,7 @@ impl Listener {
                 Ok(listener) => {
                     trc::event!(
                         Network(trc::NetworkEvent::ListenStart),
-                        ListenerId = instance.id.clone(),
+                        ListenerId = instance.load().id.clone(),
                         LocalIp = local_addr.ip(),
                         LocalPort = local_addr.port(),
                         Tls = is_tls,
@@ -72,7 +81,7 @@ impl Listener {
                 Err(err) => {
                     trc::event!(
                         Network(trc::NetworkEvent::ListenError),
-                        ListenerId = instance.id.clone(),
+                        ListenerId = instance.load().id.clone(),
                         LocalIp = local_addr.ip(),
                         LocalPort = local_addr.port(),
                         Tls = is_tls,
@@ -84,11 +93,13 @@ impl Listener {
             };
 
             // Spawn listener
-            let mut shutdown_rx = instance.shutdown_rx.clone();
+            let mut shutdown_rx = instance.load().shutdown_rx.clone();
+            let stop_tx = stop_tx.clone();
             let manager = manager.clone();
             let instance = instance.clone();
             let inner = inner.clone();
-            tokio::spawn(async move {
+            tasks.push(tokio::spawn(async move {
+                let mut stop_rx = stop_tx.subscribe();
                 let (span_start, span_end) = match self.protocol {
                     ServerProtocol::Smtp | ServerProtocol::Lmtp => (
                         EventType::Smtp(SmtpEvent::ConnectionStart),
@@ -115,13 +126,18 @@ impl Listener {
                 loop {
                     tokio::select! {
                         stream = listener.accept() => {
+                            // Settings may have been replaced by a reload, sessions keep the ones they started with
+                            let instance = instance.load_full();
+                            let is_tls = instance.is_implicit_tls();
+                            let is_https = is_tls && self.protocol == ServerProtocol::Http;
+
                             match stream {
                                 Ok((stream, remote_addr)) => {
                                     let server = inner.build_server();
+                                    let sessions = inner.data.sessions.clone();
                                     let enable_acme = (is_https && server.has_acme_tls_providers()).then(|| server.clone());
 
-                                    if has_proxies && instance.proxy_networks.iter().any(|network| network.matches(&remote_addr.ip())) {
-                                        let instance = instance.clone();
+                                    if instance.proxy_networks.iter().any(|network| network.matches(&remote_addr.ip())) {
                                         let manager = manager.clone();
 
                                         // Set socket options
@@ -136,7 +152,7 @@ impl Listener {
                                                                             .unwrap_or(remote_addr);
                                                     if let Some(session) = instance.build_session(stream, local_addr, remote_addr, &server) {
                                                         // Spawn session
-                                                        manager.spawn(session, is_tls, enable_acme, span_start, span_end);
+                                                        manager.spawn(session, is_tls, enable_acme, sessions, span_start, span_end);
                                                     }
                                                 }
                                                 Err(err) => {
@@ -156,7 +172,7 @@ impl Listener {
                                         opts.apply(&session.stream);
 
                                         // Spawn session
-                                        manager.spawn(session, is_tls, enable_acme, span_start, span_end);
+                                        manager.spawn(session, is_tls, enable_acme, sessions, span_start, span_end);
                                     }
                                 }
                                 Err(err) => {
@@ -171,13 +187,26 @@ impl Listener {
                                 }
                             }
                         },
-                        _ = shutdown_rx.changed() => {
+                        _ = stop_rx.changed() => {
+                            // Listener removed or rebound, active sessions are left to finish
+                            let instance = instance.load();
+                            trc::event!(
+                                Network(trc::NetworkEvent::ListenStop),
+                                ListenerId = instance.id.clone(),
+                                LocalIp = local_addr.ip(),
+                                Tls = instance.is_implicit_tls(),
+                                LocalPort = local_addr.port(),
+                            );
 
+                            break;
+                        }
+                        _ = shutdown_rx.changed() => {
+                            let instance = instance.load();
                             trc::event!(
                                 Network(trc::NetworkEvent::ListenStop),
                                 ListenerId = instance.id.clone(),
                                 LocalIp = local_addr.ip(),
-                                Tls = is_tls,
+                                Tls = instance.is_implicit_tls(),
                                 LocalPort = local_addr.port(),
                             );
 
@@ -186,7 +215,14 @@ impl Listener {
                         }
                     };
                 }
-            });
+            }));
+        }
+
+        ListenerHandle {
+            binding: self.binding,
+            instance,
+            stop_tx,
+            tasks,
         }
     }
 }
@@ -332,6 +368,146 @@ impl Listeners {
         }
         (shutdown_tx, shutdown_rx)
     }
+
+    // Spawns the listeners and keeps track of them, so that a configuration
+    // reload can bind new listeners and update or drain the existing ones.
+    pub async fn spawn_managed(
+        mut self,
+        inner: &Arc<Inner>,
+        spawner: ListenerSpawner,
+    ) -> (watch::Sender<bool>, watch::Receiver<bool>) {
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let mut handles = AHashMap::with_capacity(self.servers.len());
+        for server in self.servers {
+            let acceptor = self
+                .tcp_acceptors
+                .remove(&server.id)
+                .unwrap_or(TcpAcceptor::Plain);
+
+            handles.insert(
+                server.registry_id,
+                spawner(server, inner.clone(), acceptor, shutdown_rx.clone()),
+            );
+        }
+
+        *inner.data.listeners.0.lock().await = Some(ActiveListenersInner {
+            spawner,
+            shutdown_rx: shutdown_rx.clone(),
+            handles,
+        });
+
+        (shutdown_tx, shutdown_rx)
+    }
+}
+
+pub type ListenerSpawner =
+    fn(Listener, Arc<Inner>, TcpAcceptor, watch::Receiver<bool>) -> ListenerHandle;
+
+pub struct ListenerHandle {
+    binding: ListenerBinding,
+    instance: Arc<ArcSwap<ServerInstance>>,
+    stop_tx: Arc<watch::Sender<bool>>,
+    tasks: Vec<JoinHandle<()>>,
+}
+
+#[derive(Default)]
+pub struct ActiveListeners(Mutex<Option<ActiveListenersInner>>);
+
+struct ActiveListenersInner {
+    spawner: ListenerSpawner,
+    shutdown_rx: watch::Receiver<bool>,
+    handles: AHashMap<ObjectId, ListenerHandle>,
+}
+
+impl ActiveListeners {
+    pub async fn update(&self, mut listeners: Listeners, bp: &mut Bootstrap, inner: &Arc<Inner>) {
+        let mut active = self.0.lock().await;
+        let Some(active) = active.as_mut() else {
+            // Listeners were not spawned by the manager
+            return;
+        };
+
+        let mut handles = AHashMap::with_capacity(listeners.servers.len());
+        let mut stopped = Vec::new();
+        let mut spawn = Vec::new();
+        for server in listeners.servers {
+            let acceptor = listeners
+                .tcp_acceptors
+                .remove(&server.id)
+                .unwrap_or(TcpAcceptor::Plain);
+
+            let id = server.registry_id;
+            match active.handles.remove(&id) {
+                Some(handle) if handle.binding == server.binding => {
+                    handle.update(server, acceptor);
+                    handles.insert(id, handle);
+                }
+                Some(handle) => {
+                    stopped.push(handle);
+                    spawn.push((server, acceptor));
+                }
+                None => {
+                    spawn.push((server, acceptor));
+                }
+            }
+        }
+
+        // Stop accepting connections on removed or rebound listeners
+        stopped.extend(std::mem::replace(&mut active.handles, handles).into_values());
+        for handle in stopped {
+            handle.stop().await;
+        }
+
+        // Bind and spawn new listeners
+        for (mut server, acceptor) in spawn {
+            server.listeners.retain(|listener| {
+                if let Err(err) = listener.socket.bind(listener.addr) {
+                    bp.build_error(
+                        server.registry_id,
+                        format!("Failed to bind to {}: {}", listener.addr, err),
+                    );
+                    false
+                } else {
+                    true
+                }
+            });
+
+            if !server.listeners.is_empty() {
+                let id = server.registry_id;
+                let handle =
+                    (active.spawner)(server, inner.clone(), acceptor, active.shutdown_rx.clone());
+                active.handles.insert(id, handle);
+            }
+        }
+    }
+}
+
+impl ListenerHandle {
+    // Sessions opened before the update keep their original settings
+    fn update(&self, server: Listener, acceptor: TcpAcceptor) {
+        let current = self.instance.load();
+        self.instance.store(Arc::new(ServerInstance {
+            id: server.id,
+            protocol: server.protocol,
+            proxy_networks: server.proxy_networks,
+            limiter: if current.limiter.max_concurrent() == server.max_connections {
+                current.limiter.clone()
+            } else {
+                ConcurrencyLimiter::new(server.max_connections)
+            },
+            bandwidth: server.bandwidth,
+            acceptor,
+            shutdown_rx: current.shutdown_rx.clone(),
+            span_id_gen: current.span_id_gen.clone(),
+        }));
+    }
+
+    async fn stop(self) {
+        let _ = self.stop_tx.send(true);
+        for task in self.tasks {
+            let _ = task.await;
+        }
+    }
 }
 
 impl TcpListener {
@@ -343,6 +519,22 @@ impl TcpListener {
 }
 
 impl ServerInstance {
+    pub fn is_implicit_tls(&self) -> bool {
+        matches!(self.acceptor, TcpAcceptor::Tls { implicit, .. } if implicit)
+    }
+
+    // Certificates are only trusted on listeners validating them against
+    // their configured certificate authorities.
+    pub fn tls_client_certificate(&self, stream: &impl SessionStream) -> Option<ClientCertificate> {
+        if self.acceptor.is_tls_client_auth() {
+            stream
+                .tls_peer_certificate()
+                .and_then(ClientCertificate::parse)
+        } else {
+            None
+        }
+    }
+
     pub async fn tls_accept<T: SessionStream>(
         &self,
         stream: T,
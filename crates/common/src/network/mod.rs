@@ -4,9 +4,13 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use self::limiter::{ConcurrencyLimiter, InFlight};
+use self::{
+    limiter::{BandwidthLimiter, ConcurrencyLimiter, InFlight},
+    sessions::ActiveSessions,
+};
 use crate::{
     Server,
+    auth::MailingListCache,
     config::server::ServerProtocol,
     expr::{functions::ResolveVariable, *},
 };
@@ -32,6 +36,7 @@ pub mod limiter;
 pub mod listen;
 pub mod mta;
 pub mod security;
+pub mod sessions;
 pub mod stream;
 pub mod tls;
 
@@ -39,6 +44,8 @@ pub mod tls;
 pub enum RcptResolution {
     Accept,
     Expand(Arc<[Box<str>]>),
+    List(Arc<MailingListCache>),
+    ListBounce(Arc<MailingListCache>),
     Rewrite(String),
     #[default]
     UnknownRecipient,
@@ -50,6 +57,7 @@ pub struct ServerInstance {
     pub protocol: ServerProtocol,
     pub acceptor: TcpAcceptor,
     pub limiter: ConcurrencyLimiter,
+    pub bandwidth: Option<BandwidthLimiter>,
     pub proxy_networks: Vec<IpAddrOrMask>,
     pub shutdown_rx: watch::Receiver<bool>,
     pub span_id_gen: Arc<SnowflakeIdGenerator>,
@@ -61,6 +69,7 @@ pub enum TcpAcceptor {
         config: Arc<ServerConfig>,
         acceptor: TlsAcceptor,
         implicit: bool,
+        client_auth: bool,
     },
     #[default]
     Plain,
@@ -91,6 +100,7 @@ pub struct SessionData<T: SessionStream> {
 pub trait SessionStream: AsyncRead + AsyncWrite + Unpin + 'static + Sync + Send {
     fn is_tls(&self) -> bool;
     fn tls_version_and_cipher(&self) -> (Cow<'static, str>, Cow<'static, str>);
+    fn tls_peer_certificate(&self) -> Option<&[u8]>;
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -106,6 +116,7 @@ pub trait SessionManager: Sync + Send + 'static + Clone {
         mut session: SessionData<T>,
         is_tls: bool,
         acme_core: Option<Server>,
+        sessions: ActiveSessions,
         span_start: EventType,
         span_end: EventType,
     ) {
@@ -143,17 +154,20 @@ pub trait SessionManager: Sync + Send + 'static + Clone {
                             .send_with_metrics();
 
                             manager
-                                .handle(SessionData {
-                                    stream,
-                                    local_ip: session.local_ip,
-                                    local_port: session.local_port,
-                                    remote_ip: session.remote_ip,
-                                    remote_port: session.remote_port,
-                                    protocol: session.protocol,
-                                    session_id: session.session_id,
-                                    in_flight: session.in_flight,
-                                    instance: session.instance,
-                                })
+                                .handle_tracked(
+                                    SessionData {
+                                        stream,
+                                        local_ip: session.local_ip,
+                                        local_port: session.local_port,
+                                        remote_ip: session.remote_ip,
+                                        remote_port: session.remote_port,
+                                        protocol: session.protocol,
+                                        session_id: session.session_id,
+                                        in_flight: session.in_flight,
+                                        instance: session.instance,
+                                    },
+                                    &sessions,
+                                )
                                 .await;
                         }
                         Err(err) => {
@@ -188,7 +202,7 @@ pub trait SessionManager: Sync + Send + 'static + Clone {
                         .send_with_metrics();
 
                         session.stream = stream;
-                        manager.handle(session).await;
+                        manager.handle_tracked(session, &sessions).await;
                     }
                     TcpAcceptorResult::Close => return,
                 }
@@ -210,7 +224,7 @@ pub trait SessionManager: Sync + Send + 'static + Clone {
                 )
                 .send_with_metrics();
 
-                manager.handle(session).await;
+                manager.handle_tracked(session, &sessions).await;
             }
 
             // End span
@@ -225,6 +239,34 @@ pub trait SessionManager: Sync + Send + 'static + Clone {
         });
     }
 
+    // Registers the session as active for its lifetime, administrators
+    // may close it at any point.
+    fn handle_tracked<T: SessionStream>(
+        self,
+        session: SessionData<T>,
+        sessions: &ActiveSessions,
+    ) -> impl std::future::Future<Output = ()> + Send {
+        let guard = sessions.register(&session);
+        let session = guard.track(session);
+
+        async move {
+            tokio::select! {
+                _ = self.handle(session) => {}
+                _ = guard.terminated() => {
+                    let session = guard.session();
+                    trc::event!(
+                        Network(trc::NetworkEvent::SessionTerminated),
+                        SpanId = session.session_id,
+                        ListenerId = session.listener_id.clone(),
+                        RemoteIp = session.remote_ip,
+                        RemotePort = session.remote_port,
+                        AccountId = session.account_id(),
+                    );
+                }
+            }
+        }
+    }
+
     fn handle<T: SessionStream>(
         self,
         session: SessionData<T>,
@@ -290,3 +332,84 @@ pub fn ip_to_bytes_prefix(prefix: u8, ip: &IpAddr) -> Vec<u8> {
         }
     }
 }
+
+// IPv6 clients are usually assigned a whole prefix rather than a single
+// address, so limits and bans apply to the network the address belongs to.
+// IPv4-mapped addresses are folded back to IPv4.
+pub fn ip_to_network_bytes(ip: &IpAddr, ipv6_prefix: u8) -> Vec<u8> {
+    match ip.to_canonical() {
+        IpAddr::V4(ip) => ip.octets().to_vec(),
+        IpAddr::V6(ip) => (u128::from(ip) & ipv6_mask(ipv6_prefix))
+            .to_be_bytes()
+            .to_vec(),
+    }
+}
+
+pub fn ip_to_network(ip: IpAddr, ipv6_prefix: u8) -> IpAddrOrMask {
+    match ip.to_canonical() {
+        IpAddr::V6(addr) if ipv6_prefix < 128 => {
+            let mask = ipv6_mask(ipv6_prefix);
+            IpAddrOrMask::V6 {
+                addr: (u128::from(addr) & mask).into(),
+                mask,
+            }
+        }
+        ip => IpAddrOrMask::from_ip(ip),
+    }
+}
+
+fn ipv6_mask(prefix: u8) -> u128 {
+    u128::MAX
+        .checked_shl(128 - prefix.min(128) as u32)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ip_to_network, ip_to_network_bytes};
+    use registry::types::ipmask::IpAddrOrMask;
+    use std::net::IpAddr;
+
+    #[test]
+    fn ipv6_prefix_grouping() {
+        let ip = |ip: &str| ip.parse::<IpAddr>().unwrap();
+
+        // Addresses within the same /64 share a key
+        assert_eq!(
+            ip_to_network_bytes(&ip("2001:db8::1"), 64),
+            ip_to_network_bytes(&ip("2001:db8::ffff:1234"), 64)
+        );
+        assert_ne!(
+            ip_to_network_bytes(&ip("2001:db8::1"), 64),
+            ip_to_network_bytes(&ip("2001:db8:0:1::1"), 64)
+        );
+        assert_ne!(
+            ip_to_network_bytes(&ip("2001:db8::1"), 128),
+            ip_to_network_bytes(&ip("2001:db8::2"), 128)
+        );
+
+        // IPv4 and IPv4-mapped addresses are never grouped
+        assert_eq!(
+            ip_to_network_bytes(&ip("192.0.2.1"), 64),
+            vec![192, 0, 2, 1]
+        );
+        assert_eq!(
+            ip_to_network_bytes(&ip("::ffff:192.0.2.1"), 64),
+            vec![192, 0, 2, 1]
+        );
+
+        // Bans cover the whole prefix
+        let network = ip_to_network(ip("2001:db8::1"), 64);
+        assert_eq!(network, "2001:db8::/64".parse::<IpAddrOrMask>().unwrap());
+        assert!(network.matches(&ip("2001:db8::abcd")));
+        assert!(!network.matches(&ip("2001:db8:0:1::1")));
+        assert_eq!(
+            ip_to_network(ip("2001:db8::1"), 128).try_to_ip(),
+            Some(ip("2001:db8::1"))
+        );
+        assert_eq!(
+            ip_to_network(ip("::ffff:192.0.2.1"), 64).try_to_ip(),
+            Some(ip("192.0.2.1"))
+        );
+    }
+}
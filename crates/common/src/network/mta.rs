@@ -133,14 +133,17 @@ impl Server {
         {
             match address_type {
                 EmailCache::Account(id) if directory.is_none() => {
-                    if self.try_account(id).await?.is_some() {
-                        return if local_part.as_ref() == local_part_orig {
-                            Ok(RcptResolution::Accept)
-                        } else {
-                            Ok(RcptResolution::Rewrite(format!(
-                                "{local_part}@{domain_part}"
-                            )))
-                        };
+                    if let Some(account) = self.try_account(id).await? {
+                        // Expired aliases are handled as unknown recipients
+                        if account.has_address(local_part.as_ref(), domain.id) {
+                            return if local_part.as_ref() == local_part_orig {
+                                Ok(RcptResolution::Accept)
+                            } else {
+                                Ok(RcptResolution::Rewrite(format!(
+                                    "{local_part}@{domain_part}"
+                                )))
+                            };
+                        }
                     } else {
                         self.inner
                             .cache
@@ -150,7 +153,11 @@ impl Server {
                 }
                 EmailCache::MailingList(id) => {
                     if let Some(list) = self.try_list(id).await? {
-                        return Ok(RcptResolution::Expand(list.recipients.clone()));
+                        return Ok(if list.policy.is_some() {
+                            RcptResolution::List(list)
+                        } else {
+                            RcptResolution::Expand(list.recipients.clone())
+                        });
                     } else {
                         self.inner
                             .cache
@@ -162,6 +169,16 @@ impl Server {
             }
         }
 
+        // Bounces for list posts are returned to <list>-bounces@domain
+        if let Some(list_local_part) = local_part.strip_suffix("-bounces")
+            && let Some(EmailCache::MailingList(id)) =
+                self.rcpt_id_from_parts(list_local_part, domain.id).await?
+            && let Some(list) = self.try_list(id).await?
+            && list.policy.is_some()
+        {
+            return Ok(RcptResolution::ListBounce(list));
+        }
+
         // Catch-all resolution
         if let Some(catch_all) = &domain.catch_all {
             return Ok(RcptResolution::Rewrite(catch_all.to_string()));
@@ -217,12 +234,48 @@ impl Server {
         })
     }
 
+    // Library scripts scoped to a domain can only be included by accounts
+    // holding an address in that domain
+    pub async fn get_untrusted_sieve_script_for_account(
+        &self,
+        name: &str,
+        account_id: u32,
+        session_id: u64,
+    ) -> trc::Result<Option<Arc<Sieve>>> {
+        let Some(script) = self.get_untrusted_sieve_script(name, session_id) else {
+            return Ok(None);
+        };
+
+        if let Some(domain_id) = self.core.sieve.untrusted_script_domains.get(name)
+            && !self
+                .account(account_id)
+                .await
+                .caused_by(trc::location!())?
+                .addresses
+                .iter()
+                .any(|address| address.domain_id == *domain_id)
+        {
+            trc::event!(
+                Sieve(trc::SieveEvent::ScriptNotFound),
+                Id = name.to_string(),
+                AccountId = account_id,
+                Details = "Script is not available to this account's domain",
+                SpanId = session_id,
+            );
+
+            return Ok(None);
+        }
+
+        Ok(Some(script.clone()))
+    }
+
     pub fn get_route_or_default(&self, name: &str, session_id: u64) -> &RoutingStrategy {
         static LOCAL_GATEWAY: RoutingStrategy = RoutingStrategy::Local;
         static MX_GATEWAY: RoutingStrategy = RoutingStrategy::Mx(MxConfig {
             max_mx: 5,
             max_multi_homed: 2,
             ip_lookup_strategy: IpLookupStrategy::Ipv4thenIpv6,
+            proxy: None,
         });
         self.core
             .smtp
@@ -245,7 +298,11 @@ impl Server {
     }
 
     pub fn get_virtual_queue_or_default(&self, name: &QueueName) -> &VirtualQueue {
-        static DEFAULT_QUEUE: VirtualQueue = VirtualQueue { threads: 25 };
+        static DEFAULT_QUEUE: VirtualQueue = VirtualQueue {
+            threads: 25,
+            priority_threads: 0,
+            low_priority_threads: None,
+        };
         self.core
             .smtp
             .queue
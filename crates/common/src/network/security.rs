@@ -5,9 +5,10 @@
  */
 
 use crate::{
-    KV_RATE_LIMIT_AUTH, KV_RATE_LIMIT_LOITER, KV_RATE_LIMIT_RCPT, KV_RATE_LIMIT_SCAN, Server,
+    KV_AUTH_FAILURES, KV_RATE_LIMIT_AUTH, KV_RATE_LIMIT_LOITER, KV_RATE_LIMIT_RCPT,
+    KV_RATE_LIMIT_SCAN, KV_RATE_LIMIT_SMUGGLING, Server,
     ipc::{BroadcastEvent, RegistryChange},
-    network::ip_to_bytes,
+    network::{ip_to_network, ip_to_network_bytes},
 };
 use ahash::AHashSet;
 use registry::{
@@ -18,8 +19,9 @@ use registry::{
     },
     types::{datetime::UTCDateTime, ipmask::IpAddrOrMask},
 };
-use std::{fmt::Debug, hash::Hash, net::IpAddr};
+use std::{fmt::Debug, hash::Hash, net::IpAddr, time::Duration};
 use store::{
+    dispatch::lookup::KeyValue,
     registry::{
         bootstrap::Bootstrap,
         write::{RegistryWrite, RegistryWriteResult},
@@ -42,8 +44,13 @@ pub struct Security {
     pub scanner_fail_rate: Option<Rate>,
 
     pub auth_fail_rate: Option<Rate>,
+    pub auth_delay: Option<Duration>,
+    pub auth_delay_max: Duration,
+    pub auth_delay_reset: u64,
     pub rcpt_fail_rate: Option<Rate>,
     pub loiter_fail_rate: Option<Rate>,
+    pub smuggling_fail_rate: Option<Rate>,
+    pub ipv6_prefix: u8,
 
     pub default_role_ids_user: Vec<Id>,
     pub default_role_ids_group: Vec<Id>,
@@ -166,8 +173,13 @@ impl Security {
             allowed_ip_networks,
             blocked_ip_expiration: security.auth_ban_period.map(|v| v.as_secs()),
             auth_fail_rate: security.auth_ban_rate,
+            auth_delay: security.auth_delay.map(|v| v.into_inner()),
+            auth_delay_max: security.auth_delay_max.into_inner(),
+            auth_delay_reset: security.auth_delay_reset.as_secs(),
             rcpt_fail_rate: security.abuse_ban_rate,
             loiter_fail_rate: security.loiter_ban_rate,
+            smuggling_fail_rate: security.smuggling_ban_rate,
+            ipv6_prefix: security.ipv6_prefix_length.clamp(32, 128) as u8,
             http_banned_paths: security
                 .scan_ban_paths
                 .iter()
@@ -199,7 +211,7 @@ impl Server {
             let is_allowed = self.is_ip_allowed(ip)
                 || (self
                     .in_memory_store()
-                    .is_rate_allowed(KV_RATE_LIMIT_RCPT, &ip_to_bytes(&ip), rate, false)
+                    .is_rate_allowed(KV_RATE_LIMIT_RCPT, &self.ip_rate_key(&ip), rate, false)
                     .await?
                     .is_none()
                     && self
@@ -224,7 +236,7 @@ impl Server {
             let is_allowed = self.is_ip_allowed(ip)
                 || self
                     .in_memory_store()
-                    .is_rate_allowed(KV_RATE_LIMIT_SCAN, &ip_to_bytes(&ip), rate, false)
+                    .is_rate_allowed(KV_RATE_LIMIT_SCAN, &self.ip_rate_key(&ip), rate, false)
                     .await?
                     .is_none();
 
@@ -256,7 +268,7 @@ impl Server {
             let is_allowed = self.is_ip_allowed(ip)
                 || self
                     .in_memory_store()
-                    .is_rate_allowed(KV_RATE_LIMIT_LOITER, &ip_to_bytes(&ip), rate, false)
+                    .is_rate_allowed(KV_RATE_LIMIT_LOITER, &self.ip_rate_key(&ip), rate, false)
                     .await?
                     .is_none();
 
@@ -271,13 +283,33 @@ impl Server {
         Ok(false)
     }
 
+    pub async fn is_smuggling_fail2banned(&self, ip: IpAddr) -> trc::Result<bool> {
+        if let Some(rate) = &self.core.network.security.smuggling_fail_rate {
+            let is_allowed = self.is_ip_allowed(ip)
+                || self
+                    .in_memory_store()
+                    .is_rate_allowed(KV_RATE_LIMIT_SMUGGLING, &self.ip_rate_key(&ip), rate, false)
+                    .await?
+                    .is_none();
+
+            if !is_allowed {
+                return self
+                    .block_ip(ip, BlockReason::SmtpSmuggling)
+                    .await
+                    .map(|_| true);
+            }
+        }
+
+        Ok(false)
+    }
+
     pub async fn is_auth_fail2banned(&self, ip: IpAddr, login: Option<&str>) -> trc::Result<bool> {
         if let Some(rate) = &self.core.network.security.auth_fail_rate {
             let login = login.unwrap_or_default();
             let is_allowed = self.is_ip_allowed(ip)
                 || (self
                     .in_memory_store()
-                    .is_rate_allowed(KV_RATE_LIMIT_AUTH, &ip_to_bytes(&ip), rate, false)
+                    .is_rate_allowed(KV_RATE_LIMIT_AUTH, &self.ip_rate_key(&ip), rate, false)
                     .await?
                     .is_none()
                     && (login.is_empty()
@@ -306,19 +338,27 @@ impl Server {
             .security
             .blocked_ip_expiration
             .map(|v| now + v);
-        self.inner
-            .data
-            .blocked_ips
-            .write()
-            .blocked_ip_addresses
-            .insert(IpWithTtl::new(ip, expires_at.unwrap_or(u64::MAX)));
+        let address = ip_to_network(ip, self.core.network.security.ipv6_prefix);
+        {
+            let mut blocked_ips = self.inner.data.blocked_ips.write();
+            if let Some(ip) = address.try_to_ip() {
+                blocked_ips
+                    .blocked_ip_addresses
+                    .insert(IpWithTtl::new(ip, expires_at.unwrap_or(u64::MAX)));
+            } else {
+                let network = IpWithTtl::new(address.clone(), expires_at.unwrap_or(u64::MAX));
+                blocked_ips.blocked_ip_networks.retain(|n| n != &network);
+                blocked_ips.blocked_ip_networks.push(network);
+                blocked_ips.has_blocked_networks = true;
+            }
+        }
 
         // Write blocked IP to config
         let RegistryWriteResult::Success(id) = self
             .registry()
             .write(RegistryWrite::insert(
                 &BlockedIp {
-                    address: IpAddrOrMask::from_ip(ip),
+                    address,
                     created_at: UTCDateTime::from_timestamp(now as i64),
                     expires_at: expires_at.map(|ts| UTCDateTime::from_timestamp(ts as i64)),
                     reason,
@@ -340,11 +380,64 @@ impl Server {
         Ok(())
     }
 
+    // Failed logins are counted per account regardless of the source address,
+    // each further attempt is delayed twice as long as the previous one.
+    pub async fn auth_delay(&self, login: &str) -> trc::Result<Option<(u32, Duration)>> {
+        if let Some(base) = self.core.network.security.auth_delay
+            && !login.is_empty()
+        {
+            let failures = self
+                .in_memory_store()
+                .counter_get(KeyValue::<()>::build_key(
+                    KV_AUTH_FAILURES,
+                    login.to_lowercase(),
+                ))
+                .await?;
+            if failures > 0 {
+                let delay = base
+                    .saturating_mul(1u32 << (failures - 1).clamp(0, 16))
+                    .min(self.core.network.security.auth_delay_max);
+                return Ok(Some((failures as u32, delay)));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn record_auth_delay_failure(&self, login: &str) -> trc::Result<()> {
+        if self.core.network.security.auth_delay.is_some() && !login.is_empty() {
+            self.in_memory_store()
+                .counter_incr(
+                    KeyValue::with_prefix(KV_AUTH_FAILURES, login.to_lowercase(), 1)
+                        .expires(self.core.network.security.auth_delay_reset),
+                    false,
+                )
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn reset_auth_delay(&self, login: &str) -> trc::Result<()> {
+        self.in_memory_store()
+            .counter_delete(KeyValue::<()>::build_key(
+                KV_AUTH_FAILURES,
+                login.to_lowercase(),
+            ))
+            .await
+    }
+
+    // Key used to rate limit a remote address, IPv6 clients are grouped by prefix
+    pub fn ip_rate_key(&self, ip: &IpAddr) -> Vec<u8> {
+        ip_to_network_bytes(ip, self.core.network.security.ipv6_prefix)
+    }
+
     pub fn has_auth_fail2ban(&self) -> bool {
         self.core.network.security.auth_fail_rate.is_some()
     }
 
     pub fn is_ip_blocked(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
         let blocked_ips = self.inner.data.blocked_ips.read();
         (blocked_ips
             .blocked_ip_addresses
@@ -359,6 +452,7 @@ impl Server {
     }
 
     pub fn is_ip_allowed(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
         self.core
             .network
             .security
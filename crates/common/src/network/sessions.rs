@@ -0,0 +1,297 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{SessionData, SessionStream};
+use crate::config::server::ServerProtocol;
+use ahash::AHashMap;
+use parking_lot::Mutex;
+use std::{
+    borrow::Cow,
+    net::IpAddr,
+    pin::Pin,
+    sync::{
+        Arc,
+        atomic::{AtomicU32, AtomicU64, Ordering},
+    },
+    task::{Context, Poll},
+    time::{Instant, SystemTime, UNIX_EPOCH},
+};
+use tokio::{
+    io::{AsyncRead, AsyncWrite, ReadBuf},
+    sync::Notify,
+};
+
+// Sessions currently open on this node, keyed by session id
+#[derive(Clone, Default)]
+pub struct ActiveSessions {
+    sessions: Arc<Mutex<AHashMap<u64, Arc<ActiveSession>>>>,
+}
+
+pub struct ActiveSession {
+    pub session_id: u64,
+    pub listener_id: String,
+    pub protocol: ServerProtocol,
+    pub remote_ip: IpAddr,
+    pub remote_port: u16,
+    pub created_at: u64,
+    started: Instant,
+    account_id: AtomicU32,
+    last_activity: AtomicU64,
+    bytes_received: AtomicU64,
+    bytes_sent: AtomicU64,
+    terminate: Notify,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActiveSessionInfo {
+    pub id: u64,
+    pub listener_id: String,
+    pub protocol: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<u32>,
+    pub remote_ip: IpAddr,
+    pub remote_port: u16,
+    pub created_at: u64,
+    pub idle_secs: u64,
+    pub bytes_received: u64,
+    pub bytes_sent: u64,
+}
+
+// Removes the session from the registry once its task ends
+pub struct ActiveSessionGuard {
+    sessions: ActiveSessions,
+    session: Arc<ActiveSession>,
+}
+
+// Counts the bytes exchanged over a session and records its last activity
+pub struct TrackedStream<T: SessionStream> {
+    stream: T,
+    session: Arc<ActiveSession>,
+}
+
+impl ActiveSessions {
+    pub fn register<T: SessionStream>(&self, session: &SessionData<T>) -> ActiveSessionGuard {
+        let session = Arc::new(ActiveSession {
+            session_id: session.session_id,
+            listener_id: session.instance.id.clone(),
+            protocol: session.protocol,
+            remote_ip: session.remote_ip,
+            remote_port: session.remote_port,
+            created_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |d| d.as_secs()),
+            started: Instant::now(),
+            account_id: AtomicU32::new(u32::MAX),
+            last_activity: AtomicU64::new(0),
+            bytes_received: AtomicU64::new(0),
+            bytes_sent: AtomicU64::new(0),
+            terminate: Notify::new(),
+        });
+        self.sessions
+            .lock()
+            .insert(session.session_id, session.clone());
+
+        ActiveSessionGuard {
+            sessions: self.clone(),
+            session,
+        }
+    }
+
+    pub fn set_account(&self, session_id: u64, account_id: u32) {
+        if let Some(session) = self.sessions.lock().get(&session_id) {
+            session.account_id.store(account_id, Ordering::Relaxed);
+        }
+    }
+
+    pub fn list(&self) -> Vec<ActiveSessionInfo> {
+        let mut sessions = self
+            .sessions
+            .lock()
+            .values()
+            .map(|session| session.info())
+            .collect::<Vec<_>>();
+        sessions.sort_unstable_by_key(|session| session.id);
+        sessions
+    }
+
+    pub fn get(&self, session_id: u64) -> Option<ActiveSessionInfo> {
+        self.sessions
+            .lock()
+            .get(&session_id)
+            .map(|session| session.info())
+    }
+
+    // Returns the ids of the sessions that were found and signalled to close
+    pub fn terminate(&self, session_ids: impl IntoIterator<Item = u64>) -> Vec<u64> {
+        let sessions = self.sessions.lock();
+        session_ids
+            .into_iter()
+            .filter(|session_id| {
+                if let Some(session) = sessions.get(session_id) {
+                    session.terminate.notify_one();
+                    true
+                } else {
+                    false
+                }
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.sessions.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.lock().is_empty()
+    }
+}
+
+impl ActiveSession {
+    pub fn account_id(&self) -> Option<u32> {
+        let account_id = self.account_id.load(Ordering::Relaxed);
+        (account_id != u32::MAX).then_some(account_id)
+    }
+
+    pub fn idle_secs(&self) -> u64 {
+        (self.started.elapsed().as_millis() as u64)
+            .saturating_sub(self.last_activity.load(Ordering::Relaxed))
+            / 1000
+    }
+
+    pub fn info(&self) -> ActiveSessionInfo {
+        ActiveSessionInfo {
+            id: self.session_id,
+            listener_id: self.listener_id.clone(),
+            protocol: self.protocol.as_str(),
+            account_id: self.account_id(),
+            remote_ip: self.remote_ip,
+            remote_port: self.remote_port,
+            created_at: self.created_at,
+            idle_secs: self.idle_secs(),
+            bytes_received: self.bytes_received.load(Ordering::Relaxed),
+            bytes_sent: self.bytes_sent.load(Ordering::Relaxed),
+        }
+    }
+
+    fn record_activity(&self, counter: &AtomicU64, bytes: usize) {
+        if bytes > 0 {
+            counter.fetch_add(bytes as u64, Ordering::Relaxed);
+            self.last_activity
+                .store(self.started.elapsed().as_millis() as u64, Ordering::Relaxed);
+        }
+    }
+}
+
+impl ActiveSessionGuard {
+    pub fn session(&self) -> &Arc<ActiveSession> {
+        &self.session
+    }
+
+    // Resolves once an administrator requests the session to be closed
+    pub async fn terminated(&self) {
+        self.session.terminate.notified().await
+    }
+
+    pub fn track<T: SessionStream>(
+        &self,
+        session: SessionData<T>,
+    ) -> SessionData<TrackedStream<T>> {
+        SessionData {
+            stream: TrackedStream {
+                stream: session.stream,
+                session: self.session.clone(),
+            },
+            local_ip: session.local_ip,
+            local_port: session.local_port,
+            remote_ip: session.remote_ip,
+            remote_port: session.remote_port,
+            protocol: session.protocol,
+            session_id: session.session_id,
+            in_flight: session.in_flight,
+            instance: session.instance,
+        }
+    }
+}
+
+impl Drop for ActiveSessionGuard {
+    fn drop(&mut self) {
+        self.sessions
+            .sessions
+            .lock()
+            .remove(&self.session.session_id);
+    }
+}
+
+impl<T: SessionStream> AsyncRead for TrackedStream<T> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<std::io::Result<()>> {
+        let filled = buf.filled().len();
+        let result = Pin::new(&mut self.stream).poll_read(cx, buf);
+        if let Poll::Ready(Ok(())) = &result {
+            self.session
+                .record_activity(&self.session.bytes_received, buf.filled().len() - filled);
+        }
+        result
+    }
+}
+
+impl<T: SessionStream> AsyncWrite for TrackedStream<T> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<Result<usize, std::io::Error>> {
+        let result = Pin::new(&mut self.stream).poll_write(cx, buf);
+        if let Poll::Ready(Ok(bytes)) = &result {
+            self.session
+                .record_activity(&self.session.bytes_sent, *bytes);
+        }
+        result
+    }
+
+    fn poll_write_vectored(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[std::io::IoSlice<'_>],
+    ) -> Poll<Result<usize, std::io::Error>> {
+        let result = Pin::new(&mut self.stream).poll_write_vectored(cx, bufs);
+        if let Poll::Ready(Ok(bytes)) = &result {
+            self.session
+                .record_activity(&self.session.bytes_sent, *bytes);
+        }
+        result
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        self.stream.is_write_vectored()
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.stream).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Pin::new(&mut self.stream).poll_shutdown(cx)
+    }
+}
+
+impl<T: SessionStream> SessionStream for TrackedStream<T> {
+    fn is_tls(&self) -> bool {
+        self.stream.is_tls()
+    }
+
+    fn tls_version_and_cipher(&self) -> (Cow<'static, str>, Cow<'static, str>) {
+        self.stream.tls_version_and_cipher()
+    }
+
+    fn tls_peer_certificate(&self) -> Option<&[u8]> {
+        self.stream.tls_peer_certificate()
+    }
+}
@@ -23,6 +23,10 @@ impl SessionStream for TcpStream {
     fn tls_version_and_cipher(&self) -> (Cow<'static, str>, Cow<'static, str>) {
         (Cow::Borrowed(""), Cow::Borrowed(""))
     }
+
+    fn tls_peer_certificate(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 impl<T: SessionStream> SessionStream for TlsStream<T> {
@@ -62,6 +66,15 @@ impl<T: SessionStream> SessionStream for TlsStream<T> {
             .into(),
         )
     }
+    // The leaf certificate is only present when it was validated against
+    // the trusted authorities of the listener.
+    fn tls_peer_certificate(&self) -> Option<&[u8]> {
+        self.get_ref()
+            .1
+            .peer_certificates()
+            .and_then(|certs| certs.first())
+            .map(|cert| cert.as_ref())
+    }
 }
 
 impl SessionStream for ProxiedStream<TcpStream> {
@@ -82,6 +95,10 @@ impl SessionStream for ProxiedStream<TcpStream> {
             })
             .unwrap_or((Cow::Borrowed("unknown"), Cow::Borrowed("unknown")))
     }
+
+    fn tls_peer_certificate(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 #[derive(Default)]
@@ -140,4 +157,8 @@ impl SessionStream for NullIo {
             std::borrow::Cow::Borrowed(""),
         )
     }
+
+    fn tls_peer_certificate(&self) -> Option<&[u8]> {
+        None
+    }
 }
@@ -117,6 +117,7 @@ impl TcpAcceptor {
                 config,
                 acceptor,
                 implicit,
+                ..
             } if *implicit => match enable_acme {
                 None => TcpAcceptorResult::Tls(acceptor.accept(stream)),
                 Some(core) => {
@@ -193,6 +194,10 @@ impl TcpAcceptor {
     pub fn is_tls(&self) -> bool {
         matches!(self, TcpAcceptor::Tls { .. })
     }
+
+    pub fn is_tls_client_auth(&self) -> bool {
+        matches!(self, TcpAcceptor::Tls { client_auth, .. } if *client_auth)
+    }
 }
 
 impl<IO> TcpAcceptorResult<IO>
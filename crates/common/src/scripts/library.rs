@@ -0,0 +1,278 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use ahash::{AHashMap, AHashSet};
+
+#[derive(Debug, PartialEq, Eq)]
+enum Token<'x> {
+    Identifier(&'x str),
+    Tag(&'x str),
+    String(String),
+    Special(u8),
+}
+
+// Returns the names of the scripts included with ":global" by a script,
+// names built from variables can only be resolved at runtime and are skipped.
+pub fn global_includes(script: &str) -> Vec<String> {
+    let tokens = tokenize(script.as_bytes());
+    let mut includes = Vec::new();
+    let mut tokens = tokens.iter();
+    let mut is_command = true;
+
+    while let Some(token) = tokens.next() {
+        match token {
+            Token::Identifier(name) if is_command && name.eq_ignore_ascii_case("include") => {
+                let mut is_global = false;
+                let mut names = Vec::new();
+                for token in tokens.by_ref() {
+                    match token {
+                        Token::Tag(tag) => {
+                            is_global |= tag.eq_ignore_ascii_case("global");
+                        }
+                        Token::String(name) => {
+                            names.push(name);
+                        }
+                        Token::Special(b';' | b'{' | b'}') => break,
+                        _ => (),
+                    }
+                }
+
+                if is_global {
+                    for name in names {
+                        if !name.contains("${") && !includes.contains(&name.to_lowercase()) {
+                            includes.push(name.to_lowercase());
+                        }
+                    }
+                }
+                is_command = true;
+            }
+            Token::Special(b';' | b'{' | b'}') => {
+                is_command = true;
+            }
+            _ => {
+                is_command = false;
+            }
+        }
+    }
+
+    includes
+}
+
+// Returns the names of the scripts that lead into an include cycle or whose
+// include chain exceeds the maximum nesting depth.
+pub fn invalid_includes(
+    scripts: &AHashMap<String, Vec<String>>,
+    max_depth: usize,
+) -> AHashSet<String> {
+    let mut invalid = AHashSet::new();
+    let mut depths = AHashMap::with_capacity(scripts.len());
+
+    for name in scripts.keys() {
+        let mut path = Vec::new();
+        let mut script_depths = depths.clone();
+        match include_depth(scripts, name, &mut path, &mut script_depths) {
+            Some(depth) if depth < max_depth => {
+                depths = script_depths;
+            }
+            _ => {
+                invalid.insert(name.clone());
+            }
+        }
+    }
+
+    invalid
+}
+
+fn include_depth<'x>(
+    scripts: &'x AHashMap<String, Vec<String>>,
+    name: &'x str,
+    path: &mut Vec<&'x str>,
+    depths: &mut AHashMap<&'x str, usize>,
+) -> Option<usize> {
+    if let Some(depth) = depths.get(name) {
+        return Some(*depth);
+    } else if path.contains(&name) {
+        return None;
+    }
+
+    let mut depth = 0;
+    if let Some(includes) = scripts.get(name) {
+        path.push(name);
+        for include in includes {
+            if scripts.contains_key(include) {
+                depth = depth.max(include_depth(scripts, include, path, depths)? + 1);
+            }
+        }
+        path.pop();
+    }
+    depths.insert(name, depth);
+
+    Some(depth)
+}
+
+fn tokenize(script: &[u8]) -> Vec<Token<'_>> {
+    let mut tokens = Vec::new();
+    let mut pos = 0;
+
+    while let Some(&ch) = script.get(pos) {
+        match ch {
+            b'#' => {
+                while script.get(pos).is_some_and(|&ch| ch != b'\n') {
+                    pos += 1;
+                }
+            }
+            b'/' if script.get(pos + 1) == Some(&b'*') => {
+                pos += 2;
+                while pos < script.len() && !script[pos..].starts_with(b"*/") {
+                    pos += 1;
+                }
+                pos += 2;
+            }
+            b'"' => {
+                let mut value = Vec::new();
+                pos += 1;
+                while let Some(&ch) = script.get(pos) {
+                    pos += 1;
+                    match ch {
+                        b'\\' => {
+                            if let Some(&ch) = script.get(pos) {
+                                value.push(ch);
+                                pos += 1;
+                            }
+                        }
+                        b'"' => break,
+                        _ => value.push(ch),
+                    }
+                }
+                tokens.push(Token::String(String::from_utf8_lossy(&value).into_owned()));
+            }
+            b':' => {
+                let start = pos + 1;
+                pos = start;
+                while script
+                    .get(pos)
+                    .is_some_and(|ch| ch.is_ascii_alphanumeric() || *ch == b'_')
+                {
+                    pos += 1;
+                }
+                tokens.push(Token::Tag(
+                    std::str::from_utf8(&script[start..pos]).unwrap_or_default(),
+                ));
+            }
+            b'a'..=b'z' | b'A'..=b'Z' | b'_' => {
+                let start = pos;
+                while script
+                    .get(pos)
+                    .is_some_and(|ch| ch.is_ascii_alphanumeric() || *ch == b'_')
+                {
+                    pos += 1;
+                }
+                let identifier = std::str::from_utf8(&script[start..pos]).unwrap_or_default();
+
+                if identifier.eq_ignore_ascii_case("text") && script.get(pos) == Some(&b':') {
+                    // Multi-line strings end with a line containing a single dot
+                    while script.get(pos).is_some_and(|&ch| ch != b'\n') {
+                        pos += 1;
+                    }
+                    let start = pos + 1;
+                    let mut end = script.len();
+                    for (line_start, _) in script
+                        .iter()
+                        .enumerate()
+                        .skip(start)
+                        .filter(|(idx, _)| script[idx - 1] == b'\n')
+                    {
+                        let line = &script[line_start..];
+                        if line.starts_with(b".\n") || line.starts_with(b".\r\n") || line == b"." {
+                            end = line_start;
+                            break;
+                        }
+                    }
+                    tokens.push(Token::String(
+                        String::from_utf8_lossy(script.get(start..end).unwrap_or_default())
+                            .into_owned(),
+                    ));
+                    pos = script.len().min(end + 2);
+                    while script.get(pos).is_some_and(|&ch| ch != b'\n') {
+                        pos += 1;
+                    }
+                } else {
+                    tokens.push(Token::Identifier(identifier));
+                }
+            }
+            b';' | b'{' | b'}' | b'[' | b']' | b',' | b'(' | b')' => {
+                tokens.push(Token::Special(ch));
+                pos += 1;
+            }
+            _ => {
+                pos += 1;
+            }
+        }
+    }
+
+    tokens
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{global_includes, invalid_includes};
+    use ahash::AHashMap;
+
+    #[test]
+    fn parse_global_includes() {
+        for (script, expected) in [
+            (
+                "require \"include\";\ninclude :global \"Spam\";\ninclude :personal \"mine\";",
+                vec!["spam"],
+            ),
+            (
+                "require [\"include\"];\n# include :global \"commented\";\nif true {\n include :once :global [\"a\", \"b\"]; }",
+                vec!["a", "b"],
+            ),
+            (
+                "/* include :global \"hidden\"; */ include :optional :global \"shared\";",
+                vec!["shared"],
+            ),
+            (
+                "set \"name\" \"x\"; include :global \"${name}\"; include :global \"static\";",
+                vec!["static"],
+            ),
+            (
+                "vacation text:\ninclude :global \"body\";\n.\n;\ninclude :global \"after\";",
+                vec!["after"],
+            ),
+            ("if header :is \"include\" \"x\" { keep; }", vec![]),
+        ] {
+            assert_eq!(global_includes(script), expected, "{script}");
+        }
+    }
+
+    #[test]
+    fn detect_invalid_includes() {
+        let mut scripts = AHashMap::new();
+        scripts.insert("a".to_string(), vec!["b".to_string()]);
+        scripts.insert(
+            "b".to_string(),
+            vec!["c".to_string(), "missing".to_string()],
+        );
+        scripts.insert("c".to_string(), vec![]);
+        assert!(invalid_includes(&scripts, 3).is_empty());
+        assert_eq!(
+            invalid_includes(&scripts, 2)
+                .into_iter()
+                .collect::<Vec<_>>(),
+            vec!["a"]
+        );
+
+        scripts.insert("c".to_string(), vec!["a".to_string()]);
+        scripts.insert("d".to_string(), vec![]);
+        let mut invalid = invalid_includes(&scripts, 10)
+            .into_iter()
+            .collect::<Vec<_>>();
+        invalid.sort();
+        assert_eq!(invalid, vec!["a", "b", "c"]);
+    }
+}
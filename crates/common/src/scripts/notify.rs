@@ -0,0 +1,108 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{Server, USER_AGENT, manager::is_localhost_url};
+use aws_lc_rs::hmac;
+use base64::{Engine, engine::general_purpose::STANDARD};
+use reqwest::header::{CONTENT_TYPE, HeaderMap, HeaderValue};
+use serde::Serialize;
+use store::write::now;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SieveNotification<'x> {
+    pub method: &'x str,
+    pub from: Option<&'x str>,
+    pub importance: &'x str,
+    pub options: &'x [String],
+    pub message: &'x str,
+    pub account_id: u32,
+    pub envelope_from: &'x str,
+    pub envelope_to: &'x str,
+    pub subject: Option<&'x str>,
+    pub message_id: Option<&'x str>,
+    pub timestamp: u64,
+}
+
+impl<'x> SieveNotification<'x> {
+    pub fn new(method: &'x str, message: &'x str) -> Self {
+        SieveNotification {
+            method,
+            from: None,
+            importance: "normal",
+            options: &[],
+            message,
+            account_id: u32::MAX,
+            envelope_from: "",
+            envelope_to: "",
+            subject: None,
+            message_id: None,
+            timestamp: now(),
+        }
+    }
+}
+
+impl Server {
+    // https: methods are posted to the URI itself, any other allowed method
+    // is delivered through the external notification gateway.
+    pub async fn send_sieve_notification(
+        &self,
+        notification: &SieveNotification<'_>,
+    ) -> Result<(), String> {
+        let settings = &self.core.sieve.untrusted_notify;
+        let url = match notification.method.split_once(':') {
+            Some((scheme, _)) if scheme.eq_ignore_ascii_case("https") => notification.method,
+            Some(_) => settings
+                .gateway_url
+                .as_deref()
+                .ok_or_else(|| "No notification gateway is configured".to_string())?,
+            None => return Err("Invalid notification method".to_string()),
+        };
+
+        // Serialize body
+        let body = serde_json::to_string(notification)
+            .map_err(|err| format!("Failed to serialize notification: {}", err))?;
+
+        // Add HMAC-SHA256 signature
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_static("application/json"));
+        if !settings.key.is_empty() {
+            let key = hmac::Key::new(hmac::HMAC_SHA256, settings.key.as_bytes());
+            let tag = hmac::sign(&key, body.as_bytes());
+
+            headers.insert(
+                "X-Signature",
+                STANDARD.encode(tag.as_ref()).parse().unwrap(),
+            );
+        }
+
+        // Send request
+        let response = reqwest::Client::builder()
+            .timeout(settings.timeout)
+            .danger_accept_invalid_certs(is_localhost_url(url))
+            .user_agent(USER_AGENT)
+            .redirect(reqwest::redirect::Policy::none())
+            .build()
+            .map_err(|err| format!("Failed to create HTTP client: {}", err))?
+            .post(url)
+            .headers(headers)
+            .body(body)
+            .send()
+            .await
+            .map_err(|err| format!("Notification request to {url} failed: {err}"))?;
+
+        if response.status().is_success() {
+            Ok(())
+        } else {
+            Err(format!(
+                "Notification request to {} failed with code {}: {}",
+                url,
+                response.status().as_u16(),
+                response.status().canonical_reason().unwrap_or("Unknown")
+            ))
+        }
+    }
+}
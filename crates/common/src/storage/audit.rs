@@ -0,0 +1,102 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::Server;
+use registry::{
+    schema::{
+        enums::MessageAuditEvent,
+        prelude::{ObjectType, Property},
+        structs::MessageAuditEntry,
+    },
+    types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, index::IndexBuilder},
+};
+use store::write::{BatchBuilder, RegistryClass, ValueClass, now};
+use trc::AddContext;
+
+pub trait MessageAuditIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+impl MessageAuditIndex for MessageAuditEntry {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::MessageAuditEntry.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::AccountId, self.account_id.id());
+        index_builder.search(Property::Event, self.event.to_id() as u64);
+        index_builder.search(Property::Timestamp, self.timestamp.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        if is_set {
+            batch.set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(key);
+        }
+    }
+}
+
+impl Server {
+    pub fn is_audit_log_enabled(&self) -> bool {
+        self.core.email.audit_log_retention.is_some()
+    }
+
+    // Audit entries are append-only, failures are logged but never abort the operation
+    pub async fn audit_messages(
+        &self,
+        account_id: u32,
+        event: MessageAuditEvent,
+        document_ids: impl IntoIterator<Item = u32>,
+        performed_by: Option<u32>,
+        details: Option<String>,
+    ) {
+        let Some(expires_in) = self.core.email.audit_log_retention else {
+            return;
+        };
+        let timestamp = now();
+        let expires_at = UTCDateTime::from_timestamp((timestamp + expires_in.as_secs()) as i64);
+        let timestamp = UTCDateTime::from_timestamp(timestamp as i64);
+        let mut batch = BatchBuilder::new();
+
+        for document_id in document_ids {
+            MessageAuditEntry {
+                account_id: account_id.into(),
+                event,
+                document_id: document_id.into(),
+                performed_by: performed_by
+                    .filter(|performed_by| *performed_by != account_id)
+                    .map(Into::into),
+                details: details.clone(),
+                timestamp,
+                expires_at,
+            }
+            .write_ops(&mut batch, self.inner.data.registry_id_gen.generate(), true);
+
+            if batch.is_large_batch() {
+                self.write_audit_batch(account_id, std::mem::take(&mut batch))
+                    .await;
+            }
+        }
+
+        if !batch.is_empty() {
+            self.write_audit_batch(account_id, batch).await;
+        }
+    }
+
+    async fn write_audit_batch(&self, account_id: u32, mut batch: BatchBuilder) {
+        if let Err(err) = self
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())
+        {
+            trc::error!(
+                err.account_id(account_id)
+                    .details("Failed to write message audit log")
+            );
+        }
+    }
+}
@@ -10,7 +10,7 @@ use mail_parser::{
     decoders::{base64::base64_decode, quoted_printable::quoted_printable_decode},
 };
 use store::{
-    U32_LEN, U64_LEN,
+    BlobStore, SerializeInfallible, U32_LEN, U64_LEN,
     dispatch::lookup::KeyValue,
     write::{BatchBuilder, BlobLink, BlobOp, now},
 };
@@ -77,6 +77,73 @@ impl Server {
         }
     }
 
+    pub async fn account_blob_store(&self, account_id: u32) -> trc::Result<Option<BlobStore>> {
+        if !self.core.storage.blob.is_regional() || account_id == u32::MAX {
+            return Ok(None);
+        }
+
+        let Some(account) = self
+            .try_account(account_id)
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Ok(None);
+        };
+
+        if let Some(region) = account.data_region() {
+            if let Some(store) = self.core.storage.blob.in_region(region) {
+                Ok(Some(store.clone()))
+            } else {
+                Err(trc::StoreEvent::NotConfigured
+                    .into_err()
+                    .details("Account data region is not defined in the blob store")
+                    .ctx(trc::Key::AccountId, account_id)
+                    .ctx(trc::Key::Id, region.to_string())
+                    .caused_by(trc::location!()))
+            }
+        } else {
+            Ok(None)
+        }
+    }
+
+    async fn upload_blob(&self, account_id: u32, hash: &BlobHash, data: &[u8]) -> trc::Result<()> {
+        let region_store = self.account_blob_store(account_id).await?;
+
+        // Accounts pinned to a region always upload, the blob may only exist elsewhere
+        if region_store.is_some()
+            || !self
+                .core
+                .storage
+                .data
+                .blob_exists(hash)
+                .await
+                .caused_by(trc::location!())?
+        {
+            // Upload blob to store
+            region_store
+                .as_ref()
+                .unwrap_or(&self.core.storage.blob)
+                .put_blob(hash.as_ref(), data, self.core.email.compression)
+                .await
+                .caused_by(trc::location!())?;
+
+            // Commit blob
+            let mut batch = BatchBuilder::new();
+            batch.set(
+                BlobOp::Commit { hash: hash.clone() },
+                (data.len() as u64).serialize(),
+            );
+            self.core
+                .storage
+                .data
+                .write(batch.build_all())
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        Ok(())
+    }
+
     #[allow(clippy::blocks_in_conditions)]
     pub async fn put_jmap_blob(&self, account_id: u32, data: &[u8]) -> trc::Result<BlobId> {
         // First reserve the hash
@@ -99,32 +166,9 @@ impl Server {
             .await
             .caused_by(trc::location!())?;
 
-        if !self
-            .core
-            .storage
-            .data
-            .blob_exists(&hash)
+        self.upload_blob(account_id, &hash, data)
             .await
-            .caused_by(trc::location!())?
-        {
-            // Upload blob to store
-            self.core
-                .storage
-                .blob
-                .put_blob(hash.as_ref(), data, self.core.email.compression)
-                .await
-                .caused_by(trc::location!())?;
-
-            // Commit blob
-            let mut batch = BatchBuilder::new();
-            batch.set(BlobOp::Commit { hash: hash.clone() }, Vec::new());
-            self.core
-                .storage
-                .data
-                .write(batch.build_all())
-                .await
-                .caused_by(trc::location!())?;
-        }
+            .caused_by(trc::location!())?;
 
         Ok(BlobId {
             hash,
@@ -162,32 +206,9 @@ impl Server {
             .await
             .caused_by(trc::location!())?;
 
-        if !self
-            .core
-            .storage
-            .data
-            .blob_exists(&hash)
+        self.upload_blob(account_id, &hash, data)
             .await
-            .caused_by(trc::location!())?
-        {
-            // Upload blob to store
-            self.core
-                .storage
-                .blob
-                .put_blob(hash.as_ref(), data, self.core.email.compression)
-                .await
-                .caused_by(trc::location!())?;
-
-            // Commit blob
-            let mut batch = BatchBuilder::new();
-            batch.set(BlobOp::Commit { hash: hash.clone() }, Vec::new());
-            self.core
-                .storage
-                .data
-                .write(batch.build_all())
-                .await
-                .caused_by(trc::location!())?;
-        }
+            .caused_by(trc::location!())?;
 
         Ok((
             hash.clone(),
@@ -4,11 +4,20 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::auth::EncryptionKeys;
+use crate::{
+    Server,
+    auth::{
+        ACCOUNT_FLAG_ENCRYPT_ALGO_AES128, ACCOUNT_FLAG_ENCRYPT_ALGO_AES256,
+        ACCOUNT_FLAG_ENCRYPT_METHOD_PGP, ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME, AccountCache,
+        EncryptionKeys,
+    },
+};
 use mail_parser::decoders::base64::base64_decode;
-use registry::schema::structs::PublicKey;
+use registry::schema::structs::{EncryptionKeyProvider, PublicKey};
+use rsa::{RsaPublicKey, pkcs1::DecodeRsaPublicKey};
 use sequoia_openpgp::{Cert, parse::Parse, policy::StandardPolicy, types::KeyFlags};
-use std::borrow::Cow;
+use std::{borrow::Cow, sync::Arc};
+use utils::cache::CacheItemWeight;
 
 const P: StandardPolicy<'static> = StandardPolicy::new();
 
@@ -23,9 +32,90 @@ pub struct EncryptionParams {
     pub method: EncryptionMethod,
 }
 
+impl Server {
+    // Returns the account's encryption keys along with its encryption flags.
+    // Accounts with encryption enabled but no public key of their own obtain
+    // it from the configured key provider.
+    pub async fn account_encryption_keys<'x>(
+        &self,
+        account: &'x AccountCache,
+    ) -> trc::Result<Option<(Cow<'x, EncryptionKeys>, u64)>> {
+        if let Some(keys) = &account.encryption_key {
+            return Ok(Some((Cow::Borrowed(keys), account.flags)));
+        } else if account.flags
+            & (ACCOUNT_FLAG_ENCRYPT_ALGO_AES256 | ACCOUNT_FLAG_ENCRYPT_ALGO_AES128)
+            == 0
+            || !self.core.email.encryption_key_provider.is_enabled()
+        {
+            return Ok(None);
+        }
+
+        let provider = self.core.email.encryption_key_provider.for_account(
+            account.id,
+            &account.name,
+            account.id_tenant,
+        );
+        let key_reference = provider.key_reference();
+        let cache = &self.inner.cache.encryption_keys;
+        let cached = cache.get_allow_expired(key_reference.as_str());
+        let params = match cached {
+            Some((params, false)) => params,
+            _ => match fetch_public_key(&provider).await {
+                Ok(params) => {
+                    let params = Arc::new(params);
+                    cache.insert(
+                        key_reference.into_boxed_str(),
+                        params.clone(),
+                        self.core.email.encryption_key_cache_ttl,
+                    );
+                    params
+                }
+                Err(err) => {
+                    // An unavailable key provider does not block delivery, the last
+                    // known key is used when there is one, otherwise the message is
+                    // stored unencrypted.
+                    trc::error!(
+                        trc::StoreEvent::CryptoError
+                            .into_err()
+                            .account_id(account.id)
+                            .details("Failed to obtain encryption key from key provider")
+                            .reason(err)
+                    );
+                    match cached {
+                        Some((params, _)) => params,
+                        None => return Ok(None),
+                    }
+                }
+            },
+        };
+
+        let flags = (account.flags
+            & !(ACCOUNT_FLAG_ENCRYPT_METHOD_PGP | ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME))
+            | match params.method {
+                EncryptionMethod::PGP => ACCOUNT_FLAG_ENCRYPT_METHOD_PGP,
+                EncryptionMethod::SMIME => ACCOUNT_FLAG_ENCRYPT_METHOD_SMIME,
+            };
+
+        Ok(Some((Cow::Owned(params.certs.clone()), flags)))
+    }
+}
+
+async fn fetch_public_key(
+    provider: &EncryptionKeyProvider,
+) -> Result<EncryptionParams, Cow<'static, str>> {
+    let pem = provider.public_key().await?.unwrap_or_default();
+    parse_public_key_pem(&pem)?
+        .ok_or_else(|| Cow::from("No public key found in key provider response"))
+}
+
 #[allow(clippy::type_complexity)]
 pub fn parse_public_key(pk: &PublicKey) -> Result<Option<EncryptionParams>, Cow<'static, str>> {
-    let bytes_ = pk.key.as_bytes();
+    parse_public_key_pem(&pk.key)
+}
+
+#[allow(clippy::type_complexity)]
+pub fn parse_public_key_pem(pem: &str) -> Result<Option<EncryptionParams>, Cow<'static, str>> {
+    let bytes_ = pem.as_bytes();
     let mut bytes = bytes_.iter().enumerate();
     let mut buf = vec![];
     let mut method = None;
@@ -135,12 +225,27 @@ pub fn parse_public_key(pk: &PublicKey) -> Result<Option<EncryptionParams>, Cow<
                     return Err(format!("Failed to decode OpenPGP public key: {err}").into());
                 }
             },
-            EncryptionMethod::SMIME => {
-                if let Err(err) = rasn::der::decode::<rasn_pkix::Certificate>(&cert) {
+            EncryptionMethod::SMIME => match rasn::der::decode::<rasn_pkix::Certificate>(&cert) {
+                Ok(x509) => {
+                    // Messages are encrypted using RSA key transport
+                    if RsaPublicKey::from_pkcs1_der(
+                        x509.tbs_certificate
+                            .subject_public_key_info
+                            .subject_public_key
+                            .as_raw_slice(),
+                    )
+                    .is_err()
+                    {
+                        return Err(
+                            "Only RSA certificates are supported for S/MIME encryption".into()
+                        );
+                    }
+                    certs.push(cert);
+                }
+                Err(err) => {
                     return Err(format!("Failed to decode X509 certificate: {err}").into());
                 }
-                certs.push(cert);
-            }
+            },
         }
         buf.clear();
     }
@@ -151,6 +256,13 @@ pub fn parse_public_key(pk: &PublicKey) -> Result<Option<EncryptionParams>, Cow<
     }))
 }
 
+impl CacheItemWeight for EncryptionParams {
+    fn weight(&self) -> u64 {
+        (std::mem::size_of::<EncryptionParams>()
+            + self.certs.iter().map(|cert| cert.len()).sum::<usize>()) as u64
+    }
+}
+
 fn has_pgp_keys(cert: Cert) -> bool {
     cert.keys()
         .with_policy(&P, None)
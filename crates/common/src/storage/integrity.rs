@@ -0,0 +1,101 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::Server;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::BlobIntegrityIssue,
+    },
+    types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, index::IndexBuilder},
+};
+use store::{
+    ProgressTracker,
+    write::{BatchBuilder, RegistryClass, ValueClass, now},
+};
+use trc::AddContext;
+
+pub trait BlobIntegrityIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+impl BlobIntegrityIndex for BlobIntegrityIssue {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::BlobIntegrityIssue.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::BlobHash, &self.blob_hash);
+        index_builder.search(Property::Issue, self.issue.to_id() as u64);
+        index_builder.search(Property::Timestamp, self.timestamp.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        if is_set {
+            batch.set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(key);
+        }
+    }
+}
+
+impl Server {
+    // Verifies the blobs committed to a shard, damaged blobs are repaired from
+    // another replica when possible and recorded for administrators to review.
+    pub async fn scrub_blob_store(
+        &self,
+        shard_index: u8,
+        tracker: &mut impl ProgressTracker,
+    ) -> trc::Result<()> {
+        let damaged = self
+            .store()
+            .scrub_blobs(
+                self.blob_store().clone(),
+                shard_index,
+                self.core.email.blob_scrub_repair,
+                self.core.email.compression,
+                tracker,
+            )
+            .await
+            .caused_by(trc::location!())?;
+        let Some(expires_in) = self.core.email.blob_issue_retention else {
+            return Ok(());
+        };
+        if damaged.is_empty() {
+            return Ok(());
+        }
+
+        let timestamp = now();
+        let expires_at = UTCDateTime::from_timestamp((timestamp + expires_in.as_secs()) as i64);
+        let timestamp = UTCDateTime::from_timestamp(timestamp as i64);
+        let mut batch = BatchBuilder::new();
+        for blob in damaged {
+            BlobIntegrityIssue {
+                blob_hash: blob.hash.to_hex(),
+                issue: blob.issue,
+                size: blob.size,
+                repaired: blob.repaired,
+                timestamp,
+                expires_at,
+            }
+            .write_ops(&mut batch, self.inner.data.registry_id_gen.generate(), true);
+
+            if batch.is_large_batch() {
+                self.store()
+                    .write(std::mem::take(&mut batch).build_all())
+                    .await
+                    .caused_by(trc::location!())?;
+            }
+        }
+        if !batch.is_empty() {
+            self.store()
+                .write(batch.build_all())
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        Ok(())
+    }
+}
@@ -17,11 +17,13 @@ use std::sync::Arc;
 use store::{BlobStore, InMemoryStore, RegistryStore, SearchStore, Store};
 
 pub mod archive;
+pub mod audit;
 pub mod blob;
 pub mod dav;
 pub mod document;
 pub mod encryption;
 pub mod index;
+pub mod integrity;
 pub mod quota;
 pub mod state;
 pub mod transaction;
@@ -73,6 +73,7 @@ impl MetricsStore for Store {
                 MetricType::SecurityScanBan,
                 MetricType::SecurityAbuseBan,
                 MetricType::SecurityLoiterBan,
+                MetricType::SecuritySmugglingBan,
                 MetricType::SecurityIpBlocked,
                 MetricType::IncomingReportDmarcReport,
                 MetricType::IncomingReportDmarcReportWithWarnings,
@@ -45,6 +45,7 @@ impl Server {
                 EventType::Security(SecurityEvent::ScanBan),
                 EventType::Security(SecurityEvent::AbuseBan),
                 EventType::Security(SecurityEvent::LoiterBan),
+                EventType::Security(SecurityEvent::SmugglingBan),
                 EventType::Security(SecurityEvent::IpBlocked),
                 EventType::IncomingReport(IncomingReportEvent::DmarcReport),
                 EventType::IncomingReport(IncomingReportEvent::DmarcReportWithWarnings),
@@ -5,7 +5,9 @@
  */
 
 pub mod metrics;
+pub mod statistics;
 pub mod tracers;
+pub mod usage;
 pub mod webhooks;
 
 use tracers::log::spawn_log_tracer;
@@ -23,7 +25,8 @@ impl Telemetry {
             tracer.typ.spawn(
                 SubscriberBuilder::new(tracer.id)
                     .with_interests(tracer.interests)
-                    .with_lossy(tracer.lossy),
+                    .with_lossy(tracer.lossy)
+                    .with_tracing_rules(tracer.typ.accepts_tracing_rules()),
                 is_enterprise,
             );
         }
@@ -57,7 +60,8 @@ impl Telemetry {
                 tracer.typ.spawn(
                     SubscriberBuilder::new(tracer.id)
                         .with_interests(tracer.interests)
-                        .with_lossy(tracer.lossy),
+                        .with_lossy(tracer.lossy)
+                        .with_tracing_rules(tracer.typ.accepts_tracing_rules()),
                     is_enterprise,
                 );
             }
@@ -96,6 +100,24 @@ impl Telemetry {
 }
 
 impl TelemetrySubscriberType {
+    // Webhooks and the tracing history subscribe to an explicit list of events
+    pub fn accepts_tracing_rules(&self) -> bool {
+        match self {
+            TelemetrySubscriberType::ConsoleTracer(_)
+            | TelemetrySubscriberType::LogTracer(_)
+            | TelemetrySubscriberType::OtelTracer(_) => true,
+            #[cfg(unix)]
+            TelemetrySubscriberType::JournalTracer(_) => true,
+            TelemetrySubscriberType::Webhook(_) => false,
+            // SPDX-SnippetBegin
+            // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+            // SPDX-License-Identifier: LicenseRef-SEL
+            #[cfg(feature = "enterprise")]
+            TelemetrySubscriberType::StoreTracer(_) => false,
+            // SPDX-SnippetEnd
+        }
+    }
+
     pub fn spawn(self, builder: SubscriberBuilder, is_enterprise: bool) {
         match self {
             TelemetrySubscriberType::ConsoleTracer(settings) => {
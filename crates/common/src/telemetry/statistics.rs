@@ -0,0 +1,320 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::Server;
+use ahash::AHashMap;
+use parking_lot::Mutex;
+use registry::{
+    schema::{
+        enums::StatisticsPeriod,
+        prelude::{ObjectType, Property},
+        structs::{StatisticsCounter, StatisticsRollup},
+    },
+    types::{
+        EnumImpl, ObjectImpl, datetime::UTCDateTime, id::ObjectId, index::IndexBuilder, list::List,
+    },
+};
+use std::time::Duration;
+use store::{
+    SerializeInfallible, U64_LEN, ValueKey,
+    registry::ObjectIdVersioned,
+    write::{
+        BatchBuilder, RegistryClass, ValueClass, assert::AssertValue, key::KeySerializer, now,
+    },
+};
+use trc::AddContext;
+
+// Statistics are kept in memory and flushed to the rollups every 5 minutes
+const FLUSH_INTERVAL: u64 = 300;
+const MAX_TRACKED_ADDRESSES: usize = 10_000;
+const MAX_TOP_ENTRIES: usize = 25;
+
+#[derive(Default)]
+pub struct StatisticsCollector {
+    bucket: Mutex<StatisticsBucket>,
+}
+
+#[derive(Default)]
+pub struct StatisticsBucket {
+    pub messages_received: u64,
+    pub messages_submitted: u64,
+    pub ham_messages: u64,
+    pub spam_messages: u64,
+    pub auth_failures: u64,
+    pub senders: AHashMap<String, u64>,
+    pub recipients: AHashMap<String, u64>,
+}
+
+pub trait StatisticsRollupIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+impl StatisticsCollector {
+    pub fn record_message<'x>(
+        &self,
+        is_authenticated: bool,
+        sender: &str,
+        recipients: impl IntoIterator<Item = &'x str>,
+    ) {
+        let mut bucket = self.bucket.lock();
+        if is_authenticated {
+            bucket.messages_submitted += 1;
+        } else {
+            bucket.messages_received += 1;
+        }
+        if !sender.is_empty() {
+            increment_address(&mut bucket.senders, sender);
+        }
+        for recipient in recipients {
+            increment_address(&mut bucket.recipients, recipient);
+        }
+    }
+
+    pub fn record_ingest(&self, is_spam: bool) {
+        let mut bucket = self.bucket.lock();
+        if is_spam {
+            bucket.spam_messages += 1;
+        } else {
+            bucket.ham_messages += 1;
+        }
+    }
+
+    pub fn record_auth_failure(&self) {
+        self.bucket.lock().auth_failures += 1;
+    }
+
+    pub fn take(&self) -> StatisticsBucket {
+        std::mem::take(&mut *self.bucket.lock())
+    }
+}
+
+impl StatisticsBucket {
+    pub fn is_empty(&self) -> bool {
+        self.messages_received == 0
+            && self.messages_submitted == 0
+            && self.ham_messages == 0
+            && self.spam_messages == 0
+            && self.auth_failures == 0
+    }
+}
+
+impl StatisticsRollupIndex for StatisticsRollup {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::StatisticsRollup.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::Period, self.period.to_id() as u64);
+        index_builder.search(Property::From, self.from.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        let pk = rollup_primary_key(self.period, self.from.timestamp() as u64);
+        if is_set {
+            batch
+                .set(
+                    pk,
+                    ObjectIdVersioned {
+                        object_id: ObjectId::new(ObjectType::StatisticsRollup, item_id.into()),
+                        version: 0,
+                    }
+                    .serialize(),
+                )
+                .set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(pk).clear(key);
+        }
+    }
+}
+
+impl Server {
+    pub async fn store_statistics(&self) -> trc::Result<()> {
+        let bucket = self.inner.data.statistics.take();
+        let Some(expires_in) = self.core.metrics.statistics_retention else {
+            return Ok(());
+        };
+        if bucket.is_empty() {
+            return Ok(());
+        }
+
+        let top_senders = top_entries(bucket.senders.iter());
+        let top_recipients = top_entries(bucket.recipients.iter());
+        let timestamp = now();
+
+        for period in [StatisticsPeriod::Hourly, StatisticsPeriod::Daily] {
+            let period_secs = match period {
+                StatisticsPeriod::Hourly => 3600,
+                StatisticsPeriod::Daily => 86400,
+            };
+            let from = timestamp - (timestamp % period_secs);
+            let pk = rollup_primary_key(period, from);
+            let object_id = ObjectType::StatisticsRollup.to_id();
+            let mut retry_count = 0;
+
+            loop {
+                // Find the rollup for this period
+                let mut batch = BatchBuilder::new();
+                let (item_id, mut rollup, previous) = if let Some(object_id_v) = self
+                    .store()
+                    .get_value::<ObjectIdVersioned>(ValueKey::from(pk.clone()))
+                    .await
+                    .caused_by(trc::location!())?
+                {
+                    let item_id = object_id_v.object_id.id().id();
+                    let rollup = self
+                        .store()
+                        .get_value::<StatisticsRollup>(ValueKey::from(ValueClass::Registry(
+                            RegistryClass::Item { object_id, item_id },
+                        )))
+                        .await
+                        .caused_by(trc::location!())?
+                        .ok_or_else(|| {
+                            trc::StoreEvent::NotFound
+                                .into_err()
+                                .ctx(trc::Key::Id, item_id)
+                                .details("Failed to find statistics rollup")
+                                .caused_by(trc::location!())
+                        })?;
+                    batch.assert_value(pk.clone(), AssertValue::U32(object_id_v.version));
+
+                    (item_id, rollup.clone(), Some((object_id_v, rollup)))
+                } else {
+                    batch.assert_value(pk.clone(), ());
+
+                    (
+                        self.inner.data.registry_id_gen.generate(),
+                        StatisticsRollup {
+                            period,
+                            from: UTCDateTime::from_timestamp(from as i64),
+                            to: UTCDateTime::from_timestamp((from + period_secs) as i64),
+                            ..Default::default()
+                        },
+                        None,
+                    )
+                };
+
+                // Merge counters
+                rollup.messages_received += bucket.messages_received;
+                rollup.messages_submitted += bucket.messages_submitted;
+                rollup.ham_messages += bucket.ham_messages;
+                rollup.spam_messages += bucket.spam_messages;
+                rollup.auth_failures += bucket.auth_failures;
+                rollup.top_senders = merge_top_entries(&rollup.top_senders, &top_senders);
+                rollup.top_recipients = merge_top_entries(&rollup.top_recipients, &top_recipients);
+                rollup.expires_at =
+                    UTCDateTime::from_timestamp((from + period_secs + expires_in.as_secs()) as i64);
+
+                // Write rollup, replacing the previous index entries
+                if let Some((mut object_id_v, previous)) = previous {
+                    previous.write_ops(&mut batch, item_id, false);
+                    rollup.write_ops(&mut batch, item_id, true);
+                    object_id_v.version += 1;
+                    batch.set(pk.clone(), object_id_v.serialize());
+                } else {
+                    rollup.write_ops(&mut batch, item_id, true);
+                }
+
+                match self.core.storage.data.write(batch.build_all()).await {
+                    Ok(_) => break,
+                    Err(err) if err.is_assertion_failure() && retry_count < 3 => {
+                        retry_count += 1;
+                    }
+                    Err(err) => {
+                        return Err(err.caused_by(trc::location!()));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+pub fn next_statistics_flush() -> Duration {
+    Duration::from_secs(FLUSH_INTERVAL - (now() % FLUSH_INTERVAL))
+}
+
+fn rollup_primary_key(period: StatisticsPeriod, from: u64) -> ValueClass {
+    ValueClass::Registry(RegistryClass::PrimaryKey {
+        object_id: ObjectType::StatisticsRollup.to_id().into(),
+        index_id: Property::Period.to_id(),
+        key: KeySerializer::new(U64_LEN + 1)
+            .write(period.to_id() as u8)
+            .write(from)
+            .finalize(),
+    })
+}
+
+fn increment_address(addresses: &mut AHashMap<String, u64>, address: &str) {
+    if let Some(count) = addresses.get_mut(address) {
+        *count += 1;
+    } else if addresses.len() < MAX_TRACKED_ADDRESSES {
+        addresses.insert(address.to_string(), 1);
+    }
+}
+
+fn top_entries<'x>(entries: impl Iterator<Item = (&'x String, &'x u64)>) -> Vec<StatisticsCounter> {
+    let mut entries = entries
+        .map(|(name, count)| StatisticsCounter {
+            name: name.clone(),
+            count: *count,
+        })
+        .collect::<Vec<_>>();
+    entries.sort_unstable_by(|a, b| b.count.cmp(&a.count).then_with(|| a.name.cmp(&b.name)));
+    entries.truncate(MAX_TOP_ENTRIES);
+    entries
+}
+
+fn merge_top_entries(
+    current: &List<StatisticsCounter>,
+    new: &[StatisticsCounter],
+) -> List<StatisticsCounter> {
+    let mut entries = AHashMap::with_capacity(current.len() + new.len());
+    for entry in current.iter().chain(new.iter()) {
+        *entries.entry(entry.name.clone()).or_insert(0u64) += entry.count;
+    }
+    top_entries(entries.iter()).into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn top_entries_merge() {
+        let current = vec![
+            StatisticsCounter {
+                name: "a@example.org".into(),
+                count: 3,
+            },
+            StatisticsCounter {
+                name: "b@example.org".into(),
+                count: 1,
+            },
+        ]
+        .into();
+        let new = top_entries(
+            [
+                ("b@example.org".to_string(), 4u64),
+                ("c@example.org".to_string(), 1u64),
+            ]
+            .iter()
+            .map(|(name, count)| (name, count)),
+        );
+
+        let merged = merge_top_entries(&current, &new)
+            .iter()
+            .map(|entry| (entry.name.as_str(), entry.count))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            merged,
+            [
+                ("b@example.org", 5),
+                ("a@example.org", 3),
+                ("c@example.org", 1)
+            ]
+        );
+    }
+}
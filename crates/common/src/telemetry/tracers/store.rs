@@ -182,6 +182,8 @@ impl StoreTracer {
                             | EventType::Auth(
                                 AuthEvent::Success
                                     | AuthEvent::Failed
+                                    | AuthEvent::ClientCertificateSuccess
+                                    | AuthEvent::ClientCertificateFailed
                                     | AuthEvent::TooManyAttempts
                                     | AuthEvent::Error
                             )
@@ -0,0 +1,270 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::Server;
+use ahash::AHashMap;
+use parking_lot::Mutex;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::TenantUsage,
+    },
+    types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, id::ObjectId, index::IndexBuilder},
+};
+use std::sync::atomic::{AtomicU64, Ordering};
+use store::{
+    SerializeInfallible, U32_LEN, U64_LEN, ValueKey,
+    registry::{ObjectIdVersioned, RegistryQuery},
+    roaring::RoaringBitmap,
+    write::{
+        BatchBuilder, RegistryClass, ValueClass, assert::AssertValue, key::KeySerializer, now,
+    },
+};
+use trc::AddContext;
+
+const DAY_SECS: u64 = 86400;
+
+// Usage counters are kept in memory per tenant and flushed to the daily
+// records together with the traffic statistics.
+#[derive(Default)]
+pub struct UsageCollector {
+    tenants: Mutex<AHashMap<u32, UsageBucket>>,
+    last_snapshot: AtomicU64,
+}
+
+#[derive(Default, Debug, Clone, PartialEq, Eq)]
+pub struct UsageBucket {
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub api_calls: u64,
+}
+
+pub trait TenantUsageIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+impl UsageCollector {
+    pub fn record_sent(&self, tenant_id: u32, size: u64) {
+        let mut tenants = self.tenants.lock();
+        let bucket = tenants.entry(tenant_id).or_default();
+        bucket.messages_sent += 1;
+        bucket.bytes_sent += size;
+    }
+
+    pub fn record_received(&self, tenant_id: u32, size: u64) {
+        let mut tenants = self.tenants.lock();
+        let bucket = tenants.entry(tenant_id).or_default();
+        bucket.messages_received += 1;
+        bucket.bytes_received += size;
+    }
+
+    pub fn record_api_call(&self, tenant_id: u32) {
+        self.tenants.lock().entry(tenant_id).or_default().api_calls += 1;
+    }
+
+    pub fn take(&self) -> AHashMap<u32, UsageBucket> {
+        std::mem::take(&mut *self.tenants.lock())
+    }
+
+    // Returns true once per day so every tenant gets a daily record with
+    // its storage usage, even when there was no traffic.
+    fn needs_snapshot(&self, day: u64) -> bool {
+        self.last_snapshot.swap(day, Ordering::Relaxed) != day
+    }
+}
+
+impl TenantUsageIndex for TenantUsage {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::TenantUsage.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::MemberTenantId, self.member_tenant_id.id());
+        index_builder.search(Property::From, self.from.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        let pk = usage_primary_key(
+            self.member_tenant_id.document_id(),
+            self.from.timestamp() as u64,
+        );
+        if is_set {
+            batch
+                .set(
+                    pk,
+                    ObjectIdVersioned {
+                        object_id: ObjectId::new(ObjectType::TenantUsage, item_id.into()),
+                        version: 0,
+                    }
+                    .serialize(),
+                )
+                .set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(pk).clear(key);
+        }
+    }
+}
+
+impl Server {
+    pub async fn store_usage(&self) -> trc::Result<()> {
+        let mut tenants = self.inner.data.usage.take();
+        let Some(expires_in) = self.core.metrics.usage_retention else {
+            return Ok(());
+        };
+        let timestamp = now();
+        let from = timestamp - (timestamp % DAY_SECS);
+
+        if self.inner.data.usage.needs_snapshot(from) {
+            for tenant_id in self
+                .registry()
+                .query::<RoaringBitmap>(RegistryQuery::new(ObjectType::Tenant))
+                .await
+                .caused_by(trc::location!())?
+            {
+                tenants.entry(tenant_id).or_default();
+            }
+        }
+
+        for (tenant_id, bucket) in tenants {
+            let storage_used = self
+                .get_used_quota_tenant(tenant_id)
+                .await
+                .caused_by(trc::location!())?
+                .max(0) as u64;
+            let pk = usage_primary_key(tenant_id, from);
+            let object_id = ObjectType::TenantUsage.to_id();
+            let mut retry_count = 0;
+
+            loop {
+                // Find the usage record for this day
+                let mut batch = BatchBuilder::new();
+                let (item_id, mut usage, previous) = if let Some(object_id_v) = self
+                    .store()
+                    .get_value::<ObjectIdVersioned>(ValueKey::from(pk.clone()))
+                    .await
+                    .caused_by(trc::location!())?
+                {
+                    let item_id = object_id_v.object_id.id().id();
+                    let usage = self
+                        .store()
+                        .get_value::<TenantUsage>(ValueKey::from(ValueClass::Registry(
+                            RegistryClass::Item { object_id, item_id },
+                        )))
+                        .await
+                        .caused_by(trc::location!())?
+                        .ok_or_else(|| {
+                            trc::StoreEvent::NotFound
+                                .into_err()
+                                .ctx(trc::Key::Id, item_id)
+                                .details("Failed to find tenant usage")
+                                .caused_by(trc::location!())
+                        })?;
+                    batch.assert_value(pk.clone(), AssertValue::U32(object_id_v.version));
+
+                    (item_id, usage.clone(), Some((object_id_v, usage)))
+                } else {
+                    batch.assert_value(pk.clone(), ());
+
+                    (
+                        self.inner.data.registry_id_gen.generate(),
+                        TenantUsage {
+                            member_tenant_id: tenant_id.into(),
+                            from: UTCDateTime::from_timestamp(from as i64),
+                            to: UTCDateTime::from_timestamp((from + DAY_SECS) as i64),
+                            ..Default::default()
+                        },
+                        None,
+                    )
+                };
+
+                // Merge counters, storage is the latest snapshot
+                usage.storage_used = storage_used;
+                usage.messages_sent += bucket.messages_sent;
+                usage.messages_received += bucket.messages_received;
+                usage.bytes_sent += bucket.bytes_sent;
+                usage.bytes_received += bucket.bytes_received;
+                usage.api_calls += bucket.api_calls;
+                usage.expires_at =
+                    UTCDateTime::from_timestamp((from + DAY_SECS + expires_in.as_secs()) as i64);
+
+                // Write usage, replacing the previous index entries
+                if let Some((mut object_id_v, previous)) = previous {
+                    if previous == usage {
+                        break;
+                    }
+                    previous.write_ops(&mut batch, item_id, false);
+                    usage.write_ops(&mut batch, item_id, true);
+                    object_id_v.version += 1;
+                    batch.set(pk.clone(), object_id_v.serialize());
+                } else {
+                    usage.write_ops(&mut batch, item_id, true);
+                }
+
+                match self.core.storage.data.write(batch.build_all()).await {
+                    Ok(_) => break,
+                    Err(err) if err.is_assertion_failure() && retry_count < 3 => {
+                        retry_count += 1;
+                    }
+                    Err(err) => {
+                        return Err(err.caused_by(trc::location!()));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+fn usage_primary_key(tenant_id: u32, from: u64) -> ValueClass {
+    ValueClass::Registry(RegistryClass::PrimaryKey {
+        object_id: ObjectType::TenantUsage.to_id().into(),
+        index_id: Property::MemberTenantId.to_id(),
+        key: KeySerializer::new(U32_LEN + U64_LEN)
+            .write(tenant_id)
+            .write(from)
+            .finalize(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn usage_collector() {
+        let collector = UsageCollector::default();
+        collector.record_sent(1, 100);
+        collector.record_sent(1, 50);
+        collector.record_received(2, 10);
+        collector.record_api_call(1);
+
+        let tenants = collector.take();
+        assert_eq!(
+            tenants.get(&1),
+            Some(&UsageBucket {
+                messages_sent: 2,
+                bytes_sent: 150,
+                api_calls: 1,
+                ..Default::default()
+            })
+        );
+        assert_eq!(
+            tenants.get(&2),
+            Some(&UsageBucket {
+                messages_received: 1,
+                bytes_received: 10,
+                ..Default::default()
+            })
+        );
+        assert!(collector.take().is_empty());
+
+        assert!(collector.needs_snapshot(DAY_SECS));
+        assert!(!collector.needs_snapshot(DAY_SECS));
+        assert!(collector.needs_snapshot(2 * DAY_SECS));
+    }
+}
@@ -6,9 +6,12 @@
 
 use crate::{Msg, PubSubStream};
 use futures::StreamExt;
-use redis::{AsyncCommands, PushInfo, cluster::ClusterConfig, cluster_async::ClusterConnection};
+use redis::{
+    AsyncCommands, PushInfo, aio::MultiplexedConnection, cluster::ClusterConfig,
+    cluster_async::ClusterConnection,
+};
 use std::fmt::Display;
-use store::backend::redis::{RedisPool, RedisStore};
+use store::backend::redis::{RedisPool, RedisStore, pool::with_master};
 use tokio::sync::mpsc::UnboundedReceiver;
 
 pub struct RedisPubSubStream {
@@ -42,6 +45,12 @@ pub(crate) async fn redis_publish(
             .publish(topic, message)
             .await
             .map_err(into_error),
+        RedisPool::Sentinel(pool) => {
+            with_master(pool, async |conn: &mut MultiplexedConnection| {
+                conn.publish(topic, &message).await.map_err(into_error)
+            })
+            .await
+        }
     }
 }
 
@@ -80,6 +89,15 @@ pub(crate) async fn redis_subscribe(
                 rx,
             }))
         }
+        RedisPool::Sentinel(pool) => {
+            let (client, _) = pool.manager().connect_master().await?;
+            let mut pubsub = client.get_async_pubsub().await.map_err(into_error)?;
+            pubsub.subscribe(topic).await.map_err(into_error)?;
+
+            Ok(PubSubStream::Redis(RedisPubSubStream {
+                stream: pubsub.into_on_message(),
+            }))
+        }
     }
 }
 
@@ -7,7 +7,7 @@
 use crate::message::metadata::{ArchivedMessageData, MessageData};
 use common::{
     MessageCache, MessageStoreCache, MessageUidCache, MessagesCache, Server, auth::AccessToken,
-    sharing::EffectiveAcl,
+    config::mailstore::email::EmailConfig, sharing::EffectiveAcl,
 };
 use store::write::{AlignedBytes, Archive};
 use store::{ValueKey, ahash::AHashMap, roaring::RoaringBitmap};
@@ -15,7 +15,7 @@ use trc::AddContext;
 use types::{
     acl::Acl,
     collection::Collection,
-    keyword::{Keyword, OTHER},
+    keyword::{Keyword, KeywordRegistry, MAX_CUSTOM_KEYWORDS},
 };
 use utils::map::bitmap::Bitmap;
 
@@ -23,7 +23,7 @@ struct MessagesCacheBuilder {
     pub change_id: u64,
     pub items: Vec<MessageCache>,
     pub index: AHashMap<u32, u32>,
-    pub keywords: Vec<Box<str>>,
+    pub keywords: KeywordRegistry,
     pub size: u64,
 }
 
@@ -38,7 +38,7 @@ pub(crate) async fn update_email_cache(
         items: Vec::with_capacity(store_cache.emails.items.len()),
         size: 0,
         change_id: 0,
-        keywords: store_cache.emails.keywords.to_vec(),
+        keywords: store_cache.emails.keywords.clone(),
     };
 
     for (document_id, is_update) in changed_ids {
@@ -78,7 +78,7 @@ pub(crate) async fn full_email_cache_build(
     let mut cache = MessagesCacheBuilder {
         items: Vec::with_capacity(16),
         index: AHashMap::with_capacity(16),
-        keywords: Vec::new(),
+        keywords: KeywordRegistry::default(),
         size: 0,
         change_id: 0,
     };
@@ -125,18 +125,15 @@ fn insert_item(
         size: message.size.to_native(),
     };
     for keyword in message.keywords.iter() {
-        match keyword.id() {
-            Ok(id) => {
-                item.keywords |= 1 << id;
-            }
-            Err(custom) => {
-                if let Some(idx) = cache.keywords.iter().position(|k| **k == *custom) {
-                    item.keywords |= 1 << (OTHER + idx);
-                } else if cache.keywords.len() < (128 - OTHER) {
-                    cache.keywords.push(custom.into());
-                    item.keywords |= 1 << (OTHER + cache.keywords.len() - 1);
-                }
-            }
+        // Stored messages are always indexed, the configured limit only applies to new keywords
+        let id = match keyword.id() {
+            Ok(id) => Some(id),
+            Err(custom) => cache
+                .keywords
+                .get_or_insert_custom(custom, MAX_CUSTOM_KEYWORDS),
+        };
+        if let Some(id) = id {
+            item.keywords |= 1 << id;
         }
     }
 
@@ -146,12 +143,20 @@ fn insert_item(
 impl MessagesCacheBuilder {
     pub fn build(mut self) -> MessagesCache {
         self.index.shrink_to_fit();
+
+        // Release custom keywords no longer set on any message
+        self.keywords.collect_garbage(
+            self.items
+                .iter()
+                .fold(0u128, |used, item| used | item.keywords),
+        );
+
         MessagesCache {
             change_id: self.change_id,
             items: self.items.into_boxed_slice(),
             index: self.index,
-            keywords: self.keywords.into_boxed_slice(),
-            size: self.size,
+            size: self.size + self.keywords.size() as u64,
+            keywords: self.keywords,
         }
     }
 }
@@ -194,6 +199,13 @@ pub trait MessageCacheAccess {
     fn expand_keywords(&self, message: &MessageCache) -> impl Iterator<Item = Keyword>;
 
     fn has_keyword(&self, message: &MessageCache, keyword: &Keyword) -> bool;
+
+    fn accepts_keywords<'x>(
+        &self,
+        config: &EmailConfig,
+        keywords: &[Keyword],
+        added: impl IntoIterator<Item = &'x Keyword>,
+    ) -> bool;
 }
 
 impl MessageCacheAccess for MessageStoreCache {
@@ -298,15 +310,39 @@ impl MessageCacheAccess for MessageStoreCache {
     }
 
     fn expand_keywords(&self, message: &MessageCache) -> impl Iterator<Item = Keyword> {
-        KeywordsIter(message.keywords).map(move |id| match Keyword::try_from_id(id) {
-            Ok(keyword) => keyword,
-            Err(id) => Keyword::Other(self.emails.keywords[id - OTHER].clone()),
-        })
+        KeywordsIter(message.keywords).filter_map(move |id| self.emails.keywords.keyword(id))
     }
 
     fn has_keyword(&self, message: &MessageCache, keyword: &Keyword) -> bool {
         keyword_to_id(self, keyword).is_some_and(|id| message.keywords & (1 << id) != 0)
     }
+
+    // Checks the keywords of a message after adding the keywords in `added`,
+    // the limits are not enforced when keywords are only being removed.
+    fn accepts_keywords<'x>(
+        &self,
+        config: &EmailConfig,
+        keywords: &[Keyword],
+        added: impl IntoIterator<Item = &'x Keyword>,
+    ) -> bool {
+        let mut has_added = false;
+        let mut new_custom = Vec::new();
+        for keyword in added {
+            has_added = true;
+            if let Err(name) = keyword.id()
+                && self.emails.keywords.custom_id(name).is_none()
+                && !new_custom.contains(&name)
+            {
+                new_custom.push(name);
+            }
+        }
+
+        !has_added
+            || (keywords.len() <= config.max_message_keywords
+                && (new_custom.is_empty()
+                    || self.emails.keywords.len() + new_custom.len()
+                        <= config.max_custom_keywords.min(MAX_CUSTOM_KEYWORDS)))
+    }
 }
 
 fn email_insert(cache: &mut MessagesCacheBuilder, item: MessageCache) {
@@ -327,15 +363,7 @@ fn email_insert(cache: &mut MessagesCacheBuilder, item: MessageCache) {
 
 #[inline]
 fn keyword_to_id(cache: &MessageStoreCache, keyword: &Keyword) -> Option<u32> {
-    match keyword.id() {
-        Ok(id) => Some(id),
-        Err(name) => cache
-            .emails
-            .keywords
-            .iter()
-            .position(|k| **k == *name)
-            .map(|idx| (OTHER + idx) as u32),
-    }
+    cache.emails.keywords.id(keyword)
 }
 
 #[derive(Clone, Copy, Debug)]
@@ -4,16 +4,20 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use common::{MessageStoreCache, Server, UpdateLock, cache::LockResult};
-use email::{full_email_cache_build, update_email_cache};
+use common::{MailboxStatus, MessageStoreCache, Server, UpdateLock, cache::LockResult};
+use email::{MessageCacheAccess, full_email_cache_build, update_email_cache};
 use mailbox::{full_mailbox_cache_build, update_mailbox_cache};
 use std::{collections::hash_map::Entry, sync::Arc, time::Instant};
 use store::{
-    ahash::AHashMap,
+    ahash::{AHashMap, AHashSet},
     query::log::{Change, Query},
 };
 use trc::{AddContext, StoreEvent};
-use types::collection::SyncCollection;
+use types::{
+    collection::{Collection, SyncCollection},
+    field::MailboxField,
+    keyword::Keyword,
+};
 
 pub mod email;
 pub mod mailbox;
@@ -23,6 +27,12 @@ pub trait MessageCacheFetch: Sync + Send {
         &self,
         account_id: u32,
     ) -> impl Future<Output = trc::Result<Arc<MessageStoreCache>>> + Send;
+
+    fn get_mailbox_status<'x>(
+        &self,
+        account_id: u32,
+        cache: &'x MessageStoreCache,
+    ) -> impl Future<Output = trc::Result<&'x AHashMap<u32, MailboxStatus>>> + Send;
 }
 
 impl MessageCacheFetch for Server {
@@ -116,6 +126,7 @@ impl MessageCacheFetch for Server {
             }
         };
         let mut cache = cache.as_ref().clone();
+        cache.status = Default::default();
 
         let mut changed_items: AHashMap<u32, bool> = AHashMap::with_capacity(changes.changes.len());
         let mut changed_containers: AHashMap<u32, bool> =
@@ -194,6 +205,69 @@ impl MessageCacheFetch for Server {
 
         Ok(cache)
     }
+
+    // Counters are computed once per cache revision and shared by all sessions
+    async fn get_mailbox_status<'x>(
+        &self,
+        account_id: u32,
+        cache: &'x MessageStoreCache,
+    ) -> trc::Result<&'x AHashMap<u32, MailboxStatus>> {
+        cache
+            .status
+            .get_or_try_init(|| async {
+                let uid_counters = self
+                    .store()
+                    .get_counters(account_id, Collection::Mailbox, MailboxField::UidCounter)
+                    .await
+                    .caused_by(trc::location!())?;
+                let mut status = cache
+                    .mailboxes
+                    .items
+                    .iter()
+                    .map(|mailbox| {
+                        (
+                            mailbox.document_id,
+                            MailboxStatus {
+                                uid_next: uid_counters
+                                    .get(&mailbox.document_id)
+                                    .map_or(1, |uid| (*uid + 1) as u64),
+                                ..Default::default()
+                            },
+                        )
+                    })
+                    .collect::<AHashMap<_, _>>();
+                let mut threads = AHashSet::new();
+                let mut unread_threads = AHashSet::new();
+
+                for message in cache.emails.items.iter() {
+                    let is_unseen = !cache.has_keyword(message, &Keyword::Seen);
+                    let is_deleted = cache.has_keyword(message, &Keyword::Deleted);
+                    for mailbox in &message.mailboxes {
+                        let Some(status) = status.get_mut(&mailbox.mailbox_id) else {
+                            continue;
+                        };
+                        status.total_messages += 1;
+                        status.size += message.size as u64;
+                        if threads.insert((mailbox.mailbox_id, message.thread_id)) {
+                            status.total_threads += 1;
+                        }
+                        if is_unseen {
+                            status.total_unseen += 1;
+                            if unread_threads.insert((mailbox.mailbox_id, message.thread_id)) {
+                                status.unread_threads += 1;
+                            }
+                        }
+                        if is_deleted {
+                            status.total_deleted += 1;
+                            status.deleted_size += message.size as u64;
+                        }
+                    }
+                }
+
+                Ok(status)
+            })
+            .await
+    }
 }
 
 async fn full_cache_build(
@@ -222,5 +296,6 @@ async fn full_cache_build(
         mailboxes: Arc::new(mailboxes),
         last_change_id,
         size,
+        status: Default::default(),
     }))
 }
@@ -0,0 +1,98 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::Identity;
+use common::{Server, auth::EmailCache};
+use std::future::Future;
+use store::{
+    ValueKey,
+    roaring::RoaringBitmap,
+    write::{AlignedBytes, Archive},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::IdentityField};
+
+pub trait IdentityDelegation: Sync + Send {
+    fn identity_delegates(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> impl Future<Output = trc::Result<RoaringBitmap>> + Send;
+
+    fn is_delegated_sender(
+        &self,
+        account_id: u32,
+        address: &str,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+}
+
+impl IdentityDelegation for Server {
+    async fn identity_delegates(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> trc::Result<RoaringBitmap> {
+        self.store()
+            .get_value::<RoaringBitmap>(ValueKey::property(
+                account_id,
+                Collection::Identity,
+                document_id,
+                IdentityField::DelegatedTo,
+            ))
+            .await
+            .caused_by(trc::location!())
+            .map(Option::unwrap_or_default)
+    }
+
+    async fn is_delegated_sender(&self, account_id: u32, address: &str) -> trc::Result<bool> {
+        // Delegations are defined on the identities of the account that owns the address
+        let Some(EmailCache::Account(owner_id)) = self
+            .rcpt_id_from_email(address)
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Ok(false);
+        };
+        if owner_id == account_id {
+            return Ok(true);
+        }
+
+        for document_id in self
+            .document_ids(owner_id, Collection::Identity, IdentityField::DocumentId)
+            .await
+            .caused_by(trc::location!())?
+        {
+            if !self
+                .identity_delegates(owner_id, document_id)
+                .await?
+                .contains(account_id)
+            {
+                continue;
+            }
+
+            if let Some(identity) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    owner_id,
+                    Collection::Identity,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+                && identity
+                    .unarchive::<Identity>()
+                    .caused_by(trc::location!())?
+                    .email
+                    .as_str()
+                    == address
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+}
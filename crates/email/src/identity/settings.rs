@@ -0,0 +1,208 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::Identity;
+use common::Server;
+use std::future::Future;
+use store::{
+    Serialize, ValueKey,
+    write::{AlignedBytes, Archive, Archiver, BatchBuilder, BlobLink, BlobOp},
+};
+use trc::AddContext;
+use types::{blob_hash::BlobHash, collection::Collection, field::IdentityField};
+
+// Signatures up to this size are stored inline, larger ones are kept in the blob store
+pub const MAX_INLINE_SIGNATURE_SIZE: usize = 2048;
+pub const MAX_SIGNATURE_SIZE: usize = 64 * 1024;
+
+#[derive(
+    rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Default, Clone, PartialEq, Eq,
+)]
+#[rkyv(derive(Debug))]
+pub struct IdentitySettings {
+    pub text_signature: Option<BlobHash>,
+    pub html_signature: Option<BlobHash>,
+    pub bcc_to_self: bool,
+}
+
+pub trait IdentitySettingsStore: Sync + Send {
+    fn identity_settings(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> impl Future<Output = trc::Result<IdentitySettings>> + Send;
+
+    fn identity_signature(
+        &self,
+        blob_hash: &BlobHash,
+    ) -> impl Future<Output = trc::Result<String>> + Send;
+
+    fn store_identity_signatures(
+        &self,
+        account_id: u32,
+        identity: &mut Identity,
+        settings: &mut IdentitySettings,
+        batch: &mut BatchBuilder,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn inline_identity_signatures(
+        &self,
+        account_id: u32,
+        document_id: u32,
+        identity: &mut Identity,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+impl IdentitySettingsStore for Server {
+    async fn identity_settings(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> trc::Result<IdentitySettings> {
+        self.store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                account_id,
+                Collection::Identity,
+                document_id,
+                IdentityField::Settings,
+            ))
+            .await
+            .caused_by(trc::location!())?
+            .map(|settings| settings.deserialize::<IdentitySettings>())
+            .transpose()
+            .caused_by(trc::location!())
+            .map(Option::unwrap_or_default)
+    }
+
+    async fn identity_signature(&self, blob_hash: &BlobHash) -> trc::Result<String> {
+        self.blob_store()
+            .get_blob(blob_hash.as_slice(), 0..usize::MAX)
+            .await
+            .caused_by(trc::location!())?
+            .map(|bytes| {
+                String::from_utf8(bytes)
+                    .unwrap_or_else(|err| String::from_utf8_lossy(err.as_bytes()).into_owned())
+            })
+            .ok_or_else(|| {
+                trc::StoreEvent::NotFound
+                    .into_err()
+                    .details("Identity signature blob not found.")
+                    .caused_by(trc::location!())
+            })
+    }
+
+    // Moves signatures that exceed the inline limit to the blob store. The
+    // temporary holds on the new blobs are released in the same batch.
+    async fn store_identity_signatures(
+        &self,
+        account_id: u32,
+        identity: &mut Identity,
+        settings: &mut IdentitySettings,
+        batch: &mut BatchBuilder,
+    ) -> trc::Result<()> {
+        for (signature, blob_hash) in [
+            (&mut identity.text_signature, &mut settings.text_signature),
+            (&mut identity.html_signature, &mut settings.html_signature),
+        ] {
+            if signature.len() >= MAX_INLINE_SIGNATURE_SIZE {
+                let (hash, hold) = self
+                    .put_temporary_blob(account_id, signature.as_bytes(), 60)
+                    .await
+                    .caused_by(trc::location!())?;
+                batch.clear(hold);
+                *blob_hash = Some(hash);
+                signature.clear();
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn inline_identity_signatures(
+        &self,
+        account_id: u32,
+        document_id: u32,
+        identity: &mut Identity,
+    ) -> trc::Result<()> {
+        let settings = self.identity_settings(account_id, document_id).await?;
+        if let Some(blob_hash) = &settings.text_signature {
+            identity.text_signature = self.identity_signature(blob_hash).await?;
+        }
+        if let Some(blob_hash) = &settings.html_signature {
+            identity.html_signature = self.identity_signature(blob_hash).await?;
+        }
+
+        Ok(())
+    }
+}
+
+impl IdentitySettings {
+    pub fn is_empty(&self) -> bool {
+        self.text_signature.is_none() && self.html_signature.is_none() && !self.bcc_to_self
+    }
+
+    // Writes the settings and updates the signature blob links, the batch
+    // must point to the identity document.
+    pub fn update(self, batch: &mut BatchBuilder, current: &IdentitySettings) -> trc::Result<()> {
+        if self == *current {
+            return Ok(());
+        }
+
+        for (new_hash, old_hash) in [
+            (&self.text_signature, &current.text_signature),
+            (&self.html_signature, &current.html_signature),
+        ] {
+            if new_hash == old_hash {
+                continue;
+            }
+            if let Some(old_hash) = old_hash
+                && Some(old_hash) != self.text_signature.as_ref()
+                && Some(old_hash) != self.html_signature.as_ref()
+            {
+                batch.clear(BlobOp::Link {
+                    hash: old_hash.clone(),
+                    to: BlobLink::Document,
+                });
+            }
+            if let Some(new_hash) = new_hash {
+                batch.set(
+                    BlobOp::Link {
+                        hash: new_hash.clone(),
+                        to: BlobLink::Document,
+                    },
+                    Vec::new(),
+                );
+            }
+        }
+
+        if !self.is_empty() {
+            batch.set(
+                IdentityField::Settings,
+                Archiver::new(self)
+                    .serialize()
+                    .caused_by(trc::location!())?,
+            );
+        } else {
+            batch.clear(IdentityField::Settings);
+        }
+
+        Ok(())
+    }
+
+    // Removes the settings, the batch must point to the identity document
+    pub fn remove(self, batch: &mut BatchBuilder) {
+        for blob_hash in [self.text_signature, self.html_signature]
+            .into_iter()
+            .flatten()
+        {
+            batch.clear(BlobOp::Link {
+                hash: blob_hash,
+                to: BlobLink::Document,
+            });
+        }
+        batch.clear(IdentityField::Settings);
+    }
+}
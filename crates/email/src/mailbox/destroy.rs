@@ -6,14 +6,14 @@
 
 use super::*;
 use crate::{
-    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
     message::metadata::MessageData,
 };
 use common::{
     Server, auth::AccessToken, sharing::EffectiveAcl, storage::index::ObjectIndexBuilder,
 };
 use registry::schema::{
-    enums::IndexDocumentType,
+    enums::{IndexDocumentType, SharedMailboxDestroy},
     structs::{Task, TaskIndexDocument, TaskStatus},
 };
 use store::{
@@ -26,6 +26,7 @@ use types::{
     acl::Acl,
     collection::{Collection, VanishedCollection},
     field::MailboxField,
+    special_use::SpecialUse,
 };
 
 pub trait MailboxDestroy: Sync + Send {
@@ -36,6 +37,14 @@ pub trait MailboxDestroy: Sync + Send {
         access_token: &AccessToken,
         remove_emails: bool,
     ) -> impl Future<Output = trc::Result<Result<Option<u64>, MailboxDestroyError>>> + Send;
+
+    fn mailboxes_destroy(
+        &self,
+        account_id: u32,
+        document_ids: &RoaringBitmap,
+        access_token: &AccessToken,
+        remove_emails: bool,
+    ) -> impl Future<Output = trc::Result<Result<Option<u64>, (u32, MailboxDestroyError)>>> + Send;
 }
 
 pub enum MailboxDestroyError {
@@ -43,6 +52,7 @@ pub enum MailboxDestroyError {
     Forbidden,
     HasChildren,
     HasEmails,
+    HasSharedEmails,
     NotFound,
     AssertionFailed,
 }
@@ -55,175 +65,267 @@ impl MailboxDestroy for Server {
         access_token: &AccessToken,
         remove_emails: bool,
     ) -> trc::Result<Result<Option<u64>, MailboxDestroyError>> {
+        self.mailboxes_destroy(
+            account_id,
+            &RoaringBitmap::from_iter([document_id]),
+            access_token,
+            remove_emails,
+        )
+        .await
+        .map(|result| result.map_err(|(_, err)| err))
+    }
+
+    // Destroys a set of mailboxes in a single batch, a mailbox may only have
+    // sub-mailboxes if they are part of the same set.
+    async fn mailboxes_destroy(
+        &self,
+        account_id: u32,
+        document_ids: &RoaringBitmap,
+        access_token: &AccessToken,
+        remove_emails: bool,
+    ) -> trc::Result<Result<Option<u64>, (u32, MailboxDestroyError)>> {
+        let Some(first_id) = document_ids.min() else {
+            return Ok(Ok(None));
+        };
+
         // Internal folders cannot be deleted
         #[cfg(not(feature = "test_mode"))]
-        if [INBOX_ID, TRASH_ID, JUNK_ID].contains(&document_id) {
-            return Ok(Err(MailboxDestroyError::CannotDestroy));
+        if let Some(document_id) = [INBOX_ID, TRASH_ID, JUNK_ID]
+            .into_iter()
+            .find(|id| document_ids.contains(*id))
+        {
+            return Ok(Err((document_id, MailboxDestroyError::CannotDestroy)));
         }
 
-        // Verify that this mailbox does not have sub-mailboxes
+        // Verify that these mailboxes do not have sub-mailboxes outside the set
         let cache = self
             .get_cached_messages(account_id)
             .await
             .caused_by(trc::location!())?;
-        if cache
-            .mailboxes
-            .items
-            .iter()
-            .any(|item| item.parent_id == document_id)
-        {
-            return Ok(Err(MailboxDestroyError::HasChildren));
+        if let Some(item) = cache.mailboxes.items.iter().find(|item| {
+            document_ids.contains(item.parent_id) && !document_ids.contains(item.document_id)
+        }) {
+            return Ok(Err((item.parent_id, MailboxDestroyError::HasChildren)));
         }
 
-        // Verify that the mailbox is empty
-        let mut batch = BatchBuilder::new();
+        // Obtain mailboxes and validate ACLs
+        let is_shared = access_token.is_shared(account_id);
+        let mut mailboxes = Vec::with_capacity(document_ids.len() as usize);
+        for document_id in document_ids {
+            let Some(mailbox_) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::Mailbox,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                return Ok(Err((document_id, MailboxDestroyError::NotFound)));
+            };
+            if is_shared {
+                let acl = mailbox_
+                    .to_unarchived::<Mailbox>()
+                    .caused_by(trc::location!())?
+                    .inner
+                    .acls
+                    .effective_acl(access_token);
+                if !acl.contains(Acl::Delete) || (remove_emails && !acl.contains(Acl::RemoveItems))
+                {
+                    return Ok(Err((document_id, MailboxDestroyError::Forbidden)));
+                }
+            }
+            mailboxes.push((document_id, mailbox_));
+        }
 
-        batch.with_account_id(account_id);
+        // Messages filed only in the destroyed mailboxes are deleted, the
+        // remaining ones are untagged.
+        let mut message_ids = RoaringBitmap::new();
+        let mut delete_ids = RoaringBitmap::new();
+        for message in cache.emails.items.iter() {
+            if message
+                .mailboxes
+                .iter()
+                .any(|m| document_ids.contains(m.mailbox_id))
+            {
+                message_ids.insert(message.document_id);
+                if message
+                    .mailboxes
+                    .iter()
+                    .all(|m| document_ids.contains(m.mailbox_id))
+                {
+                    delete_ids.insert(message.document_id);
+                }
+            }
+        }
+        if !message_ids.is_empty() && !remove_emails {
+            let document_id = document_ids
+                .iter()
+                .find(|id| cache.in_mailbox(*id).next().is_some())
+                .unwrap_or(first_id);
+            return Ok(Err((document_id, MailboxDestroyError::HasEmails)));
+        }
 
-        let message_ids =
-            RoaringBitmap::from_iter(cache.in_mailbox(document_id).map(|m| m.document_id));
+        // Messages owned by another account are either moved to the owner's
+        // Trash or the destroy is denied, depending on the configured policy.
+        let mut reassign_to = None;
+        if is_shared && !delete_ids.is_empty() {
+            let trash_id = cache
+                .mailbox_by_role(&SpecialUse::Trash)
+                .map(|mailbox| mailbox.document_id)
+                .filter(|id| !document_ids.contains(*id));
+            match (self.core.email.shared_mailbox_destroy, trash_id) {
+                (SharedMailboxDestroy::Reassign, Some(trash_id)) => {
+                    // Reserve the UIDs for all moved messages at once
+                    let mut batch = BatchBuilder::new();
+                    batch
+                        .with_account_id(account_id)
+                        .with_collection(Collection::Mailbox)
+                        .with_document(trash_id)
+                        .add_and_get(MailboxField::UidCounter, delete_ids.len() as i64);
+                    let last_uid = self
+                        .store()
+                        .write(batch.build_all())
+                        .await
+                        .and_then(|ids| ids.last_counter_id())
+                        .caused_by(trc::location!())? as u32;
+                    let first_uid = last_uid + 1 - delete_ids.len() as u32;
+                    reassign_to = Some((trash_id, first_uid..=last_uid));
+                }
+                _ => {
+                    let document_id = document_ids
+                        .iter()
+                        .find(|id| {
+                            cache
+                                .in_mailbox(*id)
+                                .any(|m| delete_ids.contains(m.document_id))
+                        })
+                        .unwrap_or(first_id);
+                    return Ok(Err((document_id, MailboxDestroyError::HasSharedEmails)));
+                }
+            }
+        }
 
+        let mut batch = BatchBuilder::new();
+        let mut is_stale = false;
+        batch.with_account_id(account_id);
         if !message_ids.is_empty() {
-            if remove_emails {
-                // If the message is in multiple mailboxes, untag it from the current mailbox,
-                // otherwise delete it.
-
-                self.archives(
-                    account_id,
-                    Collection::Email,
-                    &message_ids,
-                    |message_id, message_data_| {
-                        // Remove mailbox from list
-                        let prev_message_data = message_data_
-                            .to_unarchived::<MessageData>()
-                            .caused_by(trc::location!())?;
-                        if !prev_message_data
-                            .inner
-                            .mailboxes
-                            .iter()
-                            .any(|id| id.mailbox_id == document_id)
-                        {
-                            return Ok(true);
+            self.archives(
+                account_id,
+                Collection::Email,
+                &message_ids,
+                |message_id, message_data_| {
+                    // Remove destroyed mailboxes from list
+                    let prev_message_data = message_data_
+                        .to_unarchived::<MessageData>()
+                        .caused_by(trc::location!())?;
+                    if !prev_message_data
+                        .inner
+                        .mailboxes
+                        .iter()
+                        .any(|m| document_ids.contains(m.mailbox_id.to_native()))
+                    {
+                        return Ok(true);
+                    }
+                    let mut new_message_data = prev_message_data.inner.to_builder();
+                    new_message_data
+                        .mailboxes
+                        .retain(|m| !document_ids.contains(m.mailbox_id));
+                    if new_message_data.mailboxes.is_empty()
+                        && let Some((trash_id, uids)) = &mut reassign_to
+                    {
+                        if let Some(uid) = uids.next() {
+                            new_message_data.add_mailbox(UidMailbox::new(*trash_id, uid));
+                        } else {
+                            // The message was filed after the UIDs were reserved
+                            is_stale = true;
+                            return Ok(false);
                         }
+                    }
 
-                        if prev_message_data.inner.mailboxes.len() == 1 {
-                            // Delete message
-                            for mailbox in prev_message_data.inner.mailboxes.iter() {
-                                batch.log_vanished_item(
-                                    VanishedCollection::Email,
-                                    (mailbox.mailbox_id.to_native(), mailbox.uid.to_native()),
-                                );
-                            }
-                            batch
-                                .with_collection(Collection::Email)
-                                .with_document(message_id)
-                                .custom(
-                                    ObjectIndexBuilder::<_, ()>::new()
-                                        .with_changed_by(access_token.account_tenant_ids())
-                                        .with_current(prev_message_data),
-                                )
-                                .caused_by(trc::location!())?
-                                .schedule_task(Task::UnindexDocument(TaskIndexDocument {
-                                    account_id: account_id.into(),
-                                    document_id: message_id.into(),
-                                    document_type: IndexDocumentType::Email,
-                                    status: TaskStatus::now(),
-                                }))
-                                .commit_point();
-                        } else {
-                            let new_message_data = MessageData {
-                                mailboxes: prev_message_data
-                                    .inner
-                                    .mailboxes
-                                    .iter()
-                                    .filter(|m| m.mailbox_id != document_id)
-                                    .map(|m| m.to_native())
-                                    .collect(),
-                                keywords: prev_message_data
-                                    .inner
-                                    .keywords
-                                    .iter()
-                                    .map(|k| k.to_native())
-                                    .collect(),
-                                thread_id: prev_message_data.inner.thread_id.to_native(),
-                                size: prev_message_data.inner.size.to_native(),
-                            };
-
-                            // Untag message from mailbox
-                            batch
-                                .with_collection(Collection::Email)
-                                .with_document(message_id)
-                                .custom(
-                                    ObjectIndexBuilder::new()
-                                        .with_changed_by(access_token.account_tenant_ids())
-                                        .with_changes(new_message_data)
-                                        .with_current(prev_message_data),
-                                )
-                                .caused_by(trc::location!())?
-                                .commit_point();
+                    if new_message_data.mailboxes.is_empty() {
+                        // Delete message
+                        for mailbox in prev_message_data.inner.mailboxes.iter() {
+                            batch.log_vanished_item(
+                                VanishedCollection::Email,
+                                (mailbox.mailbox_id.to_native(), mailbox.uid.to_native()),
+                            );
                         }
+                        batch
+                            .with_collection(Collection::Email)
+                            .with_document(message_id)
+                            .custom(
+                                ObjectIndexBuilder::<_, ()>::new()
+                                    .with_changed_by(access_token.account_tenant_ids())
+                                    .with_current(prev_message_data),
+                            )
+                            .caused_by(trc::location!())?
+                            .schedule_task(Task::UnindexDocument(TaskIndexDocument {
+                                account_id: account_id.into(),
+                                document_id: message_id.into(),
+                                document_type: IndexDocumentType::Email,
+                                status: TaskStatus::now(),
+                            }))
+                            .commit_point();
+                    } else {
+                        // Untag message from mailboxes
+                        batch
+                            .with_collection(Collection::Email)
+                            .with_document(message_id)
+                            .custom(
+                                ObjectIndexBuilder::new()
+                                    .with_changed_by(access_token.account_tenant_ids())
+                                    .with_changes(new_message_data.seal())
+                                    .with_current(prev_message_data),
+                            )
+                            .caused_by(trc::location!())?
+                            .commit_point();
+                    }
 
-                        Ok(true)
-                    },
-                )
-                .await
-                .caused_by(trc::location!())?;
-            } else {
-                return Ok(Err(MailboxDestroyError::HasEmails));
-            }
+                    Ok(true)
+                },
+            )
+            .await
+            .caused_by(trc::location!())?;
+        }
+        if is_stale {
+            return Ok(Err((first_id, MailboxDestroyError::AssertionFailed)));
         }
 
-        // Obtain mailbox
-        if let Some(mailbox_) = self
-            .store()
-            .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
-                account_id,
-                Collection::Mailbox,
-                document_id,
-            ))
-            .await
-            .caused_by(trc::location!())?
-        {
-            let mailbox = mailbox_
-                .to_unarchived::<Mailbox>()
-                .caused_by(trc::location!())?;
-            // Validate ACLs
-            if access_token.is_shared(account_id) {
-                let acl = mailbox.inner.acls.effective_acl(access_token);
-                if !acl.contains(Acl::Delete) || (remove_emails && !acl.contains(Acl::RemoveItems))
-                {
-                    return Ok(Err(MailboxDestroyError::Forbidden));
-                }
-            }
+        // Delete mailboxes
+        for (document_id, mailbox_) in &mailboxes {
             batch
                 .with_account_id(account_id)
                 .with_collection(Collection::Mailbox)
-                .with_document(document_id)
+                .with_document(*document_id)
                 .clear(MailboxField::UidCounter)
-                .custom(ObjectIndexBuilder::<_, ()>::new().with_current(mailbox))
-                .caused_by(trc::location!())?;
-        } else {
-            return Ok(Err(MailboxDestroyError::NotFound));
-        };
+                .clear(MailboxField::ImapSieveScript)
+                .custom(
+                    ObjectIndexBuilder::<_, ()>::new().with_current(
+                        mailbox_
+                            .to_unarchived::<Mailbox>()
+                            .caused_by(trc::location!())?,
+                    ),
+                )
+                .caused_by(trc::location!())?
+                .commit_point();
+        }
 
-        if !batch.is_empty() {
-            match self
-                .commit_batch(batch)
-                .await
-                .and_then(|ids| ids.last_change_id(account_id))
-            {
-                Ok(change_id) => {
-                    self.notify_task_queue();
+        match self
+            .commit_batch(batch)
+            .await
+            .and_then(|ids| ids.last_change_id(account_id))
+        {
+            Ok(change_id) => {
+                self.notify_task_queue();
 
-                    Ok(Ok(Some(change_id)))
-                }
-                Err(err) if err.is_assertion_failure() => {
-                    Ok(Err(MailboxDestroyError::AssertionFailed))
-                }
-                Err(err) => Err(err.caused_by(trc::location!())),
+                Ok(Ok(Some(change_id)))
+            }
+            Err(err) if err.is_assertion_failure() => {
+                Ok(Err((first_id, MailboxDestroyError::AssertionFailed)))
             }
-        } else {
-            Ok(Ok(None))
+            Err(err) => Err(err.caused_by(trc::location!())),
         }
     }
 }
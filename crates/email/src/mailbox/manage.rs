@@ -5,7 +5,7 @@
  */
 
 use super::*;
-use crate::cache::MessageCacheFetch;
+use crate::cache::{MessageCacheFetch, mailbox::MailboxCacheAccess};
 use common::{Server, storage::index::ObjectIndexBuilder};
 use std::future::Future;
 use store::write::BatchBuilder;
@@ -22,6 +22,7 @@ pub trait MailboxFnc: Sync + Send {
         &self,
         account_id: u32,
         path: &str,
+        role: Option<SpecialUse>,
     ) -> impl Future<Output = trc::Result<Option<u32>>> + Send;
 }
 
@@ -32,6 +33,24 @@ impl MailboxFnc for Server {
             return Ok(());
         }
 
+        // Domains may override the default folders, names are localized to the account's locale
+        let account = self
+            .try_account(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let domain = if let Some(address) = account.as_ref().and_then(|a| a.addresses.first()) {
+            self.domain_by_id(address.domain_id)
+                .await
+                .caused_by(trc::location!())?
+        } else {
+            None
+        };
+        let default_folders = domain
+            .as_ref()
+            .and_then(|domain| domain.default_folders.as_deref())
+            .unwrap_or(self.core.email.default_folders.as_slice());
+        let locale = account.map(|a| a.locale).unwrap_or_default();
+
         let mut batch = BatchBuilder::new();
         batch
             .with_account_id(account_id)
@@ -39,7 +58,7 @@ impl MailboxFnc for Server {
 
         // Create mailboxes
         let mut last_document_id = ARCHIVE_ID;
-        for folder in &self.core.email.default_folders {
+        for folder in default_folders {
             let document_id = match folder.special_use {
                 SpecialUse::Inbox => INBOX_ID,
                 SpecialUse::Trash => TRASH_ID,
@@ -51,14 +70,17 @@ impl MailboxFnc for Server {
                 | SpecialUse::Important
                 | SpecialUse::Memos
                 | SpecialUse::Scheduled
-                | SpecialUse::Snoozed => {
+                | SpecialUse::Snoozed
+                | SpecialUse::All
+                | SpecialUse::Flagged => {
                     last_document_id += 1;
                     last_document_id
                 }
                 SpecialUse::Shared => unreachable!(),
             };
 
-            let mut object = Mailbox::new(folder.name.clone()).with_role(folder.special_use);
+            let mut object =
+                Mailbox::new(folder.localized_name(locale)).with_role(folder.special_use);
             if folder.subscribe {
                 object.add_subscriber(account_id);
             }
@@ -82,7 +104,12 @@ impl MailboxFnc for Server {
         Ok(())
     }
 
-    async fn mailbox_create_path(&self, account_id: u32, path: &str) -> trc::Result<Option<u32>> {
+    async fn mailbox_create_path(
+        &self,
+        account_id: u32,
+        path: &str,
+        role: Option<SpecialUse>,
+    ) -> trc::Result<Option<u32>> {
         let cache = self
             .get_cached_messages(account_id)
             .await
@@ -134,18 +161,27 @@ impl MailboxFnc for Server {
                 .assign_document_ids(account_id, Collection::Mailbox, create_paths.len() as u64)
                 .await
                 .caused_by(trc::location!())?;
+            // Roles are unique, assign it to the new mailbox only if unused
+            let mut role = role.filter(|role| cache.mailbox_by_role(role).is_none());
             let mut batch = BatchBuilder::new();
-            for name in create_paths {
+            let last_idx = create_paths.len() - 1;
+            for (idx, name) in create_paths.into_iter().enumerate() {
                 let document_id = next_document_id;
                 next_document_id -= 1;
+                let mut mailbox = Mailbox::new(name).with_parent_id(next_parent_id);
+                if self.core.email.subscribe_on_create.auto {
+                    mailbox.add_subscriber(account_id);
+                }
+                if idx == last_idx
+                    && let Some(role) = role.take()
+                {
+                    mailbox = mailbox.with_role(role);
+                }
                 batch
                     .with_account_id(account_id)
                     .with_collection(Collection::Mailbox)
                     .with_document(document_id)
-                    .custom(
-                        ObjectIndexBuilder::<(), _>::new()
-                            .with_changes(Mailbox::new(name).with_parent_id(next_parent_id)),
-                    )
+                    .custom(ObjectIndexBuilder::<(), _>::new().with_changes(mailbox))
                     .caused_by(trc::location!())?;
                 next_parent_id = document_id + 1;
             }
@@ -9,6 +9,7 @@ use types::{acl::AclGrant, special_use::SpecialUse};
 pub mod destroy;
 pub mod index;
 pub mod manage;
+pub mod virtual_folder;
 
 pub const INBOX_ID: u32 = 0;
 pub const TRASH_ID: u32 = 1;
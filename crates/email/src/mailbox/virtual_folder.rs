@@ -0,0 +1,348 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{INBOX_ID, JUNK_ID, TRASH_ID};
+use crate::{
+    cache::{email::MessageCacheAccess, mailbox::MailboxCacheAccess},
+    message::metadata::{MESSAGE_RECEIVED_MASK, MessageMetadata},
+};
+use common::{
+    MessageCache, MessageStoreCache, Server,
+    auth::DOMAIN_FLAG_VIRTUAL_FOLDERS,
+    config::mailstore::email::{MAX_SEARCH_FOLDERS, SearchFolder, VirtualFolder},
+};
+use registry::schema::enums::VirtualFolderType;
+use std::future::Future;
+use store::{
+    Serialize, ValueKey,
+    write::{AlignedBytes, Archive, Archiver, BatchBuilder, now},
+};
+use trc::AddContext;
+use types::{
+    collection::Collection,
+    field::{EmailField, MailboxField},
+    keyword::Keyword,
+    special_use::SpecialUse,
+};
+use utils::snowflake::SnowflakeIdGenerator;
+
+// Virtual mailboxes use reserved document ids at the top of the id space
+pub const ALL_MAIL_ID: u32 = u32::MAX - 1;
+pub const FLAGGED_ID: u32 = u32::MAX - 2;
+pub const RECENT_ID: u32 = u32::MAX - 3;
+
+// Saved search mailboxes are assigned the ids right below the virtual folders
+pub const SEARCH_FOLDER_ID: u32 = u32::MAX - 4;
+pub const SEARCH_FOLDER_MIN_ID: u32 = SEARCH_FOLDER_ID + 1 - MAX_SEARCH_FOLDERS as u32;
+
+// UIDs are assigned from a per-mailbox counter the first time a message is seen in a
+// virtual mailbox, messages that leave the mailbox and come back receive a new UID
+#[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Clone, PartialEq, Eq)]
+pub struct VirtualUids {
+    pub uid_next: u32,
+    pub uids: Vec<VirtualUid>,
+}
+
+#[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VirtualUid {
+    pub document_id: u32,
+    pub uid: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VirtualMailbox {
+    All,
+    Flagged,
+    Recent { since: u64 },
+}
+
+pub trait VirtualMailboxes: Sync + Send {
+    fn virtual_folders(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<&[VirtualFolder]>> + Send;
+
+    fn search_folders(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<&[SearchFolder]>> + Send;
+
+    fn has_virtual_folders(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+
+    fn virtual_mailbox(&self, mailbox_id: u32) -> Option<VirtualMailbox>;
+
+    fn virtual_mailbox_messages<'x>(
+        &self,
+        account_id: u32,
+        mailbox: VirtualMailbox,
+        cache: &'x MessageStoreCache,
+    ) -> impl Future<Output = trc::Result<Vec<&'x MessageCache>>> + Send;
+
+    fn virtual_mailbox_uids(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        document_ids: Vec<u32>,
+    ) -> impl Future<Output = trc::Result<VirtualUids>> + Send;
+
+    fn search_folder(&self, mailbox_id: u32) -> Option<&SearchFolder>;
+}
+
+impl VirtualMailboxes for Server {
+    async fn virtual_folders(&self, account_id: u32) -> trc::Result<&[VirtualFolder]> {
+        let folders = self.core.email.virtual_folders.as_slice();
+        if !folders.is_empty() && self.has_virtual_folders(account_id).await? {
+            Ok(folders)
+        } else {
+            Ok(&[])
+        }
+    }
+
+    async fn search_folders(&self, account_id: u32) -> trc::Result<&[SearchFolder]> {
+        let folders = self.core.email.search_folders.as_slice();
+        if !folders.is_empty() && self.has_virtual_folders(account_id).await? {
+            Ok(folders)
+        } else {
+            Ok(&[])
+        }
+    }
+
+    async fn has_virtual_folders(&self, account_id: u32) -> trc::Result<bool> {
+        // Virtual folders are only shown to accounts whose primary domain enables them
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        if let Some(address) = account.addresses.first()
+            && let Some(domain) = self
+                .domain_by_id(address.domain_id)
+                .await
+                .caused_by(trc::location!())?
+        {
+            Ok(domain.flags & DOMAIN_FLAG_VIRTUAL_FOLDERS != 0)
+        } else {
+            Ok(true)
+        }
+    }
+
+    fn virtual_mailbox(&self, mailbox_id: u32) -> Option<VirtualMailbox> {
+        let folder_type = match mailbox_id {
+            ALL_MAIL_ID => VirtualFolderType::All,
+            FLAGGED_ID => VirtualFolderType::Flagged,
+            RECENT_ID => VirtualFolderType::Recent,
+            _ => return None,
+        };
+
+        self.core
+            .email
+            .virtual_folders
+            .iter()
+            .any(|f| f.folder_type == folder_type)
+            .then(|| VirtualMailbox::new(folder_type, &self.core.email.virtual_recent_period))
+    }
+
+    async fn virtual_mailbox_messages<'x>(
+        &self,
+        account_id: u32,
+        mailbox: VirtualMailbox,
+        cache: &'x MessageStoreCache,
+    ) -> trc::Result<Vec<&'x MessageCache>> {
+        let VirtualMailbox::Recent { since } = mailbox else {
+            return Ok(cache
+                .emails
+                .items
+                .iter()
+                .filter(|m| mailbox.contains(cache, m))
+                .collect());
+        };
+
+        // A message received during the period was also last modified during it,
+        // so the received date is only read for messages past that change id
+        let since_change_id = SnowflakeIdGenerator::from_timestamp(since).unwrap_or_default();
+        let mut messages = Vec::new();
+        for message in cache
+            .emails
+            .items
+            .iter()
+            .filter(|m| m.change_id >= since_change_id && mailbox.contains(cache, m))
+        {
+            if let Some(metadata) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                    account_id,
+                    Collection::Email,
+                    message.document_id,
+                    EmailField::Metadata,
+                ))
+                .await
+                .caused_by(trc::location!())?
+                && metadata
+                    .unarchive::<MessageMetadata>()
+                    .caused_by(trc::location!())?
+                    .rcvd_attach
+                    .to_native()
+                    & MESSAGE_RECEIVED_MASK
+                    >= since
+            {
+                messages.push(message);
+            }
+        }
+
+        Ok(messages)
+    }
+
+    async fn virtual_mailbox_uids(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        mut document_ids: Vec<u32>,
+    ) -> trc::Result<VirtualUids> {
+        document_ids.sort_unstable();
+        let mut retry_count = 0;
+
+        loop {
+            let current = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                    account_id,
+                    Collection::Mailbox,
+                    mailbox_id,
+                    MailboxField::VirtualUids,
+                ))
+                .await
+                .caused_by(trc::location!())?;
+            let previous = if let Some(current) = &current {
+                current
+                    .deserialize::<VirtualUids>()
+                    .caused_by(trc::location!())?
+            } else {
+                VirtualUids {
+                    uid_next: 1,
+                    uids: Vec::new(),
+                }
+            };
+
+            // Keep the UIDs of messages still in the mailbox, UIDs of removed messages are dropped
+            let mut uid_next = previous.uid_next;
+            let uids = document_ids
+                .iter()
+                .map(|&document_id| {
+                    let uid = match previous
+                        .uids
+                        .binary_search_by_key(&document_id, |uid| uid.document_id)
+                    {
+                        Ok(idx) => previous.uids[idx].uid,
+                        Err(_) => {
+                            uid_next += 1;
+                            uid_next - 1
+                        }
+                    };
+                    VirtualUid { document_id, uid }
+                })
+                .collect();
+            let updated = VirtualUids { uid_next, uids };
+            if updated == previous {
+                return Ok(updated);
+            }
+
+            let mut batch = BatchBuilder::new();
+            batch
+                .with_account_id(account_id)
+                .with_collection(Collection::Mailbox)
+                .with_document(mailbox_id);
+            if let Some(current) = &current {
+                batch.assert_value(MailboxField::VirtualUids, current);
+            } else {
+                batch.assert_value(MailboxField::VirtualUids, ());
+            }
+            let updated = Archiver::new(updated);
+            batch.set(
+                MailboxField::VirtualUids,
+                updated.serialize().caused_by(trc::location!())?,
+            );
+            match self.store().write(batch.build_all()).await {
+                Ok(_) => return Ok(updated.into_inner()),
+                Err(err) if err.is_assertion_failure() && retry_count < 3 => {
+                    retry_count += 1;
+                }
+                Err(err) => return Err(err.caused_by(trc::location!())),
+            }
+        }
+    }
+
+    fn search_folder(&self, mailbox_id: u32) -> Option<&SearchFolder> {
+        if (SEARCH_FOLDER_MIN_ID..=SEARCH_FOLDER_ID).contains(&mailbox_id) {
+            self.core
+                .email
+                .search_folders
+                .get((SEARCH_FOLDER_ID - mailbox_id) as usize)
+        } else {
+            None
+        }
+    }
+}
+
+impl VirtualMailbox {
+    pub fn new(folder_type: VirtualFolderType, recent_period: &std::time::Duration) -> Self {
+        match folder_type {
+            VirtualFolderType::All => VirtualMailbox::All,
+            VirtualFolderType::Flagged => VirtualMailbox::Flagged,
+            VirtualFolderType::Recent => VirtualMailbox::Recent {
+                since: now().saturating_sub(recent_period.as_secs()),
+            },
+        }
+    }
+
+    pub fn id(&self) -> u32 {
+        match self {
+            VirtualMailbox::All => ALL_MAIL_ID,
+            VirtualMailbox::Flagged => FLAGGED_ID,
+            VirtualMailbox::Recent { .. } => RECENT_ID,
+        }
+    }
+
+    pub fn role(&self) -> SpecialUse {
+        match self {
+            VirtualMailbox::All => SpecialUse::All,
+            VirtualMailbox::Flagged => SpecialUse::Flagged,
+            VirtualMailbox::Recent { .. } => SpecialUse::None,
+        }
+    }
+
+    // The received date of Recent messages is checked by virtual_mailbox_messages
+    fn contains(&self, cache: &MessageStoreCache, message: &MessageCache) -> bool {
+        // Messages that only live in Trash or Junk are never part of a virtual mailbox
+        message
+            .mailboxes
+            .iter()
+            .any(|m| m.mailbox_id != TRASH_ID && m.mailbox_id != JUNK_ID)
+            && match self {
+                VirtualMailbox::All => true,
+                VirtualMailbox::Flagged => cache.has_keyword(message, &Keyword::Flagged),
+                VirtualMailbox::Recent { .. } => true,
+            }
+    }
+}
+
+#[inline(always)]
+pub fn is_virtual_mailbox(mailbox_id: u32) -> bool {
+    (SEARCH_FOLDER_MIN_ID..=ALL_MAIL_ID).contains(&mailbox_id)
+}
+
+#[inline(always)]
+pub fn search_folder_id(idx: usize) -> u32 {
+    SEARCH_FOLDER_ID - idx as u32
+}
+
+// The UID counters of virtual mailboxes are only reset along with the account's Inbox
+pub fn virtual_uid_validity(cache: &MessageStoreCache, mailbox_id: u32) -> u32 {
+    cache
+        .mailbox_by_id(&INBOX_ID)
+        .map_or(mailbox_id, |inbox| {
+            inbox.uid_validity.wrapping_add(u32::MAX - mailbox_id)
+        })
+        .max(1)
+}
@@ -0,0 +1,242 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::metadata::{MESSAGE_RECEIVED_MASK, MessageData, MessageMetadata};
+use crate::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
+    mailbox::{INBOX_ID, UidMailbox, manage::MailboxFnc},
+};
+use common::{Server, storage::index::ObjectIndexBuilder};
+use mail_parser::DateTime;
+use registry::schema::{
+    enums::MessageAuditEvent,
+    structs::{Account, Domain},
+};
+use std::{collections::BTreeMap, future::Future, time::Instant};
+use store::{
+    roaring::RoaringBitmap,
+    write::{BatchBuilder, now},
+};
+use trc::AddContext;
+use types::{
+    collection::{Collection, VanishedCollection},
+    field::{EmailField, MailboxField},
+    special_use::SpecialUse,
+};
+
+pub const ARCHIVE_FOLDER_NAME: &str = "Archive";
+
+pub trait EmailAutoArchive: Sync + Send {
+    fn emails_auto_archive(&self, account_id: u32) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn emails_archive_to(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        document_ids: &RoaringBitmap,
+    ) -> impl Future<Output = trc::Result<RoaringBitmap>> + Send;
+}
+
+impl EmailAutoArchive for Server {
+    async fn emails_auto_archive(&self, account_id: u32) -> trc::Result<()> {
+        // The account setting takes precedence over the domain default,
+        // a zero period disables auto-archiving for the account.
+        let Some(Account::User(account)) = self
+            .registry()
+            .object::<Account>(account_id.into())
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Ok(());
+        };
+        let archive_after = if let Some(after) = account.auto_archive_after {
+            after.as_secs()
+        } else {
+            self.registry()
+                .object::<Domain>(account.domain_id)
+                .await
+                .caused_by(trc::location!())?
+                .and_then(|domain| domain.auto_archive_after)
+                .map_or(0, |after| after.as_secs())
+        };
+        if archive_after == 0 {
+            return Ok(());
+        }
+
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let inbox_ids = cache
+            .in_mailbox(INBOX_ID)
+            .map(|message| message.document_id)
+            .collect::<RoaringBitmap>();
+        if inbox_ids.is_empty() {
+            return Ok(());
+        }
+
+        // Group old messages by the year they were received in
+        let op_start = Instant::now();
+        let cutoff = now().saturating_sub(archive_after);
+        let mut years: BTreeMap<u16, RoaringBitmap> = BTreeMap::new();
+        self.all_archives(
+            account_id,
+            Collection::Email,
+            EmailField::Metadata.into(),
+            |document_id, archive| {
+                if inbox_ids.contains(document_id) {
+                    let metadata = archive.unarchive::<MessageMetadata>()?;
+                    let received_at = metadata.rcvd_attach.to_native() & MESSAGE_RECEIVED_MASK;
+                    if received_at < cutoff {
+                        years
+                            .entry(DateTime::from_timestamp(received_at as i64).year)
+                            .or_default()
+                            .insert(document_id);
+                    }
+                }
+
+                Ok(())
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+        if years.is_empty() {
+            return Ok(());
+        }
+
+        // Messages are filed under the Archive folder, which is created
+        // if the account does not have one.
+        let archive_path = if let Some(mailbox) = cache.mailbox_by_role(&SpecialUse::Archive) {
+            mailbox.path.to_string()
+        } else if self
+            .mailbox_create_path(account_id, ARCHIVE_FOLDER_NAME, Some(SpecialUse::Archive))
+            .await
+            .caused_by(trc::location!())?
+            .is_some()
+        {
+            ARCHIVE_FOLDER_NAME.to_string()
+        } else {
+            return Ok(());
+        };
+
+        let mut archived_ids = RoaringBitmap::new();
+        for (year, document_ids) in years {
+            let Some(mailbox_id) = self
+                .mailbox_create_path(account_id, &format!("{archive_path}/{year}"), None)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+            archived_ids |= self
+                .emails_archive_to(account_id, mailbox_id, &document_ids)
+                .await
+                .caused_by(trc::location!())?;
+        }
+        if archived_ids.is_empty() {
+            return Ok(());
+        }
+
+        trc::event!(
+            Store(trc::StoreEvent::AutoArchive),
+            AccountId = account_id,
+            Total = archived_ids.len(),
+            Elapsed = op_start.elapsed(),
+        );
+
+        // Record audit trail
+        self.audit_messages(
+            account_id,
+            MessageAuditEvent::Moved,
+            archived_ids,
+            None,
+            "auto-archive".to_string().into(),
+        )
+        .await;
+
+        Ok(())
+    }
+
+    // Moves messages from the Inbox to the given mailbox, messages that were
+    // moved or deleted in the meantime are skipped.
+    async fn emails_archive_to(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        document_ids: &RoaringBitmap,
+    ) -> trc::Result<RoaringBitmap> {
+        // Reserve the UIDs for all moved messages at once
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox)
+            .with_document(mailbox_id)
+            .add_and_get(MailboxField::UidCounter, document_ids.len() as i64);
+        let last_uid = self
+            .store()
+            .write(batch.build_all())
+            .await
+            .and_then(|ids| ids.last_counter_id())
+            .caused_by(trc::location!())? as u32;
+        let mut uids = (last_uid + 1 - document_ids.len() as u32)..=last_uid;
+
+        let mut archived_ids = RoaringBitmap::new();
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Email);
+        self.archives(
+            account_id,
+            Collection::Email,
+            document_ids,
+            |document_id, data_| {
+                let data = data_
+                    .to_unarchived::<MessageData>()
+                    .caused_by(trc::location!())?;
+                let Some(inbox_uid) = data
+                    .inner
+                    .mailboxes
+                    .iter()
+                    .find(|mailbox| mailbox.mailbox_id == INBOX_ID)
+                    .map(|mailbox| mailbox.uid.to_native())
+                else {
+                    return Ok(true);
+                };
+                let Some(uid) = uids.next() else {
+                    return Ok(false);
+                };
+
+                let is_filed = data.inner.has_mailbox_id(mailbox_id);
+                let mut new_data = data.inner.to_builder();
+                new_data.remove_mailbox(INBOX_ID);
+                if !is_filed {
+                    new_data.add_mailbox(UidMailbox::new(mailbox_id, uid));
+                }
+                batch
+                    .with_document(document_id)
+                    .custom(
+                        ObjectIndexBuilder::new()
+                            .with_current(data)
+                            .with_changes(new_data.seal()),
+                    )
+                    .caused_by(trc::location!())?
+                    .log_vanished_item(VanishedCollection::Email, (INBOX_ID, inbox_uid))
+                    .commit_point();
+                archived_ids.insert(document_id);
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        if !archived_ids.is_empty() {
+            self.commit_batch(batch).await.caused_by(trc::location!())?;
+        }
+
+        Ok(archived_ids)
+    }
+}
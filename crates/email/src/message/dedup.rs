@@ -0,0 +1,105 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{KV_DELIVERY_DEDUP, Server};
+use registry::schema::enums::DuplicateMatch;
+use std::future::Future;
+use store::dispatch::lookup::KeyValue;
+use trc::AddContext;
+use types::blob_hash::{BLOB_HASH_LEN, BlobHash};
+
+const DEDUP_MESSAGE_ID: u8 = 0;
+const DEDUP_CONTENT: u8 = 1;
+
+// Keys identifying a delivery to a mailbox, a message is a duplicate if any
+// of its keys was recorded within the configured window.
+pub struct DeliveryDedup {
+    keys: Vec<Vec<u8>>,
+    expires: u64,
+}
+
+pub trait DeliveryDedupStore: Sync + Send {
+    fn delivery_dedup(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        message_id: Option<&str>,
+        raw_message: &[u8],
+    ) -> Option<DeliveryDedup>;
+
+    fn is_duplicate_delivery(
+        &self,
+        dedup: &DeliveryDedup,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+
+    fn record_delivery(&self, dedup: DeliveryDedup)
+    -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+impl DeliveryDedupStore for Server {
+    fn delivery_dedup(
+        &self,
+        account_id: u32,
+        mailbox_id: u32,
+        message_id: Option<&str>,
+        raw_message: &[u8],
+    ) -> Option<DeliveryDedup> {
+        let window = self.core.email.duplicate_window?;
+        let mut keys = Vec::with_capacity(2);
+        let mut prefix = Vec::with_capacity((2 * std::mem::size_of::<u32>()) + 1 + BLOB_HASH_LEN);
+        prefix.extend_from_slice(&account_id.to_be_bytes());
+        prefix.extend_from_slice(&mailbox_id.to_be_bytes());
+
+        let match_ = self.core.email.duplicate_match;
+        if matches!(match_, DuplicateMatch::Any | DuplicateMatch::MessageId)
+            && let Some(message_id) = message_id
+        {
+            let mut key = prefix.clone();
+            key.push(DEDUP_MESSAGE_ID);
+            key.extend_from_slice(BlobHash::generate(message_id.as_bytes()).as_slice());
+            keys.push(key);
+        }
+        if matches!(match_, DuplicateMatch::Any | DuplicateMatch::Content) {
+            let mut key = prefix;
+            key.push(DEDUP_CONTENT);
+            key.extend_from_slice(BlobHash::generate(raw_message).as_slice());
+            keys.push(key);
+        }
+
+        (!keys.is_empty()).then(|| DeliveryDedup {
+            keys,
+            expires: window.as_secs().max(1),
+        })
+    }
+
+    async fn is_duplicate_delivery(&self, dedup: &DeliveryDedup) -> trc::Result<bool> {
+        for key in &dedup.keys {
+            if self
+                .in_memory_store()
+                .key_exists(KeyValue::<()>::build_key(KV_DELIVERY_DEDUP, key))
+                .await
+                .caused_by(trc::location!())?
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn record_delivery(&self, dedup: DeliveryDedup) -> trc::Result<()> {
+        for key in dedup.keys {
+            self.in_memory_store()
+                .key_set(
+                    KeyValue::with_prefix(KV_DELIVERY_DEDUP, key, vec![]).expires(dedup.expires),
+                )
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        Ok(())
+    }
+}
@@ -4,10 +4,10 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::metadata::MessageData;
+use super::{archive::EmailAutoArchive, drafts::DraftsPolicy, metadata::MessageData};
 use common::{Server, storage::index::ObjectIndexBuilder};
 use groupware::calendar::storage::ItipAutoExpunge;
-use registry::schema::enums::IndexDocumentType;
+use registry::schema::enums::{IndexDocumentType, MessageAuditEvent};
 use registry::schema::structs::{Task, TaskIndexDocument, TaskStatus};
 use std::future::Future;
 use store::write::key::DeserializeBigEndian;
@@ -120,6 +120,16 @@ impl EmailDeletion for Server {
                 .caused_by(trc::location!())?;
         }
 
+        // Move old Inbox messages to the yearly Archive folders
+        self.emails_auto_archive(account_id)
+            .await
+            .caused_by(trc::location!())?;
+
+        // Remove orphaned drafts and enforce drafts limits
+        self.drafts_cleanup(account_id)
+            .await
+            .caused_by(trc::location!())?;
+
         // Delete old e-mail submissions
         if let Some(hold_period) = self.core.email.email_submission_autoexpunge_after {
             self.purge_email_submissions(account_id, hold_period)
@@ -190,11 +200,26 @@ impl EmailDeletion for Server {
             .await
             .caused_by(trc::location!())?
             .tenant_id();
-        self.emails_delete(account_id, tenant_id, &mut batch, destroy_ids)
+        let audit_ids = self.is_audit_log_enabled().then(|| destroy_ids.clone());
+        let not_destroyed = self
+            .emails_delete(account_id, tenant_id, &mut batch, destroy_ids)
             .await?;
         self.commit_batch(batch).await?;
         self.notify_task_queue();
 
+        // Record audit trail
+        if let Some(mut audit_ids) = audit_ids {
+            audit_ids -= not_destroyed;
+            self.audit_messages(
+                account_id,
+                MessageAuditEvent::Expunged,
+                audit_ids,
+                None,
+                "auto-expunge".to_string().into(),
+            )
+            .await;
+        }
+
         Ok(())
     }
 
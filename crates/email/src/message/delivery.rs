@@ -4,18 +4,30 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::ingest::{EmailIngest, IngestEmail, IngestSource};
-use crate::{mailbox::INBOX_ID, sieve::ingest::SieveScriptIngest};
+use super::{
+    ingest::{EmailIngest, IngestEmail, IngestSource, IngestedEmail},
+    quarantine::SpamQuarantine,
+};
+use crate::{
+    mailbox::INBOX_ID,
+    sieve::ingest::{SieveIngestDefaults, SieveScriptIngest},
+};
 use common::{
     Server,
-    auth::BuildAccessToken,
+    auth::{AccessToken, BuildAccessToken},
+    config::mailstore::spamfilter::SpamFilterAction,
     ipc::{EmailPush, PushNotification},
 };
 use mail_parser::MessageParser;
 use registry::schema::enums::Permission;
+use spam_filter::{
+    SpamFilterInput,
+    analysis::{init::SpamFilterInit, score::SpamFilterAnalyzeScore},
+};
 use std::{borrow::Cow, future::Future};
 use store::ahash::AHashMap;
-use types::blob_hash::BlobHash;
+use trc::AddContext;
+use types::{blob_hash::BlobHash, keyword::Keyword};
 
 #[derive(Debug)]
 pub struct IngestMessage {
@@ -32,6 +44,7 @@ pub struct IngestRecipient {
     pub address: String,
     pub orcpt: Option<String>,
     pub is_spam: bool,
+    pub is_quarantined: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -57,11 +70,28 @@ pub struct AutogeneratedMessage {
     pub message: Vec<u8>,
 }
 
+// A message added by the account owner to its own Inbox that is filtered
+// as if it had been delivered.
+pub struct AppendedMessage<'x> {
+    pub access_token: &'x AccessToken,
+    pub raw_message: &'x [u8],
+    pub keywords: Vec<Keyword>,
+    pub received_at: Option<u64>,
+    pub run_sieve: bool,
+    pub run_spam_filter: bool,
+    pub session_id: u64,
+}
+
 pub trait MailDelivery: Sync + Send {
     fn deliver_message(
         &self,
         message: IngestMessage,
     ) -> impl Future<Output = LocalDeliveryResult> + Send;
+
+    fn deliver_appended_message(
+        &self,
+        message: AppendedMessage<'_>,
+    ) -> impl Future<Output = trc::Result<Option<IngestedEmail>>> + Send;
 }
 
 impl MailDelivery for Server {
@@ -156,6 +186,20 @@ impl MailDelivery for Server {
                     .build()
                     .assert_has_permission(Permission::EmailReceive)
             }) {
+                Ok(_) if rcpt.is_quarantined && self.core.spam.quarantine.is_some() => {
+                    // Hold the message until the recipient releases it
+                    self.quarantine_message(
+                        account_id,
+                        &message.message_blob,
+                        &raw_message,
+                        message.session_id,
+                    )
+                    .await
+                    .map(|_| IngestedEmail {
+                        change_id: u64::MAX,
+                        ..Default::default()
+                    })
+                }
                 Ok(access_token) => {
                     // Check if there is an active sieve script
                     match self.sieve_script_get_active(account_id).await {
@@ -181,13 +225,14 @@ impl MailDelivery for Server {
                         Ok(Some(active_script)) => {
                             self.sieve_script_ingest(
                                 &access_token,
-                                &message.message_blob,
+                                Some(&message.message_blob),
                                 &raw_message,
                                 &message.sender_address,
                                 message.sender_authenticated,
                                 &rcpt,
                                 message.session_id,
                                 active_script,
+                                SieveIngestDefaults::default(),
                                 &mut result.autogenerated,
                             )
                             .await
@@ -269,4 +314,90 @@ impl MailDelivery for Server {
 
         result
     }
+
+    // Returns None when no filtering applies and the message should be
+    // appended as requested.
+    async fn deliver_appended_message(
+        &self,
+        message: AppendedMessage<'_>,
+    ) -> trc::Result<Option<IngestedEmail>> {
+        let account_id = message.access_token.account_id();
+        let active_script = if message.run_sieve {
+            self.sieve_script_get_active(account_id)
+                .await
+                .caused_by(trc::location!())?
+        } else {
+            None
+        };
+        let run_spam_filter = message.run_spam_filter && self.core.spam.enabled;
+        if active_script.is_none() && !run_spam_filter {
+            return Ok(None);
+        }
+
+        let Some(parsed_message) = MessageParser::new().parse(message.raw_message) else {
+            return Ok(None);
+        };
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        let deliver_to = account.name().to_string();
+
+        // Classify the message, rejected or discarded messages are filed as spam
+        let is_spam = if run_spam_filter {
+            let mut ctx = self.spam_filter_init(SpamFilterInput {
+                env_rcpt_orig_to: vec![deliver_to.as_str()],
+                env_rcpt_rewritten_to: vec![deliver_to.as_str()],
+                ..SpamFilterInput::from_message(&parsed_message, message.session_id)
+            });
+            match self.spam_filter_classify(&mut ctx).await {
+                SpamFilterAction::Allow(score) => score.is_spam,
+                SpamFilterAction::Discard | SpamFilterAction::Reject => true,
+                SpamFilterAction::Disabled => false,
+            }
+        } else {
+            false
+        };
+
+        if let Some(active_script) = active_script {
+            // Replies and redirects generated by the script are not sent
+            self.sieve_script_ingest(
+                message.access_token,
+                None,
+                message.raw_message,
+                "",
+                false,
+                &IngestRecipient {
+                    address: deliver_to,
+                    orcpt: None,
+                    is_spam,
+                    is_quarantined: false,
+                },
+                message.session_id,
+                active_script,
+                SieveIngestDefaults {
+                    keywords: message.keywords,
+                    received_at: message.received_at,
+                },
+                &mut Vec::new(),
+            )
+            .await
+            .map(Some)
+        } else {
+            self.email_ingest(IngestEmail {
+                raw_message: message.raw_message,
+                blob_hash: None,
+                message: parsed_message.into(),
+                access_token: message.access_token,
+                mailbox_ids: vec![INBOX_ID],
+                keywords: message.keywords,
+                received_at: message.received_at,
+                source: IngestSource::Smtp {
+                    deliver_to: &deliver_to,
+                    is_sender_authenticated: false,
+                    is_spam,
+                },
+                session_id: message.session_id,
+            })
+            .await
+            .map(Some)
+        }
+    }
 }
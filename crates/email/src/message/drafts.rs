@@ -0,0 +1,182 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{delete::EmailDeletion, metadata::MessageMetadata};
+use crate::cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess};
+use common::{MessageCache, MessageStoreCache, Server};
+use registry::schema::enums::MessageAuditEvent;
+use std::future::Future;
+use store::{
+    ValueKey,
+    ahash::AHashMap,
+    roaring::RoaringBitmap,
+    write::{AlignedBytes, Archive, BatchBuilder},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::EmailField, keyword::Keyword, special_use::SpecialUse};
+use utils::snowflake::SnowflakeIdGenerator;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct DraftsUsage {
+    pub count: usize,
+    pub size: u64,
+}
+
+pub trait DraftsPolicy: Sync + Send {
+    fn drafts_cleanup(&self, account_id: u32) -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+impl DraftsPolicy for Server {
+    async fn drafts_cleanup(&self, account_id: u32) -> trc::Result<()> {
+        let max_count = self.core.email.drafts_max_count;
+        let max_size = self.core.email.drafts_max_size;
+        let expunge_after = self.core.email.drafts_autoexpunge_after;
+        if max_count.is_none() && max_size.is_none() && expunge_after.is_none() {
+            return Ok(());
+        }
+
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let mut drafts = drafts(&cache).collect::<Vec<_>>();
+        if drafts.is_empty() {
+            return Ok(());
+        }
+
+        // Oldest revisions first
+        drafts.sort_unstable_by_key(|m| m.change_id);
+        let mut destroy_ids = RoaringBitmap::new();
+
+        // Remove orphaned revisions, which are drafts that have not been modified
+        // recently and were superseded by a newer draft saved with the same Message-ID
+        if let Some(cutoff) = expunge_after.and_then(SnowflakeIdGenerator::from_duration) {
+            let mut thread_drafts: AHashMap<u32, usize> = AHashMap::with_capacity(drafts.len());
+            for draft in &drafts {
+                *thread_drafts.entry(draft.thread_id).or_default() += 1;
+            }
+
+            // Revisions share a Message-ID and therefore a thread
+            let mut latest: AHashMap<String, u64> = AHashMap::new();
+            let mut revisions = Vec::new();
+            for draft in drafts.iter().filter(|m| thread_drafts[&m.thread_id] > 1) {
+                let Some(metadata) = self
+                    .store()
+                    .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                        account_id,
+                        Collection::Email,
+                        draft.document_id,
+                        EmailField::Metadata,
+                    ))
+                    .await
+                    .caused_by(trc::location!())?
+                else {
+                    continue;
+                };
+                if let Some(message_id) = metadata
+                    .unarchive::<MessageMetadata>()
+                    .caused_by(trc::location!())?
+                    .contents
+                    .first()
+                    .and_then(|contents| contents.root_part().message_id())
+                {
+                    latest.insert(message_id.to_string(), draft.change_id);
+                    revisions.push((draft.document_id, draft.change_id, message_id.to_string()));
+                }
+            }
+            for (document_id, change_id, message_id) in revisions {
+                if change_id < cutoff && latest[&message_id] != change_id {
+                    destroy_ids.insert(document_id);
+                }
+            }
+            drafts.retain(|m| !destroy_ids.contains(m.document_id));
+        }
+
+        // Enforce the drafts cap by removing the oldest drafts
+        let mut usage = DraftsUsage {
+            count: drafts.len(),
+            size: drafts.iter().map(|m| m.size as u64).sum(),
+        };
+        let total_orphans = destroy_ids.len();
+        for draft in &drafts {
+            if max_count.is_some_and(|max| usage.count > max)
+                || max_size.is_some_and(|max| usage.size > max)
+            {
+                destroy_ids.insert(draft.document_id);
+                usage.count -= 1;
+                usage.size -= draft.size as u64;
+            } else {
+                break;
+            }
+        }
+
+        if destroy_ids.is_empty() {
+            return Ok(());
+        }
+
+        if destroy_ids.len() > total_orphans {
+            trc::event!(
+                Limit(trc::LimitEvent::DraftsQuota),
+                AccountId = account_id,
+                Total = destroy_ids.len() - total_orphans,
+            );
+        }
+
+        trc::event!(
+            Store(trc::StoreEvent::AutoExpunge),
+            Collection = Collection::Email.as_str(),
+            AccountId = account_id,
+            Total = destroy_ids.len(),
+        );
+
+        // Delete drafts
+        let mut batch = BatchBuilder::new();
+        let tenant_id = self
+            .account(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .tenant_id();
+        let audit_ids = self.is_audit_log_enabled().then(|| destroy_ids.clone());
+        let not_destroyed = self
+            .emails_delete(account_id, tenant_id, &mut batch, destroy_ids)
+            .await?;
+        self.commit_batch(batch).await?;
+        self.notify_task_queue();
+
+        // Record audit trail
+        if let Some(mut audit_ids) = audit_ids {
+            audit_ids -= not_destroyed;
+            self.audit_messages(
+                account_id,
+                MessageAuditEvent::Expunged,
+                audit_ids,
+                None,
+                "drafts-cleanup".to_string().into(),
+            )
+            .await;
+        }
+
+        Ok(())
+    }
+}
+
+pub fn drafts(cache: &MessageStoreCache) -> impl Iterator<Item = &MessageCache> {
+    let drafts_id = cache
+        .mailbox_by_role(&SpecialUse::Drafts)
+        .map(|m| m.document_id);
+
+    cache.emails.items.iter().filter(move |m| {
+        drafts_id.is_some_and(|drafts_id| m.mailboxes.iter().any(|mb| mb.mailbox_id == drafts_id))
+            && cache.has_keyword(m, &Keyword::Draft)
+    })
+}
+
+pub fn drafts_usage(cache: &MessageStoreCache) -> DraftsUsage {
+    drafts(cache).fold(DraftsUsage::default(), |usage, m| DraftsUsage {
+        count: usage.count + 1,
+        size: usage.size + m.size as u64,
+    })
+}
@@ -5,7 +5,7 @@
  */
 
 use crate::message::{
-    index::{IndexMessage, MAX_MESSAGE_PARTS, PREVIEW_LENGTH},
+    index::{IndexMessage, MAX_MESSAGE_PARTS, PREVIEW_LENGTH, PREVIEW_SOURCE_LENGTH},
     metadata::{
         ArchivedMessageMetadata, ArchivedMessageMetadataPart, ArchivedMetadataHeaderName,
         MESSAGE_HAS_ATTACHMENT, MESSAGE_RECEIVED_MASK, MessageData, MessageMetadata,
@@ -122,8 +122,11 @@ impl IndexMessage for BatchBuilder {
             match &part.body {
                 mail_parser::PartType::Text(text) => {
                     if part_id == preview_part_id {
-                        preview =
-                            preview_text(text.replace('\r', "").into(), PREVIEW_LENGTH).into();
+                        preview = preview_text(
+                            preview_source(text).replace('\r', "").into(),
+                            PREVIEW_LENGTH,
+                        )
+                        .into();
                     }
 
                     if !message.text_body.contains(&part_id)
@@ -133,8 +136,9 @@ impl IndexMessage for BatchBuilder {
                     }
                 }
                 mail_parser::PartType::Html(html) => {
-                    let text = html_to_text(html);
                     if part_id == preview_part_id {
+                        // Only the start of the body is converted, large parts are not parsed in full
+                        let text = html_to_text(preview_source(html));
                         preview =
                             preview_text(text.replace('\r', "").into(), PREVIEW_LENGTH).into();
                     }
@@ -249,3 +253,8 @@ impl IndexMessage for BatchBuilder {
         Ok(self)
     }
 }
+
+#[inline(always)]
+fn preview_source(text: &str) -> &str {
+    &text[..text.floor_char_boundary(PREVIEW_SOURCE_LENGTH)]
+}
@@ -18,6 +18,7 @@ pub mod search;
 
 pub(super) const MAX_MESSAGE_PARTS: usize = 1000;
 pub const PREVIEW_LENGTH: usize = 256;
+pub(super) const PREVIEW_SOURCE_LENGTH: usize = PREVIEW_LENGTH * 64;
 
 impl IndexableObject for MessageData {
     fn index_values(&self) -> impl Iterator<Item = IndexValue<'_>> {
@@ -36,8 +36,10 @@ impl ArchivedMessageMetadata {
         raw_message: &[u8],
         index_fields: &AHashSet<SearchField>,
         default_language: Language,
+        max_body_size: Option<usize>,
     ) -> IndexDocument {
         let mut detector = LanguageDetector::new();
+        let mut body_limit = BodyLimit::new(max_body_size);
         let mut language = Language::Unknown;
         let message_contents = &self.contents[0];
         let mut document = IndexDocument::new(SearchIndex::Email)
@@ -229,7 +231,9 @@ impl ArchivedMessageMetadata {
 
             let part_id = part_id as u16;
             match &part.body {
-                ArchivedMetadataPartType::Text | ArchivedMetadataPartType::Html => {
+                ArchivedMetadataPartType::Text | ArchivedMetadataPartType::Html
+                    if body_limit.has_capacity() =>
+                {
                     let text = match (part.decode_contents(&raw_message), &part.body) {
                         (DecodedPartContent::Text(text), ArchivedMetadataPartType::Text) => text,
                         (DecodedPartContent::Text(html), ArchivedMetadataPartType::Html) => {
@@ -237,6 +241,7 @@ impl ArchivedMessageMetadata {
                         }
                         _ => unreachable!(),
                     };
+                    let text = body_limit.take(text.as_ref());
 
                     if message_contents.is_html_part(part_id)
                         || message_contents.is_text_part(part_id)
@@ -245,12 +250,12 @@ impl ArchivedMessageMetadata {
                             || index_fields.contains(&SearchField::Email(EmailSearchField::Body))
                         {
                             if part_language.is_unknown() {
-                                detector.detect(text.as_ref(), MIN_LANGUAGE_SCORE);
+                                detector.detect(text, MIN_LANGUAGE_SCORE);
                             }
 
                             document.index_text(
                                 SearchField::Email(EmailSearchField::Body),
-                                text.as_ref(),
+                                text,
                                 part_language,
                             );
                         }
@@ -258,20 +263,21 @@ impl ArchivedMessageMetadata {
                         || index_fields.contains(&SearchField::Email(EmailSearchField::Attachment))
                     {
                         if part_language.is_unknown() {
-                            detector.detect(text.as_ref(), MIN_LANGUAGE_SCORE);
+                            detector.detect(text, MIN_LANGUAGE_SCORE);
                         }
 
                         document.index_text(
                             SearchField::Email(EmailSearchField::Attachment),
-                            text.as_ref(),
+                            text,
                             part_language,
                         );
                     }
                 }
                 ArchivedMetadataPartType::Message(nested_message_id)
-                    if index_fields.is_empty()
+                    if (index_fields.is_empty()
                         || index_fields
-                            .contains(&SearchField::Email(EmailSearchField::Attachment)) =>
+                            .contains(&SearchField::Email(EmailSearchField::Attachment)))
+                        && body_limit.has_capacity() =>
                 {
                     let nested_message = self.message_id(*nested_message_id);
                     let nested_message_language = nested_message
@@ -296,7 +302,9 @@ impl ArchivedMessageMetadata {
                     for sub_part in nested_message.parts.iter().take(MAX_MESSAGE_PARTS) {
                         let language = sub_part.language().unwrap_or(nested_message_language);
                         match &sub_part.body {
-                            ArchivedMetadataPartType::Text | ArchivedMetadataPartType::Html => {
+                            ArchivedMetadataPartType::Text | ArchivedMetadataPartType::Html
+                                if body_limit.has_capacity() =>
+                            {
                                 let text = match (
                                     sub_part.decode_contents(&raw_message),
                                     &sub_part.body,
@@ -311,14 +319,15 @@ impl ArchivedMessageMetadata {
                                     ) => html_to_text(html.as_ref()).into(),
                                     _ => unreachable!(),
                                 };
+                                let text = body_limit.take(text.as_ref());
 
                                 if language.is_unknown() {
-                                    detector.detect(text.as_ref(), MIN_LANGUAGE_SCORE);
+                                    detector.detect(text, MIN_LANGUAGE_SCORE);
                                 }
 
                                 document.index_text(
                                     SearchField::Email(EmailSearchField::Attachment),
-                                    text.as_ref(),
+                                    text,
                                     language,
                                 );
                             }
@@ -344,6 +353,52 @@ impl ArchivedMessageMetadata {
             EmailSearchField::HasAttachment,
             self.rcvd_attach.to_native() & MESSAGE_HAS_ATTACHMENT != 0,
         );
+
+        if body_limit.truncated {
+            trc::event!(
+                MessageIngest(trc::MessageIngestEvent::SearchIndexTruncated),
+                AccountId = account_id,
+                DocumentId = document_id,
+                Limit = max_body_size.unwrap_or_default(),
+            );
+        }
+
         document
     }
 }
+
+// Caps the amount of body and attachment text added to the search index
+struct BodyLimit {
+    remaining: usize,
+    truncated: bool,
+}
+
+impl BodyLimit {
+    fn new(max_size: Option<usize>) -> Self {
+        BodyLimit {
+            remaining: max_size.unwrap_or(usize::MAX),
+            truncated: false,
+        }
+    }
+
+    fn has_capacity(&mut self) -> bool {
+        if self.remaining > 0 {
+            true
+        } else {
+            self.truncated = true;
+            false
+        }
+    }
+
+    fn take<'x>(&mut self, text: &'x str) -> &'x str {
+        if text.len() <= self.remaining {
+            self.remaining -= text.len();
+            text
+        } else {
+            let text = &text[..text.floor_char_boundary(self.remaining)];
+            self.remaining = 0;
+            self.truncated = true;
+            text
+        }
+    }
+}
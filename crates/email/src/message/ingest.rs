@@ -10,8 +10,10 @@ use crate::{
     mailbox::{INBOX_ID, JUNK_ID, SENT_ID, TRASH_ID, UidMailbox},
     message::{
         crypto::EncryptionFlags,
+        dedup::DeliveryDedupStore,
         index::{IndexMessage, extractors::VisitText},
         metadata::{MessageData, MessageMetadata},
+        repair::repair_message,
     },
 };
 use common::{Server, auth::AccessToken};
@@ -25,7 +27,7 @@ use mail_parser::{
 };
 use registry::{
     schema::{
-        enums::IndexDocumentType,
+        enums::{IndexDocumentType, MessageAuditEvent},
         prelude::{ObjectType, Permission, Property},
         structs::{SpamTrainingSample, Task, TaskIndexDocument, TaskMergeThreads, TaskStatus},
     },
@@ -109,6 +111,11 @@ pub trait EmailIngest: Sync + Send {
         mailbox_ids: impl IntoIterator<Item = u32> + Sync + Send,
         generate_email_id: bool,
     ) -> impl Future<Output = trc::Result<impl Iterator<Item = u32> + 'static>> + Send;
+    fn assign_email_uid_ranges(
+        &self,
+        account_id: u32,
+        uid_counts: Vec<(u32, u32)>,
+    ) -> impl Future<Output = trc::Result<Vec<(u32, u32)>>> + Send;
     fn add_account_spam_sample(
         &self,
         batch: &mut BatchBuilder,
@@ -153,13 +160,41 @@ impl EmailIngest for Server {
             .caused_by(trc::location!())?;
 
         // Parse message
-        let mut raw_message = Cow::from(params.raw_message);
         let mut message = params.message.ok_or_else(|| {
             trc::EventType::MessageIngest(trc::MessageIngestEvent::Error)
                 .ctx(trc::Key::Code, 550)
                 .ctx(trc::Key::Reason, "Failed to parse e-mail message.")
         })?;
 
+        // Repair damaged MIME structures before storage
+        let repaired_message;
+        let mut raw_message = if self.core.email.repair_mime
+            && params.source.is_smtp()
+            && let Some(repaired) = repair_message(&message)
+        {
+            trc::event!(
+                MessageIngest(MessageIngestEvent::MimeRepaired),
+                SpanId = params.session_id,
+                AccountId = account_id,
+                Details = repaired.changes,
+            );
+
+            // The spooled blob no longer matches the message to be stored
+            params.blob_hash = None;
+            repaired_message = repaired.raw_message;
+            raw_message_len = repaired_message.len() as u64;
+            message = MessageParser::default()
+                .parse(repaired_message.as_slice())
+                .ok_or_else(|| {
+                    trc::EventType::MessageIngest(trc::MessageIngestEvent::Error)
+                        .ctx(trc::Key::Code, 550)
+                        .ctx(trc::Key::Reason, "Failed to parse repaired e-mail message.")
+                })?;
+            Cow::from(repaired_message.as_slice())
+        } else {
+            Cow::from(params.raw_message)
+        };
+
         // Obtain message references and thread name
         let mut message_id = None;
         let mut message_ids = Vec::new();
@@ -204,8 +239,11 @@ impl EmailIngest for Server {
                 .await?
         };
 
-        // Skip duplicate messages for SMTP ingestion
-        if !thread_result.duplicate_ids.is_empty() && params.source.is_smtp() {
+        // Skip duplicate messages for SMTP ingestion and restores, the latter
+        // keeps retried or repeated imports from creating copies of a message
+        if !thread_result.duplicate_ids.is_empty()
+            && (params.source.is_smtp() || params.source.is_restore())
+        {
             // Fetch cached messages
             let cache = self
                 .get_cached_messages(account_id)
@@ -236,6 +274,37 @@ impl EmailIngest for Server {
             }
         }
 
+        // Skip messages delivered to the same mailbox within the duplicate window
+        let delivery_dedup = if params.source.is_smtp() {
+            self.delivery_dedup(
+                account_id,
+                params.mailbox_ids.first().copied().unwrap_or(INBOX_ID),
+                message_id.as_deref(),
+                params.raw_message,
+            )
+        } else {
+            None
+        };
+        if let Some(dedup) = &delivery_dedup
+            && self.is_duplicate_delivery(dedup).await?
+        {
+            trc::event!(
+                MessageIngest(MessageIngestEvent::Duplicate),
+                SpanId = params.session_id,
+                AccountId = account_id,
+                MessageId = message_id,
+            );
+
+            return Ok(IngestedEmail {
+                document_id: 0,
+                thread_id: 0,
+                change_id: u64::MAX,
+                blob_id: BlobId::default(),
+                imap_uids: Vec::new(),
+                size: 0,
+            });
+        }
+
         // Spam classification and training
         let mut train_spam = None;
         let mut extra_headers = String::new();
@@ -451,6 +520,14 @@ impl EmailIngest for Server {
                     }
                 }
 
+                self.inner.data.statistics.record_ingest(is_spam);
+                if let Some(tenant_id) = tenant_id {
+                    self.inner
+                        .data
+                        .usage
+                        .record_received(tenant_id, raw_message_len);
+                }
+
                 is_spam
             }
             IngestSource::Jmap { train_classifier } | IngestSource::Imap { train_classifier } => {
@@ -503,11 +580,15 @@ impl EmailIngest for Server {
             IngestSource::Smtp { .. } => self.core.email.encrypt,
             IngestSource::Restore => false,
         };
-        let is_encrypted = if do_encrypt
-            && !message.is_encrypted()
-            && let Some(encrypt_keys) = &account.encryption_key
-        {
-            match message.encrypt(encrypt_keys, account.flags).await {
+        let encryption_keys = if do_encrypt && !message.is_encrypted() {
+            self.account_encryption_keys(&account)
+                .await
+                .caused_by(trc::location!())?
+        } else {
+            None
+        };
+        let is_encrypted = if let Some((encrypt_keys, encrypt_flags)) = &encryption_keys {
+            match message.encrypt(encrypt_keys, *encrypt_flags).await {
                 Ok(new_raw_message) => {
                     raw_message = Cow::from(new_raw_message);
                     raw_message_len = raw_message.len() as u64;
@@ -559,8 +640,15 @@ impl EmailIngest for Server {
             false
         };
 
-        // Store blob
-        let (blob_hash, blob_hold) = if !is_encrypted && let Some(blob_hash) = params.blob_hash {
+        // Store blob, copying spooled messages into the account's data region
+        let (blob_hash, blob_hold) = if !is_encrypted
+            && let Some(blob_hash) = params.blob_hash
+            && self
+                .account_blob_store(account_id)
+                .await
+                .caused_by(trc::location!())?
+                .is_none()
+        {
             (blob_hash.clone(), None)
         } else {
             self.put_temporary_blob(account_id, raw_message.as_ref(), 60)
@@ -691,6 +779,28 @@ impl EmailIngest for Server {
         // Request FTS index
         self.notify_task_queue();
 
+        // Record delivery for duplicate detection
+        if let Some(dedup) = delivery_dedup
+            && let Err(err) = self.record_delivery(dedup).await
+        {
+            trc::error!(
+                err.span_id(params.session_id)
+                    .details("Failed to record delivery for duplicate detection.")
+            );
+        }
+
+        // Record audit trail
+        if self.is_audit_log_enabled() {
+            self.audit_messages(
+                account_id,
+                MessageAuditEvent::Received,
+                [document_id],
+                None,
+                message_id.clone(),
+            )
+            .await;
+        }
+
         trc::event!(
             MessageIngest(match params.source {
                 IngestSource::Smtp { .. } =>
@@ -868,6 +978,43 @@ impl EmailIngest for Server {
         }
     }
 
+    async fn assign_email_uid_ranges(
+        &self,
+        account_id: u32,
+        uid_counts: Vec<(u32, u32)>,
+    ) -> trc::Result<Vec<(u32, u32)>> {
+        // Reserve a block of UIDs per mailbox with a single counter update each
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
+        for (mailbox_id, count) in &uid_counts {
+            batch
+                .with_document(*mailbox_id)
+                .add_and_get(MailboxField::UidCounter, *count as i64);
+        }
+
+        let ids = if !uid_counts.is_empty() {
+            self.core.storage.data.write(batch.build_all()).await?
+        } else {
+            AssignedIds::default()
+        };
+        if ids.ids.len() == uid_counts.len() {
+            Ok(uid_counts
+                .into_iter()
+                .zip(ids.ids)
+                .map(|((mailbox_id, count), id)| match id {
+                    AssignedId::Counter(id) => (mailbox_id, (id as u32) + 1 - count),
+                    AssignedId::ChangeId(_) => unreachable!(),
+                })
+                .collect())
+        } else {
+            Err(trc::StoreEvent::UnexpectedError
+                .caused_by(trc::location!())
+                .ctx(trc::Key::Reason, "No all UIDs were generated"))
+        }
+    }
+
     async fn add_account_spam_sample(
         &self,
         batch: &mut BatchBuilder,
@@ -998,6 +1145,10 @@ impl IngestSource<'_> {
     pub fn is_smtp(&self) -> bool {
         matches!(self, Self::Smtp { .. })
     }
+
+    pub fn is_restore(&self) -> bool {
+        matches!(self, Self::Restore)
+    }
 }
 
 pub struct ThreadInfo;
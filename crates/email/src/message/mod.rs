@@ -4,10 +4,15 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
+pub mod archive;
 pub mod copy;
 pub mod crypto;
+pub mod dedup;
 pub mod delete;
 pub mod delivery;
+pub mod drafts;
 pub mod index;
 pub mod ingest;
 pub mod metadata;
+pub mod quarantine;
+pub mod repair;
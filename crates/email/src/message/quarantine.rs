@@ -0,0 +1,557 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::ingest::{EmailIngest, IngestEmail, IngestSource};
+use crate::mailbox::INBOX_ID;
+use common::{
+    Server,
+    auth::{AccessToken, BuildAccessToken, oauth::GrantType},
+    ipc::{EmailPush, PushNotification},
+    storage::dav::DavName,
+};
+use groupware::{cache::GroupwareCache, contact::ContactCard};
+use mail_builder::{MessageBuilder, headers::HeaderType};
+use mail_parser::MessageParser;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::QuarantinedMessage,
+    },
+    types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, id::ObjectId, index::IndexBuilder},
+};
+use std::{fmt::Write, future::Future};
+use store::{
+    SerializeInfallible,
+    write::{BatchBuilder, BlobLink, BlobOp, RegistryClass, ValueClass, now},
+};
+use trc::{AddContext, SpamEvent};
+use types::{
+    blob::{BlobClass, BlobId},
+    blob_hash::BlobHash,
+    collection::{Collection, SyncCollection},
+    field::ContactField,
+};
+use utils::{sanitize_email, template::html_escape, url_params::UrlParams};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuarantineAction {
+    Release,
+    AllowSender,
+}
+
+pub trait QuarantinedMessageIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+pub trait SpamQuarantine: Sync + Send {
+    fn quarantine_message(
+        &self,
+        account_id: u32,
+        blob_hash: &BlobHash,
+        raw_message: &[u8],
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn quarantine_release(
+        &self,
+        account_id: u32,
+        item_id: u64,
+        action: QuarantineAction,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+
+    fn quarantine_release_url(
+        &self,
+        account_id: u32,
+        item_id: u64,
+        expires_at: u64,
+        action: QuarantineAction,
+    ) -> impl Future<Output = Option<String>> + Send;
+
+    fn http_quarantine_handle(
+        &self,
+        params: &str,
+        confirmed: bool,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<String>> + Send;
+
+    fn send_quarantine_digest(
+        &self,
+        account_id: u32,
+        messages: Vec<(u64, QuarantinedMessage)>,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+impl QuarantinedMessageIndex for QuarantinedMessage {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::QuarantinedMessage.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::AccountId, self.account_id.id());
+        index_builder.search(Property::ReceivedAt, self.received_at.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        // The blob is held until the message expires or is released
+        let blob_link = BlobOp::Link {
+            hash: self.blob_id.hash.clone(),
+            to: BlobLink::Temporary {
+                until: self.expires_at.timestamp() as u64,
+            },
+        };
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        batch.with_account_id(self.account_id.document_id());
+        if is_set {
+            batch
+                .set(
+                    blob_link,
+                    ObjectId::new(ObjectType::QuarantinedMessage, item_id.into()).serialize(),
+                )
+                .set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(blob_link).clear(key);
+        }
+    }
+}
+
+impl SpamQuarantine for Server {
+    async fn quarantine_message(
+        &self,
+        account_id: u32,
+        blob_hash: &BlobHash,
+        raw_message: &[u8],
+        session_id: u64,
+    ) -> trc::Result<()> {
+        let hold_for = self
+            .core
+            .spam
+            .quarantine
+            .as_ref()
+            .map_or(0, |config| config.hold_for);
+        let message = MessageParser::new().parse_headers(raw_message);
+        let from = message
+            .as_ref()
+            .and_then(|message| message.from())
+            .and_then(|from| from.first())
+            .and_then(|from| from.address())
+            .unwrap_or_default()
+            .to_string();
+        let subject = message
+            .as_ref()
+            .and_then(|message| message.subject())
+            .unwrap_or_default()
+            .to_string();
+        let now = now();
+        let item_id = self.inner.data.registry_id_gen.generate();
+
+        let mut batch = BatchBuilder::new();
+        QuarantinedMessage {
+            account_id: account_id.into(),
+            blob_id: BlobId::new(blob_hash.clone(), BlobClass::default()),
+            from: from.clone(),
+            subject,
+            size: raw_message.len() as u64,
+            received_at: UTCDateTime::from_timestamp(now as i64),
+            expires_at: UTCDateTime::from_timestamp((now + hold_for) as i64),
+        }
+        .write_ops(&mut batch, item_id, true);
+        self.store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())?;
+
+        trc::event!(
+            Spam(SpamEvent::MessageQuarantined),
+            AccountId = account_id,
+            Id = item_id,
+            From = from,
+            Size = raw_message.len(),
+            SpanId = session_id,
+        );
+
+        Ok(())
+    }
+
+    async fn quarantine_release(
+        &self,
+        account_id: u32,
+        item_id: u64,
+        action: QuarantineAction,
+        session_id: u64,
+    ) -> trc::Result<bool> {
+        let Some(message) = self
+            .registry()
+            .object::<QuarantinedMessage>(item_id.into())
+            .await
+            .caused_by(trc::location!())?
+            .filter(|message| message.account_id.document_id() == account_id)
+        else {
+            return Ok(false);
+        };
+        let Some(raw_message) = self
+            .blob_store()
+            .get_blob(message.blob_id.hash.as_slice(), 0..usize::MAX)
+            .await
+            .caused_by(trc::location!())?
+        else {
+            return Ok(false);
+        };
+        let access_token = self
+            .access_token(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .build();
+        let account_info = self
+            .account_info(account_id)
+            .await
+            .caused_by(trc::location!())?;
+
+        // Deliver the message to the Inbox and train it as ham
+        let ingested = self
+            .email_ingest(IngestEmail {
+                raw_message: &raw_message,
+                blob_hash: Some(&message.blob_id.hash),
+                message: MessageParser::new().parse(&raw_message),
+                access_token: &access_token,
+                mailbox_ids: vec![INBOX_ID],
+                keywords: vec![],
+                received_at: Some(message.received_at.timestamp() as u64),
+                source: IngestSource::Smtp {
+                    deliver_to: account_info.name(),
+                    is_sender_authenticated: false,
+                    is_spam: false,
+                },
+                session_id,
+            })
+            .await?;
+        let mut batch = BatchBuilder::new();
+        if ingested.change_id != u64::MAX {
+            self.add_account_spam_sample(
+                &mut batch,
+                account_id,
+                ingested.document_id,
+                false,
+                session_id,
+            )
+            .await?;
+        }
+        message.write_ops(&mut batch, item_id, false);
+        self.commit_batch(batch).await.caused_by(trc::location!())?;
+
+        if ingested.change_id != u64::MAX {
+            self.broadcast_push_notification(PushNotification::EmailPush(EmailPush {
+                account_id,
+                email_id: ingested.document_id,
+                change_id: ingested.change_id,
+            }))
+            .await;
+        }
+
+        if action == QuarantineAction::AllowSender
+            && let Some(sender) = sanitize_email(&message.from)
+        {
+            add_trusted_sender(self, &access_token, &sender).await?;
+        }
+
+        trc::event!(
+            Spam(SpamEvent::MessageReleased),
+            AccountId = account_id,
+            Id = item_id,
+            From = message.from,
+            Details = action.as_str(),
+            SpanId = session_id,
+        );
+
+        Ok(true)
+    }
+
+    async fn quarantine_release_url(
+        &self,
+        account_id: u32,
+        item_id: u64,
+        expires_at: u64,
+        action: QuarantineAction,
+    ) -> Option<String> {
+        let config = self.core.spam.quarantine.as_ref()?;
+        let expires_in = expires_at.checked_sub(now()).filter(|secs| *secs > 0)?;
+
+        match self
+            .encode_access_token(
+                GrantType::QuarantineRelease,
+                account_id,
+                &item_id.to_string(),
+                expires_in,
+            )
+            .await
+        {
+            Ok(token) => Some(format!(
+                "{}?i={token}&a={}",
+                config.release_url,
+                action.as_str()
+            )),
+            Err(err) => {
+                trc::error!(err.caused_by(trc::location!()));
+                None
+            }
+        }
+    }
+
+    async fn http_quarantine_handle(
+        &self,
+        params: &str,
+        confirmed: bool,
+        session_id: u64,
+    ) -> trc::Result<String> {
+        let params = UrlParams::new(params.into());
+        let action = params
+            .get("a")
+            .and_then(QuarantineAction::parse)
+            .unwrap_or(QuarantineAction::Release);
+        let token = params.get("i").unwrap_or_default();
+        let item = if !token.is_empty()
+            && let Ok(token) = self
+                .validate_access_token(GrantType::QuarantineRelease.into(), token)
+                .await
+            && let Ok(item_id) = token.client_id.parse::<u64>()
+        {
+            Some((token.account_id, item_id))
+        } else {
+            None
+        };
+
+        let mut body = String::new();
+        match item {
+            Some((account_id, item_id)) if confirmed => {
+                if self
+                    .quarantine_release(account_id, item_id, action, session_id)
+                    .await?
+                {
+                    body.push_str(match action {
+                        QuarantineAction::Release => {
+                            "<p>The message has been delivered to your Inbox.</p>"
+                        }
+                        QuarantineAction::AllowSender => concat!(
+                            "<p>The message has been delivered to your Inbox ",
+                            "and the sender was added to your contacts.</p>"
+                        ),
+                    });
+                }
+            }
+            Some((account_id, item_id)) => {
+                // Links are only followed to a confirmation page, so that mail scanners
+                // and link previews cannot release the message
+                if let Some(message) = self
+                    .registry()
+                    .object::<QuarantinedMessage>(item_id.into())
+                    .await
+                    .caused_by(trc::location!())?
+                    .filter(|message| message.account_id.document_id() == account_id)
+                {
+                    body.push_str("<p>Release this message from quarantine?</p><p>From: ");
+                    html_escape(&mut body, &message.from);
+                    body.push_str("<br>Subject: ");
+                    html_escape(&mut body, &message.subject);
+                    body.push_str(
+                        "</p><form method=\"post\"><input type=\"hidden\" name=\"i\" value=\"",
+                    );
+                    html_escape(&mut body, token);
+                    let _ = write!(
+                        &mut body,
+                        concat!(
+                            "\"><input type=\"hidden\" name=\"a\" value=\"{}\">",
+                            "<button type=\"submit\">{}</button></form>"
+                        ),
+                        action.as_str(),
+                        match action {
+                            QuarantineAction::Release => "Deliver to Inbox",
+                            QuarantineAction::AllowSender => "Deliver to Inbox and allow sender",
+                        }
+                    );
+                }
+            }
+            None => {}
+        }
+        if body.is_empty() {
+            body.push_str(
+                "<p>This message is no longer in quarantine or the link has expired.</p>",
+            );
+        }
+
+        Ok(format!(
+            concat!(
+                "<!DOCTYPE html><html><head><meta charset=\"utf-8\">",
+                "<title>Quarantine</title></head><body>{}</body></html>"
+            ),
+            body
+        ))
+    }
+
+    async fn send_quarantine_digest(
+        &self,
+        account_id: u32,
+        messages: Vec<(u64, QuarantinedMessage)>,
+    ) -> trc::Result<()> {
+        let access_token = self
+            .access_token(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .build();
+        let account_info = self
+            .account_info(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let account_email = account_info.name();
+        let account_domain = account_email.rsplit('@').next().unwrap_or("localhost");
+
+        let mut body = String::from(concat!(
+            "The following messages were classified as spam and are being held in quarantine.\n",
+            "Messages that are not released are deleted once they expire.\n\n"
+        ));
+        for (item_id, message) in &messages {
+            let expires_at = message.expires_at.timestamp() as u64;
+            let _ = write!(
+                &mut body,
+                "From: {}\nSubject: {}\nReceived: {}\nExpires: {}\n",
+                message.from, message.subject, message.received_at, message.expires_at
+            );
+            if let Some(url) = self
+                .quarantine_release_url(account_id, *item_id, expires_at, QuarantineAction::Release)
+                .await
+            {
+                let _ = writeln!(&mut body, "Release: {url}");
+            }
+            if let Some(url) = self
+                .quarantine_release_url(
+                    account_id,
+                    *item_id,
+                    expires_at,
+                    QuarantineAction::AllowSender,
+                )
+                .await
+            {
+                let _ = writeln!(&mut body, "Release and allow sender: {url}");
+            }
+            body.push('\n');
+        }
+
+        let raw_message = MessageBuilder::new()
+            .from((
+                "Quarantine",
+                format!("postmaster@{account_domain}").as_str(),
+            ))
+            .to(account_email)
+            .header("Auto-Submitted", HeaderType::Text("auto-generated".into()))
+            .subject(format!("{} messages held in quarantine", messages.len()))
+            .text_body(body)
+            .write_to_vec()
+            .unwrap_or_default();
+
+        let ingested = self
+            .email_ingest(IngestEmail {
+                raw_message: &raw_message,
+                blob_hash: None,
+                message: MessageParser::new().parse(&raw_message),
+                access_token: &access_token,
+                mailbox_ids: vec![INBOX_ID],
+                keywords: vec![],
+                received_at: None,
+                source: IngestSource::Jmap {
+                    train_classifier: false,
+                },
+                session_id: 0,
+            })
+            .await?;
+        if ingested.change_id != u64::MAX {
+            self.broadcast_push_notification(PushNotification::EmailPush(EmailPush {
+                account_id,
+                email_id: ingested.document_id,
+                change_id: ingested.change_id,
+            }))
+            .await;
+        }
+
+        trc::event!(
+            Spam(SpamEvent::QuarantineDigestSent),
+            AccountId = account_id,
+            Total = messages.len(),
+        );
+
+        Ok(())
+    }
+}
+
+// Adds the sender to the default address book, so that future messages are
+// treated as ham when the spam filter trusts contacts.
+async fn add_trusted_sender(
+    server: &Server,
+    access_token: &AccessToken,
+    sender: &str,
+) -> trc::Result<()> {
+    let account_id = access_token.account_id();
+    if server
+        .document_exists(
+            account_id,
+            Collection::ContactCard,
+            ContactField::Email,
+            sender.as_bytes(),
+        )
+        .await
+        .caused_by(trc::location!())?
+    {
+        return Ok(());
+    }
+    let Some(address_book_id) = server
+        .fetch_dav_resources(account_id, account_id, SyncCollection::AddressBook)
+        .await
+        .caused_by(trc::location!())?
+        .document_ids(true)
+        .next()
+    else {
+        return Ok(());
+    };
+
+    let Some(card) =
+        ContactCard::new_with_email(DavName::new_with_rand_name(address_book_id), sender)
+    else {
+        return Ok(());
+    };
+    let document_id = server
+        .store()
+        .assign_document_ids(account_id, Collection::ContactCard, 1)
+        .await
+        .caused_by(trc::location!())?;
+    let mut batch = BatchBuilder::new();
+    card.insert(
+        access_token.account_tenant_ids(),
+        account_id,
+        document_id,
+        &mut batch,
+    )
+    .caused_by(trc::location!())?;
+    server
+        .commit_batch(batch)
+        .await
+        .caused_by(trc::location!())?;
+    server.notify_task_queue();
+
+    Ok(())
+}
+
+impl QuarantineAction {
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "release" => Some(QuarantineAction::Release),
+            "allow" => Some(QuarantineAction::AllowSender),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QuarantineAction::Release => "release",
+            QuarantineAction::AllowSender => "allow",
+        }
+    }
+}
@@ -0,0 +1,603 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use mail_builder::encoders::base64::base64_encode;
+use mail_parser::{
+    Encoding, HeaderName, Message, MessagePart, MimeHeaders, PartType,
+    decoders::{
+        base64::base64_decode, charsets::map::charset_decoder,
+        quoted_printable::quoted_printable_decode,
+    },
+};
+use std::ops::Range;
+
+pub const REPAIR_HEADER: &str = "X-MIME-Repaired";
+
+const MAX_LINE_LEN: usize = 998;
+const MAX_ENCODED_LINE_LEN: usize = 76;
+
+pub struct RepairedMessage {
+    pub raw_message: Vec<u8>,
+    pub changes: Vec<String>,
+}
+
+struct Splice {
+    range: Range<usize>,
+    data: Vec<u8>,
+}
+
+// Repairs are applied as byte splices over the original message, so anything
+// that is not damaged is stored exactly as it was received.
+pub fn repair_message(message: &Message<'_>) -> Option<RepairedMessage> {
+    let raw = message.raw_message.as_ref();
+    let mut splices = Vec::new();
+    let mut changes = Vec::new();
+
+    for (part_id, part) in message.parts.iter().enumerate() {
+        repair_headers(raw, part, part_id == 0, &mut splices, &mut changes);
+
+        if matches!(part.body, PartType::Multipart(_) | PartType::Message(_)) {
+            continue;
+        }
+
+        let body_range = part.offset_body as usize..part.offset_end as usize;
+        let Some(body) = raw.get(body_range.clone()) else {
+            continue;
+        };
+        let is_text = matches!(part.body, PartType::Text(_) | PartType::Html(_));
+        let mut decoded = None;
+
+        match part.encoding {
+            Encoding::Base64 => {
+                if let Some(fixed) = repair_base64(body) {
+                    changes.push(format!("base64-padding=part-{part_id}"));
+                    decoded = base64_decode(&fixed);
+                    splices.push(Splice {
+                        range: body_range,
+                        data: fixed,
+                    });
+                }
+            }
+            Encoding::None if has_long_lines(body) => {
+                // Overlong lines are not valid SMTP, the body is re-encoded instead of wrapped
+                // so the decoded content stays exactly the same.
+                let encoded = if is_text {
+                    quoted_printable_encode(body)
+                } else {
+                    base64_lines(body)
+                };
+                changes.push(format!("long-lines=part-{part_id}"));
+                splices.push(Splice {
+                    range: body_range,
+                    data: encoded,
+                });
+                splices.push(set_transfer_encoding(
+                    raw,
+                    part,
+                    if is_text {
+                        "quoted-printable"
+                    } else {
+                        "base64"
+                    },
+                ));
+            }
+            _ => (),
+        }
+
+        if is_text && let Some(splice) = repair_charset(raw, part, decoded, &mut changes) {
+            splices.push(splice);
+        }
+    }
+
+    if changes.is_empty() {
+        return None;
+    }
+
+    // Splices never overlap by construction, but a damaged header might be
+    // targeted by more than one repair, in which case only the first one applies.
+    splices.sort_by_key(|splice| splice.range.start);
+    let mut raw_message = Vec::with_capacity(raw.len() + 128);
+    raw_message.extend_from_slice(REPAIR_HEADER.as_bytes());
+    raw_message.push(b':');
+    for (pos, change) in changes.iter().enumerate() {
+        if pos > 0 {
+            raw_message.extend_from_slice(b";\r\n\t");
+        } else {
+            raw_message.push(b' ');
+        }
+        raw_message.extend_from_slice(change.as_bytes());
+    }
+    raw_message.extend_from_slice(b"\r\n");
+
+    let mut offset = 0;
+    for splice in splices {
+        if splice.range.start < offset || splice.range.end > raw.len() {
+            continue;
+        }
+        raw_message.extend_from_slice(&raw[offset..splice.range.start]);
+        raw_message.extend_from_slice(&splice.data);
+        offset = splice.range.end;
+    }
+    raw_message.extend_from_slice(&raw[offset..]);
+
+    Some(RepairedMessage {
+        raw_message,
+        changes,
+    })
+}
+
+fn repair_headers(
+    raw: &[u8],
+    part: &MessagePart<'_>,
+    is_root: bool,
+    splices: &mut Vec<Splice>,
+    changes: &mut Vec<String>,
+) {
+    let mut seen: Vec<&HeaderName<'_>> = Vec::new();
+
+    for header in &part.headers {
+        let range = header.offset_field as usize..header.offset_end as usize;
+
+        // Clients disagree on which copy of a duplicate header wins, keep the first one
+        let is_singleton = match &header.name {
+            HeaderName::ContentType | HeaderName::ContentTransferEncoding => true,
+            HeaderName::Subject
+            | HeaderName::From
+            | HeaderName::To
+            | HeaderName::Cc
+            | HeaderName::Date
+            | HeaderName::MessageId
+            | HeaderName::Sender
+            | HeaderName::ReplyTo
+            | HeaderName::InReplyTo
+            | HeaderName::References
+            | HeaderName::MimeVersion => is_root,
+            _ => false,
+        };
+        if is_singleton {
+            if seen.contains(&&header.name) {
+                changes.push(format!("duplicate-header={}", header.name.as_str()));
+                splices.push(Splice {
+                    range,
+                    data: Vec::new(),
+                });
+                continue;
+            }
+            seen.push(&header.name);
+        }
+
+        if let Some(bytes) = raw.get(range.clone())
+            && has_long_lines(bytes)
+            && let Some(folded) = fold_header(bytes)
+        {
+            changes.push(format!("long-header={}", header.name.as_str()));
+            splices.push(Splice {
+                range,
+                data: folded,
+            });
+        }
+    }
+}
+
+fn repair_charset(
+    raw: &[u8],
+    part: &MessagePart<'_>,
+    decoded: Option<Vec<u8>>,
+    changes: &mut Vec<String>,
+) -> Option<Splice> {
+    let declared = part
+        .content_type()
+        .and_then(|ct| ct.attribute("charset"))
+        .map(|charset| charset.trim().to_ascii_lowercase());
+    let is_ascii = matches!(declared.as_deref(), None | Some("us-ascii" | "ascii"));
+    let is_known = declared.as_deref().is_none_or(|charset| {
+        matches!(charset, "utf-8" | "utf8" | "us-ascii" | "ascii")
+            || charset_decoder(charset.as_bytes()).is_some()
+    });
+    if is_known && !is_ascii {
+        return None;
+    }
+
+    let body = raw.get(part.offset_body as usize..part.offset_end as usize)?;
+    let decoded = match decoded {
+        Some(decoded) => decoded,
+        None => match part.encoding {
+            Encoding::None => body.to_vec(),
+            Encoding::QuotedPrintable => quoted_printable_decode(body)?,
+            Encoding::Base64 => base64_decode(body)?,
+        },
+    };
+
+    // A missing or ASCII charset is only wrong when the content has 8-bit characters
+    if is_known && decoded.is_ascii() {
+        return None;
+    }
+    let charset = if std::str::from_utf8(&decoded).is_ok() {
+        "utf-8"
+    } else {
+        "windows-1252"
+    };
+
+    let header = part
+        .headers
+        .iter()
+        .find(|header| header.name == HeaderName::ContentType)?;
+    let range = header.offset_start as usize..header.offset_end as usize;
+    let value = set_charset(raw.get(range.clone())?, charset);
+
+    changes.push(format!(
+        "charset={}>{charset}",
+        declared.as_deref().unwrap_or("none")
+    ));
+    Some(Splice { range, data: value })
+}
+
+fn set_transfer_encoding(raw: &[u8], part: &MessagePart<'_>, encoding: &str) -> Splice {
+    if let Some(header) = part
+        .headers
+        .iter()
+        .find(|header| header.name == HeaderName::ContentTransferEncoding)
+    {
+        Splice {
+            range: header.offset_start as usize..header.offset_end as usize,
+            data: format!(" {encoding}\r\n").into_bytes(),
+        }
+    } else {
+        // Insert the header right before the blank line that separates the body
+        let pos = part
+            .headers
+            .iter()
+            .map(|header| header.offset_end as usize)
+            .max()
+            .unwrap_or(part.offset_header as usize)
+            .min(raw.len());
+        Splice {
+            range: pos..pos,
+            data: format!("Content-Transfer-Encoding: {encoding}\r\n").into_bytes(),
+        }
+    }
+}
+
+fn set_charset(value: &[u8], charset: &str) -> Vec<u8> {
+    let lower = value.to_ascii_lowercase();
+    let mut pos = 0;
+
+    while let Some(found) = lower[pos..]
+        .windows(7)
+        .position(|window| window == b"charset")
+    {
+        let start = pos + found;
+        pos = start + 7;
+        if start > 0 && !matches!(lower[start - 1], b';' | b' ' | b'\t' | b'\n' | b'\r') {
+            continue;
+        }
+
+        let mut eq = pos;
+        while eq < lower.len() && matches!(lower[eq], b' ' | b'\t') {
+            eq += 1;
+        }
+        if lower.get(eq) != Some(&b'=') {
+            continue;
+        }
+        let mut value_start = eq + 1;
+        while value_start < lower.len() && matches!(lower[value_start], b' ' | b'\t') {
+            value_start += 1;
+        }
+        let value_end = if lower.get(value_start) == Some(&b'"') {
+            lower[value_start + 1..]
+                .iter()
+                .position(|&ch| ch == b'"')
+                .map_or(lower.len(), |end| value_start + end + 2)
+        } else {
+            lower[value_start..]
+                .iter()
+                .position(|ch| matches!(ch, b';' | b' ' | b'\t' | b'\r' | b'\n'))
+                .map_or(lower.len(), |end| value_start + end)
+        };
+
+        let mut result = Vec::with_capacity(value.len() + charset.len());
+        result.extend_from_slice(&value[..value_start]);
+        result.extend_from_slice(charset.as_bytes());
+        result.extend_from_slice(&value[value_end..]);
+        return result;
+    }
+
+    // No charset parameter, append one before the line ending
+    let end = value.len()
+        - value
+            .iter()
+            .rev()
+            .take_while(|ch| ch.is_ascii_whitespace())
+            .count();
+    let mut result = Vec::with_capacity(value.len() + charset.len() + 10);
+    result.extend_from_slice(&value[..end]);
+    result.extend_from_slice(b"; charset=");
+    result.extend_from_slice(charset.as_bytes());
+    result.extend_from_slice(&value[end..]);
+    result
+}
+
+fn repair_base64(body: &[u8]) -> Option<Vec<u8>> {
+    let mut data = Vec::with_capacity(body.len());
+    let mut padding = 0;
+    let mut is_damaged = false;
+
+    for &ch in body {
+        match ch {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'+' | b'/' => {
+                if padding > 0 {
+                    // Data after the padding is a concatenation, not a padding problem
+                    return None;
+                }
+                data.push(ch);
+            }
+            b'=' => padding += 1,
+            b' ' | b'\t' | b'\r' | b'\n' => (),
+            _ => is_damaged = true,
+        }
+    }
+
+    // A single trailing character does not encode a full byte and is dropped
+    if data.len() % 4 == 1 {
+        data.pop();
+        is_damaged = true;
+    }
+    let expected_padding = (4 - data.len() % 4) % 4;
+    if !is_damaged && padding == expected_padding {
+        return None;
+    }
+    data.extend(std::iter::repeat_n(b'=', expected_padding));
+
+    let mut result = Vec::with_capacity(data.len() + (data.len() / MAX_ENCODED_LINE_LEN + 1) * 2);
+    for (pos, line) in data.chunks(MAX_ENCODED_LINE_LEN).enumerate() {
+        if pos > 0 {
+            result.extend_from_slice(b"\r\n");
+        }
+        result.extend_from_slice(line);
+    }
+    result.extend(
+        body.iter()
+            .rev()
+            .take_while(|ch| ch.is_ascii_whitespace())
+            .collect::<Vec<_>>()
+            .into_iter()
+            .rev(),
+    );
+    Some(result)
+}
+
+fn fold_header(header: &[u8]) -> Option<Vec<u8>> {
+    let name_end = header.iter().position(|&ch| ch == b':')? + 1;
+    let mut result = Vec::with_capacity(header.len() + 32);
+
+    for (line_num, line) in header.split_inclusive(|&ch| ch == b'\n').enumerate() {
+        let mut line = line;
+        let mut min_pos = if line_num == 0 { name_end + 1 } else { 1 };
+
+        loop {
+            let content_len = line.len()
+                - line
+                    .iter()
+                    .rev()
+                    .take_while(|&&ch| ch == b'\r' || ch == b'\n')
+                    .count();
+            if content_len <= MAX_LINE_LEN {
+                result.extend_from_slice(line);
+                break;
+            }
+
+            // Fold at the last whitespace that fits, a header without any cannot be folded
+            let fold_at = line[min_pos..MAX_LINE_LEN]
+                .iter()
+                .rposition(|&ch| ch == b' ' || ch == b'\t')
+                .map(|pos| pos + min_pos)
+                .or_else(|| {
+                    line[MAX_LINE_LEN..content_len]
+                        .iter()
+                        .position(|&ch| ch == b' ' || ch == b'\t')
+                        .map(|pos| pos + MAX_LINE_LEN)
+                })?;
+            result.extend_from_slice(&line[..fold_at]);
+            result.extend_from_slice(b"\r\n");
+            line = &line[fold_at..];
+            min_pos = 1;
+        }
+    }
+
+    Some(result)
+}
+
+fn has_long_lines(bytes: &[u8]) -> bool {
+    bytes
+        .split(|&ch| ch == b'\n')
+        .any(|line| line.strip_suffix(b"\r").unwrap_or(line).len() > MAX_LINE_LEN)
+}
+
+fn base64_lines(bytes: &[u8]) -> Vec<u8> {
+    let encoded = base64_encode(bytes).unwrap_or_default();
+    let mut result =
+        Vec::with_capacity(encoded.len() + (encoded.len() / MAX_ENCODED_LINE_LEN + 1) * 2);
+    for (pos, line) in encoded.chunks(MAX_ENCODED_LINE_LEN).enumerate() {
+        if pos > 0 {
+            result.extend_from_slice(b"\r\n");
+        }
+        result.extend_from_slice(line);
+    }
+    result
+}
+
+fn quoted_printable_encode(bytes: &[u8]) -> Vec<u8> {
+    const HEX: &[u8; 16] = b"0123456789ABCDEF";
+    let mut result = Vec::with_capacity(bytes.len() + bytes.len() / 8);
+    let mut lines = bytes.split(|&ch| ch == b'\n').peekable();
+
+    while let Some(line) = lines.next() {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        let mut line_len = 0;
+
+        for (pos, &ch) in line.iter().enumerate() {
+            let is_last = pos == line.len() - 1;
+            let is_literal = match ch {
+                b'=' => false,
+                b' ' | b'\t' => !is_last,
+                33..=126 => true,
+                _ => false,
+            };
+            let len = if is_literal { 1 } else { 3 };
+
+            // Soft line break, leaving room for the trailing '='
+            if line_len + len > MAX_ENCODED_LINE_LEN - 1 {
+                result.extend_from_slice(b"=\r\n");
+                line_len = 0;
+            }
+            if is_literal {
+                result.push(ch);
+            } else {
+                result.push(b'=');
+                result.push(HEX[(ch >> 4) as usize]);
+                result.push(HEX[(ch & 0x0f) as usize]);
+            }
+            line_len += len;
+        }
+
+        if lines.peek().is_some() {
+            result.extend_from_slice(b"\r\n");
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mail_parser::MessageParser;
+
+    fn repair(raw: &str) -> Option<(String, Vec<String>)> {
+        let message = MessageParser::default().parse(raw.as_bytes()).unwrap();
+        repair_message(&message).map(|repaired| {
+            (
+                String::from_utf8(repaired.raw_message).unwrap(),
+                repaired.changes,
+            )
+        })
+    }
+
+    #[test]
+    fn repair_clean_message() {
+        assert!(
+            repair(concat!(
+                "From: john@example.org\r\n",
+                "Subject: hello\r\n",
+                "Content-Type: text/plain; charset=utf-8\r\n",
+                "\r\n",
+                "Hello world\r\n"
+            ))
+            .is_none()
+        );
+    }
+
+    #[test]
+    fn repair_duplicate_headers() {
+        let (raw, changes) = repair(concat!(
+            "From: john@example.org\r\n",
+            "Subject: first\r\n",
+            "Subject: second\r\n",
+            "X-Other: a\r\n",
+            "X-Other: b\r\n",
+            "\r\n",
+            "Hello world\r\n"
+        ))
+        .unwrap();
+
+        assert_eq!(changes, vec!["duplicate-header=Subject".to_string()]);
+        assert_eq!(
+            raw,
+            concat!(
+                "X-MIME-Repaired: duplicate-header=Subject\r\n",
+                "From: john@example.org\r\n",
+                "Subject: first\r\n",
+                "X-Other: a\r\n",
+                "X-Other: b\r\n",
+                "\r\n",
+                "Hello world\r\n"
+            )
+        );
+    }
+
+    #[test]
+    fn repair_base64_padding() {
+        let (raw, changes) = repair(concat!(
+            "Content-Type: multipart/mixed; boundary=\"b\"\r\n",
+            "\r\n",
+            "--b\r\n",
+            "Content-Type: text/plain; charset=utf-8\r\n",
+            "Content-Transfer-Encoding: base64\r\n",
+            "\r\n",
+            "SGVsbG8gd29ybGQ\r\n",
+            "--b--\r\n"
+        ))
+        .unwrap();
+
+        assert_eq!(changes, vec!["base64-padding=part-1".to_string()]);
+        assert!(raw.contains("\r\nSGVsbG8gd29ybGQ=\r\n--b--"), "{raw}");
+        let message = MessageParser::default().parse(raw.as_bytes()).unwrap();
+        assert_eq!(message.body_text(0).unwrap(), "Hello world");
+    }
+
+    #[test]
+    fn repair_charset_declaration() {
+        let (raw, changes) = repair(concat!(
+            "Content-Type: text/plain; charset=\"x-unknown\"; format=flowed\r\n",
+            "\r\n",
+            "Caf\u{e9}\r\n"
+        ))
+        .unwrap();
+
+        assert_eq!(changes, vec!["charset=x-unknown>utf-8".to_string()]);
+        assert!(
+            raw.contains("Content-Type: text/plain; charset=utf-8; format=flowed\r\n"),
+            "{raw}"
+        );
+
+        let message = MessageParser::default()
+            .parse(&b"Content-Type: text/plain\r\n\r\nCaf\xe9\r\n"[..])
+            .unwrap();
+        let repaired = repair_message(&message).unwrap();
+        assert_eq!(
+            repaired.changes,
+            vec!["charset=none>windows-1252".to_string()]
+        );
+        assert!(
+            String::from_utf8_lossy(&repaired.raw_message)
+                .contains("Content-Type: text/plain; charset=windows-1252\r\n")
+        );
+    }
+
+    #[test]
+    fn repair_long_lines() {
+        let long_subject = vec!["word"; 300].join(" ");
+        let long_body = "x".repeat(1200);
+        let (raw, changes) = repair(&format!(
+            "Subject: {long_subject}\r\nContent-Type: text/plain; charset=utf-8\r\n\r\n{long_body}\r\n"
+        ))
+        .unwrap();
+
+        assert_eq!(
+            changes,
+            vec![
+                "long-header=Subject".to_string(),
+                "long-lines=part-0".to_string()
+            ]
+        );
+        assert!(raw.lines().all(|line| line.len() <= MAX_LINE_LEN), "{raw}");
+        assert!(raw.contains("Content-Transfer-Encoding: quoted-printable\r\n\r\n"));
+
+        let message = MessageParser::default().parse(raw.as_bytes()).unwrap();
+        assert_eq!(message.subject().unwrap(), long_subject);
+        assert_eq!(message.body_text(0).unwrap().trim_end(), long_body);
+    }
+}
@@ -0,0 +1,281 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{
+    AccountRecord, ArchiveRecord, ArchiveWriter, EmailMailboxRecord, EmailRecord, MailboxRecord,
+    MigrationSummary, RecordWriter, SieveScriptRecord,
+};
+use crate::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    identity::{Identity, settings::IdentitySettingsStore},
+    message::metadata::{MESSAGE_RECEIVED_MASK, MessageMetadata},
+    sieve::{SieveScript, ingest::SieveScriptIngest},
+};
+use common::Server;
+use registry::schema::enums::MessageAuditEvent;
+use std::{future::Future, path::Path, time::Instant};
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive},
+};
+use trc::AddContext;
+use types::{
+    collection::Collection,
+    field::{EmailField, IdentityField, MailboxField, SieveField},
+};
+
+pub trait AccountExport: Sync + Send {
+    fn account_export(
+        &self,
+        account_id: u32,
+        path: &Path,
+    ) -> impl Future<Output = trc::Result<MigrationSummary>> + Send;
+
+    fn account_export_records(
+        &self,
+        account_id: u32,
+        writer: &mut impl RecordWriter,
+        summary: &mut MigrationSummary,
+    ) -> impl Future<Output = trc::Result<Vec<u32>>> + Send;
+}
+
+impl AccountExport for Server {
+    async fn account_export(&self, account_id: u32, path: &Path) -> trc::Result<MigrationSummary> {
+        let op_start = Instant::now();
+        let mut summary = MigrationSummary::default();
+        let mut archive = ArchiveWriter::create(path).await?;
+        let exported_ids = self
+            .account_export_records(account_id, &mut archive, &mut summary)
+            .await?;
+        archive.finish().await?;
+
+        // Record audit trail
+        self.audit_messages(
+            account_id,
+            MessageAuditEvent::Exported,
+            exported_ids,
+            None,
+            path.to_string_lossy().into_owned().into(),
+        )
+        .await;
+
+        trc::event!(
+            Store(trc::StoreEvent::AccountExported),
+            AccountId = account_id,
+            Path = path.to_string_lossy().into_owned(),
+            Total = summary.emails,
+            Elapsed = op_start.elapsed(),
+        );
+
+        Ok(summary)
+    }
+
+    async fn account_export_records(
+        &self,
+        account_id: u32,
+        writer: &mut impl RecordWriter,
+        summary: &mut MigrationSummary,
+    ) -> trc::Result<Vec<u32>> {
+        // Write account details
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        writer
+            .write(
+                &ArchiveRecord::Account(AccountRecord {
+                    name: account.name.to_string(),
+                    quota: account.quota_disk,
+                    used_quota: self
+                        .get_used_quota_account(account_id)
+                        .await
+                        .caused_by(trc::location!())?
+                        .max(0) as u64,
+                }),
+                &[],
+            )
+            .await?;
+
+        // Write mailboxes, parents first so they can be recreated in order
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let mut mailboxes = cache.mailboxes.items.iter().collect::<Vec<_>>();
+        mailboxes.sort_unstable_by_key(|m| (m.path.matches('/').count(), m.document_id));
+        let uid_counters = self
+            .store()
+            .get_counters(account_id, Collection::Mailbox, MailboxField::UidCounter)
+            .await
+            .caused_by(trc::location!())?;
+        for mailbox in mailboxes {
+            let uid_next = uid_counters
+                .get(&mailbox.document_id)
+                .copied()
+                .unwrap_or_default()
+                + 1;
+
+            writer
+                .write(
+                    &ArchiveRecord::Mailbox(MailboxRecord {
+                        id: mailbox.document_id,
+                        parent_id: mailbox.parent_id(),
+                        name: mailbox.name.clone(),
+                        role: mailbox.role.as_str().map(Into::into),
+                        sort_order: (mailbox.sort_order != u32::MAX).then_some(mailbox.sort_order),
+                        uid_validity: mailbox.uid_validity,
+                        uid_next: uid_next as u32,
+                        is_subscribed: mailbox.subscribers.contains(&account_id),
+                    }),
+                    &[],
+                )
+                .await?;
+            summary.mailboxes += 1;
+        }
+
+        // Write messages
+        let mut emails = cache.emails.items.iter().collect::<Vec<_>>();
+        emails.sort_unstable_by_key(|m| m.document_id);
+        let mut exported_ids = Vec::with_capacity(emails.len());
+        for email in emails {
+            let Some(metadata_) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                    account_id,
+                    Collection::Email,
+                    email.document_id,
+                    EmailField::Metadata,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                summary.skipped += 1;
+                continue;
+            };
+            let metadata = metadata_
+                .unarchive::<MessageMetadata>()
+                .caused_by(trc::location!())?;
+            let Some(blob) = self
+                .blob_store()
+                .get_blob(metadata.blob_hash.0.as_slice(), 0..usize::MAX)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                trc::event!(
+                    Store(trc::StoreEvent::NotFound),
+                    AccountId = account_id,
+                    DocumentId = email.document_id,
+                    Details = "Blob not found while exporting message",
+                );
+                summary.skipped += 1;
+                continue;
+            };
+
+            let mut contents = Vec::with_capacity(blob.len());
+            contents.extend_from_slice(metadata.raw_headers.as_ref());
+            contents.extend_from_slice(
+                blob.get(metadata.blob_body_offset.to_native() as usize..)
+                    .unwrap_or_default(),
+            );
+
+            writer
+                .write(
+                    &ArchiveRecord::Email(EmailRecord {
+                        id: email.document_id,
+                        received_at: metadata.rcvd_attach.to_native() & MESSAGE_RECEIVED_MASK,
+                        keywords: cache
+                            .expand_keywords(email)
+                            .map(|keyword| keyword.to_string())
+                            .collect(),
+                        mailboxes: email
+                            .mailboxes
+                            .iter()
+                            .map(|m| EmailMailboxRecord {
+                                mailbox_id: m.mailbox_id,
+                                uid: m.uid,
+                            })
+                            .collect(),
+                    }),
+                    &contents,
+                )
+                .await?;
+            exported_ids.push(email.document_id);
+            summary.emails += 1;
+        }
+
+        // Write Sieve scripts
+        let active_script_id = self.sieve_script_get_active_id(account_id).await?;
+        for document_id in self
+            .document_ids(account_id, Collection::SieveScript, SieveField::Name)
+            .await
+            .caused_by(trc::location!())?
+        {
+            let Some(script_) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::SieveScript,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+            let script = script_
+                .deserialize::<SieveScript>()
+                .caused_by(trc::location!())?;
+            let Some(source) = self
+                .blob_store()
+                .get_blob(script.blob_hash.0.as_slice(), 0..script.size as usize)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                summary.skipped += 1;
+                continue;
+            };
+
+            writer
+                .write(
+                    &ArchiveRecord::SieveScript(SieveScriptRecord {
+                        name: script.name,
+                        is_active: active_script_id == Some(document_id),
+                        vacation_response: script.vacation_response,
+                    }),
+                    &source,
+                )
+                .await?;
+            summary.sieve_scripts += 1;
+        }
+
+        // Write identities
+        for document_id in self
+            .document_ids(account_id, Collection::Identity, IdentityField::DocumentId)
+            .await
+            .caused_by(trc::location!())?
+        {
+            if let Some(identity_) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::Identity,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            {
+                let mut identity = identity_
+                    .deserialize::<Identity>()
+                    .caused_by(trc::location!())?;
+                self.inline_identity_signatures(account_id, document_id, &mut identity)
+                    .await?;
+                writer
+                    .write(&ArchiveRecord::Identity(identity), &[])
+                    .await?;
+                summary.identities += 1;
+            }
+        }
+
+        Ok(exported_ids)
+    }
+}
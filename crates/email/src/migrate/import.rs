@@ -0,0 +1,566 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{ArchiveReader, ArchiveRecord, MailboxRecord, MigrationSummary, SieveScriptRecord};
+use crate::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    identity::Identity,
+    mailbox::{INBOX_ID, Mailbox},
+    message::ingest::{EmailIngest, IngestEmail, IngestSource},
+    sieve::{SieveScript, ingest::SieveScriptIngest},
+};
+use common::{
+    MessageStoreCache, Server, auth::BuildAccessToken, storage::index::ObjectIndexBuilder,
+};
+use mail_parser::MessageParser;
+use std::{future::Future, path::Path, time::Instant};
+use store::{
+    ProgressTracker, SerializeInfallible, ValueKey,
+    ahash::AHashMap,
+    write::{AlignedBytes, Archive, Archiver, BatchBuilder, ValueClass},
+};
+use trc::AddContext;
+use types::{
+    collection::Collection,
+    field::{IdentityField, MailboxField, PrincipalField, SieveField},
+    keyword::Keyword,
+    special_use::SpecialUse,
+};
+
+pub trait AccountImport: Sync + Send {
+    fn account_import(
+        &self,
+        account_id: u32,
+        path: &Path,
+        tracker: &mut impl ProgressTracker,
+    ) -> impl Future<Output = trc::Result<MigrationSummary>> + Send;
+}
+
+struct ImportedMailbox {
+    document_id: u32,
+    is_new: bool,
+    uid_next: u32,
+    // Last UID assigned, only tracked for mailboxes whose UIDs can be preserved
+    last_uid: Option<u32>,
+}
+
+impl AccountImport for Server {
+    async fn account_import(
+        &self,
+        account_id: u32,
+        path: &Path,
+        tracker: &mut impl ProgressTracker,
+    ) -> trc::Result<MigrationSummary> {
+        let op_start = Instant::now();
+        let mut summary = MigrationSummary::default();
+        let mut archive = ArchiveReader::open(path, self.core.email.mail_max_size).await?;
+
+        // Make sure the archive fits in the target account
+        let Some(ArchiveRecord::Account(source)) = archive.next().await?.map(|entry| entry.record)
+        else {
+            return Err(trc::StoreEvent::DataCorruption
+                .into_err()
+                .details("Account archive is missing the account record")
+                .ctx(trc::Key::Path, path.to_string_lossy().into_owned()));
+        };
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        let used_quota = self
+            .get_used_quota_account(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .max(0) as u64;
+        if account.quota_disk > 0 && used_quota + source.used_quota > account.quota_disk {
+            return Err(trc::LimitEvent::Quota
+                .into_err()
+                .details("Account archive exceeds the target account quota")
+                .ctx(trc::Key::AccountId, account_id)
+                .ctx(trc::Key::Size, source.used_quota)
+                .ctx(trc::Key::Total, account.quota_disk));
+        }
+
+        let access_token = self
+            .access_token(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .build();
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let mut mailboxes: AHashMap<u32, ImportedMailbox> = AHashMap::new();
+        let mut sieve_names = Vec::new();
+        let mut activate_script_id = self
+            .sieve_script_get_active_id(account_id)
+            .await?
+            .is_none()
+            .then_some(None);
+        let mut identities = Vec::new();
+        let mut has_identities = false;
+        let mut processed = 0;
+
+        // Progress is measured by the imported bytes, a cancelled import keeps
+        // the records imported so far.
+        while let Some(entry) = archive.next().await? {
+            processed += entry.contents.len() as u64;
+            if !tracker.update(processed, source.used_quota).await {
+                summary.is_cancelled = true;
+                break;
+            }
+
+            match entry.record {
+                ArchiveRecord::Mailbox(record) => {
+                    let mailbox = self
+                        .import_mailbox(account_id, &cache, &mailboxes, &record)
+                        .await?;
+                    mailboxes.insert(record.id, mailbox);
+                    summary.mailboxes += 1;
+                }
+                ArchiveRecord::Email(record) => {
+                    let mut mailbox_ids = Vec::with_capacity(record.mailboxes.len());
+                    let mut expected_uids = Vec::with_capacity(record.mailboxes.len());
+                    let mut batch = BatchBuilder::new();
+                    batch
+                        .with_account_id(account_id)
+                        .with_collection(Collection::Mailbox);
+                    for uid_mailbox in &record.mailboxes {
+                        let Some(mailbox) = mailboxes.get(&uid_mailbox.mailbox_id) else {
+                            continue;
+                        };
+                        if mailbox_ids.contains(&mailbox.document_id) {
+                            continue;
+                        }
+
+                        // Advance the UID counter so the next assigned UID matches the original
+                        if let Some(last_uid) = mailbox.last_uid
+                            && uid_mailbox.uid > last_uid + 1
+                        {
+                            batch.with_document(mailbox.document_id).add(
+                                MailboxField::UidCounter,
+                                (uid_mailbox.uid - last_uid - 1) as i64,
+                            );
+                        }
+                        mailbox_ids.push(mailbox.document_id);
+                        expected_uids.push(uid_mailbox.uid);
+                    }
+                    if mailbox_ids.is_empty() {
+                        mailbox_ids.push(INBOX_ID);
+                        expected_uids.push(0);
+                    }
+                    if !batch.is_empty() {
+                        self.commit_batch(batch).await.caused_by(trc::location!())?;
+                    }
+
+                    match self
+                        .email_ingest(IngestEmail {
+                            raw_message: &entry.contents,
+                            blob_hash: None,
+                            message: MessageParser::new().parse(&entry.contents),
+                            access_token: &access_token,
+                            mailbox_ids: mailbox_ids.clone(),
+                            keywords: record
+                                .keywords
+                                .iter()
+                                .map(|keyword| Keyword::parse(keyword))
+                                .collect(),
+                            received_at: Some(record.received_at),
+                            source: IngestSource::Restore,
+                            session_id: 0,
+                        })
+                        .await
+                    {
+                        Ok(ingested) if ingested.change_id == u64::MAX => {
+                            // Already imported by a previous attempt
+                            summary.skipped += 1;
+                        }
+                        Ok(ingested) => {
+                            for ((mailbox_id, expected_uid), uid) in mailbox_ids
+                                .iter()
+                                .zip(expected_uids)
+                                .zip(ingested.imap_uids)
+                            {
+                                if expected_uid != 0 && uid != expected_uid {
+                                    summary.uid_changes += 1;
+                                }
+                                if let Some(mailbox) = mailboxes
+                                    .values_mut()
+                                    .find(|m| m.document_id == *mailbox_id)
+                                    && let Some(last_uid) = &mut mailbox.last_uid
+                                {
+                                    *last_uid = uid;
+                                }
+                            }
+                            summary.emails += 1;
+                        }
+                        Err(err)
+                            if err.matches(trc::EventType::MessageIngest(
+                                trc::MessageIngestEvent::Error,
+                            )) =>
+                        {
+                            trc::error!(
+                                err.account_id(account_id)
+                                    .details("Failed to import message")
+                            );
+                            summary.skipped += 1;
+                        }
+                        Err(err) => return Err(err.caused_by(trc::location!())),
+                    }
+                }
+                ArchiveRecord::SieveScript(record) => {
+                    if sieve_names.is_empty() {
+                        sieve_names = self.sieve_script_names(account_id).await?;
+                    }
+                    if sieve_names.contains(&record.name) {
+                        summary.skipped += 1;
+                        continue;
+                    }
+
+                    let is_active = record.is_active;
+                    let name = record.name.clone();
+                    if let Some(document_id) = self
+                        .import_sieve_script(account_id, record, entry.contents)
+                        .await?
+                    {
+                        if is_active && let Some(active_id) = &mut activate_script_id {
+                            *active_id = Some(document_id);
+                        }
+                        sieve_names.push(name);
+                        summary.sieve_scripts += 1;
+                    } else {
+                        summary.skipped += 1;
+                    }
+                }
+                ArchiveRecord::Identity(identity) => {
+                    if !has_identities {
+                        identities = self.identity_keys(account_id).await?;
+                        has_identities = true;
+                    }
+                    let key = (identity.name.clone(), identity.email.clone());
+                    if identities.contains(&key) {
+                        summary.skipped += 1;
+                        continue;
+                    }
+
+                    let document_id = self
+                        .store()
+                        .assign_document_ids(account_id, Collection::Identity, 1)
+                        .await
+                        .caused_by(trc::location!())?;
+                    let mut batch = BatchBuilder::new();
+                    batch
+                        .with_account_id(account_id)
+                        .with_collection(Collection::Identity)
+                        .with_document(document_id)
+                        .tag(IdentityField::DocumentId)
+                        .custom(ObjectIndexBuilder::<(), _>::new().with_changes(identity))
+                        .caused_by(trc::location!())?;
+                    self.commit_batch(batch).await.caused_by(trc::location!())?;
+                    identities.push(key);
+                    summary.identities += 1;
+                }
+                ArchiveRecord::Account(_) => {
+                    return Err(trc::StoreEvent::DataCorruption
+                        .into_err()
+                        .details("Unexpected account record in archive")
+                        .ctx(trc::Key::Path, path.to_string_lossy().into_owned()));
+                }
+            }
+        }
+
+        // Restore UID counters past the last message of each mailbox
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
+        for mailbox in mailboxes.values() {
+            if let Some(last_uid) = mailbox.last_uid
+                && mailbox.uid_next > last_uid + 1
+            {
+                batch.with_document(mailbox.document_id).add(
+                    MailboxField::UidCounter,
+                    (mailbox.uid_next - last_uid - 1) as i64,
+                );
+            }
+        }
+
+        // Activate the imported Sieve script if the account had none
+        if let Some(Some(document_id)) = activate_script_id {
+            batch
+                .with_collection(Collection::Principal)
+                .with_document(0)
+                .set(PrincipalField::ActiveScriptId, document_id.serialize());
+        }
+        if !batch.is_empty() {
+            self.commit_batch(batch).await.caused_by(trc::location!())?;
+        }
+
+        trc::event!(
+            Store(trc::StoreEvent::AccountImported),
+            AccountId = account_id,
+            Path = path.to_string_lossy().into_owned(),
+            Total = summary.emails,
+            TotalFailures = summary.skipped,
+            Elapsed = op_start.elapsed(),
+        );
+
+        Ok(summary)
+    }
+}
+
+trait AccountImportHelpers: Sync + Send {
+    fn import_mailbox(
+        &self,
+        account_id: u32,
+        cache: &MessageStoreCache,
+        mailboxes: &AHashMap<u32, ImportedMailbox>,
+        record: &MailboxRecord,
+    ) -> impl Future<Output = trc::Result<ImportedMailbox>> + Send;
+
+    fn import_sieve_script(
+        &self,
+        account_id: u32,
+        record: SieveScriptRecord,
+        contents: Vec<u8>,
+    ) -> impl Future<Output = trc::Result<Option<u32>>> + Send;
+
+    fn sieve_script_names(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Vec<String>>> + Send;
+
+    fn identity_keys(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Vec<(String, String)>>> + Send;
+}
+
+impl AccountImportHelpers for Server {
+    async fn import_mailbox(
+        &self,
+        account_id: u32,
+        cache: &MessageStoreCache,
+        mailboxes: &AHashMap<u32, ImportedMailbox>,
+        record: &MailboxRecord,
+    ) -> trc::Result<ImportedMailbox> {
+        let role = record
+            .role
+            .as_deref()
+            .and_then(SpecialUse::parse)
+            .unwrap_or(SpecialUse::None);
+        let parent = match record.parent_id {
+            Some(parent_id) => mailboxes.get(&parent_id),
+            None => None,
+        };
+
+        // Mailboxes created by this import have no existing children
+        let existing = if parent.is_none_or(|parent| !parent.is_new) {
+            let parent_id = parent.map(|parent| parent.document_id);
+            cache.mailboxes.items.iter().find(|m| {
+                (role != SpecialUse::None && m.role == role)
+                    || (m.parent_id() == parent_id && m.name.eq_ignore_ascii_case(&record.name))
+            })
+        } else {
+            None
+        };
+
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
+
+        let (document_id, is_new) = if let Some(existing) = existing {
+            // UIDs can only be preserved on empty mailboxes that never assigned one
+            let uid_counter = self
+                .store()
+                .get_counter(ValueKey {
+                    account_id,
+                    collection: Collection::Mailbox.into(),
+                    document_id: existing.document_id,
+                    class: ValueClass::Property(MailboxField::UidCounter.into()),
+                })
+                .await
+                .caused_by(trc::location!())?;
+            if uid_counter != 0 || cache.in_mailbox(existing.document_id).next().is_some() {
+                return Ok(ImportedMailbox {
+                    document_id: existing.document_id,
+                    is_new: false,
+                    uid_next: record.uid_next,
+                    last_uid: None,
+                });
+            }
+
+            let Some(mailbox) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::Mailbox,
+                    existing.document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                return Err(trc::StoreEvent::NotFound
+                    .into_err()
+                    .document_id(existing.document_id)
+                    .caused_by(trc::location!()));
+            };
+            let mailbox = mailbox
+                .into_deserialized::<Mailbox>()
+                .caused_by(trc::location!())?;
+            let mut new_mailbox = mailbox.inner.clone();
+            new_mailbox.uid_validity = record.uid_validity;
+            batch
+                .with_document(existing.document_id)
+                .custom(
+                    ObjectIndexBuilder::new()
+                        .with_current(mailbox)
+                        .with_changes(new_mailbox),
+                )
+                .caused_by(trc::location!())?;
+            (existing.document_id, false)
+        } else {
+            let document_id = self
+                .store()
+                .assign_document_ids(account_id, Collection::Mailbox, 1)
+                .await
+                .caused_by(trc::location!())?;
+            let mut mailbox = Mailbox::new(record.name.clone())
+                .with_role(role)
+                .with_parent_id(parent.map_or(0, |parent| parent.document_id + 1));
+            mailbox.uid_validity = record.uid_validity;
+            mailbox.sort_order = record.sort_order;
+            if record.is_subscribed {
+                mailbox.add_subscriber(account_id);
+            }
+            batch
+                .with_document(document_id)
+                .custom(ObjectIndexBuilder::<(), _>::new().with_changes(mailbox))
+                .caused_by(trc::location!())?;
+            (document_id, true)
+        };
+        self.commit_batch(batch).await.caused_by(trc::location!())?;
+
+        Ok(ImportedMailbox {
+            document_id,
+            is_new,
+            uid_next: record.uid_next,
+            last_uid: Some(0),
+        })
+    }
+
+    async fn import_sieve_script(
+        &self,
+        account_id: u32,
+        record: SieveScriptRecord,
+        mut contents: Vec<u8>,
+    ) -> trc::Result<Option<u32>> {
+        let size = contents.len() as u32;
+        match self.core.sieve.untrusted_compiler.compile(&contents) {
+            Ok(script) => {
+                contents.extend(
+                    Archiver::new(script)
+                        .untrusted()
+                        .serialize()
+                        .caused_by(trc::location!())?,
+                );
+            }
+            Err(err) => {
+                trc::event!(
+                    Sieve(trc::SieveEvent::UnexpectedError),
+                    AccountId = account_id,
+                    Details = "Failed to compile imported Sieve script",
+                    Id = record.name,
+                    Reason = err.to_string(),
+                );
+                return Ok(None);
+            }
+        }
+
+        let (blob_hash, blob_hold) = self.put_temporary_blob(account_id, &contents, 60).await?;
+        let document_id = self
+            .store()
+            .assign_document_ids(account_id, Collection::SieveScript, 1)
+            .await
+            .caused_by(trc::location!())?;
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::SieveScript)
+            .with_document(document_id)
+            .custom(
+                ObjectIndexBuilder::<(), _>::new()
+                    .with_changes(SieveScript {
+                        name: record.name,
+                        blob_hash,
+                        size,
+                        vacation_response: record.vacation_response,
+                    })
+                    .with_changed_by(account.account_tenant_ids()),
+            )
+            .caused_by(trc::location!())?
+            .clear(blob_hold);
+        self.commit_batch(batch).await.caused_by(trc::location!())?;
+
+        Ok(Some(document_id))
+    }
+
+    async fn sieve_script_names(&self, account_id: u32) -> trc::Result<Vec<String>> {
+        let mut names = Vec::new();
+        for document_id in self
+            .document_ids(account_id, Collection::SieveScript, SieveField::Name)
+            .await
+            .caused_by(trc::location!())?
+        {
+            if let Some(script) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::SieveScript,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            {
+                names.push(
+                    script
+                        .unarchive::<SieveScript>()
+                        .caused_by(trc::location!())?
+                        .name
+                        .to_string(),
+                );
+            }
+        }
+
+        Ok(names)
+    }
+
+    async fn identity_keys(&self, account_id: u32) -> trc::Result<Vec<(String, String)>> {
+        let mut keys = Vec::new();
+        for document_id in self
+            .document_ids(account_id, Collection::Identity, IdentityField::DocumentId)
+            .await
+            .caused_by(trc::location!())?
+        {
+            if let Some(identity) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::Identity,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            {
+                let identity = identity
+                    .unarchive::<Identity>()
+                    .caused_by(trc::location!())?;
+                keys.push((identity.name.to_string(), identity.email.to_string()));
+            }
+        }
+
+        Ok(keys)
+    }
+}
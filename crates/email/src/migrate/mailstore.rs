@@ -0,0 +1,589 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::MigrationSummary;
+use crate::{
+    mailbox::{INBOX_ID, manage::MailboxFnc},
+    message::ingest::{EmailIngest, IngestEmail, IngestSource},
+};
+use common::{
+    Server,
+    auth::{AccessToken, BuildAccessToken},
+};
+use mail_parser::{DateTime, MessageParser};
+use std::{
+    io::{Cursor, Read},
+    time::Instant,
+};
+use store::ahash::AHashMap;
+use trc::AddContext;
+use types::keyword::Keyword;
+
+// Imports messages from mbox files and zipped Maildir trees, preserving
+// received dates, flags and the folder hierarchy of the source mail store.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MailStoreFormat {
+    Mbox,
+    Maildir,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct MailStoreMessage {
+    pub folder: Vec<String>,
+    pub received_at: Option<u64>,
+    pub keywords: Vec<Keyword>,
+    pub contents: Vec<u8>,
+}
+
+pub struct MailStoreImporter {
+    server: Server,
+    account_id: u32,
+    access_token: AccessToken,
+    base_path: Option<String>,
+    mailboxes: AHashMap<Vec<String>, u32>,
+    started: Instant,
+    pub summary: MigrationSummary,
+}
+
+impl MailStoreFormat {
+    pub fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map_ignore_case!(value.as_bytes(),
+            "mbox" => MailStoreFormat::Mbox,
+            "maildir" => MailStoreFormat::Maildir,
+        )
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MailStoreFormat::Mbox => "mbox",
+            MailStoreFormat::Maildir => "maildir",
+        }
+    }
+
+    pub fn detect(data: &[u8]) -> Self {
+        if data.starts_with(b"PK\x03\x04") {
+            MailStoreFormat::Maildir
+        } else {
+            MailStoreFormat::Mbox
+        }
+    }
+
+    pub fn parse_messages(&self, data: &[u8]) -> trc::Result<Vec<MailStoreMessage>> {
+        match self {
+            MailStoreFormat::Mbox => Ok(parse_mbox(data)),
+            MailStoreFormat::Maildir => parse_maildir(data),
+        }
+    }
+}
+
+impl MailStoreImporter {
+    pub async fn new(
+        server: Server,
+        account_id: u32,
+        base_path: Option<String>,
+    ) -> trc::Result<Self> {
+        let access_token = server
+            .access_token(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .build();
+
+        Ok(Self {
+            server,
+            account_id,
+            access_token,
+            base_path: base_path
+                .map(|path| path.trim_matches('/').to_string())
+                .filter(|path| !path.is_empty()),
+            mailboxes: AHashMap::new(),
+            started: Instant::now(),
+            summary: MigrationSummary::default(),
+        })
+    }
+
+    pub async fn import(&mut self, message: MailStoreMessage) -> trc::Result<()> {
+        let mailbox_id = self.mailbox_id(&message.folder).await?;
+
+        match self
+            .server
+            .email_ingest(IngestEmail {
+                raw_message: &message.contents,
+                blob_hash: None,
+                message: MessageParser::new().parse(&message.contents),
+                access_token: &self.access_token,
+                mailbox_ids: vec![mailbox_id],
+                keywords: message.keywords,
+                received_at: message.received_at,
+                source: IngestSource::Restore,
+                session_id: 0,
+            })
+            .await
+        {
+            Ok(ingested) if ingested.change_id == u64::MAX => {
+                self.summary.skipped += 1;
+                Ok(())
+            }
+            Ok(_) => {
+                self.summary.emails += 1;
+                Ok(())
+            }
+            Err(err)
+                if err.matches(trc::EventType::MessageIngest(
+                    trc::MessageIngestEvent::Error,
+                )) =>
+            {
+                trc::error!(
+                    err.account_id(self.account_id)
+                        .details("Failed to import message")
+                );
+                self.summary.skipped += 1;
+                Ok(())
+            }
+            Err(err) => Err(err.caused_by(trc::location!())),
+        }
+    }
+
+    pub fn finish(self, format: MailStoreFormat) -> MigrationSummary {
+        trc::event!(
+            Store(trc::StoreEvent::MailStoreImported),
+            AccountId = self.account_id,
+            Type = format.as_str(),
+            Total = self.summary.emails,
+            TotalFailures = self.summary.skipped,
+            Elapsed = self.started.elapsed(),
+        );
+
+        self.summary
+    }
+
+    async fn mailbox_id(&mut self, folder: &[String]) -> trc::Result<u32> {
+        if let Some(mailbox_id) = self.mailboxes.get(folder) {
+            return Ok(*mailbox_id);
+        }
+
+        let path = self
+            .base_path
+            .iter()
+            .map(|path| path.as_str())
+            .chain(folder.iter().map(|name| name.as_str()))
+            .collect::<Vec<_>>()
+            .join("/");
+        let mailbox_id = if !path.is_empty() {
+            let mailbox_id = self
+                .server
+                .mailbox_create_path(self.account_id, &path, None)
+                .await
+                .caused_by(trc::location!())?
+                .ok_or_else(|| {
+                    trc::StoreEvent::NotFound
+                        .into_err()
+                        .details("Failed to create mailbox")
+                        .ctx(trc::Key::Path, path)
+                        .caused_by(trc::location!())
+                })?;
+            self.summary.mailboxes += 1;
+            mailbox_id
+        } else {
+            INBOX_ID
+        };
+        self.mailboxes.insert(folder.to_vec(), mailbox_id);
+
+        Ok(mailbox_id)
+    }
+}
+
+pub fn parse_mbox(data: &[u8]) -> Vec<MailStoreMessage> {
+    let mut messages = Vec::new();
+    let mut message: Option<MailStoreMessage> = None;
+    let mut last_empty = true;
+
+    for line in data.split_inclusive(|ch| *ch == b'\n') {
+        if last_empty && line.starts_with(b"From ") {
+            if let Some(message) = message.take() {
+                messages.push(finish_mbox_message(message));
+            }
+            message = Some(MailStoreMessage {
+                received_at: parse_from_line(line),
+                ..Default::default()
+            });
+            last_empty = false;
+            continue;
+        }
+
+        last_empty = matches!(line, b"\n" | b"\r\n");
+        if let Some(message) = &mut message {
+            // Unescape mboxrd ">From " lines
+            let from_pos = line.iter().position(|ch| *ch != b'>').unwrap_or(0);
+            if from_pos > 0 && line[from_pos..].starts_with(b"From ") {
+                message.contents.extend_from_slice(&line[1..]);
+            } else {
+                message.contents.extend_from_slice(line);
+            }
+        }
+    }
+
+    if let Some(message) = message {
+        messages.push(finish_mbox_message(message));
+    }
+
+    messages
+}
+
+fn finish_mbox_message(mut message: MailStoreMessage) -> MailStoreMessage {
+    // Remove the blank line separating messages
+    if message.contents.ends_with(b"\r\n\r\n") {
+        message.contents.truncate(message.contents.len() - 2);
+    } else if message.contents.ends_with(b"\n\n") {
+        message.contents.truncate(message.contents.len() - 1);
+    }
+
+    // Obtain flags from the Status, X-Status and X-Keywords headers
+    for line in message.contents.split(|ch| *ch == b'\n') {
+        let line = line.strip_suffix(b"\r").unwrap_or(line);
+        if line.is_empty() {
+            break;
+        }
+        let Some((name, value)) = std::str::from_utf8(line)
+            .ok()
+            .and_then(|line| line.split_once(':'))
+        else {
+            continue;
+        };
+
+        if name.eq_ignore_ascii_case("Status") || name.eq_ignore_ascii_case("X-Status") {
+            for flag in value.trim().chars() {
+                let keyword = match flag {
+                    'R' => Keyword::Seen,
+                    'A' => Keyword::Answered,
+                    'F' => Keyword::Flagged,
+                    'T' => Keyword::Draft,
+                    'D' => Keyword::Deleted,
+                    _ => continue,
+                };
+                if !message.keywords.contains(&keyword) {
+                    message.keywords.push(keyword);
+                }
+            }
+        } else if name.eq_ignore_ascii_case("X-Keywords") {
+            for keyword in value
+                .split([',', ' '])
+                .filter(|keyword| !keyword.is_empty())
+                .map(Keyword::parse)
+            {
+                if !message.keywords.contains(&keyword) {
+                    message.keywords.push(keyword);
+                }
+            }
+        }
+    }
+
+    message
+}
+
+// Parses the date of a "From sender Thu Nov 24 18:22:48 2022" separator line
+fn parse_from_line(line: &[u8]) -> Option<u64> {
+    let line = std::str::from_utf8(line).ok()?;
+    let mut parts = line.split_ascii_whitespace().skip(2);
+
+    // The day of the week is optional
+    let mut month = parts.next()?;
+    if month_number(month).is_none() {
+        month = parts.next()?;
+    }
+    let month = month_number(month)?;
+    let day = parts.next()?.parse::<u8>().ok()?;
+    let mut time = parts.next()?.split(':');
+    let hour = time.next()?.parse::<u8>().ok()?;
+    let minute = time.next()?.parse::<u8>().ok()?;
+    let second = time.next().and_then(|v| v.parse::<u8>().ok()).unwrap_or(0);
+    let year = parts.next()?.parse::<u16>().ok()?;
+
+    let date = DateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+        tz_before_gmt: false,
+        tz_hour: 0,
+        tz_minute: 0,
+    };
+
+    if date.is_valid() {
+        u64::try_from(date.to_timestamp()).ok()
+    } else {
+        None
+    }
+}
+
+fn month_number(month: &str) -> Option<u8> {
+    hashify::tiny_map_ignore_case!(month.as_bytes(),
+        "jan" => 1,
+        "feb" => 2,
+        "mar" => 3,
+        "apr" => 4,
+        "may" => 5,
+        "jun" => 6,
+        "jul" => 7,
+        "aug" => 8,
+        "sep" => 9,
+        "oct" => 10,
+        "nov" => 11,
+        "dec" => 12,
+    )
+}
+
+pub fn parse_maildir(data: &[u8]) -> trc::Result<Vec<MailStoreMessage>> {
+    let mut archive = zip::ZipArchive::new(Cursor::new(data)).map_err(|err| {
+        trc::StoreEvent::DataCorruption
+            .reason(err)
+            .details("Invalid Maildir archive")
+            .caused_by(trc::location!())
+    })?;
+    let mut entries = Vec::new();
+    let mut custom_keywords: AHashMap<Vec<String>, Vec<(char, Keyword)>> = AHashMap::new();
+
+    for index in 0..archive.len() {
+        let mut file = archive.by_index(index).map_err(|err| {
+            trc::StoreEvent::DataCorruption
+                .reason(err)
+                .caused_by(trc::location!())
+        })?;
+        if file.is_dir() {
+            continue;
+        }
+        let mut path = file
+            .name()
+            .split('/')
+            .filter(|part| !part.is_empty())
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>();
+        let Some(name) = path.pop() else {
+            continue;
+        };
+
+        let mut contents = Vec::with_capacity(file.size() as usize);
+        file.read_to_end(&mut contents).map_err(|err| {
+            trc::StoreEvent::DataCorruption
+                .reason(err)
+                .caused_by(trc::location!())
+        })?;
+
+        if name == "dovecot-keywords" {
+            // Custom keywords are stored as "<index> <keyword>" lines
+            custom_keywords.insert(
+                path,
+                String::from_utf8_lossy(&contents)
+                    .lines()
+                    .filter_map(|line| {
+                        let (index, keyword) = line.split_once(' ')?;
+                        let ch = char::from(b'a' + index.parse::<u8>().ok().filter(|i| *i < 26)?);
+                        Some((ch, Keyword::parse(keyword.trim())))
+                    })
+                    .collect(),
+            );
+        } else if let Some(is_new) = path.last().and_then(|dir| match dir.as_str() {
+            "cur" => Some(false),
+            "new" => Some(true),
+            _ => None,
+        }) {
+            path.pop();
+            entries.push((path, name, is_new, contents));
+        }
+    }
+
+    // The archive root is the longest common prefix of all folders, Maildir++ folders are never part of it
+    let mut root = entries
+        .first()
+        .map(|(path, ..)| path.clone())
+        .unwrap_or_default();
+    for (path, ..) in &entries {
+        let common = root
+            .iter()
+            .zip(path.iter())
+            .take_while(|(a, b)| a == b)
+            .count();
+        root.truncate(common);
+    }
+    if let Some(pos) = root.iter().position(|part| part.starts_with('.')) {
+        root.truncate(pos);
+    }
+
+    let mut messages = Vec::with_capacity(entries.len());
+    for (path, name, is_new, contents) in entries {
+        let mut keywords = Vec::new();
+        if !is_new
+            && let Some((_, flags)) = name
+                .rsplit_once(":2,")
+                .or_else(|| name.rsplit_once("!2,"))
+                .or_else(|| name.rsplit_once(";2,"))
+        {
+            let custom = custom_keywords.get(&path);
+            for flag in flags.chars() {
+                let keyword = match flag {
+                    'D' => Keyword::Draft,
+                    'F' => Keyword::Flagged,
+                    'P' => Keyword::Forwarded,
+                    'R' => Keyword::Answered,
+                    'S' => Keyword::Seen,
+                    'T' => Keyword::Deleted,
+                    'a'..='z' => {
+                        if let Some((_, keyword)) =
+                            custom.and_then(|custom| custom.iter().find(|(ch, _)| *ch == flag))
+                        {
+                            keyword.clone()
+                        } else {
+                            continue;
+                        }
+                    }
+                    _ => continue,
+                };
+                if !keywords.contains(&keyword) {
+                    keywords.push(keyword);
+                }
+            }
+        }
+
+        let folder = path
+            .into_iter()
+            .skip(root.len())
+            .flat_map(|part| {
+                if let Some(part) = part.strip_prefix('.') {
+                    part.split('.')
+                        .filter(|part| !part.is_empty())
+                        .map(|part| part.to_string())
+                        .collect::<Vec<_>>()
+                } else {
+                    vec![part]
+                }
+            })
+            .collect();
+
+        messages.push(MailStoreMessage {
+            folder,
+            received_at: name
+                .split_once('.')
+                .and_then(|(timestamp, _)| timestamp.parse::<u64>().ok()),
+            keywords,
+            contents,
+        });
+    }
+
+    Ok(messages)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    #[test]
+    fn parse_mbox_messages() {
+        let messages = parse_mbox(
+            concat!(
+                "From john@example.org Thu Nov 24 18:22:48 2022\n",
+                "From: john@example.org\n",
+                "Subject: first\n",
+                "Status: RO\n",
+                "X-Status: AF\n",
+                "\n",
+                ">From the start\n",
+                "\n",
+                "From jane@example.org Mon Jan  2 03:04:05 2023\n",
+                "From: jane@example.org\n",
+                "Subject: second\n",
+                "X-Keywords: $Label1, Work\n",
+                "\n",
+                "Hello\n",
+            )
+            .as_bytes(),
+        );
+
+        assert_eq!(
+            messages,
+            vec![
+                MailStoreMessage {
+                    folder: vec![],
+                    received_at: Some(1669314168),
+                    keywords: vec![Keyword::Seen, Keyword::Answered, Keyword::Flagged],
+                    contents: concat!(
+                        "From: john@example.org\n",
+                        "Subject: first\n",
+                        "Status: RO\n",
+                        "X-Status: AF\n",
+                        "\n",
+                        "From the start\n",
+                    )
+                    .as_bytes()
+                    .to_vec(),
+                },
+                MailStoreMessage {
+                    folder: vec![],
+                    received_at: Some(1672628645),
+                    keywords: vec![Keyword::parse("$Label1"), Keyword::parse("Work")],
+                    contents: concat!(
+                        "From: jane@example.org\n",
+                        "Subject: second\n",
+                        "X-Keywords: $Label1, Work\n",
+                        "\n",
+                        "Hello\n",
+                    )
+                    .as_bytes()
+                    .to_vec(),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn parse_maildir_archive() {
+        let mut archive = zip::ZipWriter::new(Cursor::new(Vec::new()));
+        for (name, contents) in [
+            (
+                "Maildir/cur/1700000000.M1P1.host:2,FS",
+                "Subject: inbox\r\n\r\n",
+            ),
+            ("Maildir/new/1700000001.M2P1.host", "Subject: new\r\n\r\n"),
+            (
+                "Maildir/.Work.Projects/cur/1700000002.M3P1.host:2,Ra",
+                "Subject: work\r\n\r\n",
+            ),
+            ("Maildir/.Work.Projects/dovecot-keywords", "0 $Label1\n"),
+            ("Maildir/dovecot-uidlist", "3 V1 N4\n"),
+        ] {
+            archive
+                .start_file(name, zip::write::SimpleFileOptions::default())
+                .unwrap();
+            archive.write_all(contents.as_bytes()).unwrap();
+        }
+        let data = archive.finish().unwrap().into_inner();
+
+        assert_eq!(
+            parse_maildir(&data).unwrap(),
+            vec![
+                MailStoreMessage {
+                    folder: vec![],
+                    received_at: Some(1700000000),
+                    keywords: vec![Keyword::Flagged, Keyword::Seen],
+                    contents: b"Subject: inbox\r\n\r\n".to_vec(),
+                },
+                MailStoreMessage {
+                    folder: vec![],
+                    received_at: Some(1700000001),
+                    keywords: vec![],
+                    contents: b"Subject: new\r\n\r\n".to_vec(),
+                },
+                MailStoreMessage {
+                    folder: vec!["Work".into(), "Projects".into()],
+                    received_at: Some(1700000002),
+                    keywords: vec![Keyword::Answered, Keyword::parse("$Label1")],
+                    contents: b"Subject: work\r\n\r\n".to_vec(),
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,372 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{identity::Identity, sieve::VacationResponse};
+use lz4_flex::frame::{FrameDecoder, FrameEncoder};
+use serde::{Deserialize, Serialize};
+use std::{
+    fs::File,
+    future::Future,
+    io::{BufReader, ErrorKind, Read, Write},
+    path::{Path, PathBuf},
+};
+use tokio::{io::AsyncWriteExt, sync::mpsc};
+
+pub mod export;
+pub mod import;
+pub mod mailstore;
+pub mod privacy;
+
+// Account archives are an LZ4 frame containing a sequence of JSON records,
+// each one optionally followed by raw contents (RFC 5322 messages, Sieve scripts).
+const ARCHIVE_MAGIC: &[u8] = b"STWACCT";
+const ARCHIVE_VERSION: u8 = 1;
+const ARCHIVE_FLUSH_SIZE: usize = 1024 * 1024;
+const ARCHIVE_READ_AHEAD: usize = 8;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum ArchiveRecord {
+    Account(AccountRecord),
+    Mailbox(MailboxRecord),
+    Email(EmailRecord),
+    SieveScript(SieveScriptRecord),
+    Identity(Identity),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AccountRecord {
+    pub name: String,
+    pub quota: u64,
+    pub used_quota: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MailboxRecord {
+    pub id: u32,
+    pub parent_id: Option<u32>,
+    pub name: String,
+    pub role: Option<String>,
+    pub sort_order: Option<u32>,
+    pub uid_validity: u32,
+    pub uid_next: u32,
+    pub is_subscribed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailRecord {
+    pub id: u32,
+    pub received_at: u64,
+    pub keywords: Vec<String>,
+    pub mailboxes: Vec<EmailMailboxRecord>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EmailMailboxRecord {
+    pub mailbox_id: u32,
+    pub uid: u32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SieveScriptRecord {
+    pub name: String,
+    pub is_active: bool,
+    pub vacation_response: Option<VacationResponse>,
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct MigrationSummary {
+    pub mailboxes: usize,
+    pub emails: usize,
+    pub sieve_scripts: usize,
+    pub identities: usize,
+    pub skipped: usize,
+    pub uid_changes: usize,
+    pub is_cancelled: bool,
+}
+
+pub struct ArchiveEntry {
+    pub record: ArchiveRecord,
+    pub contents: Vec<u8>,
+}
+
+// Records are compressed in memory and flushed to disk in chunks
+pub struct ArchiveWriter {
+    encoder: FrameEncoder<Vec<u8>>,
+    file: tokio::fs::File,
+    path: PathBuf,
+}
+
+// Records are decoded on a blocking thread and handed over as they are read
+pub struct ArchiveReader {
+    rx: mpsc::Receiver<trc::Result<ArchiveEntry>>,
+}
+
+struct ArchiveFileReader {
+    reader: FrameDecoder<BufReader<File>>,
+    max_entry_size: usize,
+}
+
+// Destination of exported account records and their raw contents
+pub trait RecordWriter: Send {
+    fn write(
+        &mut self,
+        record: &ArchiveRecord,
+        contents: &[u8],
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+impl ArchiveWriter {
+    pub async fn create(path: &Path) -> trc::Result<Self> {
+        let file = tokio::fs::File::create(path)
+            .await
+            .map_err(|err| io_error(err, path))?;
+        let mut encoder = FrameEncoder::new(Vec::with_capacity(ARCHIVE_FLUSH_SIZE));
+        encoder
+            .write_all(ARCHIVE_MAGIC)
+            .and_then(|_| encoder.write_all(&[ARCHIVE_VERSION]))
+            .map_err(|err| io_error(err, path))?;
+
+        Ok(Self {
+            encoder,
+            file,
+            path: path.to_path_buf(),
+        })
+    }
+
+    pub async fn write(&mut self, record: &ArchiveRecord, contents: &[u8]) -> trc::Result<()> {
+        let record = serde_json::to_vec(record).map_err(|err| {
+            trc::StoreEvent::UnexpectedError
+                .reason(err)
+                .caused_by(trc::location!())
+        })?;
+
+        for bytes in [record.as_slice(), contents] {
+            self.encoder
+                .write_all(&(bytes.len() as u32).to_le_bytes())
+                .and_then(|_| self.encoder.write_all(bytes))
+                .map_err(|err| io_error(err, &self.path))?;
+        }
+
+        if self.encoder.get_ref().len() >= ARCHIVE_FLUSH_SIZE {
+            let bytes = std::mem::take(self.encoder.get_mut());
+            self.file
+                .write_all(&bytes)
+                .await
+                .map_err(|err| io_error(err, &self.path))?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn finish(mut self) -> trc::Result<()> {
+        let bytes = self.encoder.finish().map_err(|err| {
+            trc::StoreEvent::FilesystemError
+                .reason(err)
+                .caused_by(trc::location!())
+        })?;
+        self.file
+            .write_all(&bytes)
+            .await
+            .map_err(|err| io_error(err, &self.path))?;
+        self.file
+            .sync_all()
+            .await
+            .map_err(|err| io_error(err, &self.path))
+    }
+}
+
+impl RecordWriter for ArchiveWriter {
+    async fn write(&mut self, record: &ArchiveRecord, contents: &[u8]) -> trc::Result<()> {
+        ArchiveWriter::write(self, record, contents).await
+    }
+}
+
+impl ArchiveReader {
+    pub async fn open(path: &Path, max_entry_size: usize) -> trc::Result<Self> {
+        let path = path.to_path_buf();
+        let mut reader =
+            tokio::task::spawn_blocking(move || ArchiveFileReader::open(&path, max_entry_size))
+                .await
+                .map_err(|err| {
+                    trc::EventType::Server(trc::ServerEvent::ThreadError)
+                        .reason(err)
+                        .caused_by(trc::location!())
+                })??;
+
+        let (tx, rx) = mpsc::channel(ARCHIVE_READ_AHEAD);
+        tokio::task::spawn_blocking(move || {
+            while let Some(entry) = reader.next().transpose() {
+                let is_err = entry.is_err();
+                if tx.blocking_send(entry).is_err() || is_err {
+                    break;
+                }
+            }
+        });
+
+        Ok(Self { rx })
+    }
+
+    pub async fn next(&mut self) -> trc::Result<Option<ArchiveEntry>> {
+        self.rx.recv().await.transpose()
+    }
+}
+
+impl ArchiveFileReader {
+    fn open(path: &Path, max_entry_size: usize) -> trc::Result<Self> {
+        let mut reader = FrameDecoder::new(BufReader::new(
+            File::open(path).map_err(|err| io_error(err, path))?,
+        ));
+        let mut header = [0u8; ARCHIVE_MAGIC.len() + 1];
+        reader
+            .read_exact(&mut header)
+            .map_err(|err| io_error(err, path))?;
+
+        if &header[..ARCHIVE_MAGIC.len()] != ARCHIVE_MAGIC {
+            Err(trc::StoreEvent::DataCorruption
+                .into_err()
+                .details("Not an account archive")
+                .ctx(trc::Key::Path, path.to_string_lossy().into_owned()))
+        } else if header[ARCHIVE_MAGIC.len()] != ARCHIVE_VERSION {
+            Err(trc::StoreEvent::NotSupported
+                .into_err()
+                .details("Unsupported account archive version")
+                .ctx(trc::Key::Version, header[ARCHIVE_MAGIC.len()] as u64))
+        } else {
+            Ok(Self {
+                reader,
+                max_entry_size,
+            })
+        }
+    }
+
+    fn next(&mut self) -> trc::Result<Option<ArchiveEntry>> {
+        let Some(record) = self.read_bytes(true)? else {
+            return Ok(None);
+        };
+        let record = serde_json::from_slice::<ArchiveRecord>(&record).map_err(|err| {
+            trc::StoreEvent::DeserializeError
+                .reason(err)
+                .caused_by(trc::location!())
+        })?;
+        let contents = self.read_bytes(false)?.unwrap_or_default();
+
+        Ok(Some(ArchiveEntry { record, contents }))
+    }
+
+    fn read_bytes(&mut self, allow_eof: bool) -> trc::Result<Option<Vec<u8>>> {
+        let mut len = [0u8; 4];
+        match self.reader.read_exact(&mut len) {
+            Ok(_) => {}
+            Err(err) if allow_eof && err.kind() == ErrorKind::UnexpectedEof => return Ok(None),
+            Err(err) => {
+                return Err(trc::StoreEvent::FilesystemError
+                    .reason(err)
+                    .caused_by(trc::location!()));
+            }
+        }
+
+        let len = u32::from_le_bytes(len) as usize;
+        if len > self.max_entry_size {
+            return Err(trc::StoreEvent::DataCorruption
+                .into_err()
+                .details("Account archive entry exceeds the maximum size")
+                .ctx(trc::Key::Size, len)
+                .ctx(trc::Key::Limit, self.max_entry_size)
+                .caused_by(trc::location!()));
+        }
+
+        let mut bytes = vec![0u8; len];
+        self.reader.read_exact(&mut bytes).map_err(|err| {
+            trc::StoreEvent::DataCorruption
+                .reason(err)
+                .details("Truncated account archive")
+                .caused_by(trc::location!())
+        })?;
+
+        Ok(Some(bytes))
+    }
+}
+
+fn io_error(err: std::io::Error, path: &Path) -> trc::Error {
+    trc::StoreEvent::FilesystemError
+        .reason(err)
+        .ctx(trc::Key::Path, path.to_string_lossy().into_owned())
+        .caused_by(trc::location!())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn account_archive_roundtrip() {
+        let path = std::env::temp_dir().join(format!(
+            "stalwart_account_archive_{}.lz4",
+            std::process::id()
+        ));
+        let records = [
+            (
+                ArchiveRecord::Account(AccountRecord {
+                    name: "jdoe".into(),
+                    quota: 1024,
+                    used_quota: 20,
+                }),
+                vec![],
+            ),
+            (
+                ArchiveRecord::Mailbox(MailboxRecord {
+                    id: 7,
+                    parent_id: Some(0),
+                    name: "Projects".into(),
+                    role: None,
+                    sort_order: Some(3),
+                    uid_validity: 12345,
+                    uid_next: 11,
+                    is_subscribed: true,
+                }),
+                vec![],
+            ),
+            (
+                ArchiveRecord::Email(EmailRecord {
+                    id: 1,
+                    received_at: 1700000000,
+                    keywords: vec!["$seen".into(), "$label1".into()],
+                    mailboxes: vec![EmailMailboxRecord {
+                        mailbox_id: 7,
+                        uid: 10,
+                    }],
+                }),
+                b"Subject: test\r\n\r\nhello\r\n".to_vec(),
+            ),
+        ];
+
+        let mut writer = ArchiveWriter::create(&path).await.unwrap();
+        for (record, contents) in &records {
+            writer.write(record, contents).await.unwrap();
+        }
+        writer.finish().await.unwrap();
+
+        let mut reader = ArchiveReader::open(&path, 1024).await.unwrap();
+        for (record, contents) in records {
+            let entry = reader.next().await.unwrap().unwrap();
+            assert_eq!(entry.record, record);
+            assert_eq!(entry.contents, contents);
+        }
+        assert!(reader.next().await.unwrap().is_none());
+
+        // Entries over the size limit are rejected before being allocated
+        let mut reader = ArchiveReader::open(&path, 16).await.unwrap();
+        assert!(reader.next().await.is_err());
+
+        std::fs::remove_file(&path).unwrap();
+    }
+}
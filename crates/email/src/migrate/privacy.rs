@@ -0,0 +1,235 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{
+    AccountRecord, ArchiveRecord, EmailRecord, MailboxRecord, MigrationSummary, RecordWriter,
+    SieveScriptRecord, export::AccountExport,
+};
+use crate::identity::Identity;
+use common::Server;
+use registry::{
+    schema::{
+        enums::MessageAuditEvent,
+        prelude::ObjectType,
+        structs::{LoginEntry, MessageAuditEntry},
+    },
+    types::EnumImpl,
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    io::{Cursor, Write},
+    time::Instant,
+};
+use store::{
+    ValueKey,
+    registry::RegistryQuery,
+    write::{RegistryClass, ValueClass},
+};
+use trc::AddContext;
+use types::id::Id;
+use zip::{ZipWriter, write::SimpleFileOptions};
+
+// Data exports are a ZIP file containing one JSON document per record type,
+// with messages and Sieve scripts stored as separate files next to them.
+pub trait AccountDataExport: Sync + Send {
+    fn account_data_export(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Vec<u8>>> + Send;
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportedFile<T> {
+    #[serde(flatten)]
+    pub record: T,
+    pub file: String,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ActivityRecord {
+    pub logins: Vec<LoginEntry>,
+    pub message_audit: Vec<MessageAuditEntry>,
+}
+
+struct DataExportWriter {
+    zip: ZipWriter<Cursor<Vec<u8>>>,
+    account: Option<AccountRecord>,
+    mailboxes: Vec<MailboxRecord>,
+    messages: Vec<ExportedFile<EmailRecord>>,
+    sieve_scripts: Vec<ExportedFile<SieveScriptRecord>>,
+    identities: Vec<Identity>,
+}
+
+impl AccountDataExport for Server {
+    async fn account_data_export(&self, account_id: u32) -> trc::Result<Vec<u8>> {
+        let op_start = Instant::now();
+        let mut summary = MigrationSummary::default();
+        let mut writer = DataExportWriter {
+            zip: ZipWriter::new(Cursor::new(Vec::new())),
+            account: None,
+            mailboxes: Vec::new(),
+            messages: Vec::new(),
+            sieve_scripts: Vec::new(),
+            identities: Vec::new(),
+        };
+        let exported_ids = self
+            .account_export_records(account_id, &mut writer, &mut summary)
+            .await?;
+
+        // Add the account's activity log
+        let activity = ActivityRecord {
+            logins: account_registry_items(self, account_id, ObjectType::LoginEntry).await?,
+            message_audit: account_registry_items(self, account_id, ObjectType::MessageAuditEntry)
+                .await?,
+        };
+
+        let account = writer.account.take();
+        let mailboxes = std::mem::take(&mut writer.mailboxes);
+        let messages = std::mem::take(&mut writer.messages);
+        let sieve_scripts = std::mem::take(&mut writer.sieve_scripts);
+        let identities = std::mem::take(&mut writer.identities);
+        writer.add_json("account.json", &account)?;
+        writer.add_json("mailboxes.json", &mailboxes)?;
+        writer.add_json("messages.json", &messages)?;
+        writer.add_json("sieve.json", &sieve_scripts)?;
+        writer.add_json("identities.json", &identities)?;
+        writer.add_json("activity.json", &activity)?;
+        let contents = writer
+            .zip
+            .finish()
+            .map_err(|err| {
+                trc::StoreEvent::UnexpectedError
+                    .reason(err)
+                    .caused_by(trc::location!())
+            })?
+            .into_inner();
+
+        // Record audit trail
+        self.audit_messages(
+            account_id,
+            MessageAuditEvent::Exported,
+            exported_ids,
+            None,
+            Some("Data export".into()),
+        )
+        .await;
+
+        trc::event!(
+            Store(trc::StoreEvent::AccountExported),
+            AccountId = account_id,
+            Total = summary.emails,
+            Size = contents.len(),
+            Elapsed = op_start.elapsed(),
+        );
+
+        Ok(contents)
+    }
+}
+
+impl RecordWriter for DataExportWriter {
+    async fn write(&mut self, record: &ArchiveRecord, contents: &[u8]) -> trc::Result<()> {
+        match record {
+            ArchiveRecord::Account(account) => {
+                self.account = Some(account.clone());
+            }
+            ArchiveRecord::Mailbox(mailbox) => {
+                self.mailboxes.push(mailbox.clone());
+            }
+            ArchiveRecord::Email(email) => {
+                let file = format!("messages/{}.eml", email.id);
+                self.add_file(&file, contents)?;
+                self.messages.push(ExportedFile {
+                    record: email.clone(),
+                    file,
+                });
+            }
+            ArchiveRecord::SieveScript(script) => {
+                let file = format!(
+                    "sieve/{}-{}.sieve",
+                    self.sieve_scripts.len(),
+                    safe_file_name(&script.name)
+                );
+                self.add_file(&file, contents)?;
+                self.sieve_scripts.push(ExportedFile {
+                    record: script.clone(),
+                    file,
+                });
+            }
+            ArchiveRecord::Identity(identity) => {
+                self.identities.push(identity.clone());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl DataExportWriter {
+    fn add_json<T: Serialize>(&mut self, name: &str, value: &T) -> trc::Result<()> {
+        let contents = serde_json::to_vec_pretty(value).map_err(|err| {
+            trc::StoreEvent::UnexpectedError
+                .reason(err)
+                .caused_by(trc::location!())
+        })?;
+        self.add_file(name, &contents)
+    }
+
+    fn add_file(&mut self, name: &str, contents: &[u8]) -> trc::Result<()> {
+        self.zip
+            .start_file(name, SimpleFileOptions::default())
+            .map_err(|err| err.to_string())
+            .and_then(|_| self.zip.write_all(contents).map_err(|err| err.to_string()))
+            .map_err(|err| {
+                trc::StoreEvent::UnexpectedError
+                    .reason(err)
+                    .details("Failed to write data export")
+                    .caused_by(trc::location!())
+            })
+    }
+}
+
+async fn account_registry_items<T: store::Deserialize + 'static>(
+    server: &Server,
+    account_id: u32,
+    object: ObjectType,
+) -> trc::Result<Vec<T>> {
+    let object_id = object.to_id();
+    let ids = server
+        .registry()
+        .query::<Vec<Id>>(RegistryQuery::new(object).with_account(account_id))
+        .await?;
+    let mut items = Vec::with_capacity(ids.len());
+    for id in ids {
+        if let Some(item) = server
+            .store()
+            .get_value::<T>(ValueKey::from(ValueClass::Registry(RegistryClass::Item {
+                object_id,
+                item_id: id.id(),
+            })))
+            .await
+            .caused_by(trc::location!())?
+        {
+            items.push(item);
+        }
+    }
+
+    Ok(items)
+}
+
+fn safe_file_name(name: &str) -> String {
+    name.chars()
+        .map(|ch| {
+            if ch.is_alphanumeric() || matches!(ch, '-' | '_' | '.') {
+                ch
+            } else {
+                '_'
+            }
+        })
+        .collect()
+}
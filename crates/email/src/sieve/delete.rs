@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::SieveScript;
+use super::{SieveScript, history::SieveScriptHistory};
 use common::{Server, auth::AccessToken, storage::index::ObjectIndexBuilder};
 use store::write::BatchBuilder;
 use store::{
@@ -42,6 +42,11 @@ impl SieveScriptDelete for Server {
             ))
             .await?
         {
+            // Remove any version retained for rollback
+            let previous = self
+                .sieve_script_previous_version(account_id, document_id)
+                .await?;
+
             // Delete record
             batch
                 .with_account_id(account_id)
@@ -56,8 +61,11 @@ impl SieveScriptDelete for Server {
                         )
                         .with_changed_by(access_token.account_tenant_ids()),
                 )
-                .caused_by(trc::location!())?
-                .commit_point();
+                .caused_by(trc::location!())?;
+            if let Some(previous) = previous {
+                previous.remove(batch);
+            }
+            batch.commit_point();
 
             Ok(true)
         } else {
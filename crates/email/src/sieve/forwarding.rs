@@ -0,0 +1,389 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::ActiveScript;
+use common::{Server, auth::EmailCache};
+use std::{future::Future, sync::Arc};
+use store::{
+    Serialize, ValueKey,
+    write::{AlignedBytes, Archive, ArchiveVersion, Archiver, BatchBuilder},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::PrincipalField};
+use utils::sanitize_email;
+
+pub const MAX_FORWARDING_RULES: usize = 32;
+pub const MAX_FORWARDING_ADDRESSES: usize = 10;
+pub const MAX_FORWARDING_VALUE_SIZE: usize = 255;
+
+// Name reported in Sieve events for the script generated from the settings
+pub const FORWARDING_SCRIPT_NAME: &str = "forwarding";
+
+#[derive(
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
+)]
+#[rkyv(derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct ForwardingSettings {
+    #[serde(default)]
+    pub forward_to: Vec<String>,
+    #[serde(default)]
+    pub keep_copy: bool,
+    #[serde(default)]
+    pub rules: Vec<ForwardingRule>,
+}
+
+#[derive(
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+)]
+#[rkyv(derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub struct ForwardingRule {
+    pub field: ForwardingField,
+    #[serde(default)]
+    pub operator: ForwardingOperator,
+    pub value: String,
+    pub forward_to: Vec<String>,
+    #[serde(default)]
+    pub keep_copy: bool,
+}
+
+#[derive(
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[rkyv(derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub enum ForwardingField {
+    From,
+    To,
+    Cc,
+    Subject,
+    ListId,
+}
+
+#[derive(
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Default,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[rkyv(derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub enum ForwardingOperator {
+    #[default]
+    Contains,
+    Is,
+    Matches,
+}
+
+pub trait SieveForwarding: Sync + Send {
+    fn forwarding_settings(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Option<ForwardingSettings>>> + Send;
+
+    fn forwarding_settings_update(
+        &self,
+        account_id: u32,
+        settings: Option<ForwardingSettings>,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn forwarding_settings_validate(
+        &self,
+        account_id: u32,
+        settings: &mut ForwardingSettings,
+    ) -> impl Future<Output = trc::Result<Result<(), String>>> + Send;
+
+    fn forwarding_script(
+        &self,
+        account_id: u32,
+        active_script: Option<&ActiveScript>,
+    ) -> impl Future<Output = trc::Result<Option<ActiveScript>>> + Send;
+}
+
+impl SieveForwarding for Server {
+    async fn forwarding_settings(
+        &self,
+        account_id: u32,
+    ) -> trc::Result<Option<ForwardingSettings>> {
+        self.store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                account_id,
+                Collection::Principal,
+                0,
+                PrincipalField::ForwardingSettings,
+            ))
+            .await
+            .caused_by(trc::location!())?
+            .map(|settings| settings.deserialize::<ForwardingSettings>())
+            .transpose()
+            .caused_by(trc::location!())
+    }
+
+    async fn forwarding_settings_update(
+        &self,
+        account_id: u32,
+        settings: Option<ForwardingSettings>,
+    ) -> trc::Result<()> {
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Principal)
+            .with_document(0);
+        if let Some(settings) = settings.filter(|settings| !settings.is_empty()) {
+            batch.set(
+                PrincipalField::ForwardingSettings,
+                Archiver::new(settings)
+                    .serialize()
+                    .caused_by(trc::location!())?,
+            );
+        } else {
+            batch.clear(PrincipalField::ForwardingSettings);
+        }
+        self.commit_batch(batch)
+            .await
+            .caused_by(trc::location!())
+            .map(|_| ())
+    }
+
+    // Normalizes the settings and makes sure the generated script compiles
+    async fn forwarding_settings_validate(
+        &self,
+        account_id: u32,
+        settings: &mut ForwardingSettings,
+    ) -> trc::Result<Result<(), String>> {
+        if settings.rules.len() > MAX_FORWARDING_RULES {
+            return Ok(Err(format!(
+                "At most {MAX_FORWARDING_RULES} forwarding rules are allowed."
+            )));
+        }
+
+        for rule in &mut settings.rules {
+            let value = rule.value.trim();
+            if value.is_empty() || value.len() > MAX_FORWARDING_VALUE_SIZE {
+                return Ok(Err("Invalid forwarding rule value.".to_string()));
+            }
+            rule.value = value.to_string();
+            if rule.forward_to.is_empty() {
+                return Ok(Err(
+                    "Forwarding rules require at least one address.".to_string()
+                ));
+            }
+        }
+
+        for addresses in std::iter::once(&mut settings.forward_to)
+            .chain(settings.rules.iter_mut().map(|rule| &mut rule.forward_to))
+        {
+            if addresses.len() > MAX_FORWARDING_ADDRESSES {
+                return Ok(Err(format!(
+                    "At most {MAX_FORWARDING_ADDRESSES} forwarding addresses are allowed."
+                )));
+            }
+
+            let mut sanitized = Vec::with_capacity(addresses.len());
+            for address in addresses.iter() {
+                let Some(address) = sanitize_email(address) else {
+                    return Ok(Err(format!("Invalid forwarding address {address:?}.")));
+                };
+
+                // Forwarding to the account itself would loop
+                if matches!(
+                    self.rcpt_id_from_email(&address)
+                        .await
+                        .caused_by(trc::location!())?,
+                    Some(EmailCache::Account(id)) if id == account_id
+                ) {
+                    return Ok(Err(format!(
+                        "Messages cannot be forwarded to {address:?}, it belongs to this account."
+                    )));
+                }
+
+                if !sanitized.contains(&address) {
+                    sanitized.push(address);
+                }
+            }
+            *addresses = sanitized;
+        }
+
+        if let Err(err) = self
+            .core
+            .sieve
+            .untrusted_compiler
+            .compile(settings.build_script(None).as_bytes())
+        {
+            return Ok(Err(format!(
+                "Forwarding settings could not be compiled: {err}"
+            )));
+        }
+
+        Ok(Ok(()))
+    }
+
+    // The forwarding rules run before the user's active script, which is
+    // included at the end so both can be used at the same time.
+    async fn forwarding_script(
+        &self,
+        account_id: u32,
+        active_script: Option<&ActiveScript>,
+    ) -> trc::Result<Option<ActiveScript>> {
+        let Some(settings) = self.forwarding_settings(account_id).await? else {
+            return Ok(None);
+        };
+
+        let script = settings.build_script(active_script.map(|s| s.script_name.as_str()));
+        match self
+            .core
+            .sieve
+            .untrusted_compiler
+            .compile(script.as_bytes())
+        {
+            Ok(script) => Ok(Some(ActiveScript {
+                document_id: active_script.map_or(u32::MAX, |s| s.document_id),
+                version: active_script.map_or(ArchiveVersion::Unversioned, |s| s.version),
+                script_name: FORWARDING_SCRIPT_NAME.to_string(),
+                script: Arc::new(script),
+            })),
+            Err(err) => {
+                trc::event!(
+                    Sieve(trc::SieveEvent::UnexpectedError),
+                    AccountId = account_id,
+                    Id = FORWARDING_SCRIPT_NAME.to_string(),
+                    Reason = err.to_string(),
+                    Details = "Failed to compile forwarding script.",
+                );
+                Ok(None)
+            }
+        }
+    }
+}
+
+impl ForwardingSettings {
+    pub fn is_empty(&self) -> bool {
+        self.forward_to.is_empty() && self.rules.is_empty()
+    }
+
+    pub fn build_script(&self, include: Option<&str>) -> String {
+        let mut script = String::with_capacity(256);
+        let mut require = Vec::with_capacity(2);
+        if self.keep_copy || self.rules.iter().any(|rule| rule.keep_copy) {
+            require.push("\"copy\"");
+        }
+        if include.is_some() {
+            require.push("\"include\"");
+        }
+        if !require.is_empty() {
+            script.push_str("require [");
+            script.push_str(&require.join(", "));
+            script.push_str("];\r\n\r\n");
+        }
+
+        for rule in &self.rules {
+            script.push_str(match rule.field {
+                ForwardingField::From | ForwardingField::To | ForwardingField::Cc => "if address ",
+                ForwardingField::Subject | ForwardingField::ListId => "if header ",
+            });
+            script.push_str(match rule.operator {
+                ForwardingOperator::Contains => ":contains ",
+                ForwardingOperator::Is => ":is ",
+                ForwardingOperator::Matches => ":matches ",
+            });
+            script.push_str(match rule.field {
+                ForwardingField::From => "\"from\" ",
+                ForwardingField::To => "\"to\" ",
+                ForwardingField::Cc => "\"cc\" ",
+                ForwardingField::Subject => "\"subject\" ",
+                ForwardingField::ListId => "\"list-id\" ",
+            });
+            push_string(&mut script, &rule.value);
+            script.push_str(" {\r\n");
+            push_redirects(&mut script, &rule.forward_to, rule.keep_copy, "    ");
+            script.push_str("}\r\n");
+        }
+
+        push_redirects(&mut script, &self.forward_to, self.keep_copy, "");
+
+        if let Some(name) = include {
+            script.push_str("include :personal ");
+            push_string(&mut script, name);
+            script.push_str(";\r\n");
+        }
+
+        script
+    }
+}
+
+// Redirects without a copy cancel the implicit keep and stop processing,
+// so the user's own script does not file the message.
+fn push_redirects(script: &mut String, addresses: &[String], keep_copy: bool, indent: &str) {
+    if addresses.is_empty() {
+        return;
+    }
+
+    for address in addresses {
+        script.push_str(indent);
+        script.push_str(if keep_copy {
+            "redirect :copy "
+        } else {
+            "redirect "
+        });
+        push_string(script, address);
+        script.push_str(";\r\n");
+    }
+    if !keep_copy {
+        script.push_str(indent);
+        script.push_str("stop;\r\n");
+    }
+}
+
+fn push_string(script: &mut String, value: &str) {
+    script.push('"');
+    for ch in value.chars() {
+        match ch {
+            '\\' | '"' => {
+                script.push('\\');
+            }
+            '\r' | '\n' => {
+                continue;
+            }
+            _ => (),
+        }
+        script.push(ch);
+    }
+    script.push('"');
+}
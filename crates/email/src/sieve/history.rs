@@ -0,0 +1,153 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::SieveScript;
+use common::Server;
+use std::future::Future;
+use store::{
+    Serialize, ValueKey,
+    write::{AlignedBytes, Archive, Archiver, BatchBuilder, BlobLink, BlobOp},
+};
+use trc::AddContext;
+use types::{blob_hash::BlobHash, collection::Collection, field::SieveField};
+
+#[derive(rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Clone, PartialEq, Eq)]
+#[rkyv(derive(Debug))]
+pub struct SieveScriptVersion {
+    pub blob_hash: BlobHash,
+    pub size: u32,
+}
+
+pub trait SieveScriptHistory: Sync + Send {
+    fn sieve_script_total_size(
+        &self,
+        account_id: u32,
+        exclude_id: Option<u32>,
+    ) -> impl Future<Output = trc::Result<u64>> + Send;
+
+    fn sieve_script_previous_version(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> impl Future<Output = trc::Result<Option<SieveScriptVersion>>> + Send;
+}
+
+impl SieveScriptHistory for Server {
+    async fn sieve_script_total_size(
+        &self,
+        account_id: u32,
+        exclude_id: Option<u32>,
+    ) -> trc::Result<u64> {
+        let mut total_size = 0u64;
+
+        for document_id in self
+            .document_ids(account_id, Collection::SieveScript, SieveField::Name)
+            .await
+            .caused_by(trc::location!())?
+        {
+            if Some(document_id) == exclude_id {
+                continue;
+            }
+
+            if let Some(script) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::SieveScript,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            {
+                total_size += u32::from(
+                    script
+                        .unarchive::<SieveScript>()
+                        .caused_by(trc::location!())?
+                        .size,
+                ) as u64;
+            }
+        }
+
+        Ok(total_size)
+    }
+
+    async fn sieve_script_previous_version(
+        &self,
+        account_id: u32,
+        document_id: u32,
+    ) -> trc::Result<Option<SieveScriptVersion>> {
+        self.store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                account_id,
+                Collection::SieveScript,
+                document_id,
+                SieveField::PreviousVersion,
+            ))
+            .await
+            .caused_by(trc::location!())?
+            .map(|version| version.deserialize::<SieveScriptVersion>())
+            .transpose()
+            .caused_by(trc::location!())
+    }
+}
+
+impl SieveScriptVersion {
+    pub fn new(blob_hash: BlobHash, size: u32) -> Self {
+        SieveScriptVersion { blob_hash, size }
+    }
+
+    // Keeps this version as the one to roll back to, replacing any older version.
+    // The batch must point to the script document and the script's blob has to be
+    // updated (to `new_hash`) in the same batch, after which the link to this
+    // version's blob is restored.
+    pub fn retain(
+        self,
+        batch: &mut BatchBuilder,
+        previous: Option<SieveScriptVersion>,
+        new_hash: &BlobHash,
+    ) -> trc::Result<()> {
+        if &self.blob_hash == new_hash {
+            return Ok(());
+        }
+
+        if let Some(previous) = previous
+            && previous.blob_hash != self.blob_hash
+            && &previous.blob_hash != new_hash
+        {
+            batch.clear(BlobOp::Link {
+                hash: previous.blob_hash,
+                to: BlobLink::Document,
+            });
+        }
+
+        batch
+            .set(
+                BlobOp::Link {
+                    hash: self.blob_hash.clone(),
+                    to: BlobLink::Document,
+                },
+                Vec::new(),
+            )
+            .set(
+                SieveField::PreviousVersion,
+                Archiver::new(self)
+                    .serialize()
+                    .caused_by(trc::location!())?,
+            );
+
+        Ok(())
+    }
+
+    // Removes the retained version, the batch must point to the script document
+    pub fn remove(self, batch: &mut BatchBuilder) {
+        batch
+            .clear(BlobOp::Link {
+                hash: self.blob_hash,
+                to: BlobLink::Document,
+            })
+            .clear(SieveField::PreviousVersion);
+    }
+}
@@ -0,0 +1,442 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{
+    SieveRunLimits,
+    ingest::{SieveScriptIngest, parse_special_use, sieve_keywords},
+    sieve_list_contains,
+};
+use crate::{
+    cache::{MessageCacheFetch, mailbox::MailboxCacheAccess},
+    mailbox::{INBOX_ID, TRASH_ID, manage::MailboxFnc},
+    message::metadata::MessageMetadata,
+};
+use common::{Server, auth::AccessToken, scripts::plugins::PluginContext};
+use mail_parser::MessageParser;
+use sieve::{Event, Input, Mailbox};
+use std::{future::Future, str::FromStr, sync::Arc};
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive, ValueClass},
+};
+use trc::{AddContext, SieveEvent};
+use types::{
+    collection::Collection,
+    field::{EmailField, MailboxField, PrincipalField},
+    id::Id,
+    keyword::Keyword,
+    special_use::SpecialUse,
+};
+
+// RFC 6785 - Support for Internet Message Access Protocol (IMAP) Events in Sieve
+pub const IMAPSIEVE_SCRIPT_ENTRY: &str = "/shared/imapsieve/script";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImapSieveCause {
+    Append,
+    Copy,
+    Flag,
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ImapSieveResult {
+    pub document_id: u32,
+    pub keep: bool,
+    pub flags: Option<Vec<Keyword>>,
+    pub file_into: Vec<u32>,
+}
+
+pub trait ImapSieveRun: Sync + Send {
+    fn imapsieve_script_name(
+        &self,
+        account_id: u32,
+        mailbox_id: Option<u32>,
+    ) -> impl Future<Output = trc::Result<Option<String>>> + Send;
+
+    #[allow(clippy::too_many_arguments)]
+    fn imapsieve_run(
+        &self,
+        access_token: &AccessToken,
+        account_id: u32,
+        mailbox_id: u32,
+        cause: ImapSieveCause,
+        messages: Vec<(u32, Vec<Keyword>)>,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<Vec<ImapSieveResult>>> + Send;
+}
+
+impl ImapSieveRun for Server {
+    async fn imapsieve_script_name(
+        &self,
+        account_id: u32,
+        mailbox_id: Option<u32>,
+    ) -> trc::Result<Option<String>> {
+        let (collection, document_id, class) = if let Some(mailbox_id) = mailbox_id {
+            (
+                Collection::Mailbox,
+                mailbox_id,
+                ValueClass::Property(MailboxField::ImapSieveScript.into()),
+            )
+        } else {
+            (
+                Collection::Principal,
+                0,
+                ValueClass::Property(PrincipalField::ImapSieveScript.into()),
+            )
+        };
+
+        self.store()
+            .get_value::<String>(ValueKey {
+                account_id,
+                collection: collection.into(),
+                document_id,
+                class,
+            })
+            .await
+            .caused_by(trc::location!())
+    }
+
+    async fn imapsieve_run(
+        &self,
+        access_token: &AccessToken,
+        account_id: u32,
+        mailbox_id: u32,
+        cause: ImapSieveCause,
+        messages: Vec<(u32, Vec<Keyword>)>,
+        session_id: u64,
+    ) -> trc::Result<Vec<ImapSieveResult>> {
+        // Obtain the script bound to the mailbox, falling back to the account-wide script
+        let script_name = if let Some(script_name) = self
+            .imapsieve_script_name(account_id, mailbox_id.into())
+            .await?
+        {
+            script_name
+        } else if let Some(script_name) = self.imapsieve_script_name(account_id, None).await? {
+            script_name
+        } else {
+            return Ok(vec![]);
+        };
+        let Some(script) = self
+            .sieve_script_get_by_name(account_id, &script_name)
+            .await
+            .caused_by(trc::location!())?
+            .map(Arc::new)
+        else {
+            trc::event!(
+                Sieve(SieveEvent::ScriptNotFound),
+                AccountId = account_id,
+                Id = script_name,
+                SpanId = session_id
+            );
+            return Ok(vec![]);
+        };
+
+        let mut cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let mailbox_name = cache
+            .mailbox_by_id(&mailbox_id)
+            .map(|m| m.path.clone())
+            .unwrap_or_default();
+        let account_info = self.account(account_id).await.caused_by(trc::location!())?;
+        let user_name = if access_token.account_id() != account_id {
+            self.account(access_token.account_id())
+                .await
+                .caused_by(trc::location!())?
+                .name()
+                .to_string()
+        } else {
+            account_info.name().to_string()
+        };
+        let mut results = Vec::with_capacity(messages.len());
+
+        for (document_id, changed_flags) in messages {
+            // Obtain raw message
+            let Some(metadata_) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::property(
+                    account_id,
+                    Collection::Email,
+                    document_id,
+                    EmailField::Metadata,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+            let metadata = metadata_
+                .unarchive::<MessageMetadata>()
+                .caused_by(trc::location!())?;
+            let Some(blob) = self
+                .blob_store()
+                .get_blob(metadata.blob_hash.0.as_slice(), 0..usize::MAX)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+            let mut raw_message = Vec::with_capacity(blob.len());
+            raw_message.extend_from_slice(metadata.raw_headers.as_ref());
+            raw_message.extend_from_slice(
+                blob.get(metadata.blob_body_offset.to_native() as usize..)
+                    .unwrap_or_default(),
+            );
+            let Some(message) = MessageParser::new().parse(&raw_message) else {
+                continue;
+            };
+
+            // Create Sieve instance
+            let mut instance = self.core.sieve.untrusted_runtime.filter_parsed(message);
+            instance.set_user_address(account_info.name());
+            instance.set_env_variable("imap.user", user_name.clone());
+            instance.set_env_variable("imap.email", account_info.name().to_string());
+            instance.set_env_variable("imap.cause", cause.as_str());
+            instance.set_env_variable("imap.mailbox", mailbox_name.clone());
+            if cause == ImapSieveCause::Flag {
+                instance.set_env_variable(
+                    "imap.changedflags",
+                    changed_flags
+                        .iter()
+                        .map(|flag| flag.to_string())
+                        .collect::<Vec<_>>()
+                        .join(" "),
+                );
+            }
+
+            let mut input = Input::script(script_name.clone(), script.clone());
+            let mut result = ImapSieveResult {
+                document_id,
+                ..Default::default()
+            };
+            let mut do_discard = false;
+            let limits = SieveRunLimits::new(self, account_id, session_id);
+
+            while let Some(event) = instance.run(input) {
+                if limits.is_time_exceeded(&script_name) {
+                    break;
+                }
+
+                match event {
+                    Ok(event) => match event {
+                        Event::IncludeScript { name, .. } => match &name {
+                            sieve::Script::Personal(name_) => {
+                                if let Ok(Some(script)) =
+                                    self.sieve_script_get_by_name(account_id, name_).await
+                                {
+                                    input = Input::script(name, script);
+                                } else {
+                                    input = false.into();
+                                }
+                            }
+                            sieve::Script::Global(name_) => {
+                                if let Ok(Some(script)) = self
+                                    .get_untrusted_sieve_script_for_account(
+                                        &name_.to_lowercase(),
+                                        account_id,
+                                        session_id,
+                                    )
+                                    .await
+                                {
+                                    input = Input::script(name, script);
+                                } else {
+                                    input = false.into();
+                                }
+                            }
+                        },
+                        Event::MailboxExists {
+                            mailboxes,
+                            special_use,
+                        } => {
+                            let mut exists = !mailboxes.is_empty() || !special_use.is_empty();
+                            for mailbox in mailboxes {
+                                exists &= match mailbox {
+                                    Mailbox::Name(name) => cache.mailbox_by_path(&name).is_some(),
+                                    Mailbox::Id(id) => Id::from_str(&id)
+                                        .is_ok_and(|id| cache.has_mailbox_id(&id.document_id())),
+                                };
+                            }
+                            for role in special_use.iter().map(|v| parse_special_use(v)) {
+                                exists &= match role {
+                                    Some(SpecialUse::Inbox | SpecialUse::Trash) => true,
+                                    Some(role) => cache.mailbox_by_role(&role).is_some(),
+                                    None => false,
+                                };
+                            }
+                            input = exists.into();
+                        }
+                        Event::Discard => {
+                            do_discard = true;
+                            input = true.into();
+                        }
+                        Event::Keep { flags, message_id } => {
+                            if message_id == 0 {
+                                result.keep = true;
+                                if !flags.is_empty() {
+                                    result.flags = Some(sieve_keywords(flags));
+                                }
+                            }
+                            input = true.into();
+                        }
+                        Event::FileInto {
+                            folder,
+                            flags,
+                            mailbox_id,
+                            special_use,
+                            create,
+                            message_id,
+                        } => {
+                            let mut target_id = u32::MAX;
+
+                            // Find mailbox by Id
+                            if let Some(mailbox_id) = mailbox_id.and_then(|m| Id::from_str(&m).ok())
+                            {
+                                let mailbox_id = mailbox_id.document_id();
+                                if cache.has_mailbox_id(&mailbox_id) {
+                                    target_id = mailbox_id;
+                                }
+                            }
+
+                            // Find mailbox by role
+                            if target_id == u32::MAX
+                                && let Some(special_use) =
+                                    special_use.as_deref().and_then(parse_special_use)
+                            {
+                                target_id = match special_use {
+                                    SpecialUse::Inbox => INBOX_ID,
+                                    SpecialUse::Trash => TRASH_ID,
+                                    role => cache
+                                        .mailbox_by_role(&role)
+                                        .map(|m| m.document_id)
+                                        .unwrap_or(u32::MAX),
+                                };
+                            }
+
+                            // Find mailbox by name
+                            if target_id == u32::MAX {
+                                if !create {
+                                    if let Some(m) = cache.mailbox_by_path(&folder) {
+                                        target_id = m.document_id;
+                                    }
+                                } else if let Some(document_id) = self
+                                    .mailbox_create_path(
+                                        account_id,
+                                        &folder,
+                                        special_use.as_deref().and_then(parse_special_use),
+                                    )
+                                    .await
+                                    .caused_by(trc::location!())?
+                                {
+                                    cache = self
+                                        .get_cached_messages(account_id)
+                                        .await
+                                        .caused_by(trc::location!())?;
+                                    target_id = document_id;
+                                }
+                            }
+
+                            if message_id == 0
+                                && target_id != u32::MAX
+                                && target_id != mailbox_id
+                                && !result.file_into.contains(&target_id)
+                            {
+                                result.file_into.push(target_id);
+                                if !flags.is_empty() {
+                                    result.flags = Some(sieve_keywords(flags));
+                                }
+                            }
+                            input = true.into();
+                        }
+                        Event::Function { id, arguments } => {
+                            input = self
+                                .core
+                                .run_plugin(
+                                    id,
+                                    PluginContext {
+                                        session_id,
+                                        server: self,
+                                        message: instance.message(),
+                                        modifications: &mut Vec::new(),
+                                        access_token: access_token.into(),
+                                        arguments,
+                                    },
+                                )
+                                .await;
+                        }
+                        Event::CreatedMessage { .. } => {
+                            input = true.into();
+                        }
+                        Event::ListContains {
+                            lists,
+                            values,
+                            match_as,
+                        } => {
+                            input = sieve_list_contains(
+                                self,
+                                lists,
+                                &values,
+                                match_as,
+                                &script_name,
+                                account_id,
+                                session_id,
+                            )
+                            .await
+                            .into();
+                        }
+                        Event::DuplicateId { .. }
+                        | Event::Reject { .. }
+                        | Event::SendMessage { .. }
+                        | Event::Notify { .. }
+                        | Event::SetEnvelope { .. } => {
+                            // Not available for IMAP events
+                            trc::event!(
+                                Sieve(SieveEvent::NotSupported),
+                                AccountId = account_id,
+                                Id = script_name.clone(),
+                                Details = "Action not available for IMAP events.",
+                                SpanId = session_id
+                            );
+                            input = false.into();
+                        }
+                    },
+
+                    #[cfg(feature = "test_mode")]
+                    Err(sieve::runtime::RuntimeError::ScriptErrorMessage(err)) => {
+                        panic!("Sieve test failed: {}", err);
+                    }
+
+                    Err(err) => {
+                        if limits.runtime_error(&script_name, err) {
+                            break;
+                        }
+
+                        input = true.into();
+                    }
+                }
+            }
+
+            // Fail-safe, leave the message in place unless it was explicitly moved or discarded
+            if !do_discard && result.file_into.is_empty() {
+                result.keep = true;
+            }
+
+            results.push(result);
+        }
+
+        Ok(results)
+    }
+}
+
+impl ImapSieveCause {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ImapSieveCause::Append => "APPEND",
+            ImapSieveCause::Copy => "COPY",
+            ImapSieveCause::Flag => "FLAG",
+        }
+    }
+}
@@ -4,16 +4,26 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::{ActiveScript, SeenIdHash, SieveScript};
+use super::{
+    ActiveScript, SeenIdHash, SieveRunLimits, SieveScript,
+    forwarding::SieveForwarding,
+    redirect::SieveRedirectGraph,
+    sieve_list_contains,
+    stats::{SieveActionStats, SieveStats},
+};
 use crate::{
     cache::{MessageCacheFetch, mailbox::MailboxCacheAccess},
-    mailbox::{INBOX_ID, TRASH_ID, manage::MailboxFnc},
+    mailbox::{INBOX_ID, JUNK_ID, TRASH_ID, manage::MailboxFnc},
     message::{
         delivery::{AutogeneratedMessage, IngestRecipient},
         ingest::{EmailIngest, IngestEmail, IngestSource, IngestedEmail},
     },
 };
-use common::{Server, auth::AccessToken, scripts::plugins::PluginContext};
+use common::{
+    Server,
+    auth::AccessToken,
+    scripts::{notify::SieveNotification, plugins::PluginContext},
+};
 use mail_parser::MessageParser;
 use sieve::{Envelope, Event, Input, Mailbox, Recipient, Sieve, SpamStatus};
 use std::{borrow::Cow, sync::Arc};
@@ -36,6 +46,24 @@ use types::{
     special_use::SpecialUse,
 };
 
+// Properties of the original message that are kept when the script files it,
+// used for messages that were not received over SMTP.
+#[derive(Debug, Default)]
+pub struct SieveIngestDefaults {
+    pub keywords: Vec<Keyword>,
+    pub received_at: Option<u64>,
+}
+
+// Outcome of evaluating a recipient's active script without performing any
+// of its actions, used to report where a message would be delivered.
+#[derive(Debug, Default)]
+pub struct SieveEvaluation {
+    pub mailboxes: Vec<String>,
+    pub redirects: Vec<String>,
+    pub reject_reason: Option<String>,
+    pub discard: bool,
+}
+
 struct SieveMessage<'x> {
     pub raw_message: Cow<'x, [u8]>,
     pub file_into: Vec<u32>,
@@ -48,16 +76,35 @@ pub trait SieveScriptIngest: Sync + Send {
     fn sieve_script_ingest(
         &self,
         access_token: &AccessToken,
-        blob_hash: &BlobHash,
+        blob_hash: Option<&BlobHash>,
         raw_message: &[u8],
         envelope_from: &str,
         envelope_from_authenticated: bool,
         envelope_to: &IngestRecipient,
         session_id: u64,
         active_script: ActiveScript,
+        defaults: SieveIngestDefaults,
         autogenerated: &mut Vec<AutogeneratedMessage>,
     ) -> impl Future<Output = trc::Result<IngestedEmail>> + Send;
 
+    fn sieve_script_reject_reason(
+        &self,
+        account_id: u32,
+        raw_message: &[u8],
+        envelope_from: &str,
+        envelope_to: &IngestRecipient,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<Option<String>>> + Send;
+
+    fn sieve_script_evaluate(
+        &self,
+        account_id: u32,
+        raw_message: &[u8],
+        envelope_from: &str,
+        envelope_to: &IngestRecipient,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<SieveEvaluation>> + Send;
+
     fn sieve_script_get_active_id(
         &self,
         account_id: u32,
@@ -68,6 +115,11 @@ pub trait SieveScriptIngest: Sync + Send {
         account_id: u32,
     ) -> impl Future<Output = trc::Result<Option<ActiveScript>>> + Send;
 
+    fn sieve_script_get_user_active(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Option<ActiveScript>>> + Send;
+
     fn sieve_script_get_by_name(
         &self,
         account_id: u32,
@@ -86,13 +138,14 @@ impl SieveScriptIngest for Server {
     async fn sieve_script_ingest(
         &self,
         access_token: &AccessToken,
-        blob_hash: &BlobHash,
+        blob_hash: Option<&BlobHash>,
         raw_message: &[u8],
         envelope_from: &str,
         envelope_from_authenticated: bool,
         envelope_to: &IngestRecipient,
         session_id: u64,
         active_script: ActiveScript,
+        mut defaults: SieveIngestDefaults,
         autogenerated: &mut Vec<AutogeneratedMessage>,
     ) -> trc::Result<IngestedEmail> {
         // Parse message
@@ -162,8 +215,14 @@ impl SieveScriptIngest for Server {
             imap_uids: Vec::new(),
         };
         let mut checked_ids: AHashMap<SeenIdHash, bool> = AHashMap::new();
+        let mut limits = SieveRunLimits::new(self, account_id, session_id);
+        let mut stats = SieveActionStats::default();
 
         while let Some(event) = instance.run(input) {
+            if limits.is_time_exceeded(&active_script.script_name) {
+                break;
+            }
+
             match event {
                 Ok(event) => match event {
                     Event::IncludeScript { name, .. } => match &name {
@@ -177,10 +236,15 @@ impl SieveScriptIngest for Server {
                             }
                         }
                         sieve::Script::Global(name_) => {
-                            if let Some(script) =
-                                self.get_untrusted_sieve_script(&name_.to_lowercase(), session_id)
+                            if let Ok(Some(script)) = self
+                                .get_untrusted_sieve_script_for_account(
+                                    &name_.to_lowercase(),
+                                    account_id,
+                                    session_id,
+                                )
+                                .await
                             {
-                                input = Input::script(name, script.clone());
+                                input = Input::script(name, script);
                             } else {
                                 input = false.into();
                             }
@@ -192,7 +256,7 @@ impl SieveScriptIngest for Server {
                     } => {
                         if !mailboxes.is_empty() {
                             let mut special_use_ids = Vec::with_capacity(special_use.len());
-                            for role in special_use.iter().map(|v| SpecialUse::parse(v)) {
+                            for role in special_use.iter().map(|v| parse_special_use(v)) {
                                 special_use_ids.push(match role {
                                     Some(SpecialUse::Inbox) => INBOX_ID,
                                     Some(SpecialUse::Trash) => TRASH_ID,
@@ -233,7 +297,7 @@ impl SieveScriptIngest for Server {
                         } else if !special_use.is_empty() {
                             let mut result = true;
 
-                            for role in special_use.iter().map(|v| SpecialUse::parse(v)) {
+                            for role in special_use.iter().map(|v| parse_special_use(v)) {
                                 match role {
                                     Some(SpecialUse::Inbox | SpecialUse::Trash) => {}
                                     Some(other) if cache.mailbox_by_role(&other).is_some() => {}
@@ -275,20 +339,23 @@ impl SieveScriptIngest for Server {
                         }
                     }
                     Event::Discard => {
+                        stats.discard += 1;
                         do_discard = true;
                         input = true.into();
                     }
                     Event::Reject { reason, .. } => {
+                        stats.reject += 1;
                         reject_reason = reason.into();
                         do_discard = true;
                         input = true.into();
                     }
                     Event::Keep { flags, message_id } => {
                         if let Some(message) = messages.get_mut(message_id) {
-                            message.flags = flags.into_iter().map(Keyword::from).collect();
+                            message.flags = sieve_keywords(flags);
                             if !message.file_into.contains(&INBOX_ID) {
                                 message.file_into.push(INBOX_ID);
                             }
+                            stats.keep += 1;
                             do_deliver = true;
                         } else {
                             trc::event!(
@@ -321,7 +388,7 @@ impl SieveScriptIngest for Server {
                         // Find mailbox by role
                         if target_id == u32::MAX
                             && let Some(special_use) =
-                                special_use.as_deref().and_then(SpecialUse::parse)
+                                special_use.as_deref().and_then(parse_special_use)
                         {
                             match special_use {
                                 SpecialUse::Inbox => {
@@ -345,7 +412,11 @@ impl SieveScriptIngest for Server {
                                     target_id = m.document_id;
                                 }
                             } else if let Some(document_id) = self
-                                .mailbox_create_path(account_id, &folder)
+                                .mailbox_create_path(
+                                    account_id,
+                                    &folder,
+                                    special_use.as_deref().and_then(parse_special_use),
+                                )
                                 .await
                                 .caused_by(trc::location!())?
                             {
@@ -363,11 +434,12 @@ impl SieveScriptIngest for Server {
                         }
 
                         if let Some(message) = messages.get_mut(message_id) {
-                            message.flags = flags.into_iter().map(Keyword::from).collect();
+                            message.flags = sieve_keywords(flags);
                             if !message.file_into.contains(&target_id) {
                                 message.file_into.push(target_id);
                             }
                             message.did_file_into = true;
+                            stats.file_into += 1;
                             do_deliver = true;
                         } else {
                             trc::event!(
@@ -386,7 +458,7 @@ impl SieveScriptIngest for Server {
                     } => {
                         input = true.into();
                         if let Some(message) = messages.get(message_id) {
-                            let recipients: Vec<String> = match recipient {
+                            let mut recipients: Vec<String> = match recipient {
                                 Recipient::Address(rcpt) => vec![rcpt],
                                 Recipient::Group(rcpts) => rcpts,
                                 Recipient::List(_) => {
@@ -395,6 +467,36 @@ impl SieveScriptIngest for Server {
                                 }
                             };
 
+                            if message_id == 0 {
+                                let mut allowed = Vec::with_capacity(recipients.len());
+                                for rcpt in recipients {
+                                    if self
+                                        .sieve_redirect_loops(account_id, envelope_from, &rcpt)
+                                        .await?
+                                    {
+                                        trc::event!(
+                                            Sieve(SieveEvent::RedirectLoop),
+                                            AccountId = account_id,
+                                            Id = active_script.script_name.to_string(),
+                                            From = envelope_from.to_string(),
+                                            To = rcpt,
+                                            SpanId = session_id,
+                                        );
+                                    } else {
+                                        allowed.push(rcpt);
+                                    }
+                                }
+                                recipients = allowed;
+
+                                if recipients.is_empty()
+                                    || !limits
+                                        .is_redirect_allowed(&active_script.script_name)
+                                        .await?
+                                {
+                                    continue;
+                                }
+                            }
+
                             if message.raw_message.len() <= self.core.email.mail_max_size {
                                 trc::event!(
                                     Sieve(SieveEvent::SendMessage),
@@ -407,6 +509,19 @@ impl SieveScriptIngest for Server {
                                     SpanId = session_id
                                 );
 
+                                if message_id == 0 {
+                                    stats.redirect += 1;
+                                    if let Err(err) =
+                                        self.sieve_redirect_record(account_id, &recipients).await
+                                    {
+                                        trc::error!(
+                                            err.details("Failed to record redirect targets.")
+                                                .span_id(session_id)
+                                                .caused_by(trc::location!())
+                                        );
+                                    }
+                                }
+
                                 autogenerated.push(AutogeneratedMessage {
                                     sender_address: mail_from.clone(),
                                     recipients,
@@ -436,9 +551,74 @@ impl SieveScriptIngest for Server {
                             continue;
                         }
                     }
-                    Event::ListContains { .. }
-                    | Event::Notify { .. }
-                    | Event::SetEnvelope { .. } => {
+                    Event::ListContains {
+                        lists,
+                        values,
+                        match_as,
+                    } => {
+                        input = sieve_list_contains(
+                            self,
+                            lists,
+                            &values,
+                            match_as,
+                            &active_script.script_name,
+                            account_id,
+                            session_id,
+                        )
+                        .await
+                        .into();
+                    }
+                    Event::Notify {
+                        from,
+                        importance,
+                        options,
+                        message,
+                        method,
+                    } => {
+                        if limits.is_notify_exceeded(&active_script.script_name) {
+                            break;
+                        }
+
+                        let headers = MessageParser::new().parse_headers(raw_message);
+                        let importance = format!("{importance:?}").to_lowercase();
+                        let notification = SieveNotification {
+                            from: from.as_deref(),
+                            importance: &importance,
+                            options: &options,
+                            account_id,
+                            envelope_from,
+                            envelope_to: envelope_to.address.as_str(),
+                            subject: headers.as_ref().and_then(|headers| headers.subject()),
+                            message_id: headers.as_ref().and_then(|headers| headers.message_id()),
+                            ..SieveNotification::new(&method, &message)
+                        };
+
+                        let result = self.send_sieve_notification(&notification).await;
+                        match result {
+                            Ok(_) => {
+                                trc::event!(
+                                    Sieve(SieveEvent::NotifySent),
+                                    AccountId = account_id,
+                                    Id = active_script.script_name.to_string(),
+                                    Url = method,
+                                    SpanId = session_id,
+                                );
+                                input = true.into();
+                            }
+                            Err(err) => {
+                                trc::event!(
+                                    Sieve(SieveEvent::NotifyFailed),
+                                    AccountId = account_id,
+                                    Id = active_script.script_name.to_string(),
+                                    Url = method,
+                                    Reason = err,
+                                    SpanId = session_id,
+                                );
+                                input = false.into();
+                            }
+                        }
+                    }
+                    Event::SetEnvelope { .. } => {
                         // Not allowed
                         input = false.into();
                     }
@@ -475,17 +655,26 @@ impl SieveScriptIngest for Server {
                 }
 
                 Err(err) => {
-                    trc::event!(
-                        Sieve(SieveEvent::RuntimeError),
-                        Reason = err.to_string(),
-                        SpanId = session_id
-                    );
+                    if limits.runtime_error(&active_script.script_name, err) {
+                        break;
+                    }
 
                     input = true.into();
                 }
             }
         }
 
+        if let Err(err) = self
+            .sieve_stats_record(account_id, &active_script.script_name, &stats)
+            .await
+        {
+            trc::error!(
+                err.details("Failed to record Sieve statistics.")
+                    .span_id(session_id)
+                    .caused_by(trc::location!())
+            );
+        }
+
         // Fail-safe, no discard and no keep seen, assume that something went wrong and file anyway.
         if !do_deliver && !do_discard {
             messages[0].file_into.push(INBOX_ID);
@@ -498,7 +687,7 @@ impl SieveScriptIngest for Server {
             if !sieve_message.file_into.is_empty() {
                 // Parse message if needed
                 let (blob_hash, message) = if message_id == 0 && !instance.has_message_changed() {
-                    (blob_hash.into(), instance.take_message())
+                    (blob_hash, instance.take_message())
                 } else if let Some(message) =
                     MessageParser::new().parse(sieve_message.raw_message.as_ref())
                 {
@@ -513,6 +702,19 @@ impl SieveScriptIngest for Server {
                     continue;
                 };
 
+                // Keywords set by the script are added to the original ones
+                let (keywords, received_at) = if message_id == 0 {
+                    let mut keywords = std::mem::take(&mut defaults.keywords);
+                    for keyword in sieve_message.flags {
+                        if !keywords.contains(&keyword) {
+                            keywords.push(keyword);
+                        }
+                    }
+                    (keywords, defaults.received_at)
+                } else {
+                    (sieve_message.flags, None)
+                };
+
                 // Deliver message
                 match self
                     .email_ingest(IngestEmail {
@@ -521,8 +723,8 @@ impl SieveScriptIngest for Server {
                         message: message.into(),
                         access_token,
                         mailbox_ids: sieve_message.file_into,
-                        keywords: sieve_message.flags,
-                        received_at: None,
+                        keywords,
+                        received_at,
                         source: IngestSource::Smtp {
                             deliver_to: envelope_to.address.as_str(),
                             is_sender_authenticated: envelope_from_authenticated,
@@ -558,6 +760,389 @@ impl SieveScriptIngest for Server {
         }
     }
 
+    async fn sieve_script_reject_reason(
+        &self,
+        account_id: u32,
+        raw_message: &[u8],
+        envelope_from: &str,
+        envelope_to: &IngestRecipient,
+        session_id: u64,
+    ) -> trc::Result<Option<String>> {
+        // Evaluate the active script without performing any of its actions, only
+        // lookups are allowed so the message can still be rejected before it is accepted
+        let Some(active_script) = self.sieve_script_get_active(account_id).await? else {
+            return Ok(None);
+        };
+        let Some(message) = MessageParser::new().parse(raw_message) else {
+            return Ok(None);
+        };
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let account_info = self.account(account_id).await.caused_by(trc::location!())?;
+
+        let mut instance = self.core.sieve.untrusted_runtime.filter_parsed(message);
+        instance.set_user_full_name(
+            account_info
+                .description()
+                .unwrap_or_else(|| account_info.name()),
+        );
+        instance.set_user_address(account_info.name());
+        instance.set_envelope(Envelope::From, envelope_from);
+        instance.set_envelope(Envelope::To, envelope_to.address.as_str());
+        if let Some(orcpt) = &envelope_to.orcpt {
+            instance.set_envelope(Envelope::Orcpt, orcpt.as_str());
+        }
+        instance.set_spam_status(if envelope_to.is_spam {
+            SpamStatus::Spam
+        } else {
+            SpamStatus::Ham
+        });
+
+        let mut input = Input::script(
+            active_script.script_name.to_string(),
+            active_script.script.clone(),
+        );
+
+        let mut limits = SieveRunLimits::new(self, account_id, session_id);
+        while let Some(event) = instance.run(input) {
+            if limits.is_time_exceeded(&active_script.script_name) {
+                break;
+            }
+
+            input = match event {
+                Ok(Event::Reject { reason, .. }) => {
+                    return Ok(Some(reason));
+                }
+                Ok(Event::IncludeScript { name, .. }) => match &name {
+                    sieve::Script::Personal(name_) => {
+                        if let Ok(Some(script)) =
+                            self.sieve_script_get_by_name(account_id, name_).await
+                        {
+                            Input::script(name, script)
+                        } else {
+                            false.into()
+                        }
+                    }
+                    sieve::Script::Global(name_) => {
+                        if let Ok(Some(script)) = self
+                            .get_untrusted_sieve_script_for_account(
+                                &name_.to_lowercase(),
+                                account_id,
+                                session_id,
+                            )
+                            .await
+                        {
+                            Input::script(name, script)
+                        } else {
+                            false.into()
+                        }
+                    }
+                },
+                Ok(Event::MailboxExists {
+                    mailboxes,
+                    special_use,
+                }) => {
+                    let mut exists = !mailboxes.is_empty() || !special_use.is_empty();
+                    for mailbox in mailboxes {
+                        exists &= match mailbox {
+                            Mailbox::Name(name) => cache.mailbox_by_path(&name).is_some(),
+                            Mailbox::Id(id) => Id::from_str(&id)
+                                .is_ok_and(|id| cache.has_mailbox_id(&id.document_id())),
+                        };
+                    }
+                    for role in special_use.iter().map(|v| parse_special_use(v)) {
+                        exists &= match role {
+                            Some(SpecialUse::Inbox | SpecialUse::Trash) => true,
+                            Some(role) => cache.mailbox_by_role(&role).is_some(),
+                            None => false,
+                        };
+                    }
+                    exists.into()
+                }
+                Ok(Event::DuplicateId { id, .. }) => {
+                    // Duplicate ids are only recorded when the message is delivered
+                    let id_hash = SeenIdHash::new(
+                        account_id,
+                        active_script.version.hash().unwrap_or_default(),
+                        &id,
+                    );
+                    self.in_memory_store()
+                        .key_exists(id_hash.key())
+                        .await
+                        .caused_by(trc::location!())?
+                        .into()
+                }
+                Ok(Event::ListContains {
+                    lists,
+                    values,
+                    match_as,
+                }) => sieve_list_contains(
+                    self,
+                    lists,
+                    &values,
+                    match_as,
+                    &active_script.script_name,
+                    account_id,
+                    session_id,
+                )
+                .await
+                .into(),
+                Ok(Event::Notify { .. }) => {
+                    if limits.is_notify_exceeded(&active_script.script_name) {
+                        break;
+                    }
+                    false.into()
+                }
+                Ok(Event::SetEnvelope { .. } | Event::Function { .. }) => false.into(),
+                Err(err) => {
+                    if limits.runtime_error(&active_script.script_name, err) {
+                        break;
+                    }
+                    true.into()
+                }
+                Ok(_) => true.into(),
+            };
+        }
+
+        Ok(None)
+    }
+
+    async fn sieve_script_evaluate(
+        &self,
+        account_id: u32,
+        raw_message: &[u8],
+        envelope_from: &str,
+        envelope_to: &IngestRecipient,
+        session_id: u64,
+    ) -> trc::Result<SieveEvaluation> {
+        let cache = self
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let mut evaluation = SieveEvaluation::default();
+        let mut mailbox_ids = Vec::new();
+
+        if let Some(active_script) = self.sieve_script_get_active(account_id).await?
+            && let Some(message) = MessageParser::new().parse(raw_message)
+        {
+            let account_info = self.account(account_id).await.caused_by(trc::location!())?;
+            let mut instance = self.core.sieve.untrusted_runtime.filter_parsed(message);
+            instance.set_user_full_name(
+                account_info
+                    .description()
+                    .unwrap_or_else(|| account_info.name()),
+            );
+            instance.set_user_address(account_info.name());
+            instance.set_envelope(Envelope::From, envelope_from);
+            instance.set_envelope(Envelope::To, envelope_to.address.as_str());
+            if let Some(orcpt) = &envelope_to.orcpt {
+                instance.set_envelope(Envelope::Orcpt, orcpt.as_str());
+            }
+            instance.set_spam_status(if envelope_to.is_spam {
+                SpamStatus::Spam
+            } else {
+                SpamStatus::Ham
+            });
+
+            let mut input = Input::script(
+                active_script.script_name.to_string(),
+                active_script.script.clone(),
+            );
+
+            let mut limits = SieveRunLimits::new(self, account_id, session_id);
+            while let Some(event) = instance.run(input) {
+                if limits.is_time_exceeded(&active_script.script_name) {
+                    break;
+                }
+
+                input = match event {
+                    Ok(Event::Reject { reason, .. }) => {
+                        evaluation.reject_reason = Some(reason);
+                        break;
+                    }
+                    Ok(Event::Discard) => {
+                        evaluation.discard = true;
+                        true.into()
+                    }
+                    Ok(Event::Keep { message_id: 0, .. }) => {
+                        if !mailbox_ids.contains(&INBOX_ID) {
+                            mailbox_ids.push(INBOX_ID);
+                        }
+                        true.into()
+                    }
+                    Ok(Event::FileInto {
+                        folder,
+                        mailbox_id,
+                        special_use,
+                        create,
+                        message_id: 0,
+                        ..
+                    }) => {
+                        let target_id = mailbox_id
+                            .and_then(|id| Id::from_str(&id).ok())
+                            .map(|id| id.document_id())
+                            .filter(|id| cache.has_mailbox_id(id))
+                            .or_else(|| {
+                                match special_use.as_deref().and_then(parse_special_use)? {
+                                    SpecialUse::Inbox => Some(INBOX_ID),
+                                    SpecialUse::Trash => Some(TRASH_ID),
+                                    role => cache.mailbox_by_role(&role).map(|m| m.document_id),
+                                }
+                            })
+                            .or_else(|| cache.mailbox_by_path(&folder).map(|m| m.document_id));
+
+                        match target_id {
+                            Some(target_id) => {
+                                if !mailbox_ids.contains(&target_id) {
+                                    mailbox_ids.push(target_id);
+                                }
+                            }
+                            None if create => {
+                                // The mailbox would be created on delivery
+                                if !evaluation.mailboxes.contains(&folder) {
+                                    evaluation.mailboxes.push(folder);
+                                }
+                            }
+                            None => {
+                                if !mailbox_ids.contains(&INBOX_ID) {
+                                    mailbox_ids.push(INBOX_ID);
+                                }
+                            }
+                        }
+                        true.into()
+                    }
+                    Ok(Event::SendMessage {
+                        recipient,
+                        message_id: 0,
+                        ..
+                    }) => {
+                        match recipient {
+                            Recipient::Address(rcpt) => evaluation.redirects.push(rcpt),
+                            Recipient::Group(rcpts) => evaluation.redirects.extend(rcpts),
+                            Recipient::List(_) => {}
+                        }
+                        true.into()
+                    }
+                    Ok(Event::IncludeScript { name, .. }) => match &name {
+                        sieve::Script::Personal(name_) => {
+                            if let Ok(Some(script)) =
+                                self.sieve_script_get_by_name(account_id, name_).await
+                            {
+                                Input::script(name, script)
+                            } else {
+                                false.into()
+                            }
+                        }
+                        sieve::Script::Global(name_) => {
+                            if let Ok(Some(script)) = self
+                                .get_untrusted_sieve_script_for_account(
+                                    &name_.to_lowercase(),
+                                    account_id,
+                                    session_id,
+                                )
+                                .await
+                            {
+                                Input::script(name, script)
+                            } else {
+                                false.into()
+                            }
+                        }
+                    },
+                    Ok(Event::MailboxExists {
+                        mailboxes,
+                        special_use,
+                    }) => {
+                        let mut exists = !mailboxes.is_empty() || !special_use.is_empty();
+                        for mailbox in mailboxes {
+                            exists &= match mailbox {
+                                Mailbox::Name(name) => cache.mailbox_by_path(&name).is_some(),
+                                Mailbox::Id(id) => Id::from_str(&id)
+                                    .is_ok_and(|id| cache.has_mailbox_id(&id.document_id())),
+                            };
+                        }
+                        for role in special_use.iter().map(|v| parse_special_use(v)) {
+                            exists &= match role {
+                                Some(SpecialUse::Inbox | SpecialUse::Trash) => true,
+                                Some(role) => cache.mailbox_by_role(&role).is_some(),
+                                None => false,
+                            };
+                        }
+                        exists.into()
+                    }
+                    Ok(Event::DuplicateId { id, .. }) => {
+                        let id_hash = SeenIdHash::new(
+                            account_id,
+                            active_script.version.hash().unwrap_or_default(),
+                            &id,
+                        );
+                        self.in_memory_store()
+                            .key_exists(id_hash.key())
+                            .await
+                            .caused_by(trc::location!())?
+                            .into()
+                    }
+                    Ok(Event::ListContains {
+                        lists,
+                        values,
+                        match_as,
+                    }) => sieve_list_contains(
+                        self,
+                        lists,
+                        &values,
+                        match_as,
+                        &active_script.script_name,
+                        account_id,
+                        session_id,
+                    )
+                    .await
+                    .into(),
+                    Ok(Event::Notify { .. }) => {
+                        if limits.is_notify_exceeded(&active_script.script_name) {
+                            break;
+                        }
+                        false.into()
+                    }
+                    Ok(Event::SetEnvelope { .. } | Event::Function { .. }) => false.into(),
+                    Err(err) => {
+                        if limits.runtime_error(&active_script.script_name, err) {
+                            break;
+                        }
+                        true.into()
+                    }
+                    Ok(_) => true.into(),
+                };
+            }
+        }
+
+        // Same fail-safe as delivery, messages that were neither kept nor
+        // discarded are filed into the Inbox.
+        if evaluation.reject_reason.is_none()
+            && !evaluation.discard
+            && mailbox_ids.is_empty()
+            && evaluation.mailboxes.is_empty()
+        {
+            mailbox_ids.push(INBOX_ID);
+        }
+        if envelope_to.is_spam
+            && self.core.spam.enabled
+            && mailbox_ids == [INBOX_ID]
+            && evaluation.mailboxes.is_empty()
+        {
+            mailbox_ids[0] = JUNK_ID;
+        }
+
+        let new_mailboxes = std::mem::take(&mut evaluation.mailboxes);
+        evaluation.mailboxes = mailbox_ids
+            .into_iter()
+            .filter_map(|id| cache.mailbox_by_id(&id).map(|m| m.path.clone()))
+            .chain(new_mailboxes)
+            .collect();
+
+        Ok(evaluation)
+    }
+
     async fn sieve_script_get_active_id(&self, account_id: u32) -> trc::Result<Option<u32>> {
         self.store()
             .get_value::<u32>(ValueKey {
@@ -571,6 +1156,23 @@ impl SieveScriptIngest for Server {
     }
 
     async fn sieve_script_get_active(&self, account_id: u32) -> trc::Result<Option<ActiveScript>> {
+        let active_script = self.sieve_script_get_user_active(account_id).await?;
+
+        // Forwarding settings take precedence and include the user's script
+        if let Some(script) = self
+            .forwarding_script(account_id, active_script.as_ref())
+            .await?
+        {
+            Ok(Some(script))
+        } else {
+            Ok(active_script)
+        }
+    }
+
+    async fn sieve_script_get_user_active(
+        &self,
+        account_id: u32,
+    ) -> trc::Result<Option<ActiveScript>> {
         // Find the currently active script
         if let Some(document_id) = self
             .store()
@@ -753,3 +1355,27 @@ pub struct CompiledScript {
     pub name: String,
     pub version: ArchiveVersion,
 }
+
+// RFC 8579 special-use attributes are prefixed with a backslash
+pub(crate) fn parse_special_use(value: &str) -> Option<SpecialUse> {
+    SpecialUse::parse(value.strip_prefix('\\').unwrap_or(value))
+        .filter(|role| !matches!(role, SpecialUse::Shared))
+}
+
+pub(crate) fn sieve_keywords(flags: Vec<String>) -> Vec<Keyword> {
+    let mut keywords = Vec::with_capacity(flags.len());
+    for flag in flags {
+        let keyword = match Keyword::try_parse(&flag) {
+            // \Recent is session state and cannot be set by a filter
+            Some(Keyword::Recent) => continue,
+            Some(keyword) => keyword,
+            // Unknown system flags have no keyword equivalent
+            None if flag.starts_with('\\') => continue,
+            None => Keyword::from_other(flag),
+        };
+        if !keywords.contains(&keyword) {
+            keywords.push(keyword);
+        }
+    }
+    keywords
+}
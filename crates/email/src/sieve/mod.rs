@@ -4,15 +4,190 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use common::KV_SIEVE_ID;
-use sieve::Sieve;
-use std::sync::Arc;
+use common::{
+    KV_RATE_LIMIT_SIEVE, KV_SIEVE_ID, Server, config::mailstore::scripts::SIEVE_ADDRBOOK_LIST,
+};
+use sieve::{MatchAs, Sieve, runtime::RuntimeError};
+use std::{sync::Arc, time::Instant};
 use store::{blake3, write::ArchiveVersion};
-use types::blob_hash::BlobHash;
+use trc::AddContext;
+use types::{blob_hash::BlobHash, collection::Collection, field::ContactField};
 
 pub mod delete;
+pub mod forwarding;
+pub mod history;
+pub mod imapsieve;
 pub mod index;
 pub mod ingest;
+pub mod redirect;
+pub mod stats;
+
+// User scripts may only query the lookup stores listed in allowedExtLists
+pub(crate) async fn sieve_list_contains(
+    server: &Server,
+    lists: Vec<String>,
+    values: &[String],
+    match_as: MatchAs,
+    script_name: &str,
+    account_id: u32,
+    session_id: u64,
+) -> bool {
+    for list in lists {
+        if list == SIEVE_ADDRBOOK_LIST {
+            for value in values {
+                if let Ok(true) = server
+                    .document_exists(
+                        account_id,
+                        Collection::ContactCard,
+                        ContactField::Email,
+                        value.to_lowercase().as_bytes(),
+                    )
+                    .await
+                {
+                    return true;
+                }
+            }
+        } else if let Some(store) = server
+            .core
+            .sieve
+            .untrusted_ext_lists
+            .contains(&list)
+            .then(|| server.get_lookup_store(&list))
+            .flatten()
+        {
+            for value in values {
+                if let Ok(true) = store
+                    .key_exists(if !matches!(match_as, MatchAs::Lowercase) {
+                        value.clone()
+                    } else {
+                        value.to_lowercase()
+                    })
+                    .await
+                {
+                    return true;
+                }
+            }
+        } else {
+            trc::event!(
+                Sieve(trc::SieveEvent::ListNotFound),
+                AccountId = account_id,
+                Id = script_name.to_string(),
+                SpanId = session_id,
+                Details = list,
+            );
+        }
+    }
+
+    false
+}
+
+// Execution budget of a single user script run, on top of the limits
+// already enforced by the Sieve runtime itself
+pub(crate) struct SieveRunLimits<'x> {
+    server: &'x Server,
+    account_id: u32,
+    session_id: u64,
+    started: Instant,
+    notify_actions: usize,
+}
+
+impl<'x> SieveRunLimits<'x> {
+    pub fn new(server: &'x Server, account_id: u32, session_id: u64) -> Self {
+        SieveRunLimits {
+            server,
+            account_id,
+            session_id,
+            started: Instant::now(),
+            notify_actions: 0,
+        }
+    }
+
+    pub fn is_time_exceeded(&self, script_name: &str) -> bool {
+        let elapsed = self.started.elapsed();
+        let limit = self.server.core.sieve.untrusted_max_run_time;
+        if elapsed > limit {
+            trc::event!(
+                Sieve(trc::SieveEvent::TimeLimitExceeded),
+                AccountId = self.account_id,
+                Id = script_name.to_string(),
+                Elapsed = elapsed,
+                Limit = limit,
+                SpanId = self.session_id,
+            );
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn is_notify_exceeded(&mut self, script_name: &str) -> bool {
+        let limit = self.server.core.sieve.untrusted_max_notify;
+        self.notify_actions += 1;
+        if self.notify_actions > limit {
+            trc::event!(
+                Sieve(trc::SieveEvent::NotifyLimitExceeded),
+                AccountId = self.account_id,
+                Id = script_name.to_string(),
+                Limit = limit,
+                SpanId = self.session_id,
+            );
+            true
+        } else {
+            false
+        }
+    }
+
+    // Redirects of the original message count towards a daily per-account budget
+    pub async fn is_redirect_allowed(&self, script_name: &str) -> trc::Result<bool> {
+        let Some(rate) = &self.server.core.sieve.untrusted_redirect_rate else {
+            return Ok(true);
+        };
+        if self
+            .server
+            .in_memory_store()
+            .is_rate_allowed(
+                KV_RATE_LIMIT_SIEVE,
+                &self.account_id.to_be_bytes(),
+                rate,
+                false,
+            )
+            .await
+            .caused_by(trc::location!())?
+            .is_none()
+        {
+            Ok(true)
+        } else {
+            trc::event!(
+                Sieve(trc::SieveEvent::RedirectLimitExceeded),
+                AccountId = self.account_id,
+                Id = script_name.to_string(),
+                Limit = rate.count,
+                SpanId = self.session_id,
+            );
+            Ok(false)
+        }
+    }
+
+    // Returns true when the error aborted the script
+    pub fn runtime_error(&self, script_name: &str, err: RuntimeError) -> bool {
+        if matches!(err, RuntimeError::CPULimitReached) {
+            trc::event!(
+                Sieve(trc::SieveEvent::CpuLimitExceeded),
+                AccountId = self.account_id,
+                Id = script_name.to_string(),
+                SpanId = self.session_id,
+            );
+            true
+        } else {
+            trc::event!(
+                Sieve(trc::SieveEvent::RuntimeError),
+                Reason = err.to_string(),
+                SpanId = self.session_id
+            );
+            false
+        }
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct ActiveScript {
@@ -34,15 +209,45 @@ pub struct SieveScript {
 }
 
 #[derive(
-    rkyv::Archive, rkyv::Deserialize, rkyv::Serialize, Debug, Default, Clone, PartialEq, Eq,
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Default,
+    Clone,
+    PartialEq,
+    Eq,
 )]
 #[rkyv(derive(Debug))]
+#[serde(rename_all = "camelCase")]
 pub struct VacationResponse {
     pub from_date: Option<u64>,
     pub to_date: Option<u64>,
     pub subject: Option<String>,
     pub text_body: Option<String>,
     pub html_body: Option<String>,
+    pub restrict_to: Option<VacationRestriction>,
+}
+
+#[derive(
+    rkyv::Archive,
+    rkyv::Deserialize,
+    rkyv::Serialize,
+    serde::Serialize,
+    serde::Deserialize,
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+)]
+#[rkyv(derive(Debug))]
+#[serde(rename_all = "camelCase")]
+pub enum VacationRestriction {
+    Contacts,
+    SameDomain,
 }
 
 impl SieveScript {
@@ -76,6 +281,31 @@ impl SieveScript {
     }
 }
 
+impl VacationRestriction {
+    pub fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map!(value.as_bytes(),
+            "contacts" => VacationRestriction::Contacts,
+            "sameDomain" => VacationRestriction::SameDomain,
+        )
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            VacationRestriction::Contacts => "contacts",
+            VacationRestriction::SameDomain => "sameDomain",
+        }
+    }
+}
+
+impl From<&ArchivedVacationRestriction> for VacationRestriction {
+    fn from(value: &ArchivedVacationRestriction) -> Self {
+        match value {
+            ArchivedVacationRestriction::Contacts => VacationRestriction::Contacts,
+            ArchivedVacationRestriction::SameDomain => VacationRestriction::SameDomain,
+        }
+    }
+}
+
 #[derive(Debug, Default, Clone, PartialEq, Eq, Hash)]
 #[repr(transparent)]
 pub struct SeenIdHash(pub [u8; 32]);
@@ -0,0 +1,118 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{KV_SIEVE_REDIRECT, Server, auth::EmailCache};
+use std::{collections::VecDeque, future::Future};
+use store::{ahash::AHashSet, dispatch::lookup::KeyValue};
+use trc::AddContext;
+
+pub const MAX_REDIRECT_HOPS: usize = 10;
+pub const MAX_REDIRECT_TARGETS: usize = 32;
+
+// Redirect targets not used for this long are dropped from the graph
+pub const REDIRECT_TARGET_EXPIRY: u64 = 30 * 86400;
+
+pub trait SieveRedirectGraph: Sync + Send {
+    fn sieve_redirect_targets(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Vec<String>>> + Send;
+
+    fn sieve_redirect_record(
+        &self,
+        account_id: u32,
+        recipients: &[String],
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn sieve_redirect_loops(
+        &self,
+        account_id: u32,
+        envelope_from: &str,
+        recipient: &str,
+    ) -> impl Future<Output = trc::Result<bool>> + Send;
+}
+
+impl SieveRedirectGraph for Server {
+    async fn sieve_redirect_targets(&self, account_id: u32) -> trc::Result<Vec<String>> {
+        Ok(self
+            .in_memory_store()
+            .key_get::<String>(KeyValue::<()>::build_key(
+                KV_SIEVE_REDIRECT,
+                account_id.to_be_bytes(),
+            ))
+            .await
+            .caused_by(trc::location!())?
+            .map(|targets| {
+                targets
+                    .lines()
+                    .filter(|target| !target.is_empty())
+                    .map(String::from)
+                    .collect()
+            })
+            .unwrap_or_default())
+    }
+
+    async fn sieve_redirect_record(
+        &self,
+        account_id: u32,
+        recipients: &[String],
+    ) -> trc::Result<()> {
+        // Most recently used targets go last, the oldest ones are evicted first
+        let mut targets = self.sieve_redirect_targets(account_id).await?;
+        for recipient in recipients {
+            let recipient = recipient.to_lowercase();
+            targets.retain(|target| target != &recipient);
+            targets.push(recipient);
+        }
+        if targets.len() > MAX_REDIRECT_TARGETS {
+            targets.drain(..targets.len() - MAX_REDIRECT_TARGETS);
+        }
+
+        self.in_memory_store()
+            .key_set(
+                KeyValue::with_prefix(
+                    KV_SIEVE_REDIRECT,
+                    account_id.to_be_bytes(),
+                    targets.join("\n").into_bytes(),
+                )
+                .expires(REDIRECT_TARGET_EXPIRY),
+            )
+            .await
+            .caused_by(trc::location!())
+    }
+
+    // A redirect loops when the message came from the address it is about to be
+    // sent to, or when following the redirects of local accounts leads back to
+    // this account. External targets are leaves of the graph.
+    async fn sieve_redirect_loops(
+        &self,
+        account_id: u32,
+        envelope_from: &str,
+        recipient: &str,
+    ) -> trc::Result<bool> {
+        if envelope_from.eq_ignore_ascii_case(recipient) {
+            return Ok(true);
+        }
+
+        let mut visited = AHashSet::new();
+        let mut queue = VecDeque::from([recipient.to_lowercase()]);
+        while let Some(address) = queue.pop_front() {
+            if let Some(EmailCache::Account(target_id)) = self
+                .rcpt_id_from_email(&address)
+                .await
+                .caused_by(trc::location!())?
+            {
+                if target_id == account_id {
+                    return Ok(true);
+                } else if visited.len() < MAX_REDIRECT_HOPS && visited.insert(target_id) {
+                    queue.extend(self.sieve_redirect_targets(target_id).await?);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+}
@@ -0,0 +1,154 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{SieveScript, forwarding::FORWARDING_SCRIPT_NAME};
+use common::{KV_SIEVE_STATS, Server};
+use std::future::Future;
+use store::{
+    U32_LEN, ValueKey,
+    dispatch::lookup::KeyValue,
+    write::{AlignedBytes, Archive},
+};
+use trc::AddContext;
+use types::{collection::Collection, field::SieveField};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SieveActionStats {
+    pub keep: u64,
+    pub file_into: u64,
+    pub discard: u64,
+    pub reject: u64,
+    pub redirect: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SieveScriptStats {
+    pub name: String,
+    #[serde(flatten)]
+    pub actions: SieveActionStats,
+}
+
+pub trait SieveStats: Sync + Send {
+    fn sieve_stats_record(
+        &self,
+        account_id: u32,
+        script_name: &str,
+        stats: &SieveActionStats,
+    ) -> impl Future<Output = trc::Result<()>> + Send;
+
+    fn sieve_stats(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Vec<SieveScriptStats>>> + Send;
+}
+
+impl SieveStats for Server {
+    async fn sieve_stats_record(
+        &self,
+        account_id: u32,
+        script_name: &str,
+        stats: &SieveActionStats,
+    ) -> trc::Result<()> {
+        for (action, count) in stats.counters() {
+            if count > 0 {
+                self.in_memory_store()
+                    .counter_incr(
+                        KeyValue::new(stats_key(account_id, script_name, action), count as i64),
+                        false,
+                    )
+                    .await
+                    .caused_by(trc::location!())?;
+            }
+        }
+
+        Ok(())
+    }
+
+    async fn sieve_stats(&self, account_id: u32) -> trc::Result<Vec<SieveScriptStats>> {
+        let mut script_names = Vec::new();
+        for document_id in self
+            .document_ids(account_id, Collection::SieveScript, SieveField::Name)
+            .await
+            .caused_by(trc::location!())?
+        {
+            if let Some(script) = self
+                .store()
+                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                    account_id,
+                    Collection::SieveScript,
+                    document_id,
+                ))
+                .await
+                .caused_by(trc::location!())?
+            {
+                script_names.push(
+                    script
+                        .unarchive::<SieveScript>()
+                        .caused_by(trc::location!())?
+                        .name
+                        .to_string(),
+                );
+            }
+        }
+
+        // The managed forwarding script is only listed once it has run
+        let mut results = Vec::with_capacity(script_names.len() + 1);
+        for (name, always_list) in script_names
+            .into_iter()
+            .map(|name| (name, true))
+            .chain([(FORWARDING_SCRIPT_NAME.to_string(), false)])
+        {
+            let mut actions = SieveActionStats::default();
+            for (action, counter) in actions.counters_mut() {
+                *counter = self
+                    .in_memory_store()
+                    .counter_get(stats_key(account_id, &name, action))
+                    .await
+                    .caused_by(trc::location!())?
+                    .max(0) as u64;
+            }
+
+            if always_list || actions != SieveActionStats::default() {
+                results.push(SieveScriptStats { name, actions });
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl SieveActionStats {
+    fn counters(&self) -> [(u8, u64); 5] {
+        [
+            (0, self.keep),
+            (1, self.file_into),
+            (2, self.discard),
+            (3, self.reject),
+            (4, self.redirect),
+        ]
+    }
+
+    fn counters_mut(&mut self) -> [(u8, &mut u64); 5] {
+        [
+            (0, &mut self.keep),
+            (1, &mut self.file_into),
+            (2, &mut self.discard),
+            (3, &mut self.reject),
+            (4, &mut self.redirect),
+        ]
+    }
+}
+
+fn stats_key(account_id: u32, script_name: &str, action: u8) -> Vec<u8> {
+    let mut key = Vec::with_capacity(U32_LEN + script_name.len() + 2);
+    key.push(KV_SIEVE_STATS);
+    key.extend_from_slice(&account_id.to_be_bytes());
+    key.push(action);
+    key.extend_from_slice(script_name.as_bytes());
+    key
+}
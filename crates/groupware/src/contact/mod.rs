@@ -49,6 +49,22 @@ pub struct ContactCard {
     pub size: u32,
 }
 
+impl ContactCard {
+    // Builds a card holding a single email address
+    pub fn new_with_email(name: DavName, email: &str) -> Option<Self> {
+        let raw_card = format!(
+            "BEGIN:VCARD\r\nVERSION:4.0\r\nUID:{}\r\nFN:{email}\r\nEMAIL:{email}\r\nEND:VCARD\r\n",
+            name.name
+        );
+        VCard::parse(&raw_card).ok().map(|card| ContactCard {
+            names: vec![name],
+            size: raw_card.len() as u32,
+            card,
+            ..Default::default()
+        })
+    }
+}
+
 impl AddressBook {
     pub fn preferences(&self, access_token: &AccessToken) -> &AddressBookPreferences {
         if self.preferences.len() == 1 {
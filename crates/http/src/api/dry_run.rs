@@ -0,0 +1,355 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, config::smtp::auth::VerifyStrategy, network::stream::NullIo};
+use http_proto::{HttpResponse, HttpSessionData, JsonResponse, ToHttpResponse};
+use mail_auth::{DmarcResult, IprevOutput, IprevResult, dmarc::Policy};
+use serde::{Deserialize, Serialize};
+use smtp::{
+    core::{Session, SessionData},
+    inbound::dry_run::DryRun,
+};
+use smtp_proto::{MailFrom, RcptTo};
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
+use trc::AddContext;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub(crate) struct DryRunRequest {
+    pub message: String,
+    pub remote_ip: IpAddr,
+    pub remote_port: u16,
+    pub local_ip: IpAddr,
+    pub local_port: u16,
+    pub helo: String,
+    pub mail_from: String,
+    pub rcpt_to: Vec<String>,
+    pub authenticated_as: Option<String>,
+    pub live_dns: bool,
+    pub mock_filters: bool,
+    pub iprev: Option<String>,
+    pub dmarc: Option<DryRunDmarc>,
+}
+
+#[derive(Debug, Deserialize)]
+pub(crate) struct DryRunDmarc {
+    pub result: String,
+    pub policy: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DryRunResponse {
+    pub verdict: &'static str,
+    pub stage: &'static str,
+    pub response: String,
+    pub recipients: Vec<DryRunRecipient>,
+    pub spam_verdict: Option<&'static str>,
+    pub spam_score: Option<f32>,
+    pub sieve_action: Option<&'static str>,
+    pub filters: Vec<DryRunFilterResponse>,
+    pub headers: String,
+    pub size: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DryRunRecipient {
+    pub address: String,
+    pub response: String,
+    pub is_spam: bool,
+    pub is_local: bool,
+    pub is_quarantined: bool,
+    pub mailboxes: Vec<String>,
+    pub redirects: Vec<String>,
+    pub reject_reason: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct DryRunFilterResponse {
+    pub kind: &'static str,
+    pub action: &'static str,
+    pub response: Option<String>,
+}
+
+pub(crate) trait MailFlowDryRunApi: Sync + Send {
+    fn handle_dry_run_request(
+        &self,
+        body: Option<Vec<u8>>,
+        session: &HttpSessionData,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl MailFlowDryRunApi for Server {
+    async fn handle_dry_run_request(
+        &self,
+        body: Option<Vec<u8>>,
+        session: &HttpSessionData,
+    ) -> trc::Result<HttpResponse> {
+        let request = serde_json::from_slice::<DryRunRequest>(body.as_deref().unwrap_or_default())
+            .map_err(|err| {
+                trc::EventType::Resource(trc::ResourceEvent::BadParameters).from_json_error(err)
+            })?;
+        if request.message.is_empty() || request.rcpt_to.is_empty() {
+            return Err(trc::ResourceEvent::BadParameters
+                .into_err()
+                .details("A message and at least one recipient are required"));
+        }
+
+        // Build the dry run options
+        let mut dry_run = DryRun::new(request.live_dns, request.mock_filters);
+        if let Some(dmarc) = &request.dmarc {
+            dry_run = dry_run.with_dmarc(
+                parse_dmarc_result(&dmarc.result)?,
+                parse_dmarc_policy(&dmarc.policy)?,
+            );
+        }
+        let iprev = request
+            .iprev
+            .as_deref()
+            .map(parse_iprev_result)
+            .transpose()?;
+        let authenticated_as = if let Some(email) = &request.authenticated_as {
+            let Some(account_id) = self
+                .account_id_from_email(email, false)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                return Err(trc::ResourceEvent::BadParameters
+                    .into_err()
+                    .details("Authenticated account not found")
+                    .ctx(trc::Key::Key, email.to_string()));
+            };
+            Some(
+                self.account_info(account_id)
+                    .await
+                    .caused_by(trc::location!())?,
+            )
+        } else {
+            None
+        };
+
+        // Build session
+        let mut data = SessionData::new(
+            request.local_ip,
+            request.local_port,
+            request.remote_ip,
+            request.remote_port,
+            self.lookup_asn_country(request.remote_ip).await,
+            session.session_id,
+        );
+        data.dry_run = Some(Box::new(dry_run));
+        data.tls_failure_reported = true;
+        let mut session = Session::<NullIo>::local(self.clone(), session.instance.clone(), data);
+
+        // Spawn SMTP session to avoid overflowing the stack
+        tokio::spawn(async move {
+            let mut response = DryRunResponse::default();
+
+            // Connect
+            response.stage = "connect";
+            if !session.init_conn().await {
+                return response.finish(session.has_failed().unwrap_or_default());
+            }
+            let _ = session.has_failed();
+            if !request.live_dns {
+                session.params.iprev = VerifyStrategy::Disable;
+                session.params.spf_ehlo = VerifyStrategy::Disable;
+                session.params.spf_mail_from = VerifyStrategy::Disable;
+            }
+            if let Some(result) = iprev {
+                session.data.iprev = Some(IprevOutput { result, ptr: None });
+            }
+
+            // EHLO
+            response.stage = "ehlo";
+            let _ = session.handle_ehlo(request.helo.into(), true).await;
+            if let Some(error) = session.has_failed() {
+                return response.finish(error);
+            }
+            if let Some(account_info) = authenticated_as {
+                session.data.authenticated_as = Some(account_info);
+                session.eval_post_auth_params().await;
+            }
+
+            // MAIL FROM
+            response.stage = "mail";
+            let _ = session
+                .handle_mail_from(MailFrom {
+                    address: request.mail_from.into(),
+                    ..Default::default()
+                })
+                .await;
+            if let Some(error) = session.has_failed() {
+                return response.finish(error);
+            }
+
+            // RCPT TO
+            response.stage = "rcpt";
+            session.params.rcpt_errors_wait = Duration::from_secs(0);
+            let mut has_success = false;
+            for rcpt in request.rcpt_to {
+                let _ = session
+                    .handle_rcpt_to(RcptTo {
+                        address: rcpt.as_str().into(),
+                        ..Default::default()
+                    })
+                    .await;
+                let rcpt_response = session.has_failed();
+                has_success |= rcpt_response.is_none();
+                response.recipients.push(DryRunRecipient {
+                    address: rcpt,
+                    response: rcpt_response.unwrap_or_else(|| "250 2.1.5 OK".to_string()),
+                    is_spam: false,
+                    is_local: false,
+                    is_quarantined: false,
+                    mailboxes: Vec::new(),
+                    redirects: Vec::new(),
+                    reject_reason: None,
+                });
+            }
+            if !has_success {
+                let error = response
+                    .recipients
+                    .last()
+                    .map(|rcpt| rcpt.response.clone())
+                    .unwrap_or_default();
+                return response.finish(error);
+            }
+
+            // DATA
+            response.stage = "data";
+            session.data.message = request.message.into_bytes();
+            let data_response = session.queue_message().await;
+            let data_response = String::from_utf8_lossy(&data_response).trim().to_string();
+            if let Some(dry_run) = session.data.dry_run.take() {
+                for delivery in dry_run.recipients {
+                    if let Some(rcpt) = response
+                        .recipients
+                        .iter_mut()
+                        .find(|rcpt| rcpt.address.eq_ignore_ascii_case(&delivery.address))
+                    {
+                        rcpt.is_spam = delivery.is_spam;
+                        rcpt.is_local = delivery.is_local;
+                        rcpt.is_quarantined = delivery.is_quarantined;
+                        rcpt.mailboxes = delivery.mailboxes;
+                        rcpt.redirects = delivery.redirects;
+                        rcpt.reject_reason = delivery.reject_reason;
+                    }
+                }
+                response.filters = dry_run
+                    .filters
+                    .into_iter()
+                    .map(|filter| DryRunFilterResponse {
+                        kind: filter.kind,
+                        action: filter.action,
+                        response: filter.response,
+                    })
+                    .collect();
+                response.spam_verdict = dry_run.spam_verdict;
+                response.spam_score = dry_run.spam_score;
+                response.sieve_action = dry_run.sieve_action;
+                response.headers = String::from_utf8_lossy(&dry_run.headers).into_owned();
+                response.size = dry_run.size;
+            }
+            if matches!(response.spam_verdict, Some("discard"))
+                || matches!(response.sieve_action, Some("discard"))
+            {
+                response.verdict = "discard";
+                response.response = data_response;
+                response
+            } else {
+                response.finish(data_response)
+            }
+        })
+        .await
+        .map(|response| JsonResponse::new(response).no_cache().into_http_response())
+        .map_err(|_| {
+            trc::Error::new(trc::EventType::Server(trc::ServerEvent::ThreadError))
+                .caused_by(trc::location!())
+        })
+    }
+}
+
+impl DryRunResponse {
+    fn finish(mut self, response: String) -> Self {
+        self.verdict = match response.as_bytes().first() {
+            Some(b'2') => "accept",
+            Some(b'4') => "tempfail",
+            _ => "reject",
+        };
+        self.response = response;
+        self
+    }
+}
+
+impl Default for DryRunRequest {
+    fn default() -> Self {
+        Self {
+            message: Default::default(),
+            remote_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            remote_port: Default::default(),
+            local_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            local_port: 25,
+            helo: "localhost.localdomain".to_string(),
+            mail_from: Default::default(),
+            rcpt_to: Default::default(),
+            authenticated_as: Default::default(),
+            live_dns: true,
+            mock_filters: true,
+            iprev: Default::default(),
+            dmarc: Default::default(),
+        }
+    }
+}
+
+fn parse_iprev_result(result: &str) -> trc::Result<IprevResult> {
+    match result {
+        "pass" => Ok(IprevResult::Pass),
+        "fail" => Ok(IprevResult::Fail(mail_auth::Error::NotAligned)),
+        "temperror" => Ok(IprevResult::TempError(mail_auth::Error::DnsError(
+            "Provided result".to_string(),
+        ))),
+        "permerror" => Ok(IprevResult::PermError(mail_auth::Error::NotAligned)),
+        "none" => Ok(IprevResult::None),
+        _ => Err(invalid_result("iprev", result)),
+    }
+}
+
+fn parse_dmarc_result(result: &str) -> trc::Result<DmarcResult> {
+    match result {
+        "pass" => Ok(DmarcResult::Pass),
+        "fail" => Ok(DmarcResult::Fail(mail_auth::Error::NotAligned)),
+        "temperror" => Ok(DmarcResult::TempError(mail_auth::Error::DnsError(
+            "Provided result".to_string(),
+        ))),
+        "permerror" => Ok(DmarcResult::PermError(mail_auth::Error::NotAligned)),
+        "none" => Ok(DmarcResult::None),
+        _ => Err(invalid_result("dmarc", result)),
+    }
+}
+
+fn parse_dmarc_policy(policy: &str) -> trc::Result<Policy> {
+    match policy {
+        "none" => Ok(Policy::None),
+        "quarantine" => Ok(Policy::Quarantine),
+        "reject" => Ok(Policy::Reject),
+        _ => Err(invalid_result("dmarc.policy", policy)),
+    }
+}
+
+fn invalid_result(key: &'static str, value: &str) -> trc::Error {
+    trc::ResourceEvent::BadParameters
+        .into_err()
+        .details("Invalid authentication result")
+        .ctx(trc::Key::Key, key)
+        .ctx(trc::Key::Value, value.to_string())
+}
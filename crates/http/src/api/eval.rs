@@ -0,0 +1,296 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{
+    Server,
+    expr::{StringCow, Variable, functions::ResolveVariable, if_block::BootstrapExprExt},
+};
+use compact_str::ToCompactString;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use registry::{
+    schema::{
+        enums::ExpressionVariable,
+        prelude::{OBJ_SINGLETON, Object, ObjectType, Property},
+        structs::Expression,
+    },
+    types::{
+        EnumImpl,
+        error::{Error, ValidationError},
+        id::ObjectId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, Ipv4Addr};
+use store::registry::bootstrap::Bootstrap;
+use trc::AddContext;
+use types::id::Id;
+use utils::DomainPart;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EvalRequest {
+    pub object: ObjectType,
+    #[serde(default)]
+    pub id: Option<Id>,
+    pub property: Property,
+    #[serde(default)]
+    pub expression: Option<Expression>,
+    #[serde(default)]
+    pub envelope: Envelope,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(default)]
+pub(crate) struct Envelope {
+    pub ip: IpAddr,
+    pub helo: String,
+    pub sender: String,
+    pub rcpt: Vec<String>,
+    pub auth: String,
+    pub listener: String,
+    pub protocol: String,
+    pub remote_port: u16,
+    pub local_ip: IpAddr,
+    pub local_port: u16,
+    pub is_tls: bool,
+    pub tls_client_subject: String,
+    pub tls_client_san: Vec<String>,
+    pub client_name: String,
+    pub client_version: String,
+    pub mx: String,
+    pub priority: i64,
+    pub size: u64,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct EvalResponse {
+    pub result: serde_json::Value,
+    pub matched: Option<usize>,
+    pub trace: Vec<EvalBranch>,
+    #[serde(rename = "else")]
+    pub else_: String,
+}
+
+#[derive(Debug, Serialize)]
+pub(crate) struct EvalBranch {
+    #[serde(rename = "if")]
+    pub if_: String,
+    pub then: String,
+    pub result: Option<bool>,
+}
+
+pub(crate) trait ExpressionEvalApi: Sync + Send {
+    fn handle_eval_request(
+        &self,
+        body: Option<Vec<u8>>,
+        session_id: u64,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl ExpressionEvalApi for Server {
+    async fn handle_eval_request(
+        &self,
+        body: Option<Vec<u8>>,
+        session_id: u64,
+    ) -> trc::Result<HttpResponse> {
+        let request = serde_json::from_slice::<EvalRequest>(body.as_deref().unwrap_or_default())
+            .map_err(|err| {
+                trc::EventType::Resource(trc::ResourceEvent::BadParameters).from_json_error(err)
+            })?;
+
+        // Obtain the expression context from the stored object
+        let id = request.id.unwrap_or_else(Id::singleton);
+        let object_id = ObjectId::new(request.object, id);
+        let object = if let Some(object) = self
+            .registry()
+            .get(object_id)
+            .await
+            .caused_by(trc::location!())?
+        {
+            object
+        } else if id.is_singleton() && (request.object.flags() & OBJ_SINGLETON) != 0 {
+            Object::from(request.object)
+        } else {
+            return Err(trc::ResourceEvent::NotFound.into_err());
+        };
+        let Some(mut expr_ctx) = object.inner.expression_ctxs().and_then(|ctxs| {
+            ctxs.into_iter()
+                .find(|ctx| ctx.property == request.property)
+        }) else {
+            return Err(trc::ResourceEvent::BadParameters
+                .into_err()
+                .details("Property is not an expression")
+                .ctx(trc::Key::Key, request.property.as_str()));
+        };
+
+        // Unsaved expressions are compiled with the same variables and constants
+        if let Some(expression) = &request.expression {
+            expr_ctx.expr = expression;
+        }
+        let mut bp = Bootstrap::new_uninitialized(self.registry().clone());
+        let if_block = bp.compile_expr(object_id, &expr_ctx);
+        if let Some(err) = bp.errors.into_iter().next() {
+            let reason = match err {
+                Error::Validation { errors, .. } => errors
+                    .into_iter()
+                    .filter_map(|err| match err {
+                        ValidationError::Invalid { value, .. } => Some(value),
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>()
+                    .join(", "),
+                Error::Build { message, .. } => message,
+                Error::Internal { error, .. } => error.to_string(),
+                Error::NotFound { .. } => "Object not found".to_string(),
+            };
+
+            return Err(trc::ResourceEvent::BadParameters
+                .into_err()
+                .details("Invalid expression")
+                .ctx(trc::Key::Key, request.property.as_str())
+                .reason(reason));
+        }
+
+        let envelope = request.envelope.normalize();
+        let trace = self
+            .eval_if_with_trace(&if_block, &envelope, session_id)
+            .await?;
+        let matched = trace.matched_branch();
+
+        Ok(JsonResponse::new(EvalResponse {
+            result: to_json(&trace.result),
+            matched,
+            trace: expr_ctx
+                .expr
+                .match_
+                .iter()
+                .enumerate()
+                .map(|(idx, match_)| EvalBranch {
+                    if_: match_.if_.clone(),
+                    then: match_.then.clone(),
+                    result: trace.conditions.get(idx).copied(),
+                })
+                .collect(),
+            else_: expr_ctx.expr.else_.clone(),
+        })
+        .no_cache()
+        .into_http_response())
+    }
+}
+
+impl Envelope {
+    fn normalize(mut self) -> Self {
+        self.sender = self.sender.to_lowercase();
+        self.helo = self.helo.to_lowercase();
+        for rcpt in &mut self.rcpt {
+            *rcpt = rcpt.to_lowercase();
+        }
+        self
+    }
+}
+
+impl Default for Envelope {
+    fn default() -> Self {
+        Self {
+            ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            helo: Default::default(),
+            sender: Default::default(),
+            rcpt: Default::default(),
+            auth: Default::default(),
+            listener: Default::default(),
+            protocol: "smtp".to_string(),
+            remote_port: Default::default(),
+            local_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+            local_port: 25,
+            is_tls: Default::default(),
+            tls_client_subject: Default::default(),
+            tls_client_san: Default::default(),
+            client_name: Default::default(),
+            client_version: Default::default(),
+            mx: Default::default(),
+            priority: Default::default(),
+            size: Default::default(),
+        }
+    }
+}
+
+impl ResolveVariable for Envelope {
+    fn resolve_variable(&self, variable: ExpressionVariable) -> Variable<'_> {
+        match variable {
+            ExpressionVariable::Rcpt => self
+                .rcpt
+                .last()
+                .map(|rcpt| rcpt.as_str())
+                .unwrap_or_default()
+                .into(),
+            ExpressionVariable::RcptDomain => self
+                .rcpt
+                .last()
+                .map(|rcpt| rcpt.domain_part())
+                .unwrap_or_default()
+                .into(),
+            ExpressionVariable::Recipients => self
+                .rcpt
+                .iter()
+                .map(|rcpt| Variable::from(rcpt.as_str()))
+                .collect::<Vec<_>>()
+                .into(),
+            ExpressionVariable::Sender => self.sender.as_str().into(),
+            ExpressionVariable::SenderDomain => self.sender.domain_part().into(),
+            ExpressionVariable::HeloDomain => self.helo.as_str().into(),
+            ExpressionVariable::AuthenticatedAs => self.auth.as_str().into(),
+            ExpressionVariable::Listener => self.listener.as_str().into(),
+            ExpressionVariable::RemoteIp | ExpressionVariable::ReceivedFromIp => {
+                self.ip.to_compact_string().into()
+            }
+            ExpressionVariable::RemotePort => self.remote_port.into(),
+            ExpressionVariable::LocalIp => self.local_ip.to_compact_string().into(),
+            ExpressionVariable::LocalPort | ExpressionVariable::ReceivedViaPort => {
+                self.local_port.into()
+            }
+            ExpressionVariable::Protocol => self.protocol.as_str().into(),
+            ExpressionVariable::IsTls => self.is_tls.into(),
+            ExpressionVariable::TlsClientSubject => self.tls_client_subject.as_str().into(),
+            ExpressionVariable::TlsClientSan => self
+                .tls_client_san
+                .iter()
+                .map(|name| Variable::from(name.as_str()))
+                .collect::<Vec<_>>()
+                .into(),
+            ExpressionVariable::ClientName => self.client_name.as_str().into(),
+            ExpressionVariable::ClientVersion => self.client_version.as_str().into(),
+            ExpressionVariable::IsV4 => self.ip.is_ipv4().into(),
+            ExpressionVariable::IsV6 => self.ip.is_ipv6().into(),
+            ExpressionVariable::Mx => self.mx.as_str().into(),
+            ExpressionVariable::Priority => self.priority.into(),
+            ExpressionVariable::Size => self.size.into(),
+            ExpressionVariable::Source => if !self.auth.is_empty() {
+                "authenticated"
+            } else {
+                "unauthenticated"
+            }
+            .into(),
+            _ => Variable::default(),
+        }
+    }
+
+    fn resolve_global(&self, _: &str) -> Variable<'_> {
+        Variable::Integer(0)
+    }
+}
+
+fn to_json(variable: &Variable<'_>) -> serde_json::Value {
+    match variable {
+        Variable::String(StringCow::Owned(value)) => value.as_str().into(),
+        Variable::String(StringCow::Borrowed(value)) => (*value).into(),
+        Variable::Integer(value) => (*value).into(),
+        Variable::Float(value) => (*value).into(),
+        Variable::Array(items) => items.iter().map(to_json).collect(),
+        Variable::Constant(value) => value.as_str().into(),
+    }
+}
@@ -0,0 +1,75 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, auth::AccessToken};
+use email::sieve::forwarding::{ForwardingSettings, SieveForwarding};
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+
+pub(crate) trait ForwardingApi: Sync + Send {
+    fn handle_forwarding_get(
+        &self,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_forwarding_update(
+        &self,
+        access_token: &AccessToken,
+        body: Option<Vec<u8>>,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_forwarding_delete(
+        &self,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl ForwardingApi for Server {
+    async fn handle_forwarding_get(&self, access_token: &AccessToken) -> trc::Result<HttpResponse> {
+        let settings = self
+            .forwarding_settings(access_token.account_id())
+            .await?
+            .unwrap_or_default();
+
+        Ok(JsonResponse::new(settings).no_cache().into_http_response())
+    }
+
+    async fn handle_forwarding_update(
+        &self,
+        access_token: &AccessToken,
+        body: Option<Vec<u8>>,
+    ) -> trc::Result<HttpResponse> {
+        let mut settings =
+            serde_json::from_slice::<ForwardingSettings>(body.as_deref().unwrap_or_default())
+                .map_err(|err| {
+                    trc::EventType::Resource(trc::ResourceEvent::BadParameters).from_json_error(err)
+                })?;
+        let account_id = access_token.account_id();
+
+        if let Err(reason) = self
+            .forwarding_settings_validate(account_id, &mut settings)
+            .await?
+        {
+            return Err(trc::ResourceEvent::BadParameters.into_err().details(reason));
+        }
+
+        self.forwarding_settings_update(account_id, Some(settings.clone()))
+            .await?;
+
+        Ok(JsonResponse::new(settings).no_cache().into_http_response())
+    }
+
+    async fn handle_forwarding_delete(
+        &self,
+        access_token: &AccessToken,
+    ) -> trc::Result<HttpResponse> {
+        self.forwarding_settings_update(access_token.account_id(), None)
+            .await?;
+
+        Ok(JsonResponse::new(ForwardingSettings::default())
+            .no_cache()
+            .into_http_response())
+    }
+}
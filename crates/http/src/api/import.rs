@@ -0,0 +1,146 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, auth::AccessToken};
+use email::migrate::mailstore::{MailStoreFormat, MailStoreImporter};
+use http_body_util::{StreamBody, combinators::BoxBody};
+use http_proto::{HttpRequest, HttpResponse, HttpSessionData, request::fetch_body};
+use hyper::{
+    StatusCode,
+    body::{Bytes, Frame},
+};
+use registry::schema::enums::Permission;
+use serde::Serialize;
+use std::{str::FromStr, time::Instant};
+use types::id::Id;
+use utils::url_params::UrlParams;
+
+// Number of imported messages between progress events
+const PROGRESS_INTERVAL: usize = 100;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(tag = "type")]
+pub(crate) enum ImportStage {
+    Progress {
+        total: usize,
+        imported: usize,
+        skipped: usize,
+    },
+    Completed {
+        total: usize,
+        imported: usize,
+        skipped: usize,
+        mailboxes: usize,
+        elapsed: u64,
+    },
+    Error {
+        reason: String,
+    },
+}
+
+pub(crate) trait MailStoreImportApi: Sync + Send {
+    fn handle_mail_store_import(
+        &self,
+        req: &mut HttpRequest,
+        session: &HttpSessionData,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl MailStoreImportApi for Server {
+    async fn handle_mail_store_import(
+        &self,
+        req: &mut HttpRequest,
+        session: &HttpSessionData,
+        access_token: &AccessToken,
+    ) -> trc::Result<HttpResponse> {
+        let params = UrlParams::new(req.uri().query());
+
+        // Importing into another account requires impersonation rights
+        let account_id = if let Some(account_id) = params.get("accountId") {
+            let account_id = Id::from_str(account_id)
+                .map_err(|_| trc::ResourceEvent::BadParameters.into_err())?
+                .document_id();
+            if account_id != access_token.account_id() {
+                access_token.enforce_permission(Permission::Impersonate)?;
+            }
+            account_id
+        } else {
+            access_token.account_id()
+        };
+        let format = params
+            .get("format")
+            .map(|format| {
+                MailStoreFormat::parse(format).ok_or_else(|| {
+                    trc::ResourceEvent::BadParameters
+                        .into_err()
+                        .details("Unsupported mail store format")
+                        .ctx(trc::Key::Type, format.to_string())
+                })
+            })
+            .transpose()?;
+        let mailbox = params.get("mailbox").map(|mailbox| mailbox.to_string());
+
+        // Parse the uploaded mail store
+        let data = fetch_body(req, self.core.jmap.upload_max_size, session.session_id)
+            .await
+            .ok_or_else(|| trc::LimitEvent::SizeUpload.into_err())?;
+        let format = format.unwrap_or_else(|| MailStoreFormat::detect(&data));
+        let messages = tokio::task::spawn_blocking(move || format.parse_messages(&data))
+            .await
+            .map_err(|err| {
+                trc::EventType::Server(trc::ServerEvent::ThreadError)
+                    .reason(err)
+                    .caused_by(trc::location!())
+            })??;
+        let mut importer = MailStoreImporter::new(self.clone(), account_id, mailbox).await?;
+        let started = Instant::now();
+        let total = messages.len();
+
+        Ok(HttpResponse::new(StatusCode::OK)
+            .with_content_type("text/event-stream")
+            .with_cache_control("no-store")
+            .with_stream_body(BoxBody::new(StreamBody::new(async_stream::stream! {
+                for (pos, message) in messages.into_iter().enumerate() {
+                    if let Err(err) = importer.import(message).await {
+                        let reason = err.to_string();
+                        trc::error!(err.account_id(account_id).details("Mail store import failed"));
+                        yield Ok(ImportStage::Error { reason }.to_frame());
+                        return;
+                    }
+                    if (pos + 1) % PROGRESS_INTERVAL == 0 {
+                        yield Ok(ImportStage::Progress {
+                            total,
+                            imported: importer.summary.emails,
+                            skipped: importer.summary.skipped,
+                        }
+                        .to_frame());
+                    }
+                }
+
+                let summary = importer.finish(format);
+                yield Ok(ImportStage::Completed {
+                    total,
+                    imported: summary.emails,
+                    skipped: summary.skipped,
+                    mailboxes: summary.mailboxes,
+                    elapsed: started.elapsed().as_millis() as u64,
+                }
+                .to_frame());
+            }))))
+    }
+}
+
+impl ImportStage {
+    pub fn to_frame(&self) -> Frame<Bytes> {
+        let payload = format!(
+            "event: event\ndata: [{}]\n\n",
+            serde_json::to_string(self).unwrap_or_default()
+        );
+        Frame::data(Bytes::from(payload))
+    }
+}
@@ -0,0 +1,92 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use registry::{schema::enums::TaskType, types::EnumImpl};
+use serde::Serialize;
+use services::task_manager::window::{MaintenanceWindowStatus, MaintenanceWindows};
+use store::write::now;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct MaintenanceWindow {
+    pub id: u32,
+    pub task_types: Vec<&'static str>,
+    pub is_open: bool,
+    pub is_triggered: bool,
+    pub opens_at: u64,
+    pub closes_at: Option<u64>,
+    pub rate_count: Option<u64>,
+    pub rate_period: Option<u64>,
+}
+
+pub(crate) trait MaintenanceWindowApi: Sync + Send {
+    fn handle_maintenance_window_request(
+        &self,
+        id: Option<&str>,
+        trigger: bool,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl MaintenanceWindowApi for Server {
+    async fn handle_maintenance_window_request(
+        &self,
+        id: Option<&str>,
+        trigger: bool,
+    ) -> trc::Result<HttpResponse> {
+        let id = id
+            .map(|id| {
+                id.parse::<u32>()
+                    .map_err(|_| trc::ResourceEvent::BadParameters.into_err())
+            })
+            .transpose()?;
+
+        if trigger {
+            let id = id.ok_or_else(|| trc::ResourceEvent::BadParameters.into_err())?;
+            if !self.trigger_maintenance_window(id).await? {
+                return Err(trc::ResourceEvent::NotFound.into_err());
+            }
+        }
+
+        let windows = self.maintenance_windows().await?;
+        if let Some(id) = id {
+            let window = windows
+                .into_iter()
+                .find(|window| window.id == id)
+                .ok_or_else(|| trc::ResourceEvent::NotFound.into_err())?;
+
+            Ok(JsonResponse::new(MaintenanceWindow::new(window))
+                .no_cache()
+                .into_http_response())
+        } else {
+            Ok(JsonResponse::new(
+                windows
+                    .into_iter()
+                    .map(MaintenanceWindow::new)
+                    .collect::<Vec<_>>(),
+            )
+            .no_cache()
+            .into_http_response())
+        }
+    }
+}
+
+impl MaintenanceWindow {
+    fn new(window: MaintenanceWindowStatus) -> Self {
+        let now = now();
+        MaintenanceWindow {
+            id: window.id,
+            task_types: window.task_types.iter().map(TaskType::as_str).collect(),
+            is_open: window.is_open,
+            is_triggered: window.is_triggered,
+            opens_at: now + window.opens_in.as_secs(),
+            closes_at: window.closes_in.map(|closes_in| now + closes_in.as_secs()),
+            rate_count: window.rate.as_ref().map(|rate| rate.count),
+            rate_period: window.rate.as_ref().map(|rate| rate.period.as_secs()),
+        }
+    }
+}
@@ -11,9 +11,38 @@
 pub mod telemetry;
 // SPDX-SnippetEnd
 pub mod diagnose;
+pub mod dry_run;
+pub mod eval;
+pub mod forwarding;
+pub mod import;
+pub mod maintenance;
+pub mod openapi;
+pub mod privacy;
+pub mod reindex;
+pub mod sessions;
+pub mod sieve;
+pub mod store;
+pub mod tasks;
+pub mod tracing;
+pub mod usage;
 
 use crate::{
-    api::diagnose::{DeliveryStage, spawn_delivery_diagnose},
+    api::{
+        diagnose::{DeliveryStage, spawn_delivery_diagnose},
+        dry_run::MailFlowDryRunApi,
+        eval::ExpressionEvalApi,
+        forwarding::ForwardingApi,
+        import::MailStoreImportApi,
+        maintenance::MaintenanceWindowApi,
+        privacy::AccountDataApi,
+        reindex::ReindexStatusApi,
+        sessions::SessionManagerApi,
+        sieve::SieveStatsApi,
+        store::StoreStatsApi,
+        tasks::TaskQueueApi,
+        tracing::TracingRuleApi,
+        usage::TenantUsageApi,
+    },
     auth::{
         authenticate::Authenticator, oauth::auth::OAuthApiHandler, permissions::AccountApiHandler,
     },
@@ -60,7 +89,7 @@ impl ManagementApi for Server {
         session: &HttpSessionData,
     ) -> trc::Result<HttpResponse> {
         let is_post = req.method() == Method::POST;
-        let body = if is_post {
+        let body = if is_post && !req.uri().path().starts_with("/api/import") {
             fetch_body(req, 1024 * 1024, session.session_id).await
         } else {
             None
@@ -90,7 +119,26 @@ impl ManagementApi for Server {
             "account" => {
                 // Authenticate request
                 let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
-                self.handle_account_request(&access_token).await
+
+                match (path.get(1).copied(), req.method()) {
+                    (None, _) => self.handle_account_request(&access_token).await,
+                    (Some("forwarding"), &Method::GET) => {
+                        access_token.enforce_permission(Permission::SieveForwardingGet)?;
+
+                        self.handle_forwarding_get(&access_token).await
+                    }
+                    (Some("forwarding"), &Method::POST) => {
+                        access_token.enforce_permission(Permission::SieveForwardingUpdate)?;
+
+                        self.handle_forwarding_update(&access_token, body).await
+                    }
+                    (Some("forwarding"), &Method::DELETE) => {
+                        access_token.enforce_permission(Permission::SieveForwardingUpdate)?;
+
+                        self.handle_forwarding_delete(&access_token).await
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
             }
             "schema" => {
                 // Authenticate request
@@ -109,6 +157,182 @@ impl ManagementApi for Server {
                     Ok(HttpResponse::redirect(format!("/api/schema/{SCHEMA_HASH}")))
                 }
             }
+            "eval" if is_post => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+                access_token.enforce_permission(Permission::ExpressionEvaluate)?;
+
+                self.handle_eval_request(body, session.session_id).await
+            }
+            "dry-run" if is_post => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+                access_token.enforce_permission(Permission::MailFlowDryRun)?;
+
+                self.handle_dry_run_request(body, session).await
+            }
+            "import" if is_post => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+                access_token.enforce_permission(Permission::MailStoreImport)?;
+
+                self.handle_mail_store_import(req, session, &access_token)
+                    .await
+            }
+            "maintenance" => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+
+                match (path.get(1).copied(), path.get(2).copied(), req.method()) {
+                    (id, None, &Method::GET) => {
+                        access_token.enforce_permission(Permission::MaintenanceWindowStatus)?;
+
+                        self.handle_maintenance_window_request(id, false).await
+                    }
+                    (Some(id), Some("trigger"), &Method::POST) => {
+                        access_token.enforce_permission(Permission::MaintenanceWindowTrigger)?;
+
+                        self.handle_maintenance_window_request(Some(id), true).await
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
+            }
+            "principal" => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+
+                match (path.get(1).copied(), path.get(2).copied(), req.method()) {
+                    (Some(id), Some("export"), &Method::GET) => {
+                        access_token.enforce_permission(Permission::AccountDataExport)?;
+
+                        self.handle_account_data_export(&access_token, id).await
+                    }
+                    (Some(id), Some("purge"), &Method::POST) => {
+                        access_token.enforce_permission(Permission::AccountDataPurge)?;
+
+                        self.handle_account_data_purge(&access_token, id).await
+                    }
+                    (Some(id), Some("sieve"), &Method::GET) => {
+                        access_token.enforce_permission(Permission::SieveStatsGet)?;
+
+                        self.handle_sieve_stats(&access_token, id).await
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
+            }
+            "reindex" if req.method() == Method::GET => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+                access_token.enforce_permission(Permission::ReindexStatus)?;
+
+                self.handle_reindex_status_request(path.get(1).copied())
+                    .await
+            }
+            "sessions" => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+
+                match (path.get(1).copied(), path.get(2).copied(), req.method()) {
+                    (id, None, &Method::GET) => {
+                        access_token.enforce_permission(Permission::SessionList)?;
+
+                        self.handle_session_list_request(id, &UrlParams::new(req.uri().query()))
+                    }
+                    (Some("terminate"), None, &Method::POST) => {
+                        access_token.enforce_permission(Permission::SessionTerminate)?;
+
+                        self.handle_session_terminate_request(None, body)
+                    }
+                    (Some(id), None, &Method::DELETE) => {
+                        access_token.enforce_permission(Permission::SessionTerminate)?;
+
+                        self.handle_session_terminate_request(Some(id), None)
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
+            }
+            "tasks" => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+
+                match (path.get(1).copied(), path.get(2).copied(), req.method()) {
+                    (id, None, &Method::GET) => {
+                        access_token.enforce_permission(Permission::TaskList)?;
+
+                        self.handle_task_list_request(id, &UrlParams::new(req.uri().query()))
+                            .await
+                    }
+                    (Some(id), None, &Method::DELETE) => {
+                        self.handle_task_cancel_request(&access_token, id).await
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
+            }
+            "usage" if req.method() == Method::GET => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+                access_token.enforce_permission(Permission::SysTenantUsageGet)?;
+
+                self.handle_usage_export(&access_token, &UrlParams::new(req.uri().query()))
+                    .await
+            }
+            "tracing" => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+
+                match (path.get(1).copied(), path.get(2).copied(), req.method()) {
+                    (Some("rules"), id, &Method::GET) => {
+                        access_token.enforce_permission(Permission::TracingRuleList)?;
+
+                        self.handle_tracing_rule_list(id)
+                    }
+                    (Some("rules"), None, &Method::POST) => {
+                        access_token.enforce_permission(Permission::TracingRuleManage)?;
+
+                        self.handle_tracing_rule_create(body)
+                    }
+                    (Some("rules"), Some(id), &Method::DELETE) => {
+                        access_token.enforce_permission(Permission::TracingRuleManage)?;
+
+                        self.handle_tracing_rule_delete(id)
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
+            }
+            "store" => {
+                // Authenticate request
+                let (_in_flight, access_token) = self.authenticate_headers(req, session).await?;
+
+                match (path.get(1).copied(), path.get(2).copied(), req.method()) {
+                    (Some("doctor"), None, &Method::GET) => {
+                        access_token.enforce_permission(Permission::DataStoreCheck)?;
+
+                        self.handle_store_doctor_request(false).await
+                    }
+                    (Some("doctor"), Some("repair"), &Method::POST) => {
+                        access_token.enforce_permission(Permission::DataStoreRepair)?;
+
+                        self.handle_store_doctor_request(true).await
+                    }
+                    (Some("partitions"), Some("migrate"), &Method::POST) => {
+                        access_token.enforce_permission(Permission::DataStoreRepair)?;
+
+                        self.handle_store_partitions_migrate().await
+                    }
+                    (Some("partitions"), Some(kind), &Method::DELETE) => {
+                        access_token.enforce_permission(Permission::DataStoreRepair)?;
+
+                        self.handle_store_partition_erase(kind, path.get(3).copied())
+                            .await
+                    }
+                    (_, _, &Method::GET) => {
+                        access_token.enforce_permission(Permission::DataStoreStats)?;
+
+                        self.handle_store_stats_request(&path).await
+                    }
+                    _ => Err(trc::ResourceEvent::NotFound.into_err()),
+                }
+            }
             "token" => {
                 let access_token = self.management_access_token(req, session).await?;
                 let account_id = access_token.account_id();
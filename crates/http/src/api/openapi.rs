@@ -0,0 +1,1256 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::{
+        diagnose::DeliveryStage,
+        dry_run::{DryRunRequest, DryRunResponse},
+        eval::{EvalRequest, EvalResponse},
+        import::ImportStage,
+        maintenance::MaintenanceWindow,
+        privacy::PurgeResponse,
+        reindex::ReindexStatus,
+        sessions::{TerminateRequest, TerminateResponse},
+        sieve::SieveStatsResponse,
+        tasks::TaskSummary,
+        tracing::{TracingRuleRequest, TracingRuleResponse},
+        usage::UsageRecord,
+    },
+    auth::{
+        oauth::auth::{LoginRequest, LoginResponse},
+        permissions::Account,
+    },
+};
+use common::{Server, network::sessions::ActiveSessionInfo};
+use email::sieve::forwarding::ForwardingSettings;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use registry::{schema::enums::Permission, types::EnumImpl};
+use serde_json::{Map, Value, json};
+
+pub(crate) trait ApiSchema {
+    fn schema() -> Value;
+}
+
+struct ApiRoute {
+    method: &'static str,
+    path: &'static str,
+    summary: &'static str,
+    permission: Option<Permission>,
+    is_anonymous: bool,
+    params: &'static [ApiParam],
+    request: Option<ApiBody>,
+    response: ApiBody,
+}
+
+struct ApiParam {
+    name: &'static str,
+    location: &'static str,
+    description: &'static str,
+}
+
+enum ApiBody {
+    Json(fn() -> Value),
+    EventStream(fn() -> Value),
+    Binary,
+    Text,
+}
+
+const ROUTES: &[ApiRoute] = &[
+    ApiRoute {
+        method: "post",
+        path: "/api/auth",
+        summary: "Authenticate an account and obtain an OAuth client code",
+        permission: None,
+        is_anonymous: true,
+        params: &[],
+        request: Some(ApiBody::Json(LoginRequest::schema)),
+        response: ApiBody::Json(LoginResponse::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/discover/{email}",
+        summary: "Obtain the OpenID Connect discovery document for an account",
+        permission: None,
+        is_anonymous: true,
+        params: &[ApiParam {
+            name: "email",
+            location: "path",
+            description: "Account name or email address",
+        }],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/account",
+        summary: "Obtain the permissions and locale of the authenticated account",
+        permission: None,
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(Account::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/account/forwarding",
+        summary: "Obtain the forwarding settings of the authenticated account",
+        permission: Some(Permission::SieveForwardingGet),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(ForwardingSettings::schema),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/account/forwarding",
+        summary: "Replace the forwarding settings of the authenticated account",
+        permission: Some(Permission::SieveForwardingUpdate),
+        is_anonymous: false,
+        params: &[],
+        request: Some(ApiBody::Json(ForwardingSettings::schema)),
+        response: ApiBody::Json(ForwardingSettings::schema),
+    },
+    ApiRoute {
+        method: "delete",
+        path: "/api/account/forwarding",
+        summary: "Disable message forwarding for the authenticated account",
+        permission: Some(Permission::SieveForwardingUpdate),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(ForwardingSettings::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/schema/{hash}",
+        summary: "Download the registry object schema",
+        permission: None,
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "hash",
+            location: "path",
+            description: "Schema hash, other values redirect to the current schema",
+        }],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/eval",
+        summary: "Evaluate a configuration expression against a test envelope",
+        permission: Some(Permission::ExpressionEvaluate),
+        is_anonymous: false,
+        params: &[],
+        request: Some(ApiBody::Json(EvalRequest::schema)),
+        response: ApiBody::Json(EvalResponse::schema),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/dry-run",
+        summary: "Simulate the delivery of a message through the inbound pipeline",
+        permission: Some(Permission::MailFlowDryRun),
+        is_anonymous: false,
+        params: &[],
+        request: Some(ApiBody::Json(DryRunRequest::schema)),
+        response: ApiBody::Json(DryRunResponse::schema),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/import",
+        summary: "Import a mail store archive into an account",
+        permission: Some(Permission::MailStoreImport),
+        is_anonymous: false,
+        params: &[
+            ApiParam {
+                name: "accountId",
+                location: "query",
+                description: "Target account, defaults to the authenticated account",
+            },
+            ApiParam {
+                name: "format",
+                location: "query",
+                description: "Archive format, detected automatically when omitted",
+            },
+            ApiParam {
+                name: "mailbox",
+                location: "query",
+                description: "Mailbox to import into",
+            },
+        ],
+        request: Some(ApiBody::Binary),
+        response: ApiBody::EventStream(ImportStage::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/maintenance",
+        summary: "List the maintenance windows of background tasks",
+        permission: Some(Permission::MaintenanceWindowStatus),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(maintenance_window_list),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/maintenance/{id}",
+        summary: "Obtain the status of a maintenance window",
+        permission: Some(Permission::MaintenanceWindowStatus),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Maintenance window index",
+        }],
+        request: None,
+        response: ApiBody::Json(MaintenanceWindow::schema),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/maintenance/{id}/trigger",
+        summary: "Open a maintenance window immediately for its configured duration",
+        permission: Some(Permission::MaintenanceWindowTrigger),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Maintenance window index",
+        }],
+        request: None,
+        response: ApiBody::Json(MaintenanceWindow::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/principal/{id}/export",
+        summary: "Export all data held for an account as a ZIP file",
+        permission: Some(Permission::AccountDataExport),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Account identifier",
+        }],
+        request: None,
+        response: ApiBody::Binary,
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/principal/{id}/purge",
+        summary: "Permanently delete all data held for an account",
+        permission: Some(Permission::AccountDataPurge),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Account identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(PurgeResponse::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/principal/{id}/sieve",
+        summary: "Obtain the Sieve action counters and redirect targets of an account",
+        permission: Some(Permission::SieveStatsGet),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Account identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(SieveStatsResponse::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/reindex",
+        summary: "List the search reindexing progress of all accounts",
+        permission: Some(Permission::ReindexStatus),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(reindex_status_list),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/reindex/{accountId}",
+        summary: "Obtain the search reindexing progress of an account",
+        permission: Some(Permission::ReindexStatus),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "accountId",
+            location: "path",
+            description: "Account identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(ReindexStatus::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/sessions",
+        summary: "List the sessions currently open on this node",
+        permission: Some(Permission::SessionList),
+        is_anonymous: false,
+        params: &[
+            ApiParam {
+                name: "protocol",
+                location: "query",
+                description: "Only list sessions of this protocol",
+            },
+            ApiParam {
+                name: "accountId",
+                location: "query",
+                description: "Only list sessions authenticated as this account",
+            },
+        ],
+        request: None,
+        response: ApiBody::Json(active_session_list),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/sessions/{id}",
+        summary: "Obtain the details of an open session",
+        permission: Some(Permission::SessionList),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Session identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(ActiveSessionInfo::schema),
+    },
+    ApiRoute {
+        method: "delete",
+        path: "/api/sessions/{id}",
+        summary: "Close an open session",
+        permission: Some(Permission::SessionTerminate),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Session identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(TerminateResponse::schema),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/sessions/terminate",
+        summary: "Close the selected open sessions",
+        permission: Some(Permission::SessionTerminate),
+        is_anonymous: false,
+        params: &[],
+        request: Some(ApiBody::Json(TerminateRequest::schema)),
+        response: ApiBody::Json(TerminateResponse::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/tasks",
+        summary: "List queued background tasks and the progress of running ones",
+        permission: Some(Permission::TaskList),
+        is_anonymous: false,
+        params: &[
+            ApiParam {
+                name: "type",
+                location: "query",
+                description: "Only list tasks of this type",
+            },
+            ApiParam {
+                name: "limit",
+                location: "query",
+                description: "Maximum number of tasks to list",
+            },
+        ],
+        request: None,
+        response: ApiBody::Json(task_summary_list),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/tasks/{id}",
+        summary: "Obtain the status and progress of a background task",
+        permission: Some(Permission::TaskList),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Task identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(TaskSummary::schema),
+    },
+    ApiRoute {
+        method: "delete",
+        path: "/api/tasks/{id}",
+        summary: "Cancel a queued or running background task",
+        permission: None,
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Task identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(TaskSummary::schema),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/tracing/rules",
+        summary: "List the runtime tracing rules active on this node",
+        permission: Some(Permission::TracingRuleList),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(tracing_rule_list),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/tracing/rules/{id}",
+        summary: "Obtain a runtime tracing rule",
+        permission: Some(Permission::TracingRuleList),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Tracing rule identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(TracingRuleResponse::schema),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/tracing/rules",
+        summary: "Change the level or sampling rate of events without restarting",
+        permission: Some(Permission::TracingRuleManage),
+        is_anonymous: false,
+        params: &[],
+        request: Some(ApiBody::Json(TracingRuleRequest::schema)),
+        response: ApiBody::Json(TracingRuleResponse::schema),
+    },
+    ApiRoute {
+        method: "delete",
+        path: "/api/tracing/rules/{id}",
+        summary: "Remove a runtime tracing rule",
+        permission: Some(Permission::TracingRuleManage),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Tracing rule identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/usage",
+        summary: "Export the daily usage records of tenants for billing",
+        permission: Some(Permission::SysTenantUsageGet),
+        is_anonymous: false,
+        params: &[
+            ApiParam {
+                name: "tenantId",
+                location: "query",
+                description: "Only export the usage of this tenant",
+            },
+            ApiParam {
+                name: "from",
+                location: "query",
+                description: "First day to export, as YYYY-MM-DD or a UTC timestamp",
+            },
+            ApiParam {
+                name: "to",
+                location: "query",
+                description: "Export days starting before this date",
+            },
+            ApiParam {
+                name: "format",
+                location: "query",
+                description: "Either json (default) or csv",
+            },
+        ],
+        request: None,
+        response: ApiBody::Json(usage_record_list),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/store/stats",
+        summary: "Obtain data store statistics",
+        permission: Some(Permission::DataStoreStats),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/store/doctor",
+        summary: "Check the data store for inconsistencies",
+        permission: Some(Permission::DataStoreCheck),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/store/doctor/repair",
+        summary: "Check the data store and repair inconsistencies",
+        permission: Some(Permission::DataStoreRepair),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "post",
+        path: "/api/store/partitions/migrate",
+        summary: "Move account data into FoundationDB directory partitions",
+        permission: Some(Permission::DataStoreRepair),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "delete",
+        path: "/api/store/partitions/account/{id}",
+        summary: "Erase the FoundationDB partition of an account",
+        permission: Some(Permission::DataStoreRepair),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Account identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "delete",
+        path: "/api/store/partitions/tenant/{id}",
+        summary: "Erase the FoundationDB partition of a tenant",
+        permission: Some(Permission::DataStoreRepair),
+        is_anonymous: false,
+        params: &[ApiParam {
+            name: "id",
+            location: "path",
+            description: "Tenant identifier",
+        }],
+        request: None,
+        response: ApiBody::Json(any_object),
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/token/delivery",
+        summary: "Issue a short-lived token for live delivery tests",
+        permission: Some(Permission::LiveDeliveryTest),
+        is_anonymous: false,
+        params: &[],
+        request: None,
+        response: ApiBody::Text,
+    },
+    ApiRoute {
+        method: "get",
+        path: "/api/live/delivery/{target}",
+        summary: "Run a live delivery test against a domain or address",
+        permission: Some(Permission::LiveDeliveryTest),
+        is_anonymous: false,
+        params: &[
+            ApiParam {
+                name: "target",
+                location: "path",
+                description: "Domain name or email address",
+            },
+            ApiParam {
+                name: "timeout",
+                location: "query",
+                description: "Timeout in seconds",
+            },
+            ApiParam {
+                name: "token",
+                location: "query",
+                description: "Token issued by /api/token/delivery",
+            },
+        ],
+        request: None,
+        response: ApiBody::EventStream(DeliveryStage::schema),
+    },
+];
+
+pub(crate) trait OpenApiHandler: Sync + Send {
+    fn handle_openapi_request(&self) -> trc::Result<HttpResponse>;
+}
+
+impl OpenApiHandler for Server {
+    fn handle_openapi_request(&self) -> trc::Result<HttpResponse> {
+        Ok(
+            JsonResponse::new(openapi_document(&self.core.network.http.url_https))
+                .into_http_response(),
+        )
+    }
+}
+
+fn openapi_document(base_url: &str) -> Value {
+    let mut paths = Map::new();
+    for route in ROUTES {
+        let mut operation = Map::new();
+        operation.insert("summary".into(), route.summary.into());
+        if let Some(permission) = route.permission {
+            operation.insert(
+                "description".into(),
+                format!("Requires the `{}` permission.", permission.as_str()).into(),
+            );
+            operation.insert("x-permission".into(), permission.as_str().into());
+        }
+        if route.is_anonymous {
+            operation.insert("security".into(), json!([]));
+        }
+        if !route.params.is_empty() {
+            operation.insert(
+                "parameters".into(),
+                route
+                    .params
+                    .iter()
+                    .map(|param| {
+                        json!({
+                            "name": param.name,
+                            "in": param.location,
+                            "description": param.description,
+                            "required": param.location == "path",
+                            "schema": string(),
+                        })
+                    })
+                    .collect(),
+            );
+        }
+        if let Some(request) = &route.request {
+            operation.insert(
+                "requestBody".into(),
+                json!({ "required": true, "content": request.content() }),
+            );
+        }
+        operation.insert(
+            "responses".into(),
+            json!({
+                "200": { "description": "Success", "content": route.response.content() },
+                "default": { "$ref": "#/components/responses/Problem" },
+            }),
+        );
+
+        paths
+            .entry(route.path)
+            .or_insert_with(|| Value::Object(Map::new()))
+            .as_object_mut()
+            .unwrap()
+            .insert(route.method.into(), operation.into());
+    }
+
+    json!({
+        "openapi": "3.1.0",
+        "info": {
+            "title": "Stalwart Management API",
+            "version": env!("CARGO_PKG_VERSION"),
+        },
+        "servers": [{ "url": base_url }],
+        "paths": paths,
+        "security": [{ "bearerAuth": [] }, { "basicAuth": [] }],
+        "components": {
+            "securitySchemes": {
+                "bearerAuth": { "type": "http", "scheme": "bearer" },
+                "basicAuth": { "type": "http", "scheme": "basic" },
+            },
+            "responses": {
+                "Problem": {
+                    "description": "Request failed",
+                    "content": {
+                        "application/problem+json": {
+                            "schema": object(
+                                &[
+                                    ("type", string()),
+                                    ("status", integer()),
+                                    ("title", string()),
+                                    ("detail", string()),
+                                ],
+                                &["type", "status"],
+                            ),
+                        },
+                    },
+                },
+            },
+        },
+    })
+}
+
+impl ApiBody {
+    fn content(&self) -> Value {
+        match self {
+            ApiBody::Json(schema) => json!({ "application/json": { "schema": schema() } }),
+            ApiBody::EventStream(schema) => json!({
+                "text/event-stream": {
+                    "schema": string(),
+                    "itemSchema": schema(),
+                },
+            }),
+            ApiBody::Binary => json!({
+                "application/octet-stream": { "schema": { "type": "string", "format": "binary" } },
+            }),
+            ApiBody::Text => json!({ "text/plain": { "schema": string() } }),
+        }
+    }
+}
+
+impl ApiSchema for LoginRequest {
+    fn schema() -> Value {
+        json!({
+            "oneOf": [
+                object(
+                    &[
+                        ("type", constant("authCode")),
+                        ("accountName", string()),
+                        ("accountSecret", string()),
+                        ("mfaToken", string()),
+                        ("clientId", string()),
+                        ("redirectUri", string()),
+                        ("nonce", string()),
+                        ("scope", string()),
+                        ("codeChallenge", string()),
+                        ("codeChallengeMethod", string()),
+                        ("state", string()),
+                    ],
+                    &["type", "accountName", "accountSecret", "clientId"],
+                ),
+                object(
+                    &[
+                        ("type", constant("authDevice")),
+                        ("accountName", string()),
+                        ("accountSecret", string()),
+                        ("mfaToken", string()),
+                        ("code", string()),
+                    ],
+                    &["type", "accountName", "accountSecret", "code"],
+                ),
+            ],
+        })
+    }
+}
+
+impl ApiSchema for LoginResponse {
+    fn schema() -> Value {
+        json!({
+            "oneOf": [
+                object(
+                    &[("type", constant("authenticated")), ("clientCode", string())],
+                    &["type", "clientCode"],
+                ),
+                object(&[("type", constant("verified"))], &["type"]),
+                object(&[("type", constant("mfaRequired"))], &["type"]),
+                object(&[("type", constant("failure"))], &["type"]),
+            ],
+        })
+    }
+}
+
+impl ApiSchema for Account {
+    fn schema() -> Value {
+        object(
+            &[
+                ("permissions", array(string())),
+                ("edition", string()),
+                ("locale", string()),
+            ],
+            &["permissions", "edition", "locale"],
+        )
+    }
+}
+
+impl ApiSchema for EvalRequest {
+    fn schema() -> Value {
+        object(
+            &[
+                ("object", string()),
+                ("id", string()),
+                ("property", string()),
+                ("expression", any_object()),
+                (
+                    "envelope",
+                    object(
+                        &[
+                            ("ip", string()),
+                            ("helo", string()),
+                            ("sender", string()),
+                            ("rcpt", array(string())),
+                            ("auth", string()),
+                            ("listener", string()),
+                            ("protocol", string()),
+                            ("remotePort", integer()),
+                            ("localIp", string()),
+                            ("localPort", integer()),
+                            ("isTls", boolean()),
+                            ("tlsClientSubject", string()),
+                            ("tlsClientSan", array(string())),
+                            ("mx", string()),
+                            ("priority", integer()),
+                            ("size", integer()),
+                        ],
+                        &[],
+                    ),
+                ),
+            ],
+            &["object", "property"],
+        )
+    }
+}
+
+impl ApiSchema for EvalResponse {
+    fn schema() -> Value {
+        object(
+            &[
+                ("result", json!({})),
+                ("matched", integer()),
+                (
+                    "trace",
+                    array(object(
+                        &[("if", string()), ("then", string()), ("result", boolean())],
+                        &["if", "then"],
+                    )),
+                ),
+                ("else", string()),
+            ],
+            &["result", "trace", "else"],
+        )
+    }
+}
+
+impl ApiSchema for DryRunRequest {
+    fn schema() -> Value {
+        object(
+            &[
+                ("message", string()),
+                ("remoteIp", string()),
+                ("remotePort", integer()),
+                ("localIp", string()),
+                ("localPort", integer()),
+                ("helo", string()),
+                ("mailFrom", string()),
+                ("rcptTo", array(string())),
+                ("authenticatedAs", string()),
+                ("liveDns", boolean()),
+                ("mockFilters", boolean()),
+                ("iprev", string()),
+                (
+                    "dmarc",
+                    object(
+                        &[("result", string()), ("policy", string())],
+                        &["result", "policy"],
+                    ),
+                ),
+            ],
+            &["message"],
+        )
+    }
+}
+
+impl ApiSchema for DryRunResponse {
+    fn schema() -> Value {
+        object(
+            &[
+                ("verdict", string()),
+                ("stage", string()),
+                ("response", string()),
+                (
+                    "recipients",
+                    array(object(
+                        &[
+                            ("address", string()),
+                            ("response", string()),
+                            ("isSpam", boolean()),
+                            ("isLocal", boolean()),
+                            ("isQuarantined", boolean()),
+                            ("mailboxes", array(string())),
+                            ("redirects", array(string())),
+                            ("rejectReason", string()),
+                        ],
+                        &[
+                            "address",
+                            "response",
+                            "isSpam",
+                            "isLocal",
+                            "isQuarantined",
+                            "mailboxes",
+                            "redirects",
+                        ],
+                    )),
+                ),
+                ("spamVerdict", string()),
+                ("spamScore", number()),
+                ("sieveAction", string()),
+                (
+                    "filters",
+                    array(object(
+                        &[
+                            ("kind", string()),
+                            ("action", string()),
+                            ("response", string()),
+                        ],
+                        &["kind", "action"],
+                    )),
+                ),
+                ("headers", string()),
+                ("size", integer()),
+            ],
+            &[
+                "verdict",
+                "stage",
+                "response",
+                "recipients",
+                "filters",
+                "headers",
+                "size",
+            ],
+        )
+    }
+}
+
+impl ApiSchema for ImportStage {
+    fn schema() -> Value {
+        json!({
+            "oneOf": [
+                object(
+                    &[
+                        ("type", constant("progress")),
+                        ("total", integer()),
+                        ("imported", integer()),
+                        ("skipped", integer()),
+                    ],
+                    &["type", "total", "imported", "skipped"],
+                ),
+                object(
+                    &[
+                        ("type", constant("completed")),
+                        ("total", integer()),
+                        ("imported", integer()),
+                        ("skipped", integer()),
+                        ("mailboxes", integer()),
+                        ("elapsed", integer()),
+                    ],
+                    &["type", "total", "imported", "skipped", "mailboxes", "elapsed"],
+                ),
+                object(
+                    &[("type", constant("error")), ("reason", string())],
+                    &["type", "reason"],
+                ),
+            ],
+        })
+    }
+}
+
+impl ApiSchema for PurgeResponse {
+    fn schema() -> Value {
+        object(
+            &[
+                ("accountId", integer()),
+                ("blobs", integer()),
+                ("registryItems", integer()),
+            ],
+            &["accountId", "blobs", "registryItems"],
+        )
+    }
+}
+
+impl ApiSchema for SieveStatsResponse {
+    fn schema() -> Value {
+        object(
+            &[
+                ("accountId", integer()),
+                (
+                    "scripts",
+                    array(object(
+                        &[
+                            ("name", string()),
+                            ("keep", integer()),
+                            ("fileInto", integer()),
+                            ("discard", integer()),
+                            ("reject", integer()),
+                            ("redirect", integer()),
+                        ],
+                        &["name", "keep", "fileInto", "discard", "reject", "redirect"],
+                    )),
+                ),
+                ("redirects", array(string())),
+            ],
+            &["accountId", "scripts", "redirects"],
+        )
+    }
+}
+
+impl ApiSchema for ReindexStatus {
+    fn schema() -> Value {
+        object(
+            &[
+                ("accountId", string()),
+                ("total", integer()),
+                ("indexed", integer()),
+                ("percentage", number()),
+                ("startedAt", integer()),
+                ("updatedAt", integer()),
+                ("completedAt", integer()),
+            ],
+            &[
+                "accountId",
+                "total",
+                "indexed",
+                "percentage",
+                "startedAt",
+                "updatedAt",
+            ],
+        )
+    }
+}
+
+impl ApiSchema for TaskSummary {
+    fn schema() -> Value {
+        object(
+            &[
+                ("id", string()),
+                ("type", string()),
+                ("status", string()),
+                ("due", integer()),
+                ("attemptNumber", integer()),
+                (
+                    "progress",
+                    object(
+                        &[
+                            ("processed", integer()),
+                            ("total", integer()),
+                            ("percentage", number()),
+                            ("startedAt", integer()),
+                            ("updatedAt", integer()),
+                        ],
+                        &["processed", "total", "percentage", "startedAt", "updatedAt"],
+                    ),
+                ),
+            ],
+            &["id", "type", "status", "attemptNumber"],
+        )
+    }
+}
+
+impl ApiSchema for MaintenanceWindow {
+    fn schema() -> Value {
+        object(
+            &[
+                ("id", integer()),
+                ("taskTypes", array(string())),
+                ("isOpen", boolean()),
+                ("isTriggered", boolean()),
+                ("opensAt", integer()),
+                ("closesAt", integer()),
+                ("rateCount", integer()),
+                ("ratePeriod", integer()),
+            ],
+            &["id", "taskTypes", "isOpen", "isTriggered", "opensAt"],
+        )
+    }
+}
+
+impl ApiSchema for ActiveSessionInfo {
+    fn schema() -> Value {
+        object(
+            &[
+                ("id", integer()),
+                ("listenerId", string()),
+                ("protocol", string()),
+                ("accountId", integer()),
+                ("remoteIp", string()),
+                ("remotePort", integer()),
+                ("createdAt", integer()),
+                ("idleSecs", integer()),
+                ("bytesReceived", integer()),
+                ("bytesSent", integer()),
+            ],
+            &[
+                "id",
+                "listenerId",
+                "protocol",
+                "remoteIp",
+                "remotePort",
+                "createdAt",
+                "idleSecs",
+                "bytesReceived",
+                "bytesSent",
+            ],
+        )
+    }
+}
+
+impl ApiSchema for TerminateRequest {
+    fn schema() -> Value {
+        object(&[("ids", array(integer()))], &["ids"])
+    }
+}
+
+impl ApiSchema for TerminateResponse {
+    fn schema() -> Value {
+        object(&[("terminated", array(integer()))], &["terminated"])
+    }
+}
+
+impl ApiSchema for UsageRecord {
+    fn schema() -> Value {
+        object(
+            &[
+                ("tenantId", string()),
+                ("from", string()),
+                ("to", string()),
+                ("storageUsed", integer()),
+                ("messagesSent", integer()),
+                ("messagesReceived", integer()),
+                ("bytesSent", integer()),
+                ("bytesReceived", integer()),
+                ("apiCalls", integer()),
+            ],
+            &[
+                "tenantId",
+                "from",
+                "to",
+                "storageUsed",
+                "messagesSent",
+                "messagesReceived",
+                "bytesSent",
+                "bytesReceived",
+                "apiCalls",
+            ],
+        )
+    }
+}
+
+impl ApiSchema for TracingRuleRequest {
+    fn schema() -> Value {
+        object(
+            &[
+                ("events", array(string())),
+                ("level", string()),
+                ("sampleRate", integer()),
+                ("accountId", integer()),
+                ("remoteIp", string()),
+                ("expiresIn", integer()),
+            ],
+            &["events"],
+        )
+    }
+}
+
+impl ApiSchema for TracingRuleResponse {
+    fn schema() -> Value {
+        object(
+            &[
+                ("id", integer()),
+                ("events", array(string())),
+                ("level", string()),
+                ("sampleRate", integer()),
+                ("accountId", integer()),
+                ("remoteIp", string()),
+                ("expiresAt", integer()),
+            ],
+            &["id", "events"],
+        )
+    }
+}
+
+impl ApiSchema for ForwardingSettings {
+    fn schema() -> Value {
+        object(
+            &[
+                ("forwardTo", array(string())),
+                ("keepCopy", boolean()),
+                (
+                    "rules",
+                    array(object(
+                        &[
+                            ("field", string()),
+                            ("operator", string()),
+                            ("value", string()),
+                            ("forwardTo", array(string())),
+                            ("keepCopy", boolean()),
+                        ],
+                        &["field", "value", "forwardTo"],
+                    )),
+                ),
+            ],
+            &[],
+        )
+    }
+}
+
+impl ApiSchema for DeliveryStage {
+    fn schema() -> Value {
+        // Each stage carries its own fields, only the discriminator is common
+        object(&[("type", string()), ("elapsed", integer())], &["type"])
+    }
+}
+
+fn maintenance_window_list() -> Value {
+    array(MaintenanceWindow::schema())
+}
+
+fn active_session_list() -> Value {
+    array(ActiveSessionInfo::schema())
+}
+
+fn tracing_rule_list() -> Value {
+    array(TracingRuleResponse::schema())
+}
+
+fn reindex_status_list() -> Value {
+    array(ReindexStatus::schema())
+}
+
+fn task_summary_list() -> Value {
+    array(TaskSummary::schema())
+}
+
+fn usage_record_list() -> Value {
+    array(UsageRecord::schema())
+}
+
+fn object(properties: &[(&str, Value)], required: &[&str]) -> Value {
+    json!({
+        "type": "object",
+        "properties": properties
+            .iter()
+            .map(|(name, schema)| (name.to_string(), schema.clone()))
+            .collect::<Map<_, _>>(),
+        "required": required,
+    })
+}
+
+fn any_object() -> Value {
+    json!({ "type": "object" })
+}
+
+fn array(items: Value) -> Value {
+    json!({ "type": "array", "items": items })
+}
+
+fn constant(value: &str) -> Value {
+    json!({ "type": "string", "const": value })
+}
+
+fn string() -> Value {
+    json!({ "type": "string" })
+}
+
+fn integer() -> Value {
+    json!({ "type": "integer" })
+}
+
+fn number() -> Value {
+    json!({ "type": "number" })
+}
+
+fn boolean() -> Value {
+    json!({ "type": "boolean" })
+}
@@ -0,0 +1,96 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, auth::AccessToken};
+use email::migrate::privacy::AccountDataExport;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use hyper::StatusCode;
+use serde::Serialize;
+use services::task_manager::destroy_account::AccountPurge;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct PurgeResponse {
+    pub account_id: u32,
+    pub blobs: usize,
+    pub registry_items: usize,
+}
+
+pub(crate) trait AccountDataApi: Sync + Send {
+    fn handle_account_data_export(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_account_data_purge(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl AccountDataApi for Server {
+    async fn handle_account_data_export(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> trc::Result<HttpResponse> {
+        let account_id = self.account_data_target(access_token, id).await?;
+        let contents = self.account_data_export(account_id).await?;
+
+        Ok(HttpResponse::new(StatusCode::OK)
+            .with_content_type("application/zip")
+            .with_content_disposition(format!("attachment; filename=\"account-{account_id}.zip\""))
+            .with_no_store()
+            .with_binary_body(contents))
+    }
+
+    async fn handle_account_data_purge(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> trc::Result<HttpResponse> {
+        let account_id = self.account_data_target(access_token, id).await?;
+        let summary = self.purge_account(account_id).await?;
+
+        Ok(JsonResponse::new(PurgeResponse {
+            account_id,
+            blobs: summary.blobs,
+            registry_items: summary.registry_items,
+        })
+        .no_cache()
+        .into_http_response())
+    }
+}
+
+pub(crate) trait AccountDataTarget {
+    fn account_data_target(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> impl Future<Output = trc::Result<u32>> + Send;
+}
+
+impl AccountDataTarget for Server {
+    // Tenant administrators can only act on accounts of their own tenant
+    async fn account_data_target(&self, access_token: &AccessToken, id: &str) -> trc::Result<u32> {
+        let account_id = id
+            .parse::<u32>()
+            .map_err(|_| trc::ResourceEvent::BadParameters.into_err())?;
+
+        match self.try_account(account_id).await? {
+            Some(account)
+                if access_token
+                    .tenant_id()
+                    .is_none_or(|tenant_id| account.id_tenant == Some(tenant_id)) =>
+            {
+                Ok(account_id)
+            }
+            _ => Err(trc::ResourceEvent::NotFound.into_err()),
+        }
+    }
+}
@@ -0,0 +1,82 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use registry::schema::prelude::ObjectType;
+use serde::Serialize;
+use services::task_manager::index::{ReindexProgress, reindex_progress};
+use std::str::FromStr;
+use store::{registry::RegistryQuery, roaring::RoaringBitmap};
+use trc::AddContext;
+use types::id::Id;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct ReindexStatus {
+    pub account_id: Id,
+    pub total: u32,
+    pub indexed: u32,
+    pub percentage: f64,
+    pub started_at: u64,
+    pub updated_at: u64,
+    pub completed_at: Option<u64>,
+}
+
+pub(crate) trait ReindexStatusApi: Sync + Send {
+    fn handle_reindex_status_request(
+        &self,
+        account_id: Option<&str>,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl ReindexStatusApi for Server {
+    async fn handle_reindex_status_request(
+        &self,
+        account_id: Option<&str>,
+    ) -> trc::Result<HttpResponse> {
+        if let Some(account_id) = account_id {
+            let account_id = Id::from_str(account_id)
+                .map_err(|_| trc::ResourceEvent::BadParameters.into_err())?
+                .document_id();
+            let progress = reindex_progress(self, account_id)
+                .await?
+                .ok_or_else(|| trc::ResourceEvent::NotFound.into_err())?;
+
+            Ok(JsonResponse::new(ReindexStatus::new(account_id, progress))
+                .no_cache()
+                .into_http_response())
+        } else {
+            let mut results = Vec::new();
+            for account_id in self
+                .registry()
+                .query::<RoaringBitmap>(RegistryQuery::new(ObjectType::Account))
+                .await
+                .caused_by(trc::location!())?
+            {
+                if let Some(progress) = reindex_progress(self, account_id).await? {
+                    results.push(ReindexStatus::new(account_id, progress));
+                }
+            }
+
+            Ok(JsonResponse::new(results).no_cache().into_http_response())
+        }
+    }
+}
+
+impl ReindexStatus {
+    fn new(account_id: u32, progress: ReindexProgress) -> Self {
+        ReindexStatus {
+            account_id: Id::from(account_id),
+            total: progress.total,
+            indexed: progress.indexed,
+            percentage: progress.percentage(),
+            started_at: progress.started_at,
+            updated_at: progress.updated_at,
+            completed_at: progress.completed_at,
+        }
+    }
+}
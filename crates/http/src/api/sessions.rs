@@ -0,0 +1,102 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use serde::{Deserialize, Serialize};
+use utils::url_params::UrlParams;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TerminateRequest {
+    pub ids: Vec<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TerminateResponse {
+    pub terminated: Vec<u64>,
+}
+
+pub(crate) trait SessionManagerApi: Sync + Send {
+    fn handle_session_list_request(
+        &self,
+        id: Option<&str>,
+        params: &UrlParams<'_>,
+    ) -> trc::Result<HttpResponse>;
+
+    fn handle_session_terminate_request(
+        &self,
+        id: Option<&str>,
+        body: Option<Vec<u8>>,
+    ) -> trc::Result<HttpResponse>;
+}
+
+impl SessionManagerApi for Server {
+    fn handle_session_list_request(
+        &self,
+        id: Option<&str>,
+        params: &UrlParams<'_>,
+    ) -> trc::Result<HttpResponse> {
+        let sessions = &self.inner.data.sessions;
+
+        if let Some(id) = id {
+            let session = parse_session_id(id).and_then(|id| {
+                sessions
+                    .get(id)
+                    .ok_or_else(|| trc::ResourceEvent::NotFound.into_err())
+            })?;
+
+            return Ok(JsonResponse::new(session).no_cache().into_http_response());
+        }
+
+        let protocol = params.get("protocol");
+        let account_id = params.parse::<u32>("accountId");
+        Ok(JsonResponse::new(
+            sessions
+                .list()
+                .into_iter()
+                .filter(|session| {
+                    protocol.is_none_or(|protocol| session.protocol.eq_ignore_ascii_case(protocol))
+                        && account_id
+                            .is_none_or(|account_id| session.account_id == Some(account_id))
+                })
+                .collect::<Vec<_>>(),
+        )
+        .no_cache()
+        .into_http_response())
+    }
+
+    fn handle_session_terminate_request(
+        &self,
+        id: Option<&str>,
+        body: Option<Vec<u8>>,
+    ) -> trc::Result<HttpResponse> {
+        let ids = if let Some(id) = id {
+            vec![parse_session_id(id)?]
+        } else {
+            serde_json::from_slice::<TerminateRequest>(body.as_deref().unwrap_or_default())
+                .map_err(|err| {
+                    trc::EventType::Resource(trc::ResourceEvent::BadParameters).from_json_error(err)
+                })?
+                .ids
+        };
+
+        let terminated = self.inner.data.sessions.terminate(ids);
+        if id.is_some() && terminated.is_empty() {
+            return Err(trc::ResourceEvent::NotFound.into_err());
+        }
+
+        Ok(JsonResponse::new(TerminateResponse { terminated })
+            .no_cache()
+            .into_http_response())
+    }
+}
+
+fn parse_session_id(id: &str) -> trc::Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| trc::ResourceEvent::BadParameters.into_err())
+}
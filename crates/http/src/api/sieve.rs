@@ -0,0 +1,48 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::api::privacy::AccountDataTarget;
+use common::{Server, auth::AccessToken};
+use email::sieve::{
+    redirect::SieveRedirectGraph,
+    stats::{SieveScriptStats, SieveStats},
+};
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct SieveStatsResponse {
+    pub account_id: u32,
+    pub scripts: Vec<SieveScriptStats>,
+    pub redirects: Vec<String>,
+}
+
+pub(crate) trait SieveStatsApi: Sync + Send {
+    fn handle_sieve_stats(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl SieveStatsApi for Server {
+    async fn handle_sieve_stats(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> trc::Result<HttpResponse> {
+        let account_id = self.account_data_target(access_token, id).await?;
+
+        Ok(JsonResponse::new(SieveStatsResponse {
+            account_id,
+            scripts: self.sieve_stats(account_id).await?,
+            redirects: self.sieve_redirect_targets(account_id).await?,
+        })
+        .no_cache()
+        .into_http_response())
+    }
+}
@@ -0,0 +1,97 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use store::StorePartition;
+use trc::AddContext;
+
+pub(crate) trait StoreStatsApi: Sync + Send {
+    fn handle_store_stats_request(
+        &self,
+        path: &[&str],
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_store_doctor_request(
+        &self,
+        repair: bool,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_store_partitions_migrate(
+        &self,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_store_partition_erase(
+        &self,
+        kind: &str,
+        id: Option<&str>,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl StoreStatsApi for Server {
+    async fn handle_store_stats_request(&self, path: &[&str]) -> trc::Result<HttpResponse> {
+        match path.get(1).copied() {
+            Some("stats") => {
+                let stats = self.store().stats().await.caused_by(trc::location!())?;
+
+                Ok(JsonResponse::new(stats).no_cache().into_http_response())
+            }
+            _ => Err(trc::ResourceEvent::NotFound.into_err()),
+        }
+    }
+
+    async fn handle_store_doctor_request(&self, repair: bool) -> trc::Result<HttpResponse> {
+        let report = self
+            .core
+            .store_doctor(repair)
+            .await
+            .caused_by(trc::location!())?;
+
+        Ok(JsonResponse::new(report).no_cache().into_http_response())
+    }
+
+    async fn handle_store_partitions_migrate(&self) -> trc::Result<HttpResponse> {
+        let accounts = self
+            .store()
+            .migrate_partitions()
+            .await
+            .caused_by(trc::location!())?;
+
+        Ok(
+            JsonResponse::new(serde_json::json!({ "migratedAccounts": accounts }))
+                .no_cache()
+                .into_http_response(),
+        )
+    }
+
+    async fn handle_store_partition_erase(
+        &self,
+        kind: &str,
+        id: Option<&str>,
+    ) -> trc::Result<HttpResponse> {
+        let id = id
+            .and_then(|id| id.parse::<u32>().ok())
+            .ok_or_else(|| trc::ResourceEvent::BadParameters.into_err())?;
+        let partition = match kind {
+            "account" => StorePartition::Account(id),
+            "tenant" => StorePartition::Tenant(id),
+            _ => return Err(trc::ResourceEvent::NotFound.into_err()),
+        };
+
+        if self
+            .store()
+            .erase_partition(partition)
+            .await
+            .caused_by(trc::location!())?
+        {
+            Ok(JsonResponse::new(serde_json::json!({}))
+                .no_cache()
+                .into_http_response())
+        } else {
+            Err(trc::ResourceEvent::NotFound.into_err())
+        }
+    }
+}
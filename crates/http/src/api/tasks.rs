@@ -0,0 +1,179 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, auth::AccessToken};
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use registry::{
+    schema::{enums::TaskType, structs::Task},
+    types::EnumImpl,
+};
+use serde::Serialize;
+use services::task_manager::progress::{TaskProgress, TaskProgressManager};
+use std::str::FromStr;
+use store::{
+    IterateParams, U64_LEN, ValueKey,
+    write::{TaskQueueClass, ValueClass, key::DeserializeBigEndian},
+};
+use trc::AddContext;
+use types::id::Id;
+use utils::url_params::UrlParams;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TaskSummary {
+    pub id: Id,
+    #[serde(rename = "type")]
+    pub typ: &'static str,
+    pub status: &'static str,
+    pub due: Option<u64>,
+    pub attempt_number: u64,
+    pub progress: Option<TaskProgressSummary>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TaskProgressSummary {
+    pub processed: u64,
+    pub total: u64,
+    pub percentage: f64,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+pub(crate) trait TaskQueueApi: Sync + Send {
+    fn handle_task_list_request(
+        &self,
+        id: Option<&str>,
+        params: &UrlParams<'_>,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn handle_task_cancel_request(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl TaskQueueApi for Server {
+    async fn handle_task_list_request(
+        &self,
+        id: Option<&str>,
+        params: &UrlParams<'_>,
+    ) -> trc::Result<HttpResponse> {
+        if let Some(id) = id {
+            let id = parse_task_id(id)?;
+            let task = fetch_task(self, id)
+                .await?
+                .ok_or_else(|| trc::ResourceEvent::NotFound.into_err())?;
+
+            return Ok(JsonResponse::new(TaskSummary::new(
+                id,
+                &task,
+                self.task_progress(id).await?,
+            ))
+            .no_cache()
+            .into_http_response());
+        }
+
+        // Tasks are listed in the order they are due, failed tasks go last
+        let typ = params.get("type");
+        let limit = params
+            .parse::<usize>("limit")
+            .unwrap_or(self.core.jmap.get_max_objects)
+            .min(self.core.jmap.get_max_objects);
+        let mut ids = Vec::new();
+        self.store()
+            .iterate(
+                IterateParams::new(
+                    ValueKey::from(ValueClass::TaskQueue(TaskQueueClass::Due { id: 0, due: 1 })),
+                    ValueKey::from(ValueClass::TaskQueue(TaskQueueClass::Due {
+                        id: u64::MAX,
+                        due: u64::MAX,
+                    })),
+                )
+                .ascending(),
+                |key, value| {
+                    if typ.is_none_or(|typ| {
+                        value
+                            .deserialize_be_u16(0)
+                            .ok()
+                            .and_then(TaskType::from_id)
+                            .is_some_and(|task_type| task_type.as_str() == typ)
+                    }) {
+                        ids.push(key.deserialize_be_u64(U64_LEN)?);
+                    }
+
+                    Ok(ids.len() < limit)
+                },
+            )
+            .await
+            .caused_by(trc::location!())?;
+
+        let mut results = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(task) = fetch_task(self, id).await? {
+                results.push(TaskSummary::new(id, &task, self.task_progress(id).await?));
+            }
+        }
+
+        Ok(JsonResponse::new(results).no_cache().into_http_response())
+    }
+
+    async fn handle_task_cancel_request(
+        &self,
+        access_token: &AccessToken,
+        id: &str,
+    ) -> trc::Result<HttpResponse> {
+        let id = parse_task_id(id)?;
+        let task = fetch_task(self, id)
+            .await?
+            .ok_or_else(|| trc::ResourceEvent::NotFound.into_err())?;
+        access_token.enforce_permission(task.permission())?;
+
+        self.task_cancel(id, &task).await?;
+
+        Ok(JsonResponse::new(TaskSummary::new(id, &task, None))
+            .no_cache()
+            .into_http_response())
+    }
+}
+
+impl TaskSummary {
+    fn new(id: u64, task: &Task, progress: Option<TaskProgress>) -> Self {
+        let due = task.due_timestamp();
+
+        TaskSummary {
+            id: Id::from(id),
+            typ: task.object_type().as_str(),
+            status: task.status().object_type().as_str(),
+            due: (due != u64::MAX).then_some(due),
+            attempt_number: task.attempt_number(),
+            progress: progress.map(|progress| TaskProgressSummary {
+                processed: progress.processed,
+                total: progress.total,
+                percentage: progress.percentage(),
+                started_at: progress.started_at,
+                updated_at: progress.updated_at,
+            }),
+        }
+    }
+}
+
+async fn fetch_task(server: &Server, id: u64) -> trc::Result<Option<Task>> {
+    server
+        .store()
+        .get_value::<Task>(ValueKey::from(ValueClass::TaskQueue(
+            TaskQueueClass::Task { id },
+        )))
+        .await
+        .caused_by(trc::location!())
+}
+
+fn parse_task_id(id: &str) -> trc::Result<u64> {
+    Id::from_str(id)
+        .map(|id| id.id())
+        .map_err(|_| trc::ResourceEvent::BadParameters.into_err())
+}
@@ -0,0 +1,191 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use serde::{Deserialize, Serialize};
+use std::{net::IpAddr, str::FromStr};
+use store::write::now;
+use trc::{
+    Collector, EventType, Level,
+    ipc::rules::{TracingRule, TracingScope},
+};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TracingRuleRequest {
+    pub events: Vec<String>,
+    #[serde(default)]
+    pub level: Option<String>,
+    #[serde(default)]
+    pub sample_rate: Option<u32>,
+    #[serde(default)]
+    pub account_id: Option<u32>,
+    #[serde(default)]
+    pub remote_ip: Option<IpAddr>,
+    #[serde(default)]
+    pub expires_in: Option<u64>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct TracingRuleResponse {
+    pub id: u64,
+    pub events: Vec<&'static str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub level: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sample_rate: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub account_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub remote_ip: Option<IpAddr>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<u64>,
+}
+
+pub(crate) trait TracingRuleApi: Sync + Send {
+    fn handle_tracing_rule_list(&self, id: Option<&str>) -> trc::Result<HttpResponse>;
+
+    fn handle_tracing_rule_create(&self, body: Option<Vec<u8>>) -> trc::Result<HttpResponse>;
+
+    fn handle_tracing_rule_delete(&self, id: &str) -> trc::Result<HttpResponse>;
+}
+
+impl TracingRuleApi for Server {
+    fn handle_tracing_rule_list(&self, id: Option<&str>) -> trc::Result<HttpResponse> {
+        let rules = Collector::tracing_rules();
+
+        if let Some(id) = id {
+            let id = parse_rule_id(id)?;
+            return rules
+                .into_iter()
+                .find(|rule| rule.id == id)
+                .map(|rule| {
+                    JsonResponse::new(TracingRuleResponse::from(rule))
+                        .no_cache()
+                        .into_http_response()
+                })
+                .ok_or_else(|| trc::ResourceEvent::NotFound.into_err());
+        }
+
+        Ok(JsonResponse::new(
+            rules
+                .into_iter()
+                .map(TracingRuleResponse::from)
+                .collect::<Vec<_>>(),
+        )
+        .no_cache()
+        .into_http_response())
+    }
+
+    fn handle_tracing_rule_create(&self, body: Option<Vec<u8>>) -> trc::Result<HttpResponse> {
+        let request =
+            serde_json::from_slice::<TracingRuleRequest>(body.as_deref().unwrap_or_default())
+                .map_err(|err| {
+                    trc::EventType::Resource(trc::ResourceEvent::BadParameters).from_json_error(err)
+                })?;
+
+        let events = TracingRule::parse_events(request.events.iter().map(|event| event.as_str()))
+            .ok_or_else(|| {
+            trc::ResourceEvent::BadParameters
+                .into_err()
+                .details("Invalid or unknown event types")
+        })?;
+        let mut rule = TracingRule::new(events);
+
+        // Rules either raise the level of the selected events or sample them
+        match (request.level, request.sample_rate) {
+            (Some(level), None) => {
+                let level = Level::from_str(&level)
+                    .ok()
+                    .filter(|level| *level != Level::Disable)
+                    .ok_or_else(|| {
+                        trc::ResourceEvent::BadParameters
+                            .into_err()
+                            .details("Invalid tracing level")
+                    })?;
+                rule = rule.with_level(level);
+            }
+            (None, Some(sample_rate)) if sample_rate > 1 => {
+                rule = rule.with_sample_rate(sample_rate);
+            }
+            _ => {
+                return Err(trc::ResourceEvent::BadParameters
+                    .into_err()
+                    .details("Either a level or a sample rate greater than 1 is required"));
+            }
+        }
+
+        match (request.account_id, request.remote_ip) {
+            (Some(account_id), None) => {
+                rule = rule.with_scope(TracingScope::AccountId(account_id));
+            }
+            (None, Some(remote_ip)) => {
+                rule = rule.with_scope(TracingScope::RemoteIp(remote_ip));
+            }
+            (None, None) => {}
+            (Some(_), Some(_)) => {
+                return Err(trc::ResourceEvent::BadParameters
+                    .into_err()
+                    .details("Rules can be scoped to either an account or a remote IP"));
+            }
+        }
+
+        if let Some(expires_in) = request.expires_in.filter(|expires_in| *expires_in > 0) {
+            rule = rule.with_expires(now() + expires_in);
+        }
+
+        let id = Collector::add_tracing_rule(rule);
+        let rule = Collector::tracing_rules()
+            .into_iter()
+            .find(|rule| rule.id == id)
+            .ok_or_else(|| trc::ResourceEvent::NotFound.into_err())?;
+
+        Ok(JsonResponse::new(TracingRuleResponse::from(rule))
+            .no_cache()
+            .into_http_response())
+    }
+
+    fn handle_tracing_rule_delete(&self, id: &str) -> trc::Result<HttpResponse> {
+        if Collector::remove_tracing_rule(parse_rule_id(id)?) {
+            Ok(JsonResponse::new(serde_json::json!({}))
+                .no_cache()
+                .into_http_response())
+        } else {
+            Err(trc::ResourceEvent::NotFound.into_err())
+        }
+    }
+}
+
+impl From<TracingRule> for TracingRuleResponse {
+    fn from(rule: TracingRule) -> Self {
+        let (account_id, remote_ip) = match rule.scope {
+            Some(TracingScope::AccountId(account_id)) => (Some(account_id), None),
+            Some(TracingScope::RemoteIp(remote_ip)) => (None, Some(remote_ip)),
+            None => (None, None),
+        };
+
+        TracingRuleResponse {
+            id: rule.id,
+            events: EventType::variants()
+                .iter()
+                .filter(|event_type| rule.events.get(**event_type))
+                .map(|event_type| event_type.as_str())
+                .collect(),
+            level: rule.level.map(|level| level.as_str().to_ascii_lowercase()),
+            sample_rate: (rule.sample_rate > 1).then_some(rule.sample_rate),
+            account_id,
+            remote_ip,
+            expires_at: rule.expires,
+        }
+    }
+}
+
+fn parse_rule_id(id: &str) -> trc::Result<u64> {
+    id.parse::<u64>()
+        .map_err(|_| trc::ResourceEvent::BadParameters.into_err())
+}
@@ -0,0 +1,181 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{Server, auth::AccessToken};
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use hyper::StatusCode;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::TenantUsage,
+    },
+    types::{EnumImpl, datetime::UTCDateTime},
+};
+use serde::Serialize;
+use std::{fmt::Write, str::FromStr};
+use store::{
+    ValueKey,
+    registry::RegistryQuery,
+    write::{RegistryClass, ValueClass},
+};
+use trc::AddContext;
+use types::id::Id;
+use utils::url_params::UrlParams;
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub(crate) struct UsageRecord {
+    pub tenant_id: Id,
+    pub from: UTCDateTime,
+    pub to: UTCDateTime,
+    pub storage_used: u64,
+    pub messages_sent: u64,
+    pub messages_received: u64,
+    pub bytes_sent: u64,
+    pub bytes_received: u64,
+    pub api_calls: u64,
+}
+
+pub(crate) trait TenantUsageApi: Sync + Send {
+    fn handle_usage_export(
+        &self,
+        access_token: &AccessToken,
+        params: &UrlParams<'_>,
+    ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+}
+
+impl TenantUsageApi for Server {
+    async fn handle_usage_export(
+        &self,
+        access_token: &AccessToken,
+        params: &UrlParams<'_>,
+    ) -> trc::Result<HttpResponse> {
+        let tenant_id = params
+            .get("tenantId")
+            .map(|id| {
+                Id::from_str(id).map(|id| id.document_id()).map_err(|_| {
+                    trc::ResourceEvent::BadParameters
+                        .into_err()
+                        .details(id.to_string())
+                })
+            })
+            .transpose()?;
+        let from = params.get("from").map(parse_date).transpose()?;
+        let to = params.get("to").map(parse_date).transpose()?;
+        let is_csv = match params.get("format") {
+            Some("csv") => true,
+            Some("json") | None => false,
+            Some(format) => {
+                return Err(trc::ResourceEvent::BadParameters
+                    .into_err()
+                    .details(format.to_string()));
+            }
+        };
+
+        // Tenant administrators can only export their own usage
+        let tenant_id = match (access_token.tenant_id(), tenant_id) {
+            (Some(token_tenant_id), Some(tenant_id)) if token_tenant_id != tenant_id => {
+                return Err(trc::ResourceEvent::NotFound.into_err());
+            }
+            (Some(tenant_id), _) | (None, Some(tenant_id)) => Some(tenant_id),
+            (None, None) => None,
+        };
+
+        let mut query = RegistryQuery::new(ObjectType::TenantUsage)
+            .with_tenant(tenant_id)
+            .greater_than_or_equal(Property::From, from.unwrap_or_default());
+        if let Some(to) = to {
+            query = query.less_than(Property::From, to);
+        }
+        let ids = self.registry().query::<Vec<Id>>(query).await?;
+        let ids = if !ids.is_empty() {
+            self.registry()
+                .sort_by_index(ObjectType::TenantUsage, Property::From, Some(ids), true)
+                .await?
+        } else {
+            ids
+        };
+
+        let object_id = ObjectType::TenantUsage.to_id();
+        let mut records = Vec::with_capacity(ids.len());
+        for id in ids {
+            if let Some(usage) = self
+                .store()
+                .get_value::<TenantUsage>(ValueKey::from(ValueClass::Registry(
+                    RegistryClass::Item {
+                        object_id,
+                        item_id: id.id(),
+                    },
+                )))
+                .await
+                .caused_by(trc::location!())?
+            {
+                records.push(UsageRecord {
+                    tenant_id: usage.member_tenant_id,
+                    from: usage.from,
+                    to: usage.to,
+                    storage_used: usage.storage_used,
+                    messages_sent: usage.messages_sent,
+                    messages_received: usage.messages_received,
+                    bytes_sent: usage.bytes_sent,
+                    bytes_received: usage.bytes_received,
+                    api_calls: usage.api_calls,
+                });
+            }
+        }
+
+        if is_csv {
+            Ok(HttpResponse::new(StatusCode::OK)
+                .with_content_type("text/csv; charset=utf-8")
+                .with_content_disposition("attachment; filename=\"usage.csv\"")
+                .with_no_cache()
+                .with_text_body(records_to_csv(&records)))
+        } else {
+            Ok(JsonResponse::new(records).no_cache().into_http_response())
+        }
+    }
+}
+
+// Dates are accepted either as a full UTC timestamp or as a plain day
+fn parse_date(value: &str) -> trc::Result<u64> {
+    let date = if value.len() == 10 {
+        UTCDateTime::from_str(&format!("{value}T00:00:00Z"))
+    } else {
+        UTCDateTime::from_str(value)
+    };
+
+    date.ok()
+        .map(|date| date.timestamp().max(0) as u64)
+        .ok_or_else(|| {
+            trc::ResourceEvent::BadParameters
+                .into_err()
+                .details(value.to_string())
+        })
+}
+
+fn records_to_csv(records: &[UsageRecord]) -> String {
+    let mut csv = String::with_capacity((records.len() + 1) * 96);
+    csv.push_str(concat!(
+        "tenantId,from,to,storageUsed,messagesSent,messagesReceived,",
+        "bytesSent,bytesReceived,apiCalls\r\n"
+    ));
+    for record in records {
+        let _ = write!(
+            csv,
+            "{},{},{},{},{},{},{},{},{}\r\n",
+            record.tenant_id,
+            record.from,
+            record.to,
+            record.storage_used,
+            record.messages_sent,
+            record.messages_received,
+            record.bytes_sent,
+            record.bytes_received,
+            record.api_calls
+        );
+    }
+    csv
+}
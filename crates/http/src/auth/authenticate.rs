@@ -4,12 +4,17 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use common::auth::AccessToken;
+use common::auth::{
+    AccessToken,
+    oauth::token::access_token_client_id,
+    rate_limit::{HttpClientId, HttpRateLimitClass},
+};
 use common::{HttpAuthCache, Server, auth::AuthRequest, network::limiter::InFlight};
 use directory::Credentials;
 use http_proto::{HttpRequest, HttpSessionData};
 use hyper::header;
 use mail_parser::decoders::base64::base64_decode;
+use registry::schema::enums::NetworkListenerProtocol;
 use std::future::Future;
 use std::time::{Duration, Instant};
 
@@ -39,9 +44,17 @@ impl Authenticator for Server {
                     )?;
 
                     if access_token.revision() == http_cache.revision {
+                        // Cached credentials may be presented from a different network
+                        self.assert_network_policy(&access_token, session.remote_ip)
+                            .await?;
+
+                        self.inner
+                            .data
+                            .sessions
+                            .set_account(session.session_id, access_token.account_id());
+
                         // Enforce authenticated rate limit
-                        return self
-                            .is_http_authenticated_request_allowed(&access_token, session.remote_ip)
+                        return enforce_http_rate_limits(self, req, session, &access_token)
                             .await
                             .map(|in_flight| (in_flight, access_token));
                     }
@@ -90,6 +103,21 @@ impl Authenticator for Server {
                 ))
                 .await?;
 
+            // Record the device in the login history
+            self.record_login(
+                access_token.account_id(),
+                NetworkListenerProtocol::Http,
+                session.remote_ip,
+                req.headers()
+                    .get(header::USER_AGENT)
+                    .and_then(|value| value.to_str().ok()),
+            )
+            .await;
+            self.inner
+                .data
+                .sessions
+                .set_account(session.session_id, access_token.account_id());
+
             // Cache credentials
             self.inner.cache.http_auth.insert(
                 token.into(),
@@ -103,7 +131,7 @@ impl Authenticator for Server {
             );
 
             // Enforce authenticated rate limit
-            self.is_http_authenticated_request_allowed(&access_token, session.remote_ip)
+            enforce_http_rate_limits(self, req, session, &access_token)
                 .await
                 .map(|in_flight| (in_flight, access_token))
         } else {
@@ -119,6 +147,60 @@ impl Authenticator for Server {
     }
 }
 
+async fn enforce_http_rate_limits(
+    server: &Server,
+    req: &HttpRequest,
+    session: &HttpSessionData,
+    access_token: &AccessToken,
+) -> trc::Result<Option<InFlight>> {
+    let client_id = http_client_id(req, access_token);
+    let in_flight = server
+        .is_http_authenticated_request_allowed(access_token, client_id.as_ref(), session.remote_ip)
+        .await?;
+
+    // JMAP API requests are classified once the method calls are parsed
+    let path = req.uri().path();
+    let class = if path.starts_with("/jmap/upload/") {
+        Some(HttpRateLimitClass::Upload)
+    } else if path.starts_with("/api/") {
+        Some(HttpRateLimitClass::Admin)
+    } else {
+        None
+    };
+    if let Some(class) = class {
+        server
+            .is_http_class_request_allowed(
+                access_token,
+                client_id.as_ref(),
+                class,
+                session.remote_ip,
+            )
+            .await?;
+    }
+
+    // Requests allowed through are metered per tenant
+    if let Some(tenant_id) = access_token.tenant_id() {
+        server.inner.data.usage.record_api_call(tenant_id);
+    }
+
+    Ok(in_flight)
+}
+
+// OAuth clients are told apart by their client id, API keys and app passwords by their credential
+pub fn http_client_id(req: &HttpRequest, access_token: &AccessToken) -> Option<HttpClientId> {
+    if let Some(credential_id) = access_token
+        .credential_id()
+        .filter(|credential_id| *credential_id != u32::MAX)
+    {
+        Some(HttpClientId::Credential(credential_id))
+    } else {
+        req.authorization()
+            .filter(|(mechanism, _)| mechanism.eq_ignore_ascii_case("bearer"))
+            .and_then(|(_, token)| access_token_client_id(token))
+            .map(HttpClientId::OAuth)
+    }
+}
+
 pub trait HttpHeaders {
     fn authorization(&self) -> Option<(&str, &str)>;
     fn authorization_basic(&self) -> Option<&str>;
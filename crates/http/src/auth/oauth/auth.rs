@@ -4,17 +4,27 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::{DeviceAuthResponse, FormData, MAX_POST_LEN, OAuthCode, PkceCodeChallenge};
-use crate::auth::oauth::{OAuthStatus, openid::OpenIdHandler};
+use super::{
+    DeviceAuthResponse, ErrorType, FormData, MAX_POST_LEN, OAuthCode, PkceCodeChallenge,
+    TokenResponse,
+};
+use crate::auth::oauth::{
+    OAuthStatus, openid::OpenIdHandler, registration::ClientRegistrationHandler,
+};
 use common::{
     KV_OAUTH, Server,
     auth::{
         AuthRequest,
-        oauth::{CLIENT_ID_MAX_LEN, DEVICE_CODE_LEN, USER_CODE_ALPHABET, USER_CODE_LEN},
+        oauth::{
+            CLIENT_ID_MAX_LEN, DEVICE_CODE_LEN, DEVICE_POLL_INTERVAL, USER_CODE_ALPHABET,
+            USER_CODE_LEN,
+        },
     },
 };
 use directory::Credentials;
 use http_proto::*;
+use hyper::StatusCode;
+use registry::schema::enums::OAuthGrantType;
 use std::future::Future;
 use store::{
     Serialize,
@@ -152,6 +162,7 @@ impl OAuthApiHandler for Server {
                 client_id,
                 redirect_uri,
                 nonce,
+                scope,
                 code_challenge,
                 code_challenge_method,
                 ..
@@ -173,6 +184,24 @@ impl OAuthApiHandler for Server {
                     }
                 }
 
+                // Enforce the client's grant type and scope restrictions
+                if let Err(error) = self
+                    .oauth_client_policy(
+                        &client_id,
+                        OAuthGrantType::AuthorizationCode,
+                        scope.as_deref(),
+                    )
+                    .await?
+                {
+                    return Err(trc::AuthEvent::Error.into_err().details(match error {
+                        ErrorType::InvalidScope => {
+                            "Requested scope is not allowed for this client."
+                        }
+                        ErrorType::InvalidClient => "Client registration has expired.",
+                        _ => "Client is not allowed to use the authorization code flow.",
+                    }));
+                }
+
                 // Parse and validate PKCE challenge (RFC 7636).
                 let pkce_challenge = match code_challenge {
                     Some(challenge) => {
@@ -366,6 +395,22 @@ impl OAuthApiHandler for Server {
             })?;
         let nonce = form_data.remove("nonce");
 
+        // Enforce the client's grant type and scope restrictions (RFC 8628, section 3.2)
+        if let Err(error) = self
+            .oauth_client_policy(
+                &client_id,
+                OAuthGrantType::DeviceCode,
+                form_data.get("scope"),
+            )
+            .await?
+        {
+            return Ok(JsonResponse::with_status(
+                StatusCode::BAD_REQUEST,
+                TokenResponse::error(error),
+            )
+            .into_http_response());
+        }
+
         // Generate device code
         let device_code = rng()
             .sample_iter(Alphanumeric)
@@ -424,7 +469,7 @@ impl OAuthApiHandler for Server {
             device_code,
             user_code,
             expires_in: self.core.oauth.oauth_expiry_user_code,
-            interval: 5,
+            interval: DEVICE_POLL_INTERVAL,
         })
         .no_cache()
         .into_http_response())
@@ -442,6 +487,7 @@ impl OAuthApiHandler for Server {
             grant_types_supported: &[
                 "authorization_code",
                 "implicit",
+                "refresh_token",
                 "urn:ietf:params:oauth:grant-type:device_code",
             ],
             response_types_supported: &["code", "id_token", "code token", "id_token token"],
@@ -14,15 +14,19 @@ use common::{
     },
 };
 use http_proto::{request::fetch_body, *};
-use registry::schema::{
-    enums::Permission,
-    prelude::{ObjectType, Property},
-    structs::OAuthClient,
+use registry::{
+    schema::{
+        enums::{OAuthGrantType, Permission},
+        prelude::{ObjectType, Property},
+        structs::OAuthClient,
+    },
+    types::map::Map,
 };
 use std::future::Future;
 use store::{
     rand::{Rng, distr::Alphanumeric, rng},
     registry::write::{RegistryWrite, RegistryWriteResult},
+    write::now,
 };
 use trc::{AddContext, AuthEvent};
 use types::id::Id;
@@ -40,7 +44,21 @@ pub trait ClientRegistrationHandler: Sync + Send {
         redirect_uri: Option<&str>,
         account_id: u32,
     ) -> impl Future<Output = trc::Result<Option<ErrorType>>> + Send;
+
+    fn oauth_client_policy(
+        &self,
+        client_id: &str,
+        grant_type: OAuthGrantType,
+        scope: Option<&str>,
+    ) -> impl Future<Output = trc::Result<Result<ClientPolicy, ErrorType>>> + Send;
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ClientPolicy {
+    pub expiry_token: u64,
+    pub expiry_refresh_token: Option<u64>,
 }
+
 impl ClientRegistrationHandler for Server {
     async fn handle_oauth_registration_request(
         &self,
@@ -69,6 +87,30 @@ impl ClientRegistrationHandler for Server {
             trc::EventType::Resource(trc::ResourceEvent::BadParameters).from_json_error(err)
         })?;
 
+        // Restrict the client to the requested grant types and scopes
+        let mut allowed_grant_types = Map::with_capacity(request.grant_types.len());
+        for grant_type in &request.grant_types {
+            let grant_type = match grant_type.as_str() {
+                "authorization_code" => OAuthGrantType::AuthorizationCode,
+                "urn:ietf:params:oauth:grant-type:device_code" => OAuthGrantType::DeviceCode,
+                "refresh_token" => OAuthGrantType::RefreshToken,
+                "implicit" => continue,
+                _ => {
+                    return Err(trc::ResourceEvent::BadParameters
+                        .into_err()
+                        .details(format!("Unsupported grant type {grant_type:?}.")));
+                }
+            };
+            allowed_grant_types.push(grant_type);
+        }
+        let allowed_scopes = request
+            .scope
+            .as_deref()
+            .unwrap_or_default()
+            .split_ascii_whitespace()
+            .map(|scope| scope.to_string())
+            .collect::<Vec<_>>();
+
         // Generate client ID
         let client_id = rng()
             .sample_iter(Alphanumeric)
@@ -86,6 +128,8 @@ impl ClientRegistrationHandler for Server {
                     member_tenant_id: tenant_id.map(|id| Id::new(id as u64)),
                     redirect_uris: request.redirect_uris.clone().into(),
                     logo: request.logo_uri.clone(),
+                    allowed_grant_types,
+                    allowed_scopes: allowed_scopes.into(),
                     ..Default::default()
                 }
                 .into(),
@@ -179,4 +223,72 @@ impl ClientRegistrationHandler for Server {
             ErrorType::InvalidRequest
         }))
     }
+
+    async fn oauth_client_policy(
+        &self,
+        client_id: &str,
+        grant_type: OAuthGrantType,
+        scope: Option<&str>,
+    ) -> trc::Result<Result<ClientPolicy, ErrorType>> {
+        let mut policy = ClientPolicy {
+            expiry_token: self.core.oauth.oauth_expiry_token,
+            expiry_refresh_token: Some(self.core.oauth.oauth_expiry_refresh_token),
+        };
+
+        // Unregistered clients are handled by validate_client_registration
+        let Some(id) = self
+            .registry()
+            .primary_key(
+                ObjectType::OAuthClient.into(),
+                Property::ClientId,
+                client_id.as_bytes().to_vec(),
+            )
+            .await?
+        else {
+            return Ok(Ok(policy));
+        };
+        let Some(client) = self.registry().object::<OAuthClient>(id.id()).await? else {
+            return Ok(Ok(policy));
+        };
+
+        if client
+            .expires_at
+            .is_some_and(|expires_at| expires_at.timestamp() <= now() as i64)
+        {
+            return Ok(Err(ErrorType::InvalidClient));
+        }
+
+        // An empty list of grant types or scopes places no restrictions on the client
+        let is_grant_allowed = |grant_type: &OAuthGrantType| {
+            client.allowed_grant_types.is_empty() || client.allowed_grant_types.contains(grant_type)
+        };
+        if !is_grant_allowed(&grant_type) {
+            return Ok(Err(ErrorType::UnauthorizedClient));
+        }
+        if let Some(scope) = scope
+            && !client.allowed_scopes.is_empty()
+            && scope
+                .split_ascii_whitespace()
+                .any(|scope| !client.allowed_scopes.iter().any(|v| v == scope))
+        {
+            return Ok(Err(ErrorType::InvalidScope));
+        }
+
+        if let Some(expiry) = client.access_token_expiry {
+            policy.expiry_token = expiry.as_secs();
+        }
+        policy.expiry_refresh_token = if is_grant_allowed(&OAuthGrantType::RefreshToken) {
+            Some(
+                client
+                    .refresh_token_expiry
+                    .map_or(self.core.oauth.oauth_expiry_refresh_token, |expiry| {
+                        expiry.as_secs()
+                    }),
+            )
+        } else {
+            None
+        };
+
+        Ok(Ok(policy))
+    }
 }
@@ -6,18 +6,20 @@
 
 use super::{
     ArchivedOAuthStatus, ArchivedPkceCodeChallenge, ErrorType, FormData, MAX_POST_LEN, OAuthCode,
-    OAuthResponse, OAuthStatus, TokenResponse, registration::ClientRegistrationHandler,
+    OAuthResponse, OAuthStatus, TokenResponse,
+    registration::{ClientPolicy, ClientRegistrationHandler},
 };
 use base64::{Engine, engine::general_purpose::URL_SAFE_NO_PAD};
 use common::{
-    KV_OAUTH, Server,
+    KV_OAUTH, KV_OAUTH_POLL, Server,
     auth::{
         AccessToken,
-        oauth::{GrantType, oidc::StandardClaims},
+        oauth::{DEVICE_POLL_INTERVAL, GrantType, oidc::StandardClaims},
     },
 };
 use http_proto::*;
 use hyper::StatusCode;
+use registry::schema::enums::OAuthGrantType;
 use sha2::{Digest, Sha256};
 use std::future::Future;
 use store::{
@@ -46,6 +48,7 @@ pub trait TokenHandler: Sync + Send {
         client_id: &str,
         issuer: String,
         nonce: Option<String>,
+        policy: ClientPolicy,
         with_refresh_token: bool,
         with_id_token: bool,
     ) -> impl Future<Output = trc::Result<OAuthResponse>> + Send;
@@ -101,31 +104,46 @@ impl TokenHandler for Server {
                             {
                                 TokenResponse::error(error)
                             } else {
-                                // Mark this token as issued
-                                self.in_memory_store()
-                                    .key_delete(KeyValue::<()>::build_key(
-                                        KV_OAUTH,
-                                        code.as_bytes(),
-                                    ))
-                                    .await?;
+                                match self
+                                    .oauth_client_policy(
+                                        client_id,
+                                        OAuthGrantType::AuthorizationCode,
+                                        None,
+                                    )
+                                    .await?
+                                {
+                                    Ok(policy) => {
+                                        // Mark this token as issued
+                                        self.in_memory_store()
+                                            .key_delete(KeyValue::<()>::build_key(
+                                                KV_OAUTH,
+                                                code.as_bytes(),
+                                            ))
+                                            .await?;
 
-                                // Issue token
-                                self.issue_token(
-                                    oauth.account_id.into(),
-                                    &oauth.client_id,
-                                    issuer,
-                                    oauth.nonce.as_ref().map(|s| s.as_str().into()),
-                                    true,
-                                    true,
-                                )
-                                .await
-                                .map(TokenResponse::Granted)
-                                .map_err(|err| {
-                                    trc::AuthEvent::Error
-                                        .into_err()
-                                        .details(err)
-                                        .caused_by(trc::location!())
-                                })?
+                                        // Issue token
+                                        self.issue_token(
+                                            oauth.account_id.into(),
+                                            &oauth.client_id,
+                                            issuer,
+                                            oauth.nonce.as_ref().map(|s| s.as_str().into()),
+                                            policy,
+                                            true,
+                                            true,
+                                        )
+                                        .await
+                                        .map(TokenResponse::Granted)
+                                        .map_err(
+                                            |err| {
+                                                trc::AuthEvent::Error
+                                                    .into_err()
+                                                    .details(err)
+                                                    .caused_by(trc::location!())
+                                            },
+                                        )?
+                                    }
+                                    Err(error) => TokenResponse::error(error),
+                                }
                             }
                         } else {
                             TokenResponse::error(ErrorType::InvalidGrant)
@@ -154,56 +172,83 @@ impl TokenHandler for Server {
                     let oauth = auth_code_
                         .unarchive::<OAuthCode>()
                         .caused_by(trc::location!())?;
-                    response = if oauth.client_id != client_id {
-                        TokenResponse::error(ErrorType::InvalidClient)
-                    } else {
-                        match oauth.status {
-                            ArchivedOAuthStatus::Authorized => {
-                                if let Some(error) = self
-                                    .validate_client_registration(
-                                        client_id,
-                                        None,
-                                        oauth.account_id.into(),
-                                    )
-                                    .await?
-                                {
-                                    TokenResponse::error(error)
-                                } else {
-                                    // Mark this token as issued
-                                    self.in_memory_store()
-                                        .key_delete(KeyValue::<()>::build_key(
-                                            KV_OAUTH,
-                                            device_code.as_bytes(),
-                                        ))
-                                        .await?;
+                    response =
+                        if oauth.client_id != client_id {
+                            TokenResponse::error(ErrorType::InvalidClient)
+                        } else {
+                            match oauth.status {
+                                ArchivedOAuthStatus::Authorized => {
+                                    if let Some(error) = self
+                                        .validate_client_registration(
+                                            client_id,
+                                            None,
+                                            oauth.account_id.into(),
+                                        )
+                                        .await?
+                                    {
+                                        TokenResponse::error(error)
+                                    } else {
+                                        match self
+                                            .oauth_client_policy(
+                                                client_id,
+                                                OAuthGrantType::DeviceCode,
+                                                None,
+                                            )
+                                            .await?
+                                        {
+                                            Ok(policy) => {
+                                                // Mark this token as issued
+                                                self.in_memory_store()
+                                                    .key_delete(KeyValue::<()>::build_key(
+                                                        KV_OAUTH,
+                                                        device_code.as_bytes(),
+                                                    ))
+                                                    .await?;
 
-                                    // Issue token
-                                    self.issue_token(
-                                        oauth.account_id.into(),
-                                        &oauth.client_id,
-                                        issuer,
-                                        oauth.nonce.as_ref().map(|s| s.as_str().into()),
-                                        true,
-                                        true,
-                                    )
-                                    .await
-                                    .map(TokenResponse::Granted)
-                                    .map_err(|err| {
-                                        trc::AuthEvent::Error
-                                            .into_err()
-                                            .details(err)
-                                            .caused_by(trc::location!())
-                                    })?
+                                                // Issue token
+                                                self.issue_token(
+                                                    oauth.account_id.into(),
+                                                    &oauth.client_id,
+                                                    issuer,
+                                                    oauth.nonce.as_ref().map(|s| s.as_str().into()),
+                                                    policy,
+                                                    true,
+                                                    true,
+                                                )
+                                                .await
+                                                .map(TokenResponse::Granted)
+                                                .map_err(|err| {
+                                                    trc::AuthEvent::Error
+                                                        .into_err()
+                                                        .details(err)
+                                                        .caused_by(trc::location!())
+                                                })?
+                                            }
+                                            Err(error) => TokenResponse::error(error),
+                                        }
+                                    }
+                                }
+                                ArchivedOAuthStatus::Pending => {
+                                    // Clients polling faster than the advertised interval are asked to slow down
+                                    if self
+                                        .in_memory_store()
+                                        .try_lock(
+                                            KV_OAUTH_POLL,
+                                            device_code.as_bytes(),
+                                            DEVICE_POLL_INTERVAL,
+                                        )
+                                        .await?
+                                    {
+                                        TokenResponse::error(ErrorType::AuthorizationPending)
+                                    } else {
+                                        TokenResponse::error(ErrorType::SlowDown)
+                                    }
+                                }
+                                ArchivedOAuthStatus::TokenIssued => {
+                                    TokenResponse::error(ErrorType::ExpiredToken)
                                 }
                             }
-                            ArchivedOAuthStatus::Pending => {
-                                TokenResponse::error(ErrorType::AuthorizationPending)
-                            }
-                            ArchivedOAuthStatus::TokenIssued => {
-                                TokenResponse::error(ErrorType::ExpiredToken)
-                            }
-                        }
-                    };
+                        };
                 }
             }
         } else if grant_type.eq_ignore_ascii_case("refresh_token") {
@@ -212,24 +257,35 @@ impl TokenHandler for Server {
                     .validate_access_token(GrantType::RefreshToken.into(), refresh_token)
                     .await
                 {
-                    Ok(token_info) => self
-                        .issue_token(
-                            token_info.account_id,
+                    Ok(token_info) => match self
+                        .oauth_client_policy(
                             &token_info.client_id,
-                            issuer,
+                            OAuthGrantType::RefreshToken,
                             None,
-                            token_info.expires_in
-                                <= self.core.oauth.oauth_expiry_refresh_token_renew,
-                            false,
                         )
-                        .await
-                        .map(TokenResponse::Granted)
-                        .map_err(|err| {
-                            trc::AuthEvent::Error
-                                .into_err()
-                                .details(err)
-                                .caused_by(trc::location!())
-                        })?,
+                        .await?
+                    {
+                        Ok(policy) => self
+                            .issue_token(
+                                token_info.account_id,
+                                &token_info.client_id,
+                                issuer,
+                                None,
+                                policy,
+                                token_info.expires_in
+                                    <= self.core.oauth.oauth_expiry_refresh_token_renew,
+                                false,
+                            )
+                            .await
+                            .map(TokenResponse::Granted)
+                            .map_err(|err| {
+                                trc::AuthEvent::Error
+                                    .into_err()
+                                    .details(err)
+                                    .caused_by(trc::location!())
+                            })?,
+                        Err(error) => TokenResponse::error(error),
+                    },
                     Err(err) => {
                         trc::error!(
                             err.caused_by(trc::location!())
@@ -282,6 +338,7 @@ impl TokenHandler for Server {
         client_id: &str,
         issuer: String,
         nonce: Option<String>,
+        policy: ClientPolicy,
         with_refresh_token: bool,
         with_id_token: bool,
     ) -> trc::Result<OAuthResponse> {
@@ -291,22 +348,22 @@ impl TokenHandler for Server {
                     GrantType::AccessToken,
                     account_id,
                     client_id,
-                    self.core.oauth.oauth_expiry_token,
+                    policy.expiry_token,
                 )
                 .await?,
             token_type: "bearer".to_string(),
-            expires_in: self.core.oauth.oauth_expiry_token,
-            refresh_token: if with_refresh_token {
-                self.encode_access_token(
-                    GrantType::RefreshToken,
-                    account_id,
-                    client_id,
-                    self.core.oauth.oauth_expiry_refresh_token,
-                )
-                .await?
-                .into()
-            } else {
-                None
+            expires_in: policy.expiry_token,
+            refresh_token: match policy.expiry_refresh_token {
+                Some(expiry_refresh_token) if with_refresh_token => self
+                    .encode_access_token(
+                        GrantType::RefreshToken,
+                        account_id,
+                        client_id,
+                        expiry_refresh_token,
+                    )
+                    .await?
+                    .into(),
+                _ => None,
             },
             id_token: if with_id_token {
                 // Obtain account
@@ -9,7 +9,6 @@ use chrono::Utc;
 use common::{
     KV_RATE_LIMIT_CONTACT, Server,
     config::network::{ContactForm, FieldOrDefault},
-    network::ip_to_bytes,
     psl,
 };
 use email::message::delivery::{IngestMessage, IngestRecipient, LocalDeliveryStatus, MailDelivery};
@@ -52,7 +51,7 @@ impl FormHandler for Server {
                 .in_memory_store()
                 .is_rate_allowed(
                     KV_RATE_LIMIT_CONTACT,
-                    &ip_to_bytes(&session.remote_ip),
+                    &self.ip_rate_key(&session.remote_ip),
                     rate,
                     false,
                 )
@@ -185,6 +184,7 @@ impl FormHandler for Server {
                             address: address.clone(),
                             orcpt: None,
                             is_spam: false,
+                            is_quarantined: false,
                         })
                         .collect(),
                     message_blob,
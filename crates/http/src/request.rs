@@ -6,9 +6,9 @@
 
 use crate::{
     HttpSessionManager,
-    api::{ManagementApi, ToManageHttpResponse},
+    api::{ManagementApi, ToManageHttpResponse, openapi::OpenApiHandler},
     auth::{
-        authenticate::{Authenticator, HttpHeaders},
+        authenticate::{Authenticator, HttpHeaders, http_client_id},
         oauth::{
             FormData, auth::OAuthApiHandler, openid::OpenIdHandler,
             registration::ClientRegistrationHandler, token::TokenHandler,
@@ -18,11 +18,13 @@ use crate::{
 };
 use common::{
     BuildServer, Inner, KV_ACME, Server,
+    auth::rate_limit::HttpRateLimitClass,
     ipc::PushEvent,
     manager::application::Resource,
     network::{SessionData, SessionManager, SessionStream},
 };
 use dav::{DavMethod, request::DavRequestHandler};
+use email::message::quarantine::SpamQuarantine;
 use groupware::{DavResourceName, calendar::itip::ItipIngest};
 use http_proto::{
     DownloadResponse, HtmlResponse, HttpContext, HttpRequest, HttpResponse, HttpResponseBody,
@@ -37,13 +39,16 @@ use hyper::{
 use hyper_util::rt::TokioIo;
 use jmap::{
     api::{
-        ToJmapHttpResponse, event_source::EventSourceHandler, request::RequestHandler,
-        session::SessionHandler,
+        ToJmapHttpResponse, WithRateLimitStatus, event_source::EventSourceHandler,
+        request::RequestHandler, session::SessionHandler,
     },
     blob::{download::BlobDownload, upload::BlobUpload},
     websocket::upgrade::WebSocketUpgrade,
 };
-use jmap_proto::request::{Request, capability::Session};
+use jmap_proto::request::{
+    GetRequestMethod, QueryRequestMethod, Request, RequestMethod, SetRequestMethod,
+    capability::Session,
+};
 use registry::schema::enums::Permission;
 use std::{net::IpAddr, str::FromStr, sync::Arc};
 use store::dispatch::lookup::KeyValue;
@@ -99,18 +104,27 @@ impl ParseHttp for Server {
                         .await
                         .ok_or_else(|| trc::LimitEvent::SizeRequest.into_err())?;
 
-                        return Ok(self
-                            .handle_jmap_request(
-                                Request::parse(
-                                    &bytes,
-                                    self.core.jmap.request_max_calls,
-                                    self.core.jmap.request_max_size,
-                                )?,
+                        let request = Request::parse(
+                            &bytes,
+                            self.core.jmap.request_max_calls,
+                            self.core.jmap.request_max_size,
+                        )?;
+
+                        // Enforce per endpoint class rate limit
+                        let rate_limit = self
+                            .is_http_class_request_allowed(
                                 &access_token,
-                                &session,
+                                http_client_id(&req, &access_token).as_ref(),
+                                jmap_rate_limit_class(&request),
+                                session.remote_ip,
                             )
+                            .await?;
+
+                        return Ok(self
+                            .handle_jmap_request(request, &access_token, &session)
                             .await
-                            .into_http_response());
+                            .into_http_response()
+                            .with_rate_limit_status(rate_limit));
                     }
                     ("download", &Method::GET) => {
                         // Authenticate request
@@ -280,6 +294,15 @@ impl ParseHttp for Server {
 
                     return self.handle_oidc_metadata(false).await;
                 }
+                ("openapi.json", &Method::GET) => {
+                    // Limit anonymous requests
+                    self.is_http_anonymous_request_allowed(session.remote_ip)
+                        .await?;
+
+                    return self
+                        .handle_openapi_request()
+                        .map(|response| response.with_cors_unrestricted());
+                }
                 ("acme-challenge", &Method::GET) if self.has_acme_http_providers() => {
                     if let Some(token) = path.next() {
                         return match self
@@ -451,6 +474,37 @@ impl ParseHttp for Server {
                         });
                 }
             }
+            "quarantine" => {
+                // Limit anonymous requests
+                self.is_http_anonymous_request_allowed(session.remote_ip)
+                    .await?;
+
+                if self.core.spam.quarantine.is_some()
+                    && matches!(req.method(), &Method::GET | &Method::POST)
+                {
+                    // Messages are only released once the confirmation form is submitted
+                    let (params, confirmed) = if req.method() == Method::POST {
+                        (
+                            fetch_body(&mut req, 4096, session.session_id)
+                                .await
+                                .map(|body| String::from_utf8_lossy(&body).into_owned())
+                                .unwrap_or_default(),
+                            true,
+                        )
+                    } else {
+                        (req.uri().query().unwrap_or_default().to_string(), false)
+                    };
+
+                    return self
+                        .http_quarantine_handle(&params, confirmed, session.session_id)
+                        .await
+                        .map(|response| {
+                            HtmlResponse::new(response)
+                                .into_http_response()
+                                .with_no_store()
+                        });
+                }
+            }
             "autodiscover" | "Autodiscover" | "AutoDiscover" => {
                 let document_name = path.next().unwrap_or_default();
                 if req.method() == Method::POST
@@ -638,6 +692,28 @@ impl ParseHttp for Server {
     }
 }
 
+// Registry calls count as admin requests, any write makes the whole request a set request
+fn jmap_rate_limit_class(request: &Request<'_>) -> HttpRateLimitClass {
+    let mut class = HttpRateLimitClass::Query;
+    for call in &request.method_calls {
+        match &call.method {
+            RequestMethod::Get(GetRequestMethod::Registry(_))
+            | RequestMethod::Set(SetRequestMethod::Registry(_))
+            | RequestMethod::Query(QueryRequestMethod::Registry(_)) => {
+                return HttpRateLimitClass::Admin;
+            }
+            RequestMethod::Set(_)
+            | RequestMethod::Copy(_)
+            | RequestMethod::ImportEmail(_)
+            | RequestMethod::UploadBlob(_) => {
+                class = HttpRateLimitClass::Set;
+            }
+            _ => (),
+        }
+    }
+    class
+}
+
 async fn handle_session<T: SessionStream>(inner: Arc<Inner>, session: SessionData<T>) {
     let _in_flight = session.in_flight;
     let is_tls = session.stream.is_tls();
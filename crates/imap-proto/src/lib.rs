@@ -74,6 +74,10 @@ pub enum Command {
     // RFC 9208
     GetQuota,
     GetQuotaRoot,
+
+    // RFC 5464
+    GetMetadata,
+    SetMetadata,
 }
 
 impl Command {
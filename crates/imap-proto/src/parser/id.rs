@@ -0,0 +1,96 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{Command, protocol::id, receiver::Request};
+
+const MAX_PARAMETERS: usize = 30;
+const MAX_VALUE_LEN: usize = 1024;
+
+impl Request<Command> {
+    pub fn parse_id(self) -> trc::Result<id::Arguments> {
+        let mut parameters = Vec::new();
+        let mut tokens = self.tokens.into_iter().peekable();
+
+        // RFC 2971 allows either NIL or a list of field-value pairs
+        if tokens
+            .peek()
+            .is_some_and(|token| token.is_parenthesis_open())
+        {
+            tokens.next();
+            while let Some(token) = tokens.next() {
+                if token.is_parenthesis_close() {
+                    break;
+                }
+                let field = token.unwrap_string().unwrap_or_default();
+                let value = tokens
+                    .next()
+                    .filter(|token| {
+                        !token.is_parenthesis_close()
+                            && !token.as_bytes().eq_ignore_ascii_case(b"NIL")
+                    })
+                    .map(|token| token.unwrap_string().unwrap_or_default())
+                    .unwrap_or_default();
+                if parameters.len() < MAX_PARAMETERS
+                    && field.len() <= MAX_VALUE_LEN
+                    && value.len() <= MAX_VALUE_LEN
+                {
+                    parameters.push((field, value));
+                }
+            }
+        }
+
+        Ok(id::Arguments {
+            tag: self.tag,
+            parameters,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{protocol::id, receiver::Receiver};
+
+    #[test]
+    fn parse_id() {
+        let mut receiver = Receiver::new();
+
+        for (command, arguments, user_agent) in [
+            (
+                concat!(
+                    "a023 ID (\"name\" \"Thunderbird\" \"version\" \"115.3.1\" ",
+                    "\"os\" \"Linux\" \"os-version\" \"6.1\" \"vendor\" NIL)\r\n"
+                ),
+                id::Arguments {
+                    tag: "a023".into(),
+                    parameters: vec![
+                        ("name".into(), "Thunderbird".into()),
+                        ("version".into(), "115.3.1".into()),
+                        ("os".into(), "Linux".into()),
+                        ("os-version".into(), "6.1".into()),
+                        ("vendor".into(), "".into()),
+                    ],
+                },
+                Some("Thunderbird 115.3.1 (Linux 6.1)"),
+            ),
+            (
+                "a024 ID NIL\r\n",
+                id::Arguments {
+                    tag: "a024".into(),
+                    parameters: vec![],
+                },
+                None,
+            ),
+        ] {
+            let arguments_ = receiver
+                .parse(&mut command.as_bytes().iter())
+                .unwrap()
+                .parse_id()
+                .unwrap();
+            assert_eq!(arguments_, arguments, "{command}");
+            assert_eq!(arguments_.user_agent().as_deref(), user_agent, "{command}");
+        }
+    }
+}
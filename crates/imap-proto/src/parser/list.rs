@@ -87,11 +87,12 @@ impl Request<Command> {
                             match token {
                                 Token::ParenthesisClose => break,
                                 token => {
-                                    mailbox_name.push(
+                                    mailbox_name.push(utf7_maybe_decode(
                                         token
                                             .unwrap_string()
                                             .map_err(|v| bad(self.tag.to_compact_string(), v))?,
-                                    );
+                                        is_utf8,
+                                    ));
                                 }
                             }
                         }
@@ -368,6 +369,25 @@ mod tests {
                     ],
                 },
             ),
+            (
+                concat!(
+                    "A03 LIST (SUBSCRIBED SPECIAL-USE) \"\" * ",
+                    "RETURN (SPECIAL-USE STATUS (UNSEEN UIDNEXT))\r\n"
+                ),
+                list::Arguments::Extended {
+                    tag: "A03".into(),
+                    reference_name: "".into(),
+                    mailbox_name: vec!["*".into()],
+                    selection_options: vec![
+                        SelectionOption::Subscribed,
+                        SelectionOption::SpecialUse,
+                    ],
+                    return_options: vec![
+                        ReturnOption::SpecialUse,
+                        ReturnOption::Status(vec![Status::Unseen, Status::UidNext]),
+                    ],
+                },
+            ),
         ] {
             assert_eq!(
                 receiver
@@ -0,0 +1,325 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use compact_str::ToCompactString;
+
+use crate::{
+    Command,
+    protocol::metadata::{self, Depth},
+    receiver::{Request, Token, bad},
+    utf7::utf7_maybe_decode,
+};
+
+/*
+
+   getmetadata     = "GETMETADATA" [SP getmetadata-options]
+                     SP mailbox SP entries
+
+   getmetadata-options = "(" getmetadata-option
+                         *(SP getmetadata-option) ")"
+
+   getmetadata-option = maxsize-opt / scope-opt
+
+   maxsize-opt     = "MAXSIZE" SP number
+
+   scope-opt       = "DEPTH" SP ("0" / "1" / "infinity")
+
+   entries         = entry /
+                     "(" entry *(SP entry) ")"
+
+   setmetadata     = "SETMETADATA" SP mailbox
+                     SP "(" entry-value *(SP entry-value) ")"
+
+   entry-value     = entry SP value
+
+   value           = nstring / literal8
+
+*/
+
+impl Request<Command> {
+    pub fn parse_get_metadata(self, is_utf8: bool) -> trc::Result<metadata::GetArguments> {
+        let mut tokens = self.tokens.into_iter().peekable();
+        let mut max_size = None;
+        let mut depth = Depth::Zero;
+
+        // Parse options
+        if tokens
+            .peek()
+            .is_some_and(|token| token.is_parenthesis_open())
+        {
+            tokens.next();
+
+            while let Some(token) = tokens.next() {
+                match token {
+                    Token::ParenthesisClose => break,
+                    Token::Argument(option) if option.eq_ignore_ascii_case(b"MAXSIZE") => {
+                        max_size = tokens
+                            .next()
+                            .and_then(|token| token.unwrap_string().ok())
+                            .and_then(|value| value.parse::<usize>().ok())
+                            .ok_or_else(|| {
+                                bad(self.tag.to_compact_string(), "Invalid MAXSIZE value.")
+                            })?
+                            .into();
+                    }
+                    Token::Argument(option) if option.eq_ignore_ascii_case(b"DEPTH") => {
+                        depth = match tokens.next() {
+                            Some(Token::Argument(value)) if value == b"0" => Depth::Zero,
+                            Some(Token::Argument(value)) if value == b"1" => Depth::One,
+                            Some(Token::Argument(value))
+                                if value.eq_ignore_ascii_case(b"infinity") =>
+                            {
+                                Depth::Infinity
+                            }
+                            _ => {
+                                return Err(bad(
+                                    self.tag.to_compact_string(),
+                                    "Invalid DEPTH value.",
+                                ));
+                            }
+                        };
+                    }
+                    _ => {
+                        return Err(bad(
+                            self.tag.to_compact_string(),
+                            "Invalid GETMETADATA option.",
+                        ));
+                    }
+                }
+            }
+        }
+
+        // Parse mailbox name
+        let mailbox_name = utf7_maybe_decode(
+            tokens
+                .next()
+                .ok_or_else(|| bad(self.tag.to_compact_string(), "Missing mailbox name."))?
+                .unwrap_string()
+                .map_err(|v| bad(self.tag.to_compact_string(), v))?,
+            is_utf8,
+        );
+
+        // Parse entries
+        let mut entries = Vec::new();
+        match tokens.next() {
+            Some(Token::ParenthesisOpen) => {
+                for token in tokens.by_ref() {
+                    if token.is_parenthesis_close() {
+                        break;
+                    }
+                    entries.push(parse_entry(&self.tag, token)?);
+                }
+            }
+            Some(token) => {
+                entries.push(parse_entry(&self.tag, token)?);
+            }
+            None => (),
+        }
+
+        if entries.is_empty() {
+            Err(bad(
+                self.tag.to_compact_string(),
+                "At least one entry is required.",
+            ))
+        } else if tokens.next().is_some() {
+            Err(bad(self.tag.to_compact_string(), "Too many arguments."))
+        } else {
+            Ok(metadata::GetArguments {
+                tag: self.tag,
+                mailbox_name,
+                entries,
+                max_size,
+                depth,
+            })
+        }
+    }
+
+    pub fn parse_set_metadata(self, is_utf8: bool) -> trc::Result<metadata::SetArguments> {
+        let mut tokens = self.tokens.into_iter();
+
+        // Parse mailbox name
+        let mailbox_name = utf7_maybe_decode(
+            tokens
+                .next()
+                .ok_or_else(|| bad(self.tag.to_compact_string(), "Missing mailbox name."))?
+                .unwrap_string()
+                .map_err(|v| bad(self.tag.to_compact_string(), v))?,
+            is_utf8,
+        );
+
+        // Parse entry-value pairs
+        if tokens
+            .next()
+            .is_none_or(|token| !token.is_parenthesis_open())
+        {
+            return Err(bad(
+                self.tag.to_compact_string(),
+                "Expected parenthesis after mailbox name.",
+            ));
+        }
+        let mut entries = Vec::new();
+        while let Some(token) = tokens.next() {
+            if token.is_parenthesis_close() {
+                break;
+            }
+            let entry = parse_entry(&self.tag, token)?;
+            let value = match tokens.next() {
+                Some(Token::Nil) => None,
+                Some(Token::Argument(value)) => String::from_utf8(value)
+                    .map_err(|_| {
+                        bad(
+                            self.tag.to_compact_string(),
+                            "Invalid UTF-8 in metadata value.",
+                        )
+                    })?
+                    .into(),
+                _ => {
+                    return Err(bad(self.tag.to_compact_string(), "Missing metadata value."));
+                }
+            };
+            entries.push((entry, value));
+        }
+
+        if entries.is_empty() {
+            Err(bad(
+                self.tag.to_compact_string(),
+                "At least one entry is required.",
+            ))
+        } else {
+            Ok(metadata::SetArguments {
+                tag: self.tag,
+                mailbox_name,
+                entries,
+            })
+        }
+    }
+}
+
+fn parse_entry(tag: &str, token: Token) -> trc::Result<String> {
+    let entry = token
+        .unwrap_string()
+        .map_err(|v| bad(tag.to_compact_string(), v))?;
+    let lc_entry = entry.to_ascii_lowercase();
+    if (lc_entry.starts_with("/shared/") || lc_entry.starts_with("/private/"))
+        && !lc_entry.ends_with('/')
+        && !lc_entry.contains("//")
+        && !lc_entry.contains(['*', '%'])
+        || matches!(lc_entry.as_str(), "/shared" | "/private")
+    {
+        Ok(lc_entry)
+    } else {
+        Err(bad(
+            tag.to_compact_string(),
+            format!("Invalid metadata entry {entry:?}."),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        protocol::metadata::{self, Depth},
+        receiver::Receiver,
+    };
+
+    #[test]
+    fn parse_get_metadata() {
+        let mut receiver = Receiver::new();
+
+        for (command, arguments) in [
+            (
+                "a GETMETADATA \"\" /shared/imapsieve/script\r\n",
+                metadata::GetArguments {
+                    tag: "a".into(),
+                    mailbox_name: "".into(),
+                    entries: vec!["/shared/imapsieve/script".into()],
+                    max_size: None,
+                    depth: Depth::Zero,
+                },
+            ),
+            (
+                "b GETMETADATA (MAXSIZE 1024 DEPTH infinity) Junk (/Shared /private/comment)\r\n",
+                metadata::GetArguments {
+                    tag: "b".into(),
+                    mailbox_name: "Junk".into(),
+                    entries: vec!["/shared".into(), "/private/comment".into()],
+                    max_size: Some(1024),
+                    depth: Depth::Infinity,
+                },
+            ),
+        ] {
+            assert_eq!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_get_metadata(true)
+                    .unwrap(),
+                arguments,
+                "{command}"
+            );
+        }
+
+        for command in [
+            "c GETMETADATA INBOX\r\n",
+            "d GETMETADATA INBOX /vendor/foo\r\n",
+            "e GETMETADATA (DEPTH 2) INBOX /shared\r\n",
+        ] {
+            assert!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_get_metadata(true)
+                    .is_err(),
+                "{command}"
+            );
+        }
+    }
+
+    #[test]
+    fn parse_set_metadata() {
+        let mut receiver = Receiver::new();
+
+        assert_eq!(
+            receiver
+                .parse(
+                    &mut concat!(
+                        "a SETMETADATA Junk (/shared/imapsieve/script \"report-spam\" ",
+                        "/private/comment NIL)\r\n"
+                    )
+                    .as_bytes()
+                    .iter()
+                )
+                .unwrap()
+                .parse_set_metadata(true)
+                .unwrap(),
+            metadata::SetArguments {
+                tag: "a".into(),
+                mailbox_name: "Junk".into(),
+                entries: vec![
+                    (
+                        "/shared/imapsieve/script".into(),
+                        Some("report-spam".into())
+                    ),
+                    ("/private/comment".into(), None),
+                ],
+            }
+        );
+
+        for command in [
+            "b SETMETADATA INBOX /shared/comment \"value\"\r\n",
+            "c SETMETADATA INBOX (/shared/comment)\r\n",
+        ] {
+            assert!(
+                receiver
+                    .parse(&mut command.as_bytes().iter())
+                    .unwrap()
+                    .parse_set_metadata(true)
+                    .is_err(),
+                "{command}"
+            );
+        }
+    }
+}
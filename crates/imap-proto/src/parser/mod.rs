@@ -12,9 +12,11 @@ pub mod create;
 pub mod delete;
 pub mod enable;
 pub mod fetch;
+pub mod id;
 pub mod list;
 pub mod login;
 pub mod lsub;
+pub mod metadata;
 pub mod quota;
 pub mod rename;
 pub mod search;
@@ -80,6 +82,8 @@ impl CommandParser for Command {
             "ID" => Command::Id,
             "GETQUOTA" => Command::GetQuota,
             "GETQUOTAROOT" => Command::GetQuotaRoot,
+            "GETMETADATA" => Command::GetMetadata,
+            "SETMETADATA" => Command::SetMetadata,
         )
     }
 
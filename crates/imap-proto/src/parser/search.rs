@@ -16,7 +16,7 @@ use crate::Command;
 use crate::protocol::search::{self, Filter};
 use crate::protocol::search::{ModSeqEntry, ResultOption};
 use crate::protocol::{Flag, ProtocolVersion};
-use crate::receiver::{Request, Token, bad};
+use crate::receiver::{Receiver, Request, Token, bad};
 
 use super::{parse_date, parse_number, parse_sequence_set};
 
@@ -500,6 +500,27 @@ pub fn parse_filters(
     Ok(filters)
 }
 
+// Parses the search criteria of a saved search mailbox. Sequence sets are
+// rejected as they only have a meaning within a selected mailbox.
+pub fn parse_stored_filters(criteria: &str) -> super::Result<Vec<Filter>> {
+    let command = format!("S SEARCH {criteria}\r\n");
+    let tokens = Receiver::<Command>::new()
+        .parse(&mut command.as_bytes().iter())
+        .map_err(|_| Cow::from("Invalid search criteria."))?
+        .tokens;
+    let filters = parse_filters(&mut tokens.into_iter().peekable(), None)?;
+
+    if filters.is_empty() {
+        Err(Cow::from("Missing search criteria."))
+    } else if filters.iter().any(|f| matches!(f, Filter::Sequence(..))) {
+        Err(Cow::from(
+            "Sequence sets are not allowed in saved searches.",
+        ))
+    } else {
+        Ok(filters)
+    }
+}
+
 pub fn decode_argument(
     tokens: &mut Peekable<IntoIter<Token>>,
     decoder: Option<DecoderFnc>,
@@ -867,4 +888,22 @@ mod tests {
             );
         }
     }
+
+    #[test]
+    fn parse_stored_filters() {
+        assert_eq!(
+            super::parse_stored_filters("UNSEEN OR FROM \"boss\" FLAGGED").unwrap(),
+            vec![
+                Filter::Unseen,
+                Filter::Or,
+                Filter::From("boss".into()),
+                Filter::Flagged,
+                Filter::End,
+            ]
+        );
+
+        for criteria in ["", "1:* UNSEEN", "UID 1:10", "$"] {
+            assert!(super::parse_stored_filters(criteria).is_err(), "{criteria}");
+        }
+    }
 }
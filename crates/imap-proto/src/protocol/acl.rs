@@ -107,10 +107,7 @@ impl GetAclResponse {
             buf.extend_from_slice(b" ");
             quoted_string(&mut buf, &identifier);
             buf.extend_from_slice(b" ");
-
-            for right in rights {
-                buf.push(right.to_char());
-            }
+            serialize_rights(&mut buf, &rights);
         }
         buf.extend_from_slice(b"\r\n");
         buf
@@ -132,9 +129,7 @@ impl ListRightsResponse {
         quoted_string(&mut buf, &self.identifier);
         for rights in self.permissions {
             buf.extend_from_slice(b" ");
-            for right in rights {
-                buf.push(right.to_char());
-            }
+            serialize_rights(&mut buf, &rights);
         }
         buf.extend_from_slice(b"\r\n");
         buf
@@ -151,14 +146,23 @@ impl MyRightsResponse {
             quoted_string(&mut buf, &utf7_encode(&self.mailbox_name));
         }
         buf.extend_from_slice(b" ");
-        for right in self.rights {
-            buf.push(right.to_char());
-        }
+        serialize_rights(&mut buf, &self.rights);
         buf.extend_from_slice(b"\r\n");
         buf
     }
 }
 
+// An empty set of rights is sent as an empty quoted string
+fn serialize_rights(buf: &mut Vec<u8>, rights: &[Rights]) {
+    if !rights.is_empty() {
+        for right in rights {
+            buf.push(right.to_char());
+        }
+    } else {
+        buf.extend_from_slice(b"\"\"");
+    }
+}
+
 impl Rights {
     pub fn to_char(&self) -> u8 {
         match self {
@@ -271,6 +275,24 @@ mod tests {
             "* LISTRIGHTS \"Deleted Items\" \"Fred\" lr a x\r\n"
         );
 
+        assert_eq!(
+            String::from_utf8(
+                ListRightsResponse {
+                    mailbox_name: "Shared Folders/jane/INBOX".into(),
+                    identifier: "fred@example.org".into(),
+                    permissions: vec![
+                        vec![],
+                        vec![Rights::Lookup],
+                        vec![Rights::Seen, Rights::Write],
+                        vec![Rights::Administer]
+                    ]
+                }
+                .into_bytes(true)
+            )
+            .unwrap(),
+            "* LISTRIGHTS \"Shared Folders/jane/INBOX\" \"fred@example.org\" \"\" l sw a\r\n"
+        );
+
         assert_eq!(
             String::from_utf8(
                 MyRightsResponse {
@@ -54,6 +54,9 @@ pub enum Capability {
     QuotaResource(QuotaResourceName),
     QuotaSet,
     JmapAccess,
+    Metadata,
+    MetadataServer,    //METADATA-SERVER
+    ImapSieve(String), //IMAPSIEVE=<url>
 }
 
 /*
@@ -130,6 +133,13 @@ impl Capability {
             }
             Capability::QuotaSet => b"QUOTA=SET",
             Capability::JmapAccess => b"JMAPACCESS",
+            Capability::Metadata => b"METADATA",
+            Capability::MetadataServer => b"METADATA-SERVER",
+            Capability::ImapSieve(url) => {
+                buf.extend_from_slice(b"IMAPSIEVE=");
+                buf.extend_from_slice(url.as_bytes());
+                return;
+            }
         });
     }
 
@@ -176,6 +186,8 @@ impl Capability {
                 Capability::Rights,
                 Capability::Quota,
                 Capability::QuotaResource(QuotaResourceName::Storage),
+                Capability::Metadata,
+                Capability::MetadataServer,
             ]);
         } else {
             capabilities.extend([
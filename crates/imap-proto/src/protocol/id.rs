@@ -0,0 +1,40 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Arguments {
+    pub tag: String,
+    pub parameters: Vec<(String, String)>,
+}
+
+impl Arguments {
+    pub fn get(&self, field: &str) -> Option<&str> {
+        self.parameters
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(field))
+            .map(|(_, value)| value.as_str())
+            .filter(|value| !value.is_empty())
+    }
+
+    // Builds a user agent string such as "Thunderbird 115.0 (Linux 6.1)"
+    pub fn user_agent(&self) -> Option<String> {
+        let mut user_agent = self.get("name").or_else(|| self.get("vendor"))?.to_string();
+        if let Some(version) = self.get("version") {
+            user_agent.push(' ');
+            user_agent.push_str(version);
+        }
+        if let Some(os) = self.get("os") {
+            user_agent.push_str(" (");
+            user_agent.push_str(os);
+            if let Some(os_version) = self.get("os-version") {
+                user_agent.push(' ');
+                user_agent.push_str(os_version);
+            }
+            user_agent.push(')');
+        }
+        Some(user_agent)
+    }
+}
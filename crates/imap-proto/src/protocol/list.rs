@@ -260,25 +260,18 @@ impl ImapResponse for Response {
     fn serialize(self) -> Vec<u8> {
         let mut buf = Vec::with_capacity(100);
 
-        match (self.list_items.is_empty(), self.status_items.is_empty()) {
-            (false, false) => {
-                for (list_item, status_item) in self.list_items.iter().zip(self.status_items.iter())
-                {
-                    list_item.serialize(&mut buf, self.is_rev2, self.is_utf8, self.is_lsub);
-                    status_item.serialize(&mut buf, self.is_rev2);
-                }
-            }
-            (false, true) => {
-                for list_item in &self.list_items {
-                    list_item.serialize(&mut buf, self.is_rev2, self.is_utf8, self.is_lsub);
-                }
-            }
-            (true, false) => {
-                for status_item in &self.status_items {
-                    status_item.serialize(&mut buf, self.is_rev2);
-                }
+        // Mailboxes that cannot be selected have no STATUS response
+        let mut status_items = self.status_items.iter().peekable();
+        for list_item in &self.list_items {
+            list_item.serialize(&mut buf, self.is_rev2, self.is_utf8, self.is_lsub);
+            if let Some(status_item) =
+                status_items.next_if(|item| item.mailbox_name == list_item.mailbox_name)
+            {
+                status_item.serialize(&mut buf, self.is_rev2);
             }
-            _ => (),
+        }
+        for status_item in status_items {
+            status_item.serialize(&mut buf, self.is_rev2);
         }
 
         buf
@@ -411,4 +404,50 @@ mod tests {
         assert_eq!(response_v2, expected_v2);
         assert_eq!(response_v1, expected_v1);
     }
+    #[test]
+    fn serialize_list_status_noselect() {
+        let response = super::Response {
+            list_items: vec![
+                ListItem {
+                    mailbox_name: "Shared Folders".into(),
+                    attributes: vec![Attribute::NoSelect],
+                    tags: vec![],
+                },
+                ListItem {
+                    mailbox_name: "INBOX".into(),
+                    attributes: vec![],
+                    tags: vec![],
+                },
+                ListItem {
+                    mailbox_name: "Archive".into(),
+                    attributes: vec![Attribute::Subscribed, Attribute::Archive],
+                    tags: vec![],
+                },
+            ],
+            status_items: vec![
+                StatusItem {
+                    mailbox_name: "INBOX".into(),
+                    items: vec![(Status::Unseen, StatusItemType::Number(3))],
+                },
+                StatusItem {
+                    mailbox_name: "Archive".into(),
+                    items: vec![(Status::Unseen, StatusItemType::Number(0))],
+                },
+            ],
+            is_lsub: false,
+            is_rev2: true,
+            is_utf8: true,
+        };
+
+        assert_eq!(
+            String::from_utf8(response.serialize()).unwrap(),
+            concat!(
+                "* LIST (\\NoSelect) \"/\" \"Shared Folders\"\r\n",
+                "* LIST () \"/\" \"INBOX\"\r\n",
+                "* STATUS \"INBOX\" (UNSEEN 3)\r\n",
+                "* LIST (\\Subscribed \\Archive) \"/\" \"Archive\"\r\n",
+                "* STATUS \"Archive\" (UNSEEN 0)\r\n",
+            )
+        );
+    }
 }
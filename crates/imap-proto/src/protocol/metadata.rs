@@ -0,0 +1,144 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{quoted_or_literal_string, quoted_or_literal_string_or_nil};
+use crate::utf7::utf7_encode;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GetArguments {
+    pub tag: String,
+    pub mailbox_name: String,
+    pub entries: Vec<String>,
+    pub max_size: Option<usize>,
+    pub depth: Depth,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetArguments {
+    pub tag: String,
+    pub mailbox_name: String,
+    pub entries: Vec<(String, Option<String>)>,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Depth {
+    #[default]
+    Zero,
+    One,
+    Infinity,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Response {
+    pub mailbox_name: String,
+    pub entries: Vec<(String, Option<String>)>,
+}
+
+impl Depth {
+    pub fn matches(&self, requested: &str, entry: &str) -> bool {
+        if requested.eq_ignore_ascii_case(entry) {
+            true
+        } else if let Some(child) = entry
+            .get(requested.len()..)
+            .filter(|_| entry[..requested.len()].eq_ignore_ascii_case(requested))
+            .and_then(|child| child.strip_prefix('/'))
+        {
+            match self {
+                Depth::Zero => false,
+                Depth::One => !child.contains('/'),
+                Depth::Infinity => true,
+            }
+        } else {
+            false
+        }
+    }
+}
+
+impl Response {
+    pub fn serialize(&self, buf: &mut Vec<u8>, is_utf8: bool) {
+        if !self.entries.is_empty() {
+            buf.extend_from_slice(b"* METADATA ");
+            if is_utf8 {
+                quoted_or_literal_string(buf, &self.mailbox_name);
+            } else {
+                quoted_or_literal_string(buf, &utf7_encode(&self.mailbox_name));
+            }
+            buf.extend_from_slice(b" (");
+            for (pos, (entry, value)) in self.entries.iter().enumerate() {
+                if pos > 0 {
+                    buf.push(b' ');
+                }
+                buf.extend_from_slice(entry.as_bytes());
+                buf.push(b' ');
+                quoted_or_literal_string_or_nil(buf, value.as_deref());
+            }
+            buf.extend_from_slice(b")\r\n");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::protocol::metadata::{Depth, Response};
+
+    #[test]
+    fn serialize_metadata() {
+        let mut buf = Vec::new();
+        Response {
+            mailbox_name: "Junk".into(),
+            entries: vec![
+                (
+                    "/shared/imapsieve/script".into(),
+                    Some("report-spam".into()),
+                ),
+                ("/private/comment".into(), None),
+            ],
+        }
+        .serialize(&mut buf, true);
+        assert_eq!(
+            String::from_utf8(buf).unwrap(),
+            "* METADATA \"Junk\" (/shared/imapsieve/script \"report-spam\" /private/comment NIL)\r\n"
+        );
+    }
+
+    #[test]
+    fn metadata_depth() {
+        for (depth, requested, entry, expected) in [
+            (
+                Depth::Zero,
+                "/shared/imapsieve/script",
+                "/shared/imapsieve/script",
+                true,
+            ),
+            (
+                Depth::Zero,
+                "/shared/imapsieve",
+                "/shared/imapsieve/script",
+                false,
+            ),
+            (
+                Depth::One,
+                "/shared/imapsieve",
+                "/shared/imapsieve/script",
+                true,
+            ),
+            (Depth::One, "/shared", "/shared/imapsieve/script", false),
+            (Depth::Infinity, "/SHARED", "/shared/imapsieve/script", true),
+            (
+                Depth::Infinity,
+                "/shared/imap",
+                "/shared/imapsieve/script",
+                false,
+            ),
+        ] {
+            assert_eq!(
+                depth.matches(requested, entry),
+                expected,
+                "{depth:?} {requested} {entry}"
+            );
+        }
+    }
+}
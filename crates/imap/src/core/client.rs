@@ -11,7 +11,7 @@ use common::{
     network::{SessionResult, SessionStream},
 };
 use imap_proto::{
-    Command, ResponseType, StatusResponse,
+    Command, ResponseCode, ResponseType, StatusResponse,
     receiver::{self, Request},
 };
 use trc::SecurityEvent;
@@ -237,14 +237,19 @@ impl<T: SessionStream> Session<T> {
                     .handle_get_quota_root(request)
                     .await
                     .map(|_| SessionResult::Continue),
-                Command::Unauthenticate => self
-                    .handle_unauthenticate(request)
+                Command::GetMetadata => self
+                    .handle_get_metadata(request)
                     .await
                     .map(|_| SessionResult::Continue),
-                Command::Id => self
-                    .handle_id(request)
+                Command::SetMetadata => self
+                    .handle_set_metadata(request)
                     .await
                     .map(|_| SessionResult::Continue),
+                Command::Unauthenticate => self
+                    .handle_unauthenticate(request)
+                    .await
+                    .map(|_| SessionResult::Continue),
+                Command::Id => self.handle_id(request).await,
             };
 
             match result {
@@ -308,6 +313,30 @@ impl<T: SessionStream> Session<T> {
             return Err(trc::LimitEvent::TooManyRequests.into_err());
         }
 
+        // Clients restricted by the client policy cannot modify mailboxes
+        if self.is_read_only_client
+            && matches!(
+                request.command,
+                Command::Create
+                    | Command::Delete
+                    | Command::Rename
+                    | Command::Append
+                    | Command::SetAcl
+                    | Command::DeleteAcl
+                    | Command::SetMetadata
+                    | Command::Store(_)
+                    | Command::Expunge(_)
+                    | Command::Copy(_)
+                    | Command::Move(_)
+            )
+        {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("This client is restricted to read-only access.")
+                .code(ResponseCode::NoPerm)
+                .id(request.tag));
+        }
+
         match &request.command {
             Command::Capability | Command::Noop | Command::Logout | Command::Id => Ok(request),
             Command::StartTls => {
@@ -375,7 +404,9 @@ impl<T: SessionStream> Session<T> {
             | Command::MyRights
             | Command::Unauthenticate
             | Command::GetQuota
-            | Command::GetQuotaRoot => {
+            | Command::GetQuotaRoot
+            | Command::GetMetadata
+            | Command::SetMetadata => {
                 if let State::Authenticated { .. } | State::Selected { .. } = state {
                     Ok(request)
                 } else {
@@ -14,7 +14,12 @@ use common::{
 };
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
-    mailbox::INBOX_ID,
+    mailbox::{
+        INBOX_ID,
+        virtual_folder::{
+            VirtualMailbox, VirtualMailboxes, search_folder_id, virtual_uid_validity,
+        },
+    },
 };
 use imap_proto::protocol::list::Attribute;
 use parking_lot::Mutex;
@@ -32,6 +37,11 @@ impl<T: SessionStream> SessionData<T> {
         access_token: AccessToken,
         in_flight: Option<InFlight>,
     ) -> trc::Result<Self> {
+        let domain = session
+            .server
+            .account_domain(access_token.account_id())
+            .await
+            .caused_by(trc::location!())?;
         let mut session = SessionData {
             stream_tx: session.stream_tx.clone(),
             server: session.server.clone(),
@@ -42,6 +52,8 @@ impl<T: SessionStream> SessionData<T> {
             remote_addr: session.remote_addr,
             access_token,
             in_flight,
+            bandwidth: session.instance.bandwidth.clone(),
+            domain,
         };
 
         // Fetch mailboxes for the main account
@@ -57,7 +69,7 @@ impl<T: SessionStream> SessionData<T> {
         for &account_id in session.access_token.shared_accounts(Collection::Mailbox) {
             let prefix: String = format!(
                 "{}/{}",
-                session.server.core.email.shared_folder,
+                session.shared_folder(),
                 session
                     .server
                     .account(account_id)
@@ -122,6 +134,13 @@ impl<T: SessionStream> SessionData<T> {
             last_change_id: cache.last_change_id,
         };
 
+        // Mailbox counters are shared with other sessions until the account changes
+        let status = self
+            .server
+            .get_mailbox_status(account_id, &cache)
+            .await
+            .caused_by(trc::location!())?;
+
         for mailbox in &cache.mailboxes.items {
             if shared_mailbox_ids
                 .as_ref()
@@ -141,18 +160,23 @@ impl<T: SessionStream> SessionData<T> {
                 mailbox.path.clone()
             };
             let effective_mailbox_id = self
-                .server
-                .core
-                .email
-                .default_folders
+                .default_folders()
                 .iter()
-                .find(|f| f.name == mailbox_name || f.aliases.iter().any(|a| a == &mailbox_name))
+                .find(|f| {
+                    f.name == mailbox_name
+                        || f.aliases.iter().any(|a| a == &mailbox_name)
+                        || f.localized_names.iter().any(|(_, n)| n == &mailbox_name)
+                })
                 .and_then(|f| special_uses.get(&f.special_use))
                 .copied()
                 .unwrap_or(mailbox.document_id);
             account
                 .mailbox_names
                 .insert(mailbox_name, effective_mailbox_id);
+            let mailbox_status = status
+                .get(&mailbox.document_id)
+                .copied()
+                .unwrap_or_default();
             account.mailbox_state.insert(
                 mailbox.document_id,
                 Mailbox {
@@ -172,29 +196,137 @@ impl<T: SessionStream> SessionData<T> {
                         SpecialUse::Memos => Some(Attribute::Memos),
                         SpecialUse::Scheduled => Some(Attribute::Scheduled),
                         SpecialUse::Snoozed => Some(Attribute::Snoozed),
+                        SpecialUse::All => Some(Attribute::All),
+                        SpecialUse::Flagged => Some(Attribute::Flagged),
                         _ => None,
                     },
-                    total_messages: cache.in_mailbox(mailbox.document_id).count() as u64,
-                    total_unseen: cache
-                        .in_mailbox_without_keyword(mailbox.document_id, &Keyword::Seen)
-                        .count() as u64,
-                    total_deleted: cache
-                        .in_mailbox_with_keyword(mailbox.document_id, &Keyword::Deleted)
-                        .count() as u64,
+                    total_messages: mailbox_status.total_messages,
+                    total_unseen: mailbox_status.total_unseen,
+                    total_deleted: mailbox_status.total_deleted,
                     uid_validity: mailbox.uid_validity as u64,
-                    uid_next: self
-                        .get_uid_next(&MailboxId {
-                            account_id,
-                            mailbox_id: mailbox.document_id,
-                        })
-                        .await
-                        .caused_by(trc::location!())? as u64,
-                    total_deleted_storage: None,
-                    size: None,
+                    uid_next: mailbox_status.uid_next.max(1),
+                    total_deleted_storage: Some(mailbox_status.deleted_size),
+                    size: Some(mailbox_status.size),
                 },
             );
         }
 
+        // Add virtual folders to the user's own account
+        if account.prefix.is_none() {
+            for folder in self
+                .server
+                .virtual_folders(account_id)
+                .await
+                .caused_by(trc::location!())?
+            {
+                let virtual_mailbox = VirtualMailbox::new(
+                    folder.folder_type,
+                    &self.server.core.email.virtual_recent_period,
+                );
+                let mailbox_id = virtual_mailbox.id();
+                let mut mailbox = Mailbox {
+                    is_subscribed: folder.subscribe,
+                    special_use: match virtual_mailbox.role() {
+                        SpecialUse::All => Some(Attribute::All),
+                        SpecialUse::Flagged => Some(Attribute::Flagged),
+                        _ => None,
+                    },
+                    uid_validity: virtual_uid_validity(&cache, mailbox_id) as u64,
+                    total_deleted_storage: Some(0),
+                    size: Some(0),
+                    ..Default::default()
+                };
+                let mut document_ids = Vec::new();
+                for message in self
+                    .server
+                    .virtual_mailbox_messages(account_id, virtual_mailbox, &cache)
+                    .await
+                    .caused_by(trc::location!())?
+                {
+                    document_ids.push(message.document_id);
+                    mailbox.total_messages += 1;
+                    mailbox.size = mailbox.size.map(|size| size + message.size as u64);
+                    if !cache.has_keyword(message, &Keyword::Seen) {
+                        mailbox.total_unseen += 1;
+                    }
+                    if cache.has_keyword(message, &Keyword::Deleted) {
+                        mailbox.total_deleted += 1;
+                        mailbox.total_deleted_storage = mailbox
+                            .total_deleted_storage
+                            .map(|size| size + message.size as u64);
+                    }
+                }
+                mailbox.uid_next = self
+                    .server
+                    .virtual_mailbox_uids(account_id, mailbox_id, document_ids)
+                    .await
+                    .caused_by(trc::location!())?
+                    .uid_next as u64;
+
+                account
+                    .mailbox_names
+                    .insert(folder.name.clone(), mailbox_id);
+                account.mailbox_state.insert(mailbox_id, mailbox);
+            }
+
+            // Add saved search folders
+            for (idx, folder) in self
+                .server
+                .search_folders(account_id)
+                .await
+                .caused_by(trc::location!())?
+                .iter()
+                .enumerate()
+            {
+                let mailbox_id = search_folder_id(idx);
+                let mut mailbox = Mailbox {
+                    is_subscribed: folder.subscribe,
+                    special_use: match folder.special_use {
+                        SpecialUse::All => Some(Attribute::All),
+                        SpecialUse::Flagged => Some(Attribute::Flagged),
+                        SpecialUse::Important => Some(Attribute::Important),
+                        _ => None,
+                    },
+                    uid_validity: virtual_uid_validity(&cache, mailbox_id) as u64,
+                    total_deleted_storage: Some(0),
+                    size: Some(0),
+                    ..Default::default()
+                };
+                let mut document_ids = Vec::new();
+                for message in self
+                    .search_folder_messages(account_id, folder, &cache)
+                    .await
+                    .caused_by(trc::location!())?
+                    .into_iter()
+                    .filter_map(|document_id| cache.email_by_id(&document_id))
+                {
+                    document_ids.push(message.document_id);
+                    mailbox.total_messages += 1;
+                    mailbox.size = mailbox.size.map(|size| size + message.size as u64);
+                    if !cache.has_keyword(message, &Keyword::Seen) {
+                        mailbox.total_unseen += 1;
+                    }
+                    if cache.has_keyword(message, &Keyword::Deleted) {
+                        mailbox.total_deleted += 1;
+                        mailbox.total_deleted_storage = mailbox
+                            .total_deleted_storage
+                            .map(|size| size + message.size as u64);
+                    }
+                }
+                mailbox.uid_next = self
+                    .server
+                    .virtual_mailbox_uids(account_id, mailbox_id, document_ids)
+                    .await
+                    .caused_by(trc::location!())?
+                    .uid_next as u64;
+
+                account
+                    .mailbox_names
+                    .insert(folder.name.clone(), mailbox_id);
+                account.mailbox_state.insert(mailbox_id, mailbox);
+            }
+        }
+
         Ok(account.into())
     }
 
@@ -259,7 +391,7 @@ impl<T: SessionStream> SessionData<T> {
             for account_id in added_account_ids {
                 let prefix: String = format!(
                     "{}/{}",
-                    self.server.core.email.shared_folder,
+                    self.shared_folder(),
                     self.server
                         .account(account_id)
                         .await
@@ -10,7 +10,7 @@ use super::{
 use crate::core::ImapId;
 use ahash::AHashMap;
 use common::network::SessionStream;
-use email::cache::MessageCacheFetch;
+use email::{cache::MessageCacheFetch, mailbox::virtual_folder::VirtualMailboxes};
 use imap_proto::protocol::{Sequence, expunge, select::Exists};
 use std::collections::BTreeMap;
 use store::{ValueKey, write::ValueClass};
@@ -34,20 +34,53 @@ impl<T: SessionStream> SessionData<T> {
         }
 
         // Obtain UID next and assign UIDs
-        let uid_map = cached_messages
-            .emails
-            .items
-            .iter()
-            .filter_map(|item| {
-                item.mailboxes.iter().find_map(|m| {
-                    if m.mailbox_id == mailbox.mailbox_id {
-                        Some((m.uid, item.document_id))
-                    } else {
-                        None
-                    }
+        let virtual_ids = if let Some(folder) = self.server.search_folder(mailbox.mailbox_id) {
+            // Saved searches are materialized from the query engine on each change
+            Some(
+                self.search_folder_messages(mailbox.account_id, folder, &cached_messages)
+                    .await?
+                    .into_iter()
+                    .collect::<Vec<_>>(),
+            )
+        } else if let Some(virtual_mailbox) = self.server.virtual_mailbox(mailbox.mailbox_id) {
+            Some(
+                self.server
+                    .virtual_mailbox_messages(mailbox.account_id, virtual_mailbox, &cached_messages)
+                    .await
+                    .caused_by(trc::location!())?
+                    .into_iter()
+                    .map(|item| item.document_id)
+                    .collect(),
+            )
+        } else {
+            None
+        };
+        let uid_map = if let Some(document_ids) = virtual_ids {
+            // Virtual mailboxes keep their own UID counters
+            self.server
+                .virtual_mailbox_uids(mailbox.account_id, mailbox.mailbox_id, document_ids)
+                .await
+                .caused_by(trc::location!())?
+                .uids
+                .into_iter()
+                .map(|uid| (uid.uid, uid.document_id))
+                .collect::<BTreeMap<u32, u32>>()
+        } else {
+            cached_messages
+                .emails
+                .items
+                .iter()
+                .filter_map(|item| {
+                    item.mailboxes.iter().find_map(|m| {
+                        if m.mailbox_id == mailbox.mailbox_id {
+                            Some((m.uid, item.document_id))
+                        } else {
+                            None
+                        }
+                    })
                 })
-            })
-            .collect::<BTreeMap<u32, u32>>();
+                .collect::<BTreeMap<u32, u32>>()
+        };
         let mut uid_max = 0;
         let mut id_to_imap = AHashMap::with_capacity(uid_map.len());
         let mut uid_to_id = AHashMap::with_capacity(uid_map.len());
@@ -223,17 +256,17 @@ impl SelectedMailbox {
 
             Ok(ids)
         } else {
-            let saved_ids = self.get_saved_search().await.ok_or_else(|| {
-                trc::ImapEvent::Error
-                    .into_err()
-                    .details("No saved search found.")
-            })?;
+            // An unset result variable is treated as an empty set
+            let Some(saved_ids) = self.get_saved_search().await else {
+                return Ok(AHashMap::new());
+            };
             let mut ids = AHashMap::with_capacity(saved_ids.len());
             let state = self.state.lock();
 
+            // Sequence numbers may have shifted since the search was saved
             for imap_id in saved_ids.iter() {
                 if let Some(id) = state.uid_to_id.get(&imap_id.uid) {
-                    ids.insert(*id, *imap_id);
+                    ids.insert(*id, state.id_to_imap.get(id).copied().unwrap_or(*imap_id));
                 }
             }
 
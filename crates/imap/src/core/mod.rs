@@ -7,8 +7,12 @@
 use ahash::AHashMap;
 use common::{
     Inner, Server,
-    auth::AccessToken,
-    network::{ServerInstance, SessionStream, limiter::InFlight},
+    auth::{AccessToken, DomainCache, client_cert::ClientCertificate},
+    config::mailstore::email::DefaultFolder,
+    network::{
+        ServerInstance, SessionStream,
+        limiter::{BandwidthLimiter, InFlight},
+    },
 };
 use imap_proto::{
     Command,
@@ -49,6 +53,7 @@ pub struct Session<T: SessionStream> {
     pub version: ProtocolVersion,
     pub state: State<T>,
     pub is_tls: bool,
+    pub tls_client: Option<ClientCertificate>,
     pub is_condstore: bool,
     pub is_qresync: bool,
     pub is_utf8: bool,
@@ -57,6 +62,11 @@ pub struct Session<T: SessionStream> {
     pub in_flight: InFlight,
     pub remote_addr: IpAddr,
     pub session_id: u64,
+    pub user_agent: Option<String>,
+    pub client_name: Option<String>,
+    pub client_version: Option<String>,
+    pub is_read_only_client: bool,
+    pub login_entry_id: Option<u64>,
 }
 
 pub struct SessionData<T: SessionStream> {
@@ -69,6 +79,8 @@ pub struct SessionData<T: SessionStream> {
     pub state: AtomicU32,
     pub remote_addr: IpAddr,
     pub in_flight: Option<InFlight>,
+    pub bandwidth: Option<BandwidthLimiter>,
+    pub domain: Option<Arc<DomainCache>>,
 }
 
 pub struct SelectedMailbox {
@@ -215,8 +227,25 @@ impl<T: SessionStream> SessionData<T> {
             in_flight: self.in_flight,
             access_token: self.access_token,
             remote_addr: self.remote_addr,
+            bandwidth: self.bandwidth,
+            domain: self.domain,
         }
     }
+
+    // Domains may override the default folders and the shared folder name
+    pub fn default_folders(&self) -> &[DefaultFolder] {
+        self.domain
+            .as_ref()
+            .and_then(|domain| domain.default_folders.as_deref())
+            .unwrap_or(self.server.core.email.default_folders.as_slice())
+    }
+
+    pub fn shared_folder(&self) -> &str {
+        self.domain
+            .as_ref()
+            .and_then(|domain| domain.shared_folder.as_deref())
+            .unwrap_or(self.server.core.email.shared_folder.as_str())
+    }
 }
 
 impl MailboxState {
@@ -8,7 +8,10 @@ use super::{ImapSessionManager, Session, State};
 use crate::{GREETING_WITH_TLS, GREETING_WITHOUT_TLS};
 use common::{
     BuildServer,
-    network::{SessionData, SessionManager, SessionResult, SessionStream, stream::NullIo},
+    network::{
+        SessionData, SessionManager, SessionResult, SessionStream, limiter::bandwidth_wait,
+        stream::NullIo,
+    },
 };
 use imap_proto::{
     protocol::{ProtocolVersion, SerializeResponse},
@@ -134,6 +137,7 @@ impl<T: SessionStream> Session<T> {
             return Err(());
         }
         let _ = session.stream.flush().await;
+        let tls_client = session.instance.tls_client_certificate(&session.stream);
 
         // Split stream into read and write halves
         let (stream_rx, stream_tx) = tokio::io::split(session.stream);
@@ -144,6 +148,7 @@ impl<T: SessionStream> Session<T> {
             version: ProtocolVersion::Rev1,
             state: State::NotAuthenticated { auth_failures: 0 },
             is_tls,
+            tls_client,
             is_condstore: false,
             is_qresync: false,
             is_utf8: false,
@@ -152,6 +157,11 @@ impl<T: SessionStream> Session<T> {
             session_id: session.session_id,
             in_flight: session.in_flight,
             remote_addr: session.remote_ip,
+            user_agent: None,
+            client_name: None,
+            client_version: None,
+            is_read_only_client: false,
+            login_entry_id: None,
             stream_rx,
             stream_tx: Arc::new(tokio::sync::Mutex::new(stream_tx)),
         })
@@ -190,8 +200,9 @@ impl<T: SessionStream> Session<T> {
         };
 
         // Upgrade to TLS
-        let (stream_rx, stream_tx) =
-            tokio::io::split(self.instance.tls_accept(stream, self.session_id).await?);
+        let stream = self.instance.tls_accept(stream, self.session_id).await?;
+        let tls_client = self.instance.tls_client_certificate(&stream);
+        let (stream_rx, stream_tx) = tokio::io::split(stream);
         let stream_tx = Arc::new(tokio::sync::Mutex::new(stream_tx));
 
         Ok(Session {
@@ -201,12 +212,18 @@ impl<T: SessionStream> Session<T> {
             version: self.version,
             state: state.try_replace_stream_tx(stream_tx.clone()).unwrap(),
             is_tls: true,
+            tls_client,
             is_condstore: self.is_condstore,
             is_qresync: self.is_qresync,
             is_utf8: self.is_utf8,
             session_id: self.session_id,
             in_flight: self.in_flight,
             remote_addr: self.remote_addr,
+            user_agent: self.user_agent,
+            client_name: self.client_name,
+            client_version: self.client_version,
+            is_read_only_client: self.is_read_only_client,
+            login_entry_id: self.login_entry_id,
             stream_rx,
             stream_tx,
         })
@@ -279,6 +296,26 @@ impl<T: SessionStream> super::SessionData<T> {
         }
     }
 
+    pub async fn throttle_bandwidth(&self, bytes: usize) {
+        if let Some(wait) = bandwidth_wait(
+            self.access_token
+                .imap_bandwidth()
+                .into_iter()
+                .chain(self.bandwidth.as_ref()),
+            bytes,
+        ) {
+            trc::event!(
+                Limit(trc::LimitEvent::BandwidthThrottled),
+                SpanId = self.session_id,
+                AccountId = self.account_id,
+                Size = bytes,
+                Elapsed = wait,
+            );
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
     pub async fn write_error(&self, err: trc::Error) -> trc::Result<()> {
         if err.should_write_err() {
             let bytes = err.serialize();
@@ -14,6 +14,7 @@ use common::{
     storage::index::ObjectIndexBuilder,
 };
 use compact_str::ToCompactString;
+use directory::Recipient;
 use imap_proto::{
     Command, ResponseCode, StatusResponse,
     protocol::acl::{
@@ -32,7 +33,22 @@ use types::{
     acl::{Acl, AclGrant},
     collection::Collection,
 };
-use utils::map::bitmap::Bitmap;
+use utils::{map::bitmap::Bitmap, sanitize_email};
+
+// Rights held by the owner of a mailbox, in the order they are reported
+const ALL_RIGHTS: [Rights; 11] = [
+    Rights::Read,
+    Rights::Lookup,
+    Rights::Insert,
+    Rights::DeleteMessages,
+    Rights::Expunge,
+    Rights::Seen,
+    Rights::Write,
+    Rights::CreateMailbox,
+    Rights::DeleteMailbox,
+    Rights::Post,
+    Rights::Administer,
+];
 
 impl<T: SessionStream> Session<T> {
     pub async fn handle_get_acl(&mut self, request: Request<Command>) -> trc::Result<()> {
@@ -64,22 +80,7 @@ impl<T: SessionStream> Session<T> {
                     .name()
                     .to_string();
 
-                permissions.push((
-                    account_name,
-                    vec![
-                        Rights::Read,
-                        Rights::Lookup,
-                        Rights::Insert,
-                        Rights::DeleteMessages,
-                        Rights::Expunge,
-                        Rights::Seen,
-                        Rights::Write,
-                        Rights::CreateMailbox,
-                        Rights::DeleteMailbox,
-                        Rights::Post,
-                        Rights::Administer,
-                    ],
-                ));
+                permissions.push((account_name, ALL_RIGHTS.to_vec()));
             }
 
             for item in mailbox.inner.acls.iter() {
@@ -183,46 +184,18 @@ impl<T: SessionStream> Session<T> {
                 .imap_ctx(&arguments.tag, trc::location!())?;
             let rights = if access_token.is_shared(mailbox_id.account_id) {
                 let acl = mailbox.inner.acls.effective_acl(&access_token);
-                let mut rights = Vec::with_capacity(5);
-                if acl.contains(Acl::ReadItems) {
-                    rights.push(Rights::Read);
-                    rights.push(Rights::Lookup);
-                }
-                if acl.contains(Acl::AddItems) {
-                    rights.push(Rights::Insert);
-                }
-                if acl.contains(Acl::RemoveItems) {
-                    rights.push(Rights::DeleteMessages);
-                    rights.push(Rights::Expunge);
-                }
-                if acl.contains(Acl::ModifyItems) {
-                    rights.push(Rights::Seen);
-                    rights.push(Rights::Write);
-                }
-                if acl.contains(Acl::CreateChild) {
-                    rights.push(Rights::CreateMailbox);
-                }
-                if acl.contains(Acl::Delete) {
-                    rights.push(Rights::DeleteMailbox);
-                }
-                if acl.contains(Acl::Submit) {
-                    rights.push(Rights::Post);
-                }
-                rights
+                ALL_RIGHTS
+                    .into_iter()
+                    .filter(|right| match right {
+                        Rights::Lookup => acl.contains(Acl::Read),
+                        Rights::CreateMailbox => {
+                            acl.contains(Acl::CreateChild) || acl.contains(Acl::Modify)
+                        }
+                        _ => acl.contains(Acl::from(*right)),
+                    })
+                    .collect()
             } else {
-                vec![
-                    Rights::Read,
-                    Rights::Lookup,
-                    Rights::Insert,
-                    Rights::DeleteMessages,
-                    Rights::Expunge,
-                    Rights::Seen,
-                    Rights::Write,
-                    Rights::CreateMailbox,
-                    Rights::DeleteMailbox,
-                    Rights::Post,
-                    Rights::Administer,
-                ]
+                ALL_RIGHTS.to_vec()
             };
 
             trc::event!(
@@ -274,17 +247,9 @@ impl<T: SessionStream> Session<T> {
 
             // Obtain principal id
             let acl_account_id = data
-                .server
-                .account_id_from_email(arguments.identifier.as_ref().unwrap(), false)
+                .acl_account_id(arguments.identifier.as_deref().unwrap_or_default())
                 .await
-                .imap_ctx(&arguments.tag, trc::location!())?
-                .ok_or_else(|| {
-                    trc::ImapEvent::Error
-                        .into_err()
-                        .details("Account does not exist")
-                        .id(arguments.tag.to_string())
-                        .caused_by(trc::location!())
-                })?;
+                .imap_ctx(&arguments.tag, trc::location!())?;
 
             // Prepare changes
             let mut mailbox = current_mailbox.inner.clone();
@@ -403,37 +368,62 @@ impl<T: SessionStream> Session<T> {
 
         let op_start = Instant::now();
         let arguments = request.parse_acl(self.is_utf8)?;
+        let is_utf8 = self.version.is_rev2() || self.is_utf8;
+        let data = self.state.session_data();
 
-        trc::event!(
-            Imap(trc::ImapEvent::ListRights),
-            SpanId = self.session_id,
-            MailboxName = arguments.mailbox_name.clone(),
-            Elapsed = op_start.elapsed()
-        );
-
-        self.write_bytes(
-            StatusResponse::completed(Command::ListRights)
-                .with_tag(arguments.tag)
-                .serialize(
-                    ListRightsResponse {
-                        mailbox_name: arguments.mailbox_name,
-                        identifier: arguments.identifier.unwrap(),
-                        permissions: vec![
-                            vec![Rights::Read],
-                            vec![Rights::Lookup],
-                            vec![Rights::Write, Rights::Seen],
-                            vec![Rights::Insert],
-                            vec![Rights::Expunge, Rights::DeleteMessages],
-                            vec![Rights::CreateMailbox],
-                            vec![Rights::DeleteMailbox],
-                            vec![Rights::Post],
-                            vec![Rights::Administer],
-                        ],
-                    }
-                    .into_bytes(self.version.is_rev2() || self.is_utf8),
-                ),
-        )
-        .await
+        spawn_op!(data, {
+            let (mailbox_id, _, _) = data
+                .get_acl_mailbox(&arguments, true)
+                .await
+                .imap_ctx(&arguments.tag, trc::location!())?;
+            let identifier = arguments.identifier.unwrap_or_default();
+            let acl_account_id = data
+                .acl_account_id(&identifier)
+                .await
+                .imap_ctx(&arguments.tag, trc::location!())?;
+
+            // The owner always holds every right, other accounts can be granted
+            // each group of rights independently.
+            let permissions = if acl_account_id == mailbox_id.account_id {
+                vec![ALL_RIGHTS.to_vec()]
+            } else {
+                vec![
+                    vec![],
+                    vec![Rights::Read],
+                    vec![Rights::Lookup],
+                    vec![Rights::Write, Rights::Seen],
+                    vec![Rights::Insert],
+                    vec![Rights::Expunge, Rights::DeleteMessages],
+                    vec![Rights::CreateMailbox],
+                    vec![Rights::DeleteMailbox],
+                    vec![Rights::Post],
+                    vec![Rights::Administer],
+                ]
+            };
+
+            trc::event!(
+                Imap(trc::ImapEvent::ListRights),
+                SpanId = data.session_id,
+                MailboxName = arguments.mailbox_name.clone(),
+                AccountId = mailbox_id.account_id,
+                MailboxId = mailbox_id.mailbox_id,
+                Elapsed = op_start.elapsed()
+            );
+
+            data.write_bytes(
+                StatusResponse::completed(Command::ListRights)
+                    .with_tag(arguments.tag)
+                    .serialize(
+                        ListRightsResponse {
+                            mailbox_name: arguments.mailbox_name,
+                            identifier,
+                            permissions,
+                        }
+                        .into_bytes(is_utf8),
+                    ),
+            )
+            .await
+        })
     }
 
     pub fn assert_has_permission(&self, permission: Permission) -> trc::Result<bool> {
@@ -448,6 +438,57 @@ impl<T: SessionStream> Session<T> {
 }
 
 impl<T: SessionStream> SessionData<T> {
+    // Identifiers are account names or any of their addresses, names without a
+    // domain belong to the default domain. Accounts from external directories
+    // that never logged in are provisioned on first use.
+    async fn acl_account_id(&self, identifier: &str) -> trc::Result<u32> {
+        let address = if identifier.contains('@') {
+            sanitize_email(identifier)
+        } else {
+            sanitize_email(&format!(
+                "{identifier}@{}",
+                self.server.core.email.default_domain_name
+            ))
+        };
+        let mut account_id = None;
+
+        if let Some(address) = &address {
+            account_id = self
+                .server
+                .account_id_from_email(address, false)
+                .await
+                .caused_by(trc::location!())?;
+
+            if account_id.is_none()
+                && let Some((_, domain)) = address.rsplit_once('@')
+                && let Some(domain) = self
+                    .server
+                    .domain(domain)
+                    .await
+                    .caused_by(trc::location!())?
+                && let Some(directory) = self.server.get_directory_for_cached_domain(&domain)
+                && let Recipient::Account(account) = directory
+                    .recipient(address)
+                    .await
+                    .caused_by(trc::location!())?
+            {
+                account_id = Some(
+                    Box::pin(self.server.synchronize_account(account))
+                        .await
+                        .caused_by(trc::location!())?
+                        .id,
+                );
+            }
+        }
+
+        account_id.ok_or_else(|| {
+            trc::ImapEvent::Error
+                .into_err()
+                .details("Account does not exist")
+                .caused_by(trc::location!())
+        })
+    }
+
     async fn get_acl_mailbox(
         &self,
         arguments: &Arguments,
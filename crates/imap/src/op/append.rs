@@ -10,7 +10,15 @@ use crate::{
     spawn_op,
 };
 use common::{auth::BuildAccessToken, ipc::PushNotification, network::SessionStream};
-use email::message::ingest::{EmailIngest, IngestEmail, IngestSource};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::{INBOX_ID, virtual_folder::is_virtual_mailbox},
+    message::{
+        delivery::{AppendedMessage, MailDelivery},
+        ingest::{EmailIngest, IngestEmail, IngestSource},
+    },
+    sieve::imapsieve::ImapSieveCause,
+};
 use imap_proto::{
     Command, ResponseCode, StatusResponse,
     protocol::{append::Arguments, select::HighestModSeq},
@@ -49,6 +57,13 @@ impl<T: SessionStream> Session<T> {
                 .code(ResponseCode::TryCreate)
                 .id(arguments.tag));
         };
+        if is_virtual_mailbox(mailbox.mailbox_id) {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("Messages cannot be appended to a virtual mailbox.")
+                .code(ResponseCode::Cannot)
+                .id(arguments.tag));
+        }
         let is_qresync = self.is_qresync;
 
         spawn_op!(data, {
@@ -101,34 +116,88 @@ impl<T: SessionStream> SessionData<T> {
                 .build()
         };
 
+        // Messages appended to the own Inbox may be filtered as if delivered
+        let imap = &self.server.core.imap;
+        let is_filtered = (imap.append_sieve || imap.append_spam_filter)
+            && account_id == self.account_id
+            && mailbox_id == INBOX_ID;
+
         // Append messages
+        let cache = self
+            .server
+            .get_cached_messages(account_id)
+            .await
+            .imap_ctx(&arguments.tag, trc::location!())?;
         let mut response = StatusResponse::completed(Command::Append);
         let mut created_ids = Vec::with_capacity(arguments.messages.len());
         let mut last_change_id = None;
+        let mut has_filtered = false;
         for message in arguments.messages {
-            match self
-                .server
-                .email_ingest(IngestEmail {
-                    raw_message: &message.message,
-                    message: MessageParser::new().parse(&message.message),
-                    blob_hash: None,
-                    access_token: &access_token,
-                    mailbox_ids: vec![mailbox_id],
-                    keywords: message.flags.into_iter().map(Keyword::from).collect(),
-                    received_at: message.received_at.map(|d| d as u64),
-                    source: IngestSource::Imap {
-                        train_classifier: true,
-                    },
-                    session_id: self.session_id,
-                })
-                .await
-            {
-                Ok(email) => {
-                    created_ids.push(ImapUidToId {
-                        uid: email.imap_uids[0],
-                        id: email.document_id,
-                    });
-                    last_change_id = Some(email.change_id);
+            let keywords = message
+                .flags
+                .into_iter()
+                .map(Keyword::from)
+                .collect::<Vec<_>>();
+            if !cache.accepts_keywords(&self.server.core.email, &keywords, &keywords) {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("Too many keywords.")
+                    .code(ResponseCode::Limit)
+                    .id(arguments.tag));
+            }
+            let received_at = message.received_at.map(|d| d as u64);
+            let result = if is_filtered {
+                self.server
+                    .deliver_appended_message(AppendedMessage {
+                        access_token: &access_token,
+                        raw_message: &message.message,
+                        keywords: keywords.clone(),
+                        received_at,
+                        run_sieve: imap.append_sieve,
+                        run_spam_filter: imap.append_spam_filter,
+                        session_id: self.session_id,
+                    })
+                    .await
+            } else {
+                Ok(None)
+            };
+            let result = match result {
+                Ok(Some(email)) => {
+                    has_filtered = true;
+                    Ok((email, false))
+                }
+                Ok(None) => self
+                    .server
+                    .email_ingest(IngestEmail {
+                        raw_message: &message.message,
+                        message: MessageParser::new().parse(&message.message),
+                        blob_hash: None,
+                        access_token: &access_token,
+                        mailbox_ids: vec![mailbox_id],
+                        keywords,
+                        received_at,
+                        source: IngestSource::Imap {
+                            train_classifier: true,
+                        },
+                        session_id: self.session_id,
+                    })
+                    .await
+                    .map(|email| (email, true)),
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok((email, is_appended)) => {
+                    // Filtered messages may have been filed elsewhere or discarded
+                    if is_appended {
+                        created_ids.push(ImapUidToId {
+                            uid: email.imap_uids[0],
+                            id: email.document_id,
+                        });
+                    }
+                    if email.change_id != u64::MAX {
+                        last_change_id = Some(email.change_id);
+                    }
                 }
                 Err(err) => {
                     return Err(
@@ -147,6 +216,14 @@ impl<T: SessionStream> SessionData<T> {
             }
         }
 
+        // Run IMAPSIEVE scripts
+        self.imapsieve_run(
+            &mailbox,
+            ImapSieveCause::Append,
+            created_ids.iter().map(|id| (id.id, vec![])).collect(),
+        )
+        .await;
+
         // Broadcast changes
         if let Some(change_id) = last_change_id {
             self.server
@@ -173,7 +250,9 @@ impl<T: SessionStream> SessionData<T> {
             Elapsed = op_start.elapsed()
         );
 
-        if !created_ids.is_empty() {
+        // UIDs are not reported when filtering was applied, the messages
+        // are picked up by the next mailbox synchronization instead.
+        if !created_ids.is_empty() && !has_filtered {
             let uids = created_ids.iter().map(|id| id.uid).collect();
             match selected_mailbox {
                 Some(selected_mailbox) if selected_mailbox.id == mailbox => {
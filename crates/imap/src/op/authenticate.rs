@@ -6,17 +6,17 @@
 
 use crate::core::{Session, SessionData, State};
 use common::{
-    auth::AuthRequest,
+    auth::{AccessToken, AuthRequest},
     network::{SessionStream, limiter::LimiterResult},
 };
 use directory::Credentials;
 use imap_proto::{
     Command, ResponseCode, StatusResponse,
-    protocol::{authenticate::Mechanism, capability::Capability},
+    protocol::authenticate::Mechanism,
     receiver::{self, Request},
 };
 use mail_parser::decoders::base64::base64_decode;
-use registry::schema::enums::Permission;
+use registry::schema::enums::{NetworkListenerProtocol, Permission};
 use std::sync::Arc;
 
 impl<T: SessionStream> Session<T> {
@@ -58,6 +58,37 @@ impl<T: SessionStream> Session<T> {
                     self.write_bytes(b"+ \r\n".to_vec()).await
                 }
             }
+            Mechanism::External => {
+                if let Some(response) = args.params.pop() {
+                    // An empty response is sent as "="
+                    let authz_id = if response != "=" {
+                        base64_decode(response.as_bytes())
+                            .and_then(|authz_id| String::from_utf8(authz_id).ok())
+                            .ok_or_else(|| {
+                                trc::AuthEvent::Error
+                                    .into_err()
+                                    .details("Failed to decode authorization identity.")
+                                    .id(args.tag.clone())
+                                    .code(ResponseCode::Parse)
+                            })?
+                    } else {
+                        String::new()
+                    };
+
+                    self.authenticate_external(authz_id, args.tag).await
+                } else {
+                    self.receiver.request = receiver::Request {
+                        tag: args.tag,
+                        command: Command::Authenticate,
+                        tokens: vec![
+                            receiver::Token::Argument(args.mechanism.into_bytes()),
+                            receiver::Token::Argument(b"=".to_vec()),
+                        ],
+                    };
+                    self.receiver.state = receiver::State::Argument { last_ch: b' ' };
+                    self.write_bytes(b"+ \r\n".to_vec()).await
+                }
+            }
             _ => Err(trc::AuthEvent::Error
                 .into_err()
                 .details("Authentication mechanism not supported.")
@@ -76,22 +107,56 @@ impl<T: SessionStream> Session<T> {
                 self.remote_addr,
             ))
             .await
-            .map_err(|err| {
-                if err.matches(trc::EventType::Auth(trc::AuthEvent::Failed)) {
-                    let auth_failures = self.state.auth_failures();
-                    if auth_failures < self.server.core.imap.max_auth_failures {
-                        self.state = State::NotAuthenticated {
-                            auth_failures: auth_failures + 1,
-                        };
-                    } else {
-                        return trc::AuthEvent::TooManyAttempts.into_err().caused_by(err);
-                    }
-                }
+            .map_err(|err| self.auth_failure(err, &tag))
+            .and_then(|token| token.assert_has_permission(Permission::ImapAuthenticate))?;
+
+        self.start_session(access_token, tag).await
+    }
 
-                err.id(tag.clone())
-            })
+    // Clients presenting a certificate issued by one of the trusted authorities
+    // of the listener are authenticated as the account owning its address.
+    pub async fn authenticate_external(
+        &mut self,
+        authz_id: String,
+        tag: String,
+    ) -> trc::Result<()> {
+        let Some(cert) = &self.tls_client else {
+            return Err(trc::AuthEvent::Error
+                .into_err()
+                .details("No client certificate was presented.")
+                .id(tag)
+                .code(ResponseCode::Cannot));
+        };
+
+        let access_token = self
+            .server
+            .authenticate_sasl_external(cert, &authz_id, self.session_id, self.remote_addr)
+            .await
+            .map_err(|err| self.auth_failure(err, &tag))
             .and_then(|token| token.assert_has_permission(Permission::ImapAuthenticate))?;
 
+        self.start_session(access_token, tag).await
+    }
+
+    fn auth_failure(&mut self, err: trc::Error, tag: &str) -> trc::Error {
+        if matches!(
+            err.as_ref(),
+            trc::EventType::Auth(trc::AuthEvent::Failed | trc::AuthEvent::ClientCertificateFailed)
+        ) {
+            let auth_failures = self.state.auth_failures();
+            if auth_failures < self.server.core.imap.max_auth_failures {
+                self.state = State::NotAuthenticated {
+                    auth_failures: auth_failures + 1,
+                };
+            } else {
+                return trc::AuthEvent::TooManyAttempts.into_err().caused_by(err);
+            }
+        }
+
+        err.id(tag.to_string())
+    }
+
+    async fn start_session(&mut self, access_token: AccessToken, tag: String) -> trc::Result<()> {
         // Enforce concurrency limits
         let in_flight = match access_token.is_imap_request_allowed() {
             LimiterResult::Allowed(in_flight) => Some(in_flight),
@@ -103,6 +168,22 @@ impl<T: SessionStream> Session<T> {
             LimiterResult::Disabled => None,
         };
 
+        // Record the device in the login history
+        self.login_entry_id = self
+            .server
+            .record_login(
+                access_token.account_id(),
+                NetworkListenerProtocol::Imap,
+                self.remote_addr,
+                self.user_agent.as_deref(),
+            )
+            .await;
+        self.server
+            .inner
+            .data
+            .sessions
+            .set_account(self.session_id, access_token.account_id());
+
         // Create session
         self.state = State::Authenticated {
             data: Arc::new(
@@ -114,10 +195,7 @@ impl<T: SessionStream> Session<T> {
         self.write_bytes(
             StatusResponse::ok("Authentication successful")
                 .with_code(ResponseCode::Capability {
-                    capabilities: Capability::all_capabilities(
-                        true,
-                        !self.is_tls && self.instance.acceptor.is_tls(),
-                    ),
+                    capabilities: self.capabilities(true),
                 })
                 .with_tag(tag)
                 .into_bytes(),
@@ -7,16 +7,22 @@
 use std::time::Instant;
 
 use crate::core::Session;
-use common::network::SessionStream;
+use common::{
+    config::mailstore::imap::ImapClientPolicy,
+    expr::{Variable, functions::ResolveVariable},
+    network::{SessionResult, SessionStream},
+};
+use compact_str::ToCompactString;
 use imap_proto::{
     Command, StatusResponse,
     protocol::{
         ImapResponse,
+        authenticate::Mechanism,
         capability::{Capability, Response},
     },
     receiver::Request,
 };
-use registry::schema::enums::Permission;
+use registry::schema::enums::{ExpressionVariable, NetworkListenerProtocol, Permission};
 
 impl<T: SessionStream> Session<T> {
     pub async fn handle_capability(&mut self, request: Request<Command>) -> trc::Result<()> {
@@ -37,10 +43,7 @@ impl<T: SessionStream> Session<T> {
                 .with_tag(request.tag)
                 .serialize(
                     Response {
-                        capabilities: Capability::all_capabilities(
-                            self.state.is_authenticated(),
-                            !self.is_tls && self.instance.acceptor.is_tls(),
-                        ),
+                        capabilities: self.capabilities(self.state.is_authenticated()),
                     }
                     .serialize(),
                 ),
@@ -48,20 +51,120 @@ impl<T: SessionStream> Session<T> {
         .await
     }
 
-    pub async fn handle_id(&mut self, request: Request<Command>) -> trc::Result<()> {
+    pub(crate) fn capabilities(&self, is_authenticated: bool) -> Vec<Capability> {
+        let mut capabilities = Capability::all_capabilities(
+            is_authenticated,
+            !self.is_tls && self.instance.acceptor.is_tls(),
+        );
+        if is_authenticated {
+            capabilities.push(Capability::ImapSieve(format!(
+                "sieve://{}",
+                self.server.core.network.server_name
+            )));
+        } else if self.tls_client.is_some() {
+            capabilities.push(Capability::Auth(Mechanism::External));
+        }
+        capabilities
+    }
+
+    pub async fn handle_id(&mut self, request: Request<Command>) -> trc::Result<SessionResult> {
         // Validate access
         self.assert_has_permission(Permission::ImapId)?;
 
         let op_start = Instant::now();
+        let arguments = request.parse_id()?;
+        let user_agent = arguments.user_agent();
+        let client_name = arguments.get("name").map(|name| name.to_string());
+        let client_version = arguments.get("version").map(|version| version.to_string());
+
+        // Clients identifying themselves after logging in update their login entry
+        if let Some(user_agent) = &user_agent
+            && self.state.is_authenticated()
+            && self.user_agent.as_ref() != Some(user_agent)
+        {
+            if let Some(item_id) = self.login_entry_id {
+                self.server
+                    .update_login_user_agent(item_id, user_agent)
+                    .await;
+            } else {
+                self.login_entry_id = self
+                    .server
+                    .record_login(
+                        self.state.session_data().account_id,
+                        NetworkListenerProtocol::Imap,
+                        self.remote_addr,
+                        Some(user_agent),
+                    )
+                    .await;
+            }
+        }
+
         trc::event!(
             Imap(trc::ImapEvent::Id),
             SpanId = self.session_id,
+            Details = user_agent.clone(),
+            Value = client_name.clone(),
+            Version = client_version.clone(),
             Elapsed = op_start.elapsed()
         );
 
+        if user_agent.is_some() {
+            self.user_agent = user_agent;
+        }
+        if client_name.is_some() {
+            self.client_name = client_name;
+            self.client_version = client_version;
+        }
+
+        // Apply the client policy
+        let policy = self
+            .server
+            .eval_if::<ImapClientPolicy, _>(
+                &self.server.core.imap.client_policy,
+                self,
+                self.session_id,
+            )
+            .await
+            .unwrap_or(ImapClientPolicy::Allow);
+        match policy {
+            ImapClientPolicy::Allow => {}
+            ImapClientPolicy::ReadOnly => {
+                if !self.is_read_only_client {
+                    trc::event!(
+                        Imap(trc::ImapEvent::ClientRestricted),
+                        SpanId = self.session_id,
+                        RemoteIp = self.remote_addr,
+                        Details = self.user_agent.clone(),
+                    );
+
+                    self.is_read_only_client = true;
+                }
+            }
+            ImapClientPolicy::Reject => {
+                trc::event!(
+                    Imap(trc::ImapEvent::ClientRejected),
+                    SpanId = self.session_id,
+                    RemoteIp = self.remote_addr,
+                    Details = self.user_agent.clone(),
+                );
+
+                let mut response =
+                    StatusResponse::bye("This client is not permitted on this server.")
+                        .into_bytes();
+                response.extend(
+                    StatusResponse::no("Client rejected by policy.")
+                        .with_tag(arguments.tag)
+                        .into_bytes(),
+                );
+                self.write_bytes(response).await?;
+
+                return Ok(SessionResult::Close);
+            }
+        }
+
         self.write_bytes(
             StatusResponse::completed(Command::Id)
-                .with_tag(request.tag)
+                .with_tag(arguments.tag)
                 .serialize(
                     concat!(
                         "* ID (\"name\" \"Stalwart\" \"version\" \"1.0.0\" \"vendor\" \"Stalwart Labs LLC\" ",
@@ -72,5 +175,28 @@ impl<T: SessionStream> Session<T> {
                 ),
         )
         .await
+        .map(|_| SessionResult::Continue)
+    }
+}
+
+impl<T: SessionStream> ResolveVariable for Session<T> {
+    fn resolve_variable(&self, variable: ExpressionVariable) -> Variable<'_> {
+        match variable {
+            ExpressionVariable::Listener => self.instance.id.as_str().into(),
+            ExpressionVariable::RemoteIp => self.remote_addr.to_compact_string().into(),
+            ExpressionVariable::Protocol => self.instance.protocol.as_str().into(),
+            ExpressionVariable::IsTls => self.is_tls.into(),
+            ExpressionVariable::ClientName => {
+                self.client_name.as_deref().unwrap_or_default().into()
+            }
+            ExpressionVariable::ClientVersion => {
+                self.client_version.as_deref().unwrap_or_default().into()
+            }
+            _ => Variable::default(),
+        }
+    }
+
+    fn resolve_global(&self, _: &str) -> Variable<'_> {
+        Variable::Integer(0)
     }
 }
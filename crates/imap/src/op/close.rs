@@ -8,6 +8,7 @@ use std::time::Instant;
 
 use crate::core::{Session, State};
 use common::network::SessionStream;
+use email::mailbox::virtual_folder::is_virtual_mailbox;
 use imap_proto::{Command, StatusResponse, receiver::Request};
 use trc::AddContext;
 
@@ -16,7 +17,7 @@ impl<T: SessionStream> Session<T> {
         let op_start = Instant::now();
         let (data, mailbox) = self.state.select_data();
 
-        if mailbox.is_select {
+        if mailbox.is_select && !is_virtual_mailbox(mailbox.id.mailbox_id) {
             data.expunge(mailbox.clone(), None, op_start)
                 .await
                 .caused_by(trc::location!())?;
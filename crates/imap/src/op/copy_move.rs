@@ -12,18 +12,19 @@ use crate::{
 use common::{ipc::PushNotification, network::SessionStream, storage::index::ObjectIndexBuilder};
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess},
-    mailbox::{JUNK_ID, TRASH_ID, UidMailbox},
+    mailbox::{JUNK_ID, TRASH_ID, UidMailbox, virtual_folder::is_virtual_mailbox},
     message::{
         copy::{CopyMessageError, EmailCopy},
         ingest::EmailIngest,
         metadata::MessageData,
     },
+    sieve::imapsieve::ImapSieveCause,
 };
 use imap_proto::{
     Command, ResponseCode, ResponseType, StatusResponse, protocol::copy_move::Arguments,
     receiver::Request,
 };
-use registry::schema::enums::Permission;
+use registry::schema::enums::{MessageAuditEvent, Permission};
 use std::{sync::Arc, time::Instant};
 use store::{
     ValueKey,
@@ -85,6 +86,21 @@ impl<T: SessionStream> Session<T> {
                     .id(arguments.tag));
             }
 
+            // Virtual mailboxes cannot be modified
+            if is_virtual_mailbox(dest_mailbox.mailbox_id) {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("Messages cannot be added to a virtual mailbox.")
+                    .code(ResponseCode::Cannot)
+                    .id(arguments.tag));
+            } else if is_move && is_virtual_mailbox(src_mailbox.id.mailbox_id) {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("Messages cannot be moved out of a virtual mailbox.")
+                    .code(ResponseCode::Cannot)
+                    .id(arguments.tag));
+            }
+
             data.copy_move(
                 arguments,
                 src_mailbox,
@@ -195,6 +211,8 @@ impl<T: SessionStream> SessionData<T> {
         });
         let mut did_move = false;
         let mut copied_ids = Vec::with_capacity(ids.len());
+        let mut sieve_ids = Vec::new();
+        let mut moved_ids = Vec::new();
 
         if src_mailbox.id.account_id == dest_mailbox.account_id {
             // Mailboxes are in the same account
@@ -220,11 +238,12 @@ impl<T: SessionStream> SessionData<T> {
                     .imap_ctx(&arguments.tag, trc::location!())?;
 
                 // Make sure the message still belongs to this mailbox
-                if !data
-                    .inner
-                    .mailboxes
-                    .iter()
-                    .any(|mailbox| mailbox.mailbox_id == src_mailbox.id.mailbox_id)
+                if !is_virtual_mailbox(src_mailbox.id.mailbox_id)
+                    && !data
+                        .inner
+                        .mailboxes
+                        .iter()
+                        .any(|mailbox| mailbox.mailbox_id == src_mailbox.id.mailbox_id)
                 {
                     continue;
                 }
@@ -256,6 +275,7 @@ impl<T: SessionStream> SessionData<T> {
                                 (src_mailbox.id.mailbox_id, imap_id.uid),
                             )
                             .commit_point();
+                        moved_ids.push(id);
                         did_move = true;
                     }
 
@@ -330,9 +350,11 @@ impl<T: SessionStream> SessionData<T> {
                 }
 
                 batch.commit_point();
+                sieve_ids.push((id, vec![]));
 
                 // Update changelog
                 if is_move {
+                    moved_ids.push(id);
                     did_move = true;
                 }
             }
@@ -373,6 +395,7 @@ impl<T: SessionStream> SessionData<T> {
                 {
                     Ok(email) => {
                         dest_change_id = email.change_id.into();
+                        sieve_ids.push((email.document_id, vec![]));
                         if let Some(assigned_uid) = email.imap_uids.first() {
                             debug_assert!(*assigned_uid > 0);
                             copied_ids.push((imap_id.uid, *assigned_uid));
@@ -413,6 +436,7 @@ impl<T: SessionStream> SessionData<T> {
                     .await
                     .imap_ctx(&arguments.tag, trc::location!())?;
 
+                moved_ids.extend(destroy_ids);
                 did_move = true;
             }
 
@@ -430,6 +454,30 @@ impl<T: SessionStream> SessionData<T> {
             }
         }
 
+        // Record audit trail
+        if !moved_ids.is_empty() && self.server.is_audit_log_enabled() {
+            self.server
+                .audit_messages(
+                    src_mailbox.id.account_id,
+                    MessageAuditEvent::Moved,
+                    moved_ids,
+                    self.account_id.into(),
+                    format!(
+                        "{}/{} -> {}/{}",
+                        src_mailbox.id.account_id,
+                        src_mailbox.id.mailbox_id,
+                        dest_mailbox.account_id,
+                        dest_mailbox.mailbox_id
+                    )
+                    .into(),
+                )
+                .await;
+        }
+
+        // Run IMAPSIEVE scripts
+        self.imapsieve_run(&dest_mailbox, ImapSieveCause::Copy, sieve_ids)
+            .await;
+
         // Map copied JMAP Ids to IMAP UIDs in the destination folder.
         if copied_ids.is_empty() {
             return if response.rtype != ResponseType::Ok {
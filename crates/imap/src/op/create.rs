@@ -10,7 +10,10 @@ use crate::{
     spawn_op,
 };
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
-use email::cache::{MessageCacheFetch, mailbox::MailboxCacheAccess};
+use email::{
+    cache::{MessageCacheFetch, mailbox::MailboxCacheAccess},
+    mailbox::virtual_folder::is_virtual_mailbox,
+};
 use imap_proto::{
     Command, ResponseCode, StatusResponse,
     protocol::{create::Arguments, list::Attribute},
@@ -82,6 +85,9 @@ impl<T: SessionStream> SessionData<T> {
         let mut batch = BatchBuilder::new();
         for (pos, &path_item) in params.path.iter().enumerate() {
             let mut mailbox = email::mailbox::Mailbox::new(path_item).with_parent_id(parent_id);
+            if self.server.core.email.subscribe_on_create.imap {
+                mailbox.add_subscriber(self.account_id);
+            }
 
             if pos == params.path.len() - 1
                 && let Some(mailbox_role) = arguments.mailbox_role.map(attr_to_role)
@@ -177,52 +183,51 @@ impl<T: SessionStream> SessionData<T> {
         let mut parent_mailbox_name = None;
         let (account_id, path) = {
             let mailboxes = self.mailboxes.lock();
-            let (account, full_path, prefix) =
-                if path.first() == Some(&self.server.core.email.shared_folder.as_str()) {
-                    // Shared Folders/<username>/<folder>
-                    if path.len() < 3 {
-                        return Err(trc::ImapEvent::Error
-                            .into_err()
-                            .details("Mailboxes under root shared folders are not allowed.")
-                            .code(ResponseCode::Cannot));
-                    }
-
-                    // Build path
-                    let root = &mut path[2];
-                    if root.eq_ignore_ascii_case("INBOX") {
-                        *root = "INBOX";
-                    }
-                    let full_path = path.join("/");
-                    let prefix = Some(format!("{}/{}", path[0], path[1]));
+            let (account, full_path, prefix) = if path.first() == Some(&self.shared_folder()) {
+                // Shared Folders/<username>/<folder>
+                if path.len() < 3 {
+                    return Err(trc::ImapEvent::Error
+                        .into_err()
+                        .details("Mailboxes under root shared folders are not allowed.")
+                        .code(ResponseCode::Cannot));
+                }
 
-                    // Locate account
-                    if let Some(account) = mailboxes
-                        .iter()
-                        .skip(1)
-                        .find(|account| account.prefix == prefix)
-                    {
-                        (account, full_path, prefix)
-                    } else {
-                        #[allow(clippy::unnecessary_literal_unwrap)]
-                        return Err(trc::ImapEvent::Error.into_err().details(format!(
-                            "Shared account '{}' not found.",
-                            prefix.unwrap_or_default()
-                        )));
-                    }
-                } else if let Some(account) = mailboxes.first() {
-                    let root = &mut path[0];
-                    if root.eq_ignore_ascii_case("INBOX") {
-                        *root = "INBOX";
-                    }
+                // Build path
+                let root = &mut path[2];
+                if root.eq_ignore_ascii_case("INBOX") {
+                    *root = "INBOX";
+                }
+                let full_path = path.join("/");
+                let prefix = Some(format!("{}/{}", path[0], path[1]));
 
-                    (account, path.join("/"), None)
+                // Locate account
+                if let Some(account) = mailboxes
+                    .iter()
+                    .skip(1)
+                    .find(|account| account.prefix == prefix)
+                {
+                    (account, full_path, prefix)
                 } else {
-                    return Err(trc::ImapEvent::Error
-                        .into_err()
-                        .details("Internal server error.")
-                        .caused_by(trc::location!())
-                        .code(ResponseCode::ContactAdmin));
-                };
+                    #[allow(clippy::unnecessary_literal_unwrap)]
+                    return Err(trc::ImapEvent::Error.into_err().details(format!(
+                        "Shared account '{}' not found.",
+                        prefix.unwrap_or_default()
+                    )));
+                }
+            } else if let Some(account) = mailboxes.first() {
+                let root = &mut path[0];
+                if root.eq_ignore_ascii_case("INBOX") {
+                    *root = "INBOX";
+                }
+
+                (account, path.join("/"), None)
+            } else {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("Internal server error.")
+                    .caused_by(trc::location!())
+                    .code(ResponseCode::ContactAdmin));
+            };
 
             // Locate parent mailbox
             if account.mailbox_names.contains_key(&full_path) {
@@ -261,7 +266,12 @@ impl<T: SessionStream> SessionData<T> {
 
         // Validate ACLs
         if let Some(parent_mailbox_id) = parent_mailbox_id {
-            if !self
+            if is_virtual_mailbox(parent_mailbox_id) {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("Sub mailboxes cannot be created under a virtual mailbox.")
+                    .code(ResponseCode::Cannot));
+            } else if !self
                 .check_mailbox_acl(account_id, parent_mailbox_id, Acl::CreateChild)
                 .await?
             {
@@ -338,6 +348,8 @@ fn attr_to_role(attr: Attribute) -> SpecialUse {
         Attribute::Memos => SpecialUse::Memos,
         Attribute::Scheduled => SpecialUse::Scheduled,
         Attribute::Snoozed => SpecialUse::Snoozed,
+        Attribute::All => SpecialUse::All,
+        Attribute::Flagged => SpecialUse::Flagged,
         _ => SpecialUse::None,
     }
 }
@@ -10,7 +10,10 @@ use crate::{
     spawn_op,
 };
 use common::network::SessionStream;
-use email::mailbox::destroy::{MailboxDestroy, MailboxDestroyError};
+use email::mailbox::{
+    destroy::{MailboxDestroy, MailboxDestroyError},
+    virtual_folder::is_virtual_mailbox,
+};
 use imap_proto::{
     Command, ResponseCode, StatusResponse, protocol::delete::Arguments, receiver::Request,
 };
@@ -65,6 +68,13 @@ impl<T: SessionStream> SessionData<T> {
                     .code(ResponseCode::TryCreate)
                     .id(arguments.tag));
             };
+        if is_virtual_mailbox(mailbox_id) {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("You cannot delete virtual mailboxes")
+                .code(ResponseCode::NoPerm)
+                .id(arguments.tag));
+        }
 
         // Delete message
         let access_token = self
@@ -90,6 +100,10 @@ impl<T: SessionStream> SessionData<T> {
                     (ResponseCode::HasChildren, "Mailbox has children")
                 }
                 MailboxDestroyError::HasEmails => (ResponseCode::HasChildren, "Mailbox has emails"),
+                MailboxDestroyError::HasSharedEmails => (
+                    ResponseCode::NoPerm,
+                    "Mailbox has emails owned by another account",
+                ),
                 MailboxDestroyError::NotFound => (ResponseCode::NonExistent, "Mailbox not found"),
                 MailboxDestroyError::AssertionFailed => (
                     ResponseCode::Cannot,
@@ -5,11 +5,12 @@
  */
 
 use super::{ImapContext, ToModSeq};
-use crate::core::{ImapId, SavedSearch, SelectedMailbox, Session, SessionData};
+use crate::core::{ImapId, SelectedMailbox, Session, SessionData};
 use ahash::AHashMap;
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::virtual_folder::is_virtual_mailbox,
     message::metadata::MessageData,
 };
 use imap_proto::{
@@ -18,7 +19,7 @@ use imap_proto::{
     receiver::{Request, Token},
 };
 use registry::schema::{
-    enums::{IndexDocumentType, Permission},
+    enums::{IndexDocumentType, MessageAuditEvent, Permission},
     structs::{Task, TaskIndexDocument, TaskStatus},
 };
 use std::{sync::Arc, time::Instant};
@@ -42,6 +43,15 @@ impl<T: SessionStream> Session<T> {
         let op_start = Instant::now();
         let (data, mailbox) = self.state.select_data();
 
+        // Virtual mailboxes are read-only views
+        if is_virtual_mailbox(mailbox.id.mailbox_id) {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("Messages cannot be expunged from a virtual mailbox.")
+                .code(ResponseCode::Cannot)
+                .id(request.tag));
+        }
+
         // Validate ACL
         if !data
             .check_mailbox_acl(
@@ -88,14 +98,14 @@ impl<T: SessionStream> Session<T> {
             .await
             .imap_ctx(&request.tag, trc::location!())?;
 
-        // Clear saved searches
-        *mailbox.saved_search.lock() = SavedSearch::None;
-
         // Synchronize messages
         let modseq = data
             .write_mailbox_changes(&mailbox, self.is_qresync)
             .await
             .imap_ctx(&request.tag, trc::location!())?;
+
+        // Drop expunged messages from the saved search
+        mailbox.prune_saved_search().await;
         let mut response =
             StatusResponse::completed(Command::Expunge(is_uid)).with_tag(request.tag);
 
@@ -154,6 +164,19 @@ impl<T: SessionStream> SessionData<T> {
                 .await
                 .caused_by(trc::location!())?;
             self.server.notify_task_queue();
+
+            // Record audit trail
+            if self.server.is_audit_log_enabled() {
+                self.server
+                    .audit_messages(
+                        account_id,
+                        MessageAuditEvent::Expunged,
+                        deleted_ids,
+                        self.account_id.into(),
+                        self.remote_addr.to_string().into(),
+                    )
+                    .await;
+            }
         }
 
         Ok(())
@@ -13,10 +13,13 @@ use ahash::AHashMap;
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess},
-    message::metadata::{
-        ArchivedMessageMetadata, ArchivedMessageMetadataContents, ArchivedMetadataHeaderValue,
-        ArchivedMetadataPartType, DecodedParts, MESSAGE_RECEIVED_MASK, MessageData,
-        MessageMetadata, MetadataHeaderName, PART_ENCODING_PROBLEM,
+    message::{
+        index::PREVIEW_LENGTH,
+        metadata::{
+            ArchivedMessageMetadata, ArchivedMessageMetadataContents, ArchivedMetadataHeaderValue,
+            ArchivedMetadataPartType, DecodedParts, MESSAGE_RECEIVED_MASK, MessageData,
+            MessageMetadata, MetadataHeaderName, PART_ENCODING_PROBLEM,
+        },
     },
 };
 use imap_proto::{
@@ -32,7 +35,8 @@ use imap_proto::{
     },
     receiver::Request,
 };
-use registry::schema::enums::Permission;
+use nlp::language::search_snippet::generate_preview;
+use registry::schema::enums::{MessageAuditEvent, Permission};
 use std::{borrow::Cow, sync::Arc, time::Instant};
 use store::{
     ValueKey,
@@ -43,6 +47,7 @@ use store::{
     rkyv::rend::u16_le,
     write::BatchBuilder,
 };
+use trc::AddContext;
 use types::{
     acl::Acl,
     collection::{Collection, SyncCollection, VanishedCollection},
@@ -252,9 +257,20 @@ impl<T: SessionStream> SessionData<T> {
         // Build properties list
         let mut set_seen_flags = false;
         let mut needs_blobs = false;
+        let mut needs_partial_blobs = false;
 
         for attribute in &arguments.attributes {
             match attribute {
+                Attribute::BodySection {
+                    peek,
+                    sections,
+                    partial: Some(_),
+                } if sections.is_empty() => {
+                    if mailbox.is_select && !*peek {
+                        set_seen_flags = true;
+                    }
+                    needs_partial_blobs = true;
+                }
                 Attribute::BodySection { sections, .. }
                     if sections.first().is_some_and(|s| {
                         matches!(s, Section::Header | Section::HeaderFields { .. })
@@ -285,6 +301,10 @@ impl<T: SessionStream> SessionData<T> {
             }
         }
 
+        // Partial body fetches only read the requested range from the blob store
+        // unless other attributes require the full message
+        needs_partial_blobs &= !needs_blobs;
+
         if set_seen_flags
             && !self
                 .check_mailbox_acl(
@@ -322,6 +342,7 @@ impl<T: SessionStream> SessionData<T> {
             .get_cached_messages(account_id)
             .await
             .imap_ctx(&arguments.tag, trc::location!())?;
+        let mut read_ids = Vec::new();
 
         for (seqnum, uid, id) in ids {
             // Obtain attributes and keywords
@@ -387,6 +408,30 @@ impl<T: SessionStream> SessionData<T> {
                 }
             }
 
+            let mut partial_bodies = Vec::new();
+            if needs_partial_blobs {
+                for attribute in &arguments.attributes {
+                    if let Attribute::BodySection {
+                        sections,
+                        partial: Some(partial),
+                        ..
+                    } = attribute
+                        && sections.is_empty()
+                    {
+                        if let Some(contents) = self
+                            .fetch_partial_body(metadata, *partial)
+                            .await
+                            .imap_ctx(&arguments.tag, trc::location!())?
+                        {
+                            partial_bodies.push(contents);
+                        } else {
+                            break;
+                        }
+                    }
+                }
+            }
+            let mut partial_bodies = partial_bodies.into_iter();
+
             let message = &metadata.contents[0];
             let decoded = metadata.decode_contents(raw_message.clone());
 
@@ -418,11 +463,12 @@ impl<T: SessionStream> SessionData<T> {
                     }
                     Attribute::Preview { .. } => {
                         items.push(DataItem::Preview {
-                            contents: if !metadata.preview.is_empty() {
-                                Some(metadata.preview.as_bytes().into())
-                            } else {
-                                None
-                            },
+                            contents: Some(generate_preview(
+                                metadata.preview.as_str(),
+                                PREVIEW_LENGTH,
+                            ))
+                            .filter(|preview| !preview.is_empty())
+                            .map(|preview| preview.into_bytes().into()),
                         });
                     }
                     Attribute::Rfc822Size => {
@@ -465,8 +511,13 @@ impl<T: SessionStream> SessionData<T> {
                     Attribute::BodySection {
                         sections, partial, ..
                     } => {
-                        if let Some(contents) = metadata.body_section(&decoded, sections, *partial)
-                        {
+                        let contents = if needs_partial_blobs && sections.is_empty() {
+                            partial_bodies.next().map(Cow::Owned)
+                        } else {
+                            metadata.body_section(&decoded, sections, *partial)
+                        };
+
+                        if let Some(contents) = contents {
                             items.push(DataItem::BodySection {
                                 sections: sections.to_vec(),
                                 origin_octet: partial.map(|(start, _)| start),
@@ -544,7 +595,13 @@ impl<T: SessionStream> SessionData<T> {
             // Serialize fetch item
             let mut buf = Vec::with_capacity(128);
             FetchItem { id: seqnum, items }.serialize(&mut buf);
+            if needs_blobs || needs_partial_blobs {
+                self.throttle_bandwidth(buf.len()).await;
+            }
             self.write_bytes(buf).await?;
+            if needs_blobs || needs_partial_blobs {
+                read_ids.push(id);
+            }
 
             // Add to set flags
             if set_seen_flag
@@ -599,6 +656,19 @@ impl<T: SessionStream> SessionData<T> {
             }
         }
 
+        // Record audit trail
+        if !read_ids.is_empty() && self.server.is_audit_log_enabled() {
+            self.server
+                .audit_messages(
+                    account_id,
+                    MessageAuditEvent::Read,
+                    read_ids,
+                    self.account_id.into(),
+                    self.remote_addr.to_string().into(),
+                )
+                .await;
+        }
+
         trc::event!(
             Imap(trc::ImapEvent::Fetch),
             SpanId = self.session_id,
@@ -625,6 +695,53 @@ impl<T: SessionStream> SessionData<T> {
 
         Ok(StatusResponse::completed(Command::Fetch(is_uid)).with_tag(arguments.tag))
     }
+
+    async fn fetch_partial_body(
+        &self,
+        metadata: &ArchivedMessageMetadata,
+        (start, count): (u32, u32),
+    ) -> trc::Result<Option<Vec<u8>>> {
+        // The raw message is made of the stored headers followed by the blob body
+        let headers = metadata.raw_headers.as_ref();
+        let start = start as usize;
+        let end = std::cmp::min(
+            start.saturating_add(count as usize),
+            metadata.root_part().header_to_end().end,
+        );
+        if start >= end {
+            return Ok(Some(vec![]));
+        }
+
+        let mut contents = Vec::with_capacity(end - start);
+        if let Some(bytes) = headers.get(start..std::cmp::min(end, headers.len())) {
+            contents.extend_from_slice(bytes);
+        }
+        if end > headers.len() {
+            let body_offset = metadata.blob_body_offset.to_native() as usize;
+            let Some(bytes) = self
+                .server
+                .blob_store()
+                .get_blob(
+                    metadata.blob_hash.0.as_slice(),
+                    body_offset + start.saturating_sub(headers.len())
+                        ..body_offset + end - headers.len(),
+                )
+                .await
+                .caused_by(trc::location!())?
+            else {
+                trc::event!(
+                    Store(trc::StoreEvent::NotFound),
+                    BlobId = metadata.blob_hash.0.as_slice(),
+                    Details = "Blob not found.",
+                    CausedBy = trc::location!(),
+                );
+                return Ok(None);
+            };
+            contents.extend_from_slice(&bytes);
+        }
+
+        Ok(Some(contents))
+    }
 }
 
 #[allow(clippy::result_unit_err)]
@@ -96,26 +96,19 @@ impl<T: SessionStream> Session<T> {
                     if let Some(push_notification) = push_notification {
                         let mut has_mailbox_changes = false;
                         let mut has_email_changes = false;
+                        classify_notification(
+                            push_notification,
+                            &mut has_mailbox_changes,
+                            &mut has_email_changes,
+                        );
 
-                        match push_notification {
-                            PushNotification::StateChange(state_change) => {
-                                for type_state in state_change.types {
-                                    match type_state {
-                                        DataType::Email | DataType::EmailDelivery => {
-                                            has_email_changes = true;
-                                        }
-                                        DataType::Mailbox => {
-                                            has_mailbox_changes = true;
-                                        }
-                                        _ => {}
-                                    }
-                                }
-                            },
-                            PushNotification::EmailPush(_) => {
-                                has_email_changes = true;
-                                has_mailbox_changes = true;
-                            },
-                            PushNotification::CalendarAlert(_) => (),
+                        // Coalesce queued notifications, including bursts relayed by other nodes
+                        while let Ok(push_notification) = push_rx.try_recv() {
+                            classify_notification(
+                            push_notification,
+                            &mut has_mailbox_changes,
+                            &mut has_email_changes,
+                        );
                         }
 
                         if has_mailbox_changes || has_email_changes {
@@ -131,6 +124,33 @@ impl<T: SessionStream> Session<T> {
     }
 }
 
+fn classify_notification(
+    push_notification: PushNotification,
+    has_mailbox_changes: &mut bool,
+    has_email_changes: &mut bool,
+) {
+    match push_notification {
+        PushNotification::StateChange(state_change) => {
+            for type_state in state_change.types {
+                match type_state {
+                    DataType::Email | DataType::EmailDelivery => {
+                        *has_email_changes = true;
+                    }
+                    DataType::Mailbox => {
+                        *has_mailbox_changes = true;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        PushNotification::EmailPush(_) => {
+            *has_email_changes = true;
+            *has_mailbox_changes = true;
+        }
+        PushNotification::CalendarAlert(_) => (),
+    }
+}
+
 impl<T: SessionStream> SessionData<T> {
     pub async fn write_changes(
         &self,
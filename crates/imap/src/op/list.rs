@@ -181,11 +181,9 @@ impl<T: SessionStream> SessionData<T> {
         for account in self.mailboxes.lock().iter() {
             if let Some(prefix) = &account.prefix {
                 if !added_shared_folder {
-                    if !filter_subscribed
-                        && matches_pattern(&patterns, &self.server.core.email.shared_folder)
-                    {
+                    if !filter_subscribed && matches_pattern(&patterns, self.shared_folder()) {
                         list_items.push(ListItem {
-                            mailbox_name: self.server.core.email.shared_folder.as_str().into(),
+                            mailbox_name: self.shared_folder().into(),
                             attributes: if include_children {
                                 vec![Attribute::HasChildren, Attribute::NoSelect]
                             } else {
@@ -227,19 +225,25 @@ impl<T: SessionStream> SessionData<T> {
                         );
                         continue;
                     };
+                    // Children report CHILDINFO only when they match every selection option
                     let mut has_recursive_match = false;
                     if recursive_match {
                         let prefix = format!("{}/", mailbox_name);
                         for (mailbox_name, mailbox_id) in &account.mailbox_names {
                             if mailbox_name.starts_with(&prefix)
-                                && account.mailbox_state.get(mailbox_id).unwrap().is_subscribed
+                                && account.mailbox_state.get(mailbox_id).is_some_and(|child| {
+                                    child.is_subscribed
+                                        && (!filter_special_use || child.special_use.is_some())
+                                })
                             {
                                 has_recursive_match = true;
                                 break;
                             }
                         }
                     }
-                    if !filter_subscribed || mailbox.is_subscribed || has_recursive_match {
+                    let is_selected = (!filter_subscribed || mailbox.is_subscribed)
+                        && (!filter_special_use || mailbox.special_use.is_some());
+                    if is_selected || has_recursive_match {
                         let mut attributes = Vec::with_capacity(2);
                         if include_children {
                             attributes.push(if mailbox.has_children {
@@ -251,12 +255,8 @@ impl<T: SessionStream> SessionData<T> {
                         if include_subscribed && mailbox.is_subscribed {
                             attributes.push(Attribute::Subscribed);
                         }
-                        if include_special_use {
-                            if let Some(special_use) = &mailbox.special_use {
-                                attributes.push(*special_use);
-                            } else if filter_special_use {
-                                continue;
-                            }
+                        if include_special_use && let Some(special_use) = &mailbox.special_use {
+                            attributes.push(*special_use);
                         }
                         list_items.push(ListItem {
                             mailbox_name: mailbox_name.clone(),
@@ -272,20 +272,31 @@ impl<T: SessionStream> SessionData<T> {
             }
         }
 
-        // Add status response
+        // Add status response, skipping mailboxes that cannot be selected (RFC 5819)
         let mut status_items = Vec::new();
         if let Some(include_status) = include_status {
             for list_item in &list_items {
+                if list_item
+                    .attributes
+                    .iter()
+                    .any(|attr| matches!(attr, Attribute::NoSelect | Attribute::NonExistent))
+                {
+                    continue;
+                }
+
                 match self
                     .status(list_item.mailbox_name.clone(), include_status)
                     .await
-                    .imap_ctx(&tag, trc::location!())
                 {
                     Ok(status_item) => {
                         status_items.push(status_item);
                     }
                     Err(err) => {
-                        self.write_error(err).await?;
+                        trc::error!(
+                            err.span_id(self.session_id)
+                                .ctx(trc::Key::MailboxName, list_item.mailbox_name.clone())
+                                .details("Failed to obtain mailbox status")
+                        );
                     }
                 }
             }
@@ -0,0 +1,404 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    core::{MailboxId, Session, SessionData},
+    op::ImapContext,
+    spawn_op,
+};
+use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
+use email::{
+    mailbox::UidMailbox,
+    message::{ingest::EmailIngest, metadata::MessageData},
+    sieve::imapsieve::{IMAPSIEVE_SCRIPT_ENTRY, ImapSieveCause, ImapSieveRun},
+};
+use imap_proto::{
+    Command, ResponseCode, StatusResponse,
+    protocol::metadata::{Depth, GetArguments, Response, SetArguments},
+    receiver::Request,
+};
+use registry::schema::enums::Permission;
+use std::time::Instant;
+use store::{roaring::RoaringBitmap, write::BatchBuilder};
+use trc::AddContext;
+use types::{
+    acl::Acl,
+    collection::{Collection, VanishedCollection},
+    field::{MailboxField, PrincipalField, SieveField},
+    keyword::Keyword,
+};
+
+impl<T: SessionStream> Session<T> {
+    pub async fn handle_get_metadata(&mut self, request: Request<Command>) -> trc::Result<()> {
+        // Validate access
+        self.assert_has_permission(Permission::ImapGetMetadata)?;
+
+        let data = self.state.session_data();
+        let is_utf8 = self.is_utf8;
+
+        spawn_op!(data, {
+            match request.parse_get_metadata(is_utf8) {
+                Ok(arguments) => match data.get_metadata(arguments, is_utf8).await {
+                    Ok(response) => {
+                        data.write_bytes(response).await?;
+                    }
+                    Err(error) => {
+                        data.write_error(error).await?;
+                    }
+                },
+                Err(err) => data.write_error(err).await?,
+            }
+
+            Ok(())
+        })
+    }
+
+    pub async fn handle_set_metadata(&mut self, request: Request<Command>) -> trc::Result<()> {
+        // Validate access
+        self.assert_has_permission(Permission::ImapSetMetadata)?;
+
+        let data = self.state.session_data();
+        let is_utf8 = self.is_utf8;
+
+        spawn_op!(data, {
+            match request.parse_set_metadata(is_utf8) {
+                Ok(arguments) => match data.set_metadata(arguments).await {
+                    Ok(response) => {
+                        data.write_bytes(response).await?;
+                    }
+                    Err(error) => {
+                        data.write_error(error).await?;
+                    }
+                },
+                Err(err) => data.write_error(err).await?,
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl<T: SessionStream> SessionData<T> {
+    pub async fn get_metadata(
+        &self,
+        arguments: GetArguments,
+        is_utf8: bool,
+    ) -> trc::Result<Vec<u8>> {
+        let op_start = Instant::now();
+
+        // Validate mailbox
+        let (account_id, mailbox_id) = self
+            .metadata_target(&arguments.tag, &arguments.mailbox_name, Acl::Read)
+            .await?;
+
+        // Obtain entries
+        let mut response = Response {
+            mailbox_name: arguments.mailbox_name,
+            entries: Vec::with_capacity(arguments.entries.len()),
+        };
+        for requested in &arguments.entries {
+            if arguments.depth.matches(requested, IMAPSIEVE_SCRIPT_ENTRY) {
+                if !response
+                    .entries
+                    .iter()
+                    .any(|(entry, _)| entry == IMAPSIEVE_SCRIPT_ENTRY)
+                {
+                    let value = self
+                        .server
+                        .imapsieve_script_name(account_id, mailbox_id)
+                        .await
+                        .imap_ctx(&arguments.tag, trc::location!())?
+                        .filter(|value| arguments.max_size.is_none_or(|max| value.len() <= max));
+                    if value.is_some() || requested == IMAPSIEVE_SCRIPT_ENTRY {
+                        response
+                            .entries
+                            .push((IMAPSIEVE_SCRIPT_ENTRY.to_string(), value));
+                    }
+                }
+            } else if arguments.depth == Depth::Zero {
+                // Unsupported entries are reported as non-existent
+                response.entries.push((requested.clone(), None));
+            }
+        }
+
+        trc::event!(
+            Imap(trc::ImapEvent::GetMetadata),
+            SpanId = self.session_id,
+            MailboxName = response.mailbox_name.clone(),
+            Details = arguments
+                .entries
+                .iter()
+                .map(|entry| trc::Value::from(entry.clone()))
+                .collect::<Vec<_>>(),
+            Elapsed = op_start.elapsed()
+        );
+
+        let mut buf = Vec::with_capacity(64);
+        response.serialize(&mut buf, is_utf8);
+        Ok(StatusResponse::completed(Command::GetMetadata)
+            .with_tag(arguments.tag)
+            .serialize(buf))
+    }
+
+    pub async fn set_metadata(&self, arguments: SetArguments) -> trc::Result<Vec<u8>> {
+        let op_start = Instant::now();
+
+        // Validate mailbox
+        let (account_id, mailbox_id) = self
+            .metadata_target(&arguments.tag, &arguments.mailbox_name, Acl::Modify)
+            .await?;
+
+        // Validate entries
+        let mut batch = BatchBuilder::new();
+        batch.with_account_id(account_id);
+        if let Some(mailbox_id) = mailbox_id {
+            batch
+                .with_collection(Collection::Mailbox)
+                .with_document(mailbox_id);
+        } else {
+            batch
+                .with_collection(Collection::Principal)
+                .with_document(0);
+        }
+        for (entry, value) in &arguments.entries {
+            if entry != IMAPSIEVE_SCRIPT_ENTRY {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details(format!("Metadata entry {entry:?} is not supported."))
+                    .code(ResponseCode::Cannot)
+                    .id(arguments.tag.clone()));
+            }
+
+            match value.as_deref().filter(|value| !value.is_empty()) {
+                Some(script_name) => {
+                    if self
+                        .server
+                        .document_ids_matching(
+                            account_id,
+                            Collection::SieveScript,
+                            SieveField::Name,
+                            script_name.as_bytes(),
+                        )
+                        .await
+                        .imap_ctx(&arguments.tag, trc::location!())?
+                        .is_empty()
+                    {
+                        return Err(trc::ImapEvent::Error
+                            .into_err()
+                            .details(format!("Sieve script {script_name:?} does not exist."))
+                            .code(ResponseCode::NonExistent)
+                            .id(arguments.tag.clone()));
+                    }
+
+                    if mailbox_id.is_some() {
+                        batch.set(MailboxField::ImapSieveScript, script_name.as_bytes());
+                    } else {
+                        batch.set(PrincipalField::ImapSieveScript, script_name.as_bytes());
+                    }
+                }
+                None => {
+                    if mailbox_id.is_some() {
+                        batch.clear(MailboxField::ImapSieveScript);
+                    } else {
+                        batch.clear(PrincipalField::ImapSieveScript);
+                    }
+                }
+            }
+        }
+
+        self.server
+            .commit_batch(batch)
+            .await
+            .imap_ctx(&arguments.tag, trc::location!())?;
+
+        trc::event!(
+            Imap(trc::ImapEvent::SetMetadata),
+            SpanId = self.session_id,
+            MailboxName = arguments.mailbox_name,
+            Details = arguments
+                .entries
+                .into_iter()
+                .map(|(entry, _)| trc::Value::from(entry))
+                .collect::<Vec<_>>(),
+            Elapsed = op_start.elapsed()
+        );
+
+        Ok(StatusResponse::completed(Command::SetMetadata)
+            .with_tag(arguments.tag)
+            .into_bytes())
+    }
+
+    async fn metadata_target(
+        &self,
+        tag: &str,
+        mailbox_name: &str,
+        acl: Acl,
+    ) -> trc::Result<(u32, Option<u32>)> {
+        // An empty mailbox name refers to the server entries, which are scoped to the account
+        if mailbox_name.is_empty() {
+            return Ok((self.account_id, None));
+        }
+
+        // Refresh mailboxes
+        self.synchronize_mailboxes(false)
+            .await
+            .imap_ctx(tag, trc::location!())?;
+
+        let Some(mailbox) = self.get_mailbox_by_name(mailbox_name) else {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("Mailbox does not exist.")
+                .code(ResponseCode::NonExistent)
+                .id(tag.to_string()));
+        };
+        if !self
+            .check_mailbox_acl(mailbox.account_id, mailbox.mailbox_id, acl)
+            .await
+            .imap_ctx(tag, trc::location!())?
+        {
+            return Err(trc::ImapEvent::Error
+                .into_err()
+                .details("You do not have the required permissions to access this mailbox.")
+                .code(ResponseCode::NoPerm)
+                .id(tag.to_string()));
+        }
+
+        Ok((mailbox.account_id, Some(mailbox.mailbox_id)))
+    }
+
+    pub async fn imapsieve_run(
+        &self,
+        mailbox: &MailboxId,
+        cause: ImapSieveCause,
+        messages: Vec<(u32, Vec<Keyword>)>,
+    ) {
+        if !messages.is_empty()
+            && let Err(err) = self.imapsieve_apply(mailbox, cause, messages).await
+        {
+            trc::error!(
+                err.span_id(self.session_id)
+                    .account_id(mailbox.account_id)
+                    .caused_by(trc::location!())
+            );
+        }
+    }
+
+    async fn imapsieve_apply(
+        &self,
+        mailbox: &MailboxId,
+        cause: ImapSieveCause,
+        messages: Vec<(u32, Vec<Keyword>)>,
+    ) -> trc::Result<()> {
+        let account_id = mailbox.account_id;
+        let mailbox_id = mailbox.mailbox_id;
+        let results = self
+            .server
+            .imapsieve_run(
+                &self.access_token,
+                account_id,
+                mailbox_id,
+                cause,
+                messages,
+                self.session_id,
+            )
+            .await?;
+
+        let mut batch = BatchBuilder::new();
+        let mut discard_ids = RoaringBitmap::new();
+        for result in results {
+            if !result.keep && result.file_into.is_empty() {
+                discard_ids.insert(result.document_id);
+                continue;
+            } else if result.keep && result.flags.is_none() && result.file_into.is_empty() {
+                continue;
+            }
+
+            // Obtain message data
+            let Some(data_) = self
+                .get_message_data(account_id, result.document_id)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+            let data = data_
+                .to_unarchived::<MessageData>()
+                .caused_by(trc::location!())?;
+            let Some(message_uid) = data.inner.message_uid(mailbox_id) else {
+                continue;
+            };
+
+            // Apply changes
+            let mut new_data = data.inner.to_builder();
+            if let Some(flags) = result.flags {
+                new_data.set_keywords(flags);
+            }
+            for target_id in result.file_into {
+                if !data.inner.has_mailbox_id(target_id) {
+                    new_data.add_mailbox(UidMailbox::new_unassigned(target_id));
+                }
+            }
+            if !result.keep {
+                new_data.remove_mailbox(mailbox_id);
+            }
+
+            // Assign IMAP UIDs
+            let ids = self
+                .server
+                .assign_email_ids(
+                    account_id,
+                    new_data
+                        .mailboxes
+                        .iter()
+                        .filter(|m| m.uid == 0)
+                        .map(|m| m.mailbox_id),
+                    false,
+                )
+                .await
+                .caused_by(trc::location!())?;
+            for (uid_mailbox, uid) in new_data
+                .mailboxes
+                .iter_mut()
+                .filter(|m| m.uid == 0)
+                .zip(ids)
+            {
+                uid_mailbox.uid = uid;
+            }
+
+            // Prepare write batch
+            batch
+                .with_account_id(account_id)
+                .with_collection(Collection::Email)
+                .with_document(result.document_id)
+                .custom(
+                    ObjectIndexBuilder::new()
+                        .with_current(data)
+                        .with_changes(new_data.seal()),
+                )
+                .caused_by(trc::location!())?;
+            if !result.keep {
+                batch.log_vanished_item(VanishedCollection::Email, (mailbox_id, message_uid));
+            }
+            batch.commit_point();
+        }
+
+        // Untag or delete discarded messages
+        if !discard_ids.is_empty() {
+            self.email_untag_or_delete(account_id, mailbox_id, &discard_ids, &mut batch)
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        if !batch.is_empty() {
+            self.server
+                .commit_batch(batch)
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        Ok(())
+    }
+}
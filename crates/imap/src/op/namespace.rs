@@ -24,13 +24,14 @@ impl<T: SessionStream> Session<T> {
             Elapsed = trc::Value::Duration(0)
         );
 
+        let data = self.state.session_data();
         self.write_bytes(
             StatusResponse::completed(Command::Namespace)
                 .with_tag(request.tag)
                 .serialize(
                     Response {
-                        shared_prefix: if self.state.session_data().mailboxes.lock().len() > 1 {
-                            Some(self.server.core.email.shared_folder.as_str().into())
+                        shared_prefix: if data.mailboxes.lock().len() > 1 {
+                            Some(data.shared_folder().into())
                         } else {
                             None
                         },
@@ -9,6 +9,7 @@ use crate::{
     spawn_op,
 };
 use common::{network::SessionStream, sharing::EffectiveAcl, storage::index::ObjectIndexBuilder};
+use email::mailbox::virtual_folder::is_virtual_mailbox;
 use imap_proto::{
     Command, ResponseCode, StatusResponse, protocol::rename::Arguments, receiver::Request,
 };
@@ -75,6 +76,13 @@ impl<T: SessionStream> SessionData<T> {
                 }
             }
             if let Some(mailbox_id) = mailbox_id {
+                if is_virtual_mailbox(mailbox_id) {
+                    return Err(trc::ImapEvent::Error
+                        .into_err()
+                        .details("Virtual mailboxes cannot be renamed.")
+                        .code(ResponseCode::Cannot)
+                        .id(arguments.tag));
+                }
                 mailbox_id
             } else {
                 return Err(trc::ImapEvent::Error
@@ -9,8 +9,11 @@ use crate::{
     core::{ImapId, SavedSearch, SelectedMailbox, Session, SessionData},
     spawn_op,
 };
-use common::network::SessionStream;
-use email::cache::{MessageCacheFetch, email::MessageCacheAccess};
+use common::{MessageStoreCache, config::mailstore::email::SearchFolder, network::SessionStream};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::virtual_folder::{VirtualMailbox, VirtualMailboxes},
+};
 use imap_proto::{
     Command, StatusResponse,
     protocol::{
@@ -93,9 +96,11 @@ impl<T: SessionStream> Session<T> {
                     .serialize(response)
                 }
                 Err(err) => {
-                    if let Some(prev_saved_search) = prev_saved_search {
-                        *mailbox.saved_search.lock() = prev_saved_search
-                            .map_or(SavedSearch::None, |s| SavedSearch::Results { items: s });
+                    // RFC 5182: a failed search with SAVE empties the result variable
+                    if prev_saved_search.is_some() {
+                        *mailbox.saved_search.lock() = SavedSearch::Results {
+                            items: Arc::new(Vec::new()),
+                        };
                     }
                     return Err(err.id(tag));
                 }
@@ -215,27 +220,96 @@ impl<T: SessionStream> SessionData<T> {
         prev_saved_search: &Option<Option<Arc<Vec<ImapId>>>>,
     ) -> trc::Result<(Vec<u32>, bool)> {
         // Obtain message ids
-        let mut filters = Vec::with_capacity(imap_filter.len() + 1);
         let cache = self
             .server
             .get_cached_messages(mailbox.id.account_id)
             .await
             .caused_by(trc::location!())?;
+        let message_ids = if let Some(folder) = self.server.search_folder(mailbox.id.mailbox_id) {
+            self.search_folder_messages(mailbox.id.account_id, folder, &cache)
+                .await?
+        } else if let Some(virtual_mailbox) = self.server.virtual_mailbox(mailbox.id.mailbox_id) {
+            RoaringBitmap::from_iter(
+                self.server
+                    .virtual_mailbox_messages(mailbox.id.account_id, virtual_mailbox, &cache)
+                    .await
+                    .caused_by(trc::location!())?
+                    .into_iter()
+                    .map(|m| m.document_id),
+            )
+        } else {
+            RoaringBitmap::from_iter(
+                cache
+                    .in_mailbox(mailbox.id.mailbox_id)
+                    .map(|m| m.document_id),
+            )
+        };
+
+        self.query_messages(
+            mailbox.id.account_id,
+            &cache,
+            message_ids,
+            imap_filter,
+            imap_comparator,
+            Some((mailbox, prev_saved_search)),
+        )
+        .await
+    }
+
+    // Saved search mailboxes are evaluated on demand against all messages
+    // that would be listed in the All Mail virtual folder.
+    pub async fn search_folder_messages(
+        &self,
+        account_id: u32,
+        folder: &SearchFolder,
+        cache: &MessageStoreCache,
+    ) -> trc::Result<RoaringBitmap> {
         let message_ids = RoaringBitmap::from_iter(
-            cache
-                .in_mailbox(mailbox.id.mailbox_id)
+            self.server
+                .virtual_mailbox_messages(account_id, VirtualMailbox::All, cache)
+                .await
+                .caused_by(trc::location!())?
+                .into_iter()
                 .map(|m| m.document_id),
         );
 
+        self.query_messages(
+            account_id,
+            cache,
+            message_ids,
+            folder.filters.clone(),
+            vec![],
+            None,
+        )
+        .await
+        .map(|(ids, _)| RoaringBitmap::from_iter(ids))
+    }
+
+    async fn query_messages(
+        &self,
+        account_id: u32,
+        cache: &MessageStoreCache,
+        message_ids: RoaringBitmap,
+        imap_filter: Vec<Filter>,
+        imap_comparator: Vec<Comparator>,
+        selected: Option<(&SelectedMailbox, &Option<Option<Arc<Vec<ImapId>>>>)>,
+    ) -> trc::Result<(Vec<u32>, bool)> {
         // Convert query
+        let mut filters = Vec::with_capacity(imap_filter.len() + 1);
         let mut include_highest_modseq = false;
         for filter in imap_filter {
             match filter {
                 Filter::Sequence(sequence, uid_filter) => {
+                    let Some((mailbox, prev_saved_search)) = selected else {
+                        return Err(trc::ImapEvent::Error
+                            .into_err()
+                            .details("Sequence sets are not allowed in saved searches."));
+                    };
                     let mut set = RoaringBitmap::new();
                     if let (Sequence::SavedSearch, Some(prev_saved_search)) =
-                        (&sequence, &prev_saved_search)
+                        (&sequence, prev_saved_search)
                     {
+                        // An unset result variable is treated as an empty set
                         if let Some(prev_saved_search) = prev_saved_search {
                             let state = mailbox.state.lock();
                             for imap_id in prev_saved_search.iter() {
@@ -243,10 +317,6 @@ impl<T: SessionStream> SessionData<T> {
                                     set.insert(*id);
                                 }
                             }
-                        } else {
-                            return Err(trc::ImapEvent::Error
-                                .into_err()
-                                .details("No saved search found."));
                         }
                     } else {
                         for id in mailbox.sequence_to_ids(&sequence, uid_filter).await?.keys() {
@@ -399,7 +469,7 @@ impl<T: SessionStream> SessionData<T> {
                         .server
                         .store()
                         .changes(
-                            mailbox.id.account_id,
+                            account_id,
                             SyncCollection::Email.into(),
                             Query::from_modseq(modseq),
                         )
@@ -626,7 +696,7 @@ impl<T: SessionStream> SessionData<T> {
                 SearchQuery::new(SearchIndex::Email)
                     .with_filters(filters)
                     .with_comparators(comparators)
-                    .with_account_id(mailbox.id.account_id)
+                    .with_account_id(account_id)
                     .with_mask(message_ids),
             )
             .await
@@ -651,6 +721,29 @@ impl SelectedMailbox {
         Some(v.clone())
     }
 
+    // Expunged messages are removed from the result variable rather than
+    // discarding it, as required by RFC 5182.
+    pub async fn prune_saved_search(&self) {
+        if let Some(saved_ids) = self.get_saved_search().await {
+            let items = {
+                let state = self.state.lock();
+                saved_ids
+                    .iter()
+                    .filter_map(|imap_id| {
+                        state
+                            .uid_to_id
+                            .get(&imap_id.uid)
+                            .and_then(|id| state.id_to_imap.get(id))
+                            .copied()
+                    })
+                    .collect::<Vec<_>>()
+            };
+            *self.saved_search.lock() = SavedSearch::Results {
+                items: Arc::new(items),
+            };
+        }
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn map_search_results(
         &self,
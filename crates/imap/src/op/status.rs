@@ -89,11 +89,12 @@ impl<T: SessionStream> SessionData<T> {
             mailbox
         } else {
             // Some IMAP clients will try to get the status of a mailbox with the NoSelect flag
-            return if mailbox_name == self.server.core.email.shared_folder
+            let shared_folder = self.shared_folder();
+            return if mailbox_name == shared_folder
                 || mailbox_name
                     .split_once('/')
                     .is_some_and(|(base_name, path)| {
-                        base_name == self.server.core.email.shared_folder && !path.contains('/')
+                        base_name == shared_folder && !path.contains('/')
                     })
             {
                 Ok(StatusItem {
@@ -12,8 +12,10 @@ use crate::{
 use ahash::AHashSet;
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
 use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
     mailbox::TRASH_ID,
     message::{ingest::EmailIngest, metadata::MessageData},
+    sieve::imapsieve::ImapSieveCause,
 };
 use imap_proto::{
     Command, ResponseCode, ResponseType, StatusResponse,
@@ -193,7 +195,13 @@ impl<T: SessionStream> SessionData<T> {
             .map(|k| Keyword::from(k.clone()))
             .collect::<Vec<_>>();
         let mut changed_mailboxes = AHashSet::new();
+        let mut sieve_ids = Vec::new();
         let mut batch = BatchBuilder::new();
+        let cache = self
+            .server
+            .get_cached_messages(account_id)
+            .await
+            .imap_ctx(response.tag.as_ref().unwrap(), trc::location!())?;
 
         for (id, imap_id) in &ids {
             // Obtain message data
@@ -247,6 +255,33 @@ impl<T: SessionStream> SessionData<T> {
                 continue;
             }
 
+            // Enforce keyword limits
+            if !cache.accepts_keywords(
+                &self.server.core.email,
+                &new_data.keywords,
+                new_data.added_keywords(data.inner),
+            ) {
+                return Err(trc::ImapEvent::Error
+                    .into_err()
+                    .details("Too many keywords.")
+                    .id(response.tag.unwrap_or_default())
+                    .code(ResponseCode::Limit)
+                    .caused_by(trc::location!()));
+            }
+
+            sieve_ids.push((
+                *id,
+                new_data
+                    .added_keywords(data.inner)
+                    .cloned()
+                    .chain(
+                        new_data
+                            .removed_keywords(data.inner)
+                            .map(|keyword| keyword.to_native()),
+                    )
+                    .collect::<Vec<_>>(),
+            ));
+
             // Train spam filter
             let mut train_spam = None;
             for keyword in new_data.added_keywords(data.inner) {
@@ -363,6 +398,10 @@ impl<T: SessionStream> SessionData<T> {
                             item.items.push(DataItem::ModSeq { modseq });
                         }
                     }
+
+                    // Run IMAPSIEVE scripts
+                    self.imapsieve_run(&mailbox.id, ImapSieveCause::Flag, sieve_ids)
+                        .await;
                 }
                 Err(err) if err.is_assertion_failure() => {
                     items.items.clear();
@@ -22,6 +22,8 @@ pub enum IdentityProperty {
     TextSignature,
     HtmlSignature,
     MayDelete,
+    DelegatedTo,
+    BccToSelf,
 
     // Other
     Pointer(JsonPointer<IdentityProperty>),
@@ -40,6 +42,8 @@ impl Property for IdentityProperty {
     fn to_cow(&self) -> Cow<'static, str> {
         match self {
             IdentityProperty::Bcc => "bcc",
+            IdentityProperty::BccToSelf => "bccToSelf",
+            IdentityProperty::DelegatedTo => "delegatedTo",
             IdentityProperty::Email => "email",
             IdentityProperty::HtmlSignature => "htmlSignature",
             IdentityProperty::Id => "id",
@@ -59,7 +63,9 @@ impl Element for IdentityValue {
     fn try_parse<P>(key: &Key<'_, Self::Property>, value: &str) -> Option<Self> {
         if let Key::Property(prop) = key {
             match prop.patch_or_prop() {
-                IdentityProperty::Id => Id::from_str(value).ok().map(IdentityValue::Id),
+                IdentityProperty::Id | IdentityProperty::DelegatedTo => {
+                    Id::from_str(value).ok().map(IdentityValue::Id)
+                }
                 _ => None,
             }
         } else {
@@ -85,6 +91,8 @@ impl IdentityProperty {
             b"textSignature" => IdentityProperty::TextSignature,
             b"htmlSignature" => IdentityProperty::HtmlSignature,
             b"mayDelete" => IdentityProperty::MayDelete,
+            b"delegatedTo" => IdentityProperty::DelegatedTo,
+            b"bccToSelf" => IdentityProperty::BccToSelf,
         )
         .or_else(|| {
             if allow_patch && value.contains('/') {
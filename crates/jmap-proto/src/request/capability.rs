@@ -91,6 +91,8 @@ pub enum Capability {
     MailShare = 1 << 16,
     #[serde(rename(serialize = "urn:stalwart:jmap"))]
     Stalwart = 1 << 17,
+    #[serde(rename(serialize = "urn:ietf:params:jmap:webpush-vapid"))]
+    WebPushVapid = 1 << 18,
 }
 
 #[derive(Debug, Clone, Copy, Default)]
@@ -113,6 +115,7 @@ pub enum Capabilities {
     PrincipalsAvailability(PrincipalAvailabilityCapabilities),
     Calendar(CalendarCapabilities),
     FileNode(FileNodeCapabilities),
+    WebPushVapid(WebPushVapidCapabilities),
     Empty(EmptyCapabilities),
 }
 
@@ -190,6 +193,8 @@ pub struct SubmissionCapabilities {
     pub max_delayed_send: u64,
     #[serde(rename(serialize = "submissionExtensions"))]
     pub submission_extensions: VecMap<String, Vec<String>>,
+    #[serde(rename(serialize = "undoSendWindow"))]
+    pub undo_send_window: u64,
 }
 
 #[derive(Debug, Clone, serde::Serialize)]
@@ -285,6 +290,12 @@ pub struct FileNodeCapabilities {
     pub web_write_url_template: Option<String>,
 }
 
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct WebPushVapidCapabilities {
+    #[serde(rename(serialize = "applicationServerKey"))]
+    pub application_server_key: String,
+}
+
 #[derive(Debug, Clone, Default, serde::Serialize)]
 pub struct EmptyCapabilities {}
 
@@ -315,6 +326,7 @@ impl Capability {
             Capability::FileNode => "urn:ietf:params:jmap:filenode",
             Capability::MailShare => "urn:ietf:params:jmap:mail:share",
             Capability::Stalwart => "urn:stalwart:jmap",
+            Capability::WebPushVapid => "urn:ietf:params:jmap:webpush-vapid",
         }
     }
 
@@ -337,6 +349,7 @@ impl Capability {
             Capability::FileNode,
             Capability::MailShare,
             Capability::Stalwart,
+            Capability::WebPushVapid,
         ]
     }
 }
@@ -408,8 +421,15 @@ impl Capabilities {
         &self,
         current_user_principal_id: Option<Id>,
         may_create: bool,
+        undo_send_window: u64,
     ) -> Capabilities {
         match self {
+            Capabilities::Submission(submission_capabilities) => {
+                Capabilities::Submission(SubmissionCapabilities {
+                    undo_send_window,
+                    ..submission_capabilities.clone()
+                })
+            }
             Capabilities::Contacts(contacts_capabilities) => {
                 Capabilities::Contacts(ContactsCapabilities {
                     may_create_address_book: may_create,
@@ -457,6 +477,7 @@ impl Capability {
             "urn:ietf:params:jmap:calendars:parse" => Capability::CalendarsParse,
             "urn:ietf:params:jmap:mail:share" => Capability::MailShare,
             "urn:stalwart:jmap" => Capability::Stalwart,
+            "urn:ietf:params:jmap:webpush-vapid" => Capability::WebPushVapid,
         )
     }
 }
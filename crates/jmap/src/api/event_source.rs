@@ -13,9 +13,12 @@ use hyper::{
     body::{Bytes, Frame},
 };
 use jmap_proto::{response::status::PushObject, types::state::State};
+use std::fmt::Write;
 use std::time::{Duration, Instant};
 use std::{future::Future, str::FromStr};
-use types::{id::Id, type_state::DataType};
+use store::{query::log::Query, write::now};
+use trc::AddContext;
+use types::{collection::SyncCollection, id::Id, type_state::DataType};
 use utils::map::{bitmap::Bitmap, vec_map::VecMap};
 
 struct Ping {
@@ -24,12 +27,39 @@ struct Ping {
     payload: Bytes,
 }
 
+// Event ids record when the event was sent and the last change id delivered
+// for each account, so a reconnecting client can be sent the changes it missed.
+#[derive(Default)]
+struct EventCursor {
+    timestamp: u64,
+    change_ids: VecMap<u32, u64>,
+}
+
+const SYNC_COLLECTIONS: [SyncCollection; 8] = [
+    SyncCollection::Email,
+    SyncCollection::Thread,
+    SyncCollection::Identity,
+    SyncCollection::EmailSubmission,
+    SyncCollection::SieveScript,
+    SyncCollection::FileNode,
+    SyncCollection::AddressBook,
+    SyncCollection::Calendar,
+];
+
 pub trait EventSourceHandler: Sync + Send {
     fn handle_event_source(
         &self,
         req: HttpRequest,
         access_token: AccessToken,
     ) -> impl Future<Output = trc::Result<HttpResponse>> + Send;
+
+    fn event_source_missed_changes(
+        &self,
+        access_token: &AccessToken,
+        types: &Bitmap<DataType>,
+        last_change_ids: VecMap<u32, u64>,
+        change_ids: &mut VecMap<u32, u64>,
+    ) -> impl Future<Output = trc::Result<VecMap<Id, VecMap<DataType, State>>>> + Send;
 }
 
 impl EventSourceHandler for Server {
@@ -95,9 +125,31 @@ impl EventSourceHandler for Server {
             None
         };
 
+        // Resume from the last event received by the client, accounts missing from
+        // the cursor start at their current change id
+        let mut cursor = EventCursor::default();
+        let mut changed: VecMap<Id, VecMap<DataType, State>> = VecMap::new();
+        if let Some(window) = self.core.jmap.event_source_replay_window {
+            let last_change_ids = req
+                .headers()
+                .get("last-event-id")
+                .and_then(|value| value.to_str().ok())
+                .and_then(EventCursor::parse)
+                .filter(|last_cursor| last_cursor.timestamp + window.as_secs() >= now())
+                .map(|last_cursor| last_cursor.change_ids)
+                .unwrap_or_default();
+            changed = self
+                .event_source_missed_changes(
+                    &access_token,
+                    &types,
+                    last_change_ids,
+                    &mut cursor.change_ids,
+                )
+                .await?;
+        }
+
         // Register with push manager
         let mut push_rx = self.subscribe_push_manager(&access_token, types).await?;
-        let mut changed: VecMap<Id, VecMap<DataType, State>> = VecMap::new();
         let throttle = self.core.jmap.event_source_throttle;
 
         Ok(HttpResponse::new(StatusCode::OK)
@@ -105,14 +157,21 @@ impl EventSourceHandler for Server {
             .with_cache_control("no-store")
             .with_stream_body(BoxBody::new(StreamBody::new(async_stream::stream! {
                 let mut last_message = Instant::now() - throttle;
-                let mut timeout =
-                    ping.as_ref().map(|p| p.interval).unwrap_or(LONG_1D_SLUMBER);
+                let mut timeout = if changed.is_empty() {
+                    ping.as_ref().map(|p| p.interval).unwrap_or(LONG_1D_SLUMBER)
+                } else {
+                    Duration::ZERO
+                };
 
                 loop {
                     match tokio::time::timeout(timeout, push_rx.recv()).await {
                         Ok(Some(notification)) => {
                             match notification {
                                 PushNotification::StateChange(state_change) => {
+                                    // Share notifications are not part of the account's change log
+                                    if !state_change.types.contains(DataType::ShareNotification) {
+                                        cursor.update(state_change.account_id, state_change.change_id);
+                                    }
                                     for type_state in state_change.types {
                                         changed
                                             .get_mut_or_insert(state_change.account_id.into())
@@ -127,6 +186,7 @@ impl EventSourceHandler for Server {
                                 }
                                 PushNotification::EmailPush(email_push) => {
                                     let state_change = email_push.to_state_change();
+                                    cursor.update(state_change.account_id, state_change.change_id);
                                     for type_state in state_change.types {
                                         changed
                                             .get_mut_or_insert(state_change.account_id.into())
@@ -148,8 +208,10 @@ impl EventSourceHandler for Server {
                             let response =
                                 PushObject::StateChange { changed: std::mem::take(&mut changed) };
 
+                            cursor.timestamp = now();
                             yield Ok(Frame::data(Bytes::from(format!(
-                                "event: state\ndata: {}\n\n",
+                                "id: {}\nevent: state\ndata: {}\n\n",
+                                cursor.to_event_id(),
                                 serde_json::to_string(&response).unwrap()
                             ))));
 
@@ -176,4 +238,121 @@ impl EventSourceHandler for Server {
                 }
             }))))
     }
+
+    async fn event_source_missed_changes(
+        &self,
+        access_token: &AccessToken,
+        types: &Bitmap<DataType>,
+        last_change_ids: VecMap<u32, u64>,
+        change_ids: &mut VecMap<u32, u64>,
+    ) -> trc::Result<VecMap<Id, VecMap<DataType, State>>> {
+        let mut changed: VecMap<Id, VecMap<DataType, State>> = VecMap::new();
+
+        for account_id in access_token.member_ids() {
+            let Some(mut last_change_id) = last_change_ids.get(&account_id).copied() else {
+                let mut last_change_id = 0;
+                for collection in SYNC_COLLECTIONS {
+                    last_change_id = std::cmp::max(
+                        last_change_id,
+                        self.store()
+                            .get_last_change_id(account_id, collection.into())
+                            .await
+                            .caused_by(trc::location!())?
+                            .unwrap_or_default(),
+                    );
+                }
+                change_ids.set(account_id, last_change_id);
+                continue;
+            };
+            let from_change_id = last_change_id;
+
+            for collection in SYNC_COLLECTIONS {
+                let container_type = DataType::try_from_sync(collection, true)
+                    .filter(|data_type| types.contains(*data_type));
+                let item_type = DataType::try_from_sync(collection, false)
+                    .filter(|data_type| types.contains(*data_type));
+                if container_type.is_none() && item_type.is_none() {
+                    continue;
+                }
+
+                let changes = self
+                    .store()
+                    .changes(account_id, collection.into(), Query::Since(from_change_id))
+                    .await
+                    .caused_by(trc::location!())?;
+                if changes.changes.is_empty() && !changes.is_truncated {
+                    continue;
+                }
+
+                // Missed entries that were already purged are reported with the latest
+                // state, the client will then have to fully resync that type.
+                let last_change_id_ = if changes.is_truncated {
+                    self.store()
+                        .get_last_change_id(account_id, collection.into())
+                        .await
+                        .caused_by(trc::location!())?
+                        .unwrap_or_default()
+                } else {
+                    changes.to_change_id
+                };
+                let (container_change_id, item_change_id) =
+                    if container_type == item_type || changes.is_truncated {
+                        (Some(last_change_id_), Some(last_change_id_))
+                    } else {
+                        (changes.container_change_id, changes.item_change_id)
+                    };
+
+                for (data_type, change_id) in [
+                    (container_type, container_change_id),
+                    (item_type, item_change_id),
+                ] {
+                    if let (Some(data_type), Some(change_id)) = (data_type, change_id) {
+                        changed
+                            .get_mut_or_insert(account_id.into())
+                            .set(data_type, change_id.into());
+                    }
+                }
+
+                last_change_id = std::cmp::max(last_change_id, last_change_id_);
+            }
+
+            change_ids.set(account_id, last_change_id);
+        }
+
+        Ok(changed)
+    }
+}
+
+impl EventCursor {
+    fn parse(value: &str) -> Option<Self> {
+        let mut parts = value.split('-');
+        let mut cursor = EventCursor {
+            timestamp: parts.next()?.parse().ok()?,
+            change_ids: VecMap::new(),
+        };
+
+        for part in parts {
+            let (account_id, change_id) = part.split_once('.')?;
+            cursor
+                .change_ids
+                .set(account_id.parse().ok()?, change_id.parse().ok()?);
+        }
+
+        Some(cursor)
+    }
+
+    fn update(&mut self, account_id: u32, change_id: u64) {
+        let last_change_id = self.change_ids.get_mut_or_insert(account_id);
+        if change_id > *last_change_id {
+            *last_change_id = change_id;
+        }
+    }
+
+    fn to_event_id(&self) -> String {
+        let mut event_id = self.timestamp.to_string();
+        for (account_id, change_id) in self.change_ids.iter() {
+            let _ = write!(event_id, "-{account_id}.{change_id}");
+        }
+        event_id
+    }
 }
@@ -6,7 +6,10 @@
 
 use crate::blob::UploadResponse;
 use calcard::jscalendar::JSCalendarDateTime;
-use common::ipc::{CalendarAlert, PushNotification};
+use common::{
+    auth::rate_limit::HttpRateLimitStatus,
+    ipc::{CalendarAlert, PushNotification},
+};
 use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
 use hyper::StatusCode;
 use jmap_proto::{
@@ -66,6 +69,30 @@ impl ToJmapHttpResponse for RequestError<'_> {
     }
 }
 
+pub trait WithRateLimitStatus {
+    fn with_rate_limit_status(self, status: Option<HttpRateLimitStatus>) -> Self;
+}
+
+impl WithRateLimitStatus for HttpResponse {
+    fn with_rate_limit_status(self, status: Option<HttpRateLimitStatus>) -> Self {
+        if let Some(status) = status {
+            let policy = RateLimitPolicy::new(status.name, status.limit)
+                .with_window(status.window)
+                .with_remaining(status.remaining)
+                .with_reset(status.reset);
+            let mut policy_header = String::new();
+            let mut state_header = String::new();
+            policy.fmt_policy(&mut policy_header);
+            policy.fmt_state(&mut state_header);
+
+            self.with_header("RateLimit-Policy", policy_header)
+                .with_header("RateLimit", state_header)
+        } else {
+            self
+        }
+    }
+}
+
 pub trait ToRequestError {
     fn to_request_error(&self) -> RequestError<'_>;
 }
@@ -146,7 +173,15 @@ impl ToRequestError for trc::Error {
                     trc::LimitEvent::TooManyRequests => {
                         let mut err = RequestError::too_many_requests();
                         if let Some(limit) = limit {
-                            let mut policy = RateLimitPolicy::new("requests", limit);
+                            let name = match self.value(trc::Key::Type).and_then(|v| v.as_str()) {
+                                Some("client") => "client",
+                                Some("upload") => "upload",
+                                Some("query") => "query",
+                                Some("set") => "set",
+                                Some("admin") => "admin",
+                                _ => "requests",
+                            };
+                            let mut policy = RateLimitPolicy::new(name, limit);
                             if let Some(reset) = reset {
                                 policy = policy.with_reset(reset);
                             }
@@ -176,6 +211,7 @@ impl ToRequestError for trc::Error {
                 | trc::SecurityEvent::ScanBan
                 | trc::SecurityEvent::AbuseBan
                 | trc::SecurityEvent::LoiterBan
+                | trc::SecurityEvent::SmugglingBan
                 | trc::SecurityEvent::IpBlocked => {
                     let mut err = RequestError::too_many_auth_attempts();
                     if let Some(reset) = self.value(trc::Key::Expires).and_then(|v| v.to_uint()) {
@@ -183,9 +219,9 @@ impl ToRequestError for trc::Error {
                     }
                     err
                 }
-                trc::SecurityEvent::Unauthorized | trc::SecurityEvent::IpUnauthorized => {
-                    RequestError::forbidden()
-                }
+                trc::SecurityEvent::Unauthorized
+                | trc::SecurityEvent::IpUnauthorized
+                | trc::SecurityEvent::NetworkPolicyBlocked => RequestError::forbidden(),
                 trc::SecurityEvent::IpBlockExpired | trc::SecurityEvent::IpAllowExpired => {
                     RequestError::internal_server_error()
                 }
@@ -32,7 +32,7 @@ use crate::{
     participant_identity::{get::ParticipantIdentityGet, set::ParticipantIdentitySet},
     principal::{availability::PrincipalGetAvailability, get::PrincipalGet, query::PrincipalQuery},
     push::{get::PushSubscriptionFetch, set::PushSubscriptionSet},
-    quota::{get::QuotaGet, query::QuotaQuery},
+    quota::{changes::QuotaChanges, get::QuotaGet, query::QuotaQuery},
     registry::{get::RegistryGet, query::RegistryQuery, set::RegistrySet},
     share_notification::{
         get::ShareNotificationGet, query::ShareNotificationQuery, set::ShareNotificationSet,
@@ -50,7 +50,8 @@ use http_proto::HttpSessionData;
 use jmap_proto::{
     request::{
         Call, CopyRequestMethod, GetRequestMethod, ParseRequestMethod, QueryRequestMethod, Request,
-        RequestMethod, SetRequestMethod, method::MethodName,
+        RequestMethod, SetRequestMethod,
+        method::{MethodName, MethodObject},
     },
     response::{Response, ResponseMethod, SetResponseMethod},
 };
@@ -570,9 +571,15 @@ impl RequestHandler for Server {
             RequestMethod::Changes(mut req) => {
                 set_account_id_if_missing(&mut req.account_id, access_token);
 
-                self.changes(*req, method_name.obj, access_token)
-                    .await?
-                    .into_method_response()
+                if method_name.obj == MethodObject::Quota {
+                    access_token.assert_is_member(req.account_id)?;
+
+                    self.quota_changes(*req, access_token).await?.into()
+                } else {
+                    self.changes(*req, method_name.obj, access_token)
+                        .await?
+                        .into_method_response()
+                }
             }
             RequestMethod::Copy(req) => match req {
                 CopyRequestMethod::Email(mut req) => {
@@ -39,6 +39,7 @@ impl SessionHandler for Server {
             .caused_by(trc::location!())?;
         session.username = account.name().to_string();
         let account_id = Id::from(access_token.account_id());
+        let undo_send_window = account.undo_send_window;
         let mut account = Account {
             name: account.name().to_string(),
             is_personal: true,
@@ -51,7 +52,7 @@ impl SessionHandler for Server {
                 capability,
                 account_capabilities
                     .get(&capability)
-                    .map(|v| v.to_account_capabilities(account_id.into(), true))
+                    .map(|v| v.to_account_capabilities(account_id.into(), true, undo_send_window))
                     .unwrap_or_else(|| Capabilities::Empty(EmptyCapabilities::default())),
             );
         }
@@ -74,6 +75,7 @@ impl SessionHandler for Server {
             };
 
             let account_id = Id::from(account_id);
+            let undo_send_window = account.undo_send_window;
             let mut account = Account {
                 name: account.name().to_string(),
                 is_personal: false,
@@ -85,7 +87,9 @@ impl SessionHandler for Server {
                     capability,
                     account_capabilities
                         .get(&capability)
-                        .map(|v| v.to_account_capabilities(account_id.into(), is_owner))
+                        .map(|v| {
+                            v.to_account_capabilities(account_id.into(), is_owner, undo_send_window)
+                        })
                         .unwrap_or_else(|| Capabilities::Empty(EmptyCapabilities::default())),
                 );
             }
@@ -121,7 +125,9 @@ impl AccountCapabilities for AccessToken {
                     | Capability::Principals
                     | Capability::PrincipalsAvailability
                     | Capability::Stalwart => return true,
-                    Capability::Core | Capability::PrincipalsOwner => return false,
+                    Capability::Core | Capability::PrincipalsOwner | Capability::WebPushVapid => {
+                        return false;
+                    }
                 };
                 self.has_permission(permission)
             })
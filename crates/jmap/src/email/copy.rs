@@ -8,9 +8,10 @@ use crate::{
     changes::state::JmapCacheState,
     email::{PatchResult, handle_email_patch, ingested_into_object},
 };
-use common::{Server, auth::AccessToken};
+use common::{Server, auth::AccessToken, ipc::PushNotification};
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
+    mailbox::virtual_folder::is_virtual_mailbox,
     message::copy::{CopyMessageError, EmailCopy},
 };
 use http_proto::HttpSessionData;
@@ -30,7 +31,10 @@ use jmap_proto::{
 use jmap_tools::{Key, Value};
 use std::future::Future;
 use trc::AddContext;
-use types::acl::Acl;
+use types::{
+    acl::Acl,
+    type_state::{DataType, StateChange},
+};
 use utils::map::vec_map::VecMap;
 
 pub trait JmapEmailCopy: Sync + Send {
@@ -87,6 +91,7 @@ impl JmapEmailCopy for Server {
         };
         let on_success_delete = request.on_success_destroy_original.unwrap_or(false);
         let mut destroy_ids = Vec::new();
+        let mut last_change_id = None;
 
         'create: for (id, create) in request.create.into_valid() {
             let from_message_id = id.document_id();
@@ -176,7 +181,15 @@ impl JmapEmailCopy for Server {
 
             // Verify that the mailboxIds are valid
             for mailbox_id in &mailboxes {
-                if !cache.has_mailbox_id(mailbox_id) {
+                if is_virtual_mailbox(*mailbox_id) {
+                    response.not_created.append(
+                        id,
+                        SetError::forbidden().with_description(format!(
+                            "Mailbox {mailbox_id} is a virtual mailbox and cannot be modified."
+                        )),
+                    );
+                    continue 'create;
+                } else if !cache.has_mailbox_id(mailbox_id) {
                     response.not_created.append(
                         id,
                         SetError::invalid_properties()
@@ -209,6 +222,7 @@ impl JmapEmailCopy for Server {
                 .await?
             {
                 Ok(email) => {
+                    last_change_id = email.change_id.into();
                     response
                         .created
                         .append(id, ingested_into_object(email).into());
@@ -233,6 +247,18 @@ impl JmapEmailCopy for Server {
 
         // Update state
         if !response.created.is_empty() {
+            // Message copies do not broadcast state changes
+            if let Some(change_id) = last_change_id {
+                self.broadcast_push_notification(PushNotification::StateChange(
+                    StateChange::new(account_id)
+                        .with_change_id(change_id)
+                        .with_change(DataType::Email)
+                        .with_change(DataType::Mailbox)
+                        .with_change(DataType::Thread),
+                ))
+                .await;
+            }
+
             response.new_state = self.get_cached_messages(account_id).await?.get_state(false);
         }
 
@@ -7,10 +7,10 @@
 use crate::{
     blob::download::BlobDownload, changes::state::JmapCacheState, email::ingested_into_object,
 };
-use common::{Server, auth::AccessToken};
+use common::{Server, auth::AccessToken, ipc::PushNotification};
 use email::{
-    cache::{MessageCacheFetch, mailbox::MailboxCacheAccess},
-    mailbox::JUNK_ID,
+    cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
+    mailbox::{JUNK_ID, virtual_folder::is_virtual_mailbox},
     message::ingest::{EmailIngest, IngestEmail, IngestSource},
 };
 use http_proto::HttpSessionData;
@@ -23,7 +23,12 @@ use jmap_proto::{
 };
 use mail_parser::MessageParser;
 use std::future::Future;
-use types::{acl::Acl, id::Id, keyword::Keyword};
+use types::{
+    acl::Acl,
+    id::Id,
+    keyword::Keyword,
+    type_state::{DataType, StateChange},
+};
 use utils::map::vec_map::VecMap;
 
 pub trait EmailImport: Sync + Send {
@@ -81,6 +86,7 @@ impl EmailImport for Server {
             not_created: VecMap::new(),
         };
 
+        let mut last_change_id = None;
         'outer: for (id, email) in request.emails {
             // Validate mailboxIds
             let mailbox_ids = email
@@ -99,7 +105,16 @@ impl EmailImport for Server {
                 continue;
             }
             for mailbox_id in &mailbox_ids {
-                if !cache.has_mailbox_id(mailbox_id) {
+                if is_virtual_mailbox(*mailbox_id) {
+                    response.not_created.append(
+                        id,
+                        SetError::forbidden().with_description(format!(
+                            "Mailbox {} is a virtual mailbox and cannot be modified.",
+                            Id::from(*mailbox_id)
+                        )),
+                    );
+                    continue 'outer;
+                } else if !cache.has_mailbox_id(mailbox_id) {
                     response.not_created.append(
                         id,
                         SetError::invalid_properties()
@@ -122,6 +137,17 @@ impl EmailImport for Server {
                 }
             }
 
+            // Enforce keyword limits
+            if !cache.accepts_keywords(&self.core.email, &email.keywords, &email.keywords) {
+                response.not_created.append(
+                    id,
+                    SetError::new(SetErrorType::TooManyKeywords)
+                        .with_property(EmailProperty::Keywords)
+                        .with_description("Too many keywords."),
+                );
+                continue;
+            }
+
             let MaybeInvalid::Value(blob_id) = email.blob_id else {
                 response.not_created.append(
                     id,
@@ -167,6 +193,7 @@ impl EmailImport for Server {
                 .await
             {
                 Ok(email) => {
+                    last_change_id = email.change_id.into();
                     response
                         .created
                         .append(id, ingested_into_object(email).into());
@@ -198,6 +225,18 @@ impl EmailImport for Server {
 
         // Update state
         if !response.created.is_empty() {
+            // Message ingest does not broadcast state changes
+            if let Some(change_id) = last_change_id {
+                self.broadcast_push_notification(PushNotification::StateChange(
+                    StateChange::new(account_id)
+                        .with_change_id(change_id)
+                        .with_change(DataType::Email)
+                        .with_change(DataType::Mailbox)
+                        .with_change(DataType::Thread),
+                ))
+                .await;
+            }
+
             response.new_state = self.get_cached_messages(account_id).await?.get_state(false);
         }
 
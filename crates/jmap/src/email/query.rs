@@ -6,7 +6,10 @@
 
 use crate::{api::query::QueryResponseBuilder, changes::state::JmapCacheState};
 use common::{MessageStoreCache, Server, auth::AccessToken};
-use email::cache::{MessageCacheFetch, email::MessageCacheAccess};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::virtual_folder::VirtualMailboxes,
+};
 use jmap_proto::{
     method::query::{Filter, QueryRequest, QueryResponse},
     object::email::{Email, EmailComparator, EmailFilter},
@@ -156,11 +159,28 @@ impl EmailQuery for Server {
                         }
                     }
                     EmailFilter::InMailbox(mailbox) => {
-                        filters.push(SearchFilter::is_in_set(RoaringBitmap::from_iter(
-                            cached_messages
-                                .in_mailbox(mailbox.document_id())
-                                .map(|item| item.document_id),
-                        )))
+                        let mailbox_id = mailbox.document_id();
+                        let document_ids =
+                            if let Some(virtual_mailbox) = self.virtual_mailbox(mailbox_id) {
+                                RoaringBitmap::from_iter(
+                                    self.virtual_mailbox_messages(
+                                        account_id,
+                                        virtual_mailbox,
+                                        &cached_messages,
+                                    )
+                                    .await
+                                    .caused_by(trc::location!())?
+                                    .into_iter()
+                                    .map(|item| item.document_id),
+                                )
+                            } else {
+                                RoaringBitmap::from_iter(
+                                    cached_messages
+                                        .in_mailbox(mailbox_id)
+                                        .map(|item| item.document_id),
+                                )
+                            };
+                        filters.push(SearchFilter::is_in_set(document_ids))
                     }
                     EmailFilter::InMailboxOtherThan(mailboxes) => {
                         let mailboxes = mailboxes
@@ -15,11 +15,11 @@ use common::{
 };
 use email::{
     cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
-    mailbox::{JUNK_ID, TRASH_ID, UidMailbox},
+    mailbox::{JUNK_ID, TRASH_ID, UidMailbox, virtual_folder::is_virtual_mailbox},
     message::{
         delete::EmailDeletion,
         ingest::{EmailIngest, IngestEmail, IngestSource},
-        metadata::MessageData,
+        metadata::{MessageData, MessageDataBuilder},
     },
 };
 use http_proto::HttpSessionData;
@@ -41,6 +41,7 @@ use mail_builder::{
     mime::{BodyPart, MimePart},
 };
 use mail_parser::MessageParser;
+use registry::schema::enums::MessageAuditEvent;
 use std::future::Future;
 use std::{borrow::Cow, collections::HashMap};
 use store::{
@@ -58,6 +59,19 @@ use types::{
     type_state::{DataType, StateChange},
 };
 
+// Minimum number of updates before message data is prefetched
+const PREFETCH_THRESHOLD: usize = 16;
+
+// Validated update waiting for its IMAP UIDs and write
+struct PendingUpdate {
+    id: Id,
+    document_id: u32,
+    archive: Archive<AlignedBytes>,
+    new_data: MessageDataBuilder,
+    train_spam: Option<bool>,
+    has_mailbox_changes: bool,
+}
+
 pub trait EmailSet: Sync + Send {
     fn email_set(
         &self,
@@ -698,7 +712,16 @@ impl EmailSet for Server {
 
             // Verify that the mailboxIds are valid
             for mailbox_id in &mailboxes {
-                if !cache.has_mailbox_id(mailbox_id) {
+                if is_virtual_mailbox(*mailbox_id) {
+                    response.not_created.append(
+                        id,
+                        SetError::forbidden().with_description(format!(
+                            "Mailbox {} is a virtual mailbox and cannot be modified.",
+                            Id::from(*mailbox_id)
+                        )),
+                    );
+                    continue 'create;
+                } else if !cache.has_mailbox_id(mailbox_id) {
                     response.not_created.append(
                         id,
                         SetError::invalid_properties()
@@ -724,6 +747,17 @@ impl EmailSet for Server {
                 }
             }
 
+            // Enforce keyword limits
+            if !cache.accepts_keywords(&self.core.email, &keywords, &keywords) {
+                response.not_created.append(
+                    id,
+                    SetError::new(SetErrorType::TooManyKeywords)
+                        .with_property(EmailProperty::Keywords)
+                        .with_description("Too many keywords."),
+                );
+                continue 'create;
+            }
+
             // Make sure the message is not empty
             if builder.headers.is_empty()
                 && builder.body.is_none()
@@ -789,10 +823,36 @@ impl EmailSet for Server {
         }
 
         // Process updates
+        let updates = request.unwrap_update().into_valid().collect::<Vec<_>>();
         let mut batch = BatchBuilder::new();
         let mut changed_mailboxes: AHashMap<u32, Vec<u32>> = AHashMap::new();
-        let mut will_update = Vec::with_capacity(request.update.as_ref().map_or(0, |u| u.len()));
-        'update: for (id, object) in request.unwrap_update().into_valid() {
+        let mut will_update = Vec::with_capacity(updates.len());
+        let mut moved_ids = Vec::new();
+        let mut pending = Vec::with_capacity(updates.len());
+
+        // Bulk updates, such as marking a whole mailbox as read, fetch all
+        // message data in a single scan rather than one read per message
+        let mut prefetched = AHashMap::new();
+        let is_prefetched = updates.len() > PREFETCH_THRESHOLD;
+        if is_prefetched {
+            let document_ids = updates
+                .iter()
+                .map(|(id, _)| id.document_id())
+                .collect::<RoaringBitmap>();
+            self.archives(
+                account_id,
+                Collection::Email,
+                &document_ids,
+                |document_id, archive| {
+                    prefetched.insert(document_id, archive);
+                    Ok(true)
+                },
+            )
+            .await
+            .caused_by(trc::location!())?;
+        }
+
+        'update: for (id, object) in updates {
             // Make sure id won't be destroyed
             if will_destroy.contains(&id) {
                 response.not_updated.append(id, SetError::will_destroy());
@@ -801,15 +861,18 @@ impl EmailSet for Server {
 
             // Obtain message data
             let document_id = id.document_id();
-            let data_ = match self
-                .store()
-                .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
-                    account_id,
-                    Collection::Email,
-                    document_id,
-                ))
-                .await?
-            {
+            let data_ = if is_prefetched {
+                prefetched.remove(&document_id)
+            } else {
+                self.store()
+                    .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                        account_id,
+                        Collection::Email,
+                        document_id,
+                    ))
+                    .await?
+            };
+            let data_ = match data_ {
                 Some(data) => data,
                 None => {
                     response.not_updated.append(id, SetError::not_found());
@@ -902,6 +965,21 @@ impl EmailSet for Server {
                     continue 'update;
                 }
 
+                // Enforce keyword limits
+                if !cache.accepts_keywords(
+                    &self.core.email,
+                    &new_data.keywords,
+                    new_data.added_keywords(data.inner),
+                ) {
+                    response.not_updated.append(
+                        id,
+                        SetError::new(SetErrorType::TooManyKeywords)
+                            .with_property(EmailProperty::Keywords)
+                            .with_description("Too many keywords."),
+                    );
+                    continue 'update;
+                }
+
                 // Process keyword changes
                 let mut changed_seen = false;
                 for keyword in new_data.added_keywords(data.inner) {
@@ -953,7 +1031,16 @@ impl EmailSet for Server {
 
                 // Make sure all new mailboxIds are valid
                 for mailbox_id in new_data.added_mailboxes(data.inner) {
-                    if cache.has_mailbox_id(&mailbox_id.mailbox_id) {
+                    if is_virtual_mailbox(mailbox_id.mailbox_id) {
+                        response.not_updated.append(
+                            id,
+                            SetError::forbidden().with_description(format!(
+                                "Mailbox {} is a virtual mailbox and cannot be modified.",
+                                Id::from(mailbox_id.mailbox_id)
+                            )),
+                        );
+                        continue 'update;
+                    } else if cache.has_mailbox_id(&mailbox_id.mailbox_id) {
                         // Verify permissions on shared accounts
                         if can_add_mailbox_ids
                             .as_ref()
@@ -1019,47 +1106,61 @@ impl EmailSet for Server {
                         continue 'update;
                     }
                 }
+            }
 
-                // Obtain IMAP UIDs for added mailboxes
-                let ids = self
-                    .assign_email_ids(
-                        account_id,
-                        new_data
-                            .mailboxes
-                            .iter()
-                            .filter(|m| m.uid == 0)
-                            .map(|m| m.mailbox_id),
-                        false,
-                    )
-                    .await
-                    .caused_by(trc::location!())?;
-                for (uid_mailbox, uid) in new_data
-                    .mailboxes
-                    .iter_mut()
-                    .filter(|m| m.uid == 0)
-                    .zip(ids)
-                {
-                    uid_mailbox.uid = uid;
+            pending.push(PendingUpdate {
+                id,
+                document_id,
+                archive: data_,
+                new_data,
+                train_spam,
+                has_mailbox_changes,
+            });
+        }
+
+        // Obtain IMAP UIDs for added mailboxes, reserving a single range per mailbox
+        let mut uid_counts: AHashMap<u32, u32> = AHashMap::new();
+        for update in &pending {
+            for mailbox in update.new_data.mailboxes.iter().filter(|m| m.uid == 0) {
+                *uid_counts.entry(mailbox.mailbox_id).or_default() += 1;
+            }
+        }
+        let mut next_uids = self
+            .assign_email_uid_ranges(account_id, uid_counts.into_iter().collect())
+            .await
+            .caused_by(trc::location!())?
+            .into_iter()
+            .collect::<AHashMap<_, _>>();
+
+        for mut update in pending {
+            for uid_mailbox in update.new_data.mailboxes.iter_mut().filter(|m| m.uid == 0) {
+                if let Some(uid) = next_uids.get_mut(&uid_mailbox.mailbox_id) {
+                    uid_mailbox.uid = *uid;
+                    *uid += 1;
                 }
             }
 
             // Write changes
+            let data = update
+                .archive
+                .to_unarchived::<MessageData>()
+                .caused_by(trc::location!())?;
             batch
                 .with_account_id(account_id)
                 .with_collection(Collection::Email)
-                .with_document(document_id)
+                .with_document(update.document_id)
                 .custom(
                     ObjectIndexBuilder::new()
                         .with_current(data)
-                        .with_changes(new_data.seal()),
+                        .with_changes(update.new_data.seal()),
                 )
                 .caused_by(trc::location!())?;
 
-            if let Some(train_spam) = train_spam {
+            if let Some(train_spam) = update.train_spam {
                 self.add_account_spam_sample(
                     &mut batch,
                     account_id,
-                    document_id,
+                    update.document_id,
                     train_spam,
                     session.session_id,
                 )
@@ -1068,7 +1169,10 @@ impl EmailSet for Server {
             }
 
             batch.commit_point();
-            will_update.push(id);
+            will_update.push(update.id);
+            if update.has_mailbox_changes {
+                moved_ids.push(update.document_id);
+            }
         }
 
         if !batch.is_empty() {
@@ -1092,6 +1196,18 @@ impl EmailSet for Server {
                     for id in will_update {
                         response.updated.append(id, None);
                     }
+
+                    // Record audit trail
+                    if !moved_ids.is_empty() && self.is_audit_log_enabled() {
+                        self.audit_messages(
+                            account_id,
+                            MessageAuditEvent::Moved,
+                            moved_ids,
+                            access_token.account_id().into(),
+                            session.remote_ip.to_string().into(),
+                        )
+                        .await;
+                    }
                 }
                 Err(err) if err.is_assertion_failure() => {
                     for id in will_update {
@@ -1143,6 +1259,7 @@ impl EmailSet for Server {
             if !destroy_ids.is_empty() {
                 // Batch delete messages
                 let mut batch = BatchBuilder::new();
+                let audit_ids = self.is_audit_log_enabled().then(|| destroy_ids.clone());
                 let not_destroyed = self
                     .emails_delete(
                         account_id,
@@ -1161,6 +1278,19 @@ impl EmailSet for Server {
                     self.notify_task_queue();
                 }
 
+                // Record audit trail
+                if let Some(mut audit_ids) = audit_ids {
+                    audit_ids -= &not_destroyed;
+                    self.audit_messages(
+                        account_id,
+                        MessageAuditEvent::Deleted,
+                        audit_ids,
+                        access_token.account_id().into(),
+                        session.remote_ip.to_string().into(),
+                    )
+                    .await;
+                }
+
                 // Mark messages that were not found as not destroyed (this should not occur in practice)
                 if !not_destroyed.is_empty() {
                     let mut destroyed = Vec::with_capacity(response.destroyed.len());
@@ -114,6 +114,7 @@ impl EmailSearchSnippet for Server {
         if email_ids.len() > self.core.jmap.snippet_max_results {
             return Err(trc::JmapEvent::RequestTooLarge.into_err());
         }
+        let max_len = self.core.jmap.snippet_max_length;
 
         for email_id in email_ids.into_valid() {
             let document_id = email_id.document_id();
@@ -155,7 +156,7 @@ impl EmailSearchSnippet for Server {
                 .root_part()
                 .header_value(&MetadataHeaderName::Subject)
                 .and_then(|v| v.as_text())
-                .and_then(|v| generate_snippet(v, &terms, language, is_exact))
+                .and_then(|v| generate_snippet(v, &terms, language, is_exact, max_len))
             {
                 snippet.subject = subject.into();
             }
@@ -196,7 +197,9 @@ impl EmailSearchSnippet for Server {
                             _ => unreachable!(),
                         };
 
-                        if let Some(body) = generate_snippet(&text, &terms, language, is_exact) {
+                        if let Some(body) =
+                            generate_snippet(&text, &terms, language, is_exact, max_len)
+                        {
                             snippet.preview = body.into();
                             break;
                         }
@@ -207,7 +210,9 @@ impl EmailSearchSnippet for Server {
                             _ => unreachable!(),
                         };
 
-                        if let Some(body) = generate_snippet(&text, &terms, language, is_exact) {
+                        if let Some(body) =
+                            generate_snippet(&text, &terms, language, is_exact, max_len)
+                        {
                             snippet.preview = body.into();
                             break;
                         }
@@ -230,7 +235,7 @@ impl EmailSearchSnippet for Server {
                                 };
 
                                 if let Some(body) =
-                                    generate_snippet(&text, &terms, language, is_exact)
+                                    generate_snippet(&text, &terms, language, is_exact, max_len)
                                 {
                                     snippet.preview = body.into();
                                     break 'outer;
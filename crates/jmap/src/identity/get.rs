@@ -6,7 +6,9 @@
 
 use crate::changes::state::StateManager;
 use common::{Server, storage::index::ObjectIndexBuilder};
-use email::identity::{ArchivedEmailAddress, Identity};
+use email::identity::{
+    ArchivedEmailAddress, Identity, delegation::IdentityDelegation, settings::IdentitySettingsStore,
+};
 use jmap_proto::{
     method::get::{GetRequest, GetResponse},
     object::identity::{self, IdentityProperty, IdentityValue},
@@ -53,7 +55,17 @@ impl IdentityGet for Server {
             IdentityProperty::TextSignature,
             IdentityProperty::HtmlSignature,
             IdentityProperty::MayDelete,
+            IdentityProperty::DelegatedTo,
+            IdentityProperty::BccToSelf,
         ]);
+        let needs_settings = properties.iter().any(|property| {
+            matches!(
+                property,
+                IdentityProperty::TextSignature
+                    | IdentityProperty::HtmlSignature
+                    | IdentityProperty::BccToSelf
+            )
+        });
         let account_id = request.account_id.document_id();
         let identity_ids = self.identity_get_or_create(account_id).await?;
         let ids = if let Some(ids) = ids {
@@ -99,6 +111,11 @@ impl IdentityGet for Server {
             let identity = _identity
                 .unarchive::<Identity>()
                 .caused_by(trc::location!())?;
+            let settings = if needs_settings {
+                self.identity_settings(account_id, document_id).await?
+            } else {
+                Default::default()
+            };
             let mut result = Map::with_capacity(properties.len());
             for property in &properties {
                 match property {
@@ -116,15 +133,25 @@ impl IdentityGet for Server {
                             .insert_unchecked(IdentityProperty::Email, identity.email.to_string());
                     }
                     IdentityProperty::TextSignature => {
-                        result.insert_unchecked(
-                            IdentityProperty::TextSignature,
-                            identity.text_signature.to_string(),
-                        );
+                        let signature = if let Some(blob_hash) = &settings.text_signature {
+                            self.identity_signature(blob_hash).await?
+                        } else {
+                            identity.text_signature.to_string()
+                        };
+                        result.insert_unchecked(IdentityProperty::TextSignature, signature);
                     }
                     IdentityProperty::HtmlSignature => {
+                        let signature = if let Some(blob_hash) = &settings.html_signature {
+                            self.identity_signature(blob_hash).await?
+                        } else {
+                            identity.html_signature.to_string()
+                        };
+                        result.insert_unchecked(IdentityProperty::HtmlSignature, signature);
+                    }
+                    IdentityProperty::BccToSelf => {
                         result.insert_unchecked(
-                            IdentityProperty::HtmlSignature,
-                            identity.html_signature.to_string(),
+                            IdentityProperty::BccToSelf,
+                            Value::Bool(settings.bcc_to_self),
                         );
                     }
                     IdentityProperty::Bcc => {
@@ -137,6 +164,20 @@ impl IdentityGet for Server {
                             email_to_value(&identity.reply_to),
                         );
                     }
+                    IdentityProperty::DelegatedTo => {
+                        result.insert_unchecked(
+                            IdentityProperty::DelegatedTo,
+                            Value::Array(
+                                self.identity_delegates(account_id, document_id)
+                                    .await?
+                                    .into_iter()
+                                    .map(|account_id| {
+                                        Value::Element(IdentityValue::Id(account_id.into()))
+                                    })
+                                    .collect(),
+                            ),
+                        );
+                    }
                     property => {
                         result.insert_unchecked(property.clone(), Value::Null);
                     }
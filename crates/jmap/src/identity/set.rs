@@ -5,7 +5,11 @@
  */
 
 use common::{Server, storage::index::ObjectIndexBuilder};
-use email::identity::{EmailAddress, Identity};
+use email::identity::{
+    EmailAddress, Identity,
+    delegation::IdentityDelegation,
+    settings::{IdentitySettings, IdentitySettingsStore, MAX_SIGNATURE_SIZE},
+};
 use jmap_proto::{
     error::set::{SetError, SetErrorType},
     method::set::{SetRequest, SetResponse},
@@ -18,13 +22,15 @@ use jmap_tools::{Key, Value};
 use registry::schema::enums::StorageQuota;
 use std::future::Future;
 use store::{
-    ValueKey,
+    Serialize, ValueKey,
+    roaring::RoaringBitmap,
     write::{AlignedBytes, Archive, BatchBuilder},
 };
 use trc::AddContext;
 use types::{
     collection::{Collection, SyncCollection},
     field::{Field, IdentityField},
+    id::Id,
 };
 use utils::sanitize_email;
 
@@ -33,6 +39,14 @@ pub trait IdentitySet: Sync + Send {
         &self,
         request: SetRequest<'_, identity::Identity>,
     ) -> impl Future<Output = trc::Result<SetResponse<identity::Identity>>> + Send;
+
+    fn validate_identity_delegates(
+        &self,
+        account_id: u32,
+        addresses: &[String],
+        email: &str,
+        delegates: Option<&RoaringBitmap>,
+    ) -> impl Future<Output = trc::Result<Result<(), SetError<IdentityProperty>>>> + Send;
 }
 
 impl IdentitySet for Server {
@@ -55,23 +69,38 @@ impl IdentitySet for Server {
         let mut batch = BatchBuilder::new();
         'create: for (id, object) in request.unwrap_create() {
             let mut identity = Identity::default();
+            let mut settings = IdentitySettings::default();
+            let mut delegates = None;
 
             for (property, mut value) in object.into_expanded_object() {
                 if let Err(err) = response
                     .resolve_self_references(&mut value, 0, false)
-                    .and_then(|_| validate_identity_value(&property, value, &mut identity, true))
+                    .and_then(|_| {
+                        validate_identity_value(
+                            &property,
+                            value,
+                            &mut identity,
+                            &mut settings,
+                            &mut delegates,
+                            true,
+                        )
+                    })
                 {
                     response.not_created.append(id, err);
                     continue 'create;
                 }
             }
 
-            // Validate email address
+            // Validate email address, addresses delegated by other accounts are also allowed
             if !identity.email.is_empty() {
                 if !account_info
                     .addresses()
                     .iter()
                     .any(|e| e == &identity.email)
+                    && !self
+                        .is_delegated_sender(account_id, &identity.email)
+                        .await
+                        .caused_by(trc::location!())?
                 {
                     response.not_created.append(
                         id,
@@ -93,6 +122,20 @@ impl IdentitySet for Server {
                 continue 'create;
             }
 
+            // Validate delegates
+            if let Err(err) = self
+                .validate_identity_delegates(
+                    account_id,
+                    account_info.addresses(),
+                    &identity.email,
+                    delegates.as_ref(),
+                )
+                .await?
+            {
+                response.not_created.append(id, err);
+                continue 'create;
+            }
+
             // Validate quota
             if identity_ids.len()
                 >= self.object_quota(
@@ -119,11 +162,21 @@ impl IdentitySet for Server {
             batch
                 .with_account_id(account_id)
                 .with_collection(Collection::Identity)
-                .with_document(document_id)
+                .with_document(document_id);
+            self.store_identity_signatures(account_id, &mut identity, &mut settings, &mut batch)
+                .await?;
+            batch
                 .tag(IdentityField::DocumentId)
                 .custom(ObjectIndexBuilder::<(), _>::new().with_changes(identity))
-                .caused_by(trc::location!())?
-                .commit_point();
+                .caused_by(trc::location!())?;
+            settings.update(&mut batch, &IdentitySettings::default())?;
+            if let Some(delegates) = delegates.filter(|delegates| !delegates.is_empty()) {
+                batch.set(
+                    IdentityField::DelegatedTo,
+                    delegates.serialize().caused_by(trc::location!())?,
+                );
+            }
+            batch.commit_point();
             response.created(id, document_id);
         }
 
@@ -157,12 +210,23 @@ impl IdentitySet for Server {
             let mut new_identity = identity
                 .deserialize::<Identity>()
                 .caused_by(trc::location!())?;
+            let current_settings = self.identity_settings(account_id, document_id).await?;
+            let mut settings = current_settings.clone();
+
+            let mut delegates = None;
 
             for (property, mut value) in object.into_expanded_object() {
                 if let Err(err) = response
                     .resolve_self_references(&mut value, 0, false)
                     .and_then(|_| {
-                        validate_identity_value(&property, value, &mut new_identity, false)
+                        validate_identity_value(
+                            &property,
+                            value,
+                            &mut new_identity,
+                            &mut settings,
+                            &mut delegates,
+                            false,
+                        )
                     })
                 {
                     response.not_updated.append(id, err);
@@ -170,18 +234,53 @@ impl IdentitySet for Server {
                 }
             }
 
+            // Validate delegates
+            if let Err(err) = self
+                .validate_identity_delegates(
+                    account_id,
+                    account_info.addresses(),
+                    &new_identity.email,
+                    delegates.as_ref(),
+                )
+                .await?
+            {
+                response.not_updated.append(id, err);
+                continue 'update;
+            }
+
             // Update record
             batch
                 .with_account_id(account_id)
                 .with_collection(Collection::Identity)
-                .with_document(document_id)
+                .with_document(document_id);
+            self.store_identity_signatures(
+                account_id,
+                &mut new_identity,
+                &mut settings,
+                &mut batch,
+            )
+            .await?;
+            batch
                 .custom(
                     ObjectIndexBuilder::new()
                         .with_current(identity)
                         .with_changes(new_identity),
                 )
-                .caused_by(trc::location!())?
-                .commit_point();
+                .caused_by(trc::location!())?;
+            settings.update(&mut batch, &current_settings)?;
+            match delegates {
+                Some(delegates) if !delegates.is_empty() => {
+                    batch.set(
+                        IdentityField::DelegatedTo,
+                        delegates.serialize().caused_by(trc::location!())?,
+                    );
+                }
+                Some(_) => {
+                    batch.clear(IdentityField::DelegatedTo);
+                }
+                None => (),
+            }
+            batch.commit_point();
             response.updated.append(id, None);
         }
 
@@ -189,13 +288,18 @@ impl IdentitySet for Server {
         for id in will_destroy {
             let document_id = id.document_id();
             if identity_ids.contains(document_id) {
+                let settings = self.identity_settings(account_id, document_id).await?;
+
                 // Update record
                 batch
                     .with_account_id(account_id)
                     .with_collection(Collection::Identity)
-                    .with_document(document_id)
+                    .with_document(document_id);
+                settings.remove(&mut batch);
+                batch
                     .untag(IdentityField::DocumentId)
                     .clear(Field::ARCHIVE)
+                    .clear(IdentityField::DelegatedTo)
                     .log_item_delete(SyncCollection::Identity, None)
                     .commit_point();
                 response.destroyed.push(id);
@@ -217,12 +321,60 @@ impl IdentitySet for Server {
 
         Ok(response)
     }
+
+    async fn validate_identity_delegates(
+        &self,
+        account_id: u32,
+        addresses: &[String],
+        email: &str,
+        delegates: Option<&RoaringBitmap>,
+    ) -> trc::Result<Result<(), SetError<IdentityProperty>>> {
+        let Some(delegates) = delegates.filter(|delegates| !delegates.is_empty()) else {
+            return Ok(Ok(()));
+        };
+
+        // Only addresses the directory assigns to the account can be delegated,
+        // addresses delegated by other accounts cannot be delegated further.
+        if !addresses.iter().any(|address| address == email) {
+            return Ok(Err(SetError::invalid_properties()
+                .with_property(IdentityProperty::DelegatedTo)
+                .with_description(
+                    "Only addresses configured for this account can be delegated.",
+                )));
+        }
+
+        for delegate_id in delegates {
+            if delegate_id == account_id {
+                return Ok(Err(SetError::invalid_properties()
+                    .with_property(IdentityProperty::DelegatedTo)
+                    .with_description(
+                        "Identities cannot be delegated to their owner.",
+                    )));
+            } else if self
+                .try_account(delegate_id)
+                .await
+                .caused_by(trc::location!())?
+                .is_none()
+            {
+                return Ok(Err(SetError::invalid_properties()
+                    .with_property(IdentityProperty::DelegatedTo)
+                    .with_description(format!(
+                        "Account {} does not exist.",
+                        Id::from(delegate_id)
+                    ))));
+            }
+        }
+
+        Ok(Ok(()))
+    }
 }
 
 fn validate_identity_value(
     property: &Key<'_, IdentityProperty>,
     value: Value<'_, IdentityProperty, IdentityValue>,
     identity: &mut Identity,
+    settings: &mut IdentitySettings,
+    delegates: &mut Option<RoaringBitmap>,
     is_create: bool,
 ) -> Result<(), SetError<IdentityProperty>> {
     let Key::Property(property) = property else {
@@ -242,11 +394,30 @@ fn validate_identity_value(
                     .with_description("Invalid e-mail address.")
             })?;
         }
-        (IdentityProperty::TextSignature, Value::Str(value)) if value.len() < 2048 => {
+        (IdentityProperty::TextSignature, Value::Str(value))
+            if value.len() <= MAX_SIGNATURE_SIZE =>
+        {
             identity.text_signature = value.into_owned();
+            settings.text_signature = None;
         }
-        (IdentityProperty::HtmlSignature, Value::Str(value)) if value.len() < 2048 => {
+        (IdentityProperty::HtmlSignature, Value::Str(value))
+            if value.len() <= MAX_SIGNATURE_SIZE =>
+        {
             identity.html_signature = value.into_owned();
+            settings.html_signature = None;
+        }
+        (IdentityProperty::TextSignature | IdentityProperty::HtmlSignature, Value::Str(_)) => {
+            return Err(SetError::new(SetErrorType::TooLarge)
+                .with_property(property.clone())
+                .with_description(format!(
+                    "Signatures cannot exceed {MAX_SIGNATURE_SIZE} bytes."
+                )));
+        }
+        (IdentityProperty::BccToSelf, Value::Bool(value)) => {
+            settings.bcc_to_self = value;
+        }
+        (IdentityProperty::BccToSelf, Value::Null) => {
+            settings.bcc_to_self = false;
         }
         (IdentityProperty::ReplyTo | IdentityProperty::Bcc, Value::Array(value)) => {
             let mut addresses = Vec::with_capacity(value.len());
@@ -298,14 +469,32 @@ fn validate_identity_value(
                 _ => unreachable!(),
             }
         }
+        (IdentityProperty::DelegatedTo, Value::Array(value)) => {
+            let mut account_ids = RoaringBitmap::new();
+            for item in value {
+                if let Value::Element(IdentityValue::Id(id)) = item {
+                    account_ids.insert(id.document_id());
+                } else {
+                    return Err(SetError::invalid_properties()
+                        .with_property(IdentityProperty::DelegatedTo)
+                        .with_description("Invalid account id."));
+                }
+            }
+            *delegates = Some(account_ids);
+        }
+        (IdentityProperty::DelegatedTo, Value::Null) => {
+            *delegates = Some(RoaringBitmap::new());
+        }
         (IdentityProperty::Name, Value::Null) => {
             identity.name.clear();
         }
         (IdentityProperty::TextSignature, Value::Null) => {
             identity.text_signature.clear();
+            settings.text_signature = None;
         }
         (IdentityProperty::HtmlSignature, Value::Null) => {
             identity.html_signature.clear();
+            settings.html_signature = None;
         }
         (IdentityProperty::ReplyTo, Value::Null) => identity.reply_to = None,
         (IdentityProperty::Bcc, Value::Null) => identity.bcc = None,
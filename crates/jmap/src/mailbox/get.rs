@@ -5,7 +5,10 @@
  */
 
 use common::{Server, auth::AccessToken, sharing::EffectiveAcl};
-use email::cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
+    mailbox::virtual_folder::{VirtualMailbox, VirtualMailboxes, is_virtual_mailbox},
+};
 use jmap_proto::{
     method::get::{GetRequest, GetResponse},
     object::mailbox::{Mailbox, MailboxProperty, MailboxValue},
@@ -13,7 +16,9 @@ use jmap_proto::{
 use jmap_tools::{Map, Value};
 use std::future::Future;
 use store::ahash::AHashSet;
+use trc::AddContext;
 use types::{acl::Acl, keyword::Keyword, special_use::SpecialUse};
+use utils::map::bitmap::Bitmap;
 
 use crate::api::acl::JmapRights;
 
@@ -52,6 +57,11 @@ impl MailboxGet for Server {
         } else {
             None
         };
+        let virtual_folders = if shared_ids.is_none() {
+            self.virtual_folders(account_id).await?
+        } else {
+            &[]
+        };
         let ids = if let Some(ids) = ids {
             ids
         } else {
@@ -61,10 +71,31 @@ impl MailboxGet for Server {
                 .keys()
                 .filter(|id| shared_ids.as_ref().is_none_or(|ids| ids.contains(**id)))
                 .copied()
+                .chain(virtual_folders.iter().map(|folder| {
+                    VirtualMailbox::new(folder.folder_type, &self.core.email.virtual_recent_period)
+                        .id()
+                }))
                 .take(self.core.jmap.get_max_objects)
                 .map(Into::into)
                 .collect::<Vec<_>>()
         };
+        let status = if properties.iter().any(|property| {
+            matches!(
+                property,
+                MailboxProperty::TotalEmails
+                    | MailboxProperty::UnreadEmails
+                    | MailboxProperty::TotalThreads
+                    | MailboxProperty::UnreadThreads
+            )
+        }) {
+            Some(
+                self.get_mailbox_status(account_id, &cache)
+                    .await
+                    .caused_by(trc::location!())?,
+            )
+        } else {
+            None
+        };
         let mut response = GetResponse {
             account_id: request.account_id.into(),
             state: Some(cache.mailboxes.change_id.into()),
@@ -75,6 +106,75 @@ impl MailboxGet for Server {
         for id in ids {
             // Obtain the mailbox object
             let document_id = id.document_id();
+            if is_virtual_mailbox(document_id) {
+                if let Some((folder, virtual_mailbox)) = virtual_folders.iter().find_map(|folder| {
+                    let virtual_mailbox = VirtualMailbox::new(
+                        folder.folder_type,
+                        &self.core.email.virtual_recent_period,
+                    );
+                    (virtual_mailbox.id() == document_id).then_some((folder, virtual_mailbox))
+                }) {
+                    let messages = self
+                        .virtual_mailbox_messages(account_id, virtual_mailbox, &cache)
+                        .await
+                        .caused_by(trc::location!())?;
+                    let mut mailbox = Map::with_capacity(properties.len());
+
+                    for property in &properties {
+                        let value = match property {
+                            MailboxProperty::Id => Value::Element(MailboxValue::Id(id)),
+                            MailboxProperty::Name => Value::Str(folder.name.clone().into()),
+                            MailboxProperty::Role => match virtual_mailbox.role() {
+                                SpecialUse::None => Value::Null,
+                                role => Value::Element(MailboxValue::Role(role)),
+                            },
+                            MailboxProperty::SortOrder => Value::Number(0u32.into()),
+                            MailboxProperty::TotalEmails => Value::Number(messages.len().into()),
+                            MailboxProperty::UnreadEmails => Value::Number(
+                                messages
+                                    .iter()
+                                    .filter(|m| !cache.has_keyword(m, &Keyword::Seen))
+                                    .count()
+                                    .into(),
+                            ),
+                            MailboxProperty::TotalThreads => Value::Number(
+                                messages
+                                    .iter()
+                                    .map(|m| m.thread_id)
+                                    .collect::<AHashSet<_>>()
+                                    .len()
+                                    .into(),
+                            ),
+                            MailboxProperty::UnreadThreads => Value::Number(
+                                messages
+                                    .iter()
+                                    .filter(|m| !cache.has_keyword(m, &Keyword::Seen))
+                                    .map(|m| m.thread_id)
+                                    .collect::<AHashSet<_>>()
+                                    .len()
+                                    .into(),
+                            ),
+                            // Virtual mailboxes are read-only
+                            MailboxProperty::MyRights => {
+                                JmapRights::rights::<Mailbox>(Bitmap::from_iter([
+                                    Acl::Read,
+                                    Acl::ReadItems,
+                                ]))
+                            }
+                            MailboxProperty::IsSubscribed => Value::Bool(folder.subscribe),
+                            _ => Value::Null,
+                        };
+
+                        mailbox.insert_unchecked(property.clone(), value);
+                    }
+
+                    response.list.push(mailbox.into());
+                } else {
+                    response.not_found.push(id);
+                }
+                continue;
+            }
+
             let cached_mailbox = if let Some(mailbox) =
                 cache.mailbox_by_id(&document_id).filter(|_| {
                     shared_ids
@@ -87,6 +187,10 @@ impl MailboxGet for Server {
                 continue;
             };
 
+            let mailbox_status = status
+                .and_then(|status| status.get(&document_id))
+                .copied()
+                .unwrap_or_default();
             let mut mailbox = Map::with_capacity(properties.len());
 
             for property in &properties {
@@ -108,30 +212,17 @@ impl MailboxGet for Server {
                         }
                     }
                     MailboxProperty::TotalEmails => {
-                        Value::Number(cache.in_mailbox(document_id).count().into())
+                        Value::Number(mailbox_status.total_messages.into())
+                    }
+                    MailboxProperty::UnreadEmails => {
+                        Value::Number(mailbox_status.total_unseen.into())
+                    }
+                    MailboxProperty::TotalThreads => {
+                        Value::Number(mailbox_status.total_threads.into())
+                    }
+                    MailboxProperty::UnreadThreads => {
+                        Value::Number(mailbox_status.unread_threads.into())
                     }
-                    MailboxProperty::UnreadEmails => Value::Number(
-                        cache
-                            .in_mailbox_without_keyword(document_id, &Keyword::Seen)
-                            .count()
-                            .into(),
-                    ),
-                    MailboxProperty::TotalThreads => Value::Number(
-                        cache
-                            .in_mailbox(document_id)
-                            .map(|m| m.thread_id)
-                            .collect::<AHashSet<_>>()
-                            .len()
-                            .into(),
-                    ),
-                    MailboxProperty::UnreadThreads => Value::Number(
-                        cache
-                            .in_mailbox_without_keyword(document_id, &Keyword::Seen)
-                            .map(|m| m.thread_id)
-                            .collect::<AHashSet<_>>()
-                            .len()
-                            .into(),
-                    ),
                     MailboxProperty::MyRights => {
                         if access_token.is_shared(account_id) {
                             JmapRights::rights::<Mailbox>(
@@ -18,6 +18,7 @@ use email::{
     mailbox::{
         Mailbox,
         destroy::{MailboxDestroy, MailboxDestroyError},
+        virtual_folder::is_virtual_mailbox,
     },
 };
 use jmap_proto::{
@@ -173,6 +174,15 @@ impl MailboxSet for Server {
                 continue 'update;
             };
 
+            // Virtual mailboxes are read-only
+            if is_virtual_mailbox(id.document_id()) {
+                ctx.response.not_updated.append(
+                    id,
+                    SetError::forbidden().with_description("Virtual mailboxes cannot be modified."),
+                );
+                continue 'update;
+            }
+
             // Obtain mailbox
             let document_id = id.document_id();
             if let Some(mailbox) = self
@@ -189,8 +199,14 @@ impl MailboxSet for Server {
                     .into_deserialized::<email::mailbox::Mailbox>()
                     .caused_by(trc::location!())?;
                 if ctx.is_shared {
+                    // Subscribing only requires the mailbox to be visible, as in IMAP
                     let acl = mailbox.inner.acls.effective_acl(access_token);
-                    if !acl.contains(Acl::Modify) {
+                    let is_subscription_only = object
+                        .keys()
+                        .all(|key| matches!(key, Key::Property(MailboxProperty::IsSubscribed)));
+                    if !acl.contains(Acl::Modify)
+                        && !(is_subscription_only && acl.contains(Acl::Read))
+                    {
                         ctx.response.not_updated.append(
                             id,
                             SetError::forbidden()
@@ -271,12 +287,47 @@ impl MailboxSet for Server {
             }
         }
 
-        // Process deletions
+        // Process deletions, sub-mailboxes destroyed in the same request are
+        // removed along with their parent in a single batch
+        let cache = self.get_cached_messages(account_id).await?;
+        ctx.will_destroy.retain(|id| {
+            if is_virtual_mailbox(id.document_id()) {
+                ctx.response.not_destroyed.append(
+                    *id,
+                    SetError::forbidden()
+                        .with_description("Virtual mailboxes cannot be destroyed."),
+                );
+                false
+            } else {
+                true
+            }
+        });
+        let destroy_ids =
+            RoaringBitmap::from_iter(ctx.will_destroy.iter().map(|id| id.document_id()));
+        let mut destroy_groups: Vec<(u32, Vec<Id>)> = Vec::new();
         for id in ctx.will_destroy {
+            let mut root_id = id.document_id();
+            let mut depth = 0;
+            while let Some(mailbox) = cache.mailbox_by_id(&root_id)
+                && destroy_ids.contains(mailbox.parent_id)
+                && depth < cache.mailboxes.items.len()
+            {
+                root_id = mailbox.parent_id;
+                depth += 1;
+            }
+
+            if let Some((_, ids)) = destroy_groups.iter_mut().find(|(id, _)| *id == root_id) {
+                ids.push(id);
+            } else {
+                destroy_groups.push((root_id, vec![id]));
+            }
+        }
+
+        for (_, ids) in destroy_groups {
             match self
-                .mailbox_destroy(
+                .mailboxes_destroy(
                     account_id,
-                    id.document_id(),
+                    &RoaringBitmap::from_iter(ids.iter().map(|id| id.document_id())),
                     ctx.access_token,
                     on_destroy_remove_emails,
                 )
@@ -286,34 +337,24 @@ impl MailboxSet for Server {
                     if change_id_.is_some() {
                         change_id = change_id_;
                     }
-                    ctx.response.destroyed.push(id);
+                    ctx.response.destroyed.extend(ids);
                 }
-                Err(err) => {
-                    ctx.response.not_destroyed.append(
-                        id,
-                        match err {
-                            MailboxDestroyError::CannotDestroy => SetError::forbidden()
-                                .with_description(
-                                    "You are not allowed to delete Inbox, Junk or Trash folders.",
-                                ),
-                            MailboxDestroyError::Forbidden => SetError::forbidden()
-                                .with_description("You are not allowed to delete this mailbox."),
-                            MailboxDestroyError::HasChildren => {
-                                SetError::new(SetErrorType::MailboxHasChild)
-                                    .with_description("Mailbox has at least one children.")
-                            }
-                            MailboxDestroyError::HasEmails => {
-                                SetError::new(SetErrorType::MailboxHasEmail)
-                                    .with_description("Mailbox is not empty.")
-                            }
-                            MailboxDestroyError::NotFound => SetError::not_found(),
-                            MailboxDestroyError::AssertionFailed => SetError::forbidden()
-                                .with_description(concat!(
-                                    "Another process modified a message in this mailbox ",
-                                    "while deleting it, please try again."
-                                )),
-                        },
-                    );
+                Err((failed_id, err)) => {
+                    let mut err = Some(err);
+                    for id in ids {
+                        ctx.response.not_destroyed.append(
+                            id,
+                            if id.document_id() == failed_id
+                                && let Some(err) = err.take()
+                            {
+                                destroy_error(err)
+                            } else {
+                                SetError::forbidden().with_description(
+                                    "A mailbox in the same hierarchy could not be destroyed.",
+                                )
+                            },
+                        );
+                    }
                 }
             }
         }
@@ -337,7 +378,13 @@ impl MailboxSet for Server {
         let mut changes = update
             .as_ref()
             .map(|(_, obj)| obj.inner.clone())
-            .unwrap_or_else(|| Mailbox::new(String::new()));
+            .unwrap_or_else(|| {
+                let mut mailbox = Mailbox::new(String::new());
+                if self.core.email.subscribe_on_create.jmap {
+                    mailbox.add_subscriber(ctx.access_token.account_id());
+                }
+                mailbox
+            });
         let mut has_acl_changes = false;
         for (property, mut value) in changes_.into_vec() {
             if let Err(err) = ctx.response.resolve_self_references(&mut value, 0, false) {
@@ -369,6 +416,10 @@ impl MailboxSet for Server {
                     if ctx.will_destroy.contains(&value) {
                         return Ok(Err(SetError::will_destroy()
                             .with_description("Parent ID will be destroyed.")));
+                    } else if is_virtual_mailbox(parent_id) {
+                        return Ok(Err(SetError::forbidden().with_description(
+                            "Sub mailboxes cannot be created under a virtual mailbox.",
+                        )));
                     } else if !ctx.mailbox_ids.contains(parent_id) {
                         return Ok(Err(SetError::invalid_properties()
                             .with_description("Parent ID does not exist.")));
@@ -591,3 +642,28 @@ impl MailboxSet for Server {
             .with_current_opt(current)))
     }
 }
+
+fn destroy_error(err: MailboxDestroyError) -> SetError<MailboxProperty> {
+    match err {
+        MailboxDestroyError::CannotDestroy => SetError::forbidden()
+            .with_description("You are not allowed to delete Inbox, Junk or Trash folders."),
+        MailboxDestroyError::Forbidden => {
+            SetError::forbidden().with_description("You are not allowed to delete this mailbox.")
+        }
+        MailboxDestroyError::HasChildren => SetError::new(SetErrorType::MailboxHasChild)
+            .with_description("Mailbox has at least one children."),
+        MailboxDestroyError::HasEmails => {
+            SetError::new(SetErrorType::MailboxHasEmail).with_description("Mailbox is not empty.")
+        }
+        MailboxDestroyError::HasSharedEmails => SetError::new(SetErrorType::MailboxHasEmail)
+            .with_description(concat!(
+                "Mailbox contains emails owned by another account ",
+                "that cannot be removed."
+            )),
+        MailboxDestroyError::NotFound => SetError::not_found(),
+        MailboxDestroyError::AssertionFailed => SetError::forbidden().with_description(concat!(
+            "Another process modified a message in this mailbox ",
+            "while deleting it, please try again."
+        )),
+    }
+}
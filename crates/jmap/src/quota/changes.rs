@@ -0,0 +1,72 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{QuotaItems, quota_state, quota_state_ids};
+use common::{Server, auth::AccessToken};
+use jmap_proto::{
+    method::changes::{ChangesRequest, ChangesResponse},
+    object::quota::Quota,
+    types::state::State,
+};
+use std::future::Future;
+use types::id::Id;
+
+pub trait QuotaChanges: Sync + Send {
+    fn quota_changes(
+        &self,
+        request: ChangesRequest,
+        access_token: &AccessToken,
+    ) -> impl Future<Output = trc::Result<ChangesResponse<Quota>>> + Send;
+}
+
+impl QuotaChanges for Server {
+    async fn quota_changes(
+        &self,
+        request: ChangesRequest,
+        _access_token: &AccessToken,
+    ) -> trc::Result<ChangesResponse<Quota>> {
+        let quotas = self.quota_items(request.account_id.document_id()).await?;
+        let new_state = quota_state(&quotas);
+        let mut response = ChangesResponse {
+            account_id: request.account_id,
+            old_state: request.since_state.clone(),
+            new_state: new_state.clone(),
+            has_more_changes: false,
+            created: vec![],
+            updated: vec![],
+            destroyed: vec![],
+            updated_properties: None,
+        };
+
+        // Without a change log the previous values are unknown, so any
+        // state mismatch reports every remaining quota as updated. Quotas
+        // that no longer exist are obtained from the ids encoded in the state.
+        match (request.since_state, &new_state) {
+            (State::Initial, _) => {
+                response.created = quotas.iter().map(|quota| Id::from(quota.id)).collect();
+            }
+            (State::Exact(since_state), State::Exact(new_state)) if since_state != *new_state => {
+                for quota in &quotas {
+                    if since_state & (1 << quota.id) != 0 {
+                        response.updated.push(Id::from(quota.id));
+                    } else {
+                        response.created.push(Id::from(quota.id));
+                    }
+                }
+                response.destroyed = quota_state_ids(since_state)
+                    .filter(|id| !quota_state_ids(*new_state).any(|new_id| new_id == *id))
+                    .map(Id::from)
+                    .collect();
+            }
+            (State::Exact(_), _) => {}
+            _ => {
+                return Err(trc::JmapEvent::CannotCalculateChanges.into_err());
+            }
+        }
+
+        Ok(response)
+    }
+}
@@ -4,5 +4,153 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
+use common::Server;
+use email::{cache::MessageCacheFetch, message::drafts::drafts_usage};
+use jmap_proto::types::state::State;
+use registry::schema::enums::StorageQuota;
+use std::future::Future;
+use store::blake3;
+use trc::AddContext;
+use types::type_state::DataType;
+
+pub mod changes;
 pub mod get;
 pub mod query;
+
+pub const DISK_QUOTA_ID: u32 = 0;
+pub const DRAFTS_COUNT_QUOTA_ID: u32 = 1;
+pub const DRAFTS_SIZE_QUOTA_ID: u32 = 2;
+pub const MAIL_COUNT_QUOTA_ID: u32 = 3;
+
+const QUOTA_IDS_MASK: u64 = 0xFF;
+
+pub struct QuotaItem {
+    pub id: u32,
+    pub resource_type: &'static str,
+    pub used: u64,
+    pub hard_limit: u64,
+    pub warn_limit: Option<u64>,
+    pub name: String,
+    pub description: Option<String>,
+    pub types: &'static [DataType],
+}
+
+pub trait QuotaItems: Sync + Send {
+    fn quota_items(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<Vec<QuotaItem>>> + Send;
+}
+
+impl QuotaItems for Server {
+    async fn quota_items(&self, account_id: u32) -> trc::Result<Vec<QuotaItem>> {
+        let account = self.account(account_id).await.caused_by(trc::location!())?;
+        let mut items = Vec::with_capacity(4);
+
+        if account.disk_quota() > 0 {
+            items.push(QuotaItem {
+                id: DISK_QUOTA_ID,
+                resource_type: "octets",
+                used: self
+                    .get_used_quota_account(account_id)
+                    .await
+                    .caused_by(trc::location!())?
+                    .max(0) as u64,
+                hard_limit: account.disk_quota(),
+                warn_limit: None,
+                name: account.name().to_string(),
+                description: account.description().map(|s| s.to_string()),
+                types: &[
+                    DataType::Email,
+                    DataType::SieveScript,
+                    DataType::FileNode,
+                    DataType::CalendarEvent,
+                    DataType::ContactCard,
+                ],
+            });
+        }
+
+        // Message counts and drafts are both obtained from the message cache
+        let max_emails = self.object_quota(account.object_quotas(), StorageQuota::MaxEmails);
+        if max_emails != u32::MAX
+            || self.core.email.drafts_max_count.is_some()
+            || self.core.email.drafts_max_size.is_some()
+        {
+            let cache = self
+                .get_cached_messages(account_id)
+                .await
+                .caused_by(trc::location!())?;
+            let drafts = drafts_usage(&cache);
+
+            if let Some(limit) = self.core.email.drafts_max_count {
+                items.push(QuotaItem {
+                    id: DRAFTS_COUNT_QUOTA_ID,
+                    resource_type: "count",
+                    used: drafts.count as u64,
+                    hard_limit: limit as u64,
+                    warn_limit: Some(limit as u64 / 10 * 9),
+                    name: "Drafts".to_string(),
+                    description: Some(
+                        "Drafts over this limit are removed automatically, oldest first."
+                            .to_string(),
+                    ),
+                    types: &[DataType::Email],
+                });
+            }
+            if let Some(limit) = self.core.email.drafts_max_size {
+                items.push(QuotaItem {
+                    id: DRAFTS_SIZE_QUOTA_ID,
+                    resource_type: "octets",
+                    used: drafts.size,
+                    hard_limit: limit,
+                    warn_limit: Some(limit / 10 * 9),
+                    name: "Drafts".to_string(),
+                    description: Some(
+                        "Drafts over this limit are removed automatically, oldest first."
+                            .to_string(),
+                    ),
+                    types: &[DataType::Email],
+                });
+            }
+            if max_emails != u32::MAX {
+                items.push(QuotaItem {
+                    id: MAIL_COUNT_QUOTA_ID,
+                    resource_type: "count",
+                    used: cache.emails.items.len() as u64,
+                    hard_limit: max_emails as u64,
+                    warn_limit: None,
+                    name: account.name().to_string(),
+                    description: None,
+                    types: &[DataType::Email],
+                });
+            }
+        }
+
+        Ok(items)
+    }
+}
+
+// Quotas have no change log, the state is derived from their current values
+// so it changes whenever any quota is added, removed or its usage changes.
+// The ids of the quotas that exist are kept in the lowest bits of the state,
+// which allows Quota/changes to report created and destroyed quotas.
+pub fn quota_state(items: &[QuotaItem]) -> State {
+    let mut hasher = blake3::Hasher::new();
+    let mut ids = 0;
+    for item in items {
+        hasher.update(&item.id.to_be_bytes());
+        hasher.update(&item.used.to_be_bytes());
+        hasher.update(&item.hard_limit.to_be_bytes());
+        ids |= 1 << item.id;
+    }
+    let hash = hasher.finalize();
+    State::Exact(
+        (u64::from_be_bytes(hash.as_bytes()[..8].try_into().unwrap()) & !QUOTA_IDS_MASK) | ids,
+    )
+}
+
+pub(crate) fn quota_state_ids(state: u64) -> impl Iterator<Item = u32> {
+    (0..u64::BITS)
+        .take_while(|id| 1 << id <= QUOTA_IDS_MASK)
+        .filter(move |id| state & (1 << id) != 0)
+}
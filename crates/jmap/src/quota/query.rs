@@ -4,11 +4,11 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
+use super::{QuotaItems, quota_state};
 use common::{Server, auth::AccessToken};
 use jmap_proto::{
     method::query::{QueryRequest, QueryResponse},
     object::quota::Quota,
-    types::state::State,
 };
 use std::future::Future;
 use types::id::Id;
@@ -25,19 +25,22 @@ impl QuotaQuery for Server {
     async fn quota_query(
         &self,
         request: QueryRequest<Quota>,
-        access_token: &AccessToken,
+        _access_token: &AccessToken,
     ) -> trc::Result<QueryResponse> {
+        let quotas = self.quota_items(request.account_id.document_id()).await?;
+        let ids = quotas
+            .iter()
+            .map(|quota| Id::from(quota.id))
+            .collect::<Vec<_>>();
+        let total = ids.len();
+
         Ok(QueryResponse {
             account_id: request.account_id,
-            query_state: State::Initial,
+            query_state: quota_state(&quotas),
             can_calculate_changes: false,
             position: 0,
-            ids: if self.account(access_token.account_id()).await?.disk_quota() > 0 {
-                vec![Id::new(0)]
-            } else {
-                vec![]
-            },
-            total: Some(1),
+            ids,
+            total: Some(total),
             limit: None,
         })
     }
@@ -7,9 +7,11 @@
 use crate::registry::{
     EnterpriseRegistry,
     mapping::{
-        RegistryGetResponse, account::account_get, bootstrap::bootstrap_get,
-        cluster::cluster_node_get, log::log_get, queued_message::queued_message_get,
-        report::report_get, spam_sample::spam_sample_get, task::task_get,
+        RegistryGetResponse, account::account_get, audit::audit_get, bootstrap::bootstrap_get,
+        cluster::cluster_node_get, delivery_trace::delivery_trace_get,
+        integrity::blob_integrity_get, log::log_get, login::login_get, quarantine::quarantine_get,
+        queued_message::queued_message_get, report::report_get, spam_sample::spam_sample_get,
+        statistics::statistics_get, task::task_get, usage::usage_get,
     },
 };
 use common::{Server, auth::AccessToken, network::dkim::generate_dkim_public_key};
@@ -130,6 +132,7 @@ impl RegistryGet for Server {
             | ObjectType::SystemSettings
             | ObjectType::MemoryLookupKey
             | ObjectType::MemoryLookupKeyValue
+            | ObjectType::DsnTemplate
             | ObjectType::Metrics
             | ObjectType::MetricsStore
             | ObjectType::MtaConnectionStrategy
@@ -301,6 +304,29 @@ impl RegistryGet for Server {
                                 JmapValue::Str(self.build_bind_dns_records(id, obj).await?.into()),
                             );
                         }
+                        ObjectInner::BlobStore(_)
+                            if !is_tenant_filtered
+                                && [
+                                    Property::BlobCount,
+                                    Property::BlobReferences,
+                                    Property::StoredBytes,
+                                    Property::DeduplicatedBytes,
+                                ]
+                                .iter()
+                                .any(|property| get.properties.contains(property)) =>
+                        {
+                            // Usage requires a full scan of the blob index, so it
+                            // is only returned when explicitly requested
+                            let usage = self.store().blob_usage().await?;
+                            for (property, value) in [
+                                (Property::BlobCount, usage.blobs),
+                                (Property::BlobReferences, usage.references),
+                                (Property::StoredBytes, usage.stored_bytes),
+                                (Property::DeduplicatedBytes, usage.deduplicated_bytes()),
+                            ] {
+                                extra_properties.append(property, JmapValue::Number(value.into()));
+                            }
+                        }
                         _ => {}
                     }
 
@@ -326,8 +352,21 @@ impl RegistryGet for Server {
             ObjectType::ArfExternalReport
             | ObjectType::DmarcExternalReport
             | ObjectType::TlsExternalReport
+            | ObjectType::TlsInboundReport
             | ObjectType::DmarcInternalReport
             | ObjectType::TlsInternalReport => report_get(get).await.map(|get| get.into_response()),
+            ObjectType::StatisticsRollup => {
+                statistics_get(get).await.map(|get| get.into_response())
+            }
+            ObjectType::TenantUsage => usage_get(get).await.map(|get| get.into_response()),
+            ObjectType::MessageAuditEntry => audit_get(get).await.map(|get| get.into_response()),
+            ObjectType::LoginEntry => login_get(get).await.map(|get| get.into_response()),
+            ObjectType::BlobIntegrityIssue => {
+                blob_integrity_get(get).await.map(|get| get.into_response())
+            }
+            ObjectType::DeliveryTrace => {
+                delivery_trace_get(get).await.map(|get| get.into_response())
+            }
 
             // SPDX-SnippetBegin
             // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
@@ -350,6 +389,9 @@ impl RegistryGet for Server {
             ObjectType::SpamTrainingSample => {
                 spam_sample_get(get).await.map(|get| get.into_response())
             }
+            ObjectType::QuarantinedMessage => {
+                quarantine_get(get).await.map(|get| get.into_response())
+            }
             ObjectType::Log => log_get(get).await.map(|get| get.into_response()),
             ObjectType::Bootstrap => bootstrap_get(get).await.map(|get| get.into_response()),
             ObjectType::AccountSettings
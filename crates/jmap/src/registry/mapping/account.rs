@@ -22,7 +22,7 @@ use common::{
         permissions::BuildPermissions,
     },
     cache::invalidate::CacheInvalidationBuilder,
-    ipc::CacheInvalidation,
+    ipc::{BroadcastEvent, CacheInvalidation},
 };
 use directory::core::secret::{SecretVerificationResult, hash_secret, verify_mfa_secret_hash};
 use jmap_proto::{error::set::SetError, types::state::State};
@@ -30,7 +30,7 @@ use jmap_tools::{JsonPointer, JsonPointerItem, Key, Map, Value};
 use registry::{
     jmap::{IntoValue, JsonPointerPatch, MaybeUnpatched, RegistryJsonPatch, RegistryValue},
     schema::{
-        enums::{CredentialType, StorageQuota},
+        enums::{CredentialType, Permission, StorageQuota},
         prelude::{MASKED_PASSWORD, Object, ObjectInner, ObjectType, Property},
         structs::{
             Account, AccountPassword, AccountSettings, Credential, CredentialPermissions, OtpAuth,
@@ -72,39 +72,102 @@ pub(crate) async fn account_set(
         return Ok(set);
     };
     let mut account = old_account.clone();
+    let mut catch_all = None;
 
     match set.object_type {
         ObjectType::AccountSettings => {
             'outer: for (id, value) in set.update.drain(..) {
                 if id != Id::singleton() {
                     set.response.not_updated.append(id, SetError::not_found());
+                    continue;
                 }
 
+                // Changes are only kept once the whole update has been validated
+                let mut updated_account = account.clone();
+                let mut updated_catch_all = None;
                 for (key, value) in value.into_expanded_object() {
-                    if let Key::Property(
-                        property @ (Property::EncryptionAtRest
-                        | Property::Locale
-                        | Property::Description
-                        | Property::TimeZone),
-                    ) = key
-                    {
-                        let ptr =
-                            JsonPointer::new(vec![JsonPointerItem::Key(Key::Property(property))]);
-                        if let Err(err) =
-                            account.patch(JsonPointerPatch::new(&ptr).with_create(false), value)
+                    let property = match key {
+                        Key::Property(
+                            property @ (Property::EncryptionAtRest
+                            | Property::Locale
+                            | Property::Description
+                            | Property::TimeZone
+                            | Property::AutoArchiveAfter),
+                        ) => property,
+                        Key::Property(Property::Aliases)
+                            if set
+                                .access_token
+                                .has_permission(Permission::AccountAliasManage) =>
                         {
-                            set.response.not_updated.append(id, err.into());
-                            break 'outer;
+                            Property::Aliases
                         }
-                    } else {
+                        Key::Property(Property::CatchAll)
+                            if set
+                                .access_token
+                                .has_permission(Permission::DomainCatchAllManage) =>
+                        {
+                            if let Value::Bool(catch_all) = value {
+                                updated_catch_all = Some(catch_all);
+                                continue;
+                            } else {
+                                set.response.not_updated.append(
+                                    id,
+                                    SetError::invalid_properties()
+                                        .with_property(Property::CatchAll)
+                                        .with_description("Expected a boolean value."),
+                                );
+                                continue 'outer;
+                            }
+                        }
+                        key => {
+                            set.response.not_updated.append(
+                                id,
+                                SetError::invalid_properties().with_property(key.into_owned()),
+                            );
+                            continue 'outer;
+                        }
+                    };
+
+                    let ptr = JsonPointer::new(vec![JsonPointerItem::Key(Key::Property(property))]);
+                    if let Err(err) =
+                        updated_account.patch(JsonPointerPatch::new(&ptr).with_create(false), value)
+                    {
+                        set.response.not_updated.append(id, err.into());
+                        continue 'outer;
+                    }
+                }
+
+                // Users may only add aliases on the domain of their own account
+                let now = now() as i64;
+                for alias in updated_account.aliases.values() {
+                    if old_account.aliases.values().any(|old| old == alias) {
+                        continue;
+                    } else if alias.domain_id != updated_account.domain_id {
                         set.response.not_updated.append(
                             id,
-                            SetError::invalid_properties().with_property(key.into_owned()),
+                            SetError::forbidden()
+                                .with_property(Property::Aliases)
+                                .with_description(
+                                    "Aliases must belong to the domain of the account.",
+                                ),
                         );
-                        break 'outer;
+                        continue 'outer;
+                    } else if alias
+                        .expires_at
+                        .is_some_and(|expires_at| expires_at.timestamp() <= now)
+                    {
+                        set.response.not_updated.append(
+                            id,
+                            SetError::invalid_properties()
+                                .with_property(Property::Aliases)
+                                .with_description("Alias expiration dates must be in the future."),
+                        );
+                        continue 'outer;
                     }
                 }
 
+                account = updated_account;
+                catch_all = updated_catch_all;
                 set.response.updated.append(id, None);
             }
         }
@@ -602,6 +665,9 @@ pub(crate) async fn account_set(
         _ => unreachable!(),
     }
 
+    let account_domain_id = account.domain_id;
+    let account_name = account.name.clone();
+
     if account != old_account {
         let mut cache_invalidator = CacheInvalidationBuilder::default();
         if account.encryption_at_rest != old_account.encryption_at_rest
@@ -613,6 +679,17 @@ pub(crate) async fn account_set(
         if account.credentials != old_account.credentials {
             cache_invalidator.invalidate(CacheInvalidation::AccessToken(set.account_id));
         }
+        let new_aliases = if account.aliases != old_account.aliases {
+            cache_invalidator.invalidate(CacheInvalidation::Account(set.account_id));
+            account
+                .aliases
+                .values()
+                .filter(|alias| !old_account.aliases.values().any(|old| old == *alias))
+                .map(|alias| (alias.name.to_lowercase(), alias.domain_id.document_id()))
+                .collect::<Vec<_>>()
+        } else {
+            vec![]
+        };
 
         let object = Object::new(ObjectInner::Account(Account::User(account)));
         let old_object = Object::with_revision(
@@ -633,6 +710,17 @@ pub(crate) async fn account_set(
             RegistryWriteResult::Success(_) => {
                 // Invalidate caches
                 set.server.invalidate_caches(cache_invalidator).await?;
+
+                // New aliases must be accepted as recipients right away
+                if !new_aliases.is_empty() {
+                    for (local_part, domain_id) in &new_aliases {
+                        set.server
+                            .invalidate_local_negative_account_cache(local_part, *domain_id);
+                    }
+                    set.server
+                        .cluster_broadcast(BroadcastEvent::CacheInvalidateNegative)
+                        .await;
+                }
             }
             err => {
                 let err = map_write_error(err);
@@ -665,6 +753,13 @@ pub(crate) async fn account_set(
         }
     }
 
+    // Point the catch-all address of the domain to this account, or clear it
+    if let Some(catch_all) = catch_all
+        && !set.response.updated.is_empty()
+    {
+        update_domain_catch_all(&mut set, account_domain_id, &account_name, catch_all).await?;
+    }
+
     Ok(set)
 }
 
@@ -690,6 +785,20 @@ pub(crate) async fn account_get(
 
             for id in ids.by_ref() {
                 if id == Id::singleton() {
+                    let catch_all = get
+                        .server
+                        .domain_by_id(account.domain_id.document_id())
+                        .await?
+                        .is_some_and(|domain| {
+                            domain.catch_all.as_ref().is_some_and(|catch_all| {
+                                catch_all.split_once('@').is_some_and(|(local_part, name)| {
+                                    local_part.eq_ignore_ascii_case(&account.name)
+                                        && domain.names.iter().any(|domain_name| {
+                                            domain_name.eq_ignore_ascii_case(name)
+                                        })
+                                })
+                            })
+                        });
                     get.insert(
                         id,
                         AccountSettings {
@@ -697,6 +806,9 @@ pub(crate) async fn account_get(
                             locale: account.locale,
                             description: account.description,
                             time_zone: account.time_zone,
+                            aliases: account.aliases,
+                            auto_archive_after: account.auto_archive_after,
+                            catch_all,
                         }
                         .into_value(),
                     );
@@ -915,3 +1027,63 @@ pub(crate) fn validate_credential_permissions(
             .map_err(build_set_error),
     }
 }
+
+async fn update_domain_catch_all(
+    set: &mut RegistrySetResponse<'_>,
+    domain_id: Id,
+    local_part: &str,
+    catch_all: bool,
+) -> trc::Result<()> {
+    let Some(object) = set
+        .server
+        .registry()
+        .get(ObjectId::new(ObjectType::Domain, domain_id))
+        .await?
+    else {
+        return Ok(());
+    };
+    let revision = object.revision;
+    let ObjectInner::Domain(old_domain) = object.inner else {
+        return Ok(());
+    };
+
+    let address = format!("{local_part}@{}", old_domain.name);
+    let is_catch_all = old_domain
+        .catch_all_address
+        .as_ref()
+        .is_some_and(|catch_all| catch_all.eq_ignore_ascii_case(&address));
+    let mut domain = old_domain.clone();
+    if catch_all && !is_catch_all {
+        domain.catch_all_address = Some(address);
+    } else if !catch_all && is_catch_all {
+        domain.catch_all_address = None;
+    } else {
+        return Ok(());
+    }
+
+    let object = Object::new(ObjectInner::Domain(domain));
+    let old_object = Object::with_revision(ObjectInner::Domain(old_domain), revision);
+    match set
+        .server
+        .registry()
+        .write(RegistryWrite::Update {
+            object: &object,
+            id: domain_id,
+            old_object: &old_object,
+        })
+        .await?
+    {
+        RegistryWriteResult::Success(_) => {
+            let mut cache_invalidator = CacheInvalidationBuilder::default();
+            cache_invalidator.process_update(domain_id, &old_object, &object);
+            set.server.invalidate_caches(cache_invalidator).await
+        }
+        err => {
+            let err = map_write_error(err);
+            for id in std::mem::take(&mut set.response.updated).into_keys() {
+                set.response.not_updated.append(id, err.clone());
+            }
+            Ok(())
+        }
+    }
+}
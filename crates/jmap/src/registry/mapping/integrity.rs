@@ -0,0 +1,233 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::query::QueryResponseBuilder,
+    registry::{
+        mapping::{RegistryGetResponse, RegistryQueryResponse, RegistrySetResponse},
+        query::RegistryQueryFilters,
+    },
+};
+use common::storage::integrity::BlobIntegrityIndex;
+use jmap_proto::{error::set::SetError, types::state::State};
+use registry::{
+    jmap::IntoValue,
+    schema::{enums::BlobIntegrityIssueType, prelude::Property, structs::BlobIntegrityIssue},
+    types::{EnumImpl, datetime::UTCDateTime},
+};
+use std::str::FromStr;
+use store::{
+    ValueKey,
+    registry::{RegistryFilter, RegistryQuery},
+    write::{BatchBuilder, RegistryClass, ValueClass},
+};
+use trc::AddContext;
+use types::id::Id;
+
+pub(crate) async fn blob_integrity_set(
+    mut set: RegistrySetResponse<'_>,
+) -> trc::Result<RegistrySetResponse<'_>> {
+    let object_id = set.object_type.to_id();
+
+    // Issues are reported by the blob store scrubber, administrators may only
+    // dismiss them once reviewed
+    set.fail_all_create("Blob integrity issues cannot be created");
+    set.fail_all_update("Blob integrity issues cannot be updated");
+
+    let mut batch = BatchBuilder::new();
+    for id in set.destroy.drain(..) {
+        let item_id = id.id();
+        if let Some(issue) = set
+            .server
+            .store()
+            .get_value::<BlobIntegrityIssue>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item { object_id, item_id },
+            )))
+            .await?
+            .filter(|_| !set.is_tenant_filtered)
+        {
+            issue.write_ops(&mut batch, item_id, false);
+            batch.commit_point();
+
+            set.response.destroyed.push(id);
+        } else {
+            set.response.not_destroyed.append(id, SetError::not_found());
+        }
+    }
+
+    if !batch.is_empty() {
+        set.server
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())?;
+    }
+
+    Ok(set)
+}
+
+pub(crate) async fn blob_integrity_get(
+    mut get: RegistryGetResponse<'_>,
+) -> trc::Result<RegistryGetResponse<'_>> {
+    let object_id = get.object_type.to_id();
+    let ids = if let Some(ids) = get.ids.take() {
+        ids
+    } else if !get.is_tenant_filtered {
+        get.server
+            .registry()
+            .query::<Vec<Id>>(
+                RegistryQuery::new(get.object_type)
+                    .greater_than(Property::ExpiresAt, 0u64)
+                    .with_limit(get.server.core.jmap.get_max_objects),
+            )
+            .await?
+    } else {
+        vec![]
+    };
+
+    for id in ids {
+        // The blob store is shared, so issues are only visible to system administrators
+        if let Some(issue) = get
+            .server
+            .store()
+            .get_value::<BlobIntegrityIssue>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item {
+                    object_id,
+                    item_id: id.id(),
+                },
+            )))
+            .await?
+            .filter(|_| !get.is_tenant_filtered)
+        {
+            get.insert(id, issue.into_value());
+        } else {
+            get.not_found(id);
+        }
+    }
+
+    Ok(get)
+}
+
+pub(crate) async fn blob_integrity_query(
+    mut req: RegistryQueryResponse<'_>,
+) -> trc::Result<QueryResponseBuilder> {
+    let mut query = RegistryQuery::new(req.object_type);
+
+    req.request
+        .extract_filters(|property, op, value| match property {
+            Property::BlobHash => {
+                if let Some(hash) = value.as_str() {
+                    query
+                        .filters
+                        .push(RegistryFilter::equal(property, hash.to_string(), false));
+                    true
+                } else {
+                    false
+                }
+            }
+            Property::Issue => {
+                if let Some(issue) = value.as_str().and_then(BlobIntegrityIssueType::parse) {
+                    query.filters.push(RegistryFilter::equal(
+                        property,
+                        issue.to_id() as u64,
+                        false,
+                    ));
+                    true
+                } else {
+                    false
+                }
+            }
+            Property::Timestamp | Property::ExpiresAt => {
+                if let Some(value) = value
+                    .as_str()
+                    .and_then(|value| UTCDateTime::from_str(value).ok())
+                {
+                    query.filters.push(RegistryFilter {
+                        property,
+                        op,
+                        value: (value.timestamp() as u64).into(),
+                        is_pk: false,
+                    });
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        })?;
+
+    let params = req
+        .request
+        .extract_parameters(req.server.core.jmap.query_max_results, Some(Property::Id))?;
+
+    if !query.has_filters() {
+        query.filters.push(RegistryFilter::greater_than(
+            Property::ExpiresAt,
+            0u64,
+            false,
+        ));
+    }
+    if let Some(limit) = params.limit {
+        query = query.with_limit(limit);
+        if let Some(anchor) = params.anchor {
+            query = query.with_anchor(anchor);
+        } else if let Some(position) = params.position {
+            query = query.with_index_start(position);
+        }
+    }
+
+    let matches = if req.access_token.tenant_id().is_none() {
+        req.server.registry().query::<Vec<Id>>(query).await?
+    } else {
+        vec![]
+    };
+    let results = match params.sort_by {
+        Property::Id => {
+            let mut results = matches;
+            if !params.sort_ascending {
+                results.sort_unstable_by(|a, b| b.cmp(a));
+            }
+            results
+        }
+        Property::Timestamp | Property::ExpiresAt => {
+            if !matches.is_empty() {
+                req.server
+                    .registry()
+                    .sort_by_index(
+                        req.object_type,
+                        params.sort_by,
+                        Some(matches),
+                        params.sort_ascending,
+                    )
+                    .await?
+            } else {
+                vec![]
+            }
+        }
+        property => {
+            return Err(trc::JmapEvent::UnsupportedSort.into_err().details(format!(
+                "Property {} is not supported for sorting",
+                property
+            )));
+        }
+    };
+
+    // Build response
+    let mut response = QueryResponseBuilder::new(
+        results.len(),
+        req.server.core.jmap.query_max_results,
+        State::Initial,
+        &req.request,
+    );
+
+    for id in results {
+        if !response.add_id(id) {
+            break;
+        }
+    }
+
+    Ok(response)
+}
@@ -0,0 +1,218 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::query::QueryResponseBuilder,
+    registry::{
+        mapping::{RegistryGetResponse, RegistryQueryResponse, RegistrySetResponse},
+        query::RegistryQueryFilters,
+    },
+};
+use common::auth::login_history::LoginHistoryIndex;
+use jmap_proto::{error::set::SetError, types::state::State};
+use registry::{
+    jmap::IntoValue,
+    schema::{enums::Permission, prelude::Property, structs::LoginEntry},
+    types::{EnumImpl, datetime::UTCDateTime},
+};
+use std::str::FromStr;
+use store::{
+    ValueKey,
+    registry::{RegistryFilter, RegistryQuery},
+    write::{BatchBuilder, RegistryClass, ValueClass},
+};
+use trc::AddContext;
+use types::id::Id;
+
+pub(crate) async fn login_set(
+    mut set: RegistrySetResponse<'_>,
+) -> trc::Result<RegistrySetResponse<'_>> {
+    // Login entries are recorded by the server, users may only remove them
+    set.fail_all_create("Login entries cannot be created");
+    set.fail_all_update("Login entries cannot be updated");
+
+    let object_id = set.object_type.to_id();
+    let mut batch = BatchBuilder::new();
+
+    for id in set.destroy.drain(..) {
+        let item_id = id.id();
+
+        if let Some(entry) =
+            set.server
+                .store()
+                .get_value::<LoginEntry>(ValueKey::from(ValueClass::Registry(
+                    RegistryClass::Item { object_id, item_id },
+                )))
+                .await?
+                .filter(|entry| {
+                    !set.is_account_filtered || entry.account_id.document_id() == set.account_id
+                })
+        {
+            entry.write_ops(&mut batch, item_id, false);
+            batch.commit_point();
+            set.response.destroyed.push(id);
+        } else {
+            set.response.not_destroyed.append(id, SetError::not_found());
+        }
+    }
+
+    if !batch.is_empty() {
+        set.server
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())?;
+    }
+
+    Ok(set)
+}
+
+pub(crate) async fn login_get(
+    mut get: RegistryGetResponse<'_>,
+) -> trc::Result<RegistryGetResponse<'_>> {
+    let object_id = get.object_type.to_id();
+    let ids = if let Some(ids) = get.ids.take() {
+        ids
+    } else {
+        let query = if !get.is_account_filtered {
+            RegistryQuery::new(get.object_type).greater_than(Property::ExpiresAt, 0u64)
+        } else {
+            RegistryQuery::new(get.object_type).with_account(get.account_id)
+        }
+        .with_limit(get.server.core.jmap.get_max_objects);
+
+        get.server.registry().query::<Vec<Id>>(query).await?
+    };
+
+    for id in ids {
+        if let Some(entry) = get
+            .server
+            .store()
+            .get_value::<LoginEntry>(ValueKey::from(ValueClass::Registry(RegistryClass::Item {
+                object_id,
+                item_id: id.id(),
+            })))
+            .await?
+            .filter(|entry| {
+                !get.is_account_filtered || entry.account_id.document_id() == get.account_id
+            })
+        {
+            get.insert(id, entry.into_value());
+        } else {
+            get.not_found(id);
+        }
+    }
+
+    Ok(get)
+}
+
+pub(crate) async fn login_query(
+    mut req: RegistryQueryResponse<'_>,
+) -> trc::Result<QueryResponseBuilder> {
+    let can_impersonate = req.access_token.has_permission(Permission::Impersonate);
+    let mut account_id = None;
+    let mut filters = Vec::new();
+
+    req.request
+        .extract_filters(|property, op, value| match property {
+            Property::AccountId if can_impersonate => {
+                if let Some(id) = value.as_str().and_then(|s| Id::from_str(s).ok()) {
+                    account_id = Some(id);
+                    true
+                } else {
+                    false
+                }
+            }
+            Property::Timestamp | Property::ExpiresAt => {
+                if let Some(value) = value
+                    .as_str()
+                    .and_then(|value| UTCDateTime::from_str(value).ok())
+                {
+                    filters.push(RegistryFilter {
+                        property,
+                        op,
+                        value: (value.timestamp() as u64).into(),
+                        is_pk: false,
+                    });
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        })?;
+
+    // Users only see their own logins
+    let mut query = if let Some(account_id) = account_id {
+        RegistryQuery::new(req.object_type).with_account(account_id.document_id())
+    } else if !can_impersonate {
+        RegistryQuery::new(req.object_type).with_account(req.request.account_id.document_id())
+    } else {
+        RegistryQuery::new(req.object_type).greater_than(Property::ExpiresAt, 0u64)
+    };
+    query.filters.extend(filters);
+
+    let params = req
+        .request
+        .extract_parameters(req.server.core.jmap.query_max_results, Some(Property::Id))?;
+
+    if let Some(limit) = params.limit {
+        query = query.with_limit(limit);
+        if let Some(anchor) = params.anchor {
+            query = query.with_anchor(anchor);
+        } else if let Some(position) = params.position {
+            query = query.with_index_start(position);
+        }
+    }
+
+    let matches = req.server.registry().query::<Vec<Id>>(query).await?;
+    let results = match params.sort_by {
+        Property::Id => {
+            let mut results = matches;
+            if !params.sort_ascending {
+                results.sort_unstable_by(|a, b| b.cmp(a));
+            }
+            results
+        }
+        Property::Timestamp => {
+            if !matches.is_empty() {
+                req.server
+                    .registry()
+                    .sort_by_index(
+                        req.object_type,
+                        params.sort_by,
+                        Some(matches),
+                        params.sort_ascending,
+                    )
+                    .await?
+            } else {
+                vec![]
+            }
+        }
+        property => {
+            return Err(trc::JmapEvent::UnsupportedSort.into_err().details(format!(
+                "Property {} is not supported for sorting",
+                property
+            )));
+        }
+    };
+
+    // Build response
+    let mut response = QueryResponseBuilder::new(
+        results.len(),
+        req.server.core.jmap.query_max_results,
+        State::Initial,
+        &req.request,
+    );
+
+    for id in results {
+        if !response.add_id(id) {
+            break;
+        }
+    }
+
+    Ok(response)
+}
@@ -23,19 +23,26 @@ use utils::map::vec_map::VecMap;
 
 pub mod account;
 pub mod action;
+pub mod audit;
 pub mod bootstrap;
 pub mod cluster;
+pub mod delivery_trace;
 pub mod dkim;
 pub mod domain;
+pub mod integrity;
 pub mod log;
+pub mod login;
 pub mod principal;
 pub mod public_key;
+pub mod quarantine;
 pub mod queued_message;
 pub mod report;
 pub mod sieve;
 pub mod spam_sample;
+pub mod statistics;
 pub mod task;
 pub mod tls;
+pub mod usage;
 
 // SPDX-SnippetBegin
 // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
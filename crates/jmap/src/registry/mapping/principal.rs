@@ -16,7 +16,7 @@ use registry::{
     schema::{
         enums::{AccountType, Permission, TenantStorageQuota},
         prelude::{MASKED_PASSWORD, ObjectType, Property},
-        structs::{Account, Credential, Role, Task, TaskDestroyAccount},
+        structs::{Account, Credential, Role, Task, TaskDestroyAccount, Tenant},
     },
     types::EnumImpl,
 };
@@ -53,6 +53,42 @@ pub(crate) async fn validate_account(
     }
     // SPDX-SnippetEnd
 
+    // Accounts stay pinned to their data region unless moved by a migration task
+    match old_account {
+        AccountUpdate::Update(old_account) => {
+            if account.data_region() != old_account.data_region() {
+                return Ok(Err(SetError::forbidden()
+                    .with_property(Property::DataRegion)
+                    .with_description(
+                        "Data region cannot be changed directly, use a data region migration task.",
+                    )));
+            }
+        }
+        AccountUpdate::Create(_) => {
+            let tenant_id = match &*account {
+                Account::User(account) => account.member_tenant_id,
+                Account::Group(account) => account.member_tenant_id,
+            };
+            if account.data_region().is_none()
+                && let Some(tenant_id) = tenant_id
+                && let Some(tenant) = set.server.registry().object::<Tenant>(tenant_id).await?
+                && tenant.data_region.is_some()
+            {
+                account.set_data_region(tenant.data_region);
+            }
+
+            if let Some(region) = account.data_region()
+                && set.server.core.storage.blob.in_region(region).is_none()
+            {
+                return Ok(Err(SetError::invalid_properties()
+                    .with_property(Property::DataRegion)
+                    .with_description(format!(
+                        "Data region {region:?} is not defined in the blob store."
+                    ))));
+            }
+        }
+    }
+
     let is_external_directory = if let Account::User(account) = account {
         set.server
             .domain_by_id(account.domain_id.document_id())
@@ -33,8 +33,8 @@ use registry::{
 use smtp::queue::{
     self, ArchivedError, ArchivedErrorDetails, ArchivedMessage, ArchivedStatus, ErrorDetails,
     FROM_AUTHENTICATED, FROM_AUTOGENERATED, FROM_DSN, FROM_REPORT, FROM_UNAUTHENTICATED,
-    FROM_UNAUTHENTICATED_DMARC, Message, MessageWrapper, RCPT_DSN_SENT, RCPT_SPAM_PAYLOAD,
-    Schedule, Status, spool::SmtpSpool,
+    FROM_UNAUTHENTICATED_DMARC, Message, MessageWrapper, RCPT_DSN_SENT, RCPT_QUARANTINE,
+    RCPT_SPAM_PAYLOAD, Schedule, Status, spool::SmtpSpool,
 };
 use std::str::FromStr;
 use store::{
@@ -107,6 +107,16 @@ pub(crate) async fn queued_message_set(
             has_changes = true;
         }
         if queued_message.priority as i64 != message.priority {
+            // Priorities follow the MT-PRIORITY range (RFC 6710)
+            if !(-9..=9).contains(&message.priority) {
+                set.response.not_updated.append(
+                    id,
+                    SetError::invalid_properties()
+                        .with_property(Property::Priority)
+                        .with_description("Priority must be between -9 and 9"),
+                );
+                continue 'outer;
+            }
             queued_message.priority = message.priority as i16;
             has_changes = true;
         }
@@ -671,6 +681,7 @@ fn map_message(message_in: &ArchivedMessage) -> QueuedMessage {
         for (bit, flag) in [
             (RCPT_DSN_SENT, RecipientFlag::DsnSent),
             (RCPT_SPAM_PAYLOAD, RecipientFlag::SpamPayload),
+            (RCPT_QUARANTINE, RecipientFlag::Quarantine),
         ] {
             if rcpt_flags & bit != 0 {
                 rcpt_out.flags.push(flag);
@@ -131,6 +131,9 @@ pub(crate) async fn report_set(
                 ObjectInner::TlsExternalReport(report) => {
                     report.write_ops(&mut batch, item_id, false);
                 }
+                ObjectInner::TlsInboundReport(report) => {
+                    report.write_ops(&mut batch, item_id, false);
+                }
                 ObjectInner::ArfExternalReport(report) => {
                     report.write_ops(&mut batch, item_id, false);
                 }
@@ -171,6 +174,7 @@ pub(crate) async fn report_get(
         get.object_type,
         ObjectType::DmarcExternalReport
             | ObjectType::TlsExternalReport
+            | ObjectType::TlsInboundReport
             | ObjectType::ArfExternalReport
     ) {
         if get.is_tenant_filtered {
@@ -240,7 +244,7 @@ pub(crate) async fn report_query(
             Property::Domain => {
                 if let serde_json::Value::String(value) = value {
                     match req.object_type {
-                        ObjectType::DmarcInternalReport => {
+                        ObjectType::DmarcInternalReport | ObjectType::TlsInboundReport => {
                             query.filters.push(RegistryFilter::greater_than_or_equal(
                                 property,
                                 RegistryFilterValue::Bytes(
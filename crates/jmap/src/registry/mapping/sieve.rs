@@ -5,9 +5,14 @@
  */
 
 use crate::registry::mapping::{ObjectResponse, ValidationResult};
-use common::Server;
+use common::{
+    Server,
+    scripts::library::{global_includes, invalid_includes},
+};
 use jmap_proto::error::set::SetError;
-use registry::schema::prelude::Property;
+use registry::schema::{prelude::Property, structs::SieveUserScript};
+use store::ahash::AHashMap;
+use trc::AddContext;
 
 pub(crate) async fn validate_sieve_script(
     server: &Server,
@@ -47,3 +52,52 @@ pub(crate) async fn validate_sieve_script(
 
     Ok(Ok(ObjectResponse::default()))
 }
+
+// Library scripts may include each other with ":global", reject changes that
+// introduce an include cycle or exceed the maximum nesting depth.
+pub(crate) async fn validate_sieve_library(
+    server: &Server,
+    script: &SieveUserScript,
+    old_name: Option<&str>,
+) -> ValidationResult {
+    if !script.is_active {
+        return Ok(Ok(ObjectResponse::default()));
+    }
+
+    let name = script.name.to_lowercase();
+    let mut scripts = AHashMap::new();
+    for other in server
+        .registry()
+        .list::<SieveUserScript>()
+        .await
+        .caused_by(trc::location!())?
+    {
+        let other_name = other.object.name.to_lowercase();
+        if other.object.is_active
+            && other_name != name
+            && old_name.is_none_or(|old_name| !old_name.eq_ignore_ascii_case(&other_name))
+        {
+            scripts.insert(other_name, global_includes(&other.object.contents));
+        }
+    }
+
+    let max_depth = server.core.sieve.untrusted_max_nested_includes;
+    let previous = invalid_includes(&scripts, max_depth);
+    scripts.insert(name, global_includes(&script.contents));
+    let mut invalid = invalid_includes(&scripts, max_depth)
+        .into_iter()
+        .filter(|name| !previous.contains(name))
+        .collect::<Vec<_>>();
+
+    if invalid.is_empty() {
+        Ok(Ok(ObjectResponse::default()))
+    } else {
+        invalid.sort_unstable();
+        Ok(Err(SetError::invalid_properties()
+            .with_property(Property::Contents)
+            .with_description(format!(
+                "Sieve script includes would form a cycle or exceed the maximum depth of {max_depth}: {}",
+                invalid.join(", ")
+            ))))
+    }
+}
@@ -0,0 +1,222 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    api::query::QueryResponseBuilder,
+    registry::{
+        mapping::{RegistryGetResponse, RegistryQueryResponse, RegistrySetResponse},
+        query::RegistryQueryFilters,
+    },
+};
+use common::telemetry::statistics::StatisticsRollupIndex;
+use jmap_proto::{error::set::SetError, types::state::State};
+use registry::{
+    jmap::IntoValue,
+    schema::{enums::StatisticsPeriod, prelude::Property, structs::StatisticsRollup},
+    types::{EnumImpl, datetime::UTCDateTime},
+};
+use std::str::FromStr;
+use store::{
+    ValueKey,
+    registry::{RegistryFilter, RegistryQuery},
+    write::{BatchBuilder, RegistryClass, ValueClass},
+};
+use trc::AddContext;
+use types::id::Id;
+
+pub(crate) async fn statistics_set(
+    mut set: RegistrySetResponse<'_>,
+) -> trc::Result<RegistrySetResponse<'_>> {
+    let object_id = set.object_type.to_id();
+
+    // Rollups are maintained by the server
+    set.fail_all_create("Statistics cannot be created");
+    set.fail_all_update("Statistics cannot be updated");
+
+    let mut batch = BatchBuilder::new();
+    for id in set.destroy.drain(..) {
+        let item_id = id.id();
+        if let Some(rollup) = set
+            .server
+            .store()
+            .get_value::<StatisticsRollup>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item { object_id, item_id },
+            )))
+            .await?
+            .filter(|_| !set.is_tenant_filtered)
+        {
+            rollup.write_ops(&mut batch, item_id, false);
+            batch.commit_point();
+
+            set.response.destroyed.push(id);
+        } else {
+            set.response.not_destroyed.append(id, SetError::not_found());
+        }
+    }
+
+    if !batch.is_empty() {
+        set.server
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())?;
+    }
+
+    Ok(set)
+}
+
+pub(crate) async fn statistics_get(
+    mut get: RegistryGetResponse<'_>,
+) -> trc::Result<RegistryGetResponse<'_>> {
+    let object_id = get.object_type.to_id();
+    let ids = if let Some(ids) = get.ids.take() {
+        ids
+    } else if !get.is_tenant_filtered {
+        get.server
+            .registry()
+            .query::<Vec<Id>>(
+                RegistryQuery::new(get.object_type)
+                    .greater_than(Property::ExpiresAt, 0u64)
+                    .with_limit(get.server.core.jmap.get_max_objects),
+            )
+            .await?
+    } else {
+        vec![]
+    };
+
+    for id in ids {
+        // Statistics are server-wide and not visible to tenants
+        if let Some(rollup) = get
+            .server
+            .store()
+            .get_value::<StatisticsRollup>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item {
+                    object_id,
+                    item_id: id.id(),
+                },
+            )))
+            .await?
+            .filter(|_| !get.is_tenant_filtered)
+        {
+            get.insert(id, rollup.into_value());
+        } else {
+            get.not_found(id);
+        }
+    }
+
+    Ok(get)
+}
+
+pub(crate) async fn statistics_query(
+    mut req: RegistryQueryResponse<'_>,
+) -> trc::Result<QueryResponseBuilder> {
+    let mut query = RegistryQuery::new(req.object_type);
+
+    req.request
+        .extract_filters(|property, op, value| match property {
+            Property::Period => {
+                if let Some(period) = value.as_str().and_then(StatisticsPeriod::parse) {
+                    query.filters.push(RegistryFilter::equal(
+                        property,
+                        period.to_id() as u64,
+                        false,
+                    ));
+                    true
+                } else {
+                    false
+                }
+            }
+            Property::From | Property::ExpiresAt => {
+                if let Some(value) = value
+                    .as_str()
+                    .and_then(|value| UTCDateTime::from_str(value).ok())
+                {
+                    query.filters.push(RegistryFilter {
+                        property,
+                        op,
+                        value: (value.timestamp() as u64).into(),
+                        is_pk: false,
+                    });
+                    true
+                } else {
+                    false
+                }
+            }
+            _ => false,
+        })?;
+
+    let params = req
+        .request
+        .extract_parameters(req.server.core.jmap.query_max_results, Some(Property::Id))?;
+
+    if !query.has_filters() {
+        query.filters.push(RegistryFilter::greater_than(
+            Property::ExpiresAt,
+            0u64,
+            false,
+        ));
+    }
+    if let Some(limit) = params.limit {
+        query = query.with_limit(limit);
+        if let Some(anchor) = params.anchor {
+            query = query.with_anchor(anchor);
+        } else if let Some(position) = params.position {
+            query = query.with_index_start(position);
+        }
+    }
+
+    let matches = if req.access_token.tenant_id().is_none() {
+        req.server.registry().query::<Vec<Id>>(query).await?
+    } else {
+        vec![]
+    };
+    let results = match params.sort_by {
+        Property::Id => {
+            let mut results = matches;
+            if !params.sort_ascending {
+                results.sort_unstable_by(|a, b| b.cmp(a));
+            }
+            results
+        }
+        Property::From | Property::ExpiresAt => {
+            if !matches.is_empty() {
+                req.server
+                    .registry()
+                    .sort_by_index(
+                        req.object_type,
+                        params.sort_by,
+                        Some(matches),
+                        params.sort_ascending,
+                    )
+                    .await?
+            } else {
+                vec![]
+            }
+        }
+        property => {
+            return Err(trc::JmapEvent::UnsupportedSort.into_err().details(format!(
+                "Property {} is not supported for sorting",
+                property
+            )));
+        }
+    };
+
+    // Build response
+    let mut response = QueryResponseBuilder::new(
+        results.len(),
+        req.server.core.jmap.query_max_results,
+        State::Initial,
+        &req.request,
+    );
+
+    for id in results {
+        if !response.add_id(id) {
+            break;
+        }
+    }
+
+    Ok(response)
+}
@@ -87,6 +87,9 @@ pub(crate) async fn task_set(
             | TaskType::UnindexDocument
             | TaskType::IndexTrace
             | TaskType::AccountMaintenance
+            | TaskType::AccountExport
+            | TaskType::AccountImport
+            | TaskType::DataRegionMigration
             | TaskType::TenantMaintenance
             | TaskType::StoreMaintenance
             | TaskType::SpamFilterMaintenance
@@ -9,9 +9,12 @@ use crate::{
     registry::{
         EnterpriseRegistry,
         mapping::{
-            RegistryQueryResponse, account::credential_query, cluster::cluster_node_query,
-            log::log_query, queued_message::queued_message_query, report::report_query,
-            spam_sample::spam_sample_query, task::task_query,
+            RegistryQueryResponse, account::credential_query, audit::audit_query,
+            cluster::cluster_node_query, delivery_trace::delivery_trace_query,
+            integrity::blob_integrity_query, log::log_query, login::login_query,
+            quarantine::quarantine_query, queued_message::queued_message_query,
+            report::report_query, spam_sample::spam_sample_query, statistics::statistics_query,
+            task::task_query, usage::usage_query,
         },
     },
 };
@@ -65,6 +68,7 @@ impl RegistryQuery for Server {
             ObjectType::ArfExternalReport
             | ObjectType::DmarcExternalReport
             | ObjectType::TlsExternalReport
+            | ObjectType::TlsInboundReport
             | ObjectType::DmarcInternalReport
             | ObjectType::TlsInternalReport => report_query(RegistryQueryResponse {
                 server: self,
@@ -74,6 +78,54 @@ impl RegistryQuery for Server {
             })
             .await
             .and_then(|response| response.build()),
+            ObjectType::StatisticsRollup => statistics_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+            ObjectType::TenantUsage => usage_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+            ObjectType::MessageAuditEntry => audit_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+            ObjectType::LoginEntry => login_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+            ObjectType::BlobIntegrityIssue => blob_integrity_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
+            ObjectType::DeliveryTrace => delivery_trace_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
 
             // SPDX-SnippetBegin
             // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
@@ -118,6 +170,14 @@ impl RegistryQuery for Server {
             })
             .await
             .and_then(|response| response.build()),
+            ObjectType::QuarantinedMessage => quarantine_query(RegistryQueryResponse {
+                server: self,
+                access_token,
+                object_type,
+                request,
+            })
+            .await
+            .and_then(|response| response.build()),
 
             ObjectType::QueuedMessage => queued_message_query(RegistryQueryResponse {
                 server: self,
@@ -10,21 +10,28 @@ use crate::registry::{
         ObjectResponse, RegistrySetResponse,
         account::account_set,
         action::action_set,
+        audit::audit_set,
         bootstrap::bootstrap_set,
+        delivery_trace::delivery_trace_set,
         dkim::validate_dkim_signature,
         domain::{validate_dns_server, validate_domain},
+        integrity::blob_integrity_set,
+        login::login_set,
         map_bootstrap_error,
         principal::{
             AccountUpdate, schedule_account_destruction, validate_account, validate_role,
             validate_tenant_quota,
         },
         public_key::validate_public_key,
+        quarantine::quarantine_set,
         queued_message::queued_message_set,
         report::report_set,
-        sieve::validate_sieve_script,
+        sieve::{validate_sieve_library, validate_sieve_script},
         spam_sample::spam_sample_set,
+        statistics::statistics_set,
         task::task_set,
         tls::{validate_acme_provider, validate_certificate},
+        usage::usage_set,
     },
 };
 use common::{
@@ -234,6 +241,7 @@ impl RegistrySet for Server {
             | ObjectType::HttpLookup
             | ObjectType::MemoryLookupKey
             | ObjectType::MemoryLookupKeyValue
+            | ObjectType::DsnTemplate
             | ObjectType::MtaVirtualQueue
             | ObjectType::MtaQueueQuota
             | ObjectType::MtaRoute
@@ -475,14 +483,25 @@ impl RegistrySet for Server {
                         ObjectInner::Certificate(cert) => {
                             validate_certificate(cert, modification.as_certificate()).await?
                         }
-                        ObjectInner::SieveUserScript(SieveUserScript { contents, .. }) => {
-                            validate_sieve_script(
+                        ObjectInner::SieveUserScript(script) => {
+                            match validate_sieve_script(
                                 set.server,
-                                contents,
+                                &script.contents,
                                 modification.as_sieve_script(),
                                 false,
                             )
                             .await?
+                            {
+                                Ok(_) => {
+                                    validate_sieve_library(
+                                        set.server,
+                                        script,
+                                        modification.as_sieve_script_name(),
+                                    )
+                                    .await?
+                                }
+                                err => err,
+                            }
                         }
                         ObjectInner::SieveSystemScript(SieveSystemScript { contents, .. }) => {
                             validate_sieve_script(
@@ -683,8 +702,21 @@ impl RegistrySet for Server {
             ObjectType::ArfExternalReport
             | ObjectType::DmarcExternalReport
             | ObjectType::TlsExternalReport
+            | ObjectType::TlsInboundReport
             | ObjectType::DmarcInternalReport
             | ObjectType::TlsInternalReport => report_set(set).await.map(|set| set.into_response()),
+            ObjectType::StatisticsRollup => {
+                statistics_set(set).await.map(|set| set.into_response())
+            }
+            ObjectType::TenantUsage => usage_set(set).await.map(|set| set.into_response()),
+            ObjectType::MessageAuditEntry => audit_set(set).await.map(|set| set.into_response()),
+            ObjectType::LoginEntry => login_set(set).await.map(|set| set.into_response()),
+            ObjectType::BlobIntegrityIssue => {
+                blob_integrity_set(set).await.map(|set| set.into_response())
+            }
+            ObjectType::DeliveryTrace => {
+                delivery_trace_set(set).await.map(|set| set.into_response())
+            }
 
             // SPDX-SnippetBegin
             // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
@@ -697,6 +729,9 @@ impl RegistrySet for Server {
             ObjectType::SpamTrainingSample => {
                 spam_sample_set(set).await.map(|set| set.into_response())
             }
+            ObjectType::QuarantinedMessage => {
+                quarantine_set(set).await.map(|set| set.into_response())
+            }
 
             ObjectType::AccountSettings
             | ObjectType::ApiKey
@@ -858,6 +893,20 @@ impl Modification {
         }
     }
 
+    fn as_sieve_script_name(&self) -> Option<&str> {
+        match self {
+            Modification::Update {
+                object:
+                    Object {
+                        inner: ObjectInner::SieveUserScript(SieveUserScript { name, .. }),
+                        ..
+                    },
+                ..
+            } => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
     fn as_sieve_script(&self) -> Option<&str> {
         match self {
             Modification::Create { .. } => None,
@@ -11,7 +11,7 @@ use common::{
     storage::index::ObjectIndexBuilder,
 };
 use email::{
-    identity::Identity,
+    identity::{Identity, settings::IdentitySettingsStore},
     message::metadata::{ArchivedMetadataHeaderName, ArchivedMetadataHeaderValue, MessageMetadata},
     submission::{Address, Delivered, DeliveryStatus, EmailSubmission, UndoStatus},
 };
@@ -226,18 +226,25 @@ impl EmailSubmissionSet for Server {
                 ))
                 .await?
             {
+                let submission = submission
+                    .to_unarchived::<EmailSubmission>()
+                    .caused_by(trc::location!())?;
+
+                // Destroying a submission before its send time cancels the delivery
+                if let Some(queue_id) = submission.inner.queue_id.as_ref().map(u64::from)
+                    && u64::from(submission.inner.send_at) > now()
+                    && let Some(queue_message) =
+                        self.read_message(queue_id, QueueName::default()).await
+                {
+                    queue_message.remove(self, None).await;
+                }
+
                 // Update record
                 batch
                     .with_account_id(account_id)
                     .with_collection(Collection::EmailSubmission)
                     .with_document(document_id)
-                    .custom(
-                        ObjectIndexBuilder::<_, ()>::new().with_current(
-                            submission
-                                .to_unarchived::<EmailSubmission>()
-                                .caused_by(trc::location!())?,
-                        ),
-                    )
+                    .custom(ObjectIndexBuilder::<_, ()>::new().with_current(submission))
                     .caused_by(trc::location!())?
                     .commit_point();
                 response.destroyed.push(id);
@@ -486,6 +493,10 @@ impl EmailSubmissionSet for Server {
                 .with_property(EmailSubmissionProperty::IdentityId)
                 .with_description("Identity not found.")));
         };
+        let bcc_to_self = self
+            .identity_settings(account_id, submission.identity_id)
+            .await?
+            .bcc_to_self;
 
         // Make sure the envelope address matches the identity email address
         let mail_from = if let Some(mail_from) = mail_from {
@@ -600,14 +611,36 @@ impl EmailSubmissionSet for Server {
                 .find(|header| matches!(header.name, ArchivedMetadataHeaderName::Bcc));
         }
 
-        // Update sendAt
+        // Send a copy to the identity's own address
+        if bcc_to_self
+            && !rcpt_to
+                .iter()
+                .any(|rcpt| rcpt.address.eq_ignore_ascii_case(&mail_from.address))
+        {
+            submission.envelope.rcpt_to.push(Address {
+                email: mail_from.address.to_string(),
+                parameters: None,
+            });
+            rcpt_to.push(RcptTo {
+                address: Cow::Owned(mail_from.address.to_string()),
+                ..Default::default()
+            });
+        }
+
+        // Update sendAt, holding the message for at least the account's undo window
+        let account_info = self
+            .account_info(account_id)
+            .await
+            .caused_by(trc::location!())?;
+        let undo_send_window = account_info.account.undo_send_window;
         submission.send_at = if mail_from.hold_until > 0 {
             mail_from.hold_until
         } else if mail_from.hold_for > 0 {
             mail_from.hold_for + now()
         } else {
             now()
-        };
+        }
+        .max(now() + undo_send_window);
 
         // Obtain raw message
         let mut message = if let Some(message) = self
@@ -643,15 +676,7 @@ impl EmailSubmissionSet for Server {
         let mut session = Session::<NullIo>::local(
             self.clone(),
             instance.clone(),
-            SessionData::local(
-                self.account_info(account_id)
-                    .await
-                    .caused_by(trc::location!())?,
-                None,
-                vec![],
-                vec![],
-                0,
-            ),
+            SessionData::local(account_info, None, vec![], vec![], 0),
         );
 
         // Spawn SMTP session to avoid overflowing the stack
@@ -663,6 +688,11 @@ impl EmailSubmissionSet for Server {
                     .with_description(format!("Server rejected MAIL-FROM: {}", error.trim())));
             }
 
+            // Hold the message in the queue until the undo window expires
+            if undo_send_window > session.data.future_release {
+                session.data.future_release = undo_send_window;
+            }
+
             // RCPT TO
             let mut responses = Vec::new();
             let mut has_success = false;
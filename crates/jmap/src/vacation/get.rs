@@ -6,7 +6,7 @@
 
 use crate::changes::state::StateManager;
 use common::Server;
-use email::sieve::{SieveScript, ingest::SieveScriptIngest};
+use email::sieve::{SieveScript, VacationRestriction, ingest::SieveScriptIngest};
 use jmap_proto::{
     method::get::{GetRequest, GetResponse},
     object::vacation_response::{
@@ -54,6 +54,7 @@ impl VacationResponseGet for Server {
             VacationResponseProperty::Subject,
             VacationResponseProperty::TextBody,
             VacationResponseProperty::HtmlBody,
+            VacationResponseProperty::RestrictTo,
         ]);
         let mut response = GetResponse {
             account_id: request.account_id.into(),
@@ -152,6 +153,16 @@ impl VacationResponseGet for Server {
                                 vacation.and_then(|r| r.html_body.as_ref()),
                             );
                         }
+                        VacationResponseProperty::RestrictTo => {
+                            result.insert_unchecked(
+                                VacationResponseProperty::RestrictTo,
+                                vacation.and_then(|r| {
+                                    r.restrict_to.as_ref().map(|v| {
+                                        Value::Str(VacationRestriction::from(v).as_str().into())
+                                    })
+                                }),
+                            );
+                        }
                     }
                 }
             } else {
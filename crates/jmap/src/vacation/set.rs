@@ -6,9 +6,13 @@
 
 use super::get::VacationResponseGet;
 use crate::changes::state::StateManager;
-use common::{Server, auth::AccessToken, storage::index::ObjectIndexBuilder};
+use common::{
+    Server, auth::AccessToken, config::mailstore::scripts::SIEVE_ADDRBOOK_LIST,
+    storage::index::ObjectIndexBuilder,
+};
 use email::sieve::{
-    SieveScript, VacationResponse, delete::SieveScriptDelete, ingest::SieveScriptIngest,
+    SieveScript, VacationResponse, VacationRestriction, delete::SieveScriptDelete,
+    ingest::SieveScriptIngest,
 };
 use jmap_proto::{
     error::set::{SetError, SetErrorType},
@@ -182,7 +186,7 @@ impl VacationResponseSet for Server {
                         vacation.subject = Some(value.into_owned());
                     }
                     (Key::Property(VacationResponseProperty::HtmlBody), Value::Str(value))
-                        if value.len() < 2048 =>
+                        if value.len() < 8192 =>
                     {
                         build_script = true;
                         vacation.html_body = Some(value.into_owned());
@@ -207,6 +211,12 @@ impl VacationResponseSet for Server {
                         vacation.to_date = Some(date.timestamp() as u64);
                         build_script = true;
                     }
+                    (Key::Property(VacationResponseProperty::RestrictTo), Value::Str(value))
+                        if VacationRestriction::parse(&value).is_some() =>
+                    {
+                        vacation.restrict_to = VacationRestriction::parse(&value);
+                        build_script = true;
+                    }
                     (Key::Property(VacationResponseProperty::IsEnabled), Value::Bool(value)) => {
                         is_active = value;
                     }
@@ -219,7 +229,8 @@ impl VacationResponseSet for Server {
                             | VacationResponseProperty::HtmlBody
                             | VacationResponseProperty::TextBody
                             | VacationResponseProperty::ToDate
-                            | VacationResponseProperty::FromDate,
+                            | VacationResponseProperty::FromDate
+                            | VacationResponseProperty::RestrictTo,
                         ),
                         Value::Null,
                     ) => {
@@ -241,6 +252,9 @@ impl VacationResponseSet for Server {
                                 Key::Property(VacationResponseProperty::ToDate) => {
                                     vacation.to_date = None;
                                 }
+                                Key::Property(VacationResponseProperty::RestrictTo) => {
+                                    vacation.restrict_to = None;
+                                }
                                 _ => unreachable!(),
                             }
                         }
@@ -257,6 +271,22 @@ impl VacationResponseSet for Server {
                 }
             }
 
+            // Validate date range
+            if let (Some(from_date), Some(to_date)) = (vacation.from_date, vacation.to_date)
+                && from_date > to_date
+            {
+                return Ok(set_error(
+                    response,
+                    create_id,
+                    SetError::invalid_properties()
+                        .with_properties([
+                            VacationResponseProperty::FromDate,
+                            VacationResponseProperty::ToDate,
+                        ])
+                        .with_description("The end date must be after the start date."),
+                ));
+            }
+
             let mut obj = ObjectIndexBuilder::new()
                 .with_current_opt(prev_sieve)
                 .with_changes(sieve)
@@ -368,7 +398,18 @@ impl VacationResponseSet for Server {
     fn build_script(&self, obj: &mut SieveScript) -> trc::Result<Vec<u8>> {
         // Build Sieve script
         let mut script = Vec::with_capacity(1024);
-        script.extend_from_slice(b"require [\"vacation\", \"relational\", \"date\"];\r\n\r\n");
+        let restrict_to = obj.vacation_response.as_ref().and_then(|v| v.restrict_to);
+        script.extend_from_slice(b"require [\"vacation\", \"relational\", \"date\"");
+        match restrict_to {
+            Some(VacationRestriction::Contacts) => {
+                script.extend_from_slice(b", \"envelope\", \"extlists\"");
+            }
+            Some(VacationRestriction::SameDomain) => {
+                script.extend_from_slice(b", \"envelope\", \"variables\"");
+            }
+            None => (),
+        }
+        script.extend_from_slice(b"];\r\n\r\n");
         let mut num_blocks = 0;
 
         // Add start date
@@ -387,6 +428,29 @@ impl VacationResponseSet for Server {
             num_blocks += 1;
         }
 
+        // Only reply to senders in the address book or in the recipient's domain
+        match restrict_to {
+            Some(VacationRestriction::Contacts) => {
+                script.extend_from_slice(b"if envelope :list \"from\" \"");
+                script.extend_from_slice(SIEVE_ADDRBOOK_LIST.as_bytes());
+                script.extend_from_slice(b"\" {\r\n");
+                num_blocks += 1;
+            }
+            Some(VacationRestriction::SameDomain) => {
+                script.extend_from_slice(
+                    concat!(
+                        "if envelope :domain :matches \"to\" \"*\" {\r\n",
+                        "set \"rcpt_domain\" \"${1}\";\r\n",
+                        "}\r\n",
+                        "if envelope :domain :is \"from\" \"${rcpt_domain}\" {\r\n"
+                    )
+                    .as_bytes(),
+                );
+                num_blocks += 1;
+            }
+            None => (),
+        }
+
         script.extend_from_slice(b"vacation :mime ");
         if let Some(value) = obj
             .vacation_response
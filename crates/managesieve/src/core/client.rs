@@ -94,6 +94,7 @@ impl<T: SessionStream> Session<T> {
                 Command::GetScript => self.handle_getscript(request).await,
                 Command::DeleteScript => self.handle_deletescript(request).await,
                 Command::RenameScript => self.handle_renamescript(request).await,
+                Command::RollbackScript => self.handle_rollbackscript(request).await,
                 Command::CheckScript => self.handle_checkscript(request).await,
                 Command::HaveSpace => self.handle_havespace(request).await,
                 Command::Capability => self.handle_capability("").await,
@@ -177,6 +178,7 @@ impl<T: SessionStream> Session<T> {
             | Command::GetScript
             | Command::DeleteScript
             | Command::RenameScript
+            | Command::RollbackScript
             | Command::CheckScript
             | Command::Unauthenticate => {
                 if let State::Authenticated { access_token, .. } = &self.state {
@@ -73,6 +73,7 @@ pub enum Command {
     GetScript,
     DeleteScript,
     RenameScript,
+    RollbackScript,
     CheckScript,
     #[default]
     Noop,
@@ -93,6 +94,7 @@ impl CommandParser for Command {
             b"GETSCRIPT" => Some(Command::GetScript),
             b"DELETESCRIPT" => Some(Command::DeleteScript),
             b"RENAMESCRIPT" => Some(Command::RenameScript),
+            b"ROLLBACKSCRIPT" => Some(Command::RollbackScript),
             b"CHECKSCRIPT" => Some(Command::CheckScript),
             b"NOOP" => Some(Command::Noop),
             b"UNAUTHENTICATE" => Some(Command::Unauthenticate),
@@ -118,6 +118,14 @@ mod tests {
                     ],
                 }],
             ),
+            (
+                vec!["Rollbackscript \"foo\"\r\n"],
+                vec![Request {
+                    tag: "".into(),
+                    command: Command::RollbackScript,
+                    tokens: vec![Token::Argument(b"foo".to_vec())],
+                }],
+            ),
             (
                 vec!["NOOP \"STARTTLS-SYNC-42\"\r\n"],
                 vec![Request {
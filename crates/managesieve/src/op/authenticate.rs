@@ -15,7 +15,7 @@ use imap_proto::{
     receiver::{self, Request},
 };
 use mail_parser::decoders::base64::base64_decode;
-use registry::schema::enums::Permission;
+use registry::schema::enums::{NetworkListenerProtocol, Permission};
 
 impl<T: SessionStream> Session<T> {
     pub async fn handle_authenticate(&mut self, request: Request<Command>) -> trc::Result<Vec<u8>> {
@@ -103,6 +103,21 @@ impl<T: SessionStream> Session<T> {
             LimiterResult::Disabled => None,
         };
 
+        // Record the device in the login history
+        self.server
+            .record_login(
+                access_token.account_id(),
+                NetworkListenerProtocol::ManageSieve,
+                self.remote_addr,
+                None,
+            )
+            .await;
+        self.server
+            .inner
+            .data
+            .sessions
+            .set_account(self.session_id, access_token.account_id());
+
         // Create session
         self.state = State::Authenticated {
             access_token,
@@ -19,6 +19,7 @@ pub mod logout;
 pub mod noop;
 pub mod putscript;
 pub mod renamescript;
+pub mod rollbackscript;
 pub mod setactive;
 
 impl<T: SessionStream> Session<T> {
@@ -6,7 +6,11 @@
 
 use crate::core::{Command, ResponseCode, Session, StatusResponse};
 use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
-use email::sieve::SieveScript;
+use email::sieve::{
+    SieveScript,
+    history::{SieveScriptHistory, SieveScriptVersion},
+    ingest::SieveScriptIngest,
+};
 use imap_proto::receiver::Request;
 use registry::schema::enums::{Permission, StorageQuota};
 use sieve::compiler::ErrorType;
@@ -54,16 +58,21 @@ impl<T: SessionStream> Session<T> {
             .await
             .caused_by(trc::location!())?;
 
-        if self
-            .server
-            .document_ids(account_id, Collection::SieveScript, SieveField::Name)
-            .await
-            .caused_by(trc::location!())?
-            .len()
-            >= self
+        // Validate name
+        let document_id = self.validate_name(account_id, &name).await?;
+
+        // Replacing a script does not count towards the script limit
+        if document_id.is_none()
+            && self
                 .server
-                .object_quota(account.object_quotas(), StorageQuota::MaxSieveScripts)
-                as u64
+                .document_ids(account_id, Collection::SieveScript, SieveField::Name)
+                .await
+                .caused_by(trc::location!())?
+                .len()
+                >= self
+                    .server
+                    .object_quota(account.object_quotas(), StorageQuota::MaxSieveScripts)
+                    as u64
         {
             return Err(trc::ManageSieveEvent::Error
                 .into_err()
@@ -71,6 +80,20 @@ impl<T: SessionStream> Session<T> {
                 .code(ResponseCode::QuotaMaxScripts));
         }
 
+        if let Some(max_total_size) = self.server.core.email.sieve_max_total_size
+            && self
+                .server
+                .sieve_script_total_size(account_id, document_id)
+                .await?
+                + script_size as u64
+                > max_total_size
+        {
+            return Err(trc::ManageSieveEvent::Error
+                .into_err()
+                .details("Total size of scripts exceeds quota.")
+                .code(ResponseCode::QuotaMaxSize));
+        }
+
         // Compile script
         match self
             .server
@@ -101,8 +124,7 @@ impl<T: SessionStream> Session<T> {
             }
         }
 
-        // Validate name
-        if let Some(document_id) = self.validate_name(account_id, &name).await? {
+        if let Some(document_id) = document_id {
             // Obtain script values
             let script_ = self
                 .server
@@ -124,6 +146,22 @@ impl<T: SessionStream> Session<T> {
                 .to_unarchived::<SieveScript>()
                 .caused_by(trc::location!())?;
 
+            // Keep the replaced version of the active script for rollbacks
+            let replaced_version =
+                if self.server.sieve_script_get_active_id(account_id).await? == Some(document_id) {
+                    Some((
+                        SieveScriptVersion::new(
+                            (&script.inner.blob_hash).into(),
+                            u32::from(script.inner.size),
+                        ),
+                        self.server
+                            .sieve_script_previous_version(account_id, document_id)
+                            .await?,
+                    ))
+                } else {
+                    None
+                };
+
             // Write script blob
             let (blob_hash, blob_hold) = self
                 .server
@@ -148,8 +186,11 @@ impl<T: SessionStream> Session<T> {
                         .with_current(script)
                         .with_changed_by(account.account_tenant_ids()),
                 )
-                .caused_by(trc::location!())?
-                .clear(blob_hold);
+                .caused_by(trc::location!())?;
+            if let Some((replaced_version, previous_version)) = replaced_version {
+                replaced_version.retain(&mut batch, previous_version, &blob_hash)?;
+            }
+            batch.clear(blob_hold);
 
             self.server
                 .commit_batch(batch)
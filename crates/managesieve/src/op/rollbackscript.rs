@@ -0,0 +1,142 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::core::{Command, ResponseCode, Session, StatusResponse};
+use common::{network::SessionStream, storage::index::ObjectIndexBuilder};
+use email::sieve::{
+    SieveScript,
+    history::{SieveScriptHistory, SieveScriptVersion},
+};
+use imap_proto::receiver::Request;
+use registry::schema::enums::Permission;
+use std::time::Instant;
+use store::{
+    ValueKey,
+    write::{AlignedBytes, Archive, BatchBuilder},
+};
+use trc::AddContext;
+use types::collection::Collection;
+
+impl<T: SessionStream> Session<T> {
+    pub async fn handle_rollbackscript(
+        &mut self,
+        request: Request<Command>,
+    ) -> trc::Result<Vec<u8>> {
+        // Validate access
+        self.assert_has_permission(Permission::SievePutScript)?;
+
+        let op_start = Instant::now();
+        let name = request
+            .tokens
+            .into_iter()
+            .next()
+            .and_then(|s| s.unwrap_string().ok())
+            .ok_or_else(|| {
+                trc::ManageSieveEvent::Error
+                    .into_err()
+                    .details("Expected script name as a parameter.")
+            })?;
+
+        // Versions are kept per script, so they survive renames
+        let account_id = self.state.access_token().account_id();
+        let document_id = self.get_script_id(account_id, &name).await?;
+        let previous_version = self
+            .server
+            .sieve_script_previous_version(account_id, document_id)
+            .await?
+            .ok_or_else(|| {
+                trc::ManageSieveEvent::Error
+                    .into_err()
+                    .details("There is no previous version of this script.")
+                    .code(ResponseCode::NonExistent)
+            })?;
+
+        // Obtain script values
+        let script_ = self
+            .server
+            .store()
+            .get_value::<Archive<AlignedBytes>>(ValueKey::archive(
+                account_id,
+                Collection::SieveScript,
+                document_id,
+            ))
+            .await
+            .caused_by(trc::location!())?
+            .ok_or_else(|| {
+                trc::ManageSieveEvent::Error
+                    .into_err()
+                    .details("Script not found")
+                    .code(ResponseCode::NonExistent)
+            })?;
+        let script = script_
+            .to_unarchived::<SieveScript>()
+            .caused_by(trc::location!())?;
+        let current_version = SieveScriptVersion::new(
+            (&script.inner.blob_hash).into(),
+            u32::from(script.inner.size),
+        );
+
+        // Check quota
+        let account = self.server.account(account_id).await?;
+        self.server
+            .has_available_quota(
+                &account,
+                previous_version.size.saturating_sub(current_version.size) as u64,
+            )
+            .await
+            .caused_by(trc::location!())?;
+        if let Some(max_total_size) = self.server.core.email.sieve_max_total_size
+            && self
+                .server
+                .sieve_script_total_size(account_id, Some(document_id))
+                .await?
+                + previous_version.size as u64
+                > max_total_size
+        {
+            return Err(trc::ManageSieveEvent::Error
+                .into_err()
+                .details("Total size of scripts exceeds quota.")
+                .code(ResponseCode::QuotaMaxSize));
+        }
+
+        // Swap the current and previous versions
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::SieveScript)
+            .with_document(document_id)
+            .custom(
+                ObjectIndexBuilder::new()
+                    .with_changes(
+                        script
+                            .deserialize()
+                            .caused_by(trc::location!())?
+                            .with_size(previous_version.size)
+                            .with_blob_hash(previous_version.blob_hash.clone()),
+                    )
+                    .with_current(script)
+                    .with_changed_by(account.account_tenant_ids()),
+            )
+            .caused_by(trc::location!())?;
+        let blob_hash = previous_version.blob_hash.clone();
+        current_version.retain(&mut batch, Some(previous_version), &blob_hash)?;
+
+        self.server
+            .commit_batch(batch)
+            .await
+            .caused_by(trc::location!())?;
+
+        trc::event!(
+            ManageSieve(trc::ManageSieveEvent::RollbackScript),
+            SpanId = self.session_id,
+            Id = name,
+            DocumentId = document_id,
+            Elapsed = op_start.elapsed()
+        );
+
+        Ok(StatusResponse::ok("Success.").into_bytes())
+    }
+}
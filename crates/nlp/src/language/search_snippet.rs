@@ -38,10 +38,13 @@ pub fn generate_snippet(
     needles: &[impl AsRef<str>],
     language: Language,
     is_exact: bool,
+    max_len: usize,
 ) -> Option<String> {
+    // Tokens are not length limited, CJK text has no word separators
+    // and long runs would otherwise never be matched.
     let mut terms = Vec::new();
     if is_exact {
-        let tokens = language.tokenize_text(text, 200).collect::<Vec<_>>();
+        let tokens = language.tokenize_text(text, usize::MAX).collect::<Vec<_>>();
         for tokens in tokens.windows(needles.len()) {
             if needles
                 .iter()
@@ -57,11 +60,33 @@ pub fn generate_snippet(
             }
         }
     } else {
-        for token in language.tokenize_text(text, 200) {
-            if needles.iter().any(|needle| {
+        for token in language.tokenize_text(text, usize::MAX) {
+            let word = token.word.as_ref();
+            let is_ideographic = word.len() == token.to - token.from && word.chars().any(is_cjk);
+            let mut is_match = false;
+
+            for needle in needles {
                 let needle = needle.as_ref();
-                needle == token.word.as_ref() || needle.len() > 2 && token.word.contains(needle)
-            }) {
+                if needle == word {
+                    is_match = true;
+                    break;
+                } else if needle.len() > 2 && word.contains(needle) {
+                    if is_ideographic {
+                        // Only the matching characters are highlighted in ideographic text
+                        for (pos, _) in word.match_indices(needle) {
+                            terms.push(Term {
+                                offset: token.from + pos,
+                                len: needle.len(),
+                            });
+                        }
+                    } else {
+                        is_match = true;
+                        break;
+                    }
+                }
+            }
+
+            if is_match {
                 terms.push(Term {
                     offset: token.from,
                     len: token.to - token.from,
@@ -69,19 +94,33 @@ pub fn generate_snippet(
             }
         }
     }
-    if terms.is_empty() {
+
+    // Merge overlapping and adjacent terms into a single mark
+    terms.sort_unstable_by_key(|term| term.offset);
+    let mut merged_terms: Vec<Term> = Vec::with_capacity(terms.len());
+    for term in terms {
+        if let Some(last_term) = merged_terms.last_mut()
+            && term.offset <= last_term.offset + last_term.len
+        {
+            last_term.len = std::cmp::max(last_term.offset + last_term.len, term.offset + term.len)
+                - last_term.offset;
+        } else {
+            merged_terms.push(term);
+        }
+    }
+    if merged_terms.is_empty() {
         return None;
     }
 
-    let mut snippet = String::with_capacity(text.len());
-    let start_offset = terms.first()?.offset;
+    let mut snippet = String::with_capacity(std::cmp::min(text.len(), max_len));
+    let start_offset = merged_terms.first()?.offset;
 
     if start_offset > 0 {
         let mut word_count = 0;
         let mut from_offset = 0;
         let mut last_is_space = false;
 
-        if text.len() > 240 {
+        if text.len() > max_len.saturating_sub(15) {
             for (pos, char) in text.get(0..start_offset)?.char_indices().rev() {
                 // Add up to 2 words or 40 characters of context
                 if char.is_whitespace() {
@@ -116,15 +155,19 @@ pub fn generate_snippet(
         }
     }
 
-    let mut terms = terms.iter().peekable();
+    let mut terms = merged_terms.iter().peekable();
 
     'outer: while let Some(term) = terms.next() {
-        if snippet.len() + ("<mark>".len() * 2) + term.len + 1 > 255 {
+        let term_text = text.get(term.offset..term.offset + term.len)?;
+        let term_len = term_text.chars().map(escape_char_len).sum::<usize>();
+        if snippet.len() + "<mark></mark>".len() + term_len > max_len {
             break;
         }
 
         snippet.push_str("<mark>");
-        snippet.push_str(text.get(term.offset..term.offset + term.len)?);
+        for char in term_text.chars() {
+            escape_char(char, &mut snippet);
+        }
         snippet.push_str("</mark>");
 
         let next_offset = if let Some(next_term) = terms.peek() {
@@ -144,7 +187,7 @@ pub fn generate_snippet(
                 last_is_space = true;
             }
 
-            if snippet.len() + escape_char_len(char) <= 255 {
+            if snippet.len() + escape_char_len(char) <= max_len {
                 escape_char(char, &mut snippet);
             } else {
                 break 'outer;
@@ -155,9 +198,55 @@ pub fn generate_snippet(
     Some(snippet)
 }
 
+// Plain text preview with collapsed whitespace, truncated to a number of characters.
+pub fn generate_preview(text: &str, max_chars: usize) -> String {
+    let mut preview = String::with_capacity(std::cmp::min(text.len(), max_chars));
+    let mut char_count = 0;
+    let mut last_is_space = true;
+
+    for char in text.chars() {
+        let char = if char.is_whitespace() {
+            if last_is_space {
+                continue;
+            }
+            last_is_space = true;
+            ' '
+        } else if char.is_control() {
+            continue;
+        } else {
+            last_is_space = false;
+            char
+        };
+
+        if char_count == max_chars {
+            break;
+        }
+        preview.push(char);
+        char_count += 1;
+    }
+    preview.truncate(preview.trim_end().len());
+
+    preview
+}
+
+fn is_cjk(c: char) -> bool {
+    matches!(
+        c as u32,
+        0x3040..=0x30FF
+            | 0x3400..=0x4DBF
+            | 0x4E00..=0x9FFF
+            | 0xAC00..=0xD7AF
+            | 0xF900..=0xFAFF
+            | 0x20000..=0x2FA1F
+    )
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::language::{Language, search_snippet::generate_snippet};
+    use crate::language::{
+        Language,
+        search_snippet::{generate_preview, generate_snippet},
+    };
 
     #[test]
     fn search_snippets() {
@@ -258,7 +347,7 @@ mod tests {
 
                 for part in &parts {
                     if let Some(matched) =
-                        generate_snippet(part, &needles, Language::English, false)
+                        generate_snippet(part, &needles, Language::English, false, 255)
                     {
                         results.push(matched);
                     }
@@ -268,4 +357,53 @@ mod tests {
             }
         }
     }
+
+    #[test]
+    fn search_snippets_escaped_and_cjk() {
+        for (text, needles, max_len, expected) in [
+            (
+                "Tom & Jerry <b>cartoon</b>",
+                vec!["jerry"],
+                255,
+                Some("Tom &amp; <mark>Jerry</mark> &lt;b&gt;cartoon&lt;/b&gt;"),
+            ),
+            (
+                "Hello world, hello again",
+                vec!["hello"],
+                40,
+                Some("<mark>Hello</mark> world, "),
+            ),
+            (
+                concat!(
+                    "孫子曰兵者國之大事死生之地存亡之道不可不察也孫子曰凡用兵之法馳車千駟革車千乘",
+                    "帶甲十萬千里饋糧則內外之費賓客之用膠漆之材車甲之奉日費千金然後十萬之師舉矣"
+                ),
+                vec!["千金"],
+                255,
+                Some(concat!(
+                    "孫子曰兵者國之大事死生之地存亡之道不可不察也孫子曰凡用兵之法馳車千駟革車千乘",
+                    "帶甲十萬千里饋糧則內外之費賓客之用膠漆之材車甲之奉日費<mark>千金</mark>然後十萬之師舉矣"
+                )),
+            ),
+            ("no matches here", vec!["missing"], 255, None),
+        ] {
+            assert_eq!(
+                generate_snippet(text, &needles, Language::English, false, max_len).as_deref(),
+                expected,
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn previews() {
+        for (text, max_chars, expected) in [
+            ("  Hello\r\n\r\n  world\t!  ", 256, "Hello world !"),
+            ("Côte d'Ivoire", 4, "Côte"),
+            ("孫子兵法 孫子曰", 6, "孫子兵法 孫"),
+            ("word   ", 5, "word"),
+        ] {
+            assert_eq!(generate_preview(text, max_chars), expected);
+        }
+    }
 }
@@ -78,6 +78,12 @@ impl<T: SessionStream> Session<T> {
             }
         }
 
+        // Responses to pipelined commands are sent together
+        if requests.len() > 1 {
+            self.pipeline = Some(Vec::with_capacity(1024));
+        }
+
+        let mut session_result = SessionResult::Continue;
         for request in requests {
             let result = match request {
                 Ok(command) => match self.validate_request(command).await {
@@ -163,16 +169,25 @@ impl<T: SessionStream> Session<T> {
 
             match result {
                 Ok(SessionResult::Continue) => (),
-                Ok(result) => return result,
+                Ok(result) => {
+                    session_result = result;
+                    break;
+                }
                 Err(err) => {
                     if !self.write_err(err).await {
-                        return SessionResult::Close;
+                        session_result = SessionResult::Close;
+                        break;
                     }
                 }
             }
         }
 
-        SessionResult::Continue
+        if let Err(err) = self.flush_pipeline().await {
+            trc::error!(err.span_id(self.session_id));
+            return SessionResult::Close;
+        }
+
+        session_result
     }
 
     async fn validate_request(
@@ -218,12 +233,20 @@ impl<T: SessionStream> Session<T> {
                 }
             }
             Command::Stls => {
-                if !self.stream.is_tls() {
-                    Ok(command)
-                } else {
+                if self.stream.is_tls() {
                     Err(trc::Pop3Event::Error
                         .into_err()
                         .details("Already in TLS mode."))
+                } else if !self.instance.acceptor.is_tls() {
+                    Err(trc::Pop3Event::Error
+                        .into_err()
+                        .details("TLS is not available."))
+                } else if !matches!(self.state, State::NotAuthenticated { .. }) {
+                    Err(trc::Pop3Event::Error
+                        .into_err()
+                        .details("Already authenticated."))
+                } else {
+                    Ok(command)
                 }
             }
 
@@ -44,6 +44,7 @@ pub struct Session<T: SessionStream> {
     pub in_flight: InFlight,
     pub remote_addr: IpAddr,
     pub session_id: u64,
+    pub pipeline: Option<Vec<u8>>,
 }
 
 pub enum State {
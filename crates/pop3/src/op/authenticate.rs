@@ -9,12 +9,12 @@ use crate::{
     protocol::{Command, Mechanism, request},
 };
 use common::{
-    auth::AuthRequest,
+    auth::{AccessToken, AuthRequest},
     network::{SessionStream, limiter::LimiterResult},
 };
 use directory::Credentials;
 use mail_parser::decoders::base64::base64_decode;
-use registry::schema::enums::Permission;
+use registry::schema::enums::{NetworkListenerProtocol, Permission};
 
 impl<T: SessionStream> Session<T> {
     pub async fn handle_sasl(
@@ -24,7 +24,11 @@ impl<T: SessionStream> Session<T> {
     ) -> trc::Result<()> {
         match mechanism {
             Mechanism::Plain | Mechanism::OAuthBearer | Mechanism::XOauth2 => {
-                if !params.is_empty() {
+                if params.last().is_some_and(|param| param == "*") {
+                    Err(trc::AuthEvent::Error
+                        .into_err()
+                        .details("Authentication cancelled"))
+                } else if !params.is_empty() {
                     let credentials = base64_decode(params.pop().unwrap().as_bytes())
                         .and_then(|challenge| {
                             if mechanism == Mechanism::Plain {
@@ -51,7 +55,40 @@ impl<T: SessionStream> Session<T> {
                         last_is_space: true,
                     };
 
-                    self.write_bytes("+\r\n").await
+                    self.write_bytes("+ \r\n").await
+                }
+            }
+            Mechanism::External => {
+                if params.last().is_some_and(|param| param == "*") {
+                    Err(trc::AuthEvent::Error
+                        .into_err()
+                        .details("Authentication cancelled"))
+                } else if let Some(response) = params.pop() {
+                    // An empty response is sent as "="
+                    let authz_id = if response != "=" {
+                        base64_decode(response.as_bytes())
+                            .and_then(|authz_id| String::from_utf8(authz_id).ok())
+                            .ok_or_else(|| {
+                                trc::AuthEvent::Error
+                                    .into_err()
+                                    .details("Invalid authorization identity")
+                            })?
+                    } else {
+                        String::new()
+                    };
+
+                    Box::pin(self.handle_auth_external(authz_id)).await
+                } else {
+                    self.receiver.state = request::State::Argument {
+                        request: Command::Auth {
+                            mechanism: mechanism.as_str().as_bytes().to_vec(),
+                            params: vec![b"=".to_vec()],
+                        },
+                        num: 2,
+                        last_is_space: true,
+                    };
+
+                    self.write_bytes("+ \r\n").await
                 }
             }
             _ => Err(trc::AuthEvent::Error
@@ -70,28 +107,56 @@ impl<T: SessionStream> Session<T> {
                 self.remote_addr,
             ))
             .await
-            .map_err(|err| {
-                if err.matches(trc::EventType::Auth(trc::AuthEvent::Failed)) {
-                    match &self.state {
-                        State::NotAuthenticated {
-                            auth_failures,
-                            username,
-                        } if *auth_failures < self.server.core.imap.max_auth_failures => {
-                            self.state = State::NotAuthenticated {
-                                auth_failures: auth_failures + 1,
-                                username: username.clone(),
-                            };
-                        }
-                        _ => {
-                            return trc::AuthEvent::TooManyAttempts.into_err().caused_by(err);
-                        }
-                    }
-                }
+            .map_err(|err| self.auth_failure(err))
+            .and_then(|token| token.assert_has_permission(Permission::Pop3Authenticate))?;
+
+        self.start_session(access_token).await
+    }
+
+    // Clients presenting a certificate issued by one of the trusted authorities
+    // of the listener are authenticated as the account owning its address.
+    pub async fn handle_auth_external(&mut self, authz_id: String) -> trc::Result<()> {
+        let Some(cert) = self.instance.tls_client_certificate(&self.stream) else {
+            return Err(trc::AuthEvent::Error
+                .into_err()
+                .details("No client certificate was presented."));
+        };
 
-                err
-            })
+        let access_token = self
+            .server
+            .authenticate_sasl_external(&cert, &authz_id, self.session_id, self.remote_addr)
+            .await
+            .map_err(|err| self.auth_failure(err))
             .and_then(|token| token.assert_has_permission(Permission::Pop3Authenticate))?;
 
+        self.start_session(access_token).await
+    }
+
+    fn auth_failure(&mut self, err: trc::Error) -> trc::Error {
+        if matches!(
+            err.as_ref(),
+            trc::EventType::Auth(trc::AuthEvent::Failed | trc::AuthEvent::ClientCertificateFailed)
+        ) {
+            match &self.state {
+                State::NotAuthenticated {
+                    auth_failures,
+                    username,
+                } if *auth_failures < self.server.core.imap.max_auth_failures => {
+                    self.state = State::NotAuthenticated {
+                        auth_failures: auth_failures + 1,
+                        username: username.clone(),
+                    };
+                }
+                _ => {
+                    return trc::AuthEvent::TooManyAttempts.into_err().caused_by(err);
+                }
+            }
+        }
+
+        err
+    }
+
+    async fn start_session(&mut self, access_token: AccessToken) -> trc::Result<()> {
         // Enforce concurrency limits
         let in_flight = match access_token.is_imap_request_allowed() {
             LimiterResult::Allowed(in_flight) => Some(in_flight),
@@ -104,6 +169,21 @@ impl<T: SessionStream> Session<T> {
         // Fetch mailbox
         let mailbox = self.fetch_mailbox(access_token.account_id()).await?;
 
+        // Record the device in the login history
+        self.server
+            .record_login(
+                access_token.account_id(),
+                NetworkListenerProtocol::Pop3,
+                self.remote_addr,
+                None,
+            )
+            .await;
+        self.server
+            .inner
+            .data
+            .sessions
+            .set_account(self.session_id, access_token.account_id());
+
         // Create session
         self.state = State::Authenticated {
             in_flight,
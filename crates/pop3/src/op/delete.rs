@@ -8,7 +8,7 @@ use std::time::Instant;
 
 use common::network::SessionStream;
 use email::message::delete::EmailDeletion;
-use registry::schema::enums::Permission;
+use registry::schema::enums::{MessageAuditEvent, Permission};
 use store::{roaring::RoaringBitmap, write::BatchBuilder};
 use trc::AddContext;
 
@@ -86,6 +86,7 @@ impl<T: SessionStream> Session<T> {
 
             if !deleted.is_empty() {
                 let num_deleted = deleted.len();
+                let audit_ids = self.server.is_audit_log_enabled().then(|| deleted.clone());
                 let mut batch = BatchBuilder::new();
                 let not_deleted = self
                     .server
@@ -105,6 +106,21 @@ impl<T: SessionStream> Session<T> {
                         .caused_by(trc::location!())?;
                     self.server.notify_task_queue();
                 }
+
+                // Record audit trail
+                if let Some(mut audit_ids) = audit_ids {
+                    audit_ids -= &not_deleted;
+                    self.server
+                        .audit_messages(
+                            mailbox.account_id,
+                            MessageAuditEvent::Deleted,
+                            audit_ids,
+                            self.state.access_token().account_id().into(),
+                            self.remote_addr.to_string().into(),
+                        )
+                        .await;
+                }
+
                 if not_deleted.is_empty() {
                     self.write_ok(format!(
                         "Stalwart POP3 bids you farewell ({num_deleted} messages deleted)."
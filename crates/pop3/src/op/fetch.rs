@@ -42,6 +42,26 @@ impl<T: SessionStream> Session<T> {
                 let metadata = metadata_
                     .unarchive::<MessageMetadata>()
                     .caused_by(trc::location!())?;
+
+                // Clients polling with TOP n 0 only need the headers, which
+                // are stored with the metadata so the blob is not fetched
+                if lines == Some(0) {
+                    trc::event!(
+                        Pop3(trc::Pop3Event::Fetch),
+                        SpanId = self.session_id,
+                        DocumentId = message.id,
+                        Elapsed = op_start.elapsed()
+                    );
+
+                    let response = Response::Message::<u32> {
+                        bytes: ChainedBytes::new(metadata.raw_headers.as_ref()).get_full_range(),
+                        lines: 0,
+                    }
+                    .serialize();
+                    self.throttle_bandwidth(response.len()).await;
+                    return self.write_bytes(response).await;
+                }
+
                 if let Some(bytes) = self
                     .server
                     .blob_store()
@@ -64,14 +84,13 @@ impl<T: SessionStream> Session<T> {
                         )
                         .get_full_range();
 
-                    self.write_bytes(
-                        Response::Message::<u32> {
-                            bytes,
-                            lines: lines.unwrap_or(0),
-                        }
-                        .serialize(),
-                    )
-                    .await
+                    let response = Response::Message::<u32> {
+                        bytes,
+                        lines: lines.unwrap_or(0),
+                    }
+                    .serialize();
+                    self.throttle_bandwidth(response.len()).await;
+                    self.write_bytes(response).await
                 } else {
                     Err(trc::Pop3Event::Error
                         .into_err()
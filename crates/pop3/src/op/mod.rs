@@ -18,11 +18,16 @@ pub mod list;
 
 impl<T: SessionStream> Session<T> {
     pub async fn handle_capa(&mut self) -> trc::Result<()> {
-        let mechanisms = if self.stream.is_tls() || self.server.core.imap.allow_plain_auth {
+        let mut mechanisms = if self.stream.is_tls() || self.server.core.imap.allow_plain_auth {
             vec![Mechanism::Plain, Mechanism::OAuthBearer, Mechanism::XOauth2]
         } else {
             vec![Mechanism::OAuthBearer, Mechanism::XOauth2]
         };
+        if self.instance.acceptor.is_tls_client_auth()
+            && self.stream.tls_peer_certificate().is_some()
+        {
+            mechanisms.push(Mechanism::External);
+        }
 
         trc::event!(
             Pop3(trc::Pop3Event::Capabilities),
@@ -35,7 +40,7 @@ impl<T: SessionStream> Session<T> {
         self.write_bytes(
             Response::Capability::<u32> {
                 mechanisms,
-                stls: !self.stream.is_tls(),
+                stls: !self.stream.is_tls() && self.instance.acceptor.is_tls(),
             }
             .serialize(),
         )
@@ -38,6 +38,8 @@ pub struct Parser {
 }
 
 const MAX_ARG_LEN: usize = 256;
+// OAuth bearer tokens are frequently larger than a regular argument
+const MAX_SASL_LEN: usize = 8192;
 
 impl Parser {
     pub fn parse(
@@ -210,7 +212,7 @@ impl Command<Vec<u8>, Vec<u8>> {
             Command::Auth { mechanism, params }
                 if arg_num <= 4
                     && mechanism.len() < 64
-                    && params.iter().map(|p| p.len()).sum::<usize>() < (MAX_ARG_LEN * 4) =>
+                    && params.iter().map(|p| p.len()).sum::<usize>() < MAX_SASL_LEN =>
             {
                 if arg_num == 1 {
                     mechanism.push(byte);
@@ -418,6 +420,16 @@ mod tests {
             assert_eq!(requests, chunked_expected, "Chunk size: {}", chunk_size);
         }
 
+        // Large SASL initial responses
+        let token = "a".repeat(4096);
+        assert_eq!(
+            parser.parse(&mut format!("AUTH OAUTHBEARER {token}\r\n").as_bytes().iter()),
+            Ok(Command::Auth {
+                mechanism: Mechanism::OAuthBearer,
+                params: vec![token],
+            })
+        );
+
         for cmd in [
             "user",
             "pass",
@@ -13,12 +13,15 @@ use crate::{
 };
 use common::{
     BuildServer,
-    network::{SessionData, SessionManager, SessionResult, SessionStream},
+    network::{SessionData, SessionManager, SessionResult, SessionStream, limiter::bandwidth_wait},
 };
 use std::borrow::Cow;
 use tokio::io::{AsyncReadExt, AsyncWriteExt};
 use tokio_rustls::server::TlsStream;
 
+// Pipelined responses are flushed early once they exceed this size
+const MAX_PIPELINE_SIZE: usize = 256 * 1024;
+
 impl SessionManager for Pop3SessionManager {
     #[allow(clippy::manual_async_fn)]
     fn handle<T: SessionStream>(
@@ -38,6 +41,7 @@ impl SessionManager for Pop3SessionManager {
                 in_flight: session.in_flight,
                 remote_addr: session.remote_ip,
                 session_id: session.session_id,
+                pipeline: None,
             };
 
             if session
@@ -133,6 +137,15 @@ impl<T: SessionStream> Session<T> {
     }
 
     pub async fn into_tls(self) -> Result<Session<TlsStream<T>>, ()> {
+        // Discard any state obtained before the TLS negotiation (RFC 2595, section 4)
+        let state = match self.state {
+            State::NotAuthenticated { auth_failures, .. } => State::NotAuthenticated {
+                auth_failures,
+                username: None,
+            },
+            state => state,
+        };
+
         Ok(Session {
             stream: self
                 .instance
@@ -140,11 +153,12 @@ impl<T: SessionStream> Session<T> {
                 .await?,
             server: self.server,
             instance: self.instance,
-            receiver: self.receiver,
-            state: self.state,
+            receiver: Parser::default(),
+            state,
             session_id: self.session_id,
             in_flight: self.in_flight,
             remote_addr: self.remote_addr,
+            pipeline: None,
         })
     }
 }
@@ -160,7 +174,27 @@ impl<T: SessionStream> Session<T> {
             Contents = trc::Value::from_maybe_string(bytes),
         );
 
-        self.stream.write_all(bytes.as_ref()).await.map_err(|err| {
+        if let Some(pipeline) = &mut self.pipeline {
+            pipeline.extend_from_slice(bytes);
+            if pipeline.len() < MAX_PIPELINE_SIZE {
+                return Ok(());
+            }
+            let pipeline = std::mem::take(pipeline);
+            self.write_stream(&pipeline).await
+        } else {
+            self.write_stream(bytes).await
+        }
+    }
+
+    pub async fn flush_pipeline(&mut self) -> trc::Result<()> {
+        match self.pipeline.take() {
+            Some(pipeline) if !pipeline.is_empty() => self.write_stream(&pipeline).await,
+            _ => Ok(()),
+        }
+    }
+
+    async fn write_stream(&mut self, bytes: &[u8]) -> trc::Result<()> {
+        self.stream.write_all(bytes).await.map_err(|err| {
             trc::NetworkEvent::WriteError
                 .into_err()
                 .reason(err)
@@ -174,6 +208,27 @@ impl<T: SessionStream> Session<T> {
         })
     }
 
+    pub async fn throttle_bandwidth(&self, bytes: usize) {
+        let access_token = self.state.access_token();
+        if let Some(wait) = bandwidth_wait(
+            access_token
+                .imap_bandwidth()
+                .into_iter()
+                .chain(self.instance.bandwidth.as_ref()),
+            bytes,
+        ) {
+            trc::event!(
+                Limit(trc::LimitEvent::BandwidthThrottled),
+                SpanId = self.session_id,
+                AccountId = access_token.account_id(),
+                Size = bytes,
+                Elapsed = wait,
+            );
+
+            tokio::time::sleep(wait).await;
+        }
+    }
+
     pub async fn write_ok(&mut self, message: impl Into<Cow<'static, str>>) -> trc::Result<()> {
         self.write_bytes(Response::Ok::<u32>(message.into()).serialize())
             .await
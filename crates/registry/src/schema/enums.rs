@@ -160,6 +160,22 @@ pub enum AzureEnvironment {
     UsGovernment = 2,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum BlobIntegrityIssueType {
+    #[default]
+    Missing = 0,
+    Corrupted = 1,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum BlobMirrorMode {
+    #[default]
+    Synchronous = 0,
+    Asynchronous = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum BlobStoreBaseType {
@@ -184,6 +200,9 @@ pub enum BlobStoreType {
     FoundationDb = 5,
     PostgreSql = 6,
     MySql = 7,
+    Regional = 8,
+    Tiered = 9,
+    Mirrored = 10,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -196,6 +215,7 @@ pub enum BlockReason {
     PortScanning = 3,
     Manual = 4,
     Other = 5,
+    SmtpSmuggling = 6,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -508,6 +528,7 @@ pub enum DnsResolverProtocol {
     Tls = 0,
     Udp = 1,
     Tcp = 2,
+    Https = 3,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -672,6 +693,16 @@ pub enum DnsServerType {
     Vultr = 67,
     WebSupport = 68,
     YandexCloud = 69,
+    Webhook = 70,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum DuplicateMatch {
+    #[default]
+    Any = 0,
+    MessageId = 1,
+    Content = 2,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -683,6 +714,14 @@ pub enum EncryptionAtRestType {
     Aes256 = 2,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum EncryptionKeyProviderType {
+    #[default]
+    Disabled = 0,
+    Vault = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum EventPolicy {
@@ -714,6 +753,13 @@ pub enum ExpressionConstant {
     Mixer = 16,
     Stanag4406 = 17,
     Nsep = 18,
+    Full = 19,
+    Ambiguous = 20,
+    Privacy = 21,
+    Allow = 22,
+    Reject = 23,
+    ReadOnly = 24,
+    Minimal = 25,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -737,6 +783,8 @@ pub enum ExpressionVariable {
     CcDomain = 14,
     CcLocal = 15,
     CcName = 16,
+    ClientName = 91,
+    ClientVersion = 92,
     Country = 17,
     Domain = 18,
     Email = 19,
@@ -804,6 +852,8 @@ pub enum ExpressionVariable {
     Subject = 81,
     SubjectThread = 82,
     SubjectWords = 83,
+    TlsClientSan = 93,
+    TlsClientSubject = 94,
     To = 84,
     ToDomain = 85,
     ToLocal = 86,
@@ -823,6 +873,15 @@ pub enum FailureReportingOption {
     SpfFailure = 3,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum FoundationDbPartitionMode {
+    #[default]
+    Disabled = 0,
+    Account = 1,
+    Tenant = 2,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum HttpAuthType {
@@ -846,6 +905,7 @@ pub enum InMemoryStoreBaseType {
     #[default]
     Redis = 0,
     RedisCluster = 1,
+    RedisSentinel = 2,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -856,6 +916,7 @@ pub enum InMemoryStoreType {
     Sharded = 1,
     Redis = 2,
     RedisCluster = 3,
+    RedisSentinel = 4,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -1263,6 +1324,37 @@ pub enum LookupStoreType {
     Sharded = 3,
     Redis = 4,
     RedisCluster = 5,
+    RedisSentinel = 6,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MailingListReplyTo {
+    #[default]
+    Unchanged = 0,
+    List = 1,
+    Sender = 2,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MailingListSenderRewrite {
+    #[default]
+    Disabled = 0,
+    Envelope = 1,
+    EnvelopeAndFrom = 2,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MessageAuditEvent {
+    #[default]
+    Received = 0,
+    Read = 1,
+    Moved = 2,
+    Deleted = 3,
+    Expunged = 4,
+    Exported = 5,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -1349,6 +1441,14 @@ pub enum MtaDeliveryExpirationType {
     Attempts = 1,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MtaDeliveryMode {
+    #[default]
+    Standard = 0,
+    Satellite = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum MtaDeliveryScheduleIntervalsOrDefaultType {
@@ -1402,6 +1502,14 @@ pub enum MtaProtocol {
     Lmtp = 1,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MtaProxyProtocol {
+    #[default]
+    Socks5 = 0,
+    Http = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum MtaQueueQuotaKey {
@@ -1428,6 +1536,16 @@ pub enum MtaRouteType {
     Mx = 0,
     Relay = 1,
     Local = 2,
+    LmtpExternal = 3,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MtaSenderMismatchAction {
+    #[default]
+    Reject = 0,
+    Rewrite = 1,
+    Tag = 2,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -1442,6 +1560,15 @@ pub enum MtaStage {
     Data = 5,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum MtaStructureLimitAction {
+    #[default]
+    Reject = 0,
+    Truncate = 1,
+    Tag = 2,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum NetworkListenerProtocol {
@@ -1454,6 +1581,15 @@ pub enum NetworkListenerProtocol {
     ManageSieve = 5,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum OAuthGrantType {
+    #[default]
+    AuthorizationCode = 0,
+    DeviceCode = 1,
+    RefreshToken = 2,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum OvhEndpoint {
@@ -1649,6 +1785,8 @@ pub enum Permission {
     ImapStore = 154,
     ImapSubscribe = 155,
     ImapThread = 156,
+    ImapGetMetadata = 660,
+    ImapSetMetadata = 661,
     Pop3Authenticate = 157,
     Pop3List = 158,
     Pop3Uidl = 159,
@@ -1710,7 +1848,28 @@ pub enum Permission {
     OAuthClientOverride = 215,
     LiveTracing = 216,
     LiveMetrics = 217,
+    TracingRuleList = 662,
+    TracingRuleManage = 663,
+    SieveForwardingGet = 664,
+    SieveForwardingUpdate = 665,
+    SieveStatsGet = 666,
     LiveDeliveryTest = 218,
+    ExpressionEvaluate = 667,
+    MailFlowDryRun = 668,
+    ReindexStatus = 669,
+    TaskList = 670,
+    DataStoreStats = 671,
+    DataStoreCheck = 672,
+    DataStoreRepair = 673,
+    MaintenanceWindowStatus = 674,
+    MaintenanceWindowTrigger = 675,
+    SessionList = 676,
+    SessionTerminate = 677,
+    AccountDataExport = 678,
+    AccountDataPurge = 679,
+    AccountAliasManage = 680,
+    DomainCatchAllManage = 681,
+    MailStoreImport = 682,
     SysAccountGet = 219,
     SysAccountCreate = 220,
     SysAccountUpdate = 221,
@@ -1787,6 +1946,11 @@ pub enum Permission {
     SysAsnUpdate = 292,
     SysAuthenticationGet = 293,
     SysAuthenticationUpdate = 294,
+    SysBlobIntegrityIssueGet = 683,
+    SysBlobIntegrityIssueCreate = 684,
+    SysBlobIntegrityIssueUpdate = 685,
+    SysBlobIntegrityIssueDestroy = 686,
+    SysBlobIntegrityIssueQuery = 687,
     SysBlobStoreGet = 295,
     SysBlobStoreUpdate = 296,
     SysBlockedIpGet = 297,
@@ -1825,6 +1989,11 @@ pub enum Permission {
     SysDataRetentionUpdate = 330,
     SysDataStoreGet = 331,
     SysDataStoreUpdate = 332,
+    SysDeliveryTraceGet = 688,
+    SysDeliveryTraceCreate = 689,
+    SysDeliveryTraceUpdate = 690,
+    SysDeliveryTraceDestroy = 691,
+    SysDeliveryTraceQuery = 692,
     SysDirectoryGet = 333,
     SysDirectoryCreate = 334,
     SysDirectoryUpdate = 335,
@@ -1863,6 +2032,11 @@ pub enum Permission {
     SysDomainQuery = 368,
     SysDsnReportSettingsGet = 369,
     SysDsnReportSettingsUpdate = 370,
+    SysDsnTemplateGet = 693,
+    SysDsnTemplateCreate = 694,
+    SysDsnTemplateUpdate = 695,
+    SysDsnTemplateDestroy = 696,
+    SysDsnTemplateQuery = 697,
     SysEmailGet = 371,
     SysEmailUpdate = 372,
     SysEnterpriseGet = 373,
@@ -1894,6 +2068,11 @@ pub enum Permission {
     SysLogUpdate = 399,
     SysLogDestroy = 400,
     SysLogQuery = 401,
+    SysLoginEntryGet = 698,
+    SysLoginEntryCreate = 699,
+    SysLoginEntryUpdate = 700,
+    SysLoginEntryDestroy = 701,
+    SysLoginEntryQuery = 702,
     SysMailingListGet = 402,
     SysMailingListCreate = 403,
     SysMailingListUpdate = 404,
@@ -1914,6 +2093,11 @@ pub enum Permission {
     SysMemoryLookupKeyValueUpdate = 419,
     SysMemoryLookupKeyValueDestroy = 420,
     SysMemoryLookupKeyValueQuery = 421,
+    SysMessageAuditEntryGet = 703,
+    SysMessageAuditEntryCreate = 704,
+    SysMessageAuditEntryUpdate = 705,
+    SysMessageAuditEntryDestroy = 706,
+    SysMessageAuditEntryQuery = 707,
     SysMetricGet = 422,
     SysMetricCreate = 423,
     SysMetricUpdate = 424,
@@ -2010,6 +2194,11 @@ pub enum Permission {
     SysPublicKeyUpdate = 515,
     SysPublicKeyDestroy = 516,
     SysPublicKeyQuery = 517,
+    SysQuarantinedMessageGet = 708,
+    SysQuarantinedMessageCreate = 709,
+    SysQuarantinedMessageUpdate = 710,
+    SysQuarantinedMessageDestroy = 711,
+    SysQuarantinedMessageQuery = 712,
     SysQueuedMessageGet = 518,
     SysQueuedMessageCreate = 519,
     SysQueuedMessageUpdate = 520,
@@ -2083,6 +2272,11 @@ pub enum Permission {
     SysSpamTrainingSampleQuery = 588,
     SysSpfReportSettingsGet = 589,
     SysSpfReportSettingsUpdate = 590,
+    SysStatisticsRollupGet = 713,
+    SysStatisticsRollupCreate = 714,
+    SysStatisticsRollupUpdate = 715,
+    SysStatisticsRollupDestroy = 716,
+    SysStatisticsRollupQuery = 717,
     SysStoreLookupGet = 591,
     SysStoreLookupCreate = 592,
     SysStoreLookupUpdate = 593,
@@ -2108,6 +2302,9 @@ pub enum Permission {
     TaskAcmeRenewal = 613,
     TaskDkimManagement = 614,
     TaskDnsManagement = 615,
+    TaskAccountExport = 718,
+    TaskAccountImport = 719,
+    TaskDataRegionMigration = 720,
     SysTaskGet = 616,
     SysTaskCreate = 617,
     SysTaskUpdate = 618,
@@ -2120,11 +2317,21 @@ pub enum Permission {
     SysTenantUpdate = 625,
     SysTenantDestroy = 626,
     SysTenantQuery = 627,
+    SysTenantUsageGet = 721,
+    SysTenantUsageCreate = 722,
+    SysTenantUsageUpdate = 723,
+    SysTenantUsageDestroy = 724,
+    SysTenantUsageQuery = 725,
     SysTlsExternalReportGet = 628,
     SysTlsExternalReportCreate = 629,
     SysTlsExternalReportUpdate = 630,
     SysTlsExternalReportDestroy = 631,
     SysTlsExternalReportQuery = 632,
+    SysTlsInboundReportGet = 726,
+    SysTlsInboundReportCreate = 727,
+    SysTlsInboundReportUpdate = 728,
+    SysTlsInboundReportDestroy = 729,
+    SysTlsInboundReportQuery = 730,
     SysTlsInternalReportGet = 633,
     SysTlsInternalReportCreate = 634,
     SysTlsInternalReportUpdate = 635,
@@ -2217,6 +2424,7 @@ pub enum RecipientFlag {
     #[default]
     DsnSent = 0,
     SpamPayload = 1,
+    Quarantine = 2,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2350,6 +2558,16 @@ pub enum SearchFileField {
     Content = 1,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum SearchFolderRole {
+    #[default]
+    None = 0,
+    All = 1,
+    Flagged = 2,
+    Important = 3,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum SearchStoreType {
@@ -2371,6 +2589,16 @@ pub enum SearchTracingField {
     Keywords = 2,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum SecretKeyLocalOptionalType {
+    #[default]
+    None = 0,
+    Value = 1,
+    EnvironmentVariable = 2,
+    File = 3,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum SecretKeyOptionalType {
@@ -2379,6 +2607,9 @@ pub enum SecretKeyOptionalType {
     Value = 1,
     EnvironmentVariable = 2,
     File = 3,
+    Vault = 4,
+    AwsSecretsManager = 5,
+    EnvironmentJson = 6,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2388,6 +2619,9 @@ pub enum SecretKeyType {
     Value = 0,
     EnvironmentVariable = 1,
     File = 2,
+    Vault = 3,
+    AwsSecretsManager = 4,
+    EnvironmentJson = 5,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2398,6 +2632,9 @@ pub enum SecretTextOptionalType {
     Text = 1,
     EnvironmentVariable = 2,
     File = 3,
+    Vault = 4,
+    AwsSecretsManager = 5,
+    EnvironmentJson = 6,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2407,6 +2644,9 @@ pub enum SecretTextType {
     Text = 0,
     EnvironmentVariable = 1,
     File = 2,
+    Vault = 3,
+    AwsSecretsManager = 4,
+    EnvironmentJson = 5,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2423,6 +2663,14 @@ pub enum ServiceProtocol {
     Managesieve = 7,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum SharedMailboxDestroy {
+    #[default]
+    Reassign = 0,
+    Deny = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum SieveCapability {
@@ -2617,6 +2865,14 @@ pub enum SqlAuthStoreType {
     Sqlite = 3,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum StatisticsPeriod {
+    #[default]
+    Hourly = 0,
+    Daily = 1,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum StorageQuota {
@@ -2659,6 +2915,8 @@ pub enum TaskAccountMaintenanceType {
     Reindex = 1,
     RecalculateImapUid = 2,
     RecalculateQuota = 3,
+    TierBlobs = 4,
+    RestoreBlobs = 5,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2708,6 +2966,12 @@ pub enum TaskStoreMaintenanceType {
     RemoveLockDav = 12,
     RemoveSieveId = 13,
     RemoveGreylist = 14,
+    IndexBlobSizes = 15,
+    CompactDataStore = 16,
+    FlushDataStore = 17,
+    TierBlobs = 18,
+    ScrubBlobs = 19,
+    SendQuarantineDigests = 20,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -2739,6 +3003,9 @@ pub enum TaskType {
     AcmeRenewal = 15,
     DkimManagement = 16,
     DnsManagement = 17,
+    AccountExport = 18,
+    AccountImport = 19,
+    DataRegionMigration = 20,
 }
 
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
@@ -3377,6 +3644,16 @@ pub enum TlsCipherSuite {
     TlsEcdheRsaWithChacha20Poly1305Sha256 = 8,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum TlsKeyExchangeGroup {
+    #[default]
+    X25519Mlkem768 = 0,
+    X25519 = 1,
+    Secp256r1 = 2,
+    Secp384r1 = 3,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum TlsPolicyType {
@@ -3387,6 +3664,16 @@ pub enum TlsPolicyType {
     Other = 3,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum TlsProfile {
+    #[default]
+    Custom = 0,
+    Modern = 1,
+    Intermediate = 2,
+    Old = 3,
+}
+
 #[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
 #[repr(u16)]
 pub enum TlsResultType {
@@ -3500,6 +3787,15 @@ pub enum UserRolesType {
     Custom = 2,
 }
 
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[repr(u16)]
+pub enum VirtualFolderType {
+    #[default]
+    All = 0,
+    Flagged = 1,
+    Recent = 2,
+}
+
 pub static HTTP_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::Listener,
     ExpressionVariable::RemoteIp,
@@ -3514,6 +3810,15 @@ pub static HTTP_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::Method,
 ];
 
+pub static IMAP_CLIENT_VARIABLE: &[ExpressionVariable] = &[
+    ExpressionVariable::Listener,
+    ExpressionVariable::RemoteIp,
+    ExpressionVariable::Protocol,
+    ExpressionVariable::IsTls,
+    ExpressionVariable::ClientName,
+    ExpressionVariable::ClientVersion,
+];
+
 pub static MTA_CONNECTION_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::Listener,
     ExpressionVariable::RemoteIp,
@@ -3522,6 +3827,8 @@ pub static MTA_CONNECTION_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::LocalPort,
     ExpressionVariable::Protocol,
     ExpressionVariable::IsTls,
+    ExpressionVariable::TlsClientSubject,
+    ExpressionVariable::TlsClientSan,
     ExpressionVariable::Asn,
     ExpressionVariable::Country,
 ];
@@ -3534,6 +3841,8 @@ pub static MTA_EHLO_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::LocalPort,
     ExpressionVariable::Protocol,
     ExpressionVariable::IsTls,
+    ExpressionVariable::TlsClientSubject,
+    ExpressionVariable::TlsClientSan,
     ExpressionVariable::HeloDomain,
     ExpressionVariable::Asn,
     ExpressionVariable::Country,
@@ -3547,6 +3856,8 @@ pub static MTA_MAIL_FROM_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::LocalPort,
     ExpressionVariable::Protocol,
     ExpressionVariable::IsTls,
+    ExpressionVariable::TlsClientSubject,
+    ExpressionVariable::TlsClientSan,
     ExpressionVariable::Sender,
     ExpressionVariable::SenderDomain,
     ExpressionVariable::AuthenticatedAs,
@@ -3624,6 +3935,8 @@ pub static MTA_RCPT_TO_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::LocalPort,
     ExpressionVariable::Protocol,
     ExpressionVariable::IsTls,
+    ExpressionVariable::TlsClientSubject,
+    ExpressionVariable::TlsClientSan,
     ExpressionVariable::Priority,
     ExpressionVariable::HeloDomain,
     ExpressionVariable::Asn,
@@ -3921,6 +4234,12 @@ pub static SPAM_URL_VARIABLE: &[ExpressionVariable] = &[
     ExpressionVariable::Location,
 ];
 
+pub static IMAP_CLIENT_POLICY_CONSTANT: &[ExpressionConstant] = &[
+    ExpressionConstant::Allow,
+    ExpressionConstant::ReadOnly,
+    ExpressionConstant::Reject,
+];
+
 pub static MTA_AGGREGATE_CONSTANT: &[ExpressionConstant] = &[
     ExpressionConstant::Hourly,
     ExpressionConstant::Daily,
@@ -3948,6 +4267,12 @@ pub static MTA_PRIORITY_CONSTANT: &[ExpressionConstant] = &[
     ExpressionConstant::Nsep,
 ];
 
+pub static MTA_RECEIVED_FORMAT_CONSTANT: &[ExpressionConstant] = &[
+    ExpressionConstant::Full,
+    ExpressionConstant::Privacy,
+    ExpressionConstant::Minimal,
+];
+
 pub static MTA_REQUIRE_CONSTANT: &[ExpressionConstant] = &[
     ExpressionConstant::Optional,
     ExpressionConstant::Require,
@@ -3959,3 +4284,10 @@ pub static MTA_VERIFY_CONSTANT: &[ExpressionConstant] = &[
     ExpressionConstant::Strict,
     ExpressionConstant::Disable,
 ];
+
+pub static MTA_VRFY_EXPN_CONSTANT: &[ExpressionConstant] = &[
+    ExpressionConstant::Full,
+    ExpressionConstant::Ambiguous,
+    ExpressionConstant::Privacy,
+    ExpressionConstant::Disable,
+];
@@ -860,6 +860,106 @@ impl<'de> serde::Deserialize<'de> for AzureEnvironment {
     }
 }
 
+impl EnumImpl for BlobIntegrityIssueType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"missing" => BlobIntegrityIssueType::Missing,
+            b"corrupted" => BlobIntegrityIssueType::Corrupted,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            BlobIntegrityIssueType::Missing => "missing",
+            BlobIntegrityIssueType::Corrupted => "corrupted",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(BlobIntegrityIssueType::Missing),
+            1 => Some(BlobIntegrityIssueType::Corrupted),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for BlobIntegrityIssueType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BlobIntegrityIssueType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
+impl EnumImpl for BlobMirrorMode {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"synchronous" => BlobMirrorMode::Synchronous,
+            b"asynchronous" => BlobMirrorMode::Asynchronous,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            BlobMirrorMode::Synchronous => "synchronous",
+            BlobMirrorMode::Asynchronous => "asynchronous",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(BlobMirrorMode::Synchronous),
+            1 => Some(BlobMirrorMode::Asynchronous),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for BlobMirrorMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for BlobMirrorMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for BlobStoreBaseType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -934,6 +1034,9 @@ impl EnumImpl for BlobStoreType {
             b"FoundationDb" => BlobStoreType::FoundationDb,
             b"PostgreSql" => BlobStoreType::PostgreSql,
             b"MySql" => BlobStoreType::MySql,
+            b"Regional" => BlobStoreType::Regional,
+            b"Tiered" => BlobStoreType::Tiered,
+            b"Mirrored" => BlobStoreType::Mirrored,
         }
     }
 
@@ -947,6 +1050,9 @@ impl EnumImpl for BlobStoreType {
             BlobStoreType::FoundationDb => "FoundationDb",
             BlobStoreType::PostgreSql => "PostgreSql",
             BlobStoreType::MySql => "MySql",
+            BlobStoreType::Regional => "Regional",
+            BlobStoreType::Tiered => "Tiered",
+            BlobStoreType::Mirrored => "Mirrored",
         }
     }
 
@@ -964,11 +1070,14 @@ impl EnumImpl for BlobStoreType {
             5 => Some(BlobStoreType::FoundationDb),
             6 => Some(BlobStoreType::PostgreSql),
             7 => Some(BlobStoreType::MySql),
+            8 => Some(BlobStoreType::Regional),
+            9 => Some(BlobStoreType::Tiered),
+            10 => Some(BlobStoreType::Mirrored),
             _ => None,
         }
     }
 
-    const COUNT: usize = 8;
+    const COUNT: usize = 11;
 }
 
 impl serde::Serialize for BlobStoreType {
@@ -1000,6 +1109,7 @@ impl EnumImpl for BlockReason {
             b"portScanning" => BlockReason::PortScanning,
             b"manual" => BlockReason::Manual,
             b"other" => BlockReason::Other,
+            b"smtpSmuggling" => BlockReason::SmtpSmuggling,
         }
     }
 
@@ -1011,6 +1121,7 @@ impl EnumImpl for BlockReason {
             BlockReason::PortScanning => "portScanning",
             BlockReason::Manual => "manual",
             BlockReason::Other => "other",
+            BlockReason::SmtpSmuggling => "smtpSmuggling",
         }
     }
 
@@ -1026,11 +1137,12 @@ impl EnumImpl for BlockReason {
             3 => Some(BlockReason::PortScanning),
             4 => Some(BlockReason::Manual),
             5 => Some(BlockReason::Other),
+            6 => Some(BlockReason::SmtpSmuggling),
             _ => None,
         }
     }
 
-    const COUNT: usize = 6;
+    const COUNT: usize = 7;
 }
 
 impl serde::Serialize for BlockReason {
@@ -2722,6 +2834,7 @@ impl EnumImpl for DnsResolverProtocol {
             b"tls" => DnsResolverProtocol::Tls,
             b"udp" => DnsResolverProtocol::Udp,
             b"tcp" => DnsResolverProtocol::Tcp,
+            b"https" => DnsResolverProtocol::Https,
         }
     }
 
@@ -2730,6 +2843,7 @@ impl EnumImpl for DnsResolverProtocol {
             DnsResolverProtocol::Tls => "tls",
             DnsResolverProtocol::Udp => "udp",
             DnsResolverProtocol::Tcp => "tcp",
+            DnsResolverProtocol::Https => "https",
         }
     }
 
@@ -2742,11 +2856,12 @@ impl EnumImpl for DnsResolverProtocol {
             0 => Some(DnsResolverProtocol::Tls),
             1 => Some(DnsResolverProtocol::Udp),
             2 => Some(DnsResolverProtocol::Tcp),
+            3 => Some(DnsResolverProtocol::Https),
             _ => None,
         }
     }
 
-    const COUNT: usize = 3;
+    const COUNT: usize = 4;
 }
 
 impl serde::Serialize for DnsResolverProtocol {
@@ -3161,6 +3276,7 @@ impl EnumImpl for DnsServerType {
             b"Vultr" => DnsServerType::Vultr,
             b"WebSupport" => DnsServerType::WebSupport,
             b"YandexCloud" => DnsServerType::YandexCloud,
+            b"Webhook" => DnsServerType::Webhook,
         }
         .copied()
     }
@@ -3237,6 +3353,7 @@ impl EnumImpl for DnsServerType {
             DnsServerType::Vultr => "Vultr",
             DnsServerType::WebSupport => "WebSupport",
             DnsServerType::YandexCloud => "YandexCloud",
+            DnsServerType::Webhook => "Webhook",
         }
     }
 
@@ -3316,11 +3433,12 @@ impl EnumImpl for DnsServerType {
             67 => Some(DnsServerType::Vultr),
             68 => Some(DnsServerType::WebSupport),
             69 => Some(DnsServerType::YandexCloud),
+            70 => Some(DnsServerType::Webhook),
             _ => None,
         }
     }
 
-    const COUNT: usize = 70;
+    const COUNT: usize = 71;
 }
 
 impl serde::Serialize for DnsServerType {
@@ -3342,6 +3460,59 @@ impl<'de> serde::Deserialize<'de> for DnsServerType {
     }
 }
 
+impl EnumImpl for DuplicateMatch {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"any" => DuplicateMatch::Any,
+            b"messageId" => DuplicateMatch::MessageId,
+            b"content" => DuplicateMatch::Content,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            DuplicateMatch::Any => "any",
+            DuplicateMatch::MessageId => "messageId",
+            DuplicateMatch::Content => "content",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(DuplicateMatch::Any),
+            1 => Some(DuplicateMatch::MessageId),
+            2 => Some(DuplicateMatch::Content),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for DuplicateMatch {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for DuplicateMatch {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for EncryptionAtRestType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -3395,6 +3566,56 @@ impl<'de> serde::Deserialize<'de> for EncryptionAtRestType {
     }
 }
 
+impl EnumImpl for EncryptionKeyProviderType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"Disabled" => EncryptionKeyProviderType::Disabled,
+            b"Vault" => EncryptionKeyProviderType::Vault,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            EncryptionKeyProviderType::Disabled => "Disabled",
+            EncryptionKeyProviderType::Vault => "Vault",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(EncryptionKeyProviderType::Disabled),
+            1 => Some(EncryptionKeyProviderType::Vault),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for EncryptionKeyProviderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for EncryptionKeyProviderType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for EventPolicy {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -3468,6 +3689,13 @@ impl EnumImpl for ExpressionConstant {
             b"mixer" => ExpressionConstant::Mixer,
             b"stanag4406" => ExpressionConstant::Stanag4406,
             b"nsep" => ExpressionConstant::Nsep,
+            b"full" => ExpressionConstant::Full,
+            b"ambiguous" => ExpressionConstant::Ambiguous,
+            b"privacy" => ExpressionConstant::Privacy,
+            b"allow" => ExpressionConstant::Allow,
+            b"reject" => ExpressionConstant::Reject,
+            b"read_only" => ExpressionConstant::ReadOnly,
+            b"minimal" => ExpressionConstant::Minimal,
         }
     }
 
@@ -3492,6 +3720,13 @@ impl EnumImpl for ExpressionConstant {
             ExpressionConstant::Mixer => "mixer",
             ExpressionConstant::Stanag4406 => "stanag4406",
             ExpressionConstant::Nsep => "nsep",
+            ExpressionConstant::Full => "full",
+            ExpressionConstant::Ambiguous => "ambiguous",
+            ExpressionConstant::Privacy => "privacy",
+            ExpressionConstant::Allow => "allow",
+            ExpressionConstant::Reject => "reject",
+            ExpressionConstant::ReadOnly => "read_only",
+            ExpressionConstant::Minimal => "minimal",
         }
     }
 
@@ -3520,11 +3755,18 @@ impl EnumImpl for ExpressionConstant {
             16 => Some(ExpressionConstant::Mixer),
             17 => Some(ExpressionConstant::Stanag4406),
             18 => Some(ExpressionConstant::Nsep),
+            19 => Some(ExpressionConstant::Full),
+            20 => Some(ExpressionConstant::Ambiguous),
+            21 => Some(ExpressionConstant::Privacy),
+            22 => Some(ExpressionConstant::Allow),
+            23 => Some(ExpressionConstant::Reject),
+            24 => Some(ExpressionConstant::ReadOnly),
+            25 => Some(ExpressionConstant::Minimal),
             _ => None,
         }
     }
 
-    const COUNT: usize = 19;
+    const COUNT: usize = 26;
 }
 
 impl serde::Serialize for ExpressionConstant {
@@ -3568,6 +3810,8 @@ impl EnumImpl for ExpressionVariable {
             b"cc.domain" => ExpressionVariable::CcDomain,
             b"cc.local" => ExpressionVariable::CcLocal,
             b"cc.name" => ExpressionVariable::CcName,
+            b"client.name" => ExpressionVariable::ClientName,
+            b"client.version" => ExpressionVariable::ClientVersion,
             b"country" => ExpressionVariable::Country,
             b"domain" => ExpressionVariable::Domain,
             b"email" => ExpressionVariable::Email,
@@ -3635,6 +3879,8 @@ impl EnumImpl for ExpressionVariable {
             b"subject" => ExpressionVariable::Subject,
             b"subject.thread" => ExpressionVariable::SubjectThread,
             b"subject.words" => ExpressionVariable::SubjectWords,
+            b"tls_client.san" => ExpressionVariable::TlsClientSan,
+            b"tls_client.subject" => ExpressionVariable::TlsClientSubject,
             b"to" => ExpressionVariable::To,
             b"to.domain" => ExpressionVariable::ToDomain,
             b"to.local" => ExpressionVariable::ToLocal,
@@ -3665,6 +3911,8 @@ impl EnumImpl for ExpressionVariable {
             ExpressionVariable::CcDomain => "cc.domain",
             ExpressionVariable::CcLocal => "cc.local",
             ExpressionVariable::CcName => "cc.name",
+            ExpressionVariable::ClientName => "client.name",
+            ExpressionVariable::ClientVersion => "client.version",
             ExpressionVariable::Country => "country",
             ExpressionVariable::Domain => "domain",
             ExpressionVariable::Email => "email",
@@ -3732,6 +3980,8 @@ impl EnumImpl for ExpressionVariable {
             ExpressionVariable::Subject => "subject",
             ExpressionVariable::SubjectThread => "subject.thread",
             ExpressionVariable::SubjectWords => "subject.words",
+            ExpressionVariable::TlsClientSan => "tls_client.san",
+            ExpressionVariable::TlsClientSubject => "tls_client.subject",
             ExpressionVariable::To => "to",
             ExpressionVariable::ToDomain => "to.domain",
             ExpressionVariable::ToLocal => "to.local",
@@ -3765,6 +4015,8 @@ impl EnumImpl for ExpressionVariable {
             14 => Some(ExpressionVariable::CcDomain),
             15 => Some(ExpressionVariable::CcLocal),
             16 => Some(ExpressionVariable::CcName),
+            91 => Some(ExpressionVariable::ClientName),
+            92 => Some(ExpressionVariable::ClientVersion),
             17 => Some(ExpressionVariable::Country),
             18 => Some(ExpressionVariable::Domain),
             19 => Some(ExpressionVariable::Email),
@@ -3832,6 +4084,8 @@ impl EnumImpl for ExpressionVariable {
             81 => Some(ExpressionVariable::Subject),
             82 => Some(ExpressionVariable::SubjectThread),
             83 => Some(ExpressionVariable::SubjectWords),
+            93 => Some(ExpressionVariable::TlsClientSan),
+            94 => Some(ExpressionVariable::TlsClientSubject),
             84 => Some(ExpressionVariable::To),
             85 => Some(ExpressionVariable::ToDomain),
             86 => Some(ExpressionVariable::ToLocal),
@@ -3843,7 +4097,7 @@ impl EnumImpl for ExpressionVariable {
         }
     }
 
-    const COUNT: usize = 91;
+    const COUNT: usize = 95;
 }
 
 impl serde::Serialize for ExpressionVariable {
@@ -3921,6 +4175,59 @@ impl<'de> serde::Deserialize<'de> for FailureReportingOption {
     }
 }
 
+impl EnumImpl for FoundationDbPartitionMode {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"disabled" => FoundationDbPartitionMode::Disabled,
+            b"account" => FoundationDbPartitionMode::Account,
+            b"tenant" => FoundationDbPartitionMode::Tenant,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            FoundationDbPartitionMode::Disabled => "disabled",
+            FoundationDbPartitionMode::Account => "account",
+            FoundationDbPartitionMode::Tenant => "tenant",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(FoundationDbPartitionMode::Disabled),
+            1 => Some(FoundationDbPartitionMode::Account),
+            2 => Some(FoundationDbPartitionMode::Tenant),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for FoundationDbPartitionMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for FoundationDbPartitionMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for HttpAuthType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -4030,6 +4337,7 @@ impl EnumImpl for InMemoryStoreBaseType {
             value.as_bytes(),
             b"Redis" => InMemoryStoreBaseType::Redis,
             b"RedisCluster" => InMemoryStoreBaseType::RedisCluster,
+            b"RedisSentinel" => InMemoryStoreBaseType::RedisSentinel,
         }
     }
 
@@ -4037,6 +4345,7 @@ impl EnumImpl for InMemoryStoreBaseType {
         match self {
             InMemoryStoreBaseType::Redis => "Redis",
             InMemoryStoreBaseType::RedisCluster => "RedisCluster",
+            InMemoryStoreBaseType::RedisSentinel => "RedisSentinel",
         }
     }
 
@@ -4048,11 +4357,12 @@ impl EnumImpl for InMemoryStoreBaseType {
         match id {
             0 => Some(InMemoryStoreBaseType::Redis),
             1 => Some(InMemoryStoreBaseType::RedisCluster),
+            2 => Some(InMemoryStoreBaseType::RedisSentinel),
             _ => None,
         }
     }
 
-    const COUNT: usize = 2;
+    const COUNT: usize = 3;
 }
 
 impl serde::Serialize for InMemoryStoreBaseType {
@@ -4082,6 +4392,7 @@ impl EnumImpl for InMemoryStoreType {
             b"Sharded" => InMemoryStoreType::Sharded,
             b"Redis" => InMemoryStoreType::Redis,
             b"RedisCluster" => InMemoryStoreType::RedisCluster,
+            b"RedisSentinel" => InMemoryStoreType::RedisSentinel,
         }
     }
 
@@ -4091,6 +4402,7 @@ impl EnumImpl for InMemoryStoreType {
             InMemoryStoreType::Sharded => "Sharded",
             InMemoryStoreType::Redis => "Redis",
             InMemoryStoreType::RedisCluster => "RedisCluster",
+            InMemoryStoreType::RedisSentinel => "RedisSentinel",
         }
     }
 
@@ -4104,11 +4416,12 @@ impl EnumImpl for InMemoryStoreType {
             1 => Some(InMemoryStoreType::Sharded),
             2 => Some(InMemoryStoreType::Redis),
             3 => Some(InMemoryStoreType::RedisCluster),
+            4 => Some(InMemoryStoreType::RedisSentinel),
             _ => None,
         }
     }
 
-    const COUNT: usize = 4;
+    const COUNT: usize = 5;
 }
 
 impl serde::Serialize for InMemoryStoreType {
@@ -5483,6 +5796,7 @@ impl EnumImpl for LookupStoreType {
             b"Sharded" => LookupStoreType::Sharded,
             b"Redis" => LookupStoreType::Redis,
             b"RedisCluster" => LookupStoreType::RedisCluster,
+            b"RedisSentinel" => LookupStoreType::RedisSentinel,
         }
     }
 
@@ -5494,6 +5808,7 @@ impl EnumImpl for LookupStoreType {
             LookupStoreType::Sharded => "Sharded",
             LookupStoreType::Redis => "Redis",
             LookupStoreType::RedisCluster => "RedisCluster",
+            LookupStoreType::RedisSentinel => "RedisSentinel",
         }
     }
 
@@ -5509,11 +5824,12 @@ impl EnumImpl for LookupStoreType {
             3 => Some(LookupStoreType::Sharded),
             4 => Some(LookupStoreType::Redis),
             5 => Some(LookupStoreType::RedisCluster),
+            6 => Some(LookupStoreType::RedisSentinel),
             _ => None,
         }
     }
 
-    const COUNT: usize = 6;
+    const COUNT: usize = 7;
 }
 
 impl serde::Serialize for LookupStoreType {
@@ -5535,27 +5851,21 @@ impl<'de> serde::Deserialize<'de> for LookupStoreType {
     }
 }
 
-impl EnumImpl for MessageFlag {
+impl EnumImpl for MailingListReplyTo {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
             value.as_bytes(),
-            b"authenticated" => MessageFlag::Authenticated,
-            b"unauthenticated" => MessageFlag::Unauthenticated,
-            b"unauthenticatedDmarc" => MessageFlag::UnauthenticatedDmarc,
-            b"dsn" => MessageFlag::Dsn,
-            b"report" => MessageFlag::Report,
-            b"autogenerated" => MessageFlag::Autogenerated,
+            b"unchanged" => MailingListReplyTo::Unchanged,
+            b"list" => MailingListReplyTo::List,
+            b"sender" => MailingListReplyTo::Sender,
         }
     }
 
     fn as_str(&self) -> &'static str {
         match self {
-            MessageFlag::Authenticated => "authenticated",
-            MessageFlag::Unauthenticated => "unauthenticated",
-            MessageFlag::UnauthenticatedDmarc => "unauthenticatedDmarc",
-            MessageFlag::Dsn => "dsn",
-            MessageFlag::Report => "report",
-            MessageFlag::Autogenerated => "autogenerated",
+            MailingListReplyTo::Unchanged => "unchanged",
+            MailingListReplyTo::List => "list",
+            MailingListReplyTo::Sender => "sender",
         }
     }
 
@@ -5565,20 +5875,17 @@ impl EnumImpl for MessageFlag {
 
     fn from_id(id: u16) -> Option<Self> {
         match id {
-            0 => Some(MessageFlag::Authenticated),
-            1 => Some(MessageFlag::Unauthenticated),
-            2 => Some(MessageFlag::UnauthenticatedDmarc),
-            3 => Some(MessageFlag::Dsn),
-            4 => Some(MessageFlag::Report),
-            5 => Some(MessageFlag::Autogenerated),
+            0 => Some(MailingListReplyTo::Unchanged),
+            1 => Some(MailingListReplyTo::List),
+            2 => Some(MailingListReplyTo::Sender),
             _ => None,
         }
     }
 
-    const COUNT: usize = 6;
+    const COUNT: usize = 3;
 }
 
-impl serde::Serialize for MessageFlag {
+impl serde::Serialize for MailingListReplyTo {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -5587,7 +5894,7 @@ impl serde::Serialize for MessageFlag {
     }
 }
 
-impl<'de> serde::Deserialize<'de> for MessageFlag {
+impl<'de> serde::Deserialize<'de> for MailingListReplyTo {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -5597,21 +5904,21 @@ impl<'de> serde::Deserialize<'de> for MessageFlag {
     }
 }
 
-impl EnumImpl for MetricType {
+impl EnumImpl for MailingListSenderRewrite {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
             value.as_bytes(),
-            b"Counter" => MetricType::Counter,
-            b"Gauge" => MetricType::Gauge,
-            b"Histogram" => MetricType::Histogram,
+            b"disabled" => MailingListSenderRewrite::Disabled,
+            b"envelope" => MailingListSenderRewrite::Envelope,
+            b"envelopeAndFrom" => MailingListSenderRewrite::EnvelopeAndFrom,
         }
     }
 
     fn as_str(&self) -> &'static str {
         match self {
-            MetricType::Counter => "Counter",
-            MetricType::Gauge => "Gauge",
-            MetricType::Histogram => "Histogram",
+            MailingListSenderRewrite::Disabled => "disabled",
+            MailingListSenderRewrite::Envelope => "envelope",
+            MailingListSenderRewrite::EnvelopeAndFrom => "envelopeAndFrom",
         }
     }
 
@@ -5621,9 +5928,9 @@ impl EnumImpl for MetricType {
 
     fn from_id(id: u16) -> Option<Self> {
         match id {
-            0 => Some(MetricType::Counter),
-            1 => Some(MetricType::Gauge),
-            2 => Some(MetricType::Histogram),
+            0 => Some(MailingListSenderRewrite::Disabled),
+            1 => Some(MailingListSenderRewrite::Envelope),
+            2 => Some(MailingListSenderRewrite::EnvelopeAndFrom),
             _ => None,
         }
     }
@@ -5631,7 +5938,7 @@ impl EnumImpl for MetricType {
     const COUNT: usize = 3;
 }
 
-impl serde::Serialize for MetricType {
+impl serde::Serialize for MailingListSenderRewrite {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
@@ -5640,7 +5947,7 @@ impl serde::Serialize for MetricType {
     }
 }
 
-impl<'de> serde::Deserialize<'de> for MetricType {
+impl<'de> serde::Deserialize<'de> for MailingListSenderRewrite {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
         D: serde::Deserializer<'de>,
@@ -5650,11 +5957,188 @@ impl<'de> serde::Deserialize<'de> for MetricType {
     }
 }
 
-impl EnumImpl for MetricsOtelType {
+impl EnumImpl for MessageAuditEvent {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
             value.as_bytes(),
-            b"Disabled" => MetricsOtelType::Disabled,
+            b"received" => MessageAuditEvent::Received,
+            b"read" => MessageAuditEvent::Read,
+            b"moved" => MessageAuditEvent::Moved,
+            b"deleted" => MessageAuditEvent::Deleted,
+            b"expunged" => MessageAuditEvent::Expunged,
+            b"exported" => MessageAuditEvent::Exported,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MessageAuditEvent::Received => "received",
+            MessageAuditEvent::Read => "read",
+            MessageAuditEvent::Moved => "moved",
+            MessageAuditEvent::Deleted => "deleted",
+            MessageAuditEvent::Expunged => "expunged",
+            MessageAuditEvent::Exported => "exported",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MessageAuditEvent::Received),
+            1 => Some(MessageAuditEvent::Read),
+            2 => Some(MessageAuditEvent::Moved),
+            3 => Some(MessageAuditEvent::Deleted),
+            4 => Some(MessageAuditEvent::Expunged),
+            5 => Some(MessageAuditEvent::Exported),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 6;
+}
+
+impl serde::Serialize for MessageAuditEvent {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MessageAuditEvent {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
+impl EnumImpl for MessageFlag {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"authenticated" => MessageFlag::Authenticated,
+            b"unauthenticated" => MessageFlag::Unauthenticated,
+            b"unauthenticatedDmarc" => MessageFlag::UnauthenticatedDmarc,
+            b"dsn" => MessageFlag::Dsn,
+            b"report" => MessageFlag::Report,
+            b"autogenerated" => MessageFlag::Autogenerated,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MessageFlag::Authenticated => "authenticated",
+            MessageFlag::Unauthenticated => "unauthenticated",
+            MessageFlag::UnauthenticatedDmarc => "unauthenticatedDmarc",
+            MessageFlag::Dsn => "dsn",
+            MessageFlag::Report => "report",
+            MessageFlag::Autogenerated => "autogenerated",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MessageFlag::Authenticated),
+            1 => Some(MessageFlag::Unauthenticated),
+            2 => Some(MessageFlag::UnauthenticatedDmarc),
+            3 => Some(MessageFlag::Dsn),
+            4 => Some(MessageFlag::Report),
+            5 => Some(MessageFlag::Autogenerated),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 6;
+}
+
+impl serde::Serialize for MessageFlag {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MessageFlag {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
+impl EnumImpl for MetricType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"Counter" => MetricType::Counter,
+            b"Gauge" => MetricType::Gauge,
+            b"Histogram" => MetricType::Histogram,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MetricType::Counter => "Counter",
+            MetricType::Gauge => "Gauge",
+            MetricType::Histogram => "Histogram",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MetricType::Counter),
+            1 => Some(MetricType::Gauge),
+            2 => Some(MetricType::Histogram),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for MetricType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MetricType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
+impl EnumImpl for MetricsOtelType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"Disabled" => MetricsOtelType::Disabled,
             b"Http" => MetricsOtelType::Http,
             b"Grpc" => MetricsOtelType::Grpc,
         }
@@ -5995,6 +6479,56 @@ impl<'de> serde::Deserialize<'de> for MtaDeliveryExpirationType {
     }
 }
 
+impl EnumImpl for MtaDeliveryMode {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"standard" => MtaDeliveryMode::Standard,
+            b"satellite" => MtaDeliveryMode::Satellite,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MtaDeliveryMode::Standard => "standard",
+            MtaDeliveryMode::Satellite => "satellite",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MtaDeliveryMode::Standard),
+            1 => Some(MtaDeliveryMode::Satellite),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for MtaDeliveryMode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MtaDeliveryMode {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for MtaDeliveryScheduleIntervalsOrDefaultType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -6284,6 +6818,56 @@ impl<'de> serde::Deserialize<'de> for MtaProtocol {
     }
 }
 
+impl EnumImpl for MtaProxyProtocol {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"socks5" => MtaProxyProtocol::Socks5,
+            b"http" => MtaProxyProtocol::Http,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MtaProxyProtocol::Socks5 => "socks5",
+            MtaProxyProtocol::Http => "http",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MtaProxyProtocol::Socks5),
+            1 => Some(MtaProxyProtocol::Http),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for MtaProxyProtocol {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MtaProxyProtocol {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for MtaQueueQuotaKey {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -6400,6 +6984,7 @@ impl EnumImpl for MtaRouteType {
             b"Mx" => MtaRouteType::Mx,
             b"Relay" => MtaRouteType::Relay,
             b"Local" => MtaRouteType::Local,
+            b"LmtpExternal" => MtaRouteType::LmtpExternal,
         }
     }
 
@@ -6408,6 +6993,7 @@ impl EnumImpl for MtaRouteType {
             MtaRouteType::Mx => "Mx",
             MtaRouteType::Relay => "Relay",
             MtaRouteType::Local => "Local",
+            MtaRouteType::LmtpExternal => "LmtpExternal",
         }
     }
 
@@ -6420,11 +7006,12 @@ impl EnumImpl for MtaRouteType {
             0 => Some(MtaRouteType::Mx),
             1 => Some(MtaRouteType::Relay),
             2 => Some(MtaRouteType::Local),
+            3 => Some(MtaRouteType::LmtpExternal),
             _ => None,
         }
     }
 
-    const COUNT: usize = 3;
+    const COUNT: usize = 4;
 }
 
 impl serde::Serialize for MtaRouteType {
@@ -6446,6 +7033,59 @@ impl<'de> serde::Deserialize<'de> for MtaRouteType {
     }
 }
 
+impl EnumImpl for MtaSenderMismatchAction {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"reject" => MtaSenderMismatchAction::Reject,
+            b"rewrite" => MtaSenderMismatchAction::Rewrite,
+            b"tag" => MtaSenderMismatchAction::Tag,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MtaSenderMismatchAction::Reject => "reject",
+            MtaSenderMismatchAction::Rewrite => "rewrite",
+            MtaSenderMismatchAction::Tag => "tag",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MtaSenderMismatchAction::Reject),
+            1 => Some(MtaSenderMismatchAction::Rewrite),
+            2 => Some(MtaSenderMismatchAction::Tag),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for MtaSenderMismatchAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MtaSenderMismatchAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for MtaStage {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -6508,6 +7148,59 @@ impl<'de> serde::Deserialize<'de> for MtaStage {
     }
 }
 
+impl EnumImpl for MtaStructureLimitAction {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"reject" => MtaStructureLimitAction::Reject,
+            b"truncate" => MtaStructureLimitAction::Truncate,
+            b"tag" => MtaStructureLimitAction::Tag,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            MtaStructureLimitAction::Reject => "reject",
+            MtaStructureLimitAction::Truncate => "truncate",
+            MtaStructureLimitAction::Tag => "tag",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(MtaStructureLimitAction::Reject),
+            1 => Some(MtaStructureLimitAction::Truncate),
+            2 => Some(MtaStructureLimitAction::Tag),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for MtaStructureLimitAction {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for MtaStructureLimitAction {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for NetworkListenerProtocol {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -6570,6 +7263,59 @@ impl<'de> serde::Deserialize<'de> for NetworkListenerProtocol {
     }
 }
 
+impl EnumImpl for OAuthGrantType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"authorizationCode" => OAuthGrantType::AuthorizationCode,
+            b"deviceCode" => OAuthGrantType::DeviceCode,
+            b"refreshToken" => OAuthGrantType::RefreshToken,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            OAuthGrantType::AuthorizationCode => "authorizationCode",
+            OAuthGrantType::DeviceCode => "deviceCode",
+            OAuthGrantType::RefreshToken => "refreshToken",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(OAuthGrantType::AuthorizationCode),
+            1 => Some(OAuthGrantType::DeviceCode),
+            2 => Some(OAuthGrantType::RefreshToken),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for OAuthGrantType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for OAuthGrantType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for OvhEndpoint {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -6910,6 +7656,8 @@ impl EnumImpl for Permission {
             b"imapStore" => Permission::ImapStore,
             b"imapSubscribe" => Permission::ImapSubscribe,
             b"imapThread" => Permission::ImapThread,
+            b"imapGetMetadata" => Permission::ImapGetMetadata,
+            b"imapSetMetadata" => Permission::ImapSetMetadata,
             b"pop3Authenticate" => Permission::Pop3Authenticate,
             b"pop3List" => Permission::Pop3List,
             b"pop3Uidl" => Permission::Pop3Uidl,
@@ -6971,7 +7719,28 @@ impl EnumImpl for Permission {
             b"oAuthClientOverride" => Permission::OAuthClientOverride,
             b"liveTracing" => Permission::LiveTracing,
             b"liveMetrics" => Permission::LiveMetrics,
+            b"tracingRuleList" => Permission::TracingRuleList,
+            b"tracingRuleManage" => Permission::TracingRuleManage,
+            b"sieveForwardingGet" => Permission::SieveForwardingGet,
+            b"sieveForwardingUpdate" => Permission::SieveForwardingUpdate,
+            b"sieveStatsGet" => Permission::SieveStatsGet,
             b"liveDeliveryTest" => Permission::LiveDeliveryTest,
+            b"expressionEvaluate" => Permission::ExpressionEvaluate,
+            b"mailFlowDryRun" => Permission::MailFlowDryRun,
+            b"reindexStatus" => Permission::ReindexStatus,
+            b"taskList" => Permission::TaskList,
+            b"dataStoreStats" => Permission::DataStoreStats,
+            b"dataStoreCheck" => Permission::DataStoreCheck,
+            b"dataStoreRepair" => Permission::DataStoreRepair,
+            b"maintenanceWindowStatus" => Permission::MaintenanceWindowStatus,
+            b"maintenanceWindowTrigger" => Permission::MaintenanceWindowTrigger,
+            b"sessionList" => Permission::SessionList,
+            b"sessionTerminate" => Permission::SessionTerminate,
+            b"accountDataExport" => Permission::AccountDataExport,
+            b"accountDataPurge" => Permission::AccountDataPurge,
+            b"accountAliasManage" => Permission::AccountAliasManage,
+            b"domainCatchAllManage" => Permission::DomainCatchAllManage,
+            b"mailStoreImport" => Permission::MailStoreImport,
             b"sysAccountGet" => Permission::SysAccountGet,
             b"sysAccountCreate" => Permission::SysAccountCreate,
             b"sysAccountUpdate" => Permission::SysAccountUpdate,
@@ -7342,6 +8111,11 @@ impl EnumImpl for Permission {
             b"sysSpamTrainingSampleUpdate" => Permission::SysSpamTrainingSampleUpdate,
             b"sysSpamTrainingSampleDestroy" => Permission::SysSpamTrainingSampleDestroy,
             b"sysSpamTrainingSampleQuery" => Permission::SysSpamTrainingSampleQuery,
+            b"sysQuarantinedMessageGet" => Permission::SysQuarantinedMessageGet,
+            b"sysQuarantinedMessageCreate" => Permission::SysQuarantinedMessageCreate,
+            b"sysQuarantinedMessageUpdate" => Permission::SysQuarantinedMessageUpdate,
+            b"sysQuarantinedMessageDestroy" => Permission::SysQuarantinedMessageDestroy,
+            b"sysQuarantinedMessageQuery" => Permission::SysQuarantinedMessageQuery,
             b"sysSpfReportSettingsGet" => Permission::SysSpfReportSettingsGet,
             b"sysSpfReportSettingsUpdate" => Permission::SysSpfReportSettingsUpdate,
             b"sysStoreLookupGet" => Permission::SysStoreLookupGet,
@@ -7369,6 +8143,9 @@ impl EnumImpl for Permission {
             b"taskAcmeRenewal" => Permission::TaskAcmeRenewal,
             b"taskDkimManagement" => Permission::TaskDkimManagement,
             b"taskDnsManagement" => Permission::TaskDnsManagement,
+            b"taskAccountExport" => Permission::TaskAccountExport,
+            b"taskAccountImport" => Permission::TaskAccountImport,
+            b"taskDataRegionMigration" => Permission::TaskDataRegionMigration,
             b"sysTaskGet" => Permission::SysTaskGet,
             b"sysTaskCreate" => Permission::SysTaskCreate,
             b"sysTaskUpdate" => Permission::SysTaskUpdate,
@@ -7386,6 +8163,46 @@ impl EnumImpl for Permission {
             b"sysTlsExternalReportUpdate" => Permission::SysTlsExternalReportUpdate,
             b"sysTlsExternalReportDestroy" => Permission::SysTlsExternalReportDestroy,
             b"sysTlsExternalReportQuery" => Permission::SysTlsExternalReportQuery,
+            b"sysTlsInboundReportGet" => Permission::SysTlsInboundReportGet,
+            b"sysTlsInboundReportCreate" => Permission::SysTlsInboundReportCreate,
+            b"sysTlsInboundReportUpdate" => Permission::SysTlsInboundReportUpdate,
+            b"sysTlsInboundReportDestroy" => Permission::SysTlsInboundReportDestroy,
+            b"sysTlsInboundReportQuery" => Permission::SysTlsInboundReportQuery,
+            b"sysStatisticsRollupGet" => Permission::SysStatisticsRollupGet,
+            b"sysStatisticsRollupCreate" => Permission::SysStatisticsRollupCreate,
+            b"sysStatisticsRollupUpdate" => Permission::SysStatisticsRollupUpdate,
+            b"sysStatisticsRollupDestroy" => Permission::SysStatisticsRollupDestroy,
+            b"sysStatisticsRollupQuery" => Permission::SysStatisticsRollupQuery,
+            b"sysMessageAuditEntryGet" => Permission::SysMessageAuditEntryGet,
+            b"sysMessageAuditEntryCreate" => Permission::SysMessageAuditEntryCreate,
+            b"sysMessageAuditEntryUpdate" => Permission::SysMessageAuditEntryUpdate,
+            b"sysMessageAuditEntryDestroy" => Permission::SysMessageAuditEntryDestroy,
+            b"sysMessageAuditEntryQuery" => Permission::SysMessageAuditEntryQuery,
+            b"sysBlobIntegrityIssueGet" => Permission::SysBlobIntegrityIssueGet,
+            b"sysBlobIntegrityIssueCreate" => Permission::SysBlobIntegrityIssueCreate,
+            b"sysBlobIntegrityIssueUpdate" => Permission::SysBlobIntegrityIssueUpdate,
+            b"sysBlobIntegrityIssueDestroy" => Permission::SysBlobIntegrityIssueDestroy,
+            b"sysBlobIntegrityIssueQuery" => Permission::SysBlobIntegrityIssueQuery,
+            b"sysLoginEntryGet" => Permission::SysLoginEntryGet,
+            b"sysLoginEntryCreate" => Permission::SysLoginEntryCreate,
+            b"sysLoginEntryUpdate" => Permission::SysLoginEntryUpdate,
+            b"sysLoginEntryDestroy" => Permission::SysLoginEntryDestroy,
+            b"sysLoginEntryQuery" => Permission::SysLoginEntryQuery,
+            b"sysDeliveryTraceGet" => Permission::SysDeliveryTraceGet,
+            b"sysDeliveryTraceCreate" => Permission::SysDeliveryTraceCreate,
+            b"sysDeliveryTraceUpdate" => Permission::SysDeliveryTraceUpdate,
+            b"sysDeliveryTraceDestroy" => Permission::SysDeliveryTraceDestroy,
+            b"sysDeliveryTraceQuery" => Permission::SysDeliveryTraceQuery,
+            b"sysTenantUsageGet" => Permission::SysTenantUsageGet,
+            b"sysTenantUsageCreate" => Permission::SysTenantUsageCreate,
+            b"sysTenantUsageUpdate" => Permission::SysTenantUsageUpdate,
+            b"sysTenantUsageDestroy" => Permission::SysTenantUsageDestroy,
+            b"sysTenantUsageQuery" => Permission::SysTenantUsageQuery,
+            b"sysDsnTemplateGet" => Permission::SysDsnTemplateGet,
+            b"sysDsnTemplateCreate" => Permission::SysDsnTemplateCreate,
+            b"sysDsnTemplateUpdate" => Permission::SysDsnTemplateUpdate,
+            b"sysDsnTemplateDestroy" => Permission::SysDsnTemplateDestroy,
+            b"sysDsnTemplateQuery" => Permission::SysDsnTemplateQuery,
             b"sysTlsInternalReportGet" => Permission::SysTlsInternalReportGet,
             b"sysTlsInternalReportCreate" => Permission::SysTlsInternalReportCreate,
             b"sysTlsInternalReportUpdate" => Permission::SysTlsInternalReportUpdate,
@@ -7586,6 +8403,8 @@ impl EnumImpl for Permission {
             Permission::ImapStore => "imapStore",
             Permission::ImapSubscribe => "imapSubscribe",
             Permission::ImapThread => "imapThread",
+            Permission::ImapGetMetadata => "imapGetMetadata",
+            Permission::ImapSetMetadata => "imapSetMetadata",
             Permission::Pop3Authenticate => "pop3Authenticate",
             Permission::Pop3List => "pop3List",
             Permission::Pop3Uidl => "pop3Uidl",
@@ -7647,7 +8466,28 @@ impl EnumImpl for Permission {
             Permission::OAuthClientOverride => "oAuthClientOverride",
             Permission::LiveTracing => "liveTracing",
             Permission::LiveMetrics => "liveMetrics",
+            Permission::TracingRuleList => "tracingRuleList",
+            Permission::TracingRuleManage => "tracingRuleManage",
+            Permission::SieveForwardingGet => "sieveForwardingGet",
+            Permission::SieveForwardingUpdate => "sieveForwardingUpdate",
+            Permission::SieveStatsGet => "sieveStatsGet",
             Permission::LiveDeliveryTest => "liveDeliveryTest",
+            Permission::ExpressionEvaluate => "expressionEvaluate",
+            Permission::MailFlowDryRun => "mailFlowDryRun",
+            Permission::ReindexStatus => "reindexStatus",
+            Permission::TaskList => "taskList",
+            Permission::DataStoreStats => "dataStoreStats",
+            Permission::DataStoreCheck => "dataStoreCheck",
+            Permission::DataStoreRepair => "dataStoreRepair",
+            Permission::MaintenanceWindowStatus => "maintenanceWindowStatus",
+            Permission::MaintenanceWindowTrigger => "maintenanceWindowTrigger",
+            Permission::SessionList => "sessionList",
+            Permission::SessionTerminate => "sessionTerminate",
+            Permission::AccountDataExport => "accountDataExport",
+            Permission::AccountDataPurge => "accountDataPurge",
+            Permission::AccountAliasManage => "accountAliasManage",
+            Permission::DomainCatchAllManage => "domainCatchAllManage",
+            Permission::MailStoreImport => "mailStoreImport",
             Permission::SysAccountGet => "sysAccountGet",
             Permission::SysAccountCreate => "sysAccountCreate",
             Permission::SysAccountUpdate => "sysAccountUpdate",
@@ -7724,6 +8564,11 @@ impl EnumImpl for Permission {
             Permission::SysAsnUpdate => "sysAsnUpdate",
             Permission::SysAuthenticationGet => "sysAuthenticationGet",
             Permission::SysAuthenticationUpdate => "sysAuthenticationUpdate",
+            Permission::SysBlobIntegrityIssueGet => "sysBlobIntegrityIssueGet",
+            Permission::SysBlobIntegrityIssueCreate => "sysBlobIntegrityIssueCreate",
+            Permission::SysBlobIntegrityIssueUpdate => "sysBlobIntegrityIssueUpdate",
+            Permission::SysBlobIntegrityIssueDestroy => "sysBlobIntegrityIssueDestroy",
+            Permission::SysBlobIntegrityIssueQuery => "sysBlobIntegrityIssueQuery",
             Permission::SysBlobStoreGet => "sysBlobStoreGet",
             Permission::SysBlobStoreUpdate => "sysBlobStoreUpdate",
             Permission::SysBlockedIpGet => "sysBlockedIpGet",
@@ -7762,6 +8607,11 @@ impl EnumImpl for Permission {
             Permission::SysDataRetentionUpdate => "sysDataRetentionUpdate",
             Permission::SysDataStoreGet => "sysDataStoreGet",
             Permission::SysDataStoreUpdate => "sysDataStoreUpdate",
+            Permission::SysDeliveryTraceGet => "sysDeliveryTraceGet",
+            Permission::SysDeliveryTraceCreate => "sysDeliveryTraceCreate",
+            Permission::SysDeliveryTraceUpdate => "sysDeliveryTraceUpdate",
+            Permission::SysDeliveryTraceDestroy => "sysDeliveryTraceDestroy",
+            Permission::SysDeliveryTraceQuery => "sysDeliveryTraceQuery",
             Permission::SysDirectoryGet => "sysDirectoryGet",
             Permission::SysDirectoryCreate => "sysDirectoryCreate",
             Permission::SysDirectoryUpdate => "sysDirectoryUpdate",
@@ -7800,6 +8650,11 @@ impl EnumImpl for Permission {
             Permission::SysDomainQuery => "sysDomainQuery",
             Permission::SysDsnReportSettingsGet => "sysDsnReportSettingsGet",
             Permission::SysDsnReportSettingsUpdate => "sysDsnReportSettingsUpdate",
+            Permission::SysDsnTemplateGet => "sysDsnTemplateGet",
+            Permission::SysDsnTemplateCreate => "sysDsnTemplateCreate",
+            Permission::SysDsnTemplateUpdate => "sysDsnTemplateUpdate",
+            Permission::SysDsnTemplateDestroy => "sysDsnTemplateDestroy",
+            Permission::SysDsnTemplateQuery => "sysDsnTemplateQuery",
             Permission::SysEmailGet => "sysEmailGet",
             Permission::SysEmailUpdate => "sysEmailUpdate",
             Permission::SysEnterpriseGet => "sysEnterpriseGet",
@@ -7831,6 +8686,11 @@ impl EnumImpl for Permission {
             Permission::SysLogUpdate => "sysLogUpdate",
             Permission::SysLogDestroy => "sysLogDestroy",
             Permission::SysLogQuery => "sysLogQuery",
+            Permission::SysLoginEntryGet => "sysLoginEntryGet",
+            Permission::SysLoginEntryCreate => "sysLoginEntryCreate",
+            Permission::SysLoginEntryUpdate => "sysLoginEntryUpdate",
+            Permission::SysLoginEntryDestroy => "sysLoginEntryDestroy",
+            Permission::SysLoginEntryQuery => "sysLoginEntryQuery",
             Permission::SysMailingListGet => "sysMailingListGet",
             Permission::SysMailingListCreate => "sysMailingListCreate",
             Permission::SysMailingListUpdate => "sysMailingListUpdate",
@@ -7851,6 +8711,11 @@ impl EnumImpl for Permission {
             Permission::SysMemoryLookupKeyValueUpdate => "sysMemoryLookupKeyValueUpdate",
             Permission::SysMemoryLookupKeyValueDestroy => "sysMemoryLookupKeyValueDestroy",
             Permission::SysMemoryLookupKeyValueQuery => "sysMemoryLookupKeyValueQuery",
+            Permission::SysMessageAuditEntryGet => "sysMessageAuditEntryGet",
+            Permission::SysMessageAuditEntryCreate => "sysMessageAuditEntryCreate",
+            Permission::SysMessageAuditEntryUpdate => "sysMessageAuditEntryUpdate",
+            Permission::SysMessageAuditEntryDestroy => "sysMessageAuditEntryDestroy",
+            Permission::SysMessageAuditEntryQuery => "sysMessageAuditEntryQuery",
             Permission::SysMetricGet => "sysMetricGet",
             Permission::SysMetricCreate => "sysMetricCreate",
             Permission::SysMetricUpdate => "sysMetricUpdate",
@@ -7947,6 +8812,11 @@ impl EnumImpl for Permission {
             Permission::SysPublicKeyUpdate => "sysPublicKeyUpdate",
             Permission::SysPublicKeyDestroy => "sysPublicKeyDestroy",
             Permission::SysPublicKeyQuery => "sysPublicKeyQuery",
+            Permission::SysQuarantinedMessageGet => "sysQuarantinedMessageGet",
+            Permission::SysQuarantinedMessageCreate => "sysQuarantinedMessageCreate",
+            Permission::SysQuarantinedMessageUpdate => "sysQuarantinedMessageUpdate",
+            Permission::SysQuarantinedMessageDestroy => "sysQuarantinedMessageDestroy",
+            Permission::SysQuarantinedMessageQuery => "sysQuarantinedMessageQuery",
             Permission::SysQueuedMessageGet => "sysQueuedMessageGet",
             Permission::SysQueuedMessageCreate => "sysQueuedMessageCreate",
             Permission::SysQueuedMessageUpdate => "sysQueuedMessageUpdate",
@@ -8020,6 +8890,11 @@ impl EnumImpl for Permission {
             Permission::SysSpamTrainingSampleQuery => "sysSpamTrainingSampleQuery",
             Permission::SysSpfReportSettingsGet => "sysSpfReportSettingsGet",
             Permission::SysSpfReportSettingsUpdate => "sysSpfReportSettingsUpdate",
+            Permission::SysStatisticsRollupGet => "sysStatisticsRollupGet",
+            Permission::SysStatisticsRollupCreate => "sysStatisticsRollupCreate",
+            Permission::SysStatisticsRollupUpdate => "sysStatisticsRollupUpdate",
+            Permission::SysStatisticsRollupDestroy => "sysStatisticsRollupDestroy",
+            Permission::SysStatisticsRollupQuery => "sysStatisticsRollupQuery",
             Permission::SysStoreLookupGet => "sysStoreLookupGet",
             Permission::SysStoreLookupCreate => "sysStoreLookupCreate",
             Permission::SysStoreLookupUpdate => "sysStoreLookupUpdate",
@@ -8045,6 +8920,9 @@ impl EnumImpl for Permission {
             Permission::TaskAcmeRenewal => "taskAcmeRenewal",
             Permission::TaskDkimManagement => "taskDkimManagement",
             Permission::TaskDnsManagement => "taskDnsManagement",
+            Permission::TaskAccountExport => "taskAccountExport",
+            Permission::TaskAccountImport => "taskAccountImport",
+            Permission::TaskDataRegionMigration => "taskDataRegionMigration",
             Permission::SysTaskGet => "sysTaskGet",
             Permission::SysTaskCreate => "sysTaskCreate",
             Permission::SysTaskUpdate => "sysTaskUpdate",
@@ -8057,11 +8935,21 @@ impl EnumImpl for Permission {
             Permission::SysTenantUpdate => "sysTenantUpdate",
             Permission::SysTenantDestroy => "sysTenantDestroy",
             Permission::SysTenantQuery => "sysTenantQuery",
+            Permission::SysTenantUsageGet => "sysTenantUsageGet",
+            Permission::SysTenantUsageCreate => "sysTenantUsageCreate",
+            Permission::SysTenantUsageUpdate => "sysTenantUsageUpdate",
+            Permission::SysTenantUsageDestroy => "sysTenantUsageDestroy",
+            Permission::SysTenantUsageQuery => "sysTenantUsageQuery",
             Permission::SysTlsExternalReportGet => "sysTlsExternalReportGet",
             Permission::SysTlsExternalReportCreate => "sysTlsExternalReportCreate",
             Permission::SysTlsExternalReportUpdate => "sysTlsExternalReportUpdate",
             Permission::SysTlsExternalReportDestroy => "sysTlsExternalReportDestroy",
             Permission::SysTlsExternalReportQuery => "sysTlsExternalReportQuery",
+            Permission::SysTlsInboundReportGet => "sysTlsInboundReportGet",
+            Permission::SysTlsInboundReportCreate => "sysTlsInboundReportCreate",
+            Permission::SysTlsInboundReportUpdate => "sysTlsInboundReportUpdate",
+            Permission::SysTlsInboundReportDestroy => "sysTlsInboundReportDestroy",
+            Permission::SysTlsInboundReportQuery => "sysTlsInboundReportQuery",
             Permission::SysTlsInternalReportGet => "sysTlsInternalReportGet",
             Permission::SysTlsInternalReportCreate => "sysTlsInternalReportCreate",
             Permission::SysTlsInternalReportUpdate => "sysTlsInternalReportUpdate",
@@ -8255,6 +9143,8 @@ impl EnumImpl for Permission {
             154 => Some(Permission::ImapStore),
             155 => Some(Permission::ImapSubscribe),
             156 => Some(Permission::ImapThread),
+            660 => Some(Permission::ImapGetMetadata),
+            661 => Some(Permission::ImapSetMetadata),
             157 => Some(Permission::Pop3Authenticate),
             158 => Some(Permission::Pop3List),
             159 => Some(Permission::Pop3Uidl),
@@ -8316,7 +9206,28 @@ impl EnumImpl for Permission {
             215 => Some(Permission::OAuthClientOverride),
             216 => Some(Permission::LiveTracing),
             217 => Some(Permission::LiveMetrics),
+            662 => Some(Permission::TracingRuleList),
+            663 => Some(Permission::TracingRuleManage),
+            664 => Some(Permission::SieveForwardingGet),
+            665 => Some(Permission::SieveForwardingUpdate),
+            666 => Some(Permission::SieveStatsGet),
             218 => Some(Permission::LiveDeliveryTest),
+            667 => Some(Permission::ExpressionEvaluate),
+            668 => Some(Permission::MailFlowDryRun),
+            669 => Some(Permission::ReindexStatus),
+            670 => Some(Permission::TaskList),
+            671 => Some(Permission::DataStoreStats),
+            672 => Some(Permission::DataStoreCheck),
+            673 => Some(Permission::DataStoreRepair),
+            674 => Some(Permission::MaintenanceWindowStatus),
+            675 => Some(Permission::MaintenanceWindowTrigger),
+            676 => Some(Permission::SessionList),
+            677 => Some(Permission::SessionTerminate),
+            678 => Some(Permission::AccountDataExport),
+            679 => Some(Permission::AccountDataPurge),
+            680 => Some(Permission::AccountAliasManage),
+            681 => Some(Permission::DomainCatchAllManage),
+            682 => Some(Permission::MailStoreImport),
             219 => Some(Permission::SysAccountGet),
             220 => Some(Permission::SysAccountCreate),
             221 => Some(Permission::SysAccountUpdate),
@@ -8393,6 +9304,11 @@ impl EnumImpl for Permission {
             292 => Some(Permission::SysAsnUpdate),
             293 => Some(Permission::SysAuthenticationGet),
             294 => Some(Permission::SysAuthenticationUpdate),
+            683 => Some(Permission::SysBlobIntegrityIssueGet),
+            684 => Some(Permission::SysBlobIntegrityIssueCreate),
+            685 => Some(Permission::SysBlobIntegrityIssueUpdate),
+            686 => Some(Permission::SysBlobIntegrityIssueDestroy),
+            687 => Some(Permission::SysBlobIntegrityIssueQuery),
             295 => Some(Permission::SysBlobStoreGet),
             296 => Some(Permission::SysBlobStoreUpdate),
             297 => Some(Permission::SysBlockedIpGet),
@@ -8431,6 +9347,11 @@ impl EnumImpl for Permission {
             330 => Some(Permission::SysDataRetentionUpdate),
             331 => Some(Permission::SysDataStoreGet),
             332 => Some(Permission::SysDataStoreUpdate),
+            688 => Some(Permission::SysDeliveryTraceGet),
+            689 => Some(Permission::SysDeliveryTraceCreate),
+            690 => Some(Permission::SysDeliveryTraceUpdate),
+            691 => Some(Permission::SysDeliveryTraceDestroy),
+            692 => Some(Permission::SysDeliveryTraceQuery),
             333 => Some(Permission::SysDirectoryGet),
             334 => Some(Permission::SysDirectoryCreate),
             335 => Some(Permission::SysDirectoryUpdate),
@@ -8469,6 +9390,11 @@ impl EnumImpl for Permission {
             368 => Some(Permission::SysDomainQuery),
             369 => Some(Permission::SysDsnReportSettingsGet),
             370 => Some(Permission::SysDsnReportSettingsUpdate),
+            693 => Some(Permission::SysDsnTemplateGet),
+            694 => Some(Permission::SysDsnTemplateCreate),
+            695 => Some(Permission::SysDsnTemplateUpdate),
+            696 => Some(Permission::SysDsnTemplateDestroy),
+            697 => Some(Permission::SysDsnTemplateQuery),
             371 => Some(Permission::SysEmailGet),
             372 => Some(Permission::SysEmailUpdate),
             373 => Some(Permission::SysEnterpriseGet),
@@ -8500,6 +9426,11 @@ impl EnumImpl for Permission {
             399 => Some(Permission::SysLogUpdate),
             400 => Some(Permission::SysLogDestroy),
             401 => Some(Permission::SysLogQuery),
+            698 => Some(Permission::SysLoginEntryGet),
+            699 => Some(Permission::SysLoginEntryCreate),
+            700 => Some(Permission::SysLoginEntryUpdate),
+            701 => Some(Permission::SysLoginEntryDestroy),
+            702 => Some(Permission::SysLoginEntryQuery),
             402 => Some(Permission::SysMailingListGet),
             403 => Some(Permission::SysMailingListCreate),
             404 => Some(Permission::SysMailingListUpdate),
@@ -8520,6 +9451,11 @@ impl EnumImpl for Permission {
             419 => Some(Permission::SysMemoryLookupKeyValueUpdate),
             420 => Some(Permission::SysMemoryLookupKeyValueDestroy),
             421 => Some(Permission::SysMemoryLookupKeyValueQuery),
+            703 => Some(Permission::SysMessageAuditEntryGet),
+            704 => Some(Permission::SysMessageAuditEntryCreate),
+            705 => Some(Permission::SysMessageAuditEntryUpdate),
+            706 => Some(Permission::SysMessageAuditEntryDestroy),
+            707 => Some(Permission::SysMessageAuditEntryQuery),
             422 => Some(Permission::SysMetricGet),
             423 => Some(Permission::SysMetricCreate),
             424 => Some(Permission::SysMetricUpdate),
@@ -8616,6 +9552,11 @@ impl EnumImpl for Permission {
             515 => Some(Permission::SysPublicKeyUpdate),
             516 => Some(Permission::SysPublicKeyDestroy),
             517 => Some(Permission::SysPublicKeyQuery),
+            708 => Some(Permission::SysQuarantinedMessageGet),
+            709 => Some(Permission::SysQuarantinedMessageCreate),
+            710 => Some(Permission::SysQuarantinedMessageUpdate),
+            711 => Some(Permission::SysQuarantinedMessageDestroy),
+            712 => Some(Permission::SysQuarantinedMessageQuery),
             518 => Some(Permission::SysQueuedMessageGet),
             519 => Some(Permission::SysQueuedMessageCreate),
             520 => Some(Permission::SysQueuedMessageUpdate),
@@ -8689,6 +9630,11 @@ impl EnumImpl for Permission {
             588 => Some(Permission::SysSpamTrainingSampleQuery),
             589 => Some(Permission::SysSpfReportSettingsGet),
             590 => Some(Permission::SysSpfReportSettingsUpdate),
+            713 => Some(Permission::SysStatisticsRollupGet),
+            714 => Some(Permission::SysStatisticsRollupCreate),
+            715 => Some(Permission::SysStatisticsRollupUpdate),
+            716 => Some(Permission::SysStatisticsRollupDestroy),
+            717 => Some(Permission::SysStatisticsRollupQuery),
             591 => Some(Permission::SysStoreLookupGet),
             592 => Some(Permission::SysStoreLookupCreate),
             593 => Some(Permission::SysStoreLookupUpdate),
@@ -8714,6 +9660,9 @@ impl EnumImpl for Permission {
             613 => Some(Permission::TaskAcmeRenewal),
             614 => Some(Permission::TaskDkimManagement),
             615 => Some(Permission::TaskDnsManagement),
+            718 => Some(Permission::TaskAccountExport),
+            719 => Some(Permission::TaskAccountImport),
+            720 => Some(Permission::TaskDataRegionMigration),
             616 => Some(Permission::SysTaskGet),
             617 => Some(Permission::SysTaskCreate),
             618 => Some(Permission::SysTaskUpdate),
@@ -8726,11 +9675,21 @@ impl EnumImpl for Permission {
             625 => Some(Permission::SysTenantUpdate),
             626 => Some(Permission::SysTenantDestroy),
             627 => Some(Permission::SysTenantQuery),
+            721 => Some(Permission::SysTenantUsageGet),
+            722 => Some(Permission::SysTenantUsageCreate),
+            723 => Some(Permission::SysTenantUsageUpdate),
+            724 => Some(Permission::SysTenantUsageDestroy),
+            725 => Some(Permission::SysTenantUsageQuery),
             628 => Some(Permission::SysTlsExternalReportGet),
             629 => Some(Permission::SysTlsExternalReportCreate),
             630 => Some(Permission::SysTlsExternalReportUpdate),
             631 => Some(Permission::SysTlsExternalReportDestroy),
             632 => Some(Permission::SysTlsExternalReportQuery),
+            726 => Some(Permission::SysTlsInboundReportGet),
+            727 => Some(Permission::SysTlsInboundReportCreate),
+            728 => Some(Permission::SysTlsInboundReportUpdate),
+            729 => Some(Permission::SysTlsInboundReportDestroy),
+            730 => Some(Permission::SysTlsInboundReportQuery),
             633 => Some(Permission::SysTlsInternalReportGet),
             634 => Some(Permission::SysTlsInternalReportCreate),
             635 => Some(Permission::SysTlsInternalReportUpdate),
@@ -8761,7 +9720,7 @@ impl EnumImpl for Permission {
         }
     }
 
-    const COUNT: usize = 660;
+    const COUNT: usize = 731;
 }
 
 impl serde::Serialize for Permission {
@@ -9119,6 +10078,7 @@ impl EnumImpl for RecipientFlag {
             value.as_bytes(),
             b"dsnSent" => RecipientFlag::DsnSent,
             b"spamPayload" => RecipientFlag::SpamPayload,
+            b"quarantine" => RecipientFlag::Quarantine,
         }
     }
 
@@ -9126,6 +10086,7 @@ impl EnumImpl for RecipientFlag {
         match self {
             RecipientFlag::DsnSent => "dsnSent",
             RecipientFlag::SpamPayload => "spamPayload",
+            RecipientFlag::Quarantine => "quarantine",
         }
     }
 
@@ -9137,11 +10098,12 @@ impl EnumImpl for RecipientFlag {
         match id {
             0 => Some(RecipientFlag::DsnSent),
             1 => Some(RecipientFlag::SpamPayload),
+            2 => Some(RecipientFlag::Quarantine),
             _ => None,
         }
     }
 
-    const COUNT: usize = 2;
+    const COUNT: usize = 3;
 }
 
 impl serde::Serialize for RecipientFlag {
@@ -9764,6 +10726,62 @@ impl<'de> serde::Deserialize<'de> for SearchFileField {
     }
 }
 
+impl EnumImpl for SearchFolderRole {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"none" => SearchFolderRole::None,
+            b"all" => SearchFolderRole::All,
+            b"flagged" => SearchFolderRole::Flagged,
+            b"important" => SearchFolderRole::Important,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SearchFolderRole::None => "none",
+            SearchFolderRole::All => "all",
+            SearchFolderRole::Flagged => "flagged",
+            SearchFolderRole::Important => "important",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(SearchFolderRole::None),
+            1 => Some(SearchFolderRole::All),
+            2 => Some(SearchFolderRole::Flagged),
+            3 => Some(SearchFolderRole::Important),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 4;
+}
+
+impl serde::Serialize for SearchFolderRole {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SearchFolderRole {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for SearchStoreType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -9879,6 +10897,62 @@ impl<'de> serde::Deserialize<'de> for SearchTracingField {
     }
 }
 
+impl EnumImpl for SecretKeyLocalOptionalType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"None" => SecretKeyLocalOptionalType::None,
+            b"Value" => SecretKeyLocalOptionalType::Value,
+            b"EnvironmentVariable" => SecretKeyLocalOptionalType::EnvironmentVariable,
+            b"File" => SecretKeyLocalOptionalType::File,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SecretKeyLocalOptionalType::None => "None",
+            SecretKeyLocalOptionalType::Value => "Value",
+            SecretKeyLocalOptionalType::EnvironmentVariable => "EnvironmentVariable",
+            SecretKeyLocalOptionalType::File => "File",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(SecretKeyLocalOptionalType::None),
+            1 => Some(SecretKeyLocalOptionalType::Value),
+            2 => Some(SecretKeyLocalOptionalType::EnvironmentVariable),
+            3 => Some(SecretKeyLocalOptionalType::File),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 4;
+}
+
+impl serde::Serialize for SecretKeyLocalOptionalType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SecretKeyLocalOptionalType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for SecretKeyOptionalType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -9887,6 +10961,9 @@ impl EnumImpl for SecretKeyOptionalType {
             b"Value" => SecretKeyOptionalType::Value,
             b"EnvironmentVariable" => SecretKeyOptionalType::EnvironmentVariable,
             b"File" => SecretKeyOptionalType::File,
+            b"Vault" => SecretKeyOptionalType::Vault,
+            b"AwsSecretsManager" => SecretKeyOptionalType::AwsSecretsManager,
+            b"EnvironmentJson" => SecretKeyOptionalType::EnvironmentJson,
         }
     }
 
@@ -9896,6 +10973,9 @@ impl EnumImpl for SecretKeyOptionalType {
             SecretKeyOptionalType::Value => "Value",
             SecretKeyOptionalType::EnvironmentVariable => "EnvironmentVariable",
             SecretKeyOptionalType::File => "File",
+            SecretKeyOptionalType::Vault => "Vault",
+            SecretKeyOptionalType::AwsSecretsManager => "AwsSecretsManager",
+            SecretKeyOptionalType::EnvironmentJson => "EnvironmentJson",
         }
     }
 
@@ -9909,11 +10989,14 @@ impl EnumImpl for SecretKeyOptionalType {
             1 => Some(SecretKeyOptionalType::Value),
             2 => Some(SecretKeyOptionalType::EnvironmentVariable),
             3 => Some(SecretKeyOptionalType::File),
+            4 => Some(SecretKeyOptionalType::Vault),
+            5 => Some(SecretKeyOptionalType::AwsSecretsManager),
+            6 => Some(SecretKeyOptionalType::EnvironmentJson),
             _ => None,
         }
     }
 
-    const COUNT: usize = 4;
+    const COUNT: usize = 7;
 }
 
 impl serde::Serialize for SecretKeyOptionalType {
@@ -9942,6 +11025,9 @@ impl EnumImpl for SecretKeyType {
             b"Value" => SecretKeyType::Value,
             b"EnvironmentVariable" => SecretKeyType::EnvironmentVariable,
             b"File" => SecretKeyType::File,
+            b"Vault" => SecretKeyType::Vault,
+            b"AwsSecretsManager" => SecretKeyType::AwsSecretsManager,
+            b"EnvironmentJson" => SecretKeyType::EnvironmentJson,
         }
     }
 
@@ -9950,6 +11036,9 @@ impl EnumImpl for SecretKeyType {
             SecretKeyType::Value => "Value",
             SecretKeyType::EnvironmentVariable => "EnvironmentVariable",
             SecretKeyType::File => "File",
+            SecretKeyType::Vault => "Vault",
+            SecretKeyType::AwsSecretsManager => "AwsSecretsManager",
+            SecretKeyType::EnvironmentJson => "EnvironmentJson",
         }
     }
 
@@ -9962,11 +11051,14 @@ impl EnumImpl for SecretKeyType {
             0 => Some(SecretKeyType::Value),
             1 => Some(SecretKeyType::EnvironmentVariable),
             2 => Some(SecretKeyType::File),
+            3 => Some(SecretKeyType::Vault),
+            4 => Some(SecretKeyType::AwsSecretsManager),
+            5 => Some(SecretKeyType::EnvironmentJson),
             _ => None,
         }
     }
 
-    const COUNT: usize = 3;
+    const COUNT: usize = 6;
 }
 
 impl serde::Serialize for SecretKeyType {
@@ -9996,6 +11088,9 @@ impl EnumImpl for SecretTextOptionalType {
             b"Text" => SecretTextOptionalType::Text,
             b"EnvironmentVariable" => SecretTextOptionalType::EnvironmentVariable,
             b"File" => SecretTextOptionalType::File,
+            b"Vault" => SecretTextOptionalType::Vault,
+            b"AwsSecretsManager" => SecretTextOptionalType::AwsSecretsManager,
+            b"EnvironmentJson" => SecretTextOptionalType::EnvironmentJson,
         }
     }
 
@@ -10005,6 +11100,9 @@ impl EnumImpl for SecretTextOptionalType {
             SecretTextOptionalType::Text => "Text",
             SecretTextOptionalType::EnvironmentVariable => "EnvironmentVariable",
             SecretTextOptionalType::File => "File",
+            SecretTextOptionalType::Vault => "Vault",
+            SecretTextOptionalType::AwsSecretsManager => "AwsSecretsManager",
+            SecretTextOptionalType::EnvironmentJson => "EnvironmentJson",
         }
     }
 
@@ -10018,11 +11116,14 @@ impl EnumImpl for SecretTextOptionalType {
             1 => Some(SecretTextOptionalType::Text),
             2 => Some(SecretTextOptionalType::EnvironmentVariable),
             3 => Some(SecretTextOptionalType::File),
+            4 => Some(SecretTextOptionalType::Vault),
+            5 => Some(SecretTextOptionalType::AwsSecretsManager),
+            6 => Some(SecretTextOptionalType::EnvironmentJson),
             _ => None,
         }
     }
 
-    const COUNT: usize = 4;
+    const COUNT: usize = 7;
 }
 
 impl serde::Serialize for SecretTextOptionalType {
@@ -10051,6 +11152,9 @@ impl EnumImpl for SecretTextType {
             b"Text" => SecretTextType::Text,
             b"EnvironmentVariable" => SecretTextType::EnvironmentVariable,
             b"File" => SecretTextType::File,
+            b"Vault" => SecretTextType::Vault,
+            b"AwsSecretsManager" => SecretTextType::AwsSecretsManager,
+            b"EnvironmentJson" => SecretTextType::EnvironmentJson,
         }
     }
 
@@ -10059,6 +11163,9 @@ impl EnumImpl for SecretTextType {
             SecretTextType::Text => "Text",
             SecretTextType::EnvironmentVariable => "EnvironmentVariable",
             SecretTextType::File => "File",
+            SecretTextType::Vault => "Vault",
+            SecretTextType::AwsSecretsManager => "AwsSecretsManager",
+            SecretTextType::EnvironmentJson => "EnvironmentJson",
         }
     }
 
@@ -10071,11 +11178,14 @@ impl EnumImpl for SecretTextType {
             0 => Some(SecretTextType::Text),
             1 => Some(SecretTextType::EnvironmentVariable),
             2 => Some(SecretTextType::File),
+            3 => Some(SecretTextType::Vault),
+            4 => Some(SecretTextType::AwsSecretsManager),
+            5 => Some(SecretTextType::EnvironmentJson),
             _ => None,
         }
     }
 
-    const COUNT: usize = 3;
+    const COUNT: usize = 6;
 }
 
 impl serde::Serialize for SecretTextType {
@@ -10165,6 +11275,56 @@ impl<'de> serde::Deserialize<'de> for ServiceProtocol {
     }
 }
 
+impl EnumImpl for SharedMailboxDestroy {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"reassign" => SharedMailboxDestroy::Reassign,
+            b"deny" => SharedMailboxDestroy::Deny,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            SharedMailboxDestroy::Reassign => "reassign",
+            SharedMailboxDestroy::Deny => "deny",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(SharedMailboxDestroy::Reassign),
+            1 => Some(SharedMailboxDestroy::Deny),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for SharedMailboxDestroy {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for SharedMailboxDestroy {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for SieveCapability {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -11113,6 +12273,56 @@ impl<'de> serde::Deserialize<'de> for SqlAuthStoreType {
     }
 }
 
+impl EnumImpl for StatisticsPeriod {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"hourly" => StatisticsPeriod::Hourly,
+            b"daily" => StatisticsPeriod::Daily,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            StatisticsPeriod::Hourly => "hourly",
+            StatisticsPeriod::Daily => "daily",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(StatisticsPeriod::Hourly),
+            1 => Some(StatisticsPeriod::Daily),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 2;
+}
+
+impl serde::Serialize for StatisticsPeriod {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for StatisticsPeriod {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for StorageQuota {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -11275,6 +12485,8 @@ impl EnumImpl for TaskAccountMaintenanceType {
             b"reindex" => TaskAccountMaintenanceType::Reindex,
             b"recalculateImapUid" => TaskAccountMaintenanceType::RecalculateImapUid,
             b"recalculateQuota" => TaskAccountMaintenanceType::RecalculateQuota,
+            b"tierBlobs" => TaskAccountMaintenanceType::TierBlobs,
+            b"restoreBlobs" => TaskAccountMaintenanceType::RestoreBlobs,
         }
     }
 
@@ -11284,6 +12496,8 @@ impl EnumImpl for TaskAccountMaintenanceType {
             TaskAccountMaintenanceType::Reindex => "reindex",
             TaskAccountMaintenanceType::RecalculateImapUid => "recalculateImapUid",
             TaskAccountMaintenanceType::RecalculateQuota => "recalculateQuota",
+            TaskAccountMaintenanceType::TierBlobs => "tierBlobs",
+            TaskAccountMaintenanceType::RestoreBlobs => "restoreBlobs",
         }
     }
 
@@ -11297,11 +12511,13 @@ impl EnumImpl for TaskAccountMaintenanceType {
             1 => Some(TaskAccountMaintenanceType::Reindex),
             2 => Some(TaskAccountMaintenanceType::RecalculateImapUid),
             3 => Some(TaskAccountMaintenanceType::RecalculateQuota),
+            4 => Some(TaskAccountMaintenanceType::TierBlobs),
+            5 => Some(TaskAccountMaintenanceType::RestoreBlobs),
             _ => None,
         }
     }
 
-    const COUNT: usize = 4;
+    const COUNT: usize = 6;
 }
 
 impl serde::Serialize for TaskAccountMaintenanceType {
@@ -11504,6 +12720,12 @@ impl EnumImpl for TaskStoreMaintenanceType {
             b"removeLockDav" => TaskStoreMaintenanceType::RemoveLockDav,
             b"removeSieveId" => TaskStoreMaintenanceType::RemoveSieveId,
             b"removeGreylist" => TaskStoreMaintenanceType::RemoveGreylist,
+            b"indexBlobSizes" => TaskStoreMaintenanceType::IndexBlobSizes,
+            b"compactDataStore" => TaskStoreMaintenanceType::CompactDataStore,
+            b"flushDataStore" => TaskStoreMaintenanceType::FlushDataStore,
+            b"tierBlobs" => TaskStoreMaintenanceType::TierBlobs,
+            b"scrubBlobs" => TaskStoreMaintenanceType::ScrubBlobs,
+            b"sendQuarantineDigests" => TaskStoreMaintenanceType::SendQuarantineDigests,
         }
     }
 
@@ -11524,6 +12746,12 @@ impl EnumImpl for TaskStoreMaintenanceType {
             TaskStoreMaintenanceType::RemoveLockDav => "removeLockDav",
             TaskStoreMaintenanceType::RemoveSieveId => "removeSieveId",
             TaskStoreMaintenanceType::RemoveGreylist => "removeGreylist",
+            TaskStoreMaintenanceType::IndexBlobSizes => "indexBlobSizes",
+            TaskStoreMaintenanceType::CompactDataStore => "compactDataStore",
+            TaskStoreMaintenanceType::FlushDataStore => "flushDataStore",
+            TaskStoreMaintenanceType::TierBlobs => "tierBlobs",
+            TaskStoreMaintenanceType::ScrubBlobs => "scrubBlobs",
+            TaskStoreMaintenanceType::SendQuarantineDigests => "sendQuarantineDigests",
         }
     }
 
@@ -11548,11 +12776,17 @@ impl EnumImpl for TaskStoreMaintenanceType {
             12 => Some(TaskStoreMaintenanceType::RemoveLockDav),
             13 => Some(TaskStoreMaintenanceType::RemoveSieveId),
             14 => Some(TaskStoreMaintenanceType::RemoveGreylist),
+            15 => Some(TaskStoreMaintenanceType::IndexBlobSizes),
+            16 => Some(TaskStoreMaintenanceType::CompactDataStore),
+            17 => Some(TaskStoreMaintenanceType::FlushDataStore),
+            18 => Some(TaskStoreMaintenanceType::TierBlobs),
+            19 => Some(TaskStoreMaintenanceType::ScrubBlobs),
+            20 => Some(TaskStoreMaintenanceType::SendQuarantineDigests),
             _ => None,
         }
     }
 
-    const COUNT: usize = 15;
+    const COUNT: usize = 21;
 }
 
 impl serde::Serialize for TaskStoreMaintenanceType {
@@ -11643,6 +12877,9 @@ impl EnumImpl for TaskType {
             b"AcmeRenewal" => TaskType::AcmeRenewal,
             b"DkimManagement" => TaskType::DkimManagement,
             b"DnsManagement" => TaskType::DnsManagement,
+            b"AccountExport" => TaskType::AccountExport,
+            b"AccountImport" => TaskType::AccountImport,
+            b"DataRegionMigration" => TaskType::DataRegionMigration,
         }
     }
 
@@ -11666,6 +12903,9 @@ impl EnumImpl for TaskType {
             TaskType::AcmeRenewal => "AcmeRenewal",
             TaskType::DkimManagement => "DkimManagement",
             TaskType::DnsManagement => "DnsManagement",
+            TaskType::AccountExport => "AccountExport",
+            TaskType::AccountImport => "AccountImport",
+            TaskType::DataRegionMigration => "DataRegionMigration",
         }
     }
 
@@ -11693,11 +12933,14 @@ impl EnumImpl for TaskType {
             15 => Some(TaskType::AcmeRenewal),
             16 => Some(TaskType::DkimManagement),
             17 => Some(TaskType::DnsManagement),
+            18 => Some(TaskType::AccountExport),
+            19 => Some(TaskType::AccountImport),
+            20 => Some(TaskType::DataRegionMigration),
             _ => None,
         }
     }
 
-    const COUNT: usize = 18;
+    const COUNT: usize = 21;
 }
 
 impl serde::Serialize for TaskType {
@@ -13719,6 +14962,62 @@ impl<'de> serde::Deserialize<'de> for TlsCipherSuite {
     }
 }
 
+impl EnumImpl for TlsKeyExchangeGroup {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"x25519-mlkem768" => TlsKeyExchangeGroup::X25519Mlkem768,
+            b"x25519" => TlsKeyExchangeGroup::X25519,
+            b"secp256r1" => TlsKeyExchangeGroup::Secp256r1,
+            b"secp384r1" => TlsKeyExchangeGroup::Secp384r1,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            TlsKeyExchangeGroup::X25519Mlkem768 => "x25519-mlkem768",
+            TlsKeyExchangeGroup::X25519 => "x25519",
+            TlsKeyExchangeGroup::Secp256r1 => "secp256r1",
+            TlsKeyExchangeGroup::Secp384r1 => "secp384r1",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(TlsKeyExchangeGroup::X25519Mlkem768),
+            1 => Some(TlsKeyExchangeGroup::X25519),
+            2 => Some(TlsKeyExchangeGroup::Secp256r1),
+            3 => Some(TlsKeyExchangeGroup::Secp384r1),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 4;
+}
+
+impl serde::Serialize for TlsKeyExchangeGroup {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TlsKeyExchangeGroup {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for TlsPolicyType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -13775,6 +15074,62 @@ impl<'de> serde::Deserialize<'de> for TlsPolicyType {
     }
 }
 
+impl EnumImpl for TlsProfile {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"custom" => TlsProfile::Custom,
+            b"modern" => TlsProfile::Modern,
+            b"intermediate" => TlsProfile::Intermediate,
+            b"old" => TlsProfile::Old,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            TlsProfile::Custom => "custom",
+            TlsProfile::Modern => "modern",
+            TlsProfile::Intermediate => "intermediate",
+            TlsProfile::Old => "old",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(TlsProfile::Custom),
+            1 => Some(TlsProfile::Modern),
+            2 => Some(TlsProfile::Intermediate),
+            3 => Some(TlsProfile::Old),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 4;
+}
+
+impl serde::Serialize for TlsProfile {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for TlsProfile {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
+
 impl EnumImpl for TlsResultType {
     fn parse(value: &str) -> Option<Self> {
         hashify::tiny_map! {
@@ -14347,3 +15702,56 @@ impl<'de> serde::Deserialize<'de> for UserRolesType {
         Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
     }
 }
+
+impl EnumImpl for VirtualFolderType {
+    fn parse(value: &str) -> Option<Self> {
+        hashify::tiny_map! {
+            value.as_bytes(),
+            b"all" => VirtualFolderType::All,
+            b"flagged" => VirtualFolderType::Flagged,
+            b"recent" => VirtualFolderType::Recent,
+        }
+    }
+
+    fn as_str(&self) -> &'static str {
+        match self {
+            VirtualFolderType::All => "all",
+            VirtualFolderType::Flagged => "flagged",
+            VirtualFolderType::Recent => "recent",
+        }
+    }
+
+    fn to_id(&self) -> u16 {
+        *self as u16
+    }
+
+    fn from_id(id: u16) -> Option<Self> {
+        match id {
+            0 => Some(VirtualFolderType::All),
+            1 => Some(VirtualFolderType::Flagged),
+            2 => Some(VirtualFolderType::Recent),
+            _ => None,
+        }
+    }
+
+    const COUNT: usize = 3;
+}
+
+impl serde::Serialize for VirtualFolderType {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl<'de> serde::Deserialize<'de> for VirtualFolderType {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        let s = Cow::<str>::deserialize(deserializer)?;
+        Self::parse(&s).ok_or_else(|| serde::de::Error::unknown_variant(&s, &[]))
+    }
+}
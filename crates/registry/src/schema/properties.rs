@@ -26,6 +26,7 @@ pub enum ObjectInner {
     ArfExternalReport(ArfExternalReport),
     Asn(Asn),
     Authentication(Authentication),
+    BlobIntegrityIssue(BlobIntegrityIssue),
     BlobStore(BlobStore),
     BlockedIp(BlockedIp),
     Bootstrap(Bootstrap),
@@ -39,6 +40,7 @@ pub enum ObjectInner {
     Coordinator(Coordinator),
     DataRetention(DataRetention),
     DataStore(DataStore),
+    DeliveryTrace(DeliveryTrace),
     Directory(Directory),
     DkimReportSettings(DkimReportSettings),
     DkimSignature(DkimSignature),
@@ -49,6 +51,7 @@ pub enum ObjectInner {
     DnsServer(DnsServer),
     Domain(Domain),
     DsnReportSettings(DsnReportSettings),
+    DsnTemplate(DsnTemplate),
     Email(Email),
     Enterprise(Enterprise),
     EventTracingLevel(EventTracingLevel),
@@ -60,10 +63,12 @@ pub enum ObjectInner {
     InMemoryStore(InMemoryStore),
     Jmap(Jmap),
     Log(Log),
+    LoginEntry(LoginEntry),
     MailingList(MailingList),
     MaskedEmail(MaskedEmail),
     MemoryLookupKey(MemoryLookupKey),
     MemoryLookupKeyValue(MemoryLookupKeyValue),
+    MessageAuditEntry(MessageAuditEntry),
     Metric(Metric),
     Metrics(Metrics),
     MetricsStore(MetricsStore),
@@ -91,6 +96,7 @@ pub enum ObjectInner {
     OAuthClient(OAuthClient),
     OidcProvider(OidcProvider),
     PublicKey(PublicKey),
+    QuarantinedMessage(QuarantinedMessage),
     QueuedMessage(QueuedMessage),
     ReportSettings(ReportSettings),
     Role(Role),
@@ -114,12 +120,15 @@ pub enum ObjectInner {
     SpamTag(SpamTag),
     SpamTrainingSample(SpamTrainingSample),
     SpfReportSettings(SpfReportSettings),
+    StatisticsRollup(StatisticsRollup),
     StoreLookup(StoreLookup),
     SystemSettings(SystemSettings),
     Task(Task),
     TaskManager(TaskManager),
     Tenant(Tenant),
+    TenantUsage(TenantUsage),
     TlsExternalReport(TlsExternalReport),
+    TlsInboundReport(TlsInboundReport),
     TlsInternalReport(TlsInternalReport),
     TlsReportSettings(TlsReportSettings),
     Trace(Trace),
@@ -148,6 +157,7 @@ pub enum ObjectType {
     ArfExternalReport = 13,
     Asn = 14,
     Authentication = 15,
+    BlobIntegrityIssue = 117,
     BlobStore = 16,
     BlockedIp = 17,
     Bootstrap = 18,
@@ -161,6 +171,7 @@ pub enum ObjectType {
     Coordinator = 26,
     DataRetention = 27,
     DataStore = 28,
+    DeliveryTrace = 118,
     Directory = 29,
     DkimReportSettings = 30,
     DkimSignature = 31,
@@ -171,6 +182,7 @@ pub enum ObjectType {
     DnsServer = 36,
     Domain = 37,
     DsnReportSettings = 38,
+    DsnTemplate = 119,
     Email = 39,
     Enterprise = 40,
     EventTracingLevel = 41,
@@ -182,10 +194,12 @@ pub enum ObjectType {
     InMemoryStore = 47,
     Jmap = 48,
     Log = 49,
+    LoginEntry = 120,
     MailingList = 50,
     MaskedEmail = 51,
     MemoryLookupKey = 52,
     MemoryLookupKeyValue = 53,
+    MessageAuditEntry = 121,
     Metric = 54,
     Metrics = 55,
     MetricsStore = 56,
@@ -213,6 +227,7 @@ pub enum ObjectType {
     OAuthClient = 78,
     OidcProvider = 79,
     PublicKey = 80,
+    QuarantinedMessage = 122,
     QueuedMessage = 81,
     ReportSettings = 82,
     Role = 83,
@@ -236,12 +251,15 @@ pub enum ObjectType {
     SpamTag = 101,
     SpamTrainingSample = 102,
     SpfReportSettings = 103,
+    StatisticsRollup = 123,
     StoreLookup = 104,
     SystemSettings = 105,
     Task = 106,
     TaskManager = 107,
     Tenant = 108,
+    TenantUsage = 124,
     TlsExternalReport = 109,
+    TlsInboundReport = 125,
     TlsInternalReport = 110,
     TlsReportSettings = 111,
     Trace = 112,
@@ -262,6 +280,7 @@ pub enum Property {
     AccessToken = 890,
     AccessTokenExpiry = 619,
     AccessTokens = 132,
+    AccountBandwidth = 910,
     AccountDomainId = 810,
     AccountId = 57,
     AccountIdentifier = 315,
@@ -300,20 +319,31 @@ pub enum Property {
     AllowPlainTextAuth = 424,
     AllowRelaying = 348,
     AllowSpamTraining = 369,
+    AllowedAsns = 911,
+    AllowedCountries = 912,
     AllowedEndpoints = 398,
+    AllowedExtLists = 913,
+    AllowedGrantTypes = 914,
     AllowedIps = 49,
+    AllowedNetworks = 915,
     AllowedNotifyUris = 712,
+    AllowedScopes = 916,
     Alpha = 388,
     AnonymousClientRegistration = 614,
     Ansi = 858,
+    ApiCalls = 917,
     ApiKey = 325,
     ApiUser = 892,
+    AppendSieve = 918,
+    AppendSpamFilter = 919,
     ApplicationKey = 321,
     ApplicationSecret = 322,
     ArcResult = 292,
     ArcVerify = 690,
+    ArchiveAccountId = 920,
     ArchiveDeletedAccountsFor = 203,
     ArchiveDeletedItemsFor = 202,
+    ArchiveFolder = 921,
     ArchivedAt = 58,
     ArchivedItemType = 820,
     ArchivedUntil = 59,
@@ -334,7 +364,11 @@ pub enum Property {
     AuthBanRate = 679,
     AuthCodeExpiry = 616,
     AuthCodeMaxAttempts = 613,
+    AuthDelay = 922,
+    AuthDelayMax = 923,
+    AuthDelayReset = 924,
     AuthFailure = 81,
+    AuthFailures = 925,
     AuthId = 886,
     AuthSecret = 501,
     AuthToken = 314,
@@ -342,7 +376,9 @@ pub enum Property {
     AuthenticatedAs = 740,
     AuthenticationResults = 69,
     AutoAddInvitations = 171,
+    AutoArchiveAfter = 926,
     AutoUpdateFrequency = 53,
+    Bandwidth = 927,
     BaseDn = 463,
     BaseUrl = 882,
     BearerToken = 403,
@@ -352,7 +388,12 @@ pub enum Property {
     BindDn = 464,
     BindSecret = 465,
     BlobCleanupSchedule = 200,
+    BlobCount = 928,
+    BlobHash = 929,
     BlobId = 60,
+    BlobReferences = 930,
+    BlobScrubInterval = 931,
+    BlobScrubRepair = 932,
     BlobSize = 655,
     BlobStore = 126,
     BlockCount = 766,
@@ -361,10 +402,19 @@ pub enum Property {
     Bucket = 658,
     BufferSize = 656,
     Buffered = 863,
+    Burst = 933,
+    BytesReceived = 934,
+    BytesSent = 935,
+    Callout = 936,
+    CalloutCacheTtl = 937,
+    CalloutNegativeCacheTtl = 938,
+    CalloutTimeout = 939,
     Canonicalization = 216,
+    Capabilities = 940,
     CapacityClient = 584,
     CapacityReadBuffer = 585,
     CapacitySubscription = 586,
+    CatchAll = 941,
     CatchAllAddress = 346,
     Categories = 759,
     Certificate = 176,
@@ -378,14 +428,19 @@ pub enum Property {
     Cleartext = 693,
     ClientId = 604,
     ClientIp = 898,
+    ClientPolicy = 942,
     ClientSecret = 878,
     ClientToken = 889,
     ClusterFile = 382,
+    ColdStorageAfter = 943,
+    ColdStorageArchive = 944,
+    ColdStore = 945,
     ColumnClass = 781,
     ColumnDescription = 782,
     ColumnEmail = 779,
     ColumnSecret = 780,
     Comment = 240,
+    CompactionRateLimit = 946,
     CompartmentOcid = 905,
     CompressionAlgorithm = 359,
     Concurrency = 304,
@@ -417,6 +472,7 @@ pub enum Property {
     CustomerNumber = 899,
     Dane = 569,
     DataCleanupSchedule = 199,
+    DataRegion = 947,
     DataStore = 125,
     DataTimeout = 506,
     Database = 575,
@@ -426,6 +482,7 @@ pub enum Property {
     DateRangeStart = 845,
     Day = 192,
     DeadPropertyMaxSize = 868,
+    DeduplicatedBytes = 948,
     DefaultAdminRoleIds = 108,
     DefaultCertificateId = 790,
     DefaultDisplayName = 20,
@@ -441,6 +498,7 @@ pub enum Property {
     DefaultHrefName = 21,
     DefaultLanguage = 665,
     DefaultName = 408,
+    DefaultRegion = 949,
     DefaultReturnPath = 701,
     DefaultSubject = 411,
     DefaultSubjectPrefix = 714,
@@ -452,13 +510,16 @@ pub enum Property {
     DeleteAfterUse = 777,
     DeliverAt = 238,
     DeliverBy = 518,
+    DeliverLocal = 950,
     DeliverTo = 404,
+    DeliveryMode = 951,
     DeliveryResult = 82,
     Depth = 381,
     Description = 6,
     Details = 297,
     Directory = 12,
     DirectoryId = 104,
+    DirectoryPrefix = 952,
     DisableCapabilities = 711,
     DisabledPermissions = 629,
     DiscardAfter = 872,
@@ -477,6 +538,7 @@ pub enum Property {
     DkimSignatures = 155,
     DkimStrict = 686,
     DkimVerify = 687,
+    DmarcOverrides = 953,
     DmarcPass = 294,
     DmarcPolicy = 295,
     DmarcResult = 293,
@@ -505,6 +567,8 @@ pub enum Property {
     Dsn = 519,
     Due = 797,
     DuplicateExpiry = 699,
+    DuplicateMatch = 954,
+    DuplicateWindow = 955,
     Duration = 515,
     EabHmacKey = 13,
     EabKeyId = 14,
@@ -529,12 +593,16 @@ pub enum Property {
     EnableLogExporter = 860,
     EnableSpamFilter = 562,
     EnableSpanExporter = 861,
+    EnableUserScriptReject = 956,
     Enabled = 50,
     EnabledPermissions = 628,
     EncryptAtRest = 358,
     EncryptOnAppend = 357,
     EncryptionAtRest = 9,
     EncryptionKey = 622,
+    EncryptionKeyCacheTtl = 957,
+    EncryptionKeyProvider = 958,
+    EncryptionKeys = 959,
     Endpoint = 499,
     EnvFrom = 742,
     EnvFromParameters = 743,
@@ -543,6 +611,7 @@ pub enum Property {
     EnvelopeFrom = 264,
     EnvelopeTo = 263,
     Environment = 881,
+    Error = 960,
     ErrorCommand = 210,
     ErrorMessage = 209,
     ErrorType = 208,
@@ -556,6 +625,7 @@ pub enum Property {
     EventEndTz = 803,
     EventId = 799,
     EventMessage = 43,
+    EventSourceReplayWindow = 961,
     EventSourceThrottle = 447,
     EventStart = 800,
     EventStartTz = 802,
@@ -567,6 +637,8 @@ pub enum Property {
     ExpiresAttempts = 632,
     Expiry = 512,
     Expn = 520,
+    ExpnRate = 962,
+    ExpungeDraftsAfter = 963,
     ExpungeSchedule = 198,
     ExpungeSchedulingInboxAfter = 197,
     ExpungeShareNotifyAfter = 196,
@@ -619,18 +691,28 @@ pub enum Property {
     GreylistFor = 770,
     GroupClass = 477,
     GroupId = 460,
+    HamMessages = 964,
     HeaderFrom = 265,
     Headers = 93,
+    HoldAuditLogFor = 965,
+    HoldBlobIssuesFor = 966,
+    HoldDeliveryTracesFor = 967,
+    HoldLoginHistoryFor = 968,
     HoldMetricsFor = 206,
     HoldMtaReportsFor = 204,
     HoldSamplesFor = 730,
+    HoldStatisticsFor = 969,
+    HoldTenantUsageFor = 970,
     HoldTracesFor = 205,
     Host = 333,
     HostedZoneId = 331,
     Hostname = 185,
+    Hosts = 971,
+    HotStore = 972,
     Hour = 190,
     HttpAuth = 32,
     HttpHeaders = 33,
+    HttpPath = 973,
     HttpRsvpEnable = 168,
     HttpRsvpLinkExpiry = 169,
     HttpRsvpTemplate = 175,
@@ -647,6 +729,7 @@ pub enum Property {
     InMemoryStore = 128,
     InboundReportAddresses = 651,
     InboundReportForwarding = 652,
+    InboundTlsFailureRate = 974,
     Incidents = 70,
     IncludeSource = 352,
     IndexAsn = 94,
@@ -660,6 +743,7 @@ pub enum Property {
     IndexEmail = 671,
     IndexEmailFields = 672,
     IndexKey = 421,
+    IndexMaxBodySize = 975,
     IndexTelemetry = 673,
     IndexTracingFields = 674,
     IndexValue = 422,
@@ -671,6 +755,8 @@ pub enum Property {
     IpLookupStrategy = 543,
     IpRevPtr = 290,
     IpRevResult = 289,
+    Ipv6Only = 976,
+    Ipv6PrefixLength = 977,
     IsActive = 707,
     IsArchive = 755,
     IsBad = 756,
@@ -682,6 +768,7 @@ pub enum Property {
     IsSenderAllowed = 564,
     IsSpam = 776,
     IsTls = 741,
+    Issue = 978,
     Issuer = 181,
     IssuerUrl = 606,
     ItipMaxSize = 172,
@@ -692,6 +779,7 @@ pub enum Property {
     KeyValues = 853,
     L1Ratio = 391,
     L2Ratio = 392,
+    LargeMessageSize = 979,
     LastRenewal = 186,
     LearnHamFromCard = 727,
     LearnHamFromReply = 735,
@@ -700,15 +788,18 @@ pub enum Property {
     Level = 373,
     LicenseKey = 370,
     Line = 875,
+    ListId = 980,
     ListenerIds = 183,
     Listeners = 188,
     LivePropertyMaxSize = 869,
     Locale = 7,
+    LocalizedNames = 981,
     Logo = 341,
     LogoUrl = 371,
     LoiterBanPeriod = 682,
     LoiterBanRate = 681,
     Lossy = 854,
+    LowPriorityThreadsPerNode = 982,
     MachineId = 384,
     MailExchangers = 793,
     MailFrom = 284,
@@ -716,7 +807,9 @@ pub enum Property {
     MailRua = 841,
     MailingLists = 154,
     MaintenanceType = 796,
+    MaintenanceWindows = 983,
     ManagedZone = 318,
+    MasterName = 984,
     Match = 374,
     MaxAddressBooks = 23,
     MaxAge = 566,
@@ -727,6 +820,7 @@ pub enum Property {
     MaxAttempts = 511,
     MaxAttendees = 157,
     MaxAuthFailures = 425,
+    MaxBackgroundJobs = 985,
     MaxCalendars = 160,
     MaxChangesHistory = 201,
     MaxConcurrent = 426,
@@ -735,7 +829,10 @@ pub enum Property {
     MaxConnections = 603,
     MaxContacts = 24,
     MaxCpuCycles = 702,
+    MaxCustomKeywords = 986,
     MaxDelay = 823,
+    MaxDrafts = 987,
+    MaxDraftsSize = 988,
     MaxDuration = 530,
     MaxEntries = 417,
     MaxEntrySize = 418,
@@ -744,6 +841,7 @@ pub enum Property {
     MaxFailures = 547,
     MaxFiles = 378,
     MaxFolders = 379,
+    MaxHeaderCount = 989,
     MaxHeaderSize = 715,
     MaxICalendarSize = 159,
     MaxIdentities = 363,
@@ -756,18 +854,23 @@ pub enum Property {
     MaxMailboxes = 364,
     MaxMaskedAddresses = 365,
     MaxMatchVars = 718,
+    MaxMessageKeywords = 990,
     MaxMessageSize = 354,
     MaxMessages = 361,
     MaxMethodCalls = 438,
+    MaxMimeDepth = 991,
+    MaxMimeParts = 992,
     MaxMultihomed = 544,
     MaxMxHosts = 545,
     MaxNestedBlocks = 720,
     MaxNestedForEvery = 721,
     MaxNestedIncludes = 703,
     MaxNestedTests = 722,
+    MaxNotifyActions = 993,
     MaxOutMessages = 704,
     MaxParticipantIdentities = 162,
     MaxPublicKeys = 366,
+    MaxQueueSize = 994,
     MaxReceivedHeaders = 561,
     MaxRecipients = 173,
     MaxReconnects = 580,
@@ -780,6 +883,7 @@ pub enum Property {
     MaxResults = 871,
     MaxRetries = 18,
     MaxRetryWait = 648,
+    MaxRunTime = 995,
     MaxScriptNameLength = 719,
     MaxScriptSize = 723,
     MaxScripts = 726,
@@ -788,6 +892,7 @@ pub enum Property {
     MaxStringLength = 724,
     MaxSubmissions = 362,
     MaxSubscriptions = 458,
+    MaxTotalScriptSize = 996,
     MaxUploadCount = 444,
     MaxUploadSize = 443,
     MaxVCardSize = 22,
@@ -795,19 +900,25 @@ pub enum Property {
     MaxVarSize = 706,
     MemberGroupIds = 864,
     MemberTenantId = 19,
+    MembersOnly = 997,
     Message = 92,
     MessageIdHostname = 698,
     MessageIds = 819,
     Messages = 145,
+    MessagesReceived = 998,
+    MessagesSent = 999,
+    MessagesSubmitted = 1000,
     Metric = 493,
     Metrics = 497,
     MetricsCollectionInterval = 207,
     MetricsPolicy = 498,
+    MigrationDirectory = 1001,
     MinHamSamples = 731,
     MinRetryWait = 649,
     MinSpamSamples = 732,
     MinTriggerInterval = 166,
     Minute = 191,
+    MirrorStore = 1002,
     Mode = 567,
     Model = 28,
     ModelId = 764,
@@ -817,6 +928,7 @@ pub enum Property {
     MtaStsTimeout = 572,
     Multiline = 859,
     MustMatchSender = 550,
+    MustMatchTenantDomain = 1003,
     MxHosts = 568,
     Name = 25,
     Namespace = 414,
@@ -833,6 +945,9 @@ pub enum Property {
     Notify = 513,
     NotifyCount = 642,
     NotifyDue = 643,
+    NotifyGatewayUrl = 1004,
+    NotifySignatureKey = 1005,
+    NotifyTimeout = 1006,
     NumFeatures = 390,
     NumReplicas = 350,
     NumShards = 351,
@@ -853,11 +968,13 @@ pub enum Property {
     OutboundReportSubmitter = 654,
     OverrideProxyTrustedNetworks = 590,
     OverrideType = 239,
+    OversignHeaders = 1007,
     OvhEndpoint = 324,
     Parameters = 737,
     ParseLimitContact = 433,
     ParseLimitEmail = 434,
     ParseLimitEvent = 432,
+    PartitionMode = 1008,
     Password = 876,
     PasswordDefaultExpiry = 113,
     PasswordHashAlgorithm = 109,
@@ -865,10 +982,12 @@ pub enum Property {
     PasswordMinLength = 110,
     PasswordMinStrength = 112,
     Path = 380,
+    PerformedBy = 1009,
     Period = 646,
     Permissions = 48,
     PingInterval = 583,
     Pipelining = 524,
+    PlaintextFallback = 1010,
     Policies = 846,
     PolicyAdkim = 250,
     PolicyAspf = 251,
@@ -895,7 +1014,9 @@ pub enum Property {
     Port = 299,
     Prefix = 856,
     PreserveIntermediates = 306,
+    PrimaryStore = 1011,
     Priority = 483,
+    PriorityThreadsPerNode = 1012,
     PrivateKey = 177,
     PrivateKeyPassword = 904,
     PrivateKeyPem = 903,
@@ -911,7 +1032,12 @@ pub enum Property {
     Protocol = 298,
     ProtocolVersion = 533,
     ProviderInfo = 795,
+    ProxyAddress = 1013,
+    ProxyPort = 1014,
+    ProxyProtocol = 1015,
+    ProxySecret = 1016,
     ProxyTrustedNetworks = 792,
+    ProxyUsername = 1017,
     PublicKey = 218,
     PublishRecords = 302,
     PushAttemptWait = 448,
@@ -920,7 +1046,12 @@ pub enum Property {
     PushRetryWait = 450,
     PushShardsTotal = 454,
     PushThrottle = 451,
+    PushVapidKey = 1018,
+    PushVapidSubject = 1019,
     PushVerifyTimeout = 453,
+    QuarantineDigestFrequency = 1020,
+    QuarantineFor = 1021,
+    Query = 1022,
     QueryEmailAliases = 786,
     QueryLogin = 783,
     QueryMaxResults = 437,
@@ -931,8 +1062,13 @@ pub enum Property {
     Quotas = 394,
     Rate = 532,
     RateLimit = 410,
+    RateLimitAdmin = 1023,
     RateLimitAnonymous = 397,
     RateLimitAuthenticated = 396,
+    RateLimitClient = 1024,
+    RateLimitQuery = 1025,
+    RateLimitSet = 1026,
+    RateLimitUpload = 1027,
     Ratio = 767,
     RcptToTimeout = 510,
     ReadFromReplicas = 650,
@@ -940,21 +1076,35 @@ pub enum Property {
     Reason = 45,
     ReceivedAt = 63,
     ReceivedFromIp = 636,
+    ReceivedHeaderFormat = 1028,
     ReceivedViaPort = 637,
     ReceivingIp = 836,
     ReceivingMxHelo = 835,
     ReceivingMxHostname = 834,
+    RecentFolderPeriod = 1029,
+    Recipient = 1030,
     Recipients = 484,
     Records = 256,
     RecurrenceId = 805,
+    RedirectLimit = 1031,
+    RedirectRateLimit = 1032,
+    RedirectTimeout = 1033,
     RedirectUris = 605,
     Refresh = 419,
     RefreshTokenExpiry = 617,
     RefreshTokenRenewal = 618,
     Region = 330,
+    Regions = 1034,
+    ReindexBatchSize = 1035,
+    ReindexInterval = 1036,
+    RejectBareCr = 1037,
+    RejectBareLf = 1038,
     RejectNonFqdn = 563,
     RemoteIp = 282,
     RenewBefore = 17,
+    RepairMime = 1039,
+    Repaired = 1040,
+    ReplyTo = 1041,
     Report = 66,
     ReportAddressUri = 349,
     ReportId = 244,
@@ -966,9 +1116,11 @@ pub enum Property {
     Require = 551,
     RequireAudience = 607,
     RequireClientRegistration = 615,
+    RequireMfa = 1042,
     RequireScopes = 608,
     RequireTls = 525,
     ReservoirCapacity = 733,
+    ResolvedIps = 1043,
     ResourceGroup = 880,
     ResourceUrl = 51,
     ResponseCode = 212,
@@ -981,6 +1133,7 @@ pub enum Property {
     ResponsePosExplanation = 763,
     Result = 233,
     ResultType = 832,
+    Results = 1044,
     RetireAfter = 228,
     Retry = 420,
     RetryCount = 640,
@@ -988,6 +1141,7 @@ pub enum Property {
     ReturnPath = 635,
     ReverseIpVerify = 692,
     Rewrite = 565,
+    Role = 1045,
     RoleIds = 193,
     Roles = 152,
     Rotate = 857,
@@ -1006,9 +1160,12 @@ pub enum Property {
     Scope = 281,
     Score = 745,
     ScoreDiscard = 771,
+    ScoreQuarantine = 1046,
     ScoreReject = 772,
     ScoreSpam = 773,
     Script = 553,
+    ScrubTraceHeaders = 1047,
+    SearchFolders = 1048,
     SearchStore = 127,
     Secret = 3,
     SecretAccessKey = 328,
@@ -1019,6 +1176,9 @@ pub enum Property {
     Selector = 222,
     SelectorTemplate = 226,
     SendFrequency = 230,
+    SenderDomain = 1049,
+    SenderMismatchAction = 1050,
+    SenderRewrite = 1051,
     SendingMtaIp = 833,
     Separator = 97,
     ServerHostname = 121,
@@ -1028,7 +1188,9 @@ pub enum Property {
     SessionToken = 329,
     SetMaxObjects = 440,
     ShardIndex = 830,
+    SharedMailboxDestroy = 1052,
     SharedSecret = 895,
+    ShowVirtualFolders = 1053,
     Sig0Algorithm = 336,
     SignatureAlgorithm = 623,
     SignatureKey = 624,
@@ -1036,7 +1198,10 @@ pub enum Property {
     Size = 64,
     SkipDeploy = 885,
     SkipFirst = 423,
+    Smarthost = 1054,
     SmtpGreeting = 552,
+    SmugglingBanRate = 1055,
+    SnippetMaxLength = 1056,
     SnippetMaxResults = 441,
     SocketBacklog = 591,
     SocketNoDelay = 592,
@@ -1050,6 +1215,7 @@ pub enum Property {
     SourceIps = 504,
     SourcePort = 78,
     SpamFilterRulesUrl = 775,
+    SpamMessages = 1057,
     SpfDns = 90,
     SpfEhloDomain = 285,
     SpfEhloResult = 286,
@@ -1064,14 +1230,21 @@ pub enum Property {
     StartTls = 571,
     Status = 61,
     StorageAccount = 116,
+    StorageUsed = 1058,
     Store = 778,
+    StoredBytes = 1059,
     Stores = 694,
     Strategy = 816,
+    StructureLimitAction = 1060,
     SubAddressing = 347,
     SubAuthId = 887,
     Subject = 41,
     SubjectAlternativeNames = 178,
+    SubjectPrefix = 1061,
     Subscribe = 368,
+    SubscribeOnAutoCreate = 1062,
+    SubscribeOnImapCreate = 1063,
+    SubscribeOnJmapCreate = 1064,
     SubscriptionId = 879,
     Sum = 494,
     Summary = 808,
@@ -1086,6 +1259,7 @@ pub enum Property {
     Temperature = 27,
     Template = 167,
     TenancyOcid = 900,
+    TenantDomainExemptions = 1065,
     TenantId = 831,
     Tenants = 153,
     Text = 2,
@@ -1111,16 +1285,28 @@ pub enum Property {
     Timestamp = 482,
     Title = 55,
     Tls = 542,
+    TlsCipher = 1066,
+    TlsClientAuth = 1067,
+    TlsClientCa = 1068,
     TlsDisableCipherSuites = 599,
+    TlsDisableKeyExchangeGroups = 1069,
     TlsDisableProtocols = 600,
+    TlsFingerprint = 1070,
     TlsIgnoreClientOrder = 601,
     TlsImplicit = 602,
+    TlsPreferPostQuantum = 1071,
+    TlsProfile = 1072,
+    TlsServerName = 1073,
     TlsTimeout = 573,
+    TlsVersion = 1074,
     To = 42,
     Token = 888,
+    TopRecipients = 1075,
+    TopSenders = 1076,
     TotalDeadline = 817,
     TotalFailedSessions = 850,
     TotalSuccessfulSessions = 849,
+    TraceHeaders = 1077,
     TraceId = 815,
     Tracer = 129,
     TrainFrequency = 734,
@@ -1132,6 +1318,7 @@ pub enum Property {
     TrustReplies = 774,
     TsigAlgorithm = 338,
     Ttl = 310,
+    UndoSendWindow = 1078,
     UnpackDirectory = 54,
     UpdateRecords = 812,
     UploadQuota = 445,
@@ -1140,6 +1327,7 @@ pub enum Property {
     UrlLimit = 753,
     UrlPrefix = 52,
     Urls = 647,
+    UseHttps = 1079,
     UsePermissiveCors = 400,
     UseTls = 309,
     UseXForwarded = 402,
@@ -1155,12 +1343,15 @@ pub enum Property {
     VerifyAfterWrite = 874,
     Version = 80,
     ViewName = 884,
+    VirtualFolders = 1080,
     Vrfy = 526,
+    VrfyRate = 1081,
     WaitOnFail = 548,
     WapiVersion = 893,
     WebsocketHeartbeat = 455,
     WebsocketThrottle = 456,
     WebsocketTimeout = 457,
+    WriteMode = 1082,
     Zone = 749,
     ZoneIpV4 = 98,
     ZoneIpV6 = 99,
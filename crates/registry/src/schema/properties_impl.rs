@@ -66,6 +66,7 @@ impl EnumImpl for ObjectType {
             b"MaskedEmail" => ObjectType::MaskedEmail,
             b"MemoryLookupKey" => ObjectType::MemoryLookupKey,
             b"MemoryLookupKeyValue" => ObjectType::MemoryLookupKeyValue,
+            b"DsnTemplate" => ObjectType::DsnTemplate,
             b"Metric" => ObjectType::Metric,
             b"Metrics" => ObjectType::Metrics,
             b"MetricsStore" => ObjectType::MetricsStore,
@@ -115,6 +116,7 @@ impl EnumImpl for ObjectType {
             b"SpamSettings" => ObjectType::SpamSettings,
             b"SpamTag" => ObjectType::SpamTag,
             b"SpamTrainingSample" => ObjectType::SpamTrainingSample,
+            b"QuarantinedMessage" => ObjectType::QuarantinedMessage,
             b"SpfReportSettings" => ObjectType::SpfReportSettings,
             b"StoreLookup" => ObjectType::StoreLookup,
             b"SystemSettings" => ObjectType::SystemSettings,
@@ -122,6 +124,13 @@ impl EnumImpl for ObjectType {
             b"TaskManager" => ObjectType::TaskManager,
             b"Tenant" => ObjectType::Tenant,
             b"TlsExternalReport" => ObjectType::TlsExternalReport,
+            b"TlsInboundReport" => ObjectType::TlsInboundReport,
+            b"StatisticsRollup" => ObjectType::StatisticsRollup,
+            b"TenantUsage" => ObjectType::TenantUsage,
+            b"MessageAuditEntry" => ObjectType::MessageAuditEntry,
+            b"LoginEntry" => ObjectType::LoginEntry,
+            b"BlobIntegrityIssue" => ObjectType::BlobIntegrityIssue,
+            b"DeliveryTrace" => ObjectType::DeliveryTrace,
             b"TlsInternalReport" => ObjectType::TlsInternalReport,
             b"TlsReportSettings" => ObjectType::TlsReportSettings,
             b"Trace" => ObjectType::Trace,
@@ -150,6 +159,7 @@ impl EnumImpl for ObjectType {
             ObjectType::ArfExternalReport => "ArfExternalReport",
             ObjectType::Asn => "Asn",
             ObjectType::Authentication => "Authentication",
+            ObjectType::BlobIntegrityIssue => "BlobIntegrityIssue",
             ObjectType::BlobStore => "BlobStore",
             ObjectType::BlockedIp => "BlockedIp",
             ObjectType::Bootstrap => "Bootstrap",
@@ -163,6 +173,7 @@ impl EnumImpl for ObjectType {
             ObjectType::Coordinator => "Coordinator",
             ObjectType::DataRetention => "DataRetention",
             ObjectType::DataStore => "DataStore",
+            ObjectType::DeliveryTrace => "DeliveryTrace",
             ObjectType::Directory => "Directory",
             ObjectType::DkimReportSettings => "DkimReportSettings",
             ObjectType::DkimSignature => "DkimSignature",
@@ -173,6 +184,7 @@ impl EnumImpl for ObjectType {
             ObjectType::DnsServer => "DnsServer",
             ObjectType::Domain => "Domain",
             ObjectType::DsnReportSettings => "DsnReportSettings",
+            ObjectType::DsnTemplate => "DsnTemplate",
             ObjectType::Email => "Email",
             ObjectType::Enterprise => "Enterprise",
             ObjectType::EventTracingLevel => "EventTracingLevel",
@@ -184,10 +196,12 @@ impl EnumImpl for ObjectType {
             ObjectType::InMemoryStore => "InMemoryStore",
             ObjectType::Jmap => "Jmap",
             ObjectType::Log => "Log",
+            ObjectType::LoginEntry => "LoginEntry",
             ObjectType::MailingList => "MailingList",
             ObjectType::MaskedEmail => "MaskedEmail",
             ObjectType::MemoryLookupKey => "MemoryLookupKey",
             ObjectType::MemoryLookupKeyValue => "MemoryLookupKeyValue",
+            ObjectType::MessageAuditEntry => "MessageAuditEntry",
             ObjectType::Metric => "Metric",
             ObjectType::Metrics => "Metrics",
             ObjectType::MetricsStore => "MetricsStore",
@@ -215,6 +229,7 @@ impl EnumImpl for ObjectType {
             ObjectType::OAuthClient => "OAuthClient",
             ObjectType::OidcProvider => "OidcProvider",
             ObjectType::PublicKey => "PublicKey",
+            ObjectType::QuarantinedMessage => "QuarantinedMessage",
             ObjectType::QueuedMessage => "QueuedMessage",
             ObjectType::ReportSettings => "ReportSettings",
             ObjectType::Role => "Role",
@@ -238,12 +253,15 @@ impl EnumImpl for ObjectType {
             ObjectType::SpamTag => "SpamTag",
             ObjectType::SpamTrainingSample => "SpamTrainingSample",
             ObjectType::SpfReportSettings => "SpfReportSettings",
+            ObjectType::StatisticsRollup => "StatisticsRollup",
             ObjectType::StoreLookup => "StoreLookup",
             ObjectType::SystemSettings => "SystemSettings",
             ObjectType::Task => "Task",
             ObjectType::TaskManager => "TaskManager",
             ObjectType::Tenant => "Tenant",
+            ObjectType::TenantUsage => "TenantUsage",
             ObjectType::TlsExternalReport => "TlsExternalReport",
+            ObjectType::TlsInboundReport => "TlsInboundReport",
             ObjectType::TlsInternalReport => "TlsInternalReport",
             ObjectType::TlsReportSettings => "TlsReportSettings",
             ObjectType::Trace => "Trace",
@@ -276,6 +294,7 @@ impl EnumImpl for ObjectType {
             13 => Some(ObjectType::ArfExternalReport),
             14 => Some(ObjectType::Asn),
             15 => Some(ObjectType::Authentication),
+            117 => Some(ObjectType::BlobIntegrityIssue),
             16 => Some(ObjectType::BlobStore),
             17 => Some(ObjectType::BlockedIp),
             18 => Some(ObjectType::Bootstrap),
@@ -289,6 +308,7 @@ impl EnumImpl for ObjectType {
             26 => Some(ObjectType::Coordinator),
             27 => Some(ObjectType::DataRetention),
             28 => Some(ObjectType::DataStore),
+            118 => Some(ObjectType::DeliveryTrace),
             29 => Some(ObjectType::Directory),
             30 => Some(ObjectType::DkimReportSettings),
             31 => Some(ObjectType::DkimSignature),
@@ -299,6 +319,7 @@ impl EnumImpl for ObjectType {
             36 => Some(ObjectType::DnsServer),
             37 => Some(ObjectType::Domain),
             38 => Some(ObjectType::DsnReportSettings),
+            119 => Some(ObjectType::DsnTemplate),
             39 => Some(ObjectType::Email),
             40 => Some(ObjectType::Enterprise),
             41 => Some(ObjectType::EventTracingLevel),
@@ -310,10 +331,12 @@ impl EnumImpl for ObjectType {
             47 => Some(ObjectType::InMemoryStore),
             48 => Some(ObjectType::Jmap),
             49 => Some(ObjectType::Log),
+            120 => Some(ObjectType::LoginEntry),
             50 => Some(ObjectType::MailingList),
             51 => Some(ObjectType::MaskedEmail),
             52 => Some(ObjectType::MemoryLookupKey),
             53 => Some(ObjectType::MemoryLookupKeyValue),
+            121 => Some(ObjectType::MessageAuditEntry),
             54 => Some(ObjectType::Metric),
             55 => Some(ObjectType::Metrics),
             56 => Some(ObjectType::MetricsStore),
@@ -341,6 +364,7 @@ impl EnumImpl for ObjectType {
             78 => Some(ObjectType::OAuthClient),
             79 => Some(ObjectType::OidcProvider),
             80 => Some(ObjectType::PublicKey),
+            122 => Some(ObjectType::QuarantinedMessage),
             81 => Some(ObjectType::QueuedMessage),
             82 => Some(ObjectType::ReportSettings),
             83 => Some(ObjectType::Role),
@@ -364,12 +388,15 @@ impl EnumImpl for ObjectType {
             101 => Some(ObjectType::SpamTag),
             102 => Some(ObjectType::SpamTrainingSample),
             103 => Some(ObjectType::SpfReportSettings),
+            123 => Some(ObjectType::StatisticsRollup),
             104 => Some(ObjectType::StoreLookup),
             105 => Some(ObjectType::SystemSettings),
             106 => Some(ObjectType::Task),
             107 => Some(ObjectType::TaskManager),
             108 => Some(ObjectType::Tenant),
+            124 => Some(ObjectType::TenantUsage),
             109 => Some(ObjectType::TlsExternalReport),
+            125 => Some(ObjectType::TlsInboundReport),
             110 => Some(ObjectType::TlsInternalReport),
             111 => Some(ObjectType::TlsReportSettings),
             112 => Some(ObjectType::Trace),
@@ -381,7 +408,7 @@ impl EnumImpl for ObjectType {
         }
     }
 
-    const COUNT: usize = 117;
+    const COUNT: usize = 126;
 }
 
 impl serde::Serialize for ObjectType {
@@ -415,6 +442,7 @@ impl EnumImpl for Property {
             b"accessToken" => Property::AccessToken,
             b"accessTokenExpiry" => Property::AccessTokenExpiry,
             b"accessTokens" => Property::AccessTokens,
+            b"accountBandwidth" => Property::AccountBandwidth,
             b"accountDomainId" => Property::AccountDomainId,
             b"accountId" => Property::AccountId,
             b"accountIdentifier" => Property::AccountIdentifier,
@@ -453,20 +481,31 @@ impl EnumImpl for Property {
             b"allowPlainTextAuth" => Property::AllowPlainTextAuth,
             b"allowRelaying" => Property::AllowRelaying,
             b"allowSpamTraining" => Property::AllowSpamTraining,
+            b"allowedAsns" => Property::AllowedAsns,
+            b"allowedCountries" => Property::AllowedCountries,
             b"allowedEndpoints" => Property::AllowedEndpoints,
+            b"allowedExtLists" => Property::AllowedExtLists,
+            b"allowedGrantTypes" => Property::AllowedGrantTypes,
             b"allowedIps" => Property::AllowedIps,
+            b"allowedNetworks" => Property::AllowedNetworks,
             b"allowedNotifyUris" => Property::AllowedNotifyUris,
+            b"allowedScopes" => Property::AllowedScopes,
             b"alpha" => Property::Alpha,
             b"anonymousClientRegistration" => Property::AnonymousClientRegistration,
             b"ansi" => Property::Ansi,
+            b"apiCalls" => Property::ApiCalls,
             b"apiKey" => Property::ApiKey,
             b"apiUser" => Property::ApiUser,
+            b"appendSieve" => Property::AppendSieve,
+            b"appendSpamFilter" => Property::AppendSpamFilter,
             b"applicationKey" => Property::ApplicationKey,
             b"applicationSecret" => Property::ApplicationSecret,
             b"arcResult" => Property::ArcResult,
             b"arcVerify" => Property::ArcVerify,
+            b"archiveAccountId" => Property::ArchiveAccountId,
             b"archiveDeletedAccountsFor" => Property::ArchiveDeletedAccountsFor,
             b"archiveDeletedItemsFor" => Property::ArchiveDeletedItemsFor,
+            b"archiveFolder" => Property::ArchiveFolder,
             b"archivedAt" => Property::ArchivedAt,
             b"archivedItemType" => Property::ArchivedItemType,
             b"archivedUntil" => Property::ArchivedUntil,
@@ -487,7 +526,11 @@ impl EnumImpl for Property {
             b"authBanRate" => Property::AuthBanRate,
             b"authCodeExpiry" => Property::AuthCodeExpiry,
             b"authCodeMaxAttempts" => Property::AuthCodeMaxAttempts,
+            b"authDelay" => Property::AuthDelay,
+            b"authDelayMax" => Property::AuthDelayMax,
+            b"authDelayReset" => Property::AuthDelayReset,
             b"authFailure" => Property::AuthFailure,
+            b"authFailures" => Property::AuthFailures,
             b"authId" => Property::AuthId,
             b"authSecret" => Property::AuthSecret,
             b"authToken" => Property::AuthToken,
@@ -495,7 +538,9 @@ impl EnumImpl for Property {
             b"authenticatedAs" => Property::AuthenticatedAs,
             b"authenticationResults" => Property::AuthenticationResults,
             b"autoAddInvitations" => Property::AutoAddInvitations,
+            b"autoArchiveAfter" => Property::AutoArchiveAfter,
             b"autoUpdateFrequency" => Property::AutoUpdateFrequency,
+            b"bandwidth" => Property::Bandwidth,
             b"baseDn" => Property::BaseDn,
             b"baseUrl" => Property::BaseUrl,
             b"bearerToken" => Property::BearerToken,
@@ -505,7 +550,12 @@ impl EnumImpl for Property {
             b"bindDn" => Property::BindDn,
             b"bindSecret" => Property::BindSecret,
             b"blobCleanupSchedule" => Property::BlobCleanupSchedule,
+            b"blobCount" => Property::BlobCount,
+            b"blobHash" => Property::BlobHash,
             b"blobId" => Property::BlobId,
+            b"blobReferences" => Property::BlobReferences,
+            b"blobScrubInterval" => Property::BlobScrubInterval,
+            b"blobScrubRepair" => Property::BlobScrubRepair,
             b"blobSize" => Property::BlobSize,
             b"blobStore" => Property::BlobStore,
             b"blockCount" => Property::BlockCount,
@@ -514,10 +564,19 @@ impl EnumImpl for Property {
             b"bucket" => Property::Bucket,
             b"bufferSize" => Property::BufferSize,
             b"buffered" => Property::Buffered,
+            b"burst" => Property::Burst,
+            b"bytesReceived" => Property::BytesReceived,
+            b"bytesSent" => Property::BytesSent,
+            b"callout" => Property::Callout,
+            b"calloutCacheTtl" => Property::CalloutCacheTtl,
+            b"calloutNegativeCacheTtl" => Property::CalloutNegativeCacheTtl,
+            b"calloutTimeout" => Property::CalloutTimeout,
             b"canonicalization" => Property::Canonicalization,
+            b"capabilities" => Property::Capabilities,
             b"capacityClient" => Property::CapacityClient,
             b"capacityReadBuffer" => Property::CapacityReadBuffer,
             b"capacitySubscription" => Property::CapacitySubscription,
+            b"catchAll" => Property::CatchAll,
             b"catchAllAddress" => Property::CatchAllAddress,
             b"categories" => Property::Categories,
             b"certificate" => Property::Certificate,
@@ -531,14 +590,19 @@ impl EnumImpl for Property {
             b"cleartext" => Property::Cleartext,
             b"clientId" => Property::ClientId,
             b"clientIp" => Property::ClientIp,
+            b"clientPolicy" => Property::ClientPolicy,
             b"clientSecret" => Property::ClientSecret,
             b"clientToken" => Property::ClientToken,
             b"clusterFile" => Property::ClusterFile,
+            b"coldStorageAfter" => Property::ColdStorageAfter,
+            b"coldStorageArchive" => Property::ColdStorageArchive,
+            b"coldStore" => Property::ColdStore,
             b"columnClass" => Property::ColumnClass,
             b"columnDescription" => Property::ColumnDescription,
             b"columnEmail" => Property::ColumnEmail,
             b"columnSecret" => Property::ColumnSecret,
             b"comment" => Property::Comment,
+            b"compactionRateLimit" => Property::CompactionRateLimit,
             b"compartmentOcid" => Property::CompartmentOcid,
             b"compressionAlgorithm" => Property::CompressionAlgorithm,
             b"concurrency" => Property::Concurrency,
@@ -570,6 +634,7 @@ impl EnumImpl for Property {
             b"customerNumber" => Property::CustomerNumber,
             b"dane" => Property::Dane,
             b"dataCleanupSchedule" => Property::DataCleanupSchedule,
+            b"dataRegion" => Property::DataRegion,
             b"dataStore" => Property::DataStore,
             b"dataTimeout" => Property::DataTimeout,
             b"database" => Property::Database,
@@ -579,6 +644,7 @@ impl EnumImpl for Property {
             b"dateRangeStart" => Property::DateRangeStart,
             b"day" => Property::Day,
             b"deadPropertyMaxSize" => Property::DeadPropertyMaxSize,
+            b"deduplicatedBytes" => Property::DeduplicatedBytes,
             b"defaultAdminRoleIds" => Property::DefaultAdminRoleIds,
             b"defaultCertificateId" => Property::DefaultCertificateId,
             b"defaultDisplayName" => Property::DefaultDisplayName,
@@ -594,6 +660,7 @@ impl EnumImpl for Property {
             b"defaultHrefName" => Property::DefaultHrefName,
             b"defaultLanguage" => Property::DefaultLanguage,
             b"defaultName" => Property::DefaultName,
+            b"defaultRegion" => Property::DefaultRegion,
             b"defaultReturnPath" => Property::DefaultReturnPath,
             b"defaultSubject" => Property::DefaultSubject,
             b"defaultSubjectPrefix" => Property::DefaultSubjectPrefix,
@@ -605,13 +672,16 @@ impl EnumImpl for Property {
             b"deleteAfterUse" => Property::DeleteAfterUse,
             b"deliverAt" => Property::DeliverAt,
             b"deliverBy" => Property::DeliverBy,
+            b"deliverLocal" => Property::DeliverLocal,
             b"deliverTo" => Property::DeliverTo,
+            b"deliveryMode" => Property::DeliveryMode,
             b"deliveryResult" => Property::DeliveryResult,
             b"depth" => Property::Depth,
             b"description" => Property::Description,
             b"details" => Property::Details,
             b"directory" => Property::Directory,
             b"directoryId" => Property::DirectoryId,
+            b"directoryPrefix" => Property::DirectoryPrefix,
             b"disableCapabilities" => Property::DisableCapabilities,
             b"disabledPermissions" => Property::DisabledPermissions,
             b"discardAfter" => Property::DiscardAfter,
@@ -630,6 +700,7 @@ impl EnumImpl for Property {
             b"dkimSignatures" => Property::DkimSignatures,
             b"dkimStrict" => Property::DkimStrict,
             b"dkimVerify" => Property::DkimVerify,
+            b"dmarcOverrides" => Property::DmarcOverrides,
             b"dmarcPass" => Property::DmarcPass,
             b"dmarcPolicy" => Property::DmarcPolicy,
             b"dmarcResult" => Property::DmarcResult,
@@ -658,6 +729,8 @@ impl EnumImpl for Property {
             b"dsn" => Property::Dsn,
             b"due" => Property::Due,
             b"duplicateExpiry" => Property::DuplicateExpiry,
+            b"duplicateMatch" => Property::DuplicateMatch,
+            b"duplicateWindow" => Property::DuplicateWindow,
             b"duration" => Property::Duration,
             b"eabHmacKey" => Property::EabHmacKey,
             b"eabKeyId" => Property::EabKeyId,
@@ -682,12 +755,16 @@ impl EnumImpl for Property {
             b"enableLogExporter" => Property::EnableLogExporter,
             b"enableSpamFilter" => Property::EnableSpamFilter,
             b"enableSpanExporter" => Property::EnableSpanExporter,
+            b"enableUserScriptReject" => Property::EnableUserScriptReject,
             b"enabled" => Property::Enabled,
             b"enabledPermissions" => Property::EnabledPermissions,
             b"encryptAtRest" => Property::EncryptAtRest,
             b"encryptOnAppend" => Property::EncryptOnAppend,
             b"encryptionAtRest" => Property::EncryptionAtRest,
             b"encryptionKey" => Property::EncryptionKey,
+            b"encryptionKeyCacheTtl" => Property::EncryptionKeyCacheTtl,
+            b"encryptionKeyProvider" => Property::EncryptionKeyProvider,
+            b"encryptionKeys" => Property::EncryptionKeys,
             b"endpoint" => Property::Endpoint,
             b"envFrom" => Property::EnvFrom,
             b"envFromParameters" => Property::EnvFromParameters,
@@ -696,6 +773,7 @@ impl EnumImpl for Property {
             b"envelopeFrom" => Property::EnvelopeFrom,
             b"envelopeTo" => Property::EnvelopeTo,
             b"environment" => Property::Environment,
+            b"error" => Property::Error,
             b"errorCommand" => Property::ErrorCommand,
             b"errorMessage" => Property::ErrorMessage,
             b"errorType" => Property::ErrorType,
@@ -709,6 +787,7 @@ impl EnumImpl for Property {
             b"eventEndTz" => Property::EventEndTz,
             b"eventId" => Property::EventId,
             b"eventMessage" => Property::EventMessage,
+            b"eventSourceReplayWindow" => Property::EventSourceReplayWindow,
             b"eventSourceThrottle" => Property::EventSourceThrottle,
             b"eventStart" => Property::EventStart,
             b"eventStartTz" => Property::EventStartTz,
@@ -720,6 +799,8 @@ impl EnumImpl for Property {
             b"expiresAttempts" => Property::ExpiresAttempts,
             b"expiry" => Property::Expiry,
             b"expn" => Property::Expn,
+            b"expnRate" => Property::ExpnRate,
+            b"expungeDraftsAfter" => Property::ExpungeDraftsAfter,
             b"expungeSchedule" => Property::ExpungeSchedule,
             b"expungeSchedulingInboxAfter" => Property::ExpungeSchedulingInboxAfter,
             b"expungeShareNotifyAfter" => Property::ExpungeShareNotifyAfter,
@@ -772,18 +853,28 @@ impl EnumImpl for Property {
             b"greylistFor" => Property::GreylistFor,
             b"groupClass" => Property::GroupClass,
             b"groupId" => Property::GroupId,
+            b"hamMessages" => Property::HamMessages,
             b"headerFrom" => Property::HeaderFrom,
             b"headers" => Property::Headers,
+            b"holdAuditLogFor" => Property::HoldAuditLogFor,
+            b"holdBlobIssuesFor" => Property::HoldBlobIssuesFor,
+            b"holdDeliveryTracesFor" => Property::HoldDeliveryTracesFor,
+            b"holdLoginHistoryFor" => Property::HoldLoginHistoryFor,
             b"holdMetricsFor" => Property::HoldMetricsFor,
             b"holdMtaReportsFor" => Property::HoldMtaReportsFor,
             b"holdSamplesFor" => Property::HoldSamplesFor,
+            b"holdStatisticsFor" => Property::HoldStatisticsFor,
+            b"holdTenantUsageFor" => Property::HoldTenantUsageFor,
             b"holdTracesFor" => Property::HoldTracesFor,
             b"host" => Property::Host,
             b"hostedZoneId" => Property::HostedZoneId,
             b"hostname" => Property::Hostname,
+            b"hosts" => Property::Hosts,
+            b"hotStore" => Property::HotStore,
             b"hour" => Property::Hour,
             b"httpAuth" => Property::HttpAuth,
             b"httpHeaders" => Property::HttpHeaders,
+            b"httpPath" => Property::HttpPath,
             b"httpRsvpEnable" => Property::HttpRsvpEnable,
             b"httpRsvpLinkExpiry" => Property::HttpRsvpLinkExpiry,
             b"httpRsvpTemplate" => Property::HttpRsvpTemplate,
@@ -800,6 +891,7 @@ impl EnumImpl for Property {
             b"inMemoryStore" => Property::InMemoryStore,
             b"inboundReportAddresses" => Property::InboundReportAddresses,
             b"inboundReportForwarding" => Property::InboundReportForwarding,
+            b"inboundTlsFailureRate" => Property::InboundTlsFailureRate,
             b"incidents" => Property::Incidents,
             b"includeSource" => Property::IncludeSource,
             b"indexAsn" => Property::IndexAsn,
@@ -813,6 +905,7 @@ impl EnumImpl for Property {
             b"indexEmail" => Property::IndexEmail,
             b"indexEmailFields" => Property::IndexEmailFields,
             b"indexKey" => Property::IndexKey,
+            b"indexMaxBodySize" => Property::IndexMaxBodySize,
             b"indexTelemetry" => Property::IndexTelemetry,
             b"indexTracingFields" => Property::IndexTracingFields,
             b"indexValue" => Property::IndexValue,
@@ -824,6 +917,8 @@ impl EnumImpl for Property {
             b"ipLookupStrategy" => Property::IpLookupStrategy,
             b"ipRevPtr" => Property::IpRevPtr,
             b"ipRevResult" => Property::IpRevResult,
+            b"ipv6Only" => Property::Ipv6Only,
+            b"ipv6PrefixLength" => Property::Ipv6PrefixLength,
             b"isActive" => Property::IsActive,
             b"isArchive" => Property::IsArchive,
             b"isBad" => Property::IsBad,
@@ -835,6 +930,7 @@ impl EnumImpl for Property {
             b"isSenderAllowed" => Property::IsSenderAllowed,
             b"isSpam" => Property::IsSpam,
             b"isTls" => Property::IsTls,
+            b"issue" => Property::Issue,
             b"issuer" => Property::Issuer,
             b"issuerUrl" => Property::IssuerUrl,
             b"itipMaxSize" => Property::ItipMaxSize,
@@ -845,6 +941,7 @@ impl EnumImpl for Property {
             b"keyValues" => Property::KeyValues,
             b"l1Ratio" => Property::L1Ratio,
             b"l2Ratio" => Property::L2Ratio,
+            b"largeMessageSize" => Property::LargeMessageSize,
             b"lastRenewal" => Property::LastRenewal,
             b"learnHamFromCard" => Property::LearnHamFromCard,
             b"learnHamFromReply" => Property::LearnHamFromReply,
@@ -853,15 +950,18 @@ impl EnumImpl for Property {
             b"level" => Property::Level,
             b"licenseKey" => Property::LicenseKey,
             b"line" => Property::Line,
+            b"listId" => Property::ListId,
             b"listenerIds" => Property::ListenerIds,
             b"listeners" => Property::Listeners,
             b"livePropertyMaxSize" => Property::LivePropertyMaxSize,
             b"locale" => Property::Locale,
+            b"localizedNames" => Property::LocalizedNames,
             b"logo" => Property::Logo,
             b"logoUrl" => Property::LogoUrl,
             b"loiterBanPeriod" => Property::LoiterBanPeriod,
             b"loiterBanRate" => Property::LoiterBanRate,
             b"lossy" => Property::Lossy,
+            b"lowPriorityThreadsPerNode" => Property::LowPriorityThreadsPerNode,
             b"machineId" => Property::MachineId,
             b"mailExchangers" => Property::MailExchangers,
             b"mailFrom" => Property::MailFrom,
@@ -869,7 +969,9 @@ impl EnumImpl for Property {
             b"mailRua" => Property::MailRua,
             b"mailingLists" => Property::MailingLists,
             b"maintenanceType" => Property::MaintenanceType,
+            b"maintenanceWindows" => Property::MaintenanceWindows,
             b"managedZone" => Property::ManagedZone,
+            b"masterName" => Property::MasterName,
             b"match" => Property::Match,
             b"maxAddressBooks" => Property::MaxAddressBooks,
             b"maxAge" => Property::MaxAge,
@@ -880,6 +982,7 @@ impl EnumImpl for Property {
             b"maxAttempts" => Property::MaxAttempts,
             b"maxAttendees" => Property::MaxAttendees,
             b"maxAuthFailures" => Property::MaxAuthFailures,
+            b"maxBackgroundJobs" => Property::MaxBackgroundJobs,
             b"maxCalendars" => Property::MaxCalendars,
             b"maxChangesHistory" => Property::MaxChangesHistory,
             b"maxConcurrent" => Property::MaxConcurrent,
@@ -888,7 +991,10 @@ impl EnumImpl for Property {
             b"maxConnections" => Property::MaxConnections,
             b"maxContacts" => Property::MaxContacts,
             b"maxCpuCycles" => Property::MaxCpuCycles,
+            b"maxCustomKeywords" => Property::MaxCustomKeywords,
             b"maxDelay" => Property::MaxDelay,
+            b"maxDrafts" => Property::MaxDrafts,
+            b"maxDraftsSize" => Property::MaxDraftsSize,
             b"maxDuration" => Property::MaxDuration,
             b"maxEntries" => Property::MaxEntries,
             b"maxEntrySize" => Property::MaxEntrySize,
@@ -897,6 +1003,7 @@ impl EnumImpl for Property {
             b"maxFailures" => Property::MaxFailures,
             b"maxFiles" => Property::MaxFiles,
             b"maxFolders" => Property::MaxFolders,
+            b"maxHeaderCount" => Property::MaxHeaderCount,
             b"maxHeaderSize" => Property::MaxHeaderSize,
             b"maxICalendarSize" => Property::MaxICalendarSize,
             b"maxIdentities" => Property::MaxIdentities,
@@ -909,18 +1016,23 @@ impl EnumImpl for Property {
             b"maxMailboxes" => Property::MaxMailboxes,
             b"maxMaskedAddresses" => Property::MaxMaskedAddresses,
             b"maxMatchVars" => Property::MaxMatchVars,
+            b"maxMessageKeywords" => Property::MaxMessageKeywords,
             b"maxMessageSize" => Property::MaxMessageSize,
             b"maxMessages" => Property::MaxMessages,
             b"maxMethodCalls" => Property::MaxMethodCalls,
+            b"maxMimeDepth" => Property::MaxMimeDepth,
+            b"maxMimeParts" => Property::MaxMimeParts,
             b"maxMultihomed" => Property::MaxMultihomed,
             b"maxMxHosts" => Property::MaxMxHosts,
             b"maxNestedBlocks" => Property::MaxNestedBlocks,
             b"maxNestedForEvery" => Property::MaxNestedForEvery,
             b"maxNestedIncludes" => Property::MaxNestedIncludes,
             b"maxNestedTests" => Property::MaxNestedTests,
+            b"maxNotifyActions" => Property::MaxNotifyActions,
             b"maxOutMessages" => Property::MaxOutMessages,
             b"maxParticipantIdentities" => Property::MaxParticipantIdentities,
             b"maxPublicKeys" => Property::MaxPublicKeys,
+            b"maxQueueSize" => Property::MaxQueueSize,
             b"maxReceivedHeaders" => Property::MaxReceivedHeaders,
             b"maxRecipients" => Property::MaxRecipients,
             b"maxReconnects" => Property::MaxReconnects,
@@ -933,6 +1045,7 @@ impl EnumImpl for Property {
             b"maxResults" => Property::MaxResults,
             b"maxRetries" => Property::MaxRetries,
             b"maxRetryWait" => Property::MaxRetryWait,
+            b"maxRunTime" => Property::MaxRunTime,
             b"maxScriptNameLength" => Property::MaxScriptNameLength,
             b"maxScriptSize" => Property::MaxScriptSize,
             b"maxScripts" => Property::MaxScripts,
@@ -941,6 +1054,7 @@ impl EnumImpl for Property {
             b"maxStringLength" => Property::MaxStringLength,
             b"maxSubmissions" => Property::MaxSubmissions,
             b"maxSubscriptions" => Property::MaxSubscriptions,
+            b"maxTotalScriptSize" => Property::MaxTotalScriptSize,
             b"maxUploadCount" => Property::MaxUploadCount,
             b"maxUploadSize" => Property::MaxUploadSize,
             b"maxVCardSize" => Property::MaxVCardSize,
@@ -948,19 +1062,25 @@ impl EnumImpl for Property {
             b"maxVarSize" => Property::MaxVarSize,
             b"memberGroupIds" => Property::MemberGroupIds,
             b"memberTenantId" => Property::MemberTenantId,
+            b"membersOnly" => Property::MembersOnly,
             b"message" => Property::Message,
             b"messageIdHostname" => Property::MessageIdHostname,
             b"messageIds" => Property::MessageIds,
             b"messages" => Property::Messages,
+            b"messagesReceived" => Property::MessagesReceived,
+            b"messagesSent" => Property::MessagesSent,
+            b"messagesSubmitted" => Property::MessagesSubmitted,
             b"metric" => Property::Metric,
             b"metrics" => Property::Metrics,
             b"metricsCollectionInterval" => Property::MetricsCollectionInterval,
             b"metricsPolicy" => Property::MetricsPolicy,
+            b"migrationDirectory" => Property::MigrationDirectory,
             b"minHamSamples" => Property::MinHamSamples,
             b"minRetryWait" => Property::MinRetryWait,
             b"minSpamSamples" => Property::MinSpamSamples,
             b"minTriggerInterval" => Property::MinTriggerInterval,
             b"minute" => Property::Minute,
+            b"mirrorStore" => Property::MirrorStore,
             b"mode" => Property::Mode,
             b"model" => Property::Model,
             b"modelId" => Property::ModelId,
@@ -970,6 +1090,7 @@ impl EnumImpl for Property {
             b"mtaStsTimeout" => Property::MtaStsTimeout,
             b"multiline" => Property::Multiline,
             b"mustMatchSender" => Property::MustMatchSender,
+            b"mustMatchTenantDomain" => Property::MustMatchTenantDomain,
             b"mxHosts" => Property::MxHosts,
             b"name" => Property::Name,
             b"namespace" => Property::Namespace,
@@ -986,6 +1107,9 @@ impl EnumImpl for Property {
             b"notify" => Property::Notify,
             b"notifyCount" => Property::NotifyCount,
             b"notifyDue" => Property::NotifyDue,
+            b"notifyGatewayUrl" => Property::NotifyGatewayUrl,
+            b"notifySignatureKey" => Property::NotifySignatureKey,
+            b"notifyTimeout" => Property::NotifyTimeout,
             b"numFeatures" => Property::NumFeatures,
             b"numReplicas" => Property::NumReplicas,
             b"numShards" => Property::NumShards,
@@ -1006,11 +1130,13 @@ impl EnumImpl for Property {
             b"outboundReportSubmitter" => Property::OutboundReportSubmitter,
             b"overrideProxyTrustedNetworks" => Property::OverrideProxyTrustedNetworks,
             b"overrideType" => Property::OverrideType,
+            b"oversignHeaders" => Property::OversignHeaders,
             b"ovhEndpoint" => Property::OvhEndpoint,
             b"parameters" => Property::Parameters,
             b"parseLimitContact" => Property::ParseLimitContact,
             b"parseLimitEmail" => Property::ParseLimitEmail,
             b"parseLimitEvent" => Property::ParseLimitEvent,
+            b"partitionMode" => Property::PartitionMode,
             b"password" => Property::Password,
             b"passwordDefaultExpiry" => Property::PasswordDefaultExpiry,
             b"passwordHashAlgorithm" => Property::PasswordHashAlgorithm,
@@ -1018,10 +1144,12 @@ impl EnumImpl for Property {
             b"passwordMinLength" => Property::PasswordMinLength,
             b"passwordMinStrength" => Property::PasswordMinStrength,
             b"path" => Property::Path,
+            b"performedBy" => Property::PerformedBy,
             b"period" => Property::Period,
             b"permissions" => Property::Permissions,
             b"pingInterval" => Property::PingInterval,
             b"pipelining" => Property::Pipelining,
+            b"plaintextFallback" => Property::PlaintextFallback,
             b"policies" => Property::Policies,
             b"policyAdkim" => Property::PolicyAdkim,
             b"policyAspf" => Property::PolicyAspf,
@@ -1048,7 +1176,9 @@ impl EnumImpl for Property {
             b"port" => Property::Port,
             b"prefix" => Property::Prefix,
             b"preserveIntermediates" => Property::PreserveIntermediates,
+            b"primaryStore" => Property::PrimaryStore,
             b"priority" => Property::Priority,
+            b"priorityThreadsPerNode" => Property::PriorityThreadsPerNode,
             b"privateKey" => Property::PrivateKey,
             b"privateKeyPassword" => Property::PrivateKeyPassword,
             b"privateKeyPem" => Property::PrivateKeyPem,
@@ -1064,7 +1194,12 @@ impl EnumImpl for Property {
             b"protocol" => Property::Protocol,
             b"protocolVersion" => Property::ProtocolVersion,
             b"providerInfo" => Property::ProviderInfo,
+            b"proxyAddress" => Property::ProxyAddress,
+            b"proxyPort" => Property::ProxyPort,
+            b"proxyProtocol" => Property::ProxyProtocol,
+            b"proxySecret" => Property::ProxySecret,
             b"proxyTrustedNetworks" => Property::ProxyTrustedNetworks,
+            b"proxyUsername" => Property::ProxyUsername,
             b"publicKey" => Property::PublicKey,
             b"publishRecords" => Property::PublishRecords,
             b"pushAttemptWait" => Property::PushAttemptWait,
@@ -1073,7 +1208,12 @@ impl EnumImpl for Property {
             b"pushRetryWait" => Property::PushRetryWait,
             b"pushShardsTotal" => Property::PushShardsTotal,
             b"pushThrottle" => Property::PushThrottle,
+            b"pushVapidKey" => Property::PushVapidKey,
+            b"pushVapidSubject" => Property::PushVapidSubject,
             b"pushVerifyTimeout" => Property::PushVerifyTimeout,
+            b"quarantineDigestFrequency" => Property::QuarantineDigestFrequency,
+            b"quarantineFor" => Property::QuarantineFor,
+            b"query" => Property::Query,
             b"queryEmailAliases" => Property::QueryEmailAliases,
             b"queryLogin" => Property::QueryLogin,
             b"queryMaxResults" => Property::QueryMaxResults,
@@ -1084,8 +1224,13 @@ impl EnumImpl for Property {
             b"quotas" => Property::Quotas,
             b"rate" => Property::Rate,
             b"rateLimit" => Property::RateLimit,
+            b"rateLimitAdmin" => Property::RateLimitAdmin,
             b"rateLimitAnonymous" => Property::RateLimitAnonymous,
             b"rateLimitAuthenticated" => Property::RateLimitAuthenticated,
+            b"rateLimitClient" => Property::RateLimitClient,
+            b"rateLimitQuery" => Property::RateLimitQuery,
+            b"rateLimitSet" => Property::RateLimitSet,
+            b"rateLimitUpload" => Property::RateLimitUpload,
             b"ratio" => Property::Ratio,
             b"rcptToTimeout" => Property::RcptToTimeout,
             b"readFromReplicas" => Property::ReadFromReplicas,
@@ -1093,21 +1238,35 @@ impl EnumImpl for Property {
             b"reason" => Property::Reason,
             b"receivedAt" => Property::ReceivedAt,
             b"receivedFromIp" => Property::ReceivedFromIp,
+            b"receivedHeaderFormat" => Property::ReceivedHeaderFormat,
             b"receivedViaPort" => Property::ReceivedViaPort,
             b"receivingIp" => Property::ReceivingIp,
             b"receivingMxHelo" => Property::ReceivingMxHelo,
             b"receivingMxHostname" => Property::ReceivingMxHostname,
+            b"recentFolderPeriod" => Property::RecentFolderPeriod,
+            b"recipient" => Property::Recipient,
             b"recipients" => Property::Recipients,
             b"records" => Property::Records,
             b"recurrenceId" => Property::RecurrenceId,
+            b"redirectLimit" => Property::RedirectLimit,
+            b"redirectRateLimit" => Property::RedirectRateLimit,
+            b"redirectTimeout" => Property::RedirectTimeout,
             b"redirectUris" => Property::RedirectUris,
             b"refresh" => Property::Refresh,
             b"refreshTokenExpiry" => Property::RefreshTokenExpiry,
             b"refreshTokenRenewal" => Property::RefreshTokenRenewal,
             b"region" => Property::Region,
+            b"regions" => Property::Regions,
+            b"reindexBatchSize" => Property::ReindexBatchSize,
+            b"reindexInterval" => Property::ReindexInterval,
+            b"rejectBareCr" => Property::RejectBareCr,
+            b"rejectBareLf" => Property::RejectBareLf,
             b"rejectNonFqdn" => Property::RejectNonFqdn,
             b"remoteIp" => Property::RemoteIp,
             b"renewBefore" => Property::RenewBefore,
+            b"repairMime" => Property::RepairMime,
+            b"repaired" => Property::Repaired,
+            b"replyTo" => Property::ReplyTo,
             b"report" => Property::Report,
             b"reportAddressUri" => Property::ReportAddressUri,
             b"reportId" => Property::ReportId,
@@ -1119,9 +1278,11 @@ impl EnumImpl for Property {
             b"require" => Property::Require,
             b"requireAudience" => Property::RequireAudience,
             b"requireClientRegistration" => Property::RequireClientRegistration,
+            b"requireMfa" => Property::RequireMfa,
             b"requireScopes" => Property::RequireScopes,
             b"requireTls" => Property::RequireTls,
             b"reservoirCapacity" => Property::ReservoirCapacity,
+            b"resolvedIps" => Property::ResolvedIps,
             b"resourceGroup" => Property::ResourceGroup,
             b"resourceUrl" => Property::ResourceUrl,
             b"responseCode" => Property::ResponseCode,
@@ -1134,6 +1295,7 @@ impl EnumImpl for Property {
             b"responsePosExplanation" => Property::ResponsePosExplanation,
             b"result" => Property::Result,
             b"resultType" => Property::ResultType,
+            b"results" => Property::Results,
             b"retireAfter" => Property::RetireAfter,
             b"retry" => Property::Retry,
             b"retryCount" => Property::RetryCount,
@@ -1141,6 +1303,7 @@ impl EnumImpl for Property {
             b"returnPath" => Property::ReturnPath,
             b"reverseIpVerify" => Property::ReverseIpVerify,
             b"rewrite" => Property::Rewrite,
+            b"role" => Property::Role,
             b"roleIds" => Property::RoleIds,
             b"roles" => Property::Roles,
             b"rotate" => Property::Rotate,
@@ -1159,9 +1322,12 @@ impl EnumImpl for Property {
             b"scope" => Property::Scope,
             b"score" => Property::Score,
             b"scoreDiscard" => Property::ScoreDiscard,
+            b"scoreQuarantine" => Property::ScoreQuarantine,
             b"scoreReject" => Property::ScoreReject,
             b"scoreSpam" => Property::ScoreSpam,
             b"script" => Property::Script,
+            b"scrubTraceHeaders" => Property::ScrubTraceHeaders,
+            b"searchFolders" => Property::SearchFolders,
             b"searchStore" => Property::SearchStore,
             b"secret" => Property::Secret,
             b"secretAccessKey" => Property::SecretAccessKey,
@@ -1172,6 +1338,9 @@ impl EnumImpl for Property {
             b"selector" => Property::Selector,
             b"selectorTemplate" => Property::SelectorTemplate,
             b"sendFrequency" => Property::SendFrequency,
+            b"senderDomain" => Property::SenderDomain,
+            b"senderMismatchAction" => Property::SenderMismatchAction,
+            b"senderRewrite" => Property::SenderRewrite,
             b"sendingMtaIp" => Property::SendingMtaIp,
             b"separator" => Property::Separator,
             b"serverHostname" => Property::ServerHostname,
@@ -1181,7 +1350,9 @@ impl EnumImpl for Property {
             b"sessionToken" => Property::SessionToken,
             b"setMaxObjects" => Property::SetMaxObjects,
             b"shardIndex" => Property::ShardIndex,
+            b"sharedMailboxDestroy" => Property::SharedMailboxDestroy,
             b"sharedSecret" => Property::SharedSecret,
+            b"showVirtualFolders" => Property::ShowVirtualFolders,
             b"sig0Algorithm" => Property::Sig0Algorithm,
             b"signatureAlgorithm" => Property::SignatureAlgorithm,
             b"signatureKey" => Property::SignatureKey,
@@ -1189,7 +1360,10 @@ impl EnumImpl for Property {
             b"size" => Property::Size,
             b"skipDeploy" => Property::SkipDeploy,
             b"skipFirst" => Property::SkipFirst,
+            b"smarthost" => Property::Smarthost,
             b"smtpGreeting" => Property::SmtpGreeting,
+            b"smugglingBanRate" => Property::SmugglingBanRate,
+            b"snippetMaxLength" => Property::SnippetMaxLength,
             b"snippetMaxResults" => Property::SnippetMaxResults,
             b"socketBacklog" => Property::SocketBacklog,
             b"socketNoDelay" => Property::SocketNoDelay,
@@ -1203,6 +1377,7 @@ impl EnumImpl for Property {
             b"sourceIps" => Property::SourceIps,
             b"sourcePort" => Property::SourcePort,
             b"spamFilterRulesUrl" => Property::SpamFilterRulesUrl,
+            b"spamMessages" => Property::SpamMessages,
             b"spfDns" => Property::SpfDns,
             b"spfEhloDomain" => Property::SpfEhloDomain,
             b"spfEhloResult" => Property::SpfEhloResult,
@@ -1217,14 +1392,21 @@ impl EnumImpl for Property {
             b"startTls" => Property::StartTls,
             b"status" => Property::Status,
             b"storageAccount" => Property::StorageAccount,
+            b"storageUsed" => Property::StorageUsed,
             b"store" => Property::Store,
+            b"storedBytes" => Property::StoredBytes,
             b"stores" => Property::Stores,
             b"strategy" => Property::Strategy,
+            b"structureLimitAction" => Property::StructureLimitAction,
             b"subAddressing" => Property::SubAddressing,
             b"subAuthId" => Property::SubAuthId,
             b"subject" => Property::Subject,
             b"subjectAlternativeNames" => Property::SubjectAlternativeNames,
+            b"subjectPrefix" => Property::SubjectPrefix,
             b"subscribe" => Property::Subscribe,
+            b"subscribeOnAutoCreate" => Property::SubscribeOnAutoCreate,
+            b"subscribeOnImapCreate" => Property::SubscribeOnImapCreate,
+            b"subscribeOnJmapCreate" => Property::SubscribeOnJmapCreate,
             b"subscriptionId" => Property::SubscriptionId,
             b"sum" => Property::Sum,
             b"summary" => Property::Summary,
@@ -1239,6 +1421,7 @@ impl EnumImpl for Property {
             b"temperature" => Property::Temperature,
             b"template" => Property::Template,
             b"tenancyOcid" => Property::TenancyOcid,
+            b"tenantDomainExemptions" => Property::TenantDomainExemptions,
             b"tenantId" => Property::TenantId,
             b"tenants" => Property::Tenants,
             b"text" => Property::Text,
@@ -1264,16 +1447,28 @@ impl EnumImpl for Property {
             b"timestamp" => Property::Timestamp,
             b"title" => Property::Title,
             b"tls" => Property::Tls,
+            b"tlsCipher" => Property::TlsCipher,
+            b"tlsClientAuth" => Property::TlsClientAuth,
+            b"tlsClientCa" => Property::TlsClientCa,
             b"tlsDisableCipherSuites" => Property::TlsDisableCipherSuites,
+            b"tlsDisableKeyExchangeGroups" => Property::TlsDisableKeyExchangeGroups,
             b"tlsDisableProtocols" => Property::TlsDisableProtocols,
+            b"tlsFingerprint" => Property::TlsFingerprint,
             b"tlsIgnoreClientOrder" => Property::TlsIgnoreClientOrder,
             b"tlsImplicit" => Property::TlsImplicit,
+            b"tlsPreferPostQuantum" => Property::TlsPreferPostQuantum,
+            b"tlsProfile" => Property::TlsProfile,
+            b"tlsServerName" => Property::TlsServerName,
             b"tlsTimeout" => Property::TlsTimeout,
+            b"tlsVersion" => Property::TlsVersion,
             b"to" => Property::To,
             b"token" => Property::Token,
+            b"topRecipients" => Property::TopRecipients,
+            b"topSenders" => Property::TopSenders,
             b"totalDeadline" => Property::TotalDeadline,
             b"totalFailedSessions" => Property::TotalFailedSessions,
             b"totalSuccessfulSessions" => Property::TotalSuccessfulSessions,
+            b"traceHeaders" => Property::TraceHeaders,
             b"traceId" => Property::TraceId,
             b"tracer" => Property::Tracer,
             b"trainFrequency" => Property::TrainFrequency,
@@ -1285,6 +1480,7 @@ impl EnumImpl for Property {
             b"trustReplies" => Property::TrustReplies,
             b"tsigAlgorithm" => Property::TsigAlgorithm,
             b"ttl" => Property::Ttl,
+            b"undoSendWindow" => Property::UndoSendWindow,
             b"unpackDirectory" => Property::UnpackDirectory,
             b"updateRecords" => Property::UpdateRecords,
             b"uploadQuota" => Property::UploadQuota,
@@ -1293,6 +1489,7 @@ impl EnumImpl for Property {
             b"urlLimit" => Property::UrlLimit,
             b"urlPrefix" => Property::UrlPrefix,
             b"urls" => Property::Urls,
+            b"useHttps" => Property::UseHttps,
             b"usePermissiveCors" => Property::UsePermissiveCors,
             b"useTls" => Property::UseTls,
             b"useXForwarded" => Property::UseXForwarded,
@@ -1308,12 +1505,15 @@ impl EnumImpl for Property {
             b"verifyAfterWrite" => Property::VerifyAfterWrite,
             b"version" => Property::Version,
             b"viewName" => Property::ViewName,
+            b"virtualFolders" => Property::VirtualFolders,
             b"vrfy" => Property::Vrfy,
+            b"vrfyRate" => Property::VrfyRate,
             b"waitOnFail" => Property::WaitOnFail,
             b"wapiVersion" => Property::WapiVersion,
             b"websocketHeartbeat" => Property::WebsocketHeartbeat,
             b"websocketThrottle" => Property::WebsocketThrottle,
             b"websocketTimeout" => Property::WebsocketTimeout,
+            b"writeMode" => Property::WriteMode,
             b"zone" => Property::Zone,
             b"zoneIpV4" => Property::ZoneIpV4,
             b"zoneIpV6" => Property::ZoneIpV6,
@@ -1330,6 +1530,7 @@ impl EnumImpl for Property {
             Property::AccessToken => "accessToken",
             Property::AccessTokenExpiry => "accessTokenExpiry",
             Property::AccessTokens => "accessTokens",
+            Property::AccountBandwidth => "accountBandwidth",
             Property::AccountDomainId => "accountDomainId",
             Property::AccountId => "accountId",
             Property::AccountIdentifier => "accountIdentifier",
@@ -1368,20 +1569,31 @@ impl EnumImpl for Property {
             Property::AllowPlainTextAuth => "allowPlainTextAuth",
             Property::AllowRelaying => "allowRelaying",
             Property::AllowSpamTraining => "allowSpamTraining",
+            Property::AllowedAsns => "allowedAsns",
+            Property::AllowedCountries => "allowedCountries",
             Property::AllowedEndpoints => "allowedEndpoints",
+            Property::AllowedExtLists => "allowedExtLists",
+            Property::AllowedGrantTypes => "allowedGrantTypes",
             Property::AllowedIps => "allowedIps",
+            Property::AllowedNetworks => "allowedNetworks",
             Property::AllowedNotifyUris => "allowedNotifyUris",
+            Property::AllowedScopes => "allowedScopes",
             Property::Alpha => "alpha",
             Property::AnonymousClientRegistration => "anonymousClientRegistration",
             Property::Ansi => "ansi",
+            Property::ApiCalls => "apiCalls",
             Property::ApiKey => "apiKey",
             Property::ApiUser => "apiUser",
+            Property::AppendSieve => "appendSieve",
+            Property::AppendSpamFilter => "appendSpamFilter",
             Property::ApplicationKey => "applicationKey",
             Property::ApplicationSecret => "applicationSecret",
             Property::ArcResult => "arcResult",
             Property::ArcVerify => "arcVerify",
+            Property::ArchiveAccountId => "archiveAccountId",
             Property::ArchiveDeletedAccountsFor => "archiveDeletedAccountsFor",
             Property::ArchiveDeletedItemsFor => "archiveDeletedItemsFor",
+            Property::ArchiveFolder => "archiveFolder",
             Property::ArchivedAt => "archivedAt",
             Property::ArchivedItemType => "archivedItemType",
             Property::ArchivedUntil => "archivedUntil",
@@ -1402,7 +1614,11 @@ impl EnumImpl for Property {
             Property::AuthBanRate => "authBanRate",
             Property::AuthCodeExpiry => "authCodeExpiry",
             Property::AuthCodeMaxAttempts => "authCodeMaxAttempts",
+            Property::AuthDelay => "authDelay",
+            Property::AuthDelayMax => "authDelayMax",
+            Property::AuthDelayReset => "authDelayReset",
             Property::AuthFailure => "authFailure",
+            Property::AuthFailures => "authFailures",
             Property::AuthId => "authId",
             Property::AuthSecret => "authSecret",
             Property::AuthToken => "authToken",
@@ -1410,7 +1626,9 @@ impl EnumImpl for Property {
             Property::AuthenticatedAs => "authenticatedAs",
             Property::AuthenticationResults => "authenticationResults",
             Property::AutoAddInvitations => "autoAddInvitations",
+            Property::AutoArchiveAfter => "autoArchiveAfter",
             Property::AutoUpdateFrequency => "autoUpdateFrequency",
+            Property::Bandwidth => "bandwidth",
             Property::BaseDn => "baseDn",
             Property::BaseUrl => "baseUrl",
             Property::BearerToken => "bearerToken",
@@ -1420,7 +1638,12 @@ impl EnumImpl for Property {
             Property::BindDn => "bindDn",
             Property::BindSecret => "bindSecret",
             Property::BlobCleanupSchedule => "blobCleanupSchedule",
+            Property::BlobCount => "blobCount",
+            Property::BlobHash => "blobHash",
             Property::BlobId => "blobId",
+            Property::BlobReferences => "blobReferences",
+            Property::BlobScrubInterval => "blobScrubInterval",
+            Property::BlobScrubRepair => "blobScrubRepair",
             Property::BlobSize => "blobSize",
             Property::BlobStore => "blobStore",
             Property::BlockCount => "blockCount",
@@ -1429,10 +1652,19 @@ impl EnumImpl for Property {
             Property::Bucket => "bucket",
             Property::BufferSize => "bufferSize",
             Property::Buffered => "buffered",
+            Property::Burst => "burst",
+            Property::BytesReceived => "bytesReceived",
+            Property::BytesSent => "bytesSent",
+            Property::Callout => "callout",
+            Property::CalloutCacheTtl => "calloutCacheTtl",
+            Property::CalloutNegativeCacheTtl => "calloutNegativeCacheTtl",
+            Property::CalloutTimeout => "calloutTimeout",
             Property::Canonicalization => "canonicalization",
+            Property::Capabilities => "capabilities",
             Property::CapacityClient => "capacityClient",
             Property::CapacityReadBuffer => "capacityReadBuffer",
             Property::CapacitySubscription => "capacitySubscription",
+            Property::CatchAll => "catchAll",
             Property::CatchAllAddress => "catchAllAddress",
             Property::Categories => "categories",
             Property::Certificate => "certificate",
@@ -1446,14 +1678,19 @@ impl EnumImpl for Property {
             Property::Cleartext => "cleartext",
             Property::ClientId => "clientId",
             Property::ClientIp => "clientIp",
+            Property::ClientPolicy => "clientPolicy",
             Property::ClientSecret => "clientSecret",
             Property::ClientToken => "clientToken",
             Property::ClusterFile => "clusterFile",
+            Property::ColdStorageAfter => "coldStorageAfter",
+            Property::ColdStorageArchive => "coldStorageArchive",
+            Property::ColdStore => "coldStore",
             Property::ColumnClass => "columnClass",
             Property::ColumnDescription => "columnDescription",
             Property::ColumnEmail => "columnEmail",
             Property::ColumnSecret => "columnSecret",
             Property::Comment => "comment",
+            Property::CompactionRateLimit => "compactionRateLimit",
             Property::CompartmentOcid => "compartmentOcid",
             Property::CompressionAlgorithm => "compressionAlgorithm",
             Property::Concurrency => "concurrency",
@@ -1485,6 +1722,7 @@ impl EnumImpl for Property {
             Property::CustomerNumber => "customerNumber",
             Property::Dane => "dane",
             Property::DataCleanupSchedule => "dataCleanupSchedule",
+            Property::DataRegion => "dataRegion",
             Property::DataStore => "dataStore",
             Property::DataTimeout => "dataTimeout",
             Property::Database => "database",
@@ -1494,6 +1732,7 @@ impl EnumImpl for Property {
             Property::DateRangeStart => "dateRangeStart",
             Property::Day => "day",
             Property::DeadPropertyMaxSize => "deadPropertyMaxSize",
+            Property::DeduplicatedBytes => "deduplicatedBytes",
             Property::DefaultAdminRoleIds => "defaultAdminRoleIds",
             Property::DefaultCertificateId => "defaultCertificateId",
             Property::DefaultDisplayName => "defaultDisplayName",
@@ -1509,6 +1748,7 @@ impl EnumImpl for Property {
             Property::DefaultHrefName => "defaultHrefName",
             Property::DefaultLanguage => "defaultLanguage",
             Property::DefaultName => "defaultName",
+            Property::DefaultRegion => "defaultRegion",
             Property::DefaultReturnPath => "defaultReturnPath",
             Property::DefaultSubject => "defaultSubject",
             Property::DefaultSubjectPrefix => "defaultSubjectPrefix",
@@ -1520,13 +1760,16 @@ impl EnumImpl for Property {
             Property::DeleteAfterUse => "deleteAfterUse",
             Property::DeliverAt => "deliverAt",
             Property::DeliverBy => "deliverBy",
+            Property::DeliverLocal => "deliverLocal",
             Property::DeliverTo => "deliverTo",
+            Property::DeliveryMode => "deliveryMode",
             Property::DeliveryResult => "deliveryResult",
             Property::Depth => "depth",
             Property::Description => "description",
             Property::Details => "details",
             Property::Directory => "directory",
             Property::DirectoryId => "directoryId",
+            Property::DirectoryPrefix => "directoryPrefix",
             Property::DisableCapabilities => "disableCapabilities",
             Property::DisabledPermissions => "disabledPermissions",
             Property::DiscardAfter => "discardAfter",
@@ -1545,6 +1788,7 @@ impl EnumImpl for Property {
             Property::DkimSignatures => "dkimSignatures",
             Property::DkimStrict => "dkimStrict",
             Property::DkimVerify => "dkimVerify",
+            Property::DmarcOverrides => "dmarcOverrides",
             Property::DmarcPass => "dmarcPass",
             Property::DmarcPolicy => "dmarcPolicy",
             Property::DmarcResult => "dmarcResult",
@@ -1573,6 +1817,8 @@ impl EnumImpl for Property {
             Property::Dsn => "dsn",
             Property::Due => "due",
             Property::DuplicateExpiry => "duplicateExpiry",
+            Property::DuplicateMatch => "duplicateMatch",
+            Property::DuplicateWindow => "duplicateWindow",
             Property::Duration => "duration",
             Property::EabHmacKey => "eabHmacKey",
             Property::EabKeyId => "eabKeyId",
@@ -1597,12 +1843,16 @@ impl EnumImpl for Property {
             Property::EnableLogExporter => "enableLogExporter",
             Property::EnableSpamFilter => "enableSpamFilter",
             Property::EnableSpanExporter => "enableSpanExporter",
+            Property::EnableUserScriptReject => "enableUserScriptReject",
             Property::Enabled => "enabled",
             Property::EnabledPermissions => "enabledPermissions",
             Property::EncryptAtRest => "encryptAtRest",
             Property::EncryptOnAppend => "encryptOnAppend",
             Property::EncryptionAtRest => "encryptionAtRest",
             Property::EncryptionKey => "encryptionKey",
+            Property::EncryptionKeyCacheTtl => "encryptionKeyCacheTtl",
+            Property::EncryptionKeyProvider => "encryptionKeyProvider",
+            Property::EncryptionKeys => "encryptionKeys",
             Property::Endpoint => "endpoint",
             Property::EnvFrom => "envFrom",
             Property::EnvFromParameters => "envFromParameters",
@@ -1611,6 +1861,7 @@ impl EnumImpl for Property {
             Property::EnvelopeFrom => "envelopeFrom",
             Property::EnvelopeTo => "envelopeTo",
             Property::Environment => "environment",
+            Property::Error => "error",
             Property::ErrorCommand => "errorCommand",
             Property::ErrorMessage => "errorMessage",
             Property::ErrorType => "errorType",
@@ -1624,6 +1875,7 @@ impl EnumImpl for Property {
             Property::EventEndTz => "eventEndTz",
             Property::EventId => "eventId",
             Property::EventMessage => "eventMessage",
+            Property::EventSourceReplayWindow => "eventSourceReplayWindow",
             Property::EventSourceThrottle => "eventSourceThrottle",
             Property::EventStart => "eventStart",
             Property::EventStartTz => "eventStartTz",
@@ -1635,6 +1887,8 @@ impl EnumImpl for Property {
             Property::ExpiresAttempts => "expiresAttempts",
             Property::Expiry => "expiry",
             Property::Expn => "expn",
+            Property::ExpnRate => "expnRate",
+            Property::ExpungeDraftsAfter => "expungeDraftsAfter",
             Property::ExpungeSchedule => "expungeSchedule",
             Property::ExpungeSchedulingInboxAfter => "expungeSchedulingInboxAfter",
             Property::ExpungeShareNotifyAfter => "expungeShareNotifyAfter",
@@ -1687,18 +1941,28 @@ impl EnumImpl for Property {
             Property::GreylistFor => "greylistFor",
             Property::GroupClass => "groupClass",
             Property::GroupId => "groupId",
+            Property::HamMessages => "hamMessages",
             Property::HeaderFrom => "headerFrom",
             Property::Headers => "headers",
+            Property::HoldAuditLogFor => "holdAuditLogFor",
+            Property::HoldBlobIssuesFor => "holdBlobIssuesFor",
+            Property::HoldDeliveryTracesFor => "holdDeliveryTracesFor",
+            Property::HoldLoginHistoryFor => "holdLoginHistoryFor",
             Property::HoldMetricsFor => "holdMetricsFor",
             Property::HoldMtaReportsFor => "holdMtaReportsFor",
             Property::HoldSamplesFor => "holdSamplesFor",
+            Property::HoldStatisticsFor => "holdStatisticsFor",
+            Property::HoldTenantUsageFor => "holdTenantUsageFor",
             Property::HoldTracesFor => "holdTracesFor",
             Property::Host => "host",
             Property::HostedZoneId => "hostedZoneId",
             Property::Hostname => "hostname",
+            Property::Hosts => "hosts",
+            Property::HotStore => "hotStore",
             Property::Hour => "hour",
             Property::HttpAuth => "httpAuth",
             Property::HttpHeaders => "httpHeaders",
+            Property::HttpPath => "httpPath",
             Property::HttpRsvpEnable => "httpRsvpEnable",
             Property::HttpRsvpLinkExpiry => "httpRsvpLinkExpiry",
             Property::HttpRsvpTemplate => "httpRsvpTemplate",
@@ -1715,6 +1979,7 @@ impl EnumImpl for Property {
             Property::InMemoryStore => "inMemoryStore",
             Property::InboundReportAddresses => "inboundReportAddresses",
             Property::InboundReportForwarding => "inboundReportForwarding",
+            Property::InboundTlsFailureRate => "inboundTlsFailureRate",
             Property::Incidents => "incidents",
             Property::IncludeSource => "includeSource",
             Property::IndexAsn => "indexAsn",
@@ -1728,6 +1993,7 @@ impl EnumImpl for Property {
             Property::IndexEmail => "indexEmail",
             Property::IndexEmailFields => "indexEmailFields",
             Property::IndexKey => "indexKey",
+            Property::IndexMaxBodySize => "indexMaxBodySize",
             Property::IndexTelemetry => "indexTelemetry",
             Property::IndexTracingFields => "indexTracingFields",
             Property::IndexValue => "indexValue",
@@ -1739,6 +2005,8 @@ impl EnumImpl for Property {
             Property::IpLookupStrategy => "ipLookupStrategy",
             Property::IpRevPtr => "ipRevPtr",
             Property::IpRevResult => "ipRevResult",
+            Property::Ipv6Only => "ipv6Only",
+            Property::Ipv6PrefixLength => "ipv6PrefixLength",
             Property::IsActive => "isActive",
             Property::IsArchive => "isArchive",
             Property::IsBad => "isBad",
@@ -1750,6 +2018,7 @@ impl EnumImpl for Property {
             Property::IsSenderAllowed => "isSenderAllowed",
             Property::IsSpam => "isSpam",
             Property::IsTls => "isTls",
+            Property::Issue => "issue",
             Property::Issuer => "issuer",
             Property::IssuerUrl => "issuerUrl",
             Property::ItipMaxSize => "itipMaxSize",
@@ -1760,6 +2029,7 @@ impl EnumImpl for Property {
             Property::KeyValues => "keyValues",
             Property::L1Ratio => "l1Ratio",
             Property::L2Ratio => "l2Ratio",
+            Property::LargeMessageSize => "largeMessageSize",
             Property::LastRenewal => "lastRenewal",
             Property::LearnHamFromCard => "learnHamFromCard",
             Property::LearnHamFromReply => "learnHamFromReply",
@@ -1768,15 +2038,18 @@ impl EnumImpl for Property {
             Property::Level => "level",
             Property::LicenseKey => "licenseKey",
             Property::Line => "line",
+            Property::ListId => "listId",
             Property::ListenerIds => "listenerIds",
             Property::Listeners => "listeners",
             Property::LivePropertyMaxSize => "livePropertyMaxSize",
             Property::Locale => "locale",
+            Property::LocalizedNames => "localizedNames",
             Property::Logo => "logo",
             Property::LogoUrl => "logoUrl",
             Property::LoiterBanPeriod => "loiterBanPeriod",
             Property::LoiterBanRate => "loiterBanRate",
             Property::Lossy => "lossy",
+            Property::LowPriorityThreadsPerNode => "lowPriorityThreadsPerNode",
             Property::MachineId => "machineId",
             Property::MailExchangers => "mailExchangers",
             Property::MailFrom => "mailFrom",
@@ -1784,7 +2057,9 @@ impl EnumImpl for Property {
             Property::MailRua => "mailRua",
             Property::MailingLists => "mailingLists",
             Property::MaintenanceType => "maintenanceType",
+            Property::MaintenanceWindows => "maintenanceWindows",
             Property::ManagedZone => "managedZone",
+            Property::MasterName => "masterName",
             Property::Match => "match",
             Property::MaxAddressBooks => "maxAddressBooks",
             Property::MaxAge => "maxAge",
@@ -1795,6 +2070,7 @@ impl EnumImpl for Property {
             Property::MaxAttempts => "maxAttempts",
             Property::MaxAttendees => "maxAttendees",
             Property::MaxAuthFailures => "maxAuthFailures",
+            Property::MaxBackgroundJobs => "maxBackgroundJobs",
             Property::MaxCalendars => "maxCalendars",
             Property::MaxChangesHistory => "maxChangesHistory",
             Property::MaxConcurrent => "maxConcurrent",
@@ -1803,7 +2079,10 @@ impl EnumImpl for Property {
             Property::MaxConnections => "maxConnections",
             Property::MaxContacts => "maxContacts",
             Property::MaxCpuCycles => "maxCpuCycles",
+            Property::MaxCustomKeywords => "maxCustomKeywords",
             Property::MaxDelay => "maxDelay",
+            Property::MaxDrafts => "maxDrafts",
+            Property::MaxDraftsSize => "maxDraftsSize",
             Property::MaxDuration => "maxDuration",
             Property::MaxEntries => "maxEntries",
             Property::MaxEntrySize => "maxEntrySize",
@@ -1812,6 +2091,7 @@ impl EnumImpl for Property {
             Property::MaxFailures => "maxFailures",
             Property::MaxFiles => "maxFiles",
             Property::MaxFolders => "maxFolders",
+            Property::MaxHeaderCount => "maxHeaderCount",
             Property::MaxHeaderSize => "maxHeaderSize",
             Property::MaxICalendarSize => "maxICalendarSize",
             Property::MaxIdentities => "maxIdentities",
@@ -1824,18 +2104,23 @@ impl EnumImpl for Property {
             Property::MaxMailboxes => "maxMailboxes",
             Property::MaxMaskedAddresses => "maxMaskedAddresses",
             Property::MaxMatchVars => "maxMatchVars",
+            Property::MaxMessageKeywords => "maxMessageKeywords",
             Property::MaxMessageSize => "maxMessageSize",
             Property::MaxMessages => "maxMessages",
             Property::MaxMethodCalls => "maxMethodCalls",
+            Property::MaxMimeDepth => "maxMimeDepth",
+            Property::MaxMimeParts => "maxMimeParts",
             Property::MaxMultihomed => "maxMultihomed",
             Property::MaxMxHosts => "maxMxHosts",
             Property::MaxNestedBlocks => "maxNestedBlocks",
             Property::MaxNestedForEvery => "maxNestedForEvery",
             Property::MaxNestedIncludes => "maxNestedIncludes",
             Property::MaxNestedTests => "maxNestedTests",
+            Property::MaxNotifyActions => "maxNotifyActions",
             Property::MaxOutMessages => "maxOutMessages",
             Property::MaxParticipantIdentities => "maxParticipantIdentities",
             Property::MaxPublicKeys => "maxPublicKeys",
+            Property::MaxQueueSize => "maxQueueSize",
             Property::MaxReceivedHeaders => "maxReceivedHeaders",
             Property::MaxRecipients => "maxRecipients",
             Property::MaxReconnects => "maxReconnects",
@@ -1848,6 +2133,7 @@ impl EnumImpl for Property {
             Property::MaxResults => "maxResults",
             Property::MaxRetries => "maxRetries",
             Property::MaxRetryWait => "maxRetryWait",
+            Property::MaxRunTime => "maxRunTime",
             Property::MaxScriptNameLength => "maxScriptNameLength",
             Property::MaxScriptSize => "maxScriptSize",
             Property::MaxScripts => "maxScripts",
@@ -1856,6 +2142,7 @@ impl EnumImpl for Property {
             Property::MaxStringLength => "maxStringLength",
             Property::MaxSubmissions => "maxSubmissions",
             Property::MaxSubscriptions => "maxSubscriptions",
+            Property::MaxTotalScriptSize => "maxTotalScriptSize",
             Property::MaxUploadCount => "maxUploadCount",
             Property::MaxUploadSize => "maxUploadSize",
             Property::MaxVCardSize => "maxVCardSize",
@@ -1863,19 +2150,25 @@ impl EnumImpl for Property {
             Property::MaxVarSize => "maxVarSize",
             Property::MemberGroupIds => "memberGroupIds",
             Property::MemberTenantId => "memberTenantId",
+            Property::MembersOnly => "membersOnly",
             Property::Message => "message",
             Property::MessageIdHostname => "messageIdHostname",
             Property::MessageIds => "messageIds",
             Property::Messages => "messages",
+            Property::MessagesReceived => "messagesReceived",
+            Property::MessagesSent => "messagesSent",
+            Property::MessagesSubmitted => "messagesSubmitted",
             Property::Metric => "metric",
             Property::Metrics => "metrics",
             Property::MetricsCollectionInterval => "metricsCollectionInterval",
             Property::MetricsPolicy => "metricsPolicy",
+            Property::MigrationDirectory => "migrationDirectory",
             Property::MinHamSamples => "minHamSamples",
             Property::MinRetryWait => "minRetryWait",
             Property::MinSpamSamples => "minSpamSamples",
             Property::MinTriggerInterval => "minTriggerInterval",
             Property::Minute => "minute",
+            Property::MirrorStore => "mirrorStore",
             Property::Mode => "mode",
             Property::Model => "model",
             Property::ModelId => "modelId",
@@ -1885,6 +2178,7 @@ impl EnumImpl for Property {
             Property::MtaStsTimeout => "mtaStsTimeout",
             Property::Multiline => "multiline",
             Property::MustMatchSender => "mustMatchSender",
+            Property::MustMatchTenantDomain => "mustMatchTenantDomain",
             Property::MxHosts => "mxHosts",
             Property::Name => "name",
             Property::Namespace => "namespace",
@@ -1901,6 +2195,9 @@ impl EnumImpl for Property {
             Property::Notify => "notify",
             Property::NotifyCount => "notifyCount",
             Property::NotifyDue => "notifyDue",
+            Property::NotifyGatewayUrl => "notifyGatewayUrl",
+            Property::NotifySignatureKey => "notifySignatureKey",
+            Property::NotifyTimeout => "notifyTimeout",
             Property::NumFeatures => "numFeatures",
             Property::NumReplicas => "numReplicas",
             Property::NumShards => "numShards",
@@ -1921,11 +2218,13 @@ impl EnumImpl for Property {
             Property::OutboundReportSubmitter => "outboundReportSubmitter",
             Property::OverrideProxyTrustedNetworks => "overrideProxyTrustedNetworks",
             Property::OverrideType => "overrideType",
+            Property::OversignHeaders => "oversignHeaders",
             Property::OvhEndpoint => "ovhEndpoint",
             Property::Parameters => "parameters",
             Property::ParseLimitContact => "parseLimitContact",
             Property::ParseLimitEmail => "parseLimitEmail",
             Property::ParseLimitEvent => "parseLimitEvent",
+            Property::PartitionMode => "partitionMode",
             Property::Password => "password",
             Property::PasswordDefaultExpiry => "passwordDefaultExpiry",
             Property::PasswordHashAlgorithm => "passwordHashAlgorithm",
@@ -1933,10 +2232,12 @@ impl EnumImpl for Property {
             Property::PasswordMinLength => "passwordMinLength",
             Property::PasswordMinStrength => "passwordMinStrength",
             Property::Path => "path",
+            Property::PerformedBy => "performedBy",
             Property::Period => "period",
             Property::Permissions => "permissions",
             Property::PingInterval => "pingInterval",
             Property::Pipelining => "pipelining",
+            Property::PlaintextFallback => "plaintextFallback",
             Property::Policies => "policies",
             Property::PolicyAdkim => "policyAdkim",
             Property::PolicyAspf => "policyAspf",
@@ -1963,7 +2264,9 @@ impl EnumImpl for Property {
             Property::Port => "port",
             Property::Prefix => "prefix",
             Property::PreserveIntermediates => "preserveIntermediates",
+            Property::PrimaryStore => "primaryStore",
             Property::Priority => "priority",
+            Property::PriorityThreadsPerNode => "priorityThreadsPerNode",
             Property::PrivateKey => "privateKey",
             Property::PrivateKeyPassword => "privateKeyPassword",
             Property::PrivateKeyPem => "privateKeyPem",
@@ -1979,7 +2282,12 @@ impl EnumImpl for Property {
             Property::Protocol => "protocol",
             Property::ProtocolVersion => "protocolVersion",
             Property::ProviderInfo => "providerInfo",
+            Property::ProxyAddress => "proxyAddress",
+            Property::ProxyPort => "proxyPort",
+            Property::ProxyProtocol => "proxyProtocol",
+            Property::ProxySecret => "proxySecret",
             Property::ProxyTrustedNetworks => "proxyTrustedNetworks",
+            Property::ProxyUsername => "proxyUsername",
             Property::PublicKey => "publicKey",
             Property::PublishRecords => "publishRecords",
             Property::PushAttemptWait => "pushAttemptWait",
@@ -1988,7 +2296,12 @@ impl EnumImpl for Property {
             Property::PushRetryWait => "pushRetryWait",
             Property::PushShardsTotal => "pushShardsTotal",
             Property::PushThrottle => "pushThrottle",
+            Property::PushVapidKey => "pushVapidKey",
+            Property::PushVapidSubject => "pushVapidSubject",
             Property::PushVerifyTimeout => "pushVerifyTimeout",
+            Property::QuarantineDigestFrequency => "quarantineDigestFrequency",
+            Property::QuarantineFor => "quarantineFor",
+            Property::Query => "query",
             Property::QueryEmailAliases => "queryEmailAliases",
             Property::QueryLogin => "queryLogin",
             Property::QueryMaxResults => "queryMaxResults",
@@ -1999,8 +2312,13 @@ impl EnumImpl for Property {
             Property::Quotas => "quotas",
             Property::Rate => "rate",
             Property::RateLimit => "rateLimit",
+            Property::RateLimitAdmin => "rateLimitAdmin",
             Property::RateLimitAnonymous => "rateLimitAnonymous",
             Property::RateLimitAuthenticated => "rateLimitAuthenticated",
+            Property::RateLimitClient => "rateLimitClient",
+            Property::RateLimitQuery => "rateLimitQuery",
+            Property::RateLimitSet => "rateLimitSet",
+            Property::RateLimitUpload => "rateLimitUpload",
             Property::Ratio => "ratio",
             Property::RcptToTimeout => "rcptToTimeout",
             Property::ReadFromReplicas => "readFromReplicas",
@@ -2008,21 +2326,35 @@ impl EnumImpl for Property {
             Property::Reason => "reason",
             Property::ReceivedAt => "receivedAt",
             Property::ReceivedFromIp => "receivedFromIp",
+            Property::ReceivedHeaderFormat => "receivedHeaderFormat",
             Property::ReceivedViaPort => "receivedViaPort",
             Property::ReceivingIp => "receivingIp",
             Property::ReceivingMxHelo => "receivingMxHelo",
             Property::ReceivingMxHostname => "receivingMxHostname",
+            Property::RecentFolderPeriod => "recentFolderPeriod",
+            Property::Recipient => "recipient",
             Property::Recipients => "recipients",
             Property::Records => "records",
             Property::RecurrenceId => "recurrenceId",
+            Property::RedirectLimit => "redirectLimit",
+            Property::RedirectRateLimit => "redirectRateLimit",
+            Property::RedirectTimeout => "redirectTimeout",
             Property::RedirectUris => "redirectUris",
             Property::Refresh => "refresh",
             Property::RefreshTokenExpiry => "refreshTokenExpiry",
             Property::RefreshTokenRenewal => "refreshTokenRenewal",
             Property::Region => "region",
+            Property::Regions => "regions",
+            Property::ReindexBatchSize => "reindexBatchSize",
+            Property::ReindexInterval => "reindexInterval",
+            Property::RejectBareCr => "rejectBareCr",
+            Property::RejectBareLf => "rejectBareLf",
             Property::RejectNonFqdn => "rejectNonFqdn",
             Property::RemoteIp => "remoteIp",
             Property::RenewBefore => "renewBefore",
+            Property::RepairMime => "repairMime",
+            Property::Repaired => "repaired",
+            Property::ReplyTo => "replyTo",
             Property::Report => "report",
             Property::ReportAddressUri => "reportAddressUri",
             Property::ReportId => "reportId",
@@ -2034,9 +2366,11 @@ impl EnumImpl for Property {
             Property::Require => "require",
             Property::RequireAudience => "requireAudience",
             Property::RequireClientRegistration => "requireClientRegistration",
+            Property::RequireMfa => "requireMfa",
             Property::RequireScopes => "requireScopes",
             Property::RequireTls => "requireTls",
             Property::ReservoirCapacity => "reservoirCapacity",
+            Property::ResolvedIps => "resolvedIps",
             Property::ResourceGroup => "resourceGroup",
             Property::ResourceUrl => "resourceUrl",
             Property::ResponseCode => "responseCode",
@@ -2049,6 +2383,7 @@ impl EnumImpl for Property {
             Property::ResponsePosExplanation => "responsePosExplanation",
             Property::Result => "result",
             Property::ResultType => "resultType",
+            Property::Results => "results",
             Property::RetireAfter => "retireAfter",
             Property::Retry => "retry",
             Property::RetryCount => "retryCount",
@@ -2056,6 +2391,7 @@ impl EnumImpl for Property {
             Property::ReturnPath => "returnPath",
             Property::ReverseIpVerify => "reverseIpVerify",
             Property::Rewrite => "rewrite",
+            Property::Role => "role",
             Property::RoleIds => "roleIds",
             Property::Roles => "roles",
             Property::Rotate => "rotate",
@@ -2074,9 +2410,12 @@ impl EnumImpl for Property {
             Property::Scope => "scope",
             Property::Score => "score",
             Property::ScoreDiscard => "scoreDiscard",
+            Property::ScoreQuarantine => "scoreQuarantine",
             Property::ScoreReject => "scoreReject",
             Property::ScoreSpam => "scoreSpam",
             Property::Script => "script",
+            Property::ScrubTraceHeaders => "scrubTraceHeaders",
+            Property::SearchFolders => "searchFolders",
             Property::SearchStore => "searchStore",
             Property::Secret => "secret",
             Property::SecretAccessKey => "secretAccessKey",
@@ -2087,6 +2426,9 @@ impl EnumImpl for Property {
             Property::Selector => "selector",
             Property::SelectorTemplate => "selectorTemplate",
             Property::SendFrequency => "sendFrequency",
+            Property::SenderDomain => "senderDomain",
+            Property::SenderMismatchAction => "senderMismatchAction",
+            Property::SenderRewrite => "senderRewrite",
             Property::SendingMtaIp => "sendingMtaIp",
             Property::Separator => "separator",
             Property::ServerHostname => "serverHostname",
@@ -2096,7 +2438,9 @@ impl EnumImpl for Property {
             Property::SessionToken => "sessionToken",
             Property::SetMaxObjects => "setMaxObjects",
             Property::ShardIndex => "shardIndex",
+            Property::SharedMailboxDestroy => "sharedMailboxDestroy",
             Property::SharedSecret => "sharedSecret",
+            Property::ShowVirtualFolders => "showVirtualFolders",
             Property::Sig0Algorithm => "sig0Algorithm",
             Property::SignatureAlgorithm => "signatureAlgorithm",
             Property::SignatureKey => "signatureKey",
@@ -2104,7 +2448,10 @@ impl EnumImpl for Property {
             Property::Size => "size",
             Property::SkipDeploy => "skipDeploy",
             Property::SkipFirst => "skipFirst",
+            Property::Smarthost => "smarthost",
             Property::SmtpGreeting => "smtpGreeting",
+            Property::SmugglingBanRate => "smugglingBanRate",
+            Property::SnippetMaxLength => "snippetMaxLength",
             Property::SnippetMaxResults => "snippetMaxResults",
             Property::SocketBacklog => "socketBacklog",
             Property::SocketNoDelay => "socketNoDelay",
@@ -2118,6 +2465,7 @@ impl EnumImpl for Property {
             Property::SourceIps => "sourceIps",
             Property::SourcePort => "sourcePort",
             Property::SpamFilterRulesUrl => "spamFilterRulesUrl",
+            Property::SpamMessages => "spamMessages",
             Property::SpfDns => "spfDns",
             Property::SpfEhloDomain => "spfEhloDomain",
             Property::SpfEhloResult => "spfEhloResult",
@@ -2132,14 +2480,21 @@ impl EnumImpl for Property {
             Property::StartTls => "startTls",
             Property::Status => "status",
             Property::StorageAccount => "storageAccount",
+            Property::StorageUsed => "storageUsed",
             Property::Store => "store",
+            Property::StoredBytes => "storedBytes",
             Property::Stores => "stores",
             Property::Strategy => "strategy",
+            Property::StructureLimitAction => "structureLimitAction",
             Property::SubAddressing => "subAddressing",
             Property::SubAuthId => "subAuthId",
             Property::Subject => "subject",
             Property::SubjectAlternativeNames => "subjectAlternativeNames",
+            Property::SubjectPrefix => "subjectPrefix",
             Property::Subscribe => "subscribe",
+            Property::SubscribeOnAutoCreate => "subscribeOnAutoCreate",
+            Property::SubscribeOnImapCreate => "subscribeOnImapCreate",
+            Property::SubscribeOnJmapCreate => "subscribeOnJmapCreate",
             Property::SubscriptionId => "subscriptionId",
             Property::Sum => "sum",
             Property::Summary => "summary",
@@ -2154,6 +2509,7 @@ impl EnumImpl for Property {
             Property::Temperature => "temperature",
             Property::Template => "template",
             Property::TenancyOcid => "tenancyOcid",
+            Property::TenantDomainExemptions => "tenantDomainExemptions",
             Property::TenantId => "tenantId",
             Property::Tenants => "tenants",
             Property::Text => "text",
@@ -2179,16 +2535,28 @@ impl EnumImpl for Property {
             Property::Timestamp => "timestamp",
             Property::Title => "title",
             Property::Tls => "tls",
+            Property::TlsCipher => "tlsCipher",
+            Property::TlsClientAuth => "tlsClientAuth",
+            Property::TlsClientCa => "tlsClientCa",
             Property::TlsDisableCipherSuites => "tlsDisableCipherSuites",
+            Property::TlsDisableKeyExchangeGroups => "tlsDisableKeyExchangeGroups",
             Property::TlsDisableProtocols => "tlsDisableProtocols",
+            Property::TlsFingerprint => "tlsFingerprint",
             Property::TlsIgnoreClientOrder => "tlsIgnoreClientOrder",
             Property::TlsImplicit => "tlsImplicit",
+            Property::TlsPreferPostQuantum => "tlsPreferPostQuantum",
+            Property::TlsProfile => "tlsProfile",
+            Property::TlsServerName => "tlsServerName",
             Property::TlsTimeout => "tlsTimeout",
+            Property::TlsVersion => "tlsVersion",
             Property::To => "to",
             Property::Token => "token",
+            Property::TopRecipients => "topRecipients",
+            Property::TopSenders => "topSenders",
             Property::TotalDeadline => "totalDeadline",
             Property::TotalFailedSessions => "totalFailedSessions",
             Property::TotalSuccessfulSessions => "totalSuccessfulSessions",
+            Property::TraceHeaders => "traceHeaders",
             Property::TraceId => "traceId",
             Property::Tracer => "tracer",
             Property::TrainFrequency => "trainFrequency",
@@ -2200,6 +2568,7 @@ impl EnumImpl for Property {
             Property::TrustReplies => "trustReplies",
             Property::TsigAlgorithm => "tsigAlgorithm",
             Property::Ttl => "ttl",
+            Property::UndoSendWindow => "undoSendWindow",
             Property::UnpackDirectory => "unpackDirectory",
             Property::UpdateRecords => "updateRecords",
             Property::UploadQuota => "uploadQuota",
@@ -2208,6 +2577,7 @@ impl EnumImpl for Property {
             Property::UrlLimit => "urlLimit",
             Property::UrlPrefix => "urlPrefix",
             Property::Urls => "urls",
+            Property::UseHttps => "useHttps",
             Property::UsePermissiveCors => "usePermissiveCors",
             Property::UseTls => "useTls",
             Property::UseXForwarded => "useXForwarded",
@@ -2223,12 +2593,15 @@ impl EnumImpl for Property {
             Property::VerifyAfterWrite => "verifyAfterWrite",
             Property::Version => "version",
             Property::ViewName => "viewName",
+            Property::VirtualFolders => "virtualFolders",
             Property::Vrfy => "vrfy",
+            Property::VrfyRate => "vrfyRate",
             Property::WaitOnFail => "waitOnFail",
             Property::WapiVersion => "wapiVersion",
             Property::WebsocketHeartbeat => "websocketHeartbeat",
             Property::WebsocketThrottle => "websocketThrottle",
             Property::WebsocketTimeout => "websocketTimeout",
+            Property::WriteMode => "writeMode",
             Property::Zone => "zone",
             Property::ZoneIpV4 => "zoneIpV4",
             Property::ZoneIpV6 => "zoneIpV6",
@@ -2249,6 +2622,7 @@ impl EnumImpl for Property {
             890 => Some(Property::AccessToken),
             619 => Some(Property::AccessTokenExpiry),
             132 => Some(Property::AccessTokens),
+            910 => Some(Property::AccountBandwidth),
             810 => Some(Property::AccountDomainId),
             57 => Some(Property::AccountId),
             315 => Some(Property::AccountIdentifier),
@@ -2287,20 +2661,31 @@ impl EnumImpl for Property {
             424 => Some(Property::AllowPlainTextAuth),
             348 => Some(Property::AllowRelaying),
             369 => Some(Property::AllowSpamTraining),
+            911 => Some(Property::AllowedAsns),
+            912 => Some(Property::AllowedCountries),
             398 => Some(Property::AllowedEndpoints),
+            913 => Some(Property::AllowedExtLists),
+            914 => Some(Property::AllowedGrantTypes),
             49 => Some(Property::AllowedIps),
+            915 => Some(Property::AllowedNetworks),
             712 => Some(Property::AllowedNotifyUris),
+            916 => Some(Property::AllowedScopes),
             388 => Some(Property::Alpha),
             614 => Some(Property::AnonymousClientRegistration),
             858 => Some(Property::Ansi),
+            917 => Some(Property::ApiCalls),
             325 => Some(Property::ApiKey),
             892 => Some(Property::ApiUser),
+            918 => Some(Property::AppendSieve),
+            919 => Some(Property::AppendSpamFilter),
             321 => Some(Property::ApplicationKey),
             322 => Some(Property::ApplicationSecret),
             292 => Some(Property::ArcResult),
             690 => Some(Property::ArcVerify),
+            920 => Some(Property::ArchiveAccountId),
             203 => Some(Property::ArchiveDeletedAccountsFor),
             202 => Some(Property::ArchiveDeletedItemsFor),
+            921 => Some(Property::ArchiveFolder),
             58 => Some(Property::ArchivedAt),
             820 => Some(Property::ArchivedItemType),
             59 => Some(Property::ArchivedUntil),
@@ -2321,7 +2706,11 @@ impl EnumImpl for Property {
             679 => Some(Property::AuthBanRate),
             616 => Some(Property::AuthCodeExpiry),
             613 => Some(Property::AuthCodeMaxAttempts),
+            922 => Some(Property::AuthDelay),
+            923 => Some(Property::AuthDelayMax),
+            924 => Some(Property::AuthDelayReset),
             81 => Some(Property::AuthFailure),
+            925 => Some(Property::AuthFailures),
             886 => Some(Property::AuthId),
             501 => Some(Property::AuthSecret),
             314 => Some(Property::AuthToken),
@@ -2329,7 +2718,9 @@ impl EnumImpl for Property {
             740 => Some(Property::AuthenticatedAs),
             69 => Some(Property::AuthenticationResults),
             171 => Some(Property::AutoAddInvitations),
+            926 => Some(Property::AutoArchiveAfter),
             53 => Some(Property::AutoUpdateFrequency),
+            927 => Some(Property::Bandwidth),
             463 => Some(Property::BaseDn),
             882 => Some(Property::BaseUrl),
             403 => Some(Property::BearerToken),
@@ -2339,7 +2730,12 @@ impl EnumImpl for Property {
             464 => Some(Property::BindDn),
             465 => Some(Property::BindSecret),
             200 => Some(Property::BlobCleanupSchedule),
+            928 => Some(Property::BlobCount),
+            929 => Some(Property::BlobHash),
             60 => Some(Property::BlobId),
+            930 => Some(Property::BlobReferences),
+            931 => Some(Property::BlobScrubInterval),
+            932 => Some(Property::BlobScrubRepair),
             655 => Some(Property::BlobSize),
             126 => Some(Property::BlobStore),
             766 => Some(Property::BlockCount),
@@ -2348,10 +2744,19 @@ impl EnumImpl for Property {
             658 => Some(Property::Bucket),
             656 => Some(Property::BufferSize),
             863 => Some(Property::Buffered),
+            933 => Some(Property::Burst),
+            934 => Some(Property::BytesReceived),
+            935 => Some(Property::BytesSent),
+            936 => Some(Property::Callout),
+            937 => Some(Property::CalloutCacheTtl),
+            938 => Some(Property::CalloutNegativeCacheTtl),
+            939 => Some(Property::CalloutTimeout),
             216 => Some(Property::Canonicalization),
+            940 => Some(Property::Capabilities),
             584 => Some(Property::CapacityClient),
             585 => Some(Property::CapacityReadBuffer),
             586 => Some(Property::CapacitySubscription),
+            941 => Some(Property::CatchAll),
             346 => Some(Property::CatchAllAddress),
             759 => Some(Property::Categories),
             176 => Some(Property::Certificate),
@@ -2365,14 +2770,19 @@ impl EnumImpl for Property {
             693 => Some(Property::Cleartext),
             604 => Some(Property::ClientId),
             898 => Some(Property::ClientIp),
+            942 => Some(Property::ClientPolicy),
             878 => Some(Property::ClientSecret),
             889 => Some(Property::ClientToken),
             382 => Some(Property::ClusterFile),
+            943 => Some(Property::ColdStorageAfter),
+            944 => Some(Property::ColdStorageArchive),
+            945 => Some(Property::ColdStore),
             781 => Some(Property::ColumnClass),
             782 => Some(Property::ColumnDescription),
             779 => Some(Property::ColumnEmail),
             780 => Some(Property::ColumnSecret),
             240 => Some(Property::Comment),
+            946 => Some(Property::CompactionRateLimit),
             905 => Some(Property::CompartmentOcid),
             359 => Some(Property::CompressionAlgorithm),
             304 => Some(Property::Concurrency),
@@ -2404,6 +2814,7 @@ impl EnumImpl for Property {
             899 => Some(Property::CustomerNumber),
             569 => Some(Property::Dane),
             199 => Some(Property::DataCleanupSchedule),
+            947 => Some(Property::DataRegion),
             125 => Some(Property::DataStore),
             506 => Some(Property::DataTimeout),
             575 => Some(Property::Database),
@@ -2413,6 +2824,7 @@ impl EnumImpl for Property {
             845 => Some(Property::DateRangeStart),
             192 => Some(Property::Day),
             868 => Some(Property::DeadPropertyMaxSize),
+            948 => Some(Property::DeduplicatedBytes),
             108 => Some(Property::DefaultAdminRoleIds),
             790 => Some(Property::DefaultCertificateId),
             20 => Some(Property::DefaultDisplayName),
@@ -2428,6 +2840,7 @@ impl EnumImpl for Property {
             21 => Some(Property::DefaultHrefName),
             665 => Some(Property::DefaultLanguage),
             408 => Some(Property::DefaultName),
+            949 => Some(Property::DefaultRegion),
             701 => Some(Property::DefaultReturnPath),
             411 => Some(Property::DefaultSubject),
             714 => Some(Property::DefaultSubjectPrefix),
@@ -2439,13 +2852,16 @@ impl EnumImpl for Property {
             777 => Some(Property::DeleteAfterUse),
             238 => Some(Property::DeliverAt),
             518 => Some(Property::DeliverBy),
+            950 => Some(Property::DeliverLocal),
             404 => Some(Property::DeliverTo),
+            951 => Some(Property::DeliveryMode),
             82 => Some(Property::DeliveryResult),
             381 => Some(Property::Depth),
             6 => Some(Property::Description),
             297 => Some(Property::Details),
             12 => Some(Property::Directory),
             104 => Some(Property::DirectoryId),
+            952 => Some(Property::DirectoryPrefix),
             711 => Some(Property::DisableCapabilities),
             629 => Some(Property::DisabledPermissions),
             872 => Some(Property::DiscardAfter),
@@ -2464,6 +2880,7 @@ impl EnumImpl for Property {
             155 => Some(Property::DkimSignatures),
             686 => Some(Property::DkimStrict),
             687 => Some(Property::DkimVerify),
+            953 => Some(Property::DmarcOverrides),
             294 => Some(Property::DmarcPass),
             295 => Some(Property::DmarcPolicy),
             293 => Some(Property::DmarcResult),
@@ -2492,6 +2909,8 @@ impl EnumImpl for Property {
             519 => Some(Property::Dsn),
             797 => Some(Property::Due),
             699 => Some(Property::DuplicateExpiry),
+            954 => Some(Property::DuplicateMatch),
+            955 => Some(Property::DuplicateWindow),
             515 => Some(Property::Duration),
             13 => Some(Property::EabHmacKey),
             14 => Some(Property::EabKeyId),
@@ -2516,12 +2935,16 @@ impl EnumImpl for Property {
             860 => Some(Property::EnableLogExporter),
             562 => Some(Property::EnableSpamFilter),
             861 => Some(Property::EnableSpanExporter),
+            956 => Some(Property::EnableUserScriptReject),
             50 => Some(Property::Enabled),
             628 => Some(Property::EnabledPermissions),
             358 => Some(Property::EncryptAtRest),
             357 => Some(Property::EncryptOnAppend),
             9 => Some(Property::EncryptionAtRest),
             622 => Some(Property::EncryptionKey),
+            957 => Some(Property::EncryptionKeyCacheTtl),
+            958 => Some(Property::EncryptionKeyProvider),
+            959 => Some(Property::EncryptionKeys),
             499 => Some(Property::Endpoint),
             742 => Some(Property::EnvFrom),
             743 => Some(Property::EnvFromParameters),
@@ -2530,6 +2953,7 @@ impl EnumImpl for Property {
             264 => Some(Property::EnvelopeFrom),
             263 => Some(Property::EnvelopeTo),
             881 => Some(Property::Environment),
+            960 => Some(Property::Error),
             210 => Some(Property::ErrorCommand),
             209 => Some(Property::ErrorMessage),
             208 => Some(Property::ErrorType),
@@ -2543,6 +2967,7 @@ impl EnumImpl for Property {
             803 => Some(Property::EventEndTz),
             799 => Some(Property::EventId),
             43 => Some(Property::EventMessage),
+            961 => Some(Property::EventSourceReplayWindow),
             447 => Some(Property::EventSourceThrottle),
             800 => Some(Property::EventStart),
             802 => Some(Property::EventStartTz),
@@ -2554,6 +2979,8 @@ impl EnumImpl for Property {
             632 => Some(Property::ExpiresAttempts),
             512 => Some(Property::Expiry),
             520 => Some(Property::Expn),
+            962 => Some(Property::ExpnRate),
+            963 => Some(Property::ExpungeDraftsAfter),
             198 => Some(Property::ExpungeSchedule),
             197 => Some(Property::ExpungeSchedulingInboxAfter),
             196 => Some(Property::ExpungeShareNotifyAfter),
@@ -2606,18 +3033,28 @@ impl EnumImpl for Property {
             770 => Some(Property::GreylistFor),
             477 => Some(Property::GroupClass),
             460 => Some(Property::GroupId),
+            964 => Some(Property::HamMessages),
             265 => Some(Property::HeaderFrom),
             93 => Some(Property::Headers),
+            965 => Some(Property::HoldAuditLogFor),
+            966 => Some(Property::HoldBlobIssuesFor),
+            967 => Some(Property::HoldDeliveryTracesFor),
+            968 => Some(Property::HoldLoginHistoryFor),
             206 => Some(Property::HoldMetricsFor),
             204 => Some(Property::HoldMtaReportsFor),
             730 => Some(Property::HoldSamplesFor),
+            969 => Some(Property::HoldStatisticsFor),
+            970 => Some(Property::HoldTenantUsageFor),
             205 => Some(Property::HoldTracesFor),
             333 => Some(Property::Host),
             331 => Some(Property::HostedZoneId),
             185 => Some(Property::Hostname),
+            971 => Some(Property::Hosts),
+            972 => Some(Property::HotStore),
             190 => Some(Property::Hour),
             32 => Some(Property::HttpAuth),
             33 => Some(Property::HttpHeaders),
+            973 => Some(Property::HttpPath),
             168 => Some(Property::HttpRsvpEnable),
             169 => Some(Property::HttpRsvpLinkExpiry),
             175 => Some(Property::HttpRsvpTemplate),
@@ -2634,6 +3071,7 @@ impl EnumImpl for Property {
             128 => Some(Property::InMemoryStore),
             651 => Some(Property::InboundReportAddresses),
             652 => Some(Property::InboundReportForwarding),
+            974 => Some(Property::InboundTlsFailureRate),
             70 => Some(Property::Incidents),
             352 => Some(Property::IncludeSource),
             94 => Some(Property::IndexAsn),
@@ -2647,6 +3085,7 @@ impl EnumImpl for Property {
             671 => Some(Property::IndexEmail),
             672 => Some(Property::IndexEmailFields),
             421 => Some(Property::IndexKey),
+            975 => Some(Property::IndexMaxBodySize),
             673 => Some(Property::IndexTelemetry),
             674 => Some(Property::IndexTracingFields),
             422 => Some(Property::IndexValue),
@@ -2658,6 +3097,8 @@ impl EnumImpl for Property {
             543 => Some(Property::IpLookupStrategy),
             290 => Some(Property::IpRevPtr),
             289 => Some(Property::IpRevResult),
+            976 => Some(Property::Ipv6Only),
+            977 => Some(Property::Ipv6PrefixLength),
             707 => Some(Property::IsActive),
             755 => Some(Property::IsArchive),
             756 => Some(Property::IsBad),
@@ -2669,6 +3110,7 @@ impl EnumImpl for Property {
             564 => Some(Property::IsSenderAllowed),
             776 => Some(Property::IsSpam),
             741 => Some(Property::IsTls),
+            978 => Some(Property::Issue),
             181 => Some(Property::Issuer),
             606 => Some(Property::IssuerUrl),
             172 => Some(Property::ItipMaxSize),
@@ -2679,6 +3121,7 @@ impl EnumImpl for Property {
             853 => Some(Property::KeyValues),
             391 => Some(Property::L1Ratio),
             392 => Some(Property::L2Ratio),
+            979 => Some(Property::LargeMessageSize),
             186 => Some(Property::LastRenewal),
             727 => Some(Property::LearnHamFromCard),
             735 => Some(Property::LearnHamFromReply),
@@ -2687,15 +3130,18 @@ impl EnumImpl for Property {
             373 => Some(Property::Level),
             370 => Some(Property::LicenseKey),
             875 => Some(Property::Line),
+            980 => Some(Property::ListId),
             183 => Some(Property::ListenerIds),
             188 => Some(Property::Listeners),
             869 => Some(Property::LivePropertyMaxSize),
             7 => Some(Property::Locale),
+            981 => Some(Property::LocalizedNames),
             341 => Some(Property::Logo),
             371 => Some(Property::LogoUrl),
             682 => Some(Property::LoiterBanPeriod),
             681 => Some(Property::LoiterBanRate),
             854 => Some(Property::Lossy),
+            982 => Some(Property::LowPriorityThreadsPerNode),
             384 => Some(Property::MachineId),
             793 => Some(Property::MailExchangers),
             284 => Some(Property::MailFrom),
@@ -2703,7 +3149,9 @@ impl EnumImpl for Property {
             841 => Some(Property::MailRua),
             154 => Some(Property::MailingLists),
             796 => Some(Property::MaintenanceType),
+            983 => Some(Property::MaintenanceWindows),
             318 => Some(Property::ManagedZone),
+            984 => Some(Property::MasterName),
             374 => Some(Property::Match),
             23 => Some(Property::MaxAddressBooks),
             566 => Some(Property::MaxAge),
@@ -2714,6 +3162,7 @@ impl EnumImpl for Property {
             511 => Some(Property::MaxAttempts),
             157 => Some(Property::MaxAttendees),
             425 => Some(Property::MaxAuthFailures),
+            985 => Some(Property::MaxBackgroundJobs),
             160 => Some(Property::MaxCalendars),
             201 => Some(Property::MaxChangesHistory),
             426 => Some(Property::MaxConcurrent),
@@ -2722,7 +3171,10 @@ impl EnumImpl for Property {
             603 => Some(Property::MaxConnections),
             24 => Some(Property::MaxContacts),
             702 => Some(Property::MaxCpuCycles),
+            986 => Some(Property::MaxCustomKeywords),
             823 => Some(Property::MaxDelay),
+            987 => Some(Property::MaxDrafts),
+            988 => Some(Property::MaxDraftsSize),
             530 => Some(Property::MaxDuration),
             417 => Some(Property::MaxEntries),
             418 => Some(Property::MaxEntrySize),
@@ -2731,6 +3183,7 @@ impl EnumImpl for Property {
             547 => Some(Property::MaxFailures),
             378 => Some(Property::MaxFiles),
             379 => Some(Property::MaxFolders),
+            989 => Some(Property::MaxHeaderCount),
             715 => Some(Property::MaxHeaderSize),
             159 => Some(Property::MaxICalendarSize),
             363 => Some(Property::MaxIdentities),
@@ -2743,18 +3196,23 @@ impl EnumImpl for Property {
             364 => Some(Property::MaxMailboxes),
             365 => Some(Property::MaxMaskedAddresses),
             718 => Some(Property::MaxMatchVars),
+            990 => Some(Property::MaxMessageKeywords),
             354 => Some(Property::MaxMessageSize),
             361 => Some(Property::MaxMessages),
             438 => Some(Property::MaxMethodCalls),
+            991 => Some(Property::MaxMimeDepth),
+            992 => Some(Property::MaxMimeParts),
             544 => Some(Property::MaxMultihomed),
             545 => Some(Property::MaxMxHosts),
             720 => Some(Property::MaxNestedBlocks),
             721 => Some(Property::MaxNestedForEvery),
             703 => Some(Property::MaxNestedIncludes),
             722 => Some(Property::MaxNestedTests),
+            993 => Some(Property::MaxNotifyActions),
             704 => Some(Property::MaxOutMessages),
             162 => Some(Property::MaxParticipantIdentities),
             366 => Some(Property::MaxPublicKeys),
+            994 => Some(Property::MaxQueueSize),
             561 => Some(Property::MaxReceivedHeaders),
             173 => Some(Property::MaxRecipients),
             580 => Some(Property::MaxReconnects),
@@ -2767,6 +3225,7 @@ impl EnumImpl for Property {
             871 => Some(Property::MaxResults),
             18 => Some(Property::MaxRetries),
             648 => Some(Property::MaxRetryWait),
+            995 => Some(Property::MaxRunTime),
             719 => Some(Property::MaxScriptNameLength),
             723 => Some(Property::MaxScriptSize),
             726 => Some(Property::MaxScripts),
@@ -2775,6 +3234,7 @@ impl EnumImpl for Property {
             724 => Some(Property::MaxStringLength),
             362 => Some(Property::MaxSubmissions),
             458 => Some(Property::MaxSubscriptions),
+            996 => Some(Property::MaxTotalScriptSize),
             444 => Some(Property::MaxUploadCount),
             443 => Some(Property::MaxUploadSize),
             22 => Some(Property::MaxVCardSize),
@@ -2782,19 +3242,25 @@ impl EnumImpl for Property {
             706 => Some(Property::MaxVarSize),
             864 => Some(Property::MemberGroupIds),
             19 => Some(Property::MemberTenantId),
+            997 => Some(Property::MembersOnly),
             92 => Some(Property::Message),
             698 => Some(Property::MessageIdHostname),
             819 => Some(Property::MessageIds),
             145 => Some(Property::Messages),
+            998 => Some(Property::MessagesReceived),
+            999 => Some(Property::MessagesSent),
+            1000 => Some(Property::MessagesSubmitted),
             493 => Some(Property::Metric),
             497 => Some(Property::Metrics),
             207 => Some(Property::MetricsCollectionInterval),
             498 => Some(Property::MetricsPolicy),
+            1001 => Some(Property::MigrationDirectory),
             731 => Some(Property::MinHamSamples),
             649 => Some(Property::MinRetryWait),
             732 => Some(Property::MinSpamSamples),
             166 => Some(Property::MinTriggerInterval),
             191 => Some(Property::Minute),
+            1002 => Some(Property::MirrorStore),
             567 => Some(Property::Mode),
             28 => Some(Property::Model),
             764 => Some(Property::ModelId),
@@ -2804,6 +3270,7 @@ impl EnumImpl for Property {
             572 => Some(Property::MtaStsTimeout),
             859 => Some(Property::Multiline),
             550 => Some(Property::MustMatchSender),
+            1003 => Some(Property::MustMatchTenantDomain),
             568 => Some(Property::MxHosts),
             25 => Some(Property::Name),
             414 => Some(Property::Namespace),
@@ -2820,6 +3287,9 @@ impl EnumImpl for Property {
             513 => Some(Property::Notify),
             642 => Some(Property::NotifyCount),
             643 => Some(Property::NotifyDue),
+            1004 => Some(Property::NotifyGatewayUrl),
+            1005 => Some(Property::NotifySignatureKey),
+            1006 => Some(Property::NotifyTimeout),
             390 => Some(Property::NumFeatures),
             350 => Some(Property::NumReplicas),
             351 => Some(Property::NumShards),
@@ -2840,11 +3310,13 @@ impl EnumImpl for Property {
             654 => Some(Property::OutboundReportSubmitter),
             590 => Some(Property::OverrideProxyTrustedNetworks),
             239 => Some(Property::OverrideType),
+            1007 => Some(Property::OversignHeaders),
             324 => Some(Property::OvhEndpoint),
             737 => Some(Property::Parameters),
             433 => Some(Property::ParseLimitContact),
             434 => Some(Property::ParseLimitEmail),
             432 => Some(Property::ParseLimitEvent),
+            1008 => Some(Property::PartitionMode),
             876 => Some(Property::Password),
             113 => Some(Property::PasswordDefaultExpiry),
             109 => Some(Property::PasswordHashAlgorithm),
@@ -2852,10 +3324,12 @@ impl EnumImpl for Property {
             110 => Some(Property::PasswordMinLength),
             112 => Some(Property::PasswordMinStrength),
             380 => Some(Property::Path),
+            1009 => Some(Property::PerformedBy),
             646 => Some(Property::Period),
             48 => Some(Property::Permissions),
             583 => Some(Property::PingInterval),
             524 => Some(Property::Pipelining),
+            1010 => Some(Property::PlaintextFallback),
             846 => Some(Property::Policies),
             250 => Some(Property::PolicyAdkim),
             251 => Some(Property::PolicyAspf),
@@ -2882,7 +3356,9 @@ impl EnumImpl for Property {
             299 => Some(Property::Port),
             856 => Some(Property::Prefix),
             306 => Some(Property::PreserveIntermediates),
+            1011 => Some(Property::PrimaryStore),
             483 => Some(Property::Priority),
+            1012 => Some(Property::PriorityThreadsPerNode),
             177 => Some(Property::PrivateKey),
             904 => Some(Property::PrivateKeyPassword),
             903 => Some(Property::PrivateKeyPem),
@@ -2898,7 +3374,12 @@ impl EnumImpl for Property {
             298 => Some(Property::Protocol),
             533 => Some(Property::ProtocolVersion),
             795 => Some(Property::ProviderInfo),
+            1013 => Some(Property::ProxyAddress),
+            1014 => Some(Property::ProxyPort),
+            1015 => Some(Property::ProxyProtocol),
+            1016 => Some(Property::ProxySecret),
             792 => Some(Property::ProxyTrustedNetworks),
+            1017 => Some(Property::ProxyUsername),
             218 => Some(Property::PublicKey),
             302 => Some(Property::PublishRecords),
             448 => Some(Property::PushAttemptWait),
@@ -2907,7 +3388,12 @@ impl EnumImpl for Property {
             450 => Some(Property::PushRetryWait),
             454 => Some(Property::PushShardsTotal),
             451 => Some(Property::PushThrottle),
+            1018 => Some(Property::PushVapidKey),
+            1019 => Some(Property::PushVapidSubject),
             453 => Some(Property::PushVerifyTimeout),
+            1020 => Some(Property::QuarantineDigestFrequency),
+            1021 => Some(Property::QuarantineFor),
+            1022 => Some(Property::Query),
             786 => Some(Property::QueryEmailAliases),
             783 => Some(Property::QueryLogin),
             437 => Some(Property::QueryMaxResults),
@@ -2918,8 +3404,13 @@ impl EnumImpl for Property {
             394 => Some(Property::Quotas),
             532 => Some(Property::Rate),
             410 => Some(Property::RateLimit),
+            1023 => Some(Property::RateLimitAdmin),
             397 => Some(Property::RateLimitAnonymous),
             396 => Some(Property::RateLimitAuthenticated),
+            1024 => Some(Property::RateLimitClient),
+            1025 => Some(Property::RateLimitQuery),
+            1026 => Some(Property::RateLimitSet),
+            1027 => Some(Property::RateLimitUpload),
             767 => Some(Property::Ratio),
             510 => Some(Property::RcptToTimeout),
             650 => Some(Property::ReadFromReplicas),
@@ -2927,21 +3418,35 @@ impl EnumImpl for Property {
             45 => Some(Property::Reason),
             63 => Some(Property::ReceivedAt),
             636 => Some(Property::ReceivedFromIp),
+            1028 => Some(Property::ReceivedHeaderFormat),
             637 => Some(Property::ReceivedViaPort),
             836 => Some(Property::ReceivingIp),
             835 => Some(Property::ReceivingMxHelo),
             834 => Some(Property::ReceivingMxHostname),
+            1029 => Some(Property::RecentFolderPeriod),
+            1030 => Some(Property::Recipient),
             484 => Some(Property::Recipients),
             256 => Some(Property::Records),
             805 => Some(Property::RecurrenceId),
+            1031 => Some(Property::RedirectLimit),
+            1032 => Some(Property::RedirectRateLimit),
+            1033 => Some(Property::RedirectTimeout),
             605 => Some(Property::RedirectUris),
             419 => Some(Property::Refresh),
             617 => Some(Property::RefreshTokenExpiry),
             618 => Some(Property::RefreshTokenRenewal),
             330 => Some(Property::Region),
+            1034 => Some(Property::Regions),
+            1035 => Some(Property::ReindexBatchSize),
+            1036 => Some(Property::ReindexInterval),
+            1037 => Some(Property::RejectBareCr),
+            1038 => Some(Property::RejectBareLf),
             563 => Some(Property::RejectNonFqdn),
             282 => Some(Property::RemoteIp),
             17 => Some(Property::RenewBefore),
+            1039 => Some(Property::RepairMime),
+            1040 => Some(Property::Repaired),
+            1041 => Some(Property::ReplyTo),
             66 => Some(Property::Report),
             349 => Some(Property::ReportAddressUri),
             244 => Some(Property::ReportId),
@@ -2953,9 +3458,11 @@ impl EnumImpl for Property {
             551 => Some(Property::Require),
             607 => Some(Property::RequireAudience),
             615 => Some(Property::RequireClientRegistration),
+            1042 => Some(Property::RequireMfa),
             608 => Some(Property::RequireScopes),
             525 => Some(Property::RequireTls),
             733 => Some(Property::ReservoirCapacity),
+            1043 => Some(Property::ResolvedIps),
             880 => Some(Property::ResourceGroup),
             51 => Some(Property::ResourceUrl),
             212 => Some(Property::ResponseCode),
@@ -2968,6 +3475,7 @@ impl EnumImpl for Property {
             763 => Some(Property::ResponsePosExplanation),
             233 => Some(Property::Result),
             832 => Some(Property::ResultType),
+            1044 => Some(Property::Results),
             228 => Some(Property::RetireAfter),
             420 => Some(Property::Retry),
             640 => Some(Property::RetryCount),
@@ -2975,6 +3483,7 @@ impl EnumImpl for Property {
             635 => Some(Property::ReturnPath),
             692 => Some(Property::ReverseIpVerify),
             565 => Some(Property::Rewrite),
+            1045 => Some(Property::Role),
             193 => Some(Property::RoleIds),
             152 => Some(Property::Roles),
             857 => Some(Property::Rotate),
@@ -2993,9 +3502,12 @@ impl EnumImpl for Property {
             281 => Some(Property::Scope),
             745 => Some(Property::Score),
             771 => Some(Property::ScoreDiscard),
+            1046 => Some(Property::ScoreQuarantine),
             772 => Some(Property::ScoreReject),
             773 => Some(Property::ScoreSpam),
             553 => Some(Property::Script),
+            1047 => Some(Property::ScrubTraceHeaders),
+            1048 => Some(Property::SearchFolders),
             127 => Some(Property::SearchStore),
             3 => Some(Property::Secret),
             328 => Some(Property::SecretAccessKey),
@@ -3006,6 +3518,9 @@ impl EnumImpl for Property {
             222 => Some(Property::Selector),
             226 => Some(Property::SelectorTemplate),
             230 => Some(Property::SendFrequency),
+            1049 => Some(Property::SenderDomain),
+            1050 => Some(Property::SenderMismatchAction),
+            1051 => Some(Property::SenderRewrite),
             833 => Some(Property::SendingMtaIp),
             97 => Some(Property::Separator),
             121 => Some(Property::ServerHostname),
@@ -3015,7 +3530,9 @@ impl EnumImpl for Property {
             329 => Some(Property::SessionToken),
             440 => Some(Property::SetMaxObjects),
             830 => Some(Property::ShardIndex),
+            1052 => Some(Property::SharedMailboxDestroy),
             895 => Some(Property::SharedSecret),
+            1053 => Some(Property::ShowVirtualFolders),
             336 => Some(Property::Sig0Algorithm),
             623 => Some(Property::SignatureAlgorithm),
             624 => Some(Property::SignatureKey),
@@ -3023,7 +3540,10 @@ impl EnumImpl for Property {
             64 => Some(Property::Size),
             885 => Some(Property::SkipDeploy),
             423 => Some(Property::SkipFirst),
+            1054 => Some(Property::Smarthost),
             552 => Some(Property::SmtpGreeting),
+            1055 => Some(Property::SmugglingBanRate),
+            1056 => Some(Property::SnippetMaxLength),
             441 => Some(Property::SnippetMaxResults),
             591 => Some(Property::SocketBacklog),
             592 => Some(Property::SocketNoDelay),
@@ -3037,6 +3557,7 @@ impl EnumImpl for Property {
             504 => Some(Property::SourceIps),
             78 => Some(Property::SourcePort),
             775 => Some(Property::SpamFilterRulesUrl),
+            1057 => Some(Property::SpamMessages),
             90 => Some(Property::SpfDns),
             285 => Some(Property::SpfEhloDomain),
             286 => Some(Property::SpfEhloResult),
@@ -3051,14 +3572,21 @@ impl EnumImpl for Property {
             571 => Some(Property::StartTls),
             61 => Some(Property::Status),
             116 => Some(Property::StorageAccount),
+            1058 => Some(Property::StorageUsed),
             778 => Some(Property::Store),
+            1059 => Some(Property::StoredBytes),
             694 => Some(Property::Stores),
             816 => Some(Property::Strategy),
+            1060 => Some(Property::StructureLimitAction),
             347 => Some(Property::SubAddressing),
             887 => Some(Property::SubAuthId),
             41 => Some(Property::Subject),
             178 => Some(Property::SubjectAlternativeNames),
+            1061 => Some(Property::SubjectPrefix),
             368 => Some(Property::Subscribe),
+            1062 => Some(Property::SubscribeOnAutoCreate),
+            1063 => Some(Property::SubscribeOnImapCreate),
+            1064 => Some(Property::SubscribeOnJmapCreate),
             879 => Some(Property::SubscriptionId),
             494 => Some(Property::Sum),
             808 => Some(Property::Summary),
@@ -3073,6 +3601,7 @@ impl EnumImpl for Property {
             27 => Some(Property::Temperature),
             167 => Some(Property::Template),
             900 => Some(Property::TenancyOcid),
+            1065 => Some(Property::TenantDomainExemptions),
             831 => Some(Property::TenantId),
             153 => Some(Property::Tenants),
             2 => Some(Property::Text),
@@ -3098,16 +3627,28 @@ impl EnumImpl for Property {
             482 => Some(Property::Timestamp),
             55 => Some(Property::Title),
             542 => Some(Property::Tls),
+            1066 => Some(Property::TlsCipher),
+            1067 => Some(Property::TlsClientAuth),
+            1068 => Some(Property::TlsClientCa),
             599 => Some(Property::TlsDisableCipherSuites),
+            1069 => Some(Property::TlsDisableKeyExchangeGroups),
             600 => Some(Property::TlsDisableProtocols),
+            1070 => Some(Property::TlsFingerprint),
             601 => Some(Property::TlsIgnoreClientOrder),
             602 => Some(Property::TlsImplicit),
+            1071 => Some(Property::TlsPreferPostQuantum),
+            1072 => Some(Property::TlsProfile),
+            1073 => Some(Property::TlsServerName),
             573 => Some(Property::TlsTimeout),
+            1074 => Some(Property::TlsVersion),
             42 => Some(Property::To),
             888 => Some(Property::Token),
+            1075 => Some(Property::TopRecipients),
+            1076 => Some(Property::TopSenders),
             817 => Some(Property::TotalDeadline),
             850 => Some(Property::TotalFailedSessions),
             849 => Some(Property::TotalSuccessfulSessions),
+            1077 => Some(Property::TraceHeaders),
             815 => Some(Property::TraceId),
             129 => Some(Property::Tracer),
             734 => Some(Property::TrainFrequency),
@@ -3119,6 +3660,7 @@ impl EnumImpl for Property {
             774 => Some(Property::TrustReplies),
             338 => Some(Property::TsigAlgorithm),
             310 => Some(Property::Ttl),
+            1078 => Some(Property::UndoSendWindow),
             54 => Some(Property::UnpackDirectory),
             812 => Some(Property::UpdateRecords),
             445 => Some(Property::UploadQuota),
@@ -3127,6 +3669,7 @@ impl EnumImpl for Property {
             753 => Some(Property::UrlLimit),
             52 => Some(Property::UrlPrefix),
             647 => Some(Property::Urls),
+            1079 => Some(Property::UseHttps),
             400 => Some(Property::UsePermissiveCors),
             309 => Some(Property::UseTls),
             402 => Some(Property::UseXForwarded),
@@ -3142,12 +3685,15 @@ impl EnumImpl for Property {
             874 => Some(Property::VerifyAfterWrite),
             80 => Some(Property::Version),
             884 => Some(Property::ViewName),
+            1080 => Some(Property::VirtualFolders),
             526 => Some(Property::Vrfy),
+            1081 => Some(Property::VrfyRate),
             548 => Some(Property::WaitOnFail),
             893 => Some(Property::WapiVersion),
             455 => Some(Property::WebsocketHeartbeat),
             456 => Some(Property::WebsocketThrottle),
             457 => Some(Property::WebsocketTimeout),
+            1082 => Some(Property::WriteMode),
             749 => Some(Property::Zone),
             98 => Some(Property::ZoneIpV4),
             99 => Some(Property::ZoneIpV6),
@@ -3155,7 +3701,7 @@ impl EnumImpl for Property {
         }
     }
 
-    const COUNT: usize = 910;
+    const COUNT: usize = 1083;
 }
 
 impl serde::Serialize for Property {
@@ -3196,6 +3742,7 @@ impl ObjectType {
             ObjectType::ArfExternalReport => ArfExternalReport::FLAGS,
             ObjectType::Asn => Asn::FLAGS,
             ObjectType::Authentication => Authentication::FLAGS,
+            ObjectType::BlobIntegrityIssue => BlobIntegrityIssue::FLAGS,
             ObjectType::BlobStore => BlobStore::FLAGS,
             ObjectType::BlockedIp => BlockedIp::FLAGS,
             ObjectType::Bootstrap => Bootstrap::FLAGS,
@@ -3209,6 +3756,7 @@ impl ObjectType {
             ObjectType::Coordinator => Coordinator::FLAGS,
             ObjectType::DataRetention => DataRetention::FLAGS,
             ObjectType::DataStore => DataStore::FLAGS,
+            ObjectType::DeliveryTrace => DeliveryTrace::FLAGS,
             ObjectType::Directory => Directory::FLAGS,
             ObjectType::DkimReportSettings => DkimReportSettings::FLAGS,
             ObjectType::DkimSignature => DkimSignature::FLAGS,
@@ -3219,6 +3767,7 @@ impl ObjectType {
             ObjectType::DnsServer => DnsServer::FLAGS,
             ObjectType::Domain => Domain::FLAGS,
             ObjectType::DsnReportSettings => DsnReportSettings::FLAGS,
+            ObjectType::DsnTemplate => DsnTemplate::FLAGS,
             ObjectType::Email => Email::FLAGS,
             ObjectType::Enterprise => Enterprise::FLAGS,
             ObjectType::EventTracingLevel => EventTracingLevel::FLAGS,
@@ -3230,10 +3779,12 @@ impl ObjectType {
             ObjectType::InMemoryStore => InMemoryStore::FLAGS,
             ObjectType::Jmap => Jmap::FLAGS,
             ObjectType::Log => Log::FLAGS,
+            ObjectType::LoginEntry => LoginEntry::FLAGS,
             ObjectType::MailingList => MailingList::FLAGS,
             ObjectType::MaskedEmail => MaskedEmail::FLAGS,
             ObjectType::MemoryLookupKey => MemoryLookupKey::FLAGS,
             ObjectType::MemoryLookupKeyValue => MemoryLookupKeyValue::FLAGS,
+            ObjectType::MessageAuditEntry => MessageAuditEntry::FLAGS,
             ObjectType::Metric => Metric::FLAGS,
             ObjectType::Metrics => Metrics::FLAGS,
             ObjectType::MetricsStore => MetricsStore::FLAGS,
@@ -3261,6 +3812,7 @@ impl ObjectType {
             ObjectType::OAuthClient => OAuthClient::FLAGS,
             ObjectType::OidcProvider => OidcProvider::FLAGS,
             ObjectType::PublicKey => PublicKey::FLAGS,
+            ObjectType::QuarantinedMessage => QuarantinedMessage::FLAGS,
             ObjectType::QueuedMessage => QueuedMessage::FLAGS,
             ObjectType::ReportSettings => ReportSettings::FLAGS,
             ObjectType::Role => Role::FLAGS,
@@ -3284,12 +3836,15 @@ impl ObjectType {
             ObjectType::SpamTag => SpamTag::FLAGS,
             ObjectType::SpamTrainingSample => SpamTrainingSample::FLAGS,
             ObjectType::SpfReportSettings => SpfReportSettings::FLAGS,
+            ObjectType::StatisticsRollup => StatisticsRollup::FLAGS,
             ObjectType::StoreLookup => StoreLookup::FLAGS,
             ObjectType::SystemSettings => SystemSettings::FLAGS,
             ObjectType::Task => Task::FLAGS,
             ObjectType::TaskManager => TaskManager::FLAGS,
             ObjectType::Tenant => Tenant::FLAGS,
+            ObjectType::TenantUsage => TenantUsage::FLAGS,
             ObjectType::TlsExternalReport => TlsExternalReport::FLAGS,
+            ObjectType::TlsInboundReport => TlsInboundReport::FLAGS,
             ObjectType::TlsInternalReport => TlsInternalReport::FLAGS,
             ObjectType::TlsReportSettings => TlsReportSettings::FLAGS,
             ObjectType::Trace => Trace::FLAGS,
@@ -3410,6 +3965,18 @@ impl ObjectType {
                     IndexSchemaValueType::Keyword,
                 ),
             ],
+            ObjectType::DsnTemplate => vec![
+                IndexSchema::new(
+                    Property::DomainId,
+                    IndexSchemaType::Search,
+                    IndexSchemaValueType::Id,
+                ),
+                IndexSchema::new(
+                    Property::MemberTenantId,
+                    IndexSchemaType::Search,
+                    IndexSchemaValueType::Id,
+                ),
+            ],
             ObjectType::MailingList => vec![
                 IndexSchema::new(
                     Property::Text,
@@ -3489,6 +4056,11 @@ impl ObjectType {
                 IndexSchemaType::Search,
                 IndexSchemaValueType::Id,
             )],
+            ObjectType::QuarantinedMessage => vec![IndexSchema::new(
+                Property::AccountId,
+                IndexSchemaType::Search,
+                IndexSchemaValueType::Id,
+            )],
             ObjectType::Role => vec![
                 IndexSchema::new(
                     Property::Description,
@@ -3563,6 +4135,7 @@ impl ObjectType {
             ObjectType::ArfExternalReport => Permission::SysArfExternalReportGet,
             ObjectType::Asn => Permission::SysAsnGet,
             ObjectType::Authentication => Permission::SysAuthenticationGet,
+            ObjectType::BlobIntegrityIssue => Permission::SysBlobIntegrityIssueGet,
             ObjectType::BlobStore => Permission::SysBlobStoreGet,
             ObjectType::BlockedIp => Permission::SysBlockedIpGet,
             ObjectType::Bootstrap => Permission::SysBootstrapGet,
@@ -3576,6 +4149,7 @@ impl ObjectType {
             ObjectType::Coordinator => Permission::SysCoordinatorGet,
             ObjectType::DataRetention => Permission::SysDataRetentionGet,
             ObjectType::DataStore => Permission::SysDataStoreGet,
+            ObjectType::DeliveryTrace => Permission::SysDeliveryTraceGet,
             ObjectType::Directory => Permission::SysDirectoryGet,
             ObjectType::DkimReportSettings => Permission::SysDkimReportSettingsGet,
             ObjectType::DkimSignature => Permission::SysDkimSignatureGet,
@@ -3586,6 +4160,7 @@ impl ObjectType {
             ObjectType::DnsServer => Permission::SysDnsServerGet,
             ObjectType::Domain => Permission::SysDomainGet,
             ObjectType::DsnReportSettings => Permission::SysDsnReportSettingsGet,
+            ObjectType::DsnTemplate => Permission::SysDsnTemplateGet,
             ObjectType::Email => Permission::SysEmailGet,
             ObjectType::Enterprise => Permission::SysEnterpriseGet,
             ObjectType::EventTracingLevel => Permission::SysEventTracingLevelGet,
@@ -3597,10 +4172,12 @@ impl ObjectType {
             ObjectType::InMemoryStore => Permission::SysInMemoryStoreGet,
             ObjectType::Jmap => Permission::SysJmapGet,
             ObjectType::Log => Permission::SysLogGet,
+            ObjectType::LoginEntry => Permission::SysLoginEntryGet,
             ObjectType::MailingList => Permission::SysMailingListGet,
             ObjectType::MaskedEmail => Permission::SysMaskedEmailGet,
             ObjectType::MemoryLookupKey => Permission::SysMemoryLookupKeyGet,
             ObjectType::MemoryLookupKeyValue => Permission::SysMemoryLookupKeyValueGet,
+            ObjectType::MessageAuditEntry => Permission::SysMessageAuditEntryGet,
             ObjectType::Metric => Permission::SysMetricGet,
             ObjectType::Metrics => Permission::SysMetricsGet,
             ObjectType::MetricsStore => Permission::SysMetricsStoreGet,
@@ -3628,6 +4205,7 @@ impl ObjectType {
             ObjectType::OAuthClient => Permission::SysOAuthClientGet,
             ObjectType::OidcProvider => Permission::SysOidcProviderGet,
             ObjectType::PublicKey => Permission::SysPublicKeyGet,
+            ObjectType::QuarantinedMessage => Permission::SysQuarantinedMessageGet,
             ObjectType::QueuedMessage => Permission::SysQueuedMessageGet,
             ObjectType::ReportSettings => Permission::SysReportSettingsGet,
             ObjectType::Role => Permission::SysRoleGet,
@@ -3651,12 +4229,15 @@ impl ObjectType {
             ObjectType::SpamTag => Permission::SysSpamTagGet,
             ObjectType::SpamTrainingSample => Permission::SysSpamTrainingSampleGet,
             ObjectType::SpfReportSettings => Permission::SysSpfReportSettingsGet,
+            ObjectType::StatisticsRollup => Permission::SysStatisticsRollupGet,
             ObjectType::StoreLookup => Permission::SysStoreLookupGet,
             ObjectType::SystemSettings => Permission::SysSystemSettingsGet,
             ObjectType::Task => Permission::SysTaskGet,
             ObjectType::TaskManager => Permission::SysTaskManagerGet,
             ObjectType::Tenant => Permission::SysTenantGet,
+            ObjectType::TenantUsage => Permission::SysTenantUsageGet,
             ObjectType::TlsExternalReport => Permission::SysTlsExternalReportGet,
+            ObjectType::TlsInboundReport => Permission::SysTlsInboundReportGet,
             ObjectType::TlsInternalReport => Permission::SysTlsInternalReportGet,
             ObjectType::TlsReportSettings => Permission::SysTlsReportSettingsGet,
             ObjectType::Trace => Permission::SysTraceGet,
@@ -3680,23 +4261,28 @@ impl ObjectType {
             ObjectType::Application => Permission::SysApplicationQuery,
             ObjectType::ArchivedItem => Permission::SysArchivedItemQuery,
             ObjectType::ArfExternalReport => Permission::SysArfExternalReportQuery,
+            ObjectType::BlobIntegrityIssue => Permission::SysBlobIntegrityIssueQuery,
             ObjectType::BlockedIp => Permission::SysBlockedIpQuery,
             ObjectType::Certificate => Permission::SysCertificateQuery,
             ObjectType::ClusterNode => Permission::SysClusterNodeQuery,
             ObjectType::ClusterRole => Permission::SysClusterRoleQuery,
+            ObjectType::DeliveryTrace => Permission::SysDeliveryTraceQuery,
             ObjectType::Directory => Permission::SysDirectoryQuery,
             ObjectType::DkimSignature => Permission::SysDkimSignatureQuery,
             ObjectType::DmarcExternalReport => Permission::SysDmarcExternalReportQuery,
             ObjectType::DmarcInternalReport => Permission::SysDmarcInternalReportQuery,
             ObjectType::DnsServer => Permission::SysDnsServerQuery,
             ObjectType::Domain => Permission::SysDomainQuery,
+            ObjectType::DsnTemplate => Permission::SysDsnTemplateQuery,
             ObjectType::EventTracingLevel => Permission::SysEventTracingLevelQuery,
             ObjectType::HttpLookup => Permission::SysHttpLookupQuery,
             ObjectType::Log => Permission::SysLogQuery,
+            ObjectType::LoginEntry => Permission::SysLoginEntryQuery,
             ObjectType::MailingList => Permission::SysMailingListQuery,
             ObjectType::MaskedEmail => Permission::SysMaskedEmailQuery,
             ObjectType::MemoryLookupKey => Permission::SysMemoryLookupKeyQuery,
             ObjectType::MemoryLookupKeyValue => Permission::SysMemoryLookupKeyValueQuery,
+            ObjectType::MessageAuditEntry => Permission::SysMessageAuditEntryQuery,
             ObjectType::Metric => Permission::SysMetricQuery,
             ObjectType::MtaConnectionStrategy => Permission::SysMtaConnectionStrategyQuery,
             ObjectType::MtaDeliverySchedule => Permission::SysMtaDeliveryScheduleQuery,
@@ -3711,6 +4297,7 @@ impl ObjectType {
             ObjectType::NetworkListener => Permission::SysNetworkListenerQuery,
             ObjectType::OAuthClient => Permission::SysOAuthClientQuery,
             ObjectType::PublicKey => Permission::SysPublicKeyQuery,
+            ObjectType::QuarantinedMessage => Permission::SysQuarantinedMessageQuery,
             ObjectType::QueuedMessage => Permission::SysQueuedMessageQuery,
             ObjectType::Role => Permission::SysRoleQuery,
             ObjectType::SieveSystemScript => Permission::SysSieveSystemScriptQuery,
@@ -3720,10 +4307,13 @@ impl ObjectType {
             ObjectType::SpamRule => Permission::SysSpamRuleQuery,
             ObjectType::SpamTag => Permission::SysSpamTagQuery,
             ObjectType::SpamTrainingSample => Permission::SysSpamTrainingSampleQuery,
+            ObjectType::StatisticsRollup => Permission::SysStatisticsRollupQuery,
             ObjectType::StoreLookup => Permission::SysStoreLookupQuery,
             ObjectType::Task => Permission::SysTaskQuery,
             ObjectType::Tenant => Permission::SysTenantQuery,
+            ObjectType::TenantUsage => Permission::SysTenantUsageQuery,
             ObjectType::TlsExternalReport => Permission::SysTlsExternalReportQuery,
+            ObjectType::TlsInboundReport => Permission::SysTlsInboundReportQuery,
             ObjectType::TlsInternalReport => Permission::SysTlsInternalReportQuery,
             ObjectType::Trace => Permission::SysTraceQuery,
             ObjectType::Tracer => Permission::SysTracerQuery,
@@ -3814,6 +4404,11 @@ impl ObjectType {
                 Permission::SysAuthenticationUpdate,
                 Permission::SysAuthenticationUpdate,
             ],
+            ObjectType::BlobIntegrityIssue => [
+                Permission::SysBlobIntegrityIssueCreate,
+                Permission::SysBlobIntegrityIssueUpdate,
+                Permission::SysBlobIntegrityIssueDestroy,
+            ],
             ObjectType::BlobStore => [
                 Permission::SysBlobStoreUpdate,
                 Permission::SysBlobStoreUpdate,
@@ -3879,6 +4474,11 @@ impl ObjectType {
                 Permission::SysDataStoreUpdate,
                 Permission::SysDataStoreUpdate,
             ],
+            ObjectType::DeliveryTrace => [
+                Permission::SysDeliveryTraceCreate,
+                Permission::SysDeliveryTraceUpdate,
+                Permission::SysDeliveryTraceDestroy,
+            ],
             ObjectType::Directory => [
                 Permission::SysDirectoryCreate,
                 Permission::SysDirectoryUpdate,
@@ -3929,6 +4529,11 @@ impl ObjectType {
                 Permission::SysDsnReportSettingsUpdate,
                 Permission::SysDsnReportSettingsUpdate,
             ],
+            ObjectType::DsnTemplate => [
+                Permission::SysDsnTemplateCreate,
+                Permission::SysDsnTemplateUpdate,
+                Permission::SysDsnTemplateDestroy,
+            ],
             ObjectType::Email => [
                 Permission::SysEmailUpdate,
                 Permission::SysEmailUpdate,
@@ -3984,6 +4589,11 @@ impl ObjectType {
                 Permission::SysLogUpdate,
                 Permission::SysLogDestroy,
             ],
+            ObjectType::LoginEntry => [
+                Permission::SysLoginEntryCreate,
+                Permission::SysLoginEntryUpdate,
+                Permission::SysLoginEntryDestroy,
+            ],
             ObjectType::MailingList => [
                 Permission::SysMailingListCreate,
                 Permission::SysMailingListUpdate,
@@ -4004,6 +4614,11 @@ impl ObjectType {
                 Permission::SysMemoryLookupKeyValueUpdate,
                 Permission::SysMemoryLookupKeyValueDestroy,
             ],
+            ObjectType::MessageAuditEntry => [
+                Permission::SysMessageAuditEntryCreate,
+                Permission::SysMessageAuditEntryUpdate,
+                Permission::SysMessageAuditEntryDestroy,
+            ],
             ObjectType::Metric => [
                 Permission::SysMetricCreate,
                 Permission::SysMetricUpdate,
@@ -4139,6 +4754,11 @@ impl ObjectType {
                 Permission::SysPublicKeyUpdate,
                 Permission::SysPublicKeyDestroy,
             ],
+            ObjectType::QuarantinedMessage => [
+                Permission::SysQuarantinedMessageCreate,
+                Permission::SysQuarantinedMessageUpdate,
+                Permission::SysQuarantinedMessageDestroy,
+            ],
             ObjectType::QueuedMessage => [
                 Permission::SysQueuedMessageCreate,
                 Permission::SysQueuedMessageUpdate,
@@ -4254,6 +4874,11 @@ impl ObjectType {
                 Permission::SysSpfReportSettingsUpdate,
                 Permission::SysSpfReportSettingsUpdate,
             ],
+            ObjectType::StatisticsRollup => [
+                Permission::SysStatisticsRollupCreate,
+                Permission::SysStatisticsRollupUpdate,
+                Permission::SysStatisticsRollupDestroy,
+            ],
             ObjectType::StoreLookup => [
                 Permission::SysStoreLookupCreate,
                 Permission::SysStoreLookupUpdate,
@@ -4279,11 +4904,21 @@ impl ObjectType {
                 Permission::SysTenantUpdate,
                 Permission::SysTenantDestroy,
             ],
+            ObjectType::TenantUsage => [
+                Permission::SysTenantUsageCreate,
+                Permission::SysTenantUsageUpdate,
+                Permission::SysTenantUsageDestroy,
+            ],
             ObjectType::TlsExternalReport => [
                 Permission::SysTlsExternalReportCreate,
                 Permission::SysTlsExternalReportUpdate,
                 Permission::SysTlsExternalReportDestroy,
             ],
+            ObjectType::TlsInboundReport => [
+                Permission::SysTlsInboundReportCreate,
+                Permission::SysTlsInboundReportUpdate,
+                Permission::SysTlsInboundReportDestroy,
+            ],
             ObjectType::TlsInternalReport => [
                 Permission::SysTlsInternalReportCreate,
                 Permission::SysTlsInternalReportUpdate,
@@ -4407,10 +5042,13 @@ impl ObjectInner {
             ObjectInner::DnsServer(DnsServer::Vultr(obj)) => obj.member_tenant_id,
             ObjectInner::DnsServer(DnsServer::WebSupport(obj)) => obj.member_tenant_id,
             ObjectInner::DnsServer(DnsServer::YandexCloud(obj)) => obj.member_tenant_id,
+            ObjectInner::DnsServer(DnsServer::Webhook(obj)) => obj.member_tenant_id,
             ObjectInner::Domain(obj) => obj.member_tenant_id,
+            ObjectInner::DsnTemplate(obj) => obj.member_tenant_id,
             ObjectInner::MailingList(obj) => obj.member_tenant_id,
             ObjectInner::OAuthClient(obj) => obj.member_tenant_id,
             ObjectInner::Role(obj) => obj.member_tenant_id,
+            ObjectInner::TenantUsage(obj) => Some(obj.member_tenant_id),
             ObjectInner::TlsExternalReport(obj) => obj.member_tenant_id,
             _ => None,
         }
@@ -4503,6 +5141,7 @@ impl ObjectInner {
             ObjectInner::DnsServer(DnsServer::Vultr(obj)) => obj.member_tenant_id = Some(id),
             ObjectInner::DnsServer(DnsServer::WebSupport(obj)) => obj.member_tenant_id = Some(id),
             ObjectInner::DnsServer(DnsServer::YandexCloud(obj)) => obj.member_tenant_id = Some(id),
+            ObjectInner::DnsServer(DnsServer::Webhook(obj)) => obj.member_tenant_id = Some(id),
             ObjectInner::Domain(obj) => obj.member_tenant_id = Some(id),
             ObjectInner::MailingList(obj) => obj.member_tenant_id = Some(id),
             ObjectInner::OAuthClient(obj) => obj.member_tenant_id = Some(id),
@@ -4519,8 +5158,10 @@ impl ObjectInner {
             ObjectInner::ArchivedItem(ArchivedItem::CalendarEvent(obj)) => Some(obj.account_id),
             ObjectInner::ArchivedItem(ArchivedItem::ContactCard(obj)) => Some(obj.account_id),
             ObjectInner::ArchivedItem(ArchivedItem::SieveScript(obj)) => Some(obj.account_id),
+            ObjectInner::LoginEntry(obj) => Some(obj.account_id),
             ObjectInner::MaskedEmail(obj) => Some(obj.account_id),
             ObjectInner::PublicKey(obj) => Some(obj.account_id),
+            ObjectInner::QuarantinedMessage(obj) => Some(obj.account_id),
             ObjectInner::SpamTrainingSample(obj) => obj.account_id,
             ObjectInner::Task(Task::IndexDocument(obj)) => Some(obj.account_id),
             ObjectInner::Task(Task::UnindexDocument(obj)) => Some(obj.account_id),
@@ -4531,6 +5172,9 @@ impl ObjectInner {
             ObjectInner::Task(Task::RestoreArchivedItem(obj)) => Some(obj.account_id),
             ObjectInner::Task(Task::DestroyAccount(obj)) => Some(obj.account_id),
             ObjectInner::Task(Task::AccountMaintenance(obj)) => Some(obj.account_id),
+            ObjectInner::Task(Task::AccountExport(obj)) => Some(obj.account_id),
+            ObjectInner::Task(Task::AccountImport(obj)) => Some(obj.account_id),
+            ObjectInner::Task(Task::DataRegionMigration(obj)) => Some(obj.account_id),
             _ => None,
         }
     }
@@ -4542,8 +5186,10 @@ impl ObjectInner {
             ObjectInner::ArchivedItem(ArchivedItem::CalendarEvent(obj)) => obj.account_id = id,
             ObjectInner::ArchivedItem(ArchivedItem::ContactCard(obj)) => obj.account_id = id,
             ObjectInner::ArchivedItem(ArchivedItem::SieveScript(obj)) => obj.account_id = id,
+            ObjectInner::LoginEntry(obj) => obj.account_id = id,
             ObjectInner::MaskedEmail(obj) => obj.account_id = id,
             ObjectInner::PublicKey(obj) => obj.account_id = id,
+            ObjectInner::QuarantinedMessage(obj) => obj.account_id = id,
             ObjectInner::SpamTrainingSample(obj) => obj.account_id = Some(id),
             ObjectInner::Task(Task::IndexDocument(obj)) => obj.account_id = id,
             ObjectInner::Task(Task::UnindexDocument(obj)) => obj.account_id = id,
@@ -4554,6 +5200,9 @@ impl ObjectInner {
             ObjectInner::Task(Task::RestoreArchivedItem(obj)) => obj.account_id = id,
             ObjectInner::Task(Task::DestroyAccount(obj)) => obj.account_id = id,
             ObjectInner::Task(Task::AccountMaintenance(obj)) => obj.account_id = id,
+            ObjectInner::Task(Task::AccountExport(obj)) => obj.account_id = id,
+            ObjectInner::Task(Task::AccountImport(obj)) => obj.account_id = id,
+            ObjectInner::Task(Task::DataRegionMigration(obj)) => obj.account_id = id,
             _ => {}
         }
     }
@@ -4576,6 +5225,7 @@ impl ObjectInner {
             ObjectInner::ArfExternalReport(obj) => obj.to_pickled_vec(),
             ObjectInner::Asn(obj) => obj.to_pickled_vec(),
             ObjectInner::Authentication(obj) => obj.to_pickled_vec(),
+            ObjectInner::BlobIntegrityIssue(obj) => obj.to_pickled_vec(),
             ObjectInner::BlobStore(obj) => obj.to_pickled_vec(),
             ObjectInner::BlockedIp(obj) => obj.to_pickled_vec(),
             ObjectInner::Bootstrap(obj) => obj.to_pickled_vec(),
@@ -4589,6 +5239,7 @@ impl ObjectInner {
             ObjectInner::Coordinator(obj) => obj.to_pickled_vec(),
             ObjectInner::DataRetention(obj) => obj.to_pickled_vec(),
             ObjectInner::DataStore(obj) => obj.to_pickled_vec(),
+            ObjectInner::DeliveryTrace(obj) => obj.to_pickled_vec(),
             ObjectInner::Directory(obj) => obj.to_pickled_vec(),
             ObjectInner::DkimReportSettings(obj) => obj.to_pickled_vec(),
             ObjectInner::DkimSignature(obj) => obj.to_pickled_vec(),
@@ -4599,6 +5250,7 @@ impl ObjectInner {
             ObjectInner::DnsServer(obj) => obj.to_pickled_vec(),
             ObjectInner::Domain(obj) => obj.to_pickled_vec(),
             ObjectInner::DsnReportSettings(obj) => obj.to_pickled_vec(),
+            ObjectInner::DsnTemplate(obj) => obj.to_pickled_vec(),
             ObjectInner::Email(obj) => obj.to_pickled_vec(),
             ObjectInner::Enterprise(obj) => obj.to_pickled_vec(),
             ObjectInner::EventTracingLevel(obj) => obj.to_pickled_vec(),
@@ -4610,10 +5262,12 @@ impl ObjectInner {
             ObjectInner::InMemoryStore(obj) => obj.to_pickled_vec(),
             ObjectInner::Jmap(obj) => obj.to_pickled_vec(),
             ObjectInner::Log(obj) => obj.to_pickled_vec(),
+            ObjectInner::LoginEntry(obj) => obj.to_pickled_vec(),
             ObjectInner::MailingList(obj) => obj.to_pickled_vec(),
             ObjectInner::MaskedEmail(obj) => obj.to_pickled_vec(),
             ObjectInner::MemoryLookupKey(obj) => obj.to_pickled_vec(),
             ObjectInner::MemoryLookupKeyValue(obj) => obj.to_pickled_vec(),
+            ObjectInner::MessageAuditEntry(obj) => obj.to_pickled_vec(),
             ObjectInner::Metric(obj) => obj.to_pickled_vec(),
             ObjectInner::Metrics(obj) => obj.to_pickled_vec(),
             ObjectInner::MetricsStore(obj) => obj.to_pickled_vec(),
@@ -4641,6 +5295,7 @@ impl ObjectInner {
             ObjectInner::OAuthClient(obj) => obj.to_pickled_vec(),
             ObjectInner::OidcProvider(obj) => obj.to_pickled_vec(),
             ObjectInner::PublicKey(obj) => obj.to_pickled_vec(),
+            ObjectInner::QuarantinedMessage(obj) => obj.to_pickled_vec(),
             ObjectInner::QueuedMessage(obj) => obj.to_pickled_vec(),
             ObjectInner::ReportSettings(obj) => obj.to_pickled_vec(),
             ObjectInner::Role(obj) => obj.to_pickled_vec(),
@@ -4664,12 +5319,15 @@ impl ObjectInner {
             ObjectInner::SpamTag(obj) => obj.to_pickled_vec(),
             ObjectInner::SpamTrainingSample(obj) => obj.to_pickled_vec(),
             ObjectInner::SpfReportSettings(obj) => obj.to_pickled_vec(),
+            ObjectInner::StatisticsRollup(obj) => obj.to_pickled_vec(),
             ObjectInner::StoreLookup(obj) => obj.to_pickled_vec(),
             ObjectInner::SystemSettings(obj) => obj.to_pickled_vec(),
             ObjectInner::Task(obj) => obj.to_pickled_vec(),
             ObjectInner::TaskManager(obj) => obj.to_pickled_vec(),
             ObjectInner::Tenant(obj) => obj.to_pickled_vec(),
+            ObjectInner::TenantUsage(obj) => obj.to_pickled_vec(),
             ObjectInner::TlsExternalReport(obj) => obj.to_pickled_vec(),
+            ObjectInner::TlsInboundReport(obj) => obj.to_pickled_vec(),
             ObjectInner::TlsInternalReport(obj) => obj.to_pickled_vec(),
             ObjectInner::TlsReportSettings(obj) => obj.to_pickled_vec(),
             ObjectInner::Trace(obj) => obj.to_pickled_vec(),
@@ -4707,6 +5365,9 @@ impl ObjectInner {
             }
             ObjectType::Asn => Pickle::unpickle(stream).map(ObjectInner::Asn),
             ObjectType::Authentication => Pickle::unpickle(stream).map(ObjectInner::Authentication),
+            ObjectType::BlobIntegrityIssue => {
+                Pickle::unpickle(stream).map(ObjectInner::BlobIntegrityIssue)
+            }
             ObjectType::BlobStore => Pickle::unpickle(stream).map(ObjectInner::BlobStore),
             ObjectType::BlockedIp => Pickle::unpickle(stream).map(ObjectInner::BlockedIp),
             ObjectType::Bootstrap => Pickle::unpickle(stream).map(ObjectInner::Bootstrap),
@@ -4722,6 +5383,7 @@ impl ObjectInner {
             ObjectType::Coordinator => Pickle::unpickle(stream).map(ObjectInner::Coordinator),
             ObjectType::DataRetention => Pickle::unpickle(stream).map(ObjectInner::DataRetention),
             ObjectType::DataStore => Pickle::unpickle(stream).map(ObjectInner::DataStore),
+            ObjectType::DeliveryTrace => Pickle::unpickle(stream).map(ObjectInner::DeliveryTrace),
             ObjectType::Directory => Pickle::unpickle(stream).map(ObjectInner::Directory),
             ObjectType::DkimReportSettings => {
                 Pickle::unpickle(stream).map(ObjectInner::DkimReportSettings)
@@ -4742,6 +5404,7 @@ impl ObjectInner {
             ObjectType::DsnReportSettings => {
                 Pickle::unpickle(stream).map(ObjectInner::DsnReportSettings)
             }
+            ObjectType::DsnTemplate => Pickle::unpickle(stream).map(ObjectInner::DsnTemplate),
             ObjectType::Email => Pickle::unpickle(stream).map(ObjectInner::Email),
             ObjectType::Enterprise => Pickle::unpickle(stream).map(ObjectInner::Enterprise),
             ObjectType::EventTracingLevel => {
@@ -4755,6 +5418,7 @@ impl ObjectInner {
             ObjectType::InMemoryStore => Pickle::unpickle(stream).map(ObjectInner::InMemoryStore),
             ObjectType::Jmap => Pickle::unpickle(stream).map(ObjectInner::Jmap),
             ObjectType::Log => Pickle::unpickle(stream).map(ObjectInner::Log),
+            ObjectType::LoginEntry => Pickle::unpickle(stream).map(ObjectInner::LoginEntry),
             ObjectType::MailingList => Pickle::unpickle(stream).map(ObjectInner::MailingList),
             ObjectType::MaskedEmail => Pickle::unpickle(stream).map(ObjectInner::MaskedEmail),
             ObjectType::MemoryLookupKey => {
@@ -4763,6 +5427,9 @@ impl ObjectInner {
             ObjectType::MemoryLookupKeyValue => {
                 Pickle::unpickle(stream).map(ObjectInner::MemoryLookupKeyValue)
             }
+            ObjectType::MessageAuditEntry => {
+                Pickle::unpickle(stream).map(ObjectInner::MessageAuditEntry)
+            }
             ObjectType::Metric => Pickle::unpickle(stream).map(ObjectInner::Metric),
             ObjectType::Metrics => Pickle::unpickle(stream).map(ObjectInner::Metrics),
             ObjectType::MetricsStore => Pickle::unpickle(stream).map(ObjectInner::MetricsStore),
@@ -4808,6 +5475,9 @@ impl ObjectInner {
             ObjectType::OAuthClient => Pickle::unpickle(stream).map(ObjectInner::OAuthClient),
             ObjectType::OidcProvider => Pickle::unpickle(stream).map(ObjectInner::OidcProvider),
             ObjectType::PublicKey => Pickle::unpickle(stream).map(ObjectInner::PublicKey),
+            ObjectType::QuarantinedMessage => {
+                Pickle::unpickle(stream).map(ObjectInner::QuarantinedMessage)
+            }
             ObjectType::QueuedMessage => Pickle::unpickle(stream).map(ObjectInner::QueuedMessage),
             ObjectType::ReportSettings => Pickle::unpickle(stream).map(ObjectInner::ReportSettings),
             ObjectType::Role => Pickle::unpickle(stream).map(ObjectInner::Role),
@@ -4849,14 +5519,21 @@ impl ObjectInner {
             ObjectType::SpfReportSettings => {
                 Pickle::unpickle(stream).map(ObjectInner::SpfReportSettings)
             }
+            ObjectType::StatisticsRollup => {
+                Pickle::unpickle(stream).map(ObjectInner::StatisticsRollup)
+            }
             ObjectType::StoreLookup => Pickle::unpickle(stream).map(ObjectInner::StoreLookup),
             ObjectType::SystemSettings => Pickle::unpickle(stream).map(ObjectInner::SystemSettings),
             ObjectType::Task => Pickle::unpickle(stream).map(ObjectInner::Task),
             ObjectType::TaskManager => Pickle::unpickle(stream).map(ObjectInner::TaskManager),
             ObjectType::Tenant => Pickle::unpickle(stream).map(ObjectInner::Tenant),
+            ObjectType::TenantUsage => Pickle::unpickle(stream).map(ObjectInner::TenantUsage),
             ObjectType::TlsExternalReport => {
                 Pickle::unpickle(stream).map(ObjectInner::TlsExternalReport)
             }
+            ObjectType::TlsInboundReport => {
+                Pickle::unpickle(stream).map(ObjectInner::TlsInboundReport)
+            }
             ObjectType::TlsInternalReport => {
                 Pickle::unpickle(stream).map(ObjectInner::TlsInternalReport)
             }
@@ -4912,6 +5589,9 @@ impl ObjectInner {
             ObjectType::Authentication => {
                 Authentication::deserialize(deserializer).map(ObjectInner::Authentication)
             }
+            ObjectType::BlobIntegrityIssue => {
+                BlobIntegrityIssue::deserialize(deserializer).map(ObjectInner::BlobIntegrityIssue)
+            }
             ObjectType::BlobStore => {
                 BlobStore::deserialize(deserializer).map(ObjectInner::BlobStore)
             }
@@ -4947,6 +5627,9 @@ impl ObjectInner {
             ObjectType::DataStore => {
                 DataStore::deserialize(deserializer).map(ObjectInner::DataStore)
             }
+            ObjectType::DeliveryTrace => {
+                DeliveryTrace::deserialize(deserializer).map(ObjectInner::DeliveryTrace)
+            }
             ObjectType::Directory => {
                 Directory::deserialize(deserializer).map(ObjectInner::Directory)
             }
@@ -4975,6 +5658,9 @@ impl ObjectInner {
             ObjectType::DsnReportSettings => {
                 DsnReportSettings::deserialize(deserializer).map(ObjectInner::DsnReportSettings)
             }
+            ObjectType::DsnTemplate => {
+                DsnTemplate::deserialize(deserializer).map(ObjectInner::DsnTemplate)
+            }
             ObjectType::Email => Email::deserialize(deserializer).map(ObjectInner::Email),
             ObjectType::Enterprise => {
                 Enterprise::deserialize(deserializer).map(ObjectInner::Enterprise)
@@ -4996,6 +5682,9 @@ impl ObjectInner {
             }
             ObjectType::Jmap => Jmap::deserialize(deserializer).map(ObjectInner::Jmap),
             ObjectType::Log => Log::deserialize(deserializer).map(ObjectInner::Log),
+            ObjectType::LoginEntry => {
+                LoginEntry::deserialize(deserializer).map(ObjectInner::LoginEntry)
+            }
             ObjectType::MailingList => {
                 MailingList::deserialize(deserializer).map(ObjectInner::MailingList)
             }
@@ -5007,6 +5696,9 @@ impl ObjectInner {
             }
             ObjectType::MemoryLookupKeyValue => MemoryLookupKeyValue::deserialize(deserializer)
                 .map(ObjectInner::MemoryLookupKeyValue),
+            ObjectType::MessageAuditEntry => {
+                MessageAuditEntry::deserialize(deserializer).map(ObjectInner::MessageAuditEntry)
+            }
             ObjectType::Metric => Metric::deserialize(deserializer).map(ObjectInner::Metric),
             ObjectType::Metrics => Metrics::deserialize(deserializer).map(ObjectInner::Metrics),
             ObjectType::MetricsStore => {
@@ -5077,6 +5769,9 @@ impl ObjectInner {
             ObjectType::PublicKey => {
                 PublicKey::deserialize(deserializer).map(ObjectInner::PublicKey)
             }
+            ObjectType::QuarantinedMessage => {
+                QuarantinedMessage::deserialize(deserializer).map(ObjectInner::QuarantinedMessage)
+            }
             ObjectType::QueuedMessage => {
                 QueuedMessage::deserialize(deserializer).map(ObjectInner::QueuedMessage)
             }
@@ -5130,6 +5825,9 @@ impl ObjectInner {
             ObjectType::SpfReportSettings => {
                 SpfReportSettings::deserialize(deserializer).map(ObjectInner::SpfReportSettings)
             }
+            ObjectType::StatisticsRollup => {
+                StatisticsRollup::deserialize(deserializer).map(ObjectInner::StatisticsRollup)
+            }
             ObjectType::StoreLookup => {
                 StoreLookup::deserialize(deserializer).map(ObjectInner::StoreLookup)
             }
@@ -5141,9 +5839,15 @@ impl ObjectInner {
                 TaskManager::deserialize(deserializer).map(ObjectInner::TaskManager)
             }
             ObjectType::Tenant => Tenant::deserialize(deserializer).map(ObjectInner::Tenant),
+            ObjectType::TenantUsage => {
+                TenantUsage::deserialize(deserializer).map(ObjectInner::TenantUsage)
+            }
             ObjectType::TlsExternalReport => {
                 TlsExternalReport::deserialize(deserializer).map(ObjectInner::TlsExternalReport)
             }
+            ObjectType::TlsInboundReport => {
+                TlsInboundReport::deserialize(deserializer).map(ObjectInner::TlsInboundReport)
+            }
             ObjectType::TlsInternalReport => {
                 TlsInternalReport::deserialize(deserializer).map(ObjectInner::TlsInternalReport)
             }
@@ -5167,6 +5871,7 @@ impl ObjectInner {
             ObjectInner::DmarcReportSettings(obj) => Some(obj.expression_ctxs()),
             ObjectInner::DsnReportSettings(obj) => Some(obj.expression_ctxs()),
             ObjectInner::Http(obj) => Some(obj.expression_ctxs()),
+            ObjectInner::Imap(obj) => Some(obj.expression_ctxs()),
             ObjectInner::MtaExtensions(obj) => Some(obj.expression_ctxs()),
             ObjectInner::MtaHook(obj) => Some(obj.expression_ctxs()),
             ObjectInner::MtaInboundSession(obj) => Some(obj.expression_ctxs()),
@@ -5212,6 +5917,7 @@ impl Object {
             ObjectInner::ArfExternalReport(_) => ArfExternalReport::FLAGS,
             ObjectInner::Asn(_) => Asn::FLAGS,
             ObjectInner::Authentication(_) => Authentication::FLAGS,
+            ObjectInner::BlobIntegrityIssue(_) => BlobIntegrityIssue::FLAGS,
             ObjectInner::BlobStore(_) => BlobStore::FLAGS,
             ObjectInner::BlockedIp(_) => BlockedIp::FLAGS,
             ObjectInner::Bootstrap(_) => Bootstrap::FLAGS,
@@ -5225,6 +5931,7 @@ impl Object {
             ObjectInner::Coordinator(_) => Coordinator::FLAGS,
             ObjectInner::DataRetention(_) => DataRetention::FLAGS,
             ObjectInner::DataStore(_) => DataStore::FLAGS,
+            ObjectInner::DeliveryTrace(_) => DeliveryTrace::FLAGS,
             ObjectInner::Directory(_) => Directory::FLAGS,
             ObjectInner::DkimReportSettings(_) => DkimReportSettings::FLAGS,
             ObjectInner::DkimSignature(_) => DkimSignature::FLAGS,
@@ -5235,6 +5942,7 @@ impl Object {
             ObjectInner::DnsServer(_) => DnsServer::FLAGS,
             ObjectInner::Domain(_) => Domain::FLAGS,
             ObjectInner::DsnReportSettings(_) => DsnReportSettings::FLAGS,
+            ObjectInner::DsnTemplate(_) => DsnTemplate::FLAGS,
             ObjectInner::Email(_) => Email::FLAGS,
             ObjectInner::Enterprise(_) => Enterprise::FLAGS,
             ObjectInner::EventTracingLevel(_) => EventTracingLevel::FLAGS,
@@ -5246,10 +5954,12 @@ impl Object {
             ObjectInner::InMemoryStore(_) => InMemoryStore::FLAGS,
             ObjectInner::Jmap(_) => Jmap::FLAGS,
             ObjectInner::Log(_) => Log::FLAGS,
+            ObjectInner::LoginEntry(_) => LoginEntry::FLAGS,
             ObjectInner::MailingList(_) => MailingList::FLAGS,
             ObjectInner::MaskedEmail(_) => MaskedEmail::FLAGS,
             ObjectInner::MemoryLookupKey(_) => MemoryLookupKey::FLAGS,
             ObjectInner::MemoryLookupKeyValue(_) => MemoryLookupKeyValue::FLAGS,
+            ObjectInner::MessageAuditEntry(_) => MessageAuditEntry::FLAGS,
             ObjectInner::Metric(_) => Metric::FLAGS,
             ObjectInner::Metrics(_) => Metrics::FLAGS,
             ObjectInner::MetricsStore(_) => MetricsStore::FLAGS,
@@ -5277,6 +5987,7 @@ impl Object {
             ObjectInner::OAuthClient(_) => OAuthClient::FLAGS,
             ObjectInner::OidcProvider(_) => OidcProvider::FLAGS,
             ObjectInner::PublicKey(_) => PublicKey::FLAGS,
+            ObjectInner::QuarantinedMessage(_) => QuarantinedMessage::FLAGS,
             ObjectInner::QueuedMessage(_) => QueuedMessage::FLAGS,
             ObjectInner::ReportSettings(_) => ReportSettings::FLAGS,
             ObjectInner::Role(_) => Role::FLAGS,
@@ -5300,12 +6011,15 @@ impl Object {
             ObjectInner::SpamTag(_) => SpamTag::FLAGS,
             ObjectInner::SpamTrainingSample(_) => SpamTrainingSample::FLAGS,
             ObjectInner::SpfReportSettings(_) => SpfReportSettings::FLAGS,
+            ObjectInner::StatisticsRollup(_) => StatisticsRollup::FLAGS,
             ObjectInner::StoreLookup(_) => StoreLookup::FLAGS,
             ObjectInner::SystemSettings(_) => SystemSettings::FLAGS,
             ObjectInner::Task(_) => Task::FLAGS,
             ObjectInner::TaskManager(_) => TaskManager::FLAGS,
             ObjectInner::Tenant(_) => Tenant::FLAGS,
+            ObjectInner::TenantUsage(_) => TenantUsage::FLAGS,
             ObjectInner::TlsExternalReport(_) => TlsExternalReport::FLAGS,
+            ObjectInner::TlsInboundReport(_) => TlsInboundReport::FLAGS,
             ObjectInner::TlsInternalReport(_) => TlsInternalReport::FLAGS,
             ObjectInner::TlsReportSettings(_) => TlsReportSettings::FLAGS,
             ObjectInner::Trace(_) => Trace::FLAGS,
@@ -5334,6 +6048,7 @@ impl Object {
             ObjectInner::ArfExternalReport(_) => ObjectType::ArfExternalReport,
             ObjectInner::Asn(_) => ObjectType::Asn,
             ObjectInner::Authentication(_) => ObjectType::Authentication,
+            ObjectInner::BlobIntegrityIssue(_) => ObjectType::BlobIntegrityIssue,
             ObjectInner::BlobStore(_) => ObjectType::BlobStore,
             ObjectInner::BlockedIp(_) => ObjectType::BlockedIp,
             ObjectInner::Bootstrap(_) => ObjectType::Bootstrap,
@@ -5347,6 +6062,7 @@ impl Object {
             ObjectInner::Coordinator(_) => ObjectType::Coordinator,
             ObjectInner::DataRetention(_) => ObjectType::DataRetention,
             ObjectInner::DataStore(_) => ObjectType::DataStore,
+            ObjectInner::DeliveryTrace(_) => ObjectType::DeliveryTrace,
             ObjectInner::Directory(_) => ObjectType::Directory,
             ObjectInner::DkimReportSettings(_) => ObjectType::DkimReportSettings,
             ObjectInner::DkimSignature(_) => ObjectType::DkimSignature,
@@ -5357,6 +6073,7 @@ impl Object {
             ObjectInner::DnsServer(_) => ObjectType::DnsServer,
             ObjectInner::Domain(_) => ObjectType::Domain,
             ObjectInner::DsnReportSettings(_) => ObjectType::DsnReportSettings,
+            ObjectInner::DsnTemplate(_) => ObjectType::DsnTemplate,
             ObjectInner::Email(_) => ObjectType::Email,
             ObjectInner::Enterprise(_) => ObjectType::Enterprise,
             ObjectInner::EventTracingLevel(_) => ObjectType::EventTracingLevel,
@@ -5368,10 +6085,12 @@ impl Object {
             ObjectInner::InMemoryStore(_) => ObjectType::InMemoryStore,
             ObjectInner::Jmap(_) => ObjectType::Jmap,
             ObjectInner::Log(_) => ObjectType::Log,
+            ObjectInner::LoginEntry(_) => ObjectType::LoginEntry,
             ObjectInner::MailingList(_) => ObjectType::MailingList,
             ObjectInner::MaskedEmail(_) => ObjectType::MaskedEmail,
             ObjectInner::MemoryLookupKey(_) => ObjectType::MemoryLookupKey,
             ObjectInner::MemoryLookupKeyValue(_) => ObjectType::MemoryLookupKeyValue,
+            ObjectInner::MessageAuditEntry(_) => ObjectType::MessageAuditEntry,
             ObjectInner::Metric(_) => ObjectType::Metric,
             ObjectInner::Metrics(_) => ObjectType::Metrics,
             ObjectInner::MetricsStore(_) => ObjectType::MetricsStore,
@@ -5399,6 +6118,7 @@ impl Object {
             ObjectInner::OAuthClient(_) => ObjectType::OAuthClient,
             ObjectInner::OidcProvider(_) => ObjectType::OidcProvider,
             ObjectInner::PublicKey(_) => ObjectType::PublicKey,
+            ObjectInner::QuarantinedMessage(_) => ObjectType::QuarantinedMessage,
             ObjectInner::QueuedMessage(_) => ObjectType::QueuedMessage,
             ObjectInner::ReportSettings(_) => ObjectType::ReportSettings,
             ObjectInner::Role(_) => ObjectType::Role,
@@ -5422,12 +6142,15 @@ impl Object {
             ObjectInner::SpamTag(_) => ObjectType::SpamTag,
             ObjectInner::SpamTrainingSample(_) => ObjectType::SpamTrainingSample,
             ObjectInner::SpfReportSettings(_) => ObjectType::SpfReportSettings,
+            ObjectInner::StatisticsRollup(_) => ObjectType::StatisticsRollup,
             ObjectInner::StoreLookup(_) => ObjectType::StoreLookup,
             ObjectInner::SystemSettings(_) => ObjectType::SystemSettings,
             ObjectInner::Task(_) => ObjectType::Task,
             ObjectInner::TaskManager(_) => ObjectType::TaskManager,
             ObjectInner::Tenant(_) => ObjectType::Tenant,
+            ObjectInner::TenantUsage(_) => ObjectType::TenantUsage,
             ObjectInner::TlsExternalReport(_) => ObjectType::TlsExternalReport,
+            ObjectInner::TlsInboundReport(_) => ObjectType::TlsInboundReport,
             ObjectInner::TlsInternalReport(_) => ObjectType::TlsInternalReport,
             ObjectInner::TlsReportSettings(_) => ObjectType::TlsReportSettings,
             ObjectInner::Trace(_) => ObjectType::Trace,
@@ -5485,6 +6208,7 @@ impl Object {
             ObjectInner::ArfExternalReport(obj) => obj.validate(errors),
             ObjectInner::Asn(obj) => obj.validate(errors),
             ObjectInner::Authentication(obj) => obj.validate(errors),
+            ObjectInner::BlobIntegrityIssue(obj) => obj.validate(errors),
             ObjectInner::BlobStore(obj) => obj.validate(errors),
             ObjectInner::BlockedIp(obj) => obj.validate(errors),
             ObjectInner::Bootstrap(obj) => obj.validate(errors),
@@ -5498,6 +6222,7 @@ impl Object {
             ObjectInner::Coordinator(obj) => obj.validate(errors),
             ObjectInner::DataRetention(obj) => obj.validate(errors),
             ObjectInner::DataStore(obj) => obj.validate(errors),
+            ObjectInner::DeliveryTrace(obj) => obj.validate(errors),
             ObjectInner::Directory(obj) => obj.validate(errors),
             ObjectInner::DkimReportSettings(obj) => obj.validate(errors),
             ObjectInner::DkimSignature(obj) => obj.validate(errors),
@@ -5508,6 +6233,7 @@ impl Object {
             ObjectInner::DnsServer(obj) => obj.validate(errors),
             ObjectInner::Domain(obj) => obj.validate(errors),
             ObjectInner::DsnReportSettings(obj) => obj.validate(errors),
+            ObjectInner::DsnTemplate(obj) => obj.validate(errors),
             ObjectInner::Email(obj) => obj.validate(errors),
             ObjectInner::Enterprise(obj) => obj.validate(errors),
             ObjectInner::EventTracingLevel(obj) => obj.validate(errors),
@@ -5519,10 +6245,12 @@ impl Object {
             ObjectInner::InMemoryStore(obj) => obj.validate(errors),
             ObjectInner::Jmap(obj) => obj.validate(errors),
             ObjectInner::Log(obj) => obj.validate(errors),
+            ObjectInner::LoginEntry(obj) => obj.validate(errors),
             ObjectInner::MailingList(obj) => obj.validate(errors),
             ObjectInner::MaskedEmail(obj) => obj.validate(errors),
             ObjectInner::MemoryLookupKey(obj) => obj.validate(errors),
             ObjectInner::MemoryLookupKeyValue(obj) => obj.validate(errors),
+            ObjectInner::MessageAuditEntry(obj) => obj.validate(errors),
             ObjectInner::Metric(obj) => obj.validate(errors),
             ObjectInner::Metrics(obj) => obj.validate(errors),
             ObjectInner::MetricsStore(obj) => obj.validate(errors),
@@ -5550,6 +6278,7 @@ impl Object {
             ObjectInner::OAuthClient(obj) => obj.validate(errors),
             ObjectInner::OidcProvider(obj) => obj.validate(errors),
             ObjectInner::PublicKey(obj) => obj.validate(errors),
+            ObjectInner::QuarantinedMessage(obj) => obj.validate(errors),
             ObjectInner::QueuedMessage(obj) => obj.validate(errors),
             ObjectInner::ReportSettings(obj) => obj.validate(errors),
             ObjectInner::Role(obj) => obj.validate(errors),
@@ -5573,12 +6302,15 @@ impl Object {
             ObjectInner::SpamTag(obj) => obj.validate(errors),
             ObjectInner::SpamTrainingSample(obj) => obj.validate(errors),
             ObjectInner::SpfReportSettings(obj) => obj.validate(errors),
+            ObjectInner::StatisticsRollup(obj) => obj.validate(errors),
             ObjectInner::StoreLookup(obj) => obj.validate(errors),
             ObjectInner::SystemSettings(obj) => obj.validate(errors),
             ObjectInner::Task(obj) => obj.validate(errors),
             ObjectInner::TaskManager(obj) => obj.validate(errors),
             ObjectInner::Tenant(obj) => obj.validate(errors),
+            ObjectInner::TenantUsage(obj) => obj.validate(errors),
             ObjectInner::TlsExternalReport(obj) => obj.validate(errors),
+            ObjectInner::TlsInboundReport(obj) => obj.validate(errors),
             ObjectInner::TlsInternalReport(obj) => obj.validate(errors),
             ObjectInner::TlsReportSettings(obj) => obj.validate(errors),
             ObjectInner::Trace(obj) => obj.validate(errors),
@@ -5607,6 +6339,7 @@ impl Object {
             ObjectInner::ArfExternalReport(obj) => obj.index(i),
             ObjectInner::Asn(obj) => obj.index(i),
             ObjectInner::Authentication(obj) => obj.index(i),
+            ObjectInner::BlobIntegrityIssue(obj) => obj.index(i),
             ObjectInner::BlobStore(obj) => obj.index(i),
             ObjectInner::BlockedIp(obj) => obj.index(i),
             ObjectInner::Bootstrap(obj) => obj.index(i),
@@ -5620,6 +6353,7 @@ impl Object {
             ObjectInner::Coordinator(obj) => obj.index(i),
             ObjectInner::DataRetention(obj) => obj.index(i),
             ObjectInner::DataStore(obj) => obj.index(i),
+            ObjectInner::DeliveryTrace(obj) => obj.index(i),
             ObjectInner::Directory(obj) => obj.index(i),
             ObjectInner::DkimReportSettings(obj) => obj.index(i),
             ObjectInner::DkimSignature(obj) => obj.index(i),
@@ -5630,6 +6364,7 @@ impl Object {
             ObjectInner::DnsServer(obj) => obj.index(i),
             ObjectInner::Domain(obj) => obj.index(i),
             ObjectInner::DsnReportSettings(obj) => obj.index(i),
+            ObjectInner::DsnTemplate(obj) => obj.index(i),
             ObjectInner::Email(obj) => obj.index(i),
             ObjectInner::Enterprise(obj) => obj.index(i),
             ObjectInner::EventTracingLevel(obj) => obj.index(i),
@@ -5641,10 +6376,12 @@ impl Object {
             ObjectInner::InMemoryStore(obj) => obj.index(i),
             ObjectInner::Jmap(obj) => obj.index(i),
             ObjectInner::Log(obj) => obj.index(i),
+            ObjectInner::LoginEntry(obj) => obj.index(i),
             ObjectInner::MailingList(obj) => obj.index(i),
             ObjectInner::MaskedEmail(obj) => obj.index(i),
             ObjectInner::MemoryLookupKey(obj) => obj.index(i),
             ObjectInner::MemoryLookupKeyValue(obj) => obj.index(i),
+            ObjectInner::MessageAuditEntry(obj) => obj.index(i),
             ObjectInner::Metric(obj) => obj.index(i),
             ObjectInner::Metrics(obj) => obj.index(i),
             ObjectInner::MetricsStore(obj) => obj.index(i),
@@ -5672,6 +6409,7 @@ impl Object {
             ObjectInner::OAuthClient(obj) => obj.index(i),
             ObjectInner::OidcProvider(obj) => obj.index(i),
             ObjectInner::PublicKey(obj) => obj.index(i),
+            ObjectInner::QuarantinedMessage(obj) => obj.index(i),
             ObjectInner::QueuedMessage(obj) => obj.index(i),
             ObjectInner::ReportSettings(obj) => obj.index(i),
             ObjectInner::Role(obj) => obj.index(i),
@@ -5695,12 +6433,15 @@ impl Object {
             ObjectInner::SpamTag(obj) => obj.index(i),
             ObjectInner::SpamTrainingSample(obj) => obj.index(i),
             ObjectInner::SpfReportSettings(obj) => obj.index(i),
+            ObjectInner::StatisticsRollup(obj) => obj.index(i),
             ObjectInner::StoreLookup(obj) => obj.index(i),
             ObjectInner::SystemSettings(obj) => obj.index(i),
             ObjectInner::Task(obj) => obj.index(i),
             ObjectInner::TaskManager(obj) => obj.index(i),
             ObjectInner::Tenant(obj) => obj.index(i),
+            ObjectInner::TenantUsage(obj) => obj.index(i),
             ObjectInner::TlsExternalReport(obj) => obj.index(i),
+            ObjectInner::TlsInboundReport(obj) => obj.index(i),
             ObjectInner::TlsInternalReport(obj) => obj.index(i),
             ObjectInner::TlsReportSettings(obj) => obj.index(i),
             ObjectInner::Trace(obj) => obj.index(i),
@@ -5733,6 +6474,7 @@ impl Object {
             ObjectInner::ArfExternalReport(obj) => obj.patch(pointer, value),
             ObjectInner::Asn(obj) => obj.patch(pointer, value),
             ObjectInner::Authentication(obj) => obj.patch(pointer, value),
+            ObjectInner::BlobIntegrityIssue(obj) => obj.patch(pointer, value),
             ObjectInner::BlobStore(obj) => obj.patch(pointer, value),
             ObjectInner::BlockedIp(obj) => obj.patch(pointer, value),
             ObjectInner::Bootstrap(obj) => obj.patch(pointer, value),
@@ -5746,6 +6488,7 @@ impl Object {
             ObjectInner::Coordinator(obj) => obj.patch(pointer, value),
             ObjectInner::DataRetention(obj) => obj.patch(pointer, value),
             ObjectInner::DataStore(obj) => obj.patch(pointer, value),
+            ObjectInner::DeliveryTrace(obj) => obj.patch(pointer, value),
             ObjectInner::Directory(obj) => obj.patch(pointer, value),
             ObjectInner::DkimReportSettings(obj) => obj.patch(pointer, value),
             ObjectInner::DkimSignature(obj) => obj.patch(pointer, value),
@@ -5756,6 +6499,7 @@ impl Object {
             ObjectInner::DnsServer(obj) => obj.patch(pointer, value),
             ObjectInner::Domain(obj) => obj.patch(pointer, value),
             ObjectInner::DsnReportSettings(obj) => obj.patch(pointer, value),
+            ObjectInner::DsnTemplate(obj) => obj.patch(pointer, value),
             ObjectInner::Email(obj) => obj.patch(pointer, value),
             ObjectInner::Enterprise(obj) => obj.patch(pointer, value),
             ObjectInner::EventTracingLevel(obj) => obj.patch(pointer, value),
@@ -5767,10 +6511,12 @@ impl Object {
             ObjectInner::InMemoryStore(obj) => obj.patch(pointer, value),
             ObjectInner::Jmap(obj) => obj.patch(pointer, value),
             ObjectInner::Log(obj) => obj.patch(pointer, value),
+            ObjectInner::LoginEntry(obj) => obj.patch(pointer, value),
             ObjectInner::MailingList(obj) => obj.patch(pointer, value),
             ObjectInner::MaskedEmail(obj) => obj.patch(pointer, value),
             ObjectInner::MemoryLookupKey(obj) => obj.patch(pointer, value),
             ObjectInner::MemoryLookupKeyValue(obj) => obj.patch(pointer, value),
+            ObjectInner::MessageAuditEntry(obj) => obj.patch(pointer, value),
             ObjectInner::Metric(obj) => obj.patch(pointer, value),
             ObjectInner::Metrics(obj) => obj.patch(pointer, value),
             ObjectInner::MetricsStore(obj) => obj.patch(pointer, value),
@@ -5798,6 +6544,7 @@ impl Object {
             ObjectInner::OAuthClient(obj) => obj.patch(pointer, value),
             ObjectInner::OidcProvider(obj) => obj.patch(pointer, value),
             ObjectInner::PublicKey(obj) => obj.patch(pointer, value),
+            ObjectInner::QuarantinedMessage(obj) => obj.patch(pointer, value),
             ObjectInner::QueuedMessage(obj) => obj.patch(pointer, value),
             ObjectInner::ReportSettings(obj) => obj.patch(pointer, value),
             ObjectInner::Role(obj) => obj.patch(pointer, value),
@@ -5821,12 +6568,15 @@ impl Object {
             ObjectInner::SpamTag(obj) => obj.patch(pointer, value),
             ObjectInner::SpamTrainingSample(obj) => obj.patch(pointer, value),
             ObjectInner::SpfReportSettings(obj) => obj.patch(pointer, value),
+            ObjectInner::StatisticsRollup(obj) => obj.patch(pointer, value),
             ObjectInner::StoreLookup(obj) => obj.patch(pointer, value),
             ObjectInner::SystemSettings(obj) => obj.patch(pointer, value),
             ObjectInner::Task(obj) => obj.patch(pointer, value),
             ObjectInner::TaskManager(obj) => obj.patch(pointer, value),
             ObjectInner::Tenant(obj) => obj.patch(pointer, value),
+            ObjectInner::TenantUsage(obj) => obj.patch(pointer, value),
             ObjectInner::TlsExternalReport(obj) => obj.patch(pointer, value),
+            ObjectInner::TlsInboundReport(obj) => obj.patch(pointer, value),
             ObjectInner::TlsInternalReport(obj) => obj.patch(pointer, value),
             ObjectInner::TlsReportSettings(obj) => obj.patch(pointer, value),
             ObjectInner::Trace(obj) => obj.patch(pointer, value),
@@ -5857,6 +6607,7 @@ impl IntoValue for Object {
             ObjectInner::ArfExternalReport(obj) => obj.into_value(),
             ObjectInner::Asn(obj) => obj.into_value(),
             ObjectInner::Authentication(obj) => obj.into_value(),
+            ObjectInner::BlobIntegrityIssue(obj) => obj.into_value(),
             ObjectInner::BlobStore(obj) => obj.into_value(),
             ObjectInner::BlockedIp(obj) => obj.into_value(),
             ObjectInner::Bootstrap(obj) => obj.into_value(),
@@ -5870,6 +6621,7 @@ impl IntoValue for Object {
             ObjectInner::Coordinator(obj) => obj.into_value(),
             ObjectInner::DataRetention(obj) => obj.into_value(),
             ObjectInner::DataStore(obj) => obj.into_value(),
+            ObjectInner::DeliveryTrace(obj) => obj.into_value(),
             ObjectInner::Directory(obj) => obj.into_value(),
             ObjectInner::DkimReportSettings(obj) => obj.into_value(),
             ObjectInner::DkimSignature(obj) => obj.into_value(),
@@ -5880,6 +6632,7 @@ impl IntoValue for Object {
             ObjectInner::DnsServer(obj) => obj.into_value(),
             ObjectInner::Domain(obj) => obj.into_value(),
             ObjectInner::DsnReportSettings(obj) => obj.into_value(),
+            ObjectInner::DsnTemplate(obj) => obj.into_value(),
             ObjectInner::Email(obj) => obj.into_value(),
             ObjectInner::Enterprise(obj) => obj.into_value(),
             ObjectInner::EventTracingLevel(obj) => obj.into_value(),
@@ -5891,10 +6644,12 @@ impl IntoValue for Object {
             ObjectInner::InMemoryStore(obj) => obj.into_value(),
             ObjectInner::Jmap(obj) => obj.into_value(),
             ObjectInner::Log(obj) => obj.into_value(),
+            ObjectInner::LoginEntry(obj) => obj.into_value(),
             ObjectInner::MailingList(obj) => obj.into_value(),
             ObjectInner::MaskedEmail(obj) => obj.into_value(),
             ObjectInner::MemoryLookupKey(obj) => obj.into_value(),
             ObjectInner::MemoryLookupKeyValue(obj) => obj.into_value(),
+            ObjectInner::MessageAuditEntry(obj) => obj.into_value(),
             ObjectInner::Metric(obj) => obj.into_value(),
             ObjectInner::Metrics(obj) => obj.into_value(),
             ObjectInner::MetricsStore(obj) => obj.into_value(),
@@ -5922,6 +6677,7 @@ impl IntoValue for Object {
             ObjectInner::OAuthClient(obj) => obj.into_value(),
             ObjectInner::OidcProvider(obj) => obj.into_value(),
             ObjectInner::PublicKey(obj) => obj.into_value(),
+            ObjectInner::QuarantinedMessage(obj) => obj.into_value(),
             ObjectInner::QueuedMessage(obj) => obj.into_value(),
             ObjectInner::ReportSettings(obj) => obj.into_value(),
             ObjectInner::Role(obj) => obj.into_value(),
@@ -5945,12 +6701,15 @@ impl IntoValue for Object {
             ObjectInner::SpamTag(obj) => obj.into_value(),
             ObjectInner::SpamTrainingSample(obj) => obj.into_value(),
             ObjectInner::SpfReportSettings(obj) => obj.into_value(),
+            ObjectInner::StatisticsRollup(obj) => obj.into_value(),
             ObjectInner::StoreLookup(obj) => obj.into_value(),
             ObjectInner::SystemSettings(obj) => obj.into_value(),
             ObjectInner::Task(obj) => obj.into_value(),
             ObjectInner::TaskManager(obj) => obj.into_value(),
             ObjectInner::Tenant(obj) => obj.into_value(),
+            ObjectInner::TenantUsage(obj) => obj.into_value(),
             ObjectInner::TlsExternalReport(obj) => obj.into_value(),
+            ObjectInner::TlsInboundReport(obj) => obj.into_value(),
             ObjectInner::TlsInternalReport(obj) => obj.into_value(),
             ObjectInner::TlsReportSettings(obj) => obj.into_value(),
             ObjectInner::Trace(obj) => obj.into_value(),
@@ -5981,6 +6740,7 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::ArfExternalReport => ObjectInner::ArfExternalReport(Default::default()),
             ObjectType::Asn => ObjectInner::Asn(Default::default()),
             ObjectType::Authentication => ObjectInner::Authentication(Default::default()),
+            ObjectType::BlobIntegrityIssue => ObjectInner::BlobIntegrityIssue(Default::default()),
             ObjectType::BlobStore => ObjectInner::BlobStore(Default::default()),
             ObjectType::BlockedIp => ObjectInner::BlockedIp(Default::default()),
             ObjectType::Bootstrap => ObjectInner::Bootstrap(Default::default()),
@@ -5994,6 +6754,7 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::Coordinator => ObjectInner::Coordinator(Default::default()),
             ObjectType::DataRetention => ObjectInner::DataRetention(Default::default()),
             ObjectType::DataStore => ObjectInner::DataStore(Default::default()),
+            ObjectType::DeliveryTrace => ObjectInner::DeliveryTrace(Default::default()),
             ObjectType::Directory => ObjectInner::Directory(Default::default()),
             ObjectType::DkimReportSettings => ObjectInner::DkimReportSettings(Default::default()),
             ObjectType::DkimSignature => ObjectInner::DkimSignature(Default::default()),
@@ -6004,6 +6765,7 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::DnsServer => ObjectInner::DnsServer(Default::default()),
             ObjectType::Domain => ObjectInner::Domain(Default::default()),
             ObjectType::DsnReportSettings => ObjectInner::DsnReportSettings(Default::default()),
+            ObjectType::DsnTemplate => ObjectInner::DsnTemplate(Default::default()),
             ObjectType::Email => ObjectInner::Email(Default::default()),
             ObjectType::Enterprise => ObjectInner::Enterprise(Default::default()),
             ObjectType::EventTracingLevel => ObjectInner::EventTracingLevel(Default::default()),
@@ -6015,12 +6777,14 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::InMemoryStore => ObjectInner::InMemoryStore(Default::default()),
             ObjectType::Jmap => ObjectInner::Jmap(Default::default()),
             ObjectType::Log => ObjectInner::Log(Default::default()),
+            ObjectType::LoginEntry => ObjectInner::LoginEntry(Default::default()),
             ObjectType::MailingList => ObjectInner::MailingList(Default::default()),
             ObjectType::MaskedEmail => ObjectInner::MaskedEmail(Default::default()),
             ObjectType::MemoryLookupKey => ObjectInner::MemoryLookupKey(Default::default()),
             ObjectType::MemoryLookupKeyValue => {
                 ObjectInner::MemoryLookupKeyValue(Default::default())
             }
+            ObjectType::MessageAuditEntry => ObjectInner::MessageAuditEntry(Default::default()),
             ObjectType::Metric => ObjectInner::Metric(Default::default()),
             ObjectType::Metrics => ObjectInner::Metrics(Default::default()),
             ObjectType::MetricsStore => ObjectInner::MetricsStore(Default::default()),
@@ -6050,6 +6814,7 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::OAuthClient => ObjectInner::OAuthClient(Default::default()),
             ObjectType::OidcProvider => ObjectInner::OidcProvider(Default::default()),
             ObjectType::PublicKey => ObjectInner::PublicKey(Default::default()),
+            ObjectType::QuarantinedMessage => ObjectInner::QuarantinedMessage(Default::default()),
             ObjectType::QueuedMessage => ObjectInner::QueuedMessage(Default::default()),
             ObjectType::ReportSettings => ObjectInner::ReportSettings(Default::default()),
             ObjectType::Role => ObjectInner::Role(Default::default()),
@@ -6077,12 +6842,15 @@ impl From<ObjectType> for ObjectInner {
             ObjectType::SpamTag => ObjectInner::SpamTag(Default::default()),
             ObjectType::SpamTrainingSample => ObjectInner::SpamTrainingSample(Default::default()),
             ObjectType::SpfReportSettings => ObjectInner::SpfReportSettings(Default::default()),
+            ObjectType::StatisticsRollup => ObjectInner::StatisticsRollup(Default::default()),
             ObjectType::StoreLookup => ObjectInner::StoreLookup(Default::default()),
             ObjectType::SystemSettings => ObjectInner::SystemSettings(Default::default()),
             ObjectType::Task => ObjectInner::Task(Default::default()),
             ObjectType::TaskManager => ObjectInner::TaskManager(Default::default()),
             ObjectType::Tenant => ObjectInner::Tenant(Default::default()),
+            ObjectType::TenantUsage => ObjectInner::TenantUsage(Default::default()),
             ObjectType::TlsExternalReport => ObjectInner::TlsExternalReport(Default::default()),
+            ObjectType::TlsInboundReport => ObjectInner::TlsInboundReport(Default::default()),
             ObjectType::TlsInternalReport => ObjectInner::TlsInternalReport(Default::default()),
             ObjectType::TlsReportSettings => ObjectInner::TlsReportSettings(Default::default()),
             ObjectType::Trace => ObjectInner::Trace(Default::default()),
@@ -6334,6 +7102,21 @@ impl From<Object> for Authentication {
     }
 }
 
+impl From<BlobIntegrityIssue> for ObjectInner {
+    fn from(value: BlobIntegrityIssue) -> Self {
+        ObjectInner::BlobIntegrityIssue(value)
+    }
+}
+
+impl From<Object> for BlobIntegrityIssue {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::BlobIntegrityIssue(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<BlobStore> for ObjectInner {
     fn from(value: BlobStore) -> Self {
         ObjectInner::BlobStore(value)
@@ -6529,6 +7312,21 @@ impl From<Object> for DataStore {
     }
 }
 
+impl From<DeliveryTrace> for ObjectInner {
+    fn from(value: DeliveryTrace) -> Self {
+        ObjectInner::DeliveryTrace(value)
+    }
+}
+
+impl From<Object> for DeliveryTrace {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::DeliveryTrace(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<Directory> for ObjectInner {
     fn from(value: Directory) -> Self {
         ObjectInner::Directory(value)
@@ -6679,6 +7477,21 @@ impl From<Object> for DsnReportSettings {
     }
 }
 
+impl From<DsnTemplate> for ObjectInner {
+    fn from(value: DsnTemplate) -> Self {
+        ObjectInner::DsnTemplate(value)
+    }
+}
+
+impl From<Object> for DsnTemplate {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::DsnTemplate(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<Email> for ObjectInner {
     fn from(value: Email) -> Self {
         ObjectInner::Email(value)
@@ -6844,6 +7657,21 @@ impl From<Object> for Log {
     }
 }
 
+impl From<LoginEntry> for ObjectInner {
+    fn from(value: LoginEntry) -> Self {
+        ObjectInner::LoginEntry(value)
+    }
+}
+
+impl From<Object> for LoginEntry {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::LoginEntry(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<MailingList> for ObjectInner {
     fn from(value: MailingList) -> Self {
         ObjectInner::MailingList(value)
@@ -6904,6 +7732,21 @@ impl From<Object> for MemoryLookupKeyValue {
     }
 }
 
+impl From<MessageAuditEntry> for ObjectInner {
+    fn from(value: MessageAuditEntry) -> Self {
+        ObjectInner::MessageAuditEntry(value)
+    }
+}
+
+impl From<Object> for MessageAuditEntry {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::MessageAuditEntry(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<Metric> for ObjectInner {
     fn from(value: Metric) -> Self {
         ObjectInner::Metric(value)
@@ -7309,6 +8152,21 @@ impl From<Object> for PublicKey {
     }
 }
 
+impl From<QuarantinedMessage> for ObjectInner {
+    fn from(value: QuarantinedMessage) -> Self {
+        ObjectInner::QuarantinedMessage(value)
+    }
+}
+
+impl From<Object> for QuarantinedMessage {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::QuarantinedMessage(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<QueuedMessage> for ObjectInner {
     fn from(value: QueuedMessage) -> Self {
         ObjectInner::QueuedMessage(value)
@@ -7654,6 +8512,21 @@ impl From<Object> for SpfReportSettings {
     }
 }
 
+impl From<StatisticsRollup> for ObjectInner {
+    fn from(value: StatisticsRollup) -> Self {
+        ObjectInner::StatisticsRollup(value)
+    }
+}
+
+impl From<Object> for StatisticsRollup {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::StatisticsRollup(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<StoreLookup> for ObjectInner {
     fn from(value: StoreLookup) -> Self {
         ObjectInner::StoreLookup(value)
@@ -7729,6 +8602,21 @@ impl From<Object> for Tenant {
     }
 }
 
+impl From<TenantUsage> for ObjectInner {
+    fn from(value: TenantUsage) -> Self {
+        ObjectInner::TenantUsage(value)
+    }
+}
+
+impl From<Object> for TenantUsage {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::TenantUsage(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<TlsExternalReport> for ObjectInner {
     fn from(value: TlsExternalReport) -> Self {
         ObjectInner::TlsExternalReport(value)
@@ -7744,6 +8632,21 @@ impl From<Object> for TlsExternalReport {
     }
 }
 
+impl From<TlsInboundReport> for ObjectInner {
+    fn from(value: TlsInboundReport) -> Self {
+        ObjectInner::TlsInboundReport(value)
+    }
+}
+
+impl From<Object> for TlsInboundReport {
+    fn from(obj: Object) -> Self {
+        match obj.inner {
+            ObjectInner::TlsInboundReport(obj) => obj,
+            _ => unreachable!(),
+        }
+    }
+}
+
 impl From<TlsInternalReport> for ObjectInner {
     fn from(value: TlsInternalReport) -> Self {
         ObjectInner::TlsInternalReport(value)
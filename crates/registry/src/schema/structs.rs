@@ -36,6 +36,12 @@ pub struct AccountSettings {
     pub time_zone: Option<TimeZone>,
     #[serde(rename = "encryptionAtRest")]
     pub encryption_at_rest: EncryptionAtRest,
+    #[serde(rename = "aliases")]
+    pub aliases: List<EmailAlias>,
+    #[serde(rename = "autoArchiveAfter")]
+    pub auto_archive_after: Option<Duration>,
+    #[serde(rename = "catchAll")]
+    pub catch_all: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -499,6 +505,32 @@ pub struct AzureStore {
     pub key_prefix: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BandwidthLimit {
+    #[serde(rename = "rate")]
+    pub rate: u64,
+    #[serde(rename = "burst")]
+    pub burst: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlobIntegrityIssue {
+    #[serde(rename = "blobHash")]
+    pub blob_hash: String,
+    #[serde(rename = "issue")]
+    pub issue: BlobIntegrityIssueType,
+    #[serde(rename = "size")]
+    pub size: Option<u64>,
+    #[serde(rename = "repaired")]
+    pub repaired: bool,
+    #[serde(rename = "timestamp")]
+    pub timestamp: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum BlobStore {
@@ -510,6 +542,9 @@ pub enum BlobStore {
     FoundationDb(FoundationDbStore),
     PostgreSql(PostgreSqlStore),
     MySql(MySqlStore),
+    Regional(RegionalBlobStore),
+    Tiered(TieredBlobStore),
+    Mirrored(MirroredBlobStore),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -523,6 +558,15 @@ pub enum BlobStoreBase {
     MySql(MySqlStore),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct BlobStoreRegion {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "store")]
+    pub store: BlobStoreBase,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct BlockedIp {
@@ -618,6 +662,8 @@ pub struct Cache {
     pub dkim_signatures: u64,
     #[serde(rename = "negativeTtl")]
     pub negative_ttl: Duration,
+    #[serde(rename = "encryptionKeys")]
+    pub encryption_keys: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -882,6 +928,24 @@ pub struct DataRetention {
     pub hold_metrics_for: Option<Duration>,
     #[serde(rename = "metricsCollectionInterval")]
     pub metrics_collection_interval: Cron,
+    #[serde(rename = "expungeDraftsAfter")]
+    pub expunge_drafts_after: Option<Duration>,
+    #[serde(rename = "holdStatisticsFor")]
+    pub hold_statistics_for: Option<Duration>,
+    #[serde(rename = "holdAuditLogFor")]
+    pub hold_audit_log_for: Option<Duration>,
+    #[serde(rename = "blobScrubInterval")]
+    pub blob_scrub_interval: Option<Duration>,
+    #[serde(rename = "blobScrubRepair")]
+    pub blob_scrub_repair: bool,
+    #[serde(rename = "holdBlobIssuesFor")]
+    pub hold_blob_issues_for: Option<Duration>,
+    #[serde(rename = "holdLoginHistoryFor")]
+    pub hold_login_history_for: Option<Duration>,
+    #[serde(rename = "holdDeliveryTracesFor")]
+    pub hold_delivery_traces_for: Option<Duration>,
+    #[serde(rename = "holdTenantUsageFor")]
+    pub hold_tenant_usage_for: Option<Duration>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -913,6 +977,53 @@ pub struct DeliveryError {
     pub response_message: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeliveryTrace {
+    #[serde(rename = "queueId")]
+    pub queue_id: Id,
+    #[serde(rename = "domain")]
+    pub domain: String,
+    #[serde(rename = "mxHosts")]
+    pub mx_hosts: List<String>,
+    #[serde(rename = "hosts")]
+    pub hosts: List<DeliveryTraceHost>,
+    #[serde(rename = "results")]
+    pub results: List<DeliveryTraceResult>,
+    #[serde(rename = "timestamp")]
+    pub timestamp: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeliveryTraceHost {
+    #[serde(rename = "hostname")]
+    pub hostname: String,
+    #[serde(rename = "resolvedIps")]
+    pub resolved_ips: Map<IpAddr>,
+    #[serde(rename = "remoteIp")]
+    pub remote_ip: Option<IpAddr>,
+    #[serde(rename = "tlsVersion")]
+    pub tls_version: Option<String>,
+    #[serde(rename = "tlsCipher")]
+    pub tls_cipher: Option<String>,
+    #[serde(rename = "capabilities")]
+    pub capabilities: Map<String>,
+    #[serde(rename = "error")]
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DeliveryTraceResult {
+    #[serde(rename = "recipient")]
+    pub recipient: String,
+    #[serde(rename = "status")]
+    pub status: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum Directory {
@@ -961,6 +1072,8 @@ pub struct Dkim1Signature {
     pub next_transition_at: Option<UTCDateTime>,
     #[serde(rename = "stage")]
     pub stage: DkimRotationStage,
+    #[serde(rename = "oversignHeaders")]
+    pub oversign_headers: Map<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1065,6 +1178,19 @@ pub struct DmarcInternalReport {
     pub deliver_at: UTCDateTime,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DmarcOverride {
+    #[serde(rename = "senderDomain")]
+    pub sender_domain: Option<String>,
+    #[serde(rename = "listId")]
+    pub list_id: Option<String>,
+    #[serde(rename = "overrideType")]
+    pub override_type: DmarcPolicyOverride,
+    #[serde(rename = "comment")]
+    pub comment: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct DmarcPolicyOverrideReason {
@@ -1255,6 +1381,10 @@ pub struct DnsCustomResolver {
     pub address: IpAddr,
     #[serde(rename = "port")]
     pub port: u64,
+    #[serde(rename = "tlsServerName")]
+    pub tls_server_name: Option<String>,
+    #[serde(rename = "httpPath")]
+    pub http_path: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1319,6 +1449,8 @@ pub struct DnsResolverCustom {
     pub timeout: Duration,
     #[serde(rename = "tcpOnError")]
     pub tcp_on_error: bool,
+    #[serde(rename = "plaintextFallback")]
+    pub plaintext_fallback: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1338,6 +1470,10 @@ pub struct DnsResolverTls {
     pub timeout: Duration,
     #[serde(rename = "tcpOnError")]
     pub tcp_on_error: bool,
+    #[serde(rename = "useHttps")]
+    pub use_https: bool,
+    #[serde(rename = "plaintextFallback")]
+    pub plaintext_fallback: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -1413,6 +1549,7 @@ pub enum DnsServer {
     Vultr(DnsServerCloud),
     WebSupport(DnsServerWebSupport),
     YandexCloud(DnsServerYandexCloud),
+    Webhook(DnsServerWebhook),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2626,6 +2763,33 @@ pub struct DnsServerWebSupport {
     pub propagation_delay: Option<Duration>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DnsServerWebhook {
+    #[serde(rename = "url")]
+    pub url: String,
+    #[serde(rename = "httpAuth")]
+    pub http_auth: HttpAuth,
+    #[serde(rename = "httpHeaders")]
+    pub http_headers: VecMap<String, String>,
+    #[serde(rename = "allowInvalidCerts")]
+    pub allow_invalid_certs: bool,
+    #[serde(rename = "description")]
+    pub description: String,
+    #[serde(rename = "memberTenantId")]
+    pub member_tenant_id: Option<Id>,
+    #[serde(rename = "timeout")]
+    pub timeout: Duration,
+    #[serde(rename = "ttl")]
+    pub ttl: Duration,
+    #[serde(rename = "pollingInterval")]
+    pub polling_interval: Duration,
+    #[serde(rename = "propagationTimeout")]
+    pub propagation_timeout: Duration,
+    #[serde(rename = "propagationDelay")]
+    pub propagation_delay: Option<Duration>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct DnsServerYandexCloud {
@@ -2682,6 +2846,18 @@ pub struct Domain {
     pub allow_relaying: bool,
     #[serde(rename = "reportAddressUri")]
     pub report_address_uri: Option<String>,
+    #[serde(rename = "showVirtualFolders")]
+    pub show_virtual_folders: bool,
+    #[serde(rename = "defaultFolders")]
+    pub default_folders: VecMap<SpecialUse, EmailFolder>,
+    #[serde(rename = "requireMfa")]
+    pub require_mfa: bool,
+    #[serde(rename = "coldStorageAfter")]
+    pub cold_storage_after: Option<Duration>,
+    #[serde(rename = "coldStorageArchive")]
+    pub cold_storage_archive: bool,
+    #[serde(rename = "autoArchiveAfter")]
+    pub auto_archive_after: Option<Duration>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2695,6 +2871,23 @@ pub struct DsnReportSettings {
     pub dkim_sign_domain: Expression,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct DsnTemplate {
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "domainId")]
+    pub domain_id: Option<Id>,
+    #[serde(rename = "memberTenantId")]
+    pub member_tenant_id: Option<Id>,
+    #[serde(rename = "locale")]
+    pub locale: Option<Locale>,
+    #[serde(rename = "subject")]
+    pub subject: Option<String>,
+    #[serde(rename = "body")]
+    pub body: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ElasticSearchStore {
@@ -2747,6 +2940,38 @@ pub struct Email {
     pub max_masked_addresses: Option<u64>,
     #[serde(rename = "maxPublicKeys")]
     pub max_public_keys: Option<u64>,
+    #[serde(rename = "virtualFolders")]
+    pub virtual_folders: VecMap<VirtualFolderType, EmailFolder>,
+    #[serde(rename = "recentFolderPeriod")]
+    pub recent_folder_period: Duration,
+    #[serde(rename = "maxDrafts")]
+    pub max_drafts: Option<u64>,
+    #[serde(rename = "maxDraftsSize")]
+    pub max_drafts_size: Option<u64>,
+    #[serde(rename = "repairMime")]
+    pub repair_mime: bool,
+    #[serde(rename = "encryptionKeyProvider")]
+    pub encryption_key_provider: EncryptionKeyProvider,
+    #[serde(rename = "encryptionKeyCacheTtl")]
+    pub encryption_key_cache_ttl: Duration,
+    #[serde(rename = "searchFolders")]
+    pub search_folders: List<EmailSearchFolder>,
+    #[serde(rename = "duplicateWindow")]
+    pub duplicate_window: Option<Duration>,
+    #[serde(rename = "duplicateMatch")]
+    pub duplicate_match: DuplicateMatch,
+    #[serde(rename = "sharedMailboxDestroy")]
+    pub shared_mailbox_destroy: SharedMailboxDestroy,
+    #[serde(rename = "maxMessageKeywords")]
+    pub max_message_keywords: u64,
+    #[serde(rename = "maxCustomKeywords")]
+    pub max_custom_keywords: u64,
+    #[serde(rename = "subscribeOnImapCreate")]
+    pub subscribe_on_imap_create: bool,
+    #[serde(rename = "subscribeOnJmapCreate")]
+    pub subscribe_on_jmap_create: bool,
+    #[serde(rename = "subscribeOnAutoCreate")]
+    pub subscribe_on_auto_create: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2760,6 +2985,8 @@ pub struct EmailAlias {
     pub domain_id: Id,
     #[serde(rename = "description")]
     pub description: Option<String>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: Option<UTCDateTime>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2773,6 +3000,21 @@ pub struct EmailFolder {
     pub subscribe: bool,
     #[serde(rename = "aliases")]
     pub aliases: Map<String>,
+    #[serde(rename = "localizedNames")]
+    pub localized_names: VecMap<Locale, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EmailSearchFolder {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "query")]
+    pub query: String,
+    #[serde(rename = "role")]
+    pub role: SearchFolderRole,
+    #[serde(rename = "subscribe")]
+    pub subscribe: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2783,6 +3025,13 @@ pub enum EncryptionAtRest {
     Aes256(EncryptionSettings),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "@type")]
+pub enum EncryptionKeyProvider {
+    Disabled,
+    Vault(SecretKeyVault),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct EncryptionSettings {
@@ -2867,6 +3116,10 @@ pub struct FoundationDbStore {
     pub transaction_retry_limit: Option<u64>,
     #[serde(rename = "transactionTimeout")]
     pub transaction_timeout: Option<Duration>,
+    #[serde(rename = "partitionMode")]
+    pub partition_mode: FoundationDbPartitionMode,
+    #[serde(rename = "directoryPrefix")]
+    pub directory_prefix: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2909,6 +3162,10 @@ pub struct GroupAccount {
     pub locale: Locale,
     #[serde(rename = "timeZone")]
     pub time_zone: Option<TimeZone>,
+    #[serde(rename = "dataRegion")]
+    pub data_region: Option<String>,
+    #[serde(rename = "requireMfa")]
+    pub require_mfa: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -2928,6 +3185,16 @@ pub struct Http {
     pub response_headers: VecMap<String, String>,
     #[serde(rename = "useXForwarded")]
     pub use_x_forwarded: bool,
+    #[serde(rename = "rateLimitClient")]
+    pub rate_limit_client: Option<Rate>,
+    #[serde(rename = "rateLimitUpload")]
+    pub rate_limit_upload: Option<Rate>,
+    #[serde(rename = "rateLimitQuery")]
+    pub rate_limit_query: Option<Rate>,
+    #[serde(rename = "rateLimitSet")]
+    pub rate_limit_set: Option<Rate>,
+    #[serde(rename = "rateLimitAdmin")]
+    pub rate_limit_admin: Option<Rate>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3058,6 +3325,14 @@ pub struct Imap {
     pub timeout_authenticated: Duration,
     #[serde(rename = "timeoutIdle")]
     pub timeout_idle: Duration,
+    #[serde(rename = "accountBandwidth")]
+    pub account_bandwidth: Option<BandwidthLimit>,
+    #[serde(rename = "clientPolicy")]
+    pub client_policy: Expression,
+    #[serde(rename = "appendSieve")]
+    pub append_sieve: bool,
+    #[serde(rename = "appendSpamFilter")]
+    pub append_spam_filter: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3067,6 +3342,7 @@ pub enum InMemoryStore {
     Sharded(ShardedInMemoryStore),
     Redis(RedisStore),
     RedisCluster(RedisClusterStore),
+    RedisSentinel(RedisSentinelStore),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3074,6 +3350,7 @@ pub enum InMemoryStore {
 pub enum InMemoryStoreBase {
     Redis(RedisStore),
     RedisCluster(RedisClusterStore),
+    RedisSentinel(RedisSentinelStore),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3135,6 +3412,14 @@ pub struct Jmap {
     pub websocket_timeout: Duration,
     #[serde(rename = "maxSubscriptions")]
     pub max_subscriptions: Option<u64>,
+    #[serde(rename = "pushVapidKey")]
+    pub push_vapid_key: SecretTextOptional,
+    #[serde(rename = "pushVapidSubject")]
+    pub push_vapid_subject: Option<String>,
+    #[serde(rename = "eventSourceReplayWindow")]
+    pub event_source_replay_window: Option<Duration>,
+    #[serde(rename = "snippetMaxLength")]
+    pub snippet_max_length: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3241,6 +3526,25 @@ pub struct Log {
     pub details: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct LoginEntry {
+    #[serde(rename = "accountId")]
+    pub account_id: Id,
+    #[serde(rename = "protocol")]
+    pub protocol: NetworkListenerProtocol,
+    #[serde(rename = "remoteIp")]
+    pub remote_ip: IpAddr,
+    #[serde(rename = "userAgent")]
+    pub user_agent: Option<String>,
+    #[serde(rename = "fingerprint")]
+    pub fingerprint: String,
+    #[serde(rename = "timestamp")]
+    pub timestamp: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum LookupStore {
@@ -3250,6 +3554,7 @@ pub enum LookupStore {
     Sharded(ShardedInMemoryStore),
     Redis(RedisStore),
     RedisCluster(RedisClusterStore),
+    RedisSentinel(RedisSentinelStore),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3276,6 +3581,18 @@ pub struct MailingList {
     pub member_tenant_id: Option<Id>,
     #[serde(rename = "recipients")]
     pub recipients: Map<String>,
+    #[serde(rename = "senderRewrite")]
+    pub sender_rewrite: MailingListSenderRewrite,
+    #[serde(rename = "subjectPrefix")]
+    pub subject_prefix: Option<String>,
+    #[serde(rename = "replyTo")]
+    pub reply_to: MailingListReplyTo,
+    #[serde(rename = "membersOnly")]
+    pub members_only: bool,
+    #[serde(rename = "archiveAccountId")]
+    pub archive_account_id: Option<Id>,
+    #[serde(rename = "archiveFolder")]
+    pub archive_folder: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3346,6 +3663,25 @@ pub struct MemoryLookupKeyValue {
     pub is_glob_pattern: bool,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MessageAuditEntry {
+    #[serde(rename = "accountId")]
+    pub account_id: Id,
+    #[serde(rename = "event")]
+    pub event: MessageAuditEvent,
+    #[serde(rename = "documentId")]
+    pub document_id: Id,
+    #[serde(rename = "performedBy")]
+    pub performed_by: Option<Id>,
+    #[serde(rename = "details")]
+    pub details: Option<String>,
+    #[serde(rename = "timestamp")]
+    pub timestamp: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum Metric {
@@ -3447,6 +3783,19 @@ pub enum MetricsStore {
     MySql(MySqlStore),
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MirroredBlobStore {
+    #[serde(rename = "primaryStore")]
+    pub primary_store: BlobStoreBase,
+    #[serde(rename = "mirrorStore")]
+    pub mirror_store: BlobStoreBase,
+    #[serde(rename = "writeMode")]
+    pub write_mode: BlobMirrorMode,
+    #[serde(rename = "maxQueueSize")]
+    pub max_queue_size: u64,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MtaConnectionIpHost {
@@ -3563,6 +3912,10 @@ pub struct MtaExtensions {
     pub require_tls: Expression,
     #[serde(rename = "vrfy")]
     pub vrfy: Expression,
+    #[serde(rename = "vrfyRate")]
+    pub vrfy_rate: Expression,
+    #[serde(rename = "expnRate")]
+    pub expn_rate: Expression,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3658,6 +4011,10 @@ pub struct MtaOutboundStrategy {
     pub schedule: Expression,
     #[serde(rename = "tls")]
     pub tls: Expression,
+    #[serde(rename = "deliveryMode")]
+    pub delivery_mode: MtaDeliveryMode,
+    #[serde(rename = "smarthost")]
+    pub smarthost: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3698,6 +4055,7 @@ pub enum MtaRoute {
     Mx(MtaRouteMx),
     Relay(MtaRouteRelay),
     Local(MtaRouteCommon),
+    LmtpExternal(MtaRouteLmtpExternal),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3709,6 +4067,31 @@ pub struct MtaRouteCommon {
     pub description: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MtaRouteLmtpExternal {
+    #[serde(rename = "address")]
+    pub address: String,
+    #[serde(rename = "authSecret")]
+    pub auth_secret: SecretKeyOptional,
+    #[serde(rename = "authUsername")]
+    pub auth_username: Option<String>,
+    #[serde(rename = "port")]
+    pub port: u64,
+    #[serde(rename = "allowInvalidCerts")]
+    pub allow_invalid_certs: bool,
+    #[serde(rename = "implicitTls")]
+    pub implicit_tls: bool,
+    #[serde(rename = "deliverLocal")]
+    pub deliver_local: bool,
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "description")]
+    pub description: Option<String>,
+    #[serde(rename = "tlsFingerprint")]
+    pub tls_fingerprint: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct MtaRouteMx {
@@ -3722,6 +4105,16 @@ pub struct MtaRouteMx {
     pub name: String,
     #[serde(rename = "description")]
     pub description: Option<String>,
+    #[serde(rename = "proxyAddress")]
+    pub proxy_address: Option<String>,
+    #[serde(rename = "proxyPort")]
+    pub proxy_port: u64,
+    #[serde(rename = "proxyProtocol")]
+    pub proxy_protocol: MtaProxyProtocol,
+    #[serde(rename = "proxyUsername")]
+    pub proxy_username: Option<String>,
+    #[serde(rename = "proxySecret")]
+    pub proxy_secret: SecretKeyOptional,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3745,6 +4138,18 @@ pub struct MtaRouteRelay {
     pub name: String,
     #[serde(rename = "description")]
     pub description: Option<String>,
+    #[serde(rename = "tlsFingerprint")]
+    pub tls_fingerprint: Option<String>,
+    #[serde(rename = "proxyAddress")]
+    pub proxy_address: Option<String>,
+    #[serde(rename = "proxyPort")]
+    pub proxy_port: u64,
+    #[serde(rename = "proxyProtocol")]
+    pub proxy_protocol: MtaProxyProtocol,
+    #[serde(rename = "proxyUsername")]
+    pub proxy_username: Option<String>,
+    #[serde(rename = "proxySecret")]
+    pub proxy_secret: SecretKeyOptional,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3760,6 +4165,12 @@ pub struct MtaStageAuth {
     pub must_match_sender: Expression,
     #[serde(rename = "require")]
     pub require: Expression,
+    #[serde(rename = "mustMatchTenantDomain")]
+    pub must_match_tenant_domain: Expression,
+    #[serde(rename = "tenantDomainExemptions")]
+    pub tenant_domain_exemptions: Map<String>,
+    #[serde(rename = "senderMismatchAction")]
+    pub sender_mismatch_action: MtaSenderMismatchAction,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3800,6 +4211,30 @@ pub struct MtaStageData {
     pub script: Expression,
     #[serde(rename = "enableSpamFilter")]
     pub enable_spam_filter: Expression,
+    #[serde(rename = "largeMessageSize")]
+    pub large_message_size: Expression,
+    #[serde(rename = "enableUserScriptReject")]
+    pub enable_user_script_reject: Expression,
+    #[serde(rename = "maxHeaderCount")]
+    pub max_header_count: Expression,
+    #[serde(rename = "maxHeaderSize")]
+    pub max_header_size: Expression,
+    #[serde(rename = "maxMimeDepth")]
+    pub max_mime_depth: Expression,
+    #[serde(rename = "maxMimeParts")]
+    pub max_mime_parts: Expression,
+    #[serde(rename = "structureLimitAction")]
+    pub structure_limit_action: MtaStructureLimitAction,
+    #[serde(rename = "receivedHeaderFormat")]
+    pub received_header_format: Expression,
+    #[serde(rename = "scrubTraceHeaders")]
+    pub scrub_trace_headers: Expression,
+    #[serde(rename = "traceHeaders")]
+    pub trace_headers: Map<String>,
+    #[serde(rename = "rejectBareLf")]
+    pub reject_bare_lf: Expression,
+    #[serde(rename = "rejectBareCr")]
+    pub reject_bare_cr: Expression,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3839,6 +4274,14 @@ pub struct MtaStageRcpt {
     pub rewrite: Expression,
     #[serde(rename = "script")]
     pub script: Expression,
+    #[serde(rename = "callout")]
+    pub callout: Expression,
+    #[serde(rename = "calloutTimeout")]
+    pub callout_timeout: Duration,
+    #[serde(rename = "calloutCacheTtl")]
+    pub callout_cache_ttl: Duration,
+    #[serde(rename = "calloutNegativeCacheTtl")]
+    pub callout_negative_cache_ttl: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -3882,6 +4325,10 @@ pub struct MtaVirtualQueue {
     pub description: Option<String>,
     #[serde(rename = "threadsPerNode")]
     pub threads_per_node: u64,
+    #[serde(rename = "priorityThreadsPerNode")]
+    pub priority_threads_per_node: u64,
+    #[serde(rename = "lowPriorityThreadsPerNode")]
+    pub low_priority_threads_per_node: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4000,6 +4447,18 @@ pub struct NetworkListener {
     pub tls_timeout: Option<Duration>,
     #[serde(rename = "maxConnections")]
     pub max_connections: Option<u64>,
+    #[serde(rename = "bandwidth")]
+    pub bandwidth: Option<BandwidthLimit>,
+    #[serde(rename = "tlsClientCa")]
+    pub tls_client_ca: Option<PublicText>,
+    #[serde(rename = "tlsClientAuth")]
+    pub tls_client_auth: bool,
+    #[serde(rename = "tlsProfile")]
+    pub tls_profile: TlsProfile,
+    #[serde(rename = "tlsDisableKeyExchangeGroups")]
+    pub tls_disable_key_exchange_groups: Map<TlsKeyExchangeGroup>,
+    #[serde(rename = "tlsPreferPostQuantum")]
+    pub tls_prefer_post_quantum: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4023,6 +4482,14 @@ pub struct OAuthClient {
     pub redirect_uris: Map<String>,
     #[serde(rename = "logo")]
     pub logo: Option<String>,
+    #[serde(rename = "allowedGrantTypes")]
+    pub allowed_grant_types: Map<OAuthGrantType>,
+    #[serde(rename = "allowedScopes")]
+    pub allowed_scopes: Map<String>,
+    #[serde(rename = "accessTokenExpiry")]
+    pub access_token_expiry: Option<Duration>,
+    #[serde(rename = "refreshTokenExpiry")]
+    pub refresh_token_expiry: Option<Duration>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4196,6 +4663,25 @@ pub struct PublicTextValue {
     pub value: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct QuarantinedMessage {
+    #[serde(rename = "accountId")]
+    pub account_id: Id,
+    #[serde(rename = "blobId")]
+    pub blob_id: BlobId,
+    #[serde(rename = "from")]
+    pub from: String,
+    #[serde(rename = "subject")]
+    pub subject: String,
+    #[serde(rename = "size")]
+    pub size: u64,
+    #[serde(rename = "receivedAt")]
+    pub received_at: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum QueueExpiry {
@@ -4318,6 +4804,39 @@ pub struct RedisClusterStore {
     pub pool_timeout_recycle: Option<Duration>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RedisSentinelStore {
+    #[serde(rename = "urls")]
+    pub urls: Map<String>,
+    #[serde(rename = "masterName")]
+    pub master_name: String,
+    #[serde(rename = "useTls")]
+    pub use_tls: bool,
+    #[serde(rename = "timeout")]
+    pub timeout: Duration,
+    #[serde(rename = "authUsername")]
+    pub auth_username: Option<String>,
+    #[serde(rename = "authSecret")]
+    pub auth_secret: SecretKeyOptional,
+    #[serde(rename = "maxRetryWait")]
+    pub max_retry_wait: Option<Duration>,
+    #[serde(rename = "minRetryWait")]
+    pub min_retry_wait: Option<Duration>,
+    #[serde(rename = "maxRetries")]
+    pub max_retries: Option<u64>,
+    #[serde(rename = "protocolVersion")]
+    pub protocol_version: RedisProtocol,
+    #[serde(rename = "poolMaxConnections")]
+    pub pool_max_connections: u64,
+    #[serde(rename = "poolTimeoutCreate")]
+    pub pool_timeout_create: Option<Duration>,
+    #[serde(rename = "poolTimeoutWait")]
+    pub pool_timeout_wait: Option<Duration>,
+    #[serde(rename = "poolTimeoutRecycle")]
+    pub pool_timeout_recycle: Option<Duration>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct RedisStore {
@@ -4335,6 +4854,15 @@ pub struct RedisStore {
     pub pool_timeout_recycle: Option<Duration>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct RegionalBlobStore {
+    #[serde(rename = "regions")]
+    pub regions: List<BlobStoreRegion>,
+    #[serde(rename = "defaultRegion")]
+    pub default_region: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct ReportSettings {
@@ -4346,6 +4874,8 @@ pub struct ReportSettings {
     pub outbound_report_domain: Option<String>,
     #[serde(rename = "outboundReportSubmitter")]
     pub outbound_report_submitter: Expression,
+    #[serde(rename = "inboundTlsFailureRate")]
+    pub inbound_tls_failure_rate: Option<Rate>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4359,6 +4889,10 @@ pub struct RocksDbStore {
     pub buffer_size: u64,
     #[serde(rename = "poolWorkers")]
     pub pool_workers: Option<u64>,
+    #[serde(rename = "compactionRateLimit")]
+    pub compaction_rate_limit: Option<u64>,
+    #[serde(rename = "maxBackgroundJobs")]
+    pub max_background_jobs: Option<u64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4494,6 +5028,12 @@ pub struct Search {
     pub index_telemetry: bool,
     #[serde(rename = "indexTracingFields")]
     pub index_tracing_fields: Map<SearchTracingField>,
+    #[serde(rename = "indexMaxBodySize")]
+    pub index_max_body_size: Option<u64>,
+    #[serde(rename = "reindexBatchSize")]
+    pub reindex_batch_size: u64,
+    #[serde(rename = "reindexInterval")]
+    pub reindex_interval: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4532,6 +5072,33 @@ pub enum SecretKey {
     Value(SecretKeyValue),
     EnvironmentVariable(SecretKeyEnvironmentVariable),
     File(SecretKeyFile),
+    Vault(SecretKeyVault),
+    AwsSecretsManager(SecretKeyAwsSecretsManager),
+    EnvironmentJson(SecretKeyEnvironmentJson),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SecretKeyAwsSecretsManager {
+    #[serde(rename = "region")]
+    pub region: String,
+    #[serde(rename = "secretId")]
+    pub secret_id: String,
+    #[serde(rename = "key")]
+    pub key: Option<String>,
+    #[serde(rename = "accessKey")]
+    pub access_key: Option<String>,
+    #[serde(rename = "secretKey")]
+    pub secret_key: SecretKeyLocalOptional,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SecretKeyEnvironmentJson {
+    #[serde(rename = "variableName")]
+    pub variable_name: String,
+    #[serde(rename = "key")]
+    pub key: String,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4548,6 +5115,15 @@ pub struct SecretKeyFile {
     pub file_path: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "@type")]
+pub enum SecretKeyLocalOptional {
+    None,
+    Value(SecretKeyValue),
+    EnvironmentVariable(SecretKeyEnvironmentVariable),
+    File(SecretKeyFile),
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum SecretKeyOptional {
@@ -4555,6 +5131,9 @@ pub enum SecretKeyOptional {
     Value(SecretKeyValue),
     EnvironmentVariable(SecretKeyEnvironmentVariable),
     File(SecretKeyFile),
+    Vault(SecretKeyVault),
+    AwsSecretsManager(SecretKeyAwsSecretsManager),
+    EnvironmentJson(SecretKeyEnvironmentJson),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4564,12 +5143,30 @@ pub struct SecretKeyValue {
     pub secret: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SecretKeyVault {
+    #[serde(rename = "url")]
+    pub url: String,
+    #[serde(rename = "path")]
+    pub path: String,
+    #[serde(rename = "key")]
+    pub key: String,
+    #[serde(rename = "token")]
+    pub token: SecretKeyLocalOptional,
+    #[serde(rename = "namespace")]
+    pub namespace: Option<String>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(tag = "@type")]
 pub enum SecretText {
     Text(SecretTextValue),
     EnvironmentVariable(SecretKeyEnvironmentVariable),
     File(SecretKeyFile),
+    Vault(SecretKeyVault),
+    AwsSecretsManager(SecretKeyAwsSecretsManager),
+    EnvironmentJson(SecretKeyEnvironmentJson),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4579,6 +5176,9 @@ pub enum SecretTextOptional {
     Text(SecretTextValue),
     EnvironmentVariable(SecretKeyEnvironmentVariable),
     File(SecretKeyFile),
+    Vault(SecretKeyVault),
+    AwsSecretsManager(SecretKeyAwsSecretsManager),
+    EnvironmentJson(SecretKeyEnvironmentJson),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4609,6 +5209,16 @@ pub struct Security {
     pub scan_ban_rate: Option<Rate>,
     #[serde(rename = "scanBanPeriod")]
     pub scan_ban_period: Option<Duration>,
+    #[serde(rename = "authDelay")]
+    pub auth_delay: Option<Duration>,
+    #[serde(rename = "authDelayMax")]
+    pub auth_delay_max: Duration,
+    #[serde(rename = "authDelayReset")]
+    pub auth_delay_reset: Duration,
+    #[serde(rename = "smugglingBanRate")]
+    pub smuggling_ban_rate: Option<Rate>,
+    #[serde(rename = "ipv6PrefixLength")]
+    pub ipv6_prefix_length: u64,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4630,6 +5240,8 @@ pub struct SenderAuth {
     pub dmarc_verify: Expression,
     #[serde(rename = "reverseIpVerify")]
     pub reverse_ip_verify: Expression,
+    #[serde(rename = "dmarcOverrides")]
+    pub dmarc_overrides: List<DmarcOverride>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4774,6 +5386,22 @@ pub struct SieveUserInterpreter {
     pub max_var_size: u64,
     #[serde(rename = "maxScripts")]
     pub max_scripts: Option<u64>,
+    #[serde(rename = "allowedExtLists")]
+    pub allowed_ext_lists: Map<String>,
+    #[serde(rename = "maxTotalScriptSize")]
+    pub max_total_script_size: Option<u64>,
+    #[serde(rename = "redirectRateLimit")]
+    pub redirect_rate_limit: Option<Rate>,
+    #[serde(rename = "maxNotifyActions")]
+    pub max_notify_actions: u64,
+    #[serde(rename = "maxRunTime")]
+    pub max_run_time: Duration,
+    #[serde(rename = "notifySignatureKey")]
+    pub notify_signature_key: SecretKeyOptional,
+    #[serde(rename = "notifyGatewayUrl")]
+    pub notify_gateway_url: Option<String>,
+    #[serde(rename = "notifyTimeout")]
+    pub notify_timeout: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -4787,6 +5415,8 @@ pub struct SieveUserScript {
     pub is_active: bool,
     #[serde(rename = "contents")]
     pub contents: String,
+    #[serde(rename = "domainId")]
+    pub domain_id: Option<Id>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5010,6 +5640,10 @@ pub struct SpamDnsblSettings {
     pub ip_limit: u64,
     #[serde(rename = "urlLimit")]
     pub url_limit: u64,
+    #[serde(rename = "redirectLimit")]
+    pub redirect_limit: u64,
+    #[serde(rename = "redirectTimeout")]
+    pub redirect_timeout: Duration,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5212,6 +5846,12 @@ pub struct SpamSettings {
     pub trust_replies: bool,
     #[serde(rename = "spamFilterRulesUrl")]
     pub spam_filter_rules_url: Option<String>,
+    #[serde(rename = "scoreQuarantine")]
+    pub score_quarantine: Float,
+    #[serde(rename = "quarantineFor")]
+    pub quarantine_for: Duration,
+    #[serde(rename = "quarantineDigestFrequency")]
+    pub quarantine_digest_frequency: Option<Duration>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5319,6 +5959,42 @@ pub struct SqliteStore {
     pub pool_max_connections: u64,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticsCounter {
+    #[serde(rename = "name")]
+    pub name: String,
+    #[serde(rename = "count")]
+    pub count: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct StatisticsRollup {
+    #[serde(rename = "period")]
+    pub period: StatisticsPeriod,
+    #[serde(rename = "from")]
+    pub from: UTCDateTime,
+    #[serde(rename = "to")]
+    pub to: UTCDateTime,
+    #[serde(rename = "messagesReceived")]
+    pub messages_received: u64,
+    #[serde(rename = "messagesSubmitted")]
+    pub messages_submitted: u64,
+    #[serde(rename = "hamMessages")]
+    pub ham_messages: u64,
+    #[serde(rename = "spamMessages")]
+    pub spam_messages: u64,
+    #[serde(rename = "authFailures")]
+    pub auth_failures: u64,
+    #[serde(rename = "topSenders")]
+    pub top_senders: List<StatisticsCounter>,
+    #[serde(rename = "topRecipients")]
+    pub top_recipients: List<StatisticsCounter>,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct StoreLookup {
@@ -5364,6 +6040,8 @@ pub struct SystemSettings {
     pub services: VecMap<ServiceProtocol, Service>,
     #[serde(rename = "providerInfo")]
     pub provider_info: VecMap<ProviderInfo, String>,
+    #[serde(rename = "ipv6Only")]
+    pub ipv6_only: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5387,6 +6065,9 @@ pub enum Task {
     AcmeRenewal(TaskDomainManagement),
     DkimManagement(TaskDomainManagement),
     DnsManagement(TaskDnsManagement),
+    AccountExport(TaskAccountMigration),
+    AccountImport(TaskAccountMigration),
+    DataRegionMigration(TaskDataRegionMigration),
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5400,6 +6081,17 @@ pub struct TaskAccountMaintenance {
     pub status: TaskStatus,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskAccountMigration {
+    #[serde(rename = "accountId")]
+    pub account_id: Id,
+    #[serde(rename = "path")]
+    pub path: String,
+    #[serde(rename = "status")]
+    pub status: TaskStatus,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TaskCalendarAlarmEmail {
@@ -5468,6 +6160,17 @@ pub struct TaskCalendarItipMessage {
     pub status: TaskStatus,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskDataRegionMigration {
+    #[serde(rename = "accountId")]
+    pub account_id: Id,
+    #[serde(rename = "dataRegion")]
+    pub data_region: String,
+    #[serde(rename = "status")]
+    pub status: TaskStatus,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TaskDestroyAccount {
@@ -5536,6 +6239,19 @@ pub struct TaskIndexTrace {
     pub status: TaskStatus,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TaskMaintenanceWindow {
+    #[serde(rename = "taskTypes")]
+    pub task_types: Map<TaskType>,
+    #[serde(rename = "schedule")]
+    pub schedule: Cron,
+    #[serde(rename = "duration")]
+    pub duration: Duration,
+    #[serde(rename = "rate")]
+    pub rate: Option<Rate>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TaskManager {
@@ -5545,6 +6261,10 @@ pub struct TaskManager {
     pub strategy: TaskRetryStrategy,
     #[serde(rename = "totalDeadline")]
     pub total_deadline: Duration,
+    #[serde(rename = "maintenanceWindows")]
+    pub maintenance_windows: List<TaskMaintenanceWindow>,
+    #[serde(rename = "migrationDirectory")]
+    pub migration_directory: Option<String>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5702,6 +6422,42 @@ pub struct Tenant {
     pub permissions: Permissions,
     #[serde(rename = "quotas")]
     pub quotas: VecMap<TenantStorageQuota, u64>,
+    #[serde(rename = "dataRegion")]
+    pub data_region: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TenantUsage {
+    #[serde(rename = "memberTenantId")]
+    pub member_tenant_id: Id,
+    #[serde(rename = "from")]
+    pub from: UTCDateTime,
+    #[serde(rename = "to")]
+    pub to: UTCDateTime,
+    #[serde(rename = "storageUsed")]
+    pub storage_used: u64,
+    #[serde(rename = "messagesSent")]
+    pub messages_sent: u64,
+    #[serde(rename = "messagesReceived")]
+    pub messages_received: u64,
+    #[serde(rename = "bytesSent")]
+    pub bytes_sent: u64,
+    #[serde(rename = "bytesReceived")]
+    pub bytes_received: u64,
+    #[serde(rename = "apiCalls")]
+    pub api_calls: u64,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TieredBlobStore {
+    #[serde(rename = "hotStore")]
+    pub hot_store: BlobStoreBase,
+    #[serde(rename = "coldStore")]
+    pub cold_store: BlobStoreBase,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -5744,6 +6500,19 @@ pub struct TlsFailureDetails {
     pub failure_reason_code: Option<String>,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TlsInboundReport {
+    #[serde(rename = "domain")]
+    pub domain: String,
+    #[serde(rename = "report")]
+    pub report: TlsReport,
+    #[serde(rename = "createdAt")]
+    pub created_at: UTCDateTime,
+    #[serde(rename = "expiresAt")]
+    pub expires_at: UTCDateTime,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(default)]
 pub struct TlsInternalReport {
@@ -6105,6 +6874,18 @@ pub struct UserAccount {
     pub time_zone: Option<TimeZone>,
     #[serde(rename = "encryptionAtRest")]
     pub encryption_at_rest: EncryptionAtRest,
+    #[serde(rename = "dataRegion")]
+    pub data_region: Option<String>,
+    #[serde(rename = "undoSendWindow")]
+    pub undo_send_window: Option<Duration>,
+    #[serde(rename = "allowedNetworks")]
+    pub allowed_networks: Map<IpAddrOrMask>,
+    #[serde(rename = "allowedCountries")]
+    pub allowed_countries: Map<String>,
+    #[serde(rename = "allowedAsns")]
+    pub allowed_asns: Map<u64>,
+    #[serde(rename = "autoArchiveAfter")]
+    pub auto_archive_after: Option<Duration>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -10,7 +10,7 @@ use crate::schema::prelude::*;
 
 impl ObjectImpl for Account {
     const FLAGS: u64 = OBJ_FILTER_TENANT | OBJ_SEQ_ID;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 5;
     const OBJECT: ObjectType = ObjectType::Account;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -213,6 +213,10 @@ impl ObjectImpl for AccountSettings {
         }
         let value = &self.encryption_at_rest;
         value.validate(errors);
+        let value = &self.aliases;
+        for value in value.values() {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -230,6 +234,9 @@ impl Pickle for AccountSettings {
         self.locale.pickle(out);
         self.time_zone.pickle(out);
         self.encryption_at_rest.pickle(out);
+        self.aliases.pickle(out);
+        self.auto_archive_after.pickle(out);
+        self.catch_all.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -238,6 +245,9 @@ impl Pickle for AccountSettings {
         this.locale = Pickle::unpickle(stream)?;
         this.time_zone = Pickle::unpickle(stream)?;
         this.encryption_at_rest = Pickle::unpickle(stream)?;
+        this.aliases = Pickle::unpickle(stream)?;
+        this.auto_archive_after = Pickle::unpickle(stream)?;
+        this.catch_all = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -249,13 +259,16 @@ impl Default for AccountSettings {
             locale: Locale::EnUS,
             time_zone: Default::default(),
             encryption_at_rest: Default::default(),
+            aliases: Default::default(),
+            auto_archive_after: Default::default(),
+            catch_all: Default::default(),
         }
     }
 }
 
 impl IntoValue for AccountSettings {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(8);
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::Locale, self.locale.into_value());
         map.insert_unchecked(Property::TimeZone, self.time_zone.into_value());
@@ -263,6 +276,12 @@ impl IntoValue for AccountSettings {
             Property::EncryptionAtRest,
             self.encryption_at_rest.into_value(),
         );
+        map.insert_unchecked(Property::Aliases, self.aliases.into_value());
+        map.insert_unchecked(
+            Property::AutoArchiveAfter,
+            self.auto_archive_after.into_value(),
+        );
+        map.insert_unchecked(Property::CatchAll, self.catch_all.into_value());
         JmapValue::Object(map)
     }
 }
@@ -278,6 +297,9 @@ impl RegistryJsonPropertyPatch for AccountSettings {
             Some(Property::Locale) => self.locale.patch(pointer, value),
             Some(Property::TimeZone) => self.time_zone.patch(pointer, value),
             Some(Property::EncryptionAtRest) => self.encryption_at_rest.patch(pointer, value),
+            Some(Property::Aliases) => self.aliases.patch(pointer, value),
+            Some(Property::AutoArchiveAfter) => self.auto_archive_after.patch(pointer, value),
+            Some(Property::CatchAll) => self.catch_all.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -3594,9 +3616,168 @@ impl RegistryJsonPropertyPatch for AzureStore {
     }
 }
 
+impl BandwidthLimit {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.rate;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::Rate, 1));
+        }
+        let value = &self.burst;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::Burst, 1));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for BandwidthLimit {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.rate.pickle(out);
+        self.burst.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.rate = Pickle::unpickle(stream)?;
+        this.burst = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for BandwidthLimit {
+    fn default() -> Self {
+        Self {
+            rate: 1048576,
+            burst: 4194304,
+        }
+    }
+}
+
+impl IntoValue for BandwidthLimit {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::Rate, self.rate.into_value());
+        map.insert_unchecked(Property::Burst, self.burst.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for BandwidthLimit {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Rate) => self.rate.patch(pointer, value),
+            Some(Property::Burst) => self.burst.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl ObjectImpl for BlobIntegrityIssue {
+    const FLAGS: u64 = 0;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::BlobIntegrityIssue;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        if self.blob_hash.is_empty() {
+            errors.push(ValidationError::required(Property::BlobHash));
+        }
+        let value = &self.timestamp;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::Timestamp, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for BlobIntegrityIssue {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.blob_hash.pickle(out);
+        self.issue.pickle(out);
+        self.size.pickle(out);
+        self.repaired.pickle(out);
+        self.timestamp.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.blob_hash = Pickle::unpickle(stream)?;
+        this.issue = Pickle::unpickle(stream)?;
+        this.size = Pickle::unpickle(stream)?;
+        this.repaired = Pickle::unpickle(stream)?;
+        this.timestamp = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for BlobIntegrityIssue {
+    fn default() -> Self {
+        Self {
+            blob_hash: Default::default(),
+            issue: Default::default(),
+            size: Default::default(),
+            repaired: false,
+            timestamp: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for BlobIntegrityIssue {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(8);
+        map.insert_unchecked(Property::BlobHash, self.blob_hash.into_value());
+        map.insert_unchecked(Property::Issue, self.issue.into_value());
+        map.insert_unchecked(Property::Size, self.size.into_value());
+        map.insert_unchecked(Property::Repaired, self.repaired.into_value());
+        map.insert_unchecked(Property::Timestamp, self.timestamp.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for BlobIntegrityIssue {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::BlobHash) => self.blob_hash.patch(pointer, value),
+            Some(Property::Issue) => self.issue.patch(pointer, value),
+            Some(Property::Size) => self.size.patch(pointer, value),
+            Some(Property::Repaired) => self.repaired.patch(pointer, value),
+            Some(Property::Timestamp) => self.timestamp.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for BlobStore {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 1;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::BlobStore;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -3609,6 +3790,9 @@ impl ObjectImpl for BlobStore {
             BlobStore::FoundationDb(inner) => inner.validate(errors),
             BlobStore::PostgreSql(inner) => inner.validate(errors),
             BlobStore::MySql(inner) => inner.validate(errors),
+            BlobStore::Regional(inner) => inner.validate(errors),
+            BlobStore::Tiered(inner) => inner.validate(errors),
+            BlobStore::Mirrored(inner) => inner.validate(errors),
         }
     }
 
@@ -3655,6 +3839,18 @@ impl Pickle for BlobStore {
                 7u16.pickle(out);
                 inner.pickle(out);
             }
+            BlobStore::Regional(inner) => {
+                8u16.pickle(out);
+                inner.pickle(out);
+            }
+            BlobStore::Tiered(inner) => {
+                9u16.pickle(out);
+                inner.pickle(out);
+            }
+            BlobStore::Mirrored(inner) => {
+                10u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -3668,6 +3864,9 @@ impl Pickle for BlobStore {
             5 => Pickle::unpickle(stream).map(BlobStore::FoundationDb),
             6 => Pickle::unpickle(stream).map(BlobStore::PostgreSql),
             7 => Pickle::unpickle(stream).map(BlobStore::MySql),
+            8 => Pickle::unpickle(stream).map(BlobStore::Regional),
+            9 => Pickle::unpickle(stream).map(BlobStore::Tiered),
+            10 => Pickle::unpickle(stream).map(BlobStore::Mirrored),
             _ => None,
         }
     }
@@ -3730,6 +3929,27 @@ impl IntoValue for BlobStore {
                     .insert_unchecked(Property::Type, JmapValue::Str("MySql".into()));
                 obj
             }
+            BlobStore::Regional(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Regional".into()));
+                obj
+            }
+            BlobStore::Tiered(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Tiered".into()));
+                obj
+            }
+            BlobStore::Mirrored(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Mirrored".into()));
+                obj
+            }
         }
     }
 }
@@ -3750,6 +3970,9 @@ impl RegistryJsonPatch for BlobStore {
                 BlobStoreType::FoundationDb => *self = BlobStore::FoundationDb(Default::default()),
                 BlobStoreType::PostgreSql => *self = BlobStore::PostgreSql(Default::default()),
                 BlobStoreType::MySql => *self = BlobStore::MySql(Default::default()),
+                BlobStoreType::Regional => *self = BlobStore::Regional(Default::default()),
+                BlobStoreType::Tiered => *self = BlobStore::Tiered(Default::default()),
+                BlobStoreType::Mirrored => *self = BlobStore::Mirrored(Default::default()),
             }
         }
         match self {
@@ -3761,6 +3984,9 @@ impl RegistryJsonPatch for BlobStore {
             BlobStore::FoundationDb(inner) => inner.patch(pointer, value),
             BlobStore::PostgreSql(inner) => inner.patch(pointer, value),
             BlobStore::MySql(inner) => inner.patch(pointer, value),
+            BlobStore::Regional(inner) => inner.patch(pointer, value),
+            BlobStore::Tiered(inner) => inner.patch(pointer, value),
+            BlobStore::Mirrored(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -3776,6 +4002,9 @@ impl BlobStore {
             BlobStore::FoundationDb(_) => BlobStoreType::FoundationDb,
             BlobStore::PostgreSql(_) => BlobStoreType::PostgreSql,
             BlobStore::MySql(_) => BlobStoreType::MySql,
+            BlobStore::Regional(_) => BlobStoreType::Regional,
+            BlobStore::Tiered(_) => BlobStoreType::Tiered,
+            BlobStore::Mirrored(_) => BlobStoreType::Mirrored,
         }
     }
 }
@@ -3937,6 +4166,68 @@ impl BlobStoreBase {
     }
 }
 
+impl BlobStoreRegion {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.name;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Name));
+        }
+        self.store.validate(errors);
+        errors.len() == neb
+    }
+}
+
+impl Pickle for BlobStoreRegion {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.name.pickle(out);
+        self.store.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.name = Pickle::unpickle(stream)?;
+        this.store = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for BlobStoreRegion {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            store: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for BlobStoreRegion {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::Name, self.name.into_value());
+        map.insert_unchecked(Property::Store, self.store.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for BlobStoreRegion {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Name) => self.name.patch(pointer, value),
+            Some(Property::Store) => self.store.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for BlockedIp {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -4173,7 +4464,7 @@ impl RegistryJsonPropertyPatch for Bootstrap {
 
 impl ObjectImpl for Cache {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::Cache;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -4318,6 +4609,7 @@ impl Pickle for Cache {
         self.mailing_lists.pickle(out);
         self.dkim_signatures.pickle(out);
         self.negative_ttl.pickle(out);
+        self.encryption_keys.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -4348,6 +4640,9 @@ impl Pickle for Cache {
         this.mailing_lists = Pickle::unpickle(stream)?;
         this.dkim_signatures = Pickle::unpickle(stream)?;
         this.negative_ttl = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.encryption_keys = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -4381,13 +4676,14 @@ impl Default for Cache {
             mailing_lists: 2097152,
             dkim_signatures: 10485760,
             negative_ttl: Duration::from_millis(3600000),
+            encryption_keys: 1048576,
         }
     }
 }
 
 impl IntoValue for Cache {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(28);
+        let mut map = jmap_tools::Map::with_capacity(29);
         map.insert_unchecked(Property::AccessTokens, self.access_tokens.into_value());
         map.insert_unchecked(Property::Contacts, self.contacts.into_value());
         map.insert_unchecked(Property::DnsIpv4, self.dns_ipv4.into_value());
@@ -4420,6 +4716,7 @@ impl IntoValue for Cache {
         map.insert_unchecked(Property::MailingLists, self.mailing_lists.into_value());
         map.insert_unchecked(Property::DkimSignatures, self.dkim_signatures.into_value());
         map.insert_unchecked(Property::NegativeTtl, self.negative_ttl.into_value());
+        map.insert_unchecked(Property::EncryptionKeys, self.encryption_keys.into_value());
         JmapValue::Object(map)
     }
 }
@@ -4459,6 +4756,7 @@ impl RegistryJsonPropertyPatch for Cache {
             Some(Property::MailingLists) => self.mailing_lists.patch(pointer, value),
             Some(Property::DkimSignatures) => self.dkim_signatures.patch(pointer, value),
             Some(Property::NegativeTtl) => self.negative_ttl.patch(pointer, value),
+            Some(Property::EncryptionKeys) => self.encryption_keys.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -6477,7 +6775,7 @@ impl RegistryJsonPropertyPatch for CustomRoles {
 
 impl ObjectImpl for DataRetention {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 7;
     const OBJECT: ObjectType = ObjectType::DataRetention;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -6512,6 +6810,15 @@ impl Pickle for DataRetention {
         self.hold_traces_for.pickle(out);
         self.hold_metrics_for.pickle(out);
         self.metrics_collection_interval.pickle(out);
+        self.expunge_drafts_after.pickle(out);
+        self.hold_statistics_for.pickle(out);
+        self.hold_audit_log_for.pickle(out);
+        self.blob_scrub_interval.pickle(out);
+        self.blob_scrub_repair.pickle(out);
+        self.hold_blob_issues_for.pickle(out);
+        self.hold_login_history_for.pickle(out);
+        self.hold_delivery_traces_for.pickle(out);
+        self.hold_tenant_usage_for.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -6530,6 +6837,29 @@ impl Pickle for DataRetention {
         this.hold_traces_for = Pickle::unpickle(stream)?;
         this.hold_metrics_for = Pickle::unpickle(stream)?;
         this.metrics_collection_interval = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.expunge_drafts_after = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.hold_statistics_for = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.hold_audit_log_for = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.blob_scrub_interval = Pickle::unpickle(stream)?;
+            this.blob_scrub_repair = Pickle::unpickle(stream)?;
+            this.hold_blob_issues_for = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 5 {
+            this.hold_login_history_for = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 6 {
+            this.hold_delivery_traces_for = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 7 {
+            this.hold_tenant_usage_for = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -6560,13 +6890,22 @@ impl Default for DataRetention {
             hold_traces_for: Some(Duration::from_millis(2592000000)),
             hold_metrics_for: Some(Duration::from_millis(7776000000)),
             metrics_collection_interval: Cron::Hourly(CronHourly { minute: 0u64 }),
+            expunge_drafts_after: Default::default(),
+            hold_statistics_for: Some(Duration::from_millis(15552000000)),
+            hold_audit_log_for: None,
+            blob_scrub_interval: None,
+            blob_scrub_repair: true,
+            hold_blob_issues_for: Some(Duration::from_millis(2592000000)),
+            hold_login_history_for: Default::default(),
+            hold_delivery_traces_for: Default::default(),
+            hold_tenant_usage_for: Some(Duration::from_millis(34560000000)),
         }
     }
 }
 
 impl IntoValue for DataRetention {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(25);
         map.insert_unchecked(
             Property::ExpungeTrashAfter,
             self.expunge_trash_after.into_value(),
@@ -6617,6 +6956,42 @@ impl IntoValue for DataRetention {
             Property::MetricsCollectionInterval,
             self.metrics_collection_interval.into_value(),
         );
+        map.insert_unchecked(
+            Property::ExpungeDraftsAfter,
+            self.expunge_drafts_after.into_value(),
+        );
+        map.insert_unchecked(
+            Property::HoldStatisticsFor,
+            self.hold_statistics_for.into_value(),
+        );
+        map.insert_unchecked(
+            Property::HoldAuditLogFor,
+            self.hold_audit_log_for.into_value(),
+        );
+        map.insert_unchecked(
+            Property::BlobScrubInterval,
+            self.blob_scrub_interval.into_value(),
+        );
+        map.insert_unchecked(
+            Property::BlobScrubRepair,
+            self.blob_scrub_repair.into_value(),
+        );
+        map.insert_unchecked(
+            Property::HoldBlobIssuesFor,
+            self.hold_blob_issues_for.into_value(),
+        );
+        map.insert_unchecked(
+            Property::HoldLoginHistoryFor,
+            self.hold_login_history_for.into_value(),
+        );
+        map.insert_unchecked(
+            Property::HoldDeliveryTracesFor,
+            self.hold_delivery_traces_for.into_value(),
+        );
+        map.insert_unchecked(
+            Property::HoldTenantUsageFor,
+            self.hold_tenant_usage_for.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -6654,6 +7029,19 @@ impl RegistryJsonPropertyPatch for DataRetention {
             Some(Property::MetricsCollectionInterval) => {
                 self.metrics_collection_interval.patch(pointer, value)
             }
+            Some(Property::ExpungeDraftsAfter) => self.expunge_drafts_after.patch(pointer, value),
+            Some(Property::HoldStatisticsFor) => self.hold_statistics_for.patch(pointer, value),
+            Some(Property::HoldAuditLogFor) => self.hold_audit_log_for.patch(pointer, value),
+            Some(Property::BlobScrubInterval) => self.blob_scrub_interval.patch(pointer, value),
+            Some(Property::BlobScrubRepair) => self.blob_scrub_repair.patch(pointer, value),
+            Some(Property::HoldBlobIssuesFor) => self.hold_blob_issues_for.patch(pointer, value),
+            Some(Property::HoldLoginHistoryFor) => {
+                self.hold_login_history_for.patch(pointer, value)
+            }
+            Some(Property::HoldDeliveryTracesFor) => {
+                self.hold_delivery_traces_for.patch(pointer, value)
+            }
+            Some(Property::HoldTenantUsageFor) => self.hold_tenant_usage_for.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -6665,7 +7053,7 @@ impl RegistryJsonPropertyPatch for DataRetention {
 
 impl ObjectImpl for DataStore {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::DataStore;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -6928,6 +7316,280 @@ impl RegistryJsonPropertyPatch for DeliveryError {
     }
 }
 
+impl ObjectImpl for DeliveryTrace {
+    const FLAGS: u64 = 0;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::DeliveryTrace;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.domain;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Domain));
+        }
+        let value = &self.hosts;
+        for value in value.values() {
+            value.validate(errors);
+        }
+        let value = &self.results;
+        for value in value.values() {
+            value.validate(errors);
+        }
+        let value = &self.timestamp;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::Timestamp, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for DeliveryTrace {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.queue_id.pickle(out);
+        self.domain.pickle(out);
+        self.mx_hosts.pickle(out);
+        self.hosts.pickle(out);
+        self.results.pickle(out);
+        self.timestamp.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.queue_id = Pickle::unpickle(stream)?;
+        this.domain = Pickle::unpickle(stream)?;
+        this.mx_hosts = Pickle::unpickle(stream)?;
+        this.hosts = Pickle::unpickle(stream)?;
+        this.results = Pickle::unpickle(stream)?;
+        this.timestamp = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for DeliveryTrace {
+    fn default() -> Self {
+        Self {
+            queue_id: Default::default(),
+            domain: Default::default(),
+            mx_hosts: Default::default(),
+            hosts: Default::default(),
+            results: Default::default(),
+            timestamp: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for DeliveryTrace {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(9);
+        map.insert_unchecked(Property::QueueId, self.queue_id.into_value());
+        map.insert_unchecked(Property::Domain, self.domain.into_value());
+        map.insert_unchecked(Property::MxHosts, self.mx_hosts.into_value());
+        map.insert_unchecked(Property::Hosts, self.hosts.into_value());
+        map.insert_unchecked(Property::Results, self.results.into_value());
+        map.insert_unchecked(Property::Timestamp, self.timestamp.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for DeliveryTrace {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::QueueId) => self.queue_id.patch(pointer, value),
+            Some(Property::Domain) => self.domain.patch(pointer, value),
+            Some(Property::MxHosts) => self.mx_hosts.patch(pointer, value),
+            Some(Property::Hosts) => self.hosts.patch(pointer, value),
+            Some(Property::Results) => self.results.patch(pointer, value),
+            Some(Property::Timestamp) => self.timestamp.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl DeliveryTraceHost {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.hostname;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Hostname));
+        }
+        if let Some(value) = &self.tls_version {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::TlsVersion));
+            }
+        }
+        if let Some(value) = &self.tls_cipher {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::TlsCipher));
+            }
+        }
+        if let Some(value) = &self.error {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Error));
+            }
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for DeliveryTraceHost {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.hostname.pickle(out);
+        self.resolved_ips.pickle(out);
+        self.remote_ip.pickle(out);
+        self.tls_version.pickle(out);
+        self.tls_cipher.pickle(out);
+        self.capabilities.pickle(out);
+        self.error.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.hostname = Pickle::unpickle(stream)?;
+        this.resolved_ips = Pickle::unpickle(stream)?;
+        this.remote_ip = Pickle::unpickle(stream)?;
+        this.tls_version = Pickle::unpickle(stream)?;
+        this.tls_cipher = Pickle::unpickle(stream)?;
+        this.capabilities = Pickle::unpickle(stream)?;
+        this.error = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for DeliveryTraceHost {
+    fn default() -> Self {
+        Self {
+            hostname: Default::default(),
+            resolved_ips: Default::default(),
+            remote_ip: Default::default(),
+            tls_version: Default::default(),
+            tls_cipher: Default::default(),
+            capabilities: Default::default(),
+            error: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for DeliveryTraceHost {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(9);
+        map.insert_unchecked(Property::Hostname, self.hostname.into_value());
+        map.insert_unchecked(Property::ResolvedIps, self.resolved_ips.into_value());
+        map.insert_unchecked(Property::RemoteIp, self.remote_ip.into_value());
+        map.insert_unchecked(Property::TlsVersion, self.tls_version.into_value());
+        map.insert_unchecked(Property::TlsCipher, self.tls_cipher.into_value());
+        map.insert_unchecked(Property::Capabilities, self.capabilities.into_value());
+        map.insert_unchecked(Property::Error, self.error.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for DeliveryTraceHost {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Hostname) => self.hostname.patch(pointer, value),
+            Some(Property::ResolvedIps) => self.resolved_ips.patch(pointer, value),
+            Some(Property::RemoteIp) => self.remote_ip.patch(pointer, value),
+            Some(Property::TlsVersion) => self.tls_version.patch(pointer, value),
+            Some(Property::TlsCipher) => self.tls_cipher.patch(pointer, value),
+            Some(Property::Capabilities) => self.capabilities.patch(pointer, value),
+            Some(Property::Error) => self.error.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl DeliveryTraceResult {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.recipient;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Recipient));
+        }
+        let value = &self.status;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Status));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for DeliveryTraceResult {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.recipient.pickle(out);
+        self.status.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.recipient = Pickle::unpickle(stream)?;
+        this.status = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for DeliveryTraceResult {
+    fn default() -> Self {
+        Self {
+            recipient: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for DeliveryTraceResult {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::Recipient, self.recipient.into_value());
+        map.insert_unchecked(Property::Status, self.status.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for DeliveryTraceResult {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Recipient) => self.recipient.patch(pointer, value),
+            Some(Property::Status) => self.status.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for Directory {
     const FLAGS: u64 = OBJ_FILTER_TENANT;
     const VERSION: u8 = 0;
@@ -7212,6 +7874,12 @@ impl Dkim1Signature {
                 errors.push(ValidationError::invalid(Property::NextTransitionAt, value));
             }
         }
+        let value = &self.oversign_headers;
+        for value in value.iter() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::OversignHeaders));
+            }
+        }
         errors.len() == neb
     }
 
@@ -7241,6 +7909,7 @@ impl Pickle for Dkim1Signature {
         self.created_at.pickle(out);
         self.next_transition_at.pickle(out);
         self.stage.pickle(out);
+        self.oversign_headers.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -7259,6 +7928,9 @@ impl Pickle for Dkim1Signature {
         this.created_at = Pickle::unpickle(stream)?;
         this.next_transition_at = Pickle::unpickle(stream)?;
         this.stage = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.oversign_headers = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -7286,13 +7958,14 @@ impl Default for Dkim1Signature {
             created_at: Default::default(),
             next_transition_at: Default::default(),
             stage: DkimRotationStage::Active,
+            oversign_headers: Default::default(),
         }
     }
 }
 
 impl IntoValue for Dkim1Signature {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(17);
         map.insert_unchecked(Property::Auid, self.auid.into_value());
         map.insert_unchecked(
             Property::Canonicalization,
@@ -7313,6 +7986,10 @@ impl IntoValue for Dkim1Signature {
             self.next_transition_at.into_value(),
         );
         map.insert_unchecked(Property::Stage, self.stage.into_value());
+        map.insert_unchecked(
+            Property::OversignHeaders,
+            self.oversign_headers.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -7349,6 +8026,7 @@ impl RegistryJsonPropertyPatch for Dkim1Signature {
             Some(Property::CreatedAt) => pointer.assert_server_set(),
             Some(Property::NextTransitionAt) => self.next_transition_at.patch(pointer, value),
             Some(Property::Stage) => self.stage.patch(pointer, value),
+            Some(Property::OversignHeaders) => self.oversign_headers.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -7710,7 +8388,7 @@ impl RegistryJsonPropertyPatch for DkimReportSettings {
 
 impl ObjectImpl for DkimSignature {
     const FLAGS: u64 = OBJ_FILTER_TENANT;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::DkimSignature;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -8196,6 +8874,91 @@ impl RegistryJsonPropertyPatch for DmarcInternalReport {
     }
 }
 
+impl DmarcOverride {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        if let Some(value) = &self.sender_domain {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::SenderDomain));
+            }
+        }
+        if let Some(value) = &self.list_id {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::ListId));
+            }
+        }
+        if self.sender_domain.is_none() && self.list_id.is_none() {
+            errors.push(ValidationError::required(Property::SenderDomain));
+        }
+        if let Some(value) = &self.comment {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Comment));
+            }
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for DmarcOverride {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.sender_domain.pickle(out);
+        self.list_id.pickle(out);
+        self.override_type.pickle(out);
+        self.comment.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.sender_domain = Pickle::unpickle(stream)?;
+        this.list_id = Pickle::unpickle(stream)?;
+        this.override_type = Pickle::unpickle(stream)?;
+        this.comment = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for DmarcOverride {
+    fn default() -> Self {
+        Self {
+            sender_domain: Default::default(),
+            list_id: Default::default(),
+            override_type: DmarcPolicyOverride::TrustedForwarder,
+            comment: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for DmarcOverride {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(6);
+        map.insert_unchecked(Property::SenderDomain, self.sender_domain.into_value());
+        map.insert_unchecked(Property::ListId, self.list_id.into_value());
+        map.insert_unchecked(Property::OverrideType, self.override_type.into_value());
+        map.insert_unchecked(Property::Comment, self.comment.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for DmarcOverride {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::SenderDomain) => self.sender_domain.patch(pointer, value),
+            Some(Property::ListId) => self.list_id.patch(pointer, value),
+            Some(Property::OverrideType) => self.override_type.patch(pointer, value),
+            Some(Property::Comment) => self.comment.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl DmarcPolicyOverrideReason {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -9551,6 +10314,8 @@ impl Pickle for DnsCustomResolver {
         self.protocol.pickle(out);
         self.address.pickle(out);
         self.port.pickle(out);
+        self.tls_server_name.pickle(out);
+        self.http_path.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -9558,6 +10323,12 @@ impl Pickle for DnsCustomResolver {
         this.protocol = Pickle::unpickle(stream)?;
         this.address = Pickle::unpickle(stream)?;
         this.port = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.tls_server_name = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.http_path = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -9568,16 +10339,20 @@ impl Default for DnsCustomResolver {
             protocol: DnsResolverProtocol::Udp,
             address: IpAddr::from_str("127.0.0.1").unwrap(),
             port: 53u64,
+            tls_server_name: None,
+            http_path: None,
         }
     }
 }
 
 impl IntoValue for DnsCustomResolver {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(5);
+        let mut map = jmap_tools::Map::with_capacity(7);
         map.insert_unchecked(Property::Protocol, self.protocol.into_value());
         map.insert_unchecked(Property::Address, self.address.into_value());
         map.insert_unchecked(Property::Port, self.port.into_value());
+        map.insert_unchecked(Property::TlsServerName, self.tls_server_name.into_value());
+        map.insert_unchecked(Property::HttpPath, self.http_path.into_value());
         JmapValue::Object(map)
     }
 }
@@ -9594,6 +10369,8 @@ impl RegistryJsonPropertyPatch for DnsCustomResolver {
                 .address
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
             Some(Property::Port) => self.port.patch(pointer, value),
+            Some(Property::TlsServerName) => self.tls_server_name.patch(pointer, value),
+            Some(Property::HttpPath) => self.http_path.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -9793,7 +10570,7 @@ impl RegistryJsonPropertyPatch for DnsManagementProperties {
 
 impl ObjectImpl for DnsResolver {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::DnsResolver;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -10036,6 +10813,7 @@ impl Pickle for DnsResolverCustom {
         self.preserve_intermediates.pickle(out);
         self.timeout.pickle(out);
         self.tcp_on_error.pickle(out);
+        self.plaintext_fallback.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -10047,6 +10825,9 @@ impl Pickle for DnsResolverCustom {
         this.preserve_intermediates = Pickle::unpickle(stream)?;
         this.timeout = Pickle::unpickle(stream)?;
         this.tcp_on_error = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.plaintext_fallback = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -10061,13 +10842,14 @@ impl Default for DnsResolverCustom {
             preserve_intermediates: true,
             timeout: Duration::from_millis(5000),
             tcp_on_error: true,
+            plaintext_fallback: false,
         }
     }
 }
 
 impl IntoValue for DnsResolverCustom {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(9);
+        let mut map = jmap_tools::Map::with_capacity(10);
         map.insert_unchecked(Property::Servers, self.servers.into_value());
         map.insert_unchecked(Property::Attempts, self.attempts.into_value());
         map.insert_unchecked(Property::Concurrency, self.concurrency.into_value());
@@ -10078,6 +10860,10 @@ impl IntoValue for DnsResolverCustom {
         );
         map.insert_unchecked(Property::Timeout, self.timeout.into_value());
         map.insert_unchecked(Property::TcpOnError, self.tcp_on_error.into_value());
+        map.insert_unchecked(
+            Property::PlaintextFallback,
+            self.plaintext_fallback.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -10098,6 +10884,7 @@ impl RegistryJsonPropertyPatch for DnsResolverCustom {
             }
             Some(Property::Timeout) => self.timeout.patch(pointer, value),
             Some(Property::TcpOnError) => self.tcp_on_error.patch(pointer, value),
+            Some(Property::PlaintextFallback) => self.plaintext_fallback.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -10122,6 +10909,8 @@ impl Pickle for DnsResolverTls {
         self.preserve_intermediates.pickle(out);
         self.timeout.pickle(out);
         self.tcp_on_error.pickle(out);
+        self.use_https.pickle(out);
+        self.plaintext_fallback.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -10133,6 +10922,12 @@ impl Pickle for DnsResolverTls {
         this.preserve_intermediates = Pickle::unpickle(stream)?;
         this.timeout = Pickle::unpickle(stream)?;
         this.tcp_on_error = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.use_https = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.plaintext_fallback = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -10147,13 +10942,15 @@ impl Default for DnsResolverTls {
             preserve_intermediates: true,
             timeout: Duration::from_millis(5000),
             tcp_on_error: true,
+            use_https: false,
+            plaintext_fallback: false,
         }
     }
 }
 
 impl IntoValue for DnsResolverTls {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(9);
+        let mut map = jmap_tools::Map::with_capacity(11);
         map.insert_unchecked(Property::UseTls, self.use_tls.into_value());
         map.insert_unchecked(Property::Attempts, self.attempts.into_value());
         map.insert_unchecked(Property::Concurrency, self.concurrency.into_value());
@@ -10164,6 +10961,11 @@ impl IntoValue for DnsResolverTls {
         );
         map.insert_unchecked(Property::Timeout, self.timeout.into_value());
         map.insert_unchecked(Property::TcpOnError, self.tcp_on_error.into_value());
+        map.insert_unchecked(Property::UseHttps, self.use_https.into_value());
+        map.insert_unchecked(
+            Property::PlaintextFallback,
+            self.plaintext_fallback.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -10184,6 +10986,8 @@ impl RegistryJsonPropertyPatch for DnsResolverTls {
             }
             Some(Property::Timeout) => self.timeout.patch(pointer, value),
             Some(Property::TcpOnError) => self.tcp_on_error.patch(pointer, value),
+            Some(Property::UseHttps) => self.use_https.patch(pointer, value),
+            Some(Property::PlaintextFallback) => self.plaintext_fallback.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -10270,6 +11074,7 @@ impl ObjectImpl for DnsServer {
             DnsServer::Vultr(inner) => inner.validate(errors),
             DnsServer::WebSupport(inner) => inner.validate(errors),
             DnsServer::YandexCloud(inner) => inner.validate(errors),
+            DnsServer::Webhook(inner) => inner.validate(errors),
         }
     }
 
@@ -10483,6 +11288,9 @@ impl ObjectImpl for DnsServer {
             DnsServer::YandexCloud(object) => {
                 object.index(i);
             }
+            DnsServer::Webhook(object) => {
+                object.index(i);
+            }
         }
     }
 }
@@ -10775,6 +11583,10 @@ impl Pickle for DnsServer {
                 69u16.pickle(out);
                 inner.pickle(out);
             }
+            DnsServer::Webhook(inner) => {
+                70u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -10850,6 +11662,7 @@ impl Pickle for DnsServer {
             67 => Pickle::unpickle(stream).map(DnsServer::Vultr),
             68 => Pickle::unpickle(stream).map(DnsServer::WebSupport),
             69 => Pickle::unpickle(stream).map(DnsServer::YandexCloud),
+            70 => Pickle::unpickle(stream).map(DnsServer::Webhook),
             _ => None,
         }
     }
@@ -11346,6 +12159,13 @@ impl IntoValue for DnsServer {
                     .insert_unchecked(Property::Type, JmapValue::Str("YandexCloud".into()));
                 obj
             }
+            DnsServer::Webhook(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Webhook".into()));
+                obj
+            }
         }
     }
 }
@@ -11430,6 +12250,7 @@ impl RegistryJsonPatch for DnsServer {
                 DnsServerType::Vultr => *self = DnsServer::Vultr(Default::default()),
                 DnsServerType::WebSupport => *self = DnsServer::WebSupport(Default::default()),
                 DnsServerType::YandexCloud => *self = DnsServer::YandexCloud(Default::default()),
+                DnsServerType::Webhook => *self = DnsServer::Webhook(Default::default()),
             }
         }
         match self {
@@ -11503,6 +12324,7 @@ impl RegistryJsonPatch for DnsServer {
             DnsServer::Vultr(inner) => inner.patch(pointer, value),
             DnsServer::WebSupport(inner) => inner.patch(pointer, value),
             DnsServer::YandexCloud(inner) => inner.patch(pointer, value),
+            DnsServer::Webhook(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -11580,6 +12402,7 @@ impl DnsServer {
             DnsServer::Vultr(_) => DnsServerType::Vultr,
             DnsServer::WebSupport(_) => DnsServerType::WebSupport,
             DnsServer::YandexCloud(_) => DnsServerType::YandexCloud,
+            DnsServer::Webhook(_) => DnsServerType::Webhook,
         }
     }
 }
@@ -18948,30 +19771,180 @@ impl RegistryJsonPropertyPatch for DnsServerVercel {
     }
 }
 
-impl DnsServerVolcengine {
+impl DnsServerVolcengine {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.access_key;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::AccessKey));
+        }
+        let value = &self.secret_key;
+        value.validate(errors);
+        if let Some(value) = &self.region {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Region));
+            }
+        }
+        if let Some(value) = &self.host {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Host));
+            }
+        }
+        if let Some(value) = &self.scheme {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Scheme));
+            }
+        }
+        let value = &self.description;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Description));
+        }
+        if let Some(value) = &self.member_tenant_id {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::MemberTenantId));
+            }
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.foreign_key(ObjectType::Tenant, self.member_tenant_id, None);
+        if let Some(value) = &self.member_tenant_id {
+            i.search(Property::MemberTenantId, value);
+        }
+    }
+}
+
+impl Pickle for DnsServerVolcengine {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.access_key.pickle(out);
+        self.secret_key.pickle(out);
+        self.region.pickle(out);
+        self.host.pickle(out);
+        self.scheme.pickle(out);
+        self.description.pickle(out);
+        self.member_tenant_id.pickle(out);
+        self.timeout.pickle(out);
+        self.ttl.pickle(out);
+        self.polling_interval.pickle(out);
+        self.propagation_timeout.pickle(out);
+        self.propagation_delay.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.access_key = Pickle::unpickle(stream)?;
+        this.secret_key = Pickle::unpickle(stream)?;
+        this.region = Pickle::unpickle(stream)?;
+        this.host = Pickle::unpickle(stream)?;
+        this.scheme = Pickle::unpickle(stream)?;
+        this.description = Pickle::unpickle(stream)?;
+        this.member_tenant_id = Pickle::unpickle(stream)?;
+        this.timeout = Pickle::unpickle(stream)?;
+        this.ttl = Pickle::unpickle(stream)?;
+        this.polling_interval = Pickle::unpickle(stream)?;
+        this.propagation_timeout = Pickle::unpickle(stream)?;
+        this.propagation_delay = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for DnsServerVolcengine {
+    fn default() -> Self {
+        Self {
+            access_key: Default::default(),
+            secret_key: Default::default(),
+            region: Default::default(),
+            host: Default::default(),
+            scheme: Default::default(),
+            description: Default::default(),
+            member_tenant_id: Default::default(),
+            timeout: Duration::from_millis(30000),
+            ttl: Duration::from_millis(300000),
+            polling_interval: Duration::from_millis(15000),
+            propagation_timeout: Duration::from_millis(60000),
+            propagation_delay: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for DnsServerVolcengine {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(14);
+        map.insert_unchecked(Property::AccessKey, self.access_key.into_value());
+        map.insert_unchecked(Property::SecretKey, self.secret_key.into_value());
+        map.insert_unchecked(Property::Region, self.region.into_value());
+        map.insert_unchecked(Property::Host, self.host.into_value());
+        map.insert_unchecked(Property::Scheme, self.scheme.into_value());
+        map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
+        map.insert_unchecked(Property::Timeout, self.timeout.into_value());
+        map.insert_unchecked(Property::Ttl, self.ttl.into_value());
+        map.insert_unchecked(
+            Property::PollingInterval,
+            self.polling_interval.into_value(),
+        );
+        map.insert_unchecked(
+            Property::PropagationTimeout,
+            self.propagation_timeout.into_value(),
+        );
+        map.insert_unchecked(
+            Property::PropagationDelay,
+            self.propagation_delay.into_value(),
+        );
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for DnsServerVolcengine {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::AccessKey) => self
+                .access_key
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::SecretKey) => self.secret_key.patch(pointer, value),
+            Some(Property::Region) => self
+                .region
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::Host) => self
+                .host
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::Scheme) => self
+                .scheme
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::Description) => self
+                .description
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::MemberTenantId) => self
+                .member_tenant_id
+                .patch(pointer.assert_can_set_tenant()?, value),
+            Some(Property::Timeout) => self.timeout.patch(pointer, value),
+            Some(Property::Ttl) => self.ttl.patch(pointer, value),
+            Some(Property::PollingInterval) => self.polling_interval.patch(pointer, value),
+            Some(Property::PropagationTimeout) => self.propagation_timeout.patch(pointer, value),
+            Some(Property::PropagationDelay) => self.propagation_delay.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl DnsServerWebSupport {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
-        let value = &self.access_key;
+        let value = &self.api_key;
         if value.is_empty() {
-            errors.push(ValidationError::required(Property::AccessKey));
+            errors.push(ValidationError::required(Property::ApiKey));
         }
-        let value = &self.secret_key;
+        let value = &self.secret;
         value.validate(errors);
-        if let Some(value) = &self.region {
-            if value.is_empty() {
-                errors.push(ValidationError::required(Property::Region));
-            }
-        }
-        if let Some(value) = &self.host {
-            if value.is_empty() {
-                errors.push(ValidationError::required(Property::Host));
-            }
-        }
-        if let Some(value) = &self.scheme {
-            if value.is_empty() {
-                errors.push(ValidationError::required(Property::Scheme));
-            }
-        }
         let value = &self.description;
         if value.is_empty() {
             errors.push(ValidationError::required(Property::Description));
@@ -18992,13 +19965,10 @@ impl DnsServerVolcengine {
     }
 }
 
-impl Pickle for DnsServerVolcengine {
+impl Pickle for DnsServerWebSupport {
     fn pickle(&self, out: &mut Vec<u8>) {
-        self.access_key.pickle(out);
-        self.secret_key.pickle(out);
-        self.region.pickle(out);
-        self.host.pickle(out);
-        self.scheme.pickle(out);
+        self.api_key.pickle(out);
+        self.secret.pickle(out);
         self.description.pickle(out);
         self.member_tenant_id.pickle(out);
         self.timeout.pickle(out);
@@ -19010,11 +19980,8 @@ impl Pickle for DnsServerVolcengine {
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
         let mut this = Self::default();
-        this.access_key = Pickle::unpickle(stream)?;
-        this.secret_key = Pickle::unpickle(stream)?;
-        this.region = Pickle::unpickle(stream)?;
-        this.host = Pickle::unpickle(stream)?;
-        this.scheme = Pickle::unpickle(stream)?;
+        this.api_key = Pickle::unpickle(stream)?;
+        this.secret = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
         this.member_tenant_id = Pickle::unpickle(stream)?;
         this.timeout = Pickle::unpickle(stream)?;
@@ -19026,14 +19993,11 @@ impl Pickle for DnsServerVolcengine {
     }
 }
 
-impl Default for DnsServerVolcengine {
+impl Default for DnsServerWebSupport {
     fn default() -> Self {
         Self {
-            access_key: Default::default(),
-            secret_key: Default::default(),
-            region: Default::default(),
-            host: Default::default(),
-            scheme: Default::default(),
+            api_key: Default::default(),
+            secret: Default::default(),
             description: Default::default(),
             member_tenant_id: Default::default(),
             timeout: Duration::from_millis(30000),
@@ -19045,14 +20009,11 @@ impl Default for DnsServerVolcengine {
     }
 }
 
-impl IntoValue for DnsServerVolcengine {
+impl IntoValue for DnsServerWebSupport {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(14);
-        map.insert_unchecked(Property::AccessKey, self.access_key.into_value());
-        map.insert_unchecked(Property::SecretKey, self.secret_key.into_value());
-        map.insert_unchecked(Property::Region, self.region.into_value());
-        map.insert_unchecked(Property::Host, self.host.into_value());
-        map.insert_unchecked(Property::Scheme, self.scheme.into_value());
+        let mut map = jmap_tools::Map::with_capacity(11);
+        map.insert_unchecked(Property::ApiKey, self.api_key.into_value());
+        map.insert_unchecked(Property::Secret, self.secret.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
         map.insert_unchecked(Property::Timeout, self.timeout.into_value());
@@ -19073,26 +20034,17 @@ impl IntoValue for DnsServerVolcengine {
     }
 }
 
-impl RegistryJsonPropertyPatch for DnsServerVolcengine {
+impl RegistryJsonPropertyPatch for DnsServerWebSupport {
     fn patch_property<'x>(
         &mut self,
         mut pointer: JsonPointerPatch<'_>,
         value: JmapValue<'x>,
     ) -> PatchResult<'x> {
         match pointer.next_property() {
-            Some(Property::AccessKey) => self
-                .access_key
-                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
-            Some(Property::SecretKey) => self.secret_key.patch(pointer, value),
-            Some(Property::Region) => self
-                .region
-                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
-            Some(Property::Host) => self
-                .host
-                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
-            Some(Property::Scheme) => self
-                .scheme
+            Some(Property::ApiKey) => self
+                .api_key
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::Secret) => self.secret.patch(pointer, value),
             Some(Property::Description) => self
                 .description
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
@@ -19113,15 +20065,21 @@ impl RegistryJsonPropertyPatch for DnsServerVolcengine {
     }
 }
 
-impl DnsServerWebSupport {
+impl DnsServerWebhook {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
-        let value = &self.api_key;
+        let value = &self.url;
         if value.is_empty() {
-            errors.push(ValidationError::required(Property::ApiKey));
+            errors.push(ValidationError::required(Property::Url));
         }
-        let value = &self.secret;
+        let value = &self.http_auth;
         value.validate(errors);
+        let value = &self.http_headers;
+        for value in value.values() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::HttpHeaders));
+            }
+        }
         let value = &self.description;
         if value.is_empty() {
             errors.push(ValidationError::required(Property::Description));
@@ -19142,10 +20100,12 @@ impl DnsServerWebSupport {
     }
 }
 
-impl Pickle for DnsServerWebSupport {
+impl Pickle for DnsServerWebhook {
     fn pickle(&self, out: &mut Vec<u8>) {
-        self.api_key.pickle(out);
-        self.secret.pickle(out);
+        self.url.pickle(out);
+        self.http_auth.pickle(out);
+        self.http_headers.pickle(out);
+        self.allow_invalid_certs.pickle(out);
         self.description.pickle(out);
         self.member_tenant_id.pickle(out);
         self.timeout.pickle(out);
@@ -19157,8 +20117,10 @@ impl Pickle for DnsServerWebSupport {
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
         let mut this = Self::default();
-        this.api_key = Pickle::unpickle(stream)?;
-        this.secret = Pickle::unpickle(stream)?;
+        this.url = Pickle::unpickle(stream)?;
+        this.http_auth = Pickle::unpickle(stream)?;
+        this.http_headers = Pickle::unpickle(stream)?;
+        this.allow_invalid_certs = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
         this.member_tenant_id = Pickle::unpickle(stream)?;
         this.timeout = Pickle::unpickle(stream)?;
@@ -19170,11 +20132,13 @@ impl Pickle for DnsServerWebSupport {
     }
 }
 
-impl Default for DnsServerWebSupport {
+impl Default for DnsServerWebhook {
     fn default() -> Self {
         Self {
-            api_key: Default::default(),
-            secret: Default::default(),
+            url: Default::default(),
+            http_auth: Default::default(),
+            http_headers: Default::default(),
+            allow_invalid_certs: false,
             description: Default::default(),
             member_tenant_id: Default::default(),
             timeout: Duration::from_millis(30000),
@@ -19186,11 +20150,16 @@ impl Default for DnsServerWebSupport {
     }
 }
 
-impl IntoValue for DnsServerWebSupport {
+impl IntoValue for DnsServerWebhook {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(11);
-        map.insert_unchecked(Property::ApiKey, self.api_key.into_value());
-        map.insert_unchecked(Property::Secret, self.secret.into_value());
+        let mut map = jmap_tools::Map::with_capacity(13);
+        map.insert_unchecked(Property::Url, self.url.into_value());
+        map.insert_unchecked(Property::HttpAuth, self.http_auth.into_value());
+        map.insert_unchecked(Property::HttpHeaders, self.http_headers.into_value());
+        map.insert_unchecked(
+            Property::AllowInvalidCerts,
+            self.allow_invalid_certs.into_value(),
+        );
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
         map.insert_unchecked(Property::Timeout, self.timeout.into_value());
@@ -19211,17 +20180,21 @@ impl IntoValue for DnsServerWebSupport {
     }
 }
 
-impl RegistryJsonPropertyPatch for DnsServerWebSupport {
+impl RegistryJsonPropertyPatch for DnsServerWebhook {
     fn patch_property<'x>(
         &mut self,
         mut pointer: JsonPointerPatch<'_>,
         value: JmapValue<'x>,
     ) -> PatchResult<'x> {
         match pointer.next_property() {
-            Some(Property::ApiKey) => self
-                .api_key
+            Some(Property::Url) => self
+                .url
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
-            Some(Property::Secret) => self.secret.patch(pointer, value),
+            Some(Property::HttpAuth) => self.http_auth.patch(pointer, value),
+            Some(Property::HttpHeaders) => self
+                .http_headers
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::AllowInvalidCerts) => self.allow_invalid_certs.patch(pointer, value),
             Some(Property::Description) => self
                 .description
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
@@ -19373,7 +20346,7 @@ impl RegistryJsonPropertyPatch for DnsServerYandexCloud {
 
 impl ObjectImpl for Domain {
     const FLAGS: u64 = OBJ_FILTER_TENANT | OBJ_SEQ_ID;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 6;
     const OBJECT: ObjectType = ObjectType::Domain;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -19430,6 +20403,10 @@ impl ObjectImpl for Domain {
                 errors.push(ValidationError::required(Property::ReportAddressUri));
             }
         }
+        let value = &self.default_folders;
+        for value in value.values() {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -19469,6 +20446,12 @@ impl Pickle for Domain {
         self.sub_addressing.pickle(out);
         self.allow_relaying.pickle(out);
         self.report_address_uri.pickle(out);
+        self.show_virtual_folders.pickle(out);
+        self.default_folders.pickle(out);
+        self.require_mfa.pickle(out);
+        self.cold_storage_after.pickle(out);
+        self.cold_storage_archive.pickle(out);
+        self.auto_archive_after.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -19488,6 +20471,24 @@ impl Pickle for Domain {
         this.sub_addressing = Pickle::unpickle(stream)?;
         this.allow_relaying = Pickle::unpickle(stream)?;
         this.report_address_uri = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.show_virtual_folders = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.default_folders = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.require_mfa = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 5 {
+            this.cold_storage_after = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 5 {
+            this.cold_storage_archive = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 6 {
+            this.auto_archive_after = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -19510,13 +20511,19 @@ impl Default for Domain {
             sub_addressing: Default::default(),
             allow_relaying: false,
             report_address_uri: Some("mailto:postmaster".to_string()),
+            show_virtual_folders: true,
+            default_folders: Default::default(),
+            require_mfa: Default::default(),
+            cold_storage_after: Default::default(),
+            cold_storage_archive: false,
+            auto_archive_after: Default::default(),
         }
     }
 }
 
 impl IntoValue for Domain {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(17);
+        let mut map = jmap_tools::Map::with_capacity(23);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Aliases, self.aliases.into_value());
         map.insert_unchecked(Property::IsEnabled, self.is_enabled.into_value());
@@ -19541,6 +20548,24 @@ impl IntoValue for Domain {
             Property::ReportAddressUri,
             self.report_address_uri.into_value(),
         );
+        map.insert_unchecked(
+            Property::ShowVirtualFolders,
+            self.show_virtual_folders.into_value(),
+        );
+        map.insert_unchecked(Property::DefaultFolders, self.default_folders.into_value());
+        map.insert_unchecked(Property::RequireMfa, self.require_mfa.into_value());
+        map.insert_unchecked(
+            Property::ColdStorageAfter,
+            self.cold_storage_after.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ColdStorageArchive,
+            self.cold_storage_archive.into_value(),
+        );
+        map.insert_unchecked(
+            Property::AutoArchiveAfter,
+            self.auto_archive_after.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -19580,6 +20605,12 @@ impl RegistryJsonPropertyPatch for Domain {
             Some(Property::ReportAddressUri) => self
                 .report_address_uri
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::ShowVirtualFolders) => self.show_virtual_folders.patch(pointer, value),
+            Some(Property::DefaultFolders) => self.default_folders.patch(pointer, value),
+            Some(Property::RequireMfa) => self.require_mfa.patch(pointer, value),
+            Some(Property::ColdStorageAfter) => self.cold_storage_after.patch(pointer, value),
+            Some(Property::ColdStorageArchive) => self.cold_storage_archive.patch(pointer, value),
+            Some(Property::AutoArchiveAfter) => self.auto_archive_after.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -19721,6 +20752,122 @@ impl RegistryJsonPropertyPatch for DsnReportSettings {
     }
 }
 
+impl ObjectImpl for DsnTemplate {
+    const FLAGS: u64 = OBJ_FILTER_TENANT;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::DsnTemplate;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        if let Some(value) = &self.description {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Description));
+            }
+        }
+        if let Some(value) = &self.domain_id {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::DomainId));
+            }
+        }
+        if let Some(value) = &self.member_tenant_id {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::MemberTenantId));
+            }
+        }
+        if let Some(value) = &self.subject {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Subject));
+            }
+        }
+        let value = &self.body;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Body));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.foreign_key(ObjectType::Domain, self.domain_id, None);
+        if let Some(value) = &self.domain_id {
+            i.search(Property::DomainId, value);
+        }
+        i.foreign_key(ObjectType::Tenant, self.member_tenant_id, None);
+        if let Some(value) = &self.member_tenant_id {
+            i.search(Property::MemberTenantId, value);
+        }
+    }
+}
+
+impl Pickle for DsnTemplate {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.description.pickle(out);
+        self.domain_id.pickle(out);
+        self.member_tenant_id.pickle(out);
+        self.locale.pickle(out);
+        self.subject.pickle(out);
+        self.body.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.description = Pickle::unpickle(stream)?;
+        this.domain_id = Pickle::unpickle(stream)?;
+        this.member_tenant_id = Pickle::unpickle(stream)?;
+        this.locale = Pickle::unpickle(stream)?;
+        this.subject = Pickle::unpickle(stream)?;
+        this.body = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for DsnTemplate {
+    fn default() -> Self {
+        Self {
+            description: Default::default(),
+            domain_id: Default::default(),
+            member_tenant_id: Default::default(),
+            locale: Default::default(),
+            subject: Default::default(),
+            body: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for DsnTemplate {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(8);
+        map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
+        map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
+        map.insert_unchecked(Property::Locale, self.locale.into_value());
+        map.insert_unchecked(Property::Subject, self.subject.into_value());
+        map.insert_unchecked(Property::Body, self.body.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for DsnTemplate {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Description) => self.description.patch(pointer, value),
+            Some(Property::DomainId) => self.domain_id.patch(pointer, value),
+            Some(Property::MemberTenantId) => self.member_tenant_id.patch(pointer, value),
+            Some(Property::Locale) => self.locale.patch(pointer, value),
+            Some(Property::Subject) => self.subject.patch(pointer, value),
+            Some(Property::Body) => self.body.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ElasticSearchStore {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -19840,7 +20987,7 @@ impl RegistryJsonPropertyPatch for ElasticSearchStore {
 
 impl ObjectImpl for Email {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 10;
     const OBJECT: ObjectType = ObjectType::Email;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -19898,6 +21045,26 @@ impl ObjectImpl for Email {
                 errors.push(ValidationError::min_value(Property::MaxPublicKeys, 1));
             }
         }
+        let value = &self.virtual_folders;
+        for value in value.values() {
+            value.validate(errors);
+        }
+        let value = &self.encryption_key_provider;
+        value.validate(errors);
+        for value in self.search_folders.values() {
+            value.validate(errors);
+        }
+        let value = &self.max_message_keywords;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::MaxMessageKeywords, 1));
+        }
+        let value = &self.max_custom_keywords;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::MaxCustomKeywords, 1));
+        }
+        if *value > 99 {
+            errors.push(ValidationError::max_value(Property::MaxCustomKeywords, 99));
+        }
         errors.len() == neb
     }
 
@@ -19920,6 +21087,22 @@ impl Pickle for Email {
         self.max_mailboxes.pickle(out);
         self.max_masked_addresses.pickle(out);
         self.max_public_keys.pickle(out);
+        self.virtual_folders.pickle(out);
+        self.recent_folder_period.pickle(out);
+        self.max_drafts.pickle(out);
+        self.max_drafts_size.pickle(out);
+        self.repair_mime.pickle(out);
+        self.encryption_key_provider.pickle(out);
+        self.encryption_key_cache_ttl.pickle(out);
+        self.search_folders.pickle(out);
+        self.duplicate_window.pickle(out);
+        self.duplicate_match.pickle(out);
+        self.shared_mailbox_destroy.pickle(out);
+        self.max_message_keywords.pickle(out);
+        self.max_custom_keywords.pickle(out);
+        self.subscribe_on_imap_create.pickle(out);
+        self.subscribe_on_jmap_create.pickle(out);
+        self.subscribe_on_auto_create.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -19938,6 +21121,44 @@ impl Pickle for Email {
         this.max_mailboxes = Pickle::unpickle(stream)?;
         this.max_masked_addresses = Pickle::unpickle(stream)?;
         this.max_public_keys = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.virtual_folders = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.recent_folder_period = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.max_drafts = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.max_drafts_size = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.repair_mime = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 5 {
+            this.encryption_key_provider = Pickle::unpickle(stream)?;
+            this.encryption_key_cache_ttl = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 6 {
+            this.search_folders = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 7 {
+            this.duplicate_window = Pickle::unpickle(stream)?;
+            this.duplicate_match = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 8 {
+            this.shared_mailbox_destroy = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 9 {
+            this.max_message_keywords = Pickle::unpickle(stream)?;
+            this.max_custom_keywords = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 10 {
+            this.subscribe_on_imap_create = Pickle::unpickle(stream)?;
+            this.subscribe_on_jmap_create = Pickle::unpickle(stream)?;
+            this.subscribe_on_auto_create = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -19959,13 +21180,29 @@ impl Default for Email {
             max_mailboxes: Some(250u64),
             max_masked_addresses: Some(5u64),
             max_public_keys: Some(5u64),
+            virtual_folders: Default::default(),
+            recent_folder_period: Duration::from_millis(604800000),
+            max_drafts: Default::default(),
+            max_drafts_size: Default::default(),
+            repair_mime: false,
+            encryption_key_provider: Default::default(),
+            encryption_key_cache_ttl: Duration::from_millis(3600000),
+            search_folders: Default::default(),
+            duplicate_window: Default::default(),
+            duplicate_match: Default::default(),
+            shared_mailbox_destroy: Default::default(),
+            max_message_keywords: 64u64,
+            max_custom_keywords: 99u64,
+            subscribe_on_imap_create: false,
+            subscribe_on_jmap_create: false,
+            subscribe_on_auto_create: true,
         }
     }
 }
 
 impl IntoValue for Email {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(32);
         map.insert_unchecked(
             Property::MaxAttachmentSize,
             self.max_attachment_size.into_value(),
@@ -19998,6 +21235,52 @@ impl IntoValue for Email {
             self.max_masked_addresses.into_value(),
         );
         map.insert_unchecked(Property::MaxPublicKeys, self.max_public_keys.into_value());
+        map.insert_unchecked(Property::VirtualFolders, self.virtual_folders.into_value());
+        map.insert_unchecked(
+            Property::RecentFolderPeriod,
+            self.recent_folder_period.into_value(),
+        );
+        map.insert_unchecked(Property::MaxDrafts, self.max_drafts.into_value());
+        map.insert_unchecked(Property::MaxDraftsSize, self.max_drafts_size.into_value());
+        map.insert_unchecked(Property::RepairMime, self.repair_mime.into_value());
+        map.insert_unchecked(
+            Property::EncryptionKeyProvider,
+            self.encryption_key_provider.into_value(),
+        );
+        map.insert_unchecked(
+            Property::EncryptionKeyCacheTtl,
+            self.encryption_key_cache_ttl.into_value(),
+        );
+        map.insert_unchecked(Property::SearchFolders, self.search_folders.into_value());
+        map.insert_unchecked(
+            Property::DuplicateWindow,
+            self.duplicate_window.into_value(),
+        );
+        map.insert_unchecked(Property::DuplicateMatch, self.duplicate_match.into_value());
+        map.insert_unchecked(
+            Property::SharedMailboxDestroy,
+            self.shared_mailbox_destroy.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MaxMessageKeywords,
+            self.max_message_keywords.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MaxCustomKeywords,
+            self.max_custom_keywords.into_value(),
+        );
+        map.insert_unchecked(
+            Property::SubscribeOnImapCreate,
+            self.subscribe_on_imap_create.into_value(),
+        );
+        map.insert_unchecked(
+            Property::SubscribeOnJmapCreate,
+            self.subscribe_on_jmap_create.into_value(),
+        );
+        map.insert_unchecked(
+            Property::SubscribeOnAutoCreate,
+            self.subscribe_on_auto_create.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -20027,6 +21310,34 @@ impl RegistryJsonPropertyPatch for Email {
             Some(Property::MaxMailboxes) => self.max_mailboxes.patch(pointer, value),
             Some(Property::MaxMaskedAddresses) => self.max_masked_addresses.patch(pointer, value),
             Some(Property::MaxPublicKeys) => self.max_public_keys.patch(pointer, value),
+            Some(Property::VirtualFolders) => self.virtual_folders.patch(pointer, value),
+            Some(Property::RecentFolderPeriod) => self.recent_folder_period.patch(pointer, value),
+            Some(Property::MaxDrafts) => self.max_drafts.patch(pointer, value),
+            Some(Property::MaxDraftsSize) => self.max_drafts_size.patch(pointer, value),
+            Some(Property::RepairMime) => self.repair_mime.patch(pointer, value),
+            Some(Property::EncryptionKeyProvider) => {
+                self.encryption_key_provider.patch(pointer, value)
+            }
+            Some(Property::EncryptionKeyCacheTtl) => {
+                self.encryption_key_cache_ttl.patch(pointer, value)
+            }
+            Some(Property::SearchFolders) => self.search_folders.patch(pointer, value),
+            Some(Property::DuplicateWindow) => self.duplicate_window.patch(pointer, value),
+            Some(Property::DuplicateMatch) => self.duplicate_match.patch(pointer, value),
+            Some(Property::SharedMailboxDestroy) => {
+                self.shared_mailbox_destroy.patch(pointer, value)
+            }
+            Some(Property::MaxMessageKeywords) => self.max_message_keywords.patch(pointer, value),
+            Some(Property::MaxCustomKeywords) => self.max_custom_keywords.patch(pointer, value),
+            Some(Property::SubscribeOnImapCreate) => {
+                self.subscribe_on_imap_create.patch(pointer, value)
+            }
+            Some(Property::SubscribeOnJmapCreate) => {
+                self.subscribe_on_jmap_create.patch(pointer, value)
+            }
+            Some(Property::SubscribeOnAutoCreate) => {
+                self.subscribe_on_auto_create.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -20052,6 +21363,11 @@ impl EmailAlias {
                 errors.push(ValidationError::required(Property::Description));
             }
         }
+        if let Some(value) = &self.expires_at {
+            if !value.is_valid() {
+                errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+            }
+        }
         errors.len() == neb
     }
 
@@ -20068,6 +21384,7 @@ impl Pickle for EmailAlias {
         self.name.pickle(out);
         self.domain_id.pickle(out);
         self.description.pickle(out);
+        self.expires_at.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -20076,6 +21393,7 @@ impl Pickle for EmailAlias {
         this.name = Pickle::unpickle(stream)?;
         this.domain_id = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
         Some(this)
     }
 }
@@ -20087,6 +21405,7 @@ impl Default for EmailAlias {
             name: Default::default(),
             domain_id: Default::default(),
             description: Default::default(),
+            expires_at: Default::default(),
         }
     }
 }
@@ -20098,6 +21417,7 @@ impl IntoValue for EmailAlias {
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
         JmapValue::Object(map)
     }
 }
@@ -20116,6 +21436,7 @@ impl RegistryJsonPropertyPatch for EmailAlias {
             ),
             Some(Property::DomainId) => self.domain_id.patch(pointer, value),
             Some(Property::Description) => self.description.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -20138,6 +21459,12 @@ impl EmailFolder {
                 errors.push(ValidationError::required(Property::Aliases));
             }
         }
+        let value = &self.localized_names;
+        for value in value.values() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::LocalizedNames));
+            }
+        }
         errors.len() == neb
     }
 }
@@ -20148,6 +21475,7 @@ impl Pickle for EmailFolder {
         self.create.pickle(out);
         self.subscribe.pickle(out);
         self.aliases.pickle(out);
+        self.localized_names.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -20156,6 +21484,9 @@ impl Pickle for EmailFolder {
         this.create = Pickle::unpickle(stream)?;
         this.subscribe = Pickle::unpickle(stream)?;
         this.aliases = Pickle::unpickle(stream)?;
+        if stream.version() >= 3 {
+            this.localized_names = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -20167,17 +21498,19 @@ impl Default for EmailFolder {
             create: true,
             subscribe: true,
             aliases: Default::default(),
+            localized_names: Default::default(),
         }
     }
 }
 
 impl IntoValue for EmailFolder {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(7);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Create, self.create.into_value());
         map.insert_unchecked(Property::Subscribe, self.subscribe.into_value());
         map.insert_unchecked(Property::Aliases, self.aliases.into_value());
+        map.insert_unchecked(Property::LocalizedNames, self.localized_names.into_value());
         JmapValue::Object(map)
     }
 }
@@ -20197,6 +21530,82 @@ impl RegistryJsonPropertyPatch for EmailFolder {
             Some(Property::Aliases) => self
                 .aliases
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::LocalizedNames) => self.localized_names.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl EmailSearchFolder {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.name;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Name));
+        }
+        let value = &self.query;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Query));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for EmailSearchFolder {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.name.pickle(out);
+        self.query.pickle(out);
+        self.role.pickle(out);
+        self.subscribe.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.name = Pickle::unpickle(stream)?;
+        this.query = Pickle::unpickle(stream)?;
+        this.role = Pickle::unpickle(stream)?;
+        this.subscribe = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for EmailSearchFolder {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            query: Default::default(),
+            role: Default::default(),
+            subscribe: true,
+        }
+    }
+}
+
+impl IntoValue for EmailSearchFolder {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(6);
+        map.insert_unchecked(Property::Name, self.name.into_value());
+        map.insert_unchecked(Property::Query, self.query.into_value());
+        map.insert_unchecked(Property::Role, self.role.into_value());
+        map.insert_unchecked(Property::Subscribe, self.subscribe.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for EmailSearchFolder {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Name) => self.name.patch(pointer, value),
+            Some(Property::Query) => self.query.patch(pointer, value),
+            Some(Property::Role) => self.role.patch(pointer, value),
+            Some(Property::Subscribe) => self.subscribe.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -20322,18 +21731,103 @@ impl EncryptionAtRest {
     }
 }
 
+impl EncryptionKeyProvider {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        match self {
+            EncryptionKeyProvider::Disabled => true,
+            EncryptionKeyProvider::Vault(inner) => inner.validate(errors),
+        }
+    }
+}
+
+impl Default for EncryptionKeyProvider {
+    fn default() -> Self {
+        EncryptionKeyProvider::Disabled
+    }
+}
+
+impl Pickle for EncryptionKeyProvider {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        match self {
+            EncryptionKeyProvider::Disabled => {
+                0u16.pickle(out);
+            }
+            EncryptionKeyProvider::Vault(inner) => {
+                1u16.pickle(out);
+                inner.pickle(out);
+            }
+        }
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        match u16::unpickle(stream)? {
+            0 => Some(EncryptionKeyProvider::Disabled),
+            1 => Pickle::unpickle(stream).map(EncryptionKeyProvider::Vault),
+            _ => None,
+        }
+    }
+}
+
+impl IntoValue for EncryptionKeyProvider {
+    fn into_value(self) -> JmapValue<'static> {
+        match self {
+            EncryptionKeyProvider::Disabled => {
+                let mut obj = jmap_tools::Map::new();
+                obj.insert_unchecked(Property::Type, JmapValue::Str("Disabled".into()));
+                JmapValue::Object(obj)
+            }
+            EncryptionKeyProvider::Vault(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Vault".into()));
+                obj
+            }
+        }
+    }
+}
+
+impl RegistryJsonPatch for EncryptionKeyProvider {
+    fn patch<'x>(
+        &mut self,
+        pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        if !pointer.has_next() {
+            match object_type(&pointer, &value)? {
+                EncryptionKeyProviderType::Disabled => *self = EncryptionKeyProvider::Disabled,
+                EncryptionKeyProviderType::Vault => {
+                    *self = EncryptionKeyProvider::Vault(Default::default())
+                }
+            }
+        }
+        match self {
+            EncryptionKeyProvider::Disabled => pointer.assert_eof(),
+            EncryptionKeyProvider::Vault(inner) => inner.patch(pointer, value),
+        }
+    }
+}
+
+impl EncryptionKeyProvider {
+    pub fn object_type(&self) -> EncryptionKeyProviderType {
+        match self {
+            EncryptionKeyProvider::Disabled => EncryptionKeyProviderType::Disabled,
+            EncryptionKeyProvider::Vault(_) => EncryptionKeyProviderType::Vault,
+        }
+    }
+}
+
 impl EncryptionSettings {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        // The public key is optional when keys are obtained from an external provider
         let neb = errors.len();
-        let value = &self.public_key;
-        if !value.is_valid() {
-            errors.push(ValidationError::required(Property::PublicKey));
-        }
         errors.len() == neb
     }
 
     fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
-        i.foreign_key(ObjectType::PublicKey, self.public_key.into(), None);
+        if self.public_key.is_valid() {
+            i.foreign_key(ObjectType::PublicKey, self.public_key.into(), None);
+        }
     }
 }
 
@@ -20848,6 +22342,9 @@ impl FoundationDbStore {
                 ));
             }
         }
+        if self.directory_prefix.is_empty() {
+            errors.push(ValidationError::required(Property::DirectoryPrefix));
+        }
         errors.len() == neb
     }
 }
@@ -20860,6 +22357,8 @@ impl Pickle for FoundationDbStore {
         self.transaction_retry_delay.pickle(out);
         self.transaction_retry_limit.pickle(out);
         self.transaction_timeout.pickle(out);
+        self.partition_mode.pickle(out);
+        self.directory_prefix.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -20870,6 +22369,10 @@ impl Pickle for FoundationDbStore {
         this.transaction_retry_delay = Pickle::unpickle(stream)?;
         this.transaction_retry_limit = Pickle::unpickle(stream)?;
         this.transaction_timeout = Pickle::unpickle(stream)?;
+        if stream.version() >= 2 {
+            this.partition_mode = Pickle::unpickle(stream)?;
+            this.directory_prefix = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -20883,13 +22386,15 @@ impl Default for FoundationDbStore {
             transaction_retry_delay: Default::default(),
             transaction_retry_limit: Default::default(),
             transaction_timeout: Default::default(),
+            partition_mode: FoundationDbPartitionMode::Disabled,
+            directory_prefix: "stalwart".to_string(),
         }
     }
 }
 
 impl IntoValue for FoundationDbStore {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(8);
+        let mut map = jmap_tools::Map::with_capacity(10);
         map.insert_unchecked(Property::ClusterFile, self.cluster_file.into_value());
         map.insert_unchecked(Property::DatacenterId, self.datacenter_id.into_value());
         map.insert_unchecked(Property::MachineId, self.machine_id.into_value());
@@ -20905,6 +22410,11 @@ impl IntoValue for FoundationDbStore {
             Property::TransactionTimeout,
             self.transaction_timeout.into_value(),
         );
+        map.insert_unchecked(Property::PartitionMode, self.partition_mode.into_value());
+        map.insert_unchecked(
+            Property::DirectoryPrefix,
+            self.directory_prefix.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -20932,6 +22442,8 @@ impl RegistryJsonPropertyPatch for FoundationDbStore {
                 self.transaction_retry_limit.patch(pointer, value)
             }
             Some(Property::TransactionTimeout) => self.transaction_timeout.patch(pointer, value),
+            Some(Property::PartitionMode) => self.partition_mode.patch(pointer, value),
+            Some(Property::DirectoryPrefix) => self.directory_prefix.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -21062,6 +22574,11 @@ impl GroupAccount {
         for value in value.values() {
             value.validate(errors);
         }
+        if let Some(value) = &self.data_region
+            && value.is_empty()
+        {
+            errors.push(ValidationError::required(Property::DataRegion));
+        }
         errors.len() == neb
     }
 
@@ -21098,6 +22615,8 @@ impl Pickle for GroupAccount {
         self.aliases.pickle(out);
         self.locale.pickle(out);
         self.time_zone.pickle(out);
+        self.data_region.pickle(out);
+        self.require_mfa.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -21113,6 +22632,12 @@ impl Pickle for GroupAccount {
         this.aliases = Pickle::unpickle(stream)?;
         this.locale = Pickle::unpickle(stream)?;
         this.time_zone = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.data_region = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.require_mfa = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -21131,13 +22656,15 @@ impl Default for GroupAccount {
             aliases: Default::default(),
             locale: Locale::EnUS,
             time_zone: Default::default(),
+            data_region: Default::default(),
+            require_mfa: Default::default(),
         }
     }
 }
 
 impl IntoValue for GroupAccount {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(13);
+        let mut map = jmap_tools::Map::with_capacity(15);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
@@ -21149,6 +22676,8 @@ impl IntoValue for GroupAccount {
         map.insert_unchecked(Property::Aliases, self.aliases.into_value());
         map.insert_unchecked(Property::Locale, self.locale.into_value());
         map.insert_unchecked(Property::TimeZone, self.time_zone.into_value());
+        map.insert_unchecked(Property::DataRegion, self.data_region.into_value());
+        map.insert_unchecked(Property::RequireMfa, self.require_mfa.into_value());
         JmapValue::Object(map)
     }
 }
@@ -21178,6 +22707,8 @@ impl RegistryJsonPropertyPatch for GroupAccount {
             Some(Property::Aliases) => self.aliases.patch(pointer, value),
             Some(Property::Locale) => self.locale.patch(pointer, value),
             Some(Property::TimeZone) => self.time_zone.patch(pointer, value),
+            Some(Property::DataRegion) => self.data_region.patch(pointer, value),
+            Some(Property::RequireMfa) => self.require_mfa.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -21189,7 +22720,7 @@ impl RegistryJsonPropertyPatch for GroupAccount {
 
 impl ObjectImpl for Http {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::Http;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -21208,6 +22739,21 @@ impl ObjectImpl for Http {
                 errors.push(ValidationError::required(Property::ResponseHeaders));
             }
         }
+        if let Some(value) = &self.rate_limit_client {
+            value.validate(errors);
+        }
+        if let Some(value) = &self.rate_limit_upload {
+            value.validate(errors);
+        }
+        if let Some(value) = &self.rate_limit_query {
+            value.validate(errors);
+        }
+        if let Some(value) = &self.rate_limit_set {
+            value.validate(errors);
+        }
+        if let Some(value) = &self.rate_limit_admin {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -21242,6 +22788,11 @@ impl Pickle for Http {
         self.use_permissive_cors.pickle(out);
         self.response_headers.pickle(out);
         self.use_x_forwarded.pickle(out);
+        self.rate_limit_client.pickle(out);
+        self.rate_limit_upload.pickle(out);
+        self.rate_limit_query.pickle(out);
+        self.rate_limit_set.pickle(out);
+        self.rate_limit_admin.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -21253,6 +22804,21 @@ impl Pickle for Http {
         this.use_permissive_cors = Pickle::unpickle(stream)?;
         this.response_headers = Pickle::unpickle(stream)?;
         this.use_x_forwarded = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.rate_limit_client = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.rate_limit_upload = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.rate_limit_query = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.rate_limit_set = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.rate_limit_admin = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -21276,13 +22842,18 @@ impl Default for Http {
             use_permissive_cors: false,
             response_headers: Default::default(),
             use_x_forwarded: false,
+            rate_limit_client: Default::default(),
+            rate_limit_upload: Default::default(),
+            rate_limit_query: Default::default(),
+            rate_limit_set: Default::default(),
+            rate_limit_admin: Default::default(),
         }
     }
 }
 
 impl IntoValue for Http {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(9);
+        let mut map = jmap_tools::Map::with_capacity(14);
         map.insert_unchecked(
             Property::RateLimitAuthenticated,
             self.rate_limit_authenticated.into_value(),
@@ -21305,6 +22876,17 @@ impl IntoValue for Http {
             self.response_headers.into_value(),
         );
         map.insert_unchecked(Property::UseXForwarded, self.use_x_forwarded.into_value());
+        map.insert_unchecked(
+            Property::RateLimitClient,
+            self.rate_limit_client.into_value(),
+        );
+        map.insert_unchecked(
+            Property::RateLimitUpload,
+            self.rate_limit_upload.into_value(),
+        );
+        map.insert_unchecked(Property::RateLimitQuery, self.rate_limit_query.into_value());
+        map.insert_unchecked(Property::RateLimitSet, self.rate_limit_set.into_value());
+        map.insert_unchecked(Property::RateLimitAdmin, self.rate_limit_admin.into_value());
         JmapValue::Object(map)
     }
 }
@@ -21327,6 +22909,11 @@ impl RegistryJsonPropertyPatch for Http {
                 .response_headers
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
             Some(Property::UseXForwarded) => self.use_x_forwarded.patch(pointer, value),
+            Some(Property::RateLimitClient) => self.rate_limit_client.patch(pointer, value),
+            Some(Property::RateLimitUpload) => self.rate_limit_upload.patch(pointer, value),
+            Some(Property::RateLimitQuery) => self.rate_limit_query.patch(pointer, value),
+            Some(Property::RateLimitSet) => self.rate_limit_set.patch(pointer, value),
+            Some(Property::RateLimitAdmin) => self.rate_limit_admin.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -22104,7 +23691,7 @@ impl RegistryJsonPropertyPatch for HurricaneCredential {
 
 impl ObjectImpl for Imap {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 3;
     const OBJECT: ObjectType = ObjectType::Imap;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -22121,12 +23708,36 @@ impl ObjectImpl for Imap {
         if let Some(value) = &self.max_request_rate {
             value.validate(errors);
         }
+        if let Some(value) = &self.account_bandwidth {
+            value.validate(errors);
+        }
+        let value = &self.client_policy;
+        value.validate(errors);
         errors.len() == neb
     }
 
     fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
 }
 
+impl Imap {
+    pub fn ctx_client_policy(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.client_policy,
+            default: Some(Expression {
+                else_: "allow".to_string(),
+                ..Default::default()
+            }),
+            property: Property::ClientPolicy,
+            allowed_variables: IMAP_CLIENT_VARIABLE,
+            allowed_constants: IMAP_CLIENT_POLICY_CONSTANT,
+        }
+    }
+
+    pub fn expression_ctxs(&self) -> Vec<ExpressionContext<'_>> {
+        vec![self.ctx_client_policy()]
+    }
+}
+
 impl Pickle for Imap {
     fn pickle(&self, out: &mut Vec<u8>) {
         self.allow_plain_text_auth.pickle(out);
@@ -22137,6 +23748,10 @@ impl Pickle for Imap {
         self.timeout_anonymous.pickle(out);
         self.timeout_authenticated.pickle(out);
         self.timeout_idle.pickle(out);
+        self.account_bandwidth.pickle(out);
+        self.client_policy.pickle(out);
+        self.append_sieve.pickle(out);
+        self.append_spam_filter.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -22149,6 +23764,16 @@ impl Pickle for Imap {
         this.timeout_anonymous = Pickle::unpickle(stream)?;
         this.timeout_authenticated = Pickle::unpickle(stream)?;
         this.timeout_idle = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.account_bandwidth = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.client_policy = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.append_sieve = Pickle::unpickle(stream)?;
+            this.append_spam_filter = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -22167,13 +23792,20 @@ impl Default for Imap {
             timeout_anonymous: Duration::from_millis(60000),
             timeout_authenticated: Duration::from_millis(1800000),
             timeout_idle: Duration::from_millis(1800000),
+            account_bandwidth: Default::default(),
+            client_policy: Expression {
+                else_: "allow".to_string(),
+                ..Default::default()
+            },
+            append_sieve: false,
+            append_spam_filter: false,
         }
     }
 }
 
 impl IntoValue for Imap {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(10);
+        let mut map = jmap_tools::Map::with_capacity(14);
         map.insert_unchecked(
             Property::AllowPlainTextAuth,
             self.allow_plain_text_auth.into_value(),
@@ -22194,6 +23826,16 @@ impl IntoValue for Imap {
             self.timeout_authenticated.into_value(),
         );
         map.insert_unchecked(Property::TimeoutIdle, self.timeout_idle.into_value());
+        map.insert_unchecked(
+            Property::AccountBandwidth,
+            self.account_bandwidth.into_value(),
+        );
+        map.insert_unchecked(Property::ClientPolicy, self.client_policy.into_value());
+        map.insert_unchecked(Property::AppendSieve, self.append_sieve.into_value());
+        map.insert_unchecked(
+            Property::AppendSpamFilter,
+            self.append_spam_filter.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -22215,6 +23857,10 @@ impl RegistryJsonPropertyPatch for Imap {
                 self.timeout_authenticated.patch(pointer, value)
             }
             Some(Property::TimeoutIdle) => self.timeout_idle.patch(pointer, value),
+            Some(Property::AccountBandwidth) => self.account_bandwidth.patch(pointer, value),
+            Some(Property::ClientPolicy) => self.client_policy.patch(pointer, value),
+            Some(Property::AppendSieve) => self.append_sieve.patch(pointer, value),
+            Some(Property::AppendSpamFilter) => self.append_spam_filter.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -22235,6 +23881,7 @@ impl ObjectImpl for InMemoryStore {
             InMemoryStore::Sharded(inner) => inner.validate(errors),
             InMemoryStore::Redis(inner) => inner.validate(errors),
             InMemoryStore::RedisCluster(inner) => inner.validate(errors),
+            InMemoryStore::RedisSentinel(inner) => inner.validate(errors),
         }
     }
 
@@ -22265,6 +23912,10 @@ impl Pickle for InMemoryStore {
                 3u16.pickle(out);
                 inner.pickle(out);
             }
+            InMemoryStore::RedisSentinel(inner) => {
+                4u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -22274,6 +23925,7 @@ impl Pickle for InMemoryStore {
             1 => Pickle::unpickle(stream).map(InMemoryStore::Sharded),
             2 => Pickle::unpickle(stream).map(InMemoryStore::Redis),
             3 => Pickle::unpickle(stream).map(InMemoryStore::RedisCluster),
+            4 => Pickle::unpickle(stream).map(InMemoryStore::RedisSentinel),
             _ => None,
         }
     }
@@ -22308,6 +23960,13 @@ impl IntoValue for InMemoryStore {
                     .insert_unchecked(Property::Type, JmapValue::Str("RedisCluster".into()));
                 obj
             }
+            InMemoryStore::RedisSentinel(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("RedisSentinel".into()));
+                obj
+            }
         }
     }
 }
@@ -22326,6 +23985,9 @@ impl RegistryJsonPatch for InMemoryStore {
                 InMemoryStoreType::RedisCluster => {
                     *self = InMemoryStore::RedisCluster(Default::default())
                 }
+                InMemoryStoreType::RedisSentinel => {
+                    *self = InMemoryStore::RedisSentinel(Default::default())
+                }
             }
         }
         match self {
@@ -22333,6 +23995,7 @@ impl RegistryJsonPatch for InMemoryStore {
             InMemoryStore::Sharded(inner) => inner.patch(pointer, value),
             InMemoryStore::Redis(inner) => inner.patch(pointer, value),
             InMemoryStore::RedisCluster(inner) => inner.patch(pointer, value),
+            InMemoryStore::RedisSentinel(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -22344,6 +24007,7 @@ impl InMemoryStore {
             InMemoryStore::Sharded(_) => InMemoryStoreType::Sharded,
             InMemoryStore::Redis(_) => InMemoryStoreType::Redis,
             InMemoryStore::RedisCluster(_) => InMemoryStoreType::RedisCluster,
+            InMemoryStore::RedisSentinel(_) => InMemoryStoreType::RedisSentinel,
         }
     }
 }
@@ -22353,6 +24017,7 @@ impl InMemoryStoreBase {
         match self {
             InMemoryStoreBase::Redis(inner) => inner.validate(errors),
             InMemoryStoreBase::RedisCluster(inner) => inner.validate(errors),
+            InMemoryStoreBase::RedisSentinel(inner) => inner.validate(errors),
         }
     }
 }
@@ -22374,6 +24039,10 @@ impl Pickle for InMemoryStoreBase {
                 1u16.pickle(out);
                 inner.pickle(out);
             }
+            InMemoryStoreBase::RedisSentinel(inner) => {
+                2u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -22381,6 +24050,7 @@ impl Pickle for InMemoryStoreBase {
         match u16::unpickle(stream)? {
             0 => Pickle::unpickle(stream).map(InMemoryStoreBase::Redis),
             1 => Pickle::unpickle(stream).map(InMemoryStoreBase::RedisCluster),
+            2 => Pickle::unpickle(stream).map(InMemoryStoreBase::RedisSentinel),
             _ => None,
         }
     }
@@ -22403,6 +24073,13 @@ impl IntoValue for InMemoryStoreBase {
                     .insert_unchecked(Property::Type, JmapValue::Str("RedisCluster".into()));
                 obj
             }
+            InMemoryStoreBase::RedisSentinel(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("RedisSentinel".into()));
+                obj
+            }
         }
     }
 }
@@ -22421,11 +24098,15 @@ impl RegistryJsonPatch for InMemoryStoreBase {
                 InMemoryStoreBaseType::RedisCluster => {
                     *self = InMemoryStoreBase::RedisCluster(Default::default())
                 }
+                InMemoryStoreBaseType::RedisSentinel => {
+                    *self = InMemoryStoreBase::RedisSentinel(Default::default())
+                }
             }
         }
         match self {
             InMemoryStoreBase::Redis(inner) => inner.patch(pointer, value),
             InMemoryStoreBase::RedisCluster(inner) => inner.patch(pointer, value),
+            InMemoryStoreBase::RedisSentinel(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -22435,13 +24116,14 @@ impl InMemoryStoreBase {
         match self {
             InMemoryStoreBase::Redis(_) => InMemoryStoreBaseType::Redis,
             InMemoryStoreBase::RedisCluster(_) => InMemoryStoreBaseType::RedisCluster,
+            InMemoryStoreBase::RedisSentinel(_) => InMemoryStoreBaseType::RedisSentinel,
         }
     }
 }
 
 impl ObjectImpl for Jmap {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 3;
     const OBJECT: ObjectType = ObjectType::Jmap;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -22527,6 +24209,15 @@ impl ObjectImpl for Jmap {
                 errors.push(ValidationError::min_value(Property::MaxSubscriptions, 1));
             }
         }
+        let value = &self.push_vapid_key;
+        value.validate(errors);
+        let value = &self.snippet_max_length;
+        if *value < 32 {
+            errors.push(ValidationError::min_value(Property::SnippetMaxLength, 32));
+        }
+        if *value > 255 {
+            errors.push(ValidationError::max_value(Property::SnippetMaxLength, 255));
+        }
         errors.len() == neb
     }
 
@@ -22563,6 +24254,10 @@ impl Pickle for Jmap {
         self.websocket_throttle.pickle(out);
         self.websocket_timeout.pickle(out);
         self.max_subscriptions.pickle(out);
+        self.push_vapid_key.pickle(out);
+        self.push_vapid_subject.pickle(out);
+        self.event_source_replay_window.pickle(out);
+        self.snippet_max_length.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -22595,6 +24290,18 @@ impl Pickle for Jmap {
         this.websocket_throttle = Pickle::unpickle(stream)?;
         this.websocket_timeout = Pickle::unpickle(stream)?;
         this.max_subscriptions = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.push_vapid_key = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.push_vapid_subject = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.event_source_replay_window = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.snippet_max_length = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -22630,13 +24337,17 @@ impl Default for Jmap {
             websocket_throttle: Duration::from_millis(1000),
             websocket_timeout: Duration::from_millis(600000),
             max_subscriptions: Some(15u64),
+            push_vapid_key: Default::default(),
+            push_vapid_subject: Default::default(),
+            event_source_replay_window: Some(Duration::from_millis(3600000)),
+            snippet_max_length: 255u64,
         }
     }
 }
 
 impl IntoValue for Jmap {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(30);
+        let mut map = jmap_tools::Map::with_capacity(34);
         map.insert_unchecked(
             Property::ParseLimitEvent,
             self.parse_limit_event.into_value(),
@@ -22719,6 +24430,19 @@ impl IntoValue for Jmap {
             Property::MaxSubscriptions,
             self.max_subscriptions.into_value(),
         );
+        map.insert_unchecked(Property::PushVapidKey, self.push_vapid_key.into_value());
+        map.insert_unchecked(
+            Property::PushVapidSubject,
+            self.push_vapid_subject.into_value(),
+        );
+        map.insert_unchecked(
+            Property::EventSourceReplayWindow,
+            self.event_source_replay_window.into_value(),
+        );
+        map.insert_unchecked(
+            Property::SnippetMaxLength,
+            self.snippet_max_length.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -22762,6 +24486,12 @@ impl RegistryJsonPropertyPatch for Jmap {
             Some(Property::WebsocketThrottle) => self.websocket_throttle.patch(pointer, value),
             Some(Property::WebsocketTimeout) => self.websocket_timeout.patch(pointer, value),
             Some(Property::MaxSubscriptions) => self.max_subscriptions.patch(pointer, value),
+            Some(Property::PushVapidKey) => self.push_vapid_key.patch(pointer, value),
+            Some(Property::PushVapidSubject) => self.push_vapid_subject.patch(pointer, value),
+            Some(Property::EventSourceReplayWindow) => {
+                self.event_source_replay_window.patch(pointer, value)
+            }
+            Some(Property::SnippetMaxLength) => self.snippet_max_length.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -23467,6 +25197,107 @@ impl RegistryJsonPropertyPatch for Log {
     }
 }
 
+impl ObjectImpl for LoginEntry {
+    const FLAGS: u64 = OBJ_FILTER_ACCOUNT;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::LoginEntry;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        if let Some(value) = &self.user_agent {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::UserAgent));
+            }
+        }
+        let value = &self.timestamp;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::Timestamp, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for LoginEntry {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.account_id.pickle(out);
+        self.protocol.pickle(out);
+        self.remote_ip.pickle(out);
+        self.user_agent.pickle(out);
+        self.fingerprint.pickle(out);
+        self.timestamp.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.account_id = Pickle::unpickle(stream)?;
+        this.protocol = Pickle::unpickle(stream)?;
+        this.remote_ip = Pickle::unpickle(stream)?;
+        this.user_agent = Pickle::unpickle(stream)?;
+        this.fingerprint = Pickle::unpickle(stream)?;
+        this.timestamp = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for LoginEntry {
+    fn default() -> Self {
+        Self {
+            account_id: Default::default(),
+            protocol: Default::default(),
+            remote_ip: Default::default(),
+            user_agent: Default::default(),
+            fingerprint: Default::default(),
+            timestamp: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for LoginEntry {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(9);
+        map.insert_unchecked(Property::AccountId, self.account_id.into_value());
+        map.insert_unchecked(Property::Protocol, self.protocol.into_value());
+        map.insert_unchecked(Property::RemoteIp, self.remote_ip.into_value());
+        map.insert_unchecked(Property::UserAgent, self.user_agent.into_value());
+        map.insert_unchecked(Property::Fingerprint, self.fingerprint.into_value());
+        map.insert_unchecked(Property::Timestamp, self.timestamp.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for LoginEntry {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::AccountId) => self.account_id.patch(pointer, value),
+            Some(Property::Protocol) => self.protocol.patch(pointer, value),
+            Some(Property::RemoteIp) => self.remote_ip.patch(pointer, value),
+            Some(Property::UserAgent) => self.user_agent.patch(pointer, value),
+            Some(Property::Fingerprint) => self.fingerprint.patch(pointer, value),
+            Some(Property::Timestamp) => self.timestamp.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl LookupStore {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         match self {
@@ -23476,6 +25307,7 @@ impl LookupStore {
             LookupStore::Sharded(inner) => inner.validate(errors),
             LookupStore::Redis(inner) => inner.validate(errors),
             LookupStore::RedisCluster(inner) => inner.validate(errors),
+            LookupStore::RedisSentinel(inner) => inner.validate(errors),
         }
     }
 }
@@ -23513,6 +25345,10 @@ impl Pickle for LookupStore {
                 5u16.pickle(out);
                 inner.pickle(out);
             }
+            LookupStore::RedisSentinel(inner) => {
+                6u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -23524,6 +25360,7 @@ impl Pickle for LookupStore {
             3 => Pickle::unpickle(stream).map(LookupStore::Sharded),
             4 => Pickle::unpickle(stream).map(LookupStore::Redis),
             5 => Pickle::unpickle(stream).map(LookupStore::RedisCluster),
+            6 => Pickle::unpickle(stream).map(LookupStore::RedisSentinel),
             _ => None,
         }
     }
@@ -23574,6 +25411,13 @@ impl IntoValue for LookupStore {
                     .insert_unchecked(Property::Type, JmapValue::Str("RedisCluster".into()));
                 obj
             }
+            LookupStore::RedisSentinel(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("RedisSentinel".into()));
+                obj
+            }
         }
     }
 }
@@ -23594,6 +25438,9 @@ impl RegistryJsonPatch for LookupStore {
                 LookupStoreType::RedisCluster => {
                     *self = LookupStore::RedisCluster(Default::default())
                 }
+                LookupStoreType::RedisSentinel => {
+                    *self = LookupStore::RedisSentinel(Default::default())
+                }
             }
         }
         match self {
@@ -23603,6 +25450,7 @@ impl RegistryJsonPatch for LookupStore {
             LookupStore::Sharded(inner) => inner.patch(pointer, value),
             LookupStore::Redis(inner) => inner.patch(pointer, value),
             LookupStore::RedisCluster(inner) => inner.patch(pointer, value),
+            LookupStore::RedisSentinel(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -23616,6 +25464,7 @@ impl LookupStore {
             LookupStore::Sharded(_) => LookupStoreType::Sharded,
             LookupStore::Redis(_) => LookupStoreType::Redis,
             LookupStore::RedisCluster(_) => LookupStoreType::RedisCluster,
+            LookupStore::RedisSentinel(_) => LookupStoreType::RedisSentinel,
         }
     }
 }
@@ -23693,7 +25542,7 @@ impl RegistryJsonPropertyPatch for MailExchanger {
 
 impl ObjectImpl for MailingList {
     const FLAGS: u64 = OBJ_FILTER_TENANT | OBJ_SEQ_ID;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::MailingList;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -23726,6 +25575,20 @@ impl ObjectImpl for MailingList {
                 errors.push(ValidationError::required(Property::Recipients));
             }
         }
+        if let Some(value) = &self.subject_prefix {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::SubjectPrefix));
+            }
+        }
+        if let Some(value) = &self.archive_account_id {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::ArchiveAccountId));
+            }
+        }
+        let value = &self.archive_folder;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::ArchiveFolder));
+        }
         errors.len() == neb
     }
 
@@ -23746,6 +25609,7 @@ impl ObjectImpl for MailingList {
         for value in self.recipients.iter() {
             i.text(Property::Text, value);
         }
+        i.foreign_key(ObjectType::Account, self.archive_account_id, None);
     }
 }
 
@@ -23757,6 +25621,12 @@ impl Pickle for MailingList {
         self.aliases.pickle(out);
         self.member_tenant_id.pickle(out);
         self.recipients.pickle(out);
+        self.sender_rewrite.pickle(out);
+        self.subject_prefix.pickle(out);
+        self.reply_to.pickle(out);
+        self.members_only.pickle(out);
+        self.archive_account_id.pickle(out);
+        self.archive_folder.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -23767,6 +25637,14 @@ impl Pickle for MailingList {
         this.aliases = Pickle::unpickle(stream)?;
         this.member_tenant_id = Pickle::unpickle(stream)?;
         this.recipients = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.sender_rewrite = Pickle::unpickle(stream)?;
+            this.subject_prefix = Pickle::unpickle(stream)?;
+            this.reply_to = Pickle::unpickle(stream)?;
+            this.members_only = Pickle::unpickle(stream)?;
+            this.archive_account_id = Pickle::unpickle(stream)?;
+            this.archive_folder = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -23780,19 +25658,34 @@ impl Default for MailingList {
             aliases: Default::default(),
             member_tenant_id: Default::default(),
             recipients: Default::default(),
+            sender_rewrite: Default::default(),
+            subject_prefix: Default::default(),
+            reply_to: Default::default(),
+            members_only: Default::default(),
+            archive_account_id: Default::default(),
+            archive_folder: "Lists".to_string(),
         }
     }
 }
 
 impl IntoValue for MailingList {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(8);
+        let mut map = jmap_tools::Map::with_capacity(14);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::Aliases, self.aliases.into_value());
         map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
         map.insert_unchecked(Property::Recipients, self.recipients.into_value());
+        map.insert_unchecked(Property::SenderRewrite, self.sender_rewrite.into_value());
+        map.insert_unchecked(Property::SubjectPrefix, self.subject_prefix.into_value());
+        map.insert_unchecked(Property::ReplyTo, self.reply_to.into_value());
+        map.insert_unchecked(Property::MembersOnly, self.members_only.into_value());
+        map.insert_unchecked(
+            Property::ArchiveAccountId,
+            self.archive_account_id.into_value(),
+        );
+        map.insert_unchecked(Property::ArchiveFolder, self.archive_folder.into_value());
         JmapValue::Object(map)
     }
 }
@@ -23818,6 +25711,12 @@ impl RegistryJsonPropertyPatch for MailingList {
             Some(Property::Recipients) => self
                 .recipients
                 .patch(pointer.with_validators(&[StringValidator::Email]), value),
+            Some(Property::SenderRewrite) => self.sender_rewrite.patch(pointer, value),
+            Some(Property::SubjectPrefix) => self.subject_prefix.patch(pointer, value),
+            Some(Property::ReplyTo) => self.reply_to.patch(pointer, value),
+            Some(Property::MembersOnly) => self.members_only.patch(pointer, value),
+            Some(Property::ArchiveAccountId) => self.archive_account_id.patch(pointer, value),
+            Some(Property::ArchiveFolder) => self.archive_folder.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -24259,6 +26158,107 @@ impl RegistryJsonPropertyPatch for MemoryLookupKeyValue {
     }
 }
 
+impl ObjectImpl for MessageAuditEntry {
+    const FLAGS: u64 = 0;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::MessageAuditEntry;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        if let Some(value) = &self.details {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Details));
+            }
+        }
+        let value = &self.timestamp;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::Timestamp, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for MessageAuditEntry {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.account_id.pickle(out);
+        self.event.pickle(out);
+        self.document_id.pickle(out);
+        self.performed_by.pickle(out);
+        self.details.pickle(out);
+        self.timestamp.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.account_id = Pickle::unpickle(stream)?;
+        this.event = Pickle::unpickle(stream)?;
+        this.document_id = Pickle::unpickle(stream)?;
+        this.performed_by = Pickle::unpickle(stream)?;
+        this.details = Pickle::unpickle(stream)?;
+        this.timestamp = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for MessageAuditEntry {
+    fn default() -> Self {
+        Self {
+            account_id: Default::default(),
+            event: Default::default(),
+            document_id: Default::default(),
+            performed_by: Default::default(),
+            details: Default::default(),
+            timestamp: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for MessageAuditEntry {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(9);
+        map.insert_unchecked(Property::AccountId, self.account_id.into_value());
+        map.insert_unchecked(Property::Event, self.event.into_value());
+        map.insert_unchecked(Property::DocumentId, self.document_id.into_value());
+        map.insert_unchecked(Property::PerformedBy, self.performed_by.into_value());
+        map.insert_unchecked(Property::Details, self.details.into_value());
+        map.insert_unchecked(Property::Timestamp, self.timestamp.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for MessageAuditEntry {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::AccountId) => self.account_id.patch(pointer, value),
+            Some(Property::Event) => self.event.patch(pointer, value),
+            Some(Property::DocumentId) => self.document_id.patch(pointer, value),
+            Some(Property::PerformedBy) => self.performed_by.patch(pointer, value),
+            Some(Property::Details) => self.details.patch(pointer, value),
+            Some(Property::Timestamp) => self.timestamp.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for Metric {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -24979,7 +26979,7 @@ impl RegistryJsonPropertyPatch for MetricsPrometheusProperties {
 
 impl ObjectImpl for MetricsStore {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::MetricsStore;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -25116,6 +27116,79 @@ impl MetricsStore {
     }
 }
 
+impl MirroredBlobStore {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        self.primary_store.validate(errors);
+        self.mirror_store.validate(errors);
+        let value = &self.max_queue_size;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::MaxQueueSize, 1));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for MirroredBlobStore {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.primary_store.pickle(out);
+        self.mirror_store.pickle(out);
+        self.write_mode.pickle(out);
+        self.max_queue_size.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.primary_store = Pickle::unpickle(stream)?;
+        this.mirror_store = Pickle::unpickle(stream)?;
+        this.write_mode = Pickle::unpickle(stream)?;
+        this.max_queue_size = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for MirroredBlobStore {
+    fn default() -> Self {
+        Self {
+            primary_store: Default::default(),
+            mirror_store: Default::default(),
+            write_mode: Default::default(),
+            max_queue_size: 10000u64,
+        }
+    }
+}
+
+impl IntoValue for MirroredBlobStore {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(6);
+        map.insert_unchecked(Property::PrimaryStore, self.primary_store.into_value());
+        map.insert_unchecked(Property::MirrorStore, self.mirror_store.into_value());
+        map.insert_unchecked(Property::WriteMode, self.write_mode.into_value());
+        map.insert_unchecked(Property::MaxQueueSize, self.max_queue_size.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for MirroredBlobStore {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::PrimaryStore) => self.primary_store.patch(pointer, value),
+            Some(Property::MirrorStore) => self.mirror_store.patch(pointer, value),
+            Some(Property::WriteMode) => self.write_mode.patch(pointer, value),
+            Some(Property::MaxQueueSize) => self.max_queue_size.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl MtaConnectionIpHost {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -25820,7 +27893,7 @@ impl MtaDeliveryScheduleIntervalsOrDefault {
 
 impl ObjectImpl for MtaExtensions {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::MtaExtensions;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -25845,6 +27918,10 @@ impl ObjectImpl for MtaExtensions {
         value.validate(errors);
         let value = &self.vrfy;
         value.validate(errors);
+        let value = &self.vrfy_rate;
+        value.validate(errors);
+        let value = &self.expn_rate;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -25901,15 +27978,15 @@ impl MtaExtensions {
         ExpressionContext {
             expr: &self.expn,
             default: Some(Expression {
-                else_: "false".to_string(),
+                else_: "disable".to_string(),
                 match_: List::from_iter([ExpressionMatch {
                     if_: "!is_empty(authenticated_as)".to_string(),
-                    then: "true".to_string(),
+                    then: "full".to_string(),
                 }]),
             }),
             property: Property::Expn,
             allowed_variables: MTA_MAIL_FROM_VARIABLE,
-            allowed_constants: &[],
+            allowed_constants: MTA_VRFY_EXPN_CONSTANT,
         }
     }
 
@@ -25988,14 +28065,40 @@ impl MtaExtensions {
         ExpressionContext {
             expr: &self.vrfy,
             default: Some(Expression {
-                else_: "false".to_string(),
+                else_: "disable".to_string(),
                 match_: List::from_iter([ExpressionMatch {
                     if_: "!is_empty(authenticated_as)".to_string(),
-                    then: "true".to_string(),
+                    then: "full".to_string(),
                 }]),
             }),
             property: Property::Vrfy,
             allowed_variables: MTA_MAIL_FROM_VARIABLE,
+            allowed_constants: MTA_VRFY_EXPN_CONSTANT,
+        }
+    }
+
+    pub fn ctx_vrfy_rate(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.vrfy_rate,
+            default: Some(Expression {
+                else_: "[10, 1m]".to_string(),
+                ..Default::default()
+            }),
+            property: Property::VrfyRate,
+            allowed_variables: MTA_MAIL_FROM_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_expn_rate(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.expn_rate,
+            default: Some(Expression {
+                else_: "[10, 1m]".to_string(),
+                ..Default::default()
+            }),
+            property: Property::ExpnRate,
+            allowed_variables: MTA_MAIL_FROM_VARIABLE,
             allowed_constants: &[],
         }
     }
@@ -26012,6 +28115,8 @@ impl MtaExtensions {
             self.ctx_pipelining(),
             self.ctx_require_tls(),
             self.ctx_vrfy(),
+            self.ctx_vrfy_rate(),
+            self.ctx_expn_rate(),
         ]
     }
 }
@@ -26028,6 +28133,8 @@ impl Pickle for MtaExtensions {
         self.pipelining.pickle(out);
         self.require_tls.pickle(out);
         self.vrfy.pickle(out);
+        self.vrfy_rate.pickle(out);
+        self.expn_rate.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -26042,6 +28149,10 @@ impl Pickle for MtaExtensions {
         this.pipelining = Pickle::unpickle(stream)?;
         this.require_tls = Pickle::unpickle(stream)?;
         this.vrfy = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.vrfy_rate = Pickle::unpickle(stream)?;
+            this.expn_rate = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -26068,10 +28179,10 @@ impl Default for MtaExtensions {
                 }]),
             },
             expn: Expression {
-                else_: "false".to_string(),
+                else_: "disable".to_string(),
                 match_: List::from_iter([ExpressionMatch {
                     if_: "!is_empty(authenticated_as)".to_string(),
-                    then: "true".to_string(),
+                    then: "full".to_string(),
                 }]),
             },
             future_release: Expression {
@@ -26101,19 +28212,27 @@ impl Default for MtaExtensions {
                 ..Default::default()
             },
             vrfy: Expression {
-                else_: "false".to_string(),
+                else_: "disable".to_string(),
                 match_: List::from_iter([ExpressionMatch {
                     if_: "!is_empty(authenticated_as)".to_string(),
-                    then: "true".to_string(),
+                    then: "full".to_string(),
                 }]),
             },
+            vrfy_rate: Expression {
+                else_: "[10, 1m]".to_string(),
+                ..Default::default()
+            },
+            expn_rate: Expression {
+                else_: "[10, 1m]".to_string(),
+                ..Default::default()
+            },
         }
     }
 }
 
 impl IntoValue for MtaExtensions {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(12);
+        let mut map = jmap_tools::Map::with_capacity(14);
         map.insert_unchecked(Property::Chunking, self.chunking.into_value());
         map.insert_unchecked(Property::DeliverBy, self.deliver_by.into_value());
         map.insert_unchecked(Property::Dsn, self.dsn.into_value());
@@ -26124,6 +28243,8 @@ impl IntoValue for MtaExtensions {
         map.insert_unchecked(Property::Pipelining, self.pipelining.into_value());
         map.insert_unchecked(Property::RequireTls, self.require_tls.into_value());
         map.insert_unchecked(Property::Vrfy, self.vrfy.into_value());
+        map.insert_unchecked(Property::VrfyRate, self.vrfy_rate.into_value());
+        map.insert_unchecked(Property::ExpnRate, self.expn_rate.into_value());
         JmapValue::Object(map)
     }
 }
@@ -26145,6 +28266,8 @@ impl RegistryJsonPropertyPatch for MtaExtensions {
             Some(Property::Pipelining) => self.pipelining.patch(pointer, value),
             Some(Property::RequireTls) => self.require_tls.patch(pointer, value),
             Some(Property::Vrfy) => self.vrfy.patch(pointer, value),
+            Some(Property::VrfyRate) => self.vrfy_rate.patch(pointer, value),
+            Some(Property::ExpnRate) => self.expn_rate.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -26721,7 +28844,7 @@ impl RegistryJsonPropertyPatch for MtaMilter {
 
 impl ObjectImpl for MtaOutboundStrategy {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::MtaOutboundStrategy;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -26734,6 +28857,11 @@ impl ObjectImpl for MtaOutboundStrategy {
         value.validate(errors);
         let value = &self.tls;
         value.validate(errors);
+        if let Some(value) = &self.smarthost {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Smarthost));
+            }
+        }
         errors.len() == neb
     }
 
@@ -26828,6 +28956,8 @@ impl Pickle for MtaOutboundStrategy {
         self.route.pickle(out);
         self.schedule.pickle(out);
         self.tls.pickle(out);
+        self.delivery_mode.pickle(out);
+        self.smarthost.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -26836,6 +28966,12 @@ impl Pickle for MtaOutboundStrategy {
         this.route = Pickle::unpickle(stream)?;
         this.schedule = Pickle::unpickle(stream)?;
         this.tls = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.delivery_mode = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.smarthost = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -26878,17 +29014,21 @@ impl Default for MtaOutboundStrategy {
                     then: "'invalid-tls'".to_string(),
                 }]),
             },
+            delivery_mode: Default::default(),
+            smarthost: Default::default(),
         }
     }
 }
 
 impl IntoValue for MtaOutboundStrategy {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(8);
         map.insert_unchecked(Property::Connection, self.connection.into_value());
         map.insert_unchecked(Property::Route, self.route.into_value());
         map.insert_unchecked(Property::Schedule, self.schedule.into_value());
         map.insert_unchecked(Property::Tls, self.tls.into_value());
+        map.insert_unchecked(Property::DeliveryMode, self.delivery_mode.into_value());
+        map.insert_unchecked(Property::Smarthost, self.smarthost.into_value());
         JmapValue::Object(map)
     }
 }
@@ -26904,6 +29044,8 @@ impl RegistryJsonPropertyPatch for MtaOutboundStrategy {
             Some(Property::Route) => self.route.patch(pointer, value),
             Some(Property::Schedule) => self.schedule.patch(pointer, value),
             Some(Property::Tls) => self.tls.patch(pointer, value),
+            Some(Property::DeliveryMode) => self.delivery_mode.patch(pointer, value),
+            Some(Property::Smarthost) => self.smarthost.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -27140,7 +29282,7 @@ impl RegistryJsonPropertyPatch for MtaQueueQuota {
 
 impl ObjectImpl for MtaRoute {
     const FLAGS: u64 = 0;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::MtaRoute;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -27148,6 +29290,7 @@ impl ObjectImpl for MtaRoute {
             MtaRoute::Mx(inner) => inner.validate(errors),
             MtaRoute::Relay(inner) => inner.validate(errors),
             MtaRoute::Local(inner) => inner.validate(errors),
+            MtaRoute::LmtpExternal(inner) => inner.validate(errors),
         }
     }
 
@@ -27162,6 +29305,9 @@ impl ObjectImpl for MtaRoute {
             MtaRoute::Local(object) => {
                 object.index(i);
             }
+            MtaRoute::LmtpExternal(object) => {
+                object.index(i);
+            }
         }
     }
 }
@@ -27187,6 +29333,10 @@ impl Pickle for MtaRoute {
                 2u16.pickle(out);
                 inner.pickle(out);
             }
+            MtaRoute::LmtpExternal(inner) => {
+                3u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -27195,6 +29345,7 @@ impl Pickle for MtaRoute {
             0 => Pickle::unpickle(stream).map(MtaRoute::Mx),
             1 => Pickle::unpickle(stream).map(MtaRoute::Relay),
             2 => Pickle::unpickle(stream).map(MtaRoute::Local),
+            3 => Pickle::unpickle(stream).map(MtaRoute::LmtpExternal),
             _ => None,
         }
     }
@@ -27224,6 +29375,13 @@ impl IntoValue for MtaRoute {
                     .insert_unchecked(Property::Type, JmapValue::Str("Local".into()));
                 obj
             }
+            MtaRoute::LmtpExternal(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("LmtpExternal".into()));
+                obj
+            }
         }
     }
 }
@@ -27239,12 +29397,14 @@ impl RegistryJsonPatch for MtaRoute {
                 MtaRouteType::Mx => *self = MtaRoute::Mx(Default::default()),
                 MtaRouteType::Relay => *self = MtaRoute::Relay(Default::default()),
                 MtaRouteType::Local => *self = MtaRoute::Local(Default::default()),
+                MtaRouteType::LmtpExternal => *self = MtaRoute::LmtpExternal(Default::default()),
             }
         }
         match self {
             MtaRoute::Mx(inner) => inner.patch(pointer, value),
             MtaRoute::Relay(inner) => inner.patch(pointer, value),
             MtaRoute::Local(inner) => inner.patch(pointer, value),
+            MtaRoute::LmtpExternal(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -27255,6 +29415,7 @@ impl MtaRoute {
             MtaRoute::Mx(_) => MtaRouteType::Mx,
             MtaRoute::Relay(_) => MtaRouteType::Relay,
             MtaRoute::Local(_) => MtaRouteType::Local,
+            MtaRoute::LmtpExternal(_) => MtaRouteType::LmtpExternal,
         }
     }
 }
@@ -27329,6 +29490,144 @@ impl RegistryJsonPropertyPatch for MtaRouteCommon {
     }
 }
 
+impl MtaRouteLmtpExternal {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.address;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Address));
+        }
+        let value = &self.auth_secret;
+        value.validate(errors);
+        if let Some(value) = &self.auth_username {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::AuthUsername));
+            }
+        }
+        let value = &self.port;
+        if *value > 65535 {
+            errors.push(ValidationError::max_value(Property::Port, 65535));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::Port, 1));
+        }
+        let value = &self.name;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Name));
+        }
+        if let Some(value) = &self.description {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Description));
+            }
+        }
+        if let Some(value) = &self.tls_fingerprint {
+            if value.len() != 64 || !value.chars().all(|ch| ch.is_ascii_hexdigit()) {
+                errors.push(ValidationError::invalid(Property::TlsFingerprint, value));
+            }
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.unique(Property::Name, &self.name);
+    }
+}
+
+impl Pickle for MtaRouteLmtpExternal {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.address.pickle(out);
+        self.auth_secret.pickle(out);
+        self.auth_username.pickle(out);
+        self.port.pickle(out);
+        self.allow_invalid_certs.pickle(out);
+        self.implicit_tls.pickle(out);
+        self.deliver_local.pickle(out);
+        self.name.pickle(out);
+        self.description.pickle(out);
+        self.tls_fingerprint.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.address = Pickle::unpickle(stream)?;
+        this.auth_secret = Pickle::unpickle(stream)?;
+        this.auth_username = Pickle::unpickle(stream)?;
+        this.port = Pickle::unpickle(stream)?;
+        this.allow_invalid_certs = Pickle::unpickle(stream)?;
+        this.implicit_tls = Pickle::unpickle(stream)?;
+        this.deliver_local = Pickle::unpickle(stream)?;
+        this.name = Pickle::unpickle(stream)?;
+        this.description = Pickle::unpickle(stream)?;
+        this.tls_fingerprint = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for MtaRouteLmtpExternal {
+    fn default() -> Self {
+        Self {
+            address: Default::default(),
+            auth_secret: Default::default(),
+            auth_username: Default::default(),
+            port: 24u64,
+            allow_invalid_certs: false,
+            implicit_tls: false,
+            deliver_local: false,
+            name: Default::default(),
+            description: Default::default(),
+            tls_fingerprint: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for MtaRouteLmtpExternal {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(12);
+        map.insert_unchecked(Property::Address, self.address.into_value());
+        map.insert_unchecked(Property::AuthSecret, self.auth_secret.into_value());
+        map.insert_unchecked(Property::AuthUsername, self.auth_username.into_value());
+        map.insert_unchecked(Property::Port, self.port.into_value());
+        map.insert_unchecked(
+            Property::AllowInvalidCerts,
+            self.allow_invalid_certs.into_value(),
+        );
+        map.insert_unchecked(Property::ImplicitTls, self.implicit_tls.into_value());
+        map.insert_unchecked(Property::DeliverLocal, self.deliver_local.into_value());
+        map.insert_unchecked(Property::Name, self.name.into_value());
+        map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::TlsFingerprint, self.tls_fingerprint.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for MtaRouteLmtpExternal {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Address) => self
+                .address
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::AuthSecret) => self.auth_secret.patch(pointer, value),
+            Some(Property::AuthUsername) => self.auth_username.patch(pointer, value),
+            Some(Property::Port) => self.port.patch(pointer, value),
+            Some(Property::AllowInvalidCerts) => self.allow_invalid_certs.patch(pointer, value),
+            Some(Property::ImplicitTls) => self.implicit_tls.patch(pointer, value),
+            Some(Property::DeliverLocal) => self.deliver_local.patch(pointer, value),
+            Some(Property::Name) => self.name.patch(pointer.assert_read_only()?, value),
+            Some(Property::Description) => self.description.patch(pointer, value),
+            Some(Property::TlsFingerprint) => self.tls_fingerprint.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl MtaRouteMx {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -27349,6 +29648,25 @@ impl MtaRouteMx {
                 errors.push(ValidationError::required(Property::Description));
             }
         }
+        if let Some(value) = &self.proxy_address {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::ProxyAddress));
+            }
+        }
+        let value = &self.proxy_port;
+        if *value > 65535 {
+            errors.push(ValidationError::max_value(Property::ProxyPort, 65535));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::ProxyPort, 1));
+        }
+        if let Some(value) = &self.proxy_username {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::ProxyUsername));
+            }
+        }
+        let value = &self.proxy_secret;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -27364,6 +29682,11 @@ impl Pickle for MtaRouteMx {
         self.max_mx_hosts.pickle(out);
         self.name.pickle(out);
         self.description.pickle(out);
+        self.proxy_address.pickle(out);
+        self.proxy_port.pickle(out);
+        self.proxy_protocol.pickle(out);
+        self.proxy_username.pickle(out);
+        self.proxy_secret.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -27373,6 +29696,13 @@ impl Pickle for MtaRouteMx {
         this.max_mx_hosts = Pickle::unpickle(stream)?;
         this.name = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
+        if stream.version() >= 2 {
+            this.proxy_address = Pickle::unpickle(stream)?;
+            this.proxy_port = Pickle::unpickle(stream)?;
+            this.proxy_protocol = Pickle::unpickle(stream)?;
+            this.proxy_username = Pickle::unpickle(stream)?;
+            this.proxy_secret = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -27385,13 +29715,18 @@ impl Default for MtaRouteMx {
             max_mx_hosts: 5u64,
             name: Default::default(),
             description: Default::default(),
+            proxy_address: Default::default(),
+            proxy_port: 1080u64,
+            proxy_protocol: MtaProxyProtocol::Socks5,
+            proxy_username: Default::default(),
+            proxy_secret: Default::default(),
         }
     }
 }
 
 impl IntoValue for MtaRouteMx {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(7);
+        let mut map = jmap_tools::Map::with_capacity(12);
         map.insert_unchecked(
             Property::IpLookupStrategy,
             self.ip_lookup_strategy.into_value(),
@@ -27400,6 +29735,11 @@ impl IntoValue for MtaRouteMx {
         map.insert_unchecked(Property::MaxMxHosts, self.max_mx_hosts.into_value());
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::ProxyAddress, self.proxy_address.into_value());
+        map.insert_unchecked(Property::ProxyPort, self.proxy_port.into_value());
+        map.insert_unchecked(Property::ProxyProtocol, self.proxy_protocol.into_value());
+        map.insert_unchecked(Property::ProxyUsername, self.proxy_username.into_value());
+        map.insert_unchecked(Property::ProxySecret, self.proxy_secret.into_value());
         JmapValue::Object(map)
     }
 }
@@ -27416,6 +29756,11 @@ impl RegistryJsonPropertyPatch for MtaRouteMx {
             Some(Property::MaxMxHosts) => self.max_mx_hosts.patch(pointer, value),
             Some(Property::Name) => self.name.patch(pointer.assert_read_only()?, value),
             Some(Property::Description) => self.description.patch(pointer, value),
+            Some(Property::ProxyAddress) => self.proxy_address.patch(pointer, value),
+            Some(Property::ProxyPort) => self.proxy_port.patch(pointer, value),
+            Some(Property::ProxyProtocol) => self.proxy_protocol.patch(pointer, value),
+            Some(Property::ProxyUsername) => self.proxy_username.patch(pointer, value),
+            Some(Property::ProxySecret) => self.proxy_secret.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -27455,6 +29800,30 @@ impl MtaRouteRelay {
                 errors.push(ValidationError::required(Property::Description));
             }
         }
+        if let Some(value) = &self.tls_fingerprint {
+            if value.len() != 64 || !value.chars().all(|ch| ch.is_ascii_hexdigit()) {
+                errors.push(ValidationError::invalid(Property::TlsFingerprint, value));
+            }
+        }
+        if let Some(value) = &self.proxy_address {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::ProxyAddress));
+            }
+        }
+        let value = &self.proxy_port;
+        if *value > 65535 {
+            errors.push(ValidationError::max_value(Property::ProxyPort, 65535));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::ProxyPort, 1));
+        }
+        if let Some(value) = &self.proxy_username {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::ProxyUsername));
+            }
+        }
+        let value = &self.proxy_secret;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -27474,6 +29843,12 @@ impl Pickle for MtaRouteRelay {
         self.implicit_tls.pickle(out);
         self.name.pickle(out);
         self.description.pickle(out);
+        self.tls_fingerprint.pickle(out);
+        self.proxy_address.pickle(out);
+        self.proxy_port.pickle(out);
+        self.proxy_protocol.pickle(out);
+        self.proxy_username.pickle(out);
+        self.proxy_secret.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -27487,6 +29862,16 @@ impl Pickle for MtaRouteRelay {
         this.implicit_tls = Pickle::unpickle(stream)?;
         this.name = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.tls_fingerprint = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.proxy_address = Pickle::unpickle(stream)?;
+            this.proxy_port = Pickle::unpickle(stream)?;
+            this.proxy_protocol = Pickle::unpickle(stream)?;
+            this.proxy_username = Pickle::unpickle(stream)?;
+            this.proxy_secret = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -27503,13 +29888,19 @@ impl Default for MtaRouteRelay {
             implicit_tls: false,
             name: Default::default(),
             description: Default::default(),
+            tls_fingerprint: Default::default(),
+            proxy_address: Default::default(),
+            proxy_port: 1080u64,
+            proxy_protocol: MtaProxyProtocol::Socks5,
+            proxy_username: Default::default(),
+            proxy_secret: Default::default(),
         }
     }
 }
 
 impl IntoValue for MtaRouteRelay {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(11);
+        let mut map = jmap_tools::Map::with_capacity(17);
         map.insert_unchecked(Property::Address, self.address.into_value());
         map.insert_unchecked(Property::AuthSecret, self.auth_secret.into_value());
         map.insert_unchecked(Property::AuthUsername, self.auth_username.into_value());
@@ -27522,6 +29913,12 @@ impl IntoValue for MtaRouteRelay {
         map.insert_unchecked(Property::ImplicitTls, self.implicit_tls.into_value());
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
+        map.insert_unchecked(Property::TlsFingerprint, self.tls_fingerprint.into_value());
+        map.insert_unchecked(Property::ProxyAddress, self.proxy_address.into_value());
+        map.insert_unchecked(Property::ProxyPort, self.proxy_port.into_value());
+        map.insert_unchecked(Property::ProxyProtocol, self.proxy_protocol.into_value());
+        map.insert_unchecked(Property::ProxyUsername, self.proxy_username.into_value());
+        map.insert_unchecked(Property::ProxySecret, self.proxy_secret.into_value());
         JmapValue::Object(map)
     }
 }
@@ -27544,6 +29941,12 @@ impl RegistryJsonPropertyPatch for MtaRouteRelay {
             Some(Property::ImplicitTls) => self.implicit_tls.patch(pointer, value),
             Some(Property::Name) => self.name.patch(pointer.assert_read_only()?, value),
             Some(Property::Description) => self.description.patch(pointer, value),
+            Some(Property::TlsFingerprint) => self.tls_fingerprint.patch(pointer, value),
+            Some(Property::ProxyAddress) => self.proxy_address.patch(pointer, value),
+            Some(Property::ProxyPort) => self.proxy_port.patch(pointer, value),
+            Some(Property::ProxyProtocol) => self.proxy_protocol.patch(pointer, value),
+            Some(Property::ProxyUsername) => self.proxy_username.patch(pointer, value),
+            Some(Property::ProxySecret) => self.proxy_secret.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -27555,7 +29958,7 @@ impl RegistryJsonPropertyPatch for MtaRouteRelay {
 
 impl ObjectImpl for MtaStageAuth {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::MtaStageAuth;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -27570,6 +29973,14 @@ impl ObjectImpl for MtaStageAuth {
         value.validate(errors);
         let value = &self.require;
         value.validate(errors);
+        let value = &self.must_match_tenant_domain;
+        value.validate(errors);
+        let value = &self.tenant_domain_exemptions;
+        for value in value.iter() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::TenantDomainExemptions));
+            }
+        }
         errors.len() == neb
     }
 
@@ -27651,6 +30062,19 @@ impl MtaStageAuth {
         }
     }
 
+    pub fn ctx_must_match_tenant_domain(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.must_match_tenant_domain,
+            default: Some(Expression {
+                else_: "true".to_string(),
+                ..Default::default()
+            }),
+            property: Property::MustMatchTenantDomain,
+            allowed_variables: MTA_MAIL_FROM_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
     pub fn expression_ctxs(&self) -> Vec<ExpressionContext<'_>> {
         vec![
             self.ctx_max_failures(),
@@ -27658,6 +30082,7 @@ impl MtaStageAuth {
             self.ctx_sasl_mechanisms(),
             self.ctx_must_match_sender(),
             self.ctx_require(),
+            self.ctx_must_match_tenant_domain(),
         ]
     }
 }
@@ -27669,6 +30094,9 @@ impl Pickle for MtaStageAuth {
         self.sasl_mechanisms.pickle(out);
         self.must_match_sender.pickle(out);
         self.require.pickle(out);
+        self.must_match_tenant_domain.pickle(out);
+        self.tenant_domain_exemptions.pickle(out);
+        self.sender_mismatch_action.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -27678,6 +30106,13 @@ impl Pickle for MtaStageAuth {
         this.sasl_mechanisms = Pickle::unpickle(stream)?;
         this.must_match_sender = Pickle::unpickle(stream)?;
         this.require = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.must_match_tenant_domain = Pickle::unpickle(stream)?;
+            this.tenant_domain_exemptions = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.sender_mismatch_action = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -27714,13 +30149,19 @@ impl Default for MtaStageAuth {
                 else_: "local_port != 25".to_string(),
                 ..Default::default()
             },
+            must_match_tenant_domain: Expression {
+                else_: "true".to_string(),
+                ..Default::default()
+            },
+            tenant_domain_exemptions: Default::default(),
+            sender_mismatch_action: Default::default(),
         }
     }
 }
 
 impl IntoValue for MtaStageAuth {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(7);
+        let mut map = jmap_tools::Map::with_capacity(10);
         map.insert_unchecked(Property::MaxFailures, self.max_failures.into_value());
         map.insert_unchecked(Property::WaitOnFail, self.wait_on_fail.into_value());
         map.insert_unchecked(Property::SaslMechanisms, self.sasl_mechanisms.into_value());
@@ -27729,6 +30170,18 @@ impl IntoValue for MtaStageAuth {
             self.must_match_sender.into_value(),
         );
         map.insert_unchecked(Property::Require, self.require.into_value());
+        map.insert_unchecked(
+            Property::MustMatchTenantDomain,
+            self.must_match_tenant_domain.into_value(),
+        );
+        map.insert_unchecked(
+            Property::TenantDomainExemptions,
+            self.tenant_domain_exemptions.into_value(),
+        );
+        map.insert_unchecked(
+            Property::SenderMismatchAction,
+            self.sender_mismatch_action.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -27745,6 +30198,15 @@ impl RegistryJsonPropertyPatch for MtaStageAuth {
             Some(Property::SaslMechanisms) => self.sasl_mechanisms.patch(pointer, value),
             Some(Property::MustMatchSender) => self.must_match_sender.patch(pointer, value),
             Some(Property::Require) => self.require.patch(pointer, value),
+            Some(Property::MustMatchTenantDomain) => {
+                self.must_match_tenant_domain.patch(pointer, value)
+            }
+            Some(Property::TenantDomainExemptions) => {
+                self.tenant_domain_exemptions.patch(pointer, value)
+            }
+            Some(Property::SenderMismatchAction) => {
+                self.sender_mismatch_action.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -27888,7 +30350,7 @@ impl RegistryJsonPropertyPatch for MtaStageConnect {
 
 impl ObjectImpl for MtaStageData {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 5;
     const OBJECT: ObjectType = ObjectType::MtaStageData;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -27915,6 +30377,32 @@ impl ObjectImpl for MtaStageData {
         value.validate(errors);
         let value = &self.enable_spam_filter;
         value.validate(errors);
+        let value = &self.large_message_size;
+        value.validate(errors);
+        let value = &self.enable_user_script_reject;
+        value.validate(errors);
+        let value = &self.max_header_count;
+        value.validate(errors);
+        let value = &self.max_header_size;
+        value.validate(errors);
+        let value = &self.max_mime_depth;
+        value.validate(errors);
+        let value = &self.max_mime_parts;
+        value.validate(errors);
+        let value = &self.received_header_format;
+        value.validate(errors);
+        let value = &self.scrub_trace_headers;
+        value.validate(errors);
+        let value = &self.trace_headers;
+        for value in value.iter() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::TraceHeaders));
+            }
+        }
+        let value = &self.reject_bare_lf;
+        value.validate(errors);
+        let value = &self.reject_bare_cr;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -28083,6 +30571,136 @@ impl MtaStageData {
         }
     }
 
+    pub fn ctx_large_message_size(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.large_message_size,
+            default: Some(Expression {
+                else_: "52428800".to_string(),
+                ..Default::default()
+            }),
+            property: Property::LargeMessageSize,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_enable_user_script_reject(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.enable_user_script_reject,
+            default: Some(Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            }),
+            property: Property::EnableUserScriptReject,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_max_header_count(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.max_header_count,
+            default: Some(Expression {
+                else_: "1000".to_string(),
+                ..Default::default()
+            }),
+            property: Property::MaxHeaderCount,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_max_header_size(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.max_header_size,
+            default: Some(Expression {
+                else_: "65536".to_string(),
+                ..Default::default()
+            }),
+            property: Property::MaxHeaderSize,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_max_mime_depth(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.max_mime_depth,
+            default: Some(Expression {
+                else_: "50".to_string(),
+                ..Default::default()
+            }),
+            property: Property::MaxMimeDepth,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_max_mime_parts(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.max_mime_parts,
+            default: Some(Expression {
+                else_: "1000".to_string(),
+                ..Default::default()
+            }),
+            property: Property::MaxMimeParts,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_reject_bare_lf(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.reject_bare_lf,
+            default: Some(Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            }),
+            property: Property::RejectBareLf,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_reject_bare_cr(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.reject_bare_cr,
+            default: Some(Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            }),
+            property: Property::RejectBareCr,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
+    pub fn ctx_received_header_format(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.received_header_format,
+            default: Some(Expression {
+                else_: "full".to_string(),
+                ..Default::default()
+            }),
+            property: Property::ReceivedHeaderFormat,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: MTA_RECEIVED_FORMAT_CONSTANT,
+        }
+    }
+
+    pub fn ctx_scrub_trace_headers(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.scrub_trace_headers,
+            default: Some(Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            }),
+            property: Property::ScrubTraceHeaders,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
     pub fn expression_ctxs(&self) -> Vec<ExpressionContext<'_>> {
         vec![
             self.ctx_add_auth_results_header(),
@@ -28096,6 +30714,14 @@ impl MtaStageData {
             self.ctx_max_message_size(),
             self.ctx_script(),
             self.ctx_enable_spam_filter(),
+            self.ctx_large_message_size(),
+            self.ctx_enable_user_script_reject(),
+            self.ctx_max_header_count(),
+            self.ctx_max_header_size(),
+            self.ctx_max_mime_depth(),
+            self.ctx_max_mime_parts(),
+            self.ctx_received_header_format(),
+            self.ctx_scrub_trace_headers(),
         ]
     }
 }
@@ -28114,6 +30740,18 @@ impl Pickle for MtaStageData {
         self.max_message_size.pickle(out);
         self.script.pickle(out);
         self.enable_spam_filter.pickle(out);
+        self.large_message_size.pickle(out);
+        self.enable_user_script_reject.pickle(out);
+        self.max_header_count.pickle(out);
+        self.max_header_size.pickle(out);
+        self.max_mime_depth.pickle(out);
+        self.max_mime_parts.pickle(out);
+        self.structure_limit_action.pickle(out);
+        self.received_header_format.pickle(out);
+        self.scrub_trace_headers.pickle(out);
+        self.trace_headers.pickle(out);
+        self.reject_bare_lf.pickle(out);
+        self.reject_bare_cr.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -28130,6 +30768,28 @@ impl Pickle for MtaStageData {
         this.max_message_size = Pickle::unpickle(stream)?;
         this.script = Pickle::unpickle(stream)?;
         this.enable_spam_filter = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.large_message_size = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.enable_user_script_reject = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.max_header_count = Pickle::unpickle(stream)?;
+            this.max_header_size = Pickle::unpickle(stream)?;
+            this.max_mime_depth = Pickle::unpickle(stream)?;
+            this.max_mime_parts = Pickle::unpickle(stream)?;
+            this.structure_limit_action = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.received_header_format = Pickle::unpickle(stream)?;
+            this.scrub_trace_headers = Pickle::unpickle(stream)?;
+            this.trace_headers = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 5 {
+            this.reject_bare_lf = Pickle::unpickle(stream)?;
+            this.reject_bare_cr = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -28200,13 +30860,60 @@ impl Default for MtaStageData {
                 else_: "is_empty(authenticated_as)".to_string(),
                 ..Default::default()
             },
+            large_message_size: Expression {
+                else_: "52428800".to_string(),
+                ..Default::default()
+            },
+            enable_user_script_reject: Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            },
+            max_header_count: Expression {
+                else_: "1000".to_string(),
+                ..Default::default()
+            },
+            max_header_size: Expression {
+                else_: "65536".to_string(),
+                ..Default::default()
+            },
+            max_mime_depth: Expression {
+                else_: "50".to_string(),
+                ..Default::default()
+            },
+            max_mime_parts: Expression {
+                else_: "1000".to_string(),
+                ..Default::default()
+            },
+            structure_limit_action: MtaStructureLimitAction::Reject,
+            received_header_format: Expression {
+                else_: "full".to_string(),
+                ..Default::default()
+            },
+            scrub_trace_headers: Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            },
+            trace_headers: Map::new(vec![
+                "X-Originating-IP".to_string(),
+                "X-Mailer".to_string(),
+                "User-Agent".to_string(),
+                "X-MimeOLE".to_string(),
+            ]),
+            reject_bare_lf: Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            },
+            reject_bare_cr: Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            },
         }
     }
 }
 
 impl IntoValue for MtaStageData {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(14);
+        let mut map = jmap_tools::Map::with_capacity(26);
         map.insert_unchecked(
             Property::AddAuthResultsHeader,
             self.add_auth_results_header.into_value(),
@@ -28243,6 +30950,33 @@ impl IntoValue for MtaStageData {
             Property::EnableSpamFilter,
             self.enable_spam_filter.into_value(),
         );
+        map.insert_unchecked(
+            Property::LargeMessageSize,
+            self.large_message_size.into_value(),
+        );
+        map.insert_unchecked(
+            Property::EnableUserScriptReject,
+            self.enable_user_script_reject.into_value(),
+        );
+        map.insert_unchecked(Property::MaxHeaderCount, self.max_header_count.into_value());
+        map.insert_unchecked(Property::MaxHeaderSize, self.max_header_size.into_value());
+        map.insert_unchecked(Property::MaxMimeDepth, self.max_mime_depth.into_value());
+        map.insert_unchecked(Property::MaxMimeParts, self.max_mime_parts.into_value());
+        map.insert_unchecked(
+            Property::StructureLimitAction,
+            self.structure_limit_action.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ReceivedHeaderFormat,
+            self.received_header_format.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ScrubTraceHeaders,
+            self.scrub_trace_headers.into_value(),
+        );
+        map.insert_unchecked(Property::TraceHeaders, self.trace_headers.into_value());
+        map.insert_unchecked(Property::RejectBareLf, self.reject_bare_lf.into_value());
+        map.insert_unchecked(Property::RejectBareCr, self.reject_bare_cr.into_value());
         JmapValue::Object(map)
     }
 }
@@ -28274,6 +31008,24 @@ impl RegistryJsonPropertyPatch for MtaStageData {
             Some(Property::MaxMessageSize) => self.max_message_size.patch(pointer, value),
             Some(Property::Script) => self.script.patch(pointer, value),
             Some(Property::EnableSpamFilter) => self.enable_spam_filter.patch(pointer, value),
+            Some(Property::LargeMessageSize) => self.large_message_size.patch(pointer, value),
+            Some(Property::EnableUserScriptReject) => {
+                self.enable_user_script_reject.patch(pointer, value)
+            }
+            Some(Property::MaxHeaderCount) => self.max_header_count.patch(pointer, value),
+            Some(Property::MaxHeaderSize) => self.max_header_size.patch(pointer, value),
+            Some(Property::MaxMimeDepth) => self.max_mime_depth.patch(pointer, value),
+            Some(Property::MaxMimeParts) => self.max_mime_parts.patch(pointer, value),
+            Some(Property::StructureLimitAction) => {
+                self.structure_limit_action.patch(pointer, value)
+            }
+            Some(Property::ReceivedHeaderFormat) => {
+                self.received_header_format.patch(pointer, value)
+            }
+            Some(Property::ScrubTraceHeaders) => self.scrub_trace_headers.patch(pointer, value),
+            Some(Property::TraceHeaders) => self.trace_headers.patch(pointer, value),
+            Some(Property::RejectBareLf) => self.reject_bare_lf.patch(pointer, value),
+            Some(Property::RejectBareCr) => self.reject_bare_cr.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -28560,7 +31312,7 @@ impl RegistryJsonPropertyPatch for MtaStageMail {
 
 impl ObjectImpl for MtaStageRcpt {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::MtaStageRcpt;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -28577,6 +31329,8 @@ impl ObjectImpl for MtaStageRcpt {
         value.validate(errors);
         let value = &self.script;
         value.validate(errors);
+        let value = &self.callout;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -28662,6 +31416,19 @@ impl MtaStageRcpt {
         }
     }
 
+    pub fn ctx_callout(&self) -> ExpressionContext<'_> {
+        ExpressionContext {
+            expr: &self.callout,
+            default: Some(Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            }),
+            property: Property::Callout,
+            allowed_variables: MTA_RCPT_TO_VARIABLE,
+            allowed_constants: &[],
+        }
+    }
+
     pub fn expression_ctxs(&self) -> Vec<ExpressionContext<'_>> {
         vec![
             self.ctx_max_failures(),
@@ -28670,6 +31437,7 @@ impl MtaStageRcpt {
             self.ctx_allow_relaying(),
             self.ctx_rewrite(),
             self.ctx_script(),
+            self.ctx_callout(),
         ]
     }
 }
@@ -28682,6 +31450,10 @@ impl Pickle for MtaStageRcpt {
         self.allow_relaying.pickle(out);
         self.rewrite.pickle(out);
         self.script.pickle(out);
+        self.callout.pickle(out);
+        self.callout_timeout.pickle(out);
+        self.callout_cache_ttl.pickle(out);
+        self.callout_negative_cache_ttl.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -28692,6 +31464,12 @@ impl Pickle for MtaStageRcpt {
         this.allow_relaying = Pickle::unpickle(stream)?;
         this.rewrite = Pickle::unpickle(stream)?;
         this.script = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.callout = Pickle::unpickle(stream)?;
+            this.callout_timeout = Pickle::unpickle(stream)?;
+            this.callout_cache_ttl = Pickle::unpickle(stream)?;
+            this.callout_negative_cache_ttl = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -28723,19 +31501,36 @@ impl Default for MtaStageRcpt {
                 else_: "false".to_string(),
                 ..Default::default()
             },
+            callout: Expression {
+                else_: "false".to_string(),
+                ..Default::default()
+            },
+            callout_timeout: Duration::from_millis(30000),
+            callout_cache_ttl: Duration::from_millis(86400000),
+            callout_negative_cache_ttl: Duration::from_millis(3600000),
         }
     }
 }
 
 impl IntoValue for MtaStageRcpt {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(8);
+        let mut map = jmap_tools::Map::with_capacity(12);
         map.insert_unchecked(Property::MaxFailures, self.max_failures.into_value());
         map.insert_unchecked(Property::WaitOnFail, self.wait_on_fail.into_value());
         map.insert_unchecked(Property::MaxRecipients, self.max_recipients.into_value());
         map.insert_unchecked(Property::AllowRelaying, self.allow_relaying.into_value());
         map.insert_unchecked(Property::Rewrite, self.rewrite.into_value());
         map.insert_unchecked(Property::Script, self.script.into_value());
+        map.insert_unchecked(Property::Callout, self.callout.into_value());
+        map.insert_unchecked(Property::CalloutTimeout, self.callout_timeout.into_value());
+        map.insert_unchecked(
+            Property::CalloutCacheTtl,
+            self.callout_cache_ttl.into_value(),
+        );
+        map.insert_unchecked(
+            Property::CalloutNegativeCacheTtl,
+            self.callout_negative_cache_ttl.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -28753,6 +31548,12 @@ impl RegistryJsonPropertyPatch for MtaStageRcpt {
             Some(Property::AllowRelaying) => self.allow_relaying.patch(pointer, value),
             Some(Property::Rewrite) => self.rewrite.patch(pointer, value),
             Some(Property::Script) => self.script.patch(pointer, value),
+            Some(Property::Callout) => self.callout.patch(pointer, value),
+            Some(Property::CalloutTimeout) => self.callout_timeout.patch(pointer, value),
+            Some(Property::CalloutCacheTtl) => self.callout_cache_ttl.patch(pointer, value),
+            Some(Property::CalloutNegativeCacheTtl) => {
+                self.callout_negative_cache_ttl.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -28947,7 +31748,7 @@ impl RegistryJsonPropertyPatch for MtaTlsStrategy {
 
 impl ObjectImpl for MtaVirtualQueue {
     const FLAGS: u64 = 0;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::MtaVirtualQueue;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -28968,6 +31769,21 @@ impl ObjectImpl for MtaVirtualQueue {
         if *value < 1 {
             errors.push(ValidationError::min_value(Property::ThreadsPerNode, 1));
         }
+        let value = &self.priority_threads_per_node;
+        if *value >= self.threads_per_node {
+            errors.push(ValidationError::max_value(
+                Property::PriorityThreadsPerNode,
+                self.threads_per_node as i64 - 1,
+            ));
+        }
+        if let Some(value) = &self.low_priority_threads_per_node {
+            if *value < 1 {
+                errors.push(ValidationError::min_value(
+                    Property::LowPriorityThreadsPerNode,
+                    1,
+                ));
+            }
+        }
         errors.len() == neb
     }
 
@@ -28981,6 +31797,8 @@ impl Pickle for MtaVirtualQueue {
         self.name.pickle(out);
         self.description.pickle(out);
         self.threads_per_node.pickle(out);
+        self.priority_threads_per_node.pickle(out);
+        self.low_priority_threads_per_node.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -28988,6 +31806,10 @@ impl Pickle for MtaVirtualQueue {
         this.name = Pickle::unpickle(stream)?;
         this.description = Pickle::unpickle(stream)?;
         this.threads_per_node = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.priority_threads_per_node = Pickle::unpickle(stream)?;
+            this.low_priority_threads_per_node = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -28998,16 +31820,26 @@ impl Default for MtaVirtualQueue {
             name: Default::default(),
             description: Default::default(),
             threads_per_node: 25u64,
+            priority_threads_per_node: 0u64,
+            low_priority_threads_per_node: Default::default(),
         }
     }
 }
 
 impl IntoValue for MtaVirtualQueue {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(5);
+        let mut map = jmap_tools::Map::with_capacity(7);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::ThreadsPerNode, self.threads_per_node.into_value());
+        map.insert_unchecked(
+            Property::PriorityThreadsPerNode,
+            self.priority_threads_per_node.into_value(),
+        );
+        map.insert_unchecked(
+            Property::LowPriorityThreadsPerNode,
+            self.low_priority_threads_per_node.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -29027,6 +31859,12 @@ impl RegistryJsonPropertyPatch for MtaVirtualQueue {
             ),
             Some(Property::Description) => self.description.patch(pointer, value),
             Some(Property::ThreadsPerNode) => self.threads_per_node.patch(pointer, value),
+            Some(Property::PriorityThreadsPerNode) => {
+                self.priority_threads_per_node.patch(pointer, value)
+            }
+            Some(Property::LowPriorityThreadsPerNode) => {
+                self.low_priority_threads_per_node.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -29480,7 +32318,7 @@ impl RegistryJsonPropertyPatch for NatsCoordinator {
 
 impl ObjectImpl for NetworkListener {
     const FLAGS: u64 = 0;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 3;
     const OBJECT: ObjectType = ObjectType::NetworkListener;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -29543,6 +32381,12 @@ impl ObjectImpl for NetworkListener {
                 errors.push(ValidationError::min_value(Property::MaxConnections, 1));
             }
         }
+        if let Some(value) = &self.bandwidth {
+            value.validate(errors);
+        }
+        if let Some(value) = &self.tls_client_ca {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -29572,6 +32416,12 @@ impl Pickle for NetworkListener {
         self.tls_implicit.pickle(out);
         self.tls_timeout.pickle(out);
         self.max_connections.pickle(out);
+        self.bandwidth.pickle(out);
+        self.tls_client_ca.pickle(out);
+        self.tls_client_auth.pickle(out);
+        self.tls_profile.pickle(out);
+        self.tls_disable_key_exchange_groups.pickle(out);
+        self.tls_prefer_post_quantum.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -29595,6 +32445,18 @@ impl Pickle for NetworkListener {
         this.tls_implicit = Pickle::unpickle(stream)?;
         this.tls_timeout = Pickle::unpickle(stream)?;
         this.max_connections = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.bandwidth = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.tls_client_ca = Pickle::unpickle(stream)?;
+            this.tls_client_auth = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.tls_profile = Pickle::unpickle(stream)?;
+            this.tls_disable_key_exchange_groups = Pickle::unpickle(stream)?;
+            this.tls_prefer_post_quantum = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -29621,13 +32483,19 @@ impl Default for NetworkListener {
             tls_implicit: false,
             tls_timeout: Some(Duration::from_millis(60000)),
             max_connections: Some(8192u64),
+            bandwidth: Default::default(),
+            tls_client_ca: None,
+            tls_client_auth: false,
+            tls_profile: Default::default(),
+            tls_disable_key_exchange_groups: Default::default(),
+            tls_prefer_post_quantum: false,
         }
     }
 }
 
 impl IntoValue for NetworkListener {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(21);
+        let mut map = jmap_tools::Map::with_capacity(27);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Bind, self.bind.into_value());
         map.insert_unchecked(Property::Protocol, self.protocol.into_value());
@@ -29671,6 +32539,18 @@ impl IntoValue for NetworkListener {
         map.insert_unchecked(Property::TlsImplicit, self.tls_implicit.into_value());
         map.insert_unchecked(Property::TlsTimeout, self.tls_timeout.into_value());
         map.insert_unchecked(Property::MaxConnections, self.max_connections.into_value());
+        map.insert_unchecked(Property::Bandwidth, self.bandwidth.into_value());
+        map.insert_unchecked(Property::TlsClientCa, self.tls_client_ca.into_value());
+        map.insert_unchecked(Property::TlsClientAuth, self.tls_client_auth.into_value());
+        map.insert_unchecked(Property::TlsProfile, self.tls_profile.into_value());
+        map.insert_unchecked(
+            Property::TlsDisableKeyExchangeGroups,
+            self.tls_disable_key_exchange_groups.into_value(),
+        );
+        map.insert_unchecked(
+            Property::TlsPreferPostQuantum,
+            self.tls_prefer_post_quantum.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -29713,6 +32593,16 @@ impl RegistryJsonPropertyPatch for NetworkListener {
             Some(Property::TlsImplicit) => self.tls_implicit.patch(pointer, value),
             Some(Property::TlsTimeout) => self.tls_timeout.patch(pointer, value),
             Some(Property::MaxConnections) => self.max_connections.patch(pointer, value),
+            Some(Property::Bandwidth) => self.bandwidth.patch(pointer, value),
+            Some(Property::TlsClientCa) => self.tls_client_ca.patch(pointer, value),
+            Some(Property::TlsClientAuth) => self.tls_client_auth.patch(pointer, value),
+            Some(Property::TlsProfile) => self.tls_profile.patch(pointer, value),
+            Some(Property::TlsDisableKeyExchangeGroups) => {
+                self.tls_disable_key_exchange_groups.patch(pointer, value)
+            }
+            Some(Property::TlsPreferPostQuantum) => {
+                self.tls_prefer_post_quantum.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -29724,7 +32614,7 @@ impl RegistryJsonPropertyPatch for NetworkListener {
 
 impl ObjectImpl for OAuthClient {
     const FLAGS: u64 = OBJ_FILTER_TENANT | OBJ_SEQ_ID;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::OAuthClient;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -29774,6 +32664,12 @@ impl ObjectImpl for OAuthClient {
                 errors.push(ValidationError::required(Property::Logo));
             }
         }
+        let value = &self.allowed_scopes;
+        for value in value.iter() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::AllowedScopes));
+            }
+        }
         errors.len() == neb
     }
 
@@ -29803,6 +32699,10 @@ impl Pickle for OAuthClient {
         self.member_tenant_id.pickle(out);
         self.redirect_uris.pickle(out);
         self.logo.pickle(out);
+        self.allowed_grant_types.pickle(out);
+        self.allowed_scopes.pickle(out);
+        self.access_token_expiry.pickle(out);
+        self.refresh_token_expiry.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -29816,6 +32716,18 @@ impl Pickle for OAuthClient {
         this.member_tenant_id = Pickle::unpickle(stream)?;
         this.redirect_uris = Pickle::unpickle(stream)?;
         this.logo = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.allowed_grant_types = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.allowed_scopes = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.access_token_expiry = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.refresh_token_expiry = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -29832,13 +32744,17 @@ impl Default for OAuthClient {
             member_tenant_id: Default::default(),
             redirect_uris: Default::default(),
             logo: Default::default(),
+            allowed_grant_types: Default::default(),
+            allowed_scopes: Default::default(),
+            access_token_expiry: Default::default(),
+            refresh_token_expiry: Default::default(),
         }
     }
 }
 
 impl IntoValue for OAuthClient {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(11);
+        let mut map = jmap_tools::Map::with_capacity(15);
         map.insert_unchecked(Property::ClientId, self.client_id.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::Contacts, self.contacts.into_value());
@@ -29850,6 +32766,19 @@ impl IntoValue for OAuthClient {
         map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
         map.insert_unchecked(Property::RedirectUris, self.redirect_uris.into_value());
         map.insert_unchecked(Property::Logo, self.logo.into_value());
+        map.insert_unchecked(
+            Property::AllowedGrantTypes,
+            self.allowed_grant_types.into_value(),
+        );
+        map.insert_unchecked(Property::AllowedScopes, self.allowed_scopes.into_value());
+        map.insert_unchecked(
+            Property::AccessTokenExpiry,
+            self.access_token_expiry.into_value(),
+        );
+        map.insert_unchecked(
+            Property::RefreshTokenExpiry,
+            self.refresh_token_expiry.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -29876,6 +32805,10 @@ impl RegistryJsonPropertyPatch for OAuthClient {
                 .patch(pointer.assert_can_set_tenant()?, value),
             Some(Property::RedirectUris) => self.redirect_uris.patch(pointer, value),
             Some(Property::Logo) => self.logo.patch(pointer, value),
+            Some(Property::AllowedGrantTypes) => self.allowed_grant_types.patch(pointer, value),
+            Some(Property::AllowedScopes) => self.allowed_scopes.patch(pointer, value),
+            Some(Property::AccessTokenExpiry) => self.access_token_expiry.patch(pointer, value),
+            Some(Property::RefreshTokenExpiry) => self.refresh_token_expiry.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -31087,6 +34020,113 @@ impl RegistryJsonPropertyPatch for PublicTextValue {
     }
 }
 
+impl ObjectImpl for QuarantinedMessage {
+    const FLAGS: u64 = OBJ_FILTER_ACCOUNT;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::QuarantinedMessage;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.account_id;
+        if !value.is_valid() {
+            errors.push(ValidationError::required(Property::AccountId));
+        }
+        let value = &self.blob_id;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::BlobId));
+        }
+        let value = &self.received_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ReceivedAt, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.foreign_key(ObjectType::Account, self.account_id.into(), None);
+        i.search(Property::AccountId, &self.account_id);
+    }
+}
+
+impl Pickle for QuarantinedMessage {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.account_id.pickle(out);
+        self.blob_id.pickle(out);
+        self.from.pickle(out);
+        self.subject.pickle(out);
+        self.size.pickle(out);
+        self.received_at.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.account_id = Pickle::unpickle(stream)?;
+        this.blob_id = Pickle::unpickle(stream)?;
+        this.from = Pickle::unpickle(stream)?;
+        this.subject = Pickle::unpickle(stream)?;
+        this.size = Pickle::unpickle(stream)?;
+        this.received_at = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for QuarantinedMessage {
+    fn default() -> Self {
+        Self {
+            account_id: Default::default(),
+            blob_id: Default::default(),
+            from: Default::default(),
+            subject: Default::default(),
+            size: 0,
+            received_at: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for QuarantinedMessage {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(9);
+        map.insert_unchecked(Property::AccountId, self.account_id.into_value());
+        map.insert_unchecked(Property::BlobId, self.blob_id.into_value());
+        map.insert_unchecked(Property::From, self.from.into_value());
+        map.insert_unchecked(Property::Subject, self.subject.into_value());
+        map.insert_unchecked(Property::Size, self.size.into_value());
+        map.insert_unchecked(Property::ReceivedAt, self.received_at.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for QuarantinedMessage {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::AccountId) => pointer.assert_server_set(),
+            Some(Property::BlobId) => pointer.assert_server_set(),
+            Some(Property::From) => pointer.assert_server_set(),
+            Some(Property::Subject) => pointer.assert_server_set(),
+            Some(Property::Size) => pointer.assert_server_set(),
+            Some(Property::ReceivedAt) => pointer.assert_server_set(),
+            Some(Property::ExpiresAt) => pointer.assert_server_set(),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl QueueExpiry {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         match self {
@@ -31945,6 +34985,201 @@ impl RegistryJsonPropertyPatch for RedisClusterStore {
     }
 }
 
+impl RedisSentinelStore {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.urls;
+        for value in value.iter() {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Urls));
+            }
+        }
+        let value = &self.master_name;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::MasterName));
+        }
+        if let Some(value) = &self.auth_username {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::AuthUsername));
+            }
+        }
+        let value = &self.auth_secret;
+        value.validate(errors);
+        if let Some(value) = &self.max_retry_wait {
+            if !value.is_valid() {
+                errors.push(ValidationError::invalid(Property::MaxRetryWait, value));
+            }
+            if *value > Duration::from_millis(1024) {
+                errors.push(ValidationError::max_value(Property::MaxRetryWait, 1024));
+            }
+            if *value < Duration::from_millis(1) {
+                errors.push(ValidationError::min_value(Property::MaxRetryWait, 1));
+            }
+        }
+        if let Some(value) = &self.min_retry_wait {
+            if !value.is_valid() {
+                errors.push(ValidationError::invalid(Property::MinRetryWait, value));
+            }
+            if *value > Duration::from_millis(1024) {
+                errors.push(ValidationError::max_value(Property::MinRetryWait, 1024));
+            }
+            if *value < Duration::from_millis(1) {
+                errors.push(ValidationError::min_value(Property::MinRetryWait, 1));
+            }
+        }
+        if let Some(value) = &self.max_retries {
+            if *value > 1024 {
+                errors.push(ValidationError::max_value(Property::MaxRetries, 1024));
+            }
+            if *value < 1 {
+                errors.push(ValidationError::min_value(Property::MaxRetries, 1));
+            }
+        }
+        let value = &self.pool_max_connections;
+        if *value > 8192 {
+            errors.push(ValidationError::max_value(
+                Property::PoolMaxConnections,
+                8192,
+            ));
+        }
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::PoolMaxConnections, 1));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for RedisSentinelStore {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.urls.pickle(out);
+        self.master_name.pickle(out);
+        self.use_tls.pickle(out);
+        self.timeout.pickle(out);
+        self.auth_username.pickle(out);
+        self.auth_secret.pickle(out);
+        self.max_retry_wait.pickle(out);
+        self.min_retry_wait.pickle(out);
+        self.max_retries.pickle(out);
+        self.protocol_version.pickle(out);
+        self.pool_max_connections.pickle(out);
+        self.pool_timeout_create.pickle(out);
+        self.pool_timeout_wait.pickle(out);
+        self.pool_timeout_recycle.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.urls = Pickle::unpickle(stream)?;
+        this.master_name = Pickle::unpickle(stream)?;
+        this.use_tls = Pickle::unpickle(stream)?;
+        this.timeout = Pickle::unpickle(stream)?;
+        this.auth_username = Pickle::unpickle(stream)?;
+        this.auth_secret = Pickle::unpickle(stream)?;
+        this.max_retry_wait = Pickle::unpickle(stream)?;
+        this.min_retry_wait = Pickle::unpickle(stream)?;
+        this.max_retries = Pickle::unpickle(stream)?;
+        this.protocol_version = Pickle::unpickle(stream)?;
+        this.pool_max_connections = Pickle::unpickle(stream)?;
+        this.pool_timeout_create = Pickle::unpickle(stream)?;
+        this.pool_timeout_wait = Pickle::unpickle(stream)?;
+        this.pool_timeout_recycle = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for RedisSentinelStore {
+    fn default() -> Self {
+        Self {
+            urls: Map::new(vec!["redis://127.0.0.1:26379".to_string()]),
+            master_name: "mymaster".to_string(),
+            use_tls: false,
+            timeout: Duration::from_millis(10000),
+            auth_username: Some("stalwart".to_string()),
+            auth_secret: Default::default(),
+            max_retry_wait: Default::default(),
+            min_retry_wait: Default::default(),
+            max_retries: Default::default(),
+            protocol_version: RedisProtocol::Resp2,
+            pool_max_connections: 10u64,
+            pool_timeout_create: Some(Duration::from_millis(30000)),
+            pool_timeout_wait: Some(Duration::from_millis(30000)),
+            pool_timeout_recycle: Some(Duration::from_millis(30000)),
+        }
+    }
+}
+
+impl IntoValue for RedisSentinelStore {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(15);
+        map.insert_unchecked(Property::Urls, self.urls.into_value());
+        map.insert_unchecked(Property::MasterName, self.master_name.into_value());
+        map.insert_unchecked(Property::UseTls, self.use_tls.into_value());
+        map.insert_unchecked(Property::Timeout, self.timeout.into_value());
+        map.insert_unchecked(Property::AuthUsername, self.auth_username.into_value());
+        map.insert_unchecked(Property::AuthSecret, self.auth_secret.into_value());
+        map.insert_unchecked(Property::MaxRetryWait, self.max_retry_wait.into_value());
+        map.insert_unchecked(Property::MinRetryWait, self.min_retry_wait.into_value());
+        map.insert_unchecked(Property::MaxRetries, self.max_retries.into_value());
+        map.insert_unchecked(
+            Property::ProtocolVersion,
+            self.protocol_version.into_value(),
+        );
+        map.insert_unchecked(
+            Property::PoolMaxConnections,
+            self.pool_max_connections.into_value(),
+        );
+        map.insert_unchecked(
+            Property::PoolTimeoutCreate,
+            self.pool_timeout_create.into_value(),
+        );
+        map.insert_unchecked(
+            Property::PoolTimeoutWait,
+            self.pool_timeout_wait.into_value(),
+        );
+        map.insert_unchecked(
+            Property::PoolTimeoutRecycle,
+            self.pool_timeout_recycle.into_value(),
+        );
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for RedisSentinelStore {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Urls) => self
+                .urls
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::MasterName) => self
+                .master_name
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::UseTls) => self.use_tls.patch(pointer, value),
+            Some(Property::Timeout) => self.timeout.patch(pointer, value),
+            Some(Property::AuthUsername) => self
+                .auth_username
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::AuthSecret) => self.auth_secret.patch(pointer, value),
+            Some(Property::MaxRetryWait) => self.max_retry_wait.patch(pointer, value),
+            Some(Property::MinRetryWait) => self.min_retry_wait.patch(pointer, value),
+            Some(Property::MaxRetries) => self.max_retries.patch(pointer, value),
+            Some(Property::ProtocolVersion) => self.protocol_version.patch(pointer, value),
+            Some(Property::PoolMaxConnections) => self.pool_max_connections.patch(pointer, value),
+            Some(Property::PoolTimeoutCreate) => self.pool_timeout_create.patch(pointer, value),
+            Some(Property::PoolTimeoutWait) => self.pool_timeout_wait.patch(pointer, value),
+            Some(Property::PoolTimeoutRecycle) => self.pool_timeout_recycle.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl RedisStore {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -32050,9 +35285,79 @@ impl RegistryJsonPropertyPatch for RedisStore {
     }
 }
 
+impl RegionalBlobStore {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.regions;
+        for value in value.values() {
+            value.validate(errors);
+        }
+        if value.is_empty() {
+            errors.push(ValidationError::min_items(Property::Regions, 1));
+        }
+        let value = &self.default_region;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::DefaultRegion));
+        } else if !self.regions.values().any(|region| &region.name == value) {
+            errors.push(ValidationError::invalid(Property::DefaultRegion, value));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for RegionalBlobStore {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.regions.pickle(out);
+        self.default_region.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.regions = Pickle::unpickle(stream)?;
+        this.default_region = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for RegionalBlobStore {
+    fn default() -> Self {
+        Self {
+            regions: Default::default(),
+            default_region: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for RegionalBlobStore {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::Regions, self.regions.into_value());
+        map.insert_unchecked(Property::DefaultRegion, self.default_region.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for RegionalBlobStore {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Regions) => self.regions.patch(pointer, value),
+            Some(Property::DefaultRegion) => self.default_region.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for ReportSettings {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::ReportSettings;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -32070,6 +35375,9 @@ impl ObjectImpl for ReportSettings {
         }
         let value = &self.outbound_report_submitter;
         value.validate(errors);
+        if let Some(value) = &self.inbound_tls_failure_rate {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -32101,6 +35409,7 @@ impl Pickle for ReportSettings {
         self.inbound_report_forwarding.pickle(out);
         self.outbound_report_domain.pickle(out);
         self.outbound_report_submitter.pickle(out);
+        self.inbound_tls_failure_rate.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -32109,6 +35418,9 @@ impl Pickle for ReportSettings {
         this.inbound_report_forwarding = Pickle::unpickle(stream)?;
         this.outbound_report_domain = Pickle::unpickle(stream)?;
         this.outbound_report_submitter = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.inbound_tls_failure_rate = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -32123,13 +35435,17 @@ impl Default for ReportSettings {
                 else_: "system('hostname')".to_string(),
                 ..Default::default()
             },
+            inbound_tls_failure_rate: Some(Rate {
+                count: 100u64,
+                period: Duration::from_millis(86400000),
+            }),
         }
     }
 }
 
 impl IntoValue for ReportSettings {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(7);
         map.insert_unchecked(
             Property::InboundReportAddresses,
             self.inbound_report_addresses.into_value(),
@@ -32146,6 +35462,10 @@ impl IntoValue for ReportSettings {
             Property::OutboundReportSubmitter,
             self.outbound_report_submitter.into_value(),
         );
+        map.insert_unchecked(
+            Property::InboundTlsFailureRate,
+            self.inbound_tls_failure_rate.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -32169,6 +35489,9 @@ impl RegistryJsonPropertyPatch for ReportSettings {
             Some(Property::OutboundReportSubmitter) => {
                 self.outbound_report_submitter.patch(pointer, value)
             }
+            Some(Property::InboundTlsFailureRate) => {
+                self.inbound_tls_failure_rate.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -32217,6 +35540,8 @@ impl Pickle for RocksDbStore {
         self.blob_size.pickle(out);
         self.buffer_size.pickle(out);
         self.pool_workers.pickle(out);
+        self.compaction_rate_limit.pickle(out);
+        self.max_background_jobs.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -32225,6 +35550,12 @@ impl Pickle for RocksDbStore {
         this.blob_size = Pickle::unpickle(stream)?;
         this.buffer_size = Pickle::unpickle(stream)?;
         this.pool_workers = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.compaction_rate_limit = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 1 {
+            this.max_background_jobs = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -32236,17 +35567,27 @@ impl Default for RocksDbStore {
             blob_size: 16834u64,
             buffer_size: 134217728u64,
             pool_workers: Default::default(),
+            compaction_rate_limit: Default::default(),
+            max_background_jobs: Default::default(),
         }
     }
 }
 
 impl IntoValue for RocksDbStore {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(8);
         map.insert_unchecked(Property::Path, self.path.into_value());
         map.insert_unchecked(Property::BlobSize, self.blob_size.into_value());
         map.insert_unchecked(Property::BufferSize, self.buffer_size.into_value());
         map.insert_unchecked(Property::PoolWorkers, self.pool_workers.into_value());
+        map.insert_unchecked(
+            Property::CompactionRateLimit,
+            self.compaction_rate_limit.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MaxBackgroundJobs,
+            self.max_background_jobs.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -32264,6 +35605,8 @@ impl RegistryJsonPropertyPatch for RocksDbStore {
             Some(Property::BlobSize) => self.blob_size.patch(pointer, value),
             Some(Property::BufferSize) => self.buffer_size.patch(pointer, value),
             Some(Property::PoolWorkers) => self.pool_workers.patch(pointer, value),
+            Some(Property::CompactionRateLimit) => self.compaction_rate_limit.patch(pointer, value),
+            Some(Property::MaxBackgroundJobs) => self.max_background_jobs.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -33321,7 +36664,7 @@ impl S3StoreRegion {
 
 impl ObjectImpl for Search {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::Search;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -33330,6 +36673,10 @@ impl ObjectImpl for Search {
         if *value < 1 {
             errors.push(ValidationError::min_value(Property::IndexBatchSize, 1));
         }
+        let value = &self.reindex_batch_size;
+        if *value < 1 {
+            errors.push(ValidationError::min_value(Property::ReindexBatchSize, 1));
+        }
         errors.len() == neb
     }
 
@@ -33349,6 +36696,9 @@ impl Pickle for Search {
         self.index_email_fields.pickle(out);
         self.index_telemetry.pickle(out);
         self.index_tracing_fields.pickle(out);
+        self.index_max_body_size.pickle(out);
+        self.reindex_batch_size.pickle(out);
+        self.reindex_interval.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -33364,6 +36714,15 @@ impl Pickle for Search {
         this.index_email_fields = Pickle::unpickle(stream)?;
         this.index_telemetry = Pickle::unpickle(stream)?;
         this.index_tracing_fields = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.index_max_body_size = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.reindex_batch_size = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.reindex_interval = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -33418,13 +36777,16 @@ impl Default for Search {
                 SearchTracingField::QueueId,
                 SearchTracingField::Keywords,
             ]),
+            index_max_body_size: Default::default(),
+            reindex_batch_size: 500u64,
+            reindex_interval: Duration::from_millis(1000),
         }
     }
 }
 
 impl IntoValue for Search {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(13);
+        let mut map = jmap_tools::Map::with_capacity(16);
         map.insert_unchecked(Property::IndexBatchSize, self.index_batch_size.into_value());
         map.insert_unchecked(
             Property::DefaultLanguage,
@@ -33454,6 +36816,18 @@ impl IntoValue for Search {
             Property::IndexTracingFields,
             self.index_tracing_fields.into_value(),
         );
+        map.insert_unchecked(
+            Property::IndexMaxBodySize,
+            self.index_max_body_size.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ReindexBatchSize,
+            self.reindex_batch_size.into_value(),
+        );
+        map.insert_unchecked(
+            Property::ReindexInterval,
+            self.reindex_interval.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -33476,6 +36850,9 @@ impl RegistryJsonPropertyPatch for Search {
             Some(Property::IndexEmailFields) => self.index_email_fields.patch(pointer, value),
             Some(Property::IndexTelemetry) => self.index_telemetry.patch(pointer, value),
             Some(Property::IndexTracingFields) => self.index_tracing_fields.patch(pointer, value),
+            Some(Property::IndexMaxBodySize) => self.index_max_body_size.patch(pointer, value),
+            Some(Property::ReindexBatchSize) => self.reindex_batch_size.patch(pointer, value),
+            Some(Property::ReindexInterval) => self.reindex_interval.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -33487,7 +36864,7 @@ impl RegistryJsonPropertyPatch for Search {
 
 impl ObjectImpl for SearchStore {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::SearchStore;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -33762,6 +37139,9 @@ impl SecretKey {
             SecretKey::Value(inner) => inner.validate(errors),
             SecretKey::EnvironmentVariable(inner) => inner.validate(errors),
             SecretKey::File(inner) => inner.validate(errors),
+            SecretKey::Vault(inner) => inner.validate(errors),
+            SecretKey::AwsSecretsManager(inner) => inner.validate(errors),
+            SecretKey::EnvironmentJson(inner) => inner.validate(errors),
         }
     }
 }
@@ -33787,6 +37167,18 @@ impl Pickle for SecretKey {
                 2u16.pickle(out);
                 inner.pickle(out);
             }
+            SecretKey::Vault(inner) => {
+                3u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretKey::AwsSecretsManager(inner) => {
+                4u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretKey::EnvironmentJson(inner) => {
+                5u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -33795,6 +37187,9 @@ impl Pickle for SecretKey {
             0 => Pickle::unpickle(stream).map(SecretKey::Value),
             1 => Pickle::unpickle(stream).map(SecretKey::EnvironmentVariable),
             2 => Pickle::unpickle(stream).map(SecretKey::File),
+            3 => Pickle::unpickle(stream).map(SecretKey::Vault),
+            4 => Pickle::unpickle(stream).map(SecretKey::AwsSecretsManager),
+            5 => Pickle::unpickle(stream).map(SecretKey::EnvironmentJson),
             _ => None,
         }
     }
@@ -33824,6 +37219,27 @@ impl IntoValue for SecretKey {
                     .insert_unchecked(Property::Type, JmapValue::Str("File".into()));
                 obj
             }
+            SecretKey::Vault(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Vault".into()));
+                obj
+            }
+            SecretKey::AwsSecretsManager(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("AwsSecretsManager".into()));
+                obj
+            }
+            SecretKey::EnvironmentJson(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("EnvironmentJson".into()));
+                obj
+            }
         }
     }
 }
@@ -33841,12 +37257,22 @@ impl RegistryJsonPatch for SecretKey {
                     *self = SecretKey::EnvironmentVariable(Default::default())
                 }
                 SecretKeyType::File => *self = SecretKey::File(Default::default()),
+                SecretKeyType::Vault => *self = SecretKey::Vault(Default::default()),
+                SecretKeyType::AwsSecretsManager => {
+                    *self = SecretKey::AwsSecretsManager(Default::default())
+                }
+                SecretKeyType::EnvironmentJson => {
+                    *self = SecretKey::EnvironmentJson(Default::default())
+                }
             }
         }
         match self {
             SecretKey::Value(inner) => inner.patch(pointer, value),
             SecretKey::EnvironmentVariable(inner) => inner.patch(pointer, value),
             SecretKey::File(inner) => inner.patch(pointer, value),
+            SecretKey::Vault(inner) => inner.patch(pointer, value),
+            SecretKey::AwsSecretsManager(inner) => inner.patch(pointer, value),
+            SecretKey::EnvironmentJson(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -33857,6 +37283,166 @@ impl SecretKey {
             SecretKey::Value(_) => SecretKeyType::Value,
             SecretKey::EnvironmentVariable(_) => SecretKeyType::EnvironmentVariable,
             SecretKey::File(_) => SecretKeyType::File,
+            SecretKey::Vault(_) => SecretKeyType::Vault,
+            SecretKey::AwsSecretsManager(_) => SecretKeyType::AwsSecretsManager,
+            SecretKey::EnvironmentJson(_) => SecretKeyType::EnvironmentJson,
+        }
+    }
+}
+
+impl SecretKeyAwsSecretsManager {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.region;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Region));
+        }
+        let value = &self.secret_id;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::SecretId));
+        }
+        if let Some(value) = &self.key {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Key));
+            }
+        }
+        if let Some(value) = &self.access_key {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::AccessKey));
+            }
+        }
+        let value = &self.secret_key;
+        value.validate(errors);
+        errors.len() == neb
+    }
+}
+
+impl Pickle for SecretKeyAwsSecretsManager {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.region.pickle(out);
+        self.secret_id.pickle(out);
+        self.key.pickle(out);
+        self.access_key.pickle(out);
+        self.secret_key.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.region = Pickle::unpickle(stream)?;
+        this.secret_id = Pickle::unpickle(stream)?;
+        this.key = Pickle::unpickle(stream)?;
+        this.access_key = Pickle::unpickle(stream)?;
+        this.secret_key = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for SecretKeyAwsSecretsManager {
+    fn default() -> Self {
+        Self {
+            region: Default::default(),
+            secret_id: Default::default(),
+            key: Default::default(),
+            access_key: Default::default(),
+            secret_key: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for SecretKeyAwsSecretsManager {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(7);
+        map.insert_unchecked(Property::Region, self.region.into_value());
+        map.insert_unchecked(Property::SecretId, self.secret_id.into_value());
+        map.insert_unchecked(Property::Key, self.key.into_value());
+        map.insert_unchecked(Property::AccessKey, self.access_key.into_value());
+        map.insert_unchecked(Property::SecretKey, self.secret_key.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for SecretKeyAwsSecretsManager {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Region) => self.region.patch(pointer, value),
+            Some(Property::SecretId) => self.secret_id.patch(pointer, value),
+            Some(Property::Key) => self.key.patch(pointer, value),
+            Some(Property::AccessKey) => self.access_key.patch(pointer, value),
+            Some(Property::SecretKey) => self.secret_key.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl SecretKeyEnvironmentJson {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.variable_name;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::VariableName));
+        }
+        let value = &self.key;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Key));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for SecretKeyEnvironmentJson {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.variable_name.pickle(out);
+        self.key.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.variable_name = Pickle::unpickle(stream)?;
+        this.key = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for SecretKeyEnvironmentJson {
+    fn default() -> Self {
+        Self {
+            variable_name: Default::default(),
+            key: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for SecretKeyEnvironmentJson {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::VariableName, self.variable_name.into_value());
+        map.insert_unchecked(Property::Key, self.key.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for SecretKeyEnvironmentJson {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::VariableName) => self.variable_name.patch(pointer, value),
+            Some(Property::Key) => self.key.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
         }
     }
 }
@@ -33973,6 +37559,130 @@ impl RegistryJsonPropertyPatch for SecretKeyFile {
     }
 }
 
+impl SecretKeyLocalOptional {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        match self {
+            SecretKeyLocalOptional::None => true,
+            SecretKeyLocalOptional::Value(inner) => inner.validate(errors),
+            SecretKeyLocalOptional::EnvironmentVariable(inner) => inner.validate(errors),
+            SecretKeyLocalOptional::File(inner) => inner.validate(errors),
+        }
+    }
+}
+
+impl Default for SecretKeyLocalOptional {
+    fn default() -> Self {
+        SecretKeyLocalOptional::None
+    }
+}
+
+impl Pickle for SecretKeyLocalOptional {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        match self {
+            SecretKeyLocalOptional::None => {
+                0u16.pickle(out);
+            }
+            SecretKeyLocalOptional::Value(inner) => {
+                1u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretKeyLocalOptional::EnvironmentVariable(inner) => {
+                2u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretKeyLocalOptional::File(inner) => {
+                3u16.pickle(out);
+                inner.pickle(out);
+            }
+        }
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        match u16::unpickle(stream)? {
+            0 => Some(SecretKeyLocalOptional::None),
+            1 => Pickle::unpickle(stream).map(SecretKeyLocalOptional::Value),
+            2 => Pickle::unpickle(stream).map(SecretKeyLocalOptional::EnvironmentVariable),
+            3 => Pickle::unpickle(stream).map(SecretKeyLocalOptional::File),
+            _ => None,
+        }
+    }
+}
+
+impl IntoValue for SecretKeyLocalOptional {
+    fn into_value(self) -> JmapValue<'static> {
+        match self {
+            SecretKeyLocalOptional::None => {
+                let mut obj = jmap_tools::Map::new();
+                obj.insert_unchecked(Property::Type, JmapValue::Str("None".into()));
+                JmapValue::Object(obj)
+            }
+            SecretKeyLocalOptional::Value(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Value".into()));
+                obj
+            }
+            SecretKeyLocalOptional::EnvironmentVariable(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("EnvironmentVariable".into()));
+                obj
+            }
+            SecretKeyLocalOptional::File(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("File".into()));
+                obj
+            }
+        }
+    }
+}
+
+impl RegistryJsonPatch for SecretKeyLocalOptional {
+    fn patch<'x>(
+        &mut self,
+        pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        if !pointer.has_next() {
+            match object_type(&pointer, &value)? {
+                SecretKeyLocalOptionalType::None => *self = SecretKeyLocalOptional::None,
+                SecretKeyLocalOptionalType::Value => {
+                    *self = SecretKeyLocalOptional::Value(Default::default())
+                }
+                SecretKeyLocalOptionalType::EnvironmentVariable => {
+                    *self = SecretKeyLocalOptional::EnvironmentVariable(Default::default())
+                }
+                SecretKeyLocalOptionalType::File => {
+                    *self = SecretKeyLocalOptional::File(Default::default())
+                }
+            }
+        }
+        match self {
+            SecretKeyLocalOptional::None => pointer.assert_eof(),
+            SecretKeyLocalOptional::Value(inner) => inner.patch(pointer, value),
+            SecretKeyLocalOptional::EnvironmentVariable(inner) => inner.patch(pointer, value),
+            SecretKeyLocalOptional::File(inner) => inner.patch(pointer, value),
+        }
+    }
+}
+
+impl SecretKeyLocalOptional {
+    pub fn object_type(&self) -> SecretKeyLocalOptionalType {
+        match self {
+            SecretKeyLocalOptional::None => SecretKeyLocalOptionalType::None,
+            SecretKeyLocalOptional::Value(_) => SecretKeyLocalOptionalType::Value,
+            SecretKeyLocalOptional::EnvironmentVariable(_) => {
+                SecretKeyLocalOptionalType::EnvironmentVariable
+            }
+            SecretKeyLocalOptional::File(_) => SecretKeyLocalOptionalType::File,
+        }
+    }
+}
+
 impl SecretKeyOptional {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         match self {
@@ -33980,6 +37690,9 @@ impl SecretKeyOptional {
             SecretKeyOptional::Value(inner) => inner.validate(errors),
             SecretKeyOptional::EnvironmentVariable(inner) => inner.validate(errors),
             SecretKeyOptional::File(inner) => inner.validate(errors),
+            SecretKeyOptional::Vault(inner) => inner.validate(errors),
+            SecretKeyOptional::AwsSecretsManager(inner) => inner.validate(errors),
+            SecretKeyOptional::EnvironmentJson(inner) => inner.validate(errors),
         }
     }
 }
@@ -34008,6 +37721,18 @@ impl Pickle for SecretKeyOptional {
                 3u16.pickle(out);
                 inner.pickle(out);
             }
+            SecretKeyOptional::Vault(inner) => {
+                4u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretKeyOptional::AwsSecretsManager(inner) => {
+                5u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretKeyOptional::EnvironmentJson(inner) => {
+                6u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -34017,6 +37742,9 @@ impl Pickle for SecretKeyOptional {
             1 => Pickle::unpickle(stream).map(SecretKeyOptional::Value),
             2 => Pickle::unpickle(stream).map(SecretKeyOptional::EnvironmentVariable),
             3 => Pickle::unpickle(stream).map(SecretKeyOptional::File),
+            4 => Pickle::unpickle(stream).map(SecretKeyOptional::Vault),
+            5 => Pickle::unpickle(stream).map(SecretKeyOptional::AwsSecretsManager),
+            6 => Pickle::unpickle(stream).map(SecretKeyOptional::EnvironmentJson),
             _ => None,
         }
     }
@@ -34051,6 +37779,27 @@ impl IntoValue for SecretKeyOptional {
                     .insert_unchecked(Property::Type, JmapValue::Str("File".into()));
                 obj
             }
+            SecretKeyOptional::Vault(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Vault".into()));
+                obj
+            }
+            SecretKeyOptional::AwsSecretsManager(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("AwsSecretsManager".into()));
+                obj
+            }
+            SecretKeyOptional::EnvironmentJson(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("EnvironmentJson".into()));
+                obj
+            }
         }
     }
 }
@@ -34071,6 +37820,15 @@ impl RegistryJsonPatch for SecretKeyOptional {
                     *self = SecretKeyOptional::EnvironmentVariable(Default::default())
                 }
                 SecretKeyOptionalType::File => *self = SecretKeyOptional::File(Default::default()),
+                SecretKeyOptionalType::Vault => {
+                    *self = SecretKeyOptional::Vault(Default::default())
+                }
+                SecretKeyOptionalType::AwsSecretsManager => {
+                    *self = SecretKeyOptional::AwsSecretsManager(Default::default())
+                }
+                SecretKeyOptionalType::EnvironmentJson => {
+                    *self = SecretKeyOptional::EnvironmentJson(Default::default())
+                }
             }
         }
         match self {
@@ -34078,6 +37836,9 @@ impl RegistryJsonPatch for SecretKeyOptional {
             SecretKeyOptional::Value(inner) => inner.patch(pointer, value),
             SecretKeyOptional::EnvironmentVariable(inner) => inner.patch(pointer, value),
             SecretKeyOptional::File(inner) => inner.patch(pointer, value),
+            SecretKeyOptional::Vault(inner) => inner.patch(pointer, value),
+            SecretKeyOptional::AwsSecretsManager(inner) => inner.patch(pointer, value),
+            SecretKeyOptional::EnvironmentJson(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -34089,6 +37850,9 @@ impl SecretKeyOptional {
             SecretKeyOptional::Value(_) => SecretKeyOptionalType::Value,
             SecretKeyOptional::EnvironmentVariable(_) => SecretKeyOptionalType::EnvironmentVariable,
             SecretKeyOptional::File(_) => SecretKeyOptionalType::File,
+            SecretKeyOptional::Vault(_) => SecretKeyOptionalType::Vault,
+            SecretKeyOptional::AwsSecretsManager(_) => SecretKeyOptionalType::AwsSecretsManager,
+            SecretKeyOptional::EnvironmentJson(_) => SecretKeyOptionalType::EnvironmentJson,
         }
     }
 }
@@ -34149,12 +37913,106 @@ impl RegistryJsonPropertyPatch for SecretKeyValue {
     }
 }
 
+impl SecretKeyVault {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.url;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Url));
+        }
+        let value = &self.path;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Path));
+        }
+        let value = &self.key;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Key));
+        }
+        let value = &self.token;
+        value.validate(errors);
+        if let Some(value) = &self.namespace {
+            if value.is_empty() {
+                errors.push(ValidationError::required(Property::Namespace));
+            }
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for SecretKeyVault {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.url.pickle(out);
+        self.path.pickle(out);
+        self.key.pickle(out);
+        self.token.pickle(out);
+        self.namespace.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.url = Pickle::unpickle(stream)?;
+        this.path = Pickle::unpickle(stream)?;
+        this.key = Pickle::unpickle(stream)?;
+        this.token = Pickle::unpickle(stream)?;
+        this.namespace = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for SecretKeyVault {
+    fn default() -> Self {
+        Self {
+            url: Default::default(),
+            path: Default::default(),
+            key: Default::default(),
+            token: Default::default(),
+            namespace: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for SecretKeyVault {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(7);
+        map.insert_unchecked(Property::Url, self.url.into_value());
+        map.insert_unchecked(Property::Path, self.path.into_value());
+        map.insert_unchecked(Property::Key, self.key.into_value());
+        map.insert_unchecked(Property::Token, self.token.into_value());
+        map.insert_unchecked(Property::Namespace, self.namespace.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for SecretKeyVault {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Url) => self.url.patch(pointer, value),
+            Some(Property::Path) => self.path.patch(pointer, value),
+            Some(Property::Key) => self.key.patch(pointer, value),
+            Some(Property::Token) => self.token.patch(pointer, value),
+            Some(Property::Namespace) => self.namespace.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl SecretText {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         match self {
             SecretText::Text(inner) => inner.validate(errors),
             SecretText::EnvironmentVariable(inner) => inner.validate(errors),
             SecretText::File(inner) => inner.validate(errors),
+            SecretText::Vault(inner) => inner.validate(errors),
+            SecretText::AwsSecretsManager(inner) => inner.validate(errors),
+            SecretText::EnvironmentJson(inner) => inner.validate(errors),
         }
     }
 }
@@ -34180,6 +38038,18 @@ impl Pickle for SecretText {
                 2u16.pickle(out);
                 inner.pickle(out);
             }
+            SecretText::Vault(inner) => {
+                3u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretText::AwsSecretsManager(inner) => {
+                4u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretText::EnvironmentJson(inner) => {
+                5u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -34188,6 +38058,9 @@ impl Pickle for SecretText {
             0 => Pickle::unpickle(stream).map(SecretText::Text),
             1 => Pickle::unpickle(stream).map(SecretText::EnvironmentVariable),
             2 => Pickle::unpickle(stream).map(SecretText::File),
+            3 => Pickle::unpickle(stream).map(SecretText::Vault),
+            4 => Pickle::unpickle(stream).map(SecretText::AwsSecretsManager),
+            5 => Pickle::unpickle(stream).map(SecretText::EnvironmentJson),
             _ => None,
         }
     }
@@ -34217,6 +38090,27 @@ impl IntoValue for SecretText {
                     .insert_unchecked(Property::Type, JmapValue::Str("File".into()));
                 obj
             }
+            SecretText::Vault(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Vault".into()));
+                obj
+            }
+            SecretText::AwsSecretsManager(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("AwsSecretsManager".into()));
+                obj
+            }
+            SecretText::EnvironmentJson(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("EnvironmentJson".into()));
+                obj
+            }
         }
     }
 }
@@ -34234,12 +38128,22 @@ impl RegistryJsonPatch for SecretText {
                     *self = SecretText::EnvironmentVariable(Default::default())
                 }
                 SecretTextType::File => *self = SecretText::File(Default::default()),
+                SecretTextType::Vault => *self = SecretText::Vault(Default::default()),
+                SecretTextType::AwsSecretsManager => {
+                    *self = SecretText::AwsSecretsManager(Default::default())
+                }
+                SecretTextType::EnvironmentJson => {
+                    *self = SecretText::EnvironmentJson(Default::default())
+                }
             }
         }
         match self {
             SecretText::Text(inner) => inner.patch(pointer, value),
             SecretText::EnvironmentVariable(inner) => inner.patch(pointer, value),
             SecretText::File(inner) => inner.patch(pointer, value),
+            SecretText::Vault(inner) => inner.patch(pointer, value),
+            SecretText::AwsSecretsManager(inner) => inner.patch(pointer, value),
+            SecretText::EnvironmentJson(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -34250,6 +38154,9 @@ impl SecretText {
             SecretText::Text(_) => SecretTextType::Text,
             SecretText::EnvironmentVariable(_) => SecretTextType::EnvironmentVariable,
             SecretText::File(_) => SecretTextType::File,
+            SecretText::Vault(_) => SecretTextType::Vault,
+            SecretText::AwsSecretsManager(_) => SecretTextType::AwsSecretsManager,
+            SecretText::EnvironmentJson(_) => SecretTextType::EnvironmentJson,
         }
     }
 }
@@ -34261,6 +38168,9 @@ impl SecretTextOptional {
             SecretTextOptional::Text(inner) => inner.validate(errors),
             SecretTextOptional::EnvironmentVariable(inner) => inner.validate(errors),
             SecretTextOptional::File(inner) => inner.validate(errors),
+            SecretTextOptional::Vault(inner) => inner.validate(errors),
+            SecretTextOptional::AwsSecretsManager(inner) => inner.validate(errors),
+            SecretTextOptional::EnvironmentJson(inner) => inner.validate(errors),
         }
     }
 }
@@ -34289,6 +38199,18 @@ impl Pickle for SecretTextOptional {
                 3u16.pickle(out);
                 inner.pickle(out);
             }
+            SecretTextOptional::Vault(inner) => {
+                4u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretTextOptional::AwsSecretsManager(inner) => {
+                5u16.pickle(out);
+                inner.pickle(out);
+            }
+            SecretTextOptional::EnvironmentJson(inner) => {
+                6u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -34298,6 +38220,9 @@ impl Pickle for SecretTextOptional {
             1 => Pickle::unpickle(stream).map(SecretTextOptional::Text),
             2 => Pickle::unpickle(stream).map(SecretTextOptional::EnvironmentVariable),
             3 => Pickle::unpickle(stream).map(SecretTextOptional::File),
+            4 => Pickle::unpickle(stream).map(SecretTextOptional::Vault),
+            5 => Pickle::unpickle(stream).map(SecretTextOptional::AwsSecretsManager),
+            6 => Pickle::unpickle(stream).map(SecretTextOptional::EnvironmentJson),
             _ => None,
         }
     }
@@ -34332,6 +38257,27 @@ impl IntoValue for SecretTextOptional {
                     .insert_unchecked(Property::Type, JmapValue::Str("File".into()));
                 obj
             }
+            SecretTextOptional::Vault(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("Vault".into()));
+                obj
+            }
+            SecretTextOptional::AwsSecretsManager(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("AwsSecretsManager".into()));
+                obj
+            }
+            SecretTextOptional::EnvironmentJson(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("EnvironmentJson".into()));
+                obj
+            }
         }
     }
 }
@@ -34354,6 +38300,15 @@ impl RegistryJsonPatch for SecretTextOptional {
                 SecretTextOptionalType::File => {
                     *self = SecretTextOptional::File(Default::default())
                 }
+                SecretTextOptionalType::Vault => {
+                    *self = SecretTextOptional::Vault(Default::default())
+                }
+                SecretTextOptionalType::AwsSecretsManager => {
+                    *self = SecretTextOptional::AwsSecretsManager(Default::default())
+                }
+                SecretTextOptionalType::EnvironmentJson => {
+                    *self = SecretTextOptional::EnvironmentJson(Default::default())
+                }
             }
         }
         match self {
@@ -34361,6 +38316,9 @@ impl RegistryJsonPatch for SecretTextOptional {
             SecretTextOptional::Text(inner) => inner.patch(pointer, value),
             SecretTextOptional::EnvironmentVariable(inner) => inner.patch(pointer, value),
             SecretTextOptional::File(inner) => inner.patch(pointer, value),
+            SecretTextOptional::Vault(inner) => inner.patch(pointer, value),
+            SecretTextOptional::AwsSecretsManager(inner) => inner.patch(pointer, value),
+            SecretTextOptional::EnvironmentJson(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -34374,6 +38332,9 @@ impl SecretTextOptional {
                 SecretTextOptionalType::EnvironmentVariable
             }
             SecretTextOptional::File(_) => SecretTextOptionalType::File,
+            SecretTextOptional::Vault(_) => SecretTextOptionalType::Vault,
+            SecretTextOptional::AwsSecretsManager(_) => SecretTextOptionalType::AwsSecretsManager,
+            SecretTextOptional::EnvironmentJson(_) => SecretTextOptionalType::EnvironmentJson,
         }
     }
 }
@@ -34436,7 +38397,7 @@ impl RegistryJsonPropertyPatch for SecretTextValue {
 
 impl ObjectImpl for Security {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 3;
     const OBJECT: ObjectType = ObjectType::Security;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -34459,6 +38420,16 @@ impl ObjectImpl for Security {
         if let Some(value) = &self.scan_ban_rate {
             value.validate(errors);
         }
+        if let Some(value) = &self.smuggling_ban_rate {
+            value.validate(errors);
+        }
+        let value = &self.ipv6_prefix_length;
+        if *value < 32 {
+            errors.push(ValidationError::min_value(Property::Ipv6PrefixLength, 32));
+        }
+        if *value > 128 {
+            errors.push(ValidationError::max_value(Property::Ipv6PrefixLength, 128));
+        }
         errors.len() == neb
     }
 
@@ -34476,6 +38447,11 @@ impl Pickle for Security {
         self.scan_ban_paths.pickle(out);
         self.scan_ban_rate.pickle(out);
         self.scan_ban_period.pickle(out);
+        self.auth_delay.pickle(out);
+        self.auth_delay_max.pickle(out);
+        self.auth_delay_reset.pickle(out);
+        self.smuggling_ban_rate.pickle(out);
+        self.ipv6_prefix_length.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -34489,6 +38465,17 @@ impl Pickle for Security {
         this.scan_ban_paths = Pickle::unpickle(stream)?;
         this.scan_ban_rate = Pickle::unpickle(stream)?;
         this.scan_ban_period = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.auth_delay = Pickle::unpickle(stream)?;
+            this.auth_delay_max = Pickle::unpickle(stream)?;
+            this.auth_delay_reset = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.smuggling_ban_rate = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.ipv6_prefix_length = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -34530,13 +38517,18 @@ impl Default for Security {
                 period: Duration::from_millis(86400000),
             }),
             scan_ban_period: Default::default(),
+            auth_delay: Default::default(),
+            auth_delay_max: Duration::from_millis(30000),
+            auth_delay_reset: Duration::from_millis(3600000),
+            smuggling_ban_rate: Default::default(),
+            ipv6_prefix_length: 64u64,
         }
     }
 }
 
 impl IntoValue for Security {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(11);
+        let mut map = jmap_tools::Map::with_capacity(16);
         map.insert_unchecked(Property::AbuseBanRate, self.abuse_ban_rate.into_value());
         map.insert_unchecked(Property::AbuseBanPeriod, self.abuse_ban_period.into_value());
         map.insert_unchecked(Property::AuthBanRate, self.auth_ban_rate.into_value());
@@ -34549,6 +38541,17 @@ impl IntoValue for Security {
         map.insert_unchecked(Property::ScanBanPaths, self.scan_ban_paths.into_value());
         map.insert_unchecked(Property::ScanBanRate, self.scan_ban_rate.into_value());
         map.insert_unchecked(Property::ScanBanPeriod, self.scan_ban_period.into_value());
+        map.insert_unchecked(Property::AuthDelay, self.auth_delay.into_value());
+        map.insert_unchecked(Property::AuthDelayMax, self.auth_delay_max.into_value());
+        map.insert_unchecked(Property::AuthDelayReset, self.auth_delay_reset.into_value());
+        map.insert_unchecked(
+            Property::SmugglingBanRate,
+            self.smuggling_ban_rate.into_value(),
+        );
+        map.insert_unchecked(
+            Property::Ipv6PrefixLength,
+            self.ipv6_prefix_length.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -34571,6 +38574,11 @@ impl RegistryJsonPropertyPatch for Security {
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
             Some(Property::ScanBanRate) => self.scan_ban_rate.patch(pointer, value),
             Some(Property::ScanBanPeriod) => self.scan_ban_period.patch(pointer, value),
+            Some(Property::AuthDelay) => self.auth_delay.patch(pointer, value),
+            Some(Property::AuthDelayMax) => self.auth_delay_max.patch(pointer, value),
+            Some(Property::AuthDelayReset) => self.auth_delay_reset.patch(pointer, value),
+            Some(Property::SmugglingBanRate) => self.smuggling_ban_rate.patch(pointer, value),
+            Some(Property::Ipv6PrefixLength) => self.ipv6_prefix_length.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -34582,7 +38590,7 @@ impl RegistryJsonPropertyPatch for Security {
 
 impl ObjectImpl for SenderAuth {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::SenderAuth;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -34601,6 +38609,10 @@ impl ObjectImpl for SenderAuth {
         value.validate(errors);
         let value = &self.reverse_ip_verify;
         value.validate(errors);
+        let value = &self.dmarc_overrides;
+        for value in value.values() {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -34738,6 +38750,7 @@ impl Pickle for SenderAuth {
         self.arc_verify.pickle(out);
         self.dmarc_verify.pickle(out);
         self.reverse_ip_verify.pickle(out);
+        self.dmarc_overrides.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -34750,6 +38763,9 @@ impl Pickle for SenderAuth {
         this.arc_verify = Pickle::unpickle(stream)?;
         this.dmarc_verify = Pickle::unpickle(stream)?;
         this.reverse_ip_verify = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.dmarc_overrides = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -34802,13 +38818,14 @@ impl Default for SenderAuth {
                     then: "relaxed".to_string(),
                 }]),
             },
+            dmarc_overrides: Default::default(),
         }
     }
 }
 
 impl IntoValue for SenderAuth {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(10);
+        let mut map = jmap_tools::Map::with_capacity(11);
         map.insert_unchecked(Property::DkimSignDomain, self.dkim_sign_domain.into_value());
         map.insert_unchecked(Property::DkimStrict, self.dkim_strict.into_value());
         map.insert_unchecked(Property::DkimVerify, self.dkim_verify.into_value());
@@ -34820,6 +38837,7 @@ impl IntoValue for SenderAuth {
             Property::ReverseIpVerify,
             self.reverse_ip_verify.into_value(),
         );
+        map.insert_unchecked(Property::DmarcOverrides, self.dmarc_overrides.into_value());
         JmapValue::Object(map)
     }
 }
@@ -34839,6 +38857,7 @@ impl RegistryJsonPropertyPatch for SenderAuth {
             Some(Property::ArcVerify) => self.arc_verify.patch(pointer, value),
             Some(Property::DmarcVerify) => self.dmarc_verify.patch(pointer, value),
             Some(Property::ReverseIpVerify) => self.reverse_ip_verify.patch(pointer, value),
+            Some(Property::DmarcOverrides) => self.dmarc_overrides.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -35539,7 +39558,7 @@ impl RegistryJsonPropertyPatch for SieveSystemScript {
 
 impl ObjectImpl for SieveUserInterpreter {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 4;
     const OBJECT: ObjectType = ObjectType::SieveUserInterpreter;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -35629,6 +39648,20 @@ impl ObjectImpl for SieveUserInterpreter {
                 errors.push(ValidationError::min_value(Property::MaxScripts, 1));
             }
         }
+        if let Some(value) = &self.max_total_script_size {
+            if *value < 1 {
+                errors.push(ValidationError::min_value(Property::MaxTotalScriptSize, 1));
+            }
+        }
+        if let Some(value) = &self.redirect_rate_limit {
+            value.validate(errors);
+        }
+        let value = &self.max_notify_actions;
+        if *value > 100 {
+            errors.push(ValidationError::max_value(Property::MaxNotifyActions, 100));
+        }
+        let value = &self.notify_signature_key;
+        value.validate(errors);
         errors.len() == neb
     }
 
@@ -35662,6 +39695,14 @@ impl Pickle for SieveUserInterpreter {
         self.max_var_name_length.pickle(out);
         self.max_var_size.pickle(out);
         self.max_scripts.pickle(out);
+        self.allowed_ext_lists.pickle(out);
+        self.max_total_script_size.pickle(out);
+        self.redirect_rate_limit.pickle(out);
+        self.max_notify_actions.pickle(out);
+        self.max_run_time.pickle(out);
+        self.notify_signature_key.pickle(out);
+        self.notify_gateway_url.pickle(out);
+        self.notify_timeout.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -35691,6 +39732,22 @@ impl Pickle for SieveUserInterpreter {
         this.max_var_name_length = Pickle::unpickle(stream)?;
         this.max_var_size = Pickle::unpickle(stream)?;
         this.max_scripts = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.allowed_ext_lists = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.max_total_script_size = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.redirect_rate_limit = Pickle::unpickle(stream)?;
+            this.max_notify_actions = Pickle::unpickle(stream)?;
+            this.max_run_time = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.notify_signature_key = Pickle::unpickle(stream)?;
+            this.notify_gateway_url = Pickle::unpickle(stream)?;
+            this.notify_timeout = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -35728,13 +39785,24 @@ impl Default for SieveUserInterpreter {
             max_var_name_length: 32u64,
             max_var_size: 4096u64,
             max_scripts: Some(100u64),
+            allowed_ext_lists: Map::default(),
+            max_total_script_size: None,
+            redirect_rate_limit: Some(Rate {
+                count: 100u64,
+                period: Duration::from_millis(86400000),
+            }),
+            max_notify_actions: 5u64,
+            max_run_time: Duration::from_millis(5000),
+            notify_signature_key: Default::default(),
+            notify_gateway_url: Default::default(),
+            notify_timeout: Duration::from_millis(10000),
         }
     }
 }
 
 impl IntoValue for SieveUserInterpreter {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(27);
+        let mut map = jmap_tools::Map::with_capacity(35);
         map.insert_unchecked(
             Property::DefaultExpiryDuplicate,
             self.default_expiry_duplicate.into_value(),
@@ -35799,6 +39867,32 @@ impl IntoValue for SieveUserInterpreter {
         );
         map.insert_unchecked(Property::MaxVarSize, self.max_var_size.into_value());
         map.insert_unchecked(Property::MaxScripts, self.max_scripts.into_value());
+        map.insert_unchecked(
+            Property::AllowedExtLists,
+            self.allowed_ext_lists.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MaxTotalScriptSize,
+            self.max_total_script_size.into_value(),
+        );
+        map.insert_unchecked(
+            Property::RedirectRateLimit,
+            self.redirect_rate_limit.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MaxNotifyActions,
+            self.max_notify_actions.into_value(),
+        );
+        map.insert_unchecked(Property::MaxRunTime, self.max_run_time.into_value());
+        map.insert_unchecked(
+            Property::NotifySignatureKey,
+            self.notify_signature_key.into_value(),
+        );
+        map.insert_unchecked(
+            Property::NotifyGatewayUrl,
+            self.notify_gateway_url.into_value(),
+        );
+        map.insert_unchecked(Property::NotifyTimeout, self.notify_timeout.into_value());
         JmapValue::Object(map)
     }
 }
@@ -35849,6 +39943,16 @@ impl RegistryJsonPropertyPatch for SieveUserInterpreter {
             Some(Property::MaxVarNameLength) => self.max_var_name_length.patch(pointer, value),
             Some(Property::MaxVarSize) => self.max_var_size.patch(pointer, value),
             Some(Property::MaxScripts) => self.max_scripts.patch(pointer, value),
+            Some(Property::AllowedExtLists) => self
+                .allowed_ext_lists
+                .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::MaxTotalScriptSize) => self.max_total_script_size.patch(pointer, value),
+            Some(Property::RedirectRateLimit) => self.redirect_rate_limit.patch(pointer, value),
+            Some(Property::MaxNotifyActions) => self.max_notify_actions.patch(pointer, value),
+            Some(Property::MaxRunTime) => self.max_run_time.patch(pointer, value),
+            Some(Property::NotifySignatureKey) => self.notify_signature_key.patch(pointer, value),
+            Some(Property::NotifyGatewayUrl) => self.notify_gateway_url.patch(pointer, value),
+            Some(Property::NotifyTimeout) => self.notify_timeout.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -35860,7 +39964,7 @@ impl RegistryJsonPropertyPatch for SieveUserInterpreter {
 
 impl ObjectImpl for SieveUserScript {
     const FLAGS: u64 = 0;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::SieveUserScript;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -35878,11 +39982,20 @@ impl ObjectImpl for SieveUserScript {
         if value.is_empty() {
             errors.push(ValidationError::required(Property::Contents));
         }
+        if let Some(value) = &self.domain_id {
+            if !value.is_valid() {
+                errors.push(ValidationError::required(Property::DomainId));
+            }
+        }
         errors.len() == neb
     }
 
     fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
         i.unique(Property::Name, &self.name);
+        i.foreign_key(ObjectType::Domain, self.domain_id, None);
+        if let Some(value) = &self.domain_id {
+            i.search(Property::DomainId, value);
+        }
     }
 }
 
@@ -35892,6 +40005,7 @@ impl Pickle for SieveUserScript {
         self.description.pickle(out);
         self.is_active.pickle(out);
         self.contents.pickle(out);
+        self.domain_id.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -35900,6 +40014,9 @@ impl Pickle for SieveUserScript {
         this.description = Pickle::unpickle(stream)?;
         this.is_active = Pickle::unpickle(stream)?;
         this.contents = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.domain_id = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -35911,17 +40028,19 @@ impl Default for SieveUserScript {
             description: Default::default(),
             is_active: false,
             contents: Default::default(),
+            domain_id: Default::default(),
         }
     }
 }
 
 impl IntoValue for SieveUserScript {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(7);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::Description, self.description.into_value());
         map.insert_unchecked(Property::IsActive, self.is_active.into_value());
         map.insert_unchecked(Property::Contents, self.contents.into_value());
+        map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
         JmapValue::Object(map)
     }
 }
@@ -35943,6 +40062,7 @@ impl RegistryJsonPropertyPatch for SieveUserScript {
             Some(Property::Contents) => self
                 .contents
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::DomainId) => self.domain_id.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -37618,7 +41738,7 @@ impl RegistryJsonPropertyPatch for SpamDnsblServerUrl {
 
 impl ObjectImpl for SpamDnsblSettings {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::SpamDnsblSettings;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -37639,6 +41759,10 @@ impl ObjectImpl for SpamDnsblSettings {
         if *value < 1 {
             errors.push(ValidationError::min_value(Property::UrlLimit, 1));
         }
+        let value = &self.redirect_limit;
+        if *value > 10 {
+            errors.push(ValidationError::max_value(Property::RedirectLimit, 10));
+        }
         errors.len() == neb
     }
 
@@ -37651,6 +41775,8 @@ impl Pickle for SpamDnsblSettings {
         self.email_limit.pickle(out);
         self.ip_limit.pickle(out);
         self.url_limit.pickle(out);
+        self.redirect_limit.pickle(out);
+        self.redirect_timeout.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -37659,6 +41785,10 @@ impl Pickle for SpamDnsblSettings {
         this.email_limit = Pickle::unpickle(stream)?;
         this.ip_limit = Pickle::unpickle(stream)?;
         this.url_limit = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.redirect_limit = Pickle::unpickle(stream)?;
+            this.redirect_timeout = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -37670,17 +41800,24 @@ impl Default for SpamDnsblSettings {
             email_limit: 50u64,
             ip_limit: 50u64,
             url_limit: 50u64,
+            redirect_limit: 3u64,
+            redirect_timeout: Duration::from_millis(5000),
         }
     }
 }
 
 impl IntoValue for SpamDnsblSettings {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(6);
+        let mut map = jmap_tools::Map::with_capacity(8);
         map.insert_unchecked(Property::DomainLimit, self.domain_limit.into_value());
         map.insert_unchecked(Property::EmailLimit, self.email_limit.into_value());
         map.insert_unchecked(Property::IpLimit, self.ip_limit.into_value());
         map.insert_unchecked(Property::UrlLimit, self.url_limit.into_value());
+        map.insert_unchecked(Property::RedirectLimit, self.redirect_limit.into_value());
+        map.insert_unchecked(
+            Property::RedirectTimeout,
+            self.redirect_timeout.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -37696,6 +41833,8 @@ impl RegistryJsonPropertyPatch for SpamDnsblSettings {
             Some(Property::EmailLimit) => self.email_limit.patch(pointer, value),
             Some(Property::IpLimit) => self.ip_limit.patch(pointer, value),
             Some(Property::UrlLimit) => self.url_limit.patch(pointer, value),
+            Some(Property::RedirectLimit) => self.redirect_limit.patch(pointer, value),
+            Some(Property::RedirectTimeout) => self.redirect_timeout.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -39191,7 +43330,7 @@ impl RegistryJsonPropertyPatch for SpamRuleUrl {
 
 impl ObjectImpl for SpamSettings {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::SpamSettings;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -39222,6 +43361,13 @@ impl ObjectImpl for SpamSettings {
                 errors.push(ValidationError::required(Property::SpamFilterRulesUrl));
             }
         }
+        let value = &self.score_quarantine;
+        if *value > Float::new(100.0) {
+            errors.push(ValidationError::max_value(Property::ScoreQuarantine, 100));
+        }
+        if *value < Float::new(-100.0) {
+            errors.push(ValidationError::min_value(Property::ScoreQuarantine, -100));
+        }
         errors.len() == neb
     }
 
@@ -39238,6 +43384,9 @@ impl Pickle for SpamSettings {
         self.score_spam.pickle(out);
         self.trust_replies.pickle(out);
         self.spam_filter_rules_url.pickle(out);
+        self.score_quarantine.pickle(out);
+        self.quarantine_for.pickle(out);
+        self.quarantine_digest_frequency.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -39250,6 +43399,11 @@ impl Pickle for SpamSettings {
         this.score_spam = Pickle::unpickle(stream)?;
         this.trust_replies = Pickle::unpickle(stream)?;
         this.spam_filter_rules_url = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.score_quarantine = Pickle::unpickle(stream)?;
+            this.quarantine_for = Pickle::unpickle(stream)?;
+            this.quarantine_digest_frequency = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -39265,13 +43419,16 @@ impl Default for SpamSettings {
             score_spam: Float::new(5.0f64),
             trust_replies: true,
             spam_filter_rules_url: Some("https://github.com/stalwartlabs/spam-filter/releases/latest/download/spam-filter-rules.json.gz".to_string()),
+            score_quarantine: Float::new(0.0f64),
+            quarantine_for: Duration::from_millis(1209600000),
+            quarantine_digest_frequency: Some(Duration::from_millis(86400000)),
         }
     }
 }
 
 impl IntoValue for SpamSettings {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(10);
+        let mut map = jmap_tools::Map::with_capacity(13);
         map.insert_unchecked(Property::TrustContacts, self.trust_contacts.into_value());
         map.insert_unchecked(Property::Enable, self.enable.into_value());
         map.insert_unchecked(Property::GreylistFor, self.greylist_for.into_value());
@@ -39283,6 +43440,15 @@ impl IntoValue for SpamSettings {
             Property::SpamFilterRulesUrl,
             self.spam_filter_rules_url.into_value(),
         );
+        map.insert_unchecked(
+            Property::ScoreQuarantine,
+            self.score_quarantine.into_value(),
+        );
+        map.insert_unchecked(Property::QuarantineFor, self.quarantine_for.into_value());
+        map.insert_unchecked(
+            Property::QuarantineDigestFrequency,
+            self.quarantine_digest_frequency.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -39304,6 +43470,11 @@ impl RegistryJsonPropertyPatch for SpamSettings {
             Some(Property::SpamFilterRulesUrl) => self
                 .spam_filter_rules_url
                 .patch(pointer.with_validators(&[StringValidator::Trim]), value),
+            Some(Property::ScoreQuarantine) => self.score_quarantine.patch(pointer, value),
+            Some(Property::QuarantineFor) => self.quarantine_for.patch(pointer, value),
+            Some(Property::QuarantineDigestFrequency) => {
+                self.quarantine_digest_frequency.patch(pointer, value)
+            }
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -40261,6 +44432,201 @@ impl RegistryJsonPropertyPatch for SqliteStore {
     }
 }
 
+impl StatisticsCounter {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.name;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Name));
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for StatisticsCounter {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.name.pickle(out);
+        self.count.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.name = Pickle::unpickle(stream)?;
+        this.count = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for StatisticsCounter {
+    fn default() -> Self {
+        Self {
+            name: Default::default(),
+            count: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for StatisticsCounter {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::Name, self.name.into_value());
+        map.insert_unchecked(Property::Count, self.count.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for StatisticsCounter {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Name) => self.name.patch(pointer, value),
+            Some(Property::Count) => self.count.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl ObjectImpl for StatisticsRollup {
+    const FLAGS: u64 = 0;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::StatisticsRollup;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.from;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::From, value));
+        }
+        let value = &self.to;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::To, value));
+        }
+        let value = &self.top_senders;
+        for value in value.values() {
+            value.validate(errors);
+        }
+        let value = &self.top_recipients;
+        for value in value.values() {
+            value.validate(errors);
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for StatisticsRollup {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.period.pickle(out);
+        self.from.pickle(out);
+        self.to.pickle(out);
+        self.messages_received.pickle(out);
+        self.messages_submitted.pickle(out);
+        self.ham_messages.pickle(out);
+        self.spam_messages.pickle(out);
+        self.auth_failures.pickle(out);
+        self.top_senders.pickle(out);
+        self.top_recipients.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.period = Pickle::unpickle(stream)?;
+        this.from = Pickle::unpickle(stream)?;
+        this.to = Pickle::unpickle(stream)?;
+        this.messages_received = Pickle::unpickle(stream)?;
+        this.messages_submitted = Pickle::unpickle(stream)?;
+        this.ham_messages = Pickle::unpickle(stream)?;
+        this.spam_messages = Pickle::unpickle(stream)?;
+        this.auth_failures = Pickle::unpickle(stream)?;
+        this.top_senders = Pickle::unpickle(stream)?;
+        this.top_recipients = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for StatisticsRollup {
+    fn default() -> Self {
+        Self {
+            period: Default::default(),
+            from: Default::default(),
+            to: Default::default(),
+            messages_received: Default::default(),
+            messages_submitted: Default::default(),
+            ham_messages: Default::default(),
+            spam_messages: Default::default(),
+            auth_failures: Default::default(),
+            top_senders: Default::default(),
+            top_recipients: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for StatisticsRollup {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(13);
+        map.insert_unchecked(Property::Period, self.period.into_value());
+        map.insert_unchecked(Property::From, self.from.into_value());
+        map.insert_unchecked(Property::To, self.to.into_value());
+        map.insert_unchecked(
+            Property::MessagesReceived,
+            self.messages_received.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MessagesSubmitted,
+            self.messages_submitted.into_value(),
+        );
+        map.insert_unchecked(Property::HamMessages, self.ham_messages.into_value());
+        map.insert_unchecked(Property::SpamMessages, self.spam_messages.into_value());
+        map.insert_unchecked(Property::AuthFailures, self.auth_failures.into_value());
+        map.insert_unchecked(Property::TopSenders, self.top_senders.into_value());
+        map.insert_unchecked(Property::TopRecipients, self.top_recipients.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for StatisticsRollup {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Period) => self.period.patch(pointer, value),
+            Some(Property::From) => self.from.patch(pointer, value),
+            Some(Property::To) => self.to.patch(pointer, value),
+            Some(Property::MessagesReceived) => self.messages_received.patch(pointer, value),
+            Some(Property::MessagesSubmitted) => self.messages_submitted.patch(pointer, value),
+            Some(Property::HamMessages) => self.ham_messages.patch(pointer, value),
+            Some(Property::SpamMessages) => self.spam_messages.patch(pointer, value),
+            Some(Property::AuthFailures) => self.auth_failures.patch(pointer, value),
+            Some(Property::TopSenders) => self.top_senders.patch(pointer, value),
+            Some(Property::TopRecipients) => self.top_recipients.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for StoreLookup {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -40513,7 +44879,7 @@ impl RegistryJsonPropertyPatch for SubAddressingCustom {
 
 impl ObjectImpl for SystemSettings {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::SystemSettings;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -40583,6 +44949,7 @@ impl Pickle for SystemSettings {
         self.mail_exchangers.pickle(out);
         self.services.pickle(out);
         self.provider_info.pickle(out);
+        self.ipv6_only.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -40596,6 +44963,9 @@ impl Pickle for SystemSettings {
         this.mail_exchangers = Pickle::unpickle(stream)?;
         this.services = Pickle::unpickle(stream)?;
         this.provider_info = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.ipv6_only = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -40672,13 +45042,14 @@ impl Default for SystemSettings {
                 ),
             ]),
             provider_info: Default::default(),
+            ipv6_only: false,
         }
     }
 }
 
 impl IntoValue for SystemSettings {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(11);
+        let mut map = jmap_tools::Map::with_capacity(12);
         map.insert_unchecked(
             Property::DefaultHostname,
             self.default_hostname.into_value(),
@@ -40700,6 +45071,7 @@ impl IntoValue for SystemSettings {
         map.insert_unchecked(Property::MailExchangers, self.mail_exchangers.into_value());
         map.insert_unchecked(Property::Services, self.services.into_value());
         map.insert_unchecked(Property::ProviderInfo, self.provider_info.into_value());
+        map.insert_unchecked(Property::Ipv6Only, self.ipv6_only.into_value());
         JmapValue::Object(map)
     }
 }
@@ -40726,6 +45098,7 @@ impl RegistryJsonPropertyPatch for SystemSettings {
             Some(Property::MailExchangers) => self.mail_exchangers.patch(pointer, value),
             Some(Property::Services) => self.services.patch(pointer, value),
             Some(Property::ProviderInfo) => self.provider_info.patch(pointer, value),
+            Some(Property::Ipv6Only) => self.ipv6_only.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -40760,6 +45133,9 @@ impl ObjectImpl for Task {
             Task::AcmeRenewal(inner) => inner.validate(errors),
             Task::DkimManagement(inner) => inner.validate(errors),
             Task::DnsManagement(inner) => inner.validate(errors),
+            Task::AccountExport(inner) => inner.validate(errors),
+            Task::AccountImport(inner) => inner.validate(errors),
+            Task::DataRegionMigration(inner) => inner.validate(errors),
         }
     }
 
@@ -40815,6 +45191,15 @@ impl ObjectImpl for Task {
             Task::DnsManagement(object) => {
                 object.index(i);
             }
+            Task::AccountExport(object) => {
+                object.index(i);
+            }
+            Task::AccountImport(object) => {
+                object.index(i);
+            }
+            Task::DataRegionMigration(object) => {
+                object.index(i);
+            }
         }
     }
 }
@@ -40900,6 +45285,18 @@ impl Pickle for Task {
                 17u16.pickle(out);
                 inner.pickle(out);
             }
+            Task::AccountExport(inner) => {
+                18u16.pickle(out);
+                inner.pickle(out);
+            }
+            Task::AccountImport(inner) => {
+                19u16.pickle(out);
+                inner.pickle(out);
+            }
+            Task::DataRegionMigration(inner) => {
+                20u16.pickle(out);
+                inner.pickle(out);
+            }
         }
     }
 
@@ -40923,6 +45320,9 @@ impl Pickle for Task {
             15 => Pickle::unpickle(stream).map(Task::AcmeRenewal),
             16 => Pickle::unpickle(stream).map(Task::DkimManagement),
             17 => Pickle::unpickle(stream).map(Task::DnsManagement),
+            18 => Pickle::unpickle(stream).map(Task::AccountExport),
+            19 => Pickle::unpickle(stream).map(Task::AccountImport),
+            20 => Pickle::unpickle(stream).map(Task::DataRegionMigration),
             _ => None,
         }
     }
@@ -41059,6 +45459,27 @@ impl IntoValue for Task {
                     .insert_unchecked(Property::Type, JmapValue::Str("DnsManagement".into()));
                 obj
             }
+            Task::AccountExport(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("AccountExport".into()));
+                obj
+            }
+            Task::AccountImport(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("AccountImport".into()));
+                obj
+            }
+            Task::DataRegionMigration(obj) => {
+                let mut obj = obj.into_value();
+                obj.as_object_mut()
+                    .unwrap()
+                    .insert_unchecked(Property::Type, JmapValue::Str("DataRegionMigration".into()));
+                obj
+            }
         }
     }
 }
@@ -41101,6 +45522,11 @@ impl RegistryJsonPatch for Task {
                 TaskType::AcmeRenewal => *self = Task::AcmeRenewal(Default::default()),
                 TaskType::DkimManagement => *self = Task::DkimManagement(Default::default()),
                 TaskType::DnsManagement => *self = Task::DnsManagement(Default::default()),
+                TaskType::AccountExport => *self = Task::AccountExport(Default::default()),
+                TaskType::AccountImport => *self = Task::AccountImport(Default::default()),
+                TaskType::DataRegionMigration => {
+                    *self = Task::DataRegionMigration(Default::default())
+                }
             }
         }
         match self {
@@ -41122,6 +45548,9 @@ impl RegistryJsonPatch for Task {
             Task::AcmeRenewal(inner) => inner.patch(pointer, value),
             Task::DkimManagement(inner) => inner.patch(pointer, value),
             Task::DnsManagement(inner) => inner.patch(pointer, value),
+            Task::AccountExport(inner) => inner.patch(pointer, value),
+            Task::AccountImport(inner) => inner.patch(pointer, value),
+            Task::DataRegionMigration(inner) => inner.patch(pointer, value),
         }
     }
 }
@@ -41147,6 +45576,9 @@ impl Task {
             Task::AcmeRenewal(_) => TaskType::AcmeRenewal,
             Task::DkimManagement(_) => TaskType::DkimManagement,
             Task::DnsManagement(_) => TaskType::DnsManagement,
+            Task::AccountExport(_) => TaskType::AccountExport,
+            Task::AccountImport(_) => TaskType::AccountImport,
+            Task::DataRegionMigration(_) => TaskType::DataRegionMigration,
         }
     }
 }
@@ -41231,6 +45663,85 @@ impl RegistryJsonPropertyPatch for TaskAccountMaintenance {
     }
 }
 
+impl TaskAccountMigration {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.account_id;
+        if !value.is_valid() {
+            errors.push(ValidationError::required(Property::AccountId));
+        }
+        let value = &self.path;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Path));
+        }
+        let value = &self.status;
+        value.validate(errors);
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.foreign_key(ObjectType::Account, self.account_id.into(), None);
+    }
+}
+
+impl Pickle for TaskAccountMigration {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.account_id.pickle(out);
+        self.path.pickle(out);
+        self.status.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.account_id = Pickle::unpickle(stream)?;
+        this.path = Pickle::unpickle(stream)?;
+        this.status = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for TaskAccountMigration {
+    fn default() -> Self {
+        Self {
+            account_id: Default::default(),
+            path: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for TaskAccountMigration {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(5);
+        map.insert_unchecked(Property::AccountId, self.account_id.into_value());
+        map.insert_unchecked(Property::Path, self.path.into_value());
+        map.insert_unchecked(Property::Status, self.status.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for TaskAccountMigration {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::AccountId) => self
+                .account_id
+                .patch(pointer.assert_read_only()?.assert_can_set_account()?, value),
+            Some(Property::Path) => self.path.patch(pointer.assert_read_only()?, value),
+            Some(Property::Status) => self.status.patch(pointer, value),
+            Some(Property::Due) => pointer.assert_server_set(),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl TaskCalendarAlarmEmail {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -41629,6 +46140,87 @@ impl RegistryJsonPropertyPatch for TaskCalendarItipMessage {
     }
 }
 
+impl TaskDataRegionMigration {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.account_id;
+        if !value.is_valid() {
+            errors.push(ValidationError::required(Property::AccountId));
+        }
+        let value = &self.data_region;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::DataRegion));
+        }
+        let value = &self.status;
+        value.validate(errors);
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, i: &mut IndexBuilder<'x>) {
+        i.foreign_key(ObjectType::Account, self.account_id.into(), None);
+    }
+}
+
+impl Pickle for TaskDataRegionMigration {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.account_id.pickle(out);
+        self.data_region.pickle(out);
+        self.status.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.account_id = Pickle::unpickle(stream)?;
+        this.data_region = Pickle::unpickle(stream)?;
+        this.status = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for TaskDataRegionMigration {
+    fn default() -> Self {
+        Self {
+            account_id: Default::default(),
+            data_region: Default::default(),
+            status: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for TaskDataRegionMigration {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(5);
+        map.insert_unchecked(Property::AccountId, self.account_id.into_value());
+        map.insert_unchecked(Property::DataRegion, self.data_region.into_value());
+        map.insert_unchecked(Property::Status, self.status.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for TaskDataRegionMigration {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::AccountId) => self
+                .account_id
+                .patch(pointer.assert_read_only()?.assert_can_set_account()?, value),
+            Some(Property::DataRegion) => {
+                self.data_region.patch(pointer.assert_read_only()?, value)
+            }
+            Some(Property::Status) => self.status.patch(pointer, value),
+            Some(Property::Due) => pointer.assert_server_set(),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl TaskDestroyAccount {
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
         let neb = errors.len();
@@ -42099,9 +46691,88 @@ impl RegistryJsonPropertyPatch for TaskIndexTrace {
     }
 }
 
+impl TaskMaintenanceWindow {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.task_types;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::TaskTypes));
+        }
+        let value = &self.schedule;
+        value.validate(errors);
+        if let Some(value) = &self.rate {
+            value.validate(errors);
+        }
+        errors.len() == neb
+    }
+}
+
+impl Pickle for TaskMaintenanceWindow {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.task_types.pickle(out);
+        self.schedule.pickle(out);
+        self.duration.pickle(out);
+        self.rate.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.task_types = Pickle::unpickle(stream)?;
+        this.schedule = Pickle::unpickle(stream)?;
+        this.duration = Pickle::unpickle(stream)?;
+        this.rate = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for TaskMaintenanceWindow {
+    fn default() -> Self {
+        Self {
+            task_types: Default::default(),
+            schedule: Cron::Daily(CronDaily {
+                hour: 2u64,
+                minute: 0u64,
+            }),
+            duration: Duration::from_millis(14400000),
+            rate: None,
+        }
+    }
+}
+
+impl IntoValue for TaskMaintenanceWindow {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(6);
+        map.insert_unchecked(Property::TaskTypes, self.task_types.into_value());
+        map.insert_unchecked(Property::Schedule, self.schedule.into_value());
+        map.insert_unchecked(Property::Duration, self.duration.into_value());
+        map.insert_unchecked(Property::Rate, self.rate.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for TaskMaintenanceWindow {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::TaskTypes) => self.task_types.patch(pointer, value),
+            Some(Property::Schedule) => self.schedule.patch(pointer, value),
+            Some(Property::Duration) => self.duration.patch(pointer, value),
+            Some(Property::Rate) => self.rate.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for TaskManager {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::TaskManager;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -42112,6 +46783,9 @@ impl ObjectImpl for TaskManager {
         }
         let value = &self.strategy;
         value.validate(errors);
+        for value in self.maintenance_windows.values() {
+            value.validate(errors);
+        }
         errors.len() == neb
     }
 
@@ -42123,6 +46797,8 @@ impl Pickle for TaskManager {
         self.max_attempts.pickle(out);
         self.strategy.pickle(out);
         self.total_deadline.pickle(out);
+        self.maintenance_windows.pickle(out);
+        self.migration_directory.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -42130,6 +46806,12 @@ impl Pickle for TaskManager {
         this.max_attempts = Pickle::unpickle(stream)?;
         this.strategy = Pickle::unpickle(stream)?;
         this.total_deadline = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.maintenance_windows = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 2 {
+            this.migration_directory = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -42140,16 +46822,26 @@ impl Default for TaskManager {
             max_attempts: 3u64,
             strategy: Default::default(),
             total_deadline: Duration::from_millis(21600000),
+            maintenance_windows: Default::default(),
+            migration_directory: Default::default(),
         }
     }
 }
 
 impl IntoValue for TaskManager {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(5);
+        let mut map = jmap_tools::Map::with_capacity(7);
         map.insert_unchecked(Property::MaxAttempts, self.max_attempts.into_value());
         map.insert_unchecked(Property::Strategy, self.strategy.into_value());
         map.insert_unchecked(Property::TotalDeadline, self.total_deadline.into_value());
+        map.insert_unchecked(
+            Property::MaintenanceWindows,
+            self.maintenance_windows.into_value(),
+        );
+        map.insert_unchecked(
+            Property::MigrationDirectory,
+            self.migration_directory.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -42164,6 +46856,8 @@ impl RegistryJsonPropertyPatch for TaskManager {
             Some(Property::MaxAttempts) => self.max_attempts.patch(pointer, value),
             Some(Property::Strategy) => self.strategy.patch(pointer, value),
             Some(Property::TotalDeadline) => self.total_deadline.patch(pointer, value),
+            Some(Property::MaintenanceWindows) => self.maintenance_windows.patch(pointer, value),
+            Some(Property::MigrationDirectory) => self.migration_directory.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -43189,7 +47883,7 @@ impl RegistryJsonPropertyPatch for TaskTlsReport {
 
 impl ObjectImpl for Tenant {
     const FLAGS: u64 = OBJ_SEQ_ID;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 1;
     const OBJECT: ObjectType = ObjectType::Tenant;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -43211,6 +47905,11 @@ impl ObjectImpl for Tenant {
         value.validate(errors);
         let value = &self.permissions;
         value.validate(errors);
+        if let Some(value) = &self.data_region
+            && value.is_empty()
+        {
+            errors.push(ValidationError::required(Property::DataRegion));
+        }
         errors.len() == neb
     }
 
@@ -43228,6 +47927,7 @@ impl Pickle for Tenant {
         self.roles.pickle(out);
         self.permissions.pickle(out);
         self.quotas.pickle(out);
+        self.data_region.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -43238,6 +47938,9 @@ impl Pickle for Tenant {
         this.roles = Pickle::unpickle(stream)?;
         this.permissions = Pickle::unpickle(stream)?;
         this.quotas = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.data_region = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -43251,19 +47954,21 @@ impl Default for Tenant {
             roles: Default::default(),
             permissions: Default::default(),
             quotas: Default::default(),
+            data_region: Default::default(),
         }
     }
 }
 
 impl IntoValue for Tenant {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(8);
+        let mut map = jmap_tools::Map::with_capacity(9);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::CreatedAt, self.created_at.into_value());
         map.insert_unchecked(Property::Logo, self.logo.into_value());
         map.insert_unchecked(Property::Roles, self.roles.into_value());
         map.insert_unchecked(Property::Permissions, self.permissions.into_value());
         map.insert_unchecked(Property::Quotas, self.quotas.into_value());
+        map.insert_unchecked(Property::DataRegion, self.data_region.into_value());
         JmapValue::Object(map)
     }
 }
@@ -43282,6 +47987,184 @@ impl RegistryJsonPropertyPatch for Tenant {
             Some(Property::Permissions) => self.permissions.patch(pointer, value),
             Some(Property::Quotas) => self.quotas.patch(pointer, value),
             Some(Property::UsedDiskQuota) => pointer.assert_server_set(),
+            Some(Property::DataRegion) => self.data_region.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl ObjectImpl for TenantUsage {
+    const FLAGS: u64 = OBJ_FILTER_TENANT;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::TenantUsage;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.from;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::From, value));
+        }
+        let value = &self.to;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::To, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for TenantUsage {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.member_tenant_id.pickle(out);
+        self.from.pickle(out);
+        self.to.pickle(out);
+        self.storage_used.pickle(out);
+        self.messages_sent.pickle(out);
+        self.messages_received.pickle(out);
+        self.bytes_sent.pickle(out);
+        self.bytes_received.pickle(out);
+        self.api_calls.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.member_tenant_id = Pickle::unpickle(stream)?;
+        this.from = Pickle::unpickle(stream)?;
+        this.to = Pickle::unpickle(stream)?;
+        this.storage_used = Pickle::unpickle(stream)?;
+        this.messages_sent = Pickle::unpickle(stream)?;
+        this.messages_received = Pickle::unpickle(stream)?;
+        this.bytes_sent = Pickle::unpickle(stream)?;
+        this.bytes_received = Pickle::unpickle(stream)?;
+        this.api_calls = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for TenantUsage {
+    fn default() -> Self {
+        Self {
+            member_tenant_id: Default::default(),
+            from: Default::default(),
+            to: Default::default(),
+            storage_used: Default::default(),
+            messages_sent: Default::default(),
+            messages_received: Default::default(),
+            bytes_sent: Default::default(),
+            bytes_received: Default::default(),
+            api_calls: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for TenantUsage {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(12);
+        map.insert_unchecked(Property::MemberTenantId, self.member_tenant_id.into_value());
+        map.insert_unchecked(Property::From, self.from.into_value());
+        map.insert_unchecked(Property::To, self.to.into_value());
+        map.insert_unchecked(Property::StorageUsed, self.storage_used.into_value());
+        map.insert_unchecked(Property::MessagesSent, self.messages_sent.into_value());
+        map.insert_unchecked(
+            Property::MessagesReceived,
+            self.messages_received.into_value(),
+        );
+        map.insert_unchecked(Property::BytesSent, self.bytes_sent.into_value());
+        map.insert_unchecked(Property::BytesReceived, self.bytes_received.into_value());
+        map.insert_unchecked(Property::ApiCalls, self.api_calls.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for TenantUsage {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::MemberTenantId) => self.member_tenant_id.patch(pointer, value),
+            Some(Property::From) => self.from.patch(pointer, value),
+            Some(Property::To) => self.to.patch(pointer, value),
+            Some(Property::StorageUsed) => self.storage_used.patch(pointer, value),
+            Some(Property::MessagesSent) => self.messages_sent.patch(pointer, value),
+            Some(Property::MessagesReceived) => self.messages_received.patch(pointer, value),
+            Some(Property::BytesSent) => self.bytes_sent.patch(pointer, value),
+            Some(Property::BytesReceived) => self.bytes_received.patch(pointer, value),
+            Some(Property::ApiCalls) => self.api_calls.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
+impl TieredBlobStore {
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        self.hot_store.validate(errors);
+        self.cold_store.validate(errors);
+        errors.len() == neb
+    }
+}
+
+impl Pickle for TieredBlobStore {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.hot_store.pickle(out);
+        self.cold_store.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.hot_store = Pickle::unpickle(stream)?;
+        this.cold_store = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for TieredBlobStore {
+    fn default() -> Self {
+        Self {
+            hot_store: Default::default(),
+            cold_store: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for TieredBlobStore {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(4);
+        map.insert_unchecked(Property::HotStore, self.hot_store.into_value());
+        map.insert_unchecked(Property::ColdStore, self.cold_store.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for TieredBlobStore {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::HotStore) => self.hot_store.patch(pointer, value),
+            Some(Property::ColdStore) => self.cold_store.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -43550,6 +48433,95 @@ impl RegistryJsonPropertyPatch for TlsFailureDetails {
     }
 }
 
+impl ObjectImpl for TlsInboundReport {
+    const FLAGS: u64 = 0;
+    const VERSION: u8 = 0;
+    const OBJECT: ObjectType = ObjectType::TlsInboundReport;
+
+    fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
+        let neb = errors.len();
+        let value = &self.domain;
+        if value.is_empty() {
+            errors.push(ValidationError::required(Property::Domain));
+        }
+        let value = &self.report;
+        value.validate(errors);
+        let value = &self.created_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::CreatedAt, value));
+        }
+        let value = &self.expires_at;
+        if !value.is_valid() {
+            errors.push(ValidationError::invalid(Property::ExpiresAt, value));
+        }
+        errors.len() == neb
+    }
+
+    fn index<'x>(&'x self, _: &mut IndexBuilder<'x>) {}
+}
+
+impl Pickle for TlsInboundReport {
+    fn pickle(&self, out: &mut Vec<u8>) {
+        self.domain.pickle(out);
+        self.report.pickle(out);
+        self.created_at.pickle(out);
+        self.expires_at.pickle(out);
+    }
+
+    fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
+        let mut this = Self::default();
+        this.domain = Pickle::unpickle(stream)?;
+        this.report = Pickle::unpickle(stream)?;
+        this.created_at = Pickle::unpickle(stream)?;
+        this.expires_at = Pickle::unpickle(stream)?;
+        Some(this)
+    }
+}
+
+impl Default for TlsInboundReport {
+    fn default() -> Self {
+        Self {
+            domain: Default::default(),
+            report: Default::default(),
+            created_at: Default::default(),
+            expires_at: Default::default(),
+        }
+    }
+}
+
+impl IntoValue for TlsInboundReport {
+    fn into_value(self) -> JmapValue<'static> {
+        let mut map = jmap_tools::Map::with_capacity(6);
+        map.insert_unchecked(Property::Domain, self.domain.into_value());
+        map.insert_unchecked(Property::Report, self.report.into_value());
+        map.insert_unchecked(Property::CreatedAt, self.created_at.into_value());
+        map.insert_unchecked(Property::ExpiresAt, self.expires_at.into_value());
+        JmapValue::Object(map)
+    }
+}
+
+impl RegistryJsonPropertyPatch for TlsInboundReport {
+    fn patch_property<'x>(
+        &mut self,
+        mut pointer: JsonPointerPatch<'_>,
+        value: JmapValue<'x>,
+    ) -> PatchResult<'x> {
+        match pointer.next_property() {
+            Some(Property::Domain) => self
+                .domain
+                .patch(pointer.with_validators(&[StringValidator::Domain]), value),
+            Some(Property::Report) => self.report.patch(pointer, value),
+            Some(Property::CreatedAt) => self.created_at.patch(pointer, value),
+            Some(Property::ExpiresAt) => self.expires_at.patch(pointer, value),
+            Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
+                property: Property::Type,
+                value,
+            }),
+            _ => Err(PatchError::new(pointer, "Invalid property")),
+        }
+    }
+}
+
 impl ObjectImpl for TlsInternalReport {
     const FLAGS: u64 = 0;
     const VERSION: u8 = 0;
@@ -45764,7 +50736,7 @@ impl RegistryJsonPropertyPatch for TracerStdout {
 
 impl ObjectImpl for TracingStore {
     const FLAGS: u64 = OBJ_SINGLETON;
-    const VERSION: u8 = 0;
+    const VERSION: u8 = 2;
     const OBJECT: ObjectType = ObjectType::TracingStore;
 
     fn validate(&self, errors: &mut Vec<ValidationError>) -> bool {
@@ -45946,6 +50918,11 @@ impl UserAccount {
         }
         let value = &self.encryption_at_rest;
         value.validate(errors);
+        if let Some(value) = &self.data_region
+            && value.is_empty()
+        {
+            errors.push(ValidationError::required(Property::DataRegion));
+        }
         errors.len() == neb
     }
 
@@ -45996,6 +50973,12 @@ impl Pickle for UserAccount {
         self.locale.pickle(out);
         self.time_zone.pickle(out);
         self.encryption_at_rest.pickle(out);
+        self.data_region.pickle(out);
+        self.undo_send_window.pickle(out);
+        self.allowed_networks.pickle(out);
+        self.allowed_countries.pickle(out);
+        self.allowed_asns.pickle(out);
+        self.auto_archive_after.pickle(out);
     }
 
     fn unpickle(stream: &mut crate::pickle::PickledStream<'_>) -> Option<Self> {
@@ -46014,6 +50997,24 @@ impl Pickle for UserAccount {
         this.locale = Pickle::unpickle(stream)?;
         this.time_zone = Pickle::unpickle(stream)?;
         this.encryption_at_rest = Pickle::unpickle(stream)?;
+        if stream.version() >= 1 {
+            this.data_region = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 3 {
+            this.undo_send_window = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.allowed_networks = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.allowed_countries = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 4 {
+            this.allowed_asns = Pickle::unpickle(stream)?;
+        }
+        if stream.version() >= 5 {
+            this.auto_archive_after = Pickle::unpickle(stream)?;
+        }
         Some(this)
     }
 }
@@ -46035,13 +51036,19 @@ impl Default for UserAccount {
             locale: Locale::EnUS,
             time_zone: Default::default(),
             encryption_at_rest: Default::default(),
+            data_region: Default::default(),
+            undo_send_window: Default::default(),
+            allowed_networks: Default::default(),
+            allowed_countries: Default::default(),
+            allowed_asns: Default::default(),
+            auto_archive_after: Default::default(),
         }
     }
 }
 
 impl IntoValue for UserAccount {
     fn into_value(self) -> JmapValue<'static> {
-        let mut map = jmap_tools::Map::with_capacity(16);
+        let mut map = jmap_tools::Map::with_capacity(22);
         map.insert_unchecked(Property::Name, self.name.into_value());
         map.insert_unchecked(Property::DomainId, self.domain_id.into_value());
         map.insert_unchecked(Property::Credentials, self.credentials.into_value());
@@ -46059,6 +51066,21 @@ impl IntoValue for UserAccount {
             Property::EncryptionAtRest,
             self.encryption_at_rest.into_value(),
         );
+        map.insert_unchecked(Property::DataRegion, self.data_region.into_value());
+        map.insert_unchecked(Property::UndoSendWindow, self.undo_send_window.into_value());
+        map.insert_unchecked(
+            Property::AllowedNetworks,
+            self.allowed_networks.into_value(),
+        );
+        map.insert_unchecked(
+            Property::AllowedCountries,
+            self.allowed_countries.into_value(),
+        );
+        map.insert_unchecked(Property::AllowedAsns, self.allowed_asns.into_value());
+        map.insert_unchecked(
+            Property::AutoArchiveAfter,
+            self.auto_archive_after.into_value(),
+        );
         JmapValue::Object(map)
     }
 }
@@ -46091,6 +51113,15 @@ impl RegistryJsonPropertyPatch for UserAccount {
             Some(Property::Locale) => self.locale.patch(pointer, value),
             Some(Property::TimeZone) => self.time_zone.patch(pointer, value),
             Some(Property::EncryptionAtRest) => self.encryption_at_rest.patch(pointer, value),
+            Some(Property::DataRegion) => self.data_region.patch(pointer, value),
+            Some(Property::UndoSendWindow) => self.undo_send_window.patch(pointer, value),
+            Some(Property::AllowedNetworks) => self.allowed_networks.patch(pointer, value),
+            Some(Property::AllowedCountries) => self.allowed_countries.patch(
+                pointer.with_validators(&[StringValidator::Trim, StringValidator::Uppercase]),
+                value,
+            ),
+            Some(Property::AllowedAsns) => self.allowed_asns.patch(pointer, value),
+            Some(Property::AutoArchiveAfter) => self.auto_archive_after.patch(pointer, value),
             Some(Property::Type) => Ok(MaybeUnpatched::Unpatched {
                 property: Property::Type,
                 value,
@@ -26,6 +26,27 @@ impl Account {
             None
         }
     }
+
+    pub fn domain_id(&self) -> Id {
+        match self {
+            Account::User(user) => user.domain_id,
+            Account::Group(group) => group.domain_id,
+        }
+    }
+
+    pub fn data_region(&self) -> Option<&str> {
+        match self {
+            Account::User(user) => user.data_region.as_deref(),
+            Account::Group(group) => group.data_region.as_deref(),
+        }
+    }
+
+    pub fn set_data_region(&mut self, data_region: Option<String>) {
+        match self {
+            Account::User(user) => user.data_region = data_region,
+            Account::Group(group) => group.data_region = data_region,
+        }
+    }
 }
 
 impl UserAccount {
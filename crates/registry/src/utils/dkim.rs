@@ -6,7 +6,7 @@
 
 use crate::schema::{
     enums::{DkimRotationStage, DkimSignatureType},
-    prelude::{DkimSignature, UTCDateTime},
+    prelude::{Dkim1Signature, DkimSignature, UTCDateTime},
 };
 use types::id::Id;
 
@@ -75,6 +75,26 @@ impl DkimSignature {
             DkimSignature::Dkim1RsaSha256(sign) => sign.domain_id,
         }
     }
+
+    // Returns the signing settings without the key material, used to carry
+    // them over to the signature that replaces this one on rotation
+    pub fn signing_policy(&self) -> Dkim1Signature {
+        let sign = match self {
+            DkimSignature::Dkim1Ed25519Sha256(sign) => sign,
+            DkimSignature::Dkim1RsaSha256(sign) => sign,
+        };
+        Dkim1Signature {
+            auid: sign.auid.clone(),
+            canonicalization: sign.canonicalization,
+            expire: sign.expire,
+            headers: sign.headers.clone(),
+            oversign_headers: sign.oversign_headers.clone(),
+            report: sign.report,
+            third_party: sign.third_party.clone(),
+            third_party_hash: sign.third_party_hash,
+            ..Default::default()
+        }
+    }
 }
 
 impl DkimSignatureType {
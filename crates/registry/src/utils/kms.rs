@@ -0,0 +1,88 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::schema::prelude::EncryptionKeyProvider;
+
+impl EncryptionKeyProvider {
+    pub fn is_enabled(&self) -> bool {
+        !matches!(self, EncryptionKeyProvider::Disabled)
+    }
+
+    // Key identifiers may contain the {account}, {accountId} and {tenantId}
+    // placeholders, so a single provider serves per-account or per-tenant keys.
+    pub fn for_account(
+        &self,
+        account_id: u32,
+        account_name: &str,
+        tenant_id: Option<u32>,
+    ) -> EncryptionKeyProvider {
+        let tenant_id = tenant_id.map_or_else(|| "default".to_string(), |id| id.to_string());
+        let expand = |value: &str| {
+            value
+                .replace("{accountId}", &account_id.to_string())
+                .replace("{account}", account_name)
+                .replace("{tenantId}", &tenant_id)
+        };
+
+        match self {
+            EncryptionKeyProvider::Disabled => EncryptionKeyProvider::Disabled,
+            EncryptionKeyProvider::Vault(vault) => {
+                let mut vault = vault.clone();
+                vault.path = expand(&vault.path);
+                vault.key = expand(&vault.key);
+                EncryptionKeyProvider::Vault(vault)
+            }
+        }
+    }
+
+    // Identifies the key after placeholder expansion, accounts sharing
+    // a tenant key obtain the same reference.
+    pub fn key_reference(&self) -> String {
+        match self {
+            EncryptionKeyProvider::Disabled => String::new(),
+            EncryptionKeyProvider::Vault(vault) => {
+                format!("vault:{}:{}:{}", vault.url, vault.path, vault.key)
+            }
+        }
+    }
+
+    // Returns the PEM encoded certificate or OpenPGP public key
+    pub async fn public_key(&self) -> Result<Option<String>, String> {
+        match self {
+            EncryptionKeyProvider::Disabled => Ok(None),
+            EncryptionKeyProvider::Vault(vault) => vault.fetch().await.map(Some),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::schema::prelude::{EncryptionKeyProvider, SecretKeyVault};
+
+    #[test]
+    fn key_placeholders() {
+        let provider = EncryptionKeyProvider::Vault(SecretKeyVault {
+            url: "https://vault.example.org".to_string(),
+            path: "secret/data/mail/{tenantId}".to_string(),
+            key: "{accountId}-{account}".to_string(),
+            ..Default::default()
+        });
+
+        assert_eq!(
+            provider
+                .for_account(7, "jdoe@example.org", None)
+                .key_reference(),
+            "vault:https://vault.example.org:secret/data/mail/default:7-jdoe@example.org"
+        );
+        assert_eq!(
+            provider
+                .for_account(7, "jdoe@example.org", Some(3))
+                .key_reference(),
+            "vault:https://vault.example.org:secret/data/mail/3:7-jdoe@example.org"
+        );
+        assert!(!EncryptionKeyProvider::Disabled.is_enabled());
+    }
+}
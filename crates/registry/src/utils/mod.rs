@@ -12,6 +12,7 @@ pub mod archived_item;
 pub mod cron;
 pub mod dkim;
 pub mod http;
+pub mod kms;
 pub mod report;
 pub mod secret;
 pub mod task;
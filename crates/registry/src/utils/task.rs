@@ -29,6 +29,9 @@ impl Task {
             Task::AcmeRenewal(task) => task.status = status,
             Task::DkimManagement(task) => task.status = status,
             Task::DnsManagement(task) => task.status = status,
+            Task::AccountExport(task) => task.status = status,
+            Task::AccountImport(task) => task.status = status,
+            Task::DataRegionMigration(task) => task.status = status,
             Task::TenantMaintenance(task) => task.status = status,
         }
     }
@@ -52,6 +55,9 @@ impl Task {
             Task::AcmeRenewal(task) => &task.status,
             Task::DkimManagement(task) => &task.status,
             Task::DnsManagement(task) => &task.status,
+            Task::AccountExport(task) => &task.status,
+            Task::AccountImport(task) => &task.status,
+            Task::DataRegionMigration(task) => &task.status,
             Task::TenantMaintenance(task) => &task.status,
         }
     }
@@ -91,6 +97,9 @@ impl Task {
             Task::AcmeRenewal(_) => Permission::TaskAcmeRenewal,
             Task::DkimManagement(_) => Permission::TaskDkimManagement,
             Task::DnsManagement(_) => Permission::TaskDnsManagement,
+            Task::AccountExport(_) => Permission::TaskAccountExport,
+            Task::AccountImport(_) => Permission::TaskAccountImport,
+            Task::DataRegionMigration(_) => Permission::TaskDataRegionMigration,
             Task::TenantMaintenance(_) => Permission::TaskTenantMaintenance,
         }
     }
@@ -8,21 +8,30 @@ use super::{Event, ece::ece_encrypt};
 use crate::state_manager::PushRegistration;
 use base64::Engine;
 use calcard::jscalendar::JSCalendarDateTime;
-use common::ipc::PushNotification;
+use common::{config::mailstore::jmap::VapidKey, ipc::PushNotification};
 use email::push::PushSubscription;
 use jmap_proto::{
     response::status::{EmailPushObject, PushObject},
     types::state::State,
 };
-use reqwest::header::{CONTENT_ENCODING, CONTENT_TYPE};
-use std::time::{Duration, Instant};
+use reqwest::header::{AUTHORIZATION, CONTENT_ENCODING, CONTENT_TYPE};
+use std::{
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use tokio::sync::mpsc;
 use trc::PushSubscriptionEvent;
 use types::{id::Id, type_state::DataType};
 use utils::map::vec_map::VecMap;
 
 impl PushRegistration {
-    pub fn send(&mut self, id: Id, push_tx: mpsc::Sender<Event>, push_timeout: Duration) {
+    pub fn send(
+        &mut self,
+        id: Id,
+        push_tx: mpsc::Sender<Event>,
+        push_timeout: Duration,
+        push_vapid: Option<Arc<VapidKey>>,
+    ) {
         let server = self.server.clone();
         let notifications = std::mem::take(&mut self.notifications);
 
@@ -82,6 +91,7 @@ impl PushRegistration {
                         &server,
                         serde_json::to_string(&response).unwrap(),
                         push_timeout,
+                        push_vapid.as_deref(),
                     )
                     .await
                     {
@@ -98,8 +108,9 @@ impl PushRegistration {
 
 pub(crate) async fn http_request(
     details: &PushSubscription,
-    mut body: String,
+    body: String,
     push_timeout: Duration,
+    push_vapid: Option<&VapidKey>,
 ) -> bool {
     let client_builder = reqwest::Client::builder().timeout(push_timeout);
 
@@ -110,16 +121,42 @@ pub(crate) async fn http_request(
         .build()
         .unwrap_or_default()
         .post(details.url.as_str())
-        .header(CONTENT_TYPE, "application/json")
         .header("TTL", "86400");
 
-    if let Some(keys) = &details.keys {
-        match ece_encrypt(&keys.p256dh, &keys.auth, body.as_bytes())
-            .map(|b| base64::engine::general_purpose::URL_SAFE.encode(b))
-        {
-            Ok(body_) => {
-                body = body_;
-                client = client.header(CONTENT_ENCODING, "aes128gcm");
+    // Identify the application server to Web Push services (RFC 8292)
+    if let Some(vapid) = push_vapid {
+        match vapid.authorization(&details.url) {
+            Ok(authorization) => {
+                client = client.header(AUTHORIZATION, authorization);
+            }
+            Err(err) => {
+                trc::event!(
+                    PushSubscription(PushSubscriptionEvent::Error),
+                    Details = "Failed to sign VAPID token",
+                    Url = details.url.to_string(),
+                    Reason = err
+                );
+            }
+        }
+    }
+
+    // Encrypt the payload, Web Push services (RFC 8291) expect the raw ciphertext
+    // while the legacy format sends it base64 encoded
+    let body = if let Some(keys) = &details.keys {
+        match ece_encrypt(&keys.p256dh, &keys.auth, body.as_bytes()) {
+            Ok(body) if push_vapid.is_some() => {
+                client = client
+                    .header(CONTENT_TYPE, "application/octet-stream")
+                    .header(CONTENT_ENCODING, "aes128gcm");
+                body
+            }
+            Ok(body) => {
+                client = client
+                    .header(CONTENT_TYPE, "application/json")
+                    .header(CONTENT_ENCODING, "aes128gcm");
+                base64::engine::general_purpose::URL_SAFE
+                    .encode(body)
+                    .into_bytes()
             }
             Err(err) => {
                 // Do not reattempt if encryption fails.
@@ -133,7 +170,10 @@ pub(crate) async fn http_request(
                 return true;
             }
         }
-    }
+    } else {
+        client = client.header(CONTENT_TYPE, "application/json");
+        body.into_bytes()
+    };
 
     match client.body(body).send().await {
         Ok(response) => {
@@ -135,6 +135,7 @@ pub fn spawn_push_manager(inner: Arc<Inner>) -> mpsc::Sender<Event> {
             let push_timeout = server.core.jmap.push_timeout;
             let push_verify_timeout = server.core.jmap.push_verify_timeout;
             let push_throttle = server.core.jmap.push_throttle;
+            let push_vapid = server.core.jmap.push_vapid.clone();
 
             match event_or_timeout {
                 Ok(Some(event)) => match event {
@@ -212,6 +213,7 @@ pub fn spawn_push_manager(inner: Arc<Inner>) -> mpsc::Sender<Event> {
                                     })
                                     .unwrap_or(true)
                                 {
+                                    let push_vapid = push_vapid.clone();
                                     tokio::spawn(async move {
                                         http_request(
                                             &subscription,
@@ -225,6 +227,7 @@ pub fn spawn_push_manager(inner: Arc<Inner>) -> mpsc::Sender<Event> {
                                                 subscription.verification_code
                                             ),
                                             push_timeout,
+                                            push_vapid.as_deref(),
                                         )
                                         .await;
                                     });
@@ -344,6 +347,7 @@ pub fn spawn_push_manager(inner: Arc<Inner>) -> mpsc::Sender<Event> {
                                                     *id,
                                                     push_tx.clone(),
                                                     push_timeout,
+                                                    push_vapid.clone(),
                                                 );
                                                 retry_ids.remove(id);
                                             } else {
@@ -430,7 +434,12 @@ pub fn spawn_push_manager(inner: Arc<Inner>) -> mpsc::Sender<Event> {
                                         && last_request >= push_attempt_interval))
                             {
                                 if subscription.num_attempts < push_attempts_max {
-                                    subscription.send(*retry_id, push_tx.clone(), push_timeout);
+                                    subscription.send(
+                                        *retry_id,
+                                        push_tx.clone(),
+                                        push_timeout,
+                                        push_vapid.clone(),
+                                    );
                                 } else {
                                     trc::event!(
                                         PushSubscription(PushSubscriptionEvent::Error),
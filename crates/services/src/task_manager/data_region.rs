@@ -0,0 +1,168 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::task_manager::{TaskResult, progress::TaskMonitor};
+use common::{Server, cache::invalidate::CacheInvalidationBuilder, ipc::CacheInvalidation};
+use registry::{
+    schema::{
+        prelude::{Object, ObjectInner, ObjectType},
+        structs::TaskDataRegionMigration,
+    },
+    types::id::ObjectId,
+};
+use std::time::Instant;
+use store::{
+    ProgressTracker,
+    registry::write::{RegistryWrite, RegistryWriteResult},
+};
+use trc::AddContext;
+
+pub(crate) trait DataRegionMigrationTask: Sync + Send {
+    fn data_region_migration(
+        &self,
+        id: u64,
+        task: &TaskDataRegionMigration,
+    ) -> impl Future<Output = TaskResult> + Send;
+}
+
+impl DataRegionMigrationTask for Server {
+    async fn data_region_migration(&self, id: u64, task: &TaskDataRegionMigration) -> TaskResult {
+        let account_id = task.account_id.document_id();
+        let mut monitor = TaskMonitor::new(self, id);
+        match migrate_data_region(self, account_id, &task.data_region, &mut monitor).await {
+            Ok(result) => result,
+            Err(err) => {
+                let result = if err.is_assertion_failure() {
+                    TaskResult::temporary("Account was modified during migration, will retry.")
+                } else {
+                    TaskResult::temporary(err.to_string())
+                };
+                trc::error!(
+                    err.account_id(account_id)
+                        .details("Failed to migrate account data region")
+                );
+                result
+            }
+        }
+    }
+}
+
+async fn migrate_data_region(
+    server: &Server,
+    account_id: u32,
+    region: &str,
+    monitor: &mut TaskMonitor,
+) -> trc::Result<TaskResult> {
+    let op_start = Instant::now();
+    let blob_store = &server.core.storage.blob;
+    let Some(target_store) = blob_store.in_region(region) else {
+        return Ok(TaskResult::permanent(format!(
+            "Data region {region:?} is not defined in the blob store."
+        )));
+    };
+    let Some(object) = server
+        .registry()
+        .get(ObjectId::new(ObjectType::Account, account_id.into()))
+        .await
+        .caused_by(trc::location!())?
+    else {
+        return Ok(TaskResult::permanent("Account not found."));
+    };
+    let ObjectInner::Account(account) = &object.inner else {
+        return Ok(TaskResult::permanent("Account not found."));
+    };
+    let source_region = account
+        .data_region()
+        .or_else(|| blob_store.default_region())
+        .map(|region| region.to_string());
+
+    // Pin the account first so new blobs are written to the target region
+    if account.data_region() != Some(region) {
+        let mut new_account = account.clone();
+        new_account.set_data_region(Some(region.to_string()));
+
+        match server
+            .registry()
+            .write(RegistryWrite::update(
+                account_id.into(),
+                &Object::new(ObjectInner::Account(new_account)),
+                &object,
+            ))
+            .await?
+        {
+            RegistryWriteResult::Success(_) => {
+                server
+                    .invalidate_caches(
+                        CacheInvalidationBuilder::default()
+                            .with_invalidation(CacheInvalidation::Account(account_id)),
+                    )
+                    .await
+                    .caused_by(trc::location!())?;
+            }
+            err => {
+                return Ok(TaskResult::permanent(format!(
+                    "Failed to update account data region: {err}"
+                )));
+            }
+        }
+    }
+
+    // Copy the account's blobs, reads fall back to every region. A cancelled
+    // migration leaves the account pinned to the target region.
+    let blobs = server
+        .store()
+        .account_blobs(account_id)
+        .await
+        .caused_by(trc::location!())?;
+    let num_blobs = (blobs.exclusive.len() + blobs.shared.len()) as u64;
+    let mut total = 0;
+    for (processed, hash) in blobs
+        .exclusive
+        .iter()
+        .chain(blobs.shared.iter())
+        .enumerate()
+    {
+        if !monitor.update(processed as u64, num_blobs).await {
+            return Ok(TaskResult::Ignored);
+        }
+
+        if let Some(data) = blob_store
+            .get_blob(hash.as_slice(), 0..usize::MAX)
+            .await
+            .caused_by(trc::location!())?
+        {
+            target_store
+                .put_blob(hash.as_slice(), &data, server.core.email.compression)
+                .await
+                .caused_by(trc::location!())?;
+            total += 1;
+        }
+    }
+
+    // Remove copies left behind, blobs shared with other accounts stay in place
+    if let Some(source_store) = source_region
+        .as_deref()
+        .filter(|source_region| *source_region != region)
+        .and_then(|source_region| blob_store.in_region(source_region))
+    {
+        for hash in &blobs.exclusive {
+            source_store
+                .delete_blob(hash.as_slice())
+                .await
+                .caused_by(trc::location!())?;
+        }
+    }
+
+    trc::event!(
+        Store(trc::StoreEvent::DataRegionMigrated),
+        AccountId = account_id,
+        Id = region.to_string(),
+        Total = total,
+        Elapsed = op_start.elapsed(),
+    );
+
+    Ok(TaskResult::Success(vec![]))
+}
@@ -5,16 +5,23 @@
  */
 
 use crate::task_manager::TaskResult;
-use common::Server;
-use email::{message::metadata::MessageMetadata, sieve::SieveScript};
+use common::{
+    Server, auth::login_history::LoginHistoryIndex, cache::invalidate::CacheInvalidationBuilder,
+    ipc::CacheInvalidation, storage::audit::MessageAuditIndex,
+};
+use email::{
+    message::{metadata::MessageMetadata, quarantine::QuarantinedMessageIndex},
+    sieve::SieveScript,
+};
 use groupware::file::FileNode;
 use registry::{
     schema::{
-        prelude::{ObjectType, Property},
+        prelude::{Object, ObjectInner, ObjectType, Property},
         structs::{ArchivedItem, TaskDestroyAccount},
     },
     types::EnumImpl,
 };
+use std::time::Instant;
 use store::{
     SerializeInfallible, ValueKey,
     registry::RegistryQuery,
@@ -50,9 +57,57 @@ impl DestroyAccountTask for Server {
     }
 }
 
+// Removes all data held for an account while keeping the account itself,
+// used to honour erasure requests from data subjects.
+pub trait AccountPurge: Sync + Send {
+    fn purge_account(
+        &self,
+        account_id: u32,
+    ) -> impl Future<Output = trc::Result<AccountPurgeSummary>> + Send;
+}
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AccountPurgeSummary {
+    pub blobs: usize,
+    pub registry_items: usize,
+}
+
+impl AccountPurge for Server {
+    async fn purge_account(&self, account_id: u32) -> trc::Result<AccountPurgeSummary> {
+        let op_start = Instant::now();
+        let registry_items = destroy_account_registry_items(self, account_id).await?;
+        let blobs = destroy_account_data(self, account_id).await?;
+
+        // Cached mailboxes and DAV resources no longer match the store
+        self.inner.cache.messages.remove(&account_id);
+        self.invalidate_caches(
+            CacheInvalidationBuilder::default()
+                .with_invalidation(CacheInvalidation::DavResources(account_id))
+                .with_invalidation(CacheInvalidation::AccessToken(account_id)),
+        )
+        .await?;
+
+        trc::event!(
+            Store(trc::StoreEvent::AccountPurged),
+            AccountId = account_id,
+            Total = blobs,
+            Elapsed = op_start.elapsed(),
+        );
+
+        Ok(AccountPurgeSummary {
+            blobs,
+            registry_items,
+        })
+    }
+}
+
 async fn destroy_account(server: &Server, task: &TaskDestroyAccount) -> trc::Result<TaskResult> {
-    let account_id = task.account_id.document_id();
+    destroy_account_data(server, task.account_id.document_id()).await?;
 
+    Ok(TaskResult::Success(vec![]))
+}
+
+async fn destroy_account_data(server: &Server, account_id: u32) -> trc::Result<usize> {
     // Destroy public keys and masked emails
     for object in [ObjectType::PublicKey, ObjectType::MaskedEmail] {
         let mut batch = BatchBuilder::new();
@@ -147,7 +202,7 @@ async fn destroy_account(server: &Server, task: &TaskDestroyAccount) -> trc::Res
     }
 
     // Unlink all accounts's blobs
-    destroy_account_blobs(server, account_id).await?;
+    let blobs = destroy_account_blobs(server, account_id).await?;
 
     // Destroy account data
     server
@@ -156,10 +211,97 @@ async fn destroy_account(server: &Server, task: &TaskDestroyAccount) -> trc::Res
         .await
         .caused_by(trc::location!())?;
 
-    Ok(TaskResult::Success(vec![]))
+    Ok(blobs)
+}
+
+// Training samples, quarantined messages and activity logs are kept
+// in the registry and are not removed along with the account's data.
+async fn destroy_account_registry_items(server: &Server, account_id: u32) -> trc::Result<usize> {
+    let mut batch = BatchBuilder::new();
+    let mut total = 0;
+
+    for object in [
+        ObjectType::SpamTrainingSample,
+        ObjectType::QuarantinedMessage,
+        ObjectType::LoginEntry,
+        ObjectType::MessageAuditEntry,
+    ] {
+        let ids = server
+            .registry()
+            .query::<Vec<Id>>(RegistryQuery::new(object).with_account(account_id))
+            .await?;
+        let object_id = object.to_id();
+
+        for id in ids {
+            let item_id = id.id();
+            let Some(item) = server
+                .store()
+                .get_value::<Object>(ValueKey::from(ValueClass::Registry(RegistryClass::Item {
+                    object_id,
+                    item_id,
+                })))
+                .await?
+            else {
+                continue;
+            };
+
+            match &item.inner {
+                ObjectInner::SpamTrainingSample(sample) => {
+                    batch
+                        .with_account_id(account_id)
+                        .clear(BlobOp::Link {
+                            hash: sample.blob_id.hash.clone(),
+                            to: BlobLink::Temporary {
+                                until: sample.expires_at.timestamp() as u64,
+                            },
+                        })
+                        .clear(ValueClass::Registry(RegistryClass::Item {
+                            object_id,
+                            item_id,
+                        }))
+                        .clear(ValueClass::Registry(RegistryClass::Index {
+                            index_id: Property::AccountId.to_id(),
+                            object_id,
+                            item_id,
+                            key: (account_id as u64).serialize(),
+                        }));
+                }
+                ObjectInner::QuarantinedMessage(message) => {
+                    message.write_ops(&mut batch, item_id, false);
+                }
+                ObjectInner::LoginEntry(entry) => {
+                    entry.write_ops(&mut batch, item_id, false);
+                }
+                ObjectInner::MessageAuditEntry(entry) => {
+                    entry.write_ops(&mut batch, item_id, false);
+                }
+                _ => continue,
+            }
+            total += 1;
+
+            if batch.is_large_batch() {
+                server
+                    .store()
+                    .write(batch.build_all())
+                    .await
+                    .caused_by(trc::location!())?;
+                batch = BatchBuilder::new();
+            }
+        }
+    }
+
+    if !batch.is_empty() {
+        server
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())?;
+    }
+
+    Ok(total)
 }
 
-pub async fn destroy_account_blobs(server: &Server, account_id: u32) -> trc::Result<()> {
+pub async fn destroy_account_blobs(server: &Server, account_id: u32) -> trc::Result<usize> {
     let mut delete_keys = Vec::new();
     for (collection, field) in [
         (Collection::Email, u8::from(EmailField::Metadata)),
@@ -203,6 +345,7 @@ pub async fn destroy_account_blobs(server: &Server, account_id: u32) -> trc::Res
     }
 
     let mut batch = BatchBuilder::new();
+    let total = delete_keys.len();
     batch.with_account_id(account_id);
 
     for (collection, document_id, hash) in delete_keys {
@@ -232,5 +375,5 @@ pub async fn destroy_account_blobs(server: &Server, account_id: u32) -> trc::Res
             .caused_by(trc::location!())?;
     }
 
-    Ok(())
+    Ok(total)
 }
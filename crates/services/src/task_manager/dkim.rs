@@ -105,6 +105,8 @@ async fn dkim_management(server: &Server, task: &TaskDomainManagement) -> trc::R
         )
         .await?;
 
+    // Signing settings of the active signatures are kept on rotation
+    let mut policies = Vec::new();
     for id in signature_ids {
         let id = ObjectId::new(ObjectType::DkimSignature, id);
         let Some(key) = server.registry().get(id).await? else {
@@ -117,6 +119,9 @@ async fn dkim_management(server: &Server, task: &TaskDomainManagement) -> trc::R
         };
 
         let key_algo = key.object.object_type();
+        if key.object.is_active() && policies.iter().all(|(algo, _)| algo != &key_algo) {
+            policies.push((key_algo, key.object.signing_policy()));
+        }
         if let Some(current_stage) = key.object.rotation_due() {
             match current_stage {
                 DkimRotationStage::Pending => {
@@ -181,7 +186,11 @@ async fn dkim_management(server: &Server, task: &TaskDomainManagement) -> trc::R
             member_tenant_id: domain.member_tenant_id,
             selector: selector.clone(),
             private_key: SecretText::Text(SecretTextValue { secret }),
-            ..Default::default()
+            ..policies
+                .iter()
+                .find(|(algo, _)| algo == &algorithm)
+                .map(|(_, policy)| policy.clone())
+                .unwrap_or_default()
         };
         let mut signature = match algorithm {
             DkimSignatureType::Dkim1Ed25519Sha256 => DkimSignature::Dkim1Ed25519Sha256(signature),
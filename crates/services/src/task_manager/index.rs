@@ -4,34 +4,36 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::task_manager::{Task, TaskDetails, TaskResult};
+use crate::task_manager::{Task, TaskDetails, TaskResult, progress::TaskMonitor};
 use common::Server;
 use email::{cache::MessageCacheFetch, message::metadata::MessageMetadata};
 use groupware::{cache::GroupwareCache, calendar::CalendarEvent, contact::ContactCard};
 use registry::{
     schema::{
-        enums::IndexDocumentType,
+        enums::{IndexDocumentType, TaskAccountMaintenanceType},
         prelude::{ObjectType, Property},
-        structs::{TaskIndexDocument, TaskIndexTrace, TaskStatus},
+        structs::{TaskAccountMaintenance, TaskIndexTrace, TaskStatus},
     },
     types::EnumImpl,
 };
-use std::cmp::Ordering;
+use std::{cmp::Ordering, time::Duration};
 use store::{
-    IterateParams, ValueKey,
+    Deserialize, IterateParams, ProgressTracker, SerializeInfallible, U32_LEN, U64_LEN, ValueKey,
     ahash::AHashMap,
     rand::{self, Rng},
+    roaring::RoaringBitmap,
     search::{IndexDocument, SearchField, SearchFilter, SearchQuery},
     write::{
         AlignedBytes, Archive, BatchBuilder, SearchIndex, TelemetryClass, ValueClass,
-        key::DeserializeBigEndian, now,
+        key::{DeserializeBigEndian, KeySerializer},
+        now,
     },
 };
 use trc::{AddContext, TaskManagerEvent};
 use types::{
     blob_hash::BlobHash,
     collection::{Collection, SyncCollection},
-    field::EmailField,
+    field::{EmailField, PrincipalField},
 };
 
 pub(crate) trait SearchIndexTask: Sync + Send {
@@ -327,35 +329,196 @@ pub(crate) async fn reindex_telemetry(server: &Server) -> trc::Result<()> {
     Ok(())
 }
 
-pub(crate) async fn reindex_account(server: &Server, account_id: u32) -> trc::Result<()> {
-    let now = now() as i64;
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct ReindexProgress {
+    pub total: u32,
+    pub indexed: u32,
+    pub phase: u8,
+    pub next_document_id: u32,
+    pub started_at: u64,
+    pub updated_at: u64,
+    pub completed_at: Option<u64>,
+}
 
-    let mut batch = BatchBuilder::new();
+const REINDEX_PHASES: [IndexDocumentType; 3] = [
+    IndexDocumentType::Email,
+    IndexDocumentType::Calendar,
+    IndexDocumentType::Contacts,
+];
 
-    for document_id in server
-        .get_cached_messages(account_id)
-        .await
-        .caused_by(trc::location!())?
-        .emails
-        .items
-        .iter()
-        .map(|v| v.document_id)
-    {
-        batch.schedule_task(Task::IndexDocument(TaskIndexDocument {
-            account_id: account_id.into(),
-            document_id: document_id.into(),
-            document_type: IndexDocumentType::Email,
-            status: TaskStatus::at(now + rand::rng().random_range(0..=300)),
-        }));
+pub(crate) async fn reindex_account(
+    server: &Server,
+    id: u64,
+    task: &TaskAccountMaintenance,
+) -> trc::Result<TaskResult> {
+    let account_id = task.account_id.document_id();
+    let now = now();
+
+    // Resume an interrupted reindex or start a new one
+    let mut progress = match reindex_progress(server, account_id).await? {
+        Some(progress) if progress.completed_at.is_none() => progress,
+        _ => {
+            let mut total = 0;
+            for document_type in REINDEX_PHASES {
+                total += reindex_document_ids(server, account_id, document_type)
+                    .await?
+                    .len() as u32;
+            }
+            ReindexProgress {
+                total,
+                started_at: now,
+                ..Default::default()
+            }
+        }
+    };
 
-        if batch.is_large_batch() {
-            server.core.storage.data.write(batch.build_all()).await?;
-            batch = BatchBuilder::new();
+    // Index the next chunk of documents
+    let batch_size = server.core.email.reindex_batch_size;
+    let mut documents = Vec::with_capacity(batch_size);
+    let mut processed = 0;
+    let mut monitor = TaskMonitor::new(server, id);
+    let mut is_cancelled = false;
+    while let Some(document_type) = REINDEX_PHASES.get(progress.phase as usize).copied() {
+        let document_ids = reindex_document_ids(server, account_id, document_type).await?;
+        let next_document_id = progress.next_document_id;
+        let mut has_more = false;
+
+        for document_id in document_ids
+            .iter()
+            .filter(|document_id| *document_id >= next_document_id)
+        {
+            if processed == batch_size {
+                has_more = true;
+                break;
+            }
+            if !monitor
+                .update(
+                    (progress.indexed as usize + processed) as u64,
+                    progress.total as u64,
+                )
+                .await
+            {
+                is_cancelled = true;
+                has_more = true;
+                break;
+            }
+
+            let document = match document_type {
+                IndexDocumentType::Email => {
+                    build_email_document(server, account_id, document_id).await
+                }
+                IndexDocumentType::Calendar => {
+                    build_calendar_document(server, account_id, document_id).await
+                }
+                _ => build_contact_document(server, account_id, document_id).await,
+            }
+            .caused_by(trc::location!())?;
+            if let Some(document) = document.filter(|document| !document.is_empty()) {
+                documents.push(document);
+            }
+            progress.next_document_id = document_id + 1;
+            processed += 1;
         }
+
+        if has_more {
+            break;
+        }
+        progress.phase += 1;
+        progress.next_document_id = 0;
+    }
+
+    if !documents.is_empty() {
+        server
+            .search_store()
+            .index(documents)
+            .await
+            .caused_by(trc::location!())?;
+    }
+
+    // Documents added during the reindex are included in the count
+    progress.indexed += processed as u32;
+    progress.total = progress.total.max(progress.indexed);
+    progress.updated_at = now;
+    let is_done = progress.phase as usize >= REINDEX_PHASES.len();
+    if is_done {
+        progress.completed_at = Some(now);
     }
 
-    for document_type in [IndexDocumentType::Calendar, IndexDocumentType::Contacts] {
-        let cache = server
+    let mut batch = BatchBuilder::new();
+    batch
+        .with_account_id(account_id)
+        .with_collection(Collection::Principal)
+        .with_document(0)
+        .set(PrincipalField::ReindexProgress, progress.serialize());
+    server
+        .store()
+        .write(batch.build_all())
+        .await
+        .caused_by(trc::location!())?;
+
+    if is_cancelled {
+        // The indexed chunk is kept, a new reindex resumes from this point
+        Ok(TaskResult::Ignored)
+    } else if is_done {
+        trc::event!(
+            TaskManager(TaskManagerEvent::ReindexCompleted),
+            AccountId = account_id,
+            Total = progress.indexed,
+            Elapsed = Duration::from_secs(now.saturating_sub(progress.started_at)),
+        );
+
+        Ok(TaskResult::Success(vec![]))
+    } else {
+        trc::event!(
+            TaskManager(TaskManagerEvent::ReindexProgress),
+            AccountId = account_id,
+            TotalSuccesses = progress.indexed,
+            Total = progress.total,
+        );
+
+        // Continue with the next chunk once the configured interval has elapsed
+        Ok(TaskResult::Success(vec![Task::AccountMaintenance(
+            TaskAccountMaintenance {
+                account_id: task.account_id,
+                maintenance_type: TaskAccountMaintenanceType::Reindex,
+                status: TaskStatus::at((now + server.core.email.reindex_interval.as_secs()) as i64),
+            },
+        )]))
+    }
+}
+
+pub async fn reindex_progress(
+    server: &Server,
+    account_id: u32,
+) -> trc::Result<Option<ReindexProgress>> {
+    server
+        .store()
+        .get_value::<ReindexProgress>(ValueKey::property(
+            account_id,
+            Collection::Principal,
+            0,
+            PrincipalField::ReindexProgress,
+        ))
+        .await
+        .caused_by(trc::location!())
+}
+
+async fn reindex_document_ids(
+    server: &Server,
+    account_id: u32,
+    document_type: IndexDocumentType,
+) -> trc::Result<RoaringBitmap> {
+    match document_type {
+        IndexDocumentType::Email => Ok(server
+            .get_cached_messages(account_id)
+            .await
+            .caused_by(trc::location!())?
+            .emails
+            .items
+            .iter()
+            .map(|v| v.document_id)
+            .collect()),
+        IndexDocumentType::Calendar | IndexDocumentType::Contacts => Ok(server
             .fetch_dav_resources(
                 account_id,
                 account_id,
@@ -366,32 +529,53 @@ pub(crate) async fn reindex_account(server: &Server, account_id: u32) -> trc::Re
                 },
             )
             .await
-            .caused_by(trc::location!())?;
-        let mut batch = BatchBuilder::new();
+            .caused_by(trc::location!())?
+            .document_ids(false)
+            .collect()),
+        IndexDocumentType::File => Ok(RoaringBitmap::new()),
+    }
+}
 
-        for document_id in cache.document_ids(false) {
-            batch.schedule_task(Task::IndexDocument(TaskIndexDocument {
-                account_id: account_id.into(),
-                document_id: document_id.into(),
-                document_type,
-                status: TaskStatus::at(now + rand::rng().random_range(0..=300)),
-            }));
-
-            if batch.len() >= 2000 {
-                server.core.storage.data.write(batch.build_all()).await?;
-                batch = BatchBuilder::new();
-            }
+impl ReindexProgress {
+    pub fn percentage(&self) -> f64 {
+        if self.total > 0 {
+            (self.indexed as f64 * 100.0 / self.total as f64).min(100.0)
+        } else {
+            100.0
         }
     }
+}
 
-    if !batch.is_empty() {
-        server.core.storage.data.write(batch.build_all()).await?;
+impl SerializeInfallible for ReindexProgress {
+    fn serialize(&self) -> Vec<u8> {
+        KeySerializer::new(U32_LEN * 3 + U64_LEN * 3 + 1)
+            .write(self.total)
+            .write(self.indexed)
+            .write(self.phase)
+            .write(self.next_document_id)
+            .write(self.started_at)
+            .write(self.updated_at)
+            .write(self.completed_at.unwrap_or_default())
+            .finalize()
     }
+}
 
-    // Request indexing
-    server.notify_task_queue();
-
-    Ok(())
+impl Deserialize for ReindexProgress {
+    fn deserialize(bytes: &[u8]) -> trc::Result<Self> {
+        Ok(ReindexProgress {
+            total: bytes.deserialize_be_u32(0)?,
+            indexed: bytes.deserialize_be_u32(U32_LEN)?,
+            phase: bytes
+                .get(U32_LEN * 2)
+                .copied()
+                .ok_or_else(|| trc::StoreEvent::DataCorruption.caused_by(trc::location!()))?,
+            next_document_id: bytes.deserialize_be_u32(U32_LEN * 2 + 1)?,
+            started_at: bytes.deserialize_be_u64(U32_LEN * 3 + 1)?,
+            updated_at: bytes.deserialize_be_u64(U32_LEN * 3 + U64_LEN + 1)?,
+            completed_at: Some(bytes.deserialize_be_u64(U32_LEN * 3 + U64_LEN * 2 + 1)?)
+                .filter(|completed_at| *completed_at != 0),
+        })
+    }
 }
 
 async fn build_email_document(
@@ -435,6 +619,7 @@ async fn build_email_document(
                 &raw_message,
                 index_fields,
                 server.core.email.default_language,
+                server.core.email.index_max_body_size,
             )))
         }
         None => Ok(None),
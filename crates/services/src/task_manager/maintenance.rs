@@ -9,17 +9,27 @@ use std::time::Instant;
 use crate::task_manager::{
     TaskResult,
     index::{reindex_account, reindex_telemetry},
+    progress::TaskMonitor,
+    tiering::{restore_account_blobs, tier_account_blobs},
 };
 use common::{
-    KV_ACME, KV_GREYLIST, KV_LOCK_DAV, KV_LOCK_QUEUE_MESSAGE, KV_LOCK_TASK, KV_OAUTH,
-    KV_QUOTA_BLOB, KV_RATE_LIMIT_AUTH, KV_RATE_LIMIT_CONTACT, KV_RATE_LIMIT_HTTP_ANONYMOUS,
-    KV_RATE_LIMIT_HTTP_AUTHENTICATED, KV_RATE_LIMIT_IMAP, KV_RATE_LIMIT_LOITER, KV_RATE_LIMIT_RCPT,
-    KV_RATE_LIMIT_SCAN, KV_RATE_LIMIT_SMTP, KV_SIEVE_ID, Server,
-    storage::index::ObjectIndexBuilder,
+    KV_ACME, KV_AUTH_FAILURES, KV_GREYLIST, KV_LOCK_DAV, KV_LOCK_QUEUE_MESSAGE, KV_LOCK_TASK,
+    KV_OAUTH, KV_QUOTA_BLOB, KV_RATE_LIMIT_AUTH, KV_RATE_LIMIT_CONTACT,
+    KV_RATE_LIMIT_HTTP_ANONYMOUS, KV_RATE_LIMIT_HTTP_AUTHENTICATED, KV_RATE_LIMIT_IMAP,
+    KV_RATE_LIMIT_LOITER, KV_RATE_LIMIT_RCPT, KV_RATE_LIMIT_SCAN, KV_RATE_LIMIT_SIEVE,
+    KV_RATE_LIMIT_SMTP, KV_RATE_LIMIT_SMUGGLING, KV_RATE_LIMIT_TLS_REPORT, KV_SIEVE_ID, Server,
+    auth::login_history::LoginHistoryIndex,
+    storage::{audit::MessageAuditIndex, index::ObjectIndexBuilder, integrity::BlobIntegrityIndex},
+    telemetry::{statistics::StatisticsRollupIndex, usage::TenantUsageIndex},
 };
 use email::{
     cache::MessageCacheFetch,
-    message::{delete::EmailDeletion, ingest::EmailIngest, metadata::MessageData},
+    message::{
+        delete::EmailDeletion,
+        ingest::EmailIngest,
+        metadata::MessageData,
+        quarantine::{QuarantinedMessageIndex, SpamQuarantine},
+    },
     sieve::SieveScript,
 };
 use groupware::{
@@ -32,14 +42,16 @@ use registry::{
         enums::{TaskAccountMaintenanceType, TaskStoreMaintenanceType, TaskTenantMaintenanceType},
         prelude::{Object, ObjectInner, ObjectType, Property},
         structs::{
-            Task, TaskAccountMaintenance, TaskStatus, TaskStoreMaintenance, TaskTenantMaintenance,
+            QuarantinedMessage, Task, TaskAccountMaintenance, TaskStatus, TaskStoreMaintenance,
+            TaskTenantMaintenance,
         },
     },
     types::EnumImpl,
 };
-use smtp::reporting::index::ExternalReportIndex;
+use smtp::{outbound::trace::DeliveryTraceIndex, reporting::index::ExternalReportIndex};
 use store::{
-    Serialize, ValueKey,
+    ProgressTracker, Serialize, ValueKey,
+    ahash::AHashMap,
     rand::{self},
     registry::{RegistryFilter, RegistryQuery},
     roaring::RoaringBitmap,
@@ -55,10 +67,12 @@ use types::{
 pub(crate) trait MaintenanceTask: Sync + Send {
     fn store_maintenance(
         &self,
+        id: u64,
         task: &TaskStoreMaintenance,
     ) -> impl Future<Output = TaskResult> + Send;
     fn account_maintenance(
         &self,
+        id: u64,
         task: &TaskAccountMaintenance,
     ) -> impl Future<Output = TaskResult> + Send;
     fn tenant_maintenance(
@@ -68,8 +82,8 @@ pub(crate) trait MaintenanceTask: Sync + Send {
 }
 
 impl MaintenanceTask for Server {
-    async fn store_maintenance(&self, task: &TaskStoreMaintenance) -> TaskResult {
-        match store_maintenance(self, task).await {
+    async fn store_maintenance(&self, id: u64, task: &TaskStoreMaintenance) -> TaskResult {
+        match store_maintenance(self, id, task).await {
             Ok(result) => result,
             Err(err) => {
                 let result = TaskResult::temporary(err.to_string());
@@ -79,8 +93,8 @@ impl MaintenanceTask for Server {
         }
     }
 
-    async fn account_maintenance(&self, task: &TaskAccountMaintenance) -> TaskResult {
-        match account_maintenance(self, task).await {
+    async fn account_maintenance(&self, id: u64, task: &TaskAccountMaintenance) -> TaskResult {
+        match account_maintenance(self, id, task).await {
             Ok(result) => result,
             Err(err) => {
                 let result = TaskResult::temporary(err.to_string());
@@ -107,12 +121,14 @@ impl MaintenanceTask for Server {
 
 async fn store_maintenance(
     server: &Server,
+    id: u64,
     task: &TaskStoreMaintenance,
 ) -> trc::Result<TaskResult> {
     match task.maintenance_type {
         TaskStoreMaintenanceType::ReindexAccounts
         | TaskStoreMaintenanceType::PurgeAccounts
-        | TaskStoreMaintenanceType::ResetUserQuotas => {
+        | TaskStoreMaintenanceType::ResetUserQuotas
+        | TaskStoreMaintenanceType::TierBlobs => {
             let mut batch = BatchBuilder::new();
             let now = now() as i64;
             let maintenance_type = match task.maintenance_type {
@@ -121,6 +137,7 @@ async fn store_maintenance(
                 TaskStoreMaintenanceType::ResetUserQuotas => {
                     TaskAccountMaintenanceType::RecalculateQuota
                 }
+                TaskStoreMaintenanceType::TierBlobs => TaskAccountMaintenanceType::TierBlobs,
                 _ => unreachable!(),
             };
             for account_id in server
@@ -156,14 +173,28 @@ async fn store_maintenance(
         TaskStoreMaintenanceType::ReindexTelemetry => {
             reindex_telemetry(server).await?;
         }
+        TaskStoreMaintenanceType::SendQuarantineDigests => {
+            send_quarantine_digests(server).await?;
+        }
         TaskStoreMaintenanceType::PurgeData => {
             // Delete expired external reports
             let now = now();
             let mut batch = BatchBuilder::new();
+            let mut monitor = TaskMonitor::new(server, id);
+            let mut processed = 0;
+            let mut total = 0;
             for object in [
                 ObjectType::DmarcExternalReport,
                 ObjectType::TlsExternalReport,
+                ObjectType::TlsInboundReport,
                 ObjectType::ArfExternalReport,
+                ObjectType::StatisticsRollup,
+                ObjectType::TenantUsage,
+                ObjectType::MessageAuditEntry,
+                ObjectType::LoginEntry,
+                ObjectType::BlobIntegrityIssue,
+                ObjectType::DeliveryTrace,
+                ObjectType::QuarantinedMessage,
             ] {
                 let ids = server
                     .registry()
@@ -174,7 +205,16 @@ async fn store_maintenance(
                     )))
                     .await?;
                 let object_id = object.to_id();
+                total += ids.len() as u64;
                 for id in ids {
+                    if !monitor.update(processed, total).await {
+                        if !batch.is_empty() {
+                            server.store().write(batch.build_all()).await?;
+                        }
+                        return Ok(TaskResult::Ignored);
+                    }
+                    processed += 1;
+
                     let item_id = id.id();
                     if let Some(report) = server
                         .store()
@@ -190,9 +230,33 @@ async fn store_maintenance(
                             ObjectInner::TlsExternalReport(report) => {
                                 report.write_ops(&mut batch, item_id, false);
                             }
+                            ObjectInner::TlsInboundReport(report) => {
+                                report.write_ops(&mut batch, item_id, false);
+                            }
                             ObjectInner::ArfExternalReport(report) => {
                                 report.write_ops(&mut batch, item_id, false);
                             }
+                            ObjectInner::StatisticsRollup(rollup) => {
+                                rollup.write_ops(&mut batch, item_id, false);
+                            }
+                            ObjectInner::TenantUsage(usage) => {
+                                usage.write_ops(&mut batch, item_id, false);
+                            }
+                            ObjectInner::MessageAuditEntry(entry) => {
+                                entry.write_ops(&mut batch, item_id, false);
+                            }
+                            ObjectInner::LoginEntry(entry) => {
+                                entry.write_ops(&mut batch, item_id, false);
+                            }
+                            ObjectInner::BlobIntegrityIssue(issue) => {
+                                issue.write_ops(&mut batch, item_id, false);
+                            }
+                            ObjectInner::DeliveryTrace(trace) => {
+                                trace.write_ops(&mut batch, item_id, false);
+                            }
+                            ObjectInner::QuarantinedMessage(message) => {
+                                message.write_ops(&mut batch, item_id, false);
+                            }
                             _ => {}
                         }
 
@@ -264,19 +328,64 @@ async fn store_maintenance(
                 Elapsed = started.elapsed()
             );
         }
-        TaskStoreMaintenanceType::PurgeBlob => {
+        TaskStoreMaintenanceType::CompactDataStore | TaskStoreMaintenanceType::FlushDataStore => {
+            let started = Instant::now();
+            let (result, event) =
+                if task.maintenance_type == TaskStoreMaintenanceType::CompactDataStore {
+                    (
+                        server.store().compact().await,
+                        StoreEvent::DataStoreCompacted,
+                    )
+                } else {
+                    (server.store().flush().await, StoreEvent::DataStoreFlushed)
+                };
+
+            match result {
+                Ok(_) => {
+                    trc::event!(Store(event), Elapsed = started.elapsed());
+                }
+                Err(err) if err.matches(trc::EventType::Store(StoreEvent::NotSupported)) => {
+                    return Ok(TaskResult::permanent(
+                        "The data store backend does not support manual compaction",
+                    ));
+                }
+                Err(err) => {
+                    return Err(err.caused_by(trc::location!()));
+                }
+            }
+        }
+        TaskStoreMaintenanceType::PurgeBlob
+        | TaskStoreMaintenanceType::IndexBlobSizes
+        | TaskStoreMaintenanceType::ScrubBlobs => {
             if let Some(shard_index) = task.shard_index {
-                server
-                    .store()
-                    .purge_blobs(server.blob_store().clone(), shard_index as u8)
-                    .await
-                    .caused_by(trc::location!())?;
+                match task.maintenance_type {
+                    TaskStoreMaintenanceType::PurgeBlob => {
+                        server
+                            .store()
+                            .purge_blobs(server.blob_store().clone(), shard_index as u8)
+                            .await
+                            .caused_by(trc::location!())?;
+                    }
+                    TaskStoreMaintenanceType::IndexBlobSizes => {
+                        server
+                            .store()
+                            .index_blob_sizes(server.blob_store().clone(), shard_index as u8)
+                            .await
+                            .caused_by(trc::location!())?;
+                    }
+                    _ => {
+                        server
+                            .scrub_blob_store(shard_index as u8, &mut TaskMonitor::new(server, id))
+                            .await
+                            .caused_by(trc::location!())?;
+                    }
+                }
             } else {
                 let mut batch = BatchBuilder::new();
                 let now = now() as i64;
                 for shard_index in 0..=u8::MAX {
                     batch.schedule_task(Task::StoreMaintenance(TaskStoreMaintenance {
-                        maintenance_type: TaskStoreMaintenanceType::PurgeBlob,
+                        maintenance_type: task.maintenance_type,
                         shard_index: Some(shard_index as u64),
                         status: TaskStatus::at(now),
                     }));
@@ -346,6 +455,10 @@ async fn store_maintenance(
                     KV_RATE_LIMIT_HTTP_AUTHENTICATED,
                     KV_RATE_LIMIT_HTTP_ANONYMOUS,
                     KV_RATE_LIMIT_IMAP,
+                    KV_RATE_LIMIT_SIEVE,
+                    KV_RATE_LIMIT_SMUGGLING,
+                    KV_RATE_LIMIT_TLS_REPORT,
+                    KV_AUTH_FAILURES,
                 ][..],
                 TaskStoreMaintenanceType::ResetBlobQuotas => &[KV_QUOTA_BLOB][..],
                 TaskStoreMaintenanceType::RemoveAuthTokens => &[KV_ACME, KV_OAUTH][..],
@@ -405,8 +518,59 @@ async fn store_maintenance(
     Ok(TaskResult::Success(vec![]))
 }
 
+async fn send_quarantine_digests(server: &Server) -> trc::Result<()> {
+    let Some(digest_frequency) = server
+        .core
+        .spam
+        .quarantine
+        .as_ref()
+        .and_then(|q| q.digest_frequency)
+    else {
+        return Ok(());
+    };
+
+    // Only messages quarantined since the previous digest are listed
+    let since = now().saturating_sub(digest_frequency.as_secs());
+    let object_id = ObjectType::QuarantinedMessage.to_id();
+    let mut accounts: AHashMap<u32, Vec<(u64, QuarantinedMessage)>> = AHashMap::new();
+    for id in server
+        .registry()
+        .query::<Vec<Id>>(
+            RegistryQuery::new(ObjectType::QuarantinedMessage)
+                .greater_than_or_equal(Property::ReceivedAt, since),
+        )
+        .await?
+    {
+        let item_id = id.id();
+        if let Some(message) = server
+            .store()
+            .get_value::<QuarantinedMessage>(ValueKey::from(ValueClass::Registry(
+                RegistryClass::Item { object_id, item_id },
+            )))
+            .await?
+        {
+            accounts
+                .entry(message.account_id.document_id())
+                .or_default()
+                .push((item_id, message));
+        }
+    }
+
+    for (account_id, messages) in accounts {
+        if let Err(err) = server.send_quarantine_digest(account_id, messages).await {
+            trc::error!(
+                err.account_id(account_id)
+                    .details("Failed to send quarantine digest")
+            );
+        }
+    }
+
+    Ok(())
+}
+
 async fn account_maintenance(
     server: &Server,
+    id: u64,
     task: &TaskAccountMaintenance,
 ) -> trc::Result<TaskResult> {
     match task.maintenance_type {
@@ -414,7 +578,7 @@ async fn account_maintenance(
             server.purge_account(task.account_id.document_id()).await?;
         }
         TaskAccountMaintenanceType::Reindex => {
-            reindex_account(server, task.account_id.document_id()).await?;
+            return reindex_account(server, id, task).await;
         }
         TaskAccountMaintenanceType::RecalculateImapUid => {
             reset_imap_uids(server, task.account_id.document_id()).await?;
@@ -422,6 +586,12 @@ async fn account_maintenance(
         TaskAccountMaintenanceType::RecalculateQuota => {
             recalculate_quota(server, task.account_id.document_id()).await?;
         }
+        TaskAccountMaintenanceType::TierBlobs => {
+            tier_account_blobs(server, task.account_id.document_id()).await?;
+        }
+        TaskAccountMaintenanceType::RestoreBlobs => {
+            restore_account_blobs(server, task.account_id.document_id()).await?;
+        }
     }
 
     Ok(TaskResult::Success(vec![]))
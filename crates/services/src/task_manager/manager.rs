@@ -6,6 +6,7 @@
 
 use crate::task_manager::acme::AcmeTask;
 use crate::task_manager::alarm::SendAlarmTask;
+use crate::task_manager::data_region::DataRegionMigrationTask;
 use crate::task_manager::destroy_account::DestroyAccountTask;
 use crate::task_manager::dkim::DkimManagementTask;
 use crate::task_manager::dns::DnsManagementTask;
@@ -14,9 +15,12 @@ use crate::task_manager::index::SearchIndexTask;
 use crate::task_manager::lock::TaskLockManager;
 use crate::task_manager::maintenance::MaintenanceTask;
 use crate::task_manager::merge_threads::MergeThreadsTask;
+use crate::task_manager::migrate::AccountMigrationTask;
+use crate::task_manager::progress::TaskProgressManager;
 use crate::task_manager::report::{self, SubmitReportTask};
 use crate::task_manager::restore_item::RestoreItemTask;
 use crate::task_manager::spam_classifier::SpamFilterMaintenanceTask;
+use crate::task_manager::window::MaintenanceGate;
 use crate::task_manager::{
     DEFAULT_LOCK_EXPIRY, Locked, QUEUE_REFRESH_INTERVAL, TaskDetails, TaskFailureType, TaskInfo,
     TaskJob, TaskManagerIpc, TaskResult,
@@ -74,6 +78,7 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
         protocol: ServerProtocol::Smtp,
         acceptor: TcpAcceptor::Plain,
         limiter: ConcurrencyLimiter::new(100),
+        bandwidth: None,
         shutdown_rx: watch::channel(false).1,
         proxy_networks: vec![],
         span_id_gen: Arc::new(SnowflakeIdGenerator::new()),
@@ -92,6 +97,9 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
             }
             TaskType::DestroyAccount
             | TaskType::AccountMaintenance
+            | TaskType::AccountExport
+            | TaskType::AccountImport
+            | TaskType::DataRegionMigration
             | TaskType::TenantMaintenance
             | TaskType::StoreMaintenance => 1,
             TaskType::SpamFilterMaintenance => 2,
@@ -208,7 +216,10 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
                         .await
                     {
                         Ok(Some(task)) => {
+                            let is_cancelled = server.task_is_cancelled(job.id).await;
                             let result = match &task {
+                                // Tasks cancelled while waiting in the channel are dropped
+                                _ if is_cancelled => TaskResult::Ignored,
                                 Task::CalendarAlarmEmail(task) => {
                                     server.send_email_alarm(task, server_instance.clone()).await
                                 }
@@ -232,13 +243,20 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
                                 Task::RestoreArchivedItem(task) => server.restore_item(task).await,
                                 Task::DestroyAccount(task) => server.destroy_account(task).await,
                                 Task::AccountMaintenance(task) => {
-                                    server.account_maintenance(task).await
+                                    server.account_maintenance(job.id, task).await
+                                }
+                                Task::AccountExport(task) => server.account_export(task).await,
+                                Task::AccountImport(task) => {
+                                    server.account_import(job.id, task).await
+                                }
+                                Task::DataRegionMigration(task) => {
+                                    server.data_region_migration(job.id, task).await
                                 }
                                 Task::TenantMaintenance(task) => {
                                     server.tenant_maintenance(task).await
                                 }
                                 Task::StoreMaintenance(task) => {
-                                    server.store_maintenance(task).await
+                                    server.store_maintenance(job.id, task).await
                                 }
                                 Task::SpamFilterMaintenance(task) => {
                                     Box::pin(server.spam_filter_maintenance(task)).await
@@ -257,12 +275,14 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
 
                             refresh_queue = result.is_retry();
 
+                            let id = job.id;
                             update_tasks(
                                 &server,
                                 &mut [TaskDetails { task, info: job }],
                                 vec![result],
                             )
                             .await;
+                            server.task_progress_clear(id).await;
                         }
                         Ok(None) => {
                             trc::event!(
@@ -293,6 +313,7 @@ pub fn spawn_task_manager(inner: Arc<Inner>) {
         let mut ipc = TaskManagerIpc {
             txs: txs.try_into().expect("Incorrect number of task channels"),
             locked: Default::default(),
+            budgets: Default::default(),
             revision: 0,
         };
         let rx = inner.ipc.task_tx.clone();
@@ -336,7 +357,9 @@ impl TaskQueueManager for Server {
         let mut tasks = Vec::new();
         let now = Instant::now();
         let mut next_event = None;
+        let mut next_window: Option<Duration> = None;
         let roles = &self.core.network.roles;
+        let gate = MaintenanceGate::build(self).await;
         ipc.revision += 1;
         let _ = self
             .store()
@@ -360,7 +383,10 @@ impl TaskQueueManager for Server {
                                 | TaskType::IndexTrace => roles.search_indexing,
                                 TaskType::AccountMaintenance
                                 | TaskType::TenantMaintenance
-                                | TaskType::DestroyAccount => roles.account_maintenance,
+                                | TaskType::DestroyAccount
+                                | TaskType::AccountExport
+                                | TaskType::AccountImport
+                                | TaskType::DataRegionMigration => roles.account_maintenance,
                                 TaskType::StoreMaintenance => roles.store_maintenance,
                                 TaskType::SpamFilterMaintenance => roles.spam_training,
                                 TaskType::CalendarAlarmEmail
@@ -385,6 +411,12 @@ impl TaskQueueManager for Server {
                                 return Ok(true);
                             }
 
+                            // Heavy tasks wait for their maintenance window to open
+                            if let Some(wait) = gate.deferred(task_type) {
+                                next_window = Some(next_window.map_or(wait, |next| next.min(wait)));
+                                return Ok(true);
+                            }
+
                             match ipc.locked.entry(task_id) {
                                 Entry::Occupied(mut entry) => {
                                     let locked = entry.get_mut();
@@ -460,7 +492,17 @@ impl TaskQueueManager for Server {
         for (task_job, task_type_idx) in tasks {
             let tx = &ipc.txs[task_type_idx as usize];
 
-            if tx.capacity() > 0 {
+            if let Err(wait) = gate.acquire(task_job.typ, &mut ipc.budgets) {
+                // The window budget is exhausted, retry once it is replenished
+                trc::event!(
+                    TaskManager(TaskManagerEvent::TaskIgnored),
+                    Id = task_job.id,
+                    Details = task_job.typ.as_str(),
+                    Reason = "Maintenance window budget exhausted.",
+                );
+                ipc.locked.remove(&task_job.id);
+                next_window = Some(next_window.map_or(wait, |next| next.min(wait)));
+            } else if tx.capacity() > 0 {
                 if self.try_lock_task(task_job.id).await && tx.send(task_job).await.is_err() {
                     trc::event!(
                         Server(trc::ServerEvent::ThreadError),
@@ -478,9 +520,14 @@ impl TaskQueueManager for Server {
         let now = Instant::now();
         ipc.locked
             .retain(|_, locked| locked.expires > now && locked.revision == ipc.revision);
-        Duration::from_secs(next_event.map_or(QUEUE_REFRESH_INTERVAL, |timestamp| {
-            timestamp.saturating_sub(store::write::now())
-        }))
+        gate.retain_budgets(&mut ipc.budgets);
+        let sleep_for =
+            Duration::from_secs(next_event.map_or(QUEUE_REFRESH_INTERVAL, |timestamp| {
+                timestamp.saturating_sub(store::write::now())
+            }));
+        next_window.map_or(sleep_for, |wait| {
+            sleep_for.min(wait.max(Duration::from_secs(1)))
+        })
     }
 }
 
@@ -0,0 +1,145 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::task_manager::{TaskResult, progress::TaskMonitor};
+use common::Server;
+use email::migrate::{MigrationSummary, export::AccountExport, import::AccountImport};
+use registry::schema::structs::TaskAccountMigration;
+use std::{
+    io::ErrorKind,
+    path::{Component, Path, PathBuf},
+};
+
+pub(crate) trait AccountMigrationTask: Sync + Send {
+    fn account_export(
+        &self,
+        task: &TaskAccountMigration,
+    ) -> impl Future<Output = TaskResult> + Send;
+
+    fn account_import(
+        &self,
+        id: u64,
+        task: &TaskAccountMigration,
+    ) -> impl Future<Output = TaskResult> + Send;
+}
+
+impl AccountMigrationTask for Server {
+    async fn account_export(&self, task: &TaskAccountMigration) -> TaskResult {
+        let account_id = task.account_id.document_id();
+        let result = match migration_path(self, &task.path, false) {
+            Ok(path) => AccountExport::account_export(self, account_id, &path).await,
+            Err(err) => Err(err),
+        };
+        migration_result(result, account_id, "Failed to export account")
+    }
+
+    async fn account_import(&self, id: u64, task: &TaskAccountMigration) -> TaskResult {
+        let account_id = task.account_id.document_id();
+        let result = match migration_path(self, &task.path, true) {
+            Ok(path) => {
+                AccountImport::account_import(
+                    self,
+                    account_id,
+                    &path,
+                    &mut TaskMonitor::new(self, id),
+                )
+                .await
+            }
+            Err(err) => Err(err),
+        };
+        migration_result(result, account_id, "Failed to import account")
+    }
+}
+
+// Archives are confined to the configured migration directory, task paths must be
+// relative to it and are resolved after following symlinks.
+fn migration_path(server: &Server, path: &str, must_exist: bool) -> trc::Result<PathBuf> {
+    let Some(directory) = server
+        .core
+        .network
+        .task_manager
+        .migration_directory
+        .as_deref()
+    else {
+        return Err(trc::StoreEvent::NotConfigured
+            .into_err()
+            .details("Migration directory is not configured"));
+    };
+    let relative = Path::new(path);
+    if path.is_empty()
+        || !relative
+            .components()
+            .all(|component| matches!(component, Component::Normal(_)))
+    {
+        return Err(trc::StoreEvent::NotSupported
+            .into_err()
+            .details("Migration path must be relative to the migration directory")
+            .ctx(trc::Key::Path, path.to_string()));
+    }
+
+    let directory =
+        std::fs::canonicalize(directory).map_err(|err| io_error(err, Path::new(directory)))?;
+    let path = directory.join(relative);
+    let resolved = match std::fs::canonicalize(&path) {
+        Ok(resolved) => resolved,
+        Err(err)
+            if !must_exist
+                && err.kind() == ErrorKind::NotFound
+                && path.symlink_metadata().is_err() =>
+        {
+            // Exported archives do not exist yet, resolve their parent instead
+            let (Some(parent), Some(file_name)) = (path.parent(), path.file_name()) else {
+                return Err(io_error(err, &path));
+            };
+            std::fs::canonicalize(parent)
+                .map_err(|err| io_error(err, parent))?
+                .join(file_name)
+        }
+        Err(err) => return Err(io_error(err, &path)),
+    };
+
+    if resolved.starts_with(&directory) && resolved != directory {
+        Ok(resolved)
+    } else {
+        Err(trc::StoreEvent::NotSupported
+            .into_err()
+            .details("Migration path is outside the migration directory")
+            .ctx(trc::Key::Path, resolved.to_string_lossy().into_owned()))
+    }
+}
+
+fn io_error(err: std::io::Error, path: &Path) -> trc::Error {
+    trc::StoreEvent::FilesystemError
+        .reason(err)
+        .ctx(trc::Key::Path, path.to_string_lossy().into_owned())
+        .caused_by(trc::location!())
+}
+
+fn migration_result(
+    result: trc::Result<MigrationSummary>,
+    account_id: u32,
+    details: &'static str,
+) -> TaskResult {
+    match result {
+        Ok(summary) if summary.is_cancelled => TaskResult::Ignored,
+        Ok(_) => TaskResult::Success(vec![]),
+        Err(err) => {
+            // Retrying will not help with an unusable archive or path, or an over quota account
+            let result = if err.matches(trc::EventType::Limit(trc::LimitEvent::Quota))
+                || err.matches(trc::EventType::Store(trc::StoreEvent::DataCorruption))
+                || err.matches(trc::EventType::Store(trc::StoreEvent::NotConfigured))
+                || err.matches(trc::EventType::Store(trc::StoreEvent::NotSupported))
+                || err.matches(trc::EventType::Store(trc::StoreEvent::FilesystemError))
+            {
+                TaskResult::permanent(err.to_string())
+            } else {
+                TaskResult::temporary(err.to_string())
+            };
+            trc::error!(err.account_id(account_id).details(details));
+            result
+        }
+    }
+}
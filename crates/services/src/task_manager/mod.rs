@@ -14,9 +14,11 @@ use store::ahash::AHashMap;
 use store::write::Operation;
 use tokio::sync::mpsc;
 use trc::TaskManagerEvent;
+use window::WindowBudget;
 
 pub mod acme;
 pub mod alarm;
+pub mod data_region;
 pub mod destroy_account;
 pub mod dkim;
 pub mod dns;
@@ -26,10 +28,14 @@ pub mod lock;
 pub mod maintenance;
 pub mod manager;
 pub mod merge_threads;
+pub mod migrate;
+pub mod progress;
 pub mod report;
 pub mod restore_item;
 pub mod scheduler;
 pub mod spam_classifier;
+pub mod tiering;
+pub mod window;
 
 const QUEUE_REFRESH_INTERVAL: u64 = 60 * 5; // 5 minutes
 const DEFAULT_LOCK_EXPIRY: u64 = 60 * 60; // 1 hour
@@ -37,6 +43,7 @@ const DEFAULT_LOCK_EXPIRY: u64 = 60 * 60; // 1 hour
 pub(crate) struct TaskManagerIpc {
     txs: [mpsc::Sender<TaskJob>; TaskType::COUNT],
     locked: AHashMap<u64, Locked>,
+    budgets: AHashMap<u32, WindowBudget>,
     revision: u64,
 }
 
@@ -104,6 +111,9 @@ impl TaskInfo for Task {
             Task::AcmeRenewal(_) => "AcmeRenewal",
             Task::DkimManagement(_) => "DkimManagement",
             Task::DnsManagement(_) => "DnsManagement",
+            Task::AccountExport(_) => "AccountExport",
+            Task::AccountImport(_) => "AccountImport",
+            Task::DataRegionMigration(_) => "DataRegionMigration",
             Task::TenantMaintenance(_) => "TenantMaintenance",
         }
     }
@@ -0,0 +1,204 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::task_manager::{DEFAULT_LOCK_EXPIRY, TaskInfo, lock::TaskLockManager};
+use common::{KV_TASK_CANCEL, KV_TASK_PROGRESS, Server};
+use registry::schema::structs::Task;
+use serde::{Deserialize, Serialize};
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+use store::{
+    ProgressTracker,
+    dispatch::lookup::KeyValue,
+    write::{BatchBuilder, TaskQueueClass, ValueClass, now},
+};
+use trc::{AddContext, TaskManagerEvent};
+
+// Progress is persisted at most this often to avoid a write per processed item
+const PROGRESS_UPDATE_INTERVAL: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskProgress {
+    pub processed: u64,
+    pub total: u64,
+    pub started_at: u64,
+    pub updated_at: u64,
+}
+
+// Handed to long-running tasks to report how far they got and to find out
+// whether they were cancelled in the meantime.
+pub struct TaskMonitor {
+    server: Server,
+    id: u64,
+    progress: TaskProgress,
+    last_update: Option<Instant>,
+}
+
+pub trait TaskProgressManager: Sync + Send {
+    fn task_progress(
+        &self,
+        id: u64,
+    ) -> impl Future<Output = trc::Result<Option<TaskProgress>>> + Send;
+
+    fn task_progress_clear(&self, id: u64) -> impl Future<Output = ()> + Send;
+
+    fn task_is_cancelled(&self, id: u64) -> impl Future<Output = bool> + Send;
+
+    fn task_cancel(&self, id: u64, task: &Task) -> impl Future<Output = trc::Result<()>> + Send;
+}
+
+impl TaskProgressManager for Server {
+    async fn task_progress(&self, id: u64) -> trc::Result<Option<TaskProgress>> {
+        self.in_memory_store()
+            .key_get::<String>(KeyValue::<()>::build_key(
+                KV_TASK_PROGRESS,
+                id.to_be_bytes(),
+            ))
+            .await
+            .caused_by(trc::location!())?
+            .map(|progress| {
+                serde_json::from_str(&progress).map_err(|err| {
+                    trc::StoreEvent::DataCorruption
+                        .reason(err)
+                        .id(id)
+                        .caused_by(trc::location!())
+                })
+            })
+            .transpose()
+    }
+
+    async fn task_progress_clear(&self, id: u64) {
+        for prefix in [KV_TASK_PROGRESS, KV_TASK_CANCEL] {
+            if let Err(err) = self
+                .in_memory_store()
+                .key_delete(KeyValue::<()>::build_key(prefix, id.to_be_bytes()))
+                .await
+            {
+                trc::error!(
+                    err.id(id)
+                        .details("Failed to clear task progress")
+                        .caused_by(trc::location!())
+                );
+            }
+        }
+    }
+
+    async fn task_is_cancelled(&self, id: u64) -> bool {
+        match self
+            .in_memory_store()
+            .key_exists(KeyValue::<()>::build_key(KV_TASK_CANCEL, id.to_be_bytes()))
+            .await
+        {
+            Ok(is_cancelled) => is_cancelled,
+            Err(err) => {
+                trc::error!(
+                    err.id(id)
+                        .details("Failed to check task cancellation")
+                        .caused_by(trc::location!())
+                );
+                false
+            }
+        }
+    }
+
+    // Queued tasks are removed right away, tasks being processed are flagged
+    // and stop the next time they report progress.
+    async fn task_cancel(&self, id: u64, task: &Task) -> trc::Result<()> {
+        if self.try_lock_task(id).await {
+            let mut batch = BatchBuilder::new();
+            batch
+                .clear(ValueClass::TaskQueue(TaskQueueClass::Task { id }))
+                .clear(ValueClass::TaskQueue(TaskQueueClass::Due {
+                    id,
+                    due: task.due_timestamp(),
+                }));
+            let result = self.store().write(batch.build_all()).await;
+            self.remove_index_lock(id).await;
+            result.caused_by(trc::location!())?;
+        } else {
+            self.in_memory_store()
+                .key_set(
+                    KeyValue::with_prefix(KV_TASK_CANCEL, id.to_be_bytes(), vec![])
+                        .expires(DEFAULT_LOCK_EXPIRY),
+                )
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        trc::event!(
+            TaskManager(TaskManagerEvent::TaskCancelled),
+            Id = id,
+            Details = task.name(),
+        );
+
+        Ok(())
+    }
+}
+
+impl TaskMonitor {
+    pub fn new(server: &Server, id: u64) -> Self {
+        TaskMonitor {
+            server: server.clone(),
+            id,
+            progress: TaskProgress {
+                started_at: now(),
+                ..Default::default()
+            },
+            last_update: None,
+        }
+    }
+}
+
+impl ProgressTracker for TaskMonitor {
+    // Records the progress of the task, returns false if it was cancelled
+    async fn update(&mut self, processed: u64, total: u64) -> bool {
+        self.progress.processed = processed;
+        self.progress.total = total;
+        if self
+            .last_update
+            .is_some_and(|last_update| last_update.elapsed() < PROGRESS_UPDATE_INTERVAL)
+        {
+            return true;
+        }
+        self.last_update = Some(Instant::now());
+        self.progress.updated_at = now();
+
+        if let Err(err) = self
+            .server
+            .in_memory_store()
+            .key_set(
+                KeyValue::with_prefix(
+                    KV_TASK_PROGRESS,
+                    self.id.to_be_bytes(),
+                    serde_json::to_vec(&self.progress).unwrap_or_default(),
+                )
+                .expires(DEFAULT_LOCK_EXPIRY),
+            )
+            .await
+        {
+            trc::error!(
+                err.id(self.id)
+                    .details("Failed to store task progress")
+                    .caused_by(trc::location!())
+            );
+        }
+
+        !self.server.task_is_cancelled(self.id).await
+    }
+}
+
+impl TaskProgress {
+    pub fn percentage(&self) -> f64 {
+        if self.total > 0 {
+            (self.processed as f64 * 100.0 / self.total as f64).min(100.0)
+        } else {
+            0.0
+        }
+    }
+}
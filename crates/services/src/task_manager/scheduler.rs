@@ -14,6 +14,7 @@ use std::{
 use common::{
     BuildServer, Inner, LONG_1D_SLUMBER,
     config::{mailstore::spamfilter, telemetry::OtelMetrics},
+    telemetry::statistics::next_statistics_flush,
 };
 use registry::{
     schema::{
@@ -36,10 +37,13 @@ enum Event {
     PurgeAccount,
     PurgeDataStore,
     PurgeBlobStore,
+    ScrubBlobStore,
+    QuarantineDigest,
     OtelMetrics,
     CalculateMetrics,
     TrainSpamClassifier,
     RenewNodeIdLease,
+    StoreStatistics,
     // SPDX-SnippetBegin
     // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
     // SPDX-License-Identifier: LicenseRef-SEL
@@ -88,6 +92,22 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                 Event::PurgeBlobStore,
             );
 
+            // Blob store integrity checks
+            if let Some(scrub_frequency) = server.core.email.blob_scrub_frequency {
+                queue.schedule(Instant::now() + scrub_frequency, Event::ScrubBlobStore);
+            }
+
+            // Quarantine digests
+            if let Some(digest_frequency) = server
+                .core
+                .spam
+                .quarantine
+                .as_ref()
+                .and_then(|q| q.digest_frequency)
+            {
+                queue.schedule(Instant::now() + digest_frequency, Event::QuarantineDigest);
+            }
+
             // Node ID lease renewal
             if server.core.storage.coordinator.is_enabled() {
                 queue.schedule(
@@ -129,6 +149,12 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
             // Calculate expensive metrics
             queue.schedule(Instant::now(), Event::CalculateMetrics);
 
+            // Flush traffic statistics
+            queue.schedule(
+                Instant::now() + next_statistics_flush(),
+                Event::StoreStatistics,
+            );
+
             // SPDX-SnippetBegin
             // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
             // SPDX-License-Identifier: LicenseRef-SEL
@@ -189,6 +215,20 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                                 status: TaskStatus::now(),
                                 shard_index: None,
                             }));
+
+                            // Move aged and archived blobs to the cold tier
+                            if server.core.storage.blob.is_tiered() {
+                                trc::event!(
+                                    TaskManager(TaskManagerEvent::TaskQueued),
+                                    Type = TaskStoreMaintenanceType::TierBlobs.as_str()
+                                );
+
+                                batch.schedule_task(Task::StoreMaintenance(TaskStoreMaintenance {
+                                    maintenance_type: TaskStoreMaintenanceType::TierBlobs,
+                                    status: TaskStatus::now(),
+                                    shard_index: None,
+                                }));
+                            }
                         }
                     }
                     Event::PurgeDataStore => {
@@ -229,6 +269,52 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                             }));
                         }
                     }
+                    Event::ScrubBlobStore => {
+                        if let Some(scrub_frequency) = server.core.email.blob_scrub_frequency {
+                            queue.schedule(Instant::now() + scrub_frequency, Event::ScrubBlobStore);
+
+                            if let Some(batch) = batch.as_mut() {
+                                trc::event!(
+                                    TaskManager(TaskManagerEvent::TaskQueued),
+                                    Type = TaskStoreMaintenanceType::ScrubBlobs.as_str()
+                                );
+
+                                batch.schedule_task(Task::StoreMaintenance(TaskStoreMaintenance {
+                                    maintenance_type: TaskStoreMaintenanceType::ScrubBlobs,
+                                    status: TaskStatus::now(),
+                                    shard_index: None,
+                                }));
+                            }
+                        }
+                    }
+                    Event::QuarantineDigest => {
+                        if let Some(digest_frequency) = server
+                            .core
+                            .spam
+                            .quarantine
+                            .as_ref()
+                            .and_then(|q| q.digest_frequency)
+                        {
+                            queue.schedule(
+                                Instant::now() + digest_frequency,
+                                Event::QuarantineDigest,
+                            );
+
+                            if let Some(batch) = batch.as_mut() {
+                                trc::event!(
+                                    TaskManager(TaskManagerEvent::TaskQueued),
+                                    Type = TaskStoreMaintenanceType::SendQuarantineDigests.as_str()
+                                );
+
+                                batch.schedule_task(Task::StoreMaintenance(TaskStoreMaintenance {
+                                    maintenance_type:
+                                        TaskStoreMaintenanceType::SendQuarantineDigests,
+                                    status: TaskStatus::now(),
+                                    shard_index: None,
+                                }));
+                            }
+                        }
+                    }
                     Event::RenewNodeIdLease => {
                         queue.schedule(
                             Instant::now() + server.registry().refresh_node_id_interval(),
@@ -366,6 +452,22 @@ pub fn spawn_task_scheduler(inner: Arc<Inner>) {
                             );
                         });
                     }
+                    Event::StoreStatistics => {
+                        queue.schedule(
+                            Instant::now() + next_statistics_flush(),
+                            Event::StoreStatistics,
+                        );
+
+                        let server = server.clone();
+                        tokio::spawn(async move {
+                            if let Err(err) = server.store_statistics().await {
+                                trc::error!(err.details("Failed to store statistics"));
+                            }
+                            if let Err(err) = server.store_usage().await {
+                                trc::error!(err.details("Failed to store tenant usage"));
+                            }
+                        });
+                    }
                     Event::TrainSpamClassifier => {
                         if let Some(train_frequency) = server
                             .core
@@ -567,10 +669,12 @@ impl Event {
             Event::PurgeAccount => "purgeAccount",
             Event::PurgeDataStore => "purgeDataStore",
             Event::PurgeBlobStore => "purgeBlobStore",
+            Event::ScrubBlobStore => "scrubBlobStore",
             Event::OtelMetrics => "otelMetrics",
             Event::CalculateMetrics => "calculateMetrics",
             Event::TrainSpamClassifier => "trainSpamClassifier",
             Event::RenewNodeIdLease => "renewNodeIdLease",
+            Event::StoreStatistics => "storeStatistics",
             // SPDX-SnippetBegin
             // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <info@stalwartlabs.com>
             // SPDX-License-Identifier: LicenseRef-SEL
@@ -0,0 +1,158 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::Server;
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    message::metadata::{MESSAGE_RECEIVED_MASK, MessageMetadata},
+};
+use registry::schema::structs::{Account, Domain};
+use std::time::Instant;
+use store::write::now;
+use trc::AddContext;
+use types::{
+    blob_hash::BlobHash, collection::Collection, field::EmailField, special_use::SpecialUse,
+};
+
+pub(crate) async fn tier_account_blobs(server: &Server, account_id: u32) -> trc::Result<()> {
+    let blob_store = &server.core.storage.blob;
+    if !blob_store.is_tiered() {
+        return Ok(());
+    }
+
+    // Tiering policies are defined on the account's domain
+    let Some(account) = server
+        .registry()
+        .object::<Account>(account_id.into())
+        .await?
+    else {
+        return Ok(());
+    };
+    let Some(domain) = server
+        .registry()
+        .object::<Domain>(account.domain_id())
+        .await?
+    else {
+        return Ok(());
+    };
+    if domain.cold_storage_after.is_none() && !domain.cold_storage_archive {
+        return Ok(());
+    }
+
+    let op_start = Instant::now();
+    let cache = server
+        .get_cached_messages(account_id)
+        .await
+        .caused_by(trc::location!())?;
+    let archive_ids = if domain.cold_storage_archive {
+        let archive_paths = cache
+            .mailboxes
+            .items
+            .iter()
+            .filter(|mailbox| mailbox.role == SpecialUse::Archive)
+            .map(|mailbox| format!("{}/", mailbox.path))
+            .collect::<Vec<_>>();
+        cache
+            .mailboxes
+            .items
+            .iter()
+            .filter(|mailbox| {
+                mailbox.role == SpecialUse::Archive
+                    || archive_paths
+                        .iter()
+                        .any(|path| mailbox.path.starts_with(path.as_str()))
+            })
+            .map(|mailbox| mailbox.document_id)
+            .collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+    let cutoff = domain
+        .cold_storage_after
+        .map(|after| now().saturating_sub(after.as_secs()));
+
+    let mut hashes = Vec::new();
+    server
+        .all_archives(
+            account_id,
+            Collection::Email,
+            EmailField::Metadata.into(),
+            |document_id, archive| {
+                let metadata = archive.unarchive::<MessageMetadata>()?;
+                let received_at = metadata.rcvd_attach.to_native() & MESSAGE_RECEIVED_MASK;
+
+                if cutoff.is_some_and(|cutoff| received_at < cutoff)
+                    || cache.email_by_id(&document_id).is_some_and(|email| {
+                        email
+                            .mailboxes
+                            .iter()
+                            .any(|mailbox| archive_ids.contains(&mailbox.mailbox_id))
+                    })
+                {
+                    hashes.push(BlobHash::from(&metadata.blob_hash));
+                }
+
+                Ok(())
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+    let mut total = 0;
+    for hash in hashes {
+        if blob_store
+            .move_blob_tier(hash.as_slice(), true, server.core.email.compression)
+            .await
+            .caused_by(trc::location!())?
+        {
+            total += 1;
+        }
+    }
+
+    if total > 0 {
+        trc::event!(
+            Store(trc::StoreEvent::ColdTierMigrated),
+            AccountId = account_id,
+            Total = total,
+            Elapsed = op_start.elapsed(),
+        );
+    }
+
+    Ok(())
+}
+
+pub(crate) async fn restore_account_blobs(server: &Server, account_id: u32) -> trc::Result<()> {
+    let blob_store = &server.core.storage.blob;
+    if !blob_store.is_tiered() {
+        return Ok(());
+    }
+
+    let op_start = Instant::now();
+    let blobs = server
+        .store()
+        .account_blobs(account_id)
+        .await
+        .caused_by(trc::location!())?;
+    let mut total = 0;
+    for hash in blobs.exclusive.iter().chain(blobs.shared.iter()) {
+        if blob_store
+            .move_blob_tier(hash.as_slice(), false, server.core.email.compression)
+            .await
+            .caused_by(trc::location!())?
+        {
+            total += 1;
+        }
+    }
+
+    trc::event!(
+        Store(trc::StoreEvent::ColdTierRestored),
+        AccountId = account_id,
+        Total = total,
+        Elapsed = op_start.elapsed(),
+    );
+
+    Ok(())
+}
@@ -0,0 +1,208 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::{KV_MAINTENANCE_WINDOW, Server};
+use registry::schema::{
+    enums::TaskType,
+    structs::{Rate, TaskMaintenanceWindow},
+};
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
+use store::{ahash::AHashMap, dispatch::lookup::KeyValue};
+use trc::AddContext;
+use utils::cron::SimpleCron;
+
+#[derive(Debug, Clone)]
+pub struct MaintenanceWindowStatus {
+    pub id: u32,
+    pub task_types: Vec<TaskType>,
+    pub rate: Option<Rate>,
+    pub is_open: bool,
+    pub is_triggered: bool,
+    pub opens_in: Duration,
+    pub closes_in: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub(crate) struct WindowBudget {
+    started: Instant,
+    used: u64,
+}
+
+// Task types listed in a maintenance window only run while at least one of
+// their windows is open, optionally limited to a number of tasks per period.
+pub(crate) struct MaintenanceGate {
+    windows: Vec<MaintenanceWindowStatus>,
+}
+
+pub trait MaintenanceWindows: Sync + Send {
+    fn maintenance_windows(
+        &self,
+    ) -> impl Future<Output = trc::Result<Vec<MaintenanceWindowStatus>>> + Send;
+
+    fn trigger_maintenance_window(&self, id: u32)
+    -> impl Future<Output = trc::Result<bool>> + Send;
+}
+
+impl MaintenanceWindows for Server {
+    async fn maintenance_windows(&self) -> trc::Result<Vec<MaintenanceWindowStatus>> {
+        let windows = &self.core.network.task_manager.maintenance_windows;
+        let mut result = Vec::with_capacity(windows.len());
+
+        for (id, window) in windows.0.iter() {
+            let is_triggered = self
+                .in_memory_store()
+                .key_exists(KeyValue::<()>::build_key(
+                    KV_MAINTENANCE_WINDOW,
+                    id.to_be_bytes(),
+                ))
+                .await
+                .caused_by(trc::location!())?;
+            result.push(MaintenanceWindowStatus::new(*id, window, is_triggered));
+        }
+
+        Ok(result)
+    }
+
+    async fn trigger_maintenance_window(&self, id: u32) -> trc::Result<bool> {
+        let Some(window) = self
+            .core
+            .network
+            .task_manager
+            .maintenance_windows
+            .0
+            .get(&id)
+        else {
+            return Ok(false);
+        };
+
+        // Manual triggers keep the window open for its configured duration
+        self.in_memory_store()
+            .key_set(
+                KeyValue::with_prefix(KV_MAINTENANCE_WINDOW, id.to_be_bytes(), vec![])
+                    .expires(window.duration.as_secs().max(1)),
+            )
+            .await
+            .caused_by(trc::location!())?;
+        self.notify_task_queue();
+
+        Ok(true)
+    }
+}
+
+impl MaintenanceWindowStatus {
+    fn new(id: u32, window: &TaskMaintenanceWindow, is_triggered: bool) -> Self {
+        let cron = SimpleCron::from(window.schedule.clone());
+        let since_last = cron.time_since_last();
+        let duration = window.duration.0;
+        let closes_in = (since_last < duration).then(|| duration - since_last);
+
+        MaintenanceWindowStatus {
+            id,
+            task_types: window.task_types.iter().copied().collect(),
+            rate: window.rate.clone(),
+            is_open: closes_in.is_some() || is_triggered,
+            is_triggered,
+            opens_in: cron.time_to_next(),
+            closes_in,
+        }
+    }
+}
+
+impl MaintenanceGate {
+    pub async fn build(server: &Server) -> Self {
+        let windows = if !server
+            .core
+            .network
+            .task_manager
+            .maintenance_windows
+            .is_empty()
+        {
+            match server.maintenance_windows().await {
+                Ok(windows) => windows,
+                Err(err) => {
+                    // Fall back to the schedule if the triggers cannot be read
+                    trc::error!(err.details("Failed to retrieve maintenance window triggers."));
+                    server
+                        .core
+                        .network
+                        .task_manager
+                        .maintenance_windows
+                        .0
+                        .iter()
+                        .map(|(id, window)| MaintenanceWindowStatus::new(*id, window, false))
+                        .collect()
+                }
+            }
+        } else {
+            vec![]
+        };
+
+        MaintenanceGate { windows }
+    }
+
+    // Returns the time until the task type is allowed to run, or None if
+    // it can run now.
+    pub fn deferred(&self, task_type: TaskType) -> Option<Duration> {
+        let mut wait: Option<Duration> = None;
+        for window in self
+            .windows
+            .iter()
+            .filter(|window| window.task_types.contains(&task_type))
+        {
+            if window.is_open {
+                return None;
+            }
+            wait = Some(wait.map_or(window.opens_in, |wait| wait.min(window.opens_in)));
+        }
+        wait
+    }
+
+    // Consumes one task from the budget of the open window, returning the
+    // time until the budget is replenished when it is exhausted.
+    pub fn acquire(
+        &self,
+        task_type: TaskType,
+        budgets: &mut AHashMap<u32, WindowBudget>,
+    ) -> Result<(), Duration> {
+        let Some((id, rate)) = self
+            .windows
+            .iter()
+            .find(|window| window.is_open && window.task_types.contains(&task_type))
+            .and_then(|window| window.rate.as_ref().map(|rate| (window.id, rate)))
+        else {
+            return Ok(());
+        };
+
+        let now = Instant::now();
+        let period = rate.period.0;
+        let budget = budgets.entry(id).or_insert(WindowBudget {
+            started: now,
+            used: 0,
+        });
+        if now.duration_since(budget.started) >= period {
+            budget.started = now;
+            budget.used = 0;
+        }
+
+        if budget.used < rate.count {
+            budget.used += 1;
+            Ok(())
+        } else {
+            Err(period.saturating_sub(now.duration_since(budget.started)))
+        }
+    }
+
+    pub fn retain_budgets(&self, budgets: &mut AHashMap<u32, WindowBudget>) {
+        budgets.retain(|id, _| {
+            self.windows
+                .iter()
+                .any(|window| window.id == *id && window.is_open)
+        });
+    }
+}
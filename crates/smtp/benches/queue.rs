@@ -0,0 +1,131 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+// Measures the queue manager's per-event cost with a large backlog of queued
+// messages: deserializing every message versus checking the archived message
+// in place, and rewriting the whole message versus the recipient update used
+// by messages shared between queues.
+
+use common::config::smtp::queue::{QueueExpiry, QueueName};
+use criterion::{BenchmarkId, Criterion, Throughput, criterion_group, criterion_main};
+use smtp::queue::{Message, Recipient, RecipientUpdate, Schedule};
+use std::{
+    hint::black_box,
+    net::{IpAddr, Ipv4Addr},
+};
+use store::{
+    Deserialize,
+    write::{AlignedBytes, Archive, Archiver, now},
+};
+
+const NUM_RECIPIENTS: usize = 3;
+
+fn queued_message(num: usize, queue: QueueName) -> Message {
+    Message {
+        created: now(),
+        blob_hash: Default::default(),
+        return_path: format!("sender{num}@example.org").into_boxed_str(),
+        recipients: (0..NUM_RECIPIENTS)
+            .map(|rcpt_num| {
+                let mut rcpt = Recipient::new(format!("rcpt{rcpt_num}@domain{num}.org"));
+                rcpt.retry = Schedule::later(3600);
+                rcpt.notify = Schedule::later(86400);
+                rcpt.expires = QueueExpiry::Ttl(now() + 5 * 86400);
+                rcpt.queue = queue;
+                rcpt
+            })
+            .collect(),
+        received_from_ip: IpAddr::V4(Ipv4Addr::LOCALHOST),
+        received_via_port: 25,
+        flags: 0,
+        env_id: None,
+        priority: 0,
+        size: 1024,
+        quota_keys: Default::default(),
+    }
+}
+
+fn queue_events(c: &mut Criterion) {
+    let queue = QueueName::new("default").unwrap();
+    let mut group = c.benchmark_group("queue_events");
+    group.sample_size(10);
+
+    for num_messages in [10_000usize, 100_000] {
+        let messages = (0..num_messages)
+            .map(|num| {
+                Archiver::new(queued_message(num, queue))
+                    .serialize()
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+        let now = now();
+
+        group.throughput(Throughput::Elements(num_messages as u64));
+        group.bench_with_input(
+            BenchmarkId::new("deserialize", num_messages),
+            &messages,
+            |b, messages| {
+                b.iter(|| {
+                    for bytes in messages {
+                        let message = <Archive<AlignedBytes> as Deserialize>::deserialize(bytes)
+                            .and_then(|archive| archive.deserialize::<Message>())
+                            .unwrap();
+                        black_box(message.next_event(Some(queue)).filter(|due| *due > now));
+                    }
+                })
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("zero_copy", num_messages),
+            &messages,
+            |b, messages| {
+                b.iter(|| {
+                    for bytes in messages {
+                        let archive =
+                            <Archive<AlignedBytes> as Deserialize>::deserialize(bytes).unwrap();
+                        let message = archive.unarchive::<Message>().unwrap();
+                        black_box(message.next_idle_event(queue, now));
+                    }
+                })
+            },
+        );
+    }
+    group.finish();
+}
+
+fn queue_updates(c: &mut Criterion) {
+    let queue = QueueName::new("default").unwrap();
+    let messages = (0..10_000)
+        .map(|num| queued_message(num, queue))
+        .collect::<Vec<_>>();
+    let mut group = c.benchmark_group("queue_updates");
+    group.throughput(Throughput::Elements(messages.len() as u64));
+
+    group.bench_function("full_message", |b| {
+        b.iter(|| {
+            for message in &messages {
+                black_box(Archiver::new(message.clone()).serialize().unwrap());
+            }
+        })
+    });
+    group.bench_function("recipient_update", |b| {
+        b.iter(|| {
+            for message in &messages {
+                let update = RecipientUpdate {
+                    blob_hash: message.blob_hash.clone(),
+                    total: message.recipients.len() as u32,
+                    indexes: vec![0],
+                    recipients: vec![message.recipients[0].clone()],
+                };
+                black_box(Archiver::new(update).serialize().unwrap());
+            }
+        })
+    });
+    group.finish();
+}
+
+criterion_group!(benches, queue_events, queue_updates);
+criterion_main!(benches);
@@ -4,11 +4,14 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::{inbound::auth::SaslToken, queue::QueueId};
+use crate::{
+    inbound::{auth::SaslToken, dry_run::DryRun},
+    queue::QueueId,
+};
 use common::{
     Inner, Server,
-    auth::AccountInfo,
-    config::smtp::auth::VerifyStrategy,
+    auth::{AccountInfo, MailingListCache, client_cert::ClientCertificate},
+    config::smtp::{auth::VerifyStrategy, session::VrfyMode},
     network::{ServerInstance, asn::AsnGeoLookupResult},
 };
 use mail_auth::{IprevOutput, SpfOutput};
@@ -72,12 +75,14 @@ pub struct SessionData {
 
     pub mail_from: Option<SessionAddress>,
     pub rcpt_to: Vec<SessionAddress>,
+    pub rcpt_lists: Vec<(String, Arc<MailingListCache>, bool)>,
     pub rcpt_errors: usize,
     pub rcpt_oks: usize,
     pub message: Vec<u8>,
 
     pub authenticated_as: Option<AccountInfo>,
     pub auth_errors: usize,
+    pub tls_client: Option<ClientCertificate>,
 
     pub priority: i16,
     pub delivery_by: i64,
@@ -86,11 +91,14 @@ pub struct SessionData {
     pub valid_until: Instant,
     pub bytes_left: usize,
     pub messages_sent: usize,
+    pub tls_failure_reported: bool,
 
     pub iprev: Option<IprevOutput>,
     pub spf_ehlo: Option<SpfOutput>,
     pub spf_mail_from: Option<SpfOutput>,
     pub dnsbl_error: Option<Vec<u8>>,
+
+    pub dry_run: Option<Box<DryRun>>,
 }
 
 #[derive(Clone, Debug)]
@@ -121,8 +129,8 @@ pub struct SessionParameters {
     pub rcpt_errors_wait: Duration,
     pub rcpt_max: usize,
     pub rcpt_dsn: bool,
-    pub can_expn: bool,
-    pub can_vrfy: bool,
+    pub expn: VrfyMode,
+    pub vrfy: VrfyMode,
     pub max_message_size: usize,
 
     // Mail authentication parameters
@@ -152,7 +160,9 @@ impl SessionData {
             helo_domain: String::new(),
             mail_from: None,
             rcpt_to: Vec::new(),
+            rcpt_lists: Vec::new(),
             authenticated_as: None,
+            tls_client: None,
             priority: 0,
             valid_until: Instant::now(),
             rcpt_errors: 0,
@@ -160,6 +170,7 @@ impl SessionData {
             message: Vec::with_capacity(0),
             auth_errors: 0,
             messages_sent: 0,
+            tls_failure_reported: false,
             bytes_left: 0,
             delivery_by: 0,
             future_release: 0,
@@ -167,6 +178,7 @@ impl SessionData {
             spf_ehlo: None,
             spf_mail_from: None,
             dnsbl_error: None,
+            dry_run: None,
         }
     }
 }
@@ -234,8 +246,8 @@ impl Session<common::network::stream::NullIo> {
                 iprev: VerifyStrategy::Disable,
                 spf_ehlo: VerifyStrategy::Disable,
                 spf_mail_from: VerifyStrategy::Disable,
-                can_expn: false,
-                can_vrfy: false,
+                expn: VrfyMode::Disable,
+                vrfy: VrfyMode::Disable,
             },
         }
     }
@@ -275,21 +287,25 @@ impl SessionData {
             helo_domain: "localhost".into(),
             mail_from,
             rcpt_to,
+            rcpt_lists: Vec::new(),
             rcpt_errors: 0,
             rcpt_oks: 0,
             message,
             authenticated_as: Some(authenticated_as),
             auth_errors: 0,
+            tls_client: None,
             priority: 0,
             delivery_by: 0,
             future_release: 0,
             valid_until: Instant::now(),
             bytes_left: 0,
             messages_sent: 0,
+            tls_failure_reported: false,
             iprev: None,
             spf_ehlo: None,
             spf_mail_from: None,
             dnsbl_error: None,
+            dry_run: None,
         }
     }
 }
@@ -5,7 +5,10 @@
  */
 
 use super::Session;
-use common::{config::smtp::auth::VerifyStrategy, network::SessionStream};
+use common::{
+    config::smtp::{auth::VerifyStrategy, session::VrfyMode},
+    network::SessionStream,
+};
 use std::time::Duration;
 
 impl<T: SessionStream> Session<T> {
@@ -88,31 +91,31 @@ impl<T: SessionStream> Session<T> {
 
         // VRFY/EXPN parameters
         let ec = &self.server.core.smtp.session.extensions;
-        self.params.can_expn = self
+        self.params.expn = self
             .server
             .eval_if(&ec.expn, self, self.data.session_id)
             .await
-            .unwrap_or(false);
-        self.params.can_vrfy = self
+            .unwrap_or(VrfyMode::Disable);
+        self.params.vrfy = self
             .server
             .eval_if(&ec.vrfy, self, self.data.session_id)
             .await
-            .unwrap_or(false);
+            .unwrap_or(VrfyMode::Disable);
     }
 
     pub async fn eval_post_auth_params(&mut self) {
         // Refresh VRFY/EXPN parameters
         let ec = &self.server.core.smtp.session.extensions;
-        self.params.can_expn = self
+        self.params.expn = self
             .server
             .eval_if(&ec.expn, self, self.data.session_id)
             .await
-            .unwrap_or(false);
-        self.params.can_vrfy = self
+            .unwrap_or(VrfyMode::Disable);
+        self.params.vrfy = self
             .server
             .eval_if(&ec.vrfy, self, self.data.session_id)
             .await
-            .unwrap_or(false);
+            .unwrap_or(VrfyMode::Disable);
     }
 
     pub async fn eval_rcpt_params(&mut self) {
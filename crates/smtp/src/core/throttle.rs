@@ -6,19 +6,22 @@
 
 use super::Session;
 use common::{
-    KV_RATE_LIMIT_SMTP, ThrottleKey, config::smtp::*, expr::functions::ResolveVariable,
-    network::SessionStream,
+    KV_RATE_LIMIT_SMTP, ThrottleKey,
+    config::smtp::*,
+    expr::functions::ResolveVariable,
+    network::{SessionStream, ip_to_network_bytes},
 };
 use queue::QueueQuota;
 use registry::schema::{enums::ExpressionVariable, prelude::Property, structs::Rate};
+use std::net::IpAddr;
 use trc::SmtpEvent;
 
 pub trait NewKey: Sized {
-    fn new_key(&self, e: &impl ResolveVariable, context: &str) -> ThrottleKey;
+    fn new_key(&self, e: &impl ResolveVariable, context: &str, ipv6_prefix: u8) -> ThrottleKey;
 }
 
 impl NewKey for QueueQuota {
-    fn new_key(&self, e: &impl ResolveVariable, _: &str) -> ThrottleKey {
+    fn new_key(&self, e: &impl ResolveVariable, _: &str, _: u8) -> ThrottleKey {
         let mut hasher = blake3::Hasher::new();
 
         if (self.keys & THROTTLE_RCPT) != 0 {
@@ -75,7 +78,7 @@ impl NewKey for QueueQuota {
 }
 
 impl NewKey for QueueRateLimiter {
-    fn new_key(&self, e: &impl ResolveVariable, context: &str) -> ThrottleKey {
+    fn new_key(&self, e: &impl ResolveVariable, context: &str, ipv6_prefix: u8) -> ThrottleKey {
         let mut hasher = blake3::Hasher::new();
 
         if (self.keys & THROTTLE_RCPT) != 0 {
@@ -145,11 +148,12 @@ impl NewKey for QueueRateLimiter {
             );
         }
         if (self.keys & THROTTLE_REMOTE_IP) != 0 {
-            hasher.update(
-                e.resolve_variable(ExpressionVariable::RemoteIp)
-                    .to_string()
-                    .as_bytes(),
-            );
+            // IPv6 addresses are grouped by prefix
+            let remote_ip = e.resolve_variable(ExpressionVariable::RemoteIp).to_string();
+            match remote_ip.parse::<IpAddr>() {
+                Ok(ip) => hasher.update(&ip_to_network_bytes(&ip, ipv6_prefix)),
+                Err(_) => hasher.update(remote_ip.as_bytes()),
+            };
         }
         if (self.keys & THROTTLE_LOCAL_IP) != 0 {
             hasher.update(
@@ -170,6 +174,11 @@ impl NewKey for QueueRateLimiter {
 
 impl<T: SessionStream> Session<T> {
     pub async fn is_allowed(&mut self) -> bool {
+        // Dry runs must not consume any rate limits
+        if self.is_dry_run() {
+            return true;
+        }
+
         let throttles = if !self.data.rcpt_to.is_empty() {
             &self.server.core.smtp.queue.inbound_limiters.rcpt
         } else if self.data.mail_from.is_some() {
@@ -200,7 +209,11 @@ impl<T: SessionStream> Session<T> {
                 }
 
                 // Build throttle key
-                let key = t.new_key(self, "inbound");
+                let key = t.new_key(
+                    self,
+                    "inbound",
+                    self.server.core.network.security.ipv6_prefix,
+                );
 
                 // Check rate
                 match self
@@ -5,12 +5,16 @@
  */
 
 use crate::core::Session;
-use common::{auth::AuthRequest, network::SessionStream};
+use common::{
+    auth::{AuthRequest, client_cert::ClientCertificate},
+    network::SessionStream,
+};
 use directory::Credentials;
+use email::identity::delegation::IdentityDelegation;
 use mail_parser::decoders::base64::base64_decode;
-use registry::schema::enums::Permission;
+use registry::schema::enums::{NetworkListenerProtocol, Permission};
 use smtp_proto::{AUTH_LOGIN, AUTH_OAUTHBEARER, AUTH_PLAIN, AUTH_XOAUTH2, IntoString};
-use trc::AuthEvent;
+use trc::{AuthEvent, SmtpEvent};
 
 pub struct SaslToken {
     mechanism: u64,
@@ -126,6 +130,19 @@ impl<T: SessionStream> Session<T> {
 
         match result {
             Ok(account_info) => {
+                self.server
+                    .record_login(
+                        account_info.account_id,
+                        NetworkListenerProtocol::Smtp,
+                        self.data.remote_ip,
+                        None,
+                    )
+                    .await;
+                self.server
+                    .inner
+                    .data
+                    .sessions
+                    .set_account(self.data.session_id, account_info.account_id);
                 self.data.authenticated_as = account_info.into();
                 self.eval_post_auth_params().await;
                 self.write(b"235 2.7.0 Authentication succeeded.\r\n")
@@ -182,6 +199,72 @@ impl<T: SessionStream> Session<T> {
         Ok(false)
     }
 
+    // Clients presenting a certificate issued by one of the trusted authorities
+    // of the listener are authenticated as the account owning its address.
+    pub async fn authenticate_client_certificate(&mut self) {
+        self.data.tls_client = self
+            .stream
+            .tls_peer_certificate()
+            .and_then(ClientCertificate::parse);
+        let Some(cert) = self.data.tls_client.as_ref().filter(|_| {
+            self.instance.acceptor.is_tls_client_auth() && self.data.authenticated_as.is_none()
+        }) else {
+            return;
+        };
+
+        let result = match self
+            .server
+            .authenticate_client_certificate(cert, self.data.remote_ip)
+            .await
+            .and_then(|access_token| {
+                access_token
+                    .map(|access_token| access_token.assert_has_permission(Permission::EmailSend))
+                    .transpose()
+            }) {
+            Ok(Some(access_token)) => self
+                .server
+                .account_info(access_token.account_id())
+                .await
+                .map(Some),
+            Ok(None) => Ok(None),
+            Err(err) => Err(err),
+        };
+
+        match result {
+            Ok(Some(account_info)) => {
+                trc::event!(
+                    Smtp(SmtpEvent::ClientCertificateAuthenticated),
+                    SpanId = self.data.session_id,
+                    AccountName = account_info.name().to_string(),
+                    Details = cert.subject.clone(),
+                );
+
+                self.server
+                    .record_login(
+                        account_info.account_id,
+                        NetworkListenerProtocol::Smtp,
+                        self.data.remote_ip,
+                        None,
+                    )
+                    .await;
+                self.server
+                    .inner
+                    .data
+                    .sessions
+                    .set_account(self.data.session_id, account_info.account_id);
+                self.data.authenticated_as = account_info.into();
+                self.eval_post_auth_params().await;
+            }
+            Ok(None) => {}
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .details("Client certificate authentication failed")
+                );
+            }
+        }
+    }
+
     pub async fn auth_error(&mut self, response: &[u8]) -> Result<bool, ()> {
         tokio::time::sleep(self.params.auth_errors_wait).await;
         self.data.auth_errors += 1;
@@ -214,4 +297,56 @@ impl<T: SessionStream> Session<T> {
     pub fn authenticated_emails(&self) -> &[String] {
         self.data.authenticated_as.as_ref().unwrap().addresses()
     }
+
+    pub async fn is_allowed_sender(&self, address: &str) -> trc::Result<bool> {
+        let Some(authenticated_as) = &self.data.authenticated_as else {
+            return Ok(true);
+        };
+        if authenticated_as.name() == address
+            || authenticated_as.addresses().iter().any(|e| e == address)
+        {
+            Ok(true)
+        } else {
+            // Other accounts may delegate one of their identities to this account
+            self.server
+                .is_delegated_sender(authenticated_as.account_id, address)
+                .await
+        }
+    }
+
+    pub fn primary_sender(&self) -> Option<&str> {
+        self.data
+            .authenticated_as
+            .as_ref()
+            .and_then(|authenticated_as| authenticated_as.addresses().first())
+            .map(|address| address.as_str())
+    }
+
+    pub async fn is_tenant_domain(&self, domain: &str) -> trc::Result<bool> {
+        // Only accounts that belong to a tenant are restricted
+        let Some(tenant_id) = self
+            .data
+            .authenticated_as
+            .as_ref()
+            .and_then(|authenticated_as| authenticated_as.tenant_id())
+        else {
+            return Ok(true);
+        };
+        let config = &self.server.core.smtp.session.auth;
+        if domain.is_empty()
+            || config.tenant_domain_exemptions.contains(domain)
+            || !self
+                .server
+                .eval_if(&config.must_match_tenant_domain, self, self.data.session_id)
+                .await
+                .unwrap_or(true)
+        {
+            return Ok(true);
+        }
+
+        self.server
+            .domain(domain)
+            .await
+            .map(|domain| domain.is_some_and(|domain| domain.id_tenant == Some(tenant_id)))
+    }
 }
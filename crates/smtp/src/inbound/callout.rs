@@ -0,0 +1,301 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    core::Session,
+    outbound::{
+        NextHop,
+        client::{SmtpClient, StartTlsResult},
+        error::{AssertReply, ClientResult},
+        lookup::{DnsLookup, ToNextHop},
+    },
+};
+use common::{KV_SMTP_CALLOUT, config::smtp::queue::RoutingStrategy, network::SessionStream};
+use smtp_proto::{EhloResponse, Response};
+use std::{net::SocketAddr, sync::Arc};
+use store::dispatch::lookup::KeyValue;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
+use trc::SmtpEvent;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CalloutResult {
+    Valid,
+    Invalid,
+    Unknown,
+}
+
+const CALLOUT_VALID: u8 = b'+';
+const CALLOUT_INVALID: u8 = b'-';
+
+impl<T: SessionStream> Session<T> {
+    // Probes the destination server with MAIL FROM:<> and RCPT TO without sending
+    // any data, caching the outcome so repeated recipients are not verified again.
+    pub async fn rcpt_callout(&self, address: &str) -> CalloutResult {
+        let config = &self.server.core.smtp.session.rcpt;
+        let store = self.server.in_memory_store();
+
+        // Check cached results
+        for (status, result) in [
+            (CALLOUT_VALID, CalloutResult::Valid),
+            (CALLOUT_INVALID, CalloutResult::Invalid),
+        ] {
+            match store
+                .key_exists(KeyValue::<()>::build_key(
+                    KV_SMTP_CALLOUT,
+                    callout_key(status, address),
+                ))
+                .await
+            {
+                Ok(true) => return result,
+                Ok(false) => {}
+                Err(err) => {
+                    trc::error!(
+                        err.span_id(self.data.session_id)
+                            .caused_by(trc::location!())
+                            .details("Failed to read callout cache.")
+                    );
+                    return CalloutResult::Unknown;
+                }
+            }
+        }
+
+        let result =
+            match tokio::time::timeout(config.callout_timeout, self.callout_probe(address)).await {
+                Ok(result) => result,
+                Err(_) => {
+                    trc::event!(
+                        Smtp(SmtpEvent::CalloutFailed),
+                        SpanId = self.data.session_id,
+                        To = address.to_string(),
+                        Reason = "Timed out",
+                    );
+                    CalloutResult::Unknown
+                }
+            };
+
+        // Cache result
+        let (status, expires) = match result {
+            CalloutResult::Valid => (CALLOUT_VALID, config.callout_cache_ttl),
+            CalloutResult::Invalid => (CALLOUT_INVALID, config.callout_negative_cache_ttl),
+            CalloutResult::Unknown => return result,
+        };
+        if let Err(err) = store
+            .key_set(
+                KeyValue::with_prefix(KV_SMTP_CALLOUT, callout_key(status, address), vec![])
+                    .expires(expires.as_secs()),
+            )
+            .await
+        {
+            trc::error!(
+                err.span_id(self.data.session_id)
+                    .caused_by(trc::location!())
+                    .details("Failed to write callout cache.")
+            );
+        }
+
+        result
+    }
+
+    async fn callout_probe(&self, address: &str) -> CalloutResult {
+        let session_id = self.data.session_id;
+        let server = &self.server;
+        let route = server.get_route_or_default(
+            &server
+                .eval_if::<String, _>(&server.core.smtp.queue.route, self, session_id)
+                .await
+                .unwrap_or_else(|| "default".to_string()),
+            session_id,
+        );
+
+        // Obtain next hops
+        let domain = address
+            .rsplit_once('@')
+            .map_or(address, |(_, domain)| domain);
+        let mx_list;
+        let remote_hosts = match route {
+            RoutingStrategy::Local | RoutingStrategy::LmtpExternal(_) => {
+                return CalloutResult::Unknown;
+            }
+            RoutingStrategy::Mx(mx_config) => {
+                mx_list = match server
+                    .core
+                    .smtp
+                    .resolvers
+                    .dns
+                    .mx_lookup(domain, Some(&server.inner.cache.dns_mx))
+                    .await
+                {
+                    Ok(mx) => mx,
+                    Err(mail_auth::Error::DnsRecordNotFound(_)) => Arc::new([]),
+                    Err(err) => {
+                        trc::event!(
+                            Smtp(SmtpEvent::CalloutFailed),
+                            SpanId = session_id,
+                            To = address.to_string(),
+                            Domain = domain.to_string(),
+                            Reason = err.to_string(),
+                        );
+                        return CalloutResult::Unknown;
+                    }
+                };
+
+                match mx_list.to_remote_hosts(domain, mx_config) {
+                    Some(remote_hosts) => remote_hosts,
+                    None => return CalloutResult::Invalid,
+                }
+            }
+            RoutingStrategy::Relay(relay_config) => vec![NextHop::Relay(relay_config)],
+        };
+
+        let local_hostname = server.core.network.server_name.as_str();
+        for remote_host in &remote_hosts {
+            let remote_ips = match server.resolve_host(remote_host, self).await {
+                Ok(result) => result.remote_ips,
+                Err(err) => {
+                    trc::event!(
+                        Smtp(SmtpEvent::CalloutFailed),
+                        SpanId = session_id,
+                        To = address.to_string(),
+                        Hostname = remote_host.hostname().to_string(),
+                        Reason = err.to_string(),
+                    );
+                    continue;
+                }
+            };
+
+            for remote_ip in remote_ips {
+                let result = self
+                    .callout_connect(
+                        remote_host,
+                        SocketAddr::new(remote_ip, remote_host.port()),
+                        local_hostname,
+                        address,
+                    )
+                    .await;
+
+                match result {
+                    Ok(Some(result)) => return result,
+                    Ok(None) => {}
+                    Err(err) => {
+                        trc::event!(
+                            Smtp(SmtpEvent::CalloutFailed),
+                            SpanId = session_id,
+                            To = address.to_string(),
+                            Hostname = remote_host.hostname().to_string(),
+                            RemoteIp = remote_ip,
+                            Reason = err.to_string(),
+                        );
+                    }
+                }
+            }
+        }
+
+        CalloutResult::Unknown
+    }
+
+    async fn callout_connect(
+        &self,
+        remote_host: &NextHop<'_>,
+        remote_addr: SocketAddr,
+        local_hostname: &str,
+        address: &str,
+    ) -> ClientResult<Option<CalloutResult>> {
+        let hostname = remote_host.hostname();
+        let connectors = &self.server.inner.data.smtp_connectors;
+        let tls_connector = if remote_host.allow_invalid_certs() {
+            &connectors.dummy_verify
+        } else {
+            &connectors.pki_verify
+        };
+        let is_smtp = remote_host.is_smtp();
+        let client = SmtpClient::connect(
+            remote_addr,
+            self.server.core.smtp.session.rcpt.callout_timeout,
+            self.data.session_id,
+        )
+        .await?;
+
+        if remote_host.implicit_tls() {
+            let mut client = client.into_tls(tls_connector, hostname).await?;
+            client.read().await?.assert_code(220)?;
+            let capabilities = callout_ehlo(&mut client, local_hostname, is_smtp).await?;
+            return callout_rcpt(client, remote_host, &capabilities, address).await;
+        }
+
+        let mut client = client;
+        client.read().await?.assert_code(220)?;
+        let capabilities = callout_ehlo(&mut client, local_hostname, is_smtp).await?;
+        match client
+            .try_start_tls(tls_connector, hostname, &capabilities)
+            .await
+        {
+            StartTlsResult::Success {
+                smtp_client: mut client,
+            } => {
+                let capabilities = callout_ehlo(&mut client, local_hostname, is_smtp).await?;
+                callout_rcpt(client, remote_host, &capabilities, address).await
+            }
+            StartTlsResult::Unavailable {
+                smtp_client: client,
+                ..
+            } => callout_rcpt(client, remote_host, &capabilities, address).await,
+            StartTlsResult::Error { error } => Err(error),
+        }
+    }
+}
+
+async fn callout_ehlo<T: AsyncRead + AsyncWrite + Unpin>(
+    client: &mut SmtpClient<T>,
+    local_hostname: &str,
+    is_smtp: bool,
+) -> ClientResult<EhloResponse<String>> {
+    let cmd = if is_smtp {
+        format!("EHLO {local_hostname}\r\n")
+    } else {
+        format!("LHLO {local_hostname}\r\n")
+    };
+    client.stream.write_all(cmd.as_bytes()).await?;
+    client.stream.flush().await?;
+    client.read_ehlo().await
+}
+
+async fn callout_rcpt<T: AsyncRead + AsyncWrite + Unpin>(
+    mut client: SmtpClient<T>,
+    remote_host: &NextHop<'_>,
+    capabilities: &EhloResponse<String>,
+    address: &str,
+) -> ClientResult<Option<CalloutResult>> {
+    if let Some(credentials) = remote_host.credentials() {
+        client.authenticate(credentials, capabilities).await?;
+    }
+
+    let response = client.cmd(b"MAIL FROM:<>\r\n").await?;
+    let result = if response.is_positive_completion() {
+        callout_result(&client.cmd(format!("RCPT TO:<{address}>\r\n")).await?)
+    } else {
+        None
+    };
+    client.quit().await;
+
+    Ok(result)
+}
+
+// Only permanent "no such user" replies are treated as a rejection, any other
+// failure falls back to accepting the recipient and queueing the message.
+fn callout_result(response: &Response<String>) -> Option<CalloutResult> {
+    match response.code() {
+        200..=299 => Some(CalloutResult::Valid),
+        550..=553 => Some(CalloutResult::Invalid),
+        _ => None,
+    }
+}
+
+fn callout_key(status: u8, address: &str) -> Vec<u8> {
+    let mut key = Vec::with_capacity(address.len() + 1);
+    key.push(status);
+    key.extend_from_slice(address.as_bytes());
+    key
+}
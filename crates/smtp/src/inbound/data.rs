@@ -4,15 +4,22 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::{AuthResult, DkimSign};
+use super::{
+    AuthResult, DkimSign,
+    dry_run::DryRunDelivery,
+    limits::{MessageStructure, truncate_headers},
+};
 use crate::{
     core::{Session, SessionAddress, State},
     inbound::milter::Modification,
     queue::{
-        self, Message, MessageSource, MessageWrapper, QueueEnvelope, RCPT_SPAM_PAYLOAD,
-        quota::HasQueueQuota,
+        self, LARGE_MESSAGE, Message, MessageSource, MessageWrapper, QueueEnvelope,
+        RCPT_QUARANTINE, RCPT_SPAM_PAYLOAD, quota::HasQueueQuota,
+    },
+    reporting::{
+        analysis::AnalyzeReport,
+        dmarc::{policy_override_str, write_policy_override},
     },
-    reporting::analysis::AnalyzeReport,
     scripts::ScriptResult,
 };
 use common::{
@@ -21,21 +28,30 @@ use common::{
         smtp::{
             auth::VerifyStrategy,
             queue::{QueueExpiry, QueueName},
-            session::Stage,
+            session::{ReceivedHeaderFormat, Stage},
         },
     },
     network::SessionStream,
     psl,
     scripts::ScriptModification,
 };
+use email::{message::delivery::IngestRecipient, sieve::ingest::SieveScriptIngest};
 use mail_auth::{
-    AuthenticatedMessage, AuthenticationResults, DkimResult, DmarcResult, ReceivedSpf,
+    AuthenticatedMessage, AuthenticationResults, DkimResult, DmarcResult, ReceivedSpf, SpfResult,
     common::{crypto::Algorithm, headers::HeaderWriter, verify::VerifySignature},
     dmarc::{self, verify::DmarcParameters},
 };
 use mail_builder::headers::{date::Date, message_id::generate_message_id_header};
-use mail_parser::{MessageParser, parsers::fields::thread::thread_name};
-use registry::schema::structs::Rate;
+use mail_parser::{
+    HeaderName, Message as ParsedMessage, MessageParser, parsers::fields::thread::thread_name,
+};
+use registry::{
+    schema::{
+        enums::{MtaSenderMismatchAction, MtaStructureLimitAction},
+        structs::Rate,
+    },
+    types::EnumImpl,
+};
 use sieve::{SpamStatus, runtime::Variable};
 use smtp_proto::{
     MAIL_BY_RETURN, RCPT_NOTIFY_DELAY, RCPT_NOTIFY_FAILURE, RCPT_NOTIFY_NEVER, RCPT_NOTIFY_SUCCESS,
@@ -44,27 +60,91 @@ use std::{
     borrow::Cow,
     time::{Instant, SystemTime},
 };
-use trc::{SmtpEvent, SpamEvent};
+use trc::{SieveEvent, SmtpEvent, SpamEvent};
 use utils::DomainPart;
 
 impl<T: SessionStream> Session<T> {
     pub async fn queue_message(&mut self) -> Cow<'static, [u8]> {
-        // Parse message
-        let raw_message = std::mem::take(&mut self.data.message);
-        let parsed_message = match MessageParser::new()
-            .parse(&raw_message)
-            .filter(|p| p.headers().iter().any(|h| !h.name.is_other()))
-        {
-            Some(parsed_message) => parsed_message,
-            None => {
+        // Parse message, only the headers of large messages are parsed
+        let mut raw_message = std::mem::take(&mut self.data.message);
+        let dc = &self.server.core.smtp.session.data;
+        let is_large_message = self
+            .server
+            .eval_if::<usize, _>(&dc.large_message_size, self, self.data.session_id)
+            .await
+            .is_some_and(|max_size| max_size > 0 && raw_message.len() > max_size);
+        if is_large_message {
+            trc::event!(
+                Smtp(SmtpEvent::LargeMessage),
+                SpanId = self.data.session_id,
+                Size = raw_message.len(),
+            );
+        }
+        let Some(mut parsed_message) = parse_message(&raw_message, is_large_message) else {
+            trc::event!(
+                Smtp(SmtpEvent::MessageParseFailed),
+                SpanId = self.data.session_id,
+            );
+
+            return (&b"550 5.7.7 Failed to parse message.\r\n"[..]).into();
+        };
+
+        // Enforce header and MIME structure limits
+        let limits = self.structure_limits().await;
+        let exceeded = MessageStructure::new(&parsed_message).exceeded(&limits);
+        let mut exceeded_header = None;
+        if !exceeded.is_empty() {
+            let action = dc.structure_limit_action;
+            for exceeded in &exceeded {
                 trc::event!(
-                    Smtp(SmtpEvent::MessageParseFailed),
+                    Smtp(SmtpEvent::StructureLimitExceeded),
                     SpanId = self.data.session_id,
+                    Details = exceeded.limit.as_str(),
+                    Total = exceeded.value,
+                    Limit = exceeded.max,
+                    Result = action.as_str(),
                 );
+            }
 
-                return (&b"550 5.7.7 Failed to parse message.\r\n"[..]).into();
+            match action {
+                MtaStructureLimitAction::Tag => {
+                    exceeded_header = Some(
+                        exceeded
+                            .iter()
+                            .map(|exceeded| exceeded.limit.as_str())
+                            .collect::<Vec<_>>()
+                            .join(", "),
+                    );
+                }
+                // MIME structures cannot be truncated without corrupting the message
+                MtaStructureLimitAction::Truncate
+                    if exceeded
+                        .iter()
+                        .all(|exceeded| exceeded.limit.is_header_limit()) =>
+                {
+                    let truncated = truncate_headers(&raw_message, &parsed_message, &limits);
+                    drop(parsed_message);
+                    raw_message = truncated;
+                    parsed_message = match parse_message(&raw_message, is_large_message).filter(
+                        |parsed_message| {
+                            MessageStructure::new(parsed_message)
+                                .exceeded(&limits)
+                                .is_empty()
+                        },
+                    ) {
+                        Some(parsed_message) => parsed_message,
+                        None => {
+                            return (&b"550 5.6.0 Message exceeds header or MIME structure limits.\r\n"[..])
+                                .into();
+                        }
+                    };
+                }
+                _ => {
+                    return (&b"550 5.6.0 Message exceeds header or MIME structure limits.\r\n"[..])
+                        .into();
+                }
             }
-        };
+        }
 
         // Authenticate message
         let mut auth_message = AuthenticatedMessage::from_parsed(
@@ -75,7 +155,6 @@ impl<T: SessionStream> Session<T> {
         let has_message_id_header = auth_message.has_message_id_header();
 
         // Loop detection
-        let dc = &self.server.core.smtp.session.data;
         let ac = &self.server.core.smtp.mail_auth;
         let rc = &self.server.core.smtp.report;
         if auth_message.received_headers_count()
@@ -95,17 +174,139 @@ impl<T: SessionStream> Session<T> {
                 .into();
         }
 
+        // Make sure that tenant accounts only use their own domains in the From header
+        if self.is_authenticated() {
+            for address in parsed_message
+                .from()
+                .into_iter()
+                .flat_map(|from| from.iter())
+                .filter_map(|addr| addr.address())
+            {
+                let domain = address.domain_part().to_lowercase();
+                match self.is_tenant_domain(&domain).await {
+                    Ok(true) => (),
+                    Ok(false) => {
+                        trc::event!(
+                            Smtp(SmtpEvent::TenantDomainMismatch),
+                            SpanId = self.data.session_id,
+                            From = address.to_lowercase(),
+                            Domain = domain,
+                        );
+
+                        return (&b"550 5.7.1 From header domain is not authorized for this account.\r\n"[..])
+                            .into();
+                    }
+                    Err(err) => {
+                        trc::error!(
+                            err.span_id(self.data.session_id)
+                                .caused_by(trc::location!())
+                                .details("Failed to verify sender domain.")
+                        );
+
+                        return (&b"451 4.4.3 Unable to verify sender domain at this time.\r\n"[..])
+                            .into();
+                    }
+                }
+            }
+        }
+
+        // Make sure that the From header only contains addresses the account may send from
+        let mut from_rewrite = None;
+        let mut sender_header = None;
+        if self.is_authenticated()
+            && self
+                .server
+                .eval_if(
+                    &self.server.core.smtp.session.auth.must_match_sender,
+                    self,
+                    self.data.session_id,
+                )
+                .await
+                .unwrap_or(true)
+        {
+            let action = self.server.core.smtp.session.auth.sender_mismatch_action;
+            let mut addresses = parsed_message
+                .from()
+                .into_iter()
+                .flat_map(|from| from.iter())
+                .filter_map(|addr| addr.address().map(|address| (addr.name(), address)))
+                .map(|(name, address)| (name, address.to_lowercase()))
+                .collect::<Vec<_>>();
+
+            // Messages accepted with an unauthorized envelope sender are tagged as well
+            if action == MtaSenderMismatchAction::Tag
+                && let Some(mail_from) = &self.data.mail_from
+                && !mail_from.address_lcase.is_empty()
+            {
+                addresses.push((None, mail_from.address_lcase.clone()));
+            }
+
+            for (name, address) in addresses {
+                match self.is_allowed_sender(&address).await {
+                    Ok(true) => (),
+                    Ok(false) => match (action, self.primary_sender()) {
+                        (MtaSenderMismatchAction::Rewrite, Some(new_address)) => {
+                            trc::event!(
+                                Smtp(SmtpEvent::FromHeaderRewritten),
+                                SpanId = self.data.session_id,
+                                Details = address,
+                                From = new_address.to_string(),
+                            );
+
+                            from_rewrite = Some(Modification::ChangeHeader {
+                                index: 1,
+                                name: "From".into(),
+                                value: if let Some(name) = name {
+                                    format!(
+                                        " \"{}\" <{new_address}>",
+                                        name.replace('\\', "\\\\").replace('"', "\\\"")
+                                    )
+                                } else {
+                                    format!(" <{new_address}>")
+                                },
+                            });
+                            break;
+                        }
+                        (MtaSenderMismatchAction::Tag, Some(sender)) => {
+                            if sender_header.is_none() {
+                                trc::event!(
+                                    Smtp(SmtpEvent::SenderMismatchTagged),
+                                    SpanId = self.data.session_id,
+                                    From = address,
+                                    Details = sender.to_string(),
+                                );
+
+                                sender_header = Some(sender.to_string());
+                            }
+                        }
+                        _ => {
+                            trc::event!(
+                                Smtp(SmtpEvent::FromHeaderUnauthorized),
+                                SpanId = self.data.session_id,
+                                From = address,
+                            );
+
+                            return (&b"550 5.7.1 From header address is not authorized for this account.\r\n"[..])
+                                .into();
+                        }
+                    },
+                    Err(err) => {
+                        trc::error!(
+                            err.span_id(self.data.session_id)
+                                .caused_by(trc::location!())
+                                .details("Failed to verify sender address.")
+                        );
+
+                        return (&b"451 4.4.3 Unable to verify sender address at this time.\r\n"[..])
+                            .into();
+                    }
+                }
+            }
+        }
+
         // Verify DKIM
-        let dkim = self
-            .server
-            .eval_if(&ac.dkim.verify, self, self.data.session_id)
-            .await
-            .unwrap_or(VerifyStrategy::Relaxed);
-        let dmarc = self
-            .server
-            .eval_if(&ac.dmarc.verify, self, self.data.session_id)
-            .await
-            .unwrap_or(VerifyStrategy::Relaxed);
+        let dkim = self.eval_verify_strategy(&ac.dkim.verify).await;
+        let dmarc = self.eval_verify_strategy(&ac.dmarc.verify).await;
         let dkim_output = if dkim.verify() || dmarc.verify() {
             // Remove insecure DKIM signatures before verification
             for header in &mut auth_message.dkim_headers {
@@ -136,10 +337,11 @@ impl<T: SessionStream> Session<T> {
             let rejected = strict && !pass;
 
             // Send reports for failed signatures
-            if let Some(rate) = self
-                .server
-                .eval_if::<Rate, _>(&rc.dkim.send, self, self.data.session_id)
-                .await
+            if !self.is_dry_run()
+                && let Some(rate) = self
+                    .server
+                    .eval_if::<Rate, _>(&rc.dkim.send, self, self.data.session_id)
+                    .await
             {
                 for output in &dkim_output {
                     if let Some(rcpt) = output.failure_report_addr() {
@@ -179,11 +381,7 @@ impl<T: SessionStream> Session<T> {
         };
 
         // Verify ARC
-        let arc = self
-            .server
-            .eval_if(&ac.arc.verify, self, self.data.session_id)
-            .await
-            .unwrap_or(VerifyStrategy::Relaxed);
+        let arc = self.eval_verify_strategy(&ac.arc.verify).await;
         let arc_output = if arc.verify() {
             let time = Instant::now();
             let arc_output = self
@@ -249,9 +447,37 @@ impl<T: SessionStream> Session<T> {
         }
 
         // Verify DMARC
-        let is_report = !self.is_authenticated() && self.is_report();
-        let (dmarc_result, dmarc_policy) = match &self.data.spf_mail_from {
-            Some(spf_output) if dmarc.verify() => {
+        let is_report = !self.is_authenticated() && !self.is_dry_run() && self.is_report();
+        let mut dmarc_override = None;
+        let (dmarc_result, dmarc_policy) = match (
+            &self.data.spf_mail_from,
+            self.data
+                .dry_run
+                .as_ref()
+                .and_then(|dry_run| dry_run.dmarc.clone()),
+        ) {
+            (_, Some((dmarc_result, dmarc_policy))) => {
+                // Apply the DMARC result provided to the dry run, DNS lookups might be disabled
+                let strict = self
+                    .server
+                    .eval_if(&ac.dmarc.verify, self, self.data.session_id)
+                    .await
+                    .unwrap_or(VerifyStrategy::Relaxed)
+                    .is_strict();
+                if strict
+                    && dmarc_policy == dmarc::Policy::Reject
+                    && dmarc_result != DmarcResult::Pass
+                {
+                    return if matches!(dmarc_result, DmarcResult::TempError(_)) {
+                        (&b"451 4.7.1 Email temporarily rejected per DMARC policy.\r\n"[..]).into()
+                    } else {
+                        (&b"550 5.7.1 Email rejected per DMARC policy.\r\n"[..]).into()
+                    };
+                }
+
+                (dmarc_result.into(), dmarc_policy.into())
+            }
+            (Some(spf_output), None) if dmarc.verify() => {
                 let time = Instant::now();
                 let dmarc_output =
                     self.server
@@ -279,7 +505,39 @@ impl<T: SessionStream> Session<T> {
                 let pass = matches!(dmarc_output.spf_result(), DmarcResult::Pass)
                     || matches!(dmarc_output.dkim_result(), DmarcResult::Pass);
                 let strict = dmarc.is_strict();
-                let rejected = strict && dmarc_output.policy() == dmarc::Policy::Reject && !pass;
+
+                // Accept failures from known forwarders and mailing lists
+                dmarc_override = if !pass && dmarc_output.policy() != dmarc::Policy::None {
+                    ac.dmarc
+                        .find_override(
+                            matches!(spf_output.result(), SpfResult::Pass)
+                                .then(|| spf_output.domain())
+                                .into_iter()
+                                .chain(dkim_output.iter().filter_map(|output| {
+                                    output
+                                        .signature()
+                                        .filter(|_| matches!(output.result(), DkimResult::Pass))
+                                        .map(|signature| signature.domain())
+                                })),
+                            parsed_message
+                                .headers()
+                                .iter()
+                                .find(|header| header.name == HeaderName::ListId)
+                                .and_then(|header| {
+                                    parsed_message.raw_message().get(
+                                        header.offset_start as usize..header.offset_end as usize,
+                                    )
+                                })
+                                .and_then(|raw| std::str::from_utf8(raw).ok()),
+                        )
+                        .map(|dmarc_override| dmarc_override.reason.clone())
+                } else {
+                    None
+                };
+                let rejected = strict
+                    && dmarc_output.policy() == dmarc::Policy::Reject
+                    && !pass
+                    && dmarc_override.is_none();
                 let is_temp_fail = rejected
                     && matches!(dmarc_output.spf_result(), DmarcResult::TempError(_))
                     || matches!(dmarc_output.dkim_result(), DmarcResult::TempError(_));
@@ -311,12 +569,29 @@ impl<T: SessionStream> Session<T> {
                     Elapsed = time.elapsed(),
                 );
 
+                // The local policy takes precedence over the published one
+                let dmarc_policy = if let Some(dmarc_override) = &dmarc_override {
+                    trc::event!(
+                        Smtp(SmtpEvent::DmarcOverride),
+                        SpanId = self.data.session_id,
+                        Domain = dmarc_output.domain().to_string(),
+                        Policy = dmarc_policy.to_string(),
+                        Reason = policy_override_str(dmarc_override),
+                        Details = dmarc_override.comment.clone(),
+                    );
+
+                    dmarc::Policy::None
+                } else {
+                    dmarc_policy
+                };
+
                 // Send DMARC report
-                if dmarc_output.requested_reports() && !is_report {
+                if dmarc_output.requested_reports() && !is_report && !self.is_dry_run() {
                     self.send_dmarc_report(
                         &auth_message,
                         &auth_results,
                         rejected,
+                        dmarc_override.as_ref(),
                         dmarc_output,
                         &dkim_output,
                         &arc_output,
@@ -383,7 +658,26 @@ impl<T: SessionStream> Session<T> {
             .await
             .unwrap_or(true)
         {
-            self.write_received(&mut headers, message_id)
+            let format = self
+                .server
+                .eval_if(&dc.received_format, self, self.data.session_id)
+                .await
+                .unwrap_or_default();
+            self.write_received(&mut headers, message_id, format)
+        }
+
+        // Flag messages that exceeded the structure limits
+        if let Some(exceeded) = exceeded_header {
+            headers.extend_from_slice(b"X-Limits-Exceeded: ");
+            headers.extend_from_slice(exceeded.as_bytes());
+            headers.extend_from_slice(b"\r\n");
+        }
+
+        // Identify the actual submitter of messages sent on behalf of another address
+        if let Some(sender) = sender_header {
+            headers.extend_from_slice(b"Sender: <");
+            headers.extend_from_slice(sender.as_bytes());
+            headers.extend_from_slice(b">\r\n");
         }
 
         // Add authentication results header
@@ -394,6 +688,15 @@ impl<T: SessionStream> Session<T> {
             .unwrap_or(true)
         {
             auth_results.write_header(&mut headers);
+
+            // Record the override reason next to the DMARC result
+            if let Some(dmarc_override) = &dmarc_override
+                && headers.ends_with(b"\r\n")
+            {
+                headers.truncate(headers.len() - 2);
+                write_policy_override(&mut headers, dmarc_override);
+                headers.extend_from_slice(b"\r\n");
+            }
         }
 
         // Add Received-SPF header
@@ -437,24 +740,44 @@ impl<T: SessionStream> Session<T> {
                 SpamFilterAction::Allow(score) => {
                     // Add headers
                     headers.extend_from_slice(score.headers.as_bytes());
-                    train_spam = score.train_spam.map(|is_spam| {
-                        (
-                            is_spam,
-                            thread_name(parsed_message.subject().unwrap_or_default()).to_string(),
-                        )
-                    });
+
+                    // Large messages are classified on their headers only, skip training
+                    train_spam = score
+                        .train_spam
+                        .filter(|_| !is_large_message)
+                        .map(|is_spam| {
+                            (
+                                is_spam,
+                                thread_name(parsed_message.subject().unwrap_or_default())
+                                    .to_string(),
+                            )
+                        });
                     spam_status = Some(if score.is_spam {
                         SpamStatus::Spam
                     } else {
                         SpamStatus::Ham
                     });
+                    if let Some(dry_run) = &mut self.data.dry_run {
+                        dry_run.spam_verdict = Some(if score.is_spam { "spam" } else { "ham" });
+                        dry_run.spam_score = Some(score.score);
+                    }
 
                     // Add scores for local recipients
+                    let quarantine = self
+                        .server
+                        .core
+                        .spam
+                        .quarantine
+                        .as_ref()
+                        .is_some_and(|config| score.score >= config.threshold);
                     for (is_spam, recipient) in
                         score.results.into_iter().zip(self.data.rcpt_to.iter_mut())
                     {
                         if is_spam {
                             recipient.flags |= RCPT_SPAM_PAYLOAD;
+                            if quarantine {
+                                recipient.flags |= RCPT_QUARANTINE;
+                            }
                         }
                     }
                 }
@@ -466,6 +789,9 @@ impl<T: SessionStream> Session<T> {
                         Result = "discard",
                         Reason = "Message discarded due to excessive spam score.",
                     );
+                    if let Some(dry_run) = &mut self.data.dry_run {
+                        dry_run.spam_verdict = Some("discard");
+                    }
 
                     self.data.messages_sent += 1;
                     return (b"250 2.0.0 Message queued for delivery.\r\n"[..]).into();
@@ -478,6 +804,9 @@ impl<T: SessionStream> Session<T> {
                         Result = "reject",
                         Reason = "Message rejected due to excessive spam score.",
                     );
+                    if let Some(dry_run) = &mut self.data.dry_run {
+                        dry_run.spam_verdict = Some("reject");
+                    }
 
                     self.data.messages_sent += 1;
                     return (b"550 5.7.1 Message rejected due to excessive spam score.\r\n"[..])
@@ -489,10 +818,15 @@ impl<T: SessionStream> Session<T> {
 
         // Run Milter filters
         let mut modifications = Vec::new();
-        match self
+        let result = self
             .run_milters(Stage::Data, (&auth_message).into(), message_id.into())
-            .await
-        {
+            .await;
+        self.dry_run_filter(
+            "milter",
+            !self.server.core.smtp.session.milters.is_empty(),
+            &result,
+        );
+        match result {
             Ok(modifications_) => {
                 if !modifications_.is_empty() {
                     modifications = modifications_;
@@ -504,10 +838,15 @@ impl<T: SessionStream> Session<T> {
         };
 
         // Run MTA Hooks
-        match self
+        let result = self
             .run_mta_hooks(Stage::Data, (&auth_message).into(), message_id.into())
-            .await
-        {
+            .await;
+        self.dry_run_filter(
+            "mtaHook",
+            !self.server.core.smtp.session.hooks.is_empty(),
+            &result,
+        );
+        match result {
             Ok(modifications_) => {
                 if !modifications_.is_empty() {
                     modifications.retain(|m| !matches!(m, Modification::ReplaceBody { .. }));
@@ -520,6 +859,18 @@ impl<T: SessionStream> Session<T> {
         };
 
         // Apply modifications
+        modifications.extend(from_rewrite);
+
+        // Remove trace headers that disclose details about the submitter
+        if !dc.trace_headers.is_empty()
+            && self
+                .server
+                .eval_if(&dc.scrub_trace_headers, self, self.data.session_id)
+                .await
+                .unwrap_or(false)
+        {
+            modifications.extend(scrub_trace_headers(&auth_message, &dc.trace_headers));
+        }
         let mut edited_message = if !modifications.is_empty() {
             self.data
                 .apply_milter_modifications(modifications, &auth_message)
@@ -591,7 +942,11 @@ impl<T: SessionStream> Session<T> {
                 )
                 .with_message(parsed_message);
 
-            let modifications = match self.run_script(script_id, script.clone(), params).await {
+            let result = self.run_script(script_id, script.clone(), params).await;
+            if let Some(dry_run) = &mut self.data.dry_run {
+                dry_run.sieve_action = Some(result.as_str());
+            }
+            let modifications = match result {
                 ScriptResult::Accept { modifications } => modifications,
                 ScriptResult::Replace {
                     message,
@@ -626,14 +981,48 @@ impl<T: SessionStream> Session<T> {
             }
         }
 
+        // Reject messages that the recipients' own Sieve scripts would refuse
+        if self
+            .server
+            .eval_if(&dc.user_script_reject, self, self.data.session_id)
+            .await
+            .unwrap_or(false)
+        {
+            let mut message = Vec::with_capacity(headers.len() + raw_message.len());
+            message.extend_from_slice(&headers);
+            message.extend_from_slice(edited_message.as_deref().unwrap_or(raw_message.as_slice()));
+
+            if let Some(reason) = self.user_script_reject_reason(&message).await {
+                self.data.messages_sent += 1;
+                return format!("550 5.7.1 {}\r\n", reason.replace(['\r', '\n'], " ").trim())
+                    .into_bytes()
+                    .into();
+            }
+        }
+
+        // Mailing lists with a policy receive their own copy of the message
+        let mut rcpt_to = std::mem::take(&mut self.data.rcpt_to);
+        let mut rcpt_lists = std::mem::take(&mut self.data.rcpt_lists);
+        if self.data.dry_run.is_none() {
+            rcpt_to.retain(|rcpt| {
+                !rcpt_lists
+                    .iter()
+                    .any(|(address, _, _)| address == &rcpt.address_lcase)
+            });
+            rcpt_lists.sort_unstable_by_key(|(_, list, is_bounce)| (list.id, *is_bounce));
+            rcpt_lists.dedup_by_key(|(_, list, is_bounce)| (list.id, *is_bounce));
+        } else {
+            rcpt_lists.clear();
+        }
+
         // Build message
         let mail_from = self.data.mail_from.clone().unwrap();
-        let rcpt_to = std::mem::take(&mut self.data.rcpt_to);
         let mut message = self
             .build_message(mail_from, rcpt_to, message_id, self.data.session_id)
             .await;
 
         // Add Return-Path
+        let return_path_start = headers.len();
         if self
             .server
             .eval_if(&dc.add_return_path, self, self.data.session_id)
@@ -644,6 +1033,7 @@ impl<T: SessionStream> Session<T> {
             headers.extend_from_slice(message.message.return_path.as_bytes());
             headers.extend_from_slice(b">\r\n");
         }
+        let return_path_end = headers.len();
 
         // Add any missing headers
         if !has_date_header
@@ -669,8 +1059,27 @@ impl<T: SessionStream> Session<T> {
             headers.extend_from_slice(b"\r\n");
         }
 
-        // DKIM sign
+        // Distribute mailing list posts
         let raw_message = edited_message.as_deref().unwrap_or(raw_message.as_slice());
+        if !rcpt_lists.is_empty() {
+            // List posts have their own envelope sender
+            let mut list_headers = headers[..return_path_start].to_vec();
+            list_headers.extend_from_slice(&headers[return_path_end..]);
+            for (_, list, is_bounce) in &rcpt_lists {
+                if *is_bounce {
+                    self.receive_list_bounce(list, &headers, raw_message).await;
+                } else {
+                    self.distribute_list_post(list, &list_headers, raw_message)
+                        .await;
+                }
+            }
+        }
+        if !rcpt_lists.is_empty() && message.message.recipients.is_empty() {
+            self.data.messages_sent += 1;
+            return (b"250 2.0.0 Message accepted for delivery to mailing list.\r\n"[..]).into();
+        }
+
+        // DKIM sign
         if let Some(sign_with_domain) = self
             .server
             .eval_if::<String, _>(&ac.dkim.sign, self, self.data.session_id)
@@ -705,6 +1114,31 @@ impl<T: SessionStream> Session<T> {
 
         // Update size
         message.message.size = (raw_message.len() + headers.len()) as u64;
+        if is_large_message {
+            message.message.flags |= LARGE_MESSAGE;
+        }
+
+        // Dry runs stop right before the message is queued
+        if self.is_dry_run() {
+            let mut full_message = Vec::with_capacity(headers.len() + raw_message.len());
+            full_message.extend_from_slice(&headers);
+            full_message.extend_from_slice(raw_message);
+            let mut recipients = Vec::with_capacity(message.message.recipients.len());
+            for rcpt in &message.message.recipients {
+                recipients.push(
+                    self.dry_run_delivery(rcpt, &message.message.return_path, &full_message)
+                        .await,
+                );
+            }
+
+            if let Some(dry_run) = &mut self.data.dry_run {
+                dry_run.headers = headers;
+                dry_run.recipients = recipients;
+                dry_run.size = message.message.size;
+            }
+            self.data.messages_sent += 1;
+            return (b"250 2.0.0 Message would be queued (dry run).\r\n"[..]).into();
+        }
 
         // Verify queue quota
         if self.server.has_quota(&mut message).await {
@@ -733,6 +1167,7 @@ impl<T: SessionStream> Session<T> {
             } else {
                 MessageSource::Authenticated
             };
+            let size = message.message.size;
             if message
                 .queue(
                     Some(&headers),
@@ -745,6 +1180,14 @@ impl<T: SessionStream> Session<T> {
             {
                 self.state = State::Accepted(queue_id);
                 self.data.messages_sent += 1;
+                if let Some(tenant_id) = self
+                    .data
+                    .authenticated_as
+                    .as_ref()
+                    .and_then(|account| account.tenant_id())
+                {
+                    self.server.inner.data.usage.record_sent(tenant_id, size);
+                }
                 format!("250 2.0.0 Message queued with id {queue_id:x}.\r\n")
                     .into_bytes()
                     .into()
@@ -891,6 +1334,133 @@ impl<T: SessionStream> Session<T> {
         }
     }
 
+    // Messages are only rejected when every recipient's script rejects them, otherwise
+    // they are accepted and the rejecting recipients bounce the message on delivery.
+    async fn user_script_reject_reason(&self, raw_message: &[u8]) -> Option<String> {
+        let mail_from = self.data.mail_from.as_ref()?;
+        let mut reject_reason = None;
+
+        for rcpt in &self.data.rcpt_to {
+            let result = match self
+                .server
+                .account_id_from_email(&rcpt.address_lcase, false)
+                .await
+            {
+                Ok(Some(account_id)) => {
+                    self.server
+                        .sieve_script_reject_reason(
+                            account_id,
+                            raw_message,
+                            &mail_from.address,
+                            &IngestRecipient {
+                                address: rcpt.address_lcase.clone(),
+                                orcpt: rcpt.dsn_info.clone(),
+                                is_spam: (rcpt.flags & RCPT_SPAM_PAYLOAD) != 0,
+                                is_quarantined: (rcpt.flags & RCPT_QUARANTINE) != 0,
+                            },
+                            self.data.session_id,
+                        )
+                        .await
+                }
+                Ok(None) => Ok(None),
+                Err(err) => Err(err),
+            };
+
+            match result {
+                Ok(Some(reason)) => {
+                    trc::event!(
+                        Sieve(SieveEvent::ActionReject),
+                        SpanId = self.data.session_id,
+                        To = rcpt.address_lcase.clone(),
+                        Reason = reason.clone(),
+                    );
+
+                    reject_reason.get_or_insert(reason);
+                }
+                Ok(None) => return None,
+                Err(err) => {
+                    trc::error!(
+                        err.span_id(self.data.session_id)
+                            .caused_by(trc::location!())
+                            .details("Failed to evaluate recipient Sieve script.")
+                    );
+                    return None;
+                }
+            }
+        }
+
+        reject_reason
+    }
+
+    // Evaluates where a local recipient's copy would be filed, without delivering it
+    async fn dry_run_delivery(
+        &self,
+        rcpt: &queue::Recipient,
+        return_path: &str,
+        raw_message: &[u8],
+    ) -> DryRunDelivery {
+        let mut delivery = DryRunDelivery {
+            address: rcpt.address().to_string(),
+            is_spam: (rcpt.flags & RCPT_SPAM_PAYLOAD) != 0,
+            is_quarantined: (rcpt.flags & RCPT_QUARANTINE) != 0
+                && self.server.core.spam.quarantine.is_some(),
+            ..Default::default()
+        };
+
+        let account_id = match self
+            .server
+            .account_id_from_email(&delivery.address, false)
+            .await
+        {
+            Ok(Some(account_id)) => account_id,
+            Ok(None) => return delivery,
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to resolve dry run recipient.")
+                );
+                return delivery;
+            }
+        };
+        delivery.is_local = true;
+        if delivery.is_quarantined {
+            return delivery;
+        }
+
+        match self
+            .server
+            .sieve_script_evaluate(
+                account_id,
+                raw_message,
+                return_path,
+                &IngestRecipient {
+                    address: delivery.address.clone(),
+                    orcpt: rcpt.orcpt.as_ref().map(|orcpt| orcpt.to_string()),
+                    is_spam: delivery.is_spam,
+                    is_quarantined: false,
+                },
+                self.data.session_id,
+            )
+            .await
+        {
+            Ok(evaluation) => {
+                delivery.mailboxes = evaluation.mailboxes;
+                delivery.redirects = evaluation.redirects;
+                delivery.reject_reason = evaluation.reject_reason;
+            }
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to evaluate recipient Sieve script.")
+                );
+            }
+        }
+
+        delivery
+    }
+
     pub async fn can_send_data(&mut self) -> Result<bool, ()> {
         if !self.data.rcpt_to.is_empty() {
             if self.data.messages_sent
@@ -927,8 +1497,41 @@ impl<T: SessionStream> Session<T> {
         }
     }
 
-    fn write_received(&self, headers: &mut Vec<u8>, id: u64) {
-        headers.extend_from_slice(b"Received: from ");
+    fn write_received(&self, headers: &mut Vec<u8>, id: u64, format: ReceivedHeaderFormat) {
+        headers.extend_from_slice(b"Received: ");
+        if format == ReceivedHeaderFormat::Full {
+            self.write_received_from(headers);
+        }
+        if self.stream.is_tls() && format != ReceivedHeaderFormat::Minimal {
+            let (version, cipher) = self.stream.tls_version_and_cipher();
+            headers.extend_from_slice(b"(using ");
+            headers.extend_from_slice(version.as_bytes());
+            headers.extend_from_slice(b" with cipher ");
+            headers.extend_from_slice(cipher.as_bytes());
+            headers.extend_from_slice(b")\r\n\t");
+        }
+        headers.extend_from_slice(b"by ");
+        headers.extend_from_slice(self.hostname.as_bytes());
+        if format != ReceivedHeaderFormat::Minimal {
+            headers.extend_from_slice(b" (Stalwart SMTP)");
+        }
+        headers.extend_from_slice(b" with ");
+        headers.extend_from_slice(match (self.stream.is_tls(), !self.is_authenticated()) {
+            (true, true) => b"ESMTPS",
+            (true, false) => b"ESMTPSA",
+            (false, true) => b"ESMTP",
+            (false, false) => b"ESMTPA",
+        });
+        headers.extend_from_slice(b" id ");
+        headers.extend_from_slice(format!("{id:X}").as_bytes());
+        headers.extend_from_slice(b";\r\n\t");
+        headers.extend_from_slice(Date::now().to_rfc822().as_bytes());
+        headers.extend_from_slice(b"\r\n");
+    }
+
+    // Client details, omitted by the privacy formats
+    fn write_received_from(&self, headers: &mut Vec<u8>) {
+        headers.extend_from_slice(b"from ");
         headers.extend_from_slice(self.data.helo_domain.as_bytes());
         headers.extend_from_slice(b" (");
         headers.extend_from_slice(
@@ -962,27 +1565,35 @@ impl<T: SessionStream> Session<T> {
             headers.extend_from_slice(b")");
         }
         headers.extend_from_slice(b")\r\n\t");
-        if self.stream.is_tls() {
-            let (version, cipher) = self.stream.tls_version_and_cipher();
-            headers.extend_from_slice(b"(using ");
-            headers.extend_from_slice(version.as_bytes());
-            headers.extend_from_slice(b" with cipher ");
-            headers.extend_from_slice(cipher.as_bytes());
-            headers.extend_from_slice(b")\r\n\t");
-        }
-        headers.extend_from_slice(b"by ");
-        headers.extend_from_slice(self.hostname.as_bytes());
-        headers.extend_from_slice(b" (Stalwart SMTP) with ");
-        headers.extend_from_slice(match (self.stream.is_tls(), !self.is_authenticated()) {
-            (true, true) => b"ESMTPS",
-            (true, false) => b"ESMTPSA",
-            (false, true) => b"ESMTP",
-            (false, false) => b"ESMTPA",
-        });
-        headers.extend_from_slice(b" id ");
-        headers.extend_from_slice(format!("{id:X}").as_bytes());
-        headers.extend_from_slice(b";\r\n\t");
-        headers.extend_from_slice(Date::now().to_rfc822().as_bytes());
-        headers.extend_from_slice(b"\r\n");
     }
 }
+
+// Each removal deletes the first remaining occurrence of the header
+fn scrub_trace_headers(
+    message: &AuthenticatedMessage<'_>,
+    trace_headers: &[String],
+) -> Vec<Modification> {
+    message
+        .raw_parsed_headers()
+        .iter()
+        .filter_map(|(name, _)| {
+            trace_headers
+                .iter()
+                .find(|header| header.as_bytes().eq_ignore_ascii_case(name))
+        })
+        .map(|name| Modification::ChangeHeader {
+            index: 1,
+            name: name.clone(),
+            value: String::new(),
+        })
+        .collect()
+}
+
+fn parse_message(raw_message: &[u8], is_large_message: bool) -> Option<ParsedMessage<'_>> {
+    if is_large_message {
+        MessageParser::new().parse_headers(raw_message)
+    } else {
+        MessageParser::new().parse(raw_message)
+    }
+    .filter(|p| p.headers().iter().any(|h| !h.name.is_other()))
+}
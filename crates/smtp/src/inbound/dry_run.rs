@@ -0,0 +1,130 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{FilterResponse, milter::Modification};
+use crate::core::Session;
+use common::{config::smtp::auth::VerifyStrategy, expr::if_block::IfBlock, network::SessionStream};
+use mail_auth::{DmarcResult, dmarc::Policy};
+
+/// Options and collected outcome of a message that is run through the
+/// inbound pipeline without being queued or generating any reports.
+#[derive(Debug, Default)]
+pub struct DryRun {
+    // Options
+    pub live_dns: bool,
+    pub mock_filters: bool,
+    pub dmarc: Option<(DmarcResult, Policy)>,
+
+    // Outcome
+    pub spam_verdict: Option<&'static str>,
+    pub spam_score: Option<f32>,
+    pub sieve_action: Option<&'static str>,
+    pub headers: Vec<u8>,
+    pub filters: Vec<DryRunFilter>,
+    pub recipients: Vec<DryRunDelivery>,
+    pub size: u64,
+}
+
+/// Verdict of the milters or MTA hooks that ran on the message.
+#[derive(Debug)]
+pub struct DryRunFilter {
+    pub kind: &'static str,
+    pub action: &'static str,
+    pub response: Option<String>,
+}
+
+/// Where a recipient's copy of the message would end up.
+#[derive(Debug, Default)]
+pub struct DryRunDelivery {
+    pub address: String,
+    pub is_spam: bool,
+    pub is_local: bool,
+    pub is_quarantined: bool,
+    pub mailboxes: Vec<String>,
+    pub redirects: Vec<String>,
+    pub reject_reason: Option<String>,
+}
+
+impl DryRun {
+    pub fn new(live_dns: bool, mock_filters: bool) -> Self {
+        DryRun {
+            live_dns,
+            mock_filters,
+            ..Default::default()
+        }
+    }
+
+    pub fn with_dmarc(mut self, result: DmarcResult, policy: Policy) -> Self {
+        self.dmarc = Some((result, policy));
+        self
+    }
+}
+
+impl<T: SessionStream> Session<T> {
+    #[inline(always)]
+    pub fn is_dry_run(&self) -> bool {
+        self.data.dry_run.is_some()
+    }
+
+    pub(crate) async fn eval_verify_strategy(&self, if_block: &IfBlock) -> VerifyStrategy {
+        if self
+            .data
+            .dry_run
+            .as_ref()
+            .is_some_and(|dry_run| !dry_run.live_dns)
+        {
+            VerifyStrategy::Disable
+        } else {
+            self.server
+                .eval_if(if_block, self, self.data.session_id)
+                .await
+                .unwrap_or(VerifyStrategy::Relaxed)
+        }
+    }
+
+    pub(crate) fn has_mocked_filters(&self) -> bool {
+        self.data
+            .dry_run
+            .as_ref()
+            .is_some_and(|dry_run| dry_run.mock_filters)
+    }
+
+    pub(crate) fn dry_run_filter(
+        &mut self,
+        kind: &'static str,
+        is_configured: bool,
+        result: &Result<Vec<Modification>, FilterResponse>,
+    ) {
+        let Some(dry_run) = &mut self.data.dry_run else {
+            return;
+        };
+        if !is_configured {
+            return;
+        }
+
+        let (action, response) = if dry_run.mock_filters {
+            ("mocked", None)
+        } else {
+            match result {
+                Ok(modifications) if modifications.is_empty() => ("accept", None),
+                Ok(_) => ("modify", None),
+                Err(response) => (
+                    match response.message.as_bytes().first() {
+                        Some(b'2') => "discard",
+                        Some(b'4') => "tempfail",
+                        _ => "reject",
+                    },
+                    Some(response.message.trim().to_string()),
+                ),
+            }
+        };
+        dry_run.filters.push(DryRunFilter {
+            kind,
+            action,
+            response,
+        });
+    }
+}
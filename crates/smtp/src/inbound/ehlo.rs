@@ -6,7 +6,7 @@
 
 use crate::{core::Session, scripts::ScriptResult};
 use common::{
-    config::smtp::session::{Mechanism, Stage},
+    config::smtp::session::{Mechanism, Stage, VrfyMode},
     network::SessionStream,
 };
 use mail_auth::{
@@ -175,9 +175,9 @@ impl<T: SessionStream> Session<T> {
         // Address Expansion
         if self
             .server
-            .eval_if(&ec.expn, self, self.data.session_id)
+            .eval_if::<VrfyMode, _>(&ec.expn, self, self.data.session_id)
             .await
-            .unwrap_or(false)
+            .is_some_and(|mode| mode != VrfyMode::Disable)
         {
             response.capabilities |= EXT_EXPN;
         }
@@ -185,9 +185,9 @@ impl<T: SessionStream> Session<T> {
         // Recipient Verification
         if self
             .server
-            .eval_if(&ec.vrfy, self, self.data.session_id)
+            .eval_if::<VrfyMode, _>(&ec.vrfy, self, self.data.session_id)
             .await
-            .unwrap_or(false)
+            .is_some_and(|mode| mode != VrfyMode::Disable)
         {
             response.capabilities |= EXT_VRFY;
         }
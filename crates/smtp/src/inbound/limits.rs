@@ -0,0 +1,184 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::core::Session;
+use common::network::SessionStream;
+use mail_parser::{Message, PartType};
+
+// A limit set to zero is not enforced
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StructureLimits {
+    pub header_count: usize,
+    pub header_size: usize,
+    pub mime_depth: usize,
+    pub mime_parts: usize,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StructureLimit {
+    HeaderCount,
+    HeaderSize,
+    MimeDepth,
+    MimeParts,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExceededLimit {
+    pub limit: StructureLimit,
+    pub value: usize,
+    pub max: usize,
+}
+
+// Header counts and sizes are the largest found in any single MIME part,
+// nested messages count towards the depth of their enclosing part.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MessageStructure {
+    pub header_count: usize,
+    pub header_size: usize,
+    pub mime_depth: usize,
+    pub mime_parts: usize,
+}
+
+impl<T: SessionStream> Session<T> {
+    pub async fn structure_limits(&self) -> StructureLimits {
+        let dc = &self.server.core.smtp.session.data;
+        StructureLimits {
+            header_count: self
+                .server
+                .eval_if(&dc.max_header_count, self, self.data.session_id)
+                .await
+                .unwrap_or(1000),
+            header_size: self
+                .server
+                .eval_if(&dc.max_header_size, self, self.data.session_id)
+                .await
+                .unwrap_or(65536),
+            mime_depth: self
+                .server
+                .eval_if(&dc.max_mime_depth, self, self.data.session_id)
+                .await
+                .unwrap_or(50),
+            mime_parts: self
+                .server
+                .eval_if(&dc.max_mime_parts, self, self.data.session_id)
+                .await
+                .unwrap_or(1000),
+        }
+    }
+}
+
+impl MessageStructure {
+    pub fn new(message: &Message<'_>) -> Self {
+        let mut structure = MessageStructure::default();
+        let mut stack = vec![(message, 0usize, 1usize)];
+
+        while let Some((message, part_id, depth)) = stack.pop() {
+            let Some(part) = message.parts.get(part_id) else {
+                continue;
+            };
+            structure.mime_parts += 1;
+            structure.mime_depth = structure.mime_depth.max(depth);
+            structure.header_count = structure.header_count.max(part.headers.len());
+            for header in &part.headers {
+                structure.header_size = structure.header_size.max(header_size(header));
+            }
+
+            match &part.body {
+                PartType::Multipart(part_ids) => {
+                    stack.extend(part_ids.iter().map(|id| (message, *id as usize, depth + 1)));
+                }
+                PartType::Message(nested_message) => {
+                    stack.push((nested_message, 0, depth + 1));
+                }
+                _ => {}
+            }
+        }
+
+        structure
+    }
+
+    pub fn exceeded(&self, limits: &StructureLimits) -> Vec<ExceededLimit> {
+        [
+            (
+                StructureLimit::HeaderCount,
+                self.header_count,
+                limits.header_count,
+            ),
+            (
+                StructureLimit::HeaderSize,
+                self.header_size,
+                limits.header_size,
+            ),
+            (
+                StructureLimit::MimeDepth,
+                self.mime_depth,
+                limits.mime_depth,
+            ),
+            (
+                StructureLimit::MimeParts,
+                self.mime_parts,
+                limits.mime_parts,
+            ),
+        ]
+        .into_iter()
+        .filter(|(_, value, max)| *max > 0 && value > max)
+        .map(|(limit, value, max)| ExceededLimit { limit, value, max })
+        .collect()
+    }
+}
+
+impl StructureLimit {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            StructureLimit::HeaderCount => "header-count",
+            StructureLimit::HeaderSize => "header-size",
+            StructureLimit::MimeDepth => "mime-depth",
+            StructureLimit::MimeParts => "mime-parts",
+        }
+    }
+
+    pub fn is_header_limit(&self) -> bool {
+        matches!(
+            self,
+            StructureLimit::HeaderCount | StructureLimit::HeaderSize
+        )
+    }
+}
+
+// Removes the headers past the count limit and those exceeding the size limit
+// from every part of the message. Headers of attached messages are left as is.
+pub fn truncate_headers(
+    raw_message: &[u8],
+    message: &Message<'_>,
+    limits: &StructureLimits,
+) -> Vec<u8> {
+    let mut remove = Vec::new();
+    for part in &message.parts {
+        for (pos, header) in part.headers.iter().enumerate() {
+            if (limits.header_count > 0 && pos >= limits.header_count)
+                || (limits.header_size > 0 && header_size(header) > limits.header_size)
+            {
+                remove.push(header.offset_field as usize..header.offset_end as usize);
+            }
+        }
+    }
+    remove.sort_unstable_by_key(|range| range.start);
+
+    let mut output = Vec::with_capacity(raw_message.len());
+    let mut offset = 0;
+    for range in remove {
+        if range.start >= offset && range.end <= raw_message.len() {
+            output.extend_from_slice(&raw_message[offset..range.start]);
+            offset = range.end;
+        }
+    }
+    output.extend_from_slice(&raw_message[offset..]);
+    output
+}
+
+fn header_size(header: &mail_parser::Header<'_>) -> usize {
+    header.offset_end.saturating_sub(header.offset_field) as usize
+}
@@ -0,0 +1,357 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{core::Session, reporting::send::MtaReportSend};
+use common::{
+    Server,
+    auth::{BuildAccessToken, MailingListCache, MailingListPolicy},
+    ipc::{EmailPush, PushNotification},
+    network::SessionStream,
+};
+use email::{
+    mailbox::manage::MailboxFnc,
+    message::ingest::{EmailIngest, IngestEmail, IngestSource},
+};
+use mail_builder::headers::{Header, address::Address, text::Text};
+use mail_parser::{HeaderName, MessageParser};
+use registry::schema::enums::{MailingListReplyTo, MailingListSenderRewrite};
+use trc::{AddContext, SmtpEvent};
+
+impl<T: SessionStream> Session<T> {
+    pub fn is_list_member(&self, list: &MailingListCache) -> bool {
+        let sender = self
+            .data
+            .mail_from
+            .as_ref()
+            .map(|mail_from| mail_from.address_lcase.as_str())
+            .unwrap_or_default();
+        !sender.is_empty()
+            && list
+                .recipients
+                .iter()
+                .any(|member| member.eq_ignore_ascii_case(sender))
+    }
+
+    // Posts are rewritten and queued for the members as a new message,
+    // leaving the envelope of the original transaction untouched.
+    pub async fn distribute_list_post(
+        &self,
+        list: &MailingListCache,
+        headers: &[u8],
+        raw_message: &[u8],
+    ) {
+        let Some(policy) = list.policy.as_deref() else {
+            return;
+        };
+        let sender = self
+            .data
+            .mail_from
+            .as_ref()
+            .map(|mail_from| mail_from.address.as_str())
+            .unwrap_or_default();
+        let Some(rewritten) = rewrite_list_post(raw_message, &list.address, sender, policy) else {
+            trc::event!(
+                Smtp(SmtpEvent::MessageParseFailed),
+                SpanId = self.data.session_id,
+                To = list.address.to_string(),
+            );
+            return;
+        };
+        let mut message = Vec::with_capacity(headers.len() + rewritten.len());
+        message.extend_from_slice(headers);
+        message.extend_from_slice(&rewritten);
+
+        // Archive a copy of the post
+        if let Some((_, folder)) = &policy.archive {
+            self.server
+                .archive_list_message(
+                    list,
+                    folder,
+                    &message,
+                    self.data.authenticated_as.is_some(),
+                    self.data.session_id,
+                )
+                .await;
+        }
+
+        // Bounces are returned to the list instead of the poster
+        let return_path = if policy.sender_rewrite != MailingListSenderRewrite::Disabled {
+            list_bounce_address(&list.address)
+        } else {
+            sender.to_string()
+        };
+        let members = list
+            .recipients
+            .iter()
+            .filter(|member| !member.eq_ignore_ascii_case(&list.address))
+            .collect::<Vec<_>>();
+
+        trc::event!(
+            Smtp(SmtpEvent::MailingListDistributed),
+            SpanId = self.data.session_id,
+            To = list.address.to_string(),
+            From = return_path.clone(),
+            Total = members.len(),
+        );
+
+        if !members.is_empty() {
+            self.server
+                .send_autogenerated(
+                    return_path,
+                    members.into_iter(),
+                    message,
+                    Some(&self.server.core.smtp.mail_auth.dkim.sign),
+                    self.data.session_id,
+                )
+                .await;
+        }
+    }
+
+    // Delivery reports for list posts are filed under the list archive
+    pub async fn receive_list_bounce(
+        &self,
+        list: &MailingListCache,
+        headers: &[u8],
+        raw_message: &[u8],
+    ) {
+        let mut message = Vec::with_capacity(headers.len() + raw_message.len());
+        message.extend_from_slice(headers);
+        message.extend_from_slice(raw_message);
+        self.server
+            .receive_list_bounce(list, &message, self.data.session_id)
+            .await;
+    }
+}
+
+pub trait MailingListArchive: Sync + Send {
+    fn archive_list_message(
+        &self,
+        list: &MailingListCache,
+        folder: &str,
+        message: &[u8],
+        is_sender_authenticated: bool,
+        session_id: u64,
+    ) -> impl Future<Output = ()> + Send;
+
+    fn receive_list_bounce(
+        &self,
+        list: &MailingListCache,
+        message: &[u8],
+        session_id: u64,
+    ) -> impl Future<Output = ()> + Send;
+}
+
+impl MailingListArchive for Server {
+    async fn archive_list_message(
+        &self,
+        list: &MailingListCache,
+        folder: &str,
+        message: &[u8],
+        is_sender_authenticated: bool,
+        session_id: u64,
+    ) {
+        let Some((account_id, _)) = list
+            .policy
+            .as_ref()
+            .and_then(|policy| policy.archive.as_ref())
+        else {
+            return;
+        };
+        let account_id = *account_id;
+        let result = async {
+            let access_token = self
+                .access_token(account_id)
+                .await
+                .caused_by(trc::location!())?
+                .build();
+            let Some(mailbox_id) = self
+                .mailbox_create_path(account_id, folder, None)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                return Ok(());
+            };
+
+            let ingested = self
+                .email_ingest(IngestEmail {
+                    raw_message: message,
+                    blob_hash: None,
+                    message: MessageParser::new().parse(message),
+                    access_token: &access_token,
+                    mailbox_ids: vec![mailbox_id],
+                    keywords: vec![],
+                    received_at: None,
+                    source: IngestSource::Smtp {
+                        deliver_to: &list.address,
+                        is_sender_authenticated,
+                        is_spam: false,
+                    },
+                    session_id,
+                })
+                .await?;
+
+            if ingested.change_id != u64::MAX {
+                self.broadcast_push_notification(PushNotification::EmailPush(EmailPush {
+                    account_id,
+                    email_id: ingested.document_id,
+                    change_id: ingested.change_id,
+                }))
+                .await;
+            }
+
+            Ok::<_, trc::Error>(())
+        }
+        .await;
+
+        if let Err(err) = result {
+            trc::error!(
+                err.span_id(session_id)
+                    .account_id(account_id)
+                    .details("Failed to archive mailing list message")
+            );
+        }
+    }
+
+    async fn receive_list_bounce(&self, list: &MailingListCache, message: &[u8], session_id: u64) {
+        trc::event!(
+            Smtp(SmtpEvent::MailingListBounce),
+            SpanId = session_id,
+            To = list.address.to_string(),
+            Size = message.len(),
+        );
+
+        // Lists without an archive discard their bounces
+        if let Some((_, folder)) = list
+            .policy
+            .as_ref()
+            .and_then(|policy| policy.archive.as_ref())
+        {
+            self.archive_list_message(
+                list,
+                &format!("{folder}/Bounces"),
+                message,
+                false,
+                session_id,
+            )
+            .await;
+        }
+    }
+}
+
+pub fn list_bounce_address(list_address: &str) -> String {
+    let (local_part, domain) = list_address.rsplit_once('@').unwrap_or((list_address, ""));
+    format!("{local_part}-bounces@{domain}")
+}
+
+// Applies the list policy to the message headers, the body is never modified
+pub fn rewrite_list_post(
+    raw_message: &[u8],
+    list_address: &str,
+    sender: &str,
+    policy: &MailingListPolicy,
+) -> Option<Vec<u8>> {
+    let message = MessageParser::new().parse_headers(raw_message)?;
+    let root = message.root_part();
+    let from = message
+        .from()
+        .and_then(|from| from.first())
+        .and_then(|addr| {
+            addr.address()
+                .map(|address| (addr.name().map(|name| name.to_string()), address))
+        });
+    let rewrite_from = policy.sender_rewrite == MailingListSenderRewrite::EnvelopeAndFrom;
+    let subject = policy.subject_prefix.as_deref().map(|prefix| {
+        let subject = message.subject().unwrap_or_default();
+        if subject.to_lowercase().contains(&prefix.to_lowercase()) {
+            subject.to_string()
+        } else if subject.is_empty() {
+            prefix.to_string()
+        } else {
+            format!("{prefix} {subject}")
+        }
+    });
+
+    let mut output = Vec::with_capacity(raw_message.len() + 256);
+    for header in &root.headers {
+        let remove = match &header.name {
+            HeaderName::Subject => subject.is_some(),
+            HeaderName::From => rewrite_from,
+            HeaderName::ReplyTo => policy.reply_to != MailingListReplyTo::Unchanged,
+            HeaderName::ListId | HeaderName::ListPost => true,
+            HeaderName::Other(name) => name.eq_ignore_ascii_case("Precedence"),
+            _ => false,
+        };
+        if !remove {
+            output.extend_from_slice(
+                raw_message.get(header.offset_field as usize..header.offset_end as usize)?,
+            );
+        }
+    }
+
+    if let Some(subject) = subject {
+        write_header(&mut output, "Subject", Text::new(subject));
+    }
+    if rewrite_from {
+        // The poster's domain would fail DMARC once the message is modified
+        let name = from
+            .as_ref()
+            .and_then(|(name, _)| name.as_deref())
+            .or_else(|| from.as_ref().map(|(_, address)| *address))
+            .unwrap_or(sender);
+        write_header(
+            &mut output,
+            "From",
+            Address::new_address(Some(format!("{name} via {list_address}")), list_address),
+        );
+        if let Some((name, address)) = &from {
+            write_header(
+                &mut output,
+                "X-Original-From",
+                Address::new_address(name.clone(), *address),
+            );
+        }
+    }
+    match policy.reply_to {
+        MailingListReplyTo::Unchanged => {}
+        MailingListReplyTo::List => {
+            write_header(
+                &mut output,
+                "Reply-To",
+                Address::new_address(None::<&str>, list_address),
+            );
+        }
+        MailingListReplyTo::Sender => {
+            let address = from.as_ref().map(|(_, address)| *address).unwrap_or(sender);
+            if !address.is_empty() {
+                write_header(
+                    &mut output,
+                    "Reply-To",
+                    Address::new_address(from.as_ref().and_then(|(name, _)| name.clone()), address),
+                );
+            }
+        }
+    }
+    output.extend_from_slice(b"List-Id: <");
+    output.extend_from_slice(list_address.replace('@', ".").as_bytes());
+    output.extend_from_slice(b">\r\nList-Post: <mailto:");
+    output.extend_from_slice(list_address.as_bytes());
+    output.extend_from_slice(b">\r\nPrecedence: list\r\n");
+
+    let body_offset = (root.offset_body as usize).min(raw_message.len());
+    let header_end = root
+        .headers
+        .last()
+        .map_or(0, |header| header.offset_end as usize);
+    output.extend_from_slice(raw_message.get(header_end.min(body_offset)..)?);
+
+    Some(output)
+}
+
+fn write_header(output: &mut Vec<u8>, name: &str, value: impl Header) {
+    output.extend_from_slice(name.as_bytes());
+    output.extend_from_slice(b": ");
+    let _ = value.write_header(&mut *output, name.len() + 2);
+}
@@ -6,11 +6,15 @@
 
 use crate::{
     core::{Session, SessionAddress},
+    reporting::tls::TlsReporting,
     scripts::ScriptResult,
 };
 use common::{config::smtp::session::Stage, network::SessionStream, scripts::ScriptModification};
-use mail_auth::{IprevOutput, IprevResult, SpfOutput, SpfResult, spf::verify::SpfParameters};
-use registry::schema::structs::Rate;
+use mail_auth::{
+    IprevOutput, IprevResult, SpfOutput, SpfResult, report::tlsrpt::ResultType,
+    spf::verify::SpfParameters,
+};
+use registry::schema::{enums::MtaSenderMismatchAction, structs::Rate};
 use smtp_proto::{MAIL_BY_NOTIFY, MAIL_BY_RETURN, MAIL_REQUIRETLS, MailFrom, MtPriority};
 use std::{
     borrow::Cow,
@@ -241,35 +245,107 @@ impl<T: SessionStream> Session<T> {
                     .await
                     .unwrap_or(true) =>
             {
-                let address_lcase = self.data.mail_from.as_ref().unwrap().address_lcase.as_str();
-                if authenticated_as != address_lcase
-                    && !self
-                        .authenticated_emails()
-                        .iter()
-                        .any(|e| e == address_lcase)
-                {
-                    trc::event!(
-                        Smtp(SmtpEvent::MailFromUnauthorized),
-                        SpanId = self.data.session_id,
-                        From = address_lcase.to_string(),
-                        Details = [trc::Value::String(authenticated_as.into())]
-                            .into_iter()
-                            .chain(
-                                self.authenticated_emails()
-                                    .iter()
-                                    .map(|e| trc::Value::String(e.into()))
-                            )
-                            .collect::<Vec<_>>()
-                    );
-                    self.data.mail_from = None;
-                    return self
-                        .write(b"501 5.5.4 You are not allowed to send from this address.\r\n")
-                        .await;
+                let authenticated_as = authenticated_as.to_string();
+                let address_lcase = self.data.mail_from.as_ref().unwrap().address_lcase.clone();
+                match self.is_allowed_sender(&address_lcase).await {
+                    Ok(true) => (),
+                    Ok(false) => match (
+                        self.server.core.smtp.session.auth.sender_mismatch_action,
+                        self.primary_sender(),
+                    ) {
+                        (MtaSenderMismatchAction::Rewrite, Some(new_address)) => {
+                            let new_address = new_address.to_string();
+                            let mail_from = self.data.mail_from.as_mut().unwrap();
+
+                            trc::event!(
+                                Smtp(SmtpEvent::MailFromRewritten),
+                                SpanId = self.data.session_id,
+                                Details = address_lcase,
+                                From = new_address.clone(),
+                            );
+
+                            mail_from.address_lcase = new_address.to_lowercase();
+                            mail_from.domain = mail_from.address_lcase.domain_part().into();
+                            mail_from.address = new_address;
+                        }
+                        (MtaSenderMismatchAction::Tag, Some(sender)) => {
+                            // The Sender header is added once the message is received
+                            trc::event!(
+                                Smtp(SmtpEvent::SenderMismatchTagged),
+                                SpanId = self.data.session_id,
+                                From = address_lcase,
+                                Details = sender.to_string(),
+                            );
+                        }
+                        _ => {
+                            trc::event!(
+                                Smtp(SmtpEvent::MailFromUnauthorized),
+                                SpanId = self.data.session_id,
+                                From = address_lcase,
+                                Details = [trc::Value::String(authenticated_as.into())]
+                                    .into_iter()
+                                    .chain(
+                                        self.authenticated_emails()
+                                            .iter()
+                                            .map(|e| trc::Value::String(e.into()))
+                                    )
+                                    .collect::<Vec<_>>()
+                            );
+                            self.data.mail_from = None;
+                            return self
+                                .write(
+                                    b"501 5.5.4 You are not allowed to send from this address.\r\n",
+                                )
+                                .await;
+                        }
+                    },
+                    Err(err) => {
+                        trc::error!(
+                            err.span_id(self.data.session_id)
+                                .caused_by(trc::location!())
+                                .details("Failed to verify sender address.")
+                        );
+
+                        self.data.mail_from = None;
+                        return self
+                            .write(b"451 4.4.3 Unable to verify sender address at this time.\r\n")
+                            .await;
+                    }
                 }
             }
             _ => (),
         }
 
+        // Make sure that tenant accounts only send from their own domains
+        let domain = self.data.mail_from.as_ref().unwrap().domain.as_str();
+        match self.is_tenant_domain(domain).await {
+            Ok(true) => (),
+            Ok(false) => {
+                let mail_from = self.data.mail_from.take().unwrap();
+                trc::event!(
+                    Smtp(SmtpEvent::TenantDomainMismatch),
+                    SpanId = self.data.session_id,
+                    From = mail_from.address_lcase,
+                    Domain = mail_from.domain,
+                );
+                return self
+                    .write(b"550 5.7.1 Sender domain is not authorized for this account.\r\n")
+                    .await;
+            }
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to verify sender domain.")
+                );
+
+                self.data.mail_from = None;
+                return self
+                    .write(b"451 4.4.3 Unable to verify sender domain at this time.\r\n")
+                    .await;
+            }
+        }
+
         // Validate parameters
         let config = &self.server.core.smtp.session.extensions;
         let config_data = &self.server.core.smtp.session.data;
@@ -337,7 +413,13 @@ impl<T: SessionStream> Session<T> {
                 .is_some()
             {
                 if (-6..6).contains(&from.mt_priority) {
-                    self.data.priority = from.mt_priority as i16;
+                    // Unauthenticated clients may only lower the priority of their messages
+                    self.data.priority =
+                        if from.mt_priority > 0 && self.data.authenticated_as.is_none() {
+                            0
+                        } else {
+                            from.mt_priority as i16
+                        };
                 } else {
                     trc::event!(
                         Smtp(SmtpEvent::MtPriorityInvalid),
@@ -503,6 +585,24 @@ impl<T: SessionStream> Session<T> {
                 }
             }
 
+            // Record clients that skip an offered STARTTLS, once SPF has authenticated
+            // the sending domain
+            if !self.data.tls_failure_reported
+                && !self.stream.is_tls()
+                && self.instance.acceptor.is_tls()
+                && !self.is_authenticated()
+            {
+                self.data.tls_failure_reported = true;
+                self.server
+                    .report_inbound_tls_failure(
+                        &self.data,
+                        &self.hostname,
+                        ResultType::StartTlsNotSupported,
+                        "Client did not issue STARTTLS",
+                    )
+                    .await;
+            }
+
             trc::event!(
                 Smtp(SmtpEvent::MailFrom),
                 SpanId = self.data.session_id,
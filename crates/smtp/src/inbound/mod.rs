@@ -13,13 +13,18 @@ use mail_auth::{
 };
 
 pub mod auth;
+pub mod callout;
 pub mod data;
+pub mod dry_run;
 pub mod ehlo;
 pub mod hooks;
+pub mod limits;
+pub mod list;
 pub mod mail;
 pub mod milter;
 pub mod rcpt;
 pub mod session;
+pub mod smuggling;
 pub mod spam;
 pub mod spawn;
 pub mod vrfy;
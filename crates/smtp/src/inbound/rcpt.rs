@@ -6,6 +6,7 @@
 
 use crate::{
     core::{Session, SessionAddress},
+    inbound::callout::CalloutResult,
     scripts::ScriptResult,
 };
 use common::{
@@ -190,6 +191,7 @@ impl<T: SessionStream> Session<T> {
         // Verify address
         let rcpt = self.data.rcpt_to.last().unwrap();
         let mut rcpt_members = None;
+        let mut rcpt_list = None;
 
         match self
             .server
@@ -218,6 +220,37 @@ impl<T: SessionStream> Session<T> {
             Ok(RcptResolution::Expand(members)) => {
                 rcpt_members = Some(members);
             }
+            Ok(RcptResolution::List(list)) => {
+                // Restricted lists only accept posts from their members
+                if list
+                    .policy
+                    .as_ref()
+                    .is_some_and(|policy| policy.members_only)
+                    && !self.is_list_member(&list)
+                {
+                    trc::event!(
+                        Smtp(SmtpEvent::MailingListNotMember),
+                        SpanId = self.data.session_id,
+                        To = rcpt.address_lcase.clone(),
+                        From = self
+                            .data
+                            .mail_from
+                            .as_ref()
+                            .map(|mail_from| mail_from.address_lcase.clone()),
+                    );
+
+                    self.data.rcpt_to.pop();
+                    return self
+                        .write(b"550 5.7.1 Only list members may post to this list.\r\n")
+                        .await;
+                }
+
+                rcpt_list = Some((list, false));
+            }
+            Ok(RcptResolution::ListBounce(list)) => {
+                // Delivery reports for list posts are accepted from any sender
+                rcpt_list = Some((list, true));
+            }
             Ok(RcptResolution::UnknownRecipient) => {
                 trc::event!(
                     Smtp(SmtpEvent::MailboxDoesNotExist),
@@ -248,6 +281,30 @@ impl<T: SessionStream> Session<T> {
                         .rcpt_error(b"550 5.1.2 Relay not allowed.\r\n", rcpt_to)
                         .await;
                 }
+
+                // Verify the recipient with the destination server
+                if self.data.authenticated_as.is_none()
+                    && self
+                        .server
+                        .eval_if(&rcpt_config.callout, self, self.data.session_id)
+                        .await
+                        .unwrap_or(false)
+                    && self.rcpt_callout(&rcpt.address_lcase).await == CalloutResult::Invalid
+                {
+                    trc::event!(
+                        Smtp(SmtpEvent::CalloutRejected),
+                        SpanId = self.data.session_id,
+                        To = rcpt.address_lcase.clone(),
+                    );
+
+                    let rcpt_to = self.data.rcpt_to.pop().unwrap().address_lcase;
+                    return self
+                        .rcpt_error(
+                            b"550 5.1.1 Recipient address rejected by destination server.\r\n",
+                            rcpt_to,
+                        )
+                        .await;
+                }
             }
             Err(err) => {
                 trc::error!(
@@ -350,6 +407,12 @@ impl<T: SessionStream> Session<T> {
                 .await;
         }
 
+        // Lists with a policy are distributed once the message is received
+        if let Some((list, is_bounce)) = rcpt_list {
+            let address = self.data.rcpt_to.last().unwrap().address_lcase.clone();
+            self.data.rcpt_lists.push((address, list, is_bounce));
+        }
+
         // Expand list
         if let Some(members) = rcpt_members {
             let list_addr = self.data.rcpt_to.pop().unwrap();
@@ -347,7 +347,10 @@ impl<T: SessionStream> Session<T> {
                 State::Data(receiver) => {
                     if self.data.message.len() + bytes.len() < self.params.max_message_size {
                         if receiver.ingest(&mut iter, &mut self.data.message) {
-                            let message = self.queue_message().await;
+                            let message = match self.check_line_endings().await {
+                                Some(message) => message,
+                                None => self.queue_message().await,
+                            };
                             let num_responses = if self.instance.protocol == ServerProtocol::Smtp {
                                 1
                             } else {
@@ -470,6 +473,7 @@ impl<T: AsyncWrite + AsyncRead + Unpin> Session<T> {
         self.data.mail_from = None;
         self.data.spf_mail_from = None;
         self.data.rcpt_to.clear();
+        self.data.rcpt_lists.clear();
         self.data.message = Vec::with_capacity(0);
         self.data.priority = 0;
         self.data.delivery_by = 0;
@@ -587,6 +591,25 @@ impl<T: SessionStream> ResolveVariable for Session<T> {
             ExpressionVariable::LocalIp => self.data.local_ip_str.as_str().into(),
             ExpressionVariable::LocalPort => self.data.local_port.into(),
             ExpressionVariable::IsTls => self.stream.is_tls().into(),
+            ExpressionVariable::TlsClientSubject => self
+                .data
+                .tls_client
+                .as_ref()
+                .map(|cert| cert.subject.as_str())
+                .unwrap_or_default()
+                .into(),
+            ExpressionVariable::TlsClientSan => self
+                .data
+                .tls_client
+                .as_ref()
+                .map(|cert| {
+                    cert.subject_alt_names
+                        .iter()
+                        .map(|name| Variable::from(name.as_str()))
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default()
+                .into(),
             ExpressionVariable::Priority => self.data.priority.to_compact_string().into(),
             ExpressionVariable::Protocol => self.instance.protocol.as_str().into(),
             ExpressionVariable::Asn => self
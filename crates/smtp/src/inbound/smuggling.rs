@@ -0,0 +1,144 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::core::Session;
+use common::network::SessionStream;
+use std::borrow::Cow;
+use trc::{SecurityEvent, SmtpEvent};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LineEndings {
+    pub bare_lf: usize,
+    pub bare_cr: usize,
+    pub smuggling: usize,
+}
+
+impl<T: SessionStream> Session<T> {
+    // Returns the reply to send instead of queueing the message, an empty
+    // reply requests the session to be closed.
+    pub async fn check_line_endings(&mut self) -> Option<Cow<'static, [u8]>> {
+        let line_endings = LineEndings::new(&self.data.message);
+        if line_endings.is_clean() {
+            return None;
+        }
+
+        if line_endings.smuggling > 0 {
+            trc::event!(
+                Smtp(SmtpEvent::SmugglingAttempt),
+                SpanId = self.data.session_id,
+                RemoteIp = self.data.remote_ip,
+                Total = line_endings.smuggling,
+            );
+
+            match self
+                .server
+                .is_smuggling_fail2banned(self.data.remote_ip)
+                .await
+            {
+                Ok(true) => {
+                    trc::event!(
+                        Security(SecurityEvent::SmugglingBan),
+                        SpanId = self.data.session_id,
+                        RemoteIp = self.data.remote_ip,
+                    );
+
+                    self.data.message = Vec::with_capacity(0);
+                    return Some((&b""[..]).into());
+                }
+                Ok(false) => {}
+                Err(err) => {
+                    trc::error!(
+                        err.span_id(self.data.session_id)
+                            .caused_by(trc::location!())
+                            .details("Failed to check if IP should be banned.")
+                    );
+                }
+            }
+        }
+
+        let dc = &self.server.core.smtp.session.data;
+        let mut reject = false;
+        for (count, if_block, details) in [
+            (line_endings.bare_lf, &dc.reject_bare_lf, "LF"),
+            (line_endings.bare_cr, &dc.reject_bare_cr, "CR"),
+        ] {
+            if count > 0 {
+                let is_rejected = self
+                    .server
+                    .eval_if(if_block, self, self.data.session_id)
+                    .await
+                    .unwrap_or(false);
+
+                trc::event!(
+                    Smtp(SmtpEvent::BareLineEnding),
+                    SpanId = self.data.session_id,
+                    Details = details,
+                    Total = count,
+                    Result = if is_rejected { "reject" } else { "accept" },
+                );
+
+                reject |= is_rejected;
+            }
+        }
+
+        if reject {
+            self.data.message = Vec::with_capacity(0);
+            Some((&b"550 5.6.0 Bare CR or LF line endings are not allowed.\r\n"[..]).into())
+        } else {
+            None
+        }
+    }
+}
+
+impl LineEndings {
+    pub fn new(message: &[u8]) -> Self {
+        let mut line_endings = LineEndings::default();
+        // The message starts right after the CRLF that ended the DATA command
+        let mut line_start = Some(true);
+
+        let mut pos = 0;
+        while pos < message.len() {
+            let (is_crlf, len) = match message[pos] {
+                b'\r' if message.get(pos + 1) == Some(&b'\n') => (true, 2),
+                b'\r' => {
+                    line_endings.bare_cr += 1;
+                    (false, 1)
+                }
+                b'\n' => {
+                    line_endings.bare_lf += 1;
+                    (false, 1)
+                }
+                ch => {
+                    // A lone dot on a line where either line break is not a
+                    // CRLF is read as the end of data by lenient receivers.
+                    if ch == b'.'
+                        && let Some(was_crlf) = line_start
+                    {
+                        let next = &message[pos + 1..];
+                        let next_crlf = next.starts_with(b"\r\n");
+                        if (next_crlf || next.starts_with(b"\n") || next.starts_with(b"\r"))
+                            && (!was_crlf || !next_crlf)
+                        {
+                            line_endings.smuggling += 1;
+                        }
+                    }
+                    line_start = None;
+                    pos += 1;
+                    continue;
+                }
+            };
+
+            line_start = Some(is_crlf);
+            pos += len;
+        }
+
+        line_endings
+    }
+
+    pub fn is_clean(&self) -> bool {
+        self.bare_lf == 0 && self.bare_cr == 0
+    }
+}
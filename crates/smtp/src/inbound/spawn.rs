@@ -6,6 +6,7 @@
 
 use crate::{
     core::{Session, SessionData, SessionParameters, SmtpSessionManager, State},
+    reporting::tls::TlsReporting,
     scripts::ScriptResult,
 };
 use common::{
@@ -13,6 +14,7 @@ use common::{
     config::smtp::session::Stage,
     network::{self, SessionManager, SessionStream},
 };
+use mail_auth::report::tlsrpt::ResultType;
 use std::time::Instant;
 use tokio_rustls::server::TlsStream;
 use trc::{SecurityEvent, SmtpEvent};
@@ -39,6 +41,10 @@ impl SessionManager for SmtpSessionManager {
             params: SessionParameters::default(),
         };
 
+        if session.stream.is_tls() {
+            session.authenticate_client_certificate().await;
+        }
+
         // Enforce throttle
         if session.is_allowed().await
             && session.init_conn().await
@@ -251,17 +257,35 @@ impl<T: SessionStream> Session<T> {
     }
 
     pub async fn into_tls(self) -> Result<Session<TlsStream<T>>, ()> {
-        Ok(Session {
-            hostname: self.hostname,
-            stream: self
-                .instance
-                .tls_accept(self.stream, self.data.session_id)
-                .await?,
-            state: self.state,
-            data: self.data,
-            instance: self.instance,
-            server: self.server,
-            params: self.params,
-        })
+        match self
+            .instance
+            .tls_accept(self.stream, self.data.session_id)
+            .await
+        {
+            Ok(stream) => {
+                let mut session = Session {
+                    hostname: self.hostname,
+                    stream,
+                    state: self.state,
+                    data: self.data,
+                    instance: self.instance,
+                    server: self.server,
+                    params: self.params,
+                };
+                session.authenticate_client_certificate().await;
+                Ok(session)
+            }
+            Err(_) => {
+                self.server
+                    .report_inbound_tls_failure(
+                        &self.data,
+                        &self.hostname,
+                        ResultType::ValidationFailure,
+                        "TLS handshake failed after STARTTLS",
+                    )
+                    .await;
+                Err(())
+            }
+        }
     }
 }
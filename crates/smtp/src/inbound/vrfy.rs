@@ -5,117 +5,243 @@
  */
 
 use crate::core::Session;
-use common::network::{RcptResolution, SessionStream};
+use common::{
+    config::smtp::session::VrfyMode,
+    expr::if_block::IfBlock,
+    network::{RcptResolution, SessionStream},
+};
+use registry::schema::structs::Rate;
 use std::{borrow::Cow, fmt::Write};
 use trc::SmtpEvent;
 
 impl<T: SessionStream> Session<T> {
     pub async fn handle_vrfy(&mut self, address: Cow<'_, str>) -> Result<(), ()> {
-        if self.params.can_vrfy {
-            match self
-                .server
-                .rcpt_resolve(&address.to_lowercase(), self.data.session_id)
-                .await
-            {
-                Ok(RcptResolution::Accept | RcptResolution::Rewrite(_)) => {
-                    trc::event!(
-                        Smtp(SmtpEvent::Vrfy),
-                        SpanId = self.data.session_id,
-                        To = address.as_ref().to_string(),
-                    );
+        match self.params.vrfy {
+            VrfyMode::Full | VrfyMode::Ambiguous => {}
+            VrfyMode::Privacy => {
+                trc::event!(
+                    Smtp(SmtpEvent::Vrfy),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
 
-                    self.write(format!("250 {}\r\n", address.as_ref()).as_bytes())
-                        .await
-                }
-                Ok(
-                    RcptResolution::UnknownRecipient
-                    | RcptResolution::UnknownDomain
-                    | RcptResolution::Expand(_),
-                ) => {
-                    trc::event!(
-                        Smtp(SmtpEvent::VrfyNotFound),
-                        SpanId = self.data.session_id,
-                        To = address.as_ref().to_string(),
-                    );
-
-                    self.write(b"550 5.1.2 Address not found.\r\n").await
-                }
-                Err(err) => {
-                    trc::error!(
-                        err.span_id(self.data.session_id)
-                            .caused_by(trc::location!())
-                            .details("Failed to verify address.")
-                    );
+                return self
+                    .write(
+                        b"252 2.1.5 Cannot VRFY user, but will accept message and attempt delivery.\r\n",
+                    )
+                    .await;
+            }
+            VrfyMode::Disable => {
+                trc::event!(
+                    Smtp(SmtpEvent::VrfyDisabled),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
 
-                    self.write(b"252 2.4.3 Unable to verify address at this time.\r\n")
-                        .await
-                }
+                return self.write(b"252 2.5.1 VRFY is disabled.\r\n").await;
             }
-        } else {
+        }
+
+        // Directory lookups are rate limited to prevent address harvesting
+        if !self
+            .is_vrfy_allowed(&self.server.core.smtp.session.extensions.vrfy_rate, "vrfy")
+            .await
+        {
             trc::event!(
-                Smtp(SmtpEvent::VrfyDisabled),
+                Smtp(SmtpEvent::VrfyRateLimitExceeded),
                 SpanId = self.data.session_id,
                 To = address.as_ref().to_string(),
             );
 
-            self.write(b"252 2.5.1 VRFY is disabled.\r\n").await
+            return self
+                .write(b"452 4.7.1 Too many VRFY requests, try again later.\r\n")
+                .await;
         }
-    }
 
-    pub async fn handle_expn(&mut self, address: Cow<'_, str>) -> Result<(), ()> {
-        if self.params.can_expn {
-            match self
-                .server
-                .rcpt_resolve(&address.to_lowercase(), self.data.session_id)
+        match self
+            .server
+            .rcpt_resolve(&address.to_lowercase(), self.data.session_id)
+            .await
+        {
+            Ok(
+                RcptResolution::Accept
+                | RcptResolution::Rewrite(_)
+                | RcptResolution::List(_)
+                | RcptResolution::ListBounce(_),
+            ) if self.params.vrfy == VrfyMode::Full => {
+                trc::event!(
+                    Smtp(SmtpEvent::Vrfy),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                self.write(format!("250 {}\r\n", address.as_ref()).as_bytes())
+                    .await
+            }
+            Ok(
+                RcptResolution::Accept
+                | RcptResolution::Rewrite(_)
+                | RcptResolution::UnknownRecipient
+                | RcptResolution::Expand(_)
+                | RcptResolution::List(_)
+                | RcptResolution::ListBounce(_),
+            ) if self.params.vrfy == VrfyMode::Ambiguous => {
+                // Local domains get the same answer whether or not the mailbox exists
+                trc::event!(
+                    Smtp(SmtpEvent::Vrfy),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                self.write(
+                    b"252 2.1.5 Cannot VRFY user, but will accept message and attempt delivery.\r\n",
+                )
                 .await
-            {
-                Ok(RcptResolution::Expand(addresses)) => {
-                    let mut result = String::with_capacity(32);
-                    for (pos, value) in addresses.iter().enumerate() {
-                        let _ = write!(
-                            result,
-                            "250{}{}\r\n",
-                            if pos == addresses.len() - 1 { " " } else { "-" },
-                            value
-                        );
-                    }
-
-                    trc::event!(
-                        Smtp(SmtpEvent::Expn),
-                        SpanId = self.data.session_id,
-                        To = address.as_ref().to_string(),
-                    );
+            }
+            Ok(_) => {
+                trc::event!(
+                    Smtp(SmtpEvent::VrfyNotFound),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
 
-                    self.write(result.as_bytes()).await
-                }
-                Ok(_) => {
-                    trc::event!(
-                        Smtp(SmtpEvent::ExpnNotFound),
-                        SpanId = self.data.session_id,
-                        To = address.as_ref().to_string(),
-                    );
+                self.write(b"550 5.1.2 Address not found.\r\n").await
+            }
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to verify address.")
+                );
 
-                    self.write(b"550 5.1.2 Mailing list not found.\r\n").await
-                }
-                Err(err) => {
-                    trc::error!(
-                        err.span_id(self.data.session_id)
-                            .caused_by(trc::location!())
-                            .details("Failed to verify address.")
-                    );
+                self.write(b"252 2.4.3 Unable to verify address at this time.\r\n")
+                    .await
+            }
+        }
+    }
 
-                    self.write(b"252 2.4.3 Unable to expand mailing list at this time.\r\n")
-                        .await
-                }
+    pub async fn handle_expn(&mut self, address: Cow<'_, str>) -> Result<(), ()> {
+        match self.params.expn {
+            VrfyMode::Full | VrfyMode::Ambiguous => {}
+            VrfyMode::Privacy => {
+                trc::event!(
+                    Smtp(SmtpEvent::Expn),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                return self
+                    .write(
+                        b"252 2.1.5 Cannot EXPN list, but will accept message and attempt delivery.\r\n",
+                    )
+                    .await;
             }
-        } else {
+            VrfyMode::Disable => {
+                trc::event!(
+                    Smtp(SmtpEvent::ExpnDisabled),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                return self.write(b"252 2.5.1 EXPN is disabled.\r\n").await;
+            }
+        }
+
+        // Directory lookups are rate limited to prevent address harvesting
+        if !self
+            .is_vrfy_allowed(&self.server.core.smtp.session.extensions.expn_rate, "expn")
+            .await
+        {
             trc::event!(
-                Smtp(SmtpEvent::ExpnDisabled),
+                Smtp(SmtpEvent::ExpnRateLimitExceeded),
                 SpanId = self.data.session_id,
                 To = address.as_ref().to_string(),
             );
 
-            self.write(b"252 2.5.1 EXPN is disabled.\r\n").await
+            return self
+                .write(b"452 4.7.1 Too many EXPN requests, try again later.\r\n")
+                .await;
+        }
+
+        match self
+            .server
+            .rcpt_resolve(&address.to_lowercase(), self.data.session_id)
+            .await
+            .map(|resolution| match resolution {
+                RcptResolution::List(list) => RcptResolution::Expand(list.recipients.clone()),
+                resolution => resolution,
+            }) {
+            Ok(RcptResolution::Expand(addresses)) if self.params.expn == VrfyMode::Full => {
+                let mut result = String::with_capacity(32);
+                for (pos, value) in addresses.iter().enumerate() {
+                    let _ = write!(
+                        result,
+                        "250{}{}\r\n",
+                        if pos == addresses.len() - 1 { " " } else { "-" },
+                        value
+                    );
+                }
+
+                trc::event!(
+                    Smtp(SmtpEvent::Expn),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                self.write(result.as_bytes()).await
+            }
+            Ok(
+                RcptResolution::Accept
+                | RcptResolution::Rewrite(_)
+                | RcptResolution::UnknownRecipient
+                | RcptResolution::Expand(_)
+                | RcptResolution::List(_)
+                | RcptResolution::ListBounce(_),
+            ) if self.params.expn == VrfyMode::Ambiguous => {
+                // Members are never disclosed and lists are indistinguishable from mailboxes
+                trc::event!(
+                    Smtp(SmtpEvent::Expn),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                self.write(
+                    b"252 2.1.5 Cannot EXPN list, but will accept message and attempt delivery.\r\n",
+                )
+                .await
+            }
+            Ok(_) => {
+                trc::event!(
+                    Smtp(SmtpEvent::ExpnNotFound),
+                    SpanId = self.data.session_id,
+                    To = address.as_ref().to_string(),
+                );
+
+                self.write(b"550 5.1.2 Mailing list not found.\r\n").await
+            }
+            Err(err) => {
+                trc::error!(
+                    err.span_id(self.data.session_id)
+                        .caused_by(trc::location!())
+                        .details("Failed to verify address.")
+                );
+
+                self.write(b"252 2.4.3 Unable to expand mailing list at this time.\r\n")
+                    .await
+            }
+        }
+    }
+
+    async fn is_vrfy_allowed(&self, rate: &IfBlock, ctx: &str) -> bool {
+        if let Some(rate) = self
+            .server
+            .eval_if::<Rate, _>(rate, self, self.data.session_id)
+            .await
+        {
+            self.throttle_rcpt(&self.data.remote_ip_str, &rate, ctx)
+                .await
+        } else {
+            true
         }
     }
 }
@@ -10,6 +10,7 @@ use crate::{
     queue::{Error, ErrorDetails, HostResponse, MessageWrapper, Status},
 };
 use base64::{Engine, engine::general_purpose};
+use common::config::smtp::queue::{ProxyConfig, ProxyProtocol};
 use directory::Credentials;
 use rustls::ClientConnection;
 use rustls_pki_types::ServerName;
@@ -570,6 +571,34 @@ impl SmtpClient<TcpStream> {
         .map_err(|_| ClientError::Timeout)?
     }
 
+    /// Connects to a remote host address through a SOCKS5 or HTTP CONNECT proxy
+    pub async fn connect_proxy(
+        proxy: &ProxyConfig,
+        remote_addr: SocketAddr,
+        timeout: Duration,
+        session_id: u64,
+    ) -> ClientResult<Self> {
+        tokio::time::timeout(timeout, async {
+            let mut stream = TcpStream::connect((proxy.address.as_ref(), proxy.port)).await?;
+            match proxy.protocol {
+                ProxyProtocol::Socks5 => {
+                    socks5_handshake(&mut stream, proxy.auth.as_ref(), remote_addr).await?
+                }
+                ProxyProtocol::Http => {
+                    http_connect_handshake(&mut stream, proxy.auth.as_ref(), remote_addr).await?
+                }
+            }
+
+            Ok(SmtpClient {
+                stream,
+                timeout,
+                session_id,
+            })
+        })
+        .await
+        .map_err(|_| ClientError::Timeout)?
+    }
+
     pub async fn try_start_tls(
         mut self,
         tls_connector: &TlsConnector,
@@ -655,6 +684,121 @@ fn encode_credentials(
     ))
 }
 
+async fn socks5_handshake(
+    stream: &mut TcpStream,
+    auth: Option<&(String, String)>,
+    remote_addr: SocketAddr,
+) -> ClientResult<()> {
+    // Offer username/password authentication (RFC 1929) only when configured
+    if auth.is_some() {
+        stream.write_all(&[5, 2, 0, 2]).await?;
+    } else {
+        stream.write_all(&[5, 1, 0]).await?;
+    }
+    let mut reply = [0u8; 2];
+    stream.read_exact(&mut reply).await?;
+    match (reply, auth) {
+        ([5, 0], _) => (),
+        ([5, 2], Some((username, secret))) => {
+            if username.len() > 255 || secret.len() > 255 {
+                return Err(proxy_error("SOCKS5 credentials are too long"));
+            }
+            let mut request = Vec::with_capacity(username.len() + secret.len() + 3);
+            request.push(1);
+            request.push(username.len() as u8);
+            request.extend_from_slice(username.as_bytes());
+            request.push(secret.len() as u8);
+            request.extend_from_slice(secret.as_bytes());
+            stream.write_all(&request).await?;
+            stream.read_exact(&mut reply).await?;
+            if reply[1] != 0 {
+                return Err(proxy_error("SOCKS5 proxy authentication failed"));
+            }
+        }
+        _ => {
+            return Err(proxy_error(
+                "SOCKS5 proxy does not support the requested authentication method",
+            ));
+        }
+    }
+
+    let mut request = Vec::with_capacity(22);
+    request.extend_from_slice(&[5, 1, 0]);
+    match remote_addr.ip() {
+        IpAddr::V4(ip) => {
+            request.push(1);
+            request.extend_from_slice(&ip.octets());
+        }
+        IpAddr::V6(ip) => {
+            request.push(4);
+            request.extend_from_slice(&ip.octets());
+        }
+    }
+    request.extend_from_slice(&remote_addr.port().to_be_bytes());
+    stream.write_all(&request).await?;
+
+    let mut reply = [0u8; 4];
+    stream.read_exact(&mut reply).await?;
+    if reply[0] != 5 || reply[1] != 0 {
+        return Err(proxy_error(format!(
+            "SOCKS5 proxy refused connection with code {}",
+            reply[1]
+        )));
+    }
+
+    // Skip the bound address and port
+    let addr_len = match reply[3] {
+        1 => 4,
+        4 => 16,
+        3 => stream.read_u8().await? as usize,
+        _ => return Err(proxy_error("Invalid SOCKS5 proxy reply")),
+    };
+    let mut bound_addr = vec![0u8; addr_len + 2];
+    stream.read_exact(&mut bound_addr).await?;
+
+    Ok(())
+}
+
+async fn http_connect_handshake(
+    stream: &mut TcpStream,
+    auth: Option<&(String, String)>,
+    remote_addr: SocketAddr,
+) -> ClientResult<()> {
+    let mut request = format!("CONNECT {remote_addr} HTTP/1.1\r\nHost: {remote_addr}\r\n");
+    if let Some((username, secret)) = auth {
+        request.push_str("Proxy-Authorization: Basic ");
+        request.push_str(&general_purpose::STANDARD.encode(format!("{username}:{secret}")));
+        request.push_str("\r\n");
+    }
+    request.push_str("\r\n");
+    stream.write_all(request.as_bytes()).await?;
+
+    // Read the response one byte at a time to avoid consuming the SMTP greeting
+    let mut response = Vec::with_capacity(128);
+    while !response.ends_with(b"\r\n\r\n") {
+        if response.len() >= MAX_RESPONSE_LENGTH {
+            return Err(proxy_error("HTTP proxy response too large"));
+        }
+        response.push(stream.read_u8().await?);
+    }
+
+    let status_line = response
+        .split(|&ch| ch == b'\n')
+        .next()
+        .map(|line| String::from_utf8_lossy(line).trim().to_string())
+        .unwrap_or_default();
+    match status_line.split_ascii_whitespace().nth(1) {
+        Some(code) if code.starts_with('2') && status_line.starts_with("HTTP/1.") => Ok(()),
+        _ => Err(proxy_error(format!(
+            "HTTP proxy refused connection: {status_line}"
+        ))),
+    }
+}
+
+fn proxy_error(reason: impl Into<String>) -> ClientError {
+    ClientError::Io(std::io::Error::other(reason.into()))
+}
+
 impl SmtpClient<TlsStream<TcpStream>> {
     pub fn tls_connection(&self) -> &ClientConnection {
         self.stream.get_ref().1
@@ -14,12 +14,14 @@ use crate::outbound::error::ClientError;
 use crate::outbound::lookup::{DnsLookup, SourceIp};
 use crate::outbound::mta_sts::lookup::MtaStsLookup;
 use crate::outbound::mta_sts::verify::VerifyPolicy;
+use crate::outbound::trace::DeliveryTracer;
 use crate::outbound::{client::StartTlsResult, dane::verify::TlsaVerify};
 use crate::queue::dsn::SendDsn;
-use crate::queue::spool::SmtpSpool;
+use crate::queue::spool::{DueMessage, SmtpSpool};
 use crate::queue::throttle::IsAllowed;
 use crate::queue::{
-    Error, FROM_REPORT, HostResponse, MessageWrapper, QueueEnvelope, QueuedMessage, Status,
+    Error, FROM_REPORT, HostResponse, MessageWrapper, QueueEnvelope, QueuedMessage,
+    RCPT_LOCAL_DELIVERED, Status,
 };
 use crate::reporting::send::MtaReportSend;
 use crate::{queue::ErrorDetails, reporting::tls::TlsRptOptions};
@@ -48,7 +50,10 @@ impl QueuedMessage {
             // Lock queue event
             let queue_id = self.queue_id;
             let status = if server.try_lock_event(queue_id, self.queue_name).await {
-                if let Some(mut message) = server.read_message(queue_id, self.queue_name).await {
+                let message = server
+                    .read_due_message(queue_id, self.queue_name, self.due)
+                    .await;
+                if let DueMessage::Due(mut message) = message {
                     // Generate span id
                     message.span_id = server.inner.data.span_id_gen.generate();
                     let span_id = message.span_id;
@@ -97,6 +102,11 @@ impl QueuedMessage {
                     server.unlock_event(queue_id, self.queue_name).await;
 
                     queue_event
+                } else if let DueMessage::Rescheduled = message {
+                    // Nothing due yet, the event was moved to the next due time.
+                    server.unlock_event(queue_id, self.queue_name).await;
+
+                    QueueEventStatus::Deferred
                 } else {
                     // Message no longer exists, delete queue event.
                     let mut batch = BatchBuilder::new();
@@ -132,6 +142,7 @@ impl QueuedMessage {
                 .send(QueueEvent::WorkerDone {
                     queue_id,
                     queue_name: self.queue_name,
+                    priority: self.priority,
                     status,
                 })
                 .await
@@ -243,12 +254,15 @@ impl QueuedMessage {
 
         let no_ip = IpAddr::V4(Ipv4Addr::new(0, 0, 0, 0));
         let mut delivery_results: Vec<DeliveryResult> = Vec::new();
-        'next_route: for ((domain, route), rcpt_idxs) in routes {
+        let mut local_copies: Vec<usize> = Vec::new();
+        let mut tracer = DeliveryTracer::new(&server);
+        'next_route: for ((domain, route), mut rcpt_idxs) in routes {
             trc::event!(
                 Delivery(DeliveryEvent::DomainDeliveryStart),
                 SpanId = message.span_id,
                 Domain = domain.to_string(),
             );
+            tracer.begin_domain(domain, &rcpt_idxs);
 
             // Build envelope
             let mut envelope =
@@ -273,6 +287,11 @@ impl QueuedMessage {
             }
 
             // Obtain next hop
+            let proxy = match route {
+                RoutingStrategy::Mx(mx_config) => mx_config.proxy.as_ref(),
+                RoutingStrategy::Relay(relay_config) => relay_config.proxy.as_ref(),
+                _ => None,
+            };
             let (mut remote_hosts, mx_config, is_smtp) = match route {
                 RoutingStrategy::Local => {
                     // Deliver message locally
@@ -287,6 +306,48 @@ impl QueuedMessage {
                     None,
                     relay_config.protocol == ServerProtocol::Smtp,
                 ),
+                RoutingStrategy::LmtpExternal(lmtp_config) => {
+                    // Store a local copy first, recipients that already got one
+                    // in a previous attempt are not delivered locally again
+                    if lmtp_config.deliver_local {
+                        let pending_idxs = rcpt_idxs
+                            .iter()
+                            .copied()
+                            .filter(|&rcpt_idx| {
+                                message.message.recipients[rcpt_idx].flags & RCPT_LOCAL_DELIVERED
+                                    == 0
+                            })
+                            .collect::<Vec<_>>();
+                        let mut local_results = Vec::with_capacity(pending_idxs.len());
+                        message
+                            .deliver_local(&pending_idxs, &mut local_results, &server)
+                            .await;
+                        for result in local_results {
+                            match result {
+                                DeliveryResult::Account {
+                                    status: Status::Completed(_),
+                                    rcpt_idx,
+                                } => {
+                                    local_copies.push(rcpt_idx);
+                                }
+                                DeliveryResult::Account { rcpt_idx, .. } => {
+                                    // Recipients that could not be stored locally are
+                                    // not handed over to the external agent
+                                    rcpt_idxs.retain(|&idx| idx != rcpt_idx);
+                                    delivery_results.push(result);
+                                }
+                                result => {
+                                    delivery_results.push(result);
+                                }
+                            }
+                        }
+                        if rcpt_idxs.is_empty() {
+                            continue 'next_route;
+                        }
+                    }
+
+                    (vec![NextHop::Relay(&lmtp_config.relay)], None, false)
+                }
             };
 
             // Prepare TLS strategy
@@ -545,6 +606,7 @@ impl QueuedMessage {
                         Elapsed = time.elapsed(),
                     );
                     remote_hosts = remote_hosts_;
+                    tracer.mx_hosts(remote_hosts.iter().map(|host| host.hostname()));
                 } else {
                     trc::event!(
                         Delivery(DeliveryEvent::NullMx),
@@ -571,6 +633,7 @@ impl QueuedMessage {
             'next_host: for remote_host in &remote_hosts {
                 // Validate MTA-STS
                 envelope.mx = remote_host.hostname();
+                tracer.begin_host(envelope.mx, &last_status);
                 if let Some(mta_sts_policy) = &mta_sts_policy {
                     let strict = mta_sts_policy.enforce();
                     if !mta_sts_policy.verify(envelope.mx) {
@@ -647,6 +710,7 @@ impl QueuedMessage {
                             Limit = remote_host.max_multi_homed(),
                             Elapsed = time.elapsed(),
                         );
+                        tracer.resolved_ips(&result.remote_ips);
 
                         result
                     }
@@ -842,6 +906,7 @@ impl QueuedMessage {
                 'next_ip: for remote_ip in resolve_result.remote_ips {
                     // Throttle remote host
                     envelope.remote_ip = remote_ip;
+                    tracer.remote_ip(remote_ip);
                     for throttle in &queue_config.outbound_limiters.remote {
                         if let Err(retry_at) = server
                             .is_allowed(throttle, &envelope, message.span_id)
@@ -872,12 +937,25 @@ impl QueuedMessage {
                         message.span_id,
                     );
 
-                    // Set source IP, if any
-                    let ip_host = conn_strategy.source_ip(remote_ip.is_ipv4());
+                    // Set source IP, if any. Proxied connections originate from the proxy.
+                    let ip_host = if proxy.is_none() {
+                        conn_strategy.source_ip(remote_ip.is_ipv4())
+                    } else {
+                        None
+                    };
 
                     // Connect
                     let time = Instant::now();
-                    let mut smtp_client = match if let Some(ip_host) = ip_host {
+                    let mut smtp_client = match if let Some(proxy) = proxy {
+                        envelope.local_ip = no_ip;
+                        SmtpClient::connect_proxy(
+                            proxy,
+                            SocketAddr::new(remote_ip, remote_host.port()),
+                            conn_strategy.timeout_connect,
+                            span_id,
+                        )
+                        .await
+                    } else if let Some(ip_host) = ip_host {
                         envelope.local_ip = ip_host.ip;
                         SmtpClient::connect_using(
                             ip_host.ip,
@@ -949,8 +1027,10 @@ impl QueuedMessage {
                         || mta_sts_policy.is_some()
                         || dane_policy.is_some();
                     // As per RFC7671 Section 5.1, DANE-EE(3) allows name mismatch
+                    // Pinned relays are verified against their fingerprint instead
                     let tls_connector = if tls_strategy.allow_invalid_certs
                         || remote_host.allow_invalid_certs()
+                        || remote_host.tls_fingerprint().is_some()
                         || dane_policy.as_ref().is_some_and(|t| t.has_end_entities)
                     {
                         &server.inner.data.smtp_connectors.dummy_verify
@@ -986,6 +1066,7 @@ impl QueuedMessage {
                                     Details = capabilities.capabilities(),
                                     Elapsed = time.elapsed(),
                                 );
+                                tracer.capabilities(&capabilities);
 
                                 capabilities
                             }
@@ -1034,6 +1115,10 @@ impl QueuedMessage {
                                         ),
                                         Elapsed = time.elapsed(),
                                     );
+                                    tracer.tls(
+                                        smtp_client.tls_connection().protocol_version(),
+                                        smtp_client.tls_connection().negotiated_cipher_suite(),
+                                    );
 
                                     // Verify DANE
                                     if let Some(dane_policy) = &dane_policy
@@ -1069,6 +1154,22 @@ impl QueuedMessage {
                                         continue 'next_host;
                                     }
 
+                                    // Verify pinned certificate
+                                    if let Err(status) = remote_host.verify_fingerprint(
+                                        smtp_client.tls_connection().peer_certificates(),
+                                    ) {
+                                        trc::event!(
+                                            Delivery(DeliveryEvent::StartTlsError),
+                                            SpanId = message.span_id,
+                                            Domain = domain.to_string(),
+                                            Hostname = envelope.mx.to_string(),
+                                            Reason = from_error_status(&status),
+                                        );
+
+                                        last_status = status;
+                                        continue 'next_host;
+                                    }
+
                                     // Report TLS success
                                     if let Some(tls_report) = &tls_report {
                                         server
@@ -1211,7 +1312,13 @@ impl QueuedMessage {
                         smtp_client.timeout = tls_strategy.timeout_tls;
                         let mut smtp_client =
                             match smtp_client.into_tls(tls_connector, envelope.mx).await {
-                                Ok(smtp_client) => smtp_client,
+                                Ok(smtp_client) => {
+                                    tracer.tls(
+                                        smtp_client.tls_connection().protocol_version(),
+                                        smtp_client.tls_connection().negotiated_cipher_suite(),
+                                    );
+                                    smtp_client
+                                }
                                 Err(error) => {
                                     trc::event!(
                                         Delivery(DeliveryEvent::ImplicitTlsError),
@@ -1226,6 +1333,22 @@ impl QueuedMessage {
                                 }
                             };
 
+                        // Verify pinned certificate
+                        if let Err(status) = remote_host
+                            .verify_fingerprint(smtp_client.tls_connection().peer_certificates())
+                        {
+                            trc::event!(
+                                Delivery(DeliveryEvent::ImplicitTlsError),
+                                SpanId = message.span_id,
+                                Domain = domain.to_string(),
+                                Hostname = envelope.mx.to_string(),
+                                Reason = from_error_status(&status),
+                            );
+
+                            last_status = status;
+                            continue 'next_host;
+                        }
+
                         // Read greeting
                         smtp_client.timeout = conn_strategy.timeout_greeting;
                         if let Err(status) = smtp_client.read_greeting(envelope.mx).await {
@@ -1253,6 +1376,7 @@ impl QueuedMessage {
             }
 
             // Update status
+            tracer.host_failed(&last_status);
             delivery_results.push(DeliveryResult::domain(last_status, rcpt_idxs));
         }
 
@@ -1280,6 +1404,15 @@ impl QueuedMessage {
             }
         }
 
+        // Local copies are kept track of to avoid duplicates when the
+        // external delivery is retried
+        for rcpt_idx in local_copies {
+            message.message.recipients[rcpt_idx].flags |= RCPT_LOCAL_DELIVERED;
+        }
+
+        // Store delivery trace
+        tracer.save(&server, &message).await;
+
         // Send Delivery Status Notifications
         server.send_dsn(&mut message).await;
 
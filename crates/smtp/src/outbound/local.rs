@@ -8,8 +8,8 @@ use crate::{
     outbound::DeliveryResult,
     queue::{
         Error, ErrorDetails, FROM_AUTHENTICATED, FROM_UNAUTHENTICATED_DMARC, HostResponse,
-        MessageSource, MessageWrapper, RCPT_SPAM_PAYLOAD, Status, UnexpectedResponse,
-        quota::HasQueueQuota, spool::SmtpSpool,
+        MessageSource, MessageWrapper, RCPT_QUARANTINE, RCPT_SPAM_PAYLOAD, Status,
+        UnexpectedResponse, quota::HasQueueQuota, spool::SmtpSpool,
     },
     reporting::send::MtaReportSend,
 };
@@ -35,6 +35,7 @@ impl MessageWrapper {
                 address: rcpt_addr.to_lowercase(),
                 orcpt: rcpt.orcpt.as_ref().map(|orcpt| orcpt.to_string()),
                 is_spam: rcpt.flags & RCPT_SPAM_PAYLOAD != 0,
+                is_quarantined: rcpt.flags & RCPT_QUARANTINE != 0,
             });
             pending_recipients.push((rcpt_idx, rcpt_addr));
         }
@@ -111,11 +111,16 @@ impl DnsLookup for Server {
         remote_host: &NextHop<'_>,
         envelope: &impl ResolveVariable,
     ) -> Result<IpLookupResult, Status<HostResponse<Box<str>>, ErrorDetails>> {
+        let ipv6_only = self.core.network.ipv6_only;
         let mut remote_ips = match remote_host.fqdn_hostname() {
             HostOrIp::Host(hostname) => self
                 .ip_lookup(
                     hostname.as_ref(),
-                    remote_host.ip_lookup_strategy(),
+                    if ipv6_only {
+                        IpLookupStrategy::Ipv6Only
+                    } else {
+                        remote_host.ip_lookup_strategy()
+                    },
                     remote_host.max_multi_homed(),
                 )
                 .await
@@ -150,6 +155,19 @@ impl DnsLookup for Server {
             HostOrIp::Ip(ip) => vec![ip],
         };
 
+        // IPv6-only deployments have no IPv4 route to the remote host
+        if ipv6_only && remote_ips.iter().any(|ip| ip.is_ipv4()) {
+            remote_ips.retain(|ip| ip.is_ipv6());
+            if remote_ips.is_empty() {
+                return Err(Status::PermanentFailure(ErrorDetails {
+                    entity: remote_host.hostname().into(),
+                    details: Error::ConnectionError(
+                        "host has no IPv6 address and IPv4 is disabled".into(),
+                    ),
+                }));
+            }
+        }
+
         if !remote_ips.is_empty() {
             #[cfg(not(feature = "test_mode"))]
             if remote_ips.iter().any(|ip| ip.is_loopback()) {
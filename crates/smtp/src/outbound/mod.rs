@@ -14,6 +14,8 @@ use common::config::{
 };
 use directory::Credentials;
 use mail_auth::IpLookupStrategy;
+use rustls_pki_types::CertificateDer;
+use sha2::{Digest, Sha256};
 use smtp_proto::{Response, Severity};
 use std::{borrow::Cow, net::IpAddr};
 
@@ -25,6 +27,7 @@ pub mod local;
 pub mod lookup;
 pub mod mta_sts;
 pub mod session;
+pub mod trace;
 
 pub(super) enum DeliveryResult {
     Domain {
@@ -288,7 +291,7 @@ impl NextHop<'_> {
     }
 
     #[inline(always)]
-    fn port(&self) -> u16 {
+    pub(crate) fn port(&self) -> u16 {
         match self {
             #[cfg(feature = "test_mode")]
             NextHop::MX { .. } => 9925,
@@ -299,7 +302,7 @@ impl NextHop<'_> {
     }
 
     #[inline(always)]
-    fn credentials(&self) -> Option<&Credentials> {
+    pub(crate) fn credentials(&self) -> Option<&Credentials> {
         match self {
             NextHop::MX { .. } => None,
             NextHop::Relay(host) => host.auth.as_ref(),
@@ -307,7 +310,7 @@ impl NextHop<'_> {
     }
 
     #[inline(always)]
-    fn allow_invalid_certs(&self) -> bool {
+    pub(crate) fn allow_invalid_certs(&self) -> bool {
         #[cfg(feature = "test_mode")]
         {
             true
@@ -320,7 +323,39 @@ impl NextHop<'_> {
     }
 
     #[inline(always)]
-    fn implicit_tls(&self) -> bool {
+    fn tls_fingerprint(&self) -> Option<&[u8; 32]> {
+        match self {
+            NextHop::MX { .. } => None,
+            NextHop::Relay(host) => host.tls_fingerprint.as_ref(),
+        }
+    }
+
+    fn verify_fingerprint(
+        &self,
+        certificates: Option<&[CertificateDer<'_>]>,
+    ) -> Result<(), Status<HostResponse<Box<str>>, ErrorDetails>> {
+        let Some(fingerprint) = self.tls_fingerprint() else {
+            return Ok(());
+        };
+
+        // Compare the SHA-256 digest of the leaf certificate
+        if certificates
+            .and_then(|certificates| certificates.first())
+            .is_some_and(|certificate| Sha256::digest(certificate.as_ref())[..] == fingerprint[..])
+        {
+            Ok(())
+        } else {
+            Err(Status::TemporaryFailure(ErrorDetails {
+                entity: self.hostname().into(),
+                details: Error::TlsError(
+                    "Certificate does not match the pinned fingerprint".into(),
+                ),
+            }))
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn implicit_tls(&self) -> bool {
         match self {
             NextHop::MX { .. } => false,
             NextHop::Relay(host) => host.tls_implicit,
@@ -328,7 +363,7 @@ impl NextHop<'_> {
     }
 
     #[inline(always)]
-    fn is_smtp(&self) -> bool {
+    pub(crate) fn is_smtp(&self) -> bool {
         match self {
             NextHop::MX { .. } => true,
             NextHop::Relay(host) => host.protocol == ServerProtocol::Smtp,
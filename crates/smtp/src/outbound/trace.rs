@@ -0,0 +1,216 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::queue::{ErrorDetails, HostResponse, MessageWrapper, Status};
+use common::Server;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::{DeliveryTrace, DeliveryTraceHost, DeliveryTraceResult},
+    },
+    types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, index::IndexBuilder, ipaddr},
+};
+use smtp_proto::{
+    EXT_8BIT_MIME, EXT_AUTH, EXT_BINARY_MIME, EXT_CHUNKING, EXT_DELIVER_BY, EXT_DSN,
+    EXT_ENHANCED_STATUS_CODES, EXT_EXPN, EXT_FUTURE_RELEASE, EXT_MT_PRIORITY, EXT_NO_SOLICITING,
+    EXT_PIPELINING, EXT_REQUIRE_TLS, EXT_SIZE, EXT_SMTP_UTF8, EXT_START_TLS, EXT_VRFY,
+    EhloResponse,
+};
+use std::{fmt::Debug, net::IpAddr};
+use store::write::{BatchBuilder, RegistryClass, ValueClass, now};
+use trc::AddContext;
+use types::id::Id;
+
+const EXTENSIONS: &[(u32, &str)] = &[
+    (EXT_8BIT_MIME, "8BITMIME"),
+    (EXT_AUTH, "AUTH"),
+    (EXT_BINARY_MIME, "BINARYMIME"),
+    (EXT_CHUNKING, "CHUNKING"),
+    (EXT_DELIVER_BY, "DELIVERBY"),
+    (EXT_DSN, "DSN"),
+    (EXT_ENHANCED_STATUS_CODES, "ENHANCEDSTATUSCODES"),
+    (EXT_EXPN, "EXPN"),
+    (EXT_FUTURE_RELEASE, "FUTURERELEASE"),
+    (EXT_MT_PRIORITY, "MT-PRIORITY"),
+    (EXT_NO_SOLICITING, "NO-SOLICITING"),
+    (EXT_PIPELINING, "PIPELINING"),
+    (EXT_REQUIRE_TLS, "REQUIRETLS"),
+    (EXT_SIZE, "SIZE"),
+    (EXT_SMTP_UTF8, "SMTPUTF8"),
+    (EXT_START_TLS, "STARTTLS"),
+    (EXT_VRFY, "VRFY"),
+];
+
+pub trait DeliveryTraceIndex {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool);
+}
+
+impl DeliveryTraceIndex for DeliveryTrace {
+    fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
+        let object_id = ObjectType::DeliveryTrace.to_id();
+        let mut index_builder = IndexBuilder::default();
+        index_builder.search(Property::QueueId, self.queue_id.id());
+        index_builder.search(Property::Domain, &self.domain);
+        index_builder.search(Property::Timestamp, self.timestamp.timestamp());
+        index_builder.search(Property::ExpiresAt, self.expires_at.timestamp());
+        batch.registry_index(object_id, item_id, index_builder.keys.iter(), is_set);
+
+        let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
+        if is_set {
+            batch.set(key, self.to_pickled_vec());
+        } else {
+            batch.clear(key);
+        }
+    }
+}
+
+// Records a delivery attempt, one trace is kept for each destination domain
+// along with the recipients routed to it.
+pub(crate) struct DeliveryTracer {
+    traces: Vec<(Vec<usize>, DeliveryTrace)>,
+    is_enabled: bool,
+}
+
+impl DeliveryTracer {
+    pub fn new(server: &Server) -> Self {
+        DeliveryTracer {
+            traces: Vec::new(),
+            is_enabled: server.core.smtp.queue.trace_retention.is_some(),
+        }
+    }
+
+    pub fn begin_domain(&mut self, domain: &str, rcpt_idxs: &[usize]) {
+        if self.is_enabled {
+            self.traces.push((
+                rcpt_idxs.to_vec(),
+                DeliveryTrace {
+                    domain: domain.to_string(),
+                    ..Default::default()
+                },
+            ));
+        }
+    }
+
+    pub fn mx_hosts<'x>(&mut self, hostnames: impl Iterator<Item = &'x str>) {
+        if let Some(trace) = self.trace() {
+            trace.mx_hosts = hostnames.map(|host| host.to_string()).collect();
+        }
+    }
+
+    // Hosts are only tried after the previous one failed
+    pub fn begin_host(
+        &mut self,
+        hostname: &str,
+        last_status: &Status<HostResponse<Box<str>>, ErrorDetails>,
+    ) {
+        self.host_failed(last_status);
+        if let Some(trace) = self.trace() {
+            trace.hosts.push(DeliveryTraceHost {
+                hostname: hostname.to_string(),
+                ..Default::default()
+            });
+        }
+    }
+
+    pub fn host_failed(&mut self, status: &Status<HostResponse<Box<str>>, ErrorDetails>) {
+        if let Some(host) = self.host()
+            && host.error.is_none()
+            && matches!(
+                status,
+                Status::TemporaryFailure(_) | Status::PermanentFailure(_)
+            )
+        {
+            host.error = Some(status.to_string());
+        }
+    }
+
+    pub fn resolved_ips(&mut self, ips: &[IpAddr]) {
+        if let Some(host) = self.host() {
+            for ip in ips {
+                host.resolved_ips.push(ipaddr::IpAddr(*ip));
+            }
+        }
+    }
+
+    pub fn remote_ip(&mut self, ip: IpAddr) {
+        if let Some(host) = self.host() {
+            host.remote_ip = Some(ipaddr::IpAddr(ip));
+        }
+    }
+
+    pub fn capabilities(&mut self, capabilities: &EhloResponse<String>) {
+        if let Some(host) = self.host() {
+            host.capabilities.clear();
+            for (capability, name) in EXTENSIONS {
+                if capabilities.has_capability(*capability) {
+                    host.capabilities.push(name.to_string());
+                }
+            }
+        }
+    }
+
+    pub fn tls(&mut self, version: Option<impl Debug>, cipher: Option<impl Debug>) {
+        if let Some(host) = self.host() {
+            host.tls_version = version.map(|version| format!("{version:?}"));
+            host.tls_cipher = cipher.map(|cipher| format!("{cipher:?}"));
+        }
+    }
+
+    pub async fn save(self, server: &Server, message: &MessageWrapper) {
+        let Some(retention) = server.core.smtp.queue.trace_retention else {
+            return;
+        };
+        if self.traces.is_empty() {
+            return;
+        }
+
+        let timestamp = now();
+        let mut batch = BatchBuilder::new();
+        for (rcpt_idxs, mut trace) in self.traces {
+            trace.queue_id = Id::from(message.queue_id);
+            trace.timestamp = UTCDateTime::from_timestamp(timestamp as i64);
+            trace.expires_at =
+                UTCDateTime::from_timestamp((timestamp + retention.as_secs()) as i64);
+            for rcpt in rcpt_idxs
+                .into_iter()
+                .filter_map(|idx| message.message.recipients.get(idx))
+            {
+                trace.results.push(DeliveryTraceResult {
+                    recipient: rcpt.address().to_string(),
+                    status: rcpt.status.to_string(),
+                });
+            }
+
+            trace.write_ops(
+                &mut batch,
+                server.inner.data.registry_id_gen.generate(),
+                true,
+            );
+            batch.commit_point();
+        }
+
+        if let Err(err) = server
+            .store()
+            .write(batch.build_all())
+            .await
+            .caused_by(trc::location!())
+        {
+            trc::error!(
+                err.span_id(message.span_id)
+                    .details("Failed to write delivery trace")
+            );
+        }
+    }
+
+    fn trace(&mut self) -> Option<&mut DeliveryTrace> {
+        self.traces.last_mut().map(|(_, trace)| trace)
+    }
+
+    fn host(&mut self) -> Option<&mut DeliveryTraceHost> {
+        self.trace()
+            .and_then(|trace| trace.hosts.values_mut().last())
+    }
+}
@@ -9,20 +9,26 @@ use super::{
     Error, ErrorDetails, HostResponse, Message, MessageSource, QueueEnvelope, RCPT_DSN_SENT,
     Recipient, Status,
 };
+use crate::inbound::list::MailingListArchive;
 use crate::queue::{MessageWrapper, UnexpectedResponse};
 use crate::reporting::send::MtaReportSend;
-use common::Server;
+use common::{
+    Server, auth::EmailCache, config::smtp::queue::DsnTemplateVariable, i18n,
+    network::RcptResolution,
+};
 use mail_builder::MessageBuilder;
 use mail_builder::headers::HeaderType;
 use mail_builder::headers::content_type::ContentType;
 use mail_builder::mime::{BodyPart, MimePart, make_boundary};
 use mail_parser::DateTime;
+use registry::schema::enums::Locale;
 use smtp_proto::{
     RCPT_NOTIFY_DELAY, RCPT_NOTIFY_FAILURE, RCPT_NOTIFY_NEVER, RCPT_NOTIFY_SUCCESS, Response,
 };
 use std::fmt::Write;
 use std::future::Future;
 use store::write::now;
+use utils::template::Variables;
 
 pub trait SendDsn: Sync + Send {
     fn send_dsn(&self, message: &mut MessageWrapper) -> impl Future<Output = ()> + Send;
@@ -37,26 +43,36 @@ impl SendDsn for Server {
         if !message.message.return_path.is_empty() {
             // Build DSN
             if let Some(dsn) = message.build_dsn(self).await {
-                let mut dsn_message = self.new_message("", message.span_id);
-                dsn_message
-                    .expand_and_add_recipient(message.message.return_path.as_ref(), self)
-                    .await;
-
-                // Sign message
-                let signature = self
-                    .sign_message(message, &self.core.smtp.queue.dsn.sign, &dsn)
-                    .await;
-
-                // Queue DSN
-                dsn_message
-                    .queue(
-                        signature.as_deref(),
-                        &dsn,
-                        message.span_id,
-                        self,
-                        MessageSource::Dsn,
-                    )
-                    .await;
+                // Reports for list posts are archived rather than queued
+                if let Some((local_part, _)) = message.message.return_path.rsplit_once('@')
+                    && local_part.ends_with("-bounces")
+                    && let Ok(RcptResolution::ListBounce(list)) = self
+                        .rcpt_resolve(&message.message.return_path.to_lowercase(), message.span_id)
+                        .await
+                {
+                    self.receive_list_bounce(&list, &dsn, message.span_id).await;
+                } else {
+                    let mut dsn_message = self.new_message("", message.span_id);
+                    dsn_message
+                        .expand_and_add_recipient(message.message.return_path.as_ref(), self)
+                        .await;
+
+                    // Sign message
+                    let signature = self
+                        .sign_message(message, &self.core.smtp.queue.dsn.sign, &dsn)
+                        .await;
+
+                    // Queue DSN
+                    dsn_message
+                        .queue(
+                            signature.as_deref(),
+                            &dsn,
+                            message.span_id,
+                            self,
+                            MessageSource::Dsn,
+                        )
+                        .await;
+                }
             }
         } else {
             // Handle double bounce
@@ -198,60 +214,6 @@ impl MessageWrapper {
         let has_delay = !txt_delay.is_empty();
         let has_failure = !txt_failed.is_empty();
 
-        let mut txt = String::with_capacity(txt_len + 128);
-        let (subject, is_mixed) = if has_success && !has_delay && !has_failure {
-            txt.push_str(
-                "Your message has been successfully delivered to the following recipients:\r\n\r\n",
-            );
-            ("Successfully delivered message", false)
-        } else if has_delay && !has_success && !has_failure {
-            txt.push_str("There was a temporary problem delivering your message to the following recipients:\r\n\r\n");
-            ("Warning: Delay in message delivery", false)
-        } else if has_failure && !has_success && !has_delay {
-            txt.push_str(
-                "Your message could not be delivered to the following recipients:\r\n\r\n",
-            );
-            ("Failed to deliver message", false)
-        } else if has_success {
-            txt.push_str("Your message has been partially delivered:\r\n\r\n");
-            ("Partially delivered message", true)
-        } else {
-            txt.push_str("Your message could not be delivered to some recipients:\r\n\r\n");
-            (
-                "Warning: Temporary and permanent failures during message delivery",
-                true,
-            )
-        };
-
-        if has_success {
-            if is_mixed {
-                txt.push_str(
-                    "    ----- Delivery to the following addresses was successful -----\r\n",
-                );
-            }
-
-            txt.push_str(&txt_success);
-            txt.push_str("\r\n");
-        }
-
-        if has_delay {
-            if is_mixed {
-                txt.push_str(
-                    "    ----- There was a temporary problem delivering to these addresses -----\r\n",
-                );
-            }
-            txt.push_str(&txt_delay);
-            txt.push_str("\r\n");
-        }
-
-        if has_failure {
-            if is_mixed {
-                txt.push_str("    ----- Delivery to the following addresses failed -----\r\n");
-            }
-            txt.push_str(&txt_failed);
-            txt.push_str("\r\n");
-        }
-
         // Obtain hostname and sender addresses
         let from_name = server
             .eval_if(&config.dsn.name, &self.message, self.span_id)
@@ -270,6 +232,78 @@ impl MessageWrapper {
             .await
             .unwrap_or_else(|| String::from("localhost"));
 
+        // Localize the report using the sender's language, when the sender is local
+        let (domain_id, tenant_id, sender_locale) = self.dsn_sender_scope(server).await;
+        let locale = i18n::locale_or_default(sender_locale.as_str());
+        let (subject, summary, is_mixed) = if has_success && !has_delay && !has_failure {
+            (
+                locale.dsn_subject_delivered,
+                locale.dsn_summary_delivered,
+                false,
+            )
+        } else if has_delay && !has_success && !has_failure {
+            (
+                locale.dsn_subject_delayed,
+                locale.dsn_summary_delayed,
+                false,
+            )
+        } else if has_failure && !has_success && !has_delay {
+            (locale.dsn_subject_failed, locale.dsn_summary_failed, false)
+        } else if has_success {
+            (locale.dsn_subject_partial, locale.dsn_summary_partial, true)
+        } else {
+            (locale.dsn_subject_mixed, locale.dsn_summary_mixed, true)
+        };
+
+        let (subject, txt) =
+            if let Some(template) = config.dsn.template(domain_id, tenant_id, sender_locale) {
+                let mut variables = Variables::new();
+                variables.insert_single(
+                    DsnTemplateVariable::Sender,
+                    self.message.return_path.as_ref(),
+                );
+                variables.insert_single(DsnTemplateVariable::ReportingMta, reporting_mta.as_str());
+                variables.insert_single(DsnTemplateVariable::Summary, summary);
+                if has_success {
+                    variables.insert_single(DsnTemplateVariable::Delivered, txt_success.as_str());
+                }
+                if has_delay {
+                    variables.insert_single(DsnTemplateVariable::Delayed, txt_delay.as_str());
+                }
+                if has_failure {
+                    variables.insert_single(DsnTemplateVariable::Failed, txt_failed.as_str());
+                }
+
+                (
+                    template
+                        .subject
+                        .as_ref()
+                        .map(|subject| subject.eval_plain(&variables))
+                        .unwrap_or_else(|| subject.to_string()),
+                    template.body.eval_plain(&variables),
+                )
+            } else {
+                let mut txt = String::with_capacity(txt_len + 128);
+                txt.push_str(summary);
+                txt.push_str("\r\n\r\n");
+
+                for (has_section, section, text) in [
+                    (has_success, locale.dsn_section_delivered, &txt_success),
+                    (has_delay, locale.dsn_section_delayed, &txt_delay),
+                    (has_failure, locale.dsn_section_failed, &txt_failed),
+                ] {
+                    if has_section {
+                        if is_mixed {
+                            let _ = write!(txt, "    ----- {section} -----\r\n");
+                        }
+                        txt.push_str(text);
+                        txt.push_str("\r\n");
+                    }
+                }
+
+                (subject.to_string(), txt)
+            };
+
         // Prepare DSN
         let mut dsn_header = String::with_capacity(dsn.len() + 128);
         self.message
@@ -357,6 +391,38 @@ impl MessageWrapper {
             .into()
     }
 
+    async fn dsn_sender_scope(&self, server: &Server) -> (Option<u32>, Option<u32>, Locale) {
+        let Some((_, domain)) = self.message.return_path.rsplit_once('@') else {
+            return (None, None, Locale::default());
+        };
+
+        let result = async {
+            let Some(domain) = server.domain(domain).await? else {
+                return Ok((None, None, Locale::default()));
+            };
+            let locale = match server
+                .rcpt_id_from_email(self.message.return_path.as_ref())
+                .await?
+            {
+                Some(EmailCache::Account(account_id)) => server.account(account_id).await?.locale(),
+                _ => Locale::default(),
+            };
+
+            trc::Result::Ok((Some(domain.id), domain.id_tenant, locale))
+        }
+        .await;
+
+        result.unwrap_or_else(|err| {
+            trc::error!(
+                err.span_id(self.span_id)
+                    .details("Failed to obtain DSN sender details")
+                    .caused_by(trc::location!())
+            );
+
+            (None, None, Locale::default())
+        })
+    }
+
     pub async fn update_next_dsn(&mut self, server: &Server) {
         let now = now();
         let mut notify_changes = Vec::new();
@@ -9,11 +9,12 @@ use crate::queue::{Recipient, spool::LOCK_EXPIRY};
 use ahash::AHashMap;
 use common::{
     BuildServer, Inner,
-    config::smtp::queue::{QueueExpiry, QueueName},
+    config::smtp::queue::{QueueExpiry, QueueName, VirtualQueue},
     ipc::{QueueEvent, QueueEventStatus},
 };
 use rand::{Rng, seq::SliceRandom};
 use std::{
+    cmp::Reverse,
     collections::hash_map::Entry,
     sync::{Arc, atomic::Ordering},
     time::{Duration, Instant},
@@ -34,7 +35,10 @@ pub struct Queue {
 #[derive(Debug)]
 pub struct QueueStats {
     pub in_flight: usize,
+    pub in_flight_low: usize,
     pub max_in_flight: usize,
+    pub priority_threads: usize,
+    pub max_in_flight_low: Option<usize>,
     pub last_warning: Instant,
 }
 
@@ -101,9 +105,14 @@ impl Queue {
                     let server = self.core.build_server();
                     let mut queue_events = server.next_event(self).await;
 
+                    // Higher priority lanes are served first, messages within
+                    // the same lane are delivered in random order
                     if queue_events.messages.len() > 3 {
                         queue_events.messages.shuffle(&mut rand::rng());
                     }
+                    queue_events
+                        .messages
+                        .sort_by_key(|event| Reverse(event.priority));
 
                     for queue_event in &queue_events.messages {
                         // Fetch queue stats
@@ -112,18 +121,19 @@ impl Queue {
                             None => {
                                 let queue_config =
                                     server.get_virtual_queue_or_default(&queue_event.queue_name);
-                                self.stats.insert(
-                                    queue_event.queue_name,
-                                    QueueStats::new(queue_config.threads),
-                                );
+                                self.stats
+                                    .insert(queue_event.queue_name, QueueStats::new(queue_config));
                                 self.stats.get_mut(&queue_event.queue_name).unwrap()
                             }
                         };
 
                         // Enforce concurrency limits
-                        if stats.has_capacity() {
+                        if stats.has_lane_capacity(queue_event.priority) {
                             // Deliver message
                             stats.in_flight += 1;
+                            if queue_event.priority < 0 {
+                                stats.in_flight_low += 1;
+                            }
                             queue_event.try_deliver(server.clone());
                         } else {
                             if stats.last_warning.elapsed() >= BACK_PRESSURE_WARN_INTERVAL {
@@ -161,10 +171,14 @@ impl Queue {
             QueueEvent::WorkerDone {
                 queue_id,
                 queue_name,
+                priority,
                 status,
             } => {
                 let queue_stats = self.stats.get_mut(&queue_name).unwrap();
                 queue_stats.in_flight -= 1;
+                if priority < 0 {
+                    queue_stats.in_flight_low -= 1;
+                }
 
                 match status {
                     QueueEventStatus::Completed => {
@@ -208,8 +222,10 @@ impl Queue {
                 for (name, settings) in &server.core.smtp.queue.virtual_queues {
                     if let Some(stats) = self.stats.get_mut(name) {
                         stats.max_in_flight = settings.threads;
+                        stats.priority_threads = settings.priority_threads;
+                        stats.max_in_flight_low = settings.low_priority_threads;
                     } else {
-                        self.stats.insert(*name, QueueStats::new(settings.threads));
+                        self.stats.insert(*name, QueueStats::new(settings));
                     }
                 }
 
@@ -360,10 +376,13 @@ pub trait SpawnQueue {
 }
 
 impl QueueStats {
-    fn new(max_in_flight: usize) -> Self {
+    fn new(settings: &VirtualQueue) -> Self {
         QueueStats {
             in_flight: 0,
-            max_in_flight,
+            in_flight_low: 0,
+            max_in_flight: settings.threads,
+            priority_threads: settings.priority_threads,
+            max_in_flight_low: settings.low_priority_threads,
             last_warning: Instant::now() - BACK_PRESSURE_WARN_INTERVAL,
         }
     }
@@ -372,4 +391,18 @@ impl QueueStats {
     pub fn has_capacity(&self) -> bool {
         self.in_flight < self.max_in_flight
     }
+
+    // Messages with a positive priority may use all threads, the rest
+    // leave the reserved priority threads free.
+    pub fn has_lane_capacity(&self, priority: i16) -> bool {
+        if priority > 0 {
+            self.has_capacity()
+        } else {
+            self.in_flight + self.priority_threads < self.max_in_flight
+                && (priority == 0
+                    || self
+                        .max_in_flight_low
+                        .is_none_or(|max| self.in_flight_low < max))
+        }
+    }
 }
@@ -39,6 +39,7 @@ pub struct QueuedMessage {
     pub due: u64,
     pub queue_id: QueueId,
     pub queue_name: QueueName,
+    pub priority: i16,
 }
 
 #[derive(Debug, Clone)]
@@ -72,6 +73,18 @@ pub struct Message {
     pub quota_keys: Box<[QuotaKey]>,
 }
 
+// Retry state of the recipients handled by a single queue, merged into
+// the stored message when other queues are also delivering it. It is only
+// handed to the merge function of the node saving the message and never
+// stored, the merged result keeps the `Message` layout.
+#[derive(rkyv::Serialize, rkyv::Deserialize, rkyv::Archive, Debug, Clone, PartialEq, Eq)]
+pub struct RecipientUpdate {
+    pub blob_hash: BlobHash,
+    pub total: u32,
+    pub indexes: Vec<u32>,
+    pub recipients: Vec<Recipient>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct MessageWrapper {
     pub queue_id: QueueId,
@@ -125,10 +138,13 @@ pub const FROM_UNAUTHENTICATED_DMARC: u64 = 1 << 34;
 pub const FROM_DSN: u64 = 1 << 35;
 pub const FROM_REPORT: u64 = 1 << 36;
 pub const FROM_AUTOGENERATED: u64 = 1 << 37;
+pub const LARGE_MESSAGE: u64 = 1 << 38;
 
 pub const RCPT_DSN_SENT: u64 = 1 << 32;
 //pub const RCPT_STATUS_CHANGED: u64 = 1 << 33;
 pub const RCPT_SPAM_PAYLOAD: u64 = 1 << 34;
+pub const RCPT_QUARANTINE: u64 = 1 << 35;
+pub const RCPT_LOCAL_DELIVERED: u64 = 1 << 36;
 
 #[derive(
     Debug,
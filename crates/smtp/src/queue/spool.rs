@@ -6,24 +6,24 @@
 
 use super::{
     ArchivedMessage, ArchivedStatus, Message, MessageSource, QueueEnvelope, QueueId, QueuedMessage,
-    QuotaKey, Recipient, Schedule, Status,
+    QuotaKey, Recipient, RecipientUpdate, Schedule, Status,
 };
 use crate::queue::manager::{LockedMessage, Queue};
 use crate::queue::{
     FROM_AUTHENTICATED, FROM_AUTOGENERATED, FROM_DSN, FROM_REPORT, FROM_UNAUTHENTICATED,
-    FROM_UNAUTHENTICATED_DMARC, MessageWrapper,
+    FROM_UNAUTHENTICATED_DMARC, LARGE_MESSAGE, MessageWrapper,
 };
 use ahash::AHashSet;
 use common::config::smtp::queue::{ArchivedQueueExpiry, QueueName};
 use common::ipc::QueueEvent;
 use common::network::RcptResolution;
 use common::{KV_LOCK_QUEUE_MESSAGE, Server};
+use registry::schema::enums::CompressionAlgo;
 use registry::schema::prelude::{ObjectType, Property};
 use registry::schema::structs::SpamTrainingSample;
 use registry::types::datetime::UTCDateTime;
 use registry::types::id::ObjectId;
 use registry::types::{EnumImpl, ObjectImpl};
-use std::borrow::Cow;
 use std::collections::hash_map::Entry;
 use std::future::Future;
 use std::net::{IpAddr, Ipv4Addr};
@@ -49,6 +49,12 @@ pub struct QueuedMessages {
     pub next_refresh: u64,
 }
 
+pub enum DueMessage {
+    Due(MessageWrapper),
+    Rescheduled,
+    NotFound,
+}
+
 pub trait SmtpSpool: Sync + Send {
     fn new_message(&self, return_path: impl AsRef<str>, span_id: u64) -> MessageWrapper;
 
@@ -72,6 +78,13 @@ pub trait SmtpSpool: Sync + Send {
         queue_name: QueueName,
     ) -> impl Future<Output = Option<MessageWrapper>> + Send;
 
+    fn read_due_message(
+        &self,
+        id: QueueId,
+        queue_name: QueueName,
+        prev_event: u64,
+    ) -> impl Future<Output = DueMessage> + Send;
+
     fn read_message_archive(
         &self,
         id: QueueId,
@@ -131,8 +144,8 @@ impl SmtpSpool for Server {
         let result = self
             .store()
             .iterate(
-                IterateParams::new(from_key, to_key).ascending().no_values(),
-                |key, _| {
+                IterateParams::new(from_key, to_key).ascending(),
+                |key, value| {
                     let due = key.deserialize_be_u64(0)?;
 
                     if due <= now {
@@ -180,6 +193,7 @@ impl SmtpSpool for Server {
                                 due,
                                 queue_id,
                                 queue_name,
+                                priority: event_priority(value),
                             });
                         }
 
@@ -272,6 +286,85 @@ impl SmtpSpool for Server {
         }
     }
 
+    async fn read_due_message(
+        &self,
+        queue_id: QueueId,
+        queue_name: QueueName,
+        prev_event: u64,
+    ) -> DueMessage {
+        let archive = match self.read_message_archive(queue_id).await {
+            Ok(Some(archive)) => archive,
+            Ok(None) => return DueMessage::NotFound,
+            Err(err) => {
+                trc::error!(
+                    err.details("Failed to read message.")
+                        .caused_by(trc::location!())
+                );
+
+                return DueMessage::NotFound;
+            }
+        };
+
+        // The archived message is inspected in place and only deserialized when
+        // there is work due for this queue, otherwise the event is moved ahead.
+        let next_event = match archive.unarchive::<Message>() {
+            Ok(message) => message
+                .next_idle_event(queue_name, now())
+                .map(|due| (due, message.priority.to_native())),
+            Err(err) => {
+                trc::error!(
+                    err.details("Failed to read message.")
+                        .caused_by(trc::location!())
+                );
+
+                return DueMessage::NotFound;
+            }
+        };
+
+        if let Some((due, priority)) = next_event {
+            let mut batch = BatchBuilder::new();
+            batch
+                .clear(ValueClass::Queue(QueueClass::MessageEvent(
+                    store::write::QueueEvent {
+                        due: prev_event,
+                        queue_id,
+                        queue_name: queue_name.into_inner(),
+                    },
+                )))
+                .set(
+                    ValueClass::Queue(QueueClass::MessageEvent(store::write::QueueEvent {
+                        due,
+                        queue_id,
+                        queue_name: queue_name.into_inner(),
+                    })),
+                    priority.to_be_bytes().to_vec(),
+                );
+
+            match self.store().write(batch.build_all()).await {
+                Ok(_) => return DueMessage::Rescheduled,
+                Err(err) => {
+                    trc::error!(
+                        err.details("Failed to reschedule queue event.")
+                            .caused_by(trc::location!())
+                            .ctx(trc::Key::QueueId, queue_id)
+                    );
+                }
+            }
+        }
+
+        match archive.deserialize::<Message>() {
+            Ok(message) => DueMessage::Due(MessageWrapper::new(message, queue_id, queue_name)),
+            Err(err) => {
+                trc::error!(
+                    err.details("Failed to read message.")
+                        .caused_by(trc::location!())
+                );
+
+                DueMessage::NotFound
+            }
+        }
+    }
+
     async fn read_message_archive(
         &self,
         id: QueueId,
@@ -299,6 +392,14 @@ impl MessageWrapper {
     }
 }
 
+// Queue events carry the message priority as their value, events written
+// before priority lanes were introduced have no value and use the normal lane.
+fn event_priority(value: &[u8]) -> i16 {
+    value
+        .get(..2)
+        .map_or(0, |bytes| i16::from_be_bytes([bytes[0], bytes[1]]))
+}
+
 fn lock_id(queue_id: QueueId, queue_name: QueueName) -> [u8; 16] {
     let mut id = [0; 16];
     id[..8].copy_from_slice(&queue_id.to_be_bytes());
@@ -316,6 +417,11 @@ impl MessageWrapper {
         source: MessageSource,
     ) -> bool {
         // Set flags
+        let is_authenticated = match &source {
+            MessageSource::Authenticated => Some(true),
+            MessageSource::Unauthenticated { .. } => Some(false),
+            _ => None,
+        };
         let (flags, event, train_spam) = match source {
             MessageSource::Authenticated => (
                 FROM_AUTHENTICATED,
@@ -348,20 +454,17 @@ impl MessageWrapper {
         };
         self.message.flags |= flags;
 
-        // Write blob
-        let message = if let Some(raw_headers) = raw_headers {
-            let mut message = Vec::with_capacity(raw_headers.len() + raw_message.len());
-            message.extend_from_slice(raw_headers);
-            message.extend_from_slice(raw_message);
-            Cow::Owned(message)
-        } else {
-            raw_message.into()
-        };
-        self.message.blob_hash = BlobHash::generate(message.as_ref());
+        // Write blob, the headers and message are not copied into a single buffer
+        let message = raw_headers
+            .into_iter()
+            .chain([raw_message])
+            .collect::<Vec<_>>();
+        let message_len = message.iter().map(|part| part.len()).sum::<usize>();
+        self.message.blob_hash = BlobHash::generate_chained(&message);
 
         // Generate id
         if self.message.size == 0 {
-            self.message.size = message.len() as u64;
+            self.message.size = message_len as u64;
         }
 
         // Reserve and write blob
@@ -386,13 +489,15 @@ impl MessageWrapper {
 
             return false;
         }
+        // Large messages are stored uncompressed, straight from the DATA buffer
+        let compression = if self.message.flags & LARGE_MESSAGE != 0 {
+            CompressionAlgo::None
+        } else {
+            server.core.email.compression
+        };
         if let Err(err) = server
             .blob_store()
-            .put_blob(
-                self.message.blob_hash.as_slice(),
-                message.as_ref(),
-                server.core.email.compression,
-            )
+            .put_blob_chained(self.message.blob_hash.as_slice(), &message, compression)
             .await
         {
             trc::error!(
@@ -428,6 +533,15 @@ impl MessageWrapper {
             Expires = self.message.expires(None).map(trc::Value::Timestamp),
         );
 
+        // Update traffic statistics
+        if let Some(is_authenticated) = is_authenticated {
+            server.inner.data.statistics.record_message(
+                is_authenticated,
+                &self.message.return_path.to_lowercase(),
+                self.message.recipients.iter().map(|r| r.address.as_ref()),
+            );
+        }
+
         // Write message to queue
         let mut batch = BatchBuilder::new();
 
@@ -453,7 +567,7 @@ impl MessageWrapper {
                     queue_id: self.queue_id,
                     queue_name: queue_name.into_inner(),
                 })),
-                Vec::new(),
+                self.message.priority.to_be_bytes().to_vec(),
             );
         }
 
@@ -522,7 +636,7 @@ impl MessageWrapper {
                 BlobOp::Commit {
                     hash: self.message.blob_hash.clone(),
                 },
-                vec![],
+                (message_len as u64).serialize(),
             )
             .set(
                 ValueClass::Queue(QueueClass::Message(self.queue_id)),
@@ -583,6 +697,11 @@ impl MessageWrapper {
                     self.add_expanded_recipient(addr, server).await;
                 }
             }
+            Ok(RcptResolution::List(list)) => {
+                for addr in list.recipients.as_ref() {
+                    self.add_expanded_recipient(addr, server).await;
+                }
+            }
             Ok(_) => {
                 self.add_expanded_recipient(rcpt, server).await;
             }
@@ -642,11 +761,34 @@ impl MessageWrapper {
                     queue_id: self.queue_id,
                     queue_name: queue_name.into_inner(),
                 })),
-                Vec::new(),
+                self.message.priority.to_be_bytes().to_vec(),
             );
         }
 
-        let message_bytes = match Archiver::new(self.message).serialize() {
+        // Messages shared with other queues only write back the recipients
+        // handled by this queue, the rest of the message is left untouched.
+        let value = if self.is_multi_queue {
+            let mut update = RecipientUpdate {
+                blob_hash: self.message.blob_hash,
+                total: self.message.recipients.len() as u32,
+                indexes: Vec::new(),
+                recipients: Vec::new(),
+            };
+            for (rcpt_idx, rcpt) in self
+                .message
+                .recipients
+                .into_iter()
+                .enumerate()
+                .filter(|(_, rcpt)| rcpt.queue == self.queue_name)
+            {
+                update.indexes.push(rcpt_idx as u32);
+                update.recipients.push(rcpt);
+            }
+            Archiver::new(update).serialize()
+        } else {
+            Archiver::new(self.message).serialize()
+        };
+        let value = match value {
             Ok(data) => data,
             Err(err) => {
                 trc::error!(
@@ -657,13 +799,15 @@ impl MessageWrapper {
                 return false;
             }
         };
+        // Merge parameters are built and consumed within the same write, changing
+        // their layout does not affect nodes running other versions as long as
+        // the stored message remains a `Message` archive.
         if self.is_multi_queue {
             batch.merge_fnc(
                 ValueClass::Queue(QueueClass::Message(self.queue_id)),
-                Params::with_capacity(3)
+                Params::with_capacity(2)
                     .with_u64(self.queue_id)
-                    .with_bytes(self.queue_name.into_inner().to_vec())
-                    .with_bytes(message_bytes),
+                    .with_bytes(value),
                 |params, _, bytes| {
                     let mut cur_message = <Archive<AlignedBytes> as Deserialize>::deserialize(
                         bytes.ok_or_else(|| {
@@ -677,25 +821,24 @@ impl MessageWrapper {
                     .and_then(|archive| archive.deserialize::<Message>())
                     .caused_by(trc::location!())?;
 
-                    let new_message_ =
-                        <Archive<AlignedBytes> as Deserialize>::deserialize(params.bytes(2))
+                    let update_ =
+                        <Archive<AlignedBytes> as Deserialize>::deserialize(params.bytes(1))
                             .caused_by(trc::location!())?;
-                    let new_message = new_message_
-                        .unarchive::<Message>()
+                    let update = update_
+                        .unarchive::<RecipientUpdate>()
                         .caused_by(trc::location!())?;
 
-                    if cur_message.blob_hash.as_slice() == new_message.blob_hash.0.as_slice()
-                        && cur_message.recipients.len() == new_message.recipients.len()
+                    if cur_message.blob_hash.as_slice() == update.blob_hash.0.as_slice()
+                        && cur_message.recipients.len() == update.total.to_native() as usize
                     {
-                        let queue_name = params.bytes(1);
-                        for (rcpt_idx, rcpt) in new_message
-                            .recipients
-                            .iter()
-                            .enumerate()
-                            .filter(|(_, rcpt)| rcpt.queue.as_slice() == queue_name)
+                        for (rcpt_idx, rcpt) in update.indexes.iter().zip(update.recipients.iter())
                         {
-                            cur_message.recipients[rcpt_idx] =
-                                rkyv_deserialize(rcpt).caused_by(trc::location!())?;
+                            if let Some(cur_rcpt) = cur_message
+                                .recipients
+                                .get_mut(rcpt_idx.to_native() as usize)
+                            {
+                                *cur_rcpt = rkyv_deserialize(rcpt).caused_by(trc::location!())?;
+                            }
                         }
 
                         Archiver::new(cur_message)
@@ -712,10 +855,7 @@ impl MessageWrapper {
                 },
             );
         } else {
-            batch.set(
-                ValueClass::Queue(QueueClass::Message(self.queue_id)),
-                message_bytes,
-            );
+            batch.set(ValueClass::Queue(QueueClass::Message(self.queue_id)), value);
         }
 
         if let Err(err) = server.store().write(batch.build_all()).await {
@@ -861,6 +1001,25 @@ impl ArchivedMessage {
         next_event
     }
 
+    // Returns the next event of a queue when none of its recipients need
+    // to be retried, notified or expired yet.
+    pub fn next_idle_event(&self, queue: QueueName, now: u64) -> Option<u64> {
+        let has_expired_attempts = self.recipients.iter().any(|rcpt| {
+            matches!(
+                rcpt.status,
+                ArchivedStatus::Scheduled | ArchivedStatus::TemporaryFailure(_)
+            ) && rcpt.queue == queue
+                && matches!(&rcpt.expires, ArchivedQueueExpiry::Attempts(count)
+                    if rcpt.retry.inner.to_native() >= count.to_native())
+        });
+
+        if !has_expired_attempts {
+            self.next_event(Some(queue)).filter(|due| *due > now)
+        } else {
+            None
+        }
+    }
+
     pub fn next_notify_event(&self, queue: Option<QueueName>) -> Option<u64> {
         let mut next_notify = None;
 
@@ -40,7 +40,8 @@ impl IsAllowed for Server {
                 .await
                 .unwrap_or(false)
         {
-            let key = throttle.new_key(envelope, "outbound");
+            let key =
+                throttle.new_key(envelope, "outbound", self.core.network.security.ipv6_prefix);
 
             match self
                 .in_memory_store()
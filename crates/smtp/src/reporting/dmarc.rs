@@ -22,7 +22,10 @@ use mail_auth::{
     SpfResult,
     common::verify::VerifySignature,
     dmarc::{self},
-    report::{AuthFailureType, IdentityAlignment, PolicyPublished, Record, SPFDomainScope},
+    report::{
+        ActionDisposition, AuthFailureType, IdentityAlignment, PolicyOverride,
+        PolicyOverrideReason, PolicyPublished, Record, SPFDomainScope,
+    },
 };
 use registry::{
     schema::{
@@ -48,6 +51,7 @@ impl<T: SessionStream> Session<T> {
         message: &AuthenticatedMessage<'_>,
         auth_results: &AuthenticationResults<'_>,
         rejected: bool,
+        policy_override: Option<&PolicyOverrideReason>,
         dmarc_output: DmarcOutput,
         dkim_output: &[DkimOutput<'_>],
         arc_output: &Option<ArcOutput<'_>>,
@@ -291,6 +295,11 @@ impl<T: SessionStream> Session<T> {
         if let Some(arc_output) = arc_output {
             report_record = report_record.with_arc_output(arc_output);
         }
+        if let Some(policy_override) = policy_override {
+            let policy_evaluated = &mut report_record.row.policy_evaluated;
+            policy_evaluated.disposition = ActionDisposition::None;
+            policy_evaluated.reason.push(policy_override.clone());
+        }
 
         // Submit DMARC report event
         self.server
@@ -657,3 +666,33 @@ impl DmarcReporting for Server {
         }
     }
 }
+
+pub(crate) fn policy_override_str(reason: &PolicyOverrideReason) -> &'static str {
+    match reason.type_ {
+        PolicyOverride::Forwarded => "forwarded",
+        PolicyOverride::SampledOut => "sampled_out",
+        PolicyOverride::TrustedForwarder => "trusted_forwarder",
+        PolicyOverride::MailingList => "mailing_list",
+        PolicyOverride::LocalPolicy => "local_policy",
+        PolicyOverride::Other => "other",
+    }
+}
+
+pub(crate) fn write_policy_override(buf: &mut Vec<u8>, reason: &PolicyOverrideReason) {
+    buf.extend_from_slice(b" policy.override=");
+    buf.extend_from_slice(policy_override_str(reason).as_bytes());
+    if let Some(comment) = reason
+        .comment
+        .as_deref()
+        .filter(|comment| !comment.is_empty())
+    {
+        buf.extend_from_slice(b" (");
+        for ch in comment.chars() {
+            if !matches!(ch, '(' | ')' | '\\' | '\r' | '\n') {
+                let mut bytes = [0u8; 4];
+                buf.extend_from_slice(ch.encode_utf8(&mut bytes).as_bytes());
+            }
+        }
+        buf.push(b')');
+    }
+}
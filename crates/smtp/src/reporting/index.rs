@@ -10,7 +10,7 @@ use registry::{
         prelude::{ObjectType, Property},
         structs::{
             ArfExternalReport, DmarcExternalReport, DmarcInternalReport, Task, TaskDmarcReport,
-            TaskStatus, TaskTlsReport, TlsExternalReport, TlsInternalReport,
+            TaskStatus, TaskTlsReport, TlsExternalReport, TlsInboundReport, TlsInternalReport,
         },
     },
     types::{EnumImpl, ObjectImpl, datetime::UTCDateTime, id::ObjectId, index::IndexBuilder},
@@ -111,8 +111,13 @@ pub trait ExternalReportIndex: ObjectImpl {
 
     fn success_fail_count(&self) -> (u64, u64);
 
+    fn primary_key(&self) -> Option<ValueClass> {
+        None
+    }
+
     fn write_ops(&self, batch: &mut BatchBuilder, item_id: u64, is_set: bool) {
-        let object_id = Self::OBJECT.to_id();
+        let object = Self::OBJECT;
+        let object_id = object.to_id();
         let mut index_builder = IndexBuilder::default();
         for text in self.text() {
             index_builder.text(Property::Text, text);
@@ -131,8 +136,21 @@ pub trait ExternalReportIndex: ObjectImpl {
 
         let key = ValueClass::Registry(RegistryClass::Item { object_id, item_id });
         if is_set {
+            if let Some(pk) = self.primary_key() {
+                batch.set(
+                    pk,
+                    ObjectIdVersioned {
+                        object_id: ObjectId::new(object, item_id.into()),
+                        version: 0,
+                    }
+                    .serialize(),
+                );
+            }
             batch.set(key, self.to_pickled_vec());
         } else {
+            if let Some(pk) = self.primary_key() {
+                batch.clear(pk);
+            }
             batch.clear(key);
         }
     }
@@ -344,6 +362,53 @@ impl ExternalReportIndex for TlsExternalReport {
     }
 }
 
+impl ExternalReportIndex for TlsInboundReport {
+    fn domains(&self) -> impl Iterator<Item = &str> {
+        non_empty(&self.domain).into_iter()
+    }
+
+    fn text(&self) -> impl Iterator<Item = &str> {
+        non_empty(&self.domain)
+            .into_iter()
+            .chain(self.report.policies.iter().flat_map(|p| {
+                p.failure_details
+                    .iter()
+                    .filter_map(|fd| non_empty_opt(&fd.receiving_mx_hostname))
+            }))
+    }
+
+    fn tenant_id(&self) -> Option<Id> {
+        None
+    }
+
+    fn expires_at(&self) -> u64 {
+        self.expires_at.timestamp() as u64
+    }
+
+    fn success_fail_count(&self) -> (u64, u64) {
+        self.report
+            .policies
+            .iter()
+            .fold((0, 0), |(success, fail), policy| {
+                (
+                    success + policy.total_successful_sessions,
+                    fail + policy.total_failed_sessions,
+                )
+            })
+    }
+
+    fn primary_key(&self) -> Option<ValueClass> {
+        Some(ValueClass::Registry(RegistryClass::PrimaryKey {
+            object_id: ObjectType::TlsInboundReport.to_id().into(),
+            index_id: Property::Domain.to_id(),
+            key: KeySerializer::new(self.domain.len() + U64_LEN)
+                .write(self.domain.as_str())
+                .write(self.report.date_range_start.timestamp() as u64)
+                .finalize(),
+        }))
+    }
+}
+
 #[inline(always)]
 fn non_empty(s: &str) -> Option<&str> {
     if s.is_empty() { None } else { Some(s) }
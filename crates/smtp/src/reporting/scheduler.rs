@@ -21,6 +21,7 @@ impl SpawnReport for mpsc::Receiver<ReportingEvent> {
                 match event {
                     ReportingEvent::Dmarc(event) => server.schedule_dmarc(event).await,
                     ReportingEvent::Tls(event) => server.schedule_tls(event).await,
+                    ReportingEvent::TlsInbound(event) => server.schedule_tls_inbound(event).await,
                     ReportingEvent::Stop => break,
                 }
             }
@@ -6,27 +6,34 @@
 
 use super::AggregateTimestamp;
 use crate::{
+    core::SessionData,
     queue::RecipientDomain,
-    reporting::{index::InternalReportIndex, send::MtaReportSend},
+    reporting::{
+        index::{ExternalReportIndex, InternalReportIndex},
+        send::MtaReportSend,
+    },
 };
 use common::{
-    Server, USER_AGENT,
+    KV_RATE_LIMIT_TLS_REPORT, Server, USER_AGENT,
     config::smtp::{
         report::AggregateFrequency,
         resolver::{Mode, MxPattern},
     },
-    ipc::{TlsEvent, ToHash},
+    ipc::{TlsEvent, TlsInboundEvent, ToHash},
 };
 use mail_auth::{
+    SpfOutput, SpfResult,
     flate2::{Compression, write::GzEncoder},
     mta_sts::{ReportUri, TlsRpt},
-    report::tlsrpt::{FailureDetails, PolicyDetails},
+    report::tlsrpt::{FailureDetails, PolicyDetails, ResultType},
 };
 use registry::{
     schema::{
         enums::TlsPolicyType,
         prelude::{ObjectType, Property},
-        structs::{TlsFailureDetails, TlsInternalReport, TlsReport, TlsReportPolicy},
+        structs::{
+            TlsFailureDetails, TlsInboundReport, TlsInternalReport, TlsReport, TlsReportPolicy,
+        },
     },
     types::{EnumImpl, ObjectImpl, datetime::UTCDateTime},
 };
@@ -34,11 +41,11 @@ use reqwest::header::CONTENT_TYPE;
 use std::fmt::Write;
 use std::{future::Future, sync::Arc, time::Duration};
 use store::{
-    SerializeInfallible, ValueKey,
+    SerializeInfallible, U64_LEN, ValueKey,
     registry::ObjectIdVersioned,
-    write::{BatchBuilder, RegistryClass, ValueClass, assert::AssertValue},
+    write::{BatchBuilder, RegistryClass, ValueClass, assert::AssertValue, key::KeySerializer},
 };
-use trc::{AddContext, OutgoingReportEvent};
+use trc::{AddContext, LimitEvent, OutgoingReportEvent, TlsRptEvent};
 
 #[derive(Debug, Clone)]
 pub struct TlsRptOptions {
@@ -63,6 +70,16 @@ pub trait TlsReporting: Sync + Send {
     ) -> impl Future<Output = trc::Result<()>> + Send;
 
     fn schedule_tls(&self, event: Box<TlsEvent>) -> impl Future<Output = ()> + Send;
+
+    fn report_inbound_tls_failure(
+        &self,
+        session: &SessionData,
+        hostname: &str,
+        result_type: ResultType,
+        reason: &str,
+    ) -> impl Future<Output = ()> + Send;
+
+    fn schedule_tls_inbound(&self, event: Box<TlsInboundEvent>) -> impl Future<Output = ()> + Send;
 }
 
 impl TlsReporting for Server {
@@ -485,4 +502,244 @@ impl TlsReporting for Server {
             }
         }
     }
+
+    async fn report_inbound_tls_failure(
+        &self,
+        session: &SessionData,
+        hostname: &str,
+        result_type: ResultType,
+        reason: &str,
+    ) {
+        if self.core.smtp.report.analysis.store.is_none() {
+            return;
+        }
+
+        // The HELO and MAIL FROM identities are chosen by the client, failures are
+        // only attributed to a domain once it has been authenticated by SPF
+        let is_spf_pass = |spf: &Option<SpfOutput>| {
+            spf.as_ref()
+                .is_some_and(|spf| spf.result() == SpfResult::Pass)
+        };
+        let domain = match &session.mail_from {
+            Some(mail_from) if is_spf_pass(&session.spf_mail_from) => {
+                if !mail_from.address.is_empty() {
+                    mail_from.domain.as_str()
+                } else {
+                    session.helo_domain.as_str()
+                }
+            }
+            _ if is_spf_pass(&session.spf_ehlo) => session.helo_domain.as_str(),
+            _ => return,
+        };
+        if domain.is_empty() {
+            return;
+        }
+        let domain = domain.to_lowercase();
+
+        // Limit the failures recorded for each sending domain and IP address
+        if let Some(rate) = &self.core.smtp.report.analysis.tls_failure_rate {
+            let mut ip_key = vec![0u8];
+            ip_key.extend_from_slice(&self.ip_rate_key(&session.remote_ip));
+            let mut domain_key = vec![1u8];
+            domain_key.extend_from_slice(domain.as_bytes());
+
+            for key in [ip_key, domain_key] {
+                match self
+                    .in_memory_store()
+                    .is_rate_allowed(KV_RATE_LIMIT_TLS_REPORT, &key, rate, false)
+                    .await
+                {
+                    Ok(None) => {}
+                    Ok(Some(_)) => {
+                        trc::event!(
+                            Limit(LimitEvent::TooManyRequests),
+                            SpanId = session.session_id,
+                            Domain = domain.clone(),
+                            RemoteIp = session.remote_ip,
+                        );
+                        return;
+                    }
+                    Err(err) => {
+                        trc::error!(err.span_id(session.session_id).caused_by(trc::location!()));
+                        return;
+                    }
+                }
+            }
+        }
+
+        trc::event!(
+            TlsRpt(TlsRptEvent::InboundFailure),
+            SpanId = session.session_id,
+            Domain = domain.clone(),
+            RemoteIp = session.remote_ip,
+            Reason = reason.to_string(),
+        );
+
+        self.schedule_report(TlsInboundEvent {
+            domain,
+            failure: FailureDetails::new(result_type)
+                .with_sending_mta_ip(session.remote_ip)
+                .with_receiving_mx_hostname(hostname)
+                .with_receiving_ip(session.local_ip)
+                .with_failure_reason_code(reason),
+            span_id: session.session_id,
+        })
+        .await;
+    }
+
+    async fn schedule_tls_inbound(&self, event: Box<TlsInboundEvent>) {
+        // Inbound failures are kept alongside received reports and share their retention
+        let Some(expires_in) = self.core.smtp.report.analysis.store else {
+            return;
+        };
+        let object_id = ObjectType::TlsInboundReport.to_id();
+        let date_range_start = AggregateFrequency::Daily.to_timestamp();
+        let pk = ValueClass::Registry(RegistryClass::PrimaryKey {
+            object_id: object_id.into(),
+            index_id: Property::Domain.to_id(),
+            key: KeySerializer::new(event.domain.len() + U64_LEN)
+                .write(event.domain.as_str())
+                .write(date_range_start)
+                .finalize(),
+        });
+        let mut rety_count = 0;
+
+        loop {
+            // Find the report for this domain and day
+            let mut batch = BatchBuilder::new();
+            let report = match self
+                .store()
+                .get_value::<ObjectIdVersioned>(ValueKey::from(pk.clone()))
+                .await
+            {
+                Ok(Some(object_id_v)) => {
+                    match self
+                        .store()
+                        .get_value::<TlsInboundReport>(ValueKey::from(ValueClass::Registry(
+                            RegistryClass::Item {
+                                object_id,
+                                item_id: object_id_v.object_id.id().id(),
+                            },
+                        )))
+                        .await
+                    {
+                        Ok(Some(report)) => Some((object_id_v, report)),
+                        Ok(None) => {
+                            trc::event!(
+                                Store(trc::StoreEvent::NotFound),
+                                Id = object_id_v.object_id.id().id(),
+                                CausedBy = trc::location!(),
+                                Details = "Failed to find inbound TLS report for domain"
+                            );
+
+                            return;
+                        }
+                        Err(err) => {
+                            trc::error!(
+                                err.caused_by(trc::location!())
+                                    .details("Failed to query registry for inbound TLS report")
+                            );
+                            return;
+                        }
+                    }
+                }
+                Ok(None) => None,
+                Err(err) => {
+                    trc::error!(
+                        err.caused_by(trc::location!())
+                            .details("Failed to query registry for inbound TLS report")
+                    );
+                    return;
+                }
+            };
+
+            // Create report if missing
+            let (item_id, mut report, previous) = if let Some((object_id_v, report)) = report {
+                batch.assert_value(pk.clone(), AssertValue::U32(object_id_v.version));
+
+                (
+                    object_id_v.object_id.id().id(),
+                    report.clone(),
+                    Some((object_id_v, report)),
+                )
+            } else {
+                let item_id = self.inner.data.queue_id_gen.generate();
+                let date_range_end = date_range_start + AggregateFrequency::Daily.as_secs();
+
+                let report = TlsInboundReport {
+                    domain: event.domain.clone(),
+                    report: TlsReport {
+                        report_id: format!("{date_range_start}_{}", event.domain),
+                        organization_name: None,
+                        contact_info: None,
+                        date_range_start: UTCDateTime::from_timestamp(date_range_start as i64),
+                        date_range_end: UTCDateTime::from_timestamp(date_range_end as i64),
+                        policies: vec![TlsReportPolicy {
+                            policy_type: TlsPolicyType::NoPolicyFound,
+                            policy_domain: event.domain.clone(),
+                            ..Default::default()
+                        }]
+                        .into(),
+                    },
+                    created_at: UTCDateTime::now(),
+                    expires_at: UTCDateTime::from_timestamp(
+                        (date_range_end + expires_in.as_secs()) as i64,
+                    ),
+                };
+
+                batch.assert_value(pk.clone(), ());
+
+                (item_id, report, None)
+            };
+
+            // Add failure details
+            let Some(policy) = report.report.policies.0.inner.first_mut() else {
+                return;
+            };
+            let policy = &mut policy.value;
+            let mut failure = TlsFailureDetails::from(event.failure.clone());
+            if let Some(idx) = policy
+                .failure_details
+                .0
+                .inner
+                .iter()
+                .position(|d| d.value.eq_except_count(&failure))
+            {
+                policy.failure_details.0.inner[idx]
+                    .value
+                    .failed_session_count += 1;
+            } else {
+                failure.failed_session_count = 1;
+                policy.failure_details.push(failure);
+            }
+            policy.total_failed_sessions += 1;
+
+            // Write entry, replacing the previous index entries
+            if let Some((mut object_id_v, previous)) = previous {
+                previous.write_ops(&mut batch, item_id, false);
+                report.write_ops(&mut batch, item_id, true);
+                object_id_v.version += 1;
+                batch.set(pk.clone(), object_id_v.serialize());
+            } else {
+                report.write_ops(&mut batch, item_id, true);
+            }
+
+            match self.core.storage.data.write(batch.build_all()).await {
+                Ok(_) => {
+                    break;
+                }
+                Err(err) => {
+                    if err.is_assertion_failure() && rety_count < 3 {
+                        rety_count += 1;
+                        continue;
+                    }
+                    trc::error!(
+                        err.caused_by(trc::location!())
+                            .details("Failed to write inbound TLS report")
+                    );
+                    break;
+                }
+            }
+        }
+    }
 }
@@ -276,7 +276,10 @@ impl RunScript for Server {
                         } else {
                             instance.message().raw_message().into()
                         };
-                        if let Some(raw_message) = raw_message.filter(|m| !m.is_empty()) {
+                        // Dry runs never send any messages
+                        if let Some(raw_message) =
+                            raw_message.filter(|m| !m.is_empty() && !params.dry_run)
+                        {
                             let headers = if let Some(sign_domain) = &params.sign_domain {
                                 match self.dkim_signers(sign_domain).await {
                                     Ok(Some(signers)) => {
@@ -69,7 +69,8 @@ impl<T: SessionStream> Session<T> {
             )
             .set_variable("tls.version", tls_version)
             .set_variable("tls.cipher", tls_cipher)
-            .set_variable("stage", stage);
+            .set_variable("stage", stage)
+            .with_dry_run(self.data.dry_run.is_some());
         if let Some(ip_rev) = &self.data.iprev {
             params = params.set_variable("iprev.result", ip_rev.result().as_str());
             if let Some(ptr) = ip_rev.ptr.as_ref().and_then(|addrs| addrs.first()) {
@@ -31,6 +31,17 @@ pub enum ScriptResult {
     Discard,
 }
 
+impl ScriptResult {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ScriptResult::Accept { .. } => "accept",
+            ScriptResult::Replace { .. } => "replace",
+            ScriptResult::Reject(_) => "reject",
+            ScriptResult::Discard => "discard",
+        }
+    }
+}
+
 pub struct ScriptParameters<'x> {
     message: Option<Message<'x>>,
     headers: Option<&'x [u8]>,
@@ -43,6 +54,7 @@ pub struct ScriptParameters<'x> {
     access_token: Option<&'x AccessToken>,
     spam_status: Option<SpamStatus>,
     session_id: u64,
+    dry_run: bool,
 }
 
 impl<'x> ScriptParameters<'x> {
@@ -59,6 +71,7 @@ impl<'x> ScriptParameters<'x> {
             access_token: None,
             spam_status: None,
             session_id: Default::default(),
+            dry_run: false,
         }
     }
 
@@ -127,6 +140,11 @@ impl<'x> ScriptParameters<'x> {
         self.session_id = session_id;
         self
     }
+
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
 }
 
 impl Default for ScriptParameters<'_> {
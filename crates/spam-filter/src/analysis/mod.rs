@@ -151,3 +151,14 @@ pub(crate) async fn is_url_redirector(server: &Server, url: &str, span_id: u64)
         false
     }
 }
+
+pub(crate) async fn url_reputation(server: &Server, host: &str, span_id: u64) -> Option<String> {
+    let store = server.get_lookup_store("url-reputation")?;
+    match store.key_get::<String>(host).await {
+        Ok(reputation) => reputation.filter(|reputation| !reputation.is_empty()),
+        Err(err) => {
+            trc::error!(err.span_id(span_id).caused_by(trc::location!()));
+            None
+        }
+    }
+}
@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::{ElementLocation, is_trusted_domain, is_url_redirector};
+use super::{ElementLocation, is_trusted_domain, is_url_redirector, url_reputation};
 use crate::modules::dnsbl::check_dnsbl;
 use crate::modules::expression::StringResolver;
 use crate::modules::html::SRC;
@@ -138,6 +138,8 @@ impl SpamFilterAnalyzeUrl for Server {
         }
 
         if !ctx.input.is_train {
+            let max_redirects = self.core.spam.dnsbl.max_redirects;
+            let redirect_timeout = self.core.spam.dnsbl.redirect_timeout;
             let mut redirected_urls = HashSet::new();
             for url in &urls {
                 for ch in url.element.url.chars() {
@@ -181,13 +183,9 @@ impl SpamFilterAnalyzeUrl for Server {
                         let mut redirect_count = 1;
                         let mut url_redirect = Cow::Borrowed(url.element.url.as_str());
 
-                        while redirect_count <= 3 {
-                            match http_get_header(
-                                url_redirect.as_ref(),
-                                LOCATION,
-                                Duration::from_secs(5),
-                            )
-                            .await
+                        while redirect_count <= max_redirects {
+                            match http_get_header(url_redirect.as_ref(), LOCATION, redirect_timeout)
+                                .await
                             {
                                 Ok(Some(location)) => {
                                     let location = UrlParts::new(location);
@@ -218,7 +216,7 @@ impl SpamFilterAnalyzeUrl for Server {
                             break;
                         }
 
-                        if redirect_count > 3 {
+                        if redirect_count > max_redirects {
                             ctx.result.add_tag("URL_REDIRECTOR_NESTED");
                         }
                     }
@@ -227,6 +225,7 @@ impl SpamFilterAnalyzeUrl for Server {
 
             urls.extend(redirected_urls);
 
+            let mut checked_hosts = HashSet::new();
             for (el, url_parsed) in urls.iter().filter_map(|el| {
                 el.element
                     .url_parsed
@@ -253,6 +252,22 @@ impl SpamFilterAnalyzeUrl for Server {
                         }
                     }
 
+                    // Check local reputation, the full hostname takes precedence over the SLD
+                    for name in [Some(host.fqdn.as_str()), host.sld.as_deref()]
+                        .into_iter()
+                        .flatten()
+                    {
+                        if !checked_hosts.insert(name) {
+                            break;
+                        }
+                        if let Some(reputation) =
+                            url_reputation(self, name, ctx.input.span_id).await
+                        {
+                            ctx.result.add_tag(reputation_tag(&reputation));
+                            break;
+                        }
+                    }
+
                     // Check Domain DNSBL
                     if let Some(sld) = &host.sld {
                         check_dnsbl(
@@ -324,6 +339,19 @@ async fn http_get_header(
         })
 }
 
+fn reputation_tag(reputation: &str) -> String {
+    let mut tag = String::with_capacity(reputation.len() + 8);
+    tag.push_str("URL_REP_");
+    for ch in reputation.trim().chars() {
+        if ch.is_ascii_alphanumeric() {
+            tag.push(ch.to_ascii_uppercase());
+        } else {
+            tag.push('_');
+        }
+    }
+    tag
+}
+
 fn is_single_url<T, E, U, I>(tokens: &[TokenType<T, E, U, I>]) -> bool {
     let mut url_count = 0;
     let mut word_count = 0;
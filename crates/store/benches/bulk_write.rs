@@ -0,0 +1,101 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+// Compares writing a bulk update (such as marking a whole mailbox as read) as
+// one transaction per message against coalescing it into commit points.
+//
+// SQLite is used by default, set STORE=foundationdb to run against the
+// FoundationDB cluster in the default cluster file.
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use store::{Store, write::BatchBuilder};
+use types::{collection::Collection, field::EmailField};
+
+const ACCOUNT_ID: u32 = 1;
+const VALUE_LEN: usize = 256;
+
+async fn open_store() -> Store {
+    let store = match std::env::var("STORE").as_deref() {
+        #[cfg(feature = "foundation")]
+        Ok("foundationdb") => store::backend::foundationdb::FoundationDbStore::open(
+            registry::schema::structs::FoundationDbStore::default(),
+        )
+        .await
+        .unwrap(),
+        #[cfg(feature = "sqlite")]
+        Ok("sqlite") | Err(_) => {
+            let path = std::env::temp_dir().join("stalwart_bulk_write_bench.db");
+            let _ = std::fs::remove_file(&path);
+            store::backend::sqlite::SqliteStore::open(registry::schema::structs::SqliteStore {
+                path: path.to_string_lossy().into_owned(),
+                ..Default::default()
+            })
+            .unwrap()
+        }
+        store => panic!("Unsupported store {store:?}, check the enabled features."),
+    };
+    store.create_tables().await.unwrap();
+    store
+}
+
+async fn write_per_message(store: &Store, num_messages: u32) {
+    for document_id in 0..num_messages {
+        let mut batch = BatchBuilder::new();
+        batch
+            .with_account_id(ACCOUNT_ID)
+            .with_collection(Collection::Email)
+            .with_document(document_id)
+            .set(EmailField::Archive, vec![0u8; VALUE_LEN]);
+        store.write(batch.build_all()).await.unwrap();
+    }
+}
+
+async fn write_coalesced(store: &Store, num_messages: u32) {
+    let mut batch = BatchBuilder::new();
+    for document_id in 0..num_messages {
+        batch
+            .with_account_id(ACCOUNT_ID)
+            .with_collection(Collection::Email)
+            .with_document(document_id)
+            .set(EmailField::Archive, vec![0u8; VALUE_LEN]);
+        batch.commit_point();
+    }
+
+    let mut commit_points = batch.commit_points();
+    for commit_point in commit_points.iter() {
+        store.write(batch.build_one(commit_point)).await.unwrap();
+    }
+}
+
+fn bulk_write(c: &mut Criterion) {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let store = runtime.block_on(open_store());
+
+    let mut group = c.benchmark_group("bulk_write");
+    group.sample_size(10);
+    for num_messages in [500u32, 5000] {
+        group.bench_with_input(
+            BenchmarkId::new("per_message", num_messages),
+            &num_messages,
+            |b, &num_messages| {
+                b.to_async(&runtime)
+                    .iter(|| write_per_message(&store, num_messages))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("coalesced", num_messages),
+            &num_messages,
+            |b, &num_messages| {
+                b.to_async(&runtime)
+                    .iter(|| write_coalesced(&store, num_messages))
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bulk_write);
+criterion_main!(benches);
@@ -0,0 +1,218 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: LicenseRef-SEL
+ *
+ * This file is subject to the Stalwart Enterprise License Agreement (SEL) and
+ * is NOT open source software.
+ *
+ */
+
+use super::open_blob_store_base;
+use crate::{BlobStore, CompressionAlgo};
+use registry::schema::{enums::BlobMirrorMode, structs::MirroredBlobStore};
+use std::{ops::Range, sync::Arc, time::Duration};
+use tokio::sync::mpsc;
+use trc::{AddContext, StoreEvent};
+
+const MAX_REPAIR_ATTEMPTS: u32 = 5;
+
+pub struct MirroredBlob {
+    pub primary: BlobStore,
+    pub mirror: BlobStore,
+    pub is_async: bool,
+    repair_tx: mpsc::Sender<MirrorRepair>,
+}
+
+// Pending operations to bring both copies back in sync, only keys are queued
+// and the data is read back from the intact copy when the repair runs.
+enum MirrorRepair {
+    Copy {
+        key: Vec<u8>,
+        to_mirror: bool,
+        compression: CompressionAlgo,
+    },
+    Delete {
+        key: Vec<u8>,
+    },
+}
+
+impl MirroredBlob {
+    pub async fn open(config: MirroredBlobStore) -> Result<BlobStore, String> {
+        let primary = open_blob_store_base(config.primary_store).await?;
+        let mirror = open_blob_store_base(config.mirror_store).await?;
+        let (repair_tx, repair_rx) = mpsc::channel(config.max_queue_size as usize);
+
+        spawn_repair_queue(primary.clone(), mirror.clone(), repair_rx);
+
+        Ok(BlobStore::Mirrored(Arc::new(MirroredBlob {
+            primary,
+            mirror,
+            is_async: config.write_mode == BlobMirrorMode::Asynchronous,
+            repair_tx,
+        })))
+    }
+
+    pub async fn get_blob(&self, key: &[u8], range: Range<usize>) -> trc::Result<Option<Vec<u8>>> {
+        // Reads fall back to the mirror when the primary copy is unavailable
+        let primary_err = match self.primary.get_blob(key, range.clone()).await {
+            Ok(Some(data)) => return Ok(Some(data)),
+            Ok(None) => None,
+            Err(err) => Some(err),
+        };
+
+        match self.mirror.get_blob(key, range).await {
+            Ok(Some(data)) => {
+                trc::event!(
+                    Store(StoreEvent::BlobMirrorFallback),
+                    Key = key,
+                    Reason = primary_err.as_ref().map(|err| err.to_string()),
+                );
+
+                if primary_err.is_none() {
+                    self.queue(MirrorRepair::Copy {
+                        key: key.to_vec(),
+                        to_mirror: false,
+                        compression: CompressionAlgo::default(),
+                    });
+                }
+
+                Ok(Some(data))
+            }
+            Ok(None) => primary_err.map_or(Ok(None), Err),
+            Err(err) => Err(primary_err.unwrap_or(err)),
+        }
+    }
+
+    pub async fn put_blob(
+        &self,
+        key: &[u8],
+        data: &[u8],
+        compression: CompressionAlgo,
+    ) -> trc::Result<()> {
+        self.primary
+            .put_blob(key, data, compression)
+            .await
+            .caused_by(trc::location!())?;
+
+        if self.is_async {
+            self.queue(MirrorRepair::Copy {
+                key: key.to_vec(),
+                to_mirror: true,
+                compression,
+            });
+        } else if let Err(err) = self.mirror.put_blob(key, data, compression).await {
+            // The primary copy is durable, the mirror is brought up to date later
+            trc::event!(
+                Store(StoreEvent::BlobMirrorFailed),
+                Key = key,
+                CausedBy = err,
+            );
+
+            self.queue(MirrorRepair::Copy {
+                key: key.to_vec(),
+                to_mirror: true,
+                compression,
+            });
+        }
+
+        Ok(())
+    }
+
+    pub async fn delete_blob(&self, key: &[u8]) -> trc::Result<bool> {
+        let deleted = self
+            .primary
+            .delete_blob(key)
+            .await
+            .caused_by(trc::location!())?;
+
+        if self.is_async {
+            self.queue(MirrorRepair::Delete { key: key.to_vec() });
+            Ok(deleted)
+        } else {
+            match self.mirror.delete_blob(key).await {
+                Ok(mirror_deleted) => Ok(deleted || mirror_deleted),
+                Err(err) => {
+                    trc::event!(
+                        Store(StoreEvent::BlobMirrorFailed),
+                        Key = key,
+                        CausedBy = err,
+                    );
+
+                    self.queue(MirrorRepair::Delete { key: key.to_vec() });
+                    Ok(deleted)
+                }
+            }
+        }
+    }
+
+    fn queue(&self, repair: MirrorRepair) {
+        // A full queue is not fatal, missing copies are also repaired by the blob scrubber
+        if let Err(err) = self.repair_tx.try_send(repair) {
+            let key = match err.into_inner() {
+                MirrorRepair::Copy { key, .. } | MirrorRepair::Delete { key, .. } => key,
+            };
+            trc::event!(Store(StoreEvent::BlobMirrorQueueFull), Key = key);
+        }
+    }
+}
+
+fn spawn_repair_queue(
+    primary: BlobStore,
+    mirror: BlobStore,
+    mut repair_rx: mpsc::Receiver<MirrorRepair>,
+) {
+    tokio::spawn(async move {
+        while let Some(repair) = repair_rx.recv().await {
+            let mut attempt = 0;
+
+            loop {
+                let result = match &repair {
+                    MirrorRepair::Copy {
+                        key,
+                        to_mirror,
+                        compression,
+                    } => {
+                        let (from, to) = if *to_mirror {
+                            (&primary, &mirror)
+                        } else {
+                            (&mirror, &primary)
+                        };
+
+                        match from.get_blob(key, 0..usize::MAX).await {
+                            Ok(Some(data)) => to.put_blob(key, &data, *compression).await,
+                            // Nothing to copy, the blob was deleted in the meantime
+                            Ok(None) => Ok(()),
+                            Err(err) => Err(err),
+                        }
+                    }
+                    MirrorRepair::Delete { key } => mirror.delete_blob(key).await.map(|_| ()),
+                };
+
+                let key = match &repair {
+                    MirrorRepair::Copy { key, .. } | MirrorRepair::Delete { key, .. } => key,
+                };
+                match result {
+                    Ok(()) => {
+                        trc::event!(Store(StoreEvent::BlobMirrorRepaired), Key = key.as_slice());
+                        break;
+                    }
+                    Err(err) => {
+                        attempt += 1;
+                        if attempt >= MAX_REPAIR_ATTEMPTS {
+                            trc::event!(
+                                Store(StoreEvent::BlobMirrorFailed),
+                                Key = key.as_slice(),
+                                Total = attempt,
+                                CausedBy = err,
+                            );
+                            break;
+                        }
+
+                        tokio::time::sleep(Duration::from_secs(1 << attempt)).await;
+                    }
+                }
+            }
+        }
+    });
+}
@@ -8,7 +8,43 @@
  *
  */
 
+pub mod mirrored_blob;
 #[cfg(any(feature = "postgres", feature = "mysql"))]
 pub mod read_replica;
+pub mod regional_blob;
 pub mod sharded_blob;
 pub mod sharded_lookup;
+pub mod tiered_blob;
+
+use crate::{BlobStore, backend::fs::FsStore};
+use registry::schema::structs::BlobStoreBase;
+
+#[allow(unreachable_patterns)]
+pub(crate) async fn open_blob_store_base(store: BlobStoreBase) -> Result<BlobStore, String> {
+    match store {
+        #[cfg(feature = "s3")]
+        BlobStoreBase::S3(s3_store) => crate::backend::s3::S3Store::open(s3_store).await,
+        #[cfg(feature = "azure")]
+        BlobStoreBase::Azure(azure_store) => {
+            crate::backend::azure::AzureStore::open(azure_store).await
+        }
+        BlobStoreBase::FileSystem(file_system_store) => FsStore::open(file_system_store).await,
+        #[cfg(feature = "foundation")]
+        BlobStoreBase::FoundationDb(foundation_db_store) => {
+            crate::backend::foundationdb::FdbStore::open(foundation_db_store)
+                .await
+                .map(BlobStore::Store)
+        }
+        #[cfg(feature = "postgres")]
+        BlobStoreBase::PostgreSql(postgre_sql_store) => {
+            crate::backend::postgres::PostgresStore::open(postgre_sql_store)
+                .await
+                .map(BlobStore::Store)
+        }
+        #[cfg(feature = "mysql")]
+        BlobStoreBase::MySql(my_sql_store) => crate::backend::mysql::MysqlStore::open(my_sql_store)
+            .await
+            .map(BlobStore::Store),
+        _ => Err("Binary was not compiled with the selected blob store backend".to_string()),
+    }
+}
@@ -166,6 +166,43 @@ impl SQLReadReplica {
         Err(last_error.unwrap())
     }
 
+    pub async fn iterate_counters<T: Key>(
+        &self,
+        begin: T,
+        end: T,
+        mut cb: impl for<'x> FnMut(&'x [u8], i64) -> trc::Result<bool> + Sync + Send,
+    ) -> trc::Result<()> {
+        let mut last_error = None;
+        for store in [
+            &self.replicas
+                [self.last_used_replica.fetch_add(1, Ordering::Relaxed) % self.replicas.len()],
+            &self.primary,
+        ] {
+            match match store {
+                #[cfg(feature = "postgres")]
+                Store::PostgreSQL(store) => {
+                    store
+                        .iterate_counters(begin.clone(), end.clone(), &mut cb)
+                        .await
+                }
+                #[cfg(feature = "mysql")]
+                Store::MySQL(store) => {
+                    store
+                        .iterate_counters(begin.clone(), end.clone(), &mut cb)
+                        .await
+                }
+                _ => panic!("Invalid store type"),
+            } {
+                Ok(result) => return Ok(result),
+                Err(err) => {
+                    last_error = Some(err);
+                }
+            }
+        }
+
+        Err(last_error.unwrap())
+    }
+
     pub async fn get_counter(
         &self,
         key: impl Into<ValueKey<ValueClass>> + Sync + Send,
@@ -0,0 +1,100 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: LicenseRef-SEL
+ *
+ * This file is subject to the Stalwart Enterprise License Agreement (SEL) and
+ * is NOT open source software.
+ *
+ */
+
+use super::open_blob_store_base;
+use crate::{BlobStore, CompressionAlgo};
+use registry::schema::structs::RegionalBlobStore;
+use std::{ops::Range, sync::Arc};
+use trc::AddContext;
+
+pub struct RegionalBlob {
+    pub regions: Vec<(String, BlobStore)>,
+    pub default_region: usize,
+}
+
+impl RegionalBlob {
+    pub async fn open(config: RegionalBlobStore) -> Result<BlobStore, String> {
+        let mut regions: Vec<(String, BlobStore)> = Vec::new();
+
+        for region in config.regions {
+            if regions.iter().any(|(name, _)| name == &region.name) {
+                return Err(format!("Duplicate blob store region {:?}", region.name));
+            }
+
+            let store = open_blob_store_base(region.store).await?;
+            regions.push((region.name, store));
+        }
+
+        let default_region = regions
+            .iter()
+            .position(|(name, _)| name == &config.default_region)
+            .ok_or_else(|| {
+                format!(
+                    "Default blob store region {:?} is not defined",
+                    config.default_region
+                )
+            })?;
+
+        Ok(BlobStore::Regional(Arc::new(RegionalBlob {
+            regions,
+            default_region,
+        })))
+    }
+
+    pub fn region(&self, name: &str) -> Option<&BlobStore> {
+        self.regions
+            .iter()
+            .find_map(|(region, store)| (region == name).then_some(store))
+    }
+
+    pub fn default_store(&self) -> &BlobStore {
+        &self.regions[self.default_region].1
+    }
+
+    pub async fn get_blob(&self, key: &[u8], range: Range<usize>) -> trc::Result<Option<Vec<u8>>> {
+        // Blobs are content addressed, so the first region holding the key wins
+        if let Some(data) = self.default_store().get_blob(key, range.clone()).await? {
+            return Ok(Some(data));
+        }
+
+        for (idx, (_, store)) in self.regions.iter().enumerate() {
+            if idx != self.default_region
+                && let Some(data) = store.get_blob(key, range.clone()).await?
+            {
+                return Ok(Some(data));
+            }
+        }
+
+        Ok(None)
+    }
+
+    pub async fn put_blob(
+        &self,
+        key: &[u8],
+        data: &[u8],
+        compression: CompressionAlgo,
+    ) -> trc::Result<()> {
+        // Writes without an account context land in the default region
+        self.default_store().put_blob(key, data, compression).await
+    }
+
+    pub async fn delete_blob(&self, key: &[u8]) -> trc::Result<bool> {
+        let mut deleted = false;
+        for (region, store) in &self.regions {
+            deleted |= store
+                .delete_blob(key)
+                .await
+                .add_context(|err| err.ctx(trc::Key::Id, region.clone()))
+                .caused_by(trc::location!())?;
+        }
+
+        Ok(deleted)
+    }
+}
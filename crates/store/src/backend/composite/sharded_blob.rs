@@ -106,7 +106,12 @@ impl ShardedBlob {
                 BlobStore::S3(store) => store.get_blob(key, read_range).await,
                 #[cfg(feature = "azure")]
                 BlobStore::Azure(store) => store.get_blob(key, read_range).await,
-                BlobStore::Sharded(_) => unimplemented!(),
+                BlobStore::Sharded(_)
+                | BlobStore::Regional(_)
+                | BlobStore::Tiered(_)
+                | BlobStore::Mirrored(_) => {
+                    unimplemented!()
+                }
             }
         }
         .await
@@ -143,7 +148,12 @@ impl ShardedBlob {
                 BlobStore::S3(store) => store.put_blob(key, data).await,
                 #[cfg(feature = "azure")]
                 BlobStore::Azure(store) => store.put_blob(key, data).await,
-                BlobStore::Sharded(_) => unimplemented!(),
+                BlobStore::Sharded(_)
+                | BlobStore::Regional(_)
+                | BlobStore::Tiered(_)
+                | BlobStore::Mirrored(_) => {
+                    unimplemented!()
+                }
             }
         }
         .await
@@ -180,7 +190,12 @@ impl ShardedBlob {
                 BlobStore::S3(store) => store.delete_blob(key).await,
                 #[cfg(feature = "azure")]
                 BlobStore::Azure(store) => store.delete_blob(key).await,
-                BlobStore::Sharded(_) => unimplemented!(),
+                BlobStore::Sharded(_)
+                | BlobStore::Regional(_)
+                | BlobStore::Tiered(_)
+                | BlobStore::Mirrored(_) => {
+                    unimplemented!()
+                }
             }
         }
         .await
@@ -39,6 +39,10 @@ impl ShardedInMemory {
                     InMemoryStoreBase::RedisCluster(redis_cluster_store) => {
                         crate::backend::redis::RedisStore::open_cluster(redis_cluster_store).await
                     }
+                    #[cfg(feature = "redis")]
+                    InMemoryStoreBase::RedisSentinel(redis_sentinel_store) => {
+                        crate::backend::redis::RedisStore::open_sentinel(redis_sentinel_store).await
+                    }
                     _ => Err(
                         "Binary was not compiled with the selected in-memory backend".to_string(),
                     ),
@@ -0,0 +1,90 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: LicenseRef-SEL
+ *
+ * This file is subject to the Stalwart Enterprise License Agreement (SEL) and
+ * is NOT open source software.
+ *
+ */
+
+use super::open_blob_store_base;
+use crate::{BlobStore, CompressionAlgo};
+use registry::schema::structs::TieredBlobStore;
+use std::{ops::Range, sync::Arc};
+use trc::AddContext;
+
+pub struct TieredBlob {
+    pub hot: BlobStore,
+    pub cold: BlobStore,
+}
+
+impl TieredBlob {
+    pub async fn open(config: TieredBlobStore) -> Result<BlobStore, String> {
+        Ok(BlobStore::Tiered(Arc::new(TieredBlob {
+            hot: open_blob_store_base(config.hot_store).await?,
+            cold: open_blob_store_base(config.cold_store).await?,
+        })))
+    }
+
+    pub async fn get_blob(&self, key: &[u8], range: Range<usize>) -> trc::Result<Option<Vec<u8>>> {
+        // Reads fall through to the cold tier for blobs that were migrated
+        if let Some(data) = self.hot.get_blob(key, range.clone()).await? {
+            Ok(Some(data))
+        } else {
+            self.cold.get_blob(key, range).await
+        }
+    }
+
+    pub async fn put_blob(
+        &self,
+        key: &[u8],
+        data: &[u8],
+        compression: CompressionAlgo,
+    ) -> trc::Result<()> {
+        self.hot.put_blob(key, data, compression).await
+    }
+
+    pub async fn delete_blob(&self, key: &[u8]) -> trc::Result<bool> {
+        let hot = self
+            .hot
+            .delete_blob(key)
+            .await
+            .caused_by(trc::location!())?;
+        let cold = self
+            .cold
+            .delete_blob(key)
+            .await
+            .caused_by(trc::location!())?;
+
+        Ok(hot || cold)
+    }
+
+    pub async fn move_blob(
+        &self,
+        key: &[u8],
+        to_cold: bool,
+        compression: CompressionAlgo,
+    ) -> trc::Result<bool> {
+        let (from, to) = if to_cold {
+            (&self.hot, &self.cold)
+        } else {
+            (&self.cold, &self.hot)
+        };
+
+        if let Some(data) = from
+            .get_blob(key, 0..usize::MAX)
+            .await
+            .caused_by(trc::location!())?
+        {
+            // Write the copy before removing the original so reads never miss
+            to.put_blob(key, &data, compression)
+                .await
+                .caused_by(trc::location!())?;
+            from.delete_blob(key).await.caused_by(trc::location!())?;
+            Ok(true)
+        } else {
+            Ok(false)
+        }
+    }
+}
@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use super::FdbStore;
+use super::{FdbStore, partition::FdbPartitions};
 use crate::Store;
 use foundationdb::{Database, api, api::NetworkAutoStop, options::DatabaseOption};
 use parking_lot::Mutex;
@@ -57,9 +57,13 @@ impl FdbStore {
                 .map_err(|err| format!("Failed to set option: {err:?}"))?;
         }
 
+        let partitions =
+            FdbPartitions::open(&db, config.partition_mode, &config.directory_prefix).await?;
+
         Ok(Store::FoundationDb(Arc::new(Self {
             db,
             version: Default::default(),
+            partitions,
         })))
     }
 }
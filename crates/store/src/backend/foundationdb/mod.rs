@@ -9,6 +9,7 @@ use std::time::{Duration, Instant};
 
 pub mod blob;
 pub mod main;
+pub mod partition;
 pub mod read;
 pub mod write;
 
@@ -18,6 +19,7 @@ pub const TRANSACTION_EXPIRY: Duration = Duration::from_secs(1);
 pub struct FdbStore {
     db: Database,
     version: parking_lot::Mutex<ReadVersion>,
+    partitions: Option<partition::FdbPartitions>,
 }
 
 pub(crate) struct ReadVersion {
@@ -0,0 +1,756 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{
+    FdbStore, into_error,
+    read::{ChunkedValue, read_chunked_value},
+};
+use crate::{
+    Deserialize, SUBSPACE_INDEXES, SUBSPACE_LOGS, SUBSPACE_PROPERTY, StorePartition, WITH_SUBSPACE,
+    write::{MAX_COMMIT_ATTEMPTS, RegistryClass, ValueClass, key::KeySerializer, now},
+};
+use ahash::AHashMap;
+use foundationdb::{
+    Database, KeySelector, RangeOption, Transaction,
+    directory::{Directory, DirectoryError, DirectoryLayer},
+    options,
+};
+use futures::TryStreamExt;
+use parking_lot::Mutex;
+use registry::{
+    schema::{
+        enums::FoundationDbPartitionMode,
+        prelude::{Object, ObjectType},
+    },
+    types::EnumImpl,
+};
+use std::sync::Arc;
+
+// Maximum number of bytes copied per transaction while migrating an account
+const MAX_MIGRATION_BATCH: usize = 1024 * 1024;
+
+// Subspaces holding per-account data, their keys start with the account id
+const PARTITIONED_SUBSPACES: [u8; 3] = [SUBSPACE_PROPERTY, SUBSPACE_INDEXES, SUBSPACE_LOGS];
+
+// Index entries of accounts being migrated start with this byte, followed by
+// the lock expiration and the prefix of the target partition. Directory
+// prefixes are tuple-encoded integers and never start with it.
+const MIGRATION_LOCK: u8 = 0;
+
+// The lock is refreshed after every copied batch, a lock that was not
+// refreshed for this long belongs to an aborted migration.
+const MIGRATION_LOCK_EXPIRY: u64 = 300;
+
+pub(crate) struct FdbPartitions {
+    mode: FoundationDbPartitionMode,
+    root: Vec<String>,
+    directory: DirectoryLayer,
+    index: Vec<u8>,
+    // Only partitioned accounts are cached, accounts in the flat keyspace
+    // can be migrated by any node at any time.
+    cache: Mutex<AHashMap<u32, Arc<[u8]>>>,
+}
+
+enum IndexEntry {
+    Partitioned(Arc<[u8]>),
+    Migrating { prefix: Arc<[u8]>, expires: u64 },
+}
+
+pub(crate) enum WritePartition {
+    Flat,
+    Partitioned(Arc<[u8]>),
+    Migrating,
+}
+
+pub(crate) struct PartitionRange {
+    pub begin: Vec<u8>,
+    pub end: Vec<u8>,
+    pub offset: usize,
+}
+
+impl FdbPartitions {
+    pub async fn open(
+        db: &Database,
+        mode: FoundationDbPartitionMode,
+        prefix: &str,
+    ) -> Result<Option<Self>, String> {
+        if mode == FoundationDbPartitionMode::Disabled {
+            return Ok(None);
+        }
+
+        let root = prefix
+            .split('/')
+            .filter(|name| !name.is_empty())
+            .map(|name| name.to_string())
+            .collect::<Vec<_>>();
+        let directory = DirectoryLayer::default();
+        let trx = db
+            .create_trx()
+            .map_err(|err| format!("Failed to create transaction: {err:?}"))?;
+        let mut index_path = root.clone();
+        index_path.push("index".to_string());
+        let index = directory
+            .create_or_open(&trx, &index_path, None, None)
+            .await
+            .and_then(|index| index.bytes().map(|bytes| bytes.to_vec()))
+            .map_err(|err| format!("Failed to open partition index: {err:?}"))?;
+        trx.commit()
+            .await
+            .map_err(|err| format!("Failed to open partition index: {err:?}"))?;
+
+        Ok(Some(Self {
+            mode,
+            root,
+            directory,
+            index,
+            cache: Mutex::new(AHashMap::new()),
+        }))
+    }
+
+    fn index_key(&self, account_id: u32) -> Vec<u8> {
+        KeySerializer::new(self.index.len() + 4)
+            .write(self.index.as_slice())
+            .write(account_id)
+            .finalize()
+    }
+}
+
+impl FdbStore {
+    // Returns the physical key used for reads, which is prefixed with the
+    // partition of the account when the key belongs to a partitioned subspace.
+    pub(crate) async fn physical_key(&self, key: Vec<u8>) -> trc::Result<Vec<u8>> {
+        if self.partitions.is_some()
+            && let Some(account_id) = partitioned_account(&key)
+            && let Some(prefix) = self.partition(account_id).await?
+        {
+            Ok(prefixed_key(&prefix, key))
+        } else {
+            Ok(key)
+        }
+    }
+
+    // Returns the physical key used for writes, partitions are resolved once
+    // per transaction attempt. Returns None while the account is being migrated.
+    pub(crate) async fn physical_write_key(
+        &self,
+        trx: &Transaction,
+        resolved: &mut AHashMap<u32, WritePartition>,
+        key: Vec<u8>,
+    ) -> trc::Result<Option<Vec<u8>>> {
+        let Some(account_id) = self
+            .partitions
+            .as_ref()
+            .and_then(|_| partitioned_account(&key))
+        else {
+            return Ok(Some(key));
+        };
+        if !resolved.contains_key(&account_id) {
+            let partition = self.write_partition(trx, account_id).await?;
+            resolved.insert(account_id, partition);
+        }
+
+        Ok(match &resolved[&account_id] {
+            WritePartition::Flat => Some(key),
+            WritePartition::Partitioned(prefix) => Some(prefixed_key(prefix, key)),
+            WritePartition::Migrating => None,
+        })
+    }
+
+    // Accounts still in the flat keyspace or being migrated are read from the
+    // flat keyspace.
+    pub(crate) async fn partition(&self, account_id: u32) -> trc::Result<Option<Arc<[u8]>>> {
+        let Some(partitions) = &self.partitions else {
+            return Ok(None);
+        };
+        if let Some(prefix) = partitions.cache.lock().get(&account_id) {
+            return Ok(Some(prefix.clone()));
+        }
+
+        let trx = self.read_trx().await?;
+        match self.index_entry(&trx, account_id, true).await? {
+            Some(IndexEntry::Partitioned(prefix)) => {
+                partitions.cache.lock().insert(account_id, prefix.clone());
+                Ok(Some(prefix))
+            }
+            Some(IndexEntry::Migrating { .. }) | None => Ok(None),
+        }
+    }
+
+    // The index is read without snapshot isolation, so the write transaction
+    // conflicts with any migration or erase of the account that commits first.
+    // Accounts with data in the flat keyspace keep using it until they are
+    // migrated, a partition is only created for accounts without legacy data.
+    async fn write_partition(
+        &self,
+        trx: &Transaction,
+        account_id: u32,
+    ) -> trc::Result<WritePartition> {
+        let partitions = self.partitions.as_ref().unwrap();
+        match self.index_entry(trx, account_id, false).await? {
+            Some(IndexEntry::Partitioned(prefix)) => {
+                return Ok(WritePartition::Partitioned(prefix));
+            }
+            Some(IndexEntry::Migrating { expires, .. }) if expires > now() => {
+                return Ok(WritePartition::Migrating);
+            }
+            _ => {}
+        }
+
+        if self.has_flat_data(trx, account_id).await? {
+            Ok(WritePartition::Flat)
+        } else {
+            let prefix = self.create_partition(trx, account_id).await?;
+            trx.set(&partitions.index_key(account_id), &prefix);
+            Ok(WritePartition::Partitioned(prefix))
+        }
+    }
+
+    async fn index_entry(
+        &self,
+        trx: &Transaction,
+        account_id: u32,
+        snapshot: bool,
+    ) -> trc::Result<Option<IndexEntry>> {
+        let partitions = self.partitions.as_ref().unwrap();
+        Ok(trx
+            .get(&partitions.index_key(account_id), snapshot)
+            .await
+            .map_err(into_error)?
+            .and_then(|value| IndexEntry::parse(value.as_ref())))
+    }
+
+    // Splits a range into the flat keyspace and the partitions it spans
+    pub(crate) async fn partition_ranges(
+        &self,
+        begin: Vec<u8>,
+        end: Vec<u8>,
+    ) -> trc::Result<Vec<PartitionRange>> {
+        let subspace = begin.first().copied().unwrap_or_default();
+        if self.partitions.is_none()
+            || !PARTITIONED_SUBSPACES.contains(&subspace)
+            || end.first() != Some(&subspace)
+        {
+            return Ok(vec![PartitionRange::flat(begin, end)]);
+        }
+
+        // Keys shorter than an account id span the whole subspace
+        let from_account = partitioned_account(&begin).unwrap_or(0);
+        let to_account = partitioned_account(&end).unwrap_or(u32::MAX);
+
+        if from_account == to_account {
+            return Ok(vec![match self.partition(from_account).await? {
+                Some(prefix) => PartitionRange::partitioned(&prefix, &begin, &end),
+                None => PartitionRange::flat(begin, end),
+            }]);
+        }
+
+        // Data of accounts that were not migrated yet is returned first
+        let mut ranges = Vec::new();
+        for (account_id, prefix) in self.partition_index(from_account, to_account).await? {
+            let (account_begin, account_end) = account_range(subspace, account_id);
+            ranges.push(PartitionRange::partitioned(
+                &prefix,
+                if account_id == from_account {
+                    &begin
+                } else {
+                    &account_begin
+                },
+                if account_id == to_account {
+                    &end
+                } else {
+                    &account_end
+                },
+            ));
+        }
+        ranges.insert(0, PartitionRange::flat(begin, end));
+
+        Ok(ranges)
+    }
+
+    // Moves the data of all accounts in the flat keyspace into partitions,
+    // writes to an account wait while it is being migrated.
+    pub(crate) async fn migrate_partitions(&self) -> trc::Result<usize> {
+        if self.partitions.is_none() {
+            return Err(trc::StoreEvent::NotConfigured
+                .into_err()
+                .details("Partitioning is disabled"));
+        }
+
+        let mut migrated = 0;
+        for subspace in PARTITIONED_SUBSPACES {
+            let mut account_id = 0;
+            while let Some(next_account_id) = self.next_flat_account(subspace, account_id).await? {
+                self.migrate_account(next_account_id).await?;
+                migrated += 1;
+
+                if next_account_id == u32::MAX {
+                    break;
+                }
+                account_id = next_account_id + 1;
+            }
+        }
+
+        Ok(migrated)
+    }
+
+    pub(crate) async fn erase_partition(&self, id: StorePartition) -> trc::Result<bool> {
+        let Some(partitions) = &self.partitions else {
+            return Err(trc::StoreEvent::NotConfigured
+                .into_err()
+                .details("Partitioning is disabled"));
+        };
+
+        let accounts = match id {
+            StorePartition::Account(account_id) => self
+                .partition_index(account_id, account_id)
+                .await?
+                .into_iter()
+                .map(|(account_id, _)| account_id)
+                .collect::<Vec<_>>(),
+            StorePartition::Tenant(tenant_id) => {
+                if partitions.mode != FoundationDbPartitionMode::Tenant {
+                    return Err(trc::StoreEvent::NotSupported
+                        .into_err()
+                        .details("Partitions are not grouped by tenant"));
+                }
+
+                let trx = self.db.create_trx().map_err(into_error)?;
+                let prefix = partitions
+                    .directory
+                    .open(
+                        &trx,
+                        &path(&partitions.root, "tenant", Some(tenant_id as u64)),
+                        None,
+                    )
+                    .await
+                    .ok()
+                    .and_then(|directory| directory.bytes().ok().map(|bytes| bytes.to_vec()));
+                drop(trx);
+
+                match prefix {
+                    Some(prefix) => self
+                        .partition_index(0, u32::MAX)
+                        .await?
+                        .into_iter()
+                        .filter(|(_, account_prefix)| account_prefix.as_ref() == prefix)
+                        .map(|(account_id, _)| account_id)
+                        .collect(),
+                    None => vec![],
+                }
+            }
+        };
+
+        if accounts.is_empty() {
+            return Ok(false);
+        }
+
+        let trx = self.db.create_trx().map_err(into_error)?;
+        match (partitions.mode, id) {
+            (FoundationDbPartitionMode::Tenant, StorePartition::Account(account_id)) => {
+                // Other accounts of the tenant share the same directory
+                let prefix = self.partition(account_id).await?.unwrap_or_default();
+                for subspace in PARTITIONED_SUBSPACES {
+                    let range = account_range(subspace, account_id);
+                    let range = PartitionRange::partitioned(&prefix, &range.0, &range.1);
+                    trx.clear_range(&range.begin, &range.end);
+                }
+            }
+            (FoundationDbPartitionMode::Tenant, StorePartition::Tenant(tenant_id)) => {
+                remove_directory(
+                    &partitions.directory,
+                    &trx,
+                    &path(&partitions.root, "tenant", Some(tenant_id as u64)),
+                )
+                .await?;
+            }
+            (_, _) => {
+                for account_id in &accounts {
+                    remove_directory(
+                        &partitions.directory,
+                        &trx,
+                        &path(&partitions.root, "account", Some(*account_id as u64)),
+                    )
+                    .await?;
+                }
+            }
+        }
+        for account_id in &accounts {
+            trx.clear(&partitions.index_key(*account_id));
+        }
+        self.commit(trx, false).await?;
+
+        let mut cache = partitions.cache.lock();
+        for account_id in &accounts {
+            cache.remove(account_id);
+        }
+
+        Ok(true)
+    }
+
+    async fn migrate_account(&self, account_id: u32) -> trc::Result<()> {
+        let partitions = self.partitions.as_ref().unwrap();
+        let prefix = self.lock_account(account_id).await?;
+
+        for subspace in PARTITIONED_SUBSPACES {
+            let (begin, end) = account_range(subspace, account_id);
+            self.copy_range(account_id, begin, end, &prefix).await?;
+        }
+
+        // Switch to the partition and remove the copied data, unless the lock
+        // was taken over in the meantime
+        let mut retry_count = 0;
+        loop {
+            let trx = self.db.create_trx().map_err(into_error)?;
+            match self.index_entry(&trx, account_id, false).await? {
+                Some(IndexEntry::Migrating {
+                    prefix: lock_prefix,
+                    expires,
+                }) if lock_prefix == prefix && expires > now() + MIGRATION_LOCK_EXPIRY / 2 => {}
+                _ => {
+                    return Err(trc::StoreEvent::FoundationdbError
+                        .into_err()
+                        .account_id(account_id)
+                        .details("Partition migration lock expired"));
+                }
+            }
+            trx.set(&partitions.index_key(account_id), &prefix);
+            for subspace in PARTITIONED_SUBSPACES {
+                let (begin, end) = account_range(subspace, account_id);
+                trx.clear_range(&begin, &end);
+            }
+            if self.commit(trx, retry_count < MAX_COMMIT_ATTEMPTS).await? {
+                break;
+            }
+            retry_count += 1;
+        }
+        partitions.cache.lock().insert(account_id, prefix);
+
+        trc::event!(
+            Store(trc::StoreEvent::PartitionMigrated),
+            AccountId = account_id,
+        );
+
+        Ok(())
+    }
+
+    // Writers read the index entry of the account in their transaction, so
+    // they either commit before the lock or wait until the migration completes.
+    async fn lock_account(&self, account_id: u32) -> trc::Result<Arc<[u8]>> {
+        let partitions = self.partitions.as_ref().unwrap();
+        let mut retry_count = 0;
+        loop {
+            let trx = self.db.create_trx().map_err(into_error)?;
+            let prefix = match self.index_entry(&trx, account_id, false).await? {
+                Some(IndexEntry::Migrating { expires, .. }) if expires > now() => {
+                    return Err(trc::StoreEvent::FoundationdbError
+                        .into_err()
+                        .account_id(account_id)
+                        .details("Account is already being migrated"));
+                }
+                Some(IndexEntry::Partitioned(prefix))
+                | Some(IndexEntry::Migrating { prefix, .. }) => prefix,
+                None => self.create_partition(&trx, account_id).await?,
+            };
+            trx.set(
+                &partitions.index_key(account_id),
+                &IndexEntry::lock_value(&prefix),
+            );
+            if self.commit(trx, retry_count < MAX_COMMIT_ATTEMPTS).await? {
+                partitions.cache.lock().remove(&account_id);
+                return Ok(prefix);
+            }
+            retry_count += 1;
+        }
+    }
+
+    async fn copy_range(
+        &self,
+        account_id: u32,
+        begin: Vec<u8>,
+        end: Vec<u8>,
+        prefix: &[u8],
+    ) -> trc::Result<()> {
+        let partitions = self.partitions.as_ref().unwrap();
+        let mut last_key = None;
+        let mut retry_count = 0;
+
+        loop {
+            // The source range is read without snapshot isolation so that a
+            // batch conflicts with any write that slipped past the lock.
+            let trx = self.db.create_trx().map_err(into_error)?;
+            let mut values = trx.get_ranges_keyvalues(
+                RangeOption {
+                    begin: match &last_key {
+                        Some(last_key) => KeySelector::first_greater_than(last_key),
+                        None => KeySelector::first_greater_or_equal(&begin),
+                    },
+                    end: KeySelector::first_greater_or_equal(&end),
+                    mode: options::StreamingMode::WantAll,
+                    reverse: false,
+                    ..Default::default()
+                },
+                false,
+            );
+
+            let mut batch_size = 0;
+            let mut batch_last_key = None;
+            while let Some(value) = values.try_next().await.map_err(into_error)? {
+                trx.set(
+                    &KeySerializer::new(prefix.len() + value.key().len())
+                        .write(prefix)
+                        .write(value.key())
+                        .finalize(),
+                    value.value(),
+                );
+                batch_size += value.key().len() + value.value().len();
+                batch_last_key = Some(value.key().to_vec());
+                if batch_size >= MAX_MIGRATION_BATCH {
+                    break;
+                }
+            }
+            drop(values);
+
+            if batch_last_key.is_none() {
+                return Ok(());
+            }
+            trx.set(
+                &partitions.index_key(account_id),
+                &IndexEntry::lock_value(prefix),
+            );
+            if !self.commit(trx, retry_count < MAX_COMMIT_ATTEMPTS).await? {
+                retry_count += 1;
+                continue;
+            }
+            if batch_size < MAX_MIGRATION_BATCH {
+                return Ok(());
+            }
+            last_key = batch_last_key;
+            retry_count = 0;
+        }
+    }
+
+    async fn create_partition(&self, trx: &Transaction, account_id: u32) -> trc::Result<Arc<[u8]>> {
+        let partitions = self.partitions.as_ref().unwrap();
+        let path = match partitions.mode {
+            FoundationDbPartitionMode::Tenant => {
+                // Tenants are resolved once, accounts keep their partition
+                // when they are later moved to a different tenant.
+                let key = ValueClass::Registry(RegistryClass::Item {
+                    object_id: ObjectType::Account.to_id(),
+                    item_id: account_id as u64,
+                })
+                .serialize(0, 0, 0, WITH_SUBSPACE);
+                let object = match read_chunked_value(&key, trx, true).await? {
+                    ChunkedValue::Single(bytes) => Some(bytes.to_vec()),
+                    ChunkedValue::Chunked { bytes, .. } => Some(bytes),
+                    ChunkedValue::None => None,
+                };
+                let tenant_id = object
+                    .map(|bytes| Object::deserialize_with_key(&key[1..], &bytes))
+                    .transpose()?
+                    .and_then(|object| object.inner.member_tenant_id())
+                    .map(|id| id.id());
+                path(&partitions.root, "tenant", tenant_id)
+            }
+            _ => path(&partitions.root, "account", Some(account_id as u64)),
+        };
+
+        partitions
+            .directory
+            .create_or_open(trx, &path, None, None)
+            .await
+            .and_then(|directory| directory.bytes().map(Arc::from))
+            .map_err(into_directory_error)
+    }
+
+    async fn has_flat_data(&self, trx: &Transaction, account_id: u32) -> trc::Result<bool> {
+        for subspace in PARTITIONED_SUBSPACES {
+            let (begin, end) = account_range(subspace, account_id);
+            if trx
+                .get_ranges_keyvalues(
+                    RangeOption {
+                        begin: KeySelector::first_greater_or_equal(&begin),
+                        end: KeySelector::first_greater_or_equal(&end),
+                        limit: Some(1),
+                        mode: options::StreamingMode::Small,
+                        ..Default::default()
+                    },
+                    true,
+                )
+                .try_next()
+                .await
+                .map_err(into_error)?
+                .is_some()
+            {
+                return Ok(true);
+            }
+        }
+
+        Ok(false)
+    }
+
+    async fn next_flat_account(&self, subspace: u8, account_id: u32) -> trc::Result<Option<u32>> {
+        let begin = KeySerializer::new(5)
+            .write(subspace)
+            .write(account_id)
+            .finalize();
+        let end = [subspace + 1];
+        let trx = self.db.create_trx().map_err(into_error)?;
+
+        Ok(trx
+            .get_ranges_keyvalues(
+                RangeOption {
+                    begin: KeySelector::first_greater_or_equal(&begin),
+                    end: KeySelector::first_greater_or_equal(&end[..]),
+                    limit: Some(1),
+                    mode: options::StreamingMode::Small,
+                    ..Default::default()
+                },
+                true,
+            )
+            .try_next()
+            .await
+            .map_err(into_error)?
+            .and_then(|value| partitioned_account(value.key())))
+    }
+
+    async fn partition_index(
+        &self,
+        from_account: u32,
+        to_account: u32,
+    ) -> trc::Result<Vec<(u32, Arc<[u8]>)>> {
+        let partitions = self.partitions.as_ref().unwrap();
+        let begin = partitions.index_key(from_account);
+        let end = partitions.index_key(to_account);
+        let trx = self.read_trx().await?;
+        let mut values = trx.get_ranges_keyvalues(
+            RangeOption {
+                begin: KeySelector::first_greater_or_equal(&begin),
+                end: KeySelector::first_greater_than(&end),
+                mode: options::StreamingMode::WantAll,
+                reverse: false,
+                ..Default::default()
+            },
+            true,
+        );
+
+        let mut results = Vec::new();
+        while let Some(value) = values.try_next().await.map_err(into_error)? {
+            if let Some(account_id) = value
+                .key()
+                .get(partitions.index.len()..)
+                .and_then(|bytes| bytes.try_into().ok())
+                .map(u32::from_be_bytes)
+                && let Some(IndexEntry::Partitioned(prefix)) = IndexEntry::parse(value.value())
+            {
+                results.push((account_id, prefix));
+            }
+        }
+
+        Ok(results)
+    }
+}
+
+impl IndexEntry {
+    fn parse(value: &[u8]) -> Option<Self> {
+        match value.split_first()? {
+            (&MIGRATION_LOCK, rest) => Some(IndexEntry::Migrating {
+                expires: u64::from_be_bytes(rest.get(..8)?.try_into().ok()?),
+                prefix: Arc::from(rest.get(8..)?),
+            }),
+            _ => Some(IndexEntry::Partitioned(Arc::from(value))),
+        }
+    }
+
+    fn lock_value(prefix: &[u8]) -> Vec<u8> {
+        KeySerializer::new(prefix.len() + 9)
+            .write(MIGRATION_LOCK)
+            .write(now() + MIGRATION_LOCK_EXPIRY)
+            .write(prefix)
+            .finalize()
+    }
+}
+
+impl PartitionRange {
+    fn flat(begin: Vec<u8>, end: Vec<u8>) -> Self {
+        Self {
+            begin,
+            end,
+            offset: 0,
+        }
+    }
+
+    fn partitioned(prefix: &[u8], begin: &[u8], end: &[u8]) -> Self {
+        Self {
+            begin: KeySerializer::new(prefix.len() + begin.len())
+                .write(prefix)
+                .write(begin)
+                .finalize(),
+            end: KeySerializer::new(prefix.len() + end.len())
+                .write(prefix)
+                .write(end)
+                .finalize(),
+            offset: prefix.len(),
+        }
+    }
+}
+
+fn prefixed_key(prefix: &[u8], key: Vec<u8>) -> Vec<u8> {
+    KeySerializer::new(prefix.len() + key.len())
+        .write(prefix)
+        .write(key.as_slice())
+        .finalize()
+}
+
+fn partitioned_account(key: &[u8]) -> Option<u32> {
+    if PARTITIONED_SUBSPACES.contains(key.first()?) {
+        key.get(1..5)
+            .and_then(|bytes| bytes.try_into().ok())
+            .map(u32::from_be_bytes)
+    } else {
+        None
+    }
+}
+
+fn account_range(subspace: u8, account_id: u32) -> (Vec<u8>, Vec<u8>) {
+    let begin = KeySerializer::new(5)
+        .write(subspace)
+        .write(account_id)
+        .finalize();
+    let end = if account_id < u32::MAX {
+        KeySerializer::new(5)
+            .write(subspace)
+            .write(account_id + 1)
+            .finalize()
+    } else {
+        vec![subspace + 1]
+    };
+
+    (begin, end)
+}
+
+fn path(root: &[String], kind: &str, id: Option<u64>) -> Vec<String> {
+    let mut path = root.to_vec();
+    path.push(kind.to_string());
+    path.push(id.map_or_else(|| "default".to_string(), |id| id.to_string()));
+    path
+}
+
+async fn remove_directory(
+    directory: &DirectoryLayer,
+    trx: &Transaction,
+    path: &[String],
+) -> trc::Result<()> {
+    directory
+        .remove_if_exists(trx, path)
+        .await
+        .map(|_| ())
+        .map_err(into_directory_error)
+}
+
+fn into_directory_error(error: DirectoryError) -> trc::Error {
+    trc::StoreEvent::FoundationdbError.reason(format!("{error:?}"))
+}
@@ -35,9 +35,10 @@ impl FdbStore {
         U: Deserialize,
     {
         let key = key.serialize(WITH_SUBSPACE);
+        let physical_key = self.physical_key(key.clone()).await?;
         let trx = self.read_trx().await?;
 
-        match read_chunked_value(&key, &trx, true).await? {
+        match read_chunked_value(&physical_key, &trx, true).await? {
             ChunkedValue::Single(bytes) => {
                 U::deserialize_with_key(key.get(1..).unwrap_or_default(), &bytes).map(Some)
             }
@@ -49,7 +50,7 @@ impl FdbStore {
     }
 
     pub(crate) async fn key_exists(&self, key: impl Key) -> trc::Result<bool> {
-        let key = key.serialize(WITH_SUBSPACE);
+        let key = self.physical_key(key.serialize(WITH_SUBSPACE)).await?;
         let trx = self.read_trx().await?;
 
         match read_chunked_value(&key, &trx, true).await? {
@@ -63,114 +64,135 @@ impl FdbStore {
         params: IterateParams<T>,
         mut cb: impl for<'x> FnMut(&'x [u8], &'x [u8]) -> trc::Result<bool> + Sync + Send,
     ) -> trc::Result<()> {
-        let begin = params.begin.serialize(WITH_SUBSPACE);
-        let end = params.end.serialize(WITH_SUBSPACE);
+        let mut ranges = self
+            .partition_ranges(
+                params.begin.serialize(WITH_SUBSPACE),
+                params.end.serialize(WITH_SUBSPACE),
+            )
+            .await?;
+        if !params.ascending {
+            ranges.reverse();
+        }
 
         if !params.first {
-            let mut last_key = vec![];
             let mut chunked_key: Option<ChunkedValueCollector> = None;
 
-            'outer: loop {
-                let begin_selector = if last_key.is_empty() {
-                    KeySelector::first_greater_or_equal(&begin)
-                } else {
-                    KeySelector::first_greater_than(&last_key)
-                };
+            for range in &ranges {
+                let begin = &range.begin;
+                let end = &range.end;
+                let skip = range.offset + 1;
+                let mut last_key = vec![];
 
-                let trx = self.read_trx().await?;
-                let mut values = trx.get_ranges(
-                    RangeOption {
-                        begin: begin_selector,
-                        end: KeySelector::first_greater_than(&end),
-                        mode: options::StreamingMode::WantAll,
-                        reverse: !params.ascending,
-                        ..Default::default()
-                    },
-                    true,
-                );
+                'outer: loop {
+                    let begin_selector = if last_key.is_empty() {
+                        KeySelector::first_greater_or_equal(begin)
+                    } else {
+                        KeySelector::first_greater_than(&last_key)
+                    };
 
-                let mut last_key_ = vec![];
-                loop {
-                    match values.try_next().await {
-                        Ok(Some(values)) => {
-                            let mut key = &[] as &[u8];
-                            for value in values.iter() {
-                                key = value.key();
-
-                                // Check whether we are collecting a chunked value
-                                let cb_key = key.get(1..).unwrap_or_default();
-                                let cb_value = value.value();
-
-                                if let Some(chunk) = &mut chunked_key {
-                                    if chunk.key.len() + 1 == cb_key.len()
-                                        && cb_key[..chunk.key.len()] == chunk.key[..]
-                                    {
-                                        // This is a chunk of the current value
-                                        chunk.bytes.extend_from_slice(cb_value);
-                                        continue;
-                                    } else {
-                                        // Return collected chunked value
-                                        if !cb(&chunk.key, &chunk.bytes)? {
-                                            return Ok(());
-                                        }
+                    let trx = self.read_trx().await?;
+                    let mut values = trx.get_ranges(
+                        RangeOption {
+                            begin: begin_selector,
+                            end: KeySelector::first_greater_than(end),
+                            mode: options::StreamingMode::WantAll,
+                            reverse: !params.ascending,
+                            ..Default::default()
+                        },
+                        true,
+                    );
+
+                    let mut last_key_ = vec![];
+                    loop {
+                        match values.try_next().await {
+                            Ok(Some(values)) => {
+                                let mut key = &[] as &[u8];
+                                for value in values.iter() {
+                                    key = value.key();
+
+                                    // Check whether we are collecting a chunked value
+                                    let cb_key = key.get(skip..).unwrap_or_default();
+                                    let cb_value = value.value();
 
-                                        // Reset collector
-                                        chunked_key = None;
+                                    if let Some(chunk) = &mut chunked_key {
+                                        if chunk.key.len() + 1 == cb_key.len()
+                                            && cb_key[..chunk.key.len()] == chunk.key[..]
+                                        {
+                                            // This is a chunk of the current value
+                                            chunk.bytes.extend_from_slice(cb_value);
+                                            continue;
+                                        } else {
+                                            // Return collected chunked value
+                                            if !cb(&chunk.key, &chunk.bytes)? {
+                                                return Ok(());
+                                            }
+
+                                            // Reset collector
+                                            chunked_key = None;
+                                        }
                                     }
-                                }
 
-                                if cb_value.len() < MAX_VALUE_SIZE {
-                                    if !cb(cb_key, cb_value)? {
-                                        return Ok(());
+                                    if cb_value.len() < MAX_VALUE_SIZE {
+                                        if !cb(cb_key, cb_value)? {
+                                            return Ok(());
+                                        }
+                                    } else {
+                                        // Start collecting chunked value
+                                        chunked_key = Some(ChunkedValueCollector {
+                                            key: cb_key.to_vec(),
+                                            bytes: cb_value.to_vec(),
+                                        });
                                     }
-                                } else {
-                                    // Start collecting chunked value
-                                    chunked_key = Some(ChunkedValueCollector {
-                                        key: cb_key.to_vec(),
-                                        bytes: cb_value.to_vec(),
-                                    });
+                                }
+                                if values.more() {
+                                    last_key_ = key.to_vec();
                                 }
                             }
-                            if values.more() {
-                                last_key_ = key.to_vec();
-                            }
-                        }
-                        Ok(None) => {
-                            // Return any chunked value collected
-                            if let Some(chunked_key) = chunked_key.take() {
-                                cb(&chunked_key.key, &chunked_key.bytes)?;
-                            }
+                            Ok(None) => {
+                                // Return any chunked value collected
+                                if let Some(chunked_key) = chunked_key.take()
+                                    && !cb(&chunked_key.key, &chunked_key.bytes)?
+                                {
+                                    return Ok(());
+                                }
 
-                            break 'outer;
-                        }
-                        Err(e) => {
-                            if e.code() == 1007 && !last_key_.is_empty() {
-                                // Transaction is too old to perform reads or be committed
-                                drop(values);
-                                last_key = last_key_;
-                                continue 'outer;
-                            } else {
-                                return Err(into_error(e));
+                                break 'outer;
+                            }
+                            Err(e) => {
+                                if e.code() == 1007 && !last_key_.is_empty() {
+                                    // Transaction is too old to perform reads or be committed
+                                    drop(values);
+                                    last_key = last_key_;
+                                    continue 'outer;
+                                } else {
+                                    return Err(into_error(e));
+                                }
                             }
                         }
                     }
                 }
             }
         } else {
-            let trx = self.read_trx().await?;
-            let mut values = trx.get_ranges_keyvalues(
-                RangeOption {
-                    begin: KeySelector::first_greater_or_equal(&begin),
-                    end: KeySelector::first_greater_than(&end),
-                    mode: options::StreamingMode::Small,
-                    reverse: !params.ascending,
-                    ..Default::default()
-                },
-                true,
-            );
-
-            if let Some(value) = values.try_next().await.map_err(into_error)? {
-                cb(value.key().get(1..).unwrap_or_default(), value.value())?;
+            for range in ranges {
+                let trx = self.read_trx().await?;
+                let mut values = trx.get_ranges_keyvalues(
+                    RangeOption {
+                        begin: KeySelector::first_greater_or_equal(&range.begin),
+                        end: KeySelector::first_greater_than(&range.end),
+                        mode: options::StreamingMode::Small,
+                        reverse: !params.ascending,
+                        ..Default::default()
+                    },
+                    true,
+                );
+
+                if let Some(value) = values.try_next().await.map_err(into_error)? {
+                    cb(
+                        value.key().get(range.offset + 1..).unwrap_or_default(),
+                        value.value(),
+                    )?;
+                    break;
+                }
             }
         }
 
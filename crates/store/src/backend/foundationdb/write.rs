@@ -16,6 +16,7 @@ use crate::{
     },
     *,
 };
+use ahash::AHashMap;
 use foundationdb::{
     FdbError, KeySelector, RangeOption, Transaction,
     options::{self, MutationType},
@@ -40,6 +41,8 @@ impl FdbStore {
             let mut document_id = u32::MAX;
             let mut change_id = 0u64;
             let mut result = AssignedIds::default();
+            let mut partitions = AHashMap::new();
+            let mut is_migrating = false;
 
             let trx = self.db.create_trx().map_err(into_error)?;
 
@@ -58,7 +61,7 @@ impl FdbStore {
                 }
             }
 
-            for op in batch.ops.iter_mut() {
+            'ops: for op in batch.ops.iter_mut() {
                 match op {
                     Operation::AccountId {
                         account_id: account_id_,
@@ -79,8 +82,17 @@ impl FdbStore {
                         document_id = *document_id_;
                     }
                     Operation::Value { class, op } => {
-                        let mut key =
-                            class.serialize(account_id, collection, document_id, WITH_SUBSPACE);
+                        let Some(mut key) = self
+                            .physical_write_key(
+                                &trx,
+                                &mut partitions,
+                                class.serialize(account_id, collection, document_id, WITH_SUBSPACE),
+                            )
+                            .await?
+                        else {
+                            is_migrating = true;
+                            break 'ops;
+                        };
 
                         match op {
                             ValueOp::Set(value) => {
@@ -166,7 +178,7 @@ impl FdbStore {
                             }
                             ValueOp::Clear => {
                                 if matches!(
-                                    key[0],
+                                    class.subspace(collection),
                                     SUBSPACE_TASK_QUEUE
                                         | SUBSPACE_IN_MEMORY_VALUE
                                         | SUBSPACE_PROPERTY
@@ -209,6 +221,11 @@ impl FdbStore {
                             key: &*key,
                         }
                         .serialize(WITH_SUBSPACE);
+                        let Some(key) = self.physical_write_key(&trx, &mut partitions, key).await?
+                        else {
+                            is_migrating = true;
+                            break 'ops;
+                        };
 
                         if *set {
                             trx.set(&key, &[]);
@@ -223,6 +240,11 @@ impl FdbStore {
                             change_id,
                         }
                         .serialize(WITH_SUBSPACE);
+                        let Some(key) = self.physical_write_key(&trx, &mut partitions, key).await?
+                        else {
+                            is_migrating = true;
+                            break 'ops;
+                        };
 
                         trx.set(&key, set);
                     }
@@ -230,8 +252,17 @@ impl FdbStore {
                         class,
                         assert_value,
                     } => {
-                        let key =
-                            class.serialize(account_id, collection, document_id, WITH_SUBSPACE);
+                        let Some(key) = self
+                            .physical_write_key(
+                                &trx,
+                                &mut partitions,
+                                class.serialize(account_id, collection, document_id, WITH_SUBSPACE),
+                            )
+                            .await?
+                        else {
+                            is_migrating = true;
+                            break 'ops;
+                        };
 
                         let matches = match read_chunked_value(&key, &trx, false).await {
                             Ok(ChunkedValue::Single(bytes)) => assert_value.matches(bytes.as_ref()),
@@ -250,7 +281,16 @@ impl FdbStore {
                 }
             }
 
-            if self
+            if is_migrating {
+                // Wait until the migration of the account completes
+                trx.cancel();
+                if start.elapsed() >= MAX_COMMIT_TIME {
+                    return Err(trc::StoreEvent::FoundationdbError
+                        .ctx(trc::Key::Reason, "Account is being migrated to a partition"));
+                }
+                let backoff = rand::rng().random_range(250..=500);
+                tokio::time::sleep(Duration::from_millis(backoff)).await;
+            } else if self
                 .commit(
                     trx,
                     retry_count < MAX_COMMIT_ATTEMPTS && start.elapsed() < MAX_COMMIT_TIME,
@@ -339,11 +379,14 @@ impl FdbStore {
     }
 
     pub(crate) async fn delete_range(&self, from: impl Key, to: impl Key) -> trc::Result<()> {
-        let from = from.serialize(WITH_SUBSPACE);
-        let to = to.serialize(WITH_SUBSPACE);
+        let ranges = self
+            .partition_ranges(from.serialize(WITH_SUBSPACE), to.serialize(WITH_SUBSPACE))
+            .await?;
 
         let trx = self.db.create_trx().map_err(into_error)?;
-        trx.clear_range(&from, &to);
+        for range in ranges {
+            trx.clear_range(&range.begin, &range.end);
+        }
         self.commit(trx, false).await.map(|_| ())
     }
 }
@@ -67,6 +67,20 @@ impl FsStore {
         }))
     }
 
+    pub(crate) async fn get_blob_tail(&self, key: &[u8]) -> trc::Result<Option<(usize, u8)>> {
+        let mut blob = match File::open(self.build_path(key)).await {
+            Ok(blob) => blob,
+            Err(_) => return Ok(None),
+        };
+        let blob_size = blob.metadata().await.map_err(into_error)?.len() as usize;
+        if blob_size == 0 {
+            return Ok(None);
+        }
+
+        blob.seek(SeekFrom::End(-1)).await.map_err(into_error)?;
+        Ok(Some((blob_size, blob.read_u8().await.map_err(into_error)?)))
+    }
+
     pub(crate) async fn put_blob(&self, key: &[u8], data: &[u8]) -> trc::Result<()> {
         let blob_path = self.build_path(key);
 
@@ -38,7 +38,7 @@ pub const MAX_TOKEN_LENGTH: usize = (u8::MAX >> 1) as usize;
 pub const MAX_TOKEN_MASK: usize = MAX_TOKEN_LENGTH - 1;
 
 #[allow(dead_code)]
-fn deserialize_i64_le(key: &[u8], bytes: &[u8]) -> trc::Result<i64> {
+pub(crate) fn deserialize_i64_le(key: &[u8], bytes: &[u8]) -> trc::Result<i64> {
     Ok(i64::from_le_bytes(bytes[..].try_into().map_err(|_| {
         trc::Error::corrupted_key(key, bytes.into(), trc::location!())
     })?))
@@ -137,4 +137,35 @@ impl MysqlStore {
             Err(e) => Err(into_error(e)),
         }
     }
+
+    pub(crate) async fn iterate_counters<T: Key>(
+        &self,
+        begin: T,
+        end: T,
+        mut cb: impl for<'x> FnMut(&'x [u8], i64) -> trc::Result<bool> + Sync + Send,
+    ) -> trc::Result<()> {
+        let mut conn = self.conn_pool.get_conn().await.map_err(into_error)?;
+        let table = char::from(begin.subspace());
+        let begin = begin.serialize(0);
+        let end = end.serialize(0);
+
+        let s = conn
+            .prep(format!(
+                "SELECT k, v FROM {table} WHERE k >= ? AND k <= ? ORDER BY k ASC"
+            ))
+            .await
+            .map_err(into_error)?;
+        let mut rows = conn
+            .exec_stream::<(Vec<u8>, i64), _, _>(&s, (begin, end))
+            .await
+            .map_err(into_error)?;
+
+        while let Some((key, value)) = rows.try_next().await.map_err(into_error)? {
+            if !cb(&key, value)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
 }
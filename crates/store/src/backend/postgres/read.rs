@@ -130,4 +130,40 @@ impl PostgresStore {
             Err(e) => Err(into_error(e)),
         }
     }
+
+    pub(crate) async fn iterate_counters<T: Key>(
+        &self,
+        begin: T,
+        end: T,
+        mut cb: impl for<'x> FnMut(&'x [u8], i64) -> trc::Result<bool> + Sync + Send,
+    ) -> trc::Result<()> {
+        let conn = self.conn_pool.get().await.map_err(into_pool_error)?;
+        let table = char::from(begin.subspace());
+        let begin = begin.serialize(0);
+        let end = end.serialize(0);
+
+        let s = conn
+            .prepare_cached(&format!(
+                "SELECT k, v FROM {table} WHERE k >= $1 AND k <= $2 ORDER BY k ASC"
+            ))
+            .await
+            .map_err(into_error)?;
+        let rows = conn
+            .query_raw(&s, &[&begin, &end])
+            .await
+            .map_err(into_error)?;
+
+        pin_mut!(rows);
+
+        while let Some(row) = rows.try_next().await.map_err(into_error)? {
+            let key = row.try_get::<_, &[u8]>(0).map_err(into_error)?;
+            let value = row.try_get::<_, i64>(1).map_err(into_error)?;
+
+            if !cb(key, value)? {
+                break;
+            }
+        }
+
+        Ok(())
+    }
 }
@@ -4,11 +4,11 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use redis::AsyncCommands;
+use redis::{AsyncCommands, aio::MultiplexedConnection};
 
 use crate::Deserialize;
 
-use super::{RedisPool, RedisStore, into_error};
+use super::{RedisPool, RedisStore, into_error, pool::with_master};
 
 impl RedisStore {
     pub async fn key_set(&self, key: &[u8], value: &[u8], expires: Option<u64>) -> trc::Result<()> {
@@ -31,6 +31,12 @@ impl RedisStore {
                 )
                 .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.key_set_(conn, key, value, expires).await
+                })
+                .await
+            }
         }
     }
 
@@ -54,6 +60,12 @@ impl RedisStore {
                 )
                 .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.key_incr_(conn, key, value, expires).await
+                })
+                .await
+            }
         }
     }
 
@@ -67,6 +79,12 @@ impl RedisStore {
                 self.key_delete_(pool.get().await.map_err(into_error)?.as_mut(), key)
                     .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.key_delete_(conn, key).await
+                })
+                .await
+            }
         }
     }
 
@@ -80,6 +98,12 @@ impl RedisStore {
                 self.key_delete_prefix_(pool.get().await.map_err(into_error)?.as_mut(), prefix)
                     .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.key_delete_prefix_(conn, prefix).await
+                })
+                .await
+            }
         }
     }
 
@@ -96,6 +120,12 @@ impl RedisStore {
                 self.key_get_(pool.get().await.map_err(into_error)?.as_mut(), key)
                     .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.key_get_(conn, key).await
+                })
+                .await
+            }
         }
     }
 
@@ -109,6 +139,12 @@ impl RedisStore {
                 self.counter_get_(pool.get().await.map_err(into_error)?.as_mut(), key)
                     .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.counter_get_(conn, key).await
+                })
+                .await
+            }
         }
     }
 
@@ -122,6 +158,12 @@ impl RedisStore {
                 self.key_exists_(pool.get().await.map_err(into_error)?.as_mut(), key)
                     .await
             }
+            RedisPool::Sentinel(pool) => {
+                with_master(pool, async |conn: &mut MultiplexedConnection| {
+                    self.key_exists_(conn, key).await
+                })
+                .await
+            }
         }
     }
 
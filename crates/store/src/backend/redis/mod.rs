@@ -17,7 +17,11 @@ use registry::{
     schema::{enums::RedisProtocol, structs},
     types::duration::Duration,
 };
-use std::{fmt::Display, sync::Arc};
+use std::{
+    fmt::Display,
+    sync::{Arc, atomic::AtomicU64},
+    time::Instant,
+};
 
 pub mod lookup;
 pub mod pool;
@@ -37,9 +41,30 @@ pub struct RedisClusterConnectionManager {
     timeout: std::time::Duration,
 }
 
+pub struct RedisSentinelConnectionManager {
+    sentinels: Vec<Client>,
+    master_name: String,
+    master_scheme: &'static str,
+    master_auth: String,
+    master_params: &'static str,
+    timeout: std::time::Duration,
+    retry: RetryPolicy,
+    started: Instant,
+    // Milliseconds since start of the last detected failover and master check
+    failover_at: AtomicU64,
+    checked_at: AtomicU64,
+}
+
+pub(crate) struct RetryPolicy {
+    pub max_retries: u32,
+    pub min_wait: std::time::Duration,
+    pub max_wait: std::time::Duration,
+}
+
 pub enum RedisPool {
     Single(Pool<RedisConnectionManager>),
     Cluster(Pool<RedisClusterConnectionManager>),
+    Sentinel(Pool<RedisSentinelConnectionManager>),
 }
 
 impl RedisStore {
@@ -100,6 +125,92 @@ impl RedisStore {
             )?),
         })))
     }
+
+    pub async fn open_sentinel(
+        config: structs::RedisSentinelStore,
+    ) -> Result<InMemoryStore, String> {
+        let sentinels = config
+            .urls
+            .iter()
+            .map(|url| {
+                Client::open(url.as_str())
+                    .map_err(|err| format!("Failed to open Redis Sentinel client: {err:?}"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if sentinels.is_empty() {
+            return Err("At least one Redis Sentinel URL is required".to_string());
+        }
+
+        // Credentials and options are passed to the master through its URL
+        let mut master_auth = String::new();
+        let secret = config.auth_secret.secret().await?;
+        if config.auth_username.is_some() || secret.is_some() {
+            if let Some(username) = &config.auth_username {
+                master_auth.push_str(&encode_userinfo(username));
+            }
+            if let Some(secret) = &secret {
+                master_auth.push(':');
+                master_auth.push_str(&encode_userinfo(secret));
+            }
+            master_auth.push('@');
+        }
+
+        Ok(InMemoryStore::Redis(Arc::new(RedisStore {
+            pool: RedisPool::Sentinel(build_pool(
+                RedisSentinelConnectionManager {
+                    sentinels,
+                    master_name: config.master_name,
+                    master_scheme: if config.use_tls { "rediss" } else { "redis" },
+                    master_auth,
+                    master_params: if matches!(config.protocol_version, RedisProtocol::Resp3) {
+                        "?protocol=resp3"
+                    } else {
+                        ""
+                    },
+                    timeout: config.timeout.into_inner(),
+                    retry: RetryPolicy {
+                        max_retries: config.max_retries.unwrap_or(3) as u32,
+                        min_wait: config
+                            .min_retry_wait
+                            .map(|v| v.into_inner())
+                            .unwrap_or(std::time::Duration::from_millis(10)),
+                        max_wait: config
+                            .max_retry_wait
+                            .map(|v| v.into_inner())
+                            .unwrap_or(std::time::Duration::from_millis(1000)),
+                    },
+                    started: Instant::now(),
+                    failover_at: AtomicU64::new(0),
+                    checked_at: AtomicU64::new(0),
+                },
+                config.pool_max_connections,
+                config.pool_timeout_create,
+                config.pool_timeout_wait,
+                config.pool_timeout_recycle,
+            )?),
+        })))
+    }
+}
+
+impl RetryPolicy {
+    // Exponential backoff between the minimum and maximum wait times
+    pub fn wait(&self, attempt: u32) -> std::time::Duration {
+        self.min_wait
+            .saturating_mul(1 << attempt.min(16))
+            .min(self.max_wait)
+    }
+}
+
+fn encode_userinfo(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        if byte.is_ascii_alphanumeric() || matches!(byte, b'-' | b'.' | b'_' | b'~') {
+            encoded.push(byte as char);
+        } else {
+            encoded.push_str(&format!("%{byte:02X}"));
+        }
+    }
+    encoded
 }
 
 fn build_pool<M: Manager>(
@@ -129,6 +240,7 @@ impl std::fmt::Debug for RedisPool {
         match self {
             Self::Single(_) => f.debug_tuple("Single").finish(),
             Self::Cluster(_) => f.debug_tuple("Cluster").finish(),
+            Self::Sentinel(_) => f.debug_tuple("Sentinel").finish(),
         }
     }
 }
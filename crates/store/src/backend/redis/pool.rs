@@ -4,13 +4,21 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use deadpool::managed;
+use deadpool::managed::{self, Object, Pool};
 use redis::{
     aio::{ConnectionLike, MultiplexedConnection},
     cluster_async::ClusterConnection,
 };
+use std::{sync::atomic::Ordering, time::Instant};
 
-use super::{RedisClusterConnectionManager, RedisConnectionManager, into_error};
+use super::{
+    RedisClusterConnectionManager, RedisConnectionManager, RedisSentinelConnectionManager,
+    into_error,
+};
+use redis::{Client, Value};
+
+// Pooled connections are checked against the master role at most this often
+const MASTER_CHECK_INTERVAL: u64 = 5_000;
 
 impl managed::Manager for RedisConnectionManager {
     type Type = MultiplexedConnection;
@@ -59,3 +67,167 @@ impl managed::Manager for RedisClusterConnectionManager {
             .map_err(|err| managed::RecycleError::Backend(into_error(err)))
     }
 }
+
+impl managed::Manager for RedisSentinelConnectionManager {
+    type Type = MultiplexedConnection;
+    type Error = trc::Error;
+
+    async fn create(&self) -> Result<MultiplexedConnection, trc::Error> {
+        self.connect_master().await.map(|(_, conn)| conn)
+    }
+
+    async fn recycle(
+        &self,
+        conn: &mut MultiplexedConnection,
+        metrics: &managed::Metrics,
+    ) -> managed::RecycleResult<trc::Error> {
+        // Connections opened before a failover point to the demoted master
+        if self.elapsed(metrics.created) < self.failover_at.load(Ordering::Relaxed) {
+            return Err(managed::RecycleError::Backend(
+                trc::StoreEvent::RedisError.ctx(trc::Key::Details, "Redis master changed"),
+            ));
+        }
+
+        // Failovers without connection errors are detected by a periodic role check
+        let now = self.elapsed(Instant::now());
+        let checked_at = self.checked_at.load(Ordering::Relaxed);
+        if now.saturating_sub(checked_at) >= MASTER_CHECK_INTERVAL
+            && self
+                .checked_at
+                .compare_exchange(checked_at, now, Ordering::Relaxed, Ordering::Relaxed)
+                .is_ok()
+        {
+            is_master(conn).await.map_err(|err| {
+                self.failover();
+                managed::RecycleError::Backend(err)
+            })
+        } else {
+            conn.req_packed_command(&redis::cmd("PING"))
+                .await
+                .map(|_| ())
+                .map_err(|err| managed::RecycleError::Backend(into_error(err)))
+        }
+    }
+}
+
+// Commands that fail on a connection that no longer reaches the master are
+// retried with backoff on a new connection to the current master
+pub async fn with_master<T>(
+    pool: &Pool<RedisSentinelConnectionManager>,
+    mut f: impl AsyncFnMut(&mut MultiplexedConnection) -> trc::Result<T>,
+) -> trc::Result<T> {
+    let manager = pool.manager();
+    let mut attempt = 0;
+
+    loop {
+        let mut conn = pool.get().await.map_err(into_error)?;
+        match f(conn.as_mut()).await {
+            Ok(result) => return Ok(result),
+            Err(err)
+                if attempt < manager.retry.max_retries
+                    && is_master(conn.as_mut()).await.is_err() =>
+            {
+                trc::event!(
+                    Store(trc::StoreEvent::RedisError),
+                    Details = "Retrying command after Redis master failure",
+                    CausedBy = err,
+                );
+
+                manager.failover();
+                drop(Object::take(conn));
+                tokio::time::sleep(manager.retry.wait(attempt)).await;
+                attempt += 1;
+            }
+            Err(err) => return Err(err),
+        }
+    }
+}
+
+impl RedisSentinelConnectionManager {
+    // Asks each sentinel for the current master address, retrying with
+    // backoff until a node that reports itself as master is reached.
+    pub async fn connect_master(&self) -> trc::Result<(Client, MultiplexedConnection)> {
+        let mut last_err = None;
+
+        for attempt in 0..=self.retry.max_retries {
+            if attempt > 0 {
+                tokio::time::sleep(self.retry.wait(attempt - 1)).await;
+            }
+
+            for sentinel in &self.sentinels {
+                match self.try_connect_master(sentinel).await {
+                    Ok(result) => return Ok(result),
+                    Err(err) => {
+                        last_err = Some(err);
+                    }
+                }
+            }
+        }
+
+        Err(last_err.unwrap_or_else(|| {
+            trc::StoreEvent::RedisError.ctx(trc::Key::Details, "No Redis Sentinel available")
+        }))
+    }
+
+    async fn try_connect_master(
+        &self,
+        sentinel: &Client,
+    ) -> trc::Result<(Client, MultiplexedConnection)> {
+        let mut conn = self.connect(sentinel).await?;
+        let (host, port) = redis::cmd("SENTINEL")
+            .arg("get-master-addr-by-name")
+            .arg(&self.master_name)
+            .query_async::<Option<(String, u16)>>(&mut conn)
+            .await
+            .map_err(into_error)?
+            .ok_or_else(|| {
+                trc::StoreEvent::RedisError
+                    .ctx(trc::Key::Details, "Unknown Redis Sentinel master")
+                    .ctx(trc::Key::Id, self.master_name.clone())
+            })?;
+        let host = if host.contains(':') {
+            format!("[{host}]")
+        } else {
+            host
+        };
+
+        let client = Client::open(format!(
+            "{}://{}{host}:{port}/{}",
+            self.master_scheme, self.master_auth, self.master_params
+        ))
+        .map_err(into_error)?;
+        let mut conn = self.connect(&client).await?;
+        is_master(&mut conn).await?;
+
+        Ok((client, conn))
+    }
+
+    fn failover(&self) {
+        self.failover_at
+            .fetch_max(self.elapsed(Instant::now()), Ordering::Relaxed);
+    }
+
+    fn elapsed(&self, instant: Instant) -> u64 {
+        instant.saturating_duration_since(self.started).as_millis() as u64
+    }
+
+    async fn connect(&self, client: &Client) -> trc::Result<MultiplexedConnection> {
+        match tokio::time::timeout(self.timeout, client.get_multiplexed_async_connection()).await {
+            Ok(conn) => conn.map_err(into_error),
+            Err(_) => Err(trc::StoreEvent::RedisError.ctx(trc::Key::Details, "Connection Timeout")),
+        }
+    }
+}
+
+async fn is_master(conn: &mut MultiplexedConnection) -> trc::Result<()> {
+    let role = redis::cmd("ROLE")
+        .query_async::<Vec<Value>>(conn)
+        .await
+        .map_err(into_error)?;
+
+    match role.first() {
+        Some(Value::BulkString(role)) if role == b"master" => Ok(()),
+        Some(Value::SimpleString(role)) if role == "master" => Ok(()),
+        _ => Err(trc::StoreEvent::RedisError.ctx(trc::Key::Details, "Node is not a master")),
+    }
+}
@@ -77,18 +77,30 @@ impl RocksDbStore {
         let mut db_opts = Options::default();
         db_opts.create_missing_column_families(true);
         db_opts.create_if_missing(true);
-        db_opts.set_max_background_jobs(std::cmp::max(num_cpus::get() as i32, 3));
+        db_opts.set_max_background_jobs(
+            config
+                .max_background_jobs
+                .filter(|v| *v > 0)
+                .map(|v| v as i32)
+                .unwrap_or_else(|| std::cmp::max(num_cpus::get() as i32, 3)),
+        );
         db_opts.increase_parallelism(std::cmp::max(num_cpus::get() as i32, 3));
         db_opts.set_level_zero_file_num_compaction_trigger(1);
         db_opts.set_level_compaction_dynamic_level_bytes(true);
         //db_opts.set_keep_log_file_num(100);
         //db_opts.set_max_successive_merges(100);
         db_opts.set_write_buffer_size(config.buffer_size as usize);
+        if let Some(rate_limit) = config.compaction_rate_limit.filter(|v| *v > 0) {
+            // Limits the I/O bandwidth used by flushes and background compactions
+            db_opts.set_ratelimiter(rate_limit as i64, 100_000, 10);
+        }
+        let column_families = cfs.iter().map(|cf| cf.name().to_string()).collect();
 
         Ok(Store::RocksDb(Arc::new(RocksDbStore {
             db: OptimisticTransactionDB::open_cf_descriptors(&db_opts, idx_path, cfs)
                 .map_err(|err| format!("Failed to open database: {:?}", err))?
                 .into(),
+            column_families,
             worker_pool: rayon::ThreadPoolBuilder::new()
                 .num_threads(std::cmp::max(
                     config
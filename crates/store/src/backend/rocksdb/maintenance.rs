@@ -0,0 +1,92 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{RocksDbStore, into_error};
+use crate::{ColumnFamilyStats, LevelStats, StoreStats};
+use rocksdb::properties;
+
+impl RocksDbStore {
+    pub(crate) async fn stats(&self) -> trc::Result<StoreStats> {
+        let db = self.db.clone();
+        self.spawn_worker(move || {
+            let live_files = db.live_files().map_err(into_error)?;
+            let mut stats = StoreStats::default();
+
+            for name in &self.column_families {
+                let Some(cf) = db.cf_handle(name) else {
+                    continue;
+                };
+                let property = |property: &properties::PropName| {
+                    db.property_int_value_cf(&cf, property)
+                        .map(Option::unwrap_or_default)
+                        .map_err(into_error)
+                };
+
+                let mut levels: Vec<LevelStats> = Vec::new();
+                for file in live_files
+                    .iter()
+                    .filter(|file| &file.column_family_name == name)
+                {
+                    let level = file.level.max(0) as u32;
+                    if let Some(level_stats) = levels.iter_mut().find(|l| l.level == level) {
+                        level_stats.files += 1;
+                        level_stats.size += file.size as u64;
+                    } else {
+                        levels.push(LevelStats {
+                            level,
+                            files: 1,
+                            size: file.size as u64,
+                        });
+                    }
+                }
+                levels.sort_unstable_by_key(|l| l.level);
+
+                stats.column_families.push(ColumnFamilyStats {
+                    name: name.clone(),
+                    estimated_keys: property(properties::ESTIMATE_NUM_KEYS)?,
+                    live_data_size: property(properties::ESTIMATE_LIVE_DATA_SIZE)?,
+                    sst_files_size: property(properties::TOTAL_SST_FILES_SIZE)?,
+                    memtable_size: property(properties::CUR_SIZE_ALL_MEM_TABLES)?,
+                    pending_compaction_bytes: property(
+                        properties::ESTIMATE_PENDING_COMPACTION_BYTES,
+                    )?,
+                    levels,
+                });
+            }
+
+            Ok(stats)
+        })
+        .await
+    }
+
+    pub(crate) async fn compact(&self) -> trc::Result<()> {
+        let db = self.db.clone();
+        self.spawn_worker(move || {
+            for name in &self.column_families {
+                if let Some(cf) = db.cf_handle(name) {
+                    db.compact_range_cf(&cf, None::<&[u8]>, None::<&[u8]>);
+                }
+            }
+
+            Ok(())
+        })
+        .await
+    }
+
+    pub(crate) async fn flush(&self) -> trc::Result<()> {
+        let db = self.db.clone();
+        self.spawn_worker(move || {
+            for name in &self.column_families {
+                if let Some(cf) = db.cf_handle(name) {
+                    db.flush_cf(&cf).map_err(into_error)?;
+                }
+            }
+
+            Ok(())
+        })
+        .await
+    }
+}
@@ -12,6 +12,7 @@ use crate::{SUBSPACE_BLOBS, SUBSPACE_INDEXES, SUBSPACE_LOGS};
 
 pub mod blob;
 pub mod main;
+pub mod maintenance;
 pub mod read;
 pub mod write;
 
@@ -34,6 +35,7 @@ impl CfHandle for OptimisticTransactionDB<MultiThreaded> {
 
 pub struct RocksDbStore {
     db: Arc<OptimisticTransactionDB<MultiThreaded>>,
+    column_families: Vec<String>,
     worker_pool: rayon::ThreadPool,
 }
 
@@ -137,6 +137,29 @@ impl S3Store {
         }
     }
 
+    pub(crate) async fn get_blob_tail(&self, key: &[u8]) -> trc::Result<Option<(usize, u8)>> {
+        let path = self.build_key(key);
+        let (head, status) = self.bucket.head_object(&path).await.map_err(into_error)?;
+        let blob_size = match status {
+            200..=299 => head.content_length.unwrap_or_default().max(0) as usize,
+            404 => return Ok(None),
+            code => {
+                return Err(trc::StoreEvent::S3Error
+                    .reason("HEAD request failed")
+                    .ctx(trc::Key::Code, code));
+            }
+        };
+        if blob_size == 0 {
+            return Ok(None);
+        }
+
+        Ok(self
+            .get_blob(key, blob_size - 1..blob_size)
+            .await?
+            .and_then(|bytes| bytes.last().copied())
+            .map(|marker| (blob_size, marker)))
+    }
+
     pub(crate) async fn put_blob(&self, key: &[u8], data: &[u8]) -> trc::Result<()> {
         let path = self.build_key(key);
         let mut retries_left = self.max_retries;
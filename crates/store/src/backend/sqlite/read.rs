@@ -149,4 +149,46 @@ impl SqliteStore {
         })
         .await
     }
+
+    pub(crate) async fn iterate_counters<T: Key>(
+        &self,
+        begin: T,
+        end: T,
+        mut cb: impl for<'x> FnMut(&'x [u8], i64) -> trc::Result<bool> + Sync + Send,
+    ) -> trc::Result<()> {
+        let manager = self.conn_pool.clone();
+        self.spawn_worker(move || {
+            let conn = manager.get().map_err(into_error)?;
+            let table = char::from(begin.subspace());
+            let begin = begin.serialize(0);
+            let end = end.serialize(0);
+
+            let mut query = conn
+                .prepare_cached(&format!(
+                    "SELECT k, v FROM {table} WHERE k >= ? AND k <= ? ORDER BY k ASC"
+                ))
+                .map_err(into_error)?;
+            let mut rows = query.query([&begin, &end]).map_err(into_error)?;
+
+            while let Some(row) = rows.next().map_err(into_error)? {
+                let key = row
+                    .get_ref(0)
+                    .map_err(into_error)?
+                    .as_bytes()
+                    .map_err(into_error)?;
+                let value = row
+                    .get_ref(1)
+                    .map_err(into_error)?
+                    .as_i64()
+                    .map_err(into_error)?;
+
+                if !cb(key, value)? {
+                    break;
+                }
+            }
+
+            Ok(())
+        })
+        .await
+    }
 }
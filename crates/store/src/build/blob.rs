@@ -4,7 +4,7 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::{BlobStore, backend::fs::FsStore, registry::bootstrap::Bootstrap};
+use crate::{BlobStore, CompressionAlgo, backend::fs::FsStore, registry::bootstrap::Bootstrap};
 use registry::schema::{prelude::ObjectType, structs};
 
 #[allow(unreachable_patterns)]
@@ -45,6 +45,18 @@ impl BlobStore {
             #[cfg(feature = "enterprise")]
             structs::BlobStore::Sharded(store) => {
                 crate::backend::composite::sharded_blob::ShardedBlob::open(store).await
+            }
+            #[cfg(feature = "enterprise")]
+            structs::BlobStore::Regional(store) => {
+                crate::backend::composite::regional_blob::RegionalBlob::open(store).await
+            }
+            #[cfg(feature = "enterprise")]
+            structs::BlobStore::Tiered(store) => {
+                crate::backend::composite::tiered_blob::TieredBlob::open(store).await
+            }
+            #[cfg(feature = "enterprise")]
+            structs::BlobStore::Mirrored(store) => {
+                crate::backend::composite::mirrored_blob::MirroredBlob::open(store).await
             } // SPDX-SnippetEnd
             _ => Err("Binary was not compiled with the selected blob store backend".to_string()),
         };
@@ -64,14 +76,115 @@ impl BlobStore {
     #[cfg(feature = "enterprise")]
     pub fn downgrade_store(self) -> BlobStore {
         match self {
-            BlobStore::Sharded(_) => BlobStore::default(),
+            BlobStore::Sharded(_)
+            | BlobStore::Regional(_)
+            | BlobStore::Tiered(_)
+            | BlobStore::Mirrored(_) => BlobStore::default(),
             other => other,
         }
     }
 
     #[cfg(feature = "enterprise")]
     pub fn is_enterprise(&self) -> bool {
-        matches!(self, BlobStore::Sharded(_))
+        matches!(
+            self,
+            BlobStore::Sharded(_)
+                | BlobStore::Regional(_)
+                | BlobStore::Tiered(_)
+                | BlobStore::Mirrored(_)
+        )
     }
     // SPDX-SnippetEnd
+
+    pub fn in_region(&self, region: &str) -> Option<&BlobStore> {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        if let BlobStore::Regional(store) = self {
+            return store.region(region);
+        }
+        // SPDX-SnippetEnd
+
+        let _ = region;
+        None
+    }
+
+    pub fn default_region(&self) -> Option<&str> {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        if let BlobStore::Regional(store) = self {
+            return Some(store.regions[store.default_region].0.as_str());
+        }
+        // SPDX-SnippetEnd
+
+        None
+    }
+
+    pub fn is_regional(&self) -> bool {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        if matches!(self, BlobStore::Regional(_)) {
+            return true;
+        }
+        // SPDX-SnippetEnd
+
+        false
+    }
+
+    pub fn is_tiered(&self) -> bool {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        if matches!(self, BlobStore::Tiered(_)) {
+            return true;
+        }
+        // SPDX-SnippetEnd
+
+        false
+    }
+
+    // Backends that may each hold a copy of a blob, composite stores expose
+    // their members so damaged copies can be found and repaired individually.
+    pub fn replicas(&self) -> Vec<&BlobStore> {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        match self {
+            BlobStore::Regional(store) => {
+                return store.regions.iter().map(|(_, store)| store).collect();
+            }
+            BlobStore::Tiered(store) => return vec![&store.hot, &store.cold],
+            BlobStore::Mirrored(store) => return vec![&store.primary, &store.mirror],
+            _ => {}
+        }
+        // SPDX-SnippetEnd
+
+        vec![self]
+    }
+
+    pub async fn move_blob_tier(
+        &self,
+        key: &[u8],
+        to_cold: bool,
+        compression: CompressionAlgo,
+    ) -> trc::Result<bool> {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        if let BlobStore::Tiered(store) = self {
+            return store.move_blob(key, to_cold, compression).await;
+        }
+        // SPDX-SnippetEnd
+
+        let _ = (key, to_cold, compression);
+        Ok(false)
+    }
 }
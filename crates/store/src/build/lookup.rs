@@ -5,7 +5,9 @@
  */
 
 use crate::{LookupStores, registry::bootstrap::Bootstrap};
-use registry::schema::structs::{LookupStore, StoreLookup};
+use registry::schema::structs::{
+    HttpLookup, LookupStore, MemoryLookupKey, MemoryLookupKeyValue, StoreLookup,
+};
 use std::collections::hash_map::Entry;
 
 impl LookupStores {
@@ -17,6 +19,28 @@ impl LookupStores {
         stores
     }
 
+    // Lists the lookup namespaces without opening the underlying stores
+    pub async fn namespaces(bp: &mut Bootstrap) -> Vec<String> {
+        let mut namespaces = Vec::new();
+        for store in bp.list_infallible::<StoreLookup>().await {
+            namespaces.push(store.object.namespace);
+        }
+        for lookup in bp.list_infallible::<MemoryLookupKeyValue>().await {
+            namespaces.push(lookup.object.namespace);
+        }
+        for lookup in bp.list_infallible::<MemoryLookupKey>().await {
+            namespaces.push(lookup.object.namespace);
+        }
+        for http in bp.list_infallible::<HttpLookup>().await {
+            if http.object.enable {
+                namespaces.push(http.object.namespace);
+            }
+        }
+        namespaces.sort_unstable();
+        namespaces.dedup();
+        namespaces
+    }
+
     #[allow(unreachable_patterns)]
     pub async fn parse_stores(&mut self, bp: &mut Bootstrap) {
         for store in bp.list_infallible::<StoreLookup>().await {
@@ -60,6 +84,10 @@ impl LookupStores {
                 LookupStore::RedisCluster(redis_cluster_store) => {
                     crate::backend::redis::RedisStore::open_cluster(redis_cluster_store).await
                 }
+                #[cfg(feature = "redis")]
+                LookupStore::RedisSentinel(redis_sentinel_store) => {
+                    crate::backend::redis::RedisStore::open_sentinel(redis_sentinel_store).await
+                }
                 _ => Err(
                     "Binary was not compiled with the selected lookup store backend".to_string(),
                 ),
@@ -16,6 +16,24 @@ const NONE_MARKER: u8 = 0x00;
 impl BlobStore {
     pub async fn get_blob(&self, key: &[u8], range: Range<usize>) -> trc::Result<Option<Vec<u8>>> {
         let start_time = Instant::now();
+
+        // Uncompressed blobs are read partially from stores supporting ranged reads
+        if (range.start != 0 || range.end != usize::MAX)
+            && let Some(result) = self
+                .get_blob_range(key, range.clone())
+                .await
+                .caused_by(trc::location!())?
+        {
+            trc::event!(
+                Store(StoreEvent::BlobRead),
+                Key = key,
+                Elapsed = start_time.elapsed(),
+                Size = result.as_ref().map_or(0, |data| data.len()),
+            );
+
+            return Ok(result);
+        }
+
         let result = match &self {
             BlobStore::Store(store) => match store {
                 #[cfg(feature = "sqlite")]
@@ -47,6 +65,12 @@ impl BlobStore {
             // SPDX-License-Identifier: LicenseRef-SEL
             #[cfg(feature = "enterprise")]
             BlobStore::Sharded(store) => store.get_blob(key, 0..usize::MAX).await,
+            #[cfg(feature = "enterprise")]
+            BlobStore::Regional(store) => return Box::pin(store.get_blob(key, range)).await,
+            #[cfg(feature = "enterprise")]
+            BlobStore::Tiered(store) => return Box::pin(store.get_blob(key, range)).await,
+            #[cfg(feature = "enterprise")]
+            BlobStore::Mirrored(store) => return Box::pin(store.get_blob(key, range)).await,
             // SPDX-SnippetEnd
         }
         .caused_by(trc::location!())?;
@@ -104,12 +128,62 @@ impl BlobStore {
         }
     }
 
+    // Returns None when the store does not support ranged reads or when the
+    // blob is compressed and needs to be read in full.
+    async fn get_blob_range(
+        &self,
+        key: &[u8],
+        range: Range<usize>,
+    ) -> trc::Result<Option<Option<Vec<u8>>>> {
+        let tail = match self {
+            BlobStore::Fs(store) => store.get_blob_tail(key).await?,
+            #[cfg(feature = "s3")]
+            BlobStore::S3(store) => store.get_blob_tail(key).await?,
+            _ => return Ok(None),
+        };
+
+        match tail {
+            Some((blob_size, NONE_MARKER)) => {
+                let range = range.start..std::cmp::min(range.end, blob_size - 1);
+                if range.start >= range.end {
+                    return Ok(Some(Some(vec![])));
+                }
+
+                match self {
+                    BlobStore::Fs(store) => store.get_blob(key, range).await.map(Some),
+                    #[cfg(feature = "s3")]
+                    BlobStore::S3(store) => store.get_blob(key, range).await.map(Some),
+                    _ => Ok(None),
+                }
+            }
+            Some(_) => Ok(None),
+            None => Ok(Some(None)),
+        }
+    }
+
     pub async fn put_blob(
         &self,
         key: &[u8],
         data: &[u8],
         compression: CompressionAlgo,
     ) -> trc::Result<()> {
+        // SPDX-SnippetBegin
+        // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+        // SPDX-License-Identifier: LicenseRef-SEL
+        #[cfg(feature = "enterprise")]
+        if let BlobStore::Regional(store) = self {
+            return Box::pin(store.put_blob(key, data, compression)).await;
+        }
+        #[cfg(feature = "enterprise")]
+        if let BlobStore::Tiered(store) = self {
+            return Box::pin(store.put_blob(key, data, compression)).await;
+        }
+        #[cfg(feature = "enterprise")]
+        if let BlobStore::Mirrored(store) = self {
+            return Box::pin(store.put_blob(key, data, compression)).await;
+        }
+        // SPDX-SnippetEnd
+
         let data = match compression {
             CompressionAlgo::None => {
                 let mut uncompressed = Vec::with_capacity(data.len() + 1);
@@ -137,6 +211,45 @@ impl BlobStore {
             }
         };
 
+        self.write_blob(key, data).await
+    }
+
+    // Large blobs made of several buffers (such as headers followed by the message
+    // body) are written uncompressed without first copying the parts into one buffer
+    pub async fn put_blob_chained(
+        &self,
+        key: &[u8],
+        parts: &[&[u8]],
+        compression: CompressionAlgo,
+    ) -> trc::Result<()> {
+        match parts {
+            [data] => self.put_blob(key, data, compression).await,
+            // SPDX-SnippetBegin
+            // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+            // SPDX-License-Identifier: LicenseRef-SEL
+            #[cfg(feature = "enterprise")]
+            _ if matches!(
+                self,
+                BlobStore::Regional(_) | BlobStore::Tiered(_) | BlobStore::Mirrored(_)
+            ) =>
+            {
+                self.put_blob(key, &parts.concat(), compression).await
+            }
+            // SPDX-SnippetEnd
+            _ if compression == CompressionAlgo::None => {
+                let mut data =
+                    Vec::with_capacity(parts.iter().map(|part| part.len()).sum::<usize>() + 1);
+                for part in parts {
+                    data.extend_from_slice(part);
+                }
+                data.push(NONE_MARKER);
+                self.write_blob(key, data).await
+            }
+            _ => self.put_blob(key, &parts.concat(), compression).await,
+        }
+    }
+
+    async fn write_blob(&self, key: &[u8], data: Vec<u8>) -> trc::Result<()> {
         let start_time = Instant::now();
         let result = match &self {
             BlobStore::Store(store) => match store {
@@ -169,7 +282,10 @@ impl BlobStore {
             // SPDX-License-Identifier: LicenseRef-SEL
             #[cfg(feature = "enterprise")]
             BlobStore::Sharded(store) => store.put_blob(key, &data).await,
-            // SPDX-SnippetEnd
+            #[cfg(feature = "enterprise")]
+            BlobStore::Regional(_) | BlobStore::Tiered(_) | BlobStore::Mirrored(_) => {
+                unreachable!()
+            } // SPDX-SnippetEnd
         }
         .caused_by(trc::location!());
 
@@ -216,6 +332,12 @@ impl BlobStore {
             // SPDX-License-Identifier: LicenseRef-SEL
             #[cfg(feature = "enterprise")]
             BlobStore::Sharded(store) => store.delete_blob(key).await,
+            #[cfg(feature = "enterprise")]
+            BlobStore::Regional(store) => return Box::pin(store.delete_blob(key)).await,
+            #[cfg(feature = "enterprise")]
+            BlobStore::Tiered(store) => return Box::pin(store.delete_blob(key)).await,
+            #[cfg(feature = "enterprise")]
+            BlobStore::Mirrored(store) => return Box::pin(store.delete_blob(key)).await,
             // SPDX-SnippetEnd
         }
         .caused_by(trc::location!());
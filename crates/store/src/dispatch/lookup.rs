@@ -300,6 +300,23 @@ impl InMemoryStore {
         rate: &Rate,
         soft_check: bool,
     ) -> trc::Result<Option<u64>> {
+        let (requests, expires_in) = self.rate_usage(prefix, key, rate, soft_check).await?;
+
+        if requests <= rate.count {
+            Ok(None)
+        } else {
+            Ok(Some(expires_in))
+        }
+    }
+
+    // Returns the number of requests in the current window and the seconds until it resets
+    pub async fn rate_usage(
+        &self,
+        prefix: u8,
+        key: &[u8],
+        rate: &Rate,
+        soft_check: bool,
+    ) -> trc::Result<(u64, u64)> {
         let now = now();
         let range_start = now / rate.period.as_secs();
         let range_end = (range_start * rate.period.as_secs()) + rate.period.as_secs();
@@ -318,11 +335,7 @@ impl InMemoryStore {
             self.counter_get(bucket).await.caused_by(trc::location!())? + 1
         };
 
-        if requests <= rate.count as i64 {
-            Ok(None)
-        } else {
-            Ok(Some(expires_in))
-        }
+        Ok((requests.max(0) as u64, expires_in))
     }
 
     pub async fn try_lock(&self, prefix: u8, key: &[u8], duration: u64) -> trc::Result<bool> {
@@ -7,12 +7,14 @@
 use super::DocumentSet;
 use crate::{
     Deserialize, IterateParams, Key, QueryResult, SUBSPACE_COUNTER, SUBSPACE_INDEXES,
-    SUBSPACE_LOGS, Store, U32_LEN, Value, ValueKey,
+    SUBSPACE_LOGS, Store, StorePartition, StoreStats, U32_LEN, Value, ValueKey,
+    backend::deserialize_i64_le,
     write::{
         AnyClass, AnyKey, AssignedIds, Batch, BatchBuilder, Operation, ValueClass, ValueOp,
         key::{DeserializeBigEndian, KeySerializer},
     },
 };
+use ahash::AHashMap;
 use compact_str::ToCompactString;
 use std::time::Instant;
 use trc::{AddContext, StoreEvent};
@@ -133,6 +135,81 @@ impl Store {
         .caused_by(trc::location!())
     }
 
+    pub async fn iterate_counters<T: Key>(
+        &self,
+        begin: T,
+        end: T,
+        mut cb: impl for<'x> FnMut(&'x [u8], i64) -> trc::Result<bool> + Sync + Send,
+    ) -> trc::Result<()> {
+        let start_time = Instant::now();
+        let result = match self {
+            #[cfg(feature = "sqlite")]
+            Self::SQLite(store) => store.iterate_counters(begin, end, cb).await,
+            #[cfg(feature = "postgres")]
+            Self::PostgreSQL(store) => store.iterate_counters(begin, end, cb).await,
+            #[cfg(feature = "mysql")]
+            Self::MySQL(store) => store.iterate_counters(begin, end, cb).await,
+            // SPDX-SnippetBegin
+            // SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+            // SPDX-License-Identifier: LicenseRef-SEL
+            #[cfg(all(feature = "enterprise", any(feature = "postgres", feature = "mysql")))]
+            Self::SQLReadReplica(store) => store.iterate_counters(begin, end, cb).await,
+            // SPDX-SnippetEnd
+            Self::None => Err(trc::StoreEvent::NotConfigured.into()),
+            // Key-value stores keep counters as little-endian integers
+            _ => {
+                self.iterate(IterateParams::new(begin, end), |key, value| {
+                    cb(key, deserialize_i64_le(key, value)?)
+                })
+                .await
+            }
+        }
+        .caused_by(trc::location!());
+
+        trc::event!(
+            Store(StoreEvent::DataIterate),
+            Elapsed = start_time.elapsed(),
+        );
+
+        result
+    }
+
+    /// Fetches the value of a per-document counter for all documents
+    /// in a collection using a single range scan.
+    pub async fn get_counters(
+        &self,
+        account_id: u32,
+        collection: impl Into<u8>,
+        field: impl Into<u8>,
+    ) -> trc::Result<AHashMap<u32, i64>> {
+        let collection = collection.into();
+        let field = field.into();
+        let mut counters = AHashMap::new();
+
+        self.iterate_counters(
+            ValueKey {
+                account_id,
+                collection,
+                document_id: 0,
+                class: ValueClass::Property(field),
+            },
+            ValueKey {
+                account_id,
+                collection,
+                document_id: u32::MAX,
+                class: ValueClass::Property(field),
+            },
+            |key, value| {
+                counters.insert(key.deserialize_be_u32(key.len() - U32_LEN)?, value);
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        Ok(counters)
+    }
+
     #[allow(unreachable_patterns)]
     #[allow(unused_variables)]
     pub async fn sql_query<T: QueryResult + std::fmt::Debug>(
@@ -238,6 +315,52 @@ impl Store {
         .caused_by(trc::location!())
     }
 
+    pub async fn stats(&self) -> trc::Result<StoreStats> {
+        match self {
+            #[cfg(feature = "rocks")]
+            Self::RocksDb(store) => store.stats().await,
+            _ => Err(trc::StoreEvent::NotSupported.into_err()),
+        }
+        .caused_by(trc::location!())
+    }
+
+    pub async fn compact(&self) -> trc::Result<()> {
+        match self {
+            #[cfg(feature = "rocks")]
+            Self::RocksDb(store) => store.compact().await,
+            _ => Err(trc::StoreEvent::NotSupported.into_err()),
+        }
+        .caused_by(trc::location!())
+    }
+
+    pub async fn flush(&self) -> trc::Result<()> {
+        match self {
+            #[cfg(feature = "rocks")]
+            Self::RocksDb(store) => store.flush().await,
+            _ => Err(trc::StoreEvent::NotSupported.into_err()),
+        }
+        .caused_by(trc::location!())
+    }
+
+    pub async fn migrate_partitions(&self) -> trc::Result<usize> {
+        match self {
+            #[cfg(feature = "foundation")]
+            Self::FoundationDb(store) => store.migrate_partitions().await,
+            _ => Err(trc::StoreEvent::NotSupported.into_err()),
+        }
+        .caused_by(trc::location!())
+    }
+
+    #[allow(unused_variables)]
+    pub async fn erase_partition(&self, partition: StorePartition) -> trc::Result<bool> {
+        match self {
+            #[cfg(feature = "foundation")]
+            Self::FoundationDb(store) => store.erase_partition(partition).await,
+            _ => Err(trc::StoreEvent::NotSupported.into_err()),
+        }
+        .caused_by(trc::location!())
+    }
+
     pub async fn delete_range(&self, from: impl Key, to: impl Key) -> trc::Result<()> {
         match self {
             #[cfg(feature = "sqlite")]
@@ -57,6 +57,18 @@ pub trait SerializeInfallible {
     fn serialize(&self) -> Vec<u8>;
 }
 
+// Receives the progress of long-running operations, which stop as soon as
+// it returns false.
+pub trait ProgressTracker: Send {
+    fn update(&mut self, processed: u64, total: u64) -> impl Future<Output = bool> + Send;
+}
+
+impl ProgressTracker for () {
+    async fn update(&mut self, _: u64, _: u64) -> bool {
+        true
+    }
+}
+
 // Key serialization flags
 pub(crate) const WITH_SUBSPACE: u32 = 1;
 
@@ -179,6 +191,12 @@ pub enum BlobStore {
     // SPDX-License-Identifier: LicenseRef-SEL
     #[cfg(feature = "enterprise")]
     Sharded(Arc<backend::composite::sharded_blob::ShardedBlob>),
+    #[cfg(feature = "enterprise")]
+    Regional(Arc<backend::composite::regional_blob::RegionalBlob>),
+    #[cfg(feature = "enterprise")]
+    Tiered(Arc<backend::composite::tiered_blob::TieredBlob>),
+    #[cfg(feature = "enterprise")]
+    Mirrored(Arc<backend::composite::mirrored_blob::MirroredBlob>),
     // SPDX-SnippetEnd
 }
 
@@ -358,6 +376,38 @@ pub struct NamedRows {
     pub rows: Vec<Row>,
 }
 
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreStats {
+    pub column_families: Vec<ColumnFamilyStats>,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnFamilyStats {
+    pub name: String,
+    pub estimated_keys: u64,
+    pub live_data_size: u64,
+    pub sst_files_size: u64,
+    pub memtable_size: u64,
+    pub pending_compaction_bytes: u64,
+    pub levels: Vec<LevelStats>,
+}
+
+#[derive(Clone, Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LevelStats {
+    pub level: u32,
+    pub files: u64,
+    pub size: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum StorePartition {
+    Account(u32),
+    Tenant(u32),
+}
+
 #[derive(Clone, Copy)]
 pub enum QueryType {
     Execute,
@@ -6,12 +6,12 @@
 
 use super::{BlobOp, Operation, ValueClass, ValueOp, key::DeserializeBigEndian, now};
 use crate::{
-    BlobStore, Deserialize, IterateParams, SerializeInfallible, Store, U16_LEN, U32_LEN, U64_LEN,
-    ValueKey,
+    BlobStore, CompressionAlgo, Deserialize, IterateParams, ProgressTracker, SerializeInfallible,
+    Store, U16_LEN, U32_LEN, U64_LEN, ValueKey,
     write::{BatchBuilder, BlobLink, RegistryClass},
 };
 use registry::{
-    schema::prelude::Property,
+    schema::{enums::BlobIntegrityIssueType, prelude::Property},
     types::{EnumImpl, id::ObjectId},
 };
 use std::time::Instant;
@@ -21,12 +21,39 @@ use types::{
     blob_hash::{BLOB_HASH_LEN, BlobHash},
 };
 
+const TEMP_LINK: usize = BLOB_HASH_LEN + U32_LEN + U64_LEN;
+pub(crate) const DOC_LINK: usize = BLOB_HASH_LEN + U64_LEN + 1;
+pub(crate) const ID_LINK: usize = BLOB_HASH_LEN + U64_LEN;
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct BlobQuota {
     pub bytes: usize,
     pub count: usize,
 }
 
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct AccountBlobs {
+    pub exclusive: Vec<BlobHash>,
+    pub shared: Vec<BlobHash>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct DamagedBlob {
+    pub hash: BlobHash,
+    pub issue: BlobIntegrityIssueType,
+    pub size: Option<u64>,
+    pub repaired: bool,
+}
+
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct BlobUsage {
+    pub blobs: u64,
+    pub references: u64,
+    pub stored_bytes: u64,
+    pub referenced_bytes: u64,
+    pub unsized_blobs: u64,
+}
+
 impl Store {
     pub async fn blob_exists(&self, hash: impl AsRef<BlobHash> + Sync + Send) -> trc::Result<bool> {
         self.key_exists(ValueKey {
@@ -203,6 +230,372 @@ impl Store {
 
         Ok(())
     }
+
+    pub async fn blob_usage(&self) -> trc::Result<BlobUsage> {
+        let from_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit {
+                hash: BlobHash::default(),
+            }),
+        };
+        let to_key = ValueKey {
+            account_id: u32::MAX,
+            collection: u8::MAX,
+            document_id: u32::MAX,
+            class: ValueClass::Blob(BlobOp::Link {
+                hash: BlobHash::new_max(),
+                to: BlobLink::Document,
+            }),
+        };
+
+        let mut usage = BlobUsage::default();
+        let mut last_hash = BlobHash::default();
+        let mut last_size = None;
+        let mut last_references = 0;
+        self.iterate(
+            IterateParams::new(from_key, to_key).ascending(),
+            |key, value| {
+                let hash = key.get(0..BLOB_HASH_LEN).ok_or_else(|| {
+                    trc::Error::corrupted_key(key, value.into(), trc::location!())
+                })?;
+                if hash != last_hash.as_slice() {
+                    usage.add(last_size.take(), last_references);
+                    last_hash = BlobHash::try_from_hash_slice(hash).unwrap();
+                    last_references = 0;
+                }
+
+                match key.len() {
+                    BLOB_HASH_LEN => {
+                        // Blobs committed before sizes were recorded have an empty value
+                        last_size = Some(value.deserialize_be_u64(0).ok());
+                    }
+                    DOC_LINK | ID_LINK => {
+                        last_references += 1;
+                    }
+                    _ => {}
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+        usage.add(last_size, last_references);
+
+        Ok(usage)
+    }
+
+    pub async fn account_blobs(&self, account_id: u32) -> trc::Result<AccountBlobs> {
+        let from_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit {
+                hash: BlobHash::default(),
+            }),
+        };
+        let to_key = ValueKey {
+            account_id: u32::MAX,
+            collection: u8::MAX,
+            document_id: u32::MAX,
+            class: ValueClass::Blob(BlobOp::Link {
+                hash: BlobHash::new_max(),
+                to: BlobLink::Document,
+            }),
+        };
+
+        // Links are keyed by hash, so the whole link space has to be scanned
+        let mut blobs = AccountBlobs::default();
+        let mut last_hash = BlobHash::default();
+        let mut is_linked = false;
+        let mut is_shared = false;
+        self.iterate(
+            IterateParams::new(from_key, to_key).ascending().no_values(),
+            |key, _| {
+                let hash = key
+                    .get(0..BLOB_HASH_LEN)
+                    .ok_or_else(|| trc::Error::corrupted_key(key, None, trc::location!()))?;
+                if hash != last_hash.as_slice() {
+                    blobs.add(std::mem::take(&mut last_hash), is_linked, is_shared);
+                    last_hash = BlobHash::try_from_hash_slice(hash).unwrap();
+                    is_linked = false;
+                    is_shared = false;
+                }
+
+                match key.len() {
+                    TEMP_LINK | DOC_LINK => {
+                        if key.deserialize_be_u32(BLOB_HASH_LEN)? == account_id {
+                            is_linked = true;
+                        } else {
+                            is_shared = true;
+                        }
+                    }
+                    ID_LINK => {
+                        is_shared = true;
+                    }
+                    _ => {}
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+        blobs.add(last_hash, is_linked, is_shared);
+
+        Ok(blobs)
+    }
+
+    pub async fn index_blob_sizes(
+        &self,
+        blob_store: BlobStore,
+        shard_index: u8,
+    ) -> trc::Result<()> {
+        let mut from_hash = BlobHash::default();
+        let mut to_hash = BlobHash::new_max();
+        from_hash.0[0] = shard_index;
+        to_hash.0[0] = shard_index;
+        let from_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit { hash: from_hash }),
+        };
+        let to_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit { hash: to_hash }),
+        };
+
+        // Find committed blobs without a recorded size
+        let mut hashes = Vec::new();
+        self.iterate(
+            IterateParams::new(from_key, to_key).ascending(),
+            |key, value| {
+                if key.len() == BLOB_HASH_LEN && value.is_empty() {
+                    hashes.push(BlobHash::try_from_hash_slice(key).unwrap());
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        let mut batch = BatchBuilder::new();
+        for hash in hashes {
+            let Some(blob) = blob_store
+                .get_blob(hash.as_slice(), 0..usize::MAX)
+                .await
+                .caused_by(trc::location!())?
+            else {
+                continue;
+            };
+
+            batch.set(BlobOp::Commit { hash }, (blob.len() as u64).serialize());
+
+            if batch.is_large_batch() {
+                self.write(batch.build_all())
+                    .await
+                    .caused_by(trc::location!())?;
+                batch = BatchBuilder::new();
+            }
+        }
+        if !batch.is_empty() {
+            self.write(batch.build_all())
+                .await
+                .caused_by(trc::location!())?;
+        }
+
+        Ok(())
+    }
+
+    pub async fn scrub_blobs_all_shards(
+        &self,
+        blob_store: BlobStore,
+        repair: bool,
+        compression: CompressionAlgo,
+    ) -> trc::Result<Vec<DamagedBlob>> {
+        let mut damaged = Vec::new();
+        for shard_index in 0u8..=255 {
+            damaged.extend(
+                self.scrub_blobs(
+                    blob_store.clone(),
+                    shard_index,
+                    repair,
+                    compression,
+                    &mut (),
+                )
+                .await?,
+            );
+        }
+        Ok(damaged)
+    }
+
+    pub async fn scrub_blobs(
+        &self,
+        blob_store: BlobStore,
+        shard_index: u8,
+        repair: bool,
+        compression: CompressionAlgo,
+        tracker: &mut impl ProgressTracker,
+    ) -> trc::Result<Vec<DamagedBlob>> {
+        let started = Instant::now();
+        let mut from_hash = BlobHash::default();
+        let mut to_hash = BlobHash::new_max();
+        from_hash.0[0] = shard_index;
+        to_hash.0[0] = shard_index;
+        let from_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit { hash: from_hash }),
+        };
+        let to_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit { hash: to_hash }),
+        };
+
+        // Committed blobs must be present in the blob store
+        let mut hashes = Vec::new();
+        self.iterate(
+            IterateParams::new(from_key, to_key).ascending(),
+            |key, value| {
+                if key.len() == BLOB_HASH_LEN {
+                    hashes.push((
+                        BlobHash::try_from_hash_slice(key).unwrap(),
+                        value.deserialize_be_u64(0).ok(),
+                    ));
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        let total = hashes.len();
+        let replicas = blob_store.replicas();
+        let mut damaged = Vec::new();
+        for (processed, (hash, size)) in hashes.into_iter().enumerate() {
+            if !tracker.update(processed as u64, total as u64).await {
+                break;
+            }
+
+            // Each replica is checked on its own, a copy that does not match its
+            // hash is hidden from regular reads as long as another one is intact.
+            let mut intact = None;
+            let mut corrupted = Vec::new();
+            for replica in &replicas {
+                match replica.get_blob(hash.as_slice(), 0..usize::MAX).await {
+                    Ok(Some(data)) => {
+                        if BlobHash::generate(&data) == hash {
+                            intact = Some(data);
+                        } else {
+                            corrupted.push(*replica);
+                        }
+                    }
+                    Ok(None) => {}
+                    Err(err) if err.matches(trc::EventType::Store(StoreEvent::DecompressError)) => {
+                        corrupted.push(*replica);
+                    }
+                    Err(err) => return Err(err.caused_by(trc::location!())),
+                }
+            }
+
+            let issue = match (&intact, corrupted.is_empty()) {
+                (Some(_), true) => continue,
+                (None, true) => {
+                    trc::event!(
+                        Store(StoreEvent::BlobMissing),
+                        BlobId = hash.to_hex(),
+                        Size = size,
+                    );
+                    BlobIntegrityIssueType::Missing
+                }
+                (_, false) => {
+                    trc::event!(
+                        Store(StoreEvent::BlobCorrupted),
+                        BlobId = hash.to_hex(),
+                        Size = size,
+                        Total = corrupted.len(),
+                    );
+                    BlobIntegrityIssueType::Corrupted
+                }
+            };
+
+            // Damaged copies are overwritten with an intact one from another replica
+            let mut repaired = false;
+            if repair && let Some(data) = &intact {
+                for replica in corrupted {
+                    replica
+                        .put_blob(hash.as_slice(), data, compression)
+                        .await
+                        .caused_by(trc::location!())?;
+                }
+                repaired = true;
+
+                trc::event!(Store(StoreEvent::BlobRepaired), BlobId = hash.to_hex());
+            }
+
+            damaged.push(DamagedBlob {
+                hash,
+                issue,
+                size,
+                repaired,
+            });
+        }
+
+        trc::event!(
+            Store(StoreEvent::BlobStoreScrubbed),
+            Id = shard_index as u16,
+            Total = total,
+            TotalFailures = damaged.len(),
+            Elapsed = started.elapsed()
+        );
+
+        Ok(damaged)
+    }
+}
+
+impl AccountBlobs {
+    fn add(&mut self, hash: BlobHash, is_linked: bool, is_shared: bool) {
+        if is_linked {
+            if is_shared {
+                self.shared.push(hash);
+            } else {
+                self.exclusive.push(hash);
+            }
+        }
+    }
+}
+
+impl BlobUsage {
+    fn add(&mut self, size: Option<Option<u64>>, references: u64) {
+        match size {
+            Some(Some(size)) => {
+                self.blobs += 1;
+                self.references += references;
+                self.stored_bytes += size;
+                self.referenced_bytes += size * references.max(1);
+            }
+            Some(None) => {
+                self.blobs += 1;
+                self.references += references;
+                self.unsized_blobs += 1;
+            }
+            None => {}
+        }
+    }
+
+    pub fn deduplicated_bytes(&self) -> u64 {
+        self.referenced_bytes.saturating_sub(self.stored_bytes)
+    }
 }
 
 struct BlobPurgeState {
@@ -251,10 +644,6 @@ impl BlobPurgeState {
     }
 
     pub fn process_key(&mut self, key: &[u8], value: &[u8]) -> trc::Result<()> {
-        const TEMP_LINK: usize = BLOB_HASH_LEN + U32_LEN + U64_LEN;
-        const DOC_LINK: usize = BLOB_HASH_LEN + U64_LEN + 1;
-        const ID_LINK: usize = BLOB_HASH_LEN + U64_LEN;
-
         match key.len() {
             BLOB_HASH_LEN => {
                 // Main blob entry
@@ -0,0 +1,371 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{
+    AnyKey, BatchBuilder, BlobLink, BlobOp, ValueClass,
+    blob::{DOC_LINK, ID_LINK},
+    key::DeserializeBigEndian,
+};
+use crate::{IterateParams, SUBSPACE_INDEXES, Store, U32_LEN, ValueKey};
+use ahash::AHashMap;
+use roaring::RoaringBitmap;
+use std::time::Instant;
+use trc::{AddContext, StoreEvent};
+use types::{
+    blob_hash::{BLOB_HASH_LEN, BlobHash},
+    collection::Collection,
+};
+
+#[derive(Debug, Default, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreDoctorReport {
+    pub accounts: u64,
+    pub keys_checked: u64,
+    pub findings: Vec<StoreFinding>,
+    pub repaired: u64,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StoreFinding {
+    pub issue: StoreIssue,
+    pub account_id: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub grant_account_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub collection: Option<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub document_id: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_hash: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub keys: Option<u64>,
+    pub repaired: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StoreIssue {
+    // Index keys of an account that no longer exists
+    OrphanedIndex,
+    // Permissions granted on or to an account that no longer exists
+    OrphanedAcl,
+    // Blob links held by an account that no longer exists
+    OrphanedBlobLink,
+    // Blob links to a blob that was never committed, these are only reported
+    // as the documents referencing them cannot be restored automatically
+    DanglingBlobLink,
+}
+
+impl Store {
+    // Cross-checks the data store against the set of existing accounts. Repairs
+    // only ever remove keys that belong to destroyed accounts.
+    pub async fn doctor(
+        &self,
+        accounts: &RoaringBitmap,
+        repair: bool,
+    ) -> trc::Result<StoreDoctorReport> {
+        let started = Instant::now();
+        let mut report = StoreDoctorReport {
+            accounts: accounts.len(),
+            ..Default::default()
+        };
+
+        self.doctor_indexes(accounts, repair, &mut report)
+            .await
+            .caused_by(trc::location!())?;
+        self.doctor_acls(accounts, repair, &mut report)
+            .await
+            .caused_by(trc::location!())?;
+        self.doctor_blob_links(accounts, repair, &mut report)
+            .await
+            .caused_by(trc::location!())?;
+
+        report.repaired = report
+            .findings
+            .iter()
+            .filter(|finding| finding.repaired)
+            .count() as u64;
+        report.elapsed_ms = started.elapsed().as_millis() as u64;
+
+        trc::event!(
+            Store(StoreEvent::DataStoreChecked),
+            Total = report.keys_checked,
+            TotalFailures = report.findings.len(),
+            Details = report.repaired,
+            Elapsed = started.elapsed()
+        );
+
+        Ok(report)
+    }
+
+    async fn doctor_indexes(
+        &self,
+        accounts: &RoaringBitmap,
+        repair: bool,
+        report: &mut StoreDoctorReport,
+    ) -> trc::Result<()> {
+        // Keys owned by u32::MAX are not bound to an account
+        let mut orphaned: AHashMap<u32, u64> = AHashMap::new();
+        self.iterate(
+            IterateParams::new(
+                AnyKey {
+                    subspace: SUBSPACE_INDEXES,
+                    key: 0u32.to_be_bytes().to_vec(),
+                },
+                AnyKey {
+                    subspace: SUBSPACE_INDEXES,
+                    key: u32::MAX.to_be_bytes().to_vec(),
+                },
+            )
+            .no_values(),
+            |key, _| {
+                report.keys_checked += 1;
+                let account_id = key.deserialize_be_u32(0)?;
+                if account_id != u32::MAX && !accounts.contains(account_id) {
+                    *orphaned.entry(account_id).or_default() += 1;
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        let mut orphaned = orphaned.into_iter().collect::<Vec<_>>();
+        orphaned.sort_unstable();
+        for (account_id, keys) in orphaned {
+            if repair {
+                self.delete_range(
+                    AnyKey {
+                        subspace: SUBSPACE_INDEXES,
+                        key: account_id.to_be_bytes().to_vec(),
+                    },
+                    AnyKey {
+                        subspace: SUBSPACE_INDEXES,
+                        key: (account_id + 1).to_be_bytes().to_vec(),
+                    },
+                )
+                .await
+                .caused_by(trc::location!())?;
+            }
+
+            report.findings.push(StoreFinding {
+                keys: Some(keys),
+                repaired: repair,
+                ..StoreFinding::new(StoreIssue::OrphanedIndex, account_id)
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn doctor_acls(
+        &self,
+        accounts: &RoaringBitmap,
+        repair: bool,
+        report: &mut StoreDoctorReport,
+    ) -> trc::Result<()> {
+        let from_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Acl(0),
+        };
+        let to_key = ValueKey {
+            account_id: u32::MAX,
+            collection: u8::MAX,
+            document_id: u32::MAX,
+            class: ValueClass::Acl(u32::MAX),
+        };
+
+        let mut findings = Vec::new();
+        self.iterate(
+            IterateParams::new(from_key, to_key).ascending().no_values(),
+            |key, _| {
+                report.keys_checked += 1;
+                let grant_account_id = key.deserialize_be_u32(0)?;
+                let account_id = key.deserialize_be_u32(U32_LEN)?;
+                if !accounts.contains(grant_account_id) || !accounts.contains(account_id) {
+                    findings.push(StoreFinding {
+                        grant_account_id: Some(grant_account_id),
+                        collection: Some(*key.get(U32_LEN * 2).ok_or_else(|| {
+                            trc::Error::corrupted_key(key, None, trc::location!())
+                        })?),
+                        document_id: Some(key.deserialize_be_u32((U32_LEN * 2) + 1)?),
+                        repaired: repair,
+                        ..StoreFinding::new(StoreIssue::OrphanedAcl, account_id)
+                    });
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        if repair {
+            let mut batch = BatchBuilder::new();
+            for finding in &findings {
+                batch
+                    .with_account_id(finding.account_id)
+                    .with_collection(Collection::from(finding.collection.unwrap_or_default()))
+                    .with_document(finding.document_id.unwrap_or_default())
+                    .acl_revoke(finding.grant_account_id.unwrap_or_default());
+
+                if batch.is_large_batch() {
+                    self.write(std::mem::take(&mut batch).build_all())
+                        .await
+                        .caused_by(trc::location!())?;
+                }
+            }
+            if !batch.is_empty() {
+                self.write(batch.build_all())
+                    .await
+                    .caused_by(trc::location!())?;
+            }
+        }
+        report.findings.extend(findings);
+
+        Ok(())
+    }
+
+    async fn doctor_blob_links(
+        &self,
+        accounts: &RoaringBitmap,
+        repair: bool,
+        report: &mut StoreDoctorReport,
+    ) -> trc::Result<()> {
+        let from_key = ValueKey {
+            account_id: 0,
+            collection: 0,
+            document_id: 0,
+            class: ValueClass::Blob(BlobOp::Commit {
+                hash: BlobHash::default(),
+            }),
+        };
+        let to_key = ValueKey {
+            account_id: u32::MAX,
+            collection: u8::MAX,
+            document_id: u32::MAX,
+            class: ValueClass::Blob(BlobOp::Link {
+                hash: BlobHash::new_max(),
+                to: BlobLink::Document,
+            }),
+        };
+
+        // Commits sort before the links of the same hash
+        let mut findings = Vec::new();
+        let mut orphaned = Vec::new();
+        let mut last_hash = BlobHash::default();
+        let mut is_committed = false;
+        self.iterate(
+            IterateParams::new(from_key, to_key).ascending().no_values(),
+            |key, _| {
+                report.keys_checked += 1;
+                let hash = key
+                    .get(0..BLOB_HASH_LEN)
+                    .ok_or_else(|| trc::Error::corrupted_key(key, None, trc::location!()))?;
+                if hash != last_hash.as_slice() {
+                    last_hash = BlobHash::try_from_hash_slice(hash).unwrap();
+                    is_committed = false;
+                }
+
+                match key.len() {
+                    BLOB_HASH_LEN => {
+                        is_committed = true;
+                    }
+                    DOC_LINK => {
+                        let account_id = key.deserialize_be_u32(BLOB_HASH_LEN)?;
+                        let is_orphaned = account_id != u32::MAX && !accounts.contains(account_id);
+                        if is_orphaned || !is_committed {
+                            let collection = key[BLOB_HASH_LEN + U32_LEN];
+                            let document_id =
+                                key.deserialize_be_u32(BLOB_HASH_LEN + U32_LEN + 1)?;
+                            if is_orphaned {
+                                orphaned.push((
+                                    account_id,
+                                    collection,
+                                    document_id,
+                                    last_hash.clone(),
+                                ));
+                            }
+                            findings.push(StoreFinding {
+                                collection: Some(collection),
+                                document_id: Some(document_id),
+                                blob_hash: Some(last_hash.to_hex()),
+                                repaired: repair && is_orphaned,
+                                ..StoreFinding::new(
+                                    if is_orphaned {
+                                        StoreIssue::OrphanedBlobLink
+                                    } else {
+                                        StoreIssue::DanglingBlobLink
+                                    },
+                                    account_id,
+                                )
+                            });
+                        }
+                    }
+                    ID_LINK if !is_committed => {
+                        findings.push(StoreFinding {
+                            blob_hash: Some(last_hash.to_hex()),
+                            ..StoreFinding::new(StoreIssue::DanglingBlobLink, u32::MAX)
+                        });
+                    }
+                    _ => {}
+                }
+
+                Ok(true)
+            },
+        )
+        .await
+        .caused_by(trc::location!())?;
+
+        if repair {
+            let mut batch = BatchBuilder::new();
+            for (account_id, collection, document_id, hash) in orphaned {
+                batch
+                    .with_account_id(account_id)
+                    .with_collection(Collection::from(collection))
+                    .with_document(document_id)
+                    .clear(BlobOp::Link {
+                        hash,
+                        to: BlobLink::Document,
+                    });
+
+                if batch.is_large_batch() {
+                    self.write(std::mem::take(&mut batch).build_all())
+                        .await
+                        .caused_by(trc::location!())?;
+                }
+            }
+            if !batch.is_empty() {
+                self.write(batch.build_all())
+                    .await
+                    .caused_by(trc::location!())?;
+            }
+        }
+        report.findings.extend(findings);
+
+        Ok(())
+    }
+}
+
+impl StoreFinding {
+    fn new(issue: StoreIssue, account_id: u32) -> Self {
+        StoreFinding {
+            issue,
+            account_id,
+            grant_account_id: None,
+            collection: None,
+            document_id: None,
+            blob_hash: None,
+            keys: None,
+            repaired: false,
+        }
+    }
+}
@@ -481,6 +481,7 @@ const REG_METRIC: u16 = ObjectType::Metric as u16;
 const REPORT_EXTERNAL_ARF: u16 = ObjectType::ArfExternalReport as u16;
 const REPORT_EXTERNAL_DMARC: u16 = ObjectType::DmarcExternalReport as u16;
 const REPORT_EXTERNAL_TLS: u16 = ObjectType::TlsExternalReport as u16;
+const REPORT_INBOUND_TLS: u16 = ObjectType::TlsInboundReport as u16;
 const REPORT_INTERNAL_DMARC: u16 = ObjectType::DmarcInternalReport as u16;
 const REPORT_INTERNAL_TLS: u16 = ObjectType::TlsInternalReport as u16;
 
@@ -561,9 +562,10 @@ impl ValueClass {
                     REG_SPAM_SAMPLE => SUBSPACE_SPAM_SAMPLES,
                     REG_TRACE => SUBSPACE_TELEMETRY_SPAN,
                     REG_METRIC => SUBSPACE_TELEMETRY_METRIC,
-                    REPORT_EXTERNAL_ARF | REPORT_EXTERNAL_DMARC | REPORT_EXTERNAL_TLS => {
-                        SUBSPACE_REPORT_IN
-                    }
+                    REPORT_EXTERNAL_ARF
+                    | REPORT_EXTERNAL_DMARC
+                    | REPORT_EXTERNAL_TLS
+                    | REPORT_INBOUND_TLS => SUBSPACE_REPORT_IN,
                     REPORT_INTERNAL_DMARC | REPORT_INTERNAL_TLS => SUBSPACE_REPORT_OUT,
                     _ => SUBSPACE_REGISTRY,
                 },
@@ -19,7 +19,7 @@ use types::{
     collection::{Collection, SyncCollection, VanishedCollection},
     field::{
         CalendarEventField, CalendarNotificationField, ContactField, EmailField,
-        EmailSubmissionField, Field, MailboxField, PrincipalField, SieveField,
+        EmailSubmissionField, Field, IdentityField, MailboxField, PrincipalField, SieveField,
     },
 };
 use utils::{
@@ -31,6 +31,7 @@ pub mod assert;
 pub mod batch;
 pub mod bitpack;
 pub mod blob;
+pub mod doctor;
 pub mod key;
 pub mod log;
 pub mod serialize;
@@ -528,6 +529,12 @@ impl From<PrincipalField> for ValueClass {
     }
 }
 
+impl From<IdentityField> for ValueClass {
+    fn from(value: IdentityField) -> Self {
+        ValueClass::Property(value.into())
+    }
+}
+
 impl From<SieveField> for ValueClass {
     fn from(value: SieveField) -> Self {
         ValueClass::Property(value.into())
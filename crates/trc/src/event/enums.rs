@@ -6,8 +6,8 @@
 
 // This file is auto-generated. Do not edit directly.
 
-pub const TOTAL_EVENT_COUNT: usize = 605;
-pub const TOTAL_METRIC_COUNT: usize = 339;
+pub const TOTAL_EVENT_COUNT: usize = 671;
+pub const TOTAL_METRIC_COUNT: usize = 342;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum EventType {
@@ -111,6 +111,8 @@ pub enum AuthEvent {
     Error = 34,
     Warning = 595,
     CredentialExpired = 276,
+    ClientCertificateSuccess = 605,
+    ClientCertificateFailed = 606,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -286,6 +288,8 @@ pub enum ImapEvent {
     Append = 159,
     Capabilities = 160,
     Id = 172,
+    ClientRejected = 607,
+    ClientRestricted = 608,
     Close = 161,
     Copy = 164,
     Move = 179,
@@ -311,6 +315,8 @@ pub enum ImapEvent {
     Unsubscribe = 194,
     Thread = 193,
     GetQuota = 57,
+    GetMetadata = 609,
+    SetMetadata = 610,
     Error = 168,
     RawInput = 183,
     RawOutput = 184,
@@ -385,7 +391,9 @@ pub enum LimitEvent {
     Quota = 242,
     BlobQuota = 237,
     TenantQuota = 553,
+    DraftsQuota = 611,
     TooManyRequests = 245,
+    BandwidthThrottled = 612,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -413,6 +421,7 @@ pub enum ManageSieveEvent {
     GetScript = 263,
     DeleteScript = 261,
     RenameScript = 270,
+    RollbackScript = 613,
     CheckScript = 257,
     HaveSpace = 264,
     ListScripts = 265,
@@ -436,7 +445,9 @@ pub enum MessageIngestEvent {
     JmapAppend = 285,
     Duplicate = 281,
     Error = 282,
+    MimeRepaired = 614,
     SearchIndex = 142,
+    SearchIndexTruncated = 615,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -496,6 +507,7 @@ pub enum NetworkEvent {
     SplitError = 326,
     Timeout = 327,
     Closed = 317,
+    SessionTerminated = 616,
     ProxyError = 323,
     SetOptError = 325,
 }
@@ -600,13 +612,16 @@ pub enum ResourceEvent {
 #[repr(u16)]
 pub enum SecurityEvent {
     AuthenticationBan = 33,
+    AuthenticationDelayed = 617,
     AbuseBan = 549,
     ScanBan = 558,
     LoiterBan = 550,
+    SmugglingBan = 618,
     IpBlocked = 318,
     IpBlockExpired = 593,
     IpAllowExpired = 594,
     IpUnauthorized = 279,
+    NetworkPolicyBlocked = 619,
     Unauthorized = 552,
 }
 
@@ -637,6 +652,13 @@ pub enum SieveEvent {
     UnexpectedError = 407,
     NotSupported = 402,
     QuotaExceeded = 403,
+    CpuLimitExceeded = 620,
+    TimeLimitExceeded = 621,
+    RedirectLimitExceeded = 622,
+    RedirectLoop = 623,
+    NotifyLimitExceeded = 624,
+    NotifySent = 625,
+    NotifyFailed = 626,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -653,7 +675,11 @@ pub enum SmtpEvent {
     MissingAuthDirectory = 452,
     MessageParseFailed = 450,
     MessageTooLarge = 451,
+    LargeMessage = 627,
     LoopDetected = 443,
+    StructureLimitExceeded = 628,
+    BareLineEnding = 629,
+    SmugglingAttempt = 630,
     DkimPass = 422,
     DkimFail = 421,
     ArcPass = 410,
@@ -664,6 +690,7 @@ pub enum SmtpEvent {
     SpfFromFail = 475,
     DmarcPass = 424,
     DmarcFail = 423,
+    DmarcOverride = 631,
     IprevPass = 441,
     IprevFail = 440,
     TooManyMessages = 483,
@@ -675,11 +702,17 @@ pub enum SmtpEvent {
     MailFromUnauthenticated = 447,
     MailFromUnauthorized = 448,
     MailFromNotAllowed = 551,
+    TenantDomainMismatch = 632,
+    FromHeaderUnauthorized = 633,
+    FromHeaderRewritten = 634,
+    SenderMismatchTagged = 635,
     MailFromRewritten = 446,
     MailFromMissing = 445,
     MailFrom = 444,
     MultipleMailFrom = 456,
     MailboxDoesNotExist = 449,
+    CalloutRejected = 636,
+    CalloutFailed = 637,
     RelayNotAllowed = 468,
     RcptTo = 464,
     RcptToDuplicate = 465,
@@ -694,9 +727,14 @@ pub enum SmtpEvent {
     Vrfy = 487,
     VrfyNotFound = 489,
     VrfyDisabled = 488,
+    VrfyRateLimitExceeded = 638,
     Expn = 429,
     ExpnNotFound = 431,
     ExpnDisabled = 430,
+    ExpnRateLimitExceeded = 639,
+    MailingListNotMember = 640,
+    MailingListDistributed = 641,
+    MailingListBounce = 642,
     RequireTlsDisabled = 471,
     DeliverByDisabled = 418,
     DeliverByInvalid = 419,
@@ -709,6 +747,7 @@ pub enum SmtpEvent {
     AuthMechanismNotSupported = 412,
     AuthExchangeTooLong = 411,
     AlreadyAuthenticated = 408,
+    ClientCertificateAuthenticated = 643,
     Noop = 457,
     StartTls = 477,
     StartTlsUnavailable = 479,
@@ -742,6 +781,9 @@ pub enum SpamEvent {
     ModelNotReady = 496,
     ModelNotFound = 497,
     RulesUpdated = 280,
+    MessageQuarantined = 644,
+    MessageReleased = 645,
+    QuarantineDigestSent = 646,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -797,8 +839,28 @@ pub enum StoreEvent {
     LdapWarning = 519,
     HttpStoreFetch = 492,
     AutoExpunge = 364,
+    AutoArchive = 647,
     BlobStorePurged = 369,
     DataStorePurged = 368,
+    DataStoreCompacted = 648,
+    DataStoreFlushed = 649,
+    MailStoreImported = 650,
+    AccountExported = 651,
+    AccountImported = 652,
+    AccountPurged = 653,
+    DataRegionMigrated = 654,
+    ColdTierMigrated = 655,
+    ColdTierRestored = 656,
+    PartitionMigrated = 657,
+    BlobMissing = 658,
+    BlobCorrupted = 659,
+    BlobRepaired = 660,
+    BlobStoreScrubbed = 661,
+    DataStoreChecked = 662,
+    BlobMirrorFallback = 663,
+    BlobMirrorFailed = 664,
+    BlobMirrorRepaired = 665,
+    BlobMirrorQueueFull = 666,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -815,6 +877,9 @@ pub enum TaskManagerEvent {
     MetadataNotFound = 145,
     SchedulerStarted = 150,
     ManagerStarted = 367,
+    ReindexProgress = 667,
+    ReindexCompleted = 668,
+    TaskCancelled = 669,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -851,6 +916,7 @@ pub enum TlsRptEvent {
     RecordFetch = 540,
     RecordFetchError = 541,
     RecordNotFound = 560,
+    InboundFailure = 670,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -1102,6 +1168,7 @@ pub enum MetricType {
     SecurityAbuseBan = 230,
     SecurityScanBan = 231,
     SecurityLoiterBan = 232,
+    SecuritySmugglingBan = 339,
     SecurityIpBlocked = 233,
     SecurityUnauthorized = 234,
     ServerMemory = 23,
@@ -1130,6 +1197,8 @@ pub enum MetricType {
     SmtpMessageParseFailed = 253,
     SmtpMessageTooLarge = 254,
     SmtpLoopDetected = 255,
+    SmtpBareLineEnding = 340,
+    SmtpSmugglingAttempt = 341,
     SmtpDkimPass = 256,
     SmtpDkimFail = 257,
     SmtpArcPass = 258,
@@ -51,6 +51,8 @@ impl EventType {
             b"auth.error" => EventType::Auth(AuthEvent::Error),
             b"auth.warning" => EventType::Auth(AuthEvent::Warning),
             b"auth.credential-expired" => EventType::Auth(AuthEvent::CredentialExpired),
+            b"auth.client-certificate-success" => EventType::Auth(AuthEvent::ClientCertificateSuccess),
+            b"auth.client-certificate-failed" => EventType::Auth(AuthEvent::ClientCertificateFailed),
             b"calendar.rule-expansion-error" => EventType::Calendar(CalendarEvent::RuleExpansionError),
             b"calendar.alarm-sent" => EventType::Calendar(CalendarEvent::AlarmSent),
             b"calendar.alarm-skipped" => EventType::Calendar(CalendarEvent::AlarmSkipped),
@@ -176,6 +178,8 @@ impl EventType {
             b"imap.append" => EventType::Imap(ImapEvent::Append),
             b"imap.capabilities" => EventType::Imap(ImapEvent::Capabilities),
             b"imap.id" => EventType::Imap(ImapEvent::Id),
+            b"imap.client-rejected" => EventType::Imap(ImapEvent::ClientRejected),
+            b"imap.client-restricted" => EventType::Imap(ImapEvent::ClientRestricted),
             b"imap.close" => EventType::Imap(ImapEvent::Close),
             b"imap.copy" => EventType::Imap(ImapEvent::Copy),
             b"imap.move" => EventType::Imap(ImapEvent::Move),
@@ -201,6 +205,8 @@ impl EventType {
             b"imap.unsubscribe" => EventType::Imap(ImapEvent::Unsubscribe),
             b"imap.thread" => EventType::Imap(ImapEvent::Thread),
             b"imap.get-quota" => EventType::Imap(ImapEvent::GetQuota),
+            b"imap.get-metadata" => EventType::Imap(ImapEvent::GetMetadata),
+            b"imap.set-metadata" => EventType::Imap(ImapEvent::SetMetadata),
             b"imap.error" => EventType::Imap(ImapEvent::Error),
             b"imap.raw-input" => EventType::Imap(ImapEvent::RawInput),
             b"imap.raw-output" => EventType::Imap(ImapEvent::RawOutput),
@@ -255,7 +261,9 @@ impl EventType {
             b"limit.quota" => EventType::Limit(LimitEvent::Quota),
             b"limit.blob-quota" => EventType::Limit(LimitEvent::BlobQuota),
             b"limit.tenant-quota" => EventType::Limit(LimitEvent::TenantQuota),
+            b"limit.drafts-quota" => EventType::Limit(LimitEvent::DraftsQuota),
             b"limit.too-many-requests" => EventType::Limit(LimitEvent::TooManyRequests),
+            b"limit.bandwidth-throttled" => EventType::Limit(LimitEvent::BandwidthThrottled),
             b"mail-auth.parse-error" => EventType::MailAuth(MailAuthEvent::ParseError),
             b"mail-auth.missing-parameters" => EventType::MailAuth(MailAuthEvent::MissingParameters),
             b"mail-auth.no-headers-found" => EventType::MailAuth(MailAuthEvent::NoHeadersFound),
@@ -273,6 +281,7 @@ impl EventType {
             b"manage-sieve.get-script" => EventType::ManageSieve(ManageSieveEvent::GetScript),
             b"manage-sieve.delete-script" => EventType::ManageSieve(ManageSieveEvent::DeleteScript),
             b"manage-sieve.rename-script" => EventType::ManageSieve(ManageSieveEvent::RenameScript),
+            b"manage-sieve.rollback-script" => EventType::ManageSieve(ManageSieveEvent::RollbackScript),
             b"manage-sieve.check-script" => EventType::ManageSieve(ManageSieveEvent::CheckScript),
             b"manage-sieve.have-space" => EventType::ManageSieve(ManageSieveEvent::HaveSpace),
             b"manage-sieve.list-scripts" => EventType::ManageSieve(ManageSieveEvent::ListScripts),
@@ -291,7 +300,9 @@ impl EventType {
             b"message-ingest.jmap-append" => EventType::MessageIngest(MessageIngestEvent::JmapAppend),
             b"message-ingest.duplicate" => EventType::MessageIngest(MessageIngestEvent::Duplicate),
             b"message-ingest.error" => EventType::MessageIngest(MessageIngestEvent::Error),
+            b"message-ingest.mime-repaired" => EventType::MessageIngest(MessageIngestEvent::MimeRepaired),
             b"message-ingest.search-index" => EventType::MessageIngest(MessageIngestEvent::SearchIndex),
+            b"message-ingest.search-index-truncated" => EventType::MessageIngest(MessageIngestEvent::SearchIndexTruncated),
             b"milter.read" => EventType::Milter(MilterEvent::Read),
             b"milter.write" => EventType::Milter(MilterEvent::Write),
             b"milter.action-accept" => EventType::Milter(MilterEvent::ActionAccept),
@@ -331,6 +342,7 @@ impl EventType {
             b"network.split-error" => EventType::Network(NetworkEvent::SplitError),
             b"network.timeout" => EventType::Network(NetworkEvent::Timeout),
             b"network.closed" => EventType::Network(NetworkEvent::Closed),
+            b"network.session-terminated" => EventType::Network(NetworkEvent::SessionTerminated),
             b"network.proxy-error" => EventType::Network(NetworkEvent::ProxyError),
             b"network.set-opt-error" => EventType::Network(NetworkEvent::SetOptError),
             b"outgoing-report.spf-report" => EventType::OutgoingReport(OutgoingReportEvent::SpfReport),
@@ -400,13 +412,16 @@ impl EventType {
             b"resource.application-updated" => EventType::Resource(ResourceEvent::ApplicationUpdated),
             b"resource.application-unpacked" => EventType::Resource(ResourceEvent::ApplicationUnpacked),
             b"security.authentication-ban" => EventType::Security(SecurityEvent::AuthenticationBan),
+            b"security.authentication-delayed" => EventType::Security(SecurityEvent::AuthenticationDelayed),
             b"security.abuse-ban" => EventType::Security(SecurityEvent::AbuseBan),
             b"security.scan-ban" => EventType::Security(SecurityEvent::ScanBan),
             b"security.loiter-ban" => EventType::Security(SecurityEvent::LoiterBan),
+            b"security.smuggling-ban" => EventType::Security(SecurityEvent::SmugglingBan),
             b"security.ip-blocked" => EventType::Security(SecurityEvent::IpBlocked),
             b"security.ip-block-expired" => EventType::Security(SecurityEvent::IpBlockExpired),
             b"security.ip-allow-expired" => EventType::Security(SecurityEvent::IpAllowExpired),
             b"security.ip-unauthorized" => EventType::Security(SecurityEvent::IpUnauthorized),
+            b"security.network-policy-blocked" => EventType::Security(SecurityEvent::NetworkPolicyBlocked),
             b"security.unauthorized" => EventType::Security(SecurityEvent::Unauthorized),
             b"server.startup" => EventType::Server(ServerEvent::Startup),
             b"server.shutdown" => EventType::Server(ServerEvent::Shutdown),
@@ -427,6 +442,13 @@ impl EventType {
             b"sieve.unexpected-error" => EventType::Sieve(SieveEvent::UnexpectedError),
             b"sieve.not-supported" => EventType::Sieve(SieveEvent::NotSupported),
             b"sieve.quota-exceeded" => EventType::Sieve(SieveEvent::QuotaExceeded),
+            b"sieve.cpu-limit-exceeded" => EventType::Sieve(SieveEvent::CpuLimitExceeded),
+            b"sieve.time-limit-exceeded" => EventType::Sieve(SieveEvent::TimeLimitExceeded),
+            b"sieve.redirect-limit-exceeded" => EventType::Sieve(SieveEvent::RedirectLimitExceeded),
+            b"sieve.redirect-loop" => EventType::Sieve(SieveEvent::RedirectLoop),
+            b"sieve.notify-limit-exceeded" => EventType::Sieve(SieveEvent::NotifyLimitExceeded),
+            b"sieve.notify-sent" => EventType::Sieve(SieveEvent::NotifySent),
+            b"sieve.notify-failed" => EventType::Sieve(SieveEvent::NotifyFailed),
             b"smtp.connection-start" => EventType::Smtp(SmtpEvent::ConnectionStart),
             b"smtp.connection-end" => EventType::Smtp(SmtpEvent::ConnectionEnd),
             b"smtp.error" => EventType::Smtp(SmtpEvent::Error),
@@ -438,7 +460,11 @@ impl EventType {
             b"smtp.missing-auth-directory" => EventType::Smtp(SmtpEvent::MissingAuthDirectory),
             b"smtp.message-parse-failed" => EventType::Smtp(SmtpEvent::MessageParseFailed),
             b"smtp.message-too-large" => EventType::Smtp(SmtpEvent::MessageTooLarge),
+            b"smtp.large-message" => EventType::Smtp(SmtpEvent::LargeMessage),
             b"smtp.loop-detected" => EventType::Smtp(SmtpEvent::LoopDetected),
+            b"smtp.structure-limit-exceeded" => EventType::Smtp(SmtpEvent::StructureLimitExceeded),
+            b"smtp.bare-line-ending" => EventType::Smtp(SmtpEvent::BareLineEnding),
+            b"smtp.smuggling-attempt" => EventType::Smtp(SmtpEvent::SmugglingAttempt),
             b"smtp.dkim-pass" => EventType::Smtp(SmtpEvent::DkimPass),
             b"smtp.dkim-fail" => EventType::Smtp(SmtpEvent::DkimFail),
             b"smtp.arc-pass" => EventType::Smtp(SmtpEvent::ArcPass),
@@ -449,6 +475,7 @@ impl EventType {
             b"smtp.spf-from-fail" => EventType::Smtp(SmtpEvent::SpfFromFail),
             b"smtp.dmarc-pass" => EventType::Smtp(SmtpEvent::DmarcPass),
             b"smtp.dmarc-fail" => EventType::Smtp(SmtpEvent::DmarcFail),
+            b"smtp.dmarc-override" => EventType::Smtp(SmtpEvent::DmarcOverride),
             b"smtp.iprev-pass" => EventType::Smtp(SmtpEvent::IprevPass),
             b"smtp.iprev-fail" => EventType::Smtp(SmtpEvent::IprevFail),
             b"smtp.too-many-messages" => EventType::Smtp(SmtpEvent::TooManyMessages),
@@ -460,11 +487,17 @@ impl EventType {
             b"smtp.mail-from-unauthenticated" => EventType::Smtp(SmtpEvent::MailFromUnauthenticated),
             b"smtp.mail-from-unauthorized" => EventType::Smtp(SmtpEvent::MailFromUnauthorized),
             b"smtp.mail-from-not-allowed" => EventType::Smtp(SmtpEvent::MailFromNotAllowed),
+            b"smtp.tenant-domain-mismatch" => EventType::Smtp(SmtpEvent::TenantDomainMismatch),
+            b"smtp.from-header-unauthorized" => EventType::Smtp(SmtpEvent::FromHeaderUnauthorized),
+            b"smtp.from-header-rewritten" => EventType::Smtp(SmtpEvent::FromHeaderRewritten),
+            b"smtp.sender-mismatch-tagged" => EventType::Smtp(SmtpEvent::SenderMismatchTagged),
             b"smtp.mail-from-rewritten" => EventType::Smtp(SmtpEvent::MailFromRewritten),
             b"smtp.mail-from-missing" => EventType::Smtp(SmtpEvent::MailFromMissing),
             b"smtp.mail-from" => EventType::Smtp(SmtpEvent::MailFrom),
             b"smtp.multiple-mail-from" => EventType::Smtp(SmtpEvent::MultipleMailFrom),
             b"smtp.mailbox-does-not-exist" => EventType::Smtp(SmtpEvent::MailboxDoesNotExist),
+            b"smtp.callout-rejected" => EventType::Smtp(SmtpEvent::CalloutRejected),
+            b"smtp.callout-failed" => EventType::Smtp(SmtpEvent::CalloutFailed),
             b"smtp.relay-not-allowed" => EventType::Smtp(SmtpEvent::RelayNotAllowed),
             b"smtp.rcpt-to" => EventType::Smtp(SmtpEvent::RcptTo),
             b"smtp.rcpt-to-duplicate" => EventType::Smtp(SmtpEvent::RcptToDuplicate),
@@ -479,9 +512,14 @@ impl EventType {
             b"smtp.vrfy" => EventType::Smtp(SmtpEvent::Vrfy),
             b"smtp.vrfy-not-found" => EventType::Smtp(SmtpEvent::VrfyNotFound),
             b"smtp.vrfy-disabled" => EventType::Smtp(SmtpEvent::VrfyDisabled),
+            b"smtp.vrfy-rate-limit-exceeded" => EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded),
             b"smtp.expn" => EventType::Smtp(SmtpEvent::Expn),
             b"smtp.expn-not-found" => EventType::Smtp(SmtpEvent::ExpnNotFound),
             b"smtp.expn-disabled" => EventType::Smtp(SmtpEvent::ExpnDisabled),
+            b"smtp.expn-rate-limit-exceeded" => EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded),
+            b"smtp.mailing-list-not-member" => EventType::Smtp(SmtpEvent::MailingListNotMember),
+            b"smtp.mailing-list-distributed" => EventType::Smtp(SmtpEvent::MailingListDistributed),
+            b"smtp.mailing-list-bounce" => EventType::Smtp(SmtpEvent::MailingListBounce),
             b"smtp.require-tls-disabled" => EventType::Smtp(SmtpEvent::RequireTlsDisabled),
             b"smtp.deliver-by-disabled" => EventType::Smtp(SmtpEvent::DeliverByDisabled),
             b"smtp.deliver-by-invalid" => EventType::Smtp(SmtpEvent::DeliverByInvalid),
@@ -494,6 +532,7 @@ impl EventType {
             b"smtp.auth-mechanism-not-supported" => EventType::Smtp(SmtpEvent::AuthMechanismNotSupported),
             b"smtp.auth-exchange-too-long" => EventType::Smtp(SmtpEvent::AuthExchangeTooLong),
             b"smtp.already-authenticated" => EventType::Smtp(SmtpEvent::AlreadyAuthenticated),
+            b"smtp.client-certificate-authenticated" => EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated),
             b"smtp.noop" => EventType::Smtp(SmtpEvent::Noop),
             b"smtp.start-tls" => EventType::Smtp(SmtpEvent::StartTls),
             b"smtp.start-tls-unavailable" => EventType::Smtp(SmtpEvent::StartTlsUnavailable),
@@ -522,6 +561,9 @@ impl EventType {
             b"spam.model-not-ready" => EventType::Spam(SpamEvent::ModelNotReady),
             b"spam.model-not-found" => EventType::Spam(SpamEvent::ModelNotFound),
             b"spam.rules-updated" => EventType::Spam(SpamEvent::RulesUpdated),
+            b"spam.message-quarantined" => EventType::Spam(SpamEvent::MessageQuarantined),
+            b"spam.message-released" => EventType::Spam(SpamEvent::MessageReleased),
+            b"spam.quarantine-digest-sent" => EventType::Spam(SpamEvent::QuarantineDigestSent),
             b"spf.pass" => EventType::Spf(SpfEvent::Pass),
             b"spf.fail" => EventType::Spf(SpfEvent::Fail),
             b"spf.soft-fail" => EventType::Spf(SpfEvent::SoftFail),
@@ -567,8 +609,28 @@ impl EventType {
             b"store.ldap-warning" => EventType::Store(StoreEvent::LdapWarning),
             b"store.http-store-fetch" => EventType::Store(StoreEvent::HttpStoreFetch),
             b"store.auto-expunge" => EventType::Store(StoreEvent::AutoExpunge),
+            b"store.auto-archive" => EventType::Store(StoreEvent::AutoArchive),
             b"store.blob-store-purged" => EventType::Store(StoreEvent::BlobStorePurged),
             b"store.data-store-purged" => EventType::Store(StoreEvent::DataStorePurged),
+            b"store.data-store-compacted" => EventType::Store(StoreEvent::DataStoreCompacted),
+            b"store.data-store-flushed" => EventType::Store(StoreEvent::DataStoreFlushed),
+            b"store.mail-store-imported" => EventType::Store(StoreEvent::MailStoreImported),
+            b"store.account-exported" => EventType::Store(StoreEvent::AccountExported),
+            b"store.account-imported" => EventType::Store(StoreEvent::AccountImported),
+            b"store.account-purged" => EventType::Store(StoreEvent::AccountPurged),
+            b"store.data-region-migrated" => EventType::Store(StoreEvent::DataRegionMigrated),
+            b"store.cold-tier-migrated" => EventType::Store(StoreEvent::ColdTierMigrated),
+            b"store.cold-tier-restored" => EventType::Store(StoreEvent::ColdTierRestored),
+            b"store.partition-migrated" => EventType::Store(StoreEvent::PartitionMigrated),
+            b"store.blob-missing" => EventType::Store(StoreEvent::BlobMissing),
+            b"store.blob-corrupted" => EventType::Store(StoreEvent::BlobCorrupted),
+            b"store.blob-repaired" => EventType::Store(StoreEvent::BlobRepaired),
+            b"store.blob-store-scrubbed" => EventType::Store(StoreEvent::BlobStoreScrubbed),
+            b"store.data-store-checked" => EventType::Store(StoreEvent::DataStoreChecked),
+            b"store.blob-mirror-fallback" => EventType::Store(StoreEvent::BlobMirrorFallback),
+            b"store.blob-mirror-failed" => EventType::Store(StoreEvent::BlobMirrorFailed),
+            b"store.blob-mirror-repaired" => EventType::Store(StoreEvent::BlobMirrorRepaired),
+            b"store.blob-mirror-queue-full" => EventType::Store(StoreEvent::BlobMirrorQueueFull),
             b"task-manager.task-acquired" => EventType::TaskManager(TaskManagerEvent::TaskAcquired),
             b"task-manager.task-queued" => EventType::TaskManager(TaskManagerEvent::TaskQueued),
             b"task-manager.task-scheduled" => EventType::TaskManager(TaskManagerEvent::TaskScheduled),
@@ -580,6 +642,9 @@ impl EventType {
             b"task-manager.metadata-not-found" => EventType::TaskManager(TaskManagerEvent::MetadataNotFound),
             b"task-manager.scheduler-started" => EventType::TaskManager(TaskManagerEvent::SchedulerStarted),
             b"task-manager.manager-started" => EventType::TaskManager(TaskManagerEvent::ManagerStarted),
+            b"task-manager.reindex-progress" => EventType::TaskManager(TaskManagerEvent::ReindexProgress),
+            b"task-manager.reindex-completed" => EventType::TaskManager(TaskManagerEvent::ReindexCompleted),
+            b"task-manager.task-cancelled" => EventType::TaskManager(TaskManagerEvent::TaskCancelled),
             b"telemetry.alert-event" => EventType::Telemetry(TelemetryEvent::AlertEvent),
             b"telemetry.alert-message" => EventType::Telemetry(TelemetryEvent::AlertMessage),
             b"telemetry.log-error" => EventType::Telemetry(TelemetryEvent::LogError),
@@ -601,6 +666,7 @@ impl EventType {
             b"tls-rpt.record-fetch" => EventType::TlsRpt(TlsRptEvent::RecordFetch),
             b"tls-rpt.record-fetch-error" => EventType::TlsRpt(TlsRptEvent::RecordFetchError),
             b"tls-rpt.record-not-found" => EventType::TlsRpt(TlsRptEvent::RecordNotFound),
+            b"tls-rpt.inbound-failure" => EventType::TlsRpt(TlsRptEvent::InboundFailure),
             b"web-dav.propfind" => EventType::WebDav(WebDavEvent::Propfind),
             b"web-dav.proppatch" => EventType::WebDav(WebDavEvent::Proppatch),
             b"web-dav.get" => EventType::WebDav(WebDavEvent::Get),
@@ -662,6 +728,10 @@ impl EventType {
             EventType::Auth(AuthEvent::Error) => "auth.error",
             EventType::Auth(AuthEvent::Warning) => "auth.warning",
             EventType::Auth(AuthEvent::CredentialExpired) => "auth.credential-expired",
+            EventType::Auth(AuthEvent::ClientCertificateSuccess) => {
+                "auth.client-certificate-success"
+            }
+            EventType::Auth(AuthEvent::ClientCertificateFailed) => "auth.client-certificate-failed",
             EventType::Calendar(CalendarEvent::RuleExpansionError) => {
                 "calendar.rule-expansion-error"
             }
@@ -807,6 +877,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Append) => "imap.append",
             EventType::Imap(ImapEvent::Capabilities) => "imap.capabilities",
             EventType::Imap(ImapEvent::Id) => "imap.id",
+            EventType::Imap(ImapEvent::ClientRejected) => "imap.client-rejected",
+            EventType::Imap(ImapEvent::ClientRestricted) => "imap.client-restricted",
             EventType::Imap(ImapEvent::Close) => "imap.close",
             EventType::Imap(ImapEvent::Copy) => "imap.copy",
             EventType::Imap(ImapEvent::Move) => "imap.move",
@@ -832,6 +904,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => "imap.unsubscribe",
             EventType::Imap(ImapEvent::Thread) => "imap.thread",
             EventType::Imap(ImapEvent::GetQuota) => "imap.get-quota",
+            EventType::Imap(ImapEvent::GetMetadata) => "imap.get-metadata",
+            EventType::Imap(ImapEvent::SetMetadata) => "imap.set-metadata",
             EventType::Imap(ImapEvent::Error) => "imap.error",
             EventType::Imap(ImapEvent::RawInput) => "imap.raw-input",
             EventType::Imap(ImapEvent::RawOutput) => "imap.raw-output",
@@ -918,7 +992,9 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => "limit.quota",
             EventType::Limit(LimitEvent::BlobQuota) => "limit.blob-quota",
             EventType::Limit(LimitEvent::TenantQuota) => "limit.tenant-quota",
+            EventType::Limit(LimitEvent::DraftsQuota) => "limit.drafts-quota",
             EventType::Limit(LimitEvent::TooManyRequests) => "limit.too-many-requests",
+            EventType::Limit(LimitEvent::BandwidthThrottled) => "limit.bandwidth-throttled",
             EventType::MailAuth(MailAuthEvent::ParseError) => "mail-auth.parse-error",
             EventType::MailAuth(MailAuthEvent::MissingParameters) => "mail-auth.missing-parameters",
             EventType::MailAuth(MailAuthEvent::NoHeadersFound) => "mail-auth.no-headers-found",
@@ -944,6 +1020,9 @@ impl EventType {
             EventType::ManageSieve(ManageSieveEvent::GetScript) => "manage-sieve.get-script",
             EventType::ManageSieve(ManageSieveEvent::DeleteScript) => "manage-sieve.delete-script",
             EventType::ManageSieve(ManageSieveEvent::RenameScript) => "manage-sieve.rename-script",
+            EventType::ManageSieve(ManageSieveEvent::RollbackScript) => {
+                "manage-sieve.rollback-script"
+            }
             EventType::ManageSieve(ManageSieveEvent::CheckScript) => "manage-sieve.check-script",
             EventType::ManageSieve(ManageSieveEvent::HaveSpace) => "manage-sieve.have-space",
             EventType::ManageSieve(ManageSieveEvent::ListScripts) => "manage-sieve.list-scripts",
@@ -968,9 +1047,15 @@ impl EventType {
             }
             EventType::MessageIngest(MessageIngestEvent::Duplicate) => "message-ingest.duplicate",
             EventType::MessageIngest(MessageIngestEvent::Error) => "message-ingest.error",
+            EventType::MessageIngest(MessageIngestEvent::MimeRepaired) => {
+                "message-ingest.mime-repaired"
+            }
             EventType::MessageIngest(MessageIngestEvent::SearchIndex) => {
                 "message-ingest.search-index"
             }
+            EventType::MessageIngest(MessageIngestEvent::SearchIndexTruncated) => {
+                "message-ingest.search-index-truncated"
+            }
             EventType::Milter(MilterEvent::Read) => "milter.read",
             EventType::Milter(MilterEvent::Write) => "milter.write",
             EventType::Milter(MilterEvent::ActionAccept) => "milter.action-accept",
@@ -1012,6 +1097,7 @@ impl EventType {
             EventType::Network(NetworkEvent::SplitError) => "network.split-error",
             EventType::Network(NetworkEvent::Timeout) => "network.timeout",
             EventType::Network(NetworkEvent::Closed) => "network.closed",
+            EventType::Network(NetworkEvent::SessionTerminated) => "network.session-terminated",
             EventType::Network(NetworkEvent::ProxyError) => "network.proxy-error",
             EventType::Network(NetworkEvent::SetOptError) => "network.set-opt-error",
             EventType::OutgoingReport(OutgoingReportEvent::SpfReport) => {
@@ -1123,13 +1209,20 @@ impl EventType {
                 "resource.application-unpacked"
             }
             EventType::Security(SecurityEvent::AuthenticationBan) => "security.authentication-ban",
+            EventType::Security(SecurityEvent::AuthenticationDelayed) => {
+                "security.authentication-delayed"
+            }
             EventType::Security(SecurityEvent::AbuseBan) => "security.abuse-ban",
             EventType::Security(SecurityEvent::ScanBan) => "security.scan-ban",
             EventType::Security(SecurityEvent::LoiterBan) => "security.loiter-ban",
+            EventType::Security(SecurityEvent::SmugglingBan) => "security.smuggling-ban",
             EventType::Security(SecurityEvent::IpBlocked) => "security.ip-blocked",
             EventType::Security(SecurityEvent::IpBlockExpired) => "security.ip-block-expired",
             EventType::Security(SecurityEvent::IpAllowExpired) => "security.ip-allow-expired",
             EventType::Security(SecurityEvent::IpUnauthorized) => "security.ip-unauthorized",
+            EventType::Security(SecurityEvent::NetworkPolicyBlocked) => {
+                "security.network-policy-blocked"
+            }
             EventType::Security(SecurityEvent::Unauthorized) => "security.unauthorized",
             EventType::Server(ServerEvent::Startup) => "server.startup",
             EventType::Server(ServerEvent::Shutdown) => "server.shutdown",
@@ -1150,6 +1243,13 @@ impl EventType {
             EventType::Sieve(SieveEvent::UnexpectedError) => "sieve.unexpected-error",
             EventType::Sieve(SieveEvent::NotSupported) => "sieve.not-supported",
             EventType::Sieve(SieveEvent::QuotaExceeded) => "sieve.quota-exceeded",
+            EventType::Sieve(SieveEvent::CpuLimitExceeded) => "sieve.cpu-limit-exceeded",
+            EventType::Sieve(SieveEvent::TimeLimitExceeded) => "sieve.time-limit-exceeded",
+            EventType::Sieve(SieveEvent::RedirectLimitExceeded) => "sieve.redirect-limit-exceeded",
+            EventType::Sieve(SieveEvent::RedirectLoop) => "sieve.redirect-loop",
+            EventType::Sieve(SieveEvent::NotifyLimitExceeded) => "sieve.notify-limit-exceeded",
+            EventType::Sieve(SieveEvent::NotifySent) => "sieve.notify-sent",
+            EventType::Sieve(SieveEvent::NotifyFailed) => "sieve.notify-failed",
             EventType::Smtp(SmtpEvent::ConnectionStart) => "smtp.connection-start",
             EventType::Smtp(SmtpEvent::ConnectionEnd) => "smtp.connection-end",
             EventType::Smtp(SmtpEvent::Error) => "smtp.error",
@@ -1163,7 +1263,11 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MissingAuthDirectory) => "smtp.missing-auth-directory",
             EventType::Smtp(SmtpEvent::MessageParseFailed) => "smtp.message-parse-failed",
             EventType::Smtp(SmtpEvent::MessageTooLarge) => "smtp.message-too-large",
+            EventType::Smtp(SmtpEvent::LargeMessage) => "smtp.large-message",
             EventType::Smtp(SmtpEvent::LoopDetected) => "smtp.loop-detected",
+            EventType::Smtp(SmtpEvent::StructureLimitExceeded) => "smtp.structure-limit-exceeded",
+            EventType::Smtp(SmtpEvent::BareLineEnding) => "smtp.bare-line-ending",
+            EventType::Smtp(SmtpEvent::SmugglingAttempt) => "smtp.smuggling-attempt",
             EventType::Smtp(SmtpEvent::DkimPass) => "smtp.dkim-pass",
             EventType::Smtp(SmtpEvent::DkimFail) => "smtp.dkim-fail",
             EventType::Smtp(SmtpEvent::ArcPass) => "smtp.arc-pass",
@@ -1174,6 +1278,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => "smtp.spf-from-fail",
             EventType::Smtp(SmtpEvent::DmarcPass) => "smtp.dmarc-pass",
             EventType::Smtp(SmtpEvent::DmarcFail) => "smtp.dmarc-fail",
+            EventType::Smtp(SmtpEvent::DmarcOverride) => "smtp.dmarc-override",
             EventType::Smtp(SmtpEvent::IprevPass) => "smtp.iprev-pass",
             EventType::Smtp(SmtpEvent::IprevFail) => "smtp.iprev-fail",
             EventType::Smtp(SmtpEvent::TooManyMessages) => "smtp.too-many-messages",
@@ -1185,11 +1290,17 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MailFromUnauthenticated) => "smtp.mail-from-unauthenticated",
             EventType::Smtp(SmtpEvent::MailFromUnauthorized) => "smtp.mail-from-unauthorized",
             EventType::Smtp(SmtpEvent::MailFromNotAllowed) => "smtp.mail-from-not-allowed",
+            EventType::Smtp(SmtpEvent::TenantDomainMismatch) => "smtp.tenant-domain-mismatch",
+            EventType::Smtp(SmtpEvent::FromHeaderUnauthorized) => "smtp.from-header-unauthorized",
+            EventType::Smtp(SmtpEvent::FromHeaderRewritten) => "smtp.from-header-rewritten",
+            EventType::Smtp(SmtpEvent::SenderMismatchTagged) => "smtp.sender-mismatch-tagged",
             EventType::Smtp(SmtpEvent::MailFromRewritten) => "smtp.mail-from-rewritten",
             EventType::Smtp(SmtpEvent::MailFromMissing) => "smtp.mail-from-missing",
             EventType::Smtp(SmtpEvent::MailFrom) => "smtp.mail-from",
             EventType::Smtp(SmtpEvent::MultipleMailFrom) => "smtp.multiple-mail-from",
             EventType::Smtp(SmtpEvent::MailboxDoesNotExist) => "smtp.mailbox-does-not-exist",
+            EventType::Smtp(SmtpEvent::CalloutRejected) => "smtp.callout-rejected",
+            EventType::Smtp(SmtpEvent::CalloutFailed) => "smtp.callout-failed",
             EventType::Smtp(SmtpEvent::RelayNotAllowed) => "smtp.relay-not-allowed",
             EventType::Smtp(SmtpEvent::RcptTo) => "smtp.rcpt-to",
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => "smtp.rcpt-to-duplicate",
@@ -1204,9 +1315,14 @@ impl EventType {
             EventType::Smtp(SmtpEvent::Vrfy) => "smtp.vrfy",
             EventType::Smtp(SmtpEvent::VrfyNotFound) => "smtp.vrfy-not-found",
             EventType::Smtp(SmtpEvent::VrfyDisabled) => "smtp.vrfy-disabled",
+            EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded) => "smtp.vrfy-rate-limit-exceeded",
             EventType::Smtp(SmtpEvent::Expn) => "smtp.expn",
             EventType::Smtp(SmtpEvent::ExpnNotFound) => "smtp.expn-not-found",
             EventType::Smtp(SmtpEvent::ExpnDisabled) => "smtp.expn-disabled",
+            EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded) => "smtp.expn-rate-limit-exceeded",
+            EventType::Smtp(SmtpEvent::MailingListNotMember) => "smtp.mailing-list-not-member",
+            EventType::Smtp(SmtpEvent::MailingListDistributed) => "smtp.mailing-list-distributed",
+            EventType::Smtp(SmtpEvent::MailingListBounce) => "smtp.mailing-list-bounce",
             EventType::Smtp(SmtpEvent::RequireTlsDisabled) => "smtp.require-tls-disabled",
             EventType::Smtp(SmtpEvent::DeliverByDisabled) => "smtp.deliver-by-disabled",
             EventType::Smtp(SmtpEvent::DeliverByInvalid) => "smtp.deliver-by-invalid",
@@ -1221,6 +1337,9 @@ impl EventType {
             }
             EventType::Smtp(SmtpEvent::AuthExchangeTooLong) => "smtp.auth-exchange-too-long",
             EventType::Smtp(SmtpEvent::AlreadyAuthenticated) => "smtp.already-authenticated",
+            EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated) => {
+                "smtp.client-certificate-authenticated"
+            }
             EventType::Smtp(SmtpEvent::Noop) => "smtp.noop",
             EventType::Smtp(SmtpEvent::StartTls) => "smtp.start-tls",
             EventType::Smtp(SmtpEvent::StartTlsUnavailable) => "smtp.start-tls-unavailable",
@@ -1249,6 +1368,9 @@ impl EventType {
             EventType::Spam(SpamEvent::ModelNotReady) => "spam.model-not-ready",
             EventType::Spam(SpamEvent::ModelNotFound) => "spam.model-not-found",
             EventType::Spam(SpamEvent::RulesUpdated) => "spam.rules-updated",
+            EventType::Spam(SpamEvent::MessageQuarantined) => "spam.message-quarantined",
+            EventType::Spam(SpamEvent::MessageReleased) => "spam.message-released",
+            EventType::Spam(SpamEvent::QuarantineDigestSent) => "spam.quarantine-digest-sent",
             EventType::Spf(SpfEvent::Pass) => "spf.pass",
             EventType::Spf(SpfEvent::Fail) => "spf.fail",
             EventType::Spf(SpfEvent::SoftFail) => "spf.soft-fail",
@@ -1294,8 +1416,28 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning) => "store.ldap-warning",
             EventType::Store(StoreEvent::HttpStoreFetch) => "store.http-store-fetch",
             EventType::Store(StoreEvent::AutoExpunge) => "store.auto-expunge",
+            EventType::Store(StoreEvent::AutoArchive) => "store.auto-archive",
             EventType::Store(StoreEvent::BlobStorePurged) => "store.blob-store-purged",
             EventType::Store(StoreEvent::DataStorePurged) => "store.data-store-purged",
+            EventType::Store(StoreEvent::DataStoreCompacted) => "store.data-store-compacted",
+            EventType::Store(StoreEvent::DataStoreFlushed) => "store.data-store-flushed",
+            EventType::Store(StoreEvent::MailStoreImported) => "store.mail-store-imported",
+            EventType::Store(StoreEvent::AccountExported) => "store.account-exported",
+            EventType::Store(StoreEvent::AccountImported) => "store.account-imported",
+            EventType::Store(StoreEvent::AccountPurged) => "store.account-purged",
+            EventType::Store(StoreEvent::DataRegionMigrated) => "store.data-region-migrated",
+            EventType::Store(StoreEvent::ColdTierMigrated) => "store.cold-tier-migrated",
+            EventType::Store(StoreEvent::ColdTierRestored) => "store.cold-tier-restored",
+            EventType::Store(StoreEvent::PartitionMigrated) => "store.partition-migrated",
+            EventType::Store(StoreEvent::BlobMissing) => "store.blob-missing",
+            EventType::Store(StoreEvent::BlobCorrupted) => "store.blob-corrupted",
+            EventType::Store(StoreEvent::BlobRepaired) => "store.blob-repaired",
+            EventType::Store(StoreEvent::BlobStoreScrubbed) => "store.blob-store-scrubbed",
+            EventType::Store(StoreEvent::DataStoreChecked) => "store.data-store-checked",
+            EventType::Store(StoreEvent::BlobMirrorFallback) => "store.blob-mirror-fallback",
+            EventType::Store(StoreEvent::BlobMirrorFailed) => "store.blob-mirror-failed",
+            EventType::Store(StoreEvent::BlobMirrorRepaired) => "store.blob-mirror-repaired",
+            EventType::Store(StoreEvent::BlobMirrorQueueFull) => "store.blob-mirror-queue-full",
             EventType::TaskManager(TaskManagerEvent::TaskAcquired) => "task-manager.task-acquired",
             EventType::TaskManager(TaskManagerEvent::TaskQueued) => "task-manager.task-queued",
             EventType::TaskManager(TaskManagerEvent::TaskScheduled) => {
@@ -1315,6 +1457,15 @@ impl EventType {
             EventType::TaskManager(TaskManagerEvent::ManagerStarted) => {
                 "task-manager.manager-started"
             }
+            EventType::TaskManager(TaskManagerEvent::ReindexProgress) => {
+                "task-manager.reindex-progress"
+            }
+            EventType::TaskManager(TaskManagerEvent::ReindexCompleted) => {
+                "task-manager.reindex-completed"
+            }
+            EventType::TaskManager(TaskManagerEvent::TaskCancelled) => {
+                "task-manager.task-cancelled"
+            }
             EventType::Telemetry(TelemetryEvent::AlertEvent) => "telemetry.alert-event",
             EventType::Telemetry(TelemetryEvent::AlertMessage) => "telemetry.alert-message",
             EventType::Telemetry(TelemetryEvent::LogError) => "telemetry.log-error",
@@ -1346,6 +1497,7 @@ impl EventType {
             EventType::TlsRpt(TlsRptEvent::RecordFetch) => "tls-rpt.record-fetch",
             EventType::TlsRpt(TlsRptEvent::RecordFetchError) => "tls-rpt.record-fetch-error",
             EventType::TlsRpt(TlsRptEvent::RecordNotFound) => "tls-rpt.record-not-found",
+            EventType::TlsRpt(TlsRptEvent::InboundFailure) => "tls-rpt.inbound-failure",
             EventType::WebDav(WebDavEvent::Propfind) => "web-dav.propfind",
             EventType::WebDav(WebDavEvent::Proppatch) => "web-dav.proppatch",
             EventType::WebDav(WebDavEvent::Get) => "web-dav.get",
@@ -1406,6 +1558,8 @@ impl EventType {
             EventType::Auth(AuthEvent::Error) => 34,
             EventType::Auth(AuthEvent::Warning) => 595,
             EventType::Auth(AuthEvent::CredentialExpired) => 276,
+            EventType::Auth(AuthEvent::ClientCertificateSuccess) => 605,
+            EventType::Auth(AuthEvent::ClientCertificateFailed) => 606,
             EventType::Calendar(CalendarEvent::RuleExpansionError) => 576,
             EventType::Calendar(CalendarEvent::AlarmSent) => 579,
             EventType::Calendar(CalendarEvent::AlarmSkipped) => 580,
@@ -1531,6 +1685,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Append) => 159,
             EventType::Imap(ImapEvent::Capabilities) => 160,
             EventType::Imap(ImapEvent::Id) => 172,
+            EventType::Imap(ImapEvent::ClientRejected) => 607,
+            EventType::Imap(ImapEvent::ClientRestricted) => 608,
             EventType::Imap(ImapEvent::Close) => 161,
             EventType::Imap(ImapEvent::Copy) => 164,
             EventType::Imap(ImapEvent::Move) => 179,
@@ -1556,6 +1712,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => 194,
             EventType::Imap(ImapEvent::Thread) => 193,
             EventType::Imap(ImapEvent::GetQuota) => 57,
+            EventType::Imap(ImapEvent::GetMetadata) => 609,
+            EventType::Imap(ImapEvent::SetMetadata) => 610,
             EventType::Imap(ImapEvent::Error) => 168,
             EventType::Imap(ImapEvent::RawInput) => 183,
             EventType::Imap(ImapEvent::RawOutput) => 184,
@@ -1610,7 +1768,9 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => 242,
             EventType::Limit(LimitEvent::BlobQuota) => 237,
             EventType::Limit(LimitEvent::TenantQuota) => 553,
+            EventType::Limit(LimitEvent::DraftsQuota) => 611,
             EventType::Limit(LimitEvent::TooManyRequests) => 245,
+            EventType::Limit(LimitEvent::BandwidthThrottled) => 612,
             EventType::MailAuth(MailAuthEvent::ParseError) => 254,
             EventType::MailAuth(MailAuthEvent::MissingParameters) => 252,
             EventType::MailAuth(MailAuthEvent::NoHeadersFound) => 253,
@@ -1628,6 +1788,7 @@ impl EventType {
             EventType::ManageSieve(ManageSieveEvent::GetScript) => 263,
             EventType::ManageSieve(ManageSieveEvent::DeleteScript) => 261,
             EventType::ManageSieve(ManageSieveEvent::RenameScript) => 270,
+            EventType::ManageSieve(ManageSieveEvent::RollbackScript) => 613,
             EventType::ManageSieve(ManageSieveEvent::CheckScript) => 257,
             EventType::ManageSieve(ManageSieveEvent::HaveSpace) => 264,
             EventType::ManageSieve(ManageSieveEvent::ListScripts) => 265,
@@ -1646,7 +1807,9 @@ impl EventType {
             EventType::MessageIngest(MessageIngestEvent::JmapAppend) => 285,
             EventType::MessageIngest(MessageIngestEvent::Duplicate) => 281,
             EventType::MessageIngest(MessageIngestEvent::Error) => 282,
+            EventType::MessageIngest(MessageIngestEvent::MimeRepaired) => 614,
             EventType::MessageIngest(MessageIngestEvent::SearchIndex) => 142,
+            EventType::MessageIngest(MessageIngestEvent::SearchIndexTruncated) => 615,
             EventType::Milter(MilterEvent::Read) => 299,
             EventType::Milter(MilterEvent::Write) => 303,
             EventType::Milter(MilterEvent::ActionAccept) => 287,
@@ -1686,6 +1849,7 @@ impl EventType {
             EventType::Network(NetworkEvent::SplitError) => 326,
             EventType::Network(NetworkEvent::Timeout) => 327,
             EventType::Network(NetworkEvent::Closed) => 317,
+            EventType::Network(NetworkEvent::SessionTerminated) => 616,
             EventType::Network(NetworkEvent::ProxyError) => 323,
             EventType::Network(NetworkEvent::SetOptError) => 325,
             EventType::OutgoingReport(OutgoingReportEvent::SpfReport) => 342,
@@ -1755,13 +1919,16 @@ impl EventType {
             EventType::Resource(ResourceEvent::ApplicationUpdated) => 601,
             EventType::Resource(ResourceEvent::ApplicationUnpacked) => 602,
             EventType::Security(SecurityEvent::AuthenticationBan) => 33,
+            EventType::Security(SecurityEvent::AuthenticationDelayed) => 617,
             EventType::Security(SecurityEvent::AbuseBan) => 549,
             EventType::Security(SecurityEvent::ScanBan) => 558,
             EventType::Security(SecurityEvent::LoiterBan) => 550,
+            EventType::Security(SecurityEvent::SmugglingBan) => 618,
             EventType::Security(SecurityEvent::IpBlocked) => 318,
             EventType::Security(SecurityEvent::IpBlockExpired) => 593,
             EventType::Security(SecurityEvent::IpAllowExpired) => 594,
             EventType::Security(SecurityEvent::IpUnauthorized) => 279,
+            EventType::Security(SecurityEvent::NetworkPolicyBlocked) => 619,
             EventType::Security(SecurityEvent::Unauthorized) => 552,
             EventType::Server(ServerEvent::Startup) => 393,
             EventType::Server(ServerEvent::Shutdown) => 392,
@@ -1782,6 +1949,13 @@ impl EventType {
             EventType::Sieve(SieveEvent::UnexpectedError) => 407,
             EventType::Sieve(SieveEvent::NotSupported) => 402,
             EventType::Sieve(SieveEvent::QuotaExceeded) => 403,
+            EventType::Sieve(SieveEvent::CpuLimitExceeded) => 620,
+            EventType::Sieve(SieveEvent::TimeLimitExceeded) => 621,
+            EventType::Sieve(SieveEvent::RedirectLimitExceeded) => 622,
+            EventType::Sieve(SieveEvent::RedirectLoop) => 623,
+            EventType::Sieve(SieveEvent::NotifyLimitExceeded) => 624,
+            EventType::Sieve(SieveEvent::NotifySent) => 625,
+            EventType::Sieve(SieveEvent::NotifyFailed) => 626,
             EventType::Smtp(SmtpEvent::ConnectionStart) => 417,
             EventType::Smtp(SmtpEvent::ConnectionEnd) => 416,
             EventType::Smtp(SmtpEvent::Error) => 428,
@@ -1793,7 +1967,11 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MissingAuthDirectory) => 452,
             EventType::Smtp(SmtpEvent::MessageParseFailed) => 450,
             EventType::Smtp(SmtpEvent::MessageTooLarge) => 451,
+            EventType::Smtp(SmtpEvent::LargeMessage) => 627,
             EventType::Smtp(SmtpEvent::LoopDetected) => 443,
+            EventType::Smtp(SmtpEvent::StructureLimitExceeded) => 628,
+            EventType::Smtp(SmtpEvent::BareLineEnding) => 629,
+            EventType::Smtp(SmtpEvent::SmugglingAttempt) => 630,
             EventType::Smtp(SmtpEvent::DkimPass) => 422,
             EventType::Smtp(SmtpEvent::DkimFail) => 421,
             EventType::Smtp(SmtpEvent::ArcPass) => 410,
@@ -1804,6 +1982,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => 475,
             EventType::Smtp(SmtpEvent::DmarcPass) => 424,
             EventType::Smtp(SmtpEvent::DmarcFail) => 423,
+            EventType::Smtp(SmtpEvent::DmarcOverride) => 631,
             EventType::Smtp(SmtpEvent::IprevPass) => 441,
             EventType::Smtp(SmtpEvent::IprevFail) => 440,
             EventType::Smtp(SmtpEvent::TooManyMessages) => 483,
@@ -1815,11 +1994,17 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MailFromUnauthenticated) => 447,
             EventType::Smtp(SmtpEvent::MailFromUnauthorized) => 448,
             EventType::Smtp(SmtpEvent::MailFromNotAllowed) => 551,
+            EventType::Smtp(SmtpEvent::TenantDomainMismatch) => 632,
+            EventType::Smtp(SmtpEvent::FromHeaderUnauthorized) => 633,
+            EventType::Smtp(SmtpEvent::FromHeaderRewritten) => 634,
+            EventType::Smtp(SmtpEvent::SenderMismatchTagged) => 635,
             EventType::Smtp(SmtpEvent::MailFromRewritten) => 446,
             EventType::Smtp(SmtpEvent::MailFromMissing) => 445,
             EventType::Smtp(SmtpEvent::MailFrom) => 444,
             EventType::Smtp(SmtpEvent::MultipleMailFrom) => 456,
             EventType::Smtp(SmtpEvent::MailboxDoesNotExist) => 449,
+            EventType::Smtp(SmtpEvent::CalloutRejected) => 636,
+            EventType::Smtp(SmtpEvent::CalloutFailed) => 637,
             EventType::Smtp(SmtpEvent::RelayNotAllowed) => 468,
             EventType::Smtp(SmtpEvent::RcptTo) => 464,
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => 465,
@@ -1834,9 +2019,14 @@ impl EventType {
             EventType::Smtp(SmtpEvent::Vrfy) => 487,
             EventType::Smtp(SmtpEvent::VrfyNotFound) => 489,
             EventType::Smtp(SmtpEvent::VrfyDisabled) => 488,
+            EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded) => 638,
             EventType::Smtp(SmtpEvent::Expn) => 429,
             EventType::Smtp(SmtpEvent::ExpnNotFound) => 431,
             EventType::Smtp(SmtpEvent::ExpnDisabled) => 430,
+            EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded) => 639,
+            EventType::Smtp(SmtpEvent::MailingListNotMember) => 640,
+            EventType::Smtp(SmtpEvent::MailingListDistributed) => 641,
+            EventType::Smtp(SmtpEvent::MailingListBounce) => 642,
             EventType::Smtp(SmtpEvent::RequireTlsDisabled) => 471,
             EventType::Smtp(SmtpEvent::DeliverByDisabled) => 418,
             EventType::Smtp(SmtpEvent::DeliverByInvalid) => 419,
@@ -1849,6 +2039,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::AuthMechanismNotSupported) => 412,
             EventType::Smtp(SmtpEvent::AuthExchangeTooLong) => 411,
             EventType::Smtp(SmtpEvent::AlreadyAuthenticated) => 408,
+            EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated) => 643,
             EventType::Smtp(SmtpEvent::Noop) => 457,
             EventType::Smtp(SmtpEvent::StartTls) => 477,
             EventType::Smtp(SmtpEvent::StartTlsUnavailable) => 479,
@@ -1877,6 +2068,9 @@ impl EventType {
             EventType::Spam(SpamEvent::ModelNotReady) => 496,
             EventType::Spam(SpamEvent::ModelNotFound) => 497,
             EventType::Spam(SpamEvent::RulesUpdated) => 280,
+            EventType::Spam(SpamEvent::MessageQuarantined) => 644,
+            EventType::Spam(SpamEvent::MessageReleased) => 645,
+            EventType::Spam(SpamEvent::QuarantineDigestSent) => 646,
             EventType::Spf(SpfEvent::Pass) => 501,
             EventType::Spf(SpfEvent::Fail) => 498,
             EventType::Spf(SpfEvent::SoftFail) => 503,
@@ -1922,8 +2116,28 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning) => 519,
             EventType::Store(StoreEvent::HttpStoreFetch) => 492,
             EventType::Store(StoreEvent::AutoExpunge) => 364,
+            EventType::Store(StoreEvent::AutoArchive) => 647,
             EventType::Store(StoreEvent::BlobStorePurged) => 369,
             EventType::Store(StoreEvent::DataStorePurged) => 368,
+            EventType::Store(StoreEvent::DataStoreCompacted) => 648,
+            EventType::Store(StoreEvent::DataStoreFlushed) => 649,
+            EventType::Store(StoreEvent::MailStoreImported) => 650,
+            EventType::Store(StoreEvent::AccountExported) => 651,
+            EventType::Store(StoreEvent::AccountImported) => 652,
+            EventType::Store(StoreEvent::AccountPurged) => 653,
+            EventType::Store(StoreEvent::DataRegionMigrated) => 654,
+            EventType::Store(StoreEvent::ColdTierMigrated) => 655,
+            EventType::Store(StoreEvent::ColdTierRestored) => 656,
+            EventType::Store(StoreEvent::PartitionMigrated) => 657,
+            EventType::Store(StoreEvent::BlobMissing) => 658,
+            EventType::Store(StoreEvent::BlobCorrupted) => 659,
+            EventType::Store(StoreEvent::BlobRepaired) => 660,
+            EventType::Store(StoreEvent::BlobStoreScrubbed) => 661,
+            EventType::Store(StoreEvent::DataStoreChecked) => 662,
+            EventType::Store(StoreEvent::BlobMirrorFallback) => 663,
+            EventType::Store(StoreEvent::BlobMirrorFailed) => 664,
+            EventType::Store(StoreEvent::BlobMirrorRepaired) => 665,
+            EventType::Store(StoreEvent::BlobMirrorQueueFull) => 666,
             EventType::TaskManager(TaskManagerEvent::TaskAcquired) => 578,
             EventType::TaskManager(TaskManagerEvent::TaskQueued) => 149,
             EventType::TaskManager(TaskManagerEvent::TaskScheduled) => 370,
@@ -1935,6 +2149,9 @@ impl EventType {
             EventType::TaskManager(TaskManagerEvent::MetadataNotFound) => 145,
             EventType::TaskManager(TaskManagerEvent::SchedulerStarted) => 150,
             EventType::TaskManager(TaskManagerEvent::ManagerStarted) => 367,
+            EventType::TaskManager(TaskManagerEvent::ReindexProgress) => 667,
+            EventType::TaskManager(TaskManagerEvent::ReindexCompleted) => 668,
+            EventType::TaskManager(TaskManagerEvent::TaskCancelled) => 669,
             EventType::Telemetry(TelemetryEvent::AlertEvent) => 548,
             EventType::Telemetry(TelemetryEvent::AlertMessage) => 365,
             EventType::Telemetry(TelemetryEvent::LogError) => 535,
@@ -1956,6 +2173,7 @@ impl EventType {
             EventType::TlsRpt(TlsRptEvent::RecordFetch) => 540,
             EventType::TlsRpt(TlsRptEvent::RecordFetchError) => 541,
             EventType::TlsRpt(TlsRptEvent::RecordNotFound) => 560,
+            EventType::TlsRpt(TlsRptEvent::InboundFailure) => 670,
             EventType::WebDav(WebDavEvent::Propfind) => 147,
             EventType::WebDav(WebDavEvent::Proppatch) => 148,
             EventType::WebDav(WebDavEvent::Get) => 335,
@@ -2016,6 +2234,8 @@ impl EventType {
             34 => Some(EventType::Auth(AuthEvent::Error)),
             595 => Some(EventType::Auth(AuthEvent::Warning)),
             276 => Some(EventType::Auth(AuthEvent::CredentialExpired)),
+            605 => Some(EventType::Auth(AuthEvent::ClientCertificateSuccess)),
+            606 => Some(EventType::Auth(AuthEvent::ClientCertificateFailed)),
             576 => Some(EventType::Calendar(CalendarEvent::RuleExpansionError)),
             579 => Some(EventType::Calendar(CalendarEvent::AlarmSent)),
             580 => Some(EventType::Calendar(CalendarEvent::AlarmSkipped)),
@@ -2141,6 +2361,8 @@ impl EventType {
             159 => Some(EventType::Imap(ImapEvent::Append)),
             160 => Some(EventType::Imap(ImapEvent::Capabilities)),
             172 => Some(EventType::Imap(ImapEvent::Id)),
+            607 => Some(EventType::Imap(ImapEvent::ClientRejected)),
+            608 => Some(EventType::Imap(ImapEvent::ClientRestricted)),
             161 => Some(EventType::Imap(ImapEvent::Close)),
             164 => Some(EventType::Imap(ImapEvent::Copy)),
             179 => Some(EventType::Imap(ImapEvent::Move)),
@@ -2166,6 +2388,8 @@ impl EventType {
             194 => Some(EventType::Imap(ImapEvent::Unsubscribe)),
             193 => Some(EventType::Imap(ImapEvent::Thread)),
             57 => Some(EventType::Imap(ImapEvent::GetQuota)),
+            609 => Some(EventType::Imap(ImapEvent::GetMetadata)),
+            610 => Some(EventType::Imap(ImapEvent::SetMetadata)),
             168 => Some(EventType::Imap(ImapEvent::Error)),
             183 => Some(EventType::Imap(ImapEvent::RawInput)),
             184 => Some(EventType::Imap(ImapEvent::RawOutput)),
@@ -2238,7 +2462,9 @@ impl EventType {
             242 => Some(EventType::Limit(LimitEvent::Quota)),
             237 => Some(EventType::Limit(LimitEvent::BlobQuota)),
             553 => Some(EventType::Limit(LimitEvent::TenantQuota)),
+            611 => Some(EventType::Limit(LimitEvent::DraftsQuota)),
             245 => Some(EventType::Limit(LimitEvent::TooManyRequests)),
+            612 => Some(EventType::Limit(LimitEvent::BandwidthThrottled)),
             254 => Some(EventType::MailAuth(MailAuthEvent::ParseError)),
             252 => Some(EventType::MailAuth(MailAuthEvent::MissingParameters)),
             253 => Some(EventType::MailAuth(MailAuthEvent::NoHeadersFound)),
@@ -2256,6 +2482,7 @@ impl EventType {
             263 => Some(EventType::ManageSieve(ManageSieveEvent::GetScript)),
             261 => Some(EventType::ManageSieve(ManageSieveEvent::DeleteScript)),
             270 => Some(EventType::ManageSieve(ManageSieveEvent::RenameScript)),
+            613 => Some(EventType::ManageSieve(ManageSieveEvent::RollbackScript)),
             257 => Some(EventType::ManageSieve(ManageSieveEvent::CheckScript)),
             264 => Some(EventType::ManageSieve(ManageSieveEvent::HaveSpace)),
             265 => Some(EventType::ManageSieve(ManageSieveEvent::ListScripts)),
@@ -2274,7 +2501,11 @@ impl EventType {
             285 => Some(EventType::MessageIngest(MessageIngestEvent::JmapAppend)),
             281 => Some(EventType::MessageIngest(MessageIngestEvent::Duplicate)),
             282 => Some(EventType::MessageIngest(MessageIngestEvent::Error)),
+            614 => Some(EventType::MessageIngest(MessageIngestEvent::MimeRepaired)),
             142 => Some(EventType::MessageIngest(MessageIngestEvent::SearchIndex)),
+            615 => Some(EventType::MessageIngest(
+                MessageIngestEvent::SearchIndexTruncated,
+            )),
             299 => Some(EventType::Milter(MilterEvent::Read)),
             303 => Some(EventType::Milter(MilterEvent::Write)),
             287 => Some(EventType::Milter(MilterEvent::ActionAccept)),
@@ -2314,6 +2545,7 @@ impl EventType {
             326 => Some(EventType::Network(NetworkEvent::SplitError)),
             327 => Some(EventType::Network(NetworkEvent::Timeout)),
             317 => Some(EventType::Network(NetworkEvent::Closed)),
+            616 => Some(EventType::Network(NetworkEvent::SessionTerminated)),
             323 => Some(EventType::Network(NetworkEvent::ProxyError)),
             325 => Some(EventType::Network(NetworkEvent::SetOptError)),
             342 => Some(EventType::OutgoingReport(OutgoingReportEvent::SpfReport)),
@@ -2403,13 +2635,16 @@ impl EventType {
             601 => Some(EventType::Resource(ResourceEvent::ApplicationUpdated)),
             602 => Some(EventType::Resource(ResourceEvent::ApplicationUnpacked)),
             33 => Some(EventType::Security(SecurityEvent::AuthenticationBan)),
+            617 => Some(EventType::Security(SecurityEvent::AuthenticationDelayed)),
             549 => Some(EventType::Security(SecurityEvent::AbuseBan)),
             558 => Some(EventType::Security(SecurityEvent::ScanBan)),
             550 => Some(EventType::Security(SecurityEvent::LoiterBan)),
+            618 => Some(EventType::Security(SecurityEvent::SmugglingBan)),
             318 => Some(EventType::Security(SecurityEvent::IpBlocked)),
             593 => Some(EventType::Security(SecurityEvent::IpBlockExpired)),
             594 => Some(EventType::Security(SecurityEvent::IpAllowExpired)),
             279 => Some(EventType::Security(SecurityEvent::IpUnauthorized)),
+            619 => Some(EventType::Security(SecurityEvent::NetworkPolicyBlocked)),
             552 => Some(EventType::Security(SecurityEvent::Unauthorized)),
             393 => Some(EventType::Server(ServerEvent::Startup)),
             392 => Some(EventType::Server(ServerEvent::Shutdown)),
@@ -2430,6 +2665,13 @@ impl EventType {
             407 => Some(EventType::Sieve(SieveEvent::UnexpectedError)),
             402 => Some(EventType::Sieve(SieveEvent::NotSupported)),
             403 => Some(EventType::Sieve(SieveEvent::QuotaExceeded)),
+            620 => Some(EventType::Sieve(SieveEvent::CpuLimitExceeded)),
+            621 => Some(EventType::Sieve(SieveEvent::TimeLimitExceeded)),
+            622 => Some(EventType::Sieve(SieveEvent::RedirectLimitExceeded)),
+            623 => Some(EventType::Sieve(SieveEvent::RedirectLoop)),
+            624 => Some(EventType::Sieve(SieveEvent::NotifyLimitExceeded)),
+            625 => Some(EventType::Sieve(SieveEvent::NotifySent)),
+            626 => Some(EventType::Sieve(SieveEvent::NotifyFailed)),
             417 => Some(EventType::Smtp(SmtpEvent::ConnectionStart)),
             416 => Some(EventType::Smtp(SmtpEvent::ConnectionEnd)),
             428 => Some(EventType::Smtp(SmtpEvent::Error)),
@@ -2441,7 +2683,11 @@ impl EventType {
             452 => Some(EventType::Smtp(SmtpEvent::MissingAuthDirectory)),
             450 => Some(EventType::Smtp(SmtpEvent::MessageParseFailed)),
             451 => Some(EventType::Smtp(SmtpEvent::MessageTooLarge)),
+            627 => Some(EventType::Smtp(SmtpEvent::LargeMessage)),
             443 => Some(EventType::Smtp(SmtpEvent::LoopDetected)),
+            628 => Some(EventType::Smtp(SmtpEvent::StructureLimitExceeded)),
+            629 => Some(EventType::Smtp(SmtpEvent::BareLineEnding)),
+            630 => Some(EventType::Smtp(SmtpEvent::SmugglingAttempt)),
             422 => Some(EventType::Smtp(SmtpEvent::DkimPass)),
             421 => Some(EventType::Smtp(SmtpEvent::DkimFail)),
             410 => Some(EventType::Smtp(SmtpEvent::ArcPass)),
@@ -2452,6 +2698,7 @@ impl EventType {
             475 => Some(EventType::Smtp(SmtpEvent::SpfFromFail)),
             424 => Some(EventType::Smtp(SmtpEvent::DmarcPass)),
             423 => Some(EventType::Smtp(SmtpEvent::DmarcFail)),
+            631 => Some(EventType::Smtp(SmtpEvent::DmarcOverride)),
             441 => Some(EventType::Smtp(SmtpEvent::IprevPass)),
             440 => Some(EventType::Smtp(SmtpEvent::IprevFail)),
             483 => Some(EventType::Smtp(SmtpEvent::TooManyMessages)),
@@ -2463,11 +2710,17 @@ impl EventType {
             447 => Some(EventType::Smtp(SmtpEvent::MailFromUnauthenticated)),
             448 => Some(EventType::Smtp(SmtpEvent::MailFromUnauthorized)),
             551 => Some(EventType::Smtp(SmtpEvent::MailFromNotAllowed)),
+            632 => Some(EventType::Smtp(SmtpEvent::TenantDomainMismatch)),
+            633 => Some(EventType::Smtp(SmtpEvent::FromHeaderUnauthorized)),
+            634 => Some(EventType::Smtp(SmtpEvent::FromHeaderRewritten)),
+            635 => Some(EventType::Smtp(SmtpEvent::SenderMismatchTagged)),
             446 => Some(EventType::Smtp(SmtpEvent::MailFromRewritten)),
             445 => Some(EventType::Smtp(SmtpEvent::MailFromMissing)),
             444 => Some(EventType::Smtp(SmtpEvent::MailFrom)),
             456 => Some(EventType::Smtp(SmtpEvent::MultipleMailFrom)),
             449 => Some(EventType::Smtp(SmtpEvent::MailboxDoesNotExist)),
+            636 => Some(EventType::Smtp(SmtpEvent::CalloutRejected)),
+            637 => Some(EventType::Smtp(SmtpEvent::CalloutFailed)),
             468 => Some(EventType::Smtp(SmtpEvent::RelayNotAllowed)),
             464 => Some(EventType::Smtp(SmtpEvent::RcptTo)),
             465 => Some(EventType::Smtp(SmtpEvent::RcptToDuplicate)),
@@ -2482,9 +2735,13 @@ impl EventType {
             487 => Some(EventType::Smtp(SmtpEvent::Vrfy)),
             489 => Some(EventType::Smtp(SmtpEvent::VrfyNotFound)),
             488 => Some(EventType::Smtp(SmtpEvent::VrfyDisabled)),
+            638 => Some(EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded)),
             429 => Some(EventType::Smtp(SmtpEvent::Expn)),
             431 => Some(EventType::Smtp(SmtpEvent::ExpnNotFound)),
             430 => Some(EventType::Smtp(SmtpEvent::ExpnDisabled)),
+            639 => Some(EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded)),
+            640 => Some(EventType::Smtp(SmtpEvent::MailingListNotMember)),
+            641 => Some(EventType::Smtp(SmtpEvent::MailingListDistributed)),
             471 => Some(EventType::Smtp(SmtpEvent::RequireTlsDisabled)),
             418 => Some(EventType::Smtp(SmtpEvent::DeliverByDisabled)),
             419 => Some(EventType::Smtp(SmtpEvent::DeliverByInvalid)),
@@ -2497,6 +2754,7 @@ impl EventType {
             412 => Some(EventType::Smtp(SmtpEvent::AuthMechanismNotSupported)),
             411 => Some(EventType::Smtp(SmtpEvent::AuthExchangeTooLong)),
             408 => Some(EventType::Smtp(SmtpEvent::AlreadyAuthenticated)),
+            643 => Some(EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated)),
             457 => Some(EventType::Smtp(SmtpEvent::Noop)),
             477 => Some(EventType::Smtp(SmtpEvent::StartTls)),
             479 => Some(EventType::Smtp(SmtpEvent::StartTlsUnavailable)),
@@ -2525,6 +2783,9 @@ impl EventType {
             496 => Some(EventType::Spam(SpamEvent::ModelNotReady)),
             497 => Some(EventType::Spam(SpamEvent::ModelNotFound)),
             280 => Some(EventType::Spam(SpamEvent::RulesUpdated)),
+            644 => Some(EventType::Spam(SpamEvent::MessageQuarantined)),
+            645 => Some(EventType::Spam(SpamEvent::MessageReleased)),
+            646 => Some(EventType::Spam(SpamEvent::QuarantineDigestSent)),
             501 => Some(EventType::Spf(SpfEvent::Pass)),
             498 => Some(EventType::Spf(SpfEvent::Fail)),
             503 => Some(EventType::Spf(SpfEvent::SoftFail)),
@@ -2570,8 +2831,28 @@ impl EventType {
             519 => Some(EventType::Store(StoreEvent::LdapWarning)),
             492 => Some(EventType::Store(StoreEvent::HttpStoreFetch)),
             364 => Some(EventType::Store(StoreEvent::AutoExpunge)),
+            647 => Some(EventType::Store(StoreEvent::AutoArchive)),
             369 => Some(EventType::Store(StoreEvent::BlobStorePurged)),
             368 => Some(EventType::Store(StoreEvent::DataStorePurged)),
+            648 => Some(EventType::Store(StoreEvent::DataStoreCompacted)),
+            649 => Some(EventType::Store(StoreEvent::DataStoreFlushed)),
+            650 => Some(EventType::Store(StoreEvent::MailStoreImported)),
+            651 => Some(EventType::Store(StoreEvent::AccountExported)),
+            652 => Some(EventType::Store(StoreEvent::AccountImported)),
+            653 => Some(EventType::Store(StoreEvent::AccountPurged)),
+            654 => Some(EventType::Store(StoreEvent::DataRegionMigrated)),
+            655 => Some(EventType::Store(StoreEvent::ColdTierMigrated)),
+            656 => Some(EventType::Store(StoreEvent::ColdTierRestored)),
+            657 => Some(EventType::Store(StoreEvent::PartitionMigrated)),
+            658 => Some(EventType::Store(StoreEvent::BlobMissing)),
+            659 => Some(EventType::Store(StoreEvent::BlobCorrupted)),
+            660 => Some(EventType::Store(StoreEvent::BlobRepaired)),
+            661 => Some(EventType::Store(StoreEvent::BlobStoreScrubbed)),
+            662 => Some(EventType::Store(StoreEvent::DataStoreChecked)),
+            663 => Some(EventType::Store(StoreEvent::BlobMirrorFallback)),
+            664 => Some(EventType::Store(StoreEvent::BlobMirrorFailed)),
+            665 => Some(EventType::Store(StoreEvent::BlobMirrorRepaired)),
+            666 => Some(EventType::Store(StoreEvent::BlobMirrorQueueFull)),
             578 => Some(EventType::TaskManager(TaskManagerEvent::TaskAcquired)),
             149 => Some(EventType::TaskManager(TaskManagerEvent::TaskQueued)),
             370 => Some(EventType::TaskManager(TaskManagerEvent::TaskScheduled)),
@@ -2583,6 +2864,10 @@ impl EventType {
             145 => Some(EventType::TaskManager(TaskManagerEvent::MetadataNotFound)),
             150 => Some(EventType::TaskManager(TaskManagerEvent::SchedulerStarted)),
             367 => Some(EventType::TaskManager(TaskManagerEvent::ManagerStarted)),
+            667 => Some(EventType::TaskManager(TaskManagerEvent::ReindexProgress)),
+            668 => Some(EventType::TaskManager(TaskManagerEvent::ReindexCompleted)),
+            669 => Some(EventType::TaskManager(TaskManagerEvent::TaskCancelled)),
+            642 => Some(EventType::Smtp(SmtpEvent::MailingListBounce)),
             548 => Some(EventType::Telemetry(TelemetryEvent::AlertEvent)),
             365 => Some(EventType::Telemetry(TelemetryEvent::AlertMessage)),
             535 => Some(EventType::Telemetry(TelemetryEvent::LogError)),
@@ -2608,6 +2893,7 @@ impl EventType {
             540 => Some(EventType::TlsRpt(TlsRptEvent::RecordFetch)),
             541 => Some(EventType::TlsRpt(TlsRptEvent::RecordFetchError)),
             560 => Some(EventType::TlsRpt(TlsRptEvent::RecordNotFound)),
+            670 => Some(EventType::TlsRpt(TlsRptEvent::InboundFailure)),
             147 => Some(EventType::WebDav(WebDavEvent::Propfind)),
             148 => Some(EventType::WebDav(WebDavEvent::Proppatch)),
             335 => Some(EventType::WebDav(WebDavEvent::Get)),
@@ -2692,6 +2978,7 @@ impl EventType {
             EventType::Acme(AcmeEvent::TlsAlpnReceived) => Level::Info,
             EventType::Auth(AuthEvent::Success) => Level::Info,
             EventType::Auth(AuthEvent::ClientRegistration) => Level::Info,
+            EventType::Auth(AuthEvent::ClientCertificateSuccess) => Level::Info,
             EventType::Calendar(CalendarEvent::AlarmSent) => Level::Info,
             EventType::Calendar(CalendarEvent::ItipMessageSent) => Level::Info,
             EventType::Calendar(CalendarEvent::ItipMessageReceived) => Level::Info,
@@ -2818,6 +3105,7 @@ impl EventType {
             EventType::Security(SecurityEvent::AbuseBan) => Level::Info,
             EventType::Security(SecurityEvent::ScanBan) => Level::Info,
             EventType::Security(SecurityEvent::LoiterBan) => Level::Info,
+            EventType::Security(SecurityEvent::SmugglingBan) => Level::Info,
             EventType::Security(SecurityEvent::IpBlocked) => Level::Info,
             EventType::Security(SecurityEvent::IpBlockExpired) => Level::Info,
             EventType::Security(SecurityEvent::IpAllowExpired) => Level::Info,
@@ -2885,6 +3173,52 @@ impl EventType {
             EventType::TlsRpt(TlsRptEvent::RecordFetch) => Level::Info,
             EventType::TlsRpt(TlsRptEvent::RecordFetchError) => Level::Info,
             EventType::TlsRpt(TlsRptEvent::RecordNotFound) => Level::Info,
+            EventType::Limit(LimitEvent::DraftsQuota) => Level::Info,
+            EventType::Store(StoreEvent::AccountExported) => Level::Info,
+            EventType::Store(StoreEvent::AccountImported) => Level::Info,
+            EventType::Smtp(SmtpEvent::LargeMessage) => Level::Info,
+            EventType::MessageIngest(MessageIngestEvent::SearchIndexTruncated) => Level::Info,
+            EventType::TlsRpt(TlsRptEvent::InboundFailure) => Level::Info,
+            EventType::Smtp(SmtpEvent::TenantDomainMismatch) => Level::Info,
+            EventType::Store(StoreEvent::DataRegionMigrated) => Level::Info,
+            EventType::TaskManager(TaskManagerEvent::ReindexProgress) => Level::Info,
+            EventType::TaskManager(TaskManagerEvent::ReindexCompleted) => Level::Info,
+            EventType::TaskManager(TaskManagerEvent::TaskCancelled) => Level::Info,
+            EventType::Smtp(SmtpEvent::DmarcOverride) => Level::Info,
+            EventType::Store(StoreEvent::DataStoreCompacted) => Level::Info,
+            EventType::Store(StoreEvent::DataStoreFlushed) => Level::Info,
+            EventType::Store(StoreEvent::MailStoreImported) => Level::Info,
+            EventType::Security(SecurityEvent::NetworkPolicyBlocked) => Level::Info,
+            EventType::Store(StoreEvent::ColdTierMigrated) => Level::Info,
+            EventType::Store(StoreEvent::ColdTierRestored) => Level::Info,
+            EventType::Store(StoreEvent::AutoArchive) => Level::Info,
+            EventType::Store(StoreEvent::PartitionMigrated) => Level::Info,
+            EventType::Smtp(SmtpEvent::FromHeaderUnauthorized) => Level::Info,
+            EventType::Smtp(SmtpEvent::FromHeaderRewritten) => Level::Info,
+            EventType::Smtp(SmtpEvent::SenderMismatchTagged) => Level::Info,
+            EventType::Store(StoreEvent::BlobRepaired) => Level::Info,
+            EventType::Store(StoreEvent::BlobStoreScrubbed) => Level::Info,
+            EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded) => Level::Info,
+            EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded) => Level::Info,
+            EventType::MessageIngest(MessageIngestEvent::MimeRepaired) => Level::Info,
+            EventType::Security(SecurityEvent::AuthenticationDelayed) => Level::Info,
+            EventType::Smtp(SmtpEvent::MailingListNotMember) => Level::Info,
+            EventType::Smtp(SmtpEvent::MailingListDistributed) => Level::Info,
+            EventType::Smtp(SmtpEvent::MailingListBounce) => Level::Info,
+            EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated) => Level::Info,
+            EventType::Sieve(SieveEvent::NotifySent) => Level::Info,
+            EventType::Store(StoreEvent::DataStoreChecked) => Level::Info,
+            EventType::Imap(ImapEvent::ClientRejected) => Level::Info,
+            EventType::Imap(ImapEvent::ClientRestricted) => Level::Info,
+            EventType::Smtp(SmtpEvent::StructureLimitExceeded) => Level::Info,
+            EventType::Smtp(SmtpEvent::BareLineEnding) => Level::Info,
+            EventType::Smtp(SmtpEvent::SmugglingAttempt) => Level::Info,
+            EventType::Network(NetworkEvent::SessionTerminated) => Level::Info,
+            EventType::Spam(SpamEvent::MessageQuarantined) => Level::Info,
+            EventType::Spam(SpamEvent::MessageReleased) => Level::Info,
+            EventType::Spam(SpamEvent::QuarantineDigestSent) => Level::Info,
+            EventType::Store(StoreEvent::AccountPurged) => Level::Info,
+            EventType::Smtp(SmtpEvent::CalloutRejected) => Level::Info,
             EventType::Ai(AiEvent::LlmResponse) => Level::Trace,
             EventType::Auth(AuthEvent::MfaRequired) => Level::Trace,
             EventType::Cluster(ClusterEvent::MessageReceived) => Level::Trace,
@@ -2972,6 +3306,18 @@ impl EventType {
             EventType::Telemetry(TelemetryEvent::JournalError) => Level::Warn,
             EventType::Tls(TlsEvent::NoCertificatesAvailable) => Level::Warn,
             EventType::Tls(TlsEvent::MultipleCertificatesAvailable) => Level::Warn,
+            EventType::Store(StoreEvent::BlobMissing) => Level::Warn,
+            EventType::Store(StoreEvent::BlobCorrupted) => Level::Warn,
+            EventType::Sieve(SieveEvent::CpuLimitExceeded) => Level::Warn,
+            EventType::Sieve(SieveEvent::TimeLimitExceeded) => Level::Warn,
+            EventType::Sieve(SieveEvent::RedirectLimitExceeded) => Level::Warn,
+            EventType::Sieve(SieveEvent::RedirectLoop) => Level::Warn,
+            EventType::Sieve(SieveEvent::NotifyLimitExceeded) => Level::Warn,
+            EventType::Store(StoreEvent::BlobMirrorFallback) => Level::Warn,
+            EventType::Store(StoreEvent::BlobMirrorFailed) => Level::Warn,
+            EventType::Store(StoreEvent::BlobMirrorQueueFull) => Level::Warn,
+            EventType::Sieve(SieveEvent::NotifyFailed) => Level::Warn,
+            EventType::Smtp(SmtpEvent::CalloutFailed) => Level::Debug,
             _ => Level::Debug,
         }
     }
@@ -3017,6 +3363,12 @@ impl EventType {
             EventType::Auth(AuthEvent::Error) => "Authentication error",
             EventType::Auth(AuthEvent::Warning) => "Authentication warning",
             EventType::Auth(AuthEvent::CredentialExpired) => "Credential expired",
+            EventType::Auth(AuthEvent::ClientCertificateSuccess) => {
+                "Client certificate authentication successful"
+            }
+            EventType::Auth(AuthEvent::ClientCertificateFailed) => {
+                "Client certificate authentication failed"
+            }
             EventType::Calendar(CalendarEvent::RuleExpansionError) => {
                 "Calendar rule expansion error"
             }
@@ -3170,6 +3522,10 @@ impl EventType {
             EventType::Imap(ImapEvent::Append) => "IMAP APPEND command",
             EventType::Imap(ImapEvent::Capabilities) => "IMAP CAPABILITIES command",
             EventType::Imap(ImapEvent::Id) => "IMAP ID command",
+            EventType::Imap(ImapEvent::ClientRejected) => "IMAP client rejected by policy",
+            EventType::Imap(ImapEvent::ClientRestricted) => {
+                "IMAP client restricted to read-only access"
+            }
             EventType::Imap(ImapEvent::Close) => "IMAP CLOSE command",
             EventType::Imap(ImapEvent::Copy) => "IMAP COPY command",
             EventType::Imap(ImapEvent::Move) => "IMAP MOVE command",
@@ -3195,6 +3551,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => "IMAP UNSUBSCRIBE command",
             EventType::Imap(ImapEvent::Thread) => "IMAP THREAD command",
             EventType::Imap(ImapEvent::GetQuota) => "IMAP GETQUOTA command",
+            EventType::Imap(ImapEvent::GetMetadata) => "IMAP GETMETADATA command",
+            EventType::Imap(ImapEvent::SetMetadata) => "IMAP SETMETADATA command",
             EventType::Imap(ImapEvent::Error) => "IMAP error occurred",
             EventType::Imap(ImapEvent::RawInput) => "Raw IMAP input received",
             EventType::Imap(ImapEvent::RawOutput) => "Raw IMAP output sent",
@@ -3273,7 +3631,11 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota) => "Quota limit reached",
             EventType::Limit(LimitEvent::BlobQuota) => "Blob quota limit reached",
             EventType::Limit(LimitEvent::TenantQuota) => "Tenant quota limit reached",
+            EventType::Limit(LimitEvent::DraftsQuota) => "Drafts quota limit reached",
             EventType::Limit(LimitEvent::TooManyRequests) => "Too many requests",
+            EventType::Limit(LimitEvent::BandwidthThrottled) => {
+                "Bandwidth limit reached, throttling transfer"
+            }
             EventType::MailAuth(MailAuthEvent::ParseError) => "Mail authentication parse error",
             EventType::MailAuth(MailAuthEvent::MissingParameters) => {
                 "Missing mail authentication parameters"
@@ -3305,6 +3667,9 @@ impl EventType {
             EventType::ManageSieve(ManageSieveEvent::RenameScript) => {
                 "ManageSieve RENAME script command"
             }
+            EventType::ManageSieve(ManageSieveEvent::RollbackScript) => {
+                "ManageSieve ROLLBACKSCRIPT command"
+            }
             EventType::ManageSieve(ManageSieveEvent::CheckScript) => {
                 "ManageSieve CHECK script command"
             }
@@ -3331,7 +3696,13 @@ impl EventType {
             EventType::MessageIngest(MessageIngestEvent::JmapAppend) => "Message appended via JMAP",
             EventType::MessageIngest(MessageIngestEvent::Duplicate) => "Skipping duplicate message",
             EventType::MessageIngest(MessageIngestEvent::Error) => "Message ingestion error",
+            EventType::MessageIngest(MessageIngestEvent::MimeRepaired) => {
+                "Damaged MIME structure repaired before storage"
+            }
             EventType::MessageIngest(MessageIngestEvent::SearchIndex) => "Search index updated",
+            EventType::MessageIngest(MessageIngestEvent::SearchIndexTruncated) => {
+                "Search index body text truncated"
+            }
             EventType::Milter(MilterEvent::Read) => "Reading from Milter",
             EventType::Milter(MilterEvent::Write) => "Writing to Milter",
             EventType::Milter(MilterEvent::ActionAccept) => "Milter action: Accept",
@@ -3375,6 +3746,9 @@ impl EventType {
             EventType::Network(NetworkEvent::SplitError) => "Network split error",
             EventType::Network(NetworkEvent::Timeout) => "Network timeout",
             EventType::Network(NetworkEvent::Closed) => "Network connection closed",
+            EventType::Network(NetworkEvent::SessionTerminated) => {
+                "Session terminated by an administrator"
+            }
             EventType::Network(NetworkEvent::ProxyError) => "Proxy protocol error",
             EventType::Network(NetworkEvent::SetOptError) => "Network set option error",
             EventType::OutgoingReport(OutgoingReportEvent::SpfReport) => "SPF report sent",
@@ -3486,13 +3860,20 @@ impl EventType {
             EventType::Security(SecurityEvent::AuthenticationBan) => {
                 "Banned due to authentication errors"
             }
+            EventType::Security(SecurityEvent::AuthenticationDelayed) => {
+                "Authentication delayed after repeated failures"
+            }
             EventType::Security(SecurityEvent::AbuseBan) => "Banned due to abuse",
             EventType::Security(SecurityEvent::ScanBan) => "Banned due to scan",
             EventType::Security(SecurityEvent::LoiterBan) => "Banned due to loitering",
+            EventType::Security(SecurityEvent::SmugglingBan) => "Banned due to SMTP smuggling",
             EventType::Security(SecurityEvent::IpBlocked) => "Blocked IP address",
             EventType::Security(SecurityEvent::IpBlockExpired) => "IP block expired",
             EventType::Security(SecurityEvent::IpAllowExpired) => "IP allow expired",
             EventType::Security(SecurityEvent::IpUnauthorized) => "Unauthorized IP address",
+            EventType::Security(SecurityEvent::NetworkPolicyBlocked) => {
+                "Login blocked by account network policy"
+            }
             EventType::Security(SecurityEvent::Unauthorized) => "Unauthorized access",
             EventType::Server(ServerEvent::Startup) => "Starting Stalwart Server",
             EventType::Server(ServerEvent::Shutdown) => "Shutting down Stalwart Server",
@@ -3513,6 +3894,21 @@ impl EventType {
             EventType::Sieve(SieveEvent::UnexpectedError) => "Unexpected Sieve error",
             EventType::Sieve(SieveEvent::NotSupported) => "Sieve action not supported",
             EventType::Sieve(SieveEvent::QuotaExceeded) => "Sieve quota exceeded",
+            EventType::Sieve(SieveEvent::CpuLimitExceeded) => {
+                "Sieve script exceeded its CPU instruction budget"
+            }
+            EventType::Sieve(SieveEvent::TimeLimitExceeded) => {
+                "Sieve script exceeded its maximum run time"
+            }
+            EventType::Sieve(SieveEvent::RedirectLimitExceeded) => {
+                "Sieve redirect rate limit exceeded"
+            }
+            EventType::Sieve(SieveEvent::RedirectLoop) => "Sieve redirect loop detected",
+            EventType::Sieve(SieveEvent::NotifyLimitExceeded) => {
+                "Sieve notify action limit exceeded"
+            }
+            EventType::Sieve(SieveEvent::NotifySent) => "Sieve notification sent",
+            EventType::Sieve(SieveEvent::NotifyFailed) => "Failed to send Sieve notification",
             EventType::Smtp(SmtpEvent::ConnectionStart) => "SMTP connection started",
             EventType::Smtp(SmtpEvent::ConnectionEnd) => "SMTP connection ended",
             EventType::Smtp(SmtpEvent::Error) => "SMTP error occurred",
@@ -3524,7 +3920,13 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MissingAuthDirectory) => "Missing auth directory",
             EventType::Smtp(SmtpEvent::MessageParseFailed) => "Message parsing failed",
             EventType::Smtp(SmtpEvent::MessageTooLarge) => "Message too large",
+            EventType::Smtp(SmtpEvent::LargeMessage) => "Large message received",
             EventType::Smtp(SmtpEvent::LoopDetected) => "Mail loop detected",
+            EventType::Smtp(SmtpEvent::StructureLimitExceeded) => {
+                "Message header or MIME structure limit exceeded"
+            }
+            EventType::Smtp(SmtpEvent::BareLineEnding) => "Bare CR or LF line ending received",
+            EventType::Smtp(SmtpEvent::SmugglingAttempt) => "SMTP smuggling attempt detected",
             EventType::Smtp(SmtpEvent::DkimPass) => "DKIM verification passed",
             EventType::Smtp(SmtpEvent::DkimFail) => "DKIM verification failed",
             EventType::Smtp(SmtpEvent::ArcPass) => "ARC verification passed",
@@ -3535,6 +3937,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => "SPF From check failed",
             EventType::Smtp(SmtpEvent::DmarcPass) => "DMARC check passed",
             EventType::Smtp(SmtpEvent::DmarcFail) => "DMARC check failed",
+            EventType::Smtp(SmtpEvent::DmarcOverride) => "DMARC policy overridden",
             EventType::Smtp(SmtpEvent::IprevPass) => "IPREV check passed",
             EventType::Smtp(SmtpEvent::IprevFail) => "IPREV check failed",
             EventType::Smtp(SmtpEvent::TooManyMessages) => "Too many messages",
@@ -3548,11 +3951,23 @@ impl EventType {
             }
             EventType::Smtp(SmtpEvent::MailFromUnauthorized) => "MAIL FROM unauthorized",
             EventType::Smtp(SmtpEvent::MailFromNotAllowed) => "MAIL FROM not allowed",
+            EventType::Smtp(SmtpEvent::TenantDomainMismatch) => {
+                "Sender domain does not belong to the tenant"
+            }
+            EventType::Smtp(SmtpEvent::FromHeaderUnauthorized) => {
+                "From header address unauthorized"
+            }
+            EventType::Smtp(SmtpEvent::FromHeaderRewritten) => "From header address rewritten",
+            EventType::Smtp(SmtpEvent::SenderMismatchTagged) => "Unauthorized sender tagged",
             EventType::Smtp(SmtpEvent::MailFromRewritten) => "MAIL FROM address rewritten",
             EventType::Smtp(SmtpEvent::MailFromMissing) => "MAIL FROM address missing",
             EventType::Smtp(SmtpEvent::MailFrom) => "SMTP MAIL FROM command",
             EventType::Smtp(SmtpEvent::MultipleMailFrom) => "Multiple MAIL FROM commands",
             EventType::Smtp(SmtpEvent::MailboxDoesNotExist) => "Mailbox does not exist",
+            EventType::Smtp(SmtpEvent::CalloutRejected) => {
+                "Recipient rejected by destination server"
+            }
+            EventType::Smtp(SmtpEvent::CalloutFailed) => "Recipient callout verification failed",
             EventType::Smtp(SmtpEvent::RelayNotAllowed) => "Relay not allowed",
             EventType::Smtp(SmtpEvent::RcptTo) => "SMTP RCPT TO command",
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => "Duplicate RCPT TO",
@@ -3567,9 +3982,20 @@ impl EventType {
             EventType::Smtp(SmtpEvent::Vrfy) => "SMTP VRFY command",
             EventType::Smtp(SmtpEvent::VrfyNotFound) => "VRFY address not found",
             EventType::Smtp(SmtpEvent::VrfyDisabled) => "VRFY command disabled",
+            EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded) => "VRFY rate limit exceeded",
             EventType::Smtp(SmtpEvent::Expn) => "SMTP EXPN command",
             EventType::Smtp(SmtpEvent::ExpnNotFound) => "EXPN address not found",
             EventType::Smtp(SmtpEvent::ExpnDisabled) => "EXPN command disabled",
+            EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded) => "EXPN rate limit exceeded",
+            EventType::Smtp(SmtpEvent::MailingListNotMember) => {
+                "Sender is not allowed to post to the mailing list"
+            }
+            EventType::Smtp(SmtpEvent::MailingListDistributed) => {
+                "Message distributed to mailing list members"
+            }
+            EventType::Smtp(SmtpEvent::MailingListBounce) => {
+                "Delivery status notification received for a mailing list"
+            }
             EventType::Smtp(SmtpEvent::RequireTlsDisabled) => "REQUIRETLS extension disabled",
             EventType::Smtp(SmtpEvent::DeliverByDisabled) => "DELIVERBY extension disabled",
             EventType::Smtp(SmtpEvent::DeliverByInvalid) => "Invalid DELIVERBY parameter",
@@ -3584,6 +4010,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::AuthMechanismNotSupported) => "Auth mechanism not supported",
             EventType::Smtp(SmtpEvent::AuthExchangeTooLong) => "Auth exchange too long",
             EventType::Smtp(SmtpEvent::AlreadyAuthenticated) => "Already authenticated",
+            EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated) => {
+                "Client authenticated with a TLS certificate"
+            }
             EventType::Smtp(SmtpEvent::Noop) => "SMTP NOOP command",
             EventType::Smtp(SmtpEvent::StartTls) => "SMTP STARTTLS command",
             EventType::Smtp(SmtpEvent::StartTlsUnavailable) => "STARTTLS unavailable",
@@ -3612,6 +4041,9 @@ impl EventType {
             EventType::Spam(SpamEvent::ModelNotReady) => "Spam classifier model not ready",
             EventType::Spam(SpamEvent::ModelNotFound) => "Spam classifier model not found",
             EventType::Spam(SpamEvent::RulesUpdated) => "Spam filter rules updated",
+            EventType::Spam(SpamEvent::MessageQuarantined) => "Message held in quarantine",
+            EventType::Spam(SpamEvent::MessageReleased) => "Quarantined message released",
+            EventType::Spam(SpamEvent::QuarantineDigestSent) => "Quarantine digest sent",
             EventType::Spf(SpfEvent::Pass) => "SPF check passed",
             EventType::Spf(SpfEvent::Fail) => "SPF check failed",
             EventType::Spf(SpfEvent::SoftFail) => "SPF soft fail",
@@ -3659,8 +4091,46 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning) => "LDAP authentication warning",
             EventType::Store(StoreEvent::HttpStoreFetch) => "HTTP store updated",
             EventType::Store(StoreEvent::AutoExpunge) => "Auto-expunge executed",
+            EventType::Store(StoreEvent::AutoArchive) => "Auto-archive executed",
             EventType::Store(StoreEvent::BlobStorePurged) => "Blob store purge completed",
             EventType::Store(StoreEvent::DataStorePurged) => "Data store purge completed",
+            EventType::Store(StoreEvent::DataStoreCompacted) => "Data store compaction completed",
+            EventType::Store(StoreEvent::DataStoreFlushed) => "Data store flush completed",
+            EventType::Store(StoreEvent::MailStoreImported) => {
+                "Messages imported from mail store archive"
+            }
+            EventType::Store(StoreEvent::AccountExported) => "Account exported to archive",
+            EventType::Store(StoreEvent::AccountImported) => "Account imported from archive",
+            EventType::Store(StoreEvent::AccountPurged) => {
+                "All data of an account has been permanently purged"
+            }
+            EventType::Store(StoreEvent::DataRegionMigrated) => {
+                "Account data migrated to another region"
+            }
+            EventType::Store(StoreEvent::ColdTierMigrated) => {
+                "Account blobs moved to the cold storage tier"
+            }
+            EventType::Store(StoreEvent::ColdTierRestored) => {
+                "Account blobs restored from the cold storage tier"
+            }
+            EventType::Store(StoreEvent::PartitionMigrated) => {
+                "Account data moved to its FoundationDB partition"
+            }
+            EventType::Store(StoreEvent::BlobMissing) => {
+                "Committed blob not found in the blob store"
+            }
+            EventType::Store(StoreEvent::BlobCorrupted) => "Blob contents do not match their hash",
+            EventType::Store(StoreEvent::BlobRepaired) => "Damaged blob restored from a replica",
+            EventType::Store(StoreEvent::BlobStoreScrubbed) => {
+                "Blob store integrity check completed"
+            }
+            EventType::Store(StoreEvent::DataStoreChecked) => {
+                "Data store integrity check completed"
+            }
+            EventType::Store(StoreEvent::BlobMirrorFallback) => "Blob read from mirror store",
+            EventType::Store(StoreEvent::BlobMirrorFailed) => "Failed to update blob mirror",
+            EventType::Store(StoreEvent::BlobMirrorRepaired) => "Blob mirror repaired",
+            EventType::Store(StoreEvent::BlobMirrorQueueFull) => "Blob mirror queue full",
             EventType::TaskManager(TaskManagerEvent::TaskAcquired) => "Task acquired from queue",
             EventType::TaskManager(TaskManagerEvent::TaskQueued) => "Task queued for processing",
             EventType::TaskManager(TaskManagerEvent::TaskScheduled) => {
@@ -3680,6 +4150,13 @@ impl EventType {
             }
             EventType::TaskManager(TaskManagerEvent::SchedulerStarted) => "Task scheduler started",
             EventType::TaskManager(TaskManagerEvent::ManagerStarted) => "Task manager started",
+            EventType::TaskManager(TaskManagerEvent::ReindexProgress) => {
+                "Account reindex progressed"
+            }
+            EventType::TaskManager(TaskManagerEvent::ReindexCompleted) => {
+                "Account reindex completed"
+            }
+            EventType::TaskManager(TaskManagerEvent::TaskCancelled) => "Task cancelled",
             EventType::Telemetry(TelemetryEvent::AlertEvent) => "Alert event triggered",
             EventType::Telemetry(TelemetryEvent::AlertMessage) => "Alert message sent",
             EventType::Telemetry(TelemetryEvent::LogError) => "Log collector error",
@@ -3711,6 +4188,7 @@ impl EventType {
             EventType::TlsRpt(TlsRptEvent::RecordFetch) => "Fetched TLS-RPT record",
             EventType::TlsRpt(TlsRptEvent::RecordFetchError) => "Error fetching TLS-RPT record",
             EventType::TlsRpt(TlsRptEvent::RecordNotFound) => "TLS-RPT record not found",
+            EventType::TlsRpt(TlsRptEvent::InboundFailure) => "Inbound TLS failure recorded",
             EventType::WebDav(WebDavEvent::Propfind) => "WebDAV PROPFIND request",
             EventType::WebDav(WebDavEvent::Proppatch) => "WebDAV PROPPATCH request",
             EventType::WebDav(WebDavEvent::Get) => "WebDAV GET request",
@@ -3744,6 +4222,9 @@ impl EventType {
             EventType::Auth(AuthEvent::ClientRegistration) => "Authentication error",
             EventType::Auth(AuthEvent::Error) => "Authentication error",
             EventType::Auth(AuthEvent::CredentialExpired) => "Credential expired",
+            EventType::Auth(AuthEvent::ClientCertificateFailed) => {
+                "Client certificate authentication failed"
+            }
             EventType::Imap(ImapEvent::ConnectionStart) => "IMAP error",
             EventType::Imap(ImapEvent::ConnectionEnd) => "IMAP error",
             EventType::Imap(ImapEvent::GetAcl) => "IMAP error",
@@ -3753,6 +4234,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Append) => "IMAP error",
             EventType::Imap(ImapEvent::Capabilities) => "IMAP error",
             EventType::Imap(ImapEvent::Id) => "IMAP error",
+            EventType::Imap(ImapEvent::ClientRejected) => "IMAP error",
+            EventType::Imap(ImapEvent::ClientRestricted) => "IMAP error",
             EventType::Imap(ImapEvent::Close) => "IMAP error",
             EventType::Imap(ImapEvent::Copy) => "IMAP error",
             EventType::Imap(ImapEvent::Move) => "IMAP error",
@@ -3778,6 +4261,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe) => "IMAP error",
             EventType::Imap(ImapEvent::Thread) => "IMAP error",
             EventType::Imap(ImapEvent::GetQuota) => "IMAP error",
+            EventType::Imap(ImapEvent::GetMetadata) => "IMAP error",
+            EventType::Imap(ImapEvent::SetMetadata) => "IMAP error",
             EventType::Imap(ImapEvent::Error) => "IMAP error",
             EventType::Imap(ImapEvent::RawInput) => "IMAP error",
             EventType::Imap(ImapEvent::RawOutput) => "IMAP error",
@@ -3822,6 +4307,7 @@ impl EventType {
             EventType::ManageSieve(ManageSieveEvent::GetScript) => "ManageSieve error",
             EventType::ManageSieve(ManageSieveEvent::DeleteScript) => "ManageSieve error",
             EventType::ManageSieve(ManageSieveEvent::RenameScript) => "ManageSieve error",
+            EventType::ManageSieve(ManageSieveEvent::RollbackScript) => "ManageSieve error",
             EventType::ManageSieve(ManageSieveEvent::CheckScript) => "ManageSieve error",
             EventType::ManageSieve(ManageSieveEvent::HaveSpace) => "ManageSieve error",
             EventType::ManageSieve(ManageSieveEvent::ListScripts) => "ManageSieve error",
@@ -3845,6 +4331,7 @@ impl EventType {
             EventType::Network(NetworkEvent::SplitError) => "Network error",
             EventType::Network(NetworkEvent::Timeout) => "Network error",
             EventType::Network(NetworkEvent::Closed) => "Network error",
+            EventType::Network(NetworkEvent::SessionTerminated) => "Network error",
             EventType::Network(NetworkEvent::ProxyError) => "Network error",
             EventType::Network(NetworkEvent::SetOptError) => "Network error",
             EventType::Pop3(Pop3Event::ConnectionStart) => "POP3 error",
@@ -3870,9 +4357,11 @@ impl EventType {
             EventType::Resource(ResourceEvent::Error) => "Resource error",
             EventType::Resource(ResourceEvent::DownloadExternal) => "Other status",
             EventType::Security(SecurityEvent::AuthenticationBan) => "Insufficient permissions",
+            EventType::Security(SecurityEvent::AuthenticationDelayed) => "Authentication delayed",
             EventType::Security(SecurityEvent::AbuseBan) => "Insufficient permissions",
             EventType::Security(SecurityEvent::ScanBan) => "Insufficient permissions",
             EventType::Security(SecurityEvent::LoiterBan) => "Insufficient permissions",
+            EventType::Security(SecurityEvent::SmugglingBan) => "Insufficient permissions",
             EventType::Security(SecurityEvent::IpBlocked) => "Insufficient permissions",
             EventType::Security(SecurityEvent::IpBlockExpired) => "Insufficient permissions",
             EventType::Security(SecurityEvent::IpAllowExpired) => "Insufficient permissions",
@@ -3890,6 +4379,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MessageParseFailed) => "SMTP error",
             EventType::Smtp(SmtpEvent::MessageTooLarge) => "SMTP error",
             EventType::Smtp(SmtpEvent::LoopDetected) => "SMTP error",
+            EventType::Smtp(SmtpEvent::StructureLimitExceeded) => "SMTP error",
+            EventType::Smtp(SmtpEvent::BareLineEnding) => "SMTP error",
+            EventType::Smtp(SmtpEvent::SmugglingAttempt) => "SMTP error",
             EventType::Smtp(SmtpEvent::DkimPass) => "SMTP error",
             EventType::Smtp(SmtpEvent::DkimFail) => "SMTP error",
             EventType::Smtp(SmtpEvent::ArcPass) => "SMTP error",
@@ -3900,6 +4392,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail) => "SMTP error",
             EventType::Smtp(SmtpEvent::DmarcPass) => "SMTP error",
             EventType::Smtp(SmtpEvent::DmarcFail) => "SMTP error",
+            EventType::Smtp(SmtpEvent::DmarcOverride) => "SMTP error",
             EventType::Smtp(SmtpEvent::IprevPass) => "SMTP error",
             EventType::Smtp(SmtpEvent::IprevFail) => "SMTP error",
             EventType::Smtp(SmtpEvent::TooManyMessages) => "SMTP error",
@@ -3911,11 +4404,17 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MailFromUnauthenticated) => "SMTP error",
             EventType::Smtp(SmtpEvent::MailFromUnauthorized) => "SMTP error",
             EventType::Smtp(SmtpEvent::MailFromNotAllowed) => "SMTP error",
+            EventType::Smtp(SmtpEvent::TenantDomainMismatch) => "SMTP error",
+            EventType::Smtp(SmtpEvent::FromHeaderUnauthorized) => "SMTP error",
+            EventType::Smtp(SmtpEvent::FromHeaderRewritten) => "SMTP error",
+            EventType::Smtp(SmtpEvent::SenderMismatchTagged) => "SMTP error",
             EventType::Smtp(SmtpEvent::MailFromRewritten) => "SMTP error",
             EventType::Smtp(SmtpEvent::MailFromMissing) => "SMTP error",
             EventType::Smtp(SmtpEvent::MailFrom) => "SMTP error",
             EventType::Smtp(SmtpEvent::MultipleMailFrom) => "SMTP error",
             EventType::Smtp(SmtpEvent::MailboxDoesNotExist) => "SMTP error",
+            EventType::Smtp(SmtpEvent::CalloutRejected) => "SMTP error",
+            EventType::Smtp(SmtpEvent::CalloutFailed) => "SMTP error",
             EventType::Smtp(SmtpEvent::RelayNotAllowed) => "SMTP error",
             EventType::Smtp(SmtpEvent::RcptTo) => "SMTP error",
             EventType::Smtp(SmtpEvent::RcptToDuplicate) => "SMTP error",
@@ -3945,6 +4444,9 @@ impl EventType {
             EventType::Smtp(SmtpEvent::AuthMechanismNotSupported) => "SMTP error",
             EventType::Smtp(SmtpEvent::AuthExchangeTooLong) => "SMTP error",
             EventType::Smtp(SmtpEvent::AlreadyAuthenticated) => "SMTP error",
+            EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated) => {
+                "The client was authenticated using a TLS client certificate"
+            }
             EventType::Smtp(SmtpEvent::Noop) => "SMTP error",
             EventType::Smtp(SmtpEvent::StartTls) => "SMTP error",
             EventType::Smtp(SmtpEvent::StartTlsUnavailable) => "SMTP error",
@@ -3999,6 +4501,39 @@ impl EventType {
             EventType::Store(StoreEvent::LdapQuery) => "Store error",
             EventType::Store(StoreEvent::LdapWarning) => "Store error",
             EventType::Store(StoreEvent::HttpStoreFetch) => "Store error",
+            EventType::Store(StoreEvent::DataRegionMigrated) => {
+                "Account data migrated to another region"
+            }
+            EventType::Store(StoreEvent::ColdTierMigrated) => "Account blobs moved to cold storage",
+            EventType::Store(StoreEvent::ColdTierRestored) => {
+                "Account blobs restored from cold storage"
+            }
+            EventType::Store(StoreEvent::PartitionMigrated) => "Account data partitioned",
+            EventType::Store(StoreEvent::BlobMissing) => "Blob missing from the blob store",
+            EventType::Store(StoreEvent::BlobCorrupted) => "Blob contents are corrupted",
+            EventType::Store(StoreEvent::BlobMirrorFallback) => {
+                "Blob was read from the mirror store because the primary copy is unavailable"
+            }
+            EventType::Store(StoreEvent::BlobMirrorFailed) => {
+                "The blob mirror could not be updated, it will be repaired later"
+            }
+            EventType::Store(StoreEvent::BlobMirrorRepaired) => {
+                "A pending blob mirror operation was completed"
+            }
+            EventType::Store(StoreEvent::BlobMirrorQueueFull) => {
+                "The blob mirror repair queue is full, the operation was dropped"
+            }
+            EventType::Smtp(SmtpEvent::MailingListNotMember) => "Mailing list post rejected",
+            EventType::Smtp(SmtpEvent::MailingListDistributed) => {
+                "Mailing list message distributed"
+            }
+            EventType::Smtp(SmtpEvent::MailingListBounce) => "Mailing list bounce received",
+            EventType::Sieve(SieveEvent::NotifySent) => "Sieve notification sent",
+            EventType::Sieve(SieveEvent::NotifyFailed) => "Failed to send Sieve notification",
+            EventType::Store(StoreEvent::DataStoreChecked) => {
+                "Data store integrity check completed"
+            }
+            EventType::Store(StoreEvent::AccountPurged) => "Account data purged",
             _ => "Internal Server Error",
         }
     }
@@ -4042,6 +4577,8 @@ impl EventType {
             EventType::Auth(AuthEvent::Error),
             EventType::Auth(AuthEvent::Warning),
             EventType::Auth(AuthEvent::CredentialExpired),
+            EventType::Auth(AuthEvent::ClientCertificateSuccess),
+            EventType::Auth(AuthEvent::ClientCertificateFailed),
             EventType::Calendar(CalendarEvent::RuleExpansionError),
             EventType::Calendar(CalendarEvent::AlarmSent),
             EventType::Calendar(CalendarEvent::AlarmSkipped),
@@ -4167,6 +4704,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Append),
             EventType::Imap(ImapEvent::Capabilities),
             EventType::Imap(ImapEvent::Id),
+            EventType::Imap(ImapEvent::ClientRejected),
+            EventType::Imap(ImapEvent::ClientRestricted),
             EventType::Imap(ImapEvent::Close),
             EventType::Imap(ImapEvent::Copy),
             EventType::Imap(ImapEvent::Move),
@@ -4192,6 +4731,8 @@ impl EventType {
             EventType::Imap(ImapEvent::Unsubscribe),
             EventType::Imap(ImapEvent::Thread),
             EventType::Imap(ImapEvent::GetQuota),
+            EventType::Imap(ImapEvent::GetMetadata),
+            EventType::Imap(ImapEvent::SetMetadata),
             EventType::Imap(ImapEvent::Error),
             EventType::Imap(ImapEvent::RawInput),
             EventType::Imap(ImapEvent::RawOutput),
@@ -4246,7 +4787,9 @@ impl EventType {
             EventType::Limit(LimitEvent::Quota),
             EventType::Limit(LimitEvent::BlobQuota),
             EventType::Limit(LimitEvent::TenantQuota),
+            EventType::Limit(LimitEvent::DraftsQuota),
             EventType::Limit(LimitEvent::TooManyRequests),
+            EventType::Limit(LimitEvent::BandwidthThrottled),
             EventType::MailAuth(MailAuthEvent::ParseError),
             EventType::MailAuth(MailAuthEvent::MissingParameters),
             EventType::MailAuth(MailAuthEvent::NoHeadersFound),
@@ -4264,6 +4807,7 @@ impl EventType {
             EventType::ManageSieve(ManageSieveEvent::GetScript),
             EventType::ManageSieve(ManageSieveEvent::DeleteScript),
             EventType::ManageSieve(ManageSieveEvent::RenameScript),
+            EventType::ManageSieve(ManageSieveEvent::RollbackScript),
             EventType::ManageSieve(ManageSieveEvent::CheckScript),
             EventType::ManageSieve(ManageSieveEvent::HaveSpace),
             EventType::ManageSieve(ManageSieveEvent::ListScripts),
@@ -4282,7 +4826,9 @@ impl EventType {
             EventType::MessageIngest(MessageIngestEvent::JmapAppend),
             EventType::MessageIngest(MessageIngestEvent::Duplicate),
             EventType::MessageIngest(MessageIngestEvent::Error),
+            EventType::MessageIngest(MessageIngestEvent::MimeRepaired),
             EventType::MessageIngest(MessageIngestEvent::SearchIndex),
+            EventType::MessageIngest(MessageIngestEvent::SearchIndexTruncated),
             EventType::Milter(MilterEvent::Read),
             EventType::Milter(MilterEvent::Write),
             EventType::Milter(MilterEvent::ActionAccept),
@@ -4322,6 +4868,7 @@ impl EventType {
             EventType::Network(NetworkEvent::SplitError),
             EventType::Network(NetworkEvent::Timeout),
             EventType::Network(NetworkEvent::Closed),
+            EventType::Network(NetworkEvent::SessionTerminated),
             EventType::Network(NetworkEvent::ProxyError),
             EventType::Network(NetworkEvent::SetOptError),
             EventType::OutgoingReport(OutgoingReportEvent::SpfReport),
@@ -4391,13 +4938,16 @@ impl EventType {
             EventType::Resource(ResourceEvent::ApplicationUpdated),
             EventType::Resource(ResourceEvent::ApplicationUnpacked),
             EventType::Security(SecurityEvent::AuthenticationBan),
+            EventType::Security(SecurityEvent::AuthenticationDelayed),
             EventType::Security(SecurityEvent::AbuseBan),
             EventType::Security(SecurityEvent::ScanBan),
             EventType::Security(SecurityEvent::LoiterBan),
+            EventType::Security(SecurityEvent::SmugglingBan),
             EventType::Security(SecurityEvent::IpBlocked),
             EventType::Security(SecurityEvent::IpBlockExpired),
             EventType::Security(SecurityEvent::IpAllowExpired),
             EventType::Security(SecurityEvent::IpUnauthorized),
+            EventType::Security(SecurityEvent::NetworkPolicyBlocked),
             EventType::Security(SecurityEvent::Unauthorized),
             EventType::Server(ServerEvent::Startup),
             EventType::Server(ServerEvent::Shutdown),
@@ -4418,6 +4968,13 @@ impl EventType {
             EventType::Sieve(SieveEvent::UnexpectedError),
             EventType::Sieve(SieveEvent::NotSupported),
             EventType::Sieve(SieveEvent::QuotaExceeded),
+            EventType::Sieve(SieveEvent::CpuLimitExceeded),
+            EventType::Sieve(SieveEvent::TimeLimitExceeded),
+            EventType::Sieve(SieveEvent::RedirectLimitExceeded),
+            EventType::Sieve(SieveEvent::RedirectLoop),
+            EventType::Sieve(SieveEvent::NotifyLimitExceeded),
+            EventType::Sieve(SieveEvent::NotifySent),
+            EventType::Sieve(SieveEvent::NotifyFailed),
             EventType::Smtp(SmtpEvent::ConnectionStart),
             EventType::Smtp(SmtpEvent::ConnectionEnd),
             EventType::Smtp(SmtpEvent::Error),
@@ -4429,7 +4986,11 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MissingAuthDirectory),
             EventType::Smtp(SmtpEvent::MessageParseFailed),
             EventType::Smtp(SmtpEvent::MessageTooLarge),
+            EventType::Smtp(SmtpEvent::LargeMessage),
             EventType::Smtp(SmtpEvent::LoopDetected),
+            EventType::Smtp(SmtpEvent::StructureLimitExceeded),
+            EventType::Smtp(SmtpEvent::BareLineEnding),
+            EventType::Smtp(SmtpEvent::SmugglingAttempt),
             EventType::Smtp(SmtpEvent::DkimPass),
             EventType::Smtp(SmtpEvent::DkimFail),
             EventType::Smtp(SmtpEvent::ArcPass),
@@ -4440,6 +5001,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::SpfFromFail),
             EventType::Smtp(SmtpEvent::DmarcPass),
             EventType::Smtp(SmtpEvent::DmarcFail),
+            EventType::Smtp(SmtpEvent::DmarcOverride),
             EventType::Smtp(SmtpEvent::IprevPass),
             EventType::Smtp(SmtpEvent::IprevFail),
             EventType::Smtp(SmtpEvent::TooManyMessages),
@@ -4451,11 +5013,17 @@ impl EventType {
             EventType::Smtp(SmtpEvent::MailFromUnauthenticated),
             EventType::Smtp(SmtpEvent::MailFromUnauthorized),
             EventType::Smtp(SmtpEvent::MailFromNotAllowed),
+            EventType::Smtp(SmtpEvent::TenantDomainMismatch),
+            EventType::Smtp(SmtpEvent::FromHeaderUnauthorized),
+            EventType::Smtp(SmtpEvent::FromHeaderRewritten),
+            EventType::Smtp(SmtpEvent::SenderMismatchTagged),
             EventType::Smtp(SmtpEvent::MailFromRewritten),
             EventType::Smtp(SmtpEvent::MailFromMissing),
             EventType::Smtp(SmtpEvent::MailFrom),
             EventType::Smtp(SmtpEvent::MultipleMailFrom),
             EventType::Smtp(SmtpEvent::MailboxDoesNotExist),
+            EventType::Smtp(SmtpEvent::CalloutRejected),
+            EventType::Smtp(SmtpEvent::CalloutFailed),
             EventType::Smtp(SmtpEvent::RelayNotAllowed),
             EventType::Smtp(SmtpEvent::RcptTo),
             EventType::Smtp(SmtpEvent::RcptToDuplicate),
@@ -4470,9 +5038,14 @@ impl EventType {
             EventType::Smtp(SmtpEvent::Vrfy),
             EventType::Smtp(SmtpEvent::VrfyNotFound),
             EventType::Smtp(SmtpEvent::VrfyDisabled),
+            EventType::Smtp(SmtpEvent::VrfyRateLimitExceeded),
             EventType::Smtp(SmtpEvent::Expn),
             EventType::Smtp(SmtpEvent::ExpnNotFound),
             EventType::Smtp(SmtpEvent::ExpnDisabled),
+            EventType::Smtp(SmtpEvent::ExpnRateLimitExceeded),
+            EventType::Smtp(SmtpEvent::MailingListNotMember),
+            EventType::Smtp(SmtpEvent::MailingListDistributed),
+            EventType::Smtp(SmtpEvent::MailingListBounce),
             EventType::Smtp(SmtpEvent::RequireTlsDisabled),
             EventType::Smtp(SmtpEvent::DeliverByDisabled),
             EventType::Smtp(SmtpEvent::DeliverByInvalid),
@@ -4485,6 +5058,7 @@ impl EventType {
             EventType::Smtp(SmtpEvent::AuthMechanismNotSupported),
             EventType::Smtp(SmtpEvent::AuthExchangeTooLong),
             EventType::Smtp(SmtpEvent::AlreadyAuthenticated),
+            EventType::Smtp(SmtpEvent::ClientCertificateAuthenticated),
             EventType::Smtp(SmtpEvent::Noop),
             EventType::Smtp(SmtpEvent::StartTls),
             EventType::Smtp(SmtpEvent::StartTlsUnavailable),
@@ -4513,6 +5087,9 @@ impl EventType {
             EventType::Spam(SpamEvent::ModelNotReady),
             EventType::Spam(SpamEvent::ModelNotFound),
             EventType::Spam(SpamEvent::RulesUpdated),
+            EventType::Spam(SpamEvent::MessageQuarantined),
+            EventType::Spam(SpamEvent::MessageReleased),
+            EventType::Spam(SpamEvent::QuarantineDigestSent),
             EventType::Spf(SpfEvent::Pass),
             EventType::Spf(SpfEvent::Fail),
             EventType::Spf(SpfEvent::SoftFail),
@@ -4558,8 +5135,28 @@ impl EventType {
             EventType::Store(StoreEvent::LdapWarning),
             EventType::Store(StoreEvent::HttpStoreFetch),
             EventType::Store(StoreEvent::AutoExpunge),
+            EventType::Store(StoreEvent::AutoArchive),
             EventType::Store(StoreEvent::BlobStorePurged),
             EventType::Store(StoreEvent::DataStorePurged),
+            EventType::Store(StoreEvent::DataStoreCompacted),
+            EventType::Store(StoreEvent::DataStoreFlushed),
+            EventType::Store(StoreEvent::MailStoreImported),
+            EventType::Store(StoreEvent::AccountExported),
+            EventType::Store(StoreEvent::AccountImported),
+            EventType::Store(StoreEvent::AccountPurged),
+            EventType::Store(StoreEvent::DataRegionMigrated),
+            EventType::Store(StoreEvent::ColdTierMigrated),
+            EventType::Store(StoreEvent::ColdTierRestored),
+            EventType::Store(StoreEvent::PartitionMigrated),
+            EventType::Store(StoreEvent::BlobMissing),
+            EventType::Store(StoreEvent::BlobCorrupted),
+            EventType::Store(StoreEvent::BlobRepaired),
+            EventType::Store(StoreEvent::BlobStoreScrubbed),
+            EventType::Store(StoreEvent::DataStoreChecked),
+            EventType::Store(StoreEvent::BlobMirrorFallback),
+            EventType::Store(StoreEvent::BlobMirrorFailed),
+            EventType::Store(StoreEvent::BlobMirrorRepaired),
+            EventType::Store(StoreEvent::BlobMirrorQueueFull),
             EventType::TaskManager(TaskManagerEvent::TaskAcquired),
             EventType::TaskManager(TaskManagerEvent::TaskQueued),
             EventType::TaskManager(TaskManagerEvent::TaskScheduled),
@@ -4571,6 +5168,9 @@ impl EventType {
             EventType::TaskManager(TaskManagerEvent::MetadataNotFound),
             EventType::TaskManager(TaskManagerEvent::SchedulerStarted),
             EventType::TaskManager(TaskManagerEvent::ManagerStarted),
+            EventType::TaskManager(TaskManagerEvent::ReindexProgress),
+            EventType::TaskManager(TaskManagerEvent::ReindexCompleted),
+            EventType::TaskManager(TaskManagerEvent::TaskCancelled),
             EventType::Telemetry(TelemetryEvent::AlertEvent),
             EventType::Telemetry(TelemetryEvent::AlertMessage),
             EventType::Telemetry(TelemetryEvent::LogError),
@@ -4592,6 +5192,7 @@ impl EventType {
             EventType::TlsRpt(TlsRptEvent::RecordFetch),
             EventType::TlsRpt(TlsRptEvent::RecordFetchError),
             EventType::TlsRpt(TlsRptEvent::RecordNotFound),
+            EventType::TlsRpt(TlsRptEvent::InboundFailure),
             EventType::WebDav(WebDavEvent::Propfind),
             EventType::WebDav(WebDavEvent::Proppatch),
             EventType::WebDav(WebDavEvent::Get),
@@ -4842,6 +5443,7 @@ impl MetricType {
             b"security.abuse-ban" => MetricType::SecurityAbuseBan,
             b"security.scan-ban" => MetricType::SecurityScanBan,
             b"security.loiter-ban" => MetricType::SecurityLoiterBan,
+            b"security.smuggling-ban" => MetricType::SecuritySmugglingBan,
             b"security.ip-blocked" => MetricType::SecurityIpBlocked,
             b"security.unauthorized" => MetricType::SecurityUnauthorized,
             b"server.memory" => MetricType::ServerMemory,
@@ -4870,6 +5472,8 @@ impl MetricType {
             b"smtp.message-parse-failed" => MetricType::SmtpMessageParseFailed,
             b"smtp.message-too-large" => MetricType::SmtpMessageTooLarge,
             b"smtp.loop-detected" => MetricType::SmtpLoopDetected,
+            b"smtp.bare-line-ending" => MetricType::SmtpBareLineEnding,
+            b"smtp.smuggling-attempt" => MetricType::SmtpSmugglingAttempt,
             b"smtp.dkim-pass" => MetricType::SmtpDkimPass,
             b"smtp.dkim-fail" => MetricType::SmtpDkimFail,
             b"smtp.arc-pass" => MetricType::SmtpArcPass,
@@ -5197,6 +5801,7 @@ impl MetricType {
             MetricType::SecurityAbuseBan => "security.abuse-ban",
             MetricType::SecurityScanBan => "security.scan-ban",
             MetricType::SecurityLoiterBan => "security.loiter-ban",
+            MetricType::SecuritySmugglingBan => "security.smuggling-ban",
             MetricType::SecurityIpBlocked => "security.ip-blocked",
             MetricType::SecurityUnauthorized => "security.unauthorized",
             MetricType::ServerMemory => "server.memory",
@@ -5225,6 +5830,8 @@ impl MetricType {
             MetricType::SmtpMessageParseFailed => "smtp.message-parse-failed",
             MetricType::SmtpMessageTooLarge => "smtp.message-too-large",
             MetricType::SmtpLoopDetected => "smtp.loop-detected",
+            MetricType::SmtpBareLineEnding => "smtp.bare-line-ending",
+            MetricType::SmtpSmugglingAttempt => "smtp.smuggling-attempt",
             MetricType::SmtpDkimPass => "smtp.dkim-pass",
             MetricType::SmtpDkimFail => "smtp.dkim-fail",
             MetricType::SmtpArcPass => "smtp.arc-pass",
@@ -5543,6 +6150,7 @@ impl MetricType {
             MetricType::SecurityAbuseBan => 230,
             MetricType::SecurityScanBan => 231,
             MetricType::SecurityLoiterBan => 232,
+            MetricType::SecuritySmugglingBan => 339,
             MetricType::SecurityIpBlocked => 233,
             MetricType::SecurityUnauthorized => 234,
             MetricType::ServerMemory => 23,
@@ -5571,6 +6179,8 @@ impl MetricType {
             MetricType::SmtpMessageParseFailed => 253,
             MetricType::SmtpMessageTooLarge => 254,
             MetricType::SmtpLoopDetected => 255,
+            MetricType::SmtpBareLineEnding => 340,
+            MetricType::SmtpSmugglingAttempt => 341,
             MetricType::SmtpDkimPass => 256,
             MetricType::SmtpDkimFail => 257,
             MetricType::SmtpArcPass => 258,
@@ -5887,6 +6497,7 @@ impl MetricType {
             230 => Some(MetricType::SecurityAbuseBan),
             231 => Some(MetricType::SecurityScanBan),
             232 => Some(MetricType::SecurityLoiterBan),
+            339 => Some(MetricType::SecuritySmugglingBan),
             233 => Some(MetricType::SecurityIpBlocked),
             234 => Some(MetricType::SecurityUnauthorized),
             23 => Some(MetricType::ServerMemory),
@@ -5915,6 +6526,8 @@ impl MetricType {
             253 => Some(MetricType::SmtpMessageParseFailed),
             254 => Some(MetricType::SmtpMessageTooLarge),
             255 => Some(MetricType::SmtpLoopDetected),
+            340 => Some(MetricType::SmtpBareLineEnding),
+            341 => Some(MetricType::SmtpSmugglingAttempt),
             256 => Some(MetricType::SmtpDkimPass),
             257 => Some(MetricType::SmtpDkimFail),
             258 => Some(MetricType::SmtpArcPass),
@@ -6009,318 +6622,321 @@ impl MetricType {
 
     pub fn event_id(&self) -> usize {
         match self {
-            MetricType::AcmeAuthError => 1,
-            MetricType::AcmeAuthTooManyAttempts => 4,
-            MetricType::AcmeOrderCompleted => 16,
-            MetricType::AcmeOrderInvalid => 17,
-            MetricType::AcmeClientMissingSni => 6,
-            MetricType::AcmeTlsAlpnError => 24,
-            MetricType::AcmeTokenNotFound => 26,
-            MetricType::AcmeError => 15,
-            MetricType::ArcChainTooLong => 28,
-            MetricType::ArcInvalidInstance => 31,
-            MetricType::ArcInvalidCv => 30,
-            MetricType::ArcHasHeaderTag => 29,
-            MetricType::ArcBrokenChain => 27,
-            MetricType::AuthSuccess => 37,
-            MetricType::AuthFailed => 35,
-            MetricType::AuthTooManyAttempts => 38,
-            MetricType::AuthError => 34,
-            MetricType::CalendarAlarmSent => 579,
-            MetricType::CalendarAlarmFailed => 582,
-            MetricType::CalendarItipMessageSent => 583,
-            MetricType::CalendarItipMessageReceived => 584,
-            MetricType::CalendarItipMessageError => 585,
-            MetricType::ClusterSubscriberError => 41,
-            MetricType::ClusterSubscriberDisconnected => 42,
-            MetricType::ClusterPublisherError => 45,
-            MetricType::DaneAuthenticationSuccess => 67,
-            MetricType::DaneAuthenticationFailure => 66,
-            MetricType::DaneNoCertificatesFound => 69,
-            MetricType::DaneCertificateParseError => 68,
-            MetricType::DaneTlsaRecordFetchError => 71,
-            MetricType::DaneTlsaRecordNotFound => 75,
-            MetricType::DaneTlsaRecordNotDnssecSigned => 74,
-            MetricType::DaneTlsaRecordInvalid => 72,
-            MetricType::DeliveryAttemptStart => 77,
-            MetricType::DeliveryAttemptEnd => 76,
-            MetricType::DeliveryCompleted => 80,
-            MetricType::DeliveryMxLookupFailed => 102,
-            MetricType::DeliveryIpLookupFailed => 96,
-            MetricType::DeliveryNullMx => 103,
-            MetricType::DeliveryGreetingFailed => 93,
-            MetricType::DeliveryEhloRejected => 91,
-            MetricType::DeliveryAuthFailed => 79,
-            MetricType::DeliveryMailFromRejected => 98,
-            MetricType::DeliveryDelivered => 84,
-            MetricType::DeliveryRcptToRejected => 109,
-            MetricType::DeliveryRcptToFailed => 108,
-            MetricType::DeliveryMessageRejected => 99,
-            MetricType::DeliveryStartTlsUnavailable => 113,
-            MetricType::DeliveryStartTlsError => 112,
-            MetricType::DeliveryStartTlsDisabled => 111,
-            MetricType::DeliveryImplicitTlsError => 94,
-            MetricType::DeliveryConcurrencyLimitExceeded => 81,
-            MetricType::DeliveryRateLimitExceeded => 104,
-            MetricType::DeliveryDoubleBounce => 86,
-            MetricType::DeliveryDsnSuccess => 88,
-            MetricType::DeliveryDsnTempFail => 89,
+            MetricType::AcmeAuthError => 27,
+            MetricType::AcmeAuthTooManyAttempts => 28,
+            MetricType::AcmeOrderCompleted => 29,
+            MetricType::AcmeOrderInvalid => 30,
+            MetricType::AcmeClientMissingSni => 31,
+            MetricType::AcmeTlsAlpnError => 32,
+            MetricType::AcmeTokenNotFound => 33,
+            MetricType::AcmeError => 34,
+            MetricType::ArcChainTooLong => 39,
+            MetricType::ArcInvalidInstance => 40,
+            MetricType::ArcInvalidCv => 41,
+            MetricType::ArcHasHeaderTag => 42,
+            MetricType::ArcBrokenChain => 43,
+            MetricType::AuthSuccess => 44,
+            MetricType::AuthFailed => 45,
+            MetricType::AuthTooManyAttempts => 46,
+            MetricType::AuthError => 47,
+            MetricType::CalendarAlarmSent => 48,
+            MetricType::CalendarAlarmFailed => 49,
+            MetricType::CalendarItipMessageSent => 50,
+            MetricType::CalendarItipMessageReceived => 51,
+            MetricType::CalendarItipMessageError => 52,
+            MetricType::ClusterSubscriberError => 53,
+            MetricType::ClusterSubscriberDisconnected => 54,
+            MetricType::ClusterPublisherError => 55,
+            MetricType::DaneAuthenticationSuccess => 56,
+            MetricType::DaneAuthenticationFailure => 57,
+            MetricType::DaneNoCertificatesFound => 58,
+            MetricType::DaneCertificateParseError => 59,
+            MetricType::DaneTlsaRecordFetchError => 60,
+            MetricType::DaneTlsaRecordNotFound => 61,
+            MetricType::DaneTlsaRecordNotDnssecSigned => 62,
+            MetricType::DaneTlsaRecordInvalid => 63,
+            MetricType::DeliveryAttemptStart => 64,
+            MetricType::DeliveryAttemptEnd => 65,
+            MetricType::DeliveryCompleted => 66,
+            MetricType::DeliveryMxLookupFailed => 67,
+            MetricType::DeliveryIpLookupFailed => 68,
+            MetricType::DeliveryNullMx => 69,
+            MetricType::DeliveryGreetingFailed => 70,
+            MetricType::DeliveryEhloRejected => 71,
+            MetricType::DeliveryAuthFailed => 72,
+            MetricType::DeliveryMailFromRejected => 73,
+            MetricType::DeliveryDelivered => 74,
+            MetricType::DeliveryRcptToRejected => 75,
+            MetricType::DeliveryRcptToFailed => 76,
+            MetricType::DeliveryMessageRejected => 77,
+            MetricType::DeliveryStartTlsUnavailable => 78,
+            MetricType::DeliveryStartTlsError => 79,
+            MetricType::DeliveryStartTlsDisabled => 80,
+            MetricType::DeliveryImplicitTlsError => 81,
+            MetricType::DeliveryConcurrencyLimitExceeded => 82,
+            MetricType::DeliveryRateLimitExceeded => 83,
+            MetricType::DeliveryDoubleBounce => 84,
+            MetricType::DeliveryDsnSuccess => 85,
+            MetricType::DeliveryDsnTempFail => 86,
             MetricType::DeliveryDsnPermFail => 87,
-            MetricType::DkimPass => 121,
-            MetricType::DkimNeutral => 119,
-            MetricType::DkimFail => 114,
-            MetricType::DkimPermError => 122,
-            MetricType::DkimTempError => 127,
-            MetricType::DkimNone => 120,
-            MetricType::DkimUnsupportedVersion => 131,
-            MetricType::DkimUnsupportedAlgorithm => 128,
-            MetricType::DkimUnsupportedCanonicalization => 129,
-            MetricType::DkimUnsupportedKeyType => 130,
-            MetricType::DkimFailedBodyHashMatch => 116,
-            MetricType::DkimFailedVerification => 117,
-            MetricType::DkimFailedAuidMatch => 115,
-            MetricType::DkimRevokedPublicKey => 123,
-            MetricType::DkimIncompatibleAlgorithms => 118,
-            MetricType::DkimSignatureExpired => 124,
-            MetricType::DkimSignatureLength => 125,
-            MetricType::DkimSignerNotFound => 126,
-            MetricType::DmarcPass => 134,
-            MetricType::DmarcFail => 132,
-            MetricType::DmarcPermError => 135,
-            MetricType::DmarcTempError => 136,
-            MetricType::DmarcNone => 133,
-            MetricType::DnsRecordCreationFailed => 9,
-            MetricType::DnsRecordDeletionFailed => 10,
-            MetricType::DnsRecordLookupFailed => 11,
-            MetricType::DnsRecordPropagationTimeout => 14,
-            MetricType::EvalError => 138,
-            MetricType::EvalDirectoryNotFound => 137,
-            MetricType::EvalStoreNotFound => 140,
-            MetricType::HttpConnectionStart => 153,
-            MetricType::HttpError => 154,
-            MetricType::HttpRequestBody => 155,
-            MetricType::HttpResponseBody => 157,
-            MetricType::HttpXForwardedMissing => 158,
-            MetricType::ImapConnectionStart => 163,
-            MetricType::ImapConnectionEnd => 162,
-            MetricType::IncomingReportDmarcReport => 200,
-            MetricType::IncomingReportDmarcReportWithWarnings => 201,
-            MetricType::IncomingReportTlsReport => 206,
-            MetricType::IncomingReportTlsReportWithWarnings => 207,
-            MetricType::IncomingReportAbuseReport => 195,
-            MetricType::IncomingReportAuthFailureReport => 197,
-            MetricType::IncomingReportFraudReport => 202,
-            MetricType::IncomingReportNotSpamReport => 204,
-            MetricType::IncomingReportVirusReport => 209,
-            MetricType::IncomingReportOtherReport => 205,
-            MetricType::IncomingReportMessageParseFailed => 203,
-            MetricType::IncomingReportDmarcParseFailed => 199,
-            MetricType::IncomingReportTlsRpcParseFailed => 208,
-            MetricType::IncomingReportArfParseFailed => 196,
-            MetricType::IncomingReportDecompressError => 198,
-            MetricType::IprevPass => 212,
-            MetricType::IprevFail => 210,
-            MetricType::IprevPermError => 213,
-            MetricType::IprevTempError => 214,
-            MetricType::IprevNone => 211,
-            MetricType::JmapMethodCall => 223,
-            MetricType::JmapInvalidArguments => 221,
-            MetricType::JmapRequestTooLarge => 227,
-            MetricType::JmapUnsupportedFilter => 232,
-            MetricType::JmapUnsupportedSort => 233,
-            MetricType::JmapUnknownMethod => 231,
-            MetricType::JmapForbidden => 220,
-            MetricType::JmapNotJson => 225,
-            MetricType::JmapNotRequest => 226,
-            MetricType::JmapWebsocketStart => 235,
-            MetricType::JmapWebsocketError => 234,
-            MetricType::LimitSizeRequest => 243,
-            MetricType::LimitSizeUpload => 244,
-            MetricType::LimitCallsIn => 238,
-            MetricType::LimitConcurrentRequest => 240,
-            MetricType::LimitConcurrentUpload => 241,
-            MetricType::LimitConcurrentConnection => 239,
-            MetricType::LimitQuota => 242,
-            MetricType::LimitBlobQuota => 237,
-            MetricType::LimitTenantQuota => 553,
-            MetricType::LimitTooManyRequests => 245,
-            MetricType::MailAuthParseError => 254,
-            MetricType::MailAuthMissingParameters => 252,
-            MetricType::MailAuthNoHeadersFound => 253,
-            MetricType::MailAuthCrypto => 247,
-            MetricType::MailAuthIo => 251,
-            MetricType::MailAuthBase64 => 246,
-            MetricType::MailAuthDnsError => 248,
-            MetricType::MailAuthDnsRecordNotFound => 250,
-            MetricType::MailAuthDnsInvalidRecordType => 249,
-            MetricType::MailAuthPolicyNotAligned => 255,
-            MetricType::ManageSieveConnectionStart => 259,
-            MetricType::ManageSieveConnectionEnd => 258,
-            MetricType::MessageIngestHam => 283,
-            MetricType::MessageIngestSpam => 286,
-            MetricType::MessageIngestImapAppend => 284,
-            MetricType::MessageIngestJmapAppend => 285,
-            MetricType::MessageIngestDuplicate => 281,
-            MetricType::MessageIngestError => 282,
-            MetricType::MessageIngestSearchIndex => 142,
-            MetricType::MilterActionAccept => 287,
-            MetricType::MilterActionDiscard => 289,
-            MetricType::MilterActionReject => 290,
-            MetricType::MilterActionTempFail => 293,
-            MetricType::MilterActionReplyCode => 291,
-            MetricType::MilterActionConnectionFailure => 288,
-            MetricType::MilterActionShutdown => 292,
-            MetricType::MtaHookActionAccept => 304,
-            MetricType::MtaHookActionDiscard => 305,
-            MetricType::MtaHookActionReject => 307,
-            MetricType::MtaHookActionQuarantine => 306,
-            MetricType::MtaHookError => 308,
-            MetricType::MtaStsAuthorized => 309,
-            MetricType::MtaStsNotAuthorized => 311,
-            MetricType::MtaStsInvalidPolicy => 310,
-            MetricType::NetworkTimeout => 327,
-            MetricType::OutgoingReportSpfReport => 342,
-            MetricType::OutgoingReportSpfRateLimited => 341,
-            MetricType::OutgoingReportDkimReport => 330,
-            MetricType::OutgoingReportDkimRateLimited => 329,
-            MetricType::OutgoingReportDmarcReport => 333,
-            MetricType::OutgoingReportDmarcRateLimited => 332,
-            MetricType::OutgoingReportDmarcAggregateReport => 331,
-            MetricType::OutgoingReportTlsAggregate => 344,
-            MetricType::OutgoingReportHttpSubmission => 334,
-            MetricType::OutgoingReportUnauthorizedReportingAddress => 345,
-            MetricType::OutgoingReportReportingAddressValidationError => 340,
-            MetricType::OutgoingReportNotFound => 339,
-            MetricType::OutgoingReportSubmissionError => 343,
-            MetricType::OutgoingReportNoRecipientsFound => 338,
-            MetricType::Pop3ConnectionStart => 348,
-            MetricType::Pop3ConnectionEnd => 347,
-            MetricType::PushSubscriptionSuccess => 373,
-            MetricType::PushSubscriptionError => 371,
-            MetricType::PushSubscriptionNotFound => 372,
-            MetricType::QueueMessageQueued => 380,
-            MetricType::QueueAuthenticatedMessageQueued => 381,
-            MetricType::QueueReportQueued => 382,
-            MetricType::QueueDsnQueued => 379,
-            MetricType::QueueAutogeneratedQueued => 378,
-            MetricType::QueueRescheduled => 385,
-            MetricType::QueueBlobNotFound => 374,
-            MetricType::QueueRateLimitExceeded => 384,
-            MetricType::QueueConcurrencyLimitExceeded => 375,
-            MetricType::QueueQuotaExceeded => 383,
-            MetricType::ResourceNotFound => 389,
-            MetricType::ResourceBadParameters => 386,
-            MetricType::ResourceError => 388,
-            MetricType::SecurityAuthenticationBan => 33,
-            MetricType::SecurityAbuseBan => 549,
-            MetricType::SecurityScanBan => 558,
-            MetricType::SecurityLoiterBan => 550,
-            MetricType::SecurityIpBlocked => 318,
-            MetricType::SecurityUnauthorized => 552,
-            MetricType::ServerThreadError => 395,
-            MetricType::SieveActionAccept => 396,
-            MetricType::SieveActionAcceptReplace => 397,
-            MetricType::SieveActionDiscard => 398,
-            MetricType::SieveActionReject => 399,
-            MetricType::SieveSendMessage => 406,
-            MetricType::SieveMessageTooLarge => 401,
-            MetricType::SieveRuntimeError => 404,
-            MetricType::SieveUnexpectedError => 407,
-            MetricType::SieveNotSupported => 402,
-            MetricType::SieveQuotaExceeded => 403,
-            MetricType::SmtpConnectionStart => 417,
-            MetricType::SmtpConnectionEnd => 416,
-            MetricType::SmtpError => 428,
-            MetricType::SmtpConcurrencyLimitExceeded => 415,
-            MetricType::SmtpTransferLimitExceeded => 485,
-            MetricType::SmtpRateLimitExceeded => 461,
-            MetricType::SmtpTimeLimitExceeded => 481,
-            MetricType::SmtpMessageParseFailed => 450,
-            MetricType::SmtpMessageTooLarge => 451,
-            MetricType::SmtpLoopDetected => 443,
-            MetricType::SmtpDkimPass => 422,
-            MetricType::SmtpDkimFail => 421,
-            MetricType::SmtpArcPass => 410,
-            MetricType::SmtpArcFail => 409,
-            MetricType::SmtpSpfEhloPass => 474,
-            MetricType::SmtpSpfEhloFail => 473,
-            MetricType::SmtpSpfFromPass => 476,
-            MetricType::SmtpSpfFromFail => 475,
-            MetricType::SmtpDmarcPass => 424,
-            MetricType::SmtpDmarcFail => 423,
-            MetricType::SmtpIprevPass => 441,
-            MetricType::SmtpIprevFail => 440,
-            MetricType::SmtpTooManyMessages => 483,
-            MetricType::SmtpInvalidEhlo => 436,
-            MetricType::SmtpDidNotSayEhlo => 420,
-            MetricType::SmtpMailFromUnauthenticated => 447,
-            MetricType::SmtpMailFromUnauthorized => 448,
-            MetricType::SmtpMailFromMissing => 445,
-            MetricType::SmtpMultipleMailFrom => 456,
-            MetricType::SmtpMailboxDoesNotExist => 449,
-            MetricType::SmtpRelayNotAllowed => 468,
-            MetricType::SmtpRcptToDuplicate => 465,
-            MetricType::SmtpRcptToMissing => 466,
-            MetricType::SmtpTooManyRecipients => 484,
-            MetricType::SmtpTooManyInvalidRcpt => 482,
-            MetricType::SmtpAuthMechanismNotSupported => 412,
-            MetricType::SmtpAuthExchangeTooLong => 411,
-            MetricType::SmtpCommandNotImplemented => 414,
-            MetricType::SmtpInvalidCommand => 435,
-            MetricType::SmtpSyntaxError => 480,
-            MetricType::SmtpRequestTooLarge => 470,
-            MetricType::SpamPyzorError => 494,
-            MetricType::SpamDnsblError => 563,
-            MetricType::SpamTrainCompleted => 495,
-            MetricType::SpamTrainSampleAdded => 143,
-            MetricType::SpamClassify => 490,
-            MetricType::SpamModelNotReady => 496,
-            MetricType::SpfPass => 501,
-            MetricType::SpfFail => 498,
-            MetricType::SpfSoftFail => 503,
-            MetricType::SpfNeutral => 499,
-            MetricType::SpfTempError => 504,
-            MetricType::SpfPermError => 502,
-            MetricType::SpfNone => 500,
-            MetricType::StoreAssertValueFailed => 505,
-            MetricType::StoreFoundationdbError => 518,
-            MetricType::StoreMysqlError => 522,
-            MetricType::StorePostgresqlError => 527,
-            MetricType::StoreRocksdbError => 529,
-            MetricType::StoreSqliteError => 532,
-            MetricType::StoreLdapError => 520,
-            MetricType::StoreElasticsearchError => 516,
-            MetricType::StoreRedisError => 528,
-            MetricType::StoreS3Error => 530,
-            MetricType::StoreAzureError => 559,
-            MetricType::StoreFilesystemError => 517,
-            MetricType::StorePoolError => 526,
-            MetricType::StoreDataCorruption => 511,
-            MetricType::StoreDecompressError => 514,
-            MetricType::StoreDeserializeError => 515,
-            MetricType::StoreNotFound => 524,
-            MetricType::StoreNotConfigured => 523,
-            MetricType::StoreNotSupported => 525,
-            MetricType::StoreUnexpectedError => 533,
-            MetricType::StoreCryptoError => 510,
-            MetricType::StoreHttpStoreError => 493,
-            MetricType::StoreBlobMissingMarker => 507,
-            MetricType::StoreDataWrite => 513,
-            MetricType::StoreDataIterate => 512,
-            MetricType::StoreBlobRead => 508,
-            MetricType::StoreBlobWrite => 509,
-            MetricType::StoreBlobDelete => 506,
-            MetricType::TaskManagerBlobNotFound => 141,
-            MetricType::TaskManagerMetadataNotFound => 145,
-            MetricType::TelemetryAlertEvent => 548,
-            MetricType::TelemetryAlertMessage => 365,
-            MetricType::TelemetryLogError => 535,
-            MetricType::TelemetryWebhookError => 539,
-            MetricType::TelemetryOtelExporterError => 536,
-            MetricType::TelemetryOtelMetricsExporterError => 537,
-            MetricType::TelemetryPrometheusExporterError => 538,
-            MetricType::TelemetryJournalError => 534,
-            MetricType::TlsHandshakeError => 544,
+            MetricType::DkimPass => 88,
+            MetricType::DkimNeutral => 89,
+            MetricType::DkimFail => 90,
+            MetricType::DkimPermError => 91,
+            MetricType::DkimTempError => 92,
+            MetricType::DkimNone => 93,
+            MetricType::DkimUnsupportedVersion => 94,
+            MetricType::DkimUnsupportedAlgorithm => 95,
+            MetricType::DkimUnsupportedCanonicalization => 96,
+            MetricType::DkimUnsupportedKeyType => 97,
+            MetricType::DkimFailedBodyHashMatch => 98,
+            MetricType::DkimFailedVerification => 99,
+            MetricType::DkimFailedAuidMatch => 100,
+            MetricType::DkimRevokedPublicKey => 101,
+            MetricType::DkimIncompatibleAlgorithms => 102,
+            MetricType::DkimSignatureExpired => 103,
+            MetricType::DkimSignatureLength => 104,
+            MetricType::DkimSignerNotFound => 105,
+            MetricType::DmarcPass => 106,
+            MetricType::DmarcFail => 107,
+            MetricType::DmarcPermError => 108,
+            MetricType::DmarcTempError => 109,
+            MetricType::DmarcNone => 110,
+            MetricType::DnsRecordCreationFailed => 35,
+            MetricType::DnsRecordDeletionFailed => 36,
+            MetricType::DnsRecordLookupFailed => 37,
+            MetricType::DnsRecordPropagationTimeout => 38,
+            MetricType::EvalError => 111,
+            MetricType::EvalDirectoryNotFound => 112,
+            MetricType::EvalStoreNotFound => 113,
+            MetricType::HttpConnectionStart => 337,
+            MetricType::HttpError => 114,
+            MetricType::HttpRequestBody => 115,
+            MetricType::HttpResponseBody => 116,
+            MetricType::HttpXForwardedMissing => 117,
+            MetricType::ImapConnectionStart => 118,
+            MetricType::ImapConnectionEnd => 119,
+            MetricType::IncomingReportDmarcReport => 120,
+            MetricType::IncomingReportDmarcReportWithWarnings => 121,
+            MetricType::IncomingReportTlsReport => 122,
+            MetricType::IncomingReportTlsReportWithWarnings => 123,
+            MetricType::IncomingReportAbuseReport => 124,
+            MetricType::IncomingReportAuthFailureReport => 125,
+            MetricType::IncomingReportFraudReport => 126,
+            MetricType::IncomingReportNotSpamReport => 127,
+            MetricType::IncomingReportVirusReport => 128,
+            MetricType::IncomingReportOtherReport => 129,
+            MetricType::IncomingReportMessageParseFailed => 130,
+            MetricType::IncomingReportDmarcParseFailed => 131,
+            MetricType::IncomingReportTlsRpcParseFailed => 132,
+            MetricType::IncomingReportArfParseFailed => 133,
+            MetricType::IncomingReportDecompressError => 134,
+            MetricType::IprevPass => 135,
+            MetricType::IprevFail => 136,
+            MetricType::IprevPermError => 137,
+            MetricType::IprevTempError => 138,
+            MetricType::IprevNone => 139,
+            MetricType::JmapMethodCall => 140,
+            MetricType::JmapInvalidArguments => 141,
+            MetricType::JmapRequestTooLarge => 142,
+            MetricType::JmapUnsupportedFilter => 143,
+            MetricType::JmapUnsupportedSort => 144,
+            MetricType::JmapUnknownMethod => 145,
+            MetricType::JmapForbidden => 146,
+            MetricType::JmapNotJson => 147,
+            MetricType::JmapNotRequest => 148,
+            MetricType::JmapWebsocketStart => 149,
+            MetricType::JmapWebsocketError => 150,
+            MetricType::LimitSizeRequest => 151,
+            MetricType::LimitSizeUpload => 152,
+            MetricType::LimitCallsIn => 153,
+            MetricType::LimitConcurrentRequest => 154,
+            MetricType::LimitConcurrentUpload => 155,
+            MetricType::LimitConcurrentConnection => 156,
+            MetricType::LimitQuota => 157,
+            MetricType::LimitBlobQuota => 158,
+            MetricType::LimitTenantQuota => 159,
+            MetricType::LimitTooManyRequests => 160,
+            MetricType::MailAuthParseError => 161,
+            MetricType::MailAuthMissingParameters => 162,
+            MetricType::MailAuthNoHeadersFound => 163,
+            MetricType::MailAuthCrypto => 164,
+            MetricType::MailAuthIo => 165,
+            MetricType::MailAuthBase64 => 166,
+            MetricType::MailAuthDnsError => 167,
+            MetricType::MailAuthDnsRecordNotFound => 168,
+            MetricType::MailAuthDnsInvalidRecordType => 169,
+            MetricType::MailAuthPolicyNotAligned => 170,
+            MetricType::ManageSieveConnectionStart => 171,
+            MetricType::ManageSieveConnectionEnd => 172,
+            MetricType::MessageIngestHam => 173,
+            MetricType::MessageIngestSpam => 174,
+            MetricType::MessageIngestImapAppend => 175,
+            MetricType::MessageIngestJmapAppend => 176,
+            MetricType::MessageIngestDuplicate => 177,
+            MetricType::MessageIngestError => 178,
+            MetricType::MessageIngestSearchIndex => 179,
+            MetricType::MilterActionAccept => 180,
+            MetricType::MilterActionDiscard => 181,
+            MetricType::MilterActionReject => 182,
+            MetricType::MilterActionTempFail => 183,
+            MetricType::MilterActionReplyCode => 184,
+            MetricType::MilterActionConnectionFailure => 185,
+            MetricType::MilterActionShutdown => 186,
+            MetricType::MtaHookActionAccept => 187,
+            MetricType::MtaHookActionDiscard => 188,
+            MetricType::MtaHookActionReject => 189,
+            MetricType::MtaHookActionQuarantine => 190,
+            MetricType::MtaHookError => 191,
+            MetricType::MtaStsAuthorized => 192,
+            MetricType::MtaStsNotAuthorized => 193,
+            MetricType::MtaStsInvalidPolicy => 194,
+            MetricType::NetworkTimeout => 195,
+            MetricType::OutgoingReportSpfReport => 196,
+            MetricType::OutgoingReportSpfRateLimited => 197,
+            MetricType::OutgoingReportDkimReport => 198,
+            MetricType::OutgoingReportDkimRateLimited => 199,
+            MetricType::OutgoingReportDmarcReport => 200,
+            MetricType::OutgoingReportDmarcRateLimited => 201,
+            MetricType::OutgoingReportDmarcAggregateReport => 202,
+            MetricType::OutgoingReportTlsAggregate => 203,
+            MetricType::OutgoingReportHttpSubmission => 204,
+            MetricType::OutgoingReportUnauthorizedReportingAddress => 205,
+            MetricType::OutgoingReportReportingAddressValidationError => 206,
+            MetricType::OutgoingReportNotFound => 207,
+            MetricType::OutgoingReportSubmissionError => 208,
+            MetricType::OutgoingReportNoRecipientsFound => 209,
+            MetricType::Pop3ConnectionStart => 210,
+            MetricType::Pop3ConnectionEnd => 211,
+            MetricType::PushSubscriptionSuccess => 213,
+            MetricType::PushSubscriptionError => 214,
+            MetricType::PushSubscriptionNotFound => 215,
+            MetricType::QueueMessageQueued => 216,
+            MetricType::QueueAuthenticatedMessageQueued => 217,
+            MetricType::QueueReportQueued => 218,
+            MetricType::QueueDsnQueued => 219,
+            MetricType::QueueAutogeneratedQueued => 220,
+            MetricType::QueueRescheduled => 221,
+            MetricType::QueueBlobNotFound => 222,
+            MetricType::QueueRateLimitExceeded => 223,
+            MetricType::QueueConcurrencyLimitExceeded => 224,
+            MetricType::QueueQuotaExceeded => 225,
+            MetricType::ResourceNotFound => 226,
+            MetricType::ResourceBadParameters => 227,
+            MetricType::ResourceError => 228,
+            MetricType::SecurityAuthenticationBan => 229,
+            MetricType::SecurityAbuseBan => 230,
+            MetricType::SecurityScanBan => 231,
+            MetricType::SecurityLoiterBan => 232,
+            MetricType::SecuritySmugglingBan => 339,
+            MetricType::SecurityIpBlocked => 233,
+            MetricType::SecurityUnauthorized => 234,
+            MetricType::ServerThreadError => 235,
+            MetricType::SieveActionAccept => 236,
+            MetricType::SieveActionAcceptReplace => 237,
+            MetricType::SieveActionDiscard => 238,
+            MetricType::SieveActionReject => 239,
+            MetricType::SieveSendMessage => 240,
+            MetricType::SieveMessageTooLarge => 241,
+            MetricType::SieveRuntimeError => 242,
+            MetricType::SieveUnexpectedError => 243,
+            MetricType::SieveNotSupported => 244,
+            MetricType::SieveQuotaExceeded => 245,
+            MetricType::SmtpConnectionStart => 246,
+            MetricType::SmtpConnectionEnd => 247,
+            MetricType::SmtpError => 248,
+            MetricType::SmtpConcurrencyLimitExceeded => 249,
+            MetricType::SmtpTransferLimitExceeded => 250,
+            MetricType::SmtpRateLimitExceeded => 251,
+            MetricType::SmtpTimeLimitExceeded => 252,
+            MetricType::SmtpMessageParseFailed => 253,
+            MetricType::SmtpMessageTooLarge => 254,
+            MetricType::SmtpLoopDetected => 255,
+            MetricType::SmtpBareLineEnding => 340,
+            MetricType::SmtpSmugglingAttempt => 341,
+            MetricType::SmtpDkimPass => 256,
+            MetricType::SmtpDkimFail => 257,
+            MetricType::SmtpArcPass => 258,
+            MetricType::SmtpArcFail => 259,
+            MetricType::SmtpSpfEhloPass => 260,
+            MetricType::SmtpSpfEhloFail => 261,
+            MetricType::SmtpSpfFromPass => 262,
+            MetricType::SmtpSpfFromFail => 263,
+            MetricType::SmtpDmarcPass => 264,
+            MetricType::SmtpDmarcFail => 265,
+            MetricType::SmtpIprevPass => 266,
+            MetricType::SmtpIprevFail => 267,
+            MetricType::SmtpTooManyMessages => 268,
+            MetricType::SmtpInvalidEhlo => 269,
+            MetricType::SmtpDidNotSayEhlo => 270,
+            MetricType::SmtpMailFromUnauthenticated => 271,
+            MetricType::SmtpMailFromUnauthorized => 272,
+            MetricType::SmtpMailFromMissing => 273,
+            MetricType::SmtpMultipleMailFrom => 274,
+            MetricType::SmtpMailboxDoesNotExist => 275,
+            MetricType::SmtpRelayNotAllowed => 276,
+            MetricType::SmtpRcptToDuplicate => 277,
+            MetricType::SmtpRcptToMissing => 278,
+            MetricType::SmtpTooManyRecipients => 279,
+            MetricType::SmtpTooManyInvalidRcpt => 280,
+            MetricType::SmtpAuthMechanismNotSupported => 281,
+            MetricType::SmtpAuthExchangeTooLong => 282,
+            MetricType::SmtpCommandNotImplemented => 283,
+            MetricType::SmtpInvalidCommand => 284,
+            MetricType::SmtpSyntaxError => 285,
+            MetricType::SmtpRequestTooLarge => 286,
+            MetricType::SpamPyzorError => 287,
+            MetricType::SpamDnsblError => 288,
+            MetricType::SpamTrainCompleted => 289,
+            MetricType::SpamTrainSampleAdded => 290,
+            MetricType::SpamClassify => 291,
+            MetricType::SpamModelNotReady => 292,
+            MetricType::SpfPass => 293,
+            MetricType::SpfFail => 294,
+            MetricType::SpfSoftFail => 295,
+            MetricType::SpfNeutral => 296,
+            MetricType::SpfTempError => 297,
+            MetricType::SpfPermError => 298,
+            MetricType::SpfNone => 299,
+            MetricType::StoreAssertValueFailed => 300,
+            MetricType::StoreFoundationdbError => 301,
+            MetricType::StoreMysqlError => 302,
+            MetricType::StorePostgresqlError => 303,
+            MetricType::StoreRocksdbError => 304,
+            MetricType::StoreSqliteError => 305,
+            MetricType::StoreLdapError => 306,
+            MetricType::StoreElasticsearchError => 307,
+            MetricType::StoreRedisError => 308,
+            MetricType::StoreS3Error => 309,
+            MetricType::StoreAzureError => 310,
+            MetricType::StoreFilesystemError => 311,
+            MetricType::StorePoolError => 312,
+            MetricType::StoreDataCorruption => 313,
+            MetricType::StoreDecompressError => 314,
+            MetricType::StoreDeserializeError => 315,
+            MetricType::StoreNotFound => 316,
+            MetricType::StoreNotConfigured => 317,
+            MetricType::StoreNotSupported => 318,
+            MetricType::StoreUnexpectedError => 319,
+            MetricType::StoreCryptoError => 320,
+            MetricType::StoreHttpStoreError => 321,
+            MetricType::StoreBlobMissingMarker => 322,
+            MetricType::StoreDataWrite => 323,
+            MetricType::StoreDataIterate => 324,
+            MetricType::StoreBlobRead => 325,
+            MetricType::StoreBlobWrite => 326,
+            MetricType::StoreBlobDelete => 327,
+            MetricType::TaskManagerBlobNotFound => 328,
+            MetricType::TaskManagerMetadataNotFound => 329,
+            MetricType::TelemetryAlertEvent => 338,
+            MetricType::TelemetryAlertMessage => 212,
+            MetricType::TelemetryLogError => 330,
+            MetricType::TelemetryWebhookError => 331,
+            MetricType::TelemetryOtelExporterError => 332,
+            MetricType::TelemetryOtelMetricsExporterError => 333,
+            MetricType::TelemetryPrometheusExporterError => 334,
+            MetricType::TelemetryJournalError => 335,
+            MetricType::TlsHandshakeError => 336,
             _ => usize::MAX,
         }
     }
@@ -6562,6 +7178,7 @@ impl MetricType {
             MetricType::SecurityAbuseBan => "Banned due to abuse",
             MetricType::SecurityScanBan => "Banned due to scan",
             MetricType::SecurityLoiterBan => "Banned due to loitering",
+            MetricType::SecuritySmugglingBan => "Banned due to SMTP smuggling",
             MetricType::SecurityIpBlocked => "Blocked IP address",
             MetricType::SecurityUnauthorized => "Unauthorized access",
             MetricType::ServerMemory => "Server memory usage",
@@ -6590,6 +7207,8 @@ impl MetricType {
             MetricType::SmtpMessageParseFailed => "Message parsing failed",
             MetricType::SmtpMessageTooLarge => "Message too large",
             MetricType::SmtpLoopDetected => "Mail loop detected",
+            MetricType::SmtpBareLineEnding => "Bare CR or LF line ending received",
+            MetricType::SmtpSmugglingAttempt => "SMTP smuggling attempt detected",
             MetricType::SmtpDkimPass => "DKIM verification passed",
             MetricType::SmtpDkimFail => "DKIM verification failed",
             MetricType::SmtpArcPass => "ARC verification passed",
@@ -6899,6 +7518,7 @@ impl MetricType {
             | MetricType::SecurityAbuseBan
             | MetricType::SecurityScanBan
             | MetricType::SecurityLoiterBan
+            | MetricType::SecuritySmugglingBan
             | MetricType::SecurityIpBlocked
             | MetricType::SecurityUnauthorized
             | MetricType::ServerThreadError
@@ -6922,6 +7542,8 @@ impl MetricType {
             | MetricType::SmtpMessageParseFailed
             | MetricType::SmtpMessageTooLarge
             | MetricType::SmtpLoopDetected
+            | MetricType::SmtpBareLineEnding
+            | MetricType::SmtpSmugglingAttempt
             | MetricType::SmtpDkimPass
             | MetricType::SmtpDkimFail
             | MetricType::SmtpArcPass
@@ -7250,6 +7872,7 @@ impl MetricType {
             MetricType::SecurityAbuseBan,
             MetricType::SecurityScanBan,
             MetricType::SecurityLoiterBan,
+            MetricType::SecuritySmugglingBan,
             MetricType::SecurityIpBlocked,
             MetricType::SecurityUnauthorized,
             MetricType::ServerMemory,
@@ -7278,6 +7901,8 @@ impl MetricType {
             MetricType::SmtpMessageParseFailed,
             MetricType::SmtpMessageTooLarge,
             MetricType::SmtpLoopDetected,
+            MetricType::SmtpBareLineEnding,
+            MetricType::SmtpSmugglingAttempt,
             MetricType::SmtpDkimPass,
             MetricType::SmtpDkimFail,
             MetricType::SmtpArcPass,
@@ -15,6 +15,7 @@ use atomics::bitset::AtomicBitset;
 use ipc::{
     USIZE_BITS,
     channel::{CHANNEL_FLAGS, CHANNEL_UPDATE_MARKER, Receiver},
+    rules::{ActiveRule, BASE_INTERESTS, RuleAction, TRACING_RULES, TracingRule},
     subscriber::{Interests, Subscriber},
 };
 use parking_lot::Mutex;
@@ -49,6 +50,9 @@ pub(crate) enum Update {
     UpdateLevels {
         levels: AHashMap<EventType, Level>,
     },
+    UpdateRules {
+        rules: Vec<TracingRule>,
+    },
     Shutdown,
 }
 
@@ -57,6 +61,7 @@ pub struct Collector {
     subscribers: Vec<Subscriber>,
     levels: [Level; TOTAL_EVENT_COUNT],
     active_spans: AHashMap<u64, Arc<Event<EventDetails>>>,
+    rules: Vec<ActiveRule>,
 }
 
 const HTTP_CONN_START: usize = EventType::Http(HttpEvent::ConnectionStart).to_id() as usize;
@@ -195,9 +200,20 @@ impl Collector {
                                 }
                             };
 
+                            // Apply tracing rules
+                            let is_forced = if !self.rules.is_empty() {
+                                match self.apply_rules(event_id, &event, timestamp) {
+                                    RuleAction::Deliver => false,
+                                    RuleAction::Force => true,
+                                    RuleAction::Discard => continue,
+                                }
+                            } else {
+                                false
+                            };
+
                             // Send to subscribers
                             for subscriber in self.subscribers.iter_mut() {
-                                subscriber.push_event(event_id, event.clone());
+                                subscriber.push_event(event_id, event.clone(), is_forced);
                             }
                         }
                         Ok(None) => {
@@ -274,6 +290,17 @@ impl Collector {
                         }
                     }
                 }
+                Update::UpdateRules { rules } => {
+                    let mut active_rules = Vec::with_capacity(rules.len());
+                    for rule in rules {
+                        if let Some(pos) = self.rules.iter().position(|r| r.rule.id == rule.id) {
+                            active_rules.push(self.rules.swap_remove(pos));
+                        } else {
+                            active_rules.push(ActiveRule::new(rule));
+                        }
+                    }
+                    self.rules = active_rules;
+                }
                 Update::Shutdown => return false,
             }
         }
@@ -281,19 +308,58 @@ impl Collector {
         true
     }
 
-    pub fn set_interests(mut interests: Interests) {
-        if !interests.is_empty() {
-            for event_type in EVENT_TYPES.iter() {
-                if event_type.is_span_start() || event_type.is_span_end() {
-                    interests.set(*event_type);
-                }
+    fn apply_rules(
+        &mut self,
+        event_id: usize,
+        event: &Event<EventDetails>,
+        timestamp: u64,
+    ) -> RuleAction {
+        let mut action = RuleAction::Deliver;
+        let mut has_expired = false;
+
+        for rule in self.rules.iter_mut() {
+            if rule.rule.is_expired(timestamp) {
+                has_expired = true;
+            } else if let Some(rule_action) = rule.apply(event_id, event) {
+                action = rule_action;
+                break;
             }
         }
 
-        TRACE_INTERESTS.update(interests);
+        // Stop tracking sessions once they end
+        if matches!(
+            event_id,
+            HTTP_CONN_END
+                | IMAP_CONN_END
+                | POP3_CONN_END
+                | SMTP_CONN_END
+                | MANAGE_SIEVE_CONN_END
+                | EV_ATTEMPT_END
+        ) && let Some(span_id) = event.span_id()
+        {
+            for rule in self.rules.iter_mut() {
+                rule.spans.remove(&span_id);
+            }
+        }
+
+        if has_expired {
+            self.rules.retain(|rule| !rule.rule.is_expired(timestamp));
+            Collector::expire_tracing_rules(timestamp);
+        }
+
+        action
+    }
+
+    pub fn set_interests(interests: Interests) {
+        *BASE_INTERESTS.lock() = Some(interests);
+        Collector::apply_tracing_rules(&TRACING_RULES.lock());
     }
 
     pub fn union_interests(interests: Interests) {
+        BASE_INTERESTS
+            .lock()
+            .get_or_insert_with(Default::default)
+            .union(&interests);
         TRACE_INTERESTS.union(interests);
     }
 
@@ -348,6 +414,7 @@ impl Default for Collector {
             levels: [Level::Disable; TOTAL_EVENT_COUNT],
             active_spans: AHashMap::new(),
             receivers: Vec::new(),
+            rules: Vec::new(),
         };
 
         for event in EVENT_TYPES.iter() {
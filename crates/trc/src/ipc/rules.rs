@@ -0,0 +1,289 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use std::{
+    net::IpAddr,
+    sync::atomic::{AtomicU64, Ordering},
+    time::SystemTime,
+};
+
+use ahash::AHashSet;
+use parking_lot::Mutex;
+
+use crate::{Event, EventDetails, EventType, Key, Level, Value};
+
+use super::{
+    collector::{COLLECTOR_UPDATES, Collector, EVENT_TYPES, TRACE_INTERESTS, Update},
+    subscriber::Interests,
+};
+
+// Runtime tracing rules are kept in memory and are not persisted, they are
+// meant for temporarily increasing or reducing the verbosity of a node.
+pub(crate) static TRACING_RULES: Mutex<Vec<TracingRule>> = Mutex::new(Vec::new());
+pub(crate) static BASE_INTERESTS: Mutex<Option<Interests>> = Mutex::new(None);
+static RULE_ID: AtomicU64 = AtomicU64::new(1);
+
+#[derive(Debug, Clone)]
+pub struct TracingRule {
+    pub id: u64,
+    pub events: Interests,
+    pub level: Option<Level>,
+    pub sample_rate: u32,
+    pub scope: Option<TracingScope>,
+    pub expires: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TracingScope {
+    AccountId(u32),
+    RemoteIp(IpAddr),
+}
+
+pub(crate) struct ActiveRule {
+    pub rule: TracingRule,
+    pub spans: AHashSet<u64>,
+    pub seen: u64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum RuleAction {
+    Deliver,
+    Force,
+    Discard,
+}
+
+impl TracingRule {
+    pub fn new(events: Interests) -> Self {
+        Self {
+            id: 0,
+            events,
+            level: None,
+            sample_rate: 0,
+            scope: None,
+            expires: None,
+        }
+    }
+
+    // Events are matched either by their full name or by a prefix ending
+    // in '*', for example "smtp.*" or "network.*".
+    pub fn parse_events<'x>(patterns: impl IntoIterator<Item = &'x str>) -> Option<Interests> {
+        let mut events = Interests::default();
+        for pattern in patterns {
+            let pattern = pattern.trim();
+            if let Some(prefix) = pattern.strip_suffix('*') {
+                let mut has_match = false;
+                for event_type in EVENT_TYPES.iter() {
+                    if event_type.as_str().starts_with(prefix) {
+                        events.set(*event_type);
+                        has_match = true;
+                    }
+                }
+                if !has_match {
+                    return None;
+                }
+            } else {
+                events.set(EventType::parse(pattern)?);
+            }
+        }
+
+        (!events.is_empty()).then_some(events)
+    }
+
+    pub fn with_level(mut self, level: Level) -> Self {
+        self.level = Some(level);
+        self
+    }
+
+    pub fn with_sample_rate(mut self, sample_rate: u32) -> Self {
+        self.sample_rate = sample_rate;
+        self
+    }
+
+    pub fn with_scope(mut self, scope: TracingScope) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    pub fn with_expires(mut self, expires: u64) -> Self {
+        self.expires = Some(expires);
+        self
+    }
+
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires.is_some_and(|expires| expires <= now)
+    }
+
+    // Events that need to be emitted for the rule to take effect
+    fn enabled_events(&self) -> Option<Interests> {
+        let level = self.level?;
+        let mut events = Interests::default();
+        for event_type in EVENT_TYPES.iter() {
+            if self.events.get(*event_type) && level.is_contained(event_type.level()) {
+                events.set(*event_type);
+            }
+        }
+        Some(events)
+    }
+}
+
+impl TracingScope {
+    fn matches(&self, key: &Key, value: &Value) -> bool {
+        match (self, key, value) {
+            (TracingScope::AccountId(account_id), Key::AccountId, Value::UInt(value)) => {
+                *value == *account_id as u64
+            }
+            (TracingScope::RemoteIp(IpAddr::V4(ip)), Key::RemoteIp, Value::Ipv4(value)) => {
+                ip == value
+            }
+            (TracingScope::RemoteIp(IpAddr::V6(ip)), Key::RemoteIp, Value::Ipv6(value)) => {
+                ip == value
+            }
+            _ => false,
+        }
+    }
+}
+
+impl ActiveRule {
+    pub fn new(rule: TracingRule) -> Self {
+        Self {
+            rule,
+            spans: AHashSet::new(),
+            seen: 0,
+        }
+    }
+
+    pub fn apply(&mut self, event_id: usize, event: &Event<EventDetails>) -> Option<RuleAction> {
+        // Scoped rules follow the whole session once a matching key is seen
+        if let Some(scope) = &self.rule.scope {
+            let span_id = event.span_id();
+            if !span_id.is_some_and(|span_id| self.spans.contains(&span_id)) {
+                let matches = event
+                    .keys
+                    .iter()
+                    .chain(
+                        event
+                            .inner
+                            .span
+                            .as_ref()
+                            .map_or([].iter(), |s| s.keys.iter()),
+                    )
+                    .any(|(key, value)| scope.matches(key, value));
+                if !matches {
+                    return None;
+                } else if let Some(span_id) = span_id {
+                    self.spans.insert(span_id);
+                }
+            }
+        }
+
+        if !self.rule.events.get(event_id) {
+            None
+        } else if self.rule.level.is_some() {
+            Some(RuleAction::Force)
+        } else if self.rule.sample_rate > 1 {
+            self.seen += 1;
+            if (self.seen - 1) % self.rule.sample_rate as u64 == 0 {
+                Some(RuleAction::Deliver)
+            } else {
+                Some(RuleAction::Discard)
+            }
+        } else {
+            None
+        }
+    }
+}
+
+impl Collector {
+    pub fn add_tracing_rule(mut rule: TracingRule) -> u64 {
+        rule.id = RULE_ID.fetch_add(1, Ordering::Relaxed);
+        let id = rule.id;
+        TRACING_RULES.lock().push(rule);
+        Collector::update_tracing_rules();
+        id
+    }
+
+    pub fn remove_tracing_rule(id: u64) -> bool {
+        let mut rules = TRACING_RULES.lock();
+        let num_rules = rules.len();
+        rules.retain(|rule| rule.id != id);
+        let is_removed = rules.len() != num_rules;
+        drop(rules);
+
+        if is_removed {
+            Collector::update_tracing_rules();
+        }
+        is_removed
+    }
+
+    pub fn tracing_rules() -> Vec<TracingRule> {
+        let now = now();
+        if TRACING_RULES.lock().iter().any(|rule| rule.is_expired(now)) {
+            TRACING_RULES.lock().retain(|rule| !rule.is_expired(now));
+            Collector::update_tracing_rules();
+        }
+        TRACING_RULES.lock().clone()
+    }
+
+    pub(crate) fn update_tracing_rules() {
+        let rules = TRACING_RULES.lock().clone();
+        Collector::apply_tracing_rules(&rules);
+        COLLECTOR_UPDATES.lock().push(Update::UpdateRules { rules });
+        Collector::reload();
+    }
+
+    pub(crate) fn expire_tracing_rules(now: u64) {
+        let mut rules = TRACING_RULES.lock();
+        rules.retain(|rule| !rule.is_expired(now));
+        Collector::apply_tracing_rules(&rules);
+    }
+
+    // Global interests are the union of the configured tracers and any
+    // events enabled by tracing rules.
+    pub(crate) fn apply_tracing_rules(rules: &[TracingRule]) {
+        let mut interests = BASE_INTERESTS.lock().clone().unwrap_or_default();
+        for rule in rules {
+            if let Some(events) = rule.enabled_events() {
+                interests.union(&events);
+            }
+        }
+
+        if !interests.is_empty() {
+            for event_type in EVENT_TYPES.iter() {
+                if event_type.is_span_start() || event_type.is_span_end() {
+                    interests.set(*event_type);
+                }
+            }
+        }
+
+        TRACE_INTERESTS.update(interests);
+    }
+}
+
+pub(crate) fn now() -> u64 {
+    SystemTime::now()
+        .duration_since(SystemTime::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{EventType, SmtpEvent, StoreEvent};
+
+    use super::TracingRule;
+
+    #[test]
+    fn parse_rule_events() {
+        let events = TracingRule::parse_events(["smtp.*", "store.data-write"]).unwrap();
+        assert!(events.get(EventType::Smtp(SmtpEvent::ConnectionStart)));
+        assert!(events.get(EventType::Smtp(SmtpEvent::CalloutRejected)));
+        assert!(events.get(EventType::Store(StoreEvent::DataWrite)));
+        assert!(!events.get(EventType::Store(StoreEvent::DataIterate)));
+
+        assert!(TracingRule::parse_events(["smtp.unknown-event"]).is_none());
+        assert!(TracingRule::parse_events(["unknown.*"]).is_none());
+        assert!(TracingRule::parse_events([]).is_none());
+    }
+}
@@ -28,6 +28,7 @@ pub(crate) struct Subscriber {
     pub interests: Interests,
     pub tx: mpsc::Sender<EventBatch>,
     pub lossy: bool,
+    pub accepts_rules: bool,
     pub batch: EventBatch,
 }
 
@@ -35,12 +36,18 @@ pub struct SubscriberBuilder {
     pub id: String,
     pub interests: Interests,
     pub lossy: bool,
+    pub accepts_rules: bool,
 }
 
 impl Subscriber {
     #[inline(always)]
-    pub fn push_event(&mut self, event_id: usize, trace: Arc<Event<EventDetails>>) {
-        if self.interests.get(event_id) {
+    pub fn push_event(
+        &mut self,
+        event_id: usize,
+        trace: Arc<Event<EventDetails>>,
+        is_forced: bool,
+    ) {
+        if self.interests.get(event_id) || (is_forced && self.accepts_rules) {
             self.batch.push(trace);
         }
     }
@@ -76,6 +83,7 @@ impl SubscriberBuilder {
             id,
             interests: Default::default(),
             lossy: true,
+            accepts_rules: false,
         }
     }
 
@@ -105,6 +113,13 @@ impl SubscriberBuilder {
         self
     }
 
+    // Subscribers accepting tracing rules also receive events that were
+    // enabled at runtime, regardless of their configured interests.
+    pub fn with_tracing_rules(mut self, accepts_rules: bool) -> Self {
+        self.accepts_rules = accepts_rules;
+        self
+    }
+
     pub fn register(self) -> (mpsc::Sender<EventBatch>, mpsc::Receiver<EventBatch>) {
         let (tx, rx) = mpsc::channel(8192);
 
@@ -114,6 +129,7 @@ impl SubscriberBuilder {
                 interests: self.interests,
                 tx: tx.clone(),
                 lossy: self.lossy,
+                accepts_rules: self.accepts_rules,
                 batch: Vec::new(),
             },
         });
@@ -34,6 +34,14 @@ impl BlobHash {
         BlobHash(blake3::hash(value.as_ref()).into())
     }
 
+    pub fn generate_chained(values: &[&[u8]]) -> Self {
+        let mut hasher = blake3::Hasher::new();
+        for value in values {
+            hasher.update(value);
+        }
+        BlobHash(hasher.finalize().into())
+    }
+
     pub fn try_from_hash_slice(value: &[u8]) -> Result<BlobHash, std::array::TryFromSliceError> {
         value.try_into().map(BlobHash)
     }
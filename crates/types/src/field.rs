@@ -48,6 +48,8 @@ pub enum EmailField {
 #[repr(u8)]
 pub enum MailboxField {
     UidCounter = 84,
+    ImapSieveScript = 85,
+    VirtualUids = 86,
     Archive = ARCHIVE_FIELD,
 }
 
@@ -56,6 +58,7 @@ pub enum MailboxField {
 pub enum SieveField {
     Name,
     Ids,
+    PreviousVersion,
     Archive,
 }
 
@@ -71,6 +74,8 @@ pub enum EmailSubmissionField {
 pub enum IdentityField {
     Archive,
     DocumentId,
+    DelegatedTo,
+    Settings,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -81,7 +86,10 @@ pub enum PrincipalField {
     DefaultCalendarId = 47,
     DefaultAddressBookId = 48,
     ActiveScriptId = 49,
+    ImapSieveScript = 53,
+    ReindexProgress = 52,
     PushSubscriptions = 44,
+    ForwardingSettings = 55,
 }
 
 impl From<ContactField> for u8 {
@@ -128,6 +136,8 @@ impl From<MailboxField> for u8 {
     fn from(value: MailboxField) -> Self {
         match value {
             MailboxField::UidCounter => 84,
+            MailboxField::ImapSieveScript => 85,
+            MailboxField::VirtualUids => 86,
             MailboxField::Archive => ARCHIVE_FIELD,
         }
     }
@@ -138,6 +148,7 @@ impl From<SieveField> for u8 {
         match value {
             SieveField::Name => 13,
             SieveField::Ids => 84,
+            SieveField::PreviousVersion => 86,
             SieveField::Archive => ARCHIVE_FIELD,
         }
     }
@@ -159,7 +170,10 @@ impl From<PrincipalField> for u8 {
             PrincipalField::DefaultCalendarId => 47,
             PrincipalField::DefaultAddressBookId => 48,
             PrincipalField::ActiveScriptId => 49,
+            PrincipalField::ImapSieveScript => 53,
+            PrincipalField::ReindexProgress => 52,
             PrincipalField::PushSubscriptions => 44,
+            PrincipalField::ForwardingSettings => 55,
             PrincipalField::Archive => ARCHIVE_FIELD,
         }
     }
@@ -170,6 +184,8 @@ impl From<IdentityField> for u8 {
         match value {
             IdentityField::Archive => ARCHIVE_FIELD,
             IdentityField::DocumentId => 51,
+            IdentityField::DelegatedTo => 52,
+            IdentityField::Settings => 54,
         }
     }
 }
@@ -37,6 +37,7 @@ pub const NEW: usize = 26;
 pub const NOTIFY: usize = 27;
 pub const UNSUBSCRIBED: usize = 28;
 pub const OTHER: usize = 29;
+pub const MAX_CUSTOM_KEYWORDS: usize = 128 - OTHER;
 
 #[derive(
     rkyv::Serialize,
@@ -286,6 +287,103 @@ impl Keyword {
     }
 }
 
+// Assigns ids to the keywords of an account, system keywords have fixed ids
+// while custom keywords take the first free slot after them and keep it until
+// no message references them anymore.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct KeywordRegistry {
+    custom: Vec<Option<Box<str>>>,
+}
+
+impl KeywordRegistry {
+    pub fn id(&self, keyword: &Keyword) -> Option<u32> {
+        match keyword.id() {
+            Ok(id) => Some(id),
+            Err(name) => self.custom_id(name),
+        }
+    }
+
+    pub fn custom_id(&self, name: &str) -> Option<u32> {
+        self.custom
+            .iter()
+            .position(|slot| slot.as_deref() == Some(name))
+            .map(|idx| (OTHER + idx) as u32)
+    }
+
+    // Returns None when all the slots allowed by the limit are taken
+    pub fn get_or_insert(&mut self, keyword: &Keyword, limit: usize) -> Option<u32> {
+        match keyword.id() {
+            Ok(id) => Some(id),
+            Err(name) => self.get_or_insert_custom(name, limit),
+        }
+    }
+
+    pub fn get_or_insert_custom(&mut self, name: &str, limit: usize) -> Option<u32> {
+        if let Some(id) = self.custom_id(name) {
+            Some(id)
+        } else if self.len() >= limit.min(MAX_CUSTOM_KEYWORDS) {
+            None
+        } else if let Some(idx) = self.custom.iter().position(Option::is_none) {
+            self.custom[idx] = Some(name.into());
+            Some((OTHER + idx) as u32)
+        } else {
+            self.custom.push(Some(name.into()));
+            Some((OTHER + self.custom.len() - 1) as u32)
+        }
+    }
+
+    pub fn keyword(&self, id: usize) -> Option<Keyword> {
+        match Keyword::try_from_id(id) {
+            Ok(keyword) => Some(keyword),
+            Err(id) => self
+                .custom
+                .get(id.checked_sub(OTHER)?)?
+                .as_ref()
+                .map(|name| Keyword::Other(name.clone())),
+        }
+    }
+
+    pub fn custom_keywords(&self) -> impl Iterator<Item = (u32, &str)> {
+        self.custom
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, slot)| slot.as_deref().map(|name| ((OTHER + idx) as u32, name)))
+    }
+
+    // Releases the custom keywords whose bit is not set in the mask of
+    // keyword ids in use, the remaining keywords keep their ids.
+    pub fn collect_garbage(&mut self, used: u128) -> usize {
+        let mut released = 0;
+        for (idx, slot) in self.custom.iter_mut().enumerate() {
+            if slot.is_some() && used & (1 << (OTHER + idx)) == 0 {
+                *slot = None;
+                released += 1;
+            }
+        }
+        while self.custom.last().is_some_and(Option::is_none) {
+            self.custom.pop();
+        }
+        released
+    }
+
+    pub fn len(&self) -> usize {
+        self.custom.iter().filter(|slot| slot.is_some()).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.custom.iter().all(Option::is_none)
+    }
+
+    pub fn size(&self) -> usize {
+        self.custom
+            .iter()
+            .map(|slot| {
+                std::mem::size_of::<Option<Box<str>>>() + slot.as_ref().map_or(0, |s| s.len())
+            })
+            .sum()
+    }
+}
+
 impl From<String> for Keyword {
     fn from(value: String) -> Self {
         Keyword::try_parse(&value).unwrap_or_else(|| Keyword::from_other(value))
@@ -534,3 +632,53 @@ impl<'x, P: Property, E: Element + From<Keyword>> From<Keyword> for Value<'x, P,
         Value::Element(E::from(id))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Keyword, KeywordRegistry, MAX_CUSTOM_KEYWORDS, OTHER, SEEN};
+
+    #[test]
+    fn keyword_registry() {
+        let mut registry = KeywordRegistry::default();
+        let work = Keyword::Other("Work".into());
+        let home = Keyword::Other("Home".into());
+        let todo = Keyword::Other("$Todo".into());
+
+        // System keywords have fixed ids
+        assert_eq!(registry.get_or_insert(&Keyword::Seen, 0), Some(SEEN as u32));
+        assert_eq!(registry.keyword(SEEN), Some(Keyword::Seen));
+
+        // Custom keywords are assigned ids in order
+        assert_eq!(registry.get_or_insert(&work, 2), Some(OTHER as u32));
+        assert_eq!(registry.get_or_insert(&home, 2), Some(OTHER as u32 + 1));
+        assert_eq!(registry.get_or_insert(&work, 2), Some(OTHER as u32));
+        assert_eq!(registry.get_or_insert(&todo, 2), None);
+        assert_eq!(registry.id(&home), Some(OTHER as u32 + 1));
+        assert_eq!(registry.keyword(OTHER + 1), Some(home.clone()));
+        assert_eq!(registry.len(), 2);
+
+        // Unused keywords are released without renumbering the others
+        assert_eq!(registry.collect_garbage(1 << (OTHER + 1)), 1);
+        assert_eq!(registry.id(&work), None);
+        assert_eq!(registry.id(&home), Some(OTHER as u32 + 1));
+        assert_eq!(registry.keyword(OTHER), None);
+
+        // Released slots are reused
+        assert_eq!(registry.get_or_insert(&todo, 2), Some(OTHER as u32));
+        assert_eq!(
+            registry.custom_keywords().collect::<Vec<_>>(),
+            vec![(OTHER as u32, "$Todo"), (OTHER as u32 + 1, "Home")]
+        );
+        assert_eq!(registry.collect_garbage(0), 2);
+        assert!(registry.is_empty());
+
+        // The number of custom keywords is capped by the cache bitmap
+        for idx in 0..MAX_CUSTOM_KEYWORDS {
+            assert_eq!(
+                registry.get_or_insert(&Keyword::Other(idx.to_string().into()), usize::MAX),
+                Some((OTHER + idx) as u32)
+            );
+        }
+        assert_eq!(registry.get_or_insert(&work, usize::MAX), None);
+    }
+}
@@ -33,6 +33,8 @@ pub enum SpecialUse {
     Memos,
     Scheduled,
     Snoozed,
+    All,
+    Flagged,
 }
 
 impl SpecialUse {
@@ -49,6 +51,8 @@ impl SpecialUse {
             b"memos" => SpecialUse::Memos,
             b"scheduled" => SpecialUse::Scheduled,
             b"snoozed" => SpecialUse::Snoozed,
+            b"all" => SpecialUse::All,
+            b"flagged" => SpecialUse::Flagged,
         )
     }
 
@@ -65,6 +69,8 @@ impl SpecialUse {
             SpecialUse::Memos => Some("memos"),
             SpecialUse::Scheduled => Some("scheduled"),
             SpecialUse::Snoozed => Some("snoozed"),
+            SpecialUse::All => Some("all"),
+            SpecialUse::Flagged => Some("flagged"),
             SpecialUse::None => None,
         }
     }
@@ -84,6 +90,8 @@ impl ArchivedSpecialUse {
             ArchivedSpecialUse::Memos => Some("memos"),
             ArchivedSpecialUse::Scheduled => Some("scheduled"),
             ArchivedSpecialUse::Snoozed => Some("snoozed"),
+            ArchivedSpecialUse::All => Some("all"),
+            ArchivedSpecialUse::Flagged => Some("flagged"),
             ArchivedSpecialUse::None => None,
         }
     }
@@ -103,6 +111,8 @@ impl From<&ArchivedSpecialUse> for SpecialUse {
             ArchivedSpecialUse::Memos => SpecialUse::Memos,
             ArchivedSpecialUse::Scheduled => SpecialUse::Scheduled,
             ArchivedSpecialUse::Snoozed => SpecialUse::Snoozed,
+            ArchivedSpecialUse::All => SpecialUse::All,
+            ArchivedSpecialUse::Flagged => SpecialUse::Flagged,
             ArchivedSpecialUse::None => SpecialUse::None,
         }
     }
@@ -134,6 +134,17 @@ impl<K: Eq + Hash + CacheItemWeight, V: Clone + CacheItemWeight> CacheWithTtl<K,
         })
     }
 
+    // Returns the value along with whether it has expired, without evicting it
+    #[inline(always)]
+    pub fn get_allow_expired<Q>(&self, key: &Q) -> Option<(V, bool)>
+    where
+        Q: Hash + Equivalent<K> + ?Sized,
+    {
+        self.0
+            .get(key)
+            .map(|v| (v.value, v.expires <= Instant::now()))
+    }
+
     #[inline(always)]
     pub async fn get_value_or_guard_async<'a, Q>(
         &'a self,
@@ -59,6 +59,11 @@ impl SimpleCron {
         (next - now).to_std().unwrap_or_else(|_| self.as_duration())
     }
 
+    // Time elapsed since the schedule last fired
+    pub fn time_since_last(&self) -> Duration {
+        self.as_duration().saturating_sub(self.time_to_next())
+    }
+
     pub fn as_duration(&self) -> Duration {
         match self {
             SimpleCron::Day { .. } => Duration::from_secs(24 * 60 * 60),
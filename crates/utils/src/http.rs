@@ -34,6 +34,15 @@ pub fn build_http_client(
     }
 }
 
+pub fn is_localhost_url(url: &str) -> bool {
+    url.split_once("://")
+        .map(|(_, url)| url.split_once('/').map_or(url, |(host, _)| host))
+        .is_some_and(|host| {
+            let host = host.rsplit_once(':').map_or(host, |(host, _)| host);
+            host == "localhost" || host == "127.0.0.1" || host == "[::1]"
+        })
+}
+
 pub fn build_http_headers(
     raw_headers: impl IntoIterator<Item = (String, String)>,
     username: Option<&str>,
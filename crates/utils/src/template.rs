@@ -126,6 +126,21 @@ impl<T: FromStr + Eq + Hash + std::fmt::Debug> Template<T> {
     }
 
     pub fn eval<V>(&self, variables: &Variables<T, V>) -> String
+    where
+        V: AsRef<str>,
+    {
+        self.render(variables, true)
+    }
+
+    // Plain text templates ignore the escape marker
+    pub fn eval_plain<V>(&self, variables: &Variables<T, V>) -> String
+    where
+        V: AsRef<str>,
+    {
+        self.render(variables, false)
+    }
+
+    fn render<V>(&self, variables: &Variables<T, V>, escape_html: bool) -> String
     where
         V: AsRef<str>,
     {
@@ -139,7 +154,7 @@ impl<T: FromStr + Eq + Hash + std::fmt::Debug> Template<T> {
                 TemplateItem::Static(s) => result.push_str(s),
                 TemplateItem::Variable { name, escape } => {
                     if let Some(Variable::Single(variable)) = variables.items.get(name) {
-                        if *escape {
+                        if *escape && escape_html {
                             html_escape(&mut result, variable.as_ref())
                         } else {
                             result.push_str(variable.as_ref());
@@ -168,7 +183,7 @@ impl<T: FromStr + Eq + Hash + std::fmt::Debug> Template<T> {
                                     TemplateItem::Static(s) => result.push_str(s),
                                     TemplateItem::Variable { name, escape } => {
                                         if let Some(variable) = entry.get(name) {
-                                            if *escape {
+                                            if *escape && escape_html {
                                                 html_escape(&mut result, variable.as_ref())
                                             } else {
                                                 result.push_str(variable.as_ref());
@@ -196,7 +211,7 @@ impl<T: FromStr + Eq + Hash + std::fmt::Debug> Template<T> {
     }
 }
 
-fn html_escape(result: &mut String, input: &str) {
+pub fn html_escape(result: &mut String, input: &str) {
     for c in input.chars() {
         match c {
             '&' => result.push_str("&amp;"),
@@ -536,6 +551,22 @@ Best regards"#;
         assert_eq!(result, "Item1: Item2: ");
     }
 
+    #[test]
+    fn test_plain_text_is_not_escaped() {
+        let template = Template::parse("Delivery to {{rcpt}} failed").unwrap();
+        let mut vars = Variables::<String, String>::new();
+        vars.insert_single("rcpt".to_string(), "<jane@example.org>".to_string());
+
+        assert_eq!(
+            template.eval(&vars),
+            "Delivery to &lt;jane@example.org&gt; failed"
+        );
+        assert_eq!(
+            template.eval_plain(&vars),
+            "Delivery to <jane@example.org> failed"
+        );
+    }
+
     /*#[test]
     fn test_full() {
         // Load static html in memory from resources/email-templates/calendar-alarm.html
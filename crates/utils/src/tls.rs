@@ -35,7 +35,7 @@ pub fn build_tls_connector(allow_invalid_certs: bool) -> Result<TlsConnector, St
 }
 
 #[derive(Debug)]
-struct DummyVerifier;
+pub struct DummyVerifier;
 
 impl ServerCertVerifier for DummyVerifier {
     fn verify_server_cert(
@@ -4,9 +4,14 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::utils::server::TestServer;
+use crate::utils::{
+    http_server::{HttpMessage, spawn_mock_http_server},
+    server::TestServer,
+};
 use common::network::dns::update::DNS_RECORDS;
-use dns_update::{CAARecord, bind::BindSerializer};
+use dns_update::{CAARecord, DnsRecord, bind::BindSerializer};
+use http_proto::{JsonResponse, ToHttpResponse};
+use hyper::Method;
 use registry::{
     schema::{
         enums::{AcmeChallengeType, DnsRecordType},
@@ -14,12 +19,14 @@ use registry::{
         structs::{
             AcmeProvider, CertificateManagement, CertificateManagementProperties, DkimManagement,
             DkimManagementProperties, DnsManagement, DnsManagementProperties, DnsServer,
-            DnsServerCloudflare, SecretKey, SecretKeyValue,
+            DnsServerCloudflare, DnsServerWebhook, HttpAuth, HttpAuthBearer, SecretKey,
+            SecretKeyValue,
         },
     },
     types::map::Map,
 };
 use serde_json::json;
+use std::sync::{Arc, Mutex};
 
 const EXPECTED_ZONE: &str = r#"example.org. IN CAA 0 iodef "mailto:postmaster@example.org"
 example.org. IN CAA 0 issue "pebble.letsencrypt.org"
@@ -180,6 +187,110 @@ pub async fn test(test: &TestServer) {
 
     assert_eq!(BindSerializer::serialize(&records), EXPECTED_ZONE);
 
+    // Test webhook DNS provider
+    let requests = Arc::new(Mutex::new(Vec::<serde_json::Value>::new()));
+    let requests_ = requests.clone();
+    let _tx = spawn_mock_http_server(
+        test,
+        Arc::new(move |req: HttpMessage| {
+            assert_eq!(req.uri.path(), "/dns");
+            assert_eq!(req.method, Method::POST);
+            assert_eq!(
+                req.headers.get("authorization").map(|v| v.as_str()),
+                Some("Bearer dns-secret")
+            );
+            let request =
+                serde_json::from_slice::<serde_json::Value>(req.body.as_ref().unwrap()).unwrap();
+            let response = if request["action"] == "list" {
+                json!({"records": ["v=spf1 -all"]})
+            } else {
+                json!({})
+            };
+            requests_.lock().unwrap().push(request);
+            JsonResponse::new(&response).into_http_response()
+        }),
+        9091,
+    )
+    .await;
+    let webhook_id = account
+        .registry_create_object(DnsServer::Webhook(DnsServerWebhook {
+            url: "https://127.0.0.1:9091/dns".to_string(),
+            http_auth: HttpAuth::Bearer(HttpAuthBearer {
+                bearer_token: SecretKey::Value(SecretKeyValue {
+                    secret: "dns-secret".into(),
+                }),
+            }),
+            allow_invalid_certs: true,
+            description: "Webhook DNS server".to_string(),
+            ..Default::default()
+        }))
+        .await;
+    let updater = test
+        .server
+        .build_dns_updater(webhook_id)
+        .await
+        .unwrap()
+        .unwrap();
+    updater
+        .set_rrset(
+            "example.org",
+            "_acme-challenge.example.org",
+            dns_update::DnsRecordType::TXT,
+            vec![DnsRecord::TXT("challenge-token".into())],
+        )
+        .await
+        .unwrap();
+    assert!(matches!(
+        updater
+            .list_rrset("example.org", "example.org", dns_update::DnsRecordType::TXT)
+            .await
+            .unwrap()
+            .as_slice(),
+        [DnsRecord::TXT(txt)] if txt == "v=spf1 -all"
+    ));
+    assert!(
+        updater
+            .list_rrset("example.org", "example.org", dns_update::DnsRecordType::MX)
+            .await
+            .is_err()
+    );
+    updater
+        .remove_from_rrset(
+            "example.org",
+            "_acme-challenge.example.org",
+            dns_update::DnsRecordType::TXT,
+            vec![DnsRecord::TXT("challenge-token".into())],
+        )
+        .await
+        .unwrap();
+    assert_eq!(
+        std::mem::take(&mut *requests.lock().unwrap()),
+        vec![
+            json!({
+                "action": "set",
+                "origin": "example.org",
+                "name": "_acme-challenge.example.org",
+                "type": "TXT",
+                "ttl": 300,
+                "records": ["challenge-token"]
+            }),
+            json!({
+                "action": "list",
+                "origin": "example.org",
+                "name": "example.org",
+                "type": "TXT",
+                "records": []
+            }),
+            json!({
+                "action": "remove",
+                "origin": "example.org",
+                "name": "_acme-challenge.example.org",
+                "type": "TXT",
+                "records": ["challenge-token"]
+            }),
+        ]
+    );
+
     // Cleanup
     account
         .registry_update_object(
@@ -7,10 +7,11 @@
 use crate::{
     imap::idle,
     utils::{
-        imap::{ImapConnection, Type},
+        imap::{AssertResult, ImapConnection, Type},
         server::TestServerBuilder,
     },
 };
+use email::mailbox::INBOX_ID;
 use imap_proto::ResponseType;
 use registry::{
     schema::{
@@ -202,6 +203,43 @@ pub async fn cluster_tests() {
     let mut node1_client = imap_client("jdoe@example.com", "this is john's secret", 1).await;
     let mut node2_client = imap_client("jdoe@example.com", "this is john's secret", 2).await;
     idle::test(&mut node1_client, &mut node2_client, true).await;
+
+    // Messages imported through JMAP on another node wake up IDLE sessions
+    node2_client.send("IDLE").await;
+    node2_client
+        .assert_read(Type::Continuation, ResponseType::Ok)
+        .await;
+    let mut account = account;
+    account.http_listener_port = 11001;
+    account
+        .jmap_client()
+        .await
+        .email_import(
+            concat!(
+                "From: bill@example.com\r\n",
+                "To: jdoe@example.com\r\n",
+                "Subject: TPS Report (imported)\r\n",
+                "\r\n",
+                "Did you get the memo?"
+            )
+            .as_bytes()
+            .to_vec(),
+            [Id::from(INBOX_ID).to_string()],
+            None::<Vec<&str>>,
+            None,
+        )
+        .await
+        .unwrap();
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    node2_client
+        .assert_read(Type::Status, ResponseType::Ok)
+        .await
+        .assert_contains("STATUS \"INBOX\"")
+        .assert_contains("MESSAGES 2");
+    node2_client.send_raw("DONE").await;
+    node2_client
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await;
 }
 
 async fn imap_client(login: &str, secret: &str, node_id: u32) -> ImapConnection {
@@ -87,6 +87,7 @@ pub async fn test() {
         aliases[0],
         &EmailAlias {
             description: None,
+            expires_at: None,
             domain_id,
             enabled: true,
             name: "john.doe".to_string(),
@@ -96,6 +97,7 @@ pub async fn test() {
         aliases[1],
         &EmailAlias {
             description: None,
+            expires_at: None,
             domain_id,
             enabled: true,
             name: "j.doe".to_string(),
@@ -198,6 +200,7 @@ pub async fn test() {
         aliases[2],
         &EmailAlias {
             description: None,
+            expires_at: None,
             domain_id,
             enabled: true,
             name: "johnny".to_string(),
@@ -256,6 +259,7 @@ pub async fn test() {
         aliases[0],
         &EmailAlias {
             description: None,
+            expires_at: None,
             domain_id,
             enabled: true,
             name: "everyone".to_string(),
@@ -83,7 +83,16 @@ pub async fn test(
     imap_jane
         .assert_read(Type::Tagged, ResponseType::Ok)
         .await
-        .assert_equals("* LISTRIGHTS \"INBOX\" \"jdoe@example.com\" r l ws i et k x p a");
+        .assert_equals("* LISTRIGHTS \"INBOX\" \"jdoe@example.com\" \"\" r l ws i et k x p a");
+    imap_jane
+        .send("LISTRIGHTS INBOX jane.smith@example.com")
+        .await;
+    imap_jane
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_equals("* LISTRIGHTS \"INBOX\" \"jane.smith@example.com\" rlitewskxpa");
+    imap_jane.send("LISTRIGHTS INBOX nobody@example.com").await;
+    imap_jane.assert_read(Type::Tagged, ResponseType::No).await;
 
     // Jane shares her Inbox to John, expect a Shared Folders item in John's list
     imap_jane.send("SETACL INBOX jdoe@example.com lr").await;
@@ -120,6 +129,13 @@ pub async fn test(
         .assert_read(Type::Tagged, ResponseType::Ok)
         .await
         .assert_contains("Shared Folders/jane.smith@example.com/INBOX");
+    imap_bill
+        .send("MYRIGHTS \"Shared Folders/jane.smith@example.com/INBOX\"")
+        .await;
+    imap_bill
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_equals("* MYRIGHTS \"Shared Folders/jane.smith@example.com/INBOX\" rltewsx");
 
     // Namespace should now return the Shared Folders namespace
     imap_john.send("NAMESPACE").await;
@@ -128,6 +144,12 @@ pub async fn test(
         .await
         .assert_equals("* NAMESPACE ((\"\" \"/\")) ((\"Shared Folders\" \"/\")) NIL");
 
+    // John can't list the rights of Jane's Inbox without the administer right
+    imap_john
+        .send("LISTRIGHTS \"Shared Folders/jane.smith@example.com/INBOX\" jdoe@example.com")
+        .await;
+    imap_john.assert_read(Type::Tagged, ResponseType::No).await;
+
     // List John's right on Jane's Inbox
     imap_john
         .send("MYRIGHTS \"Shared Folders/jane.smith@example.com/INBOX\"")
@@ -148,8 +170,13 @@ pub async fn test(
 
     // Grant insert access to John on Jane's Inbox, and try inserting the
     // message again.
-    imap_jane.send("SETACL INBOX jdoe@example.com +i").await;
+    imap_jane.send("SETACL INBOX john.doe@example.com +i").await;
     imap_jane.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap_jane.send("GETACL INBOX").await;
+    imap_jane
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("\"jdoe@example.com\" irl");
     imap_john
         .send("MYRIGHTS \"Shared Folders/jane.smith@example.com/INBOX\"")
         .await;
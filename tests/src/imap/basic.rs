@@ -57,3 +57,45 @@ pub async fn test(imap: &mut ImapConnection, _imap_check: &mut ImapConnection) {
     imap.send_untagged("AGJvYXR5AG1jYm9hdGZhY2U=").await;
     imap.assert_read(Type::Tagged, ResponseType::No).await;
 }
+
+pub async fn test_client_policy(name: &str, secret: &str) {
+    println!("Running client policy tests...");
+
+    // Banned clients are disconnected
+    let mut imap = ImapConnection::connect(b"_z ").await;
+    imap.assert_read(Type::Untagged, ResponseType::Ok).await;
+    imap.send("ID (\"name\" \"BannedMail\" \"version\" \"2.1\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_contains("* BYE");
+    imap.assert_disconnect().await;
+
+    // Other versions of a restricted client are allowed
+    let mut imap = ImapConnection::connect(b"_z ").await;
+    imap.assert_read(Type::Untagged, ResponseType::Ok).await;
+    imap.authenticate(name, secret).await;
+    imap.send("ID (\"name\" \"OldMail\" \"version\" \"2.0\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("CREATE \"Client Policy\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("DELETE \"Client Policy\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+
+    // Outdated clients can only read
+    let mut imap = ImapConnection::connect(b"_z ").await;
+    imap.assert_read(Type::Untagged, ResponseType::Ok).await;
+    imap.authenticate(name, secret).await;
+    imap.send("ID (\"name\" \"OldMail\" \"version\" \"1.0\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("LIST \"\" \"*\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("CREATE \"Client Policy\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_contains("NOPERM");
+    imap.send("LOGOUT").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+}
@@ -174,4 +174,47 @@ pub async fn test(imap: &mut ImapConnection, imap_check: &mut ImapConnection) {
     imap.assert_read(Type::Tagged, ResponseType::Ok)
         .await
         .assert_contains("COPYUID");
+
+    // Mailbox counters are refreshed after changes made by other sessions
+    imap_check.send_ok("CREATE \"Stracciatella\"").await;
+    imap.send("STATUS \"Stracciatella\" (MESSAGES UNSEEN DELETED SIZE UIDNEXT)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("MESSAGES 0")
+        .assert_contains("UNSEEN 0")
+        .assert_contains("DELETED 0")
+        .assert_contains("SIZE 0")
+        .assert_contains("UIDNEXT 1");
+    let message = "From: test@example.com\r\nSubject: Stracciatella\r\n\r\nCreamy.\r\n";
+    imap_check
+        .send_ok(&format!(
+            "APPEND \"Stracciatella\" {{{}+}}\r\n{}",
+            message.len(),
+            message
+        ))
+        .await;
+    imap.send("STATUS \"Stracciatella\" (MESSAGES UNSEEN DELETED SIZE UIDNEXT)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("MESSAGES 1")
+        .assert_contains("UNSEEN 1")
+        .assert_contains("DELETED 0")
+        .assert_contains(&format!("SIZE {}", message.len()))
+        .assert_contains("UIDNEXT 2");
+    imap_check.send_ok("SELECT \"Stracciatella\"").await;
+    imap_check
+        .send_ok("STORE 1 +FLAGS (\\Seen \\Deleted)")
+        .await;
+    imap.send("STATUS \"Stracciatella\" (MESSAGES UNSEEN DELETED DELETED-STORAGE)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("MESSAGES 1")
+        .assert_contains("UNSEEN 0")
+        .assert_contains("DELETED 1")
+        .assert_contains(&format!("DELETED-STORAGE {}", message.len()));
+    imap_check.send_ok("UNSELECT").await;
+    imap_check.send_ok("DELETE \"Stracciatella\"").await;
 }
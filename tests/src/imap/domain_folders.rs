@@ -0,0 +1,98 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{AssertResult, Type};
+use crate::utils::server::TestServer;
+use imap_proto::ResponseType;
+use jmap_client::mailbox::Role;
+use registry::schema::prelude::{ObjectType, Property};
+use serde_json::json;
+
+pub async fn test(test: &TestServer) {
+    println!("Running domain default folder tests...");
+    let admin = test.account("admin@example.com");
+
+    // Domain folders override the global folders role by role
+    let domain_id = admin.find_or_create_domain("folders.org").await;
+    admin
+        .registry_update_object(
+            ObjectType::Domain,
+            domain_id,
+            json!({
+                Property::DefaultFolders: {
+                    "sent": {"name": "Outbox", "create": true, "subscribe": true},
+                    "archive": {"name": "Old Mail", "create": true, "subscribe": true},
+                    "shared": {"name": "Team Folders"},
+                },
+            }),
+        )
+        .await;
+    let account = admin
+        .create_user_account(
+            "folders@folders.org",
+            "domain folders + extra safety",
+            "Domain Folders",
+            &[],
+            vec![],
+        )
+        .await;
+
+    // JMAP uses the merged folder names
+    let client = account.jmap_client().await;
+    let mut request = client.build();
+    request.get_mailbox();
+    let mailboxes = request
+        .send()
+        .await
+        .unwrap()
+        .unwrap_method_responses()
+        .pop()
+        .unwrap()
+        .unwrap_get_mailbox()
+        .unwrap()
+        .take_list();
+    for (role, name) in [
+        (Role::Inbox, "Inbox"),
+        (Role::Trash, "Deleted Items"),
+        (Role::Sent, "Outbox"),
+        (Role::Archive, "Old Mail"),
+    ] {
+        assert!(
+            mailboxes
+                .iter()
+                .any(|mailbox| mailbox.role() == role && mailbox.name() == Some(name)),
+            "Expected {role:?} mailbox {name:?} in {mailboxes:?}"
+        );
+    }
+
+    // IMAP uses the merged folder names and the domain's shared folder
+    let mut imap = account.imap_client().await;
+    imap.send("LIST \"\" \"*\" RETURN (SPECIAL-USE)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_folders(
+            [
+                ("INBOX", vec![""]),
+                ("Deleted Items", vec!["\\Trash"]),
+                ("Old Mail", vec!["\\Archive"]),
+                ("Outbox", vec!["\\Sent"]),
+            ],
+            false,
+        );
+    imap.send("STATUS \"Team Folders\" (MESSAGES)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("STATUS \"Shared Folders\" (MESSAGES)").await;
+    imap.assert_read(Type::Tagged, ResponseType::No).await;
+
+    // Remove test data
+    imap.send_ok("LOGOUT").await;
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+    admin
+        .registry_destroy(ObjectType::Domain, [domain_id])
+        .await
+        .assert_destroyed(&[domain_id]);
+}
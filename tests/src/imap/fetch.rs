@@ -88,6 +88,15 @@ pub async fn test(imap: &mut ImapConnection, _imap_check: &mut ImapConnection) {
         .collect();
     imap.assert_last_contains_bytes(&fraktur_utf16_le);
 
+    // Partial fetches spanning the headers and the body
+    imap.send("UID FETCH 10 (BODY.PEEK[]<0.9> BODY.PEEK[]<100.1000> BODY.PEEK[]<1400.100>)")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("BODY[]<0> {9}")
+        .assert_contains("BODY[]<100> {1000}")
+        .assert_contains("BODY[]<1400> {57}");
+
     // We are in EXAMINE mode, fetching body should not set \Seen
     imap.send("UID FETCH 10 (FLAGS)").await;
     imap.assert_read(Type::Tagged, ResponseType::Ok)
@@ -0,0 +1,165 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{AssertResult, ImapConnection, Type};
+use crate::utils::{server::TestServer, sieve::SieveConnection};
+use imap_proto::ResponseType;
+use registry::schema::{prelude::Property, structs::Imap};
+
+pub async fn test(test: &TestServer) {
+    println!("Running IMAPSIEVE tests...");
+
+    // Upload script
+    let account = test.account("jdoe@example.com");
+    let mut sieve = SieveConnection::connect().await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.authenticate(account.name(), account.secret()).await;
+    sieve
+        .send_literal(
+            "PUTSCRIPT \"report\" ",
+            concat!(
+                "require [\"imap4flags\", \"environment\", \"variables\"];\r\n",
+                "if environment :is \"imap.cause\" \"COPY\" {\r\n",
+                "  addflag \"$Reported\";\r\n",
+                "}\r\n",
+                "if environment :is \"imap.cause\" \"FLAG\" {\r\n",
+                "  discard;\r\n",
+                "}\r\n"
+            ),
+        )
+        .await;
+    sieve.assert_read(ResponseType::Ok).await;
+
+    // Connect to IMAP
+    let mut imap = ImapConnection::connect(b"_x ").await;
+    imap.assert_read(Type::Untagged, ResponseType::Ok).await;
+    imap.authenticate(account.name(), account.secret()).await;
+    imap.send("CAPABILITY").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("METADATA")
+        .assert_contains("IMAPSIEVE=sieve://");
+    imap.send_ok("CREATE Reported").await;
+
+    // Bind script to mailbox
+    imap.send("SETMETADATA Reported (/shared/imapsieve/script \"unknown\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_response_code("NONEXISTENT");
+    imap.send("SETMETADATA Reported (/shared/comment \"hello\")")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::No).await;
+    imap.send_ok("SETMETADATA Reported (/shared/imapsieve/script \"report\")")
+        .await;
+    imap.send("GETMETADATA Reported /shared/imapsieve/script")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("* METADATA \"Reported\" (/shared/imapsieve/script \"report\")");
+    imap.send("GETMETADATA \"\" /shared/imapsieve/script").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("/shared/imapsieve/script NIL");
+
+    // Copying a message into the mailbox runs the script
+    imap.send_ok("CREATE ImapSieve").await;
+    imap.append(
+        "ImapSieve",
+        "From: john@example.org\r\nSubject: report me\r\n\r\nTest\r\n",
+    )
+    .await;
+    imap.send_ok("SELECT ImapSieve").await;
+    imap.send_ok("COPY 1 Reported").await;
+    imap.send_ok("SELECT Reported").await;
+    imap.send("FETCH 1 (FLAGS)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("$Reported");
+
+    // Changing flags runs the script
+    imap.send_ok("STORE 1 +FLAGS (\\Seen)").await;
+    imap.send("STATUS Reported (MESSAGES)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("MESSAGES 0");
+
+    // Remove binding
+    imap.send_ok("SETMETADATA Reported (/shared/imapsieve/script NIL)")
+        .await;
+    imap.send("GETMETADATA Reported /shared/imapsieve/script")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("/shared/imapsieve/script NIL");
+
+    // Messages appended to the Inbox are filtered by the active script
+    sieve
+        .send_literal(
+            "PUTSCRIPT \"append\" ",
+            concat!(
+                "require \"fileinto\";\r\n",
+                "if header :contains \"Subject\" \"filter me\" {\r\n",
+                "  fileinto \"Filtered\";\r\n",
+                "}\r\n"
+            ),
+        )
+        .await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("SETACTIVE \"append\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    imap.send_ok("CREATE Filtered").await;
+    let admin = test.account("admin");
+    admin
+        .registry_update_setting(
+            Imap {
+                append_sieve: true,
+                ..Default::default()
+            },
+            &[Property::AppendSieve],
+        )
+        .await;
+    admin.reload_settings().await;
+    let message = "From: john@example.org\r\nSubject: filter me\r\n\r\nTest\r\n";
+    imap.send(&format!("APPEND INBOX {{{}}}", message.len()))
+        .await;
+    imap.assert_read(Type::Continuation, ResponseType::Ok).await;
+    imap.send_untagged(message).await;
+    let response = imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    assert!(
+        !response.iter().any(|line| line.contains("APPENDUID")),
+        "{response:?}"
+    );
+    imap.send("STATUS Filtered (MESSAGES)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("MESSAGES 1");
+
+    // Appending to other mailboxes bypasses the script
+    imap.append("ImapSieve", message).await;
+    imap.send("STATUS Filtered (MESSAGES)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("MESSAGES 1");
+    admin
+        .registry_update_setting(Imap::default(), &[Property::AppendSieve])
+        .await;
+    admin.reload_settings().await;
+    sieve.send("SETACTIVE \"\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("DELETESCRIPT \"append\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+
+    // Clean up
+    imap.send_ok("UNSELECT").await;
+    for mailbox in ["Reported", "ImapSieve", "Filtered"] {
+        imap.send_ok(&format!("DELETE {mailbox}")).await;
+    }
+    imap.send("LOGOUT").await;
+    imap.assert_read(Type::Untagged, ResponseType::Bye).await;
+    sieve.send("DELETESCRIPT \"report\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+}
@@ -6,6 +6,7 @@
 
 use imap::op::list::matches_pattern;
 use imap_proto::ResponseType;
+use jmap_client::mailbox;
 
 use crate::utils::server::TestServer;
 
@@ -238,6 +239,20 @@ pub async fn test(
             true,
         );
 
+    // Combined subscribed and special-use filters with status
+    imap.send("SUBSCRIBE \"Recycle Bin\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    imap.send("LIST (SUBSCRIBED SPECIAL-USE) \"\" \"*\" RETURN (STATUS (MESSAGES UNSEEN))")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_folders([("Recycle Bin", ["Subscribed", "Trash"])], false)
+        .assert_count("* LIST ", 1)
+        .assert_contains("* STATUS \"Recycle Bin\" (MESSAGES ")
+        .assert_not_contains("* STATUS \"INBOX\"");
+    imap.send("UNSUBSCRIBE \"Recycle Bin\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+
     // Recursive match including children
     imap.send("LIST (SUBSCRIBED RECURSIVEMATCH) \"\" \"*\" RETURN (CHILDREN)")
         .await;
@@ -287,6 +302,40 @@ pub async fn test(
             .assert_folders([("INBOX", ["Subscribed", "HasNoChildren"])], true);
     }
 
+    // Subscriptions changed over JMAP are visible over IMAP and vice versa
+    let client = test.account("jdoe@example.com").jmap_client().await;
+    let broccoli_id = client
+        .mailbox_query(
+            mailbox::query::Filter::name("Broccoli").into(),
+            None::<Vec<_>>,
+        )
+        .await
+        .unwrap()
+        .take_ids()
+        .pop()
+        .unwrap();
+    client.mailbox_subscribe(&broccoli_id, true).await.unwrap();
+    imap.send("LIST (SUBSCRIBED) \"\" \"*\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_folders(
+            [
+                ("INBOX", ["Subscribed"]),
+                ("Veggies/Green/Broccoli", ["Subscribed"]),
+            ],
+            true,
+        );
+    imap.send("UNSUBSCRIBE \"Veggies/Green/Broccoli\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok).await;
+    assert!(
+        !client
+            .mailbox_get(&broccoli_id, None::<Vec<_>>)
+            .await
+            .unwrap()
+            .unwrap()
+            .is_subscribed()
+    );
+
     // LIST Filters
     imap.send("LIST \"\" \"%\"").await;
     imap.assert_read(Type::Tagged, ResponseType::Ok)
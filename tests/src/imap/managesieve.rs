@@ -92,6 +92,33 @@ pub async fn test(test: &TestServer) {
         .assert_contains("minimalist script")
         .assert_contains("holidays\" ACTIVE");
 
+    // RollbackScript restores the version replaced on the active script
+    sieve.send("ROLLBACKSCRIPT \"minimalist script\"").await;
+    sieve
+        .assert_read(ResponseType::No)
+        .await
+        .assert_contains("NONEXISTENT");
+    sieve.send("PUTSCRIPT \"holidays\" \"keep;\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("RENAMESCRIPT \"holidays\" \"trip\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("ROLLBACKSCRIPT \"trip\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("GETSCRIPT \"trip\"").await;
+    sieve
+        .assert_read(ResponseType::Ok)
+        .await
+        .assert_contains("Gone fishin'");
+    sieve.send("RENAMESCRIPT \"trip\" \"holidays\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("ROLLBACKSCRIPT \"holidays\"").await;
+    sieve.assert_read(ResponseType::Ok).await;
+    sieve.send("GETSCRIPT \"holidays\"").await;
+    sieve
+        .assert_read(ResponseType::Ok)
+        .await
+        .assert_contains("keep;");
+
     // Deleting an active script should not be allowed
     sieve.send("DELETESCRIPT \"holidays\"").await;
     sieve
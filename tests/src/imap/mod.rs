@@ -11,14 +11,18 @@ pub mod basic;
 pub mod body_structure;
 pub mod condstore;
 pub mod copy_move;
+pub mod domain_folders;
 pub mod fetch;
 pub mod idle;
+pub mod imapsieve;
 pub mod mailbox;
 pub mod managesieve;
 pub mod pop;
+pub mod sasl_external;
 pub mod search;
 pub mod store;
 pub mod thread;
+pub mod virtual_folder;
 
 use crate::utils::{
     imap::{AssertResult, ImapConnection, Type},
@@ -31,11 +35,11 @@ use registry::{
         enums::{Permission, SpecialUse},
         prelude::ObjectType,
         structs::{
-            Email, EmailFolder, Expression, Imap, MemoryLookupKey, MtaStageAuth, MtaStageData,
-            SpamClassifier, SpamTag, SpamTagScore,
+            Email, EmailFolder, Expression, ExpressionMatch, Imap, MemoryLookupKey, MtaStageAuth,
+            MtaStageData, SpamClassifier, SpamTag, SpamTagScore,
         },
     },
-    types::float::Float,
+    types::{float::Float, list::List},
 };
 use serde_json::json;
 use std::{path::PathBuf, time::Instant};
@@ -127,6 +131,19 @@ pub async fn imap_tests() {
     admin
         .registry_create_object(Imap {
             allow_plain_text_auth: true,
+            client_policy: Expression {
+                match_: List::from_iter([
+                    ExpressionMatch {
+                        if_: "client.name == 'BannedMail'".into(),
+                        then: "reject".into(),
+                    },
+                    ExpressionMatch {
+                        if_: "client.name == 'OldMail' && client.version == '1.0'".into(),
+                        then: "read_only".into(),
+                    },
+                ]),
+                else_: "allow".into(),
+            },
             ..Default::default()
         })
         .await;
@@ -231,6 +248,7 @@ pub async fn imap_tests() {
     for imap in [&mut imap, &mut imap_check] {
         imap.authenticate(account.name(), account.secret()).await;
     }
+    basic::test_client_policy(account.name(), account.secret()).await;
 
     // Delete folders
     for mailbox in ["Drafts", "Junk Mail", "Sent Items"] {
@@ -248,6 +266,8 @@ pub async fn imap_tests() {
     idle::test(&mut imap, &mut imap_check, false).await;
     condstore::test(&mut imap, &mut imap_check).await;
     acl::test(&mut imap, &mut imap_check, &test).await;
+    virtual_folder::test(&test).await;
+    domain_folders::test(&test).await;
 
     // Logout
     for imap in [&mut imap, &mut imap_check] {
@@ -264,6 +284,9 @@ pub async fn imap_tests() {
     // Run ManageSieve tests
     managesieve::test(&test).await;
 
+    // Run IMAPSIEVE tests
+    imapsieve::test(&test).await;
+
     // Run POP3 tests
     pop::test(&test).await;
 
@@ -10,6 +10,11 @@ use crate::utils::{
     server::TestServer,
     smtp::SmtpConnection,
 };
+use registry::schema::{
+    prelude::Property,
+    structs::{BandwidthLimit, Imap},
+};
+use std::time::{Duration, Instant};
 
 pub async fn test(test: &TestServer) {
     println!("Running POP3 tests...");
@@ -46,7 +51,19 @@ pub async fn test(test: &TestServer) {
     pop3.assert_read(ResponseType::Multiline)
         .await
         .assert_contains("SASL PLAIN")
-        .assert_contains("IMPLEMENTATION");
+        .assert_contains("IMPLEMENTATION")
+        .assert_not_contains("STLS");
+
+    // STLS is not available over implicit TLS
+    pop3.send("STLS").await;
+    pop3.assert_read(ResponseType::Err).await;
+
+    // Cancel a SASL exchange
+    pop3.send("AUTH OAUTHBEARER").await;
+    pop3.send("*").await;
+    pop3.assert_read(ResponseType::Err)
+        .await
+        .assert_contains("+ ");
 
     // Noop
     pop3.send("NOOP").await;
@@ -134,6 +151,26 @@ pub async fn test(test: &TestServer) {
         .assert_contains("+OK 201 octets")
         .assert_contains("Subject: TPS Report 2")
         .assert_not_contains("I'm going to need those TPS 2 reports ASAP.");
+    pop3.send("TOP 2 0").await;
+    pop3.assert_read(ResponseType::Multiline)
+        .await
+        .assert_contains("Subject: TPS Report 1")
+        .assert_contains("X-Spam-Status: No")
+        .assert_not_contains("I'm going to need those TPS 1 reports ASAP.");
+
+    // Pipelined commands
+    pop3.send("STAT\r\nTOP 1 0\r\nLIST 3\r\nNOOP").await;
+    pop3.assert_read(ResponseType::Ok)
+        .await
+        .assert_contains("+OK 3 603");
+    pop3.assert_read(ResponseType::Multiline)
+        .await
+        .assert_contains("Subject: TPS Report 0")
+        .assert_not_contains("I'm going to need those TPS 0 reports ASAP.");
+    pop3.assert_read(ResponseType::Ok)
+        .await
+        .assert_contains("+OK 3 201");
+    pop3.assert_read(ResponseType::Ok).await;
 
     // DELE + RSET + QUIT (should not delete messages)
     pop3.send("DELE 1").await;
@@ -183,4 +220,75 @@ pub async fn test(test: &TestServer) {
         .await
         .assert_contains("+OK 0 0");
     pop3.send("QUIT").await;
+
+    // Message downloads over IMAP and POP3 share the account's bandwidth limit
+    let admin = test.account("admin@example.com");
+    admin
+        .registry_update_setting(
+            Imap {
+                account_bandwidth: Some(BandwidthLimit {
+                    rate: 20_000,
+                    burst: 20_000,
+                }),
+                ..Default::default()
+            },
+            &[Property::AccountBandwidth],
+        )
+        .await;
+    admin.reload_settings().await;
+    let account = admin
+        .create_user_account(
+            "throttled@example.com",
+            "this download is throttled",
+            "Throttled",
+            &[],
+            vec![],
+        )
+        .await;
+    let mut imap = account.imap_client().await;
+    imap.append(
+        "INBOX",
+        &format!(
+            "From: bill@example.com\r\nSubject: Large report\r\n\r\n{}",
+            format!("{}\r\n", "x".repeat(98)).repeat(250)
+        ),
+    )
+    .await;
+    imap.send_ok("SELECT INBOX").await;
+    let time = Instant::now();
+    imap.send_ok("FETCH 1 (FLAGS)").await;
+    assert!(
+        time.elapsed() < Duration::from_millis(200),
+        "{:?}",
+        time.elapsed()
+    );
+    let time = Instant::now();
+    imap.send_ok("FETCH 1 BODY[]").await;
+    assert!(
+        time.elapsed() >= Duration::from_millis(200),
+        "{:?}",
+        time.elapsed()
+    );
+    let mut pop3 = Pop3Connection::connect().await;
+    pop3.authenticate(account.name(), account.secret()).await;
+    let time = Instant::now();
+    pop3.send("RETR 1").await;
+    pop3.assert_read(ResponseType::Multiline)
+        .await
+        .assert_contains("Subject: Large report");
+    assert!(
+        time.elapsed() >= Duration::from_millis(800),
+        "{:?}",
+        time.elapsed()
+    );
+    pop3.send("QUIT").await;
+    imap.send_ok("LOGOUT").await;
+
+    // Remove test data
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+    admin
+        .registry_update_setting(Imap::default(), &[Property::AccountBandwidth])
+        .await;
+    admin.reload_settings().await;
 }
@@ -0,0 +1,354 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::server::TestServerBuilder;
+use base64::{Engine, engine::general_purpose};
+use rcgen::{
+    BasicConstraints, CertificateParams, DnType, ExtendedKeyUsagePurpose, Ia5String, IsCa, Issuer,
+    KeyPair, KeyUsagePurpose, SanType,
+};
+use registry::{
+    schema::{
+        enums::NetworkListenerProtocol,
+        prelude::SocketAddr,
+        structs::{
+            Certificate, NetworkListener, PublicText, PublicTextValue, SecretText, SecretTextValue,
+        },
+    },
+    types::{datetime::UTCDateTime, map::Map},
+};
+use rustls::{ClientConfig, RootCertStore};
+use rustls_pki_types::{CertificateDer, PrivateKeyDer, PrivatePkcs8KeyDer, ServerName};
+use std::{str::FromStr, sync::Arc, time::Duration};
+use store::write::now;
+use tokio::{
+    io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines, ReadHalf, WriteHalf},
+    net::TcpStream,
+};
+use tokio_rustls::{TlsConnector, client::TlsStream};
+
+const IMAP_PORT: u16 = 19055;
+const POP3_PORT: u16 = 19056;
+
+#[tokio::test(flavor = "multi_thread")]
+pub async fn sasl_external_tests() {
+    let ca = TestCa::new();
+    let (server_cert, server_key) = ca.issue_server("mail.example.org");
+    let mut builder = TestServerBuilder::new("sasl_external_tests")
+        .await
+        .with_http_listener(19057)
+        .await
+        .with_object(Certificate {
+            certificate: PublicText::Text(PublicTextValue {
+                value: server_cert.pem(),
+            }),
+            private_key: SecretText::Text(SecretTextValue {
+                secret: server_key.serialize_pem(),
+            }),
+            issuer: "Stalwart Test CA".to_string(),
+            not_valid_after: UTCDateTime::from_timestamp((now() + 86400) as i64),
+            subject_alternative_names: Map::new(vec!["mail.example.org".to_string()]),
+            ..Default::default()
+        })
+        .await;
+    for (protocol, name, port) in [
+        (NetworkListenerProtocol::Imap, "imap-external", IMAP_PORT),
+        (NetworkListenerProtocol::Pop3, "pop3-external", POP3_PORT),
+    ] {
+        builder = builder
+            .with_object(NetworkListener {
+                bind: Map::new(vec![
+                    SocketAddr::from_str(&format!("0.0.0.0:{port}")).unwrap(),
+                ]),
+                name: name.to_string(),
+                protocol,
+                use_tls: true,
+                tls_implicit: true,
+                tls_client_auth: true,
+                tls_client_ca: Some(PublicText::Text(PublicTextValue {
+                    value: ca.pem.clone(),
+                })),
+                ..Default::default()
+            })
+            .await;
+    }
+    let test = builder.build().await;
+    let admin = test.create_admin_account("admin@example.com").await;
+    let account = admin
+        .create_user_account(
+            "jdoe@example.com",
+            "12345 + extra safety",
+            "John Doe",
+            &["john.doe@example.com"],
+            vec![],
+        )
+        .await;
+
+    let jdoe = ca.issue_client("jdoe@example.com");
+    let stranger = ca.issue_client("stranger@example.org");
+
+    // EXTERNAL is only offered to clients presenting a certificate
+    println!("Running IMAP SASL EXTERNAL tests...");
+    let mut imap = TlsClient::connect(IMAP_PORT, &ca, None).await;
+    imap.read_line().await;
+    let capabilities = imap.imap("a", "CAPABILITY").await;
+    assert!(
+        !capabilities
+            .iter()
+            .any(|line| line.contains("AUTH=EXTERNAL")),
+        "{capabilities:?}"
+    );
+    imap.imap("b", "AUTHENTICATE EXTERNAL =")
+        .await
+        .assert_last_starts_with("b NO [CANNOT]");
+
+    // The authorization identity has to be one of the certificate addresses
+    let mut imap = TlsClient::connect(IMAP_PORT, &ca, Some(&jdoe)).await;
+    imap.read_line().await;
+    let capabilities = imap.imap("a", "CAPABILITY").await;
+    assert!(
+        capabilities
+            .iter()
+            .any(|line| line.contains("AUTH=EXTERNAL")),
+        "{capabilities:?}"
+    );
+    imap.imap(
+        "b",
+        &format!("AUTHENTICATE EXTERNAL {}", authz_id("john.doe@example.com")),
+    )
+    .await
+    .assert_last_starts_with("b NO");
+    imap.imap(
+        "c",
+        &format!("AUTHENTICATE EXTERNAL {}", authz_id("JDoe@Example.com")),
+    )
+    .await
+    .assert_last_starts_with("c OK");
+    imap.imap("d", "SELECT INBOX")
+        .await
+        .assert_last_starts_with("d OK");
+
+    // An empty authorization identity maps to the certificate owner
+    let mut imap = TlsClient::connect(IMAP_PORT, &ca, Some(&jdoe)).await;
+    imap.read_line().await;
+    imap.imap("a", "AUTHENTICATE EXTERNAL =")
+        .await
+        .assert_last_starts_with("a OK");
+
+    // Certificates without a matching account count as failed attempts
+    let mut imap = TlsClient::connect(IMAP_PORT, &ca, Some(&stranger)).await;
+    imap.read_line().await;
+    for tag in ["a", "b", "c"] {
+        imap.imap(tag, "AUTHENTICATE EXTERNAL =")
+            .await
+            .assert_last_starts_with(&format!("{tag} NO"));
+    }
+    imap.send("d AUTHENTICATE EXTERNAL =").await;
+    imap.assert_disconnected().await;
+
+    // POP3 follows the same rules
+    println!("Running POP3 SASL EXTERNAL tests...");
+    let mut pop3 = TlsClient::connect(POP3_PORT, &ca, None).await;
+    pop3.read_line().await;
+    let capabilities = pop3.pop3_capa().await;
+    assert!(
+        !capabilities
+            .iter()
+            .any(|line| line.starts_with("SASL") && line.contains("EXTERNAL")),
+        "{capabilities:?}"
+    );
+    pop3.pop3("AUTH EXTERNAL =")
+        .await
+        .assert_last_starts_with("-ERR");
+
+    let mut pop3 = TlsClient::connect(POP3_PORT, &ca, Some(&jdoe)).await;
+    pop3.read_line().await;
+    let capabilities = pop3.pop3_capa().await;
+    assert!(
+        capabilities
+            .iter()
+            .any(|line| line.starts_with("SASL") && line.contains("EXTERNAL")),
+        "{capabilities:?}"
+    );
+    pop3.pop3(&format!(
+        "AUTH EXTERNAL {}",
+        authz_id("john.doe@example.com")
+    ))
+    .await
+    .assert_last_starts_with("-ERR");
+    pop3.pop3(&format!("AUTH EXTERNAL {}", authz_id("jdoe@example.com")))
+        .await
+        .assert_last_starts_with("+OK");
+    pop3.pop3("STAT").await.assert_last_starts_with("+OK 0 0");
+
+    let mut pop3 = TlsClient::connect(POP3_PORT, &ca, Some(&stranger)).await;
+    pop3.read_line().await;
+    for _ in 0..3 {
+        pop3.pop3("AUTH EXTERNAL =")
+            .await
+            .assert_last_starts_with("-ERR");
+    }
+    pop3.send("AUTH EXTERNAL =").await;
+    pop3.assert_disconnected().await;
+
+    // Remove test data
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+}
+
+fn authz_id(email: &str) -> String {
+    general_purpose::STANDARD.encode(email)
+}
+
+struct TestCa {
+    issuer: Issuer<'static, KeyPair>,
+    cert: CertificateDer<'static>,
+    pem: String,
+}
+
+struct TestIdentity {
+    cert: CertificateDer<'static>,
+    key: PrivateKeyDer<'static>,
+}
+
+impl TestCa {
+    fn new() -> Self {
+        let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        params
+            .distinguished_name
+            .push(DnType::CommonName, "Stalwart Test CA");
+        params.is_ca = IsCa::Ca(BasicConstraints::Unconstrained);
+        params.key_usages = vec![KeyUsagePurpose::KeyCertSign, KeyUsagePurpose::CrlSign];
+        let key = KeyPair::generate().unwrap();
+        let cert = params.self_signed(&key).unwrap();
+
+        TestCa {
+            cert: cert.der().clone(),
+            pem: cert.pem(),
+            issuer: Issuer::new(params, key),
+        }
+    }
+
+    fn issue_server(&self, hostname: &str) -> (rcgen::Certificate, KeyPair) {
+        let params = CertificateParams::new(vec![hostname.to_string()]).unwrap();
+        let key = KeyPair::generate().unwrap();
+        (params.signed_by(&key, &self.issuer).unwrap(), key)
+    }
+
+    fn issue_client(&self, email: &str) -> TestIdentity {
+        let mut params = CertificateParams::new(Vec::<String>::new()).unwrap();
+        params.distinguished_name.push(DnType::CommonName, email);
+        params.subject_alt_names = vec![SanType::Rfc822Name(Ia5String::try_from(email).unwrap())];
+        params.extended_key_usages = vec![ExtendedKeyUsagePurpose::ClientAuth];
+        let key = KeyPair::generate().unwrap();
+
+        TestIdentity {
+            cert: params.signed_by(&key, &self.issuer).unwrap().der().clone(),
+            key: PrivateKeyDer::Pkcs8(PrivatePkcs8KeyDer::from(key.serialize_der())),
+        }
+    }
+}
+
+struct TlsClient {
+    reader: Lines<BufReader<ReadHalf<TlsStream<TcpStream>>>>,
+    writer: WriteHalf<TlsStream<TcpStream>>,
+}
+
+trait AssertLastLine {
+    fn assert_last_starts_with(&self, prefix: &str);
+}
+
+impl AssertLastLine for Vec<String> {
+    fn assert_last_starts_with(&self, prefix: &str) {
+        if !self.last().is_some_and(|line| line.starts_with(prefix)) {
+            panic!("Expected {prefix:?} from server but got: {self:?}");
+        }
+    }
+}
+
+impl TlsClient {
+    async fn connect(port: u16, ca: &TestCa, identity: Option<&TestIdentity>) -> Self {
+        let mut roots = RootCertStore::empty();
+        roots.add(ca.cert.clone()).unwrap();
+        let config = ClientConfig::builder().with_root_certificates(roots);
+        let config = if let Some(identity) = identity {
+            config
+                .with_client_auth_cert(vec![identity.cert.clone()], identity.key.clone_key())
+                .unwrap()
+        } else {
+            config.with_no_client_auth()
+        };
+        let (reader, writer) = tokio::io::split(
+            TlsConnector::from(Arc::new(config))
+                .connect(
+                    ServerName::try_from("mail.example.org").unwrap().to_owned(),
+                    TcpStream::connect(format!("127.0.0.1:{port}"))
+                        .await
+                        .unwrap(),
+                )
+                .await
+                .unwrap(),
+        );
+
+        TlsClient {
+            reader: BufReader::new(reader).lines(),
+            writer,
+        }
+    }
+
+    async fn imap(&mut self, tag: &str, command: &str) -> Vec<String> {
+        self.send(&format!("{tag} {command}")).await;
+        let tag = format!("{tag} ");
+        self.read_until(|line| line.starts_with(&tag)).await
+    }
+
+    async fn pop3(&mut self, command: &str) -> Vec<String> {
+        self.send(command).await;
+        self.read_until(|line| line.starts_with("+OK") || line.starts_with("-ERR"))
+            .await
+    }
+
+    async fn pop3_capa(&mut self) -> Vec<String> {
+        self.send("CAPA").await;
+        self.read_until(|line| line == ".").await
+    }
+
+    async fn send(&mut self, text: &str) {
+        self.writer.write_all(text.as_bytes()).await.unwrap();
+        self.writer.write_all(b"\r\n").await.unwrap();
+    }
+
+    async fn read_line(&mut self) -> String {
+        match tokio::time::timeout(Duration::from_millis(1500), self.reader.next_line()).await {
+            Ok(Ok(Some(line))) => line,
+            Ok(Ok(None)) => panic!("Connection closed by server."),
+            Ok(Err(err)) => panic!("Connection broken: {err}"),
+            Err(_) => panic!("Timeout while waiting for server response."),
+        }
+    }
+
+    async fn read_until(&mut self, is_done: impl Fn(&str) -> bool) -> Vec<String> {
+        let mut lines = Vec::new();
+        loop {
+            let line = self.read_line().await;
+            let done = is_done(&line);
+            lines.push(line);
+            if done {
+                return lines;
+            }
+        }
+    }
+
+    async fn assert_disconnected(&mut self) {
+        loop {
+            match tokio::time::timeout(Duration::from_millis(1500), self.reader.next_line()).await {
+                Ok(Ok(Some(_))) => {}
+                Ok(Ok(None) | Err(_)) => return,
+                Err(_) => panic!("Expected the server to close the connection."),
+            }
+        }
+    }
+}
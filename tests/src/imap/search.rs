@@ -88,6 +88,13 @@ pub async fn test(imap: &mut ImapConnection, imap_check: &mut ImapConnection, te
         .await
         .assert_equals("* SEARCH 1 2");
 
+    // An unset result variable is an empty set
+    imap_check.send("UID FETCH $ (FLAGS)").await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("FETCH (", 0);
+
     // Saved search
     imap_check.send(
         "UID SEARCH RETURN (SAVE ALL) OR OR FROM nathaniel FROM vandelay OR SUBJECT rfc FROM gore",
@@ -118,6 +125,14 @@ pub async fn test(imap: &mut ImapConnection, imap_check: &mut ImapConnection, te
         .await
         .assert_contains("MIN 2 MAX 9");
 
+    // Pipelined operations on the saved results
+    imap_check.send("UID FETCH $ (UID)").await;
+    imap_check
+        .assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_count("FETCH (", 6)
+        .assert_contains("UID 10");
+
     // Sort
     imap_check
         .send("UID SORT (REVERSE SUBJECT REVERSE DATE) UTF-8 FROM Nathaniel")
@@ -7,6 +7,7 @@
 use super::{AssertResult, ImapConnection, Type};
 use crate::utils::server::TestServer;
 use imap_proto::ResponseType;
+use registry::schema::{prelude::Property, structs::Email};
 
 pub async fn test(imap: &mut ImapConnection, _imap_check: &mut ImapConnection, test: &TestServer) {
     println!("Running STORE tests...");
@@ -72,4 +73,75 @@ pub async fn test(imap: &mut ImapConnection, _imap_check: &mut ImapConnection, t
         .await
         .assert_count("FLAGS", 3)
         .assert_count("Answered", 0);
+
+    // Keyword limits are enforced when keywords are added
+    let admin = test.account("admin@example.com");
+    admin
+        .registry_update_setting(
+            Email {
+                max_message_keywords: 3,
+                max_custom_keywords: 2,
+                ..Default::default()
+            },
+            &[Property::MaxMessageKeywords, Property::MaxCustomKeywords],
+        )
+        .await;
+    admin.reload_settings().await;
+    let account = admin
+        .create_user_account(
+            "keywords@example.com",
+            "these keywords are limited",
+            "Keywords",
+            &[],
+            vec![],
+        )
+        .await;
+    let mut imap = account.imap_client().await;
+    for subject in ["First", "Second"] {
+        let message = format!("Subject: {subject}\r\n\r\n{subject} message.\r\n");
+        imap.send_ok(&format!(
+            "APPEND INBOX {{{}+}}\r\n{}",
+            message.len(),
+            message
+        ))
+        .await;
+    }
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("STORE 1 +FLAGS (ProjectA ProjectB)").await;
+    imap.send("STORE 2 +FLAGS (ProjectC)").await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_contains("[LIMIT]");
+    imap.send_ok("STORE 2 +FLAGS (ProjectA)").await;
+    imap.send("STORE 1 +FLAGS (\\Seen \\Flagged)").await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_contains("[LIMIT]");
+    imap.send("APPEND INBOX (ProjectD) {5+}\r\nHello").await;
+    imap.assert_read(Type::Tagged, ResponseType::No)
+        .await
+        .assert_contains("[LIMIT]");
+
+    // Custom keywords no longer set on any message are released
+    imap.send_ok("STORE 1:2 -FLAGS (ProjectA ProjectB)").await;
+    imap.send_ok("STORE 2 +FLAGS (ProjectC ProjectD)").await;
+    imap.send("FETCH 1:2 (FLAGS)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("ProjectC")
+        .assert_contains("ProjectD")
+        .assert_count("ProjectA", 0)
+        .assert_count("ProjectB", 0);
+
+    // Remove test data
+    imap.send_ok("LOGOUT").await;
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+    admin
+        .registry_update_setting(
+            Email::default(),
+            &[Property::MaxMessageKeywords, Property::MaxCustomKeywords],
+        )
+        .await;
+    admin.reload_settings().await;
 }
@@ -0,0 +1,283 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use super::{AssertResult, ImapConnection, Type};
+use crate::{jmap::mail::acl::assert_forbidden, utils::server::TestServer};
+use email::mailbox::virtual_folder::ALL_MAIL_ID;
+use imap_proto::ResponseType;
+use jmap_client::{email::query::Filter, mailbox, principal::ACL};
+use registry::{
+    schema::{
+        enums::{SearchFolderRole, VirtualFolderType},
+        prelude::{ObjectType, Property},
+        structs::{Email, EmailFolder, EmailSearchFolder},
+    },
+    types::{duration::Duration, list::List},
+};
+use serde_json::json;
+use types::id::Id;
+use utils::map::vec_map::VecMap;
+
+pub async fn test(test: &TestServer) {
+    println!("Running virtual folder tests...");
+    let admin = test.account("admin@example.com");
+    admin
+        .registry_update_setting(
+            Email {
+                virtual_folders: VecMap::from_iter(
+                    [
+                        (VirtualFolderType::All, "All Mail"),
+                        (VirtualFolderType::Flagged, "Flagged"),
+                        (VirtualFolderType::Recent, "Recent"),
+                    ]
+                    .into_iter()
+                    .map(|(folder_type, name)| {
+                        (
+                            folder_type,
+                            EmailFolder {
+                                name: name.into(),
+                                create: true,
+                                ..Default::default()
+                            },
+                        )
+                    }),
+                ),
+                recent_folder_period: Duration::from_millis(86_400_000),
+                search_folders: List::from_iter([EmailSearchFolder {
+                    name: "Boss".into(),
+                    query: "UNSEEN FROM \"boss@example.com\"".into(),
+                    role: SearchFolderRole::Important,
+                    subscribe: true,
+                }]),
+                ..Default::default()
+            },
+            &[
+                Property::VirtualFolders,
+                Property::RecentFolderPeriod,
+                Property::SearchFolders,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+    let domain_id = admin.find_or_create_domain("virtual.org").await;
+    admin
+        .registry_update_object(
+            ObjectType::Domain,
+            domain_id,
+            json!({
+                Property::ShowVirtualFolders: true,
+            }),
+        )
+        .await;
+    let account = admin
+        .create_user_account(
+            "news@virtual.org",
+            "virtual folders + extra safety",
+            "Virtual Folders",
+            &[],
+            vec![],
+        )
+        .await;
+    let mut imap = account.imap_client().await;
+
+    imap.send("LIST \"\" \"*\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_folders(
+            [
+                ("All Mail", vec!["\\All"]),
+                ("Flagged", vec!["\\Flagged"]),
+                ("Recent", vec![]),
+                ("Boss", vec!["\\Important", "\\Subscribed"]),
+            ],
+            false,
+        );
+
+    // Recent messages are selected by their received date
+    append(&mut imap, Some("01-Jan-2020 10:00:00 +0000"), "Old news").await;
+    append(&mut imap, None, "Breaking news").await;
+    assert_exists(&mut imap, "Recent", 1).await;
+
+    // Changing the flags of an old message does not make it recent
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("UID STORE 1 +FLAGS (\\Flagged)").await;
+    assert_exists(&mut imap, "Recent", 1).await;
+    assert_exists(&mut imap, "Flagged", 1).await;
+    let uid_validity = assert_exists(&mut imap, "All Mail", 2).await;
+    let uids = fetch_uids(&mut imap).await;
+    assert_eq!(uids.len(), 2);
+
+    // UIDs of expunged messages are never assigned again
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("UID STORE 2 +FLAGS (\\Deleted)").await;
+    imap.send_ok("EXPUNGE").await;
+    imap.send("STATUS \"All Mail\" (UIDNEXT)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains(&format!("UIDNEXT {}", uids[1] + 1));
+    append(&mut imap, None, "More news").await;
+    assert_eq!(
+        assert_exists(&mut imap, "All Mail", 2).await,
+        uid_validity,
+        "UIDVALIDITY of a virtual folder changed"
+    );
+    let new_uids = fetch_uids(&mut imap).await;
+    assert_eq!(new_uids.len(), 2);
+    assert_eq!(new_uids[0], uids[0]);
+    assert!(new_uids[1] > uids[1], "{new_uids:?} reuses {uids:?}");
+
+    // Messages that leave a virtual folder and come back are assigned a new UID
+    assert_exists(&mut imap, "Flagged", 1).await;
+    let flagged_uids = fetch_uids(&mut imap).await;
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("UID STORE 1 -FLAGS (\\Flagged)").await;
+    assert_exists(&mut imap, "Flagged", 0).await;
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("UID STORE 1 +FLAGS (\\Flagged)").await;
+    assert_exists(&mut imap, "Flagged", 1).await;
+    let new_flagged_uids = fetch_uids(&mut imap).await;
+    assert_eq!(new_flagged_uids.len(), 1);
+    assert!(
+        new_flagged_uids[0] > flagged_uids[0],
+        "{new_flagged_uids:?} reuses {flagged_uids:?}"
+    );
+
+    // JMAP reports virtual mailboxes as read-only and rejects writes
+    let client = account.jmap_client().await;
+    let all_mail_id = Id::new(ALL_MAIL_ID as u64).to_string();
+    let rights = client
+        .mailbox_get(&all_mail_id, [mailbox::Property::MyRights].into())
+        .await
+        .unwrap()
+        .unwrap()
+        .my_rights()
+        .unwrap()
+        .acl_list();
+    assert!(rights.contains(&ACL::ReadItems), "{rights:?}");
+    assert!(
+        !rights.contains(&ACL::AddItems) && !rights.contains(&ACL::SetKeywords),
+        "{rights:?}"
+    );
+    let email_id = client
+        .email_query(None::<Filter>, None::<Vec<_>>)
+        .await
+        .unwrap()
+        .take_ids()
+        .pop()
+        .unwrap();
+    assert_forbidden(client.email_set_mailboxes(&email_id, [&all_mail_id]).await);
+    let mut request = client.build();
+    request
+        .set_mailbox()
+        .update(&all_mail_id)
+        .name("Everything");
+    assert_forbidden(
+        request
+            .send_set_mailbox()
+            .await
+            .unwrap()
+            .updated(&all_mail_id),
+    );
+    let mut request = client.build();
+    request.set_mailbox().destroy([&all_mail_id]);
+    assert_forbidden(
+        request
+            .send_set_mailbox()
+            .await
+            .unwrap()
+            .destroyed(&all_mail_id),
+    );
+    assert_exists(&mut imap, "All Mail", 2).await;
+
+    // Saved searches list the messages matching their stored query
+    for (flags, subject) in [("(\\Seen) ", "Read report"), ("", "Unread report")] {
+        let message = format!(
+            "From: boss@example.com\r\nTo: news@virtual.org\r\nSubject: {subject}\r\n\r\n{subject}.\r\n"
+        );
+        imap.send_ok(&format!(
+            "APPEND INBOX {flags}{{{}+}}\r\n{}",
+            message.len(),
+            message
+        ))
+        .await;
+    }
+    test.wait_for_tasks().await;
+    assert_exists(&mut imap, "Boss", 1).await;
+    imap.send("FETCH 1 (FLAGS BODY.PEEK[HEADER.FIELDS (SUBJECT)])")
+        .await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains("Subject: Unread report")
+        .assert_not_contains("Subject: Read report");
+    imap.send("SEARCH SUBJECT \"Unread\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_equals("* SEARCH 1");
+    imap.send("SEARCH SUBJECT \"News\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_equals("* SEARCH");
+
+    // Saved searches are read-only and are evaluated again on every select
+    imap.send("APPEND \"Boss\" {5+}\r\nHello").await;
+    imap.assert_read(Type::Tagged, ResponseType::No).await;
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("STORE 1:* +FLAGS (\\Seen)").await;
+    assert_exists(&mut imap, "Boss", 0).await;
+
+    // Remove test data
+    imap.send_ok("LOGOUT").await;
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+    admin
+        .registry_destroy(ObjectType::Domain, [domain_id])
+        .await
+        .assert_destroyed(&[domain_id]);
+    admin
+        .registry_update_setting(
+            Email::default(),
+            &[
+                Property::VirtualFolders,
+                Property::RecentFolderPeriod,
+                Property::SearchFolders,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+}
+
+async fn append(imap: &mut ImapConnection, date: Option<&str>, subject: &str) {
+    let message = format!(
+        "From: news@example.com\r\nTo: news@virtual.org\r\nSubject: {subject}\r\n\r\n{subject}.\r\n"
+    );
+    imap.send_ok(&format!(
+        "APPEND INBOX {}{{{}+}}\r\n{}",
+        date.map(|date| format!("\"{date}\" ")).unwrap_or_default(),
+        message.len(),
+        message
+    ))
+    .await;
+}
+
+async fn assert_exists(imap: &mut ImapConnection, mailbox: &str, exists: usize) -> String {
+    imap.send(&format!("SELECT \"{mailbox}\"")).await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_contains(&format!("* {exists} EXISTS"))
+        .into_uid_validity()
+}
+
+async fn fetch_uids(imap: &mut ImapConnection) -> Vec<u32> {
+    imap.send("UID FETCH 1:* (UID)").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .iter()
+        .filter_map(|line| {
+            line.split_once("(UID ")
+                .and_then(|(_, uid)| uid.trim_end_matches(')').parse().ok())
+        })
+        .collect()
+}
@@ -55,7 +55,11 @@ pub async fn test(test: &TestServer) {
         .await
         .unwrap()
         .take_id();
-    assert_state(&mut event_rx, account.id_string(), &[DataType::Mailbox]).await;
+    let last_event_id = assert_state(&mut event_rx, account.id_string(), &[DataType::Mailbox])
+        .await
+        .id()
+        .expect("missing event id")
+        .to_string();
 
     // Multiple changes should be grouped and delivered in intervals
     for num in 0..5 {
@@ -67,6 +71,32 @@ pub async fn test(test: &TestServer) {
     assert_state(&mut event_rx, account.id_string(), &[DataType::Mailbox]).await;
     assert_ping(&mut event_rx).await; // Pings are only received in cfg(test)
 
+    // Reconnecting with the last event id should replay the missed changes
+    let mut resumed = client
+        .event_source(
+            [DataType::Mailbox, DataType::Email].into(),
+            true,
+            None,
+            Some(&last_event_id),
+        )
+        .await
+        .unwrap();
+    match tokio::time::timeout(Duration::from_millis(700), resumed.next()).await {
+        Ok(Some(Ok(PushNotification::StateChange(changes)))) => {
+            assert_eq!(
+                changes
+                    .changes(account.id_string())
+                    .unwrap()
+                    .map(|x| x.0)
+                    .collect::<AHashSet<&DataType>>(),
+                [DataType::Mailbox].iter().collect::<AHashSet<&DataType>>()
+            );
+        }
+        _ => {
+            panic!("Did not receive replayed changes.");
+        }
+    }
+
     // Ingest email and expect state change
     let mut lmtp = SmtpConnection::connect().await;
     lmtp.ingest(
@@ -123,7 +153,7 @@ async fn assert_state(
     event_rx: &mut mpsc::Receiver<Changes>,
     account_id: &str,
     state: &[DataType],
-) {
+) -> Changes {
     match tokio::time::timeout(Duration::from_millis(700), event_rx.recv()).await {
         Ok(Some(changes)) => {
             assert_eq!(
@@ -134,6 +164,7 @@ async fn assert_state(
                     .collect::<AHashSet<&DataType>>(),
                 state.iter().collect::<AHashSet<&DataType>>()
             );
+            changes
         }
         result => {
             panic!("Timeout waiting for event {:?}: {:?}", state, result);
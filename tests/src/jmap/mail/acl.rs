@@ -15,7 +15,11 @@ use jmap_client::{
     mailbox::{self, Role},
     principal::ACL,
 };
-use registry::schema::prelude::ObjectType;
+use registry::schema::{
+    enums::SharedMailboxDestroy,
+    prelude::{ObjectType, Property as RegistryProperty},
+    structs::Email,
+};
 use serde_json::json;
 use std::fmt::Debug;
 use store::ahash::AHashMap;
@@ -489,6 +493,84 @@ pub async fn test(test: &TestServer) {
         .await
         .unwrap();
 
+    // Emails owned by Jane are moved to her Trash when John destroys a shared mailbox
+    for policy in [SharedMailboxDestroy::Reassign, SharedMailboxDestroy::Deny] {
+        admin
+            .registry_update_setting(
+                Email {
+                    shared_mailbox_destroy: policy,
+                    ..Default::default()
+                },
+                &[RegistryProperty::SharedMailboxDestroy],
+            )
+            .await;
+        admin.reload_settings().await;
+
+        let mailbox_id = john_client
+            .set_default_account_id(jane.id_string())
+            .mailbox_create("John's temporary mailbox", Some(&inbox_id), Role::None)
+            .await
+            .unwrap()
+            .take_id();
+        jane_client
+            .mailbox_update_acl(
+                &mailbox_id,
+                john.id_string(),
+                [ACL::ReadItems, ACL::AddItems, ACL::Delete, ACL::RemoveItems],
+            )
+            .await
+            .unwrap();
+        let email_id_3 = john_client
+            .set_default_account_id(jane.id_string())
+            .email_copy(
+                john.id_string(),
+                email_ids.get("john").unwrap().first().unwrap(),
+                [&mailbox_id],
+                None::<Vec<&str>>,
+                None,
+            )
+            .await
+            .unwrap()
+            .take_id();
+
+        let result = john_client
+            .set_default_account_id(jane.id_string())
+            .mailbox_destroy(&mailbox_id, true)
+            .await;
+        if policy == SharedMailboxDestroy::Reassign {
+            result.unwrap();
+            assert_eq!(
+                jane_client
+                    .email_get(&email_id_3, [Property::MailboxIds].into())
+                    .await
+                    .unwrap()
+                    .unwrap()
+                    .mailbox_ids(),
+                [trash_id.as_str()]
+            );
+        } else {
+            assert!(
+                matches!(
+                    result,
+                    Err(jmap_client::Error::Set(SetError {
+                        type_: SetErrorType::MailboxHasEmail,
+                        ..
+                    }))
+                ),
+                "{result:?}"
+            );
+            jane_client.email_destroy(&email_id_3).await.unwrap();
+            jane_client
+                .mailbox_destroy(&mailbox_id, true)
+                .await
+                .unwrap();
+        }
+    }
+    admin
+        .registry_update_setting(Email::default(), &[RegistryProperty::SharedMailboxDestroy])
+        .await;
+    admin.reload_settings().await;
+
     // Try changing ACL
     assert_forbidden(
         john_client
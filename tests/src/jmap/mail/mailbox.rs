@@ -472,6 +472,24 @@ pub async fn test(test: &TestServer) {
         }))
     ));
 
+    // Folders can be deleted along with their children in the same request
+    let mut request = client.build();
+    request
+        .set_mailbox()
+        .destroy([&id_map["1.2"], &id_map["1.2.1"]]);
+    let response = request.send_set_mailbox().await.unwrap();
+    assert!(response.destroyed(&id_map["1.2"]).is_ok());
+    assert!(response.destroyed(&id_map["1.2.1"]).is_ok());
+    for id in ["1.2", "1.2.1"] {
+        assert!(
+            client
+                .mailbox_get(&id_map[id], None::<Vec<_>>)
+                .await
+                .unwrap()
+                .is_none()
+        );
+    }
+
     // Deleting folders with contents is not allowed (unless remove_emails is true)
     let mut request = client.build();
     request.set_mailbox().destroy([&id_map["trash"]]);
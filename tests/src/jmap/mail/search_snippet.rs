@@ -97,11 +97,12 @@ pub async fn test(test: &TestServer) {
         (
             Filter::text("孫子兵法").into(),
             "text_plain_chinese",
-            Some("<mark>孫</mark><mark>子</mark><mark>兵法</mark>"),
+            Some("<mark>孫子兵法</mark>"),
             Some(concat!(
-                "&lt;&quot;<mark>孫</mark><mark>子</mark><mark>兵法</mark>：&quot;&gt; ",
-                "<mark>孫</mark><mark>子</mark>曰：兵者，國之大事，死生之地，存亡之道，",
-                "不可不察也。 <mark>孫</mark><mark>子</mark>曰：凡用兵之法，馳車千駟"
+                "&lt;&quot;<mark>孫子兵法</mark>：&quot;&gt; ",
+                "<mark>孫子</mark>曰：兵者，國之大事，死生之地，存亡之道，",
+                "不可不察也。 <mark>孫子</mark>曰：凡用兵之法，馳車千駟，革車千乘，",
+                "帶甲十萬；千里饋糧，則"
             )),
         ),
         (
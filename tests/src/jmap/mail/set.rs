@@ -14,7 +14,7 @@ use jmap_client::{
     Error, Set,
     client::Client,
     core::set::{SetError, SetErrorType},
-    email::{self, Email},
+    email::{self, Email, import::EmailImportResponse},
     mailbox::Role,
 };
 use registry::schema::prelude::ObjectType;
@@ -29,6 +29,7 @@ pub async fn test(test: &TestServer) {
 
     create(&client, &mailbox_id).await;
     update(&client, &mailbox_id).await;
+    bulk_update(&client).await;
 
     test.destroy_all_mailboxes(account).await;
     test.account("admin@example.com")
@@ -296,6 +297,80 @@ async fn update(client: &Client, root_mailbox_id: &str) {
         .unwrap();
 }
 
+async fn bulk_update(client: &Client) {
+    const NUM_MESSAGES: usize = 5000;
+    const IMPORT_BATCH: usize = 500;
+
+    let mailbox_id = client
+        .mailbox_create("Bulk", None::<String>, Role::None)
+        .await
+        .unwrap()
+        .take_id();
+
+    // Import messages
+    let mut email_ids = Vec::with_capacity(NUM_MESSAGES);
+    for chunk in (0..NUM_MESSAGES).collect::<Vec<_>>().chunks(IMPORT_BATCH) {
+        let mut request = client.build();
+        let import_request = request.import_email();
+        let mut create_ids = Vec::with_capacity(chunk.len());
+        for num in chunk {
+            let blob_id = client
+                .upload(
+                    None,
+                    format!("Subject: bulk {num}\r\n\r\nmessage {num}\r\n").into_bytes(),
+                    None,
+                )
+                .await
+                .unwrap()
+                .take_blob_id();
+            let import_item = import_request
+                .email(blob_id)
+                .mailbox_ids([mailbox_id.clone()]);
+            create_ids.push(import_item.create_id());
+        }
+        let mut response = request.send_single::<EmailImportResponse>().await.unwrap();
+        for create_id in create_ids {
+            email_ids.push(response.created(&create_id).unwrap().take_id());
+        }
+    }
+
+    // Mark all messages as read in a single request
+    let mut request = client.build();
+    let set_request = request.set_email();
+    for email_id in &email_ids {
+        set_request.update(email_id).keyword("$seen", true);
+    }
+    let response = request.send_set_email().await.unwrap();
+    for email_id in &email_ids {
+        response.updated(email_id).unwrap();
+    }
+
+    // All updates must be reported as changes
+    let mut state = response.old_state().unwrap().to_string();
+    let mut updated = AHashSet::new();
+    loop {
+        let changes = client.email_changes(state, None).await.unwrap();
+        updated.extend(changes.updated().iter().cloned());
+        state = changes.new_state().to_string();
+        if !changes.has_more_changes() {
+            break;
+        }
+    }
+    assert_eq!(updated.len(), NUM_MESSAGES);
+
+    // Messages keep their keywords
+    let mut request = client.build();
+    request
+        .get_email()
+        .ids(email_ids.iter().take(10))
+        .properties([email::Property::Keywords]);
+    for email in request.send_get_email().await.unwrap().take_list() {
+        assert_eq!(email.keywords(), ["$seen"]);
+    }
+
+    client.mailbox_destroy(&mailbox_id, true).await.unwrap();
+}
+
 pub async fn assert_email_properties(
     client: &Client,
     message_id: &str,
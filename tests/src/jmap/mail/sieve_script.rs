@@ -5,19 +5,41 @@
  */
 
 use crate::{
-    jmap::mail::submission::{MockMessage, assert_message_delivery, spawn_mock_smtp_server},
-    utils::{dns::DnsCache, server::TestServer, smtp::SmtpConnection},
+    jmap::mail::submission::{
+        MockMessage, assert_message_delivery, expect_nothing, spawn_mock_smtp_server,
+    },
+    utils::{
+        dns::DnsCache,
+        http::HttpRequest,
+        http_server::{HttpMessage, spawn_mock_http_server},
+        server::TestServer,
+        smtp::SmtpConnection,
+    },
 };
+use base64::{Engine, engine::general_purpose::STANDARD};
+use http_proto::{JsonResponse, ToHttpResponse};
 use jmap_client::{
     Error,
     core::set::{SetError, SetErrorType},
-    email, mailbox,
+    email,
+    mailbox::{self, Role},
     sieve::query::{Comparator, Filter},
 };
-use registry::schema::{prelude::ObjectType, structs::SieveUserScript};
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::{
+            Expression, MemoryLookupKey, MtaStageData, Rate, SecretKeyOptional, SecretKeyValue,
+            SieveUserInterpreter, SieveUserScript,
+        },
+    },
+    types::map::Map,
+};
+use serde_json::json;
 use std::{
     fs,
     path::PathBuf,
+    sync::{Arc, Mutex},
     time::{Duration, Instant},
 };
 
@@ -26,13 +48,14 @@ pub async fn test(test: &TestServer) {
 
     // Create a global script
     let admin = test.account("admin@example.com");
-    admin
+    let common_id = admin
         .registry_create_object(SieveUserScript {
             contents: "require \"reject\";\nreject \"Rejected from a global script.\";\nstop;\n"
                 .into(),
             description: None,
             is_active: true,
             name: "common".into(),
+            domain_id: None,
         })
         .await;
     admin.reload_settings().await;
@@ -207,10 +230,24 @@ pub async fn test(test: &TestServer) {
             panic!("Keyword {} not found in {:?}.", keyword, email.keywords());
         }
     }
+    let archive_ids = client
+        .mailbox_query(
+            mailbox::query::Filter::role(Role::Archive).into(),
+            None::<Vec<_>>,
+        )
+        .await
+        .unwrap()
+        .take_ids();
+    assert_eq!(archive_ids.len(), 1);
+    assert!(
+        email.mailbox_ids().contains(&archive_ids[0].as_str()),
+        "Archive mailbox not found in {:?}.",
+        email.mailbox_ids()
+    );
     assert_eq!(
         email.mailbox_ids().len(),
-        2,
-        "Expected 2 mailbox ids, found {:?}.",
+        3,
+        "Expected 3 mailbox ids, found {:?}.",
         email.mailbox_ids()
     );
     for mailbox_pos in [mailbox_ids.len() - 1, mailbox_ids.len() - 2] {
@@ -373,6 +410,112 @@ pub async fn test(test: &TestServer) {
     )
     .await;
 
+    // Rejects can also be returned during the SMTP transaction
+    admin
+        .registry_update_setting(
+            MtaStageData {
+                enable_user_script_reject: Expression {
+                    else_: "true".into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[Property::EnableUserScriptReject],
+        )
+        .await;
+    admin.reload_settings().await;
+    let response = lmtp
+        .ingest_with_code(
+            "bill@remote.org",
+            &["jdoe@example.com"],
+            concat!(
+                "From: bill@remote.org\r\n",
+                "Message-ID: <5678@example.com>\r\n",
+                "Subject: Holidays\r\n",
+                "\r\n",
+                "Remember to file your T.P.S. reports before ",
+                "going on holidays."
+            ),
+            5,
+        )
+        .await;
+    assert!(
+        response
+            .iter()
+            .any(|line| line.contains("Rejected from a global script")),
+        "{response:?}"
+    );
+    admin
+        .registry_update_setting(MtaStageData::default(), &[Property::EnableUserScriptReject])
+        .await;
+    admin.reload_settings().await;
+
+    // Library scripts scoped to another domain cannot be included
+    let library_domain_id = admin.find_or_create_domain("library.org").await;
+    admin
+        .registry_update_object(
+            ObjectType::SieveUserScript,
+            common_id,
+            json!({
+                Property::DomainId: library_domain_id,
+            }),
+        )
+        .await;
+    admin.reload_settings().await;
+    lmtp.ingest(
+        "bill@remote.org",
+        &["jdoe@example.com"],
+        concat!(
+            "From: bill@remote.org\r\n",
+            "Message-ID: <9012@example.com>\r\n",
+            "Subject: Holidays\r\n",
+            "\r\n",
+            "Remember to file your T.P.S. reports before ",
+            "going on holidays."
+        ),
+    )
+    .await;
+    expect_nothing(&mut smtp_rx).await;
+    admin
+        .registry_update_object(
+            ObjectType::SieveUserScript,
+            common_id,
+            json!({
+                Property::DomainId: null,
+            }),
+        )
+        .await;
+    admin
+        .registry_destroy(ObjectType::Domain, [library_domain_id])
+        .await
+        .assert_destroyed(&[library_domain_id]);
+    admin.reload_settings().await;
+
+    // Library scripts cannot include each other in a cycle
+    let library_id = admin
+        .registry_create_object(SieveUserScript {
+            contents: "require \"include\";\ninclude :global \"library-b\";\n".into(),
+            description: None,
+            is_active: true,
+            name: "library-a".into(),
+            domain_id: None,
+        })
+        .await;
+    admin
+        .registry_create_object_expect_err(SieveUserScript {
+            contents: "require \"include\";\ninclude :global \"library-a\";\n".into(),
+            description: None,
+            is_active: true,
+            name: "library-b".into(),
+            domain_id: None,
+        })
+        .await
+        .assert_description_contains("cycle");
+    admin
+        .registry_destroy(ObjectType::SieveUserScript, [library_id])
+        .await
+        .assert_destroyed(&[library_id]);
+
     // Run enclose + redirect tests
     client
         .sieve_script_create(
@@ -415,6 +558,39 @@ pub async fn test(test: &TestServer) {
         "Redirected message was stored."
     );
 
+    // Messages sent by the redirect target are not redirected back to it
+    lmtp.ingest(
+        "jane@remote.org",
+        &["jdoe@example.com"],
+        concat!(
+            "From: jane@remote.org\r\n",
+            "To: jdoe@example.com\r\n",
+            "Subject: Re: TPS Report\r\n",
+            "\r\n",
+            "Did you get the memo?"
+        ),
+    )
+    .await;
+    expect_nothing(&mut smtp_rx).await;
+
+    // Action counters and redirect targets are available to administrators
+    let stats = HttpRequest::with_credentials(8899, admin.name(), admin.secret())
+        .get::<serde_json::Value>(&format!(
+            "/api/principal/{}/sieve",
+            account.id().document_id()
+        ))
+        .await
+        .unwrap();
+    let script = stats["scripts"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|script| script["name"] == "test_redirect_enclose")
+        .unwrap_or_else(|| panic!("Script not found: {stats}"));
+    assert_eq!(script["redirect"], 1, "{stats}");
+    assert_eq!(script["discard"], 2, "{stats}");
+    assert_eq!(stats["redirects"], serde_json::json!(["jane@remote.org"]));
+
     // Run notify + editheader + notify + fcc tests
     client
         .sieve_script_create("test_notify_fcc", get_script("test_notify_fcc"), true)
@@ -498,6 +674,226 @@ pub async fn test(test: &TestServer) {
         panic!("Email {:?} not found in: {:#?}", subject, emails);
     }
 
+    // External lists are resolved against the lookup stores allowed for user scripts
+    for namespace in ["sieve-vip", "sieve-blocked"] {
+        admin
+            .registry_create_object(MemoryLookupKey {
+                is_glob_pattern: false,
+                key: "bill@remote.org".into(),
+                namespace: namespace.into(),
+            })
+            .await;
+    }
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter {
+                allowed_ext_lists: Map::new(vec!["sieve-vip".to_string()]),
+                ..Default::default()
+            },
+            &[Property::AllowedExtLists],
+        )
+        .await;
+    admin.reload_settings().await;
+    admin.reload_lookup_stores().await;
+    client
+        .sieve_script_create("test_extlists", get_script("test_extlists"), true)
+        .await
+        .unwrap();
+    for (from, subject) in [
+        ("bill@remote.org", "Lists from a VIP"),
+        ("jane@remote.org", "Lists from a stranger"),
+    ] {
+        lmtp.ingest(
+            from,
+            &["jdoe@example.com"],
+            &format!("From: {from}\r\nTo: jdoe@example.com\r\nSubject: {subject}\r\n\r\nTPS"),
+        )
+        .await;
+    }
+    let mut request = client.build();
+    request
+        .get_email()
+        .properties([email::Property::Keywords, email::Property::Subject]);
+    let emails = request.send_get_email().await.unwrap().take_list();
+    for (subject, is_vip) in [("Lists from a VIP", true), ("Lists from a stranger", false)] {
+        let email = emails
+            .iter()
+            .find(|email| email.subject() == Some(subject))
+            .unwrap_or_else(|| panic!("Email {subject:?} not found in: {emails:#?}"));
+        assert_eq!(email.keywords().contains(&"$vip"), is_vip, "{email:#?}");
+        assert!(!email.keywords().contains(&"$blocked"), "{email:#?}");
+    }
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter::default(),
+            &[Property::AllowedExtLists],
+        )
+        .await;
+    admin.reload_settings().await;
+    admin
+        .registry_destroy_all(ObjectType::MemoryLookupKey)
+        .await;
+    admin.reload_lookup_stores().await;
+
+    // Redirects and notifications are limited for user scripts
+    let (mut smtp_rx, smtp_settings) = spawn_mock_smtp_server();
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter {
+                redirect_rate_limit: Some(Rate {
+                    count: 1,
+                    period: (2 * 86_400_000u64).into(),
+                }),
+                max_notify_actions: 1,
+                ..Default::default()
+            },
+            &[Property::RedirectRateLimit, Property::MaxNotifyActions],
+        )
+        .await;
+    admin.reload_settings().await;
+    client
+        .sieve_script_create("test_limits", get_script("test_limits"), true)
+        .await
+        .unwrap();
+    for num in 1..=2 {
+        lmtp.ingest(
+            "bill@remote.org",
+            &["jdoe@example.com"],
+            &format!(
+                "From: bill@remote.org\r\nTo: jdoe@example.com\r\nSubject: Redirect {num}\r\n\r\nTPS"
+            ),
+        )
+        .await;
+    }
+    assert_message_delivery(
+        &mut smtp_rx,
+        MockMessage::new("<jdoe@example.com>", ["<alerts@remote.org>"], "@Redirect 1"),
+    )
+    .await;
+    expect_nothing(&mut smtp_rx).await;
+    lmtp.ingest(
+        "bill@remote.org",
+        &["jdoe@example.com"],
+        "From: bill@remote.org\r\nTo: jdoe@example.com\r\nSubject: Notify\r\n\r\nTPS",
+    )
+    .await;
+    assert_message_delivery(
+        &mut smtp_rx,
+        MockMessage::new(
+            "<jdoe@example.com>",
+            ["<alerts@remote.org>"],
+            "@First notification",
+        ),
+    )
+    .await;
+    expect_nothing(&mut smtp_rx).await;
+    smtp_settings.lock().do_stop = true;
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter::default(),
+            &[Property::RedirectRateLimit, Property::MaxNotifyActions],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Notifications are posted to https: methods or handed over to the gateway
+    let requests = Arc::new(Mutex::new(Vec::<(String, String, Vec<u8>)>::new()));
+    let requests_ = requests.clone();
+    let _tx = spawn_mock_http_server(
+        test,
+        Arc::new(move |req: HttpMessage| {
+            requests_.lock().unwrap().push((
+                req.uri.path().to_string(),
+                req.headers.get("x-signature").cloned().unwrap_or_default(),
+                req.body.unwrap_or_default(),
+            ));
+            JsonResponse::new(&json!({})).into_http_response()
+        }),
+        9095,
+    )
+    .await;
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter {
+                allowed_notify_uris: Map::new(vec![
+                    "mailto".to_string(),
+                    "https".to_string(),
+                    "xmpp".to_string(),
+                ]),
+                notify_signature_key: SecretKeyOptional::Value(SecretKeyValue {
+                    secret: "notify-secret".into(),
+                }),
+                notify_gateway_url: Some("https://127.0.0.1:9095/gateway".to_string()),
+                ..Default::default()
+            },
+            &[
+                Property::AllowedNotifyUris,
+                Property::NotifySignatureKey,
+                Property::NotifyGatewayUrl,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+    client
+        .sieve_script_create(
+            "test_notify_webhook",
+            get_script("test_notify_webhook"),
+            true,
+        )
+        .await
+        .unwrap();
+    lmtp.ingest(
+        "bill@remote.org",
+        &["jdoe@example.com"],
+        concat!(
+            "From: bill@remote.org\r\n",
+            "To: jdoe@example.com\r\n",
+            "Subject: Webhooks\r\n",
+            "Message-ID: <webhooks@remote.org>\r\n",
+            "\r\n",
+            "TPS"
+        ),
+    )
+    .await;
+    let requests = std::mem::take(&mut *requests.lock().unwrap());
+    assert_eq!(
+        requests
+            .iter()
+            .map(|(path, _, _)| path.as_str())
+            .collect::<Vec<_>>(),
+        ["/notify", "/gateway"]
+    );
+    let key = ring::hmac::Key::new(ring::hmac::HMAC_SHA256, b"notify-secret");
+    for ((_, signature, body), (method, message, importance)) in requests.iter().zip([
+        (
+            "https://127.0.0.1:9095/notify",
+            "Webhook notification",
+            "high",
+        ),
+        ("xmpp:jdoe@example.com", "Gateway notification", "normal"),
+    ]) {
+        ring::hmac::verify(&key, body, &STANDARD.decode(signature).unwrap()).unwrap();
+        let body = serde_json::from_slice::<serde_json::Value>(body).unwrap();
+        assert_eq!(body["method"], method, "{body}");
+        assert_eq!(body["message"], message, "{body}");
+        assert_eq!(body["importance"], importance, "{body}");
+        assert_eq!(body["envelopeFrom"], "bill@remote.org", "{body}");
+        assert_eq!(body["envelopeTo"], "jdoe@example.com", "{body}");
+        assert_eq!(body["subject"], "Webhooks", "{body}");
+        assert_eq!(body["messageId"], "webhooks@remote.org", "{body}");
+    }
+    admin
+        .registry_update_setting(
+            SieveUserInterpreter::default(),
+            &[
+                Property::AllowedNotifyUris,
+                Property::NotifySignatureKey,
+                Property::NotifyGatewayUrl,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+
     // Remove test data
     client.sieve_script_deactivate().await.unwrap();
     let mut request = client.build();
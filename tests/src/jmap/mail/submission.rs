@@ -6,7 +6,7 @@
 
 use crate::{
     jmap::mail::set::assert_email_properties,
-    utils::{dns::DnsCache, server::TestServer},
+    utils::{dns::DnsCache, http::HttpRequest, server::TestServer},
 };
 use ahash::AHashMap;
 use jmap_client::{
@@ -15,12 +15,15 @@ use jmap_client::{
     email_submission::{Address, Delivered, DeliveryStatus, Displayed, UndoStatus, query::Filter},
     mailbox::Role,
 };
+use jmap_proto::request::method::MethodObject;
 use mail_parser::DateTime;
+use registry::schema::prelude::{ObjectType, Property};
+use serde_json::json;
 use std::{
     sync::Arc,
     time::{Duration, Instant},
 };
-use store::parking_lot::Mutex;
+use store::{parking_lot::Mutex, write::now};
 use tokio::{
     io::{AsyncBufReadExt, AsyncWriteExt, BufReader},
     net::TcpListener,
@@ -123,6 +126,75 @@ pub async fn test(test: &TestServer) {
         .unwrap()
         .take_id();
 
+    // Large signatures are kept in the blob store and returned as-is
+    let signature = "Regards,\n".repeat(1024);
+    account
+        .jmap_update(
+            MethodObject::Identity,
+            [(
+                &identity_id,
+                json!({
+                    "textSignature": &signature,
+                    "bccToSelf": true
+                }),
+            )],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&identity_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Identity,
+            ["textSignature", "htmlSignature", "bccToSelf"],
+            [&identity_id],
+        )
+        .await;
+    assert_eq!(response.list()[0]["textSignature"], signature);
+    assert_eq!(response.list()[0]["htmlSignature"], "");
+    assert_eq!(response.list()[0]["bccToSelf"], true);
+    account
+        .jmap_update(
+            MethodObject::Identity,
+            [(
+                &identity_id,
+                json!({
+                    "textSignature": "Regards",
+                    "bccToSelf": false
+                }),
+            )],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated(&identity_id);
+    let response = account
+        .jmap_get(
+            MethodObject::Identity,
+            ["textSignature", "bccToSelf"],
+            [&identity_id],
+        )
+        .await;
+    assert_eq!(response.list()[0]["textSignature"], "Regards");
+    assert_eq!(response.list()[0]["bccToSelf"], false);
+
+    // Identities cannot be delegated to their owner
+    assert_eq!(
+        account
+            .jmap_update(
+                MethodObject::Identity,
+                [(
+                    &identity_id,
+                    json!({
+                        "delegatedTo": [account.id_string()]
+                    }),
+                )],
+                Vec::<(&str, &str)>::new(),
+            )
+            .await
+            .not_updated(&identity_id)
+            .description(),
+        "Identities cannot be delegated to their owner."
+    );
+
     // Create test mailboxes
     let mailbox_id = client
         .mailbox_create("JMAP EmailSubmission", None::<String>, Role::None)
@@ -441,6 +513,82 @@ pub async fn test(test: &TestServer) {
         ),])
     );
 
+    // Enable an undo window for the account and confirm it is advertised
+    test.account("admin@example.com")
+        .registry_update_object(
+            ObjectType::Account,
+            account.id(),
+            json!({
+                Property::UndoSendWindow: 2000
+            }),
+        )
+        .await;
+    let session = HttpRequest::with_credentials(8899, account.name(), account.secret())
+        .get::<serde_json::Value>("/jmap/session")
+        .await
+        .unwrap();
+    assert_eq!(
+        session["accounts"][account.id_string()]["accountCapabilities"]["urn:ietf:params:jmap:submission"]
+            ["undoSendWindow"],
+        2,
+        "{session}"
+    );
+
+    // Destroying a submission within the undo window cancels it
+    let submitted_at = now() as i64;
+    let email_submission_id = client
+        .email_submission_create_envelope(
+            &email_id,
+            &identity_id,
+            "jdoe@example.com",
+            ["tim@foobar.com"],
+        )
+        .await
+        .unwrap()
+        .take_id();
+    let email_submission = client
+        .email_submission_get(&email_submission_id, None)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(email_submission.send_at().unwrap() >= submitted_at + 2);
+    assert_eq!(
+        email_submission.undo_status().unwrap(),
+        &UndoStatus::Pending
+    );
+    client
+        .email_submission_destroy(&email_submission_id)
+        .await
+        .unwrap();
+    tokio::time::sleep(Duration::from_millis(2000)).await;
+    expect_nothing(&mut smtp_rx).await;
+
+    // The message is released once the undo window expires
+    client
+        .email_submission_create_envelope(
+            &email_id,
+            &identity_id,
+            "jdoe@example.com",
+            ["tim@foobar.com"],
+        )
+        .await
+        .unwrap();
+    expect_nothing(&mut smtp_rx).await;
+    assert_message_delivery(
+        &mut smtp_rx,
+        MockMessage::new("<jdoe@example.com>", ["<tim@foobar.com>"], &email_body),
+    )
+    .await;
+    test.account("admin@example.com")
+        .registry_update_object(
+            ObjectType::Account,
+            account.id(),
+            json!({
+                Property::UndoSendWindow: null
+            }),
+        )
+        .await;
+
     // Verify onSuccessUpdateEmail action
     let mut request = client.build();
     let set_request = request.set_email_submission();
@@ -8,9 +8,11 @@ use crate::{
     jmap::mail::submission::{
         MockMessage, assert_message_delivery, expect_nothing, spawn_mock_smtp_server,
     },
-    utils::{dns::DnsCache, server::TestServer, smtp::SmtpConnection},
+    utils::{dns::DnsCache, jmap::JmapUtils, server::TestServer, smtp::SmtpConnection},
 };
 use chrono::{TimeDelta, Utc};
+use jmap_proto::{error::set::SetErrorType, request::method::MethodObject};
+use serde_json::json;
 use std::time::Instant;
 
 pub async fn test(test: &TestServer) {
@@ -132,6 +134,80 @@ pub async fn test(test: &TestServer) {
         )
         .await
         .unwrap();
+
+    // Responses can be restricted to senders in the same domain
+    account
+        .jmap_update(
+            MethodObject::VacationResponse,
+            [("singleton", json!({"restrictTo": "sameDomain"}))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated("singleton");
+    assert_eq!(
+        account
+            .jmap_get(
+                MethodObject::VacationResponse,
+                ["restrictTo"],
+                ["singleton"]
+            )
+            .await
+            .list()[0]["restrictTo"],
+        "sameDomain"
+    );
+    lmtp.ingest(
+        "ted@remote.org",
+        &["jdoe@example.com"],
+        concat!(
+            "From: ted@remote.org\r\n",
+            "To: jdoe@example.com\r\n",
+            "Subject: Lunch?\r\n",
+            "\r\n",
+            "Are you free for lunch tomorrow?",
+        ),
+    )
+    .await;
+
+    expect_nothing(&mut smtp_rx).await;
+
+    // Date ranges ending before they start are rejected
+    account
+        .jmap_update(
+            MethodObject::VacationResponse,
+            [(
+                "singleton",
+                json!({
+                    "fromDate": "2030-01-02T00:00:00Z",
+                    "toDate": "2030-01-01T00:00:00Z"
+                }),
+            )],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .not_updated("singleton")
+        .to_set_error()
+        .assert_type(SetErrorType::InvalidProperties)
+        .assert_properties(&["fromDate", "toDate"]);
+
+    account
+        .jmap_update(
+            MethodObject::VacationResponse,
+            [("singleton", json!({"restrictTo": null}))],
+            Vec::<(&str, &str)>::new(),
+        )
+        .await
+        .updated("singleton");
+    assert_eq!(
+        account
+            .jmap_get(
+                MethodObject::VacationResponse,
+                ["restrictTo"],
+                ["singleton"]
+            )
+            .await
+            .list()[0]["restrictTo"],
+        json!(null)
+    );
     smtp_settings.lock().do_stop = true;
     lmtp.ingest(
         "jane_smith@remote.org",
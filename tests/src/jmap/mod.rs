@@ -10,7 +10,8 @@ use registry::{
         enums::{MtaProtocol, Permission},
         structs::{
             CalendarAlarm, Expression, ExpressionMatch, Imap, Jmap, MtaExtensions,
-            MtaOutboundStrategy, MtaRoute, MtaRouteRelay, MtaStageAuth, Sharing,
+            MtaOutboundStrategy, MtaRoute, MtaRouteRelay, MtaStageAuth, SecretTextOptional,
+            SecretTextValue, Sharing,
         },
     },
     types::list::List,
@@ -96,6 +97,10 @@ pub async fn jmap_tests() {
             push_throttle: 500u64.into(),
             websocket_throttle: 500u64.into(),
             push_attempt_wait: 500u64.into(),
+            push_vapid_key: SecretTextOptional::Text(SecretTextValue {
+                secret: core::push_subscription::VAPID_KEY.to_string(),
+            }),
+            push_vapid_subject: Some("mailto:postmaster@example.org".to_string()),
             ..Default::default()
         })
         .await;
@@ -30,8 +30,8 @@ use registry::{
         enums::{AiModelType, TaskSpamFilterMaintenanceType},
         prelude::{ObjectType, Property},
         structs::{
-            self, AiModel, MemoryLookupKey, SpamLlm, SpamLlmProperties, SpamSettings, Task,
-            TaskSpamFilterMaintenance, TaskStatus,
+            self, AiModel, MemoryLookupKey, MemoryLookupKeyValue, SpamLlm, SpamLlmProperties,
+            SpamSettings, Task, TaskSpamFilterMaintenance, TaskStatus,
         },
     },
     types::{float::Float, map::Map},
@@ -140,6 +140,14 @@ async fn antispam() {
             namespace: "url-redirectors".into(),
         })
         .await;
+    admin
+        .registry_create_object(MemoryLookupKeyValue {
+            is_glob_pattern: false,
+            key: "reputation-bad.com".into(),
+            value: "phishing".into(),
+            namespace: "url-reputation".into(),
+        })
+        .await;
     admin.mta_allow_relaying().await;
     admin.mta_no_auth().await;
     admin.mta_allow_non_fqdn().await;
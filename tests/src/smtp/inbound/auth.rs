@@ -8,11 +8,19 @@ use crate::{
     smtp::session::{TestSession, VerifyResponse},
     utils::server::TestServerBuilder,
 };
+use common::auth::AccountInfo;
+use jmap::identity::get::IdentityGet;
 use registry::{
-    schema::structs::{Expression, ExpressionMatch, MtaExtensions, MtaStageAuth},
-    types::list::List,
+    schema::{
+        enums::MtaSenderMismatchAction,
+        structs::{Expression, ExpressionMatch, MtaExtensions, MtaStageAuth},
+    },
+    types::{list::List, map::Map},
 };
 use smtp::core::State;
+use std::sync::Arc;
+use store::{Serialize, roaring::RoaringBitmap, write::BatchBuilder};
+use types::{collection::Collection, field::IdentityField};
 
 #[tokio::test]
 async fn auth() {
@@ -26,6 +34,7 @@ async fn auth() {
 
     // Create test users
     let admin = test.account("admin");
+    let mut account_ids = Vec::new();
     for (name, secret, description, aliases) in [
         (
             "john@example.org",
@@ -40,9 +49,13 @@ async fn auth() {
             &["jane@example.org"],
         ),
     ] {
-        admin
-            .create_user_account(name, secret, description, aliases, vec![])
-            .await;
+        account_ids.push(
+            admin
+                .create_user_account(name, secret, description, aliases, vec![])
+                .await
+                .id()
+                .document_id(),
+        );
     }
 
     // Add test settings
@@ -77,6 +90,12 @@ async fn auth() {
                 else_: "100ms".into(),
                 ..Default::default()
             },
+            must_match_tenant_domain: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            tenant_domain_exemptions: Map::new(vec!["example.net".into()]),
+            sender_mismatch_action: MtaSenderMismatchAction::Reject,
         })
         .await;
     admin
@@ -142,6 +161,43 @@ async fn auth() {
     session.mail_from("john@example.org", "250").await;
     session.data.mail_from.take();
 
+    // Addresses delegated through an identity of another account are allowed
+    let (john_id, jane_id) = (account_ids[0], account_ids[1]);
+    session.mail_from("jane@example.org", "501 5.5.4").await;
+    let identity_id = test
+        .server
+        .identity_get_or_create(jane_id)
+        .await
+        .unwrap()
+        .min()
+        .unwrap();
+    let mut batch = BatchBuilder::new();
+    batch
+        .with_account_id(jane_id)
+        .with_collection(Collection::Identity)
+        .with_document(identity_id)
+        .set(
+            IdentityField::DelegatedTo,
+            RoaringBitmap::from_iter([john_id]).serialize().unwrap(),
+        );
+    test.server.store().write(batch.build_all()).await.unwrap();
+    session.mail_from("jane@example.org", "250").await;
+    session.data.mail_from.take();
+
+    // Tenant accounts should only be able to send from their tenant's domains
+    let authenticated_as = session.data.authenticated_as.clone().unwrap();
+    let mut account = authenticated_as.account.as_ref().clone();
+    account.id_tenant = Some(u32::MAX - 1);
+    session.data.authenticated_as = Some(AccountInfo {
+        account: Arc::new(account),
+        addresses: vec!["john@example.org".into(), "john@example.net".into()],
+        ..authenticated_as.clone()
+    });
+    session.mail_from("john@example.org", "550 5.7.1").await;
+    session.mail_from("john@example.net", "250").await;
+    session.data.mail_from.take();
+    session.data.authenticated_as = Some(authenticated_as);
+
     // Should not be able to authenticate twice
     session
         .auth_plain("john@example.org", "12345 + extra safety", "503 5.5.1")
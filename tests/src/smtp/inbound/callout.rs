@@ -0,0 +1,117 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::session::TestSession,
+    utils::{dns::DnsCache, server::TestServerBuilder},
+};
+use common::KV_SMTP_CALLOUT;
+use mail_auth::MX;
+use registry::schema::{
+    enums::NetworkListenerProtocol,
+    structs::{Expression, MtaStageRcpt},
+};
+use std::time::{Duration, Instant};
+use store::dispatch::lookup::KeyValue;
+
+#[tokio::test]
+#[serial_test::serial]
+async fn rcpt_callout() {
+    let mut local = TestServerBuilder::new("smtp_callout_local")
+        .await
+        .with_http_listener(19032)
+        .await
+        .disable_services()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_callout_remote")
+        .await
+        .with_http_listener(19033)
+        .await
+        .with_listener(NetworkListenerProtocol::Smtp, "smtp-debug", 9925, false)
+        .await
+        .disable_services()
+        .build()
+        .await;
+
+    // Relay all recipients and verify them with the destination server
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            callout: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_disable_spam_filter().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+
+    // The destination server only accepts a single recipient
+    let remote_admin = remote.account("admin");
+    remote_admin
+        .registry_create_object(MtaStageRcpt {
+            allow_relaying: Expression {
+                else_: "rcpt == 'ok@foobar.org'".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_disable_spam_filter().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+
+    // Add mock DNS entries
+    local.server.mx_add(
+        "foobar.org",
+        vec![MX {
+            exchanges: vec!["mx.foobar.org".into()].into_boxed_slice(),
+            preference: 10,
+        }],
+        Instant::now() + Duration::from_secs(10),
+    );
+    local.server.ipv4_add(
+        "mx.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(30),
+    );
+
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session.mail_from("john@test.org", "250").await;
+    session.rcpt_to("ok@foobar.org", "250").await;
+    session.rcpt_to("unknown@foobar.org", "550 5.1.1").await;
+
+    // Results are cached
+    let store = local.server.in_memory_store();
+    for (key, exists) in [
+        ("+ok@foobar.org", true),
+        ("-unknown@foobar.org", true),
+        ("-ok@foobar.org", false),
+    ] {
+        assert_eq!(
+            store
+                .key_exists(KeyValue::<()>::build_key(KV_SMTP_CALLOUT, key))
+                .await
+                .unwrap(),
+            exists,
+            "{key}"
+        );
+    }
+    session.rcpt_to("unknown@foobar.org", "550 5.1.1").await;
+}
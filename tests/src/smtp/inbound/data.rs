@@ -13,13 +13,14 @@ use crate::{
 };
 use registry::{
     schema::{
-        enums::MtaQueueQuotaKey,
-        prelude::ObjectType,
+        enums::{MtaQueueQuotaKey, MtaStructureLimitAction},
+        prelude::{ObjectType, Property},
         structs::{
-            Expression, ExpressionMatch, MtaQueueQuota, MtaStageData, SenderAuth, SpamSettings,
+            Expression, ExpressionMatch, MtaQueueQuota, MtaStageData, Rate, Security, SenderAuth,
+            SpamSettings,
         },
     },
-    types::{list::List, map::Map},
+    types::{duration::Duration, list::List, map::Map},
 };
 
 #[tokio::test]
@@ -140,6 +141,20 @@ async fn data() {
                 else_: "3".into(),
                 ..Default::default()
             },
+            max_header_count: Expression {
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "remote_ip = '10.0.0.4'".into(),
+                    then: "5".into(),
+                }]),
+                else_: "1000".into(),
+            },
+            max_mime_depth: Expression {
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "remote_ip = '10.0.0.4'".into(),
+                    then: "1".into(),
+                }]),
+                else_: "50".into(),
+            },
             ..Default::default()
         })
         .await;
@@ -248,6 +263,250 @@ async fn data() {
         .assert_contains("Authentication-Results: ")
         .assert_contains("Received-SPF: ");
 
+    // Messages exceeding the structure limits are rejected by default
+    let many_headers = concat!(
+        "From: alice@test.org\r\n",
+        "To: mike@test.com\r\n",
+        "Subject: Structure limits\r\n",
+        "Date: Fri, 11 Jul 2003 21:00:37 -0700\r\n",
+        "Message-ID: <limits@test.org>\r\n",
+        "X-Overflow: true\r\n",
+        "\r\n",
+        "Test message.\r\n"
+    );
+    session.data.remote_ip_str = "10.0.0.4".into();
+    session.eval_session_params().await;
+    session
+        .send_message(
+            "alice@test.org",
+            &["mike@test.com"],
+            many_headers,
+            "550 5.6.0",
+        )
+        .await;
+    session
+        .send_message(
+            "alice@test.org",
+            &["mike@test.com"],
+            "test:multipart",
+            "550 5.6.0",
+        )
+        .await;
+
+    // Tagging delivers the message unchanged with a header listing the exceeded limits
+    for (action, expected_header, has_overflow) in [
+        (
+            MtaStructureLimitAction::Tag,
+            "X-Limits-Exceeded: header-count",
+            true,
+        ),
+        (MtaStructureLimitAction::Truncate, "Subject: ", false),
+    ] {
+        test.account("admin")
+            .registry_update_setting(
+                MtaStageData {
+                    structure_limit_action: action,
+                    ..Default::default()
+                },
+                &[Property::StructureLimitAction],
+            )
+            .await;
+        test.account("admin").reload_settings().await;
+        test.reload_core();
+        test.expect_reload_settings().await;
+
+        let mut session = test.new_mta_session();
+        session.data.remote_ip_str = "10.0.0.4".into();
+        session.eval_session_params().await;
+        session.ehlo("mx.test.org").await;
+        session
+            .send_message("alice@test.org", &["mike@test.com"], many_headers, "250")
+            .await;
+        let lines = test.expect_message().await.read_lines(&test).await;
+        lines.assert_contains(expected_header);
+        assert_eq!(
+            lines.iter().any(|line| line.starts_with("X-Overflow:")),
+            has_overflow,
+            "{lines:?}"
+        );
+
+        // MIME structures are never truncated
+        session
+            .send_message(
+                "alice@test.org",
+                &["mike@test.com"],
+                "test:multipart",
+                if action == MtaStructureLimitAction::Tag {
+                    "250"
+                } else {
+                    "550 5.6.0"
+                },
+            )
+            .await;
+        if action == MtaStructureLimitAction::Tag {
+            test.expect_message()
+                .await
+                .read_lines(&test)
+                .await
+                .assert_contains("X-Limits-Exceeded: mime-depth");
+        }
+    }
+
+    // Client details and trace headers are removed when requested
+    test.account("admin")
+        .registry_update_setting(
+            MtaStageData {
+                received_header_format: Expression {
+                    else_: "privacy".into(),
+                    ..Default::default()
+                },
+                scrub_trace_headers: Expression {
+                    else_: "true".into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[Property::ReceivedHeaderFormat, Property::ScrubTraceHeaders],
+        )
+        .await;
+    test.account("admin").reload_settings().await;
+    test.reload_core();
+    test.expect_reload_settings().await;
+
+    let mut trace_session = test.new_mta_session();
+    trace_session.data.remote_ip_str = "10.0.0.3".into();
+    trace_session.eval_session_params().await;
+    trace_session.ehlo("mx.test.org").await;
+    trace_session
+        .send_message(
+            "bill@doe.org",
+            &["mike@test.com"],
+            concat!(
+                "From: bill@doe.org\r\n",
+                "To: mike@test.com\r\n",
+                "Subject: Trace headers\r\n",
+                "X-Originating-IP: [192.168.1.20]\r\n",
+                "User-Agent: Mail Client/1.0\r\n",
+                "\r\n",
+                "Test message.\r\n"
+            ),
+            "250",
+        )
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("Received: by ")
+        .assert_contains("Subject: Trace headers")
+        .assert_not_contains("Received: from mx.test.org")
+        .assert_not_contains("X-Originating-IP:")
+        .assert_not_contains("User-Agent:");
+
+    // Bare line endings are only rejected where configured
+    test.account("admin")
+        .registry_update_setting(
+            MtaStageData {
+                reject_bare_lf: Expression {
+                    match_: List::from_iter([ExpressionMatch {
+                        if_: "remote_ip = '10.0.0.5'".into(),
+                        then: "true".into(),
+                    }]),
+                    else_: "false".into(),
+                },
+                reject_bare_cr: Expression {
+                    match_: List::from_iter([ExpressionMatch {
+                        if_: "remote_ip = '10.0.0.5'".into(),
+                        then: "true".into(),
+                    }]),
+                    else_: "false".into(),
+                },
+                ..Default::default()
+            },
+            &[Property::RejectBareLf, Property::RejectBareCr],
+        )
+        .await;
+    test.account("admin")
+        .registry_update_setting(
+            Security {
+                smuggling_ban_rate: Some(Rate {
+                    count: 1,
+                    period: Duration::from_millis(86400000),
+                }),
+                ..Default::default()
+            },
+            &[Property::SmugglingBanRate],
+        )
+        .await;
+    test.account("admin").reload_settings().await;
+    test.reload_core();
+    test.expect_reload_settings().await;
+
+    let bare_lf = concat!(
+        "From: alice@test.org\n",
+        "To: mike@test.com\n",
+        "Subject: Bare LF\n",
+        "\n",
+        "Test message.\n"
+    );
+    let bare_cr = concat!(
+        "From: alice@test.org\r\n",
+        "To: mike@test.com\r\n",
+        "Subject: Bare CR\r\n",
+        "\r\n",
+        "Test\rmessage.\r\n"
+    );
+    let mut strict_session = test.new_mta_session();
+    strict_session.data.remote_ip_str = "10.0.0.5".into();
+    strict_session.data.remote_ip = strict_session.data.remote_ip_str.parse().unwrap();
+    strict_session.eval_session_params().await;
+    strict_session.ehlo("mx.test.org").await;
+    for message in [bare_lf, bare_cr] {
+        strict_session
+            .send_message("alice@test.org", &["mike@test.com"], message, "550 5.6.0")
+            .await;
+    }
+
+    let mut lenient_session = test.new_mta_session();
+    lenient_session.data.remote_ip_str = "10.0.0.6".into();
+    lenient_session.data.remote_ip = lenient_session.data.remote_ip_str.parse().unwrap();
+    lenient_session.eval_session_params().await;
+    lenient_session.ehlo("mx.test.org").await;
+    lenient_session
+        .send_message("alice@test.org", &["mike@test.com"], bare_lf, "250")
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("Subject: Bare LF");
+
+    // Repeated smuggling attempts get the client banned
+    let smuggled = concat!(
+        "From: alice@test.org\r\n",
+        "To: mike@test.com\r\n",
+        "Subject: Smuggling\r\n",
+        "\r\n",
+        "Test message.\n",
+        ".\n",
+        "MAIL FROM:<admin@test.com>\r\n"
+    );
+    lenient_session
+        .send_message("alice@test.org", &["mike@test.com"], smuggled, "250")
+        .await;
+    test.expect_message().await;
+    lenient_session.mail_from("alice@test.org", "250").await;
+    lenient_session.rcpt_to("mike@test.com", "250").await;
+    lenient_session.ingest(b"DATA\r\n").await.unwrap();
+    lenient_session.response().assert_code("354");
+    assert!(
+        lenient_session
+            .ingest(format!("{smuggled}\r\n.\r\n").as_bytes())
+            .await
+            .is_err()
+    );
+    assert!(test.server.is_ip_blocked("10.0.0.6".parse().unwrap()));
+
     // Only one message is allowed in the queue from john@doe.org
     session.data.remote_ip_str = "10.0.0.2".into();
     session.eval_session_params().await;
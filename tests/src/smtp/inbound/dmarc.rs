@@ -16,13 +16,18 @@ use mail_auth::{
     common::{parse::TxtRecordParser, verify::DomainKey},
     dkim::DomainKeyReport,
     dmarc::Dmarc,
-    report::DmarcResult,
+    report::{ActionDisposition, DmarcResult, PolicyOverride},
     spf::Spf,
 };
 use registry::{
-    schema::structs::{
-        CertificateManagement, DkimManagement, DkimReportSettings, DmarcReportSettings,
-        DnsManagement, Domain, Expression, ExpressionMatch, SenderAuth, SpfReportSettings,
+    schema::{
+        enums::DmarcPolicyOverride,
+        prelude::Property,
+        structs::{
+            CertificateManagement, DkimManagement, DkimReportSettings, DmarcOverride,
+            DmarcReportSettings, DnsManagement, Domain, Expression, ExpressionMatch, SenderAuth,
+            SpfReportSettings,
+        },
     },
     types::list::List,
 };
@@ -346,4 +351,121 @@ async fn dmarc() {
         .assert_contains("spf=pass")
         .assert_contains("dmarc=pass")
         .assert_contains("Received-SPF: pass");
+
+    // Accept DMARC failures from known mailing lists and forwarders
+    admin
+        .registry_update_setting(
+            SenderAuth {
+                dmarc_overrides: List::from_iter([
+                    DmarcOverride {
+                        list_id: Some("dev.lists.example.org".into()),
+                        override_type: DmarcPolicyOverride::MailingList,
+                        comment: Some("Developers list".into()),
+                        ..Default::default()
+                    },
+                    DmarcOverride {
+                        sender_domain: Some("test.net".into()),
+                        override_type: DmarcPolicyOverride::TrustedForwarder,
+                        ..Default::default()
+                    },
+                ]),
+                ..Default::default()
+            },
+            &[Property::DmarcOverrides],
+        )
+        .await;
+    admin.reload_settings().await;
+    test.reload_core();
+    test.expect_reload_settings().await;
+    test.server.txt_add(
+        "_dmarc.forwarded.org",
+        Dmarc::parse(b"v=DMARC1; p=reject; rua=mailto:dmarc-feedback@forwarded.org").unwrap(),
+        Instant::now() + Duration::from_secs(5),
+    );
+    let mut session = test.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.data.remote_ip = session.data.remote_ip_str.parse().unwrap();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.net").await;
+
+    // Unknown lists are still rejected
+    session
+        .send_message(
+            "joe@test.net",
+            &["jdoe@localdomain.org"],
+            concat!(
+                "From: bill@forwarded.org\r\n",
+                "List-Id: Other List <other.lists.example.org>\r\n",
+                "Subject: TPS Report\r\n\r\n",
+                "I'm going to need those TPS reports ASAP.\r\n"
+            ),
+            "550 5.7.1",
+        )
+        .await;
+    let report = test.read_report().await.unwrap_dmarc();
+    assert_eq!(report.domain, "forwarded.org");
+    assert!(report.report_record.row.policy_evaluated.reason.is_empty());
+
+    // Known lists are accepted and the override is recorded
+    session
+        .send_message(
+            "joe@test.net",
+            &["jdoe@localdomain.org"],
+            concat!(
+                "From: bill@forwarded.org\r\n",
+                "List-Id: Developers <Dev.Lists.Example.org>\r\n",
+                "Subject: TPS Report\r\n\r\n",
+                "I'm going to need those TPS reports ASAP.\r\n"
+            ),
+            "250",
+        )
+        .await;
+    let report = test.read_report().await.unwrap_dmarc();
+    let policy_evaluated = &report.report_record.row.policy_evaluated;
+    assert_eq!(policy_evaluated.disposition, ActionDisposition::None);
+    assert_eq!(policy_evaluated.reason.len(), 1);
+    assert_eq!(
+        policy_evaluated.reason[0].type_,
+        PolicyOverride::MailingList
+    );
+    assert_eq!(
+        policy_evaluated.reason[0].comment.as_deref(),
+        Some("Developers list")
+    );
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("dmarc=fail")
+        .assert_contains("policy.override=mailing_list (Developers list)");
+
+    // Forwarders are matched by their authenticated domain
+    test.server.txt_add(
+        "test.net",
+        Spf::parse(b"v=spf1 ip4:10.0.0.1 -all").unwrap(),
+        Instant::now() + Duration::from_secs(5),
+    );
+    session
+        .send_message(
+            "joe@test.net",
+            &["jdoe@localdomain.org"],
+            concat!(
+                "From: bill@forwarded.org\r\n",
+                "Subject: TPS Report\r\n\r\n",
+                "I'm going to need those TPS reports ASAP.\r\n"
+            ),
+            "250",
+        )
+        .await;
+    let report = test.read_report().await.unwrap_dmarc();
+    assert_eq!(
+        report.report_record.row.policy_evaluated.reason[0].type_,
+        PolicyOverride::TrustedForwarder
+    );
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("spf=pass")
+        .assert_contains("policy.override=trusted_forwarder");
 }
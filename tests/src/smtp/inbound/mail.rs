@@ -301,6 +301,15 @@ async fn mail() {
     assert_eq!(session.data.priority, -3);
     session.rset().await;
 
+    // Unauthenticated clients cannot raise the priority
+    session
+        .ingest(b"MAIL FROM:<jane@foobar.org> MT-PRIORITY=3\r\n")
+        .await
+        .unwrap();
+    session.response().assert_code("250");
+    assert_eq!(session.data.priority, 0);
+    session.rset().await;
+
     // Test REQUIRETLS extension
     session
         .ingest(b"MAIL FROM:<jane@foobar.org> REQUIRETLS\r\n")
@@ -23,6 +23,7 @@ pub mod antispam;
 pub mod asn;
 pub mod auth;
 pub mod basic;
+pub mod callout;
 pub mod data;
 pub mod dmarc;
 pub mod ehlo;
@@ -100,6 +101,7 @@ impl TestServer {
             due: self.message_due(queue_id).await,
             queue_id,
             queue_name: QueueName::new("remote").unwrap(),
+            priority: 0,
         }
     }
 
@@ -122,6 +124,7 @@ impl TestServer {
             due: self.message_due(queue_id).await,
             queue_id,
             queue_name: QueueName::new(queue_name).unwrap(),
+            priority: 0,
         }
     }
 
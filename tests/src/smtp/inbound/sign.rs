@@ -15,13 +15,19 @@ use mail_auth::{
     common::{parse::TxtRecordParser, verify::DomainKey},
     spf::Spf,
 };
-use registry::schema::{
-    enums::{DkimCanonicalization, DkimRotationStage},
-    structs::{
-        CertificateManagement, Dkim1Signature, DkimManagement, DkimSignature, DnsManagement,
-        Domain, Expression, SecretText, SecretTextValue, SenderAuth,
+use registry::{
+    schema::{
+        enums::{DkimCanonicalization, DkimRotationStage},
+        prelude::ObjectType,
+        structs::{
+            CertificateManagement, Dkim1Signature, DkimManagement, DkimSignature, DnsManagement,
+            Domain, Expression, ExpressionMatch, MtaStageData, SecretText, SecretTextValue,
+            SenderAuth, SpamSettings,
+        },
     },
+    types::list::List,
 };
+use serde_json::json;
 use std::time::{Duration, Instant};
 use types::id::Id;
 
@@ -48,7 +54,7 @@ async fn sign_and_seal() {
             ..Default::default()
         })
         .await;
-    admin.create_dkim_signatures(domain_id).await;
+    let signature_ids = admin.create_dkim_signatures(domain_id).await;
     admin.mta_no_auth().await;
     admin.mta_add_all_headers().await;
     admin
@@ -148,6 +154,57 @@ async fn sign_and_seal() {
             "DKIM-Signature: v=1; a=rsa-sha256; s=rsa; d=example.com; c=simple/relaxed;",
         );
 
+    // Signing policy changes are applied without reloading
+    test.account("admin")
+        .registry_update_object(
+            ObjectType::DkimSignature,
+            signature_ids[0],
+            json!({
+                "canonicalization": "relaxed/relaxed",
+                "headers": {"From": true, "Subject": true},
+                "oversignHeaders": {"From": true}
+            }),
+        )
+        .await;
+    session
+        .send_message(
+            "bill@foobar.org",
+            &["jdoe@example.com"],
+            "test:no_dkim",
+            "250",
+        )
+        .await;
+    let message = test.expect_message().await.read_message(&test).await;
+    let signature = message
+        .split("DKIM-Signature:")
+        .find(|signature| signature.contains("s=rsa;"))
+        .unwrap_or_else(|| panic!("Missing RSA signature: {message}"));
+    assert!(
+        signature.contains("c=relaxed/relaxed;"),
+        "Unexpected canonicalization: {signature}"
+    );
+    let signed_headers = signature
+        .split("h=")
+        .nth(1)
+        .and_then(|value| value.split(';').next())
+        .unwrap()
+        .chars()
+        .filter(|ch| !ch.is_whitespace())
+        .collect::<String>()
+        .to_lowercase();
+    assert_eq!(
+        signed_headers
+            .split(':')
+            .filter(|header| *header == "from")
+            .count(),
+        2,
+        "From should be oversigned: {signed_headers}"
+    );
+    assert!(
+        !signed_headers.split(':').any(|header| header == "to"),
+        "To should not be signed: {signed_headers}"
+    );
+
     // Test ARC verify
     session
         .send_message("bill@foobar.org", &["jdoe@example.com"], "test:arc", "250")
@@ -178,6 +235,194 @@ async fn sign_and_seal() {
         );*/
 }
 
+#[tokio::test]
+async fn verify_large_message() {
+    let mut test = TestServerBuilder::new("smtp_large_message_test")
+        .await
+        .with_http_listener(19058)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // Messages over 100 bytes are only parsed up to their headers
+    let admin = test.account("admin");
+    admin
+        .registry_create_object(Domain {
+            name: "example.com".into(),
+            certificate_management: CertificateManagement::Manual,
+            dns_management: DnsManagement::Manual,
+            dkim_management: DkimManagement::Manual,
+            allow_relaying: true,
+            ..Default::default()
+        })
+        .await;
+    admin.mta_no_auth().await;
+    admin
+        .registry_create_object(SpamSettings {
+            enable: false,
+            ..Default::default()
+        })
+        .await;
+    admin
+        .registry_create_object(MtaStageData {
+            add_auth_results_header: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            large_message_size: Expression {
+                else_: "100".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    admin
+        .registry_create_object(SenderAuth {
+            dmarc_verify: Expression {
+                else_: "relaxed".into(),
+                ..Default::default()
+            },
+            reverse_ip_verify: Expression {
+                else_: "relaxed".into(),
+                ..Default::default()
+            },
+            spf_ehlo_verify: Expression {
+                else_: "relaxed".into(),
+                ..Default::default()
+            },
+            spf_from_verify: Expression {
+                else_: "relaxed".into(),
+                ..Default::default()
+            },
+            arc_verify: Expression {
+                else_: "strict".into(),
+                ..Default::default()
+            },
+            dkim_verify: Expression {
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "sender_domain = 'example.com'".into(),
+                    then: "strict".into(),
+                }]),
+                else_: "relaxed".into(),
+            },
+            ..Default::default()
+        })
+        .await;
+    admin.reload_settings().await;
+    test.reload_core();
+    test.expect_reload_settings().await;
+
+    // Add SPF and DKIM records
+    test.server.txt_add(
+        "mx.example.com",
+        Spf::parse(b"v=spf1 ip4:10.0.0.1 -all").unwrap(),
+        Instant::now() + Duration::from_secs(5),
+    );
+    test.server.txt_add(
+        "example.com",
+        Spf::parse(b"v=spf1 ip4:10.0.0.1 -all").unwrap(),
+        Instant::now() + Duration::from_secs(5),
+    );
+    for (name, record) in [
+        (
+            "ed._domainkey.example.com",
+            concat!(
+                "v=DKIM1; k=ed25519; ",
+                "p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="
+            ),
+        ),
+        (
+            "ed._domainkey.scamorza.org",
+            concat!(
+                "v=DKIM1; k=ed25519; ",
+                "p=11qYAYKxCrfVS/7TyWQHOg7hcvPapiMlrwIaaPcHURo="
+            ),
+        ),
+        (
+            "default._domainkey.example.com",
+            concat!(
+                "v=DKIM1; t=s; p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQ",
+                "KBgQDwIRP/UC3SBsEmGqZ9ZJW3/DkMoGeLnQg1fWn7/zYt",
+                "IxN2SnFCjxOCKG9v3b4jYfcTNh5ijSsq631uBItLa7od+v",
+                "/RtdC2UzJ1lWT947qR+Rcac2gbto/NMqJ0fzfVjH4OuKhi",
+                "tdY9tf6mcwGjaNBcWToIMmPSPDdQPNUYckcQ2QIDAQAB",
+            ),
+        ),
+        (
+            "rsa._domainkey.manchego.org",
+            concat!(
+                "v=DKIM1; t=s; p=MIGfMA0GCSqGSIb3DQEBAQUAA4GNADCBiQ",
+                "KBgQDwIRP/UC3SBsEmGqZ9ZJW3/DkMoGeLnQg1fWn7/zYt",
+                "IxN2SnFCjxOCKG9v3b4jYfcTNh5ijSsq631uBItLa7od+v",
+                "/RtdC2UzJ1lWT947qR+Rcac2gbto/NMqJ0fzfVjH4OuKhi",
+                "tdY9tf6mcwGjaNBcWToIMmPSPDdQPNUYckcQ2QIDAQAB",
+            ),
+        ),
+    ] {
+        test.server.txt_add(
+            name,
+            DomainKey::parse(record.as_bytes()).unwrap(),
+            Instant::now() + Duration::from_secs(5),
+        );
+    }
+
+    let mut session = test.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.data.remote_ip = session.data.remote_ip_str.parse().unwrap();
+    session.eval_session_params().await;
+    session.ehlo("mx.example.com").await;
+
+    // DKIM body hashes are still verified against the full message
+    session
+        .send_message(
+            "bill@example.com",
+            &["jdoe@example.com"],
+            "test:dkim",
+            "250",
+        )
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("dkim=pass")
+        .assert_contains("I'm going to need those TPS reports ASAP.");
+    session
+        .send_message(
+            "bill@example.com",
+            &["jdoe@example.com"],
+            "test:invalid_dkim",
+            "550 5.7.20",
+        )
+        .await;
+
+    // ARC chains are verified as well
+    session
+        .send_message(
+            "queso@manchego.org",
+            &["jdoe@example.com"],
+            "test:arc",
+            "250",
+        )
+        .await;
+    test.expect_message()
+        .await
+        .read_lines(&test)
+        .await
+        .assert_contains("We need to settle which one of us is tastier.");
+    session
+        .send_message(
+            "bill@example.com",
+            &["jdoe@example.com"],
+            "test:invalid_arc",
+            "550 5.7.29",
+        )
+        .await;
+    test.assert_no_events();
+}
+
 impl Account {
     pub async fn create_dkim_signatures(&self, domain_id: Id) -> Vec<Id> {
         let rsa_id = self
@@ -73,6 +73,22 @@ async fn throttle_inbound() {
         })
         .await;
 
+    admin
+        .registry_create_object(MtaInboundThrottle {
+            description: "Test IPv6 throttle".into(),
+            enable: true,
+            key: Map::new(vec![MtaInboundThrottleKey::RemoteIp]),
+            match_: Expression {
+                else_: "is_ipv6_addr(remote_ip)".into(),
+                ..Default::default()
+            },
+            rate: Rate {
+                count: 2,
+                period: 1000u64.into(),
+            },
+        })
+        .await;
+
     admin.reload_settings().await;
     test.reload_core();
 
@@ -123,4 +139,15 @@ async fn throttle_inbound() {
     assert!(!session.is_allowed().await, "Rate limiter failed.");
     session.data.remote_ip_str = "10.0.0.2".into();
     assert!(session.is_allowed().await, "Rate limiter too strict.");
+
+    // IPv6 clients are rate limited by /64 prefix
+    session.data.rcpt_to.clear();
+    session.data.mail_from = None;
+    session.data.remote_ip_str = "2001:db8::1".into();
+    assert!(session.is_allowed().await, "Rate limiter too strict.");
+    assert!(session.is_allowed().await, "Rate limiter too strict.");
+    session.data.remote_ip_str = "2001:db8::2".into();
+    assert!(!session.is_allowed().await, "IPv6 prefix not grouped.");
+    session.data.remote_ip_str = "2001:db8:0:1::1".into();
+    assert!(session.is_allowed().await, "Rate limiter too strict.");
 }
@@ -9,7 +9,10 @@ use crate::{
     utils::server::TestServerBuilder,
 };
 use registry::{
-    schema::structs::{Expression, ExpressionMatch, MailingList, MtaExtensions},
+    schema::{
+        prelude::Property,
+        structs::{Expression, ExpressionMatch, MailingList, MtaExtensions},
+    },
     types::{list::List, map::Map},
 };
 
@@ -116,4 +119,57 @@ async fn vrfy_expn() {
 
     // Non-existent EXPN
     session.cmd("EXPN procurement", "550 5.1.2").await;
+
+    // Ambiguous answers for 10.0.0.1 and privacy answers for everyone else
+    admin
+        .registry_update_setting(
+            MtaExtensions {
+                vrfy: Expression {
+                    match_: List::from_iter([ExpressionMatch {
+                        if_: "remote_ip = '10.0.0.1'".into(),
+                        then: "ambiguous".into(),
+                    }]),
+                    else_: "privacy".into(),
+                },
+                expn: Expression {
+                    match_: List::from_iter([ExpressionMatch {
+                        if_: "remote_ip = '10.0.0.1'".into(),
+                        then: "ambiguous".into(),
+                    }]),
+                    else_: "privacy".into(),
+                },
+                vrfy_rate: Expression {
+                    else_: "[2, 1d]".into(),
+                    ..Default::default()
+                },
+                ..Default::default()
+            },
+            &[Property::Vrfy, Property::Expn, Property::VrfyRate],
+        )
+        .await;
+    admin.reload_settings().await;
+    test.reload_core();
+
+    // Privacy mode advertises VRFY/EXPN but never looks up the directory
+    let mut session = test.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.2".into();
+    session.eval_session_params().await;
+    session
+        .ehlo("mx.foobar.org")
+        .await
+        .assert_contains("EXPN")
+        .assert_contains("VRFY");
+    session.cmd("VRFY john@foobar.org", "252 2.1.5").await;
+    session.cmd("VRFY robert@foobar.org", "252 2.1.5").await;
+    session.cmd("EXPN sales@foobar.org", "252 2.1.5").await;
+
+    // Ambiguous mode does not reveal whether a local address exists
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.cmd("EXPN sales@foobar.org", "252 2.1.5").await;
+    session.cmd("VRFY john@foobar.org", "252 2.1.5").await;
+    session.cmd("VRFY robert@foobar.org", "252 2.1.5").await;
+
+    // Directory lookups are rate limited
+    session.cmd("VRFY jane@foobar.org", "452 4.7.1").await;
 }
@@ -5,4 +5,5 @@
  */
 
 pub mod expressions;
+pub mod resolver;
 pub mod utils;
@@ -0,0 +1,156 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use common::config::smtp::resolver::{custom_name_server, encrypted_resolver_config};
+use mail_auth::hickory_resolver::config::{CLOUDFLARE, NameServerConfig, QUAD9};
+use registry::{
+    schema::{
+        enums::DnsResolverProtocol,
+        structs::{DnsCustomResolver, DnsResolverTls},
+    },
+    types::ipaddr::IpAddr,
+};
+use std::str::FromStr;
+
+#[test]
+fn resolver_upstreams() {
+    // DNS-over-HTTPS upstreams use the configured server name and path
+    let name_server = custom_name_server(
+        DnsCustomResolver {
+            protocol: DnsResolverProtocol::Https,
+            address: IpAddr::from_str("192.0.2.53").unwrap(),
+            port: 443,
+            tls_server_name: Some("dns.example.org".to_string()),
+            http_path: Some("/custom-query".to_string()),
+        },
+        false,
+    );
+    let connections = connections(&name_server);
+    assert_eq!(connections.len(), 1, "{connections:?}");
+    assert_connection(
+        &connections[0],
+        443,
+        &["Https", "dns.example.org", "/custom-query"],
+    );
+
+    // TLS upstreams default to the address as server name
+    let name_server = custom_name_server(
+        DnsCustomResolver {
+            protocol: DnsResolverProtocol::Tls,
+            address: IpAddr::from_str("192.0.2.53").unwrap(),
+            port: 853,
+            tls_server_name: None,
+            http_path: None,
+        },
+        false,
+    );
+    let connections = connections(&name_server);
+    assert_eq!(connections.len(), 1, "{connections:?}");
+    assert_connection(&connections[0], 853, &["Tls", "192.0.2.53"]);
+
+    // DNS-over-HTTPS upstreams default to the standard path
+    let name_server = custom_name_server(
+        DnsCustomResolver {
+            protocol: DnsResolverProtocol::Https,
+            address: IpAddr::from_str("192.0.2.53").unwrap(),
+            port: 443,
+            tls_server_name: Some("dns.example.org".to_string()),
+            http_path: None,
+        },
+        true,
+    );
+
+    // Plaintext fallbacks are tried after the encrypted connection
+    let connections = connections(&name_server);
+    assert_eq!(connections.len(), 3, "{connections:?}");
+    assert_connection(&connections[0], 443, &["Https", "/dns-query"]);
+    assert_connection(&connections[1], 53, &["Udp"]);
+    assert_connection(&connections[2], 53, &["Tcp"]);
+
+    // Plaintext upstreams never get a fallback
+    let name_server = custom_name_server(
+        DnsCustomResolver {
+            protocol: DnsResolverProtocol::Udp,
+            address: IpAddr::from_str("192.0.2.53").unwrap(),
+            port: 53,
+            tls_server_name: None,
+            http_path: None,
+        },
+        true,
+    );
+    let connections = connections(&name_server);
+    assert_eq!(connections.len(), 1, "{connections:?}");
+    assert_connection(&connections[0], 53, &["Udp"]);
+
+    // Public resolvers over DNS-over-HTTPS
+    let config = encrypted_resolver_config(
+        &CLOUDFLARE,
+        &DnsResolverTls {
+            use_https: true,
+            ..Default::default()
+        },
+    );
+    assert!(!config.name_servers().is_empty());
+    for name_server in config.name_servers() {
+        let connections = connections(name_server);
+        assert!(!connections.is_empty());
+        for (_, protocol) in &connections {
+            assert!(protocol.contains("Https"), "{connections:?}");
+        }
+    }
+
+    // Public resolvers over TLS with plaintext fallback
+    let config = encrypted_resolver_config(
+        &QUAD9,
+        &DnsResolverTls {
+            use_tls: true,
+            plaintext_fallback: true,
+            ..Default::default()
+        },
+    );
+    assert!(!config.name_servers().is_empty());
+    for name_server in config.name_servers() {
+        let connections = connections(name_server);
+        assert_eq!(connections.len(), 3, "{connections:?}");
+        assert!(connections[0].1.contains("Tls"), "{connections:?}");
+        assert_connection(&connections[1], 53, &["Udp"]);
+        assert_connection(&connections[2], 53, &["Tcp"]);
+    }
+
+    // Disabling encryption leaves the plaintext defaults untouched
+    let config = encrypted_resolver_config(
+        &QUAD9,
+        &DnsResolverTls {
+            use_tls: false,
+            plaintext_fallback: true,
+            ..Default::default()
+        },
+    );
+    for name_server in config.name_servers() {
+        for (port, protocol) in connections(name_server) {
+            assert_eq!(port, 53);
+            assert!(
+                protocol.contains("Udp") || protocol.contains("Tcp"),
+                "{protocol}"
+            );
+        }
+    }
+}
+
+fn connections(name_server: &NameServerConfig) -> Vec<(u16, String)> {
+    name_server
+        .connections
+        .iter()
+        .map(|connection| (connection.port, format!("{:?}", connection.protocol)))
+        .collect()
+}
+
+fn assert_connection(connection: &(u16, String), port: u16, expected: &[&str]) {
+    assert_eq!(connection.0, port, "{connection:?}");
+    for item in expected {
+        assert!(connection.1.contains(item), "{connection:?}");
+    }
+}
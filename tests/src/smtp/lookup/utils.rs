@@ -248,6 +248,7 @@ fn to_remote_hosts() {
         max_mx: 7,
         max_multi_homed: 2,
         ip_lookup_strategy: IpLookupStrategy::Ipv4thenIpv6,
+        proxy: None,
     };
     let hosts = mx.to_remote_hosts("domain", &mx_config).unwrap();
     assert_eq!(hosts.len(), 7);
@@ -90,6 +90,7 @@ async fn manage_queue() {
             name: "default".into(),
             threads_per_node: 25,
             description: None,
+            ..Default::default()
         })
         .await;
     admin
@@ -520,6 +521,24 @@ async fn manage_queue() {
         }
     }
 
+    // Bump the priority of message 'a'
+    admin
+        .registry_update_object(
+            ObjectType::QueuedMessage,
+            id_map["a"],
+            json!({
+                "priority": 5,
+            }),
+        )
+        .await;
+    assert_eq!(
+        admin
+            .registry_get::<QueuedMessage>(id_map["a"])
+            .await
+            .priority,
+        5
+    );
+
     // Cancel deliveries
     for (id, filter) in [
         ("a", &["rcpt1@example2.org", "rcpt2@example2.org"][..]),
@@ -12,6 +12,7 @@ use crate::{
     utils::{dns::DnsCache, server::TestServerBuilder},
 };
 use common::{config::smtp::queue::QueueName, ipc::QueueEvent};
+use email::cache::MessageCacheFetch;
 use registry::{
     schema::{
         enums::{MtaProtocol, NetworkListenerProtocol},
@@ -19,7 +20,8 @@ use registry::{
             Expression, ExpressionMatch, MtaConnectionStrategy, MtaDeliveryExpiration,
             MtaDeliveryExpirationTtl, MtaDeliverySchedule, MtaDeliveryScheduleInterval,
             MtaDeliveryScheduleIntervals, MtaDeliveryScheduleIntervalsOrDefault,
-            MtaOutboundStrategy, MtaRoute, MtaRouteRelay, MtaStageRcpt, MtaVirtualQueue,
+            MtaOutboundStrategy, MtaRoute, MtaRouteLmtpExternal, MtaRouteRelay, MtaStageRcpt,
+            MtaVirtualQueue,
         },
     },
     types::list::List,
@@ -105,6 +107,7 @@ async fn lmtp_delivery() {
             name: "default".into(),
             threads_per_node: 25,
             description: None,
+            ..Default::default()
         })
         .await;
     local_admin
@@ -294,3 +297,114 @@ async fn lmtp_delivery() {
     );
     remote.assert_no_events();
 }
+
+#[tokio::test]
+#[serial_test::serial]
+async fn lmtp_external_delivery() {
+    let mut local = TestServerBuilder::new("lmtp_external_local")
+        .await
+        .with_http_listener(19026)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("lmtp_external_remote")
+        .await
+        .with_http_listener(19027)
+        .await
+        .with_listener(NetworkListenerProtocol::Lmtp, "lmtp-debug", 9924, true)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // Messages for foobar.org are stored locally and handed to an external agent
+    let local_admin = local.account("admin");
+    let account = local_admin
+        .create_user_account("bill@foobar.org", "secret", "Bill", &[], vec![])
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            route: Expression {
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "rcpt_domain = 'foobar.org'".into(),
+                    then: "'lmtp-external'".into(),
+                }]),
+                else_: "'mx'".into(),
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaRoute::LmtpExternal(MtaRouteLmtpExternal {
+            address: "lmtp.foobar.org".into(),
+            allow_invalid_certs: true,
+            implicit_tls: true,
+            deliver_local: true,
+            name: "lmtp-external".into(),
+            port: 9924,
+            ..Default::default()
+        }))
+        .await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_all_extensions().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    // Add mock DNS entries
+    local.server.ipv4_add(
+        "lmtp.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message("john@test.org", &["bill@foobar.org"], "test:no_dkim", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    tokio::time::sleep(Duration::from_millis(500)).await;
+
+    // Both the external agent and the local mailbox received the message
+    assert_eq!(
+        remote
+            .expect_message()
+            .await
+            .message
+            .recipients
+            .into_iter()
+            .map(|r| r.address().to_string())
+            .collect::<Vec<_>>(),
+        vec!["bill@foobar.org".to_string()]
+    );
+    assert_eq!(
+        local
+            .server
+            .get_cached_messages(account.id().document_id())
+            .await
+            .unwrap()
+            .emails
+            .items
+            .len(),
+        1
+    );
+    local.assert_queue_is_empty().await;
+    remote.assert_no_events();
+}
@@ -0,0 +1,254 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::session::TestSession,
+    utils::{dns::DnsCache, server::TestServerBuilder},
+};
+use base64::{Engine, engine::general_purpose::STANDARD};
+use registry::{
+    schema::{
+        enums::{MtaProtocol, MtaProxyProtocol},
+        structs::{
+            Expression, ExpressionMatch, MtaOutboundStrategy, MtaRoute, MtaRouteRelay,
+            MtaStageRcpt, SecretKeyOptional, SecretKeyValue,
+        },
+    },
+    types::list::List,
+};
+use std::{
+    net::{Ipv4Addr, SocketAddr},
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::{Duration, Instant},
+};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream},
+};
+
+const PROXY_USER: &str = "proxy-user";
+const PROXY_SECRET: &str = "proxy-secret";
+
+#[tokio::test]
+#[serial_test::serial]
+async fn proxy_delivery() {
+    let mut local = TestServerBuilder::new("smtp_proxy_local")
+        .await
+        .with_http_listener(19051)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_proxy_remote")
+        .await
+        .with_http_listener(19052)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // Start mock proxies
+    let socks5_connections = Arc::new(AtomicUsize::new(0));
+    let http_connections = Arc::new(AtomicUsize::new(0));
+    spawn_proxy(9932, MtaProxyProtocol::Socks5, socks5_connections.clone()).await;
+    spawn_proxy(9933, MtaProxyProtocol::Http, http_connections.clone()).await;
+
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object(MtaStageRcpt {
+            max_recipients: Expression {
+                else_: "100".into(),
+                ..Default::default()
+            },
+            allow_relaying: Expression {
+                else_: "true".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            route: Expression {
+                match_: List::from_iter([
+                    ExpressionMatch {
+                        if_: "rcpt_domain == 'foobar.org'".into(),
+                        then: "'socks5'".into(),
+                    },
+                    ExpressionMatch {
+                        if_: "rcpt_domain == 'foobar.net'".into(),
+                        then: "'http'".into(),
+                    },
+                ]),
+                else_: "'mx'".into(),
+            },
+            ..Default::default()
+        })
+        .await;
+    for (name, port, protocol) in [
+        ("socks5", 9932, MtaProxyProtocol::Socks5),
+        ("http", 9933, MtaProxyProtocol::Http),
+    ] {
+        local_admin
+            .registry_create_object(MtaRoute::Relay(MtaRouteRelay {
+                address: "relay.foobar.org".into(),
+                implicit_tls: false,
+                allow_invalid_certs: true,
+                name: name.into(),
+                port: 9925,
+                protocol: MtaProtocol::Smtp,
+                proxy_address: Some("127.0.0.1".into()),
+                proxy_port: port,
+                proxy_protocol: protocol,
+                proxy_username: Some(PROXY_USER.into()),
+                proxy_secret: SecretKeyOptional::Value(SecretKeyValue {
+                    secret: PROXY_SECRET.into(),
+                }),
+                ..Default::default()
+            }))
+            .await;
+    }
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    // Add mock DNS entries
+    local.server.ipv4_add(
+        "relay.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    // Deliver through each proxy
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    for (rcpt, connections) in [
+        ("bill@foobar.org", &socks5_connections),
+        ("jane@foobar.net", &http_connections),
+    ] {
+        session
+            .send_message("john@test.org", &[rcpt], "test:no_dkim", "250")
+            .await;
+        local
+            .expect_message_then_deliver()
+            .await
+            .try_deliver(local.server.clone());
+        assert_eq!(
+            remote.expect_message().await.message.recipients[0].address(),
+            rcpt
+        );
+        assert_eq!(connections.load(Ordering::Relaxed), 1);
+    }
+}
+
+async fn spawn_proxy(port: u16, protocol: MtaProxyProtocol, connections: Arc<AtomicUsize>) {
+    let listener = TcpListener::bind(SocketAddr::from((Ipv4Addr::LOCALHOST, port)))
+        .await
+        .unwrap_or_else(|e| panic!("Failed to bind proxy to port {port}: {e}"));
+
+    tokio::spawn(async move {
+        while let Ok((mut stream, _)) = listener.accept().await {
+            let connections = connections.clone();
+            tokio::spawn(async move {
+                let target = match protocol {
+                    MtaProxyProtocol::Socks5 => socks5_accept(&mut stream).await,
+                    MtaProxyProtocol::Http => http_accept(&mut stream).await,
+                };
+                let Some(mut target) = target else {
+                    return;
+                };
+                connections.fetch_add(1, Ordering::Relaxed);
+                let _ = tokio::io::copy_bidirectional(&mut stream, &mut target).await;
+            });
+        }
+    });
+}
+
+async fn socks5_accept(stream: &mut TcpStream) -> Option<TcpStream> {
+    let mut buf = [0u8; 2];
+    stream.read_exact(&mut buf).await.ok()?;
+    let mut methods = vec![0u8; buf[1] as usize];
+    stream.read_exact(&mut methods).await.ok()?;
+    assert!(methods.contains(&2), "Client did not offer authentication");
+    stream.write_all(&[5, 2]).await.ok()?;
+
+    // Username/password authentication
+    stream.read_exact(&mut buf).await.ok()?;
+    let mut username = vec![0u8; buf[1] as usize];
+    stream.read_exact(&mut username).await.ok()?;
+    let mut secret = vec![0u8; stream.read_u8().await.ok()? as usize];
+    stream.read_exact(&mut secret).await.ok()?;
+    assert_eq!(username, PROXY_USER.as_bytes());
+    assert_eq!(secret, PROXY_SECRET.as_bytes());
+    stream.write_all(&[1, 0]).await.ok()?;
+
+    // Connect request
+    let mut request = [0u8; 4];
+    stream.read_exact(&mut request).await.ok()?;
+    assert_eq!(&request[..3], &[5, 1, 0]);
+    assert_eq!(request[3], 1, "Expected an IPv4 address");
+    let mut addr = [0u8; 6];
+    stream.read_exact(&mut addr).await.ok()?;
+    let target = TcpStream::connect(SocketAddr::from((
+        Ipv4Addr::new(addr[0], addr[1], addr[2], addr[3]),
+        u16::from_be_bytes([addr[4], addr[5]]),
+    )))
+    .await
+    .ok()?;
+    stream
+        .write_all(&[5, 0, 0, 1, 127, 0, 0, 1, 0, 0])
+        .await
+        .ok()?;
+
+    Some(target)
+}
+
+async fn http_accept(stream: &mut TcpStream) -> Option<TcpStream> {
+    let mut request = Vec::new();
+    while !request.ends_with(b"\r\n\r\n") {
+        request.push(stream.read_u8().await.ok()?);
+    }
+    let request = String::from_utf8(request).unwrap();
+    let target = request
+        .strip_prefix("CONNECT ")
+        .and_then(|request| request.split_once(' '))
+        .map(|(target, _)| target.to_string())
+        .unwrap_or_else(|| panic!("Invalid CONNECT request: {request:?}"));
+    assert!(
+        request.contains(&format!(
+            "Proxy-Authorization: Basic {}\r\n",
+            STANDARD.encode(format!("{PROXY_USER}:{PROXY_SECRET}"))
+        )),
+        "Missing proxy credentials: {request:?}"
+    );
+    let target = TcpStream::connect(target).await.ok()?;
+    stream
+        .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+        .await
+        .ok()?;
+
+    Some(target)
+}
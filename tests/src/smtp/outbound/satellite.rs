@@ -0,0 +1,193 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::{
+        inbound::{TestMessage, TestQueueEvent},
+        session::{TestSession, VerifyResponse},
+    },
+    utils::{dns::DnsCache, server::TestServerBuilder},
+};
+use registry::{
+    schema::{
+        enums::{MtaDeliveryMode, MtaProtocol},
+        prelude::{ObjectType, Property},
+        structs::{
+            Expression, ExpressionMatch, MtaOutboundStrategy, MtaRoute, MtaRouteRelay,
+            MtaStageAuth, SecretKeyOptional, SecretKeyValue,
+        },
+    },
+    types::list::List,
+};
+use serde_json::json;
+use std::time::{Duration, Instant};
+use store::write::now;
+
+#[tokio::test]
+#[serial_test::serial]
+async fn satellite_delivery() {
+    let mut local = TestServerBuilder::new("smtp_satellite_local")
+        .await
+        .with_http_listener(19062)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_satellite_remote")
+        .await
+        .with_http_listener(19063)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    // The smarthost only accepts authenticated submissions over TLS
+    let remote_admin = remote.account("admin");
+    remote_admin
+        .create_user_account(
+            "relay@foobar.net",
+            "relay secret + extra safety",
+            "Relay",
+            &[],
+            vec![],
+        )
+        .await;
+    remote_admin
+        .registry_create_object(MtaStageAuth {
+            require: Expression {
+                else_: "false".into(),
+                ..Default::default()
+            },
+            must_match_sender: Expression {
+                else_: "false".into(),
+                ..Default::default()
+            },
+            sasl_mechanisms: Expression {
+                match_: List::from_iter([ExpressionMatch {
+                    if_: "is_tls".into(),
+                    then: "[plain, login]".into(),
+                }]),
+                else_: "0".into(),
+            },
+            ..Default::default()
+        })
+        .await;
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_allow_non_fqdn().await;
+    remote_admin.mta_add_all_headers().await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+    let fingerprint = ring::digest::digest(
+        &ring::digest::SHA256,
+        remote
+            .server
+            .inner
+            .data
+            .tls_self_signed_cert
+            .as_ref()
+            .unwrap()
+            .cert[0]
+            .as_ref(),
+    )
+    .as_ref()
+    .iter()
+    .map(|byte| format!("{byte:02x}"))
+    .collect::<String>();
+
+    // Pin the smarthost to a certificate it does not present
+    let local_admin = local.account("admin");
+    local_admin.mta_allow_relaying().await;
+    local_admin.mta_no_auth().await;
+    let route_id = local_admin
+        .registry_create_object(MtaRoute::Relay(MtaRouteRelay {
+            address: "smarthost.foobar.net".into(),
+            port: 9925,
+            protocol: MtaProtocol::Smtp,
+            implicit_tls: false,
+            allow_invalid_certs: true,
+            auth_username: "relay@foobar.net".to_string().into(),
+            auth_secret: SecretKeyOptional::Value(SecretKeyValue {
+                secret: "relay secret + extra safety".into(),
+            }),
+            tls_fingerprint: "00".repeat(32).into(),
+            name: "smarthost".into(),
+            ..Default::default()
+        }))
+        .await;
+    local_admin
+        .registry_create_object(MtaOutboundStrategy {
+            delivery_mode: MtaDeliveryMode::Satellite,
+            smarthost: "smarthost".to_string().into(),
+            ..Default::default()
+        })
+        .await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    // MX records are never looked up, all mail goes to the smarthost
+    local.server.ipv4_add(
+        "smarthost.foobar.net",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    // Certificates that do not match the fingerprint are rejected
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message("john@test.org", &["bill@foobar.org"], "test:no_dkim", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    let mut retry = local.expect_message().await;
+    let status = retry.message.recipients[0].status.to_string();
+    assert!(
+        status.contains("smarthost.foobar.net") && status.contains("pinned fingerprint"),
+        "{status}"
+    );
+    remote.assert_no_events();
+
+    // Pinned smarthosts are authenticated and require TLS
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_update_object(
+            ObjectType::MtaRoute,
+            route_id,
+            json!({
+                Property::TlsFingerprint: fingerprint.to_uppercase()
+            }),
+        )
+        .await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+    let prev_due = retry.message.recipients[0].retry.due;
+    let queue_id = retry.queue_id;
+    retry.message.recipients[0].retry.due = now();
+    retry.save_changes(&local.server, prev_due.into()).await;
+    local
+        .delivery_attempt(queue_id)
+        .await
+        .try_deliver(local.server.clone());
+    remote
+        .expect_message()
+        .await
+        .read_lines(&remote)
+        .await
+        .assert_contains("with ESMTPSA")
+        .assert_contains("using TLSv1.3 with cipher");
+    local.read_event().await.assert_done();
+}
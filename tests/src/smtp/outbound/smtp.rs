@@ -111,6 +111,7 @@ async fn smtp_delivery() {
             name: "default".into(),
             threads_per_node: 25,
             description: None,
+            ..Default::default()
         })
         .await;
     local_admin
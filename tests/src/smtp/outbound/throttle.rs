@@ -49,6 +49,7 @@ async fn throttle_outbound() {
             name: "default".into(),
             threads_per_node: 25,
             description: None,
+            ..Default::default()
         })
         .await;
     admin
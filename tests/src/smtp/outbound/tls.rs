@@ -75,6 +75,7 @@ async fn starttls_optional() {
             name: "default".into(),
             threads_per_node: 25,
             description: None,
+            ..Default::default()
         })
         .await;
     local_admin
@@ -0,0 +1,244 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    smtp::{
+        inbound::{TestMessage, TestQueueEvent},
+        session::{TestSession, VerifyResponse},
+    },
+    utils::{
+        dns::DnsCache,
+        server::{TestServer, TestServerBuilder},
+    },
+};
+use jmap_proto::error::set::SetErrorType;
+use mail_auth::MX;
+use registry::schema::{
+    prelude::{ObjectType, Property},
+    structs::{DataRetention, DeliveryTrace, Expression, MtaStageData},
+};
+use std::{
+    net::IpAddr,
+    time::{Duration, Instant},
+};
+use types::id::Id;
+
+#[tokio::test]
+#[serial_test::serial]
+async fn delivery_traces() {
+    let mut local = TestServerBuilder::new("smtp_trace_local")
+        .await
+        .with_http_listener(19060)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let mut remote = TestServerBuilder::new("smtp_trace_remote")
+        .await
+        .with_http_listener(19061)
+        .await
+        .with_smtp_listener(9925)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+
+    let local_admin = local.account("admin");
+    local_admin.mta_allow_relaying().await;
+    local_admin.mta_no_auth().await;
+    local_admin.mta_all_extensions().await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    let remote_admin = remote.account("admin");
+    remote_admin.mta_all_extensions().await;
+    remote_admin.mta_allow_relaying().await;
+    remote_admin.mta_no_auth().await;
+    remote_admin
+        .registry_create_object(MtaStageData {
+            max_message_size: Expression {
+                else_: "1500".into(),
+                ..Default::default()
+            },
+            enable_spam_filter: Expression {
+                else_: "false".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    remote_admin.reload_settings().await;
+    remote.reload_core();
+    remote.expect_reload_settings().await;
+
+    // Add mock DNS entries
+    local.server.mx_add(
+        "foobar.org",
+        vec![MX {
+            exchanges: vec!["mx.foobar.org".into()].into_boxed_slice(),
+            preference: 10,
+        }],
+        Instant::now() + Duration::from_secs(10),
+    );
+    local.server.ipv4_add(
+        "mx.foobar.org",
+        vec!["127.0.0.1".parse().unwrap()],
+        Instant::now() + Duration::from_secs(10),
+    );
+
+    // Nothing is recorded while traces are disabled
+    let mut session = local.new_mta_session();
+    session.data.remote_ip_str = "10.0.0.1".into();
+    session.eval_session_params().await;
+    session.ehlo("mx.test.org").await;
+    session
+        .send_message("john@test.org", &["bill@foobar.org"], "test:no_dkim", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    remote.expect_message().await;
+    local.read_event().await.assert_done();
+    assert!(delivery_traces_for(&local, "foobar.org").await.is_empty());
+
+    // Enable delivery traces
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_update_setting(
+            DataRetention {
+                hold_delivery_traces_for: Some(3_600_000u64.into()),
+                ..Default::default()
+            },
+            &[Property::HoldDeliveryTracesFor],
+        )
+        .await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+
+    // Successful deliveries record the route, TLS and the status of each recipient
+    session
+        .send_message(
+            "john@test.org",
+            &["bill@foobar.org", "jane@foobar.org"],
+            "test:no_dkim",
+            "250",
+        )
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    remote.expect_message().await;
+    local.read_event().await.assert_done();
+
+    let traces = delivery_traces_for(&local, "foobar.org").await;
+    assert_eq!(traces.len(), 1);
+    let (delivered_id, trace) = &traces[0];
+    let localhost: IpAddr = "127.0.0.1".parse().unwrap();
+    assert_eq!(
+        trace.mx_hosts.iter().collect::<Vec<_>>(),
+        ["mx.foobar.org"],
+        "{trace:?}"
+    );
+    assert_eq!(trace.hosts.len(), 1, "{trace:?}");
+    let host = trace.hosts.iter().next().unwrap();
+    assert_eq!(host.hostname, "mx.foobar.org");
+    assert!(
+        host.resolved_ips.iter().any(|ip| ip.0 == localhost),
+        "{host:?}"
+    );
+    assert_eq!(host.remote_ip.as_ref().map(|ip| ip.0), Some(localhost));
+    assert!(
+        host.tls_version
+            .as_deref()
+            .is_some_and(|v| v.contains("TLSv1_3")),
+        "{host:?}"
+    );
+    assert!(host.tls_cipher.is_some(), "{host:?}");
+    assert!(host.capabilities.contains(&"SIZE".to_string()), "{host:?}");
+    assert_eq!(host.error, None);
+    assert_eq!(
+        trace
+            .results
+            .iter()
+            .map(|result| result.recipient.as_str())
+            .collect::<Vec<_>>(),
+        ["bill@foobar.org", "jane@foobar.org"]
+    );
+    for result in trace.results.iter() {
+        assert!(result.status.starts_with("Delivered"), "{result:?}");
+    }
+    assert!(trace.expires_at > trace.timestamp, "{trace:?}");
+
+    // Rejections are recorded on the host that returned them
+    session
+        .send_message("john@test.org", &["bill@foobar.org"], "test:arc", "250")
+        .await;
+    local
+        .expect_message_then_deliver()
+        .await
+        .try_deliver(local.server.clone());
+    local
+        .expect_message()
+        .await
+        .read_lines(&local)
+        .await
+        .assert_contains("Action: failed");
+    local.read_event().await.assert_done();
+    remote.assert_no_events();
+
+    let traces = delivery_traces_for(&local, "foobar.org").await;
+    assert_eq!(traces.len(), 2);
+    let (failed_id, trace) = traces.iter().find(|(id, _)| id != delivered_id).unwrap();
+    let host = trace.hosts.iter().next().unwrap();
+    assert!(
+        host.error.as_deref().is_some_and(|err| err.contains("552")),
+        "{host:?}"
+    );
+    let result = trace.results.iter().next().unwrap();
+    assert_eq!(result.recipient, "bill@foobar.org");
+    assert!(
+        result.status.starts_with("Permanent Failure") && result.status.contains("552"),
+        "{result:?}"
+    );
+    assert!(delivery_traces_for(&local, "example.org").await.is_empty());
+
+    // Traces are read-only but can be removed
+    let local_admin = local.account("admin");
+    local_admin
+        .registry_create_object_expect_err(DeliveryTrace {
+            domain: "foobar.org".to_string(),
+            ..Default::default()
+        })
+        .await
+        .assert_type(SetErrorType::Forbidden);
+    local_admin
+        .registry_destroy(ObjectType::DeliveryTrace, [*delivered_id, *failed_id])
+        .await
+        .assert_destroyed(&[*delivered_id, *failed_id]);
+    assert!(delivery_traces_for(&local, "foobar.org").await.is_empty());
+}
+
+async fn delivery_traces_for(test: &TestServer, domain: &str) -> Vec<(Id, DeliveryTrace)> {
+    let admin = test.account("admin");
+    let ids = admin
+        .registry_query_ids(
+            ObjectType::DeliveryTrace,
+            [(Property::Domain, domain)],
+            Vec::<&str>::new(),
+        )
+        .await;
+    let mut traces = Vec::with_capacity(ids.len());
+    for id in ids {
+        traces.push((id, admin.registry_get::<DeliveryTrace>(id).await));
+    }
+    traces
+}
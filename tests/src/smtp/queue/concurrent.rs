@@ -74,6 +74,7 @@ async fn concurrent_queue() {
             name: "default".into(),
             threads_per_node: 4,
             description: None,
+            ..Default::default()
         })
         .await;
     local_admin
@@ -6,9 +6,10 @@
 
 use crate::utils::server::{TestServer, TestServerBuilder};
 use common::config::smtp::queue::{QueueExpiry, QueueName};
+use mail_parser::MessageParser;
 use registry::schema::{
     enums::CompressionAlgo,
-    structs::{DsnReportSettings, Expression, ReportSettings},
+    structs::{DsnReportSettings, DsnTemplate, Expression, ReportSettings},
 };
 use smtp::queue::{
     Error, ErrorDetails, HostResponse, Message, MessageWrapper, Recipient, Schedule, Status,
@@ -194,6 +195,56 @@ async fn generate_dsn() {
     // Load queue
     let queue = local.read_queued_messages().await;
     assert_eq!(queue.len(), 4);
+
+    // Custom DSN template
+    local_admin
+        .registry_create_object(DsnTemplate {
+            subject: Some("Returned mail for {{sender}}".into()),
+            body: concat!(
+                "{{summary}}\r\n\r\n",
+                "{{#if failed}}{{failed}}{{/if failed}}",
+                "{{#if delivered}}Delivered: {{delivered}}{{/if delivered}}",
+                "-- {{reporting_mta}}\r\n"
+            )
+            .into(),
+            ..Default::default()
+        })
+        .await;
+    local_admin.reload_settings().await;
+    local.reload_core();
+    local.expect_reload_settings().await;
+    message.message.recipients.push(Recipient {
+        address: "bill@example.org".into(),
+        status: Status::PermanentFailure(ErrorDetails {
+            entity: "mx.example.org".into(),
+            details: Error::DnsError("No MX records found".into()),
+        }),
+        flags,
+        orcpt: None,
+        retry: Schedule::now(),
+        notify: Schedule::now(),
+        expires: QueueExpiry::Ttl(10),
+        queue: QueueName::default(),
+    });
+    local.server.send_dsn(&mut message).await;
+    let dsn_message = local.expect_message().await;
+    let bytes = local
+        .server
+        .blob_store()
+        .get_blob(dsn_message.message.blob_hash.as_slice(), 0..usize::MAX)
+        .await
+        .unwrap()
+        .unwrap();
+    let dsn = MessageParser::new().parse(&bytes).unwrap();
+    assert_eq!(dsn.subject(), Some("Returned mail for sender@foobar.org"));
+    assert_eq!(
+        dsn.body_text(0).unwrap(),
+        concat!(
+            "Your message could not be delivered to the following recipients:\r\n\r\n",
+            "<bill@example.org> (failed to lookup 'mx.example.org': No MX records found)\r\n",
+            "-- mx.example.org\r\n"
+        )
+    );
 }
 
 impl TestServer {
@@ -9,9 +9,12 @@ use crate::{
     utils::server::TestServerBuilder,
 };
 use common::config::smtp::queue::QueueName;
-use smtp::queue::{Error, ErrorDetails, Message, Recipient, Status, spool::SmtpSpool};
+use smtp::queue::{
+    Error, ErrorDetails, Message, Recipient, Status,
+    spool::{DueMessage, SmtpSpool},
+};
 use std::time::Duration;
-use store::write::now;
+use store::write::{BatchBuilder, QueueClass, QueueEvent, ValueClass, now};
 
 #[tokio::test]
 async fn queue_due() {
@@ -69,6 +72,103 @@ async fn queue_due() {
     local.assert_queue_is_empty().await;
 }
 
+#[tokio::test]
+async fn queue_reschedule_and_merge() {
+    let local = TestServerBuilder::new("smtp_queue_reschedule")
+        .await
+        .with_http_listener(19054)
+        .await
+        .disable_services()
+        .capture_queue()
+        .build()
+        .await;
+    let default_queue = QueueName::default();
+    let other_queue = QueueName::new("other").unwrap();
+
+    // Events that fire before any work is due are moved to the next due time
+    let mut message = new_message(0);
+    message
+        .message
+        .recipients
+        .push(build_rcpt("a", 100, 200, 300));
+    message.save_changes(&local.server, None).await;
+    let stale_due = now() - 10;
+    let mut batch = BatchBuilder::new();
+    batch.set(
+        ValueClass::Queue(QueueClass::MessageEvent(QueueEvent {
+            due: stale_due,
+            queue_id: 0,
+            queue_name: default_queue.into_inner(),
+        })),
+        0i16.to_be_bytes().to_vec(),
+    );
+    local.server.store().write(batch.build_all()).await.unwrap();
+    let queued = local.all_queued_messages().await;
+    assert_eq!(queued.messages.len(), 1);
+    assert_eq!(queued.messages[0].due, stale_due);
+    assert!(matches!(
+        local
+            .server
+            .read_due_message(0, default_queue, stale_due)
+            .await,
+        DueMessage::Rescheduled
+    ));
+    assert!(local.all_queued_messages().await.messages.is_empty());
+    assert!(matches!(
+        local
+            .server
+            .read_due_message(1, default_queue, stale_due)
+            .await,
+        DueMessage::NotFound
+    ));
+
+    // Messages with work due are deserialized
+    let mut message = new_message(1);
+    message
+        .message
+        .recipients
+        .push(build_rcpt("b", 0, 200, 300));
+    message.save_changes(&local.server, None).await;
+    let queued = local.all_queued_messages().await;
+    assert_eq!(queued.messages.len(), 1);
+    match local
+        .server
+        .read_due_message(1, default_queue, queued.messages[0].due)
+        .await
+    {
+        DueMessage::Due(message) => {
+            assert_eq!(message.queue_id, 1);
+            message.message.rcpt("b");
+        }
+        _ => panic!("Expected message to be due"),
+    }
+
+    // Queues sharing a message only write back their own recipients
+    let mut message = new_message(2);
+    message
+        .message
+        .recipients
+        .push(build_rcpt("c", 100, 200, 300));
+    message
+        .message
+        .recipients
+        .push(build_rcpt("d", 100, 200, 300));
+    message.message.rcpt_mut("d").queue = other_queue;
+    message.save_changes(&local.server, None).await;
+    let mut default_message = local.server.read_message(2, default_queue).await.unwrap();
+    let mut other_message = local.server.read_message(2, other_queue).await.unwrap();
+    assert!(default_message.is_multi_queue);
+    assert!(other_message.is_multi_queue);
+    default_message.message.rcpt_mut("c").retry.inner = 3;
+    other_message.message.rcpt_mut("d").retry.inner = 5;
+    default_message.save_changes(&local.server, None).await;
+    other_message.save_changes(&local.server, None).await;
+    let message = local.server.read_message(2, default_queue).await.unwrap();
+    assert_eq!(message.message.rcpt("c").retry.inner, 3);
+    assert_eq!(message.message.rcpt("d").retry.inner, 5);
+    assert_eq!(message.message.rcpt("d").queue, other_queue);
+}
+
 #[test]
 fn delivery_events() {
     let mut message = new_message(0).message;
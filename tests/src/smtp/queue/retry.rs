@@ -73,6 +73,7 @@ async fn queue_retry() {
             name: "default".into(),
             threads_per_node: 25,
             description: None,
+            ..Default::default()
         })
         .await;
     local_admin
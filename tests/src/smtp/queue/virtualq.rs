@@ -77,6 +77,7 @@ async fn virtual_queue() {
             name: "q1".into(),
             threads_per_node: 5,
             description: None,
+            ..Default::default()
         })
         .await;
     let queue2_id = local_admin
@@ -84,6 +85,7 @@ async fn virtual_queue() {
             name: "q2".into(),
             threads_per_node: 4,
             description: None,
+            ..Default::default()
         })
         .await;
     local_admin
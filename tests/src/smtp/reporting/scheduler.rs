@@ -7,16 +7,20 @@
 use crate::utils::server::TestServerBuilder;
 use common::{
     config::smtp::report::AggregateFrequency,
-    ipc::{DmarcEvent, PolicyType, TlsEvent},
+    ipc::{DmarcEvent, PolicyType, TlsEvent, TlsInboundEvent},
 };
 use mail_auth::{
     common::parse::TxtRecordParser,
     dmarc::Dmarc,
     mta_sts::TlsRpt,
-    report::{ActionDisposition, DmarcResult, Record},
+    report::{
+        ActionDisposition, DmarcResult, Record,
+        tlsrpt::{FailureDetails, ResultType},
+    },
 };
 use registry::schema::structs::{
-    DmarcInternalReport, DmarcReportSettings, Expression, TlsInternalReport, TlsReportSettings,
+    DmarcInternalReport, DmarcReportSettings, Expression, TlsInboundReport, TlsInternalReport,
+    TlsReportSettings,
 };
 use smtp::reporting::{dmarc::DmarcReporting, tls::TlsReporting};
 use std::sync::Arc;
@@ -186,4 +190,41 @@ async fn report_scheduler() {
     assert_eq!(total_tls, 1);
     assert_eq!(total_tls_policies, 3);
     assert_eq!(total_dmarc_policies, 2);
+
+    // Inbound TLS failures are aggregated per sending domain
+    for (domain, ip, result_type) in [
+        ("example.net", "10.0.0.1", ResultType::StartTlsNotSupported),
+        ("example.net", "10.0.0.1", ResultType::StartTlsNotSupported),
+        ("example.net", "10.0.0.2", ResultType::ValidationFailure),
+        ("example.com", "10.0.0.3", ResultType::StartTlsNotSupported),
+    ] {
+        test.server
+            .schedule_tls_inbound(Box::new(TlsInboundEvent {
+                domain: domain.to_string(),
+                failure: FailureDetails::new(result_type)
+                    .with_sending_mta_ip(ip.parse().unwrap())
+                    .with_receiving_mx_hostname("mx.foobar.org"),
+                span_id: 0,
+            }))
+            .await;
+    }
+    let mut reports = test.read_report_events::<TlsInboundReport>().await;
+    reports.sort_unstable_by(|a, b| a.1.domain.cmp(&b.1.domain));
+    assert_eq!(reports.len(), 2);
+    let (_, report) = &reports[1];
+    assert_eq!(report.domain, "example.net");
+    assert_eq!(report.report.policies.len(), 1);
+    let policy = report.report.policies.iter().next().unwrap();
+    assert_eq!(policy.total_failed_sessions, 3);
+    assert_eq!(policy.failure_details.len(), 2);
+    assert_eq!(
+        policy
+            .failure_details
+            .iter()
+            .next()
+            .unwrap()
+            .failed_session_count,
+        2
+    );
+    assert!(report.expires_at > report.report.date_range_end);
 }
@@ -5,19 +5,32 @@
  */
 
 use crate::{
-    smtp::{inbound::TestMessage, session::VerifyResponse},
-    utils::server::TestServerBuilder,
+    smtp::{
+        inbound::TestMessage,
+        session::{TestSession, VerifyResponse},
+    },
+    utils::{dns::DnsCache, server::TestServerBuilder},
+};
+use common::{
+    config::smtp::report::AggregateFrequency,
+    ipc::{ReportingEvent, TlsEvent},
 };
-use common::{config::smtp::report::AggregateFrequency, ipc::TlsEvent};
 use mail_auth::{
     common::parse::TxtRecordParser,
     flate2::read::GzDecoder,
     mta_sts::TlsRpt,
     report::tlsrpt::{FailureDetails, PolicyType, ResultType, TlsReport},
+    spf::Spf,
+};
+use registry::schema::structs::{
+    Expression, Rate, ReportSettings, SenderAuth, TlsInternalReport, TlsReportSettings,
 };
-use registry::schema::structs::{Expression, ReportSettings, TlsInternalReport, TlsReportSettings};
 use smtp::reporting::tls::{TLS_HTTP_REPORT, TlsReporting};
-use std::{io::Read, sync::Arc, time::Duration};
+use std::{
+    io::Read,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 
 #[tokio::test]
 async fn report_tls() {
@@ -253,3 +266,77 @@ async fn report_tls() {
     }
     test.assert_report_is_empty::<TlsInternalReport>().await;
 }
+
+#[tokio::test]
+async fn report_tls_inbound() {
+    let mut test = TestServerBuilder::new("smtp_report_tls_inbound_test")
+        .await
+        .with_http_listener(19053)
+        .await
+        .disable_services()
+        .capture_reporting()
+        .build()
+        .await;
+
+    let admin = test.account("admin");
+    admin
+        .registry_create_object(ReportSettings {
+            inbound_tls_failure_rate: Some(Rate {
+                count: 1,
+                period: 86_400_000u64.into(),
+            }),
+            ..Default::default()
+        })
+        .await;
+    admin
+        .registry_create_object(SenderAuth {
+            spf_ehlo_verify: Expression {
+                else_: "relaxed".into(),
+                ..Default::default()
+            },
+            spf_from_verify: Expression {
+                else_: "relaxed".into(),
+                ..Default::default()
+            },
+            ..Default::default()
+        })
+        .await;
+    admin.mta_no_auth().await;
+    admin.reload_settings().await;
+    test.reload_core();
+    test.server.txt_add(
+        "foobar.org",
+        Spf::parse(b"v=spf1 ip4:10.0.0.1 -all").unwrap(),
+        Instant::now() + Duration::from_secs(5),
+    );
+
+    // Clients skipping STARTTLS are only reported once SPF authenticates the sender
+    for (remote_ip, helo, from, expected_domain) in [
+        ("10.0.0.2", "foobar.org", "bill@foobar.org", None),
+        ("10.0.0.2", "spoofed.org", "bill@spoofed.org", None),
+        (
+            "10.0.0.1",
+            "spoofed.org",
+            "bill@foobar.org",
+            Some("foobar.org"),
+        ),
+        // Rate limited
+        ("10.0.0.1", "spoofed.org", "jane@foobar.org", None),
+    ] {
+        let mut session = test.new_mta_session();
+        session.data.remote_ip_str = remote_ip.into();
+        session.data.remote_ip = session.data.remote_ip_str.parse().unwrap();
+        session.eval_session_params().await;
+        session.ehlo(helo).await;
+        session.mail_from(from, "250").await;
+
+        match (test.try_read_report().await, expected_domain) {
+            (Some(ReportingEvent::TlsInbound(event)), Some(domain)) => {
+                assert_eq!(event.domain, domain);
+                assert_eq!(event.failure.result_type, ResultType::StartTlsNotSupported);
+            }
+            (None, None) => {}
+            (event, _) => panic!("Unexpected report {event:?} for {remote_ip} {from}"),
+        }
+    }
+}
@@ -75,6 +75,10 @@ impl SessionStream for DummyIo {
     fn tls_version_and_cipher(&self) -> (Cow<'static, str>, Cow<'static, str>) {
         ("".into(), "".into())
     }
+
+    fn tls_peer_certificate(&self) -> Option<&[u8]> {
+        None
+    }
 }
 
 impl Unpin for DummyIo {}
@@ -366,8 +370,10 @@ impl TestServerInstance for ServerInstance {
                 config: tls_config.clone(),
                 acceptor: TlsAcceptor::from(tls_config),
                 implicit: false,
+                client_auth: false,
             },
             limiter: ConcurrencyLimiter::new(100),
+            bandwidth: None,
             shutdown_rx,
             proxy_networks: vec![],
             span_id_gen: Arc::new(SnowflakeIdGenerator::new()),
@@ -8,13 +8,17 @@ use crate::utils::{cleanup::store_destroy, server::TestServerBuilder};
 use ahash::AHashMap;
 use email::message::metadata::MessageMetadata;
 use registry::{
-    schema::{enums::CompressionAlgo, structs::Jmap},
-    types::duration::Duration,
+    schema::{
+        enums::{BlobIntegrityIssueType, CompressionAlgo},
+        structs::Jmap,
+    },
+    types::{EnumImpl, duration::Duration},
 };
 use services::task_manager::destroy_account::destroy_account_blobs;
 use store::{
     BlobStore, Serialize, SerializeInfallible,
-    write::{Archiver, BatchBuilder, BlobLink, BlobOp, ValueClass, now},
+    roaring::RoaringBitmap,
+    write::{Archiver, BatchBuilder, BlobLink, BlobOp, ValueClass, doctor::StoreIssue, now},
 };
 use types::{blob::BlobClass, blob_hash::BlobHash, collection::Collection, field::EmailField};
 
@@ -444,6 +448,93 @@ pub async fn blob_tests() {
         );
     }
 
+    // Corrupt one blob and remove another from the blob store, then scrub
+    let corrupted_hash = BlobHash::generate(b"456".as_slice());
+    let missing_hash = BlobHash::generate(b"hij".as_slice());
+    blob_store
+        .put_blob(corrupted_hash.as_ref(), b"654", CompressionAlgo::Lz4)
+        .await
+        .unwrap();
+    blob_store.delete_blob(missing_hash.as_ref()).await.unwrap();
+    let mut damaged = store
+        .scrub_blobs_all_shards(blob_store.clone(), true, CompressionAlgo::Lz4)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|blob| (blob.hash, blob.issue, blob.repaired))
+        .collect::<Vec<_>>();
+    damaged.sort_unstable_by_key(|(_, issue, _)| issue.to_id());
+    assert_eq!(
+        damaged,
+        vec![
+            (missing_hash, BlobIntegrityIssueType::Missing, false),
+            (corrupted_hash, BlobIntegrityIssueType::Corrupted, false)
+        ]
+    );
+
+    // Keys left behind by destroyed accounts are repaired, dangling links are only reported
+    store_destroy(&store).await;
+    let hash = BlobHash::generate(b"doctor".as_slice());
+    let dangling_hash = BlobHash::generate(b"dangling".as_slice());
+    store
+        .write(
+            BatchBuilder::new()
+                .set(BlobOp::Commit { hash: hash.clone() }, 6u64.serialize())
+                .with_account_id(1)
+                .with_collection(Collection::Email)
+                .with_document(0)
+                .set(
+                    BlobOp::Link {
+                        hash: dangling_hash.clone(),
+                        to: BlobLink::Document,
+                    },
+                    vec![],
+                )
+                .acl_grant(2, 0u64.serialize())
+                .with_account_id(2)
+                .set(
+                    BlobOp::Link {
+                        hash: hash.clone(),
+                        to: BlobLink::Document,
+                    },
+                    vec![],
+                )
+                .build_all(),
+        )
+        .await
+        .unwrap();
+    let accounts = RoaringBitmap::from_iter([1u32]);
+    for repair in [false, true] {
+        let mut findings = store
+            .doctor(&accounts, repair)
+            .await
+            .unwrap()
+            .findings
+            .into_iter()
+            .map(|finding| (finding.issue, finding.account_id, finding.repaired))
+            .collect::<Vec<_>>();
+        findings.sort_unstable_by_key(|(issue, _, _)| *issue as u8);
+        assert_eq!(
+            findings,
+            vec![
+                (StoreIssue::OrphanedAcl, 1, repair),
+                (StoreIssue::OrphanedBlobLink, 2, repair),
+                (StoreIssue::DanglingBlobLink, 1, false),
+            ]
+        );
+    }
+    assert_eq!(
+        store
+            .doctor(&accounts, false)
+            .await
+            .unwrap()
+            .findings
+            .into_iter()
+            .map(|finding| finding.issue)
+            .collect::<Vec<_>>(),
+        vec![StoreIssue::DanglingBlobLink]
+    );
+
     test.temp_dir.delete();
 }
 
@@ -0,0 +1,200 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{
+    account::Account,
+    server::{TestServer, TestServerBuilder},
+    temp_dir::TempDir,
+};
+use email::mailbox::INBOX_ID;
+use jmap_proto::error::set::SetErrorType;
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::{
+            self, BlobStore, BlobStoreBase, BlobStoreRegion, FileSystemStore, RegionalBlobStore,
+            Task, TaskDataRegionMigration, TaskStatus, UserAccount,
+        },
+    },
+    types::list::List,
+};
+use serde_json::json;
+use types::id::Id;
+
+#[tokio::test(flavor = "multi_thread")]
+pub async fn data_region_tests() {
+    let blob_dir = TempDir::new("data_region_blobs", true);
+    let test = TestServerBuilder::new("data_region_tests")
+        .await
+        .with_http_listener(19059)
+        .await
+        .with_object(BlobStore::Regional(RegionalBlobStore {
+            regions: List::from_iter(["eu", "us"].into_iter().map(|name| BlobStoreRegion {
+                name: name.to_string(),
+                store: BlobStoreBase::FileSystem(FileSystemStore {
+                    path: blob_dir.path.join(name).to_string_lossy().to_string(),
+                    depth: 2,
+                }),
+            })),
+            default_region: "eu".to_string(),
+        }))
+        .await
+        .disable_services()
+        .build()
+        .await;
+    let admin = test.create_admin_account("admin@example.com").await;
+    let account = admin
+        .create_user_account(
+            "jdoe@example.com",
+            "12345 + extra safety",
+            "John Doe",
+            &[],
+            vec![],
+        )
+        .await;
+    let account_id = account.id().document_id();
+
+    // Accounts can only be pinned to regions defined in the blob store
+    println!("Running data region tests...");
+    let domain_id = admin.find_or_create_domain("example.com").await;
+    admin
+        .registry_create_object_expect_err(structs::Account::User(UserAccount {
+            name: "jane".to_string(),
+            domain_id,
+            data_region: Some("mars".to_string()),
+            ..Default::default()
+        }))
+        .await
+        .assert_type(SetErrorType::InvalidProperties)
+        .assert_properties(&["dataRegion"]);
+
+    // Regions are only changed through a migration task
+    admin
+        .registry_update_object_expect_err(
+            ObjectType::Account,
+            account.id(),
+            json!({
+                Property::DataRegion: "us"
+            }),
+        )
+        .await
+        .assert_type(SetErrorType::Forbidden)
+        .assert_properties(&["dataRegion"]);
+
+    // Accounts without a region write to the default region
+    let first_id = import_message(&account, "Before migration").await;
+    let blobs = test.server.store().account_blobs(account_id).await.unwrap();
+    assert!(!blobs.exclusive.is_empty());
+    for hash in &blobs.exclusive {
+        assert!(is_blob_in_region(&test, "eu", hash.as_slice()).await);
+        assert!(!is_blob_in_region(&test, "us", hash.as_slice()).await);
+    }
+
+    // Migrating moves the account's blobs and pins it to the target region
+    admin
+        .registry_create_object(Task::DataRegionMigration(TaskDataRegionMigration {
+            account_id: account.id(),
+            data_region: "us".to_string(),
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    assert_eq!(
+        admin
+            .registry_get::<structs::Account>(account.id())
+            .await
+            .data_region(),
+        Some("us")
+    );
+    for hash in &blobs.exclusive {
+        assert!(!is_blob_in_region(&test, "eu", hash.as_slice()).await);
+        assert!(is_blob_in_region(&test, "us", hash.as_slice()).await);
+    }
+    assert_message_contains(&account, &first_id, "Before migration").await;
+
+    // New blobs are written to the account's region
+    let second_id = import_message(&account, "After migration").await;
+    let new_blobs = test.server.store().account_blobs(account_id).await.unwrap();
+    let new_hashes = new_blobs
+        .exclusive
+        .iter()
+        .filter(|hash| !blobs.exclusive.contains(hash))
+        .collect::<Vec<_>>();
+    assert!(!new_hashes.is_empty());
+    for hash in new_hashes {
+        assert!(!is_blob_in_region(&test, "eu", hash.as_slice()).await);
+        assert!(is_blob_in_region(&test, "us", hash.as_slice()).await);
+    }
+    assert_message_contains(&account, &second_id, "After migration").await;
+
+    // Migrating to an undefined region fails without touching the account
+    admin
+        .registry_create_object(Task::DataRegionMigration(TaskDataRegionMigration {
+            account_id: account.id(),
+            data_region: "mars".to_string(),
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    assert_eq!(
+        admin
+            .registry_get::<structs::Account>(account.id())
+            .await
+            .data_region(),
+        Some("us")
+    );
+    assert_message_contains(&account, &first_id, "Before migration").await;
+
+    if test.is_reset() {
+        test.temp_dir.delete();
+    }
+}
+
+async fn import_message(account: &Account, subject: &str) -> String {
+    account
+        .jmap_client()
+        .await
+        .email_import(
+            format!(
+                concat!(
+                    "From: bill@example.com\r\n",
+                    "To: jdoe@example.com\r\n",
+                    "Subject: {}\r\n",
+                    "\r\n",
+                    "{} body."
+                ),
+                subject, subject
+            )
+            .into_bytes(),
+            [Id::from(INBOX_ID).to_string()],
+            None::<Vec<&str>>,
+            None,
+        )
+        .await
+        .unwrap()
+        .take_id()
+}
+
+async fn assert_message_contains(account: &Account, id: &str, text: &str) {
+    let client = account.jmap_client().await;
+    let email = client.email_get(id, None::<Vec<_>>).await.unwrap().unwrap();
+    let message =
+        String::from_utf8(client.download(email.blob_id().unwrap()).await.unwrap()).unwrap();
+    assert!(message.contains(text), "{message}");
+}
+
+async fn is_blob_in_region(test: &TestServer, region: &str, hash: &[u8]) -> bool {
+    test.server
+        .core
+        .storage
+        .blob
+        .in_region(region)
+        .unwrap()
+        .get_blob(hash, 0..usize::MAX)
+        .await
+        .unwrap()
+        .is_some()
+}
@@ -0,0 +1,107 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::temp_dir::TempDir;
+use registry::schema::{
+    enums::{BlobMirrorMode, CompressionAlgo},
+    structs::{BlobStoreBase, FileSystemStore, MirroredBlobStore},
+};
+use std::time::Duration;
+use store::{BlobStore, backend::composite::mirrored_blob::MirroredBlob};
+use types::blob_hash::BlobHash;
+
+const DATA: &[u8] = b"Lorem ipsum dolor sit amet, consectetur adipiscing elit.";
+
+#[tokio::test]
+pub async fn mirrored_blob_tests() {
+    let blob_dir = TempDir::new("mirrored_blobs", true);
+
+    for write_mode in [BlobMirrorMode::Synchronous, BlobMirrorMode::Asynchronous] {
+        println!("Testing mirrored blob store ({write_mode:?})...");
+        let path = blob_dir.path.join(format!("{write_mode:?}").to_lowercase());
+        let store = MirroredBlob::open(MirroredBlobStore {
+            primary_store: BlobStoreBase::FileSystem(FileSystemStore {
+                path: path.join("primary").to_string_lossy().to_string(),
+                depth: 2,
+            }),
+            mirror_store: BlobStoreBase::FileSystem(FileSystemStore {
+                path: path.join("mirror").to_string_lossy().to_string(),
+                depth: 2,
+            }),
+            write_mode,
+            max_queue_size: 100,
+        })
+        .await
+        .unwrap();
+        let BlobStore::Mirrored(mirrored) = &store else {
+            panic!("Expected a mirrored blob store");
+        };
+        let is_async = write_mode == BlobMirrorMode::Asynchronous;
+
+        // Writes go to both copies, asynchronous mirrors are updated in the background
+        let hash = BlobHash::generate(DATA);
+        let key = hash.as_slice();
+        store
+            .put_blob(key, DATA, CompressionAlgo::None)
+            .await
+            .unwrap();
+        assert!(has_blob(&mirrored.primary, key).await);
+        if !is_async {
+            assert!(has_blob(&mirrored.mirror, key).await);
+        }
+        wait_for_blob(&mirrored.mirror, key, true).await;
+        assert_eq!(
+            store.get_blob(key, 0..usize::MAX).await.unwrap().as_deref(),
+            Some(DATA)
+        );
+
+        // Reads fall back to the mirror and the primary copy is repaired
+        assert!(mirrored.primary.delete_blob(key).await.unwrap());
+        assert!(!has_blob(&mirrored.primary, key).await);
+        assert_eq!(
+            store.get_blob(key, 0..usize::MAX).await.unwrap().as_deref(),
+            Some(DATA)
+        );
+        wait_for_blob(&mirrored.primary, key, true).await;
+        assert_eq!(
+            mirrored
+                .primary
+                .get_blob(key, 0..usize::MAX)
+                .await
+                .unwrap()
+                .as_deref(),
+            Some(DATA)
+        );
+
+        // Deletions are applied to both copies
+        assert!(store.delete_blob(key).await.unwrap());
+        assert!(!has_blob(&mirrored.primary, key).await);
+        if !is_async {
+            assert!(!has_blob(&mirrored.mirror, key).await);
+        }
+        wait_for_blob(&mirrored.mirror, key, false).await;
+        assert_eq!(store.get_blob(key, 0..usize::MAX).await.unwrap(), None);
+    }
+
+    blob_dir.delete();
+}
+
+async fn has_blob(store: &BlobStore, key: &[u8]) -> bool {
+    store.get_blob(key, 0..usize::MAX).await.unwrap().is_some()
+}
+
+async fn wait_for_blob(store: &BlobStore, key: &[u8], exists: bool) {
+    for _ in 0..50 {
+        if has_blob(store, key).await == exists {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    panic!(
+        "Blob copy was not {}",
+        if exists { "restored" } else { "removed" }
+    );
+}
@@ -5,11 +5,17 @@
  */
 
 pub mod blob;
+pub mod data_region;
 pub mod import_export;
 pub mod lookup;
+pub mod mirrored_blob;
 pub mod ops;
+pub mod partition;
 pub mod query;
+#[cfg(feature = "redis")]
+pub mod redis_sentinel;
 pub mod registry;
+pub mod tiered_blob;
 
 use crate::utils::server::TestServerBuilder;
 use std::io::Read;
@@ -25,6 +31,7 @@ pub async fn store_tests() {
     registry::test(&test).await;
     import_export::test(&test).await;
     ops::test(&test).await;
+    partition::test(&test).await;
 
     if test.is_reset() {
         test.temp_dir.delete();
@@ -14,6 +14,7 @@ use store::{
 };
 use types::collection::Collection;
 use types::collection::SyncCollection;
+use types::field::MailboxField;
 
 // FDB max value
 const MAX_VALUE_SIZE: usize = 100000;
@@ -322,6 +323,42 @@ pub async fn test(test: &TestServer) {
         1000
     );
 
+    // Fetch multiple per-document counters in a single range scan
+    println!("Fetching mailbox counters in bulk...");
+    let mut batch = BatchBuilder::new();
+    for account_id in [0, 1] {
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
+        for document_id in [0, 3, 7, u32::MAX - 1] {
+            batch.with_document(document_id).add(
+                ValueClass::Property(MailboxField::UidCounter.into()),
+                (account_id * 100 + document_id % 100) as i64 + 1,
+            );
+        }
+    }
+    db.write(batch.build_all()).await.unwrap();
+    let mut counters = db
+        .get_counters(0, Collection::Mailbox, MailboxField::UidCounter)
+        .await
+        .unwrap()
+        .into_iter()
+        .collect::<Vec<_>>();
+    counters.sort_unstable();
+    assert_eq!(counters, vec![(0, 1), (3, 4), (7, 8), (u32::MAX - 1, 95)]);
+    let mut batch = BatchBuilder::new();
+    for account_id in [0, 1] {
+        batch
+            .with_account_id(account_id)
+            .with_collection(Collection::Mailbox);
+        for document_id in [0, 3, 7, u32::MAX - 1] {
+            batch
+                .with_document(document_id)
+                .clear(ValueClass::Property(MailboxField::UidCounter.into()));
+        }
+    }
+    db.write(batch.build_all()).await.unwrap();
+
     // Concurrent changelog
     let mut handles = Vec::new();
     let mut assigned_ids = AHashSet::new();
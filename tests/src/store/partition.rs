@@ -0,0 +1,168 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::server::TestServer;
+
+#[cfg(feature = "foundationdb")]
+const ACCOUNT_FLAT: u32 = 1_000_000;
+#[cfg(feature = "foundationdb")]
+const ACCOUNT_NEW: u32 = 1_000_001;
+#[cfg(feature = "foundationdb")]
+const ACCOUNT_BUSY: u32 = 1_000_002;
+
+#[allow(unused_variables)]
+pub async fn test(test: &TestServer) {
+    #[cfg(feature = "foundationdb")]
+    if matches!(test.server.store(), store::Store::FoundationDb(_)) {
+        use registry::schema::{enums::FoundationDbPartitionMode, structs::FoundationDbStore};
+        use store::{StorePartition, backend::foundationdb::FdbStore};
+
+        println!("Running FoundationDB partition tests...");
+
+        let open = |partition_mode| {
+            FdbStore::open(FoundationDbStore {
+                partition_mode,
+                directory_prefix: "partition-test".into(),
+                ..Default::default()
+            })
+        };
+        let flat = open(FoundationDbPartitionMode::Disabled).await.unwrap();
+
+        // Accounts with legacy data keep using the flat keyspace
+        write_values(&flat, ACCOUNT_FLAT, 0..100).await;
+        let node_a = open(FoundationDbPartitionMode::Account).await.unwrap();
+        let node_b = open(FoundationDbPartitionMode::Account).await.unwrap();
+        write_values(&node_b, ACCOUNT_FLAT, 100..101).await;
+        assert_values(&node_b, ACCOUNT_FLAT, 0..101).await;
+        assert_values(&flat, ACCOUNT_FLAT, 0..101).await;
+
+        // New accounts are partitioned right away
+        write_values(&node_a, ACCOUNT_NEW, 0..1).await;
+        assert_values(&node_b, ACCOUNT_NEW, 0..1).await;
+        assert_eq!(read_value(&flat, ACCOUNT_NEW, 0).await, None);
+
+        // Migrating on one node is seen by the other nodes
+        assert!(node_a.migrate_partitions().await.unwrap() >= 1);
+        assert_eq!(read_value(&flat, ACCOUNT_FLAT, 0).await, None);
+        assert_values(&node_b, ACCOUNT_FLAT, 0..101).await;
+        write_values(&node_b, ACCOUNT_FLAT, 101..102).await;
+        assert_values(&node_a, ACCOUNT_FLAT, 0..102).await;
+        assert_eq!(read_value(&flat, ACCOUNT_FLAT, 101).await, None);
+
+        // Writes issued while an account is being migrated are not lost
+        write_values(&flat, ACCOUNT_BUSY, 0..50000).await;
+        let writer = {
+            let node_b = node_b.clone();
+            tokio::spawn(async move {
+                for n in 50000..50200 {
+                    write_values(&node_b, ACCOUNT_BUSY, n..n + 1).await;
+                }
+            })
+        };
+        assert!(node_a.migrate_partitions().await.unwrap() >= 1);
+        writer.await.unwrap();
+        assert_values(&node_a, ACCOUNT_BUSY, 0..50200).await;
+        assert_values(&node_b, ACCOUNT_BUSY, 0..50200).await;
+        assert_eq!(read_value(&flat, ACCOUNT_BUSY, 0).await, None);
+
+        // Erased partitions are no longer readable
+        for account_id in [ACCOUNT_FLAT, ACCOUNT_NEW, ACCOUNT_BUSY] {
+            assert!(
+                node_a
+                    .erase_partition(StorePartition::Account(account_id))
+                    .await
+                    .unwrap()
+            );
+            assert_eq!(read_value(&node_b, account_id, 0).await, None);
+        }
+        assert!(
+            !node_a
+                .erase_partition(StorePartition::Account(ACCOUNT_NEW))
+                .await
+                .unwrap()
+        );
+    }
+}
+
+#[cfg(feature = "foundationdb")]
+async fn write_values(store: &store::Store, account_id: u32, range: std::ops::Range<u32>) {
+    use store::write::{BatchBuilder, ValueClass};
+    use types::collection::Collection;
+
+    let mut batch = BatchBuilder::new();
+    batch
+        .with_account_id(account_id)
+        .with_collection(Collection::Email);
+    for document_id in range {
+        batch.with_document(document_id).set(
+            ValueClass::Property(0),
+            (document_id as u64).to_be_bytes().to_vec(),
+        );
+        if batch.len() >= 1000 {
+            store.write(batch.build_all()).await.unwrap();
+            batch = BatchBuilder::new();
+            batch
+                .with_account_id(account_id)
+                .with_collection(Collection::Email);
+        }
+    }
+    if !batch.is_empty() {
+        store.write(batch.build_all()).await.unwrap();
+    }
+}
+
+#[cfg(feature = "foundationdb")]
+async fn read_value(store: &store::Store, account_id: u32, document_id: u32) -> Option<u64> {
+    store
+        .get_value::<u64>(store::ValueKey {
+            account_id,
+            collection: types::collection::Collection::Email.into(),
+            document_id,
+            class: store::write::ValueClass::Property(0),
+        })
+        .await
+        .unwrap()
+}
+
+#[cfg(feature = "foundationdb")]
+async fn assert_values(store: &store::Store, account_id: u32, range: std::ops::Range<u32>) {
+    use store::{IterateParams, U32_LEN, ValueKey, write::ValueClass};
+    use types::collection::Collection;
+
+    let mut values = Vec::new();
+    store
+        .iterate(
+            IterateParams::new(
+                ValueKey {
+                    account_id,
+                    collection: Collection::Email.into(),
+                    document_id: 0,
+                    class: ValueClass::Property(0),
+                },
+                ValueKey {
+                    account_id,
+                    collection: Collection::Email.into(),
+                    document_id: u32::MAX,
+                    class: ValueClass::Property(0),
+                },
+            ),
+            |key, value| {
+                values.push((
+                    u32::from_be_bytes(key[key.len() - U32_LEN..].try_into().unwrap()),
+                    u64::from_be_bytes(value.try_into().unwrap()),
+                ));
+                Ok(true)
+            },
+        )
+        .await
+        .unwrap();
+
+    assert_eq!(
+        values,
+        range.map(|n| (n, n as u64)).collect::<Vec<_>>(),
+        "unexpected values for account {account_id}"
+    );
+}
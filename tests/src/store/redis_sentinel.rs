@@ -0,0 +1,101 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use redis::{Client, aio::MultiplexedConnection};
+use registry::{schema::structs::RedisSentinelStore, types::map::Map};
+use store::{
+    InMemoryStore,
+    backend::redis::{RedisPool, RedisStore},
+    dispatch::lookup::KeyValue,
+};
+
+// Requires a Sentinel on 127.0.0.1:26379 monitoring "mymaster" with at least one replica
+const SENTINEL_URL: &str = "redis://127.0.0.1:26379";
+const MASTER_NAME: &str = "mymaster";
+
+#[tokio::test]
+#[ignore]
+pub async fn redis_sentinel_tests() {
+    println!("Testing Redis Sentinel failover...");
+    let store = RedisStore::open_sentinel(RedisSentinelStore {
+        urls: Map::new(vec![SENTINEL_URL.into()]),
+        master_name: MASTER_NAME.into(),
+        max_retries: Some(10),
+        ..Default::default()
+    })
+    .await
+    .unwrap();
+    let sentinel = Client::open(SENTINEL_URL).unwrap();
+    let mut sentinel = sentinel.get_multiplexed_async_connection().await.unwrap();
+
+    // The pool connects to the master reported by the sentinel
+    let master = master_addr(&mut sentinel).await;
+    assert_eq!(connected_master(&store).await, master);
+    let key = "sentinel".as_bytes().to_vec();
+    store
+        .key_set(KeyValue::new(key.clone(), "before".as_bytes().to_vec()))
+        .await
+        .unwrap();
+
+    // Promote a replica and wait for the sentinel to report it
+    tokio::time::sleep(tokio::time::Duration::from_secs(1)).await;
+    redis::cmd("SENTINEL")
+        .arg("failover")
+        .arg(MASTER_NAME)
+        .query_async::<()>(&mut sentinel)
+        .await
+        .unwrap();
+    let mut new_master = master.clone();
+    for _ in 0..60 {
+        tokio::time::sleep(tokio::time::Duration::from_millis(500)).await;
+        new_master = master_addr(&mut sentinel).await;
+        if new_master != master {
+            break;
+        }
+    }
+    assert_ne!(new_master, master, "Sentinel did not complete the failover");
+
+    // Pooled connections to the demoted master are replaced and commands are retried
+    assert_eq!(
+        store.key_get::<String>(key.clone()).await.unwrap(),
+        Some("before".to_string())
+    );
+    store
+        .key_set(KeyValue::new(key.clone(), "after".as_bytes().to_vec()))
+        .await
+        .unwrap();
+    assert_eq!(
+        store.key_get::<String>(key.clone()).await.unwrap(),
+        Some("after".to_string())
+    );
+    assert_eq!(connected_master(&store).await, new_master);
+
+    store.key_delete(key).await.unwrap();
+}
+
+async fn master_addr(sentinel: &mut MultiplexedConnection) -> (String, u16) {
+    redis::cmd("SENTINEL")
+        .arg("get-master-addr-by-name")
+        .arg(MASTER_NAME)
+        .query_async::<(String, u16)>(sentinel)
+        .await
+        .unwrap()
+}
+
+async fn connected_master(store: &InMemoryStore) -> (String, u16) {
+    let InMemoryStore::Redis(store) = store else {
+        panic!("Expected a Redis store");
+    };
+    let RedisPool::Sentinel(pool) = &store.pool else {
+        panic!("Expected a Redis Sentinel pool");
+    };
+    let (client, _) = pool.manager().connect_master().await.unwrap();
+    match &client.get_connection_info().addr {
+        redis::ConnectionAddr::Tcp(host, port) => (host.clone(), *port),
+        redis::ConnectionAddr::TcpTls { host, port, .. } => (host.clone(), *port),
+        addr => panic!("Unexpected master address {addr:?}"),
+    }
+}
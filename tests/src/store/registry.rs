@@ -48,12 +48,14 @@ pub async fn test(test: &TestServer) {
         aliases: List::from_iter([
             EmailAlias {
                 description: "Test Alias 1".to_string().into(),
+                expires_at: None,
                 domain_id: 1000u64.into(),
                 enabled: true,
                 name: "alias1".into(),
             },
             EmailAlias {
                 description: "Test Alias 2".to_string().into(),
+                expires_at: None,
                 domain_id: 1001u64.into(),
                 enabled: true,
                 name: "alias2".into(),
@@ -119,6 +121,7 @@ pub async fn test(test: &TestServer) {
         description: "B".repeat(100_000).into(),
         is_active: true,
         name: "C".repeat(100_000),
+        domain_id: None,
     };
     let script_pickle = script.to_pickled_vec();
     assert!(
@@ -316,6 +319,7 @@ pub async fn test(test: &TestServer) {
             domain_id,
             aliases: List::from_iter([EmailAlias {
                 description: "Test Alias 1".to_string().into(),
+                expires_at: None,
                 domain_id,
                 enabled: true,
                 name: "alias1".into(),
@@ -0,0 +1,230 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{
+    account::Account,
+    server::{TestServer, TestServerBuilder},
+    temp_dir::TempDir,
+};
+use email::mailbox::INBOX_ID;
+use jmap_client::mailbox::Role;
+use registry::schema::{
+    enums::{TaskAccountMaintenanceType, TaskStoreMaintenanceType},
+    prelude::{ObjectType, Property},
+    structs::{
+        BlobStore, BlobStoreBase, FileSystemStore, Task, TaskAccountMaintenance, TaskStatus,
+        TaskStoreMaintenance, TieredBlobStore,
+    },
+};
+use serde_json::json;
+use store::write::now;
+use types::{blob_hash::BlobHash, id::Id};
+
+#[tokio::test(flavor = "multi_thread")]
+pub async fn tiered_blob_tests() {
+    let blob_dir = TempDir::new("tiered_blobs", true);
+    let test = TestServerBuilder::new("tiered_blob_tests")
+        .await
+        .with_http_listener(19064)
+        .await
+        .with_object(BlobStore::Tiered(TieredBlobStore {
+            hot_store: BlobStoreBase::FileSystem(FileSystemStore {
+                path: blob_dir.path.join("hot").to_string_lossy().to_string(),
+                depth: 2,
+            }),
+            cold_store: BlobStoreBase::FileSystem(FileSystemStore {
+                path: blob_dir.path.join("cold").to_string_lossy().to_string(),
+                depth: 2,
+            }),
+        }))
+        .await
+        .disable_services()
+        .build()
+        .await;
+    let admin = test.create_admin_account("admin@example.com").await;
+    let account = admin
+        .create_user_account(
+            "jdoe@example.com",
+            "12345 + extra safety",
+            "John Doe",
+            &[],
+            vec![],
+        )
+        .await;
+    let domain_id = admin.find_or_create_domain("example.com").await;
+    let archive_id = account
+        .jmap_client()
+        .await
+        .mailbox_create("Archive", None::<String>, Role::Archive)
+        .await
+        .unwrap()
+        .take_id();
+    let inbox_id = Id::from(INBOX_ID).to_string();
+
+    // New blobs are always written to the hot tier
+    println!("Running tiered blob store tests...");
+    let (inbox_msg, inbox_blobs) =
+        import_message(&test, &account, "Recent message", &inbox_id, None).await;
+    let (archived_msg, archived_blobs) =
+        import_message(&test, &account, "Archived message", &archive_id, None).await;
+    let (old_msg, old_blobs) = import_message(
+        &test,
+        &account,
+        "Old message",
+        &inbox_id,
+        Some(now() as i64 - 400 * 86400),
+    )
+    .await;
+    for hash in inbox_blobs.iter().chain(&archived_blobs).chain(&old_blobs) {
+        assert_eq!(blob_tier(&test, hash).await, Some(false));
+    }
+
+    // Domains without a cold storage policy are never tiered
+    tier_blobs(&test).await;
+    for hash in inbox_blobs.iter().chain(&archived_blobs).chain(&old_blobs) {
+        assert_eq!(blob_tier(&test, hash).await, Some(false));
+    }
+
+    // Messages in archive folders are moved to the cold tier
+    admin
+        .registry_update_object(
+            ObjectType::Domain,
+            domain_id,
+            json!({
+                Property::ColdStorageArchive: true
+            }),
+        )
+        .await;
+    tier_blobs(&test).await;
+    for hash in &archived_blobs {
+        assert_eq!(blob_tier(&test, hash).await, Some(true));
+    }
+    for hash in inbox_blobs.iter().chain(&old_blobs) {
+        assert_eq!(blob_tier(&test, hash).await, Some(false));
+    }
+    assert_message_contains(&account, &archived_msg, "Archived message").await;
+
+    // Messages older than the retention period are moved to the cold tier
+    admin
+        .registry_update_object(
+            ObjectType::Domain,
+            domain_id,
+            json!({
+                Property::ColdStorageAfter: 30 * 86_400_000u64
+            }),
+        )
+        .await;
+    tier_blobs(&test).await;
+    for hash in archived_blobs.iter().chain(&old_blobs) {
+        assert_eq!(blob_tier(&test, hash).await, Some(true));
+    }
+    for hash in &inbox_blobs {
+        assert_eq!(blob_tier(&test, hash).await, Some(false));
+    }
+    assert_message_contains(&account, &old_msg, "Old message").await;
+    assert_message_contains(&account, &inbox_msg, "Recent message").await;
+
+    // Restoring an account moves all its blobs back to the hot tier
+    admin
+        .registry_create_object(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: account.id(),
+            maintenance_type: TaskAccountMaintenanceType::RestoreBlobs,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    for hash in inbox_blobs.iter().chain(&archived_blobs).chain(&old_blobs) {
+        assert_eq!(blob_tier(&test, hash).await, Some(false));
+    }
+    assert_message_contains(&account, &archived_msg, "Archived message").await;
+    assert_message_contains(&account, &old_msg, "Old message").await;
+
+    if test.is_reset() {
+        test.temp_dir.delete();
+    }
+}
+
+async fn tier_blobs(test: &TestServer) {
+    test.account("admin@example.com")
+        .registry_create_object(Task::StoreMaintenance(TaskStoreMaintenance {
+            maintenance_type: TaskStoreMaintenanceType::TierBlobs,
+            shard_index: None,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+}
+
+async fn import_message(
+    test: &TestServer,
+    account: &Account,
+    subject: &str,
+    mailbox_id: &str,
+    received_at: Option<i64>,
+) -> (String, Vec<BlobHash>) {
+    let account_id = account.id().document_id();
+    let blobs = test.server.store().account_blobs(account_id).await.unwrap();
+    let id = account
+        .jmap_client()
+        .await
+        .email_import(
+            format!(
+                concat!(
+                    "From: bill@example.com\r\n",
+                    "To: jdoe@example.com\r\n",
+                    "Subject: {}\r\n",
+                    "\r\n",
+                    "{} body."
+                ),
+                subject, subject
+            )
+            .into_bytes(),
+            [mailbox_id],
+            None::<Vec<&str>>,
+            received_at,
+        )
+        .await
+        .unwrap()
+        .take_id();
+    let new_blobs = test.server.store().account_blobs(account_id).await.unwrap();
+    let new_hashes = new_blobs
+        .exclusive
+        .into_iter()
+        .chain(new_blobs.shared)
+        .filter(|hash| !blobs.exclusive.contains(hash) && !blobs.shared.contains(hash))
+        .collect::<Vec<_>>();
+    assert!(!new_hashes.is_empty());
+    (id, new_hashes)
+}
+
+async fn assert_message_contains(account: &Account, id: &str, text: &str) {
+    let client = account.jmap_client().await;
+    let email = client.email_get(id, None::<Vec<_>>).await.unwrap().unwrap();
+    let message =
+        String::from_utf8(client.download(email.blob_id().unwrap()).await.unwrap()).unwrap();
+    assert!(message.contains(text), "{message}");
+}
+
+// Returns whether the blob is in the cold tier, or None if it is in neither
+async fn blob_tier(test: &TestServer, hash: &BlobHash) -> Option<bool> {
+    let store::BlobStore::Tiered(store) = &test.server.core.storage.blob else {
+        panic!("Expected a tiered blob store");
+    };
+    let in_hot = store
+        .hot
+        .get_blob(hash.as_slice(), 0..usize::MAX)
+        .await
+        .unwrap()
+        .is_some();
+    let in_cold = store
+        .cold
+        .get_blob(hash.as_slice(), 0..usize::MAX)
+        .await
+        .unwrap()
+        .is_some();
+    assert!(!(in_hot && in_cold), "Blob {hash:?} is in both tiers");
+    (in_hot || in_cold).then_some(in_cold)
+}
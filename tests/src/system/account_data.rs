@@ -0,0 +1,131 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use hyper::Method;
+use jmap_client::email;
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PurgeResponse {
+    account_id: u32,
+    blobs: usize,
+}
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running account data export and purge tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let admin = test.account("admin@example.org");
+    let account = test
+        .create_user_account(
+            "admin@example.org",
+            "gdpr@example.org",
+            "this is a very strong password",
+            &[],
+            "gdpr@example.org",
+        )
+        .await;
+    let account_id = account.id().document_id();
+
+    // Import a couple of messages
+    let mbox = concat!(
+        "From bill@example.org Thu Nov 24 18:22:48 2022\n",
+        "From: bill@example.org\n",
+        "To: gdpr@example.org\n",
+        "Subject: TPS Report #1\n",
+        "\n",
+        "Did you get the memo about the new cover sheets?\n",
+        "\n",
+        "From bill@example.org Mon Jan  2 03:04:05 2023\n",
+        "From: bill@example.org\n",
+        "To: gdpr@example.org\n",
+        "Subject: TPS Report #2\n",
+        "\n",
+        "Yeah, I'm going to need you to come in on Saturday.\n",
+    );
+    let response = http
+        .send_full(
+            Method::POST,
+            &format!("/api/import?accountId={}&format=mbox", account.id_string()),
+            Some(mbox.as_bytes().to_vec()),
+            Some("application/mbox"),
+        )
+        .await;
+    assert!(response.status.is_success(), "{}", response.body);
+
+    // Export all data held for the account
+    let response = http
+        .send_full(
+            Method::GET,
+            &format!("/api/principal/{account_id}/export"),
+            None,
+            None,
+        )
+        .await;
+    assert!(response.status.is_success(), "{}", response.body);
+    assert_eq!(
+        response
+            .headers
+            .get(hyper::header::CONTENT_TYPE)
+            .and_then(|value| value.to_str().ok()),
+        Some("application/zip")
+    );
+    for file in [
+        "account.json",
+        "mailboxes.json",
+        "messages.json",
+        "messages/",
+        "sieve.json",
+        "identities.json",
+        "activity.json",
+    ] {
+        assert!(response.body.contains(file), "missing {file}");
+    }
+
+    // Regular users can't export or purge accounts
+    let user =
+        HttpRequest::with_credentials(8899, "gdpr@example.org", "this is a very strong password");
+    for (method, action) in [(Method::GET, "export"), (Method::POST, "purge")] {
+        let response = user
+            .send_full(
+                method,
+                &format!("/api/principal/{account_id}/{action}"),
+                None,
+                None,
+            )
+            .await;
+        assert!(!response.status.is_success(), "{}", response.body);
+    }
+
+    // Unknown accounts are rejected
+    let response = http
+        .send_full(Method::POST, "/api/principal/999999/purge", None, None)
+        .await;
+    assert!(!response.status.is_success(), "{}", response.body);
+
+    // Purge all data, the account itself is kept
+    let response = http
+        .post::<PurgeResponse>(&format!("/api/principal/{account_id}/purge"), &())
+        .await
+        .unwrap();
+    assert_eq!(response.account_id, account_id);
+    assert_eq!(response.blobs, 2, "{response:?}");
+
+    let client = account.jmap_client().await;
+    assert_eq!(
+        client
+            .email_query(None::<email::query::Filter>, None::<Vec<_>>)
+            .await
+            .unwrap()
+            .ids()
+            .len(),
+        0
+    );
+
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+}
@@ -0,0 +1,219 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{account::Account, server::TestServer};
+use common::storage::audit::MessageAuditIndex;
+use email::mailbox::{INBOX_ID, TRASH_ID};
+use jmap_proto::error::set::SetErrorType;
+use registry::schema::{
+    enums::{MessageAuditEvent, TaskStoreMaintenanceType},
+    prelude::{ObjectType, Property},
+    structs::{DataRetention, MessageAuditEntry, Task, TaskStatus, TaskStoreMaintenance},
+};
+use serde_json::json;
+use std::str::FromStr;
+use store::write::BatchBuilder;
+use types::id::Id;
+
+pub async fn test(test: &TestServer) {
+    println!("Running message audit log tests...");
+    let admin = test.account("admin@example.org");
+    let account = test
+        .create_user_account(
+            "admin@example.org",
+            "audit@example.org",
+            "this is a very strong password",
+            &[],
+            "audit@example.org",
+        )
+        .await;
+    let client = account.jmap_client().await;
+
+    // Nothing is recorded while the audit log is disabled
+    let message_id = import_message(&account, "Not audited").await;
+    client.email_destroy(&message_id).await.unwrap();
+    assert!(audit_entries(test, &account, &[]).await.is_empty());
+
+    // Enable the audit log
+    admin
+        .registry_update_setting(
+            DataRetention {
+                hold_audit_log_for: Some(3_600_000u64.into()),
+                ..Default::default()
+            },
+            &[Property::HoldAuditLogFor],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Deliveries, reads, moves and deletions are recorded
+    let message_id = import_message(&account, "Audited").await;
+    let document_id = Id::from_str(&message_id).unwrap().document_id();
+    let mut imap = account.imap_client().await;
+    imap.send_ok("SELECT INBOX").await;
+    imap.send_ok("FETCH 1 (FLAGS)").await;
+    imap.send_ok("FETCH 1 (BODY.PEEK[])").await;
+    client
+        .email_set_mailboxes(&message_id, [Id::from(TRASH_ID).to_string()])
+        .await
+        .unwrap();
+    client.email_destroy(&message_id).await.unwrap();
+
+    let entries = audit_entries(test, &account, &[]).await;
+    assert_eq!(
+        entries
+            .iter()
+            .map(|(_, entry)| entry.event)
+            .collect::<Vec<_>>(),
+        [
+            MessageAuditEvent::Received,
+            MessageAuditEvent::Read,
+            MessageAuditEvent::Moved,
+            MessageAuditEvent::Deleted,
+        ]
+    );
+    for (_, entry) in &entries {
+        assert_eq!(entry.document_id.document_id(), document_id, "{entry:?}");
+        assert_eq!(entry.performed_by, None, "{entry:?}");
+        assert!(entry.expires_at > entry.timestamp, "{entry:?}");
+    }
+    for (_, entry) in &entries[1..] {
+        assert!(
+            entry
+                .details
+                .as_deref()
+                .is_some_and(|d| d.contains("127.0.0.1")),
+            "{entry:?}"
+        );
+    }
+
+    // Entries can be filtered by event
+    let deleted = audit_entries(test, &account, &[(Property::Event, "deleted")]).await;
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].0, entries[3].0);
+
+    // Entries are append-only
+    admin
+        .registry_create_object_expect_err(MessageAuditEntry {
+            account_id: account.id(),
+            event: MessageAuditEvent::Deleted,
+            ..Default::default()
+        })
+        .await
+        .assert_type(SetErrorType::Forbidden);
+    admin
+        .registry_update_object_expect_err(
+            ObjectType::MessageAuditEntry,
+            entries[0].0,
+            json!({
+                Property::Details: "tampered"
+            }),
+        )
+        .await
+        .assert_type(SetErrorType::Forbidden);
+    admin
+        .registry_destroy_object_expect_err(ObjectType::MessageAuditEntry, entries[0].0)
+        .await
+        .assert_type(SetErrorType::Forbidden);
+
+    // Expired entries are removed by the data purge
+    admin
+        .registry_update_setting(
+            DataRetention {
+                hold_audit_log_for: Some(1000u64.into()),
+                ..Default::default()
+            },
+            &[Property::HoldAuditLogFor],
+        )
+        .await;
+    admin.reload_settings().await;
+    let message_id = import_message(&account, "Short retention").await;
+    assert_eq!(audit_entries(test, &account, &[]).await.len(), 5);
+    tokio::time::sleep(std::time::Duration::from_secs(2)).await;
+    admin
+        .registry_create_object(Task::StoreMaintenance(TaskStoreMaintenance {
+            maintenance_type: TaskStoreMaintenanceType::PurgeData,
+            shard_index: None,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    assert_eq!(
+        audit_entries(test, &account, &[])
+            .await
+            .into_iter()
+            .map(|(id, _)| id)
+            .collect::<Vec<_>>(),
+        entries.iter().map(|(id, _)| *id).collect::<Vec<_>>()
+    );
+
+    // Disable the audit log and remove test data
+    admin
+        .registry_update_setting(DataRetention::default(), &[Property::HoldAuditLogFor])
+        .await;
+    admin.reload_settings().await;
+    client.email_destroy(&message_id).await.unwrap();
+    let entries = audit_entries(test, &account, &[]).await;
+    assert_eq!(entries.len(), 4);
+    let mut batch = BatchBuilder::new();
+    for (id, entry) in entries {
+        entry.write_ops(&mut batch, id.id(), false);
+    }
+    test.server.store().write(batch.build_all()).await.unwrap();
+    admin.destroy_account(account).await;
+    test.wait_for_tasks().await;
+    test.assert_is_empty().await;
+}
+
+async fn import_message(account: &Account, subject: &str) -> String {
+    account
+        .jmap_client()
+        .await
+        .email_import(
+            format!(
+                concat!(
+                    "From: bill@example.org\r\n",
+                    "To: audit@example.org\r\n",
+                    "Message-ID: <{}@example.org>\r\n",
+                    "Subject: {}\r\n",
+                    "\r\n",
+                    "TPS report."
+                ),
+                subject.replace(' ', "-"),
+                subject
+            )
+            .into_bytes(),
+            [Id::from(INBOX_ID).to_string()],
+            None::<Vec<&str>>,
+            None,
+        )
+        .await
+        .unwrap()
+        .take_id()
+}
+
+async fn audit_entries(
+    test: &TestServer,
+    account: &Account,
+    filters: &[(Property, &str)],
+) -> Vec<(Id, MessageAuditEntry)> {
+    let admin = test.account("admin@example.org");
+    let ids = admin
+        .registry_query_ids(
+            ObjectType::MessageAuditEntry,
+            filters
+                .iter()
+                .map(|(property, value)| (*property, value.to_string()))
+                .chain([(Property::AccountId, account.id().to_string())]),
+            Vec::<&str>::new(),
+        )
+        .await;
+    let mut entries = Vec::with_capacity(ids.len());
+    for id in ids {
+        entries.push((id, admin.registry_get::<MessageAuditEntry>(id).await));
+    }
+    entries
+}
@@ -261,6 +261,49 @@ pub async fn test(test: &TestServer) {
         )
         .await;
 
+    // Restrict logins to a network, regardless of the credential used
+    admin
+        .registry_update_object(
+            ObjectType::Account,
+            user_id,
+            json!({
+                Property::AllowedNetworks: {"172.16.0.0/12": true},
+                Property::AllowedCountries: {"aq": true},
+            }),
+        )
+        .await;
+    validate_password_with_ip(
+        "user@example.org",
+        "user provided strong password",
+        "172.16.1.1",
+        true,
+    )
+    .await;
+    validate_password_with_ip(
+        "user@example.org",
+        "user provided strong password",
+        "192.168.1.2",
+        false,
+    )
+    .await;
+    admin
+        .registry_update_object(
+            ObjectType::Account,
+            user_id,
+            json!({
+                Property::AllowedNetworks: {},
+                Property::AllowedCountries: {},
+            }),
+        )
+        .await;
+    validate_password_with_ip(
+        "user@example.org",
+        "user provided strong password",
+        "192.168.1.2",
+        true,
+    )
+    .await;
+
     // Create an IP-restricted App Password and verify it works
     let response = user
         .registry_create([structs::AppPassword {
@@ -339,6 +382,43 @@ pub async fn test(test: &TestServer) {
     validate_password_with_ip("user@example.org", &app_password_secret, "10.0.0.2", false).await;
     validate_password("user@example.org", "user provided strong password", true).await;
 
+    // Requiring two-factor authentication should restrict users without TOTP
+    admin
+        .registry_update_object(
+            ObjectType::Domain,
+            domain_id,
+            json!({
+                Property::RequireMfa: true
+            }),
+        )
+        .await;
+    assert_eq!(
+        user.registry_query(
+            ObjectType::PublicKey,
+            Vec::<(&str, &str)>::new(),
+            Vec::<&str>::new(),
+        )
+        .await
+        .method_response()
+        .text_field("type"),
+        "forbidden"
+    );
+    admin
+        .registry_update_object(
+            ObjectType::Domain,
+            domain_id,
+            json!({
+                Property::RequireMfa: false
+            }),
+        )
+        .await;
+    user.registry_query_ids(
+        ObjectType::PublicKey,
+        Vec::<(&str, &str)>::new(),
+        Vec::<&str>::new(),
+    )
+    .await;
+
     // Clean up
     assert_eq!(
         admin
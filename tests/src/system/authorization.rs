@@ -6,21 +6,25 @@
 
 use crate::utils::{jmap::JmapUtils, server::TestServer};
 use ahash::AHashMap;
-use common::auth::{BuildAccessToken, permissions::DefaultPermissions};
+use common::{
+    auth::{BuildAccessToken, EmailCache, permissions::DefaultPermissions},
+    network::RcptResolution,
+};
 use jmap_proto::error::set::SetErrorType;
 use registry::{
     schema::{
         enums::Permission,
         prelude::{ObjectType, Property},
         structs::{
-            self, AccountSettings, Credential, CustomRoles, PasswordCredential, Role, UserAccount,
-            UserRoles,
+            self, AccountSettings, Credential, CustomRoles, EmailAlias, PasswordCredential, Role,
+            UserAccount, UserRoles,
         },
     },
-    types::{EnumImpl, list::List, map::Map},
+    types::{EnumImpl, datetime::UTCDateTime, list::List, map::Map},
 };
 use serde_json::json;
-use std::str::FromStr;
+use std::{str::FromStr, time::Duration};
+use store::write::now;
 use types::id::Id;
 
 pub async fn test(test: &mut TestServer) {
@@ -125,6 +129,217 @@ pub async fn test(test: &mut TestServer) {
     )
     .await;
 
+    // Aliases can only be managed when allowed by the user's roles
+    let aliases = List::from_iter([EmailAlias {
+        enabled: true,
+        name: "info".to_string(),
+        domain_id,
+        description: Some("Newsletter signups".to_string()),
+        expires_at: None,
+    }]);
+    user.registry_update_object_expect_err(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::Aliases: aliases
+        }),
+    )
+    .await
+    .assert_type(SetErrorType::InvalidProperties);
+    admin
+        .registry_update_object(
+            ObjectType::Role,
+            l2_role_id,
+            json!({
+                Property::EnabledPermissions: Map::new(vec![
+                    Permission::AuthenticateWithAlias,
+                    Permission::SysAccountSettingsUpdate,
+                    Permission::AccountAliasManage,
+                ]),
+            }),
+        )
+        .await;
+    assert!(matches!(
+        test.server
+            .rcpt_id_from_parts("info", domain_id.document_id())
+            .await
+            .unwrap(),
+        None
+    ));
+    user.registry_update_object(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::Aliases: aliases
+        }),
+    )
+    .await;
+    assert!(matches!(
+        test.server
+            .rcpt_id_from_parts("info", domain_id.document_id())
+            .await
+            .unwrap(),
+        Some(EmailCache::Account(id)) if id == user_id.document_id()
+    ));
+
+    // Aliases on other domains are not allowed
+    let other_domain_id = admin.find_or_create_domain("example.com").await;
+    user.registry_update_object_expect_err(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::Aliases: List::from_iter([EmailAlias {
+                enabled: true,
+                name: "info".to_string(),
+                domain_id: other_domain_id,
+                description: None,
+                expires_at: None,
+            }])
+        }),
+    )
+    .await
+    .assert_type(SetErrorType::Forbidden);
+
+    // Rejected updates do not write any of their changes
+    user.registry_update_object_expect_err(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::Description: "Not saved",
+            Property::Aliases: List::from_iter([EmailAlias {
+                enabled: true,
+                name: "sales".to_string(),
+                domain_id: other_domain_id,
+                description: None,
+                expires_at: None,
+            }])
+        }),
+    )
+    .await
+    .assert_type(SetErrorType::Forbidden);
+    let settings = user.registry_get::<AccountSettings>(Id::singleton()).await;
+    assert_eq!(
+        settings.description.as_deref(),
+        Some("Updated description v2")
+    );
+    assert_eq!(settings.aliases, aliases);
+
+    // Time-limited aliases stop receiving messages once they expire
+    user.registry_update_object_expect_err(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::Aliases: List::from_iter([EmailAlias {
+                enabled: true,
+                name: "promo".to_string(),
+                domain_id,
+                description: None,
+                expires_at: Some(UTCDateTime::from_timestamp(now() as i64 - 60)),
+            }])
+        }),
+    )
+    .await
+    .assert_type(SetErrorType::InvalidProperties);
+    user.registry_update_object(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::Aliases: List::from_iter(aliases.values().cloned().chain([EmailAlias {
+                enabled: true,
+                name: "promo".to_string(),
+                domain_id,
+                description: None,
+                expires_at: Some(UTCDateTime::from_timestamp(now() as i64 + 2)),
+            }]))
+        }),
+    )
+    .await;
+    assert!(matches!(
+        test.server
+            .rcpt_resolve("promo@example.org", 0)
+            .await
+            .unwrap(),
+        RcptResolution::Accept
+    ));
+    tokio::time::sleep(Duration::from_secs(3)).await;
+    assert!(matches!(
+        test.server
+            .rcpt_resolve("promo@example.org", 0)
+            .await
+            .unwrap(),
+        RcptResolution::UnknownRecipient
+    ));
+    assert!(matches!(
+        test.server
+            .rcpt_resolve("info@example.org", 0)
+            .await
+            .unwrap(),
+        RcptResolution::Accept
+    ));
+
+    // Catch-all toggles require the domain catch-all permission
+    user.registry_update_object_expect_err(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::CatchAll: true
+        }),
+    )
+    .await
+    .assert_type(SetErrorType::InvalidProperties);
+    admin
+        .registry_update_object(
+            ObjectType::Role,
+            l2_role_id,
+            json!({
+                Property::EnabledPermissions: Map::new(vec![
+                    Permission::AuthenticateWithAlias,
+                    Permission::SysAccountSettingsUpdate,
+                    Permission::AccountAliasManage,
+                    Permission::DomainCatchAllManage,
+                ]),
+            }),
+        )
+        .await;
+    user.registry_update_object(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::CatchAll: true
+        }),
+    )
+    .await;
+    assert!(
+        user.registry_get::<AccountSettings>(Id::singleton())
+            .await
+            .catch_all
+    );
+    assert!(matches!(
+        test.server.rcpt_resolve("unknown@example.org", 0).await.unwrap(),
+        RcptResolution::Rewrite(address) if address == "user@example.org"
+    ));
+    user.registry_update_object(
+        ObjectType::AccountSettings,
+        Id::singleton(),
+        json!({
+            Property::CatchAll: false
+        }),
+    )
+    .await;
+    assert!(
+        !user
+            .registry_get::<AccountSettings>(Id::singleton())
+            .await
+            .catch_all
+    );
+    assert!(matches!(
+        test.server
+            .rcpt_resolve("unknown@example.org", 0)
+            .await
+            .unwrap(),
+        RcptResolution::UnknownRecipient
+    ));
+
     // Disable account settings update permission in the l3 role
     admin
         .registry_update_object(
@@ -4,7 +4,12 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::utils::{jmap::JmapUtils, server::TestServer, smtp::SmtpConnection};
+use crate::utils::{
+    http_server::{HttpMessage, spawn_mock_http_server},
+    jmap::JmapUtils,
+    server::TestServer,
+    smtp::SmtpConnection,
+};
 use common::{
     auth::{
         ACCOUNT_FLAG_ENCRYPT_ALGO_AES128, ACCOUNT_FLAG_ENCRYPT_ALGO_AES256,
@@ -13,13 +18,27 @@ use common::{
     storage::encryption::{EncryptionMethod, parse_public_key},
 };
 use email::message::crypto::EncryptMessage;
+use http_proto::{HttpResponse, JsonResponse, ToHttpResponse};
+use hyper::StatusCode;
 use mail_parser::{MessageParser, MimeHeaders};
-use registry::schema::{
-    prelude::{ObjectType, Property},
-    structs::{EncryptionAtRest, EncryptionSettings, PublicKey},
+use registry::{
+    schema::{
+        prelude::{ObjectType, Property},
+        structs::{
+            Email, EncryptionAtRest, EncryptionKeyProvider, EncryptionSettings, PublicKey,
+            SecretKeyLocalOptional, SecretKeyValue, SecretKeyVault,
+        },
+    },
+    types::duration::Duration,
 };
 use serde_json::json;
-use std::path::PathBuf;
+use std::{
+    path::PathBuf,
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
 use types::id::Id;
 
 pub async fn test(test: &mut TestServer) {
@@ -198,6 +217,155 @@ pub async fn test(test: &mut TestServer) {
         }
     }
 
+    // Obtain the encryption key from a key provider
+    let admin = test.account("admin@example.org");
+    let vault_available = Arc::new(AtomicBool::new(true));
+    let vault_available_ = vault_available.clone();
+    let vault_cert = std::fs::read_to_string(
+        PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("resources")
+            .join("crypto")
+            .join("cert_pgp.pem"),
+    )
+    .unwrap();
+    let vault_path = format!("/v1/secret/data/mail/{}", account.id().document_id());
+    let _tx = spawn_mock_http_server(
+        test,
+        Arc::new(move |req: HttpMessage| {
+            assert_eq!(
+                req.headers.get("x-vault-token").map(|v| v.as_str()),
+                Some("vault-token")
+            );
+            if vault_available_.load(Ordering::Relaxed) && req.uri.path() == vault_path {
+                JsonResponse::new(&json!({
+                    "data": {
+                        "data": {
+                            "certificate": vault_cert
+                        }
+                    }
+                }))
+                .into_http_response()
+            } else {
+                HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE)
+            }
+        }),
+        9096,
+    )
+    .await;
+    let set_key_provider = async |path: &str| {
+        admin
+            .registry_update_setting(
+                Email {
+                    encryption_key_provider: EncryptionKeyProvider::Vault(SecretKeyVault {
+                        url: "https://127.0.0.1:9096".to_string(),
+                        path: path.to_string(),
+                        key: "certificate".to_string(),
+                        token: SecretKeyLocalOptional::Value(SecretKeyValue {
+                            secret: "vault-token".to_string(),
+                        }),
+                        namespace: None,
+                    }),
+                    encryption_key_cache_ttl: Duration::from_millis(100),
+                    ..Default::default()
+                },
+                &[
+                    Property::EncryptionKeyProvider,
+                    Property::EncryptionKeyCacheTtl,
+                ],
+            )
+            .await;
+        admin.reload_settings().await;
+    };
+    set_key_provider("secret/data/mail/{accountId}").await;
+    account
+        .registry_update_object(
+            ObjectType::AccountSettings,
+            Id::singleton(),
+            json!({
+                Property::EncryptionAtRest: EncryptionAtRest::Aes256(EncryptionSettings {
+                    allow_spam_training: true,
+                    encrypt_on_append: true,
+                    public_key: Id::default(),
+                })
+            }),
+        )
+        .await;
+    lmtp.ingest(
+        "bill@example.org",
+        &["jdoe@example.org"],
+        concat!(
+            "From: bill@example.org\r\n",
+            "To: jdoe@example.org\r\n",
+            "Subject: TPS Report (provider key)\r\n",
+            "\r\n",
+            "I'm going to need those TPS reports ASAP."
+        ),
+    )
+    .await;
+
+    // An expired key is still used while the key provider is unavailable
+    vault_available.store(false, Ordering::Relaxed);
+    tokio::time::sleep(std::time::Duration::from_millis(200)).await;
+    lmtp.ingest(
+        "bill@example.org",
+        &["jdoe@example.org"],
+        concat!(
+            "From: bill@example.org\r\n",
+            "To: jdoe@example.org\r\n",
+            "Subject: TPS Report (expired provider key)\r\n",
+            "\r\n",
+            "I'm going to need those TPS reports ASAP."
+        ),
+    )
+    .await;
+
+    // Without a known key the message is delivered unencrypted
+    set_key_provider("secret/data/mail/{accountId}-rotated").await;
+    lmtp.ingest(
+        "bill@example.org",
+        &["jdoe@example.org"],
+        concat!(
+            "From: bill@example.org\r\n",
+            "To: jdoe@example.org\r\n",
+            "Subject: TPS Report (provider unavailable)\r\n",
+            "\r\n",
+            "I'm going to need those TPS reports ASAP."
+        ),
+    )
+    .await;
+
+    let mut request = client.build();
+    request.get_email();
+    let emails = request.send_get_email().await.unwrap().take_list();
+    assert_eq!(emails.len(), 6, "6 messages were expected: {:#?}.", emails);
+    for email in emails {
+        let message =
+            String::from_utf8(client.download(email.blob_id().unwrap()).await.unwrap()).unwrap();
+        if message.contains("provider key") {
+            assert!(
+                message.contains("Content-Type: multipart/encrypted"),
+                "got message {message}, expected encrypted message"
+            );
+        } else if message.contains("provider unavailable") {
+            assert!(
+                message.contains("I'm going to need those TPS reports ASAP."),
+                "got message {message}, expected plain text message"
+            );
+        }
+    }
+
+    // Reset settings
+    admin
+        .registry_update_setting(
+            Email::default(),
+            &[
+                Property::EncryptionKeyProvider,
+                Property::EncryptionKeyCacheTtl,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+
     test.account("admin@example.org")
         .destroy_account(account)
         .await;
@@ -245,6 +413,25 @@ pub async fn import_certs_and_encrypt() {
         }
     }
 
+    // Only RSA certificates can be used for S/MIME encryption
+    assert!(
+        parse_public_key(&PublicKey {
+            description: "err".into(),
+            key: String::from_utf8(
+                std::fs::read(
+                    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                        .join("resources")
+                        .join("crypto")
+                        .join("cert_smime_ec.pem"),
+                )
+                .unwrap()
+            )
+            .unwrap(),
+            ..Default::default()
+        })
+        .is_err()
+    );
+
     // S/MIME and PGP should not be allowed mixed
     assert!(
         parse_public_key(&PublicKey {
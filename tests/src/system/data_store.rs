@@ -0,0 +1,85 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use registry::{
+    schema::{
+        enums::{DataStoreType, TaskStoreMaintenanceType},
+        structs::{Task, TaskStatus, TaskStoreMaintenance},
+    },
+    types::EnumImpl,
+};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct StoreStats {
+    column_families: Vec<ColumnFamilyStats>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ColumnFamilyStats {
+    estimated_keys: u64,
+    sst_files_size: u64,
+    levels: Vec<LevelStats>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LevelStats {
+    files: u64,
+    size: u64,
+}
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running data store maintenance tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let admin = test.account("admin@example.org");
+
+    // Statistics are only available for RocksDB
+    if std::env::var("STORE")
+        .ok()
+        .and_then(|store| DataStoreType::parse(&store))
+        != Some(DataStoreType::RocksDb)
+    {
+        assert!(http.get::<StoreStats>("/api/store/stats").await.is_err());
+        return;
+    }
+
+    // Flush memtables and compact all column families
+    for maintenance_type in [
+        TaskStoreMaintenanceType::FlushDataStore,
+        TaskStoreMaintenanceType::CompactDataStore,
+    ] {
+        admin
+            .registry_create_object(Task::StoreMaintenance(TaskStoreMaintenance {
+                maintenance_type,
+                shard_index: None,
+                status: TaskStatus::now(),
+            }))
+            .await;
+        test.wait_for_tasks().await;
+    }
+
+    // Obtain column family statistics
+    let stats = http.get::<StoreStats>("/api/store/stats").await.unwrap();
+    assert!(!stats.column_families.is_empty());
+    assert!(
+        stats
+            .column_families
+            .iter()
+            .any(|cf| cf.estimated_keys > 0 && cf.sst_files_size > 0 && !cf.levels.is_empty()),
+        "{stats:?}"
+    );
+    for cf in &stats.column_families {
+        assert!(
+            cf.levels
+                .iter()
+                .all(|level| level.files > 0 && level.size > 0),
+            "{cf:?}"
+        );
+    }
+}
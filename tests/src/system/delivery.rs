@@ -9,7 +9,7 @@ use crate::utils::{
 };
 use common::{Server, auth::BuildAccessToken};
 use email::{
-    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
     mailbox::{INBOX_ID, JUNK_ID, SENT_ID},
     message::metadata::MessageMetadata,
 };
@@ -18,10 +18,10 @@ use jmap::blob::download::BlobDownload;
 use jmap_proto::error::set::SetErrorType;
 use registry::{
     schema::{
-        enums::StorageQuota,
+        enums::{MailingListReplyTo, MailingListSenderRewrite, StorageQuota},
         prelude::{ObjectType, Property},
         structs::{
-            EmailAlias, Expression, MailingList, MtaExtensions, SpamTag, SpamTagScore,
+            Email, EmailAlias, Expression, MailingList, MtaExtensions, SpamTag, SpamTagScore,
             SpamTrainingSample,
         },
     },
@@ -577,6 +577,222 @@ END:VCARD
         }
     }
 
+    // Copies of the same message delivered within the duplicate window are suppressed
+    admin
+        .registry_update_setting(
+            Email {
+                duplicate_window: Some(Duration::from_secs(3600).into()),
+                ..Default::default()
+            },
+            &[Property::DuplicateWindow],
+        )
+        .await;
+    admin.reload_settings().await;
+    for subject in ["Lunch", "Lunch (via members)"] {
+        lmtp.ingest(
+            "bill@example.org",
+            &["jane.smith@example.org"],
+            &format!(
+                concat!(
+                    "From: bill@example.org\r\n",
+                    "To: members@example.org\r\n",
+                    "Cc: jane.smith@example.org\r\n",
+                    "Message-ID: <lunch-1234@example.org>\r\n",
+                    "Subject: {}\r\n",
+                    "\r\n",
+                    "Lunch is on me today."
+                ),
+                subject
+            ),
+        )
+        .await;
+    }
+    assert_eq!(
+        test.server
+            .get_cached_messages(jane.id().document_id())
+            .await
+            .unwrap()
+            .in_mailbox(INBOX_ID)
+            .count(),
+        4
+    );
+    admin
+        .registry_update_setting(Email::default(), &[Property::DuplicateWindow])
+        .await;
+    admin.reload_settings().await;
+
+    // Damaged messages are only repaired when MIME repair is enabled
+    for (repair_mime, subject) in [(false, "Kept"), (true, "Repaired")] {
+        admin
+            .registry_update_setting(
+                Email {
+                    repair_mime,
+                    ..Default::default()
+                },
+                &[Property::RepairMime],
+            )
+            .await;
+        admin.reload_settings().await;
+        lmtp.ingest(
+            "bill@example.org",
+            &["jane.smith@example.org"],
+            &format!(
+                concat!(
+                    "From: bill@example.org\r\n",
+                    "To: jane.smith@example.org\r\n",
+                    "Subject: {}\r\n",
+                    "Subject: Duplicate\r\n",
+                    "Content-Type: text/plain\r\n",
+                    "Content-Transfer-Encoding: base64\r\n",
+                    "\r\n",
+                    "SGVsbG8gd29ybGQ\r\n"
+                ),
+                subject
+            ),
+        )
+        .await;
+        let headers = message_headers(
+            &test.server,
+            jane.id().document_id(),
+            test.server
+                .get_cached_messages(jane.id().document_id())
+                .await
+                .unwrap()
+                .in_mailbox(INBOX_ID)
+                .map(|e| e.document_id)
+                .max()
+                .unwrap(),
+        )
+        .await;
+        assert!(
+            headers.contains(&format!("Subject: {subject}")),
+            "{headers}"
+        );
+        if repair_mime {
+            for value in [
+                "X-MIME-Repaired: duplicate-header=Subject",
+                "base64-padding=part-0",
+            ] {
+                assert!(headers.contains(value), "missing {value:?} in {headers:?}");
+            }
+            assert!(!headers.contains("Subject: Duplicate"), "{headers}");
+        } else {
+            assert!(!headers.contains("X-MIME-Repaired"), "{headers}");
+            assert!(headers.contains("Subject: Duplicate"), "{headers}");
+        }
+    }
+    admin
+        .registry_update_setting(Email::default(), &[Property::RepairMime])
+        .await;
+    admin.reload_settings().await;
+
+    // Lists with a policy rewrite posts and only accept them from members
+    admin
+        .registry_create_object(MailingList {
+            name: "announce".to_string(),
+            recipients: Map::new(vec![
+                "jane.smith@example.org".to_string(),
+                "bill@example.org".to_string(),
+            ]),
+            domain_id,
+            sender_rewrite: MailingListSenderRewrite::EnvelopeAndFrom,
+            subject_prefix: Some("[announce]".to_string()),
+            reply_to: MailingListReplyTo::List,
+            members_only: true,
+            archive_account_id: Some(john.id()),
+            archive_folder: "Lists/Announce".to_string(),
+            ..Default::default()
+        })
+        .await;
+    lmtp.mail_from("jdoe@example.org", 2).await;
+    lmtp.rcpt_to("announce@example.org", 5)
+        .await
+        .assert_contains("5.7.1");
+    lmtp.rset().await;
+    lmtp.ingest(
+        "bill@example.org",
+        &["announce@example.org"],
+        concat!(
+            "From: Bill <bill@example.org>\r\n",
+            "To: announce@example.org\r\n",
+            "Reply-To: bill@example.org\r\n",
+            "Subject: Office move\r\n",
+            "\r\n",
+            "We are moving to the basement on Monday."
+        ),
+    )
+    .await;
+    tokio::time::sleep(Duration::from_millis(200)).await;
+    let jane_cache = test
+        .server
+        .get_cached_messages(jane.id().document_id())
+        .await
+        .unwrap();
+    let headers = message_headers(
+        &test.server,
+        jane.id().document_id(),
+        jane_cache
+            .in_mailbox(INBOX_ID)
+            .map(|e| e.document_id)
+            .max()
+            .unwrap(),
+    )
+    .await;
+    for value in [
+        "Subject: [announce] Office move",
+        "From: \"Bill via announce@example.org\" <announce@example.org>",
+        "X-Original-From: \"Bill\" <bill@example.org>",
+        "Reply-To: <announce@example.org>",
+        "List-Id: <announce.example.org>",
+        "List-Post: <mailto:announce@example.org>",
+        "Precedence: list",
+    ] {
+        assert!(headers.contains(value), "missing {value:?} in {headers:?}");
+    }
+    assert!(!headers.contains("Reply-To: bill@example.org"), "{headers}");
+
+    // Posts are archived and bounces to <list>-bounces are accepted
+    lmtp.ingest(
+        "",
+        &["announce-bounces@example.org"],
+        concat!(
+            "From: MAILER-DAEMON@remote.org\r\n",
+            "To: announce-bounces@example.org\r\n",
+            "Subject: Undelivered Mail Returned to Sender\r\n",
+            "Content-Type: multipart/report; report-type=delivery-status; boundary=\"b\"\r\n",
+            "\r\n",
+            "--b\r\n",
+            "Content-Type: text/plain\r\n",
+            "\r\n",
+            "Mailbox does not exist.\r\n",
+            "--b\r\n",
+            "Content-Type: message/delivery-status\r\n",
+            "\r\n",
+            "Reporting-MTA: dns;remote.org\r\n",
+            "\r\n",
+            "Final-Recipient: rfc822;gone@remote.org\r\n",
+            "Action: failed\r\n",
+            "Status: 5.1.1\r\n",
+            "--b--\r\n"
+        ),
+    )
+    .await;
+    let john_cache = test
+        .server
+        .get_cached_messages(john.id().document_id())
+        .await
+        .unwrap();
+    for (path, num_messages) in [("Lists/Announce", 1), ("Lists/Announce/Bounces", 1)] {
+        let mailbox = john_cache
+            .mailbox_by_path(path)
+            .unwrap_or_else(|| panic!("missing mailbox {path}"));
+        assert_eq!(
+            john_cache.in_mailbox(mailbox.document_id).count(),
+            num_messages,
+            "for {path}"
+        );
+    }
+
     // Remove test data
     john.registry_destroy(
         ObjectType::MaskedEmail,
@@ -616,7 +832,7 @@ impl Account {
     }
 }
 
-async fn assert_message_headers_contains(
+pub async fn assert_message_headers_contains(
     server: &Server,
     account_id: u32,
     document_id: u32,
@@ -0,0 +1,200 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{
+    http::HttpRequest,
+    imap::{AssertResult, Type},
+    server::TestServer,
+};
+use imap_proto::ResponseType;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunResponse {
+    verdict: String,
+    stage: String,
+    response: String,
+    recipients: Vec<DryRunRecipient>,
+    filters: Vec<serde_json::Value>,
+    headers: String,
+    size: u64,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct DryRunRecipient {
+    address: String,
+    response: String,
+    is_local: bool,
+    mailboxes: Vec<String>,
+    redirects: Vec<String>,
+    reject_reason: Option<String>,
+}
+
+const MESSAGE: &str = concat!(
+    "From: john@remote.net\r\n",
+    "To: admin@example.org\r\n",
+    "Subject: Dry run\r\n",
+    "\r\n",
+    "This message should never be delivered.\r\n"
+);
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running mail flow dry run tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+
+    // Inbound message to a local recipient
+    let response = dry_run(&http, local_request(MESSAGE)).await;
+    assert_eq!(response.verdict, "accept", "{response:?}");
+    assert_eq!(response.stage, "data", "{response:?}");
+    assert!(response.response.contains("dry run"), "{response:?}");
+    assert!(response.headers.contains("Received: "), "{response:?}");
+    assert!(response.size > MESSAGE.len() as u64, "{response:?}");
+    assert_eq!(response.recipients.len(), 1);
+    assert_eq!(response.recipients[0].address, "admin@example.org");
+    assert!(response.recipients[0].response.starts_with("250"));
+    assert!(response.recipients[0].is_local, "{response:?}");
+    assert_eq!(response.recipients[0].mailboxes, ["Inbox"], "{response:?}");
+    assert!(response.filters.is_empty(), "{response:?}");
+
+    // Destination mailboxes and redirects are reported from the active script
+    let client = test.account("admin").jmap_client().await;
+    let script_id = client
+        .sieve_script_create(
+            "dry_run",
+            concat!(
+                "require [\"fileinto\", \"mailbox\"];\n",
+                "if header :contains \"Subject\" \"Dry run\" {\n",
+                "    fileinto :create \"Dry Runs\";\n",
+                "    redirect \"audit@remote.net\";\n",
+                "}\n"
+            )
+            .as_bytes()
+            .to_vec(),
+            true,
+        )
+        .await
+        .unwrap()
+        .take_id();
+    let response = dry_run(&http, local_request(MESSAGE)).await;
+    assert_eq!(response.verdict, "accept", "{response:?}");
+    assert_eq!(
+        response.recipients[0].mailboxes,
+        ["Dry Runs"],
+        "{response:?}"
+    );
+    assert_eq!(
+        response.recipients[0].redirects,
+        ["audit@remote.net"],
+        "{response:?}"
+    );
+    assert_eq!(response.recipients[0].reject_reason, None, "{response:?}");
+
+    // Nothing was delivered and the mailbox was not created
+    let mut imap = test.account("admin").imap_client().await;
+    imap.send("LIST \"\" \"*\"").await;
+    imap.assert_read(Type::Tagged, ResponseType::Ok)
+        .await
+        .assert_not_contains("Dry Runs");
+    imap.send_ok("LOGOUT").await;
+
+    // Script rejections are reported for the recipient
+    client.sieve_script_deactivate().await.unwrap();
+    client.sieve_script_destroy(&script_id).await.unwrap();
+    let script_id = client
+        .sieve_script_create(
+            "dry_run",
+            concat!(
+                "require \"reject\";\n",
+                "reject \"No dry runs allowed.\";\n"
+            )
+            .as_bytes()
+            .to_vec(),
+            true,
+        )
+        .await
+        .unwrap()
+        .take_id();
+    let response = dry_run(&http, local_request(MESSAGE)).await;
+    assert_eq!(
+        response.recipients[0].reject_reason.as_deref(),
+        Some("No dry runs allowed."),
+        "{response:?}"
+    );
+    assert!(response.recipients[0].mailboxes.is_empty(), "{response:?}");
+    client.sieve_script_deactivate().await.unwrap();
+    client.sieve_script_destroy(&script_id).await.unwrap();
+
+    // Relaying is denied at the RCPT stage
+    let response = dry_run(
+        &http,
+        json!({
+            "message": MESSAGE,
+            "remoteIp": "10.0.0.1",
+            "helo": "mx.remote.net",
+            "mailFrom": "john@remote.net",
+            "rcptTo": ["jane@remote.net"],
+            "liveDns": false
+        }),
+    )
+    .await;
+    assert_eq!(response.verdict, "reject", "{response:?}");
+    assert_eq!(response.stage, "rcpt", "{response:?}");
+    assert!(response.response.starts_with("5"), "{response:?}");
+
+    // Unparseable messages are rejected at the DATA stage
+    let response = dry_run(
+        &http,
+        json!({
+            "message": "not a message",
+            "remoteIp": "10.0.0.1",
+            "helo": "mx.remote.net",
+            "mailFrom": "john@remote.net",
+            "rcptTo": ["admin@example.org"],
+            "liveDns": false
+        }),
+    )
+    .await;
+    assert_eq!(response.verdict, "reject", "{response:?}");
+    assert_eq!(response.stage, "data", "{response:?}");
+    assert!(response.response.starts_with("550 5.7.7"), "{response:?}");
+
+    // Invalid authentication results are rejected
+    let response = http
+        .post::<serde_json::Value>(
+            "/api/dry-run",
+            &json!({
+                "message": MESSAGE,
+                "mailFrom": "john@remote.net",
+                "rcptTo": ["admin@example.org"],
+                "iprev": "maybe"
+            }),
+        )
+        .await
+        .unwrap();
+    assert!(response.get("verdict").is_none(), "{response}");
+}
+
+fn local_request(message: &str) -> serde_json::Value {
+    json!({
+        "message": message,
+        "remoteIp": "10.0.0.1",
+        "helo": "mx.remote.net",
+        "mailFrom": "john@remote.net",
+        "rcptTo": ["admin@example.org"],
+        "liveDns": false,
+        "iprev": "pass",
+        "dmarc": {"result": "pass", "policy": "none"}
+    })
+}
+
+async fn dry_run(http: &HttpRequest, request: serde_json::Value) -> DryRunResponse {
+    http.post::<DryRunResponse>("/api/dry-run", &request)
+        .await
+        .unwrap()
+}
@@ -0,0 +1,151 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+struct EvalResponse {
+    result: serde_json::Value,
+    matched: Option<usize>,
+    trace: Vec<EvalBranch>,
+}
+
+#[derive(Debug, Deserialize)]
+struct EvalBranch {
+    #[serde(rename = "if")]
+    if_: String,
+    result: Option<bool>,
+}
+
+pub async fn test(_test: &mut TestServer) {
+    println!("Running expression evaluation tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+
+    // Stored expression, first branch matches
+    let response = eval(
+        &http,
+        json!({
+            "object": "MtaOutboundStrategy",
+            "property": "route",
+            "envelope": {
+                "sender": "john@remote.net",
+                "rcpt": ["Jane@Example.org"]
+            }
+        }),
+    )
+    .await;
+    assert_eq!(response.result, json!("local"), "{response:?}");
+    assert_eq!(response.matched, Some(0), "{response:?}");
+    assert_eq!(response.trace.len(), 1, "{response:?}");
+    assert_eq!(response.trace[0].if_, "is_local_domain(rcpt_domain)");
+    assert_eq!(response.trace[0].result, Some(true));
+
+    // Stored expression, falls through to the default
+    let response = eval(
+        &http,
+        json!({
+            "object": "MtaOutboundStrategy",
+            "property": "route",
+            "envelope": {
+                "sender": "jane@example.org",
+                "rcpt": ["john@remote.net"]
+            }
+        }),
+    )
+    .await;
+    assert_eq!(response.result, json!("mx"), "{response:?}");
+    assert_eq!(response.matched, None, "{response:?}");
+    assert_eq!(response.trace[0].result, Some(false));
+
+    // Unsaved expression, later branches are not evaluated
+    let response = eval(
+        &http,
+        json!({
+            "object": "MtaStageRcpt",
+            "property": "allowRelaying",
+            "expression": {
+                "match": [
+                    {"if": "remote_ip == '10.0.0.1'", "then": "true"},
+                    {"if": "!is_empty(authenticated_as)", "then": "true"}
+                ],
+                "else": "false"
+            },
+            "envelope": {
+                "ip": "10.0.0.1",
+                "helo": "mx.remote.net",
+                "sender": "john@remote.net",
+                "rcpt": ["jane@remote.net"]
+            }
+        }),
+    )
+    .await;
+    assert_eq!(response.result, json!(1), "{response:?}");
+    assert_eq!(response.matched, Some(0), "{response:?}");
+    assert_eq!(response.trace[0].result, Some(true));
+    assert_eq!(response.trace[1].result, None);
+
+    let response = eval(
+        &http,
+        json!({
+            "object": "MtaStageRcpt",
+            "property": "allowRelaying",
+            "expression": {
+                "match": [
+                    {"if": "remote_ip == '10.0.0.1'", "then": "true"},
+                    {"if": "!is_empty(authenticated_as)", "then": "true"}
+                ],
+                "else": "false"
+            },
+            "envelope": {
+                "ip": "192.168.1.1",
+                "auth": "jane@example.org",
+                "rcpt": ["john@remote.net"]
+            }
+        }),
+    )
+    .await;
+    assert_eq!(response.matched, Some(1), "{response:?}");
+    assert_eq!(response.trace[0].result, Some(false));
+    assert_eq!(response.trace[1].result, Some(true));
+
+    // Variables outside the expression context are rejected
+    let response = http
+        .post::<serde_json::Value>(
+            "/api/eval",
+            &json!({
+                "object": "MtaOutboundStrategy",
+                "property": "route",
+                "expression": {
+                    "match": [{"if": "helo_domain == 'foo'", "then": "'local'"}],
+                    "else": "'mx'"
+                }
+            }),
+        )
+        .await
+        .unwrap();
+    assert!(response.get("result").is_none(), "{response}");
+
+    // Non-expression properties are rejected
+    let response = http
+        .post::<serde_json::Value>(
+            "/api/eval",
+            &json!({
+                "object": "MtaOutboundStrategy",
+                "property": "description"
+            }),
+        )
+        .await
+        .unwrap();
+    assert!(response.get("result").is_none(), "{response}");
+}
+
+async fn eval(http: &HttpRequest, request: serde_json::Value) -> EvalResponse {
+    http.post::<EvalResponse>("/api/eval", &request)
+        .await
+        .unwrap()
+}
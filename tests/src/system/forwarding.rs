@@ -0,0 +1,107 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use hyper::Method;
+use serde::Deserialize;
+use serde_json::json;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ForwardingSettings {
+    forward_to: Vec<String>,
+    keep_copy: bool,
+    rules: Vec<ForwardingRule>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ForwardingRule {
+    field: String,
+    operator: String,
+    value: String,
+    forward_to: Vec<String>,
+}
+
+pub async fn test(_test: &mut TestServer) {
+    println!("Running forwarding settings tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+
+    // No forwarding is configured by default
+    let settings = http
+        .get::<ForwardingSettings>("/api/account/forwarding")
+        .await
+        .unwrap();
+    assert!(settings.forward_to.is_empty() && settings.rules.is_empty());
+
+    // Addresses are normalized and duplicates removed
+    let settings = http
+        .post::<ForwardingSettings>(
+            "/api/account/forwarding",
+            &json!({
+                "forwardTo": ["John@Remote.org", "john@remote.org"],
+                "keepCopy": true,
+                "rules": [{
+                    "field": "listId",
+                    "value": " dev.lists.example.org ",
+                    "forwardTo": ["lists@remote.org"]
+                }]
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(settings.forward_to, vec!["john@remote.org".to_string()]);
+    assert!(settings.keep_copy);
+    assert_eq!(settings.rules.len(), 1);
+    assert_eq!(settings.rules[0].field, "listId");
+    assert_eq!(settings.rules[0].operator, "contains");
+    assert_eq!(settings.rules[0].value, "dev.lists.example.org");
+    assert_eq!(settings.rules[0].forward_to, vec!["lists@remote.org"]);
+
+    let stored = http
+        .get::<ForwardingSettings>("/api/account/forwarding")
+        .await
+        .unwrap();
+    assert_eq!(stored.forward_to, settings.forward_to);
+    assert_eq!(stored.rules.len(), 1);
+
+    // Invalid settings are rejected
+    let too_many_rules = (0..33)
+        .map(|i| json!({ "field": "from", "value": format!("user{i}"), "forwardTo": ["a@remote.org"] }))
+        .collect::<Vec<_>>();
+    for request in [
+        json!({ "forwardTo": ["not an address"] }),
+        json!({ "forwardTo": ["admin@example.org"] }),
+        json!({ "rules": [{ "field": "subject", "value": "", "forwardTo": ["a@remote.org"] }] }),
+        json!({ "rules": [{ "field": "subject", "value": "report", "forwardTo": [] }] }),
+        json!({ "rules": [{ "field": "body", "value": "report", "forwardTo": ["a@remote.org"] }] }),
+        json!({ "rules": too_many_rules }),
+    ] {
+        let response = http
+            .send_full(
+                Method::POST,
+                "/api/account/forwarding",
+                Some(request.to_string().into_bytes()),
+                Some("application/json"),
+            )
+            .await;
+        assert!(
+            !response.status.is_success(),
+            "{request}: {}",
+            response.body
+        );
+    }
+
+    // Disable forwarding
+    http.delete::<serde_json::Value>("/api/account/forwarding")
+        .await
+        .unwrap();
+    let settings = http
+        .get::<ForwardingSettings>("/api/account/forwarding")
+        .await
+        .unwrap();
+    assert!(settings.forward_to.is_empty() && settings.rules.is_empty());
+}
@@ -0,0 +1,231 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::server::TestServer;
+use registry::{
+    schema::{
+        enums::{NetworkListenerProtocol, TlsProfile},
+        prelude::{ObjectType, Property, SocketAddr},
+        structs::NetworkListener,
+    },
+    types::map::Map,
+};
+use rustls::{
+    ClientConfig, NamedGroup, ProtocolVersion, SupportedProtocolVersion,
+    crypto::{
+        CryptoProvider, SupportedKxGroup,
+        aws_lc_rs::{default_provider, kx_group},
+    },
+    pki_types::ServerName,
+    version::{TLS12, TLS13},
+};
+use serde_json::json;
+use std::{str::FromStr, sync::Arc, time::Duration};
+use tokio::{io::AsyncReadExt, net::TcpStream};
+use tokio_rustls::TlsConnector;
+use utils::tls::DummyVerifier;
+
+pub async fn test(test: &TestServer) {
+    println!("Running listener reload tests...");
+    let admin = test.account("admin");
+    assert_eq!(greeting(19065).await, None);
+
+    // New listeners are bound on reload
+    let listener_id = admin
+        .registry_create_object(NetworkListener {
+            bind: Map::new(vec![SocketAddr::from_str("127.0.0.1:19065").unwrap()]),
+            name: "imap-reload".to_string(),
+            protocol: NetworkListenerProtocol::Imap,
+            socket_reuse_address: true,
+            use_tls: false,
+            tls_implicit: false,
+            ..Default::default()
+        })
+        .await;
+    admin.reload_settings().await;
+    assert!(
+        greeting(19065).await.is_some_and(|g| g.starts_with("* OK")),
+        "IMAP listener was not started"
+    );
+
+    // Changing the protocol rebinds the listener on the same address
+    admin
+        .registry_update_object(
+            ObjectType::NetworkListener,
+            listener_id,
+            json!({
+                Property::Protocol: "pop3"
+            }),
+        )
+        .await;
+    admin.reload_settings().await;
+    assert!(
+        greeting(19065).await.is_some_and(|g| g.starts_with("+OK")),
+        "Listener was not rebound as POP3"
+    );
+
+    // Moving a listener closes the old address
+    admin
+        .registry_update_object(
+            ObjectType::NetworkListener,
+            listener_id,
+            json!({
+                Property::Bind: {
+                    "127.0.0.1:19066": true
+                }
+            }),
+        )
+        .await;
+    admin.reload_settings().await;
+    assert_eq!(greeting(19065).await, None);
+    assert!(
+        greeting(19066).await.is_some_and(|g| g.starts_with("+OK")),
+        "Listener was not moved"
+    );
+
+    // Removed listeners stop accepting connections
+    admin
+        .registry_destroy(ObjectType::NetworkListener, [listener_id])
+        .await
+        .assert_destroyed(&[listener_id]);
+    admin.reload_settings().await;
+    assert_eq!(greeting(19066).await, None);
+
+    // TLS profiles restrict protocol versions and key exchange groups
+    let hybrid = [kx_group::X25519MLKEM768, kx_group::X25519];
+    let listener_id = admin
+        .registry_create_object(NetworkListener {
+            bind: Map::new(vec![SocketAddr::from_str("127.0.0.1:19067").unwrap()]),
+            name: "imaps-profile".to_string(),
+            protocol: NetworkListenerProtocol::Imap,
+            socket_reuse_address: true,
+            use_tls: true,
+            tls_implicit: true,
+            tls_profile: TlsProfile::Modern,
+            ..Default::default()
+        })
+        .await;
+    admin.reload_settings().await;
+    assert_eq!(
+        tls_handshake(19067, &TLS13, &hybrid).await,
+        Some((ProtocolVersion::TLSv1_3, NamedGroup::X25519MLKEM768))
+    );
+    assert_eq!(
+        tls_handshake(19067, &TLS13, &[kx_group::X25519]).await,
+        Some((ProtocolVersion::TLSv1_3, NamedGroup::X25519))
+    );
+    assert_eq!(tls_handshake(19067, &TLS12, &hybrid).await, None);
+
+    // Intermediate profiles allow TLS 1.2 and offer the hybrid group last
+    admin
+        .registry_update_object(
+            ObjectType::NetworkListener,
+            listener_id,
+            json!({
+                Property::TlsProfile: "intermediate"
+            }),
+        )
+        .await;
+    admin.reload_settings().await;
+    assert_eq!(
+        tls_handshake(19067, &TLS12, &hybrid).await,
+        Some((ProtocolVersion::TLSv1_2, NamedGroup::X25519))
+    );
+    assert_eq!(
+        tls_handshake(19067, &TLS13, &hybrid).await,
+        Some((ProtocolVersion::TLSv1_3, NamedGroup::X25519))
+    );
+
+    // The hybrid group can be preferred without changing the profile
+    admin
+        .registry_update_object(
+            ObjectType::NetworkListener,
+            listener_id,
+            json!({
+                Property::TlsPreferPostQuantum: true
+            }),
+        )
+        .await;
+    admin.reload_settings().await;
+    assert_eq!(
+        tls_handshake(19067, &TLS13, &hybrid).await,
+        Some((ProtocolVersion::TLSv1_3, NamedGroup::X25519MLKEM768))
+    );
+
+    // Disabled key exchange groups are never negotiated
+    admin
+        .registry_update_object(
+            ObjectType::NetworkListener,
+            listener_id,
+            json!({
+                Property::TlsProfile: "custom",
+                Property::TlsDisableKeyExchangeGroups: {
+                    "x25519-mlkem768": true
+                }
+            }),
+        )
+        .await;
+    admin.reload_settings().await;
+    assert_eq!(
+        tls_handshake(19067, &TLS13, &hybrid).await,
+        Some((ProtocolVersion::TLSv1_3, NamedGroup::X25519))
+    );
+    assert_eq!(
+        tls_handshake(19067, &TLS13, &[kx_group::X25519MLKEM768]).await,
+        None
+    );
+    admin
+        .registry_destroy(ObjectType::NetworkListener, [listener_id])
+        .await
+        .assert_destroyed(&[listener_id]);
+    admin.reload_settings().await;
+
+    // Listeners created at startup are left untouched
+    assert!(
+        greeting(9991).await.is_some(),
+        "Existing IMAP listener was stopped"
+    );
+}
+
+async fn greeting(port: u16) -> Option<String> {
+    let mut stream = TcpStream::connect(format!("127.0.0.1:{port}")).await.ok()?;
+    let mut buf = vec![0u8; 1024];
+    let size = tokio::time::timeout(Duration::from_secs(5), stream.read(&mut buf))
+        .await
+        .ok()?
+        .ok()?;
+    Some(String::from_utf8_lossy(&buf[..size]).into_owned())
+}
+
+async fn tls_handshake(
+    port: u16,
+    version: &'static SupportedProtocolVersion,
+    kx_groups: &[&'static dyn SupportedKxGroup],
+) -> Option<(ProtocolVersion, NamedGroup)> {
+    let config = ClientConfig::builder_with_provider(Arc::new(CryptoProvider {
+        kx_groups: kx_groups.to_vec(),
+        ..default_provider()
+    }))
+    .with_protocol_versions(&[version])
+    .unwrap()
+    .dangerous()
+    .with_custom_certificate_verifier(Arc::new(DummyVerifier))
+    .with_no_client_auth();
+    let stream = TcpStream::connect(format!("127.0.0.1:{port}")).await.ok()?;
+    let stream = tokio::time::timeout(
+        Duration::from_secs(5),
+        TlsConnector::from(Arc::new(config))
+            .connect(ServerName::try_from("localhost").unwrap(), stream),
+    )
+    .await
+    .ok()?
+    .ok()?;
+    let (_, conn) = stream.get_ref();
+    Some((
+        conn.protocol_version()?,
+        conn.negotiated_key_exchange_group()?.name(),
+    ))
+}
@@ -0,0 +1,153 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use hyper::Method;
+use jmap_client::{email, mailbox};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum ImportStage {
+    Completed {
+        total: usize,
+        imported: usize,
+        skipped: usize,
+        mailboxes: usize,
+    },
+    #[serde(other)]
+    Other,
+}
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running mail store import tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let admin = test.account("admin@example.org");
+    let account = test
+        .create_user_account(
+            "admin@example.org",
+            "mbox@example.org",
+            "this is a very strong password",
+            &[],
+            "mbox@example.org",
+        )
+        .await;
+
+    // Import an mbox file into a nested mailbox of another account
+    let mbox = concat!(
+        "From bill@example.org Thu Nov 24 18:22:48 2022\n",
+        "From: bill@example.org\n",
+        "To: mbox@example.org\n",
+        "Subject: TPS Report #1\n",
+        "Status: RO\n",
+        "X-Status: F\n",
+        "\n",
+        "Did you get the memo about the new cover sheets?\n",
+        ">From now on, use them.\n",
+        "\n",
+        "From bill@example.org Mon Jan  2 03:04:05 2023\n",
+        "From: bill@example.org\n",
+        "To: mbox@example.org\n",
+        "Subject: TPS Report #2\n",
+        "X-Keywords: $label1\n",
+        "\n",
+        "Yeah, I'm going to need you to come in on Saturday.\n",
+    );
+    let response = http
+        .send_full(
+            Method::POST,
+            &format!(
+                "/api/import?accountId={}&format=mbox&mailbox=Archive/2022",
+                account.id_string()
+            ),
+            Some(mbox.as_bytes().to_vec()),
+            Some("application/mbox"),
+        )
+        .await;
+    assert!(response.status.is_success(), "{}", response.body);
+    let stages = response
+        .body
+        .lines()
+        .filter_map(|line| line.strip_prefix("data: "))
+        .flat_map(|data| serde_json::from_str::<Vec<ImportStage>>(data).unwrap())
+        .collect::<Vec<_>>();
+    match stages.last() {
+        Some(ImportStage::Completed {
+            total,
+            imported,
+            skipped,
+            mailboxes,
+        }) => {
+            assert_eq!(*total, 2, "{stages:?}");
+            assert_eq!(*imported, 2, "{stages:?}");
+            assert_eq!(*skipped, 0, "{stages:?}");
+            assert_eq!(*mailboxes, 1, "{stages:?}");
+        }
+        _ => panic!("Unexpected import result: {stages:?}"),
+    }
+
+    // Make sure the folder structure, flags and received dates were preserved
+    let client = account.jmap_client().await;
+    let mailbox_id = client
+        .mailbox_query(mailbox::query::Filter::name("2022").into(), None::<Vec<_>>)
+        .await
+        .unwrap()
+        .take_ids()
+        .pop()
+        .expect("Mailbox 2022 was not created");
+    let email_ids = client
+        .email_query(
+            email::query::Filter::in_mailbox(&mailbox_id).into(),
+            vec![email::query::Comparator::received_at()].into(),
+        )
+        .await
+        .unwrap()
+        .take_ids();
+    assert_eq!(email_ids.len(), 2, "{email_ids:?}");
+    for (email_id, received_at, keywords) in [
+        (&email_ids[0], 1669314168, vec!["$flagged", "$seen"]),
+        (&email_ids[1], 1672628645, vec!["$label1"]),
+    ] {
+        let email = client
+            .email_get(
+                email_id,
+                [email::Property::ReceivedAt, email::Property::Keywords].into(),
+            )
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(email.received_at(), Some(received_at));
+        let mut email_keywords = email.keywords();
+        email_keywords.sort_unstable();
+        assert_eq!(email_keywords, keywords);
+    }
+
+    // Regular users can't import into other accounts
+    let response =
+        HttpRequest::with_credentials(8899, "mbox@example.org", "this is a very strong password")
+            .send_full(
+                Method::POST,
+                "/api/import?accountId=a&format=mbox",
+                Some(mbox.as_bytes().to_vec()),
+                None,
+            )
+            .await;
+    assert!(!response.status.is_success(), "{}", response.body);
+
+    // Unsupported formats are rejected
+    let response = http
+        .send_full(
+            Method::POST,
+            "/api/import?format=pst",
+            Some(mbox.as_bytes().to_vec()),
+            None,
+        )
+        .await;
+    assert!(!response.status.is_success(), "{}", response.body);
+
+    test.destroy_all_mailboxes(&account).await;
+    admin.destroy_account(account).await;
+}
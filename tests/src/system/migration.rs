@@ -0,0 +1,162 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{server::TestServer, smtp::SmtpConnection};
+use email::cache::MessageCacheFetch;
+use registry::schema::{
+    prelude::{ObjectType, Property},
+    structs::{Task, TaskAccountMigration, TaskManager, TaskStatus},
+};
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running account migration tests...");
+    let admin = test.account("admin@example.org");
+    let migration_dir = test.temp_dir.path.join("migrations");
+    std::fs::create_dir_all(&migration_dir).unwrap();
+    let source = test
+        .create_user_account(
+            "admin@example.org",
+            "export@example.org",
+            "this is a very strong password",
+            &[],
+            "export@example.org",
+        )
+        .await;
+    let target = test
+        .create_user_account(
+            "admin@example.org",
+            "import@example.org",
+            "this is a very strong password",
+            &[],
+            "import@example.org",
+        )
+        .await;
+
+    // Migrations are rejected until a migration directory is configured
+    admin
+        .registry_create_object(Task::AccountExport(TaskAccountMigration {
+            account_id: source.id(),
+            path: "export.archive".to_string(),
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks_skip_failures().await;
+    assert_failed_migration(test).await;
+    assert!(!migration_dir.join("export.archive").exists());
+
+    admin
+        .registry_update_setting(
+            TaskManager {
+                migration_directory: Some(migration_dir.to_string_lossy().into_owned()),
+                ..Default::default()
+            },
+            &[Property::MigrationDirectory],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Paths outside the migration directory are rejected
+    for path in [
+        "../export.archive".to_string(),
+        "nested/../../export.archive".to_string(),
+        test.temp_dir
+            .path
+            .join("export.archive")
+            .to_string_lossy()
+            .into_owned(),
+    ] {
+        admin
+            .registry_create_object(Task::AccountExport(TaskAccountMigration {
+                account_id: source.id(),
+                path,
+                status: TaskStatus::now(),
+            }))
+            .await;
+        test.wait_for_tasks_skip_failures().await;
+        assert_failed_migration(test).await;
+    }
+    assert!(!test.temp_dir.path.join("export.archive").exists());
+
+    // Deliver test messages to the source account
+    let mut lmtp = SmtpConnection::connect().await;
+    for i in 0..3 {
+        lmtp.ingest(
+            "bill@example.org",
+            &["export@example.org"],
+            &format!(
+                concat!(
+                    "From: bill@example.org\r\n",
+                    "To: export@example.org\r\n",
+                    "Message-ID: <migration-{i}@example.org>\r\n",
+                    "Subject: Migration test {i}\r\n",
+                    "\r\n",
+                    "Did you get the memo about the new cover sheets?\r\n",
+                ),
+                i = i
+            ),
+        )
+        .await;
+    }
+    test.wait_for_tasks().await;
+
+    // Export the source account
+    admin
+        .registry_create_object(Task::AccountExport(TaskAccountMigration {
+            account_id: source.id(),
+            path: "export.archive".to_string(),
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    assert!(migration_dir.join("export.archive").exists());
+
+    // Import the archive twice, the second run must not duplicate messages
+    for _ in 0..2 {
+        admin
+            .registry_create_object(Task::AccountImport(TaskAccountMigration {
+                account_id: target.id(),
+                path: "export.archive".to_string(),
+                status: TaskStatus::now(),
+            }))
+            .await;
+        test.wait_for_tasks().await;
+        let cache = test
+            .server
+            .get_cached_messages(target.id().document_id())
+            .await
+            .unwrap();
+        assert_eq!(cache.emails.items.len(), 3);
+    }
+
+    // Reset settings and remove test data
+    admin
+        .registry_update_setting(TaskManager::default(), &[Property::MigrationDirectory])
+        .await;
+    admin.reload_settings().await;
+    std::fs::remove_dir_all(&migration_dir).unwrap();
+    test.destroy_all_mailboxes(&source).await;
+    test.destroy_all_mailboxes(&target).await;
+    admin.destroy_account(source).await;
+    admin.destroy_account(target).await;
+}
+
+async fn assert_failed_migration(test: &TestServer) {
+    let admin = test.account("admin@example.org");
+    let tasks = admin.tasks().await;
+    assert_eq!(tasks.len(), 1, "{tasks:?}");
+    let task = tasks.into_iter().next().unwrap();
+    match &task.task {
+        Task::AccountExport(TaskAccountMigration {
+            status: TaskStatus::Failed(_),
+            ..
+        }) => {}
+        _ => panic!("Expected a failed migration task, found {:?}", task.task),
+    }
+    admin
+        .registry_destroy(ObjectType::Task, [task.id])
+        .await
+        .assert_destroyed(&[task.id]);
+}
@@ -4,19 +4,33 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
+pub mod account_data;
+pub mod audit_log;
 pub mod antispam;
 pub mod archiving;
 pub mod authentication;
 pub mod authorization;
 pub mod crypto;
+pub mod data_store;
 pub mod delivery;
 pub mod directory;
+pub mod dry_run;
+pub mod eval;
+pub mod forwarding;
+pub mod listeners;
+pub mod mail_import;
+pub mod migration;
 pub mod oidc;
+pub mod openapi;
 pub mod purge;
+pub mod quarantine;
 pub mod quota;
+pub mod reindex;
 pub mod security;
+pub mod sessions;
 pub mod task;
 pub mod tenant;
+pub mod tracing;
 
 use crate::utils::server::TestServerBuilder;
 use registry::schema::structs::{Expression, Imap, MtaStageAuth};
@@ -60,17 +74,31 @@ pub async fn system_tests() {
 
     directory::test(&test).await;
     authentication::test(&test).await;
+    openapi::test(&test).await;
     oidc::test(&mut test).await;
     authorization::test(&mut test).await;
     tenant::test(&mut test).await;
     security::test(&mut test).await;
     quota::test(&mut test).await;
     purge::test(&mut test).await;
+    audit_log::test(&test).await;
     delivery::test(&mut test).await;
+    quarantine::test(&mut test).await;
     crypto::test(&mut test).await;
     antispam::test(&mut test).await;
     archiving::test(&mut test).await;
     task::test(&mut test).await;
+    eval::test(&mut test).await;
+    dry_run::test(&mut test).await;
+    reindex::test(&mut test).await;
+    data_store::test(&mut test).await;
+    mail_import::test(&mut test).await;
+    migration::test(&mut test).await;
+    sessions::test(&mut test).await;
+    account_data::test(&mut test).await;
+    tracing::test(&mut test).await;
+    forwarding::test(&mut test).await;
+    listeners::test(&test).await;
 
     if test.is_reset() {
         test.temp_dir.delete();
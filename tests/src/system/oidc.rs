@@ -327,6 +327,14 @@ pub async fn test(test: &mut TestServer) {
         }
     );
 
+    // Polling faster than the advertised interval should be rejected
+    assert_eq!(
+        post::<TokenResponse>(&metadata.token_endpoint, &token_params).await,
+        TokenResponse::Error {
+            error: ErrorType::SlowDown
+        }
+    );
+
     // Let the code expire and make sure it's invalidated
     tokio::time::sleep(Duration::from_secs(1)).await;
     assert_eq!(
@@ -461,6 +469,103 @@ pub async fn test(test: &mut TestServer) {
         }
     );
 
+    // ------------------------
+    // Client policies
+    // ------------------------
+
+    // Register a client restricted to the device flow and a single scope
+    let registration: ClientRegistrationResponse = post_json(
+        &metadata.registration_endpoint,
+        None,
+        &ClientRegistrationRequest {
+            redirect_uris: vec!["https://localhost".to_string()],
+            grant_types: vec!["urn:ietf:params:oauth:grant-type:device_code".to_string()],
+            scope: Some("urn:ietf:params:jmap:mail".to_string()),
+            ..Default::default()
+        },
+    )
+    .await;
+    let restricted_client_id = registration.client_id;
+
+    // Scopes outside the registered ones should be rejected
+    assert_eq!(
+        post::<TokenResponse>(
+            &metadata.device_authorization_endpoint,
+            &AHashMap::from_iter([
+                ("client_id".to_string(), restricted_client_id.to_string()),
+                ("scope".to_string(), "openid".to_string()),
+            ]),
+        )
+        .await,
+        TokenResponse::Error {
+            error: ErrorType::InvalidScope
+        }
+    );
+
+    // Clients registered for other grant types cannot use the device flow
+    let registration: ClientRegistrationResponse = post_json(
+        &metadata.registration_endpoint,
+        None,
+        &ClientRegistrationRequest {
+            redirect_uris: vec!["https://localhost".to_string()],
+            grant_types: vec!["authorization_code".to_string()],
+            ..Default::default()
+        },
+    )
+    .await;
+    assert_eq!(
+        post::<TokenResponse>(
+            &metadata.device_authorization_endpoint,
+            &AHashMap::from_iter([("client_id".to_string(), registration.client_id)]),
+        )
+        .await,
+        TokenResponse::Error {
+            error: ErrorType::UnauthorizedClient
+        }
+    );
+
+    // Tokens issued to clients without the refresh token grant do not include one
+    let device_response: DeviceAuthResponse = post(
+        &metadata.device_authorization_endpoint,
+        &AHashMap::from_iter([
+            ("client_id".to_string(), restricted_client_id.to_string()),
+            ("scope".to_string(), "urn:ietf:params:jmap:mail".to_string()),
+        ]),
+    )
+    .await;
+    assert_eq!(
+        http.post::<LoginResponse>(
+            "/api/auth",
+            &LoginRequest::AuthDevice {
+                account_name: "user@example.org".to_string(),
+                account_secret: "this is a very strong password".to_string(),
+                mfa_token: None,
+                code: device_response.user_code.clone(),
+            },
+        )
+        .await
+        .unwrap(),
+        LoginResponse::Verified
+    );
+    let (_, refresh_token, _) = unwrap_token_response(
+        post(
+            &metadata.token_endpoint,
+            &AHashMap::from_iter([
+                ("client_id".to_string(), restricted_client_id.to_string()),
+                (
+                    "grant_type".to_string(),
+                    "urn:ietf:params:oauth:grant-type:device_code".to_string(),
+                ),
+                (
+                    "device_code".to_string(),
+                    device_response.device_code.to_string(),
+                ),
+            ]),
+        )
+        .await,
+    );
+    assert_eq!(refresh_token, None);
+
     // Clean up
     admin.registry_destroy_all(ObjectType::OAuthClient).await;
     admin.destroy_account(user).await;
@@ -0,0 +1,93 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::server::TestServer;
+use serde_json::Value;
+
+pub async fn test(_test: &TestServer) {
+    println!("Running OpenAPI document tests...");
+
+    // The document is served to anonymous clients from any origin
+    let response = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .build()
+        .unwrap()
+        .get("https://127.0.0.1:8899/.well-known/openapi.json")
+        .send()
+        .await
+        .unwrap();
+    assert!(response.status().is_success(), "{}", response.status());
+    assert_eq!(
+        response
+            .headers()
+            .get("access-control-allow-origin")
+            .and_then(|value| value.to_str().ok()),
+        Some("*")
+    );
+    let document = serde_json::from_slice::<Value>(&response.bytes().await.unwrap()).unwrap();
+    assert_eq!(document["openapi"], "3.1.0");
+    assert!(
+        document["servers"][0]["url"]
+            .as_str()
+            .is_some_and(|url| url.starts_with("https://")),
+        "{}",
+        document["servers"]
+    );
+
+    // Operations document their required permissions
+    let forwarding = &document["paths"]["/api/account/forwarding"];
+    for method in ["get", "post", "delete"] {
+        assert!(forwarding[method].is_object(), "{forwarding}");
+    }
+    assert_eq!(forwarding["get"]["x-permission"], "sieveForwardingGet");
+    assert_eq!(forwarding["post"]["x-permission"], "sieveForwardingUpdate");
+    assert!(
+        forwarding["post"]["requestBody"]["content"]["application/json"]["schema"].is_object(),
+        "{forwarding}"
+    );
+
+    // Anonymous operations override the global security requirements
+    assert_eq!(
+        document["paths"]["/api/auth"]["post"]["security"],
+        serde_json::json!([])
+    );
+    assert!(
+        document["paths"]["/api/account"]["get"]
+            .get("security")
+            .is_none()
+    );
+
+    // Every path parameter is declared and every failure refers to the problem response
+    let paths = document["paths"].as_object().unwrap();
+    assert!(!paths.is_empty());
+    for (path, operations) in paths {
+        for (method, operation) in operations.as_object().unwrap() {
+            let params = operation["parameters"]
+                .as_array()
+                .map(|params| {
+                    params
+                        .iter()
+                        .filter(|param| param["in"] == "path")
+                        .map(|param| param["name"].as_str().unwrap())
+                        .collect::<Vec<_>>()
+                })
+                .unwrap_or_default();
+            let expected = path
+                .split('/')
+                .filter_map(|part| part.strip_prefix('{')?.strip_suffix('}'))
+                .collect::<Vec<_>>();
+            assert_eq!(params, expected, "{method} {path}");
+            assert_eq!(
+                operation["responses"]["default"]["$ref"], "#/components/responses/Problem",
+                "{method} {path}"
+            );
+            assert!(
+                operation["responses"]["200"]["content"].is_object(),
+                "{method} {path}"
+            );
+        }
+    }
+}
@@ -5,26 +5,29 @@
  */
 
 use crate::utils::{
+    account::Account,
     imap::{AssertResult, ImapConnection, Type},
     server::TestServer,
 };
 use ahash::AHashSet;
 use common::Server;
 use email::{
-    cache::{MessageCacheFetch, email::MessageCacheAccess},
-    mailbox::{INBOX_ID, JUNK_ID, TRASH_ID},
+    cache::{MessageCacheFetch, email::MessageCacheAccess, mailbox::MailboxCacheAccess},
+    mailbox::{DRAFTS_ID, INBOX_ID, JUNK_ID, TRASH_ID},
 };
 use imap_proto::ResponseType;
+use jmap_client::client::Client;
 use registry::schema::{
     enums::{TaskAccountMaintenanceType, TaskStoreMaintenanceType},
-    prelude::Property,
+    prelude::{ObjectType, Property},
     structs::{
-        DataRetention, SpamClassifier, Task, TaskAccountMaintenance, TaskStatus,
+        DataRetention, Email, SpamClassifier, Task, TaskAccountMaintenance, TaskStatus,
         TaskStoreMaintenance,
     },
 };
+use serde_json::json;
 use store::{IterateParams, LogKey, U32_LEN, U64_LEN, write::key::DeserializeBigEndian};
-use types::id::Id;
+use types::{id::Id, special_use::SpecialUse};
 
 pub async fn test(test: &mut TestServer) {
     println!("Running Account purge tests...");
@@ -170,6 +173,11 @@ pub async fn test(test: &mut TestServer) {
     assert_eq!(cache.in_mailbox(TRASH_ID).count(), 1);
     assert_eq!(cache.in_mailbox(JUNK_ID).count(), 1);
 
+    // Blob sizes are recorded on commit
+    let usage = test.server.store().blob_usage().await.unwrap();
+    assert!(usage.blobs > 0);
+    assert_eq!(usage.unsized_blobs, 0);
+
     // Check IMAP status
     imap.send("LIST \"\" \"*\" RETURN (STATUS (MESSAGES))")
         .await;
@@ -193,6 +201,134 @@ pub async fn test(test: &mut TestServer) {
         );
     }
 
+    // Inbox messages older than the configured period are filed by year
+    client
+        .email_import(
+            concat!(
+                "From: bill@example.org\r\n",
+                "To: jdoe@example.org\r\n",
+                "Subject: TPS Report 2020\r\n",
+                "\r\n",
+                "Did you get the memo?"
+            )
+            .as_bytes()
+            .to_vec(),
+            [&inbox_id],
+            None::<Vec<&str>>,
+            Some(1600000000),
+        )
+        .await
+        .unwrap();
+    account
+        .registry_update_object(
+            ObjectType::AccountSettings,
+            Id::singleton(),
+            json!({
+                Property::AutoArchiveAfter: 30 * 86400 * 1000u64
+            }),
+        )
+        .await;
+    admin
+        .registry_create_object(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: account.id(),
+            maintenance_type: TaskAccountMaintenanceType::Purge,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    let cache = test
+        .server
+        .get_cached_messages(account.id().document_id())
+        .await
+        .unwrap();
+    let archive_id = cache
+        .mailbox_by_role(&SpecialUse::Archive)
+        .unwrap()
+        .document_id;
+    let year = cache
+        .mailboxes
+        .items
+        .iter()
+        .find(|mailbox| mailbox.parent_id == archive_id && mailbox.name == "2020")
+        .expect("Archive/2020 mailbox not created");
+    assert_eq!(cache.in_mailbox(INBOX_ID).count(), 2);
+    assert_eq!(cache.in_mailbox(year.document_id).count(), 1);
+
+    // Only drafts superseded by a newer revision with the same Message-ID are removed
+    let drafts_id = Id::from(DRAFTS_ID).to_string();
+    let mut draft_ids = Vec::new();
+    for (message_id, references) in [
+        ("<plan@example.org>", None),
+        ("<plan-reply@example.org>", Some("<plan@example.org>")),
+        ("<plan@example.org>", None),
+    ] {
+        draft_ids.push(
+            client
+                .email_import(
+                    format!(
+                        concat!(
+                            "From: jdoe@example.org\r\n",
+                            "To: bill@example.org\r\n",
+                            "Message-ID: {}\r\n",
+                            "{}",
+                            "Subject: Quarterly plan\r\n",
+                            "\r\n",
+                            "Draft revision {}."
+                        ),
+                        message_id,
+                        references
+                            .map(|id| format!("References: {id}\r\n"))
+                            .unwrap_or_default(),
+                        draft_ids.len()
+                    )
+                    .into_bytes(),
+                    [&drafts_id],
+                    Some(["$draft"]),
+                    None,
+                )
+                .await
+                .unwrap()
+                .take_id(),
+        );
+    }
+    tokio::time::sleep(std::time::Duration::from_secs(1)).await;
+    admin
+        .registry_update_setting(
+            DataRetention {
+                expunge_drafts_after: Some(1000u64.into()),
+                ..Default::default()
+            },
+            &[Property::ExpungeDraftsAfter],
+        )
+        .await;
+    admin.reload_settings().await;
+    purge_account(test, &account).await;
+    assert!(is_draft_present(&client, &draft_ids[2]).await);
+    assert!(is_draft_present(&client, &draft_ids[1]).await);
+    assert!(!is_draft_present(&client, &draft_ids[0]).await);
+
+    // The oldest drafts are removed once the cap is exceeded
+    admin
+        .registry_update_setting(
+            Email {
+                max_drafts: Some(1),
+                ..Default::default()
+            },
+            &[Property::MaxDrafts],
+        )
+        .await;
+    admin.reload_settings().await;
+    purge_account(test, &account).await;
+    assert!(is_draft_present(&client, &draft_ids[2]).await);
+    assert!(!is_draft_present(&client, &draft_ids[1]).await);
+    admin
+        .registry_update_setting(Email::default(), &[Property::MaxDrafts])
+        .await;
+    admin
+        .registry_update_setting(DataRetention::default(), &[Property::ExpungeDraftsAfter])
+        .await;
+    admin.reload_settings().await;
+
     // Delete expired training samples
     admin
         .registry_create_object(Task::StoreMaintenance(TaskStoreMaintenance {
@@ -214,6 +350,25 @@ pub async fn test(test: &mut TestServer) {
     test.cleanup().await;
 }
 
+async fn purge_account(test: &TestServer, account: &Account) {
+    test.account("admin@example.org")
+        .registry_create_object(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: account.id(),
+            maintenance_type: TaskAccountMaintenanceType::Purge,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+}
+
+async fn is_draft_present(client: &Client, id: &str) -> bool {
+    client
+        .email_get(id, None::<Vec<_>>)
+        .await
+        .unwrap()
+        .is_some()
+}
+
 async fn get_changes(server: &Server) -> (AHashSet<(u64, u8)>, bool) {
     let mut changes = AHashSet::new();
     let mut is_truncated = false;
@@ -0,0 +1,258 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::{
+    system::delivery::assert_message_headers_contains,
+    utils::{account::Account, http::HttpRequest, server::TestServer, smtp::SmtpConnection},
+};
+use email::{
+    cache::{MessageCacheFetch, email::MessageCacheAccess},
+    mailbox::INBOX_ID,
+    message::quarantine::{QuarantineAction, SpamQuarantine},
+};
+use hyper::Method;
+use registry::{
+    schema::{
+        enums::TaskStoreMaintenanceType,
+        prelude::{ObjectType, Property},
+        structs::{
+            QuarantinedMessage, SpamSettings, SpamTag, SpamTagScore, Task, TaskStatus,
+            TaskStoreMaintenance,
+        },
+    },
+    types::float::Float,
+};
+use std::time::Duration;
+use store::write::now;
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running spam quarantine tests...");
+    let admin = test.account("admin@example.org");
+
+    // Quarantine messages scoring above 50
+    admin
+        .registry_create_object(SpamTag::Score(SpamTagScore {
+            score: Float::new(1000.0),
+            tag: "GTUBE_TEST".to_string(),
+        }))
+        .await;
+    admin
+        .registry_update_setting(
+            SpamSettings {
+                score_quarantine: Float::new(50.0),
+                quarantine_for: Duration::from_secs(86400),
+                quarantine_digest_frequency: Some(Duration::from_secs(86400)),
+                ..Default::default()
+            },
+            &[
+                Property::ScoreQuarantine,
+                Property::QuarantineFor,
+                Property::QuarantineDigestFrequency,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+    let john = test
+        .create_user_account(
+            "admin@example.org",
+            "quarantine@example.org",
+            "this is a very strong password",
+            &[],
+            "quarantine@example.org",
+        )
+        .await;
+    let jane = test
+        .create_user_account(
+            "admin@example.org",
+            "quarantine-other@example.org",
+            "this is a very strong password",
+            &[],
+            "quarantine-other@example.org",
+        )
+        .await;
+
+    // Spam above the quarantine threshold is held instead of delivered
+    let mut lmtp = SmtpConnection::connect().await;
+    ingest_spam(&mut lmtp, 1).await;
+    assert_eq!(inbox_ids(test, &john).await, Vec::<u32>::new());
+    let quarantined = admin.registry_get_all::<QuarantinedMessage>().await;
+    assert_eq!(quarantined.len(), 1);
+    let (item_id, message) = quarantined.into_iter().next().unwrap();
+    assert_eq!(message.account_id, john.id());
+    assert_eq!(message.from, "spammer@example.com");
+    let item_id = item_id.id();
+    let expires_at = message.expires_at.timestamp() as u64;
+
+    // Following the link only shows a confirmation page
+    let http = HttpRequest::new();
+    let params = release_params(test, &john, item_id, expires_at).await;
+    let response = http
+        .send_full(Method::GET, &format!("/quarantine?{params}"), None, None)
+        .await;
+    assert!(response.status.is_success());
+    assert!(
+        response.body.contains("<form method=\"post\">")
+            && response.body.contains("spammer@example.com"),
+        "{}",
+        response.body
+    );
+    assert_eq!(inbox_ids(test, &john).await, Vec::<u32>::new());
+
+    // Tokens issued for another account are rejected
+    let wrong_params = release_params(test, &jane, item_id, expires_at).await;
+    assert_not_released(&http, &wrong_params).await;
+    assert_eq!(inbox_ids(test, &john).await, Vec::<u32>::new());
+    assert_eq!(inbox_ids(test, &jane).await, Vec::<u32>::new());
+
+    // Expired tokens are rejected
+    let expired_params = release_params(test, &john, item_id, now() + 1).await;
+    tokio::time::sleep(Duration::from_secs(2)).await;
+    assert_not_released(&http, &expired_params).await;
+    assert_eq!(inbox_ids(test, &john).await, Vec::<u32>::new());
+
+    // Submitting the confirmation form releases the message into the Inbox
+    let response = http
+        .send_full(
+            Method::POST,
+            "/quarantine",
+            Some(params.into_bytes()),
+            Some("application/x-www-form-urlencoded"),
+        )
+        .await;
+    assert!(
+        response
+            .body
+            .contains("The message has been delivered to your Inbox."),
+        "{}",
+        response.body
+    );
+    assert_eq!(inbox_ids(test, &john).await.len(), 1);
+    assert_eq!(
+        admin.registry_get_all::<QuarantinedMessage>().await,
+        Vec::<(_, QuarantinedMessage)>::new()
+    );
+
+    // Digests list the messages held since the previous digest
+    ingest_spam(&mut lmtp, 2).await;
+    assert_eq!(
+        admin.registry_get_all::<QuarantinedMessage>().await.len(),
+        1
+    );
+    admin
+        .registry_create_object(Task::StoreMaintenance(TaskStoreMaintenance {
+            maintenance_type: TaskStoreMaintenanceType::SendQuarantineDigests,
+            shard_index: None,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+    let inbox_ids = inbox_ids(test, &john).await;
+    assert_eq!(inbox_ids.len(), 2);
+    assert_message_headers_contains(
+        &test.server,
+        john.id().document_id(),
+        *inbox_ids.last().unwrap(),
+        "Subject: 1 messages held in quarantine",
+    )
+    .await;
+
+    // Reset settings and remove test data
+    admin
+        .registry_destroy_all(ObjectType::QuarantinedMessage)
+        .await;
+    admin.registry_destroy_all(ObjectType::SpamTag).await;
+    admin
+        .registry_update_setting(
+            SpamSettings::default(),
+            &[
+                Property::ScoreQuarantine,
+                Property::QuarantineFor,
+                Property::QuarantineDigestFrequency,
+            ],
+        )
+        .await;
+    admin.reload_settings().await;
+    test.destroy_all_mailboxes(&john).await;
+    test.destroy_all_mailboxes(&jane).await;
+    admin.destroy_account(john).await;
+    admin.destroy_account(jane).await;
+}
+
+async fn ingest_spam(lmtp: &mut SmtpConnection, num: usize) {
+    lmtp.ingest(
+        "spammer@example.com",
+        &["quarantine@example.org"],
+        &format!(
+            concat!(
+                "From: spammer@example.com\r\n",
+                "To: quarantine@example.org\r\n",
+                "Message-ID: <quarantine-{num}@example.com>\r\n",
+                "Subject: XJS*C4JDBQADN1.NSBN3*2IDNEN*GTUBE-STANDARD-ANTI-UBE-TEST-EMAIL*C.34X\r\n",
+                "\r\n",
+                "Yeah, I'm going to need you to go ahead and come in on Saturday.\r\n",
+            ),
+            num = num
+        ),
+    )
+    .await;
+}
+
+async fn release_params(
+    test: &TestServer,
+    account: &Account,
+    item_id: u64,
+    expires_at: u64,
+) -> String {
+    let url = test
+        .server
+        .quarantine_release_url(
+            account.id().document_id(),
+            item_id,
+            expires_at,
+            QuarantineAction::Release,
+        )
+        .await
+        .unwrap();
+    url.split_once('?').unwrap().1.to_string()
+}
+
+async fn assert_not_released(http: &HttpRequest, params: &str) {
+    for method in [Method::GET, Method::POST] {
+        let (query, body) = if method == Method::GET {
+            (format!("/quarantine?{params}"), None)
+        } else {
+            ("/quarantine".to_string(), Some(params.as_bytes().to_vec()))
+        };
+        let response = http
+            .send_full(
+                method,
+                &query,
+                body,
+                Some("application/x-www-form-urlencoded"),
+            )
+            .await;
+        assert!(
+            response
+                .body
+                .contains("This message is no longer in quarantine or the link has expired."),
+            "{}",
+            response.body
+        );
+    }
+}
+
+async fn inbox_ids(test: &TestServer, account: &Account) -> Vec<u32> {
+    let mut ids = test
+        .server
+        .get_cached_messages(account.id().document_id())
+        .await
+        .unwrap()
+        .in_mailbox(INBOX_ID)
+        .map(|e| e.document_id)
+        .collect::<Vec<_>>();
+    ids.sort_unstable();
+    ids
+}
@@ -8,7 +8,7 @@ use crate::utils::{
     account::Account, http::HttpRequest, jmap::JmapUtils, server::TestServer, smtp::SmtpConnection,
 };
 use email::{cache::MessageCacheFetch, mailbox::INBOX_ID};
-use jmap::blob::upload::DISABLE_UPLOAD_QUOTA;
+use jmap::{blob::upload::DISABLE_UPLOAD_QUOTA, quota::MAIL_COUNT_QUOTA_ID};
 use jmap_client::{
     core::set::{SetErrorType, SetObject},
     email::EmailBodyPart,
@@ -218,8 +218,10 @@ pub async fn test(test: &mut TestServer) {
                 "ids": null
             }),
         )
-        .await
-        .to_string();
+        .await;
+    let quota_state = response.method_response().text_field("state").to_string();
+    let quota_id = response.list()[0].text_field("id").to_string();
+    let response = response.to_string();
     assert!(response.contains("\"used\":0"), "{}", response);
     assert!(response.contains("\"hardLimit\":1024"), "{}", response);
     assert!(response.contains("\"scope\":\"account\""), "{}", response);
@@ -276,6 +278,112 @@ pub async fn test(test: &mut TestServer) {
     assert!(response.contains("\"used\":1024"), "{}", response);
     assert!(response.contains("\"hardLimit\":1024"), "{}", response);
 
+    // Test Quota/changes
+    let response = account
+        .jmap_method_call(
+            "Quota/changes",
+            json!({
+                "sinceState": quota_state
+            }),
+        )
+        .await;
+    let changes = response.method_response();
+    assert_ne!(changes.text_field("newState"), quota_state, "{response:?}");
+    assert!(
+        changes
+            .pointer("/updated")
+            .and_then(|v| v.as_array())
+            .is_some_and(|ids| ids.contains(&json!(quota_id))),
+        "{response:?}"
+    );
+    let response = account
+        .jmap_method_call(
+            "Quota/changes",
+            json!({
+                "sinceState": changes.text_field("newState")
+            }),
+        )
+        .await;
+    assert_eq!(
+        response.method_response().pointer("/updated"),
+        Some(&json!([])),
+        "{response:?}"
+    );
+
+    // Adding and removing a quota should be reported as created and destroyed
+    let mail_count_id = Id::from(MAIL_COUNT_QUOTA_ID).to_string();
+    let quota_state = response
+        .method_response()
+        .text_field("newState")
+        .to_string();
+    admin
+        .registry_update_object(
+            ObjectType::Account,
+            account_id,
+            json!({
+                "quotas": {
+                    "maxDiskQuota": 1024,
+                    "maxEmails": 100
+                }
+            }),
+        )
+        .await;
+    let response = account
+        .jmap_method_call(
+            "Quota/changes",
+            json!({
+                "sinceState": quota_state
+            }),
+        )
+        .await;
+    let changes = response.method_response();
+    assert_eq!(
+        changes.pointer("/created"),
+        Some(&json!([mail_count_id])),
+        "{response:?}"
+    );
+    assert_eq!(
+        changes.pointer("/destroyed"),
+        Some(&json!([])),
+        "{response:?}"
+    );
+    let quota_state = changes.text_field("newState").to_string();
+    admin
+        .registry_update_object(
+            ObjectType::Account,
+            account_id,
+            json!({
+                "quotas": {
+                    "maxDiskQuota": 1024
+                }
+            }),
+        )
+        .await;
+    let response = account
+        .jmap_method_call(
+            "Quota/changes",
+            json!({
+                "sinceState": quota_state
+            }),
+        )
+        .await;
+    let changes = response.method_response();
+    assert_eq!(
+        changes.pointer("/destroyed"),
+        Some(&json!([mail_count_id])),
+        "{response:?}"
+    );
+    assert_eq!(
+        changes.pointer("/created"),
+        Some(&json!([])),
+        "{response:?}"
+    );
+    assert_eq!(
+        changes.pointer("/updated"),
+        Some(&json!([quota_id])),
+        "{response:?}"
+    );
+
     // Test registry quota
     assert_eq!(
         admin
@@ -0,0 +1,127 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use email::mailbox::INBOX_ID;
+use registry::schema::{
+    enums::TaskAccountMaintenanceType,
+    prelude::Property,
+    structs::{Search, Task, TaskAccountMaintenance, TaskStatus},
+};
+use serde::Deserialize;
+use types::id::Id;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ReindexStatus {
+    account_id: Id,
+    total: u32,
+    indexed: u32,
+    percentage: f64,
+    completed_at: Option<u64>,
+}
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running reindex tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let admin = test.account("admin@example.org");
+
+    // Reindex one document per chunk
+    admin
+        .registry_update_setting(
+            Search {
+                reindex_batch_size: 1,
+                reindex_interval: 0u64.into(),
+                ..Default::default()
+            },
+            &[Property::ReindexBatchSize, Property::ReindexInterval],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Create test account and messages
+    let account = test
+        .create_user_account(
+            "admin@example.org",
+            "reindex@example.org",
+            "this is a very strong password",
+            &[],
+            "reindex@example.org",
+        )
+        .await;
+    let client = account.jmap_client().await;
+    let inbox_id = Id::from(INBOX_ID).to_string();
+    for num in 0..3 {
+        client
+            .email_import(
+                format!(
+                    concat!(
+                        "From: bill@example.org\r\n",
+                        "To: reindex@example.org\r\n",
+                        "Subject: TPS Report #{}\r\n",
+                        "\r\n",
+                        "Did you get the memo about the new cover sheets?"
+                    ),
+                    num
+                )
+                .into_bytes(),
+                [&inbox_id],
+                None::<Vec<&str>>,
+                None,
+            )
+            .await
+            .unwrap();
+    }
+    test.wait_for_tasks().await;
+
+    // No reindex has been requested yet
+    assert!(
+        http.get::<ReindexStatus>(&format!("/api/reindex/{}", account.id()))
+            .await
+            .is_err()
+    );
+
+    // Reindex the account in chunks
+    admin
+        .registry_create_object(Task::AccountMaintenance(TaskAccountMaintenance {
+            account_id: account.id(),
+            maintenance_type: TaskAccountMaintenanceType::Reindex,
+            status: TaskStatus::now(),
+        }))
+        .await;
+    test.wait_for_tasks().await;
+
+    let status = http
+        .get::<ReindexStatus>(&format!("/api/reindex/{}", account.id()))
+        .await
+        .unwrap();
+    assert_eq!(status.account_id, account.id());
+    assert_eq!(status.total, 3, "{status:?}");
+    assert_eq!(status.indexed, 3, "{status:?}");
+    assert_eq!(status.percentage, 100.0, "{status:?}");
+    assert!(status.completed_at.is_some(), "{status:?}");
+
+    // Progress is listed for all accounts
+    let statuses = http
+        .get::<Vec<ReindexStatus>>("/api/reindex")
+        .await
+        .unwrap();
+    assert!(
+        statuses
+            .iter()
+            .any(|status| status.account_id == account.id() && status.completed_at.is_some()),
+        "{statuses:?}"
+    );
+
+    // Restore settings
+    admin
+        .registry_update_setting(
+            Search::default(),
+            &[Property::ReindexBatchSize, Property::ReindexInterval],
+        )
+        .await;
+    admin.reload_settings().await;
+}
@@ -21,14 +21,18 @@ use jmap_client::{
 };
 use registry::{
     schema::{
-        enums::BlockReason,
+        enums::{BlockReason, NetworkListenerProtocol},
         prelude::{ObjectType, Property},
-        structs::{Action, BlockedIp, Http, Jmap},
+        structs::{Action, BlockedIp, DataRetention, Http, Jmap, LoginEntry, Rate, Security},
     },
-    types::ipmask::IpAddrOrMask,
+    types::{ipaddr::IpAddr, ipmask::IpAddrOrMask},
 };
 use serde_json::json;
-use std::{net::Ipv4Addr, sync::Arc, time::Duration};
+use std::{
+    net::Ipv4Addr,
+    sync::Arc,
+    time::{Duration, Instant},
+};
 use store::{registry::write::RegistryWrite, write::now};
 use types::id::Id;
 
@@ -375,6 +379,188 @@ pub async fn test(test: &mut TestServer) {
     // Wait for sleep to be done before continuing
     tokio::time::sleep(Duration::from_millis(1000)).await;
 
+    // Writes are rate limited separately from queries
+    admin
+        .registry_update_setting(
+            Http {
+                use_x_forwarded: true,
+                rate_limit_set: Some(Rate {
+                    count: 2,
+                    period: 60_000u64.into(),
+                }),
+                ..Default::default()
+            },
+            &[Property::RateLimitSet],
+        )
+        .await;
+    admin.reload_settings().await;
+    let set_body = serde_json::to_vec(&json!({
+        "using": ["urn:ietf:params:jmap:core", "urn:ietf:params:jmap:mail"],
+        "methodCalls": [
+            ["Mailbox/set", {
+                "accountId": user_id.to_string(),
+                "destroy": ["zzzz"]
+            }, "c1"]
+        ]
+    }))
+    .unwrap();
+    for remaining in [1, 0] {
+        let resp = raw_http
+            .send_full(
+                hyper::Method::POST,
+                "/jmap/",
+                Some(set_body.clone()),
+                Some("application/json"),
+            )
+            .await;
+        assert_eq!(resp.status.as_u16(), 200, "set body: {}", resp.body);
+        let policy = resp
+            .rate_limit_policy()
+            .unwrap_or_else(|| panic!("missing RateLimit-Policy header on {:?}", resp.headers));
+        assert!(
+            policy.contains("\"set\"") && policy.contains("q=2") && policy.contains("w=60"),
+            "RateLimit-Policy = {policy}"
+        );
+        let state = resp
+            .rate_limit()
+            .unwrap_or_else(|| panic!("missing RateLimit header on {:?}", resp.headers));
+        assert!(
+            state.contains(&format!("r={remaining}")),
+            "RateLimit = {state}"
+        );
+    }
+    let resp = raw_http
+        .send_full(
+            hyper::Method::POST,
+            "/jmap/",
+            Some(set_body),
+            Some("application/json"),
+        )
+        .await;
+    assert_eq!(resp.status.as_u16(), 429, "set body: {}", resp.body);
+    assert!(
+        resp.rate_limit_policy()
+            .is_some_and(|policy| policy.contains("\"set\"")),
+        "{:?}",
+        resp.headers
+    );
+    client
+        .mailbox_query(None::<mailbox::query::Filter>, None::<Vec<_>>)
+        .await
+        .unwrap();
+    admin
+        .registry_update_setting(
+            Http {
+                use_x_forwarded: true,
+                ..Default::default()
+            },
+            &[Property::RateLimitSet],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Failed logins delay further attempts against the same account from any address
+    admin
+        .registry_update_setting(
+            Security {
+                auth_delay: Some(Duration::from_secs(1).into()),
+                auth_delay_max: Duration::from_secs(2).into(),
+                ..Default::default()
+            },
+            &[Property::AuthDelay, Property::AuthDelayMax],
+        )
+        .await;
+    admin.reload_settings().await;
+    for (remote_ip, min_delay) in [("10.0.0.5", 0), ("10.0.0.6", 1), ("10.0.0.7", 2)] {
+        let time = Instant::now();
+        validate_password_with_ip("user@example.org", "wrong password", remote_ip, false).await;
+        let elapsed = time.elapsed();
+        assert!(
+            elapsed >= Duration::from_secs(min_delay)
+                && elapsed < Duration::from_secs(min_delay + 1),
+            "attempt from {remote_ip} took {elapsed:?}"
+        );
+    }
+
+    // The delay is capped and a successful login clears it
+    let mut imap = ImapConnection::connect(b"_z ").await;
+    let time = Instant::now();
+    imap.authenticate("user@example.org", "this is a very strong password")
+        .await;
+    let elapsed = time.elapsed();
+    assert!(
+        elapsed >= Duration::from_secs(2) && elapsed < Duration::from_secs(3),
+        "{elapsed:?}"
+    );
+    let time = Instant::now();
+    validate_password_with_ip("user@example.org", "wrong password", "10.0.0.8", false).await;
+    assert!(
+        time.elapsed() < Duration::from_secs(1),
+        "{:?}",
+        time.elapsed()
+    );
+    admin
+        .registry_update_setting(
+            Security::default(),
+            &[Property::AuthDelay, Property::AuthDelayMax],
+        )
+        .await;
+    admin.reload_settings().await;
+
+    // Logins are recorded once per device and can be listed and removed by the user
+    admin
+        .registry_update_setting(
+            DataRetention {
+                hold_login_history_for: Some(Duration::from_secs(86400).into()),
+                ..Default::default()
+            },
+            &[Property::HoldLoginHistoryFor],
+        )
+        .await;
+    admin.reload_settings().await;
+    for _ in 0..2 {
+        let mut imap = ImapConnection::connect(b"_w ").await;
+        imap.authenticate("user@example.org", "this is a very strong password")
+            .await;
+        imap.send_ok("ID (\"name\" \"Thunderbird\" \"version\" \"128.0\")")
+            .await;
+    }
+    let entries = user
+        .registry_get_all::<LoginEntry>()
+        .await
+        .into_iter()
+        .filter(|(_, entry)| entry.protocol == NetworkListenerProtocol::Imap)
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 1, "{entries:?}");
+    let (entry_id, entry) = &entries[0];
+    assert_eq!(entry.account_id, user_id);
+    assert_eq!(entry.user_agent.as_deref(), Some("Thunderbird 128.0"));
+    assert_eq!(
+        entry.remote_ip,
+        IpAddr(std::net::IpAddr::V4(Ipv4Addr::LOCALHOST))
+    );
+    assert!(
+        admin
+            .registry_get_all::<LoginEntry>()
+            .await
+            .iter()
+            .any(|(id, _)| id == entry_id)
+    );
+    user.registry_destroy(ObjectType::LoginEntry, [entry_id])
+        .await
+        .assert_destroyed(&[*entry_id]);
+    assert!(
+        !user
+            .registry_get_all::<LoginEntry>()
+            .await
+            .iter()
+            .any(|(id, _)| id == entry_id)
+    );
+    admin
+        .registry_update_setting(DataRetention::default(), &[Property::HoldLoginHistoryFor])
+        .await;
+    admin.reload_settings().await;
+
     // Disable X-Forwarded-For processing
     admin
         .registry_update_setting(
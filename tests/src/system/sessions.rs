@@ -0,0 +1,132 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{
+    http::HttpRequest,
+    imap::{ImapConnection, Type},
+    server::TestServer,
+};
+use imap_proto::ResponseType;
+use serde::Deserialize;
+use serde_json::json;
+use std::time::Duration;
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct ActiveSession {
+    id: u64,
+    protocol: String,
+    account_id: Option<u32>,
+    bytes_received: u64,
+    bytes_sent: u64,
+}
+
+#[derive(Debug, Deserialize)]
+struct TerminateResponse {
+    terminated: Vec<u64>,
+}
+
+pub async fn test(test: &mut TestServer) {
+    println!("Running session manager tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let account = test
+        .create_user_account(
+            "admin@example.org",
+            "sessions@example.org",
+            "this is a very strong password",
+            &[],
+            "sessions@example.org",
+        )
+        .await;
+    let account_id = account.id().document_id();
+    let query = format!("/api/sessions?protocol=imap&accountId={account_id}");
+
+    // Open two authenticated IMAP sessions
+    let mut connections = Vec::new();
+    for _ in 0..2 {
+        let mut imap = ImapConnection::connect(b"_x ").await;
+        imap.assert_read(Type::Untagged, ResponseType::Ok).await;
+        imap.authenticate("sessions@example.org", "this is a very strong password")
+            .await;
+        connections.push(imap);
+    }
+
+    let sessions = http.get::<Vec<ActiveSession>>(&query).await.unwrap();
+    assert_eq!(sessions.len(), 2, "{sessions:?}");
+    for session in &sessions {
+        assert_eq!(session.protocol, "imap");
+        assert_eq!(session.account_id, Some(account_id));
+        assert!(session.bytes_received > 0, "{session:?}");
+        assert!(session.bytes_sent > 0, "{session:?}");
+    }
+    let session = http
+        .get::<ActiveSession>(&format!("/api/sessions/{}", sessions[0].id))
+        .await
+        .unwrap();
+    assert_eq!(session.id, sessions[0].id);
+
+    // Unauthenticated sessions are not listed under the account
+    let mut anonymous = ImapConnection::connect(b"_y ").await;
+    anonymous
+        .assert_read(Type::Untagged, ResponseType::Ok)
+        .await;
+    assert_eq!(
+        http.get::<Vec<ActiveSession>>(&query).await.unwrap().len(),
+        2
+    );
+
+    // Terminate a single session
+    let response = http
+        .delete::<TerminateResponse>(&format!("/api/sessions/{}", sessions[0].id))
+        .await
+        .unwrap();
+    assert_eq!(response.terminated, vec![sessions[0].id]);
+
+    // Session ids are increasing, the first session belongs to the first connection
+    let mut first = connections.remove(0);
+    let mut second = connections.remove(0);
+    first.assert_disconnect().await;
+    assert!(wait_for_sessions(&http, &query, 1).await);
+    second.send("NOOP").await;
+    second.assert_read(Type::Tagged, ResponseType::Ok).await;
+    assert!(
+        http.get::<ActiveSession>(&format!("/api/sessions/{}", sessions[0].id))
+            .await
+            .is_err()
+    );
+    assert!(
+        http.delete::<TerminateResponse>(&format!("/api/sessions/{}", sessions[0].id))
+            .await
+            .is_err()
+    );
+
+    // Terminate the remaining sessions in bulk, unknown ids are ignored
+    let response = http
+        .post::<TerminateResponse>(
+            "/api/sessions/terminate",
+            &json!({ "ids": [sessions[1].id, sessions[0].id] }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(response.terminated, vec![sessions[1].id]);
+    second.assert_disconnect().await;
+    assert!(wait_for_sessions(&http, &query, 0).await);
+
+    // The anonymous session is still active
+    anonymous.send("NOOP").await;
+    anonymous.assert_read(Type::Tagged, ResponseType::Ok).await;
+}
+
+async fn wait_for_sessions(http: &HttpRequest, query: &str, expected: usize) -> bool {
+    for _ in 0..20 {
+        if http.get::<Vec<ActiveSession>>(query).await.unwrap().len() == expected {
+            return true;
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+    }
+
+    false
+}
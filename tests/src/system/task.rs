@@ -4,18 +4,20 @@
  * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
  */
 
-use crate::utils::{account::Account, server::TestServer};
+use crate::utils::{account::Account, http::HttpRequest, server::TestServer};
 use registry::{
     schema::{
-        enums::TaskStoreMaintenanceType,
+        enums::{TaskStoreMaintenanceType, TaskType},
         prelude::{ObjectType, Property},
         structs::{
-            Task, TaskManager, TaskRetryStrategy, TaskRetryStrategyFixed, TaskStatus,
-            TaskStatusFailed, TaskStatusPending, TaskStatusRetry, TaskStoreMaintenance,
+            Cron, CronDaily, Task, TaskMaintenanceWindow, TaskManager, TaskRetryStrategy,
+            TaskRetryStrategyFixed, TaskStatus, TaskStatusFailed, TaskStatusPending,
+            TaskStatusRetry, TaskStoreMaintenance,
         },
     },
-    types::datetime::UTCDateTime,
+    types::{datetime::UTCDateTime, list::List, map::Map},
 };
+use serde::Deserialize;
 use serde_json::json;
 use store::write::now;
 use types::id::Id;
@@ -24,6 +26,28 @@ const TASK_SUCCESS: u64 = 0;
 const TASK_TEMP_FAIL: u64 = 1;
 const TASK_PERM_FAIL: u64 = 2;
 
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct MaintenanceWindow {
+    id: u32,
+    task_types: Vec<String>,
+    is_open: bool,
+    is_triggered: bool,
+    opens_at: u64,
+    closes_at: Option<u64>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TaskSummary {
+    id: Id,
+    #[serde(rename = "type")]
+    typ: String,
+    status: String,
+    due: Option<u64>,
+    attempt_number: u64,
+}
+
 pub async fn test(test: &mut TestServer) {
     println!("Running Task manager tests...");
     let admin = test.account("admin@example.org");
@@ -78,6 +102,7 @@ pub async fn test(test: &mut TestServer) {
                     delay: 1_000u64.into(),
                 }),
                 total_deadline: 86_400_000u64.into(), // 24 hours
+                ..Default::default()
             },
             &[],
         )
@@ -112,6 +137,7 @@ pub async fn test(test: &mut TestServer) {
                     delay: 1_000u64.into(),
                 }),
                 total_deadline: 2_000u64.into(), // 2 seconds
+                ..Default::default()
             },
             &[],
         )
@@ -138,6 +164,8 @@ pub async fn test(test: &mut TestServer) {
         .assert_destroyed(&[task.id]);
 
     pagination_test(test).await;
+    api_test(test).await;
+    maintenance_window_test(test).await;
 
     test.cleanup().await;
 }
@@ -281,6 +309,140 @@ async fn pagination_test(test: &mut TestServer) {
     admin.assert_no_tasks().await;
 }
 
+async fn api_test(test: &mut TestServer) {
+    println!("Running Task API tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let admin = test.account("admin@example.org");
+
+    admin.assert_no_tasks().await;
+    let first_id = admin.schedule_test_task(TASK_SUCCESS, 3600).await;
+    let second_id = admin.schedule_test_task(TASK_SUCCESS, 7200).await;
+
+    // Queued tasks are listed in the order they are due
+    let tasks = http.get::<Vec<TaskSummary>>("/api/tasks").await.unwrap();
+    assert_eq!(
+        tasks.iter().map(|task| task.id).collect::<Vec<_>>(),
+        vec![first_id, second_id],
+        "{tasks:?}"
+    );
+    assert!(
+        tasks.iter().all(|task| task.typ == "StoreMaintenance"
+            && task.status == "Pending"
+            && task.due.is_some()
+            && task.attempt_number == 0),
+        "{tasks:?}"
+    );
+    assert_eq!(
+        http.get::<Vec<TaskSummary>>("/api/tasks?limit=1")
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(
+        http.get::<Vec<TaskSummary>>("/api/tasks?type=DkimManagement")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let task = http
+        .get::<TaskSummary>(&format!("/api/tasks/{second_id}"))
+        .await
+        .unwrap();
+    assert_eq!(task.id, second_id);
+
+    // Cancelling a queued task removes it from the queue
+    http.delete::<TaskSummary>(&format!("/api/tasks/{first_id}"))
+        .await
+        .unwrap();
+    let tasks = admin.assert_has_tasks(1).await;
+    assert_eq!(tasks[0].id, second_id);
+    assert!(
+        http.get::<TaskSummary>(&format!("/api/tasks/{first_id}"))
+            .await
+            .is_err()
+    );
+    assert!(
+        http.delete::<TaskSummary>(&format!("/api/tasks/{first_id}"))
+            .await
+            .is_err()
+    );
+
+    admin
+        .registry_destroy(ObjectType::Task, [second_id])
+        .await
+        .assert_destroyed(&[second_id]);
+    admin.assert_no_tasks().await;
+}
+
+async fn maintenance_window_test(test: &mut TestServer) {
+    println!("Running maintenance window tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let admin = test.account("admin@example.org");
+
+    // Store maintenance is restricted to a window that opens in about 12 hours
+    admin.assert_no_tasks().await;
+    admin
+        .registry_update_setting(
+            TaskManager {
+                maintenance_windows: List::from_iter([TaskMaintenanceWindow {
+                    task_types: Map::new(vec![TaskType::StoreMaintenance]),
+                    schedule: Cron::Daily(CronDaily {
+                        hour: (now() / 3600 + 12) % 24,
+                        minute: 0,
+                    }),
+                    duration: 60_000u64.into(),
+                    rate: None,
+                }]),
+                ..Default::default()
+            },
+            &[],
+        )
+        .await;
+    admin.reload_settings().await;
+    let windows = http
+        .get::<Vec<MaintenanceWindow>>("/api/maintenance")
+        .await
+        .unwrap();
+    assert_eq!(windows.len(), 1, "{windows:?}");
+    let window = &windows[0];
+    assert_eq!(window.task_types, ["StoreMaintenance"]);
+    assert!(!window.is_open && !window.is_triggered, "{window:?}");
+    assert!(window.opens_at > now(), "{window:?}");
+    assert_eq!(window.closes_at, None);
+
+    // Tasks outside their window are deferred
+    let task_id = admin.schedule_test_task(TASK_SUCCESS, 0).await;
+    tokio::time::sleep(std::time::Duration::from_millis(500)).await;
+    assert_eq!(admin.assert_has_tasks(1).await[0].id, task_id);
+
+    // Triggering the window runs the deferred tasks
+    let window = http
+        .post::<MaintenanceWindow>(&format!("/api/maintenance/{}/trigger", window.id), &())
+        .await
+        .unwrap();
+    assert!(window.is_open && window.is_triggered, "{window:?}");
+    test.wait_for_tasks().await;
+    admin.assert_no_tasks().await;
+    assert!(
+        http.post::<MaintenanceWindow>("/api/maintenance/1000/trigger", &())
+            .await
+            .is_err()
+    );
+
+    // Remove maintenance windows
+    admin
+        .registry_update_setting(TaskManager::default(), &[])
+        .await;
+    admin.reload_settings().await;
+    assert!(
+        http.get::<Vec<MaintenanceWindow>>("/api/maintenance")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+}
+
 impl Account {
     async fn schedule_test_task(&self, test_type: u64, schedule_in: u64) -> Id {
         self.registry_create_object(Task::StoreMaintenance(TaskStoreMaintenance {
@@ -8,25 +8,28 @@
  *
  */
 
-use crate::utils::{jmap::JmapUtils, server::TestServer};
+use crate::utils::{http::HttpRequest, jmap::JmapUtils, server::TestServer};
 use ahash::{AHashMap, AHashSet};
 use common::auth::BuildAccessToken;
 use email::message::delivery::{IngestMessage, IngestRecipient, LocalDeliveryStatus, MailDelivery};
+use hyper::Method;
 use jmap_proto::error::set::SetErrorType;
 use registry::{
     schema::{
         enums::{AccountType, Permission, TenantStorageQuota},
         prelude::{ObjectType, Property},
         structs::{
-            Account, CertificateManagement, Credential, Dkim1Signature, DkimManagement,
-            DkimSignature, DnsManagement, DnsServer, DnsServerCloudflare, Domain, GroupAccount,
-            MailingList, OAuthClient, PasswordCredential, Permissions, PermissionsList, Role,
-            SecretKey, SecretKeyValue, SecretText, SecretTextValue, Tenant, UserAccount, UserRoles,
+            Account, CertificateManagement, Credential, DataRetention, Dkim1Signature,
+            DkimManagement, DkimSignature, DnsManagement, DnsServer, DnsServerCloudflare, Domain,
+            GroupAccount, MailingList, OAuthClient, PasswordCredential, Permissions,
+            PermissionsList, Role, SecretKey, SecretKeyValue, SecretText, SecretTextValue, Tenant,
+            UserAccount, UserRoles,
         },
     },
     types::{EnumImpl, ObjectImpl, list::List, map::Map},
 };
 use serde_json::json;
+use std::time::Duration;
 use types::id::Id;
 use utils::map::vec_map::VecMap;
 
@@ -530,7 +533,8 @@ pub async fn test(test: &mut TestServer) {
                 recipients: vec![IngestRecipient {
                     address: "user@tenantx.org".to_string(),
                     orcpt: None,
-                    is_spam: false
+                    is_spam: false,
+                    is_quarantined: false
                 }],
                 message_blob: message_blob.clone(),
                 message_size: TEST_MESSAGE.len() as u64,
@@ -567,7 +571,8 @@ pub async fn test(test: &mut TestServer) {
                 recipients: vec![IngestRecipient {
                     address: "user@tenantx.org".to_string(),
                     orcpt: None,
-                    is_spam: false
+                    is_spam: false,
+                    is_quarantined: false
                 }],
                 message_blob,
                 message_size: TEST_MESSAGE.len() as u64,
@@ -581,6 +586,98 @@ pub async fn test(test: &mut TestServer) {
     );
     test.wait_for_tasks().await;
 
+    // Usage is metered per tenant and tenant administrators only export their own
+    admin_system
+        .registry_update_setting(
+            DataRetention {
+                hold_tenant_usage_for: Some(Duration::from_secs(86400).into()),
+                ..Default::default()
+            },
+            &[Property::HoldTenantUsageFor],
+        )
+        .await;
+    admin_system.reload_settings().await;
+    let user_y_id = tenant_y_ids[&ObjectType::Account];
+    let (message_blob, _) = test
+        .server
+        .put_temporary_blob(user_y_id.document_id(), TEST_MESSAGE.as_bytes(), 60)
+        .await
+        .unwrap();
+    assert_eq!(
+        test.server
+            .deliver_message(IngestMessage {
+                sender_address: "bill@foobar.org".to_string(),
+                sender_authenticated: true,
+                recipients: vec![IngestRecipient {
+                    address: "user@tenanty.org".to_string(),
+                    orcpt: None,
+                    is_spam: false,
+                    is_quarantined: false
+                }],
+                message_blob,
+                message_size: TEST_MESSAGE.len() as u64,
+                session_id: 0,
+            })
+            .await
+            .status,
+        vec![LocalDeliveryStatus::Success]
+    );
+    test.server.store_usage().await.unwrap();
+    let tenant_x_id = tenant_x_ids[&ObjectType::Tenant].to_string();
+    let tenant_y_id = tenant_y_ids[&ObjectType::Tenant].to_string();
+    let http_y = HttpRequest::with_credentials(8899, "admin@tenanty.org", "tenant y secret");
+    let usage = http_y
+        .get::<Vec<serde_json::Value>>("/api/usage")
+        .await
+        .unwrap();
+    assert_eq!(usage.len(), 1, "{usage:?}");
+    assert_eq!(usage[0]["tenantId"], tenant_y_id.as_str());
+    assert!(
+        usage[0]["messagesReceived"].as_u64().unwrap() >= 1
+            && usage[0]["bytesReceived"].as_u64().unwrap() >= TEST_MESSAGE.len() as u64,
+        "{usage:?}"
+    );
+    let response = http_y
+        .send_full(
+            Method::GET,
+            &format!("/api/usage?tenantId={tenant_x_id}"),
+            None,
+            None,
+        )
+        .await;
+    assert_eq!(response.status.as_u16(), 404, "{}", response.body);
+    let response = http_y
+        .send_full(Method::GET, "/api/usage?format=csv", None, None)
+        .await;
+    assert!(response.status.is_success(), "{}", response.body);
+    let mut lines = response.body.lines();
+    assert_eq!(
+        lines.next(),
+        Some(
+            "tenantId,from,to,storageUsed,messagesSent,messagesReceived,bytesSent,bytesReceived,apiCalls"
+        )
+    );
+    assert!(
+        lines.all(|line| line.starts_with(&format!("{tenant_y_id},"))),
+        "{}",
+        response.body
+    );
+    let usage =
+        HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty")
+            .get::<Vec<serde_json::Value>>(&format!("/api/usage?tenantId={tenant_x_id}"))
+            .await
+            .unwrap();
+    assert_eq!(usage.len(), 1, "{usage:?}");
+    assert_eq!(usage[0]["tenantId"], tenant_x_id.as_str());
+    assert!(usage[0]["storageUsed"].as_u64().unwrap() > 0, "{usage:?}");
+    admin_system
+        .registry_destroy_all(ObjectType::TenantUsage)
+        .await;
+    admin_system
+        .registry_update_setting(DataRetention::default(), &[Property::HoldTenantUsageFor])
+        .await;
+    admin_system.reload_settings().await;
+
     // Delete everything created during the test
     for (admin, tenant_id_pos) in [(&admin_x, 0), (&admin_y, 1)] {
         for object_type in [
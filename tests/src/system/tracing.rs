@@ -0,0 +1,128 @@
+/*
+ * SPDX-FileCopyrightText: 2020 Stalwart Labs LLC <hello@stalw.art>
+ *
+ * SPDX-License-Identifier: AGPL-3.0-only OR LicenseRef-SEL
+ */
+
+use crate::utils::{http::HttpRequest, server::TestServer};
+use hyper::Method;
+use serde::Deserialize;
+use serde_json::json;
+use trc::{Collector, EventType, SmtpEvent};
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct TracingRule {
+    id: u64,
+    events: Vec<String>,
+    level: Option<String>,
+    sample_rate: Option<u32>,
+    remote_ip: Option<String>,
+    expires_at: Option<u64>,
+}
+
+pub async fn test(_test: &mut TestServer) {
+    println!("Running runtime tracing rule tests...");
+    let http = HttpRequest::with_credentials(8899, "admin", "these_pretzels_are_making_me_thirsty");
+    let event = EventType::Smtp(SmtpEvent::RawInput);
+
+    // Raise the level of SMTP events for a single IP address
+    let verbose = http
+        .post::<TracingRule>(
+            "/api/tracing/rules",
+            &json!({
+                "events": ["smtp.*"],
+                "level": "trace",
+                "remoteIp": "10.0.0.1",
+                "expiresIn": 3600
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(verbose.level.as_deref(), Some("trace"));
+    assert_eq!(verbose.remote_ip.as_deref(), Some("10.0.0.1"));
+    assert!(verbose.expires_at.is_some());
+    assert!(
+        verbose
+            .events
+            .iter()
+            .all(|event| event.starts_with("smtp."))
+    );
+    assert!(Collector::has_interest(event));
+
+    // Sample noisy events
+    let sampled = http
+        .post::<TracingRule>(
+            "/api/tracing/rules",
+            &json!({
+                "events": ["network.*"],
+                "sampleRate": 10
+            }),
+        )
+        .await
+        .unwrap();
+    assert_eq!(sampled.sample_rate, Some(10));
+    assert!(sampled.level.is_none());
+
+    let rules = http
+        .get::<Vec<TracingRule>>("/api/tracing/rules")
+        .await
+        .unwrap();
+    assert_eq!(
+        rules.iter().map(|rule| rule.id).collect::<Vec<_>>(),
+        vec![verbose.id, sampled.id]
+    );
+    assert_eq!(
+        http.get::<TracingRule>(&format!("/api/tracing/rules/{}", sampled.id))
+            .await
+            .unwrap()
+            .events,
+        sampled.events
+    );
+
+    // Invalid rules are rejected
+    for request in [
+        json!({ "events": ["smtp.unknown-event"], "level": "trace" }),
+        json!({ "events": ["smtp.*"] }),
+        json!({ "events": ["smtp.*"], "level": "loud" }),
+        json!({ "events": ["smtp.*"], "sampleRate": 1 }),
+        json!({ "events": ["smtp.*"], "level": "trace", "sampleRate": 10 }),
+        json!({ "events": ["smtp.*"], "level": "trace", "accountId": 1, "remoteIp": "10.0.0.1" }),
+    ] {
+        let response = http
+            .send_full(
+                Method::POST,
+                "/api/tracing/rules",
+                Some(request.to_string().into_bytes()),
+                Some("application/json"),
+            )
+            .await;
+        assert!(
+            !response.status.is_success(),
+            "{request}: {}",
+            response.body
+        );
+    }
+
+    // Remove rules
+    for id in [verbose.id, sampled.id] {
+        http.delete::<serde_json::Value>(&format!("/api/tracing/rules/{id}"))
+            .await
+            .unwrap();
+    }
+    assert!(
+        http.get::<Vec<TracingRule>>("/api/tracing/rules")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let response = http
+        .send_full(
+            Method::DELETE,
+            &format!("/api/tracing/rules/{}", verbose.id),
+            None,
+            None,
+        )
+        .await;
+    assert!(!response.status.is_success(), "{}", response.body);
+}
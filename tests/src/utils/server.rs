@@ -20,7 +20,7 @@ use common::{
     BuildServer, Caches, Core, Data, DavResources, Inner, Server,
     auth::RECOVERY_ADMIN_ID,
     config::{
-        server::{Listeners, ServerProtocol},
+        server::{Listener, Listeners, ServerProtocol},
         storage::Storage,
         telemetry::Telemetry,
     },
@@ -29,6 +29,7 @@ use common::{
         boot::{IpcReceivers, build_ipc},
         defaults::BootstrapDefaults,
     },
+    network::{TcpAcceptor, listen::ListenerHandle},
     psl,
 };
 use email::message::metadata::MessageMetadata;
@@ -408,40 +409,7 @@ impl TestServerBuilder {
         }
 
         // Spawn servers
-        let (shutdown_tx, shutdown_rx) = servers.spawn(|server, acceptor, shutdown_rx| {
-            match &server.protocol {
-                ServerProtocol::Smtp | ServerProtocol::Lmtp => server.spawn(
-                    SmtpSessionManager::new(inner.clone()),
-                    inner.clone(),
-                    acceptor,
-                    shutdown_rx,
-                ),
-                ServerProtocol::Http => server.spawn(
-                    HttpSessionManager::new(inner.clone()),
-                    inner.clone(),
-                    acceptor,
-                    shutdown_rx,
-                ),
-                ServerProtocol::Imap => server.spawn(
-                    ImapSessionManager::new(inner.clone()),
-                    inner.clone(),
-                    acceptor,
-                    shutdown_rx,
-                ),
-                ServerProtocol::Pop3 => server.spawn(
-                    Pop3SessionManager::new(inner.clone()),
-                    inner.clone(),
-                    acceptor,
-                    shutdown_rx,
-                ),
-                ServerProtocol::ManageSieve => server.spawn(
-                    ManageSieveSessionManager::new(inner.clone()),
-                    inner.clone(),
-                    acceptor,
-                    shutdown_rx,
-                ),
-            };
-        });
+        let (shutdown_tx, shutdown_rx) = servers.spawn_managed(&inner, spawn_listener).await;
 
         // Start broadcast subscriber
         if !self.disable_services {
@@ -599,6 +567,46 @@ impl TestServer {
     }
 }
 
+fn spawn_listener(
+    server: Listener,
+    inner: Arc<Inner>,
+    acceptor: TcpAcceptor,
+    shutdown_rx: watch::Receiver<bool>,
+) -> ListenerHandle {
+    match &server.protocol {
+        ServerProtocol::Smtp | ServerProtocol::Lmtp => server.spawn(
+            SmtpSessionManager::new(inner.clone()),
+            inner,
+            acceptor,
+            shutdown_rx,
+        ),
+        ServerProtocol::Http => server.spawn(
+            HttpSessionManager::new(inner.clone()),
+            inner,
+            acceptor,
+            shutdown_rx,
+        ),
+        ServerProtocol::Imap => server.spawn(
+            ImapSessionManager::new(inner.clone()),
+            inner,
+            acceptor,
+            shutdown_rx,
+        ),
+        ServerProtocol::Pop3 => server.spawn(
+            Pop3SessionManager::new(inner.clone()),
+            inner,
+            acceptor,
+            shutdown_rx,
+        ),
+        ServerProtocol::ManageSieve => server.spawn(
+            ManageSieveSessionManager::new(inner.clone()),
+            inner,
+            acceptor,
+            shutdown_rx,
+        ),
+    }
+}
+
 impl Account {
     pub async fn destroy_all_mailboxes_for_account(&self, account_id: u32) {
         let mut client = self.jmap_client().await;
@@ -15,11 +15,11 @@ use registry::{
         structs::{
             BlobStore, DataStore, ElasticSearchStore, FileSystemStore, FoundationDbStore, HttpAuth,
             HttpAuthBasic, InMemoryStore, MeilisearchStore, MySqlStore, PostgreSqlStore,
-            RedisStore, RocksDbStore, S3Store, S3StoreCustomRegion, S3StoreRegion, SearchStore,
-            SecretKey, SecretKeyOptional, SecretKeyValue, SqliteStore,
+            RedisSentinelStore, RedisStore, RocksDbStore, S3Store, S3StoreCustomRegion,
+            S3StoreRegion, SearchStore, SecretKey, SecretKeyOptional, SecretKeyValue, SqliteStore,
         },
     },
-    types::{EnumImpl, duration::Duration},
+    types::{EnumImpl, duration::Duration, map::Map},
 };
 use store::write::now;
 use store::{
@@ -127,6 +127,11 @@ fn build_in_memory_store(typ: InMemoryStoreType, _path: &str) -> InMemoryStore {
             url: "redis://127.0.0.1".into(),
             ..Default::default()
         }),
+        InMemoryStoreType::RedisSentinel => InMemoryStore::RedisSentinel(RedisSentinelStore {
+            urls: Map::new(vec!["redis://127.0.0.1:26379".into()]),
+            master_name: "mymaster".into(),
+            ..Default::default()
+        }),
         _ => unreachable!(),
     }
 }